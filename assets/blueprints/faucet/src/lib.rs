@@ -1,12 +1,44 @@
 use scrypto::prelude::*;
 
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ClaimEvent {
+    pub account: ComponentAddress,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DripAmountChangedEvent {
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ClaimIntervalChangedEvent {
+    pub epochs: u64,
+}
+
 // Faucet - TestNet only
 #[blueprint]
-#[types(Hash, Epoch)]
+#[events(ClaimEvent, DripAmountChangedEvent, ClaimIntervalChangedEvent)]
+#[types(Hash, Epoch, ComponentAddress)]
 mod faucet {
+    enable_method_auth! {
+        methods {
+            free => PUBLIC;
+            claim => PUBLIC;
+            lock_fee => PUBLIC;
+            set_drip_amount => restrict_to: [OWNER];
+            set_claim_interval => restrict_to: [OWNER];
+        }
+    }
+
     struct Faucet {
         vault: Vault,
         transactions: KeyValueStore<Hash, Epoch>,
+        /// The epoch of the last claim made by each account
+        claims: KeyValueStore<ComponentAddress, Epoch>,
+        drip_amount: Decimal,
+        /// The number of epochs an account has to wait between claims
+        claim_interval_in_epochs: u64,
     }
 
     impl Faucet {
@@ -17,9 +49,14 @@ mod faucet {
             Self {
                 vault: Vault::with_bucket(bucket),
                 transactions: KeyValueStore::new_with_registered_type(),
+                claims: KeyValueStore::new_with_registered_type(),
+                drip_amount: 10000.into(),
+                claim_interval_in_epochs: 1,
             }
             .instantiate()
-            .prepare_to_globalize(OwnerRole::None)
+            .prepare_to_globalize(OwnerRole::Updatable(rule!(require(
+                AuthAddresses::system_role()
+            ))))
             .with_address(address_reservation)
             .metadata(metadata! {
                 init {
@@ -37,13 +74,33 @@ mod faucet {
             assert!(self.transactions.get(&transaction_hash).is_none());
             self.transactions.insert(transaction_hash, epoch);
 
-            let amount: Decimal = 10000.into();
+            self.take_drip()
+        }
 
-            if self.vault.amount() < amount {
-                panic!("The faucet doesn't have funds on this environment. You will need to source XRD another way.")
+        /// Deposits tokens into an account, at most once every `claim_interval_in_epochs` epochs
+        pub fn claim(&mut self, mut account: Global<Account>) {
+            let address = account.address();
+            let epoch = Runtime::current_epoch();
+            if let Some(last_claim) = self.claims.get(&address).map(|last| *last) {
+                let next_claim = last_claim
+                    .after(self.claim_interval_in_epochs)
+                    .unwrap_or(Epoch::of(u64::MAX));
+                if epoch < next_claim {
+                    panic!(
+                        "Account has already claimed in epoch {}, next claim is possible in epoch {}",
+                        last_claim.number(),
+                        next_claim.number()
+                    );
+                }
             }
+            self.claims.insert(address, epoch);
 
-            self.vault.take(amount)
+            let bucket = self.take_drip();
+            Runtime::emit_event(ClaimEvent {
+                account: address,
+                amount: bucket.amount(),
+            });
+            account.try_deposit_or_abort(bucket, None);
         }
 
         /// Locks fee
@@ -54,5 +111,24 @@ mod faucet {
 
             self.vault.as_fungible().lock_fee(amount);
         }
+
+        pub fn set_drip_amount(&mut self, amount: Decimal) {
+            assert!(amount.is_positive(), "Drip amount must be positive");
+            self.drip_amount = amount;
+            Runtime::emit_event(DripAmountChangedEvent { amount });
+        }
+
+        pub fn set_claim_interval(&mut self, epochs: u64) {
+            self.claim_interval_in_epochs = epochs;
+            Runtime::emit_event(ClaimIntervalChangedEvent { epochs });
+        }
+
+        fn take_drip(&mut self) -> Bucket {
+            if self.vault.amount() < self.drip_amount {
+                panic!("The faucet doesn't have funds on this environment. You will need to source XRD another way.")
+            }
+
+            self.vault.take(self.drip_amount)
+        }
     }
 }
@@ -61,6 +61,7 @@ impl KernelCallbackObject for TestCallbackObject {
         _: &Vec<PreAllocatedAddress>,
         _: &IndexSet<Reference>,
         _: &IndexMap<Hash, Vec<u8>>,
+        _: Option<&ComponentAddress>,
     ) -> Result<Vec<u8>, RuntimeError>
     where
         Y: KernelApi<Self>,
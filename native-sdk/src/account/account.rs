@@ -1,5 +1,8 @@
 use radix_engine_interface::api::ClientObjectApi;
-use radix_engine_interface::blueprints::account::{AccountDepositInput, ACCOUNT_DEPOSIT_IDENT};
+use radix_engine_interface::blueprints::account::{
+    AccountDepositInput, AccountTryDepositOrAbortInput, ACCOUNT_DEPOSIT_IDENT,
+    ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT,
+};
 use radix_engine_interface::blueprints::resource::Bucket;
 use radix_engine_interface::data::scrypto::{scrypto_encode, ScryptoDecode};
 use radix_engine_interface::types::ComponentAddress;
@@ -21,4 +24,25 @@ impl Account {
 
         Ok(())
     }
+
+    pub fn try_deposit_or_abort<Y, E: Debug + ScryptoDecode>(
+        &self,
+        bucket: Bucket,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: ClientObjectApi<E>,
+    {
+        api.call_method(
+            self.0.as_node_id(),
+            ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT,
+            scrypto_encode(&AccountTryDepositOrAbortInput {
+                bucket,
+                authorized_depositor_badge: None,
+            })
+            .unwrap(),
+        )?;
+
+        Ok(())
+    }
 }
@@ -1,6 +1,6 @@
 use super::hrpset::HrpSet;
 use crate::address::AddressBech32DecodeError;
-use crate::network::NetworkDefinition;
+use crate::network::{NetworkDefinition, NetworkDefinitionRegistry};
 use crate::types::EntityType;
 use bech32::{self, FromBase32, Variant};
 use sbor::rust::prelude::*;
@@ -22,6 +22,14 @@ impl AddressBech32Decoder {
         }
     }
 
+    /// Instantiates a decoder for whichever network of the registry the address belongs to.
+    pub fn for_network_of_address(
+        registry: &NetworkDefinitionRegistry,
+        address: &str,
+    ) -> Option<Self> {
+        registry.network_of_address(address).map(Self::new)
+    }
+
     pub fn validate_and_decode_ignore_hrp(
         address: &str,
     ) -> Result<(String, EntityType, Vec<u8>), AddressBech32DecodeError> {
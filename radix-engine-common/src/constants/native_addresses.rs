@@ -143,6 +143,24 @@ pub const POOL_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
     148, 250, 42, 166, 49, 140, 99, 24, 198,
 ]);
 
+/// The native package for price feed oracles.
+pub const PRICE_FEED_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
+    13, 144, 99, 24, 198, 49, 140, 96, 143, 9, 203, 76, 99, 24, 198, 49, 140, 255, 107, 207, 243,
+    223, 74, 211, 198, 49, 140, 99, 24, 198,
+]);
+
+/// The native package for recurring payment schedulers.
+pub const PAYMENT_SCHEDULER_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
+    13, 144, 99, 24, 198, 49, 140, 96, 244, 155, 154, 204, 99, 24, 198, 49, 140, 247, 170, 190, 63,
+    168, 173, 121, 230, 49, 140, 99, 24, 198,
+]);
+
+/// The native package for price-time priority order books.
+pub const ORDER_BOOK_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
+    13, 144, 99, 24, 198, 49, 140, 109, 175, 23, 154, 12, 99, 24, 198, 49, 140, 247, 188, 250, 209,
+    202, 255, 61, 70, 49, 140, 99, 24, 198,
+]);
+
 /// The native package for the transaction processor.
 pub const TRANSACTION_PROCESSOR_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
     13, 144, 99, 24, 198, 49, 140, 101, 154, 97, 48, 204, 99, 24, 198, 49, 140, 247, 168, 186, 82,
@@ -205,6 +223,13 @@ pub const CONSENSUS_MANAGER: ComponentAddress = ComponentAddress::new_or_panic([
     181, 74, 106, 134, 49, 140, 99, 24, 198,
 ]);
 
+/// The deferred execution queue native component - holds manifest fragments enqueued for execution
+/// at a later epoch.
+pub const DEFERRED_EXECUTION_QUEUE: ComponentAddress = ComponentAddress::new_or_panic([
+    192, 86, 99, 24, 198, 49, 140, 102, 164, 121, 54, 12, 99, 24, 198, 49, 140, 255, 28, 254, 210,
+    162, 170, 250, 38, 49, 140, 99, 24, 198,
+]);
+
 //=========================================================================
 // SYSTEM SINGLETON COMPONENTS - SCRYPTO
 //=========================================================================
@@ -333,6 +358,21 @@ mod tests {
             EntityType::GlobalPackage,
             "package_rdx1pkgxxxxxxxxxplxxxxxxxxxxxxx020379220524xxxxxxxxxplxxxx",
         );
+        check_address(
+            PRICE_FEED_PACKAGE.as_ref(),
+            EntityType::GlobalPackage,
+            "package_rdx1pkgxxxxxxxxxprcfedxxxxxxxxx076707005457xxxxxxxxxprcfed",
+        );
+        check_address(
+            PAYMENT_SCHEDULER_PACKAGE.as_ref(),
+            EntityType::GlobalPackage,
+            "package_rdx1pkgxxxxxxxxxpaymntxxxxxxxxx002478752670xxxxxxxxxpaymnt",
+        );
+        check_address(
+            ORDER_BOOK_PACKAGE.as_ref(),
+            EntityType::GlobalPackage,
+            "package_rdx1pkgxxxxxxxxxmtchngxxxxxxxxx000866890702xxxxxxxxxmtchng",
+        );
         check_address(
             TRANSACTION_PROCESSOR_PACKAGE.as_ref(),
             EntityType::GlobalPackage,
@@ -375,6 +415,11 @@ mod tests {
             EntityType::GlobalConsensusManager,
             "consensusmanager_rdx1scxxxxxxxxxxcnsmgrxxxxxxxxx000999665565xxxxxxxxxcnsmgr",
         );
+        check_address(
+            DEFERRED_EXECUTION_QUEUE.as_ref(),
+            EntityType::GlobalGenericComponent,
+            "component_rdx1cptxxxxxxxxxdfrexcxxxxxxxxx078876232473xxxxxxxxxdfrexc",
+        );
 
         // System singleton components - scrypto
         check_address(
@@ -1,3 +1,5 @@
+use super::MAX_MEMORY_SIZE_IN_PAGES;
+
 /// The execution cost units loaned from system
 pub const EXECUTION_COST_UNIT_LOAN: u32 = 4_000_000;
 
@@ -22,6 +24,18 @@ pub const MAX_HEAP_SUBSTATE_TOTAL_BYTES: usize = 64 * 1024 * 1024;
 /// The max total track substate size.
 pub const MAX_TRACK_SUBSTATE_TOTAL_BYTES: usize = 64 * 1024 * 1024;
 
+/// The max number of substates persisted under a single node.
+pub const MAX_NODE_SUBSTATE_COUNT: u64 = 16 * 1024 * 1024;
+
+/// The max total size of the substates persisted under a single node, including keys.
+pub const MAX_NODE_SUBSTATE_TOTAL_BYTES: u64 = 4 * 1024 * 1024 * 1024;
+
+/// The max number of linear memory pages used by the WASM instance of a single call frame.
+pub const MAX_WASM_MEMORY_PAGES_PER_CALL_FRAME: u32 = MAX_MEMORY_SIZE_IN_PAGES;
+
+/// The max number of linear memory pages used by all WASM instances alive at the same time.
+pub const MAX_WASM_MEMORY_PAGES_PER_TRANSACTION: u32 = 4 * MAX_MEMORY_SIZE_IN_PAGES;
+
 /// The maximum substate key read and write size
 pub const MAX_SUBSTATE_KEY_SIZE: usize = 1024;
 
@@ -37,6 +51,15 @@ pub const MAX_INVOKE_PAYLOAD_SIZE: usize = 1 * 1024 * 1024;
 #[cfg(feature = "coverage")]
 pub const MAX_INVOKE_PAYLOAD_SIZE: usize = 32 * 1024 * 1024;
 
+/// The maximum invoke output size.
+#[cfg(not(feature = "coverage"))]
+pub const MAX_INVOKE_OUTPUT_SIZE: usize = 1 * 1024 * 1024;
+#[cfg(feature = "coverage")]
+pub const MAX_INVOKE_OUTPUT_SIZE: usize = 32 * 1024 * 1024;
+
+/// The share of the state storage price refunded for each byte of state deleted
+pub const STATE_STORAGE_REFUND_PERCENTAGE: u8 = 50;
+
 /// The proposer's share of tips
 pub const TIPS_PROPOSER_SHARE_PERCENTAGE: u8 = 100;
 
@@ -64,6 +87,9 @@ pub const MAX_NUMBER_OF_EVENTS: usize = 256;
 /// The max number of logs
 pub const MAX_NUMBER_OF_LOGS: usize = 256;
 
+/// The max number of subscribers to a single event of a global object
+pub const MAX_EVENT_SUBSCRIBERS: usize = 16;
+
 /// The max SBOR size of metadata key
 pub const MAX_METADATA_KEY_STRING_LEN: usize = 100;
 
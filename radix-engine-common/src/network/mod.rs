@@ -1,5 +1,7 @@
 use sbor::rust::str::FromStr;
 use sbor::rust::string::String;
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
 use sbor::*;
 
 /// Network Definition is intended to be the actual definition of a network
@@ -87,17 +89,10 @@ impl FromStr for NetworkDefinition {
     type Err = ParseNetworkError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "simulator" => Ok(NetworkDefinition::simulator()),
-            "adapanet" => Ok(NetworkDefinition::adapanet()),
-            "nebunet" => Ok(NetworkDefinition::nebunet()),
-            "kisharnet" => Ok(NetworkDefinition::kisharnet()),
-            "ansharnet" => Ok(NetworkDefinition::ansharnet()),
-            "zabanet" => Ok(NetworkDefinition::zabanet()),
-            "stokenet" => Ok(NetworkDefinition::stokenet()),
-            "mainnet" => Ok(NetworkDefinition::mainnet()),
-            _ => Err(ParseNetworkError::InvalidNetworkString),
-        }
+        NetworkDefinitionRegistry::with_well_known_networks()
+            .by_logical_name(s)
+            .cloned()
+            .ok_or(ParseNetworkError::InvalidNetworkString)
     }
 }
 
@@ -106,6 +101,91 @@ pub enum ParseNetworkError {
     InvalidNetworkString,
 }
 
+/// A set of networks which tools can resolve names, ids and addresses against, so custom
+/// networks can be used alongside the ones defined here.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkDefinitionRegistry {
+    networks: Vec<NetworkDefinition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegisterNetworkError {
+    DuplicateId(u8),
+    DuplicateLogicalName(String),
+    DuplicateHrpSuffix(String),
+}
+
+impl NetworkDefinitionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_well_known_networks() -> Self {
+        Self {
+            networks: vec![
+                NetworkDefinition::simulator(),
+                NetworkDefinition::adapanet(),
+                NetworkDefinition::nebunet(),
+                NetworkDefinition::kisharnet(),
+                NetworkDefinition::ansharnet(),
+                NetworkDefinition::zabanet(),
+                NetworkDefinition::stokenet(),
+                NetworkDefinition::mainnet(),
+            ],
+        }
+    }
+
+    pub fn register(&mut self, network: NetworkDefinition) -> Result<(), RegisterNetworkError> {
+        if self.by_id(network.id).is_some() {
+            return Err(RegisterNetworkError::DuplicateId(network.id));
+        }
+        if self.by_logical_name(&network.logical_name).is_some() {
+            return Err(RegisterNetworkError::DuplicateLogicalName(network.logical_name));
+        }
+        if self.by_hrp_suffix(&network.hrp_suffix).is_some() {
+            return Err(RegisterNetworkError::DuplicateHrpSuffix(network.hrp_suffix));
+        }
+        self.networks.push(network);
+        Ok(())
+    }
+
+    pub fn networks(&self) -> &[NetworkDefinition] {
+        &self.networks
+    }
+
+    pub fn by_id(&self, id: u8) -> Option<&NetworkDefinition> {
+        self.networks.iter().find(|network| network.id == id)
+    }
+
+    /// Looks up a network by its logical name, ignoring case.
+    pub fn by_logical_name(&self, logical_name: &str) -> Option<&NetworkDefinition> {
+        self.networks
+            .iter()
+            .find(|network| network.logical_name.eq_ignore_ascii_case(logical_name))
+    }
+
+    pub fn by_hrp_suffix(&self, hrp_suffix: &str) -> Option<&NetworkDefinition> {
+        self.networks
+            .iter()
+            .find(|network| network.hrp_suffix == hrp_suffix)
+    }
+
+    /// Finds the network a Bech32m-encoded address belongs to, from the suffix of its HRP.
+    ///
+    /// Only the HRP is inspected; the address still needs to be decoded (e.g. with an
+    /// `AddressBech32Decoder` for the returned network) to be validated.
+    pub fn network_of_address(&self, address: &str) -> Option<&NetworkDefinition> {
+        let hrp = &address[..address.rfind('1')?];
+        self.networks
+            .iter()
+            .filter(|network| {
+                hrp.strip_suffix(network.hrp_suffix.as_str())
+                    .map_or(false, |entity_prefix| entity_prefix.ends_with('_'))
+            })
+            .max_by_key(|network| network.hrp_suffix.len())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,4 +214,41 @@ mod tests {
             assert_eq!(NetworkDefinition::from_str(name).unwrap().id, id)
         }
     }
+
+    #[test]
+    fn custom_network_can_be_registered_and_resolved() {
+        let mut registry = NetworkDefinitionRegistry::with_well_known_networks();
+        let localnet = NetworkDefinition {
+            id: 240,
+            logical_name: String::from("localnet"),
+            hrp_suffix: String::from("loc"),
+        };
+
+        registry.register(localnet.clone()).unwrap();
+
+        assert_eq!(registry.by_id(240), Some(&localnet));
+        assert_eq!(registry.by_logical_name("LocalNet"), Some(&localnet));
+        assert_eq!(registry.network_of_address("account_loc1qxyz"), Some(&localnet));
+        assert_eq!(
+            registry.network_of_address("internal_vault_tdx_2_1qxyz"),
+            Some(&NetworkDefinition::stokenet())
+        );
+        assert_eq!(registry.network_of_address("account_unknown1qxyz"), None);
+    }
+
+    #[test]
+    fn network_with_clashing_fields_is_rejected() {
+        let mut registry = NetworkDefinitionRegistry::with_well_known_networks();
+
+        let result = registry.register(NetworkDefinition {
+            id: 240,
+            logical_name: String::from("localnet"),
+            hrp_suffix: String::from("sim"),
+        });
+
+        assert_eq!(
+            result,
+            Err(RegisterNetworkError::DuplicateHrpSuffix(String::from("sim")))
+        );
+    }
 }
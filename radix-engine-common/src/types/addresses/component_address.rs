@@ -1,6 +1,6 @@
-use crate::address::AddressBech32Decoder;
+use crate::address::{AddressBech32Decoder, AddressBech32Encoder};
 use crate::address::{AddressBech32EncodeError, AddressDisplayContext, NO_NETWORK};
-use crate::crypto::{hash, PublicKey};
+use crate::crypto::{IsPublicKeyHash, PublicKey, PublicKeyHash};
 use crate::data::manifest::model::ManifestAddress;
 use crate::data::manifest::ManifestCustomValueKind;
 use crate::data::scrypto::model::Reference;
@@ -34,35 +34,46 @@ impl ComponentAddress {
     pub fn virtual_account_from_public_key<P: Into<PublicKey> + Clone>(
         public_key: &P,
     ) -> ComponentAddress {
-        match public_key.clone().into() {
-            PublicKey::Secp256k1(public_key) => {
-                let mut node_id: [u8; NodeId::LENGTH] = hash(public_key.to_vec()).lower_bytes();
-                node_id[0] = EntityType::GlobalVirtualSecp256k1Account as u8;
-                Self(NodeId(node_id))
-            }
-            PublicKey::Ed25519(public_key) => {
-                let mut node_id: [u8; NodeId::LENGTH] = hash(public_key.to_vec()).lower_bytes();
-                node_id[0] = EntityType::GlobalVirtualEd25519Account as u8;
-                Self(NodeId(node_id))
-            }
-        }
+        Self::virtual_account_from_public_key_hash(&PublicKeyHash::new_from_public_key(
+            &public_key.clone().into(),
+        ))
     }
 
     pub fn virtual_identity_from_public_key<P: Into<PublicKey> + Clone>(
         public_key: &P,
     ) -> ComponentAddress {
-        match public_key.clone().into() {
-            PublicKey::Secp256k1(public_key) => {
-                let mut node_id: [u8; NodeId::LENGTH] = hash(public_key.to_vec()).lower_bytes();
-                node_id[0] = EntityType::GlobalVirtualSecp256k1Identity as u8;
-                Self(NodeId(node_id))
-            }
-            PublicKey::Ed25519(public_key) => {
-                let mut node_id: [u8; NodeId::LENGTH] = hash(public_key.to_vec()).lower_bytes();
-                node_id[0] = EntityType::GlobalVirtualEd25519Identity as u8;
-                Self(NodeId(node_id))
-            }
-        }
+        Self::virtual_identity_from_public_key_hash(&PublicKeyHash::new_from_public_key(
+            &public_key.clone().into(),
+        ))
+    }
+
+    /// Derives the virtual account address from the hash of its public key, e.g. when only the
+    /// `NonFungibleLocalId` of a signature badge is known.
+    pub fn virtual_account_from_public_key_hash<P: IsPublicKeyHash>(
+        public_key_hash: &P,
+    ) -> ComponentAddress {
+        let entity_type = match public_key_hash.into_enum() {
+            PublicKeyHash::Secp256k1(_) => EntityType::GlobalVirtualSecp256k1Account,
+            PublicKeyHash::Ed25519(_) => EntityType::GlobalVirtualEd25519Account,
+        };
+        Self(NodeId::new(
+            entity_type as u8,
+            public_key_hash.get_hash_bytes(),
+        ))
+    }
+
+    /// Derives the virtual identity address from the hash of its public key.
+    pub fn virtual_identity_from_public_key_hash<P: IsPublicKeyHash>(
+        public_key_hash: &P,
+    ) -> ComponentAddress {
+        let entity_type = match public_key_hash.into_enum() {
+            PublicKeyHash::Secp256k1(_) => EntityType::GlobalVirtualSecp256k1Identity,
+            PublicKeyHash::Ed25519(_) => EntityType::GlobalVirtualEd25519Identity,
+        };
+        Self(NodeId::new(
+            entity_type as u8,
+            public_key_hash.get_hash_bytes(),
+        ))
     }
 
     pub fn as_node_id(&self) -> &NodeId {
@@ -87,6 +98,13 @@ impl ComponentAddress {
         }
     }
 
+    pub fn to_bech32(
+        &self,
+        encoder: &AddressBech32Encoder,
+    ) -> Result<String, AddressBech32EncodeError> {
+        encoder.encode(self.0.as_ref())
+    }
+
     pub fn to_hex(&self) -> String {
         self.0.to_hex()
     }
@@ -265,6 +283,7 @@ impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for ComponentAddress {
 mod tests {
     use super::*;
     use crate::internal_prelude::Ed25519PublicKey;
+    use crate::network::{NetworkDefinition, NetworkDefinitionRegistry};
 
     #[test]
     fn component_address_initialization() {
@@ -305,6 +324,57 @@ mod tests {
         println!("Decode error: {}", addr3.unwrap_err());
     }
 
+    #[test]
+    fn virtual_addresses_derived_from_public_key_hash_match_public_key() {
+        let public_key = PublicKey::Ed25519(Ed25519PublicKey([1; Ed25519PublicKey::LENGTH]));
+        let public_key_hash = PublicKeyHash::new_from_public_key(&public_key);
+
+        assert_eq!(
+            ComponentAddress::virtual_account_from_public_key_hash(&public_key_hash),
+            ComponentAddress::virtual_account_from_public_key(&public_key)
+        );
+        assert_eq!(
+            ComponentAddress::virtual_identity_from_public_key_hash(&public_key_hash),
+            ComponentAddress::virtual_identity_from_public_key(&public_key)
+        );
+    }
+
+    #[test]
+    fn component_address_bech32_round_trip() {
+        let public_key = Ed25519PublicKey([1; Ed25519PublicKey::LENGTH]);
+        let addr = ComponentAddress::virtual_account_from_public_key(&public_key);
+
+        let encoded = addr
+            .to_bech32(&AddressBech32Encoder::for_simulator())
+            .unwrap();
+
+        assert!(encoded.starts_with("account_sim1"));
+        assert_eq!(
+            ComponentAddress::try_from_bech32(&AddressBech32Decoder::for_simulator(), &encoded),
+            Some(addr)
+        );
+    }
+
+    #[test]
+    fn component_address_decodes_against_network_from_registry() {
+        let public_key = Ed25519PublicKey([1; Ed25519PublicKey::LENGTH]);
+        let addr = ComponentAddress::virtual_account_from_public_key(&public_key);
+        let encoded = addr
+            .to_bech32(&AddressBech32Encoder::new(&NetworkDefinition::stokenet()))
+            .unwrap();
+
+        let decoder = AddressBech32Decoder::for_network_of_address(
+            &NetworkDefinitionRegistry::with_well_known_networks(),
+            &encoded,
+        )
+        .unwrap();
+
+        assert_eq!(
+            ComponentAddress::try_from_bech32(&decoder, &encoded),
+            Some(addr)
+        );
+    }
+
     #[test]
     fn component_address_encode_decode_fail() {
         let mut buf = Vec::new();
@@ -1,4 +1,4 @@
-use crate::address::{AddressBech32Decoder, AddressBech32EncodeError};
+use crate::address::{AddressBech32Decoder, AddressBech32EncodeError, AddressBech32Encoder};
 use crate::address::{AddressDisplayContext, NO_NETWORK};
 use crate::data::manifest::model::ManifestAddress;
 use crate::data::manifest::ManifestCustomValueKind;
@@ -54,6 +54,13 @@ impl PackageAddress {
         }
     }
 
+    pub fn to_bech32(
+        &self,
+        encoder: &AddressBech32Encoder,
+    ) -> Result<String, AddressBech32EncodeError> {
+        encoder.encode(self.0.as_ref())
+    }
+
     pub fn to_hex(&self) -> String {
         self.0.to_hex()
     }
@@ -67,6 +74,9 @@ impl PackageAddress {
             || self.0 == crate::constants::CONSENSUS_MANAGER_PACKAGE.0
             || self.0 == crate::constants::ACCESS_CONTROLLER_PACKAGE.0
             || self.0 == crate::constants::POOL_PACKAGE.0
+            || self.0 == crate::constants::PRICE_FEED_PACKAGE.0
+            || self.0 == crate::constants::PAYMENT_SCHEDULER_PACKAGE.0
+            || self.0 == crate::constants::ORDER_BOOK_PACKAGE.0
             || self.0 == crate::constants::TRANSACTION_PROCESSOR_PACKAGE.0
             || self.0 == crate::constants::METADATA_MODULE_PACKAGE.0
             || self.0 == crate::constants::ROYALTY_MODULE_PACKAGE.0
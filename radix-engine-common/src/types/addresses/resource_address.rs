@@ -1,4 +1,4 @@
-use crate::address::AddressBech32Decoder;
+use crate::address::{AddressBech32Decoder, AddressBech32Encoder};
 use crate::address::{AddressBech32EncodeError, AddressDisplayContext, NO_NETWORK};
 use crate::data::manifest::model::ManifestAddress;
 use crate::data::manifest::ManifestCustomValueKind;
@@ -58,6 +58,13 @@ impl ResourceAddress {
         }
     }
 
+    pub fn to_bech32(
+        &self,
+        encoder: &AddressBech32Encoder,
+    ) -> Result<String, AddressBech32EncodeError> {
+        encoder.encode(self.0.as_ref())
+    }
+
     pub fn to_hex(&self) -> String {
         self.0.to_hex()
     }
@@ -8,6 +8,10 @@ use radix_engine_common::data::scrypto::*;
 use sbor::rust::prelude::*;
 use sbor::*;
 
+/// A reservation of a global address, consumed by globalizing an object at that address.
+///
+/// Reservations are pinned to the heap and can't be stored in substates, so one which is not
+/// consumed lapses with its transaction and never leaves state behind.
 #[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[must_use]
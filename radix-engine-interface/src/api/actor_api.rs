@@ -3,6 +3,7 @@ use crate::api::{ActorRefHandle, FieldIndex};
 use crate::types::*;
 use crate::Sbor;
 use bitflags::bitflags;
+use radix_engine_derive::ScryptoSbor;
 use radix_engine_interface::api::{ActorStateHandle, LockFlags};
 use sbor::rust::fmt::Debug;
 use sbor::rust::string::String;
@@ -16,6 +17,36 @@ bitflags! {
     }
 }
 
+/// The argument passed to a subscriber's hook method when a subscribed event is emitted.
+///
+/// The hook is called by the emitter once the emitting method has returned, so it can be
+/// protected with `global_caller(emitter)`.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct EventNotification {
+    pub emitter: GlobalAddress,
+    pub event_name: String,
+    pub event_data: Vec<u8>,
+}
+
+/// The size of the persisted state of a node, as stored in its `STATE_SIZE_PARTITION`.
+///
+/// Substate keys are included in `total_bytes`, while the accounting substate itself is not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ScryptoSbor)]
+pub struct NodeStateSize {
+    pub substate_count: u64,
+    pub total_bytes: u64,
+}
+
+/// Whose calls a rate limit counts.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, FromRepr, ScryptoSbor)]
+pub enum RateLimitScope {
+    /// All calls to the component share a single counter
+    Global,
+    /// Calls are counted separately for each global caller of the component
+    PerCaller,
+}
+
 /// Api which exposes methods in the context of the actor
 pub trait ClientActorApi<E: Debug> {
     /// Retrieve the current blueprint id
@@ -46,4 +77,35 @@ pub trait ClientActorApi<E: Debug> {
         event_data: Vec<u8>,
         event_flags: EventFlags,
     ) -> Result<(), E>;
+
+    /// Subscribes the current global component to an event of a global object, requires the
+    /// owner role of the emitter
+    fn actor_subscribe_to_event(
+        &mut self,
+        emitter: GlobalAddress,
+        event_name: String,
+        hook_method: String,
+    ) -> Result<(), E>;
+
+    /// Removes a subscription of the current global component
+    fn actor_unsubscribe_from_event(
+        &mut self,
+        emitter: GlobalAddress,
+        event_name: String,
+    ) -> Result<(), E>;
+
+    /// Retrieve the state size of the current method actor's node, as committed by the
+    /// previous transaction which touched it
+    fn actor_get_state_size(&mut self) -> Result<NodeStateSize, E>;
+
+    /// Counts a call against a sliding-window rate limit of the current global component.
+    /// Returns false, without counting the call, if `max` calls have already been counted
+    /// within the last `window_epochs` epochs
+    fn actor_rate_limit_check(
+        &mut self,
+        name: String,
+        scope: RateLimitScope,
+        max: u32,
+        window_epochs: u64,
+    ) -> Result<bool, E>;
 }
@@ -1,3 +1,5 @@
+use crate::blueprints::package::BlueprintInterface;
+use crate::types::BlueprintId;
 use radix_engine_common::prelude::VersionedScryptoSchema;
 use radix_engine_common::types::*;
 use sbor::rust::prelude::*;
@@ -17,4 +19,17 @@ pub trait ClientBlueprintApi<E> {
         &mut self,
         blueprint_type_id: &BlueprintTypeIdentifier,
     ) -> Result<(Rc<VersionedScryptoSchema>, ScopedTypeId), E>;
+
+    /// Retrieves the interface of the default version of a blueprint
+    fn get_blueprint_interface(
+        &mut self,
+        blueprint_id: &BlueprintId,
+    ) -> Result<BlueprintInterface, E>;
+
+    /// Retrieves the schema of an event of the default version of a blueprint
+    fn get_blueprint_event_schema(
+        &mut self,
+        blueprint_id: &BlueprintId,
+        event_name: &str,
+    ) -> Result<(Rc<VersionedScryptoSchema>, ScopedTypeId), E>;
 }
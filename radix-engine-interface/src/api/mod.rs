@@ -35,6 +35,8 @@ pub type ActorRefHandle = u32;
 pub const ACTOR_REF_SELF: ActorRefHandle = 0u32;
 pub const ACTOR_REF_OUTER: ActorRefHandle = 1u32;
 pub const ACTOR_REF_GLOBAL: ActorRefHandle = 2u32;
+/// The global object whose method called the current actor, if any.
+pub const ACTOR_REF_CALLER: ActorRefHandle = 3u32;
 pub const ACTOR_REF_AUTH_ZONE: ActorRefHandle = 8u32;
 
 pub type FieldIndex = u8;
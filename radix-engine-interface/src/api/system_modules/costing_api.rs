@@ -9,12 +9,24 @@ pub trait ClientCostingApi<E> {
     /// Add cost units to the reserve. This should never fail.
     fn lock_fee(&mut self, locked_fee: LiquidFungibleResource, contingent: bool);
 
+    /// Add cost units to the reserve, refunding what is left of the fee to the given vault
+    /// rather than to the locking vault. This should never fail.
+    fn lock_fee_with_refund(
+        &mut self,
+        locked_fee: LiquidFungibleResource,
+        contingent: bool,
+        refund_vault_id: NodeId,
+    );
+
     fn consume_cost_units(&mut self, costing_entry: ClientCostingEntry) -> Result<(), E>;
 
     fn execution_cost_unit_limit(&mut self) -> Result<u32, E>;
 
     fn execution_cost_unit_price(&mut self) -> Result<Decimal, E>;
 
+    /// Returns the execution cost units consumed so far in the transaction.
+    fn execution_cost_units_consumed(&mut self) -> Result<u32, E>;
+
     fn finalization_cost_unit_limit(&mut self) -> Result<u32, E>;
 
     fn finalization_cost_unit_price(&mut self) -> Result<Decimal, E>;
@@ -1,6 +1,8 @@
+use crate::blueprints::resource::NonFungibleGlobalId;
 use crate::sbor::rust::prelude::*;
-use crate::types::Level;
+use crate::types::{Level, LogRecord};
 use radix_engine_common::crypto::Hash;
+use radix_engine_common::data::scrypto::ScryptoValue;
 use radix_engine_common::types::GlobalAddress;
 
 pub trait ClientTransactionRuntimeApi<E> {
@@ -8,9 +10,19 @@ pub trait ClientTransactionRuntimeApi<E> {
 
     fn get_transaction_hash(&mut self) -> Result<Hash, E>;
 
+    /// Returns the virtual signature badges of the keys which signed the transaction.
+    fn get_transaction_signatories(&mut self) -> Result<Vec<NonFungibleGlobalId>, E>;
+
     fn generate_ruid(&mut self) -> Result<[u8; 32], E>;
 
     fn emit_log(&mut self, level: Level, message: String) -> Result<(), E>;
 
+    /// Emits a log along with its key-value fields.
+    fn emit_log_record(&mut self, record: LogRecord) -> Result<(), E>;
+
     fn panic(&mut self, message: String) -> Result<(), E>;
+
+    /// Halts execution like [`Self::panic`], failing the transaction with the given error value
+    /// rather than a message.
+    fn abort(&mut self, error: ScryptoValue) -> Result<(), E>;
 }
@@ -0,0 +1,36 @@
+use crate::blueprints::resource::*;
+use crate::*;
+use radix_engine_common::prelude::*;
+use sbor::rust::prelude::*;
+
+/// The method which the authorizer of an account must implement. It is called by the system
+/// whenever an owner protected method of the account is called (apart from the fee locking ones),
+/// and its approval is required on top of the owner role.
+pub const ACCOUNT_AUTHORIZER_AUTHORIZE_IDENT: &str = "authorize";
+
+/// The account method call awaiting authorization.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct ManifestSummary {
+    pub account: ComponentAddress,
+    pub method_name: String,
+    /// The scrypto encoded arguments of the call. Any buckets or proofs in them stay with the
+    /// caller while the authorizer runs.
+    pub args: Vec<u8>,
+}
+
+/// The proofs which the auth zone of the account method call awaiting authorization can see.
+#[derive(Debug, Clone, Default, Eq, PartialEq, ScryptoSbor)]
+pub struct AuthZoneSnapshot {
+    /// The proven non-fungibles, including signature and global caller badges.
+    pub non_fungibles: BTreeSet<NonFungibleGlobalId>,
+    /// The largest amount proven by a single proof of each resource.
+    pub resources: BTreeMap<ResourceAddress, Decimal>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct AccountAuthorizerAuthorizeInput {
+    pub manifest_summary: ManifestSummary,
+    pub auth_zone_snapshot: AuthZoneSnapshot,
+}
+
+pub type AccountAuthorizerAuthorizeOutput = bool;
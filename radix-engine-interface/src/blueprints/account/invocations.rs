@@ -401,3 +401,64 @@ pub struct AccountRemoveAuthorizedDepositorInput {
 }
 
 pub type AccountRemoveAuthorizedDepositorOutput = ();
+
+//=================================
+// Account Get Default Deposit Rule
+//=================================
+
+pub const ACCOUNT_GET_DEFAULT_DEPOSIT_RULE_IDENT: &str = "get_default_deposit_rule";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountGetDefaultDepositRuleInput {}
+
+pub type AccountGetDefaultDepositRuleOutput = DefaultDepositRule;
+
+//=================================
+// Account Get Resource Preference
+//=================================
+
+pub const ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT: &str = "get_resource_preference";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountGetResourcePreferenceInput {
+    pub resource_address: ResourceAddress,
+}
+
+pub type AccountGetResourcePreferenceOutput = Option<ResourcePreference>;
+
+//=================================
+// Account Is Authorized Depositor
+//=================================
+
+pub const ACCOUNT_IS_AUTHORIZED_DEPOSITOR_IDENT: &str = "is_authorized_depositor";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountIsAuthorizedDepositorInput {
+    pub badge: ResourceOrNonFungible,
+}
+
+pub type AccountIsAuthorizedDepositorOutput = bool;
+
+//========================
+// Account Set Authorizer
+//========================
+
+pub const ACCOUNT_SET_AUTHORIZER_IDENT: &str = "set_authorizer";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountSetAuthorizerInput {
+    pub authorizer: Option<ComponentAddress>,
+}
+
+pub type AccountSetAuthorizerOutput = ();
+
+//========================
+// Account Get Authorizer
+//========================
+
+pub const ACCOUNT_GET_AUTHORIZER_IDENT: &str = "get_authorizer";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountGetAuthorizerInput {}
+
+pub type AccountGetAuthorizerOutput = Option<ComponentAddress>;
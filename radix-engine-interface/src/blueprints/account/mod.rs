@@ -1,3 +1,5 @@
+mod authorizer;
 mod invocations;
 
+pub use authorizer::*;
 pub use invocations::*;
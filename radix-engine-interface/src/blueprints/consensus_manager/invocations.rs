@@ -216,7 +216,27 @@ pub enum TimePrecisionV2 {
     Second,
 }
 
-pub type TimePrecision = TimePrecisionV2;
+/// How the proposer's millisecond timestamp is brought to a coarser precision.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sbor)]
+pub enum TimeRoundingMode {
+    Down,
+    /// Rounds half-way values up
+    Nearest,
+    Up,
+}
+
+/// `Minute` and `Second` always round down, and are kept for compatibility with the earlier
+/// versions.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sbor)]
+#[sbor(type_name = "TimePrecision")]
+pub enum TimePrecisionV3 {
+    Minute,
+    Second,
+    RoundedMinute(TimeRoundingMode),
+    RoundedSecond(TimeRoundingMode),
+}
+
+pub type TimePrecision = TimePrecisionV3;
 
 pub const CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT: &str = "get_current_time";
 
@@ -232,6 +252,12 @@ pub struct ConsensusManagerGetCurrentTimeInputV2 {
     pub precision: TimePrecisionV2,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Sbor)]
+#[sbor(type_name = "ConsensusManagerGetCurrentTimeInput")]
+pub struct ConsensusManagerGetCurrentTimeInputV3 {
+    pub precision: TimePrecisionV3,
+}
+
 pub type ConsensusManagerGetCurrentTimeOutput = Instant;
 
 pub const CONSENSUS_MANAGER_COMPARE_CURRENT_TIME_IDENT: &str = "compare_current_time";
@@ -252,6 +278,14 @@ pub struct ConsensusManagerCompareCurrentTimeInputV2 {
     pub operator: TimeComparisonOperator,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+#[sbor(type_name = "ConsensusManagerCompareCurrentTimeInput")]
+pub struct ConsensusManagerCompareCurrentTimeInputV3 {
+    pub instant: Instant,
+    pub precision: TimePrecisionV3,
+    pub operator: TimeComparisonOperator,
+}
+
 pub type ConsensusManagerCompareCurrentTimeOutput = bool;
 
 pub const CONSENSUS_MANAGER_NEXT_ROUND_IDENT: &str = "next_round";
@@ -340,6 +374,51 @@ pub struct ConsensusManagerCreateValidatorManifestInput {
 
 pub type ConsensusManagerCreateValidatorOutput = (Global<ValidatorObjectTypeInfo>, Bucket, Bucket);
 
+pub const CONSENSUS_MANAGER_REGISTER_EPOCH_CHANGE_HOOK_IDENT: &str = "register_epoch_change_hook";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct ConsensusManagerRegisterEpochChangeHookInput {
+    /// The component whose method becomes invocable after every epoch change.
+    /// Note: the registration must be authorized by the component itself (i.e. its global caller
+    /// badge).
+    pub component_address: ComponentAddress,
+    /// The name of the method to invoke. It receives the new [`Epoch`] as its only argument.
+    pub method_name: String,
+    /// The XRD bond which pays for the hook's invocations.
+    pub bond: Bucket,
+}
+
+#[derive(Debug, Eq, PartialEq, ManifestSbor)]
+pub struct ConsensusManagerRegisterEpochChangeHookManifestInput {
+    pub component_address: ComponentAddress,
+    pub method_name: String,
+    pub bond: ManifestBucket,
+}
+
+pub type ConsensusManagerRegisterEpochChangeHookOutput = ();
+
+pub const CONSENSUS_MANAGER_DEREGISTER_EPOCH_CHANGE_HOOK_IDENT: &str =
+    "deregister_epoch_change_hook";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ConsensusManagerDeregisterEpochChangeHookInput {
+    pub component_address: ComponentAddress,
+}
+
+/// The remainder of the hook's XRD bond.
+pub type ConsensusManagerDeregisterEpochChangeHookOutput = Bucket;
+
+pub const CONSENSUS_MANAGER_INVOKE_EPOCH_CHANGE_HOOK_IDENT: &str = "invoke_epoch_change_hook";
+
+/// Invokes the hook of the given component with the epoch it is pending for, paying from its bond.
+/// Note: only the validators may do this, at most once per epoch change.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ConsensusManagerInvokeEpochChangeHookInput {
+    pub component_address: ComponentAddress,
+}
+
+pub type ConsensusManagerInvokeEpochChangeHookOutput = ();
+
 pub const VALIDATOR_REGISTER_IDENT: &str = "register";
 
 #[derive(Debug, Clone, Eq, PartialEq, Sbor)]
@@ -427,6 +506,38 @@ pub struct ValidatorUpdateFeeInput {
 
 pub type ValidatorUpdateFeeOutput = ();
 
+pub const VALIDATOR_UPDATE_FEE_SPLIT_IDENT: &str = "update_fee_split";
+
+/// A recipient of a portion of the validator fee (see [`ValidatorUpdateFeeSplitInput`]).
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ValidatorFeeRecipient {
+    /// An account which gets credited its share of the validator fee (as stake units), to be
+    /// claimed with [`VALIDATOR_CLAIM_FEE_SPLIT_IDENT`].
+    pub account: ComponentAddress,
+    /// A fraction of the validator fee which gets transferred to this recipient.
+    /// Must be within `(0.0, 1.0]`.
+    pub share: Decimal,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ValidatorUpdateFeeSplitInput {
+    /// The recipients among which the validator fee gets split on every emission.
+    /// The shares must sum up to at most `1.0` - any remainder stays with the validator's owner.
+    pub recipients: Vec<ValidatorFeeRecipient>,
+}
+
+pub type ValidatorUpdateFeeSplitOutput = ();
+
+pub const VALIDATOR_CLAIM_FEE_SPLIT_IDENT: &str = "claim_fee_split";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ValidatorClaimFeeSplitInput {
+    /// A fee split recipient whose credited stake units get deposited into its account.
+    pub account: ComponentAddress,
+}
+
+pub type ValidatorClaimFeeSplitOutput = ();
+
 pub const VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT: &str = "update_accept_delegated_stake";
 
 #[derive(Debug, Clone, Eq, PartialEq, Sbor)]
@@ -2,7 +2,10 @@ pub mod access_controller;
 pub mod account;
 pub mod consensus_manager;
 pub mod identity;
+pub mod oracle;
+pub mod order_book;
 pub mod package;
+pub mod payment_scheduler;
 pub mod pool;
 pub mod resource;
 pub mod test_utils;
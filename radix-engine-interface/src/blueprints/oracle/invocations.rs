@@ -0,0 +1,84 @@
+use crate::blueprints::component::*;
+use crate::blueprints::macros::*;
+use crate::blueprints::resource::*;
+use radix_engine_common::data::manifest::model::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+
+//=================================================================================================
+// Oracle Interface
+//=================================================================================================
+
+/// The method that every oracle component is expected to expose. Blueprints that consume prices
+/// should only depend on this method and its input and output types, which allows any component
+/// implementing it (the native [`PRICE_FEED_BLUEPRINT`] or a mock oracle in tests) to be used
+/// interchangeably.
+pub const ORACLE_GET_PRICE_IDENT: &str = "get_price";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct OracleGetPriceInput {
+    /// The resource being priced.
+    pub base: ResourceAddress,
+    /// The resource that the price is denominated in.
+    pub quote: ResourceAddress,
+}
+
+/// The price of one unit of the base resource in units of the quote resource, and the instant at
+/// which the price was published.
+pub type OracleGetPriceOutput = (Decimal, Instant);
+
+//=================================================================================================
+// Price Feed
+//=================================================================================================
+
+pub const PRICE_FEED_BLUEPRINT: &str = "PriceFeed";
+
+pub const PRICE_FEED_PUBLISHER_ROLE: &str = "publisher";
+
+define_type_info_marker!(Some(PRICE_FEED_PACKAGE), PriceFeed);
+
+define_invocation! {
+    blueprint_name: PriceFeed,
+    function_name: instantiate,
+    input: struct {
+        owner_role: OwnerRole,
+        max_price_age_seconds: i64,
+        publisher_badge_amount: Decimal,
+        address_reservation: Option<GlobalAddressReservation>
+    },
+    output: type (Global<PriceFeedObjectTypeInfo>, Bucket),
+    manifest_input: struct {
+        owner_role: OwnerRole,
+        max_price_age_seconds: i64,
+        publisher_badge_amount: Decimal,
+        address_reservation: Option<ManifestAddressReservation>
+    }
+}
+
+define_invocation! {
+    blueprint_name: PriceFeed,
+    function_name: publish_price,
+    input: struct {
+        base: ResourceAddress,
+        quote: ResourceAddress,
+        price: Decimal
+    },
+    output: type (),
+    manifest_input: struct {
+        base: ResourceAddress,
+        quote: ResourceAddress,
+        price: Decimal
+    }
+}
+
+define_invocation! {
+    blueprint_name: PriceFeed,
+    function_name: get_price,
+    input: type OracleGetPriceInput,
+    output: type OracleGetPriceOutput,
+    manifest_input: struct {
+        base: ResourceAddress,
+        quote: ResourceAddress
+    }
+}
@@ -0,0 +1,97 @@
+use crate::blueprints::component::*;
+use crate::blueprints::macros::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+
+pub const ORDER_BOOK_BLUEPRINT: &str = "OrderBook";
+
+define_type_info_marker!(Some(ORDER_BOOK_PACKAGE), OrderBook);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ScryptoSbor, ManifestSbor)]
+pub enum OrderSide {
+    Bid,
+    Ask,
+}
+
+impl OrderSide {
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::Bid => Self::Ask,
+            Self::Ask => Self::Bid,
+        }
+    }
+}
+
+/// An order resting on the book.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub struct Order {
+    pub side: OrderSide,
+    pub price: Decimal,
+    /// The quantity of the order which is yet to be matched.
+    pub quantity: Decimal,
+}
+
+/// A match between an incoming order and an order resting on the book.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub struct OrderFill {
+    /// The id of the resting order.
+    pub order_id: u64,
+    /// The price of the resting order, which the fill is made at.
+    pub price: Decimal,
+    /// The quantity matched by the fill.
+    pub quantity: Decimal,
+    /// The quantity of the resting order left after the fill. The order is removed from the book
+    /// once this reaches zero.
+    pub remaining_quantity: Decimal,
+}
+
+define_invocation! {
+    blueprint_name: OrderBook,
+    function_name: instantiate,
+    input: struct {
+        tick_size: Decimal
+    },
+    output: type Owned<OrderBookObjectTypeInfo>
+}
+
+define_invocation! {
+    blueprint_name: OrderBook,
+    function_name: insert_order,
+    input: struct {
+        side: OrderSide,
+        price: Decimal,
+        quantity: Decimal
+    },
+    output: type u64
+}
+
+define_invocation! {
+    blueprint_name: OrderBook,
+    function_name: cancel_order,
+    input: struct {
+        order_id: u64
+    },
+    output: type Order
+}
+
+define_invocation! {
+    blueprint_name: OrderBook,
+    function_name: match_best,
+    input: struct {
+        side: OrderSide,
+        quantity: Decimal,
+        limit_price: Option<Decimal>,
+        max_fills: u32
+    },
+    output: type Vec<OrderFill>
+}
+
+define_invocation! {
+    blueprint_name: OrderBook,
+    function_name: get_order,
+    input: struct {
+        order_id: u64
+    },
+    output: type Option<Order>
+}
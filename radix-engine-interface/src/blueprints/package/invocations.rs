@@ -87,6 +87,38 @@ pub struct PackageClaimRoyaltiesInput {}
 
 pub type PackageClaimRoyaltiesOutput = Bucket;
 
+pub const PACKAGE_ADD_ROYALTY_EXEMPTION_IDENT: &str = "PackageRoyalty_add_royalty_exemption";
+
+/// Exempts calls made by a package, or by a global component, from the royalties of this
+/// package.
+#[cfg_attr(
+    feature = "radix_engine_fuzzing",
+    derive(arbitrary::Arbitrary, serde::Serialize, serde::Deserialize)
+)]
+#[derive(
+    Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestCategorize, ManifestEncode, ManifestDecode,
+)]
+pub struct PackageAddRoyaltyExemptionInput {
+    pub caller: GlobalAddress,
+}
+
+pub type PackageAddRoyaltyExemptionOutput = ();
+
+pub const PACKAGE_REMOVE_ROYALTY_EXEMPTION_IDENT: &str = "PackageRoyalty_remove_royalty_exemption";
+
+#[cfg_attr(
+    feature = "radix_engine_fuzzing",
+    derive(arbitrary::Arbitrary, serde::Serialize, serde::Deserialize)
+)]
+#[derive(
+    Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestCategorize, ManifestEncode, ManifestDecode,
+)]
+pub struct PackageRemoveRoyaltyExemptionInput {
+    pub caller: GlobalAddress,
+}
+
+pub type PackageRemoveRoyaltyExemptionOutput = ();
+
 #[derive(Debug, Clone, Eq, PartialEq, Default, ScryptoSbor, ManifestSbor)]
 pub struct PackageDefinition {
     pub blueprints: IndexMap<String, BlueprintDefinitionInit>,
@@ -256,6 +288,7 @@ impl PackageDefinition {
                                 (function_name.to_string(), schema)
                             })
                             .collect(),
+                        ..Default::default()
                     },
                     ..Default::default()
                 },
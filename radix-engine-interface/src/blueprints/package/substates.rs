@@ -16,6 +16,10 @@ pub const TRANSACTION_TRACKER_CODE_ID: u64 = 14u64;
 pub const TEST_UTILS_CODE_ID: u64 = 15u64;
 pub const CONSENSUS_MANAGER_SECONDS_PRECISION_CODE_ID: u64 = 16u64;
 pub const POOL_V1_1_CODE_ID: u64 = 17u64;
+pub const PRICE_FEED_CODE_ID: u64 = 18u64;
+pub const PAYMENT_SCHEDULER_CODE_ID: u64 = 19u64;
+pub const ORDER_BOOK_CODE_ID: u64 = 20u64;
+pub const CONSENSUS_MANAGER_ROUNDED_TIME_CODE_ID: u64 = 21u64;
 
 pub const PACKAGE_FIELDS_PARTITION_OFFSET: PartitionOffset = PartitionOffset(0u8);
 pub const PACKAGE_BLUEPRINTS_PARTITION_OFFSET: PartitionOffset = PartitionOffset(1u8);
@@ -26,6 +30,7 @@ pub const PACKAGE_AUTH_TEMPLATE_PARTITION_OFFSET: PartitionOffset = PartitionOff
 pub const PACKAGE_VM_TYPE_PARTITION_OFFSET: PartitionOffset = PartitionOffset(5u8);
 pub const PACKAGE_ORIGINAL_CODE_PARTITION_OFFSET: PartitionOffset = PartitionOffset(6u8);
 pub const PACKAGE_INSTRUMENTED_CODE_PARTITION_OFFSET: PartitionOffset = PartitionOffset(7u8);
+pub const PACKAGE_ROYALTY_EXEMPTIONS_PARTITION_OFFSET: PartitionOffset = PartitionOffset(8u8);
 
 define_wrapped_hash!(
     /// Represents a particular instance of code under a package
@@ -61,6 +66,7 @@ pub struct FunctionSchema {
     pub receiver: Option<ReceiverInfo>,
     pub input: BlueprintPayloadDef,
     pub output: BlueprintPayloadDef,
+    pub post_conditions: Vec<FunctionPostCondition>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor, Ord, PartialOrd, Hash)]
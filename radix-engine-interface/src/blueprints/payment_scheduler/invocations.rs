@@ -0,0 +1,121 @@
+use crate::blueprints::component::*;
+use crate::blueprints::macros::*;
+use crate::blueprints::resource::*;
+use radix_engine_common::data::manifest::model::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+
+pub const PAYMENT_SCHEDULER_BLUEPRINT: &str = "PaymentScheduler";
+
+define_type_info_marker!(Some(PAYMENT_SCHEDULER_PACKAGE), PaymentScheduler);
+
+/// A payment of a fixed amount to a recipient account which repeats every `interval_epochs`
+/// epochs, starting at the epoch it was scheduled for.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub struct PaymentSchedule {
+    /// The account receiving the payments.
+    pub recipient: ComponentAddress,
+    /// The amount paid by each installment.
+    pub amount: Decimal,
+    /// The number of epochs between two installments.
+    pub interval_epochs: u64,
+    /// The epoch from which the next installment can be paid out.
+    pub next_payment_epoch: Epoch,
+}
+
+define_invocation! {
+    blueprint_name: PaymentScheduler,
+    function_name: instantiate,
+    input: struct {
+        owner_role: OwnerRole,
+        resource_address: ResourceAddress,
+        incentive_per_payment: Decimal,
+        address_reservation: Option<GlobalAddressReservation>
+    },
+    output: type Global<PaymentSchedulerObjectTypeInfo>,
+    manifest_input: struct {
+        owner_role: OwnerRole,
+        resource_address: ResourceAddress,
+        incentive_per_payment: Decimal,
+        address_reservation: Option<ManifestAddressReservation>
+    }
+}
+
+define_invocation! {
+    blueprint_name: PaymentScheduler,
+    function_name: deposit,
+    input: struct {
+        bucket: Bucket
+    },
+    output: type (),
+    manifest_input: struct {
+        bucket: ManifestBucket
+    }
+}
+
+define_invocation! {
+    blueprint_name: PaymentScheduler,
+    function_name: withdraw,
+    input: struct {
+        amount: Decimal
+    },
+    output: type Bucket,
+    manifest_input: struct {
+        amount: Decimal
+    }
+}
+
+define_invocation! {
+    blueprint_name: PaymentScheduler,
+    function_name: create_schedule,
+    input: struct {
+        recipient: ComponentAddress,
+        amount: Decimal,
+        interval_epochs: u64,
+        first_payment_epoch: Epoch
+    },
+    output: type u64,
+    manifest_input: struct {
+        recipient: ComponentAddress,
+        amount: Decimal,
+        interval_epochs: u64,
+        first_payment_epoch: Epoch
+    }
+}
+
+define_invocation! {
+    blueprint_name: PaymentScheduler,
+    function_name: cancel_schedule,
+    input: struct {
+        schedule_id: u64
+    },
+    output: type (),
+    manifest_input: struct {
+        schedule_id: u64
+    }
+}
+
+define_invocation! {
+    blueprint_name: PaymentScheduler,
+    function_name: get_schedule,
+    input: struct {
+        schedule_id: u64
+    },
+    output: type Option<PaymentSchedule>,
+    manifest_input: struct {
+        schedule_id: u64
+    }
+}
+
+define_invocation! {
+    blueprint_name: PaymentScheduler,
+    function_name: process_due_payments,
+    input: struct {
+        max_payments: u32
+    },
+    output: type Bucket,
+    manifest_input: struct {
+        max_payments: u32
+    }
+}
@@ -97,3 +97,51 @@ define_invocation! {
     output: type IndexMap<ResourceAddress, Decimal>,
     manifest_input: struct {}
 }
+
+define_invocation! {
+    blueprint_name: MultiResourcePool,
+    function_name: add_resource,
+    input: struct {
+        resource_address: ResourceAddress,
+        weight: Decimal
+    },
+    output: type (),
+    manifest_input: struct {
+        resource_address: ResourceAddress,
+        weight: Decimal
+    }
+}
+
+define_invocation! {
+    blueprint_name: MultiResourcePool,
+    function_name: retire_resource,
+    input: struct {
+        resource_address: ResourceAddress
+    },
+    output: type (),
+    manifest_input: struct {
+        resource_address: ResourceAddress
+    }
+}
+
+define_invocation! {
+    blueprint_name: MultiResourcePool,
+    function_name: set_resource_weight,
+    input: struct {
+        resource_address: ResourceAddress,
+        weight: Decimal
+    },
+    output: type (),
+    manifest_input: struct {
+        resource_address: ResourceAddress,
+        weight: Decimal
+    }
+}
+
+define_invocation! {
+    blueprint_name: MultiResourcePool,
+    function_name: get_resource_weights,
+    input: struct {},
+    output: type IndexMap<ResourceAddress, Decimal>,
+    manifest_input: struct {}
+}
@@ -26,6 +26,24 @@ define_invocation! {
     }
 }
 
+define_invocation! {
+    blueprint_name: OneResourcePool,
+    function_name: instantiate_interest_bearing,
+    input: struct {
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_address: ResourceAddress,
+        address_reservation: Option<GlobalAddressReservation>
+    },
+    output: type Global<OneResourcePoolObjectTypeInfo>,
+    manifest_input: struct {
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_address: ResourceAddress,
+        address_reservation: Option<ManifestAddressReservation>
+    }
+}
+
 define_invocation! {
     blueprint_name: OneResourcePool,
     function_name: contribute,
@@ -95,3 +113,35 @@ define_invocation! {
     output: type Decimal,
     manifest_input: struct {}
 }
+
+define_invocation! {
+    blueprint_name: OneResourcePool,
+    function_name: donate,
+    input: struct {
+        bucket: Bucket
+    },
+    output: type (),
+    manifest_input: struct {
+        bucket: ManifestBucket
+    }
+}
+
+define_invocation! {
+    blueprint_name: OneResourcePool,
+    function_name: get_accrual,
+    input: struct {},
+    output: type Option<PoolAccrual>,
+    manifest_input: struct {}
+}
+
+/// The accounting state of an interest-bearing one-resource pool.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct PoolAccrual {
+    /// The amount of the pool's resource that a single pool unit is redeemable for. Starts at one
+    /// and only ever grows as donations are made to the pool.
+    pub accrual_index: PreciseDecimal,
+
+    /// The amount of resources contributed to the pool that is yet to be redeemed, excluding any
+    /// yield that has accrued on top of it.
+    pub principal: Decimal,
+}
@@ -3,6 +3,7 @@ use crate::*;
 #[cfg(feature = "radix_engine_fuzzing")]
 use arbitrary::Arbitrary;
 use radix_engine_common::data::manifest::model::ManifestAddressReservation;
+use radix_engine_common::data::scrypto::model::Reference;
 use radix_engine_common::types::*;
 use radix_engine_interface::api::node_modules::metadata::MetadataInit;
 use radix_engine_interface::api::node_modules::ModuleConfig;
@@ -53,6 +54,36 @@ impl FungibleResourceRoles {
     }
 }
 
+/// The resource roles taken by the `_v2` create functions. These extend [`FungibleResourceRoles`]
+/// with the roles of features added after its tuple became part of the create function schemas.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Default, Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct FungibleResourceRolesV2 {
+    pub mint_roles: Option<MintRoles<RoleDefinition>>,
+    pub burn_roles: Option<BurnRoles<RoleDefinition>>,
+    pub freeze_roles: Option<FreezeRoles<RoleDefinition>>,
+    pub recall_roles: Option<RecallRoles<RoleDefinition>>,
+    pub withdraw_roles: Option<WithdrawRoles<RoleDefinition>>,
+    pub deposit_roles: Option<DepositRoles<RoleDefinition>>,
+    pub soulbound_roles: Option<SoulboundRoles<RoleDefinition>>,
+    pub deny_list_roles: Option<DenyListRoles<RoleDefinition>>,
+}
+
+impl From<FungibleResourceRoles> for FungibleResourceRolesV2 {
+    fn from(value: FungibleResourceRoles) -> Self {
+        Self {
+            mint_roles: value.mint_roles,
+            burn_roles: value.burn_roles,
+            freeze_roles: value.freeze_roles,
+            recall_roles: value.recall_roles,
+            withdraw_roles: value.withdraw_roles,
+            deposit_roles: value.deposit_roles,
+            soulbound_roles: None,
+            deny_list_roles: None,
+        }
+    }
+}
+
 #[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
 pub struct FungibleResourceManagerCreateInput {
@@ -106,6 +137,61 @@ pub struct FungibleResourceManagerCreateWithInitialSupplyManifestInput {
 
 pub type FungibleResourceManagerCreateWithInitialSupplyOutput = (ResourceAddress, Bucket);
 
+pub const FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_IDENT: &str = "create_v2";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleResourceManagerCreateV2Input {
+    pub owner_role: OwnerRole,
+    pub track_total_supply: bool,
+    pub divisibility: u8,
+    pub resource_roles: FungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct FungibleResourceManagerCreateV2ManifestInput {
+    pub owner_role: OwnerRole,
+    pub track_total_supply: bool,
+    pub divisibility: u8,
+    pub resource_roles: FungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+pub type FungibleResourceManagerCreateV2Output = ResourceAddress;
+
+pub const FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT: &str =
+    "create_with_initial_supply_v2";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleResourceManagerCreateWithInitialSupplyV2Input {
+    pub owner_role: OwnerRole,
+    pub track_total_supply: bool,
+    pub divisibility: u8,
+    pub initial_supply: Decimal,
+    pub resource_roles: FungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct FungibleResourceManagerCreateWithInitialSupplyV2ManifestInput {
+    pub owner_role: OwnerRole,
+    pub track_total_supply: bool,
+    pub divisibility: u8,
+    pub initial_supply: Decimal,
+    pub resource_roles: FungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+pub type FungibleResourceManagerCreateWithInitialSupplyV2Output = (ResourceAddress, Bucket);
+
 pub const FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT: &str = "mint";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
@@ -114,3 +200,17 @@ pub struct FungibleResourceManagerMintInput {
 }
 
 pub type FungibleResourceManagerMintOutput = Bucket;
+
+pub const FUNGIBLE_RESOURCE_MANAGER_RECALL_BATCH_IDENT: &str = "recall_batch";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleResourceManagerRecallBatchInput {
+    pub vaults: Vec<(Reference, Decimal)>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct FungibleResourceManagerRecallBatchManifestInput {
+    pub vaults: Vec<(InternalAddress, Decimal)>,
+}
+
+pub type FungibleResourceManagerRecallBatchOutput = Bucket;
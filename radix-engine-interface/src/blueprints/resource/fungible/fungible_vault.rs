@@ -15,6 +15,19 @@ pub struct FungibleVaultLockFeeInput {
 
 pub type FungibleVaultLockFeeOutput = ();
 
+pub const FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT: &str = "lock_fee_with_refund";
+
+/// Locks a fee like [`FungibleVaultLockFeeInput`], except that whatever is left of it once the
+/// transaction has been paid for is returned to `refund_vault` rather than to the locking vault.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleVaultLockFeeWithRefundInput {
+    pub amount: Decimal,
+    pub contingent: bool,
+    pub refund_vault: Reference,
+}
+
+pub type FungibleVaultLockFeeWithRefundOutput = ();
+
 pub const FUNGIBLE_VAULT_LOCK_FUNGIBLE_AMOUNT_IDENT: &str = "lock_amount";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -41,3 +54,12 @@ pub struct FungibleVaultCreateProofOfAmountInput {
 }
 
 pub type FungibleVaultCreateProofOfAmountOutput = Proof;
+
+pub const FUNGIBLE_VAULT_BATCH_RECALL_IDENT: &str = "batch_recall";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleVaultBatchRecallInput {
+    pub amount: Decimal,
+}
+
+pub type FungibleVaultBatchRecallOutput = Decimal;
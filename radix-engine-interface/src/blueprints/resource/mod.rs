@@ -172,6 +172,51 @@ macro_rules! deposit_roles {
     });
 }
 
+resource_roles!(
+    SoulboundRoles,
+    transfer_exemptor,
+    transfer_exemptor_updater,
+    TRANSFER_EXEMPTOR_ROLE,
+    TRANSFER_EXEMPTOR_UPDATER_ROLE,
+    AccessRule::DenyAll
+);
+#[macro_export]
+macro_rules! soulbound_roles {
+    {$($role:ident => $rule:expr;)*} => ({
+        Some($crate::internal_roles_struct!(SoulboundRoles, $($role => $rule;)*))
+    });
+}
+
+resource_roles!(
+    TransferRoyaltyRoles,
+    royalty_exemptor,
+    royalty_exemptor_updater,
+    ROYALTY_EXEMPTOR_ROLE,
+    ROYALTY_EXEMPTOR_UPDATER_ROLE,
+    AccessRule::DenyAll
+);
+#[macro_export]
+macro_rules! transfer_royalty_roles {
+    {$($role:ident => $rule:expr;)*} => ({
+        Some($crate::internal_roles_struct!(TransferRoyaltyRoles, $($role => $rule;)*))
+    });
+}
+
+resource_roles!(
+    DenyListRoles,
+    deny_lister,
+    deny_lister_updater,
+    DENY_LISTER_ROLE,
+    DENY_LISTER_UPDATER_ROLE,
+    AccessRule::DenyAll
+);
+#[macro_export]
+macro_rules! deny_list_roles {
+    {$($role:ident => $rule:expr;)*} => ({
+        Some($crate::internal_roles_struct!(DenyListRoles, $($role => $rule;)*))
+    });
+}
+
 resource_roles!(
     NonFungibleDataUpdateRoles,
     non_fungible_data_updater,
@@ -9,7 +9,7 @@ use radix_engine_common::data::scrypto::{
 };
 use radix_engine_common::prelude::replace_self_package_address;
 use radix_engine_common::prelude::*;
-use radix_engine_interface::api::node_modules::metadata::MetadataInit;
+use radix_engine_interface::api::node_modules::metadata::{MetadataInit, UncheckedUrl};
 use radix_engine_interface::api::node_modules::ModuleConfig;
 use radix_engine_interface::types::NonFungibleData;
 use sbor::rust::collections::{IndexMap, IndexSet};
@@ -67,6 +67,41 @@ impl NonFungibleResourceRoles {
     }
 }
 
+/// The resource roles taken by the `_v2` create functions. These extend
+/// [`NonFungibleResourceRoles`] with the roles of features added after its tuple became part of
+/// the create function schemas.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Default, Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct NonFungibleResourceRolesV2 {
+    pub mint_roles: Option<MintRoles<RoleDefinition>>,
+    pub burn_roles: Option<BurnRoles<RoleDefinition>>,
+    pub freeze_roles: Option<FreezeRoles<RoleDefinition>>,
+    pub recall_roles: Option<RecallRoles<RoleDefinition>>,
+    pub withdraw_roles: Option<WithdrawRoles<RoleDefinition>>,
+    pub deposit_roles: Option<DepositRoles<RoleDefinition>>,
+    pub non_fungible_data_update_roles: Option<NonFungibleDataUpdateRoles<RoleDefinition>>,
+    pub soulbound_roles: Option<SoulboundRoles<RoleDefinition>>,
+    pub transfer_royalty_roles: Option<TransferRoyaltyRoles<RoleDefinition>>,
+    pub deny_list_roles: Option<DenyListRoles<RoleDefinition>>,
+}
+
+impl From<NonFungibleResourceRoles> for NonFungibleResourceRolesV2 {
+    fn from(value: NonFungibleResourceRoles) -> Self {
+        Self {
+            mint_roles: value.mint_roles,
+            burn_roles: value.burn_roles,
+            freeze_roles: value.freeze_roles,
+            recall_roles: value.recall_roles,
+            withdraw_roles: value.withdraw_roles,
+            deposit_roles: value.deposit_roles,
+            non_fungible_data_update_roles: value.non_fungible_data_update_roles,
+            soulbound_roles: None,
+            transfer_royalty_roles: None,
+            deny_list_roles: None,
+        }
+    }
+}
+
 pub const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT: &str = "create";
 
 #[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
@@ -195,6 +230,191 @@ pub struct NonFungibleResourceManagerCreateRuidWithInitialSupplyGenericInput<S,
 
 pub type NonFungibleResourceManagerCreateRuidWithInitialSupplyOutput = (ResourceAddress, Bucket);
 
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_IDENT: &str = "create_v2";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateV2Input {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub resource_roles: NonFungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct NonFungibleResourceManagerCreateV2ManifestInput {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub resource_roles: NonFungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateV2GenericInput<S> {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: S,
+    pub resource_roles: NonFungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+pub type NonFungibleResourceManagerCreateV2Output = ResourceAddress;
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT: &str =
+    "create_with_initial_supply_v2";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateWithInitialSupplyV2Input {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
+    pub resource_roles: NonFungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+/// For manifest
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct NonFungibleResourceManagerCreateWithInitialSupplyV2ManifestInput {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub entries: IndexMap<NonFungibleLocalId, (ManifestValue,)>,
+    pub resource_roles: NonFungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+/// For typed value, to skip any codec
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateWithInitialSupplyV2GenericInput<S, T> {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: S,
+    pub entries: IndexMap<NonFungibleLocalId, (T,)>,
+    pub resource_roles: NonFungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+pub type NonFungibleResourceManagerCreateWithInitialSupplyV2Output = (ResourceAddress, Bucket);
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_V2_IDENT: &str =
+    "create_ruid_non_fungible_with_initial_supply_v2";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateRuidWithInitialSupplyV2Input {
+    pub owner_role: OwnerRole,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub entries: Vec<(ScryptoValue,)>,
+    pub resource_roles: NonFungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+/// For manifest
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct NonFungibleResourceManagerCreateRuidWithInitialSupplyV2ManifestInput {
+    pub owner_role: OwnerRole,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub entries: Vec<(ManifestValue,)>,
+    pub resource_roles: NonFungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+/// For typed value, to skip any codec
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateRuidWithInitialSupplyV2GenericInput<S, T> {
+    pub owner_role: OwnerRole,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: S,
+    pub entries: Vec<(T,)>,
+    pub resource_roles: NonFungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+pub type NonFungibleResourceManagerCreateRuidWithInitialSupplyV2Output = (ResourceAddress, Bucket);
+
+/// The metadata key under which an NFT collection keeps its name.
+pub const NFT_COLLECTION_NAME_METADATA_KEY: &str = "name";
+/// The metadata key under which an NFT collection keeps the URL its items are served from.
+pub const NFT_COLLECTION_BASE_URL_METADATA_KEY: &str = "base_url";
+/// The metadata key under which an NFT collection lists the field names of its item data. It is
+/// derived from the non-fungible data schema and locked when the collection is created.
+pub const NFT_COLLECTION_ITEM_FIELDS_METADATA_KEY: &str = "item_fields";
+
+/// The standard metadata every NFT collection is created with.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct NonFungibleCollectionInit {
+    pub name: String,
+    pub base_url: UncheckedUrl,
+}
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_COLLECTION_IDENT: &str = "create_collection";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateCollectionInput {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub collection: NonFungibleCollectionInit,
+    pub resource_roles: NonFungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+/// For manifest
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct NonFungibleResourceManagerCreateCollectionManifestInput {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub collection: NonFungibleCollectionInit,
+    pub resource_roles: NonFungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+/// For typed value, to skip any codec
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateCollectionGenericInput<S> {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub non_fungible_schema: S,
+    pub collection: NonFungibleCollectionInit,
+    pub resource_roles: NonFungibleResourceRolesV2,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+pub type NonFungibleResourceManagerCreateCollectionOutput = ResourceAddress;
+
 pub const NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_IDENT: &str = "update_non_fungible_data";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -241,6 +461,89 @@ pub struct NonFungibleResourceManagerGetNonFungibleInput {
 
 pub type NonFungibleResourceManagerGetNonFungibleOutput = ScryptoValue;
 
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_MINTED_IDENT: &str = "get_total_minted";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerGetTotalMintedInput {}
+
+/// The number of non-fungibles ever minted, including burnt ones, or `None` if the resource is
+/// not an NFT collection.
+pub type NonFungibleResourceManagerGetTotalMintedOutput = Option<u64>;
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_GET_MINTED_IDS_IDENT: &str = "get_minted_non_fungible_ids";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerGetMintedIdsInput {
+    /// The position in mint order to start from, the first non-fungible minted being at 0
+    pub start_index: u64,
+    pub limit: u32,
+}
+
+/// The ids of an NFT collection in the order they were minted. Burnt non-fungibles keep their
+/// position.
+pub type NonFungibleResourceManagerGetMintedIdsOutput = Vec<NonFungibleLocalId>;
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_RECOUNT_TOTAL_SUPPLY_IDENT: &str = "recount_total_supply";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct NonFungibleResourceManagerRecountTotalSupplyInput {
+    /// The most positions in mint order to look at in this call
+    pub limit: u32,
+}
+
+/// The recounted total supply once the whole mint order has been looked at, or `None` if the
+/// recount needs further calls to finish.
+pub type NonFungibleResourceManagerRecountTotalSupplyOutput = Option<Decimal>;
+
+/// The royalty charged for moving non-fungibles out of a vault without the royalty exemptor.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct TransferRoyaltyConfig {
+    /// The fungible resource the royalty is paid in
+    pub resource_address: ResourceAddress,
+    pub amount_per_non_fungible: Decimal,
+}
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_SET_TRANSFER_ROYALTY_IDENT: &str = "set_transfer_royalty";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerSetTransferRoyaltyInput {
+    pub royalty: Option<TransferRoyaltyConfig>,
+}
+
+pub type NonFungibleResourceManagerSetTransferRoyaltyOutput = ();
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_GET_TRANSFER_ROYALTY_IDENT: &str = "get_transfer_royalty";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerGetTransferRoyaltyInput {}
+
+/// The royalty currently charged on transfers, or `None` if transfers are free.
+pub type NonFungibleResourceManagerGetTransferRoyaltyOutput = Option<TransferRoyaltyConfig>;
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_CLAIM_TRANSFER_ROYALTIES_IDENT: &str =
+    "claim_transfer_royalties";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerClaimTransferRoyaltiesInput {
+    pub resource_address: ResourceAddress,
+}
+
+pub type NonFungibleResourceManagerClaimTransferRoyaltiesOutput = Bucket;
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_COLLECT_TRANSFER_ROYALTY_IDENT: &str =
+    "collect_transfer_royalty";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCollectTransferRoyaltyInput {
+    /// The number of non-fungibles being transferred
+    pub amount: Decimal,
+    pub payment: Bucket,
+}
+
+/// Whatever is left of the payment once the royalty has been taken.
+pub type NonFungibleResourceManagerCollectTransferRoyaltyOutput = Bucket;
+
 pub const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT: &str = "mint";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -300,6 +603,61 @@ pub struct NonFungibleResourceManagerMintSingleRuidGenericInput<T> {
 
 pub type NonFungibleResourceManagerMintSingleRuidOutput = (Bucket, NonFungibleLocalId);
 
+/// A range of integer ids set aside for a later mint, from `start` inclusive to `end` exclusive.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct NonFungibleIdReservation {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl NonFungibleIdReservation {
+    pub fn contains(&self, id: &NonFungibleLocalId) -> bool {
+        match id {
+            NonFungibleLocalId::Integer(id) => self.start <= id.value() && id.value() < self.end,
+            _ => false,
+        }
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = NonFungibleLocalId> {
+        (self.start..self.end).map(NonFungibleLocalId::integer)
+    }
+}
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_RESERVE_INTEGER_IDS_IDENT: &str = "reserve_integer_ids";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct NonFungibleResourceManagerReserveIntegerIdsInput {
+    pub count: u64,
+}
+
+pub type NonFungibleResourceManagerReserveIntegerIdsOutput = NonFungibleIdReservation;
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RESERVED_IDENT: &str = "mint_reserved";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerMintReservedInput {
+    pub reservation: NonFungibleIdReservation,
+    pub entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
+}
+
+/// For manifest
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct NonFungibleResourceManagerMintReservedManifestInput {
+    pub reservation: NonFungibleIdReservation,
+    pub entries: IndexMap<NonFungibleLocalId, (ManifestValue,)>,
+}
+
+/// For typed value, to skip any codec
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerMintReservedGenericInput<T> {
+    pub reservation: NonFungibleIdReservation,
+    pub entries: IndexMap<NonFungibleLocalId, (T,)>,
+}
+
+pub type NonFungibleResourceManagerMintReservedOutput = Bucket;
+
 pub const NON_FUNGIBLE_DATA_SCHEMA_VARIANT_LOCAL: u8 = 0;
 pub const NON_FUNGIBLE_DATA_SCHEMA_VARIANT_REMOTE: u8 = 1;
 
@@ -1,4 +1,4 @@
-use crate::blueprints::resource::Proof;
+use crate::blueprints::resource::{Proof, ResourceOrNonFungible};
 use crate::*;
 use radix_engine_common::data::scrypto::model::NonFungibleLocalId;
 use radix_engine_interface::blueprints::resource::Bucket;
@@ -43,6 +43,29 @@ pub struct NonFungibleVaultRecallNonFungiblesInput {
 
 pub type NonFungibleVaultRecallNonFungiblesOutput = Bucket;
 
+pub const NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_WITH_ALLOWANCE_IDENT: &str =
+    "take_non_fungibles_with_allowance";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct NonFungibleVaultTakeNonFungiblesWithAllowanceInput {
+    pub grantee: ResourceOrNonFungible,
+    pub non_fungible_local_ids: IndexSet<NonFungibleLocalId>,
+}
+
+pub type NonFungibleVaultTakeNonFungiblesWithAllowanceOutput = Bucket;
+
+pub const NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_WITH_ROYALTY_IDENT: &str =
+    "take_non_fungibles_with_royalty";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleVaultTakeNonFungiblesWithRoyaltyInput {
+    pub non_fungible_local_ids: IndexSet<NonFungibleLocalId>,
+    pub payment: Bucket,
+}
+
+/// The taken non-fungibles, and the change left over from the royalty payment.
+pub type NonFungibleVaultTakeNonFungiblesWithRoyaltyOutput = (Bucket, Bucket);
+
 pub const NON_FUNGIBLE_VAULT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT: &str =
     "create_proof_of_non_fungibles";
 
@@ -16,6 +16,12 @@ pub const RECALLER_ROLE: &str = "recaller";
 pub const RECALLER_UPDATER_ROLE: &str = "recaller_updater";
 pub const FREEZER_ROLE: &str = "freezer";
 pub const FREEZER_UPDATER_ROLE: &str = "freezer_updater";
+pub const TRANSFER_EXEMPTOR_ROLE: &str = "transfer_exemptor";
+pub const TRANSFER_EXEMPTOR_UPDATER_ROLE: &str = "transfer_exemptor_updater";
+pub const ROYALTY_EXEMPTOR_ROLE: &str = "royalty_exemptor";
+pub const ROYALTY_EXEMPTOR_UPDATER_ROLE: &str = "royalty_exemptor_updater";
+pub const DENY_LISTER_ROLE: &str = "deny_lister";
+pub const DENY_LISTER_UPDATER_ROLE: &str = "deny_lister_updater";
 pub const NON_FUNGIBLE_DATA_UPDATER_ROLE: &str = "non_fungible_data_updater";
 pub const NON_FUNGIBLE_DATA_UPDATER_UPDATER_ROLE: &str = "non_fungible_data_updater_updater";
 
@@ -53,6 +59,14 @@ pub struct ResourceManagerCreateEmptyVaultInput {}
 
 pub type ResourceManagerCreateEmptyVaultOutput = Vault;
 
+pub const RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_IDENT: &str =
+    "create_empty_recall_protected_vault";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct ResourceManagerCreateEmptyRecallProtectedVaultInput {}
+
+pub type ResourceManagerCreateEmptyRecallProtectedVaultOutput = Vault;
+
 pub const RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_IDENT: &str = "create_empty_bucket";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -81,8 +95,36 @@ pub const RESOURCE_MANAGER_GET_TOTAL_SUPPLY_IDENT: &str = "get_total_supply";
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
 pub struct ResourceManagerGetTotalSupplyInput {}
 
+/// The current total supply, or `None` if the resource was created without total supply tracking.
 pub type ResourceManagerGetTotalSupplyOutput = Option<Decimal>;
 
+pub const RESOURCE_MANAGER_DENY_HOLDER_IDENT: &str = "deny_holder";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct ResourceManagerDenyHolderInput {
+    pub holder: GlobalAddress,
+}
+
+pub type ResourceManagerDenyHolderOutput = ();
+
+pub const RESOURCE_MANAGER_ALLOW_HOLDER_IDENT: &str = "allow_holder";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct ResourceManagerAllowHolderInput {
+    pub holder: GlobalAddress,
+}
+
+pub type ResourceManagerAllowHolderOutput = ();
+
+pub const RESOURCE_MANAGER_IS_HOLDER_DENIED_IDENT: &str = "is_holder_denied";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct ResourceManagerIsHolderDeniedInput {
+    pub holder: GlobalAddress,
+}
+
+pub type ResourceManagerIsHolderDeniedOutput = bool;
+
 pub const RESOURCE_MANAGER_GET_AMOUNT_FOR_WITHDRAWAL_IDENT: &str = "amount_for_withdrawal";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -7,6 +7,16 @@ use super::AccessRule;
 
 pub const SELF_ROLE: &'static str = "_self_";
 pub const OWNER_ROLE: &'static str = "_owner_";
+/// Role of the main module which may pause and unpause a global object. Falls back to the owner
+/// role until it is set, and may only be updated by the owner.
+///
+/// Objects opt in to pausing by defining this role when they are created, see
+/// [`PAUSABLE_FEATURE`].
+pub const PAUSER_ROLE: &'static str = "_pauser_";
+/// Feature the system enables on global objects whose role assignment defined the
+/// [`PAUSER_ROLE`] on creation. Only the public methods of objects with this feature are checked
+/// against the pause state.
+pub const PAUSABLE_FEATURE: &'static str = "_pausable_";
 
 #[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, ScryptoSbor, ManifestSbor)]
@@ -6,6 +6,7 @@ use crate::*;
 use arbitrary::Arbitrary;
 use bitflags::bitflags;
 use radix_engine_common::data::scrypto::*;
+use radix_engine_common::types::Epoch;
 use sbor::rust::prelude::*;
 use sbor::*;
 
@@ -92,6 +93,62 @@ pub struct VaultBurnInput {
 
 pub type VaultBurnOutput = ();
 
+/// The most a grantee can withdraw from a vault under a [`WithdrawAllowance`].
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub enum AllowanceLimit {
+    /// Any of the vault's resource, up to this amount in total
+    Amount(Decimal),
+    /// Only these non-fungibles, which is only valid for non-fungible vaults
+    NonFungibles(IndexSet<NonFungibleLocalId>),
+}
+
+/// Permission for the holder of a badge to withdraw from a vault without its withdrawer role.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct WithdrawAllowance {
+    /// What is left of the allowance, which shrinks with every withdrawal
+    pub limit: AllowanceLimit,
+    /// The first epoch at which the allowance can no longer be used, if it ever expires
+    pub expiry_epoch: Option<Epoch>,
+}
+
+pub const VAULT_GRANT_ALLOWANCE_IDENT: &str = "grant_allowance";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct VaultGrantAllowanceInput {
+    pub grantee: ResourceOrNonFungible,
+    pub allowance: WithdrawAllowance,
+}
+
+pub type VaultGrantAllowanceOutput = ();
+
+pub const VAULT_REVOKE_ALLOWANCE_IDENT: &str = "revoke_allowance";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct VaultRevokeAllowanceInput {
+    pub grantee: ResourceOrNonFungible,
+}
+
+pub type VaultRevokeAllowanceOutput = ();
+
+pub const VAULT_GET_ALLOWANCE_IDENT: &str = "get_allowance";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct VaultGetAllowanceInput {
+    pub grantee: ResourceOrNonFungible,
+}
+
+pub type VaultGetAllowanceOutput = Option<WithdrawAllowance>;
+
+pub const VAULT_TAKE_WITH_ALLOWANCE_IDENT: &str = "take_with_allowance";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct VaultTakeWithAllowanceInput {
+    pub grantee: ResourceOrNonFungible,
+    pub amount: Decimal,
+}
+
+pub type VaultTakeWithAllowanceOutput = Bucket;
+
 //========
 // Stub
 //========
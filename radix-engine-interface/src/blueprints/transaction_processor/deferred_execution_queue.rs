@@ -0,0 +1,99 @@
+use crate::blueprints::macros::*;
+use crate::blueprints::resource::*;
+use radix_engine_common::data::manifest::model::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+use sbor::rust::prelude::*;
+
+pub const DEFERRED_EXECUTION_QUEUE_BLUEPRINT: &str = "DeferredExecutionQueue";
+
+/// A manifest fragment waiting in the deferred execution queue.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct DeferredIntent {
+    /// The manifest encoded instructions of the fragment.
+    pub manifest_encoded_instructions: Vec<u8>,
+    /// The global entities referenced by the instructions, which are made visible to the frame
+    /// running them.
+    pub references: Vec<Reference>,
+    /// The first epoch at which the fragment can be executed.
+    pub execute_at_epoch: Epoch,
+    /// The epoch from which the fragment can no longer be executed.
+    pub expiry_epoch: Epoch,
+    /// The amount of XRD paid to whoever executes the fragment.
+    pub payment: Decimal,
+    /// The rule which must be satisfied to cancel the fragment.
+    pub canceller: AccessRule,
+}
+
+define_invocation! {
+    blueprint_name: DeferredExecutionQueue,
+    function_name: create,
+    input: struct {
+        address_reservation: GlobalAddressReservation
+    },
+    output: type ComponentAddress,
+    manifest_input: struct {
+        address_reservation: ManifestAddressReservation
+    }
+}
+
+define_invocation! {
+    blueprint_name: DeferredExecutionQueue,
+    function_name: enqueue,
+    input: struct {
+        manifest_encoded_instructions: Vec<u8>,
+        references: Vec<Reference>,
+        execute_at_epoch: Epoch,
+        expiry_epoch: Epoch,
+        payment: Bucket,
+        canceller: AccessRule
+    },
+    output: type u64
+}
+
+define_invocation! {
+    blueprint_name: DeferredExecutionQueue,
+    function_name: cancel,
+    input: struct {
+        intent_id: u64
+    },
+    output: type Bucket,
+    manifest_input: struct {
+        intent_id: u64
+    }
+}
+
+define_invocation! {
+    blueprint_name: DeferredExecutionQueue,
+    function_name: execute,
+    input: struct {
+        intent_id: u64
+    },
+    output: type Bucket,
+    manifest_input: struct {
+        intent_id: u64
+    }
+}
+
+define_invocation! {
+    blueprint_name: DeferredExecutionQueue,
+    function_name: get_intent,
+    input: struct {
+        intent_id: u64
+    },
+    output: type Option<DeferredIntent>,
+    manifest_input: struct {
+        intent_id: u64
+    }
+}
+
+define_invocation! {
+    blueprint_name: DeferredExecutionQueue,
+    function_name: run_intent,
+    input: struct {
+        manifest_encoded_instructions: Vec<u8>,
+        references: Vec<Reference>
+    },
+    output: type ()
+}
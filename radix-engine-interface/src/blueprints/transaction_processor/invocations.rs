@@ -1,5 +1,6 @@
 use crate::*;
 use radix_engine_common::data::scrypto::{scrypto_decode, ScryptoDecode};
+use radix_engine_common::prelude::*;
 use sbor::rust::prelude::*;
 
 pub const TRANSACTION_PROCESSOR_BLUEPRINT: &str = "TransactionProcessor";
@@ -8,7 +9,13 @@ pub const TRANSACTION_PROCESSOR_RUN_IDENT: &str = "run";
 
 // TransactionProcessorInput in the engine
 
-pub type TransactionProcessorRunOutput = Vec<InstructionOutput>;
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct TransactionProcessorRunOutput {
+    pub outputs: Vec<InstructionOutput>,
+    /// The resources left on the worktop at the end of the transaction, which were deposited into
+    /// the worktop leftovers recipient
+    pub worktop_leftovers: IndexMap<ResourceAddress, Decimal>,
+}
 
 #[derive(Debug, Clone, Sbor, Eq, PartialEq)]
 pub enum InstructionOutput {
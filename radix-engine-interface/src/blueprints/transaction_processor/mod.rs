@@ -1,3 +1,7 @@
+mod deferred_execution_queue;
 mod invocations;
+mod subintent_processor;
 
+pub use deferred_execution_queue::*;
 pub use invocations::*;
+pub use subintent_processor::*;
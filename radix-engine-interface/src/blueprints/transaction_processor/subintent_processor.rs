@@ -0,0 +1,30 @@
+use crate::blueprints::macros::*;
+use crate::blueprints::resource::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+use sbor::rust::prelude::*;
+
+pub const SUBINTENT_PROCESSOR_BLUEPRINT: &str = "SubintentProcessor";
+
+/// The resources exchanged between a subintent and the transaction executing it.
+#[derive(Debug, Clone, PartialEq, Eq, Default, ScryptoSbor, ManifestSbor)]
+pub struct SubintentConstraints {
+    /// The minimum amount of each resource which has to be passed into the subintent.
+    pub required_inputs: IndexMap<ResourceAddress, Decimal>,
+    /// The minimum amount of each resource which the subintent has to leave on its worktop, all
+    /// of which is returned to the executing transaction.
+    pub declared_outputs: IndexMap<ResourceAddress, Decimal>,
+}
+
+define_invocation! {
+    blueprint_name: SubintentProcessor,
+    function_name: run,
+    input: struct {
+        manifest_encoded_instructions: Vec<u8>,
+        references: Vec<Reference>,
+        inputs: Vec<Bucket>,
+        constraints: SubintentConstraints
+    },
+    output: type Vec<Bucket>
+}
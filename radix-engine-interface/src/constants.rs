@@ -43,10 +43,14 @@ lazy_static! {
             GENESIS_HELPER_PACKAGE.into(),
             FAUCET_PACKAGE.into(),
             POOL_PACKAGE.into(),
+            PRICE_FEED_PACKAGE.into(),
+            PAYMENT_SCHEDULER_PACKAGE.into(),
+            ORDER_BOOK_PACKAGE.into(),
             TRANSACTION_TRACKER_PACKAGE.into(),
             // components
             CONSENSUS_MANAGER.into(),
             TRANSACTION_TRACKER.into(),
+            DEFERRED_EXECUTION_QUEUE.into(),
         )
     };
 }
@@ -56,10 +56,10 @@ pub mod prelude {
     pub use crate::traits::*;
     pub use crate::types::*;
     pub use crate::{
-        access_and_or, access_rule_node, burn_roles, dec, deposit_roles, freeze_roles,
-        internal_roles, metadata, metadata_init, metadata_init_set_entry, metadata_roles,
-        mint_roles, non_fungible_data_update_roles, pdec, recall_roles, role_entry, roles2, rule,
-        withdraw_roles,
+        access_and_or, access_rule_node, burn_roles, dec, deny_list_roles, deposit_roles,
+        freeze_roles, internal_roles, metadata, metadata_init, metadata_init_set_entry,
+        metadata_roles, mint_roles, non_fungible_data_update_roles, pdec, recall_roles, role_entry,
+        roles2, rule, soulbound_roles, transfer_royalty_roles, withdraw_roles,
     };
 }
 
@@ -15,4 +15,15 @@ pub enum ClientCostingEntry<'a> {
     PrepareWasmCode {
         size: usize,
     },
+    /// Reports the linear memory size of the calling WASM instance, which has grown by
+    /// `grown_pages` since it was last reported.
+    GrowWasmMemory {
+        total_pages: u32,
+        grown_pages: u32,
+    },
+    /// Decoding and encoding of the payload of a batched key value store read or write. The
+    /// substate operations for each entry are costed separately.
+    ProcessKeyValueEntryBatch {
+        size: usize,
+    },
 }
@@ -1,4 +1,4 @@
-use core::cell::RefCell;
+use core::cell::{OnceCell, RefCell};
 use radix_engine_common::data::scrypto::*;
 use radix_engine_common::types::*;
 use sbor::representations::*;
@@ -9,18 +9,25 @@ use sbor::traversal::*;
 use sbor::*;
 use utils::ContextualDisplay;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct IndexedScryptoValue {
     bytes: Vec<u8>,
+    /// The references and owned nodes, computed on first use for payloads which are
+    /// known to be valid, and up front for untrusted payloads.
+    index: OnceCell<ValueIndex>,
+    scrypto_value: RefCell<Option<ScryptoValue>>,
+}
+
+#[derive(Clone)]
+struct ValueIndex {
     references: Vec<NodeId>,
     owned_nodes: Vec<NodeId>,
-    scrypto_value: RefCell<Option<ScryptoValue>>,
 }
 
-impl IndexedScryptoValue {
-    fn new(bytes: Vec<u8>) -> Result<Self, DecodeError> {
+impl ValueIndex {
+    fn new(bytes: &[u8]) -> Result<Self, DecodeError> {
         let mut traverser = ScryptoTraverser::new(
-            &bytes,
+            bytes,
             SCRYPTO_SBOR_V1_MAX_DEPTH,
             ExpectedStart::PayloadPrefix(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX),
             true,
@@ -58,13 +65,36 @@ impl IndexedScryptoValue {
         }
 
         Ok(Self {
-            bytes,
             references,
             owned_nodes,
+        })
+    }
+}
+
+impl IndexedScryptoValue {
+    fn new(bytes: Vec<u8>) -> Result<Self, DecodeError> {
+        let index = ValueIndex::new(&bytes)?;
+
+        Ok(Self {
+            bytes,
+            index: OnceCell::from(index),
             scrypto_value: RefCell::new(None),
         })
     }
 
+    fn new_trusted(bytes: Vec<u8>) -> Self {
+        Self {
+            bytes,
+            index: OnceCell::new(),
+            scrypto_value: RefCell::new(None),
+        }
+    }
+
+    fn index(&self) -> &ValueIndex {
+        self.index
+            .get_or_init(|| ValueIndex::new(&self.bytes).expect("Failed to index trusted value"))
+    }
+
     fn get_scrypto_value(&self) -> Ref<ScryptoValue> {
         let is_empty = { self.scrypto_value.borrow().is_none() };
 
@@ -84,12 +114,12 @@ impl IndexedScryptoValue {
 
     pub fn from_typed<T: ScryptoEncode + ?Sized>(value: &T) -> Self {
         let bytes = scrypto_encode(value).expect("Failed to encode trusted Rust value");
-        Self::new(bytes).expect("Failed to index trusted Rust value")
+        Self::new_trusted(bytes)
     }
 
     pub fn from_scrypto_value(value: ScryptoValue) -> Self {
         let bytes = scrypto_encode(&value).expect("Failed to encode trusted ScryptoValue");
-        Self::new(bytes).expect("Failed to index trusted ScryptoValue")
+        Self::new_trusted(bytes)
     }
 
     pub fn from_slice(slice: &[u8]) -> Result<Self, DecodeError> {
@@ -112,6 +142,63 @@ impl IndexedScryptoValue {
         scrypto_decode(&self.bytes)
     }
 
+    /// Positions a decoder at the start of the value found by following `path` from the root.
+    /// Each step of the path selects a field of a tuple or of an enum variant, and the fields
+    /// before it are skipped over without being decoded.
+    fn decoder_at_path(&self, path: &[usize]) -> Result<ScryptoDecoder, DecodeError> {
+        let mut decoder = ScryptoDecoder::new(&self.bytes, SCRYPTO_SBOR_V1_MAX_DEPTH);
+        decoder.read_and_check_payload_prefix(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX)?;
+        for (depth, field_index) in path.iter().cloned().enumerate() {
+            let length = match decoder.read_value_kind()? {
+                ValueKind::Tuple => decoder.read_size()?,
+                ValueKind::Enum => {
+                    decoder.read_discriminator()?;
+                    decoder.read_size()?
+                }
+                value_kind => {
+                    return Err(DecodeError::UnexpectedValueKind {
+                        expected: ValueKind::<ScryptoCustomValueKind>::Tuple.as_u8(),
+                        actual: value_kind.as_u8(),
+                    });
+                }
+            };
+            if field_index >= length {
+                return Err(DecodeError::UnexpectedSize {
+                    expected: field_index + 1,
+                    actual: length,
+                });
+            }
+            for _ in 0..field_index {
+                skip_value(&mut decoder, depth + 1)?;
+            }
+        }
+        Ok(decoder)
+    }
+
+    /// Decodes the value at `path` (see [`Self::as_slice_at_path`]) without decoding the
+    /// rest of the payload.
+    pub fn as_typed_at_path<T: ScryptoDecode>(&self, path: &[usize]) -> Result<T, DecodeError> {
+        self.decoder_at_path(path)?.decode()
+    }
+
+    /// Returns the encoded value (without payload prefix) which is found by following `path`
+    /// from the root, where each step selects a field of a tuple or of an enum variant.
+    pub fn as_slice_at_path(&self, path: &[usize]) -> Result<&[u8], DecodeError> {
+        let mut decoder = self.decoder_at_path(path)?;
+        let start = decoder.get_offset();
+        skip_value(&mut decoder, path.len())?;
+        Ok(&self.bytes[start..decoder.get_offset()])
+    }
+
+    /// Re-frames the value at `path` as a standalone payload, without decoding it.
+    pub fn to_payload_at_path(&self, path: &[usize]) -> Result<Vec<u8>, DecodeError> {
+        let value = self.as_slice_at_path(path)?;
+        let mut payload = Vec::with_capacity(1 + value.len());
+        payload.push(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX);
+        payload.extend_from_slice(value);
+        Ok(payload)
+    }
+
     pub fn as_slice(&self) -> &[u8] {
         self.bytes.as_slice()
     }
@@ -125,18 +212,43 @@ impl IndexedScryptoValue {
     }
 
     pub fn references(&self) -> &Vec<NodeId> {
-        &self.references
+        &self.index().references
     }
 
     pub fn owned_nodes(&self) -> &Vec<NodeId> {
-        &self.owned_nodes
+        &self.index().owned_nodes
     }
 
     pub fn unpack(self) -> (Vec<u8>, Vec<NodeId>, Vec<NodeId>) {
-        (self.bytes, self.owned_nodes, self.references)
+        let index = match self.index.into_inner() {
+            Some(index) => index,
+            None => ValueIndex::new(&self.bytes).expect("Failed to index trusted value"),
+        };
+        (self.bytes, index.owned_nodes, index.references)
+    }
+}
+
+fn skip_value(decoder: &mut ScryptoDecoder, depth: usize) -> Result<(), DecodeError> {
+    let value_kind = decoder.read_value_kind()?;
+    let body_length = calculate_value_tree_body_byte_length::<ScryptoCustomExtension>(
+        decoder.peek_remaining(),
+        value_kind,
+        depth,
+        SCRYPTO_SBOR_V1_MAX_DEPTH,
+    )?;
+    decoder.read_slice(body_length)?;
+    Ok(())
+}
+
+impl PartialEq for IndexedScryptoValue {
+    fn eq(&self, other: &Self) -> bool {
+        // The index and the decoded value are both derived from the bytes
+        self.bytes == other.bytes
     }
 }
 
+impl Eq for IndexedScryptoValue {}
+
 impl Into<Vec<u8>> for IndexedScryptoValue {
     fn into(self) -> Vec<u8> {
         self.bytes
@@ -171,3 +283,83 @@ impl<'s, 'a> ContextualDisplay<ValueDisplayParameters<'s, 'a, ScryptoCustomExten
         ScryptoRawPayload::new_from_valid_slice(self.as_slice()).format(f, *context)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OWNED: NodeId = NodeId([EntityType::InternalFungibleVault as u8; NodeId::LENGTH]);
+    const REFERENCED: NodeId = NodeId([EntityType::GlobalAccount as u8; NodeId::LENGTH]);
+
+    #[test]
+    fn trusted_value_is_indexed_on_first_use() {
+        let value = IndexedScryptoValue::from_typed(&(
+            Own(OWNED),
+            vec![Reference(REFERENCED)],
+            Some(Own(OWNED)),
+        ));
+
+        assert!(value.index.get().is_none());
+        assert_eq!(value.references(), &vec![REFERENCED]);
+        assert_eq!(value.owned_nodes(), &vec![OWNED, OWNED]);
+        assert!(value.index.get().is_some());
+    }
+
+    #[test]
+    fn untrusted_value_is_validated_up_front() {
+        let mut bytes = scrypto_encode(&(1u8, Reference(REFERENCED))).unwrap();
+        bytes.pop();
+
+        assert!(IndexedScryptoValue::from_vec(bytes).is_err());
+    }
+
+    #[test]
+    fn values_with_the_same_bytes_are_equal_regardless_of_indexing() {
+        let bytes = scrypto_encode(&(Own(OWNED), 5u32)).unwrap();
+        let indexed = IndexedScryptoValue::from_vec(bytes).unwrap();
+        let trusted = IndexedScryptoValue::from_typed(&(Own(OWNED), 5u32));
+
+        assert_eq!(indexed, trusted);
+        assert_eq!(trusted.unpack(), indexed.unpack());
+    }
+
+    #[test]
+    fn can_access_nested_field_without_decoding_siblings() {
+        let value = IndexedScryptoValue::from_typed(&(
+            "skipped".to_string(),
+            Some((vec![1u8, 2, 3], Reference(REFERENCED), 7u64)),
+        ));
+
+        assert_eq!(value.as_typed_at_path::<u64>(&[1, 2]), Ok(7u64));
+        assert_eq!(
+            value.as_typed_at_path::<Reference>(&[1, 1]),
+            Ok(Reference(REFERENCED))
+        );
+        assert_eq!(
+            value.to_payload_at_path(&[1, 0]).unwrap(),
+            scrypto_encode(&vec![1u8, 2, 3]).unwrap()
+        );
+        assert_eq!(
+            value.to_payload_at_path(&[]).unwrap(),
+            value.as_slice().to_vec()
+        );
+    }
+
+    #[test]
+    fn accessing_missing_field_fails() {
+        let value = IndexedScryptoValue::from_typed(&(1u8, Some(2u8)));
+
+        assert_eq!(
+            value.as_typed_at_path::<u8>(&[2]),
+            Err(DecodeError::UnexpectedSize {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert!(matches!(
+            value.as_typed_at_path::<u8>(&[0, 0]),
+            Err(DecodeError::UnexpectedValueKind { .. })
+        ));
+        assert_eq!(value.as_typed_at_path::<u8>(&[1, 0]), Ok(2u8));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::types::Level;
+use crate::*;
+use sbor::rust::fmt;
+use sbor::rust::prelude::*;
+
+/// A log message along with key-value pairs describing the context it was emitted in.
+#[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+pub struct LogRecord {
+    pub level: Level,
+    pub message: String,
+    pub fields: Vec<(String, String)>,
+}
+
+impl LogRecord {
+    pub fn new(level: Level, message: String) -> Self {
+        Self {
+            level,
+            message,
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn with_field<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.fields.push((key.into(), value.into()));
+        self
+    }
+
+    /// Returns the value of the first field with the given key.
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// The number of bytes of the message and fields, which is what the engine meters on.
+    pub fn size(&self) -> usize {
+        self.message.len()
+            + self
+                .fields
+                .iter()
+                .map(|(k, v)| k.len() + v.len())
+                .sum::<usize>()
+    }
+}
+
+impl fmt::Display for LogRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        for (key, value) in &self.fields {
+            write!(f, " {}={}", key, value)?;
+        }
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ mod interface_well_known_types;
 mod invocation;
 mod kv_store_init;
 mod level;
+mod log_record;
 mod node_layout;
 mod package_code;
 mod royalty_config;
@@ -19,6 +20,7 @@ pub use indexed_value::*;
 pub use invocation::*;
 pub use kv_store_init::*;
 pub use level::*;
+pub use log_record::*;
 pub use node_layout::*;
 pub use package_code::*;
 pub use royalty_config::*;
@@ -19,6 +19,23 @@ pub enum BootLoaderField {
     Vm = 2u8,
 }
 
+/// Used only with global objects, keyed by event name, to record the subscribers of each event
+pub const EVENT_SUBSCRIPTIONS_PARTITION: PartitionNumber = PartitionNumber(33u8);
+
+/// Used with every persisted node apart from TRANSACTION_TRACKER, to account for the total
+/// size of the node's state
+pub const STATE_SIZE_PARTITION: PartitionNumber = PartitionNumber(34u8);
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord, FromRepr)]
+pub enum StateSizeField {
+    StateSize,
+}
+
+/// Used only with global objects, keyed by rate limit name and caller, to count the recent
+/// calls made against each rate limit
+pub const RATE_LIMITS_PARTITION: PartitionNumber = PartitionNumber(35u8);
+
 pub const TYPE_INFO_FIELD_PARTITION: PartitionNumber = PartitionNumber(0u8);
 
 #[repr(u8)]
@@ -49,7 +66,10 @@ pub const ROLE_ASSIGNMENT_FIELDS_PARTITION: PartitionNumber = PartitionNumber(5u
 pub const ROLE_ASSIGNMENT_FIELDS_PARTITION_OFFSET: PartitionOffset = PartitionOffset(0u8);
 pub const ROLE_ASSIGNMENT_ROLE_DEF_PARTITION: PartitionNumber = PartitionNumber(6u8);
 pub const ROLE_ASSIGNMENT_ROLE_DEF_PARTITION_OFFSET: PartitionOffset = PartitionOffset(1u8);
-pub const ROLE_ASSIGNMENT_MUTABILITY_PARTITION_OFFSET: PartitionOffset = PartitionOffset(2u8);
+pub const ROLE_ASSIGNMENT_PAUSE_PARTITION: PartitionNumber = PartitionNumber(7u8);
+pub const ROLE_ASSIGNMENT_PAUSE_PARTITION_OFFSET: PartitionOffset = PartitionOffset(2u8);
+pub const ROLE_ASSIGNMENT_PENDING_OWNER_PARTITION: PartitionNumber = PartitionNumber(8u8);
+pub const ROLE_ASSIGNMENT_PENDING_OWNER_PARTITION_OFFSET: PartitionOffset = PartitionOffset(3u8);
 
 //=============================
 // Blueprint partition - common
@@ -141,6 +161,8 @@ blueprint_partition_offset!(
     pub enum RoleAssignmentPartitionOffset {
         Field,
         AccessRuleKeyValue,
+        PausedModuleKeyValue,
+        PendingOwnerKeyValue,
     }
 );
 
@@ -163,6 +185,7 @@ pub enum ComponentField {
 blueprint_partition_offset!(
     pub enum FungibleResourceManagerPartitionOffset {
         Field,
+        DeniedHolderKeyValue,
     }
 );
 
@@ -176,6 +199,7 @@ blueprint_partition_offset!(
         CodeVmTypeKeyValue,
         CodeOriginalCodeKeyValue,
         CodeInstrumentedCodeKeyValue,
+        RoyaltyExemptionKeyValue,
     }
 );
 
@@ -183,12 +207,17 @@ blueprint_partition_offset!(
     pub enum NonFungibleResourceManagerPartitionOffset {
         Field,
         DataKeyValue,
+        MintedIdKeyValue,
+        RoyaltyVaultKeyValue,
+        DeniedHolderKeyValue,
+        IdReservationKeyValue,
     }
 );
 
 blueprint_partition_offset!(
     pub enum FungibleVaultPartitionOffset {
         Field,
+        AllowanceKeyValue,
     }
 );
 
@@ -196,6 +225,7 @@ blueprint_partition_offset!(
     pub enum NonFungibleVaultPartitionOffset {
         Field,
         NonFungibleIndex,
+        AllowanceKeyValue,
     }
 );
 
@@ -284,6 +314,37 @@ blueprint_partition_offset!(
 blueprint_partition_offset!(
     pub enum MultiResourcePoolPartitionOffset {
         Field,
+        ResourceConfigKeyValue,
+    }
+);
+
+blueprint_partition_offset!(
+    pub enum PriceFeedPartitionOffset {
+        Field,
+    }
+);
+
+blueprint_partition_offset!(
+    pub enum PaymentSchedulerPartitionOffset {
+        Field,
+        ScheduleKeyValue,
+        DuePaymentSortedIndex,
+    }
+);
+
+blueprint_partition_offset!(
+    pub enum OrderBookPartitionOffset {
+        Field,
+        OrderKeyValue,
+        BidLevelSortedIndex,
+        AskLevelSortedIndex,
+    }
+);
+
+blueprint_partition_offset!(
+    pub enum DeferredExecutionQueuePartitionOffset {
+        Field,
+        IntentKeyValue,
     }
 );
 
@@ -347,6 +408,7 @@ impl CollectionDescriptor for CollectionIndex {
 }
 
 substate_key!(BootLoaderField);
+substate_key!(StateSizeField);
 substate_key!(TypeInfoField);
 substate_key!(RoyaltyField);
 substate_key!(ComponentField);
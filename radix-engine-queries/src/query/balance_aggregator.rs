@@ -0,0 +1,70 @@
+use super::{StateTreeTraverser, StateTreeVisitor};
+use radix_engine_interface::{
+    blueprints::resource::{LiquidFungibleResource, LiquidNonFungibleVault},
+    math::Decimal,
+    types::{NodeId, ResourceAddress},
+};
+use radix_engine_store_interface::interface::SubstateDatabase;
+use sbor::rust::prelude::*;
+
+/// Sums the balances of the vaults found in a state tree, per resource. Non-fungible vaults
+/// contribute their amount, rather than their ids.
+pub struct BalanceAggregator {
+    balances: IndexMap<ResourceAddress, Decimal>,
+}
+
+impl BalanceAggregator {
+    pub fn new() -> Self {
+        BalanceAggregator {
+            balances: index_map_new(),
+        }
+    }
+
+    pub fn to_balances(self) -> IndexMap<ResourceAddress, Decimal> {
+        self.balances
+    }
+
+    fn add(&mut self, address: &ResourceAddress, amount: Decimal) {
+        let entry = self.balances.entry(*address).or_default();
+        // NOTE: Decimal arithmetic operation safe unwrap.
+        //       Resources have a mint limit below the Decimal max
+        *entry = entry
+            .checked_add(amount)
+            .expect("Resource overflow despite mint limit")
+    }
+}
+
+impl StateTreeVisitor for BalanceAggregator {
+    fn visit_fungible_vault(
+        &mut self,
+        _vault_id: NodeId,
+        address: &ResourceAddress,
+        resource: &LiquidFungibleResource,
+    ) {
+        self.add(address, resource.amount());
+    }
+
+    fn visit_non_fungible_vault(
+        &mut self,
+        _vault_id: NodeId,
+        address: &ResourceAddress,
+        resource: &LiquidNonFungibleVault,
+    ) {
+        self.add(address, resource.amount);
+    }
+}
+
+/// Returns the balances held by the given entity and all the nodes it owns, per resource, in the
+/// order the vaults were found.
+///
+/// This reads committed state only, and is meant for tooling such as explorers rather than for
+/// use during transaction execution.
+pub fn get_entity_balances<S: SubstateDatabase>(
+    substate_db: &S,
+    node_id: &NodeId,
+) -> IndexMap<ResourceAddress, Decimal> {
+    let mut aggregator = BalanceAggregator::new();
+    let mut traverser = StateTreeTraverser::new(substate_db, &mut aggregator, 100);
+    traverser.traverse_subtree(None, *node_id);
+    aggregator.to_balances()
+}
@@ -0,0 +1,124 @@
+use crate::typed_substate_layout::*;
+use radix_engine::prelude::*;
+use radix_engine::system::system_db_reader::{
+    ObjectPartitionDescriptor, SystemDatabaseReader, SystemPartitionDescriptor, SystemReaderError,
+};
+use radix_engine_interface::types::NodeId;
+use radix_engine_store_interface::interface::SubstateDatabase;
+use sbor::rust::vec::Vec;
+
+/// All the substates of an entity, grouped by partition.
+#[derive(Debug)]
+pub struct EntityDump {
+    pub node_id: NodeId,
+    pub entity_type: EntityType,
+    pub partitions: Vec<PartitionDump>,
+}
+
+#[derive(Debug)]
+pub struct PartitionDump {
+    pub partition_number: PartitionNumber,
+    pub descriptors: Vec<SystemPartitionDescriptor>,
+    pub substates: Vec<SubstateDump>,
+}
+
+/// A single substate, along with its typed key and value.
+///
+/// Typing failures are kept rather than aborting the dump, so that a substate which does not
+/// match the known layouts can still be inspected through its raw value.
+#[derive(Debug)]
+pub struct SubstateDump {
+    pub substate_key: SubstateKey,
+    pub typed_key: Result<TypedSubstateKey, String>,
+    pub typed_value: Result<TypedSubstateValue, String>,
+    pub raw_value: Vec<u8>,
+}
+
+pub struct EntityDumper<'s, S: SubstateDatabase> {
+    system_db_reader: SystemDatabaseReader<'s, S>,
+}
+
+impl<'s, S: SubstateDatabase> EntityDumper<'s, S> {
+    pub fn new(substate_db: &'s S) -> Self {
+        EntityDumper {
+            system_db_reader: SystemDatabaseReader::new(substate_db),
+        }
+    }
+
+    /// Reads every partition of the given node, decoding its substates with the typed substate
+    /// layout.
+    ///
+    /// Owned child nodes are not followed; they can be dumped in turn by their node ids.
+    pub fn dump_entity(&self, node_id: &NodeId) -> Result<EntityDump, SystemReaderError> {
+        let entity_type = node_id
+            .entity_type()
+            .ok_or(SystemReaderError::NodeIdDoesNotExist)?;
+        // Fails early for a node which does not exist
+        self.system_db_reader.get_type_info(node_id)?;
+
+        let mut partitions = Vec::new();
+        for partition_number in u8::MIN..=u8::MAX {
+            let partition_number = PartitionNumber(partition_number);
+            let descriptors = self
+                .system_db_reader
+                .get_partition_descriptors(node_id, &partition_number)?;
+            let Some(descriptor) = descriptors.first() else {
+                continue;
+            };
+
+            let substates: Vec<(SubstateKey, Vec<u8>)> = match descriptor {
+                SystemPartitionDescriptor::BootLoader
+                | SystemPartitionDescriptor::StateSize
+                | SystemPartitionDescriptor::TypeInfo
+                | SystemPartitionDescriptor::Object(_, ObjectPartitionDescriptor::Fields) => self
+                    .system_db_reader
+                    .substates_iter::<FieldKey>(node_id, partition_number)
+                    .collect(),
+                SystemPartitionDescriptor::Object(
+                    _,
+                    ObjectPartitionDescriptor::SortedIndexCollection(_),
+                ) => self
+                    .system_db_reader
+                    .substates_iter::<SortedKey>(node_id, partition_number)
+                    .collect(),
+                _ => self
+                    .system_db_reader
+                    .substates_iter::<MapKey>(node_id, partition_number)
+                    .collect(),
+            };
+            if substates.is_empty() {
+                continue;
+            }
+
+            let substates = substates
+                .into_iter()
+                .map(|(substate_key, raw_value)| {
+                    let typed_key =
+                        to_typed_node_substate_key(node_id, partition_number, &substate_key);
+                    let typed_value = match &typed_key {
+                        Ok(typed_key) => to_typed_substate_value(typed_key, &raw_value),
+                        Err(error) => Err(error.clone()),
+                    };
+                    SubstateDump {
+                        substate_key,
+                        typed_key,
+                        typed_value,
+                        raw_value,
+                    }
+                })
+                .collect();
+
+            partitions.push(PartitionDump {
+                partition_number,
+                descriptors,
+                substates,
+            });
+        }
+
+        Ok(EntityDump {
+            node_id: *node_id,
+            entity_type,
+            partitions,
+        })
+    }
+}
@@ -1,7 +1,11 @@
 mod accounter;
+mod balance_aggregator;
+mod entity_dump;
 mod traverse;
 mod vault_finder;
 
 pub use accounter::*;
+pub use balance_aggregator::*;
+pub use entity_dump::*;
 pub use traverse::*;
 pub use vault_finder::*;
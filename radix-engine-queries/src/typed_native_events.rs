@@ -201,11 +201,13 @@ define_structure! {
             AccountWithdrawEvent,
             AccountDepositEvent,
             AccountRejectedDepositEvent,
+            AccountDepositBatchResultEvent,
             AccountSetResourcePreferenceEvent,
             AccountRemoveResourcePreferenceEvent,
             AccountSetDefaultDepositRuleEvent,
             AccountAddAuthorizedDepositorEvent,
-            AccountRemoveAuthorizedDepositorEvent
+            AccountRemoveAuthorizedDepositorEvent,
+            AccountSetAuthorizerEvent
         ]
     },
     Identity => {
@@ -237,6 +239,9 @@ define_structure! {
             OneResourcePoolRedemptionEvent,
             OneResourcePoolWithdrawEvent,
             OneResourcePoolDepositEvent,
+            OneResourcePoolDonationEvent,
+            OneResourcePoolAccrualIndexUpdatedEvent,
+            OneResourcePoolYieldRedemptionEvent,
         ],
         TwoResourcePool => [
             TwoResourcePoolContributionEvent,
@@ -249,6 +254,9 @@ define_structure! {
             MultiResourcePoolRedemptionEvent,
             MultiResourcePoolWithdrawEvent,
             MultiResourcePoolDepositEvent,
+            MultiResourcePoolResourceAddedEvent,
+            MultiResourcePoolResourceRetiredEvent,
+            MultiResourcePoolResourceWeightSetEvent,
         ],
     },
     Resource => {
@@ -268,11 +276,16 @@ define_structure! {
             VaultCreationEvent,
             MintFungibleResourceEvent,
             BurnFungibleResourceEvent,
+            DenyHolderEvent,
+            AllowHolderEvent,
+            RecallBatchEvent,
         ],
         NonFungibleResourceManager => [
             VaultCreationEvent,
             MintNonFungibleResourceEvent,
             BurnNonFungibleResourceEvent,
+            DenyHolderEvent,
+            AllowHolderEvent,
         ]
     },
     TransactionProcessor => {
@@ -288,6 +301,10 @@ define_structure! {
             SetRoleEvent,
             SetOwnerRoleEvent,
             LockOwnerRoleEvent,
+            ProposeOwnerRoleEvent,
+            AcceptOwnerRoleEvent,
+            PauseEvent,
+            UnpauseEvent,
         ]
     },
     Metadata => {
@@ -306,6 +323,10 @@ type OneResourcePoolContributionEvent = pool_events::one_resource_pool::Contribu
 type OneResourcePoolRedemptionEvent = pool_events::one_resource_pool::RedemptionEvent;
 type OneResourcePoolWithdrawEvent = pool_events::one_resource_pool::WithdrawEvent;
 type OneResourcePoolDepositEvent = pool_events::one_resource_pool::DepositEvent;
+type OneResourcePoolDonationEvent = pool_events::one_resource_pool::DonationEvent;
+type OneResourcePoolAccrualIndexUpdatedEvent =
+    pool_events::one_resource_pool::AccrualIndexUpdatedEvent;
+type OneResourcePoolYieldRedemptionEvent = pool_events::one_resource_pool::YieldRedemptionEvent;
 
 type TwoResourcePoolContributionEvent = pool_events::two_resource_pool::ContributionEvent;
 type TwoResourcePoolRedemptionEvent = pool_events::two_resource_pool::RedemptionEvent;
@@ -316,6 +337,11 @@ type MultiResourcePoolContributionEvent = pool_events::multi_resource_pool::Cont
 type MultiResourcePoolRedemptionEvent = pool_events::multi_resource_pool::RedemptionEvent;
 type MultiResourcePoolWithdrawEvent = pool_events::multi_resource_pool::WithdrawEvent;
 type MultiResourcePoolDepositEvent = pool_events::multi_resource_pool::DepositEvent;
+type MultiResourcePoolResourceAddedEvent = pool_events::multi_resource_pool::ResourceAddedEvent;
+type MultiResourcePoolResourceRetiredEvent =
+    pool_events::multi_resource_pool::ResourceRetiredEvent;
+type MultiResourcePoolResourceWeightSetEvent =
+    pool_events::multi_resource_pool::ResourceWeightSetEvent;
 
 type FungibleVaultLockFeeEvent = fungible_vault::LockFeeEvent;
 type FungibleVaultPayFeeEvent = fungible_vault::PayFeeEvent;
@@ -330,11 +356,13 @@ type NonFungibleVaultRecallEvent = non_fungible_vault::RecallEvent;
 type AccountWithdrawEvent = account::WithdrawEvent;
 type AccountDepositEvent = account::DepositEvent;
 type AccountRejectedDepositEvent = account::RejectedDepositEvent;
+type AccountDepositBatchResultEvent = account::DepositBatchResultEvent;
 type AccountSetResourcePreferenceEvent = account::SetResourcePreferenceEvent;
 type AccountRemoveResourcePreferenceEvent = account::RemoveResourcePreferenceEvent;
 type AccountSetDefaultDepositRuleEvent = account::SetDefaultDepositRuleEvent;
 type AccountAddAuthorizedDepositorEvent = account::AddAuthorizedDepositorEvent;
 type AccountRemoveAuthorizedDepositorEvent = account::RemoveAuthorizedDepositorEvent;
+type AccountSetAuthorizerEvent = account::SetAuthorizerEvent;
 
 /// This enum uses some special syntax to define the structure of events. This makes the code for
 /// model definitions very compact, allows for very easy addition of more packages, blueprints or
@@ -19,12 +19,17 @@ use radix_engine::blueprints::pool::v1::substates::two_resource_pool::{
     TwoResourcePoolTypedSubstateKey, TwoResourcePoolTypedSubstateValue,
 };
 pub use radix_engine::blueprints::resource::*;
+use radix_engine::blueprints::transaction_processor::{
+    DeferredExecutionQueueTypedSubstateKey, DeferredExecutionQueueTypedSubstateValue,
+};
 pub use radix_engine::blueprints::transaction_tracker::*;
 pub use radix_engine::system::attached_modules::metadata::*;
 pub use radix_engine::system::attached_modules::role_assignment::*;
 pub use radix_engine::system::attached_modules::royalty::*;
+use radix_engine::system::system_substates::EventSubscriptionsSubstate;
 use radix_engine::system::system_substates::FieldSubstate;
 use radix_engine::system::system_substates::KeyValueEntrySubstate;
+use radix_engine::system::system_substates::{RateLimitKey, RateLimitSubstate};
 pub use radix_engine::system::type_info::*;
 use radix_engine::vm::VmBoot;
 pub use radix_engine_interface::api::node_modules::royalty::*;
@@ -39,6 +44,9 @@ use transaction::prelude::IntentHash;
 // Specifically:
 // * Every (EntityType, PartitionNumber, SubstateKey) should be mappable into a `TypedSubstateKey`
 // * Every (&TypedSubstateKey, Data) should be mappable into a `TypedSubstateValue`
+// * Every (NodeId, PartitionNumber, SubstateKey, Data) should be mappable through
+//   `to_typed_substate`, which also covers native objects living at well-known generic
+//   component addresses
 //
 // Please keep them these in-line with the well-known objects, and please don't
 // remove these without talking to the Network team.
@@ -79,6 +87,9 @@ use transaction::prelude::IntentHash;
 #[derive(Debug, Clone)]
 pub enum TypedSubstateKey {
     BootLoader(TypedBootLoaderSubstateKey),
+    EventSubscriptions(TypedEventSubscriptionsSubstateKey),
+    StateSize(TypedStateSizeSubstateKey),
+    RateLimits(TypedRateLimitsSubstateKey),
     TypeInfo(TypedTypeInfoSubstateKey),
     Schema(TypedSchemaSubstateKey),
     RoleAssignmentModule(TypedRoleAssignmentSubstateKey),
@@ -109,6 +120,22 @@ pub enum TypedBootLoaderSubstateKey {
     BootLoaderField(BootLoaderField),
 }
 
+#[derive(Debug, Clone)]
+pub enum TypedEventSubscriptionsSubstateKey {
+    /// The key is the event name
+    EventName(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum TypedStateSizeSubstateKey {
+    StateSizeField(StateSizeField),
+}
+
+#[derive(Debug, Clone)]
+pub enum TypedRateLimitsSubstateKey {
+    RateLimit(RateLimitKey),
+}
+
 #[derive(Debug, Clone)]
 pub enum TypedTypeInfoSubstateKey {
     TypeInfoField(TypeInfoField),
@@ -123,6 +150,8 @@ pub enum TypedSchemaSubstateKey {
 pub enum TypedRoleAssignmentSubstateKey {
     RoleAssignmentField(RoleAssignmentField),
     Rule(ModuleRoleKey),
+    PausedModule(ModuleId),
+    PendingOwner,
 }
 
 #[derive(Debug, Clone)]
@@ -155,6 +184,7 @@ pub enum TypedMainModuleSubstateKey {
     MultiResourcePool(MultiResourcePoolTypedSubstateKey),
     TransactionTrackerField(TransactionTrackerField),
     TransactionTrackerCollectionEntry(IntentHash),
+    DeferredExecutionQueue(DeferredExecutionQueueTypedSubstateKey),
     // Objects - Generic Scrypto Components
     GenericScryptoComponentField(ComponentField),
     // KVStores - Generic KV Stores
@@ -165,6 +195,53 @@ fn error(descriptor: &'static str) -> String {
     format!("Could not convert {} to TypedSubstateKey", descriptor)
 }
 
+/// Maps a raw substate of the given node into its typed key and value.
+///
+/// Unlike [`to_typed_substate_key`], this takes the node id rather than just its entity type, so
+/// that native objects which are globalized as generic components (such as the deferred execution
+/// queue) are mapped to their own layout.
+pub fn to_typed_substate(
+    node_id: &NodeId,
+    partition_num: PartitionNumber,
+    substate_key: &SubstateKey,
+    data: &[u8],
+) -> Result<(TypedSubstateKey, TypedSubstateValue), String> {
+    let typed_substate_key = to_typed_node_substate_key(node_id, partition_num, substate_key)?;
+    let typed_substate_value = to_typed_substate_value(&typed_substate_key, data)?;
+    Ok((typed_substate_key, typed_substate_value))
+}
+
+/// Maps a substate key of the given node into a [`TypedSubstateKey`], taking well-known native
+/// objects into account.
+pub fn to_typed_node_substate_key(
+    node_id: &NodeId,
+    partition_num: PartitionNumber,
+    substate_key: &SubstateKey,
+) -> Result<TypedSubstateKey, String> {
+    let entity_type = node_id
+        .entity_type()
+        .ok_or_else(|| format!("Unknown entity type of node {:?}", node_id))?;
+
+    if partition_num >= MAIN_BASE_PARTITION && node_id.eq(DEFERRED_EXECUTION_QUEUE.as_node_id()) {
+        let partition_offset = PartitionOffset(partition_num.0 - MAIN_BASE_PARTITION.0);
+        let key = DeferredExecutionQueueTypedSubstateKey::for_key_at_partition_offset(
+            partition_offset,
+            substate_key,
+        )
+        .map_err(|_| {
+            format!(
+                "Could not convert DeferredExecutionQueue (partition offset {}) {:?} key to TypedObjectSubstateKey",
+                partition_offset.0, substate_key
+            )
+        })?;
+        return Ok(TypedSubstateKey::MainModule(
+            TypedMainModuleSubstateKey::DeferredExecutionQueue(key),
+        ));
+    }
+
+    to_typed_substate_key(entity_type, partition_num, substate_key)
+}
+
 pub fn to_typed_substate_key(
     entity_type: EntityType,
     partition_num: PartitionNumber,
@@ -176,6 +253,31 @@ pub fn to_typed_substate_key(
                 BootLoaderField::try_from(substate_key).map_err(|_| error("BootLoaderField"))?,
             ))
         }
+        EVENT_SUBSCRIPTIONS_PARTITION => TypedSubstateKey::EventSubscriptions(
+            TypedEventSubscriptionsSubstateKey::EventName(
+                scrypto_decode(
+                    substate_key
+                        .for_map()
+                        .ok_or_else(|| error("EventSubscriptions key"))?,
+                )
+                .map_err(|_| error("string EventSubscriptions key"))?,
+            ),
+        ),
+        STATE_SIZE_PARTITION => {
+            TypedSubstateKey::StateSize(TypedStateSizeSubstateKey::StateSizeField(
+                StateSizeField::try_from(substate_key).map_err(|_| error("StateSizeField"))?,
+            ))
+        }
+        RATE_LIMITS_PARTITION => {
+            TypedSubstateKey::RateLimits(TypedRateLimitsSubstateKey::RateLimit(
+                scrypto_decode(
+                    substate_key
+                        .for_map()
+                        .ok_or_else(|| error("RateLimits key"))?,
+                )
+                .map_err(|_| error("RateLimits key"))?,
+            ))
+        }
         TYPE_INFO_FIELD_PARTITION => {
             TypedSubstateKey::TypeInfo(TypedTypeInfoSubstateKey::TypeInfoField(
                 TypeInfoField::try_from(substate_key).map_err(|_| error("TypeInfoField"))?,
@@ -226,6 +328,21 @@ pub fn to_typed_substate_key(
                 scrypto_decode(&key).map_err(|_| error("Access Rules key"))?,
             ))
         }
+        ROLE_ASSIGNMENT_PAUSE_PARTITION => {
+            let key = substate_key
+                .for_map()
+                .ok_or_else(|| error("Paused Module key"))?;
+            TypedSubstateKey::RoleAssignmentModule(TypedRoleAssignmentSubstateKey::PausedModule(
+                scrypto_decode(&key).map_err(|_| error("Paused Module key"))?,
+            ))
+        }
+        ROLE_ASSIGNMENT_PENDING_OWNER_PARTITION => {
+            let key = substate_key
+                .for_map()
+                .ok_or_else(|| error("Pending Owner key"))?;
+            scrypto_decode::<()>(&key).map_err(|_| error("Pending Owner key"))?;
+            TypedSubstateKey::RoleAssignmentModule(TypedRoleAssignmentSubstateKey::PendingOwner)
+        }
         partition_num @ _ if partition_num >= MAIN_BASE_PARTITION => {
             TypedSubstateKey::MainModule(to_typed_object_module_substate_key(
                 entity_type,
@@ -369,6 +486,9 @@ fn to_typed_object_substate_key_internal(
 #[derive(Debug)]
 pub enum TypedSubstateValue {
     BootLoader(BootLoaderSubstateValue),
+    EventSubscriptions(EventSubscriptionsSubstate),
+    StateSize(NodeStateSize),
+    RateLimits(RateLimitSubstate),
     TypeInfoModule(TypedTypeInfoModuleSubstateValue),
     Schema(KeyValueEntrySubstate<VersionedScryptoSchema>),
     RoleAssignmentModule(TypedRoleAssignmentModuleSubstateValue),
@@ -391,6 +511,8 @@ pub enum TypedTypeInfoModuleSubstateValue {
 pub enum TypedRoleAssignmentModuleSubstateValue {
     OwnerRole(FieldSubstate<RoleAssignmentOwnerFieldPayload>),
     Rule(KeyValueEntrySubstate<RoleAssignmentAccessRuleEntryPayload>),
+    PausedModule(KeyValueEntrySubstate<RoleAssignmentPausedModuleEntryPayload>),
+    PendingOwner(KeyValueEntrySubstate<RoleAssignmentPendingOwnerEntryPayload>),
 }
 
 #[derive(Debug)]
@@ -422,6 +544,7 @@ pub enum TypedMainModuleSubstateValue {
     MultiResourcePool(MultiResourcePoolTypedSubstateValue),
     TransactionTracker(TypedTransactionTrackerFieldValue),
     TransactionTrackerCollectionEntry(KeyValueEntrySubstate<TransactionStatusSubstateContents>),
+    DeferredExecutionQueue(DeferredExecutionQueueTypedSubstateValue),
     // Generic Scrypto Components and KV Stores
     GenericScryptoComponent(GenericScryptoComponentFieldValue),
     GenericKeyValueStoreEntry(KeyValueEntrySubstate<ScryptoOwnedRawValue>),
@@ -461,6 +584,11 @@ fn to_typed_substate_value_internal(
                 }
             })
         }
+        TypedSubstateKey::EventSubscriptions(_) => {
+            TypedSubstateValue::EventSubscriptions(scrypto_decode(data)?)
+        }
+        TypedSubstateKey::StateSize(_) => TypedSubstateValue::StateSize(scrypto_decode(data)?),
+        TypedSubstateKey::RateLimits(_) => TypedSubstateValue::RateLimits(scrypto_decode(data)?),
         TypedSubstateKey::TypeInfo(type_info_key) => {
             TypedSubstateValue::TypeInfoModule(match type_info_key {
                 TypedTypeInfoSubstateKey::TypeInfoField(TypeInfoField::TypeInfo) => {
@@ -480,6 +608,16 @@ fn to_typed_substate_value_internal(
             TypedRoleAssignmentSubstateKey::Rule(_) => TypedSubstateValue::RoleAssignmentModule(
                 TypedRoleAssignmentModuleSubstateValue::Rule(scrypto_decode(data)?),
             ),
+            TypedRoleAssignmentSubstateKey::PausedModule(_) => {
+                TypedSubstateValue::RoleAssignmentModule(
+                    TypedRoleAssignmentModuleSubstateValue::PausedModule(scrypto_decode(data)?),
+                )
+            }
+            TypedRoleAssignmentSubstateKey::PendingOwner => {
+                TypedSubstateValue::RoleAssignmentModule(
+                    TypedRoleAssignmentModuleSubstateValue::PendingOwner(scrypto_decode(data)?),
+                )
+            }
         },
         TypedSubstateKey::RoyaltyModule(royalty_module_key) => {
             TypedSubstateValue::RoyaltyModule(match royalty_module_key {
@@ -585,6 +723,11 @@ fn to_typed_object_substate_value(
         TypedMainModuleSubstateKey::TransactionTrackerCollectionEntry(_) => {
             TypedMainModuleSubstateValue::TransactionTrackerCollectionEntry(scrypto_decode(data)?)
         }
+        TypedMainModuleSubstateKey::DeferredExecutionQueue(key) => {
+            TypedMainModuleSubstateValue::DeferredExecutionQueue(
+                DeferredExecutionQueueTypedSubstateValue::from_key_and_data(key, data)?,
+            )
+        }
     };
     Ok(substate_value)
 }
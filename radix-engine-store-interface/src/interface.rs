@@ -121,6 +121,16 @@ pub trait SubstateDatabase {
         sort_key: &DbSortKey,
     ) -> Option<DbSubstateValue>;
 
+    /// Reads multiple substate values in one access, returning them in the order of the given
+    /// keys.
+    /// Backends with a per-read latency (e.g. on disk or behind a network) should override this
+    /// with a batched lookup; the default implementation reads the keys one by one.
+    fn get_substates(&self, keys: &[DbSubstateKey]) -> Vec<Option<DbSubstateValue>> {
+        keys.iter()
+            .map(|(partition_key, sort_key)| self.get_substate(partition_key, sort_key))
+            .collect()
+    }
+
     /// Iterates over all entries of the given partition (starting either from the beginning, or
     /// from the given [`DbSortKey`]), in a lexicographical order (ascending) of the [`DbSortKey`]s.
     /// Note: If the exact given starting key does not exist, the iteration starts with its
@@ -0,0 +1,240 @@
+use radix_engine_common::crypto::{hash, Hash};
+use radix_engine_common::data::scrypto::scrypto_encode;
+use radix_engine_derive::ScryptoSbor;
+use radix_engine_store_interface::interface::*;
+use sbor::rust::prelude::*;
+
+/// The database updates committed at a single state version.
+///
+/// Each chunk carries the hash of the chunk before it, so a stream of chunks forms a hash chain
+/// which can be verified against a trusted [`Checkpoint`].
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct StateChangeChunk {
+    pub state_version: u64,
+    /// The hash of the chunk at the previous state version, or [`Checkpoint::GENESIS_HASH`]
+    pub parent_hash: Hash,
+    pub database_updates: DatabaseUpdates,
+}
+
+impl StateChangeChunk {
+    pub fn hash(&self) -> Hash {
+        hash(scrypto_encode(self).unwrap())
+    }
+}
+
+/// A state version, along with the hash of the chunk which produced it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct Checkpoint {
+    pub state_version: u64,
+    pub chunk_hash: Hash,
+}
+
+impl Checkpoint {
+    pub const GENESIS_HASH: Hash = Hash([0u8; Hash::LENGTH]);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportError {
+    UnexpectedStateVersion {
+        expected: u64,
+        actual: u64,
+    },
+    ParentHashMismatch {
+        state_version: u64,
+        expected: Hash,
+        actual: Hash,
+    },
+}
+
+/// A [`CommittableSubstateDatabase`] which keeps every commit as a [`StateChangeChunk`], so the
+/// changes since any earlier state version can be exported and replayed onto another database.
+///
+/// State versions are counted from the moment the wrapper is created, starting at 0.
+pub struct CheckpointingSubstateDatabase<S> {
+    substate_db: S,
+    chunks: Vec<StateChangeChunk>,
+}
+
+impl<S> CheckpointingSubstateDatabase<S> {
+    pub fn new(substate_db: S) -> Self {
+        Self {
+            substate_db,
+            chunks: Vec::new(),
+        }
+    }
+
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            state_version: self.chunks.len() as u64,
+            chunk_hash: self
+                .chunks
+                .last()
+                .map(|chunk| chunk.hash())
+                .unwrap_or(Checkpoint::GENESIS_HASH),
+        }
+    }
+
+    /// Returns the chunks committed after the given state version, in order.
+    pub fn export_since(&self, state_version: u64) -> Vec<StateChangeChunk> {
+        let from = usize::try_from(state_version)
+            .unwrap_or(usize::MAX)
+            .min(self.chunks.len());
+        self.chunks[from..].to_vec()
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.substate_db
+    }
+
+    pub fn into_inner(self) -> S {
+        self.substate_db
+    }
+}
+
+impl<S: CommittableSubstateDatabase> CheckpointingSubstateDatabase<S> {
+    /// Commits chunks exported from another database at the same checkpoint as this one.
+    ///
+    /// The whole chain is verified before anything is committed, so a rejected import leaves
+    /// this database untouched.
+    pub fn import(&mut self, chunks: Vec<StateChangeChunk>) -> Result<Checkpoint, ImportError> {
+        let mut checkpoint = self.checkpoint();
+        for chunk in &chunks {
+            if chunk.state_version != checkpoint.state_version + 1 {
+                return Err(ImportError::UnexpectedStateVersion {
+                    expected: checkpoint.state_version + 1,
+                    actual: chunk.state_version,
+                });
+            }
+            if chunk.parent_hash != checkpoint.chunk_hash {
+                return Err(ImportError::ParentHashMismatch {
+                    state_version: chunk.state_version,
+                    expected: checkpoint.chunk_hash,
+                    actual: chunk.parent_hash,
+                });
+            }
+            checkpoint = Checkpoint {
+                state_version: chunk.state_version,
+                chunk_hash: chunk.hash(),
+            };
+        }
+
+        for chunk in chunks {
+            self.substate_db.commit(&chunk.database_updates);
+            self.chunks.push(chunk);
+        }
+
+        Ok(checkpoint)
+    }
+}
+
+impl<S: SubstateDatabase> SubstateDatabase for CheckpointingSubstateDatabase<S> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        self.substate_db.get_substate(partition_key, sort_key)
+    }
+
+    fn get_substates(&self, keys: &[DbSubstateKey]) -> Vec<Option<DbSubstateValue>> {
+        self.substate_db.get_substates(keys)
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.substate_db
+            .list_entries_from(partition_key, from_sort_key)
+    }
+}
+
+impl<S: CommittableSubstateDatabase> CommittableSubstateDatabase
+    for CheckpointingSubstateDatabase<S>
+{
+    fn commit(&mut self, database_updates: &DatabaseUpdates) {
+        let checkpoint = self.checkpoint();
+        self.substate_db.commit(database_updates);
+        self.chunks.push(StateChangeChunk {
+            state_version: checkpoint.state_version + 1,
+            parent_hash: checkpoint.chunk_hash,
+            database_updates: database_updates.clone(),
+        });
+    }
+}
+
+impl<S: ListableSubstateDatabase> ListableSubstateDatabase for CheckpointingSubstateDatabase<S> {
+    fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_> {
+        self.substate_db.list_partition_keys()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_db::InMemorySubstateDatabase;
+
+    fn set(node_key: u8, sort_key: u8, value: u8) -> DatabaseUpdates {
+        DatabaseUpdates::from_delta_maps(indexmap! {
+            DbPartitionKey { node_key: vec![node_key], partition_num: 0 } => indexmap! {
+                DbSortKey(vec![sort_key]) => DatabaseUpdate::Set(vec![value])
+            }
+        })
+    }
+
+    #[test]
+    fn importing_exported_chunks_reproduces_the_state() {
+        let mut source = CheckpointingSubstateDatabase::new(InMemorySubstateDatabase::standard());
+        let mut target = CheckpointingSubstateDatabase::new(InMemorySubstateDatabase::standard());
+        source.commit(&set(1, 1, 10));
+        target.import(source.export_since(0)).unwrap();
+        source.commit(&set(1, 2, 20));
+        source.commit(&set(2, 1, 30));
+
+        let checkpoint = target.import(source.export_since(1)).unwrap();
+
+        assert_eq!(checkpoint, source.checkpoint());
+        assert_eq!(target.inner(), source.inner());
+    }
+
+    #[test]
+    fn import_rejects_a_broken_chain_without_committing() {
+        let mut source = CheckpointingSubstateDatabase::new(InMemorySubstateDatabase::standard());
+        let mut target = CheckpointingSubstateDatabase::new(InMemorySubstateDatabase::standard());
+        source.commit(&set(1, 1, 10));
+        source.commit(&set(1, 2, 20));
+        let mut chunks = source.export_since(0);
+        chunks[0].database_updates = set(1, 1, 11);
+
+        let result = target.import(chunks);
+
+        assert!(matches!(
+            result,
+            Err(ImportError::ParentHashMismatch {
+                state_version: 2,
+                ..
+            })
+        ));
+        assert_eq!(target.checkpoint().state_version, 0);
+        assert_eq!(target.list_partition_keys().count(), 0);
+    }
+
+    #[test]
+    fn import_rejects_chunks_from_another_state_version() {
+        let mut source = CheckpointingSubstateDatabase::new(InMemorySubstateDatabase::standard());
+        let mut target = CheckpointingSubstateDatabase::new(InMemorySubstateDatabase::standard());
+        source.commit(&set(1, 1, 10));
+        source.commit(&set(1, 2, 20));
+
+        let result = target.import(source.export_since(1));
+
+        assert_eq!(
+            result,
+            Err(ImportError::UnexpectedStateVersion {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+}
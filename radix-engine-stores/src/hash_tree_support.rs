@@ -2,8 +2,8 @@ use crate::hash_tree::tree_store::{TypedInMemoryTreeStore, Version};
 use crate::hash_tree::{list_substate_hashes_at_version, put_at_next_version};
 use radix_engine_common::prelude::*;
 use radix_engine_store_interface::interface::{
-    CommittableSubstateDatabase, DatabaseUpdates, DbPartitionKey, DbSortKey, DbSubstateValue,
-    ListableSubstateDatabase, PartitionEntry, SubstateDatabase,
+    CommittableSubstateDatabase, DatabaseUpdates, DbPartitionKey, DbSortKey, DbSubstateKey,
+    DbSubstateValue, ListableSubstateDatabase, PartitionEntry, SubstateDatabase,
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -55,6 +55,10 @@ impl<D: SubstateDatabase> SubstateDatabase for HashTreeUpdatingDatabase<D> {
         self.underlying.get_substate(partition_key, sort_key)
     }
 
+    fn get_substates(&self, keys: &[DbSubstateKey]) -> Vec<Option<DbSubstateValue>> {
+        self.underlying.get_substates(keys)
+    }
+
     fn list_entries_from(
         &self,
         partition_key: &DbPartitionKey,
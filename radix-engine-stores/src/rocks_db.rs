@@ -52,6 +52,17 @@ impl SubstateDatabase for RocksdbSubstateStore {
         self.db.get_cf(self.cf(), &key_bytes).expect("IO Error")
     }
 
+    fn get_substates(&self, keys: &[DbSubstateKey]) -> Vec<Option<DbSubstateValue>> {
+        let cf = self.cf();
+        self.db
+            .multi_get_cf(keys.iter().map(|(partition_key, sort_key)| {
+                (cf, encode_to_rocksdb_bytes(partition_key, sort_key))
+            }))
+            .into_iter()
+            .map(|result| result.expect("IO Error"))
+            .collect()
+    }
+
     fn list_entries_from(
         &self,
         partition_key: &DbPartitionKey,
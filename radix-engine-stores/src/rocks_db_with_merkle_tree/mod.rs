@@ -108,6 +108,17 @@ impl SubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
             .expect("IO Error")
     }
 
+    fn get_substates(&self, keys: &[DbSubstateKey]) -> Vec<Option<DbSubstateValue>> {
+        let cf = self.cf(SUBSTATES_CF);
+        self.db
+            .multi_get_cf(keys.iter().map(|(partition_key, sort_key)| {
+                (cf, encode_to_rocksdb_bytes(partition_key, sort_key))
+            }))
+            .into_iter()
+            .map(|result| result.expect("IO Error"))
+            .collect()
+    }
+
     fn list_entries_from(
         &self,
         partition_key: &DbPartitionKey,
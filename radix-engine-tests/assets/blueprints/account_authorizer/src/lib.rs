@@ -0,0 +1,44 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod spending_limit_authorizer {
+    struct SpendingLimitAuthorizer {
+        override_key: NonFungibleGlobalId,
+        withdraw_limit: Decimal,
+    }
+
+    impl SpendingLimitAuthorizer {
+        pub fn new(
+            override_key: NonFungibleGlobalId,
+            withdraw_limit: Decimal,
+        ) -> Global<SpendingLimitAuthorizer> {
+            Self {
+                override_key,
+                withdraw_limit,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        /// Withdrawals over the limit in a single call require the override key, while any other
+        /// method is approved.
+        pub fn authorize(
+            &self,
+            manifest_summary: ManifestSummary,
+            auth_zone_snapshot: AuthZoneSnapshot,
+        ) -> bool {
+            if auth_zone_snapshot
+                .non_fungibles
+                .contains(&self.override_key)
+                || manifest_summary.method_name != "withdraw"
+            {
+                return true;
+            }
+
+            let (_resource_address, amount): (ResourceAddress, Decimal) =
+                scrypto_decode(&manifest_summary.args).unwrap();
+            amount <= self.withdraw_limit
+        }
+    }
+}
@@ -7,6 +7,26 @@ mod another {
     impl AnotherBlueprint {}
 }
 
+#[blueprint]
+mod reservation_store {
+    struct ReservationStore {
+        reservations: KeyValueStore<u32, GlobalAddressReservation>,
+    }
+
+    impl ReservationStore {
+        pub fn store_reservation() -> Global<ReservationStore> {
+            let (own, _address) =
+                Runtime::allocate_component_address(ReservationStore::blueprint_id());
+            let reservations = KeyValueStore::new();
+            reservations.insert(1u32, own);
+            Self { reservations }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+    }
+}
+
 #[blueprint]
 mod apa {
     struct AllocatedAddressTest {
@@ -2,3 +2,4 @@ pub mod auth_list_component;
 pub mod component;
 pub mod cross_component;
 pub mod external_blueprint_target;
+pub mod versioned_state;
@@ -0,0 +1,55 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor)]
+pub struct StateCounterV1 {
+    pub count: u32,
+}
+
+impl From<StateCounterV1> for state_counter::StateCounter {
+    fn from(value: StateCounterV1) -> Self {
+        Self {
+            count: value.count.into(),
+        }
+    }
+}
+
+#[blueprint]
+mod state_counter {
+    use super::StateCounterV1;
+
+    #[state_version(StateCounterV1)]
+    struct StateCounter {
+        pub count: u64,
+    }
+
+    impl StateCounter {
+        pub fn new() -> Global<StateCounter> {
+            Self { count: 0 }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        /// Instantiates a counter as if it had been created by the first version of the blueprint.
+        pub fn new_at_first_version(count: u32) -> Global<StateCounter> {
+            let state = VersionedStateCounter::V1(StateCounterV1 { count });
+            let node_id = ScryptoVmV1Api::object_new(
+                "StateCounter",
+                indexmap![0u8 => FieldValue::new(&state)],
+            );
+            let stub = StateCounterObjectStub::new(ObjectStubHandle::Own(Own(node_id)));
+            Owned::<StateCounter>(stub)
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn increment(&mut self) -> u64 {
+            self.count += 1;
+            self.count
+        }
+
+        pub fn count(&self) -> u64 {
+            self.count
+        }
+    }
+}
@@ -22,3 +22,71 @@ mod consensus_manager_test {
         }
     }
 }
+
+#[blueprint]
+mod epoch_change_hook_test {
+    struct EpochChangeHookTest {
+        invocations: u32,
+        last_epoch: Option<Epoch>,
+    }
+
+    impl EpochChangeHookTest {
+        pub fn new() -> Global<EpochChangeHookTest> {
+            Self {
+                invocations: 0,
+                last_epoch: None,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn register(&mut self, bond: Bucket) {
+            self.register_method("on_epoch_change".to_owned(), bond);
+        }
+
+        pub fn register_method(&mut self, method_name: String, bond: Bucket) {
+            ScryptoVmV1Api::object_call(
+                CONSENSUS_MANAGER.as_node_id(),
+                CONSENSUS_MANAGER_REGISTER_EPOCH_CHANGE_HOOK_IDENT,
+                scrypto_encode(&ConsensusManagerRegisterEpochChangeHookInput {
+                    component_address: Runtime::global_address(),
+                    method_name,
+                    bond,
+                })
+                .unwrap(),
+            );
+        }
+
+        pub fn deregister(&mut self) -> Bucket {
+            let rtn = ScryptoVmV1Api::object_call(
+                CONSENSUS_MANAGER.as_node_id(),
+                CONSENSUS_MANAGER_DEREGISTER_EPOCH_CHANGE_HOOK_IDENT,
+                scrypto_encode(&ConsensusManagerDeregisterEpochChangeHookInput {
+                    component_address: Runtime::global_address(),
+                })
+                .unwrap(),
+            );
+            scrypto_decode(&rtn).unwrap()
+        }
+
+        pub fn on_epoch_change(&mut self, epoch: Epoch) {
+            self.invocations += 1;
+            self.last_epoch = Some(epoch);
+        }
+
+        pub fn panic_on_epoch_change(&mut self, _epoch: Epoch) {
+            panic!("Hook failed");
+        }
+
+        pub fn spin_on_epoch_change(&mut self, epoch: Epoch) {
+            for _ in 0..2000 {
+                assert_eq!(Runtime::current_epoch(), epoch);
+            }
+        }
+
+        pub fn state(&self) -> (u32, Option<Epoch>) {
+            (self.invocations, self.last_epoch)
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct ValueChangedEvent {
+    pub value: Decimal,
+}
+
+#[blueprint]
+#[events(ValueChangedEvent)]
+mod emitter {
+    struct Emitter {
+        value: Decimal,
+    }
+
+    impl Emitter {
+        pub fn new(owner_role: OwnerRole) -> Global<Emitter> {
+            Self {
+                value: Decimal::ZERO,
+            }
+            .instantiate()
+            .prepare_to_globalize(owner_role)
+            .globalize()
+        }
+
+        pub fn set_value(&mut self, value: Decimal) {
+            self.value = value;
+            Runtime::emit_event(ValueChangedEvent { value });
+        }
+    }
+}
+
+#[blueprint]
+mod mirror {
+    enable_method_auth! {
+        roles {
+            emitter => updatable_by: [];
+        },
+        methods {
+            subscribe => PUBLIC;
+            unsubscribe => PUBLIC;
+            on_value_changed => restrict_to: [emitter];
+            mirrored => PUBLIC;
+        }
+    }
+
+    struct Mirror {
+        emitter: ComponentAddress,
+        value: Decimal,
+        notifications: u32,
+    }
+
+    impl Mirror {
+        pub fn new(emitter: ComponentAddress) -> Global<Mirror> {
+            Self {
+                emitter,
+                value: Decimal::ZERO,
+                notifications: 0,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .roles(roles! {
+                emitter => rule!(require(global_caller(emitter)));
+            })
+            .globalize()
+        }
+
+        pub fn subscribe(&self, hook_method: String) {
+            Runtime::subscribe_to_event::<ValueChangedEvent, _>(self.emitter, &hook_method);
+        }
+
+        pub fn unsubscribe(&self) {
+            Runtime::unsubscribe_from_event::<ValueChangedEvent, _>(self.emitter);
+        }
+
+        pub fn on_value_changed(&mut self, notification: EventNotification) {
+            assert_eq!(notification.emitter, self.emitter.into());
+            let event: ValueChangedEvent = scrypto_decode(&notification.event_data).unwrap();
+            self.value = event.value;
+            self.notifications += 1;
+        }
+
+        pub fn mirrored(&self) -> (Decimal, u32) {
+            (self.value, self.notifications)
+        }
+    }
+}
@@ -42,6 +42,22 @@ mod fee {
             self.doge.as_fungible().lock_fee(amount);
         }
 
+        pub fn lock_fee_with_refund_to_empty_vault(&mut self, amount: Decimal) {
+            self.xrd
+                .as_fungible()
+                .lock_fee_with_refund(amount, &self.xrd_empty.as_fungible());
+        }
+
+        pub fn lock_fee_with_refund_to_doge(&mut self, amount: Decimal) {
+            self.xrd
+                .as_fungible()
+                .lock_fee_with_refund(amount, &self.doge.as_fungible());
+        }
+
+        pub fn xrd_balances(&self) -> (Decimal, Decimal) {
+            (self.xrd.amount(), self.xrd_empty.amount())
+        }
+
         pub fn lock_fee_with_temp_vault(&mut self, amount: Decimal) {
             let vault = Vault::new(XRD);
             vault.as_fungible().lock_fee(amount);
@@ -15,5 +15,15 @@ mod fee_reserve {
                 Runtime::get_fee_balance(),
             )
         }
+
+        pub fn check_execution_cost_units_consumed() -> (u32, u32) {
+            let before = Runtime::get_execution_cost_units_consumed();
+            let after = Runtime::get_execution_cost_units_consumed();
+            (before, after)
+        }
+
+        pub fn royalty_amount_in_xrd(royalty_amount: RoyaltyAmount) -> Decimal {
+            Runtime::royalty_amount_in_xrd(&royalty_amount)
+        }
     }
 }
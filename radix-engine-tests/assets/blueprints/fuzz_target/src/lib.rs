@@ -0,0 +1,41 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor)]
+pub enum Shape {
+    Point,
+    Circle(Decimal),
+    Polygon(Vec<(i32, i32)>),
+    Nested(Box<Shape>),
+}
+
+#[blueprint]
+mod fuzz_target {
+    struct FuzzTarget {}
+
+    impl FuzzTarget {
+        pub fn new() -> Global<FuzzTarget> {
+            Self {}
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn accept(
+            &self,
+            _flag: bool,
+            _number: u64,
+            _name: String,
+            amounts: Vec<Decimal>,
+            _shape: Shape,
+            ids: IndexMap<NonFungibleLocalId, PreciseDecimal>,
+            _resource: Option<ResourceAddress>,
+            _account: Global<Account>,
+        ) -> usize {
+            amounts.len() + ids.len()
+        }
+
+        pub fn take(&self, bucket: Bucket) -> Bucket {
+            bucket
+        }
+    }
+}
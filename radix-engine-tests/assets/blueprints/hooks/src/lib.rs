@@ -0,0 +1,45 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+#[hooks(on_drop = log_drop, on_move = check_move)]
+mod ticket {
+    struct Ticket {
+        value: u32,
+        movable: bool,
+    }
+
+    impl Ticket {
+        pub fn issue_and_redeem(value: u32) -> u32 {
+            let ticket = Self {
+                value,
+                movable: false,
+            }
+            .instantiate();
+
+            ticket.drop().value
+        }
+
+        pub fn issue_and_pass_down(value: u32, movable: bool) -> u32 {
+            let ticket = Self { value, movable }.instantiate();
+
+            Blueprint::<Ticket>::redeem(ticket)
+        }
+
+        pub fn redeem(ticket: Owned<Ticket>) -> u32 {
+            ticket.drop().value
+        }
+
+        fn log_drop(&self) {
+            info!("Ticket {} dropped", self.value);
+        }
+
+        fn check_move(&self, input: OnMoveInput) {
+            if !self.movable {
+                panic!("Ticket {} can't be moved", self.value);
+            }
+            if input.is_moving_down {
+                info!("Ticket {} moved down", self.value);
+            }
+        }
+    }
+}
@@ -63,6 +63,14 @@ mod basic {
         pub fn remove(&mut self, key: String) -> Option<String> {
             self.map.remove(&key)
         }
+
+        pub fn insert_many(&mut self, entries: Vec<(String, String)>) {
+            self.map.insert_many(entries);
+        }
+
+        pub fn get_many(&self, keys: Vec<String>) -> Vec<Option<String>> {
+            self.map.get_many(&keys)
+        }
     }
 }
 
@@ -160,3 +160,40 @@ mod stored_secret {
         }
     }
 }
+
+#[blueprint]
+mod stored_own {
+    use secret::*;
+
+    struct StoredOwn {
+        component: Own,
+    }
+
+    impl StoredOwn {
+        pub fn parent_get_secret(&self) -> u32 {
+            let component: Owned<Secret> = self.component.into();
+            component.as_ref().get_secret()
+        }
+
+        pub fn parent_set_secret(&mut self, next: u32) {
+            let component: Owned<Secret> = self.component.into();
+            component.as_ref().set_secret(next)
+        }
+
+        pub fn call_write_on_stored_own_in_owned_component() -> Global<StoredOwn> {
+            let component = Blueprint::<Secret>::new(12345);
+            let my_component = Self {
+                component: component.into(),
+            }
+            .instantiate();
+
+            my_component.as_ref().parent_set_secret(99999);
+            let rtn = my_component.as_ref().parent_get_secret();
+            assert_eq!(99999, rtn);
+
+            my_component
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+    }
+}
@@ -11,6 +11,11 @@ mod logger {
             info!("{}", message)
         }
 
+        pub fn emit_log_with_fields(message: String, amount: Decimal, tag: String) {
+            info!("{}", message; amount = amount, tag = tag);
+            debug!("Done");
+        }
+
         pub fn rust_panic(message: String) {
             panic!("{}", message)
         }
@@ -22,5 +27,20 @@ mod logger {
         pub fn assert_length_5(message: String) {
             assert_eq!(message.len(), 5);
         }
+
+        pub fn abort_if_longer_than_5(message: String) {
+            if message.len() > 5 {
+                Runtime::abort(LoggerError::MessageTooLong {
+                    length: message.len() as u32,
+                    max: 5,
+                })
+            }
+            info!("{}", message)
+        }
     }
 }
+
+#[derive(ScryptoSbor)]
+pub enum LoggerError {
+    MessageTooLong { length: u32, max: u32 },
+}
@@ -283,6 +283,24 @@ mod non_fungible_test {
             assert!(resource_manager.total_supply().is_none());
         }
 
+        pub fn get_total_supply_when_built_without_tracking() -> Bucket {
+            let bucket = ResourceBuilder::new_integer_non_fungible::<Sandwich>(OwnerRole::None)
+                .track_total_supply(false)
+                .mint_initial_supply([(
+                    1u64.into(),
+                    Sandwich {
+                        name: "Zero".to_owned(),
+                        available: true,
+                        tastes_great: true,
+                        reference: None,
+                        own: None,
+                    },
+                )]);
+
+            assert!(bucket.resource_manager().total_supply().is_none());
+            bucket.into()
+        }
+
         pub fn get_total_supply() {
             let resource_manager =
                 ResourceBuilder::new_integer_non_fungible::<Sandwich>(OwnerRole::None)
@@ -296,6 +314,69 @@ mod non_fungible_test {
             assert_eq!(resource_manager.total_supply().unwrap(), Decimal::zero(),);
         }
 
+        pub fn create_nft_collection() -> (ResourceManager, Bucket) {
+            let resource_manager = ResourceBuilder::new_nft_collection::<Sandwich>(
+                OwnerRole::None,
+                "Katz's Sandwiches",
+                UncheckedUrl::of("https://www.katzsdelicatessen.com/sandwiches/"),
+            )
+            .mint_roles(mint_roles! {
+                minter => rule!(allow_all);
+                minter_updater => rule!(deny_all);
+            })
+            .create_with_no_initial_supply();
+
+            // Mint out of id order, enumeration should follow the mint order
+            let mut bucket = resource_manager.create_empty_bucket();
+            for id in [3u64, 1, 2] {
+                bucket.put(resource_manager.mint_non_fungible(
+                    &NonFungibleLocalId::integer(id),
+                    Sandwich {
+                        name: format!("Sandwich #{}", id),
+                        available: true,
+                        tastes_great: true,
+                        reference: None,
+                        own: None,
+                    },
+                ));
+            }
+
+            (resource_manager, bucket)
+        }
+
+        pub fn create_burnable_nft_collection() -> (ResourceManager, Bucket) {
+            let resource_manager = ResourceBuilder::new_nft_collection::<Sandwich>(
+                OwnerRole::Fixed(rule!(allow_all)),
+                "Katz's Sandwiches",
+                UncheckedUrl::of("https://www.katzsdelicatessen.com/sandwiches/"),
+            )
+            .mint_roles(mint_roles! {
+                minter => rule!(allow_all);
+                minter_updater => rule!(deny_all);
+            })
+            .burn_roles(burn_roles! {
+                burner => rule!(allow_all);
+                burner_updater => rule!(deny_all);
+            })
+            .create_with_no_initial_supply();
+
+            let mut bucket = resource_manager.create_empty_bucket();
+            for id in 1u64..=3 {
+                bucket.put(resource_manager.mint_non_fungible(
+                    &NonFungibleLocalId::integer(id),
+                    Sandwich {
+                        name: format!("Sandwich #{}", id),
+                        available: true,
+                        tastes_great: true,
+                        reference: None,
+                        own: None,
+                    },
+                ));
+            }
+
+            (resource_manager, bucket)
+        }
+
         pub fn non_fungible_exists() -> (Bucket, Bucket) {
             let (mint_badge, resource_manager, bucket) = Self::create_non_fungible_mutable();
             assert_eq!(
@@ -417,6 +498,36 @@ mod non_fungible_test {
             bucket
         }
 
+        pub fn take_non_fungibles_matching_from_bucket_and_vault() -> Bucket {
+            let mut bucket = Self::create_non_fungible_fixed();
+            let non_fungibles = bucket
+                .as_non_fungible()
+                .take_non_fungibles_matching(|sandwich: &Sandwich| sandwich.name.starts_with("T"));
+            assert_eq!(bucket.amount(), 1.into());
+            assert_eq!(
+                non_fungibles.non_fungible_local_ids(),
+                indexset!(NonFungibleLocalId::integer(2), NonFungibleLocalId::integer(3))
+            );
+
+            let vault = Vault::with_bucket(non_fungibles.into());
+            let non_fungible = vault
+                .as_non_fungible()
+                .take_non_fungibles_matching(10, |sandwich: &Sandwich| sandwich.name == "Three");
+            assert_eq!(vault.amount(), 1.into());
+            assert_eq!(
+                non_fungible.non_fungible_local_id(),
+                NonFungibleLocalId::integer(3)
+            );
+
+            NonFungibleTest { vault }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize();
+
+            bucket.put(non_fungible.into());
+            bucket
+        }
+
         pub fn take_and_put_vault() -> Bucket {
             let mut vault = Vault::with_bucket(Self::create_non_fungible_fixed());
             assert_eq!(vault.amount(), 3.into());
@@ -0,0 +1,50 @@
+use scrypto::blueprints::oracle::*;
+use scrypto::prelude::*;
+
+#[blueprint]
+mod mock_oracle {
+    struct MockOracle {
+        prices: KeyValueStore<(ResourceAddress, ResourceAddress), Decimal>,
+    }
+
+    impl MockOracle {
+        pub fn new() -> Global<MockOracle> {
+            Self {
+                prices: KeyValueStore::new(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn set_price(&mut self, base: ResourceAddress, quote: ResourceAddress, price: Decimal) {
+            self.prices.insert((base, quote), price);
+        }
+
+        pub fn get_price(&self, base: ResourceAddress, quote: ResourceAddress) -> (Decimal, Instant) {
+            let price = *self
+                .prices
+                .get(&(base, quote))
+                .expect("No price has been set for the pair");
+            (price, Clock::current_time_rounded_to_seconds())
+        }
+    }
+}
+
+#[blueprint]
+mod oracle_consumer {
+    struct OracleConsumer {}
+
+    impl OracleConsumer {
+        pub fn value_of(
+            oracle: Global<AnyComponent>,
+            base: ResourceAddress,
+            quote: ResourceAddress,
+            amount: Decimal,
+        ) -> Decimal {
+            let (price, _published_at): OracleGetPriceOutput =
+                oracle.call(ORACLE_GET_PRICE_IDENT, &OracleGetPriceInput { base, quote });
+            amount * price
+        }
+    }
+}
@@ -50,7 +50,10 @@ pub extern "C" fn LargeReturnSize_schema() -> Slice {
         },
         events: BlueprintEventSchemaInit::default(),
         types: BlueprintTypeSchemaInit::default(),
-        functions: BlueprintFunctionsSchemaInit { functions },
+        functions: BlueprintFunctionsSchemaInit {
+            functions,
+            post_conditions: index_map_new(),
+        },
         hooks: BlueprintHooksInit::default(),
     };
 
@@ -101,7 +104,10 @@ pub extern "C" fn MaxReturnSize_schema() -> Slice {
         },
         events: BlueprintEventSchemaInit::default(),
         types: BlueprintTypeSchemaInit::default(),
-        functions: BlueprintFunctionsSchemaInit { functions },
+        functions: BlueprintFunctionsSchemaInit {
+            functions,
+            post_conditions: index_map_new(),
+        },
         hooks: BlueprintHooksInit::default(),
     };
 
@@ -152,7 +158,10 @@ pub extern "C" fn ZeroReturnSize_schema() -> Slice {
         },
         events: BlueprintEventSchemaInit::default(),
         types: BlueprintTypeSchemaInit::default(),
-        functions: BlueprintFunctionsSchemaInit { functions },
+        functions: BlueprintFunctionsSchemaInit {
+            functions,
+            post_conditions: index_map_new(),
+        },
         hooks: BlueprintHooksInit::default(),
     };
 
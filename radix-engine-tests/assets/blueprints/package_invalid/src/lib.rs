@@ -40,7 +40,10 @@ pub extern "C" fn BadFunctionSchema_schema() -> Slice {
             },
             events: BlueprintEventSchemaInit::default(),
             types: BlueprintTypeSchemaInit::default(),
-            functions: BlueprintFunctionsSchemaInit { functions },
+            functions: BlueprintFunctionsSchemaInit {
+                functions,
+                post_conditions: index_map_new(),
+            },
             hooks: BlueprintHooksInit::default(),
         },
         royalty_config: PackageRoyaltyConfig::default(),
@@ -187,7 +187,10 @@ pub extern "C" fn SchemaComponent2_schema() -> Slice {
         },
         events: BlueprintEventSchemaInit::default(),
         types: BlueprintTypeSchemaInit::default(),
-        functions: BlueprintFunctionsSchemaInit { functions },
+        functions: BlueprintFunctionsSchemaInit {
+            functions,
+            post_conditions: index_map_new(),
+        },
         hooks: BlueprintHooksInit::default(),
     };
 
@@ -0,0 +1,16 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod post_conditions {
+    struct Exchange {}
+
+    impl Exchange {
+        pub fn exchange(
+            bucket: Bucket,
+            _resource_address: ResourceAddress,
+            _min_amount: Decimal,
+        ) -> Bucket {
+            bucket
+        }
+    }
+}
@@ -27,6 +27,26 @@ mod bucket_proof {
             bucket
         }
 
+        pub fn use_scoped_bucket_proof_for_auth(bucket: Bucket, to_burn: Bucket) -> Bucket {
+            let burnt = LocalAuthZone::with_proof(bucket.create_proof_of_all(), || {
+                if to_burn.is_empty() {
+                    return false;
+                }
+                to_burn.burn();
+                true
+            });
+            assert!(burnt);
+
+            bucket
+        }
+
+        pub fn burn_after_scoped_bucket_proof(bucket: Bucket, to_burn: Bucket) -> Bucket {
+            LocalAuthZone::with_proof(bucket.create_proof_of_all(), || {});
+            to_burn.burn(); // should fail here
+
+            bucket
+        }
+
         pub fn return_bucket_while_locked(bucket: Bucket) -> Bucket {
             let _proof = bucket.create_proof_of_all();
             bucket
@@ -0,0 +1,48 @@
+use scrypto::prelude::*;
+
+const CLAIM_LIMIT: RateLimit = RateLimit::global("claim");
+const CALLER_CLAIM_LIMIT: RateLimit = RateLimit::per_caller("claim");
+
+#[blueprint]
+mod faucet {
+    struct Faucet {}
+
+    impl Faucet {
+        pub fn new() -> Global<Faucet> {
+            Self {}
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn claim(&mut self, max: u32, window_epochs: u64) -> bool {
+            Runtime::rate_limit_check(CLAIM_LIMIT, max, window_epochs)
+        }
+
+        pub fn claim_per_caller(&mut self, max: u32, window_epochs: u64) -> bool {
+            Runtime::rate_limit_check(CALLER_CLAIM_LIMIT, max, window_epochs)
+        }
+    }
+}
+
+#[blueprint]
+mod claimer {
+    use super::faucet::Faucet;
+
+    struct Claimer {
+        faucet: Global<Faucet>,
+    }
+
+    impl Claimer {
+        pub fn new(faucet: Global<Faucet>) -> Global<Claimer> {
+            Self { faucet }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn claim(&mut self, max: u32, window_epochs: u64) -> bool {
+            self.faucet.claim_per_caller(max, window_epochs)
+        }
+    }
+}
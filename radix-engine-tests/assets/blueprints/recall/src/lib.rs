@@ -40,6 +40,17 @@ mod recall {
             (global, address)
         }
 
+        pub fn new_with_recall_protected_vault(
+            resource_address: ResourceAddress,
+        ) -> Global<RecallTest> {
+            Self {
+                vault: Vault::new_recall_protected(resource_address),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
         pub fn recall_on_internal_vault(&self) -> Bucket {
             scrypto_decode(&ScryptoVmV1Api::object_call_direct(
                 self.vault.0.as_node_id(),
@@ -66,5 +77,17 @@ mod recall {
             ))
             .unwrap()
         }
+
+        pub fn recall_on_direct_access_vault(vault: DirectAccessVault, amount: Decimal) -> Bucket {
+            vault.recall(amount)
+        }
+
+        pub fn freeze_withdraw_on_direct_access_vault(vault: DirectAccessVault) {
+            vault.freeze(VaultFreezeFlags::WITHDRAW);
+        }
+
+        pub fn unfreeze_withdraw_on_direct_access_vault(vault: DirectAccessVault) {
+            vault.unfreeze(VaultFreezeFlags::WITHDRAW);
+        }
     }
 }
@@ -258,6 +258,48 @@ mod resource_test {
 
             badge
         }
+
+        pub fn create_tokens_from_presets() -> (Bucket, Bucket, Bucket) {
+            let badge = ResourceBuilder::new_badge().mint_initial_supply(1);
+            assert_eq!(
+                badge.resource_manager().resource_type(),
+                ResourceType::Fungible { divisibility: 0 }
+            );
+            assert_eq!(badge.resource_manager().total_supply(), None);
+
+            let token = ResourceBuilder::new_standard_ownable_token(rule!(require(
+                badge.resource_address()
+            )))
+            .mint_initial_supply(100);
+            let token_manager = token.resource_manager();
+            badge.as_fungible().authorize_with_amount(dec!(1), || {
+                let minted: Bucket = token_manager.mint(50);
+                token_manager.burn(minted);
+            });
+            assert_eq!(
+                token_manager.resource_type(),
+                ResourceType::Fungible { divisibility: 18 }
+            );
+            assert_eq!(token_manager.total_supply(), Some(dec!(100)));
+
+            let fixed_supply_token = ResourceBuilder::new_fixed_supply_token(
+                metadata! {
+                    init {
+                        "name" => "FixedToken".to_owned(), locked;
+                    }
+                },
+                1000,
+            );
+            assert_eq!(fixed_supply_token.amount(), dec!(1000));
+
+            (badge.into(), token.into(), fixed_supply_token.into())
+        }
+
+        pub fn mint_fixed_supply_token() -> Bucket {
+            let fixed_supply_token = ResourceBuilder::new_fixed_supply_token(metadata!(), 1000);
+            let minted: Bucket = fixed_supply_token.resource_manager().mint(1); // should fail here
+            minted
+        }
     }
 }
 
@@ -0,0 +1,34 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod pausable_component {
+    enable_method_auth! {
+        methods {
+            public_method => PUBLIC;
+            owner_method => restrict_to: [OWNER];
+        }
+    }
+
+    struct PausableComponent {}
+
+    impl PausableComponent {
+        pub fn new(owner_role: OwnerRole, pauser: AccessRule) -> Global<PausableComponent> {
+            Self {}
+                .instantiate()
+                .prepare_to_globalize(owner_role)
+                .enable_pausing(pauser)
+                .globalize()
+        }
+
+        pub fn new_unpausable(owner_role: OwnerRole) -> Global<PausableComponent> {
+            Self {}
+                .instantiate()
+                .prepare_to_globalize(owner_role)
+                .globalize()
+        }
+
+        pub fn public_method(&self) {}
+
+        pub fn owner_method(&self) {}
+    }
+}
@@ -32,6 +32,28 @@ mod scrypto_env_test {
         pub fn bech32_encode_address(address: ComponentAddress) -> String {
             Runtime::bech32_encode_address(address)
         }
+
+        pub fn get_blueprint_id(address: ComponentAddress) -> BlueprintId {
+            Runtime::get_blueprint_id(address)
+        }
+
+        pub fn get_blueprint_function_names(address: ComponentAddress) -> Vec<String> {
+            let interface = Runtime::get_blueprint_interface(address);
+            interface.functions.keys().cloned().collect()
+        }
+
+        pub fn get_event_type_name(
+            package_address: PackageAddress,
+            blueprint_name: String,
+            event_name: String,
+        ) -> Option<String> {
+            let (schema, type_id) =
+                Runtime::get_event_schema(package_address, &blueprint_name, &event_name);
+            schema
+                .v1()
+                .resolve_type_name_from_metadata(type_id.1)
+                .map(|name| name.to_owned())
+        }
     }
 }
 
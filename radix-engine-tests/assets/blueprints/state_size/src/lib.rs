@@ -0,0 +1,25 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod state_size {
+    struct StateSize {
+        data: Vec<u8>,
+    }
+
+    impl StateSize {
+        pub fn new() -> Global<StateSize> {
+            Self { data: Vec::new() }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn grow(&mut self, n: usize) {
+            self.data.extend(vec![0u8; n]);
+        }
+
+        pub fn state_size(&self) -> NodeStateSize {
+            Runtime::state_size()
+        }
+    }
+}
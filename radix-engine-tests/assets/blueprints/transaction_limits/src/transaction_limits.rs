@@ -53,6 +53,15 @@ mod transaction_limits {
             }
         }
 
+        pub fn recursive_with_touched_memory(n: u32, m: usize) {
+            // Keep the allocation alive across the nested call
+            let v = std::hint::black_box(vec![1u8; m]);
+            if n > 1 {
+                Blueprint::<TransactionLimitTest>::recursive_with_touched_memory(n - 1, m);
+            }
+            std::hint::black_box(v);
+        }
+
         pub fn emit_event_of_size(n: usize) {
             let name = "TestEvent";
             let buf = scrypto_encode(&TestEvent {
@@ -158,6 +167,10 @@ mod invoke_limits {
         }
 
         pub fn callee(_: Vec<u8>) {}
+
+        pub fn return_of_size(n: usize) -> Vec<u8> {
+            vec![0u8; n]
+        }
     }
 }
 
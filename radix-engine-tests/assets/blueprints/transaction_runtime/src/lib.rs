@@ -16,6 +16,14 @@ mod tx_runtime {
             Runtime::generate_ruid()
         }
 
+        pub fn signatories() -> Vec<NonFungibleGlobalId> {
+            Runtime::signatories()
+        }
+
+        pub fn is_signed_by(public_key_hash: PublicKeyHash) -> bool {
+            Runtime::is_signed_by(public_key_hash)
+        }
+
         pub fn test_instance_of_and_blueprint_id() {
             let x = TransactionRuntimeTest {}
                 .instantiate()
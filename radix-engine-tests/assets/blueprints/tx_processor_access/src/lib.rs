@@ -6,6 +6,12 @@ pub enum InstructionOutput {
     None,
 }
 
+#[derive(ScryptoSbor)]
+pub struct TransactionProcessorRunOutput {
+    pub outputs: Vec<InstructionOutput>,
+    pub worktop_leftovers: IndexMap<ResourceAddress, Decimal>,
+}
+
 #[blueprint]
 mod execute_manifest {
     extern_blueprint!(
@@ -15,8 +21,9 @@ mod execute_manifest {
                 manifest_encoded_instructions: Vec<u8>,
                 global_address_reservations: Vec<GlobalAddressReservation>,
                 references: Vec<Reference>,
-                blobs: IndexMap<Hash, Vec<u8>>
-            ) -> Vec<InstructionOutput>;
+                blobs: IndexMap<Hash, Vec<u8>>,
+                worktop_leftovers_recipient: Option<ComponentAddress>
+            ) -> TransactionProcessorRunOutput;
         }
     );
 
@@ -32,6 +39,7 @@ mod execute_manifest {
                 vec![],
                 references,
                 index_map_new(),
+                None,
             );
         }
     }
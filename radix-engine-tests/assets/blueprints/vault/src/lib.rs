@@ -1,5 +1,8 @@
 pub mod non_existent_vault;
 pub mod non_fungible_vault;
+pub mod vault_allowance;
 pub mod vault_burn;
+pub mod vault_deny_list;
 pub mod vault_deposit_events;
 pub mod vault_in_structs;
+pub mod vault_transfer_royalty;
@@ -0,0 +1,35 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod vault_allowance {
+    struct VaultAllowance {
+        vault: Vault,
+    }
+
+    impl VaultAllowance {
+        pub fn new(bucket: Bucket) -> Global<VaultAllowance> {
+            Self {
+                vault: Vault::with_bucket(bucket),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn vault_id(&self) -> NodeId {
+            self.vault.0 .0
+        }
+
+        pub fn grant(&mut self, grantee: ResourceOrNonFungible, allowance: WithdrawAllowance) {
+            self.vault.grant_allowance(grantee, allowance)
+        }
+
+        pub fn revoke(&mut self, grantee: ResourceOrNonFungible) {
+            self.vault.revoke_allowance(grantee)
+        }
+
+        pub fn allowance(&self, grantee: ResourceOrNonFungible) -> Option<WithdrawAllowance> {
+            self.vault.allowance(grantee)
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod vault_deny_list {
+    struct VaultDenyList {
+        vaults: Vec<Vault>,
+    }
+
+    impl VaultDenyList {
+        pub fn new() -> Global<VaultDenyList> {
+            Self { vaults: Vec::new() }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn store_in_new_vault(&mut self, bucket: Bucket) {
+            self.vaults.push(Vault::with_bucket(bucket));
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod vault_transfer_royalty {
+    struct VaultTransferRoyalty {
+        vault: Vault,
+    }
+
+    impl VaultTransferRoyalty {
+        pub fn new(bucket: Bucket) -> Global<VaultTransferRoyalty> {
+            Self {
+                vault: Vault::with_bucket(bucket),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn take(&mut self, ids: IndexSet<NonFungibleLocalId>) -> Bucket {
+            self.vault.as_non_fungible().take_non_fungibles(&ids).into()
+        }
+
+        pub fn take_with_royalty(
+            &mut self,
+            ids: IndexSet<NonFungibleLocalId>,
+            payment: Bucket,
+        ) -> (Bucket, Bucket) {
+            let (non_fungibles, change) = self
+                .vault
+                .as_non_fungible()
+                .take_non_fungibles_with_royalty(&ids, payment);
+            (non_fungibles.into(), change)
+        }
+    }
+}
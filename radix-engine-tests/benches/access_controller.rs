@@ -0,0 +1,101 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use radix_engine::system::bootstrap::Bootstrapper;
+use radix_engine::transaction::execute_and_commit_transaction;
+use radix_engine::transaction::CostingParameters;
+use radix_engine::transaction::ExecutionConfig;
+use radix_engine::types::*;
+use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
+use radix_engine::vm::{DefaultNativeVm, ScryptoVm, Vm};
+use radix_engine_interface::blueprints::access_controller::*;
+use radix_engine_interface::rule;
+use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use transaction::model::TestTransaction;
+use transaction::prelude::*;
+
+/// Initiates a recovery as the recovery role and quick confirms it as the primary role. The
+/// proposed rule set is the current one, so that every iteration starts from the same state.
+fn bench_access_controller_recovery(c: &mut Criterion) {
+    // Set up environment.
+    let scrypto_vm = ScryptoVm {
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_validator_config: WasmValidatorConfigV1::new(),
+    };
+    let native_vm = DefaultNativeVm::new();
+    let vm = Vm::new(&scrypto_vm, native_vm);
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    Bootstrapper::new(
+        NetworkDefinition::simulator(),
+        &mut substate_db,
+        vm.clone(),
+        false,
+    )
+    .bootstrap_test_default()
+    .unwrap();
+
+    // Create a key pair for each role
+    let [primary, recovery, confirmation] = [1, 2, 3].map(|seed| {
+        NonFungibleGlobalId::from_public_key(
+            &Secp256k1PrivateKey::from_u64(seed).unwrap().public_key(),
+        )
+    });
+    let rule_set = RuleSet {
+        primary_role: rule!(require(primary.clone())),
+        recovery_role: rule!(require(recovery.clone())),
+        confirmation_role: rule!(require(confirmation)),
+    };
+
+    // Create an access controller
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .take_from_worktop(XRD, 1, "controlled_asset")
+        .create_access_controller(
+            "controlled_asset",
+            rule_set.primary_role.clone(),
+            rule_set.recovery_role.clone(),
+            rule_set.confirmation_role.clone(),
+            None,
+        )
+        .build();
+    let access_controller = execute_and_commit_transaction(
+        &mut substate_db,
+        vm.clone(),
+        &CostingParameters::default(),
+        &ExecutionConfig::for_notarized_transaction(NetworkDefinition::simulator()),
+        &TestTransaction::new_from_nonce(manifest, 1)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset![]),
+    )
+    .expect_commit(true)
+    .new_component_addresses()[0];
+
+    // Create a recovery manifest
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .initiate_recovery_as_recovery(access_controller, rule_set.clone(), None)
+        .quick_confirm_recovery(access_controller, Proposer::Recovery, rule_set, None)
+        .build();
+
+    // Loop
+    let mut nonce = 2;
+    c.bench_function("transaction::access_controller_recovery", |b| {
+        b.iter(|| {
+            let receipt = execute_and_commit_transaction(
+                &mut substate_db,
+                vm.clone(),
+                &CostingParameters::default(),
+                &ExecutionConfig::for_notarized_transaction(NetworkDefinition::simulator()),
+                &TestTransaction::new_from_nonce(manifest.clone(), nonce)
+                    .prepare()
+                    .unwrap()
+                    .get_executable(btreeset![primary.clone(), recovery.clone()]),
+            );
+            receipt.expect_commit_success();
+            nonce += 1;
+        })
+    });
+}
+
+criterion_group!(access_controller, bench_access_controller_recovery);
+criterion_main!(access_controller);
@@ -0,0 +1,59 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use radix_engine::types::*;
+use radix_engine_tests::common::*;
+use scrypto_unit::TestRunnerBuilder;
+use transaction::prelude::*;
+
+/// Writes a batch of entries into a component's key value store within each transaction. The keys
+/// are the same for every iteration, so that the store does not grow while benchmarking.
+///
+/// To benchmark, run
+/// ```bash
+/// cargo bench --bench kv_store
+/// ```
+fn bench_kv_store_insert(c: &mut Criterion) {
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+
+    // Publish package and create component
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("kv_store"));
+    let component_address = test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(package_address, "Basic", "new", manifest_args!())
+                .build(),
+            vec![],
+        )
+        .expect_commit(true)
+        .new_component_addresses()[0];
+
+    let mut group = c.benchmark_group("transaction::kv_store_insert");
+    for entries in [1, 10, 50] {
+        let entries: Vec<(String, String)> = (0..entries)
+            .map(|i| (format!("key_{}", i), format!("value_{}", i)))
+            .collect();
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                component_address,
+                "insert_many",
+                manifest_args!(entries.clone()),
+            )
+            .build();
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(entries.len()),
+            &manifest,
+            |b, manifest| {
+                b.iter(|| {
+                    let receipt = test_runner.execute_manifest(manifest.clone(), vec![]);
+                    receipt.expect_commit_success();
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(kv_store, bench_kv_store_insert);
+criterion_main!(kv_store);
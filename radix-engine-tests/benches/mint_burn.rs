@@ -0,0 +1,108 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use radix_engine::system::bootstrap::Bootstrapper;
+use radix_engine::transaction::execute_and_commit_transaction;
+use radix_engine::transaction::CostingParameters;
+use radix_engine::transaction::ExecutionConfig;
+use radix_engine::types::*;
+use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
+use radix_engine::vm::{DefaultNativeVm, ScryptoVm, Vm};
+use radix_engine_interface::rule;
+use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use transaction::model::TestTransaction;
+use transaction::prelude::*;
+
+/// Mints and burns a fungible resource repeatedly within each transaction, which creates and
+/// drops many buckets on the heap.
+fn bench_mint_burn(c: &mut Criterion) {
+    // Set up environment.
+    let scrypto_vm = ScryptoVm {
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_validator_config: WasmValidatorConfigV1::new(),
+    };
+    let native_vm = DefaultNativeVm::new();
+    let vm = Vm::new(&scrypto_vm, native_vm);
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    Bootstrapper::new(
+        NetworkDefinition::simulator(),
+        &mut substate_db,
+        vm.clone(),
+        false,
+    )
+    .bootstrap_test_default()
+    .unwrap();
+
+    // Create a freely mintable and burnable resource
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_fungible_resource(
+            OwnerRole::None,
+            true,
+            DIVISIBILITY_MAXIMUM,
+            FungibleResourceRoles {
+                mint_roles: mint_roles! {
+                    minter => rule!(allow_all);
+                    minter_updater => rule!(deny_all);
+                },
+                burn_roles: burn_roles! {
+                    burner => rule!(allow_all);
+                    burner_updater => rule!(deny_all);
+                },
+                ..Default::default()
+            },
+            metadata!(),
+            None,
+        )
+        .build();
+    let resource_address = execute_and_commit_transaction(
+        &mut substate_db,
+        vm.clone(),
+        &CostingParameters::default(),
+        &ExecutionConfig::for_notarized_transaction(NetworkDefinition::simulator()),
+        &TestTransaction::new_from_nonce(manifest, 1)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset![]),
+    )
+    .expect_commit(true)
+    .new_resource_addresses()[0];
+
+    let costing_parameters = CostingParameters::default();
+    let execution_config =
+        ExecutionConfig::for_notarized_transaction(NetworkDefinition::simulator());
+    let mut group = c.benchmark_group("transaction::mint_burn");
+    let mut nonce = 2;
+    for rounds in [1, 10, 50] {
+        let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+        for _ in 0..rounds {
+            builder = builder
+                .mint_fungible(resource_address, 1)
+                .burn_all_from_worktop(resource_address);
+        }
+        let manifest = builder.build();
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(rounds),
+            &manifest,
+            |b, manifest| {
+                b.iter(|| {
+                    let receipt = execute_and_commit_transaction(
+                        &mut substate_db,
+                        vm.clone(),
+                        &costing_parameters,
+                        &execution_config,
+                        &TestTransaction::new_from_nonce(manifest.clone(), nonce)
+                            .prepare()
+                            .unwrap()
+                            .get_executable(btreeset![]),
+                    );
+                    receipt.expect_commit_success();
+                    nonce += 1;
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(mint_burn, bench_mint_burn);
+criterion_main!(mint_burn);
@@ -0,0 +1,62 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use radix_engine::blueprints::access_controller::AccessControllerNativeExport;
+use radix_engine::blueprints::resource::ResourceNativeExport;
+
+/// Resolves every export name of the largest native packages to its dispatch entry, comparing the
+/// precomputed table against a linear scan over the export names.
+fn bench_native_dispatch(c: &mut Criterion) {
+    let resource_export_names: Vec<&str> = ResourceNativeExport::ALL
+        .iter()
+        .map(|export| export.export_name())
+        .collect();
+    let access_controller_export_names: Vec<&str> = AccessControllerNativeExport::ALL
+        .iter()
+        .map(|export| export.export_name())
+        .collect();
+
+    let mut group = c.benchmark_group("native_dispatch");
+    group.bench_function("resource::table", |b| {
+        b.iter(|| {
+            for export_name in &resource_export_names {
+                black_box(ResourceNativeExport::from_export_name(black_box(export_name)).unwrap());
+            }
+        })
+    });
+    group.bench_function("resource::linear_scan", |b| {
+        b.iter(|| {
+            for export_name in &resource_export_names {
+                black_box(
+                    ResourceNativeExport::ALL
+                        .iter()
+                        .find(|export| export.export_name() == *black_box(export_name))
+                        .unwrap(),
+                );
+            }
+        })
+    });
+    group.bench_function("access_controller::table", |b| {
+        b.iter(|| {
+            for export_name in &access_controller_export_names {
+                black_box(
+                    AccessControllerNativeExport::from_export_name(black_box(export_name)).unwrap(),
+                );
+            }
+        })
+    });
+    group.bench_function("access_controller::linear_scan", |b| {
+        b.iter(|| {
+            for export_name in &access_controller_export_names {
+                black_box(
+                    AccessControllerNativeExport::ALL
+                        .iter()
+                        .find(|export| export.export_name() == *black_box(export_name))
+                        .unwrap(),
+                );
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(native_dispatch, bench_native_dispatch);
+criterion_main!(native_dispatch);
@@ -0,0 +1,110 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use radix_engine::system::bootstrap::Bootstrapper;
+use radix_engine::transaction::execute_and_commit_transaction;
+use radix_engine::transaction::CostingParameters;
+use radix_engine::transaction::ExecutionConfig;
+use radix_engine::types::*;
+use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
+use radix_engine::vm::{DefaultNativeVm, ScryptoVm, Vm};
+use radix_engine_interface::rule;
+use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use transaction::model::TestTransaction;
+use transaction::prelude::*;
+
+/// Creates proofs of amount from an account repeatedly within each transaction, which locks the
+/// vault once per proof and moves every proof into the auth zone.
+fn bench_create_proof(c: &mut Criterion) {
+    // Set up environment.
+    let scrypto_vm = ScryptoVm {
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_validator_config: WasmValidatorConfigV1::new(),
+    };
+    let native_vm = DefaultNativeVm::new();
+    let vm = Vm::new(&scrypto_vm, native_vm);
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    Bootstrapper::new(
+        NetworkDefinition::simulator(),
+        &mut substate_db,
+        vm.clone(),
+        false,
+    )
+    .bootstrap_test_default()
+    .unwrap();
+
+    // Create a key pair
+    let private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+    let public_key = private_key.public_key();
+    let signer = NonFungibleGlobalId::from_public_key(&public_key);
+
+    // Create an account holding XRD
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .new_account_advanced(OwnerRole::Updatable(rule!(require(signer.clone()))), None)
+        .build();
+    let account = execute_and_commit_transaction(
+        &mut substate_db,
+        vm.clone(),
+        &CostingParameters::default(),
+        &ExecutionConfig::for_notarized_transaction(NetworkDefinition::simulator()),
+        &TestTransaction::new_from_nonce(manifest, 1)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset![signer.clone()]),
+    )
+    .expect_commit(true)
+    .new_component_addresses()[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    execute_and_commit_transaction(
+        &mut substate_db,
+        vm.clone(),
+        &CostingParameters::default(),
+        &ExecutionConfig::for_notarized_transaction(NetworkDefinition::simulator()),
+        &TestTransaction::new_from_nonce(manifest, 2)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset![]),
+    )
+    .expect_commit(true);
+
+    let costing_parameters = CostingParameters::default();
+    let execution_config =
+        ExecutionConfig::for_notarized_transaction(NetworkDefinition::simulator());
+    let mut group = c.benchmark_group("transaction::create_proof");
+    let mut nonce = 3;
+    for rounds in [1, 10, 50] {
+        let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+        for _ in 0..rounds {
+            builder = builder.create_proof_from_account_of_amount(account, XRD, 1);
+        }
+        let manifest = builder.drop_auth_zone_proofs().build();
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(rounds),
+            &manifest,
+            |b, manifest| {
+                b.iter(|| {
+                    let receipt = execute_and_commit_transaction(
+                        &mut substate_db,
+                        vm.clone(),
+                        &costing_parameters,
+                        &execution_config,
+                        &TestTransaction::new_from_nonce(manifest.clone(), nonce)
+                            .prepare()
+                            .unwrap()
+                            .get_executable(btreeset![signer.clone()]),
+                    );
+                    receipt.expect_commit_success();
+                    nonce += 1;
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(proofs, bench_create_proof);
+criterion_main!(proofs);
@@ -1,5 +1,7 @@
 use radix_engine_tests::common::*;
+use radix_engine::transaction::CostingParameters;
 use radix_engine::types::*;
+use radix_engine::vm::wasm::{DefaultWasmEngine, WasmiEngine};
 use scrypto::resource::DIVISIBILITY_MAXIMUM;
 use scrypto_unit::*;
 use transaction::prelude::*;
@@ -21,6 +23,99 @@ fn test_simple_deterministic_execution() {
     assert_eq!(test_runner0.substate_db(), test_runner1.substate_db());
 }
 
+#[test]
+fn test_seeded_test_runners_are_deterministic() {
+    // Arrange
+    let mut test_runner0 = TestRunnerBuilder::new()
+        .with_seed(42)
+        .with_state_hashing()
+        .build();
+    let mut test_runner1 = TestRunnerBuilder::new()
+        .with_seed(42)
+        .with_state_hashing()
+        .build();
+
+    // Act
+    let accounts0 = test_runner0.new_accounts(3);
+    let accounts1 = test_runner1.new_accounts(3);
+    let resources0 = test_runner0.create_fungible_resources(2, dec!(100), 18, accounts0[0].2);
+    let resources1 = test_runner1.create_fungible_resources(2, dec!(100), 18, accounts1[0].2);
+
+    // Assert
+    for (account0, account1) in accounts0.iter().zip(accounts1.iter()) {
+        assert_eq!(account0.0, account1.0);
+        assert_eq!(account0.2, account1.2);
+    }
+    assert_eq!(resources0, resources1);
+    assert_eq!(test_runner0.get_state_hash(), test_runner1.get_state_hash());
+}
+
+#[test]
+fn test_different_seeds_generate_different_keys() {
+    // Arrange
+    let mut test_runner0 = TestRunnerBuilder::new().with_seed(1).build();
+    let mut test_runner1 = TestRunnerBuilder::new().with_seed(2).build();
+
+    // Act
+    let (public_key0, _) = test_runner0.new_key_pair();
+    let (public_key1, _) = test_runner1.new_key_pair();
+
+    // Assert
+    assert_ne!(public_key0, public_key1);
+}
+
+#[test]
+fn test_side_by_side_execution_with_different_wasm_engines_has_no_differences() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let receipts = test_runner.execute_manifest_side_by_side(
+        manifest,
+        vec![],
+        &VmConfiguration::<DefaultWasmEngine>::default(),
+        &VmConfiguration::<WasmiEngine>::default(),
+    );
+
+    // Assert
+    receipts.expect_no_differences().expect_commit_success();
+}
+
+#[test]
+fn test_side_by_side_execution_with_different_costing_parameters_reports_fee_differences() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let mut costing_parameters = CostingParameters::default();
+    costing_parameters.execution_cost_unit_price *= dec!(2);
+
+    // Act
+    let receipts = test_runner.execute_manifest_side_by_side(
+        manifest,
+        vec![],
+        &VmConfiguration::<DefaultWasmEngine>::default(),
+        &VmConfiguration::<DefaultWasmEngine>::default()
+            .with_costing_parameters(costing_parameters),
+    );
+
+    // Assert
+    receipts.left.expect_commit_success();
+    receipts.right.expect_commit_success();
+    assert!(receipts.differences.contains(&ReceiptDifference::FeeSummary));
+    assert!(!receipts.differences.contains(&ReceiptDifference::ApplicationEvents));
+}
+
 #[test]
 fn same_executions_result_in_same_final_state_hash() {
     let state_hashes = (0..5)
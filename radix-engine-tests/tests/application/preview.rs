@@ -143,6 +143,84 @@ fn test_assume_all_signature_proofs_flag_method_authorization() {
     result.unwrap().expect_commit_success();
 }
 
+#[test]
+fn test_virtual_non_fungible_proof_method_authorization() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let (_, _, badge_holder) = test_runner.new_allocated_account();
+    let badge = test_runner.create_non_fungible_resource(badge_holder);
+    let badge_id = NonFungibleLocalId::integer(1);
+    let withdraw_auth = rule!(require(NonFungibleGlobalId::new(badge, badge_id.clone())));
+    let account = test_runner.new_account_advanced(OwnerRole::Fixed(withdraw_auth));
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 1)
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    let (_, preview_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        manifest,
+        &PreviewFlags::default(),
+    );
+    let preview_intent_without_badge = PreviewIntentV2::from(preview_intent);
+    let mut preview_intent = preview_intent_without_badge.clone();
+    preview_intent.virtual_proofs = vec![VirtualProof::NonFungibles(badge, btreeset!(badge_id))];
+
+    // Act
+    let receipt = test_runner.preview_v2(preview_intent, &network).unwrap();
+    let receipt_without_badge = test_runner
+        .preview_v2(preview_intent_without_badge, &network)
+        .unwrap();
+
+    // Assert
+    receipt.expect_commit_success();
+    receipt_without_badge.expect_auth_failure();
+}
+
+#[test]
+fn test_virtual_amount_proof_method_authorization() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let (_, _, badge_holder) = test_runner.new_allocated_account();
+    let badge = test_runner.create_fungible_resource(dec!("100"), 18, badge_holder);
+    let withdraw_auth = rule!(require_amount(dec!("10"), badge));
+    let account = test_runner.new_account_advanced(OwnerRole::Fixed(withdraw_auth));
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 1)
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    let (_, preview_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        manifest,
+        &PreviewFlags::default(),
+    );
+    let mut sufficient_preview_intent = PreviewIntentV2::from(preview_intent);
+    let mut insufficient_preview_intent = sufficient_preview_intent.clone();
+    sufficient_preview_intent.virtual_proofs = vec![VirtualProof::Amount(badge, dec!("10"))];
+    insufficient_preview_intent.virtual_proofs = vec![VirtualProof::Amount(badge, dec!("5"))];
+
+    // Act
+    let receipt = test_runner
+        .preview_v2(sufficient_preview_intent, &network)
+        .unwrap();
+    let insufficient_receipt = test_runner
+        .preview_v2(insufficient_preview_intent, &network)
+        .unwrap();
+
+    // Assert
+    receipt.expect_commit_success();
+    insufficient_receipt.expect_auth_failure();
+}
+
 fn prepare_matching_test_tx_and_preview_intent(
     test_runner: &mut DefaultTestRunner,
     network: &NetworkDefinition,
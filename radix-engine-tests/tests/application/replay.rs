@@ -0,0 +1,85 @@
+use radix_engine::transaction::*;
+use radix_engine::types::*;
+use radix_engine::vm::wasm::DefaultWasmEngine;
+use radix_engine::vm::{DefaultNativeVm, ScryptoVm, Vm};
+use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use scrypto_unit::*;
+use transaction::model::RawNotarizedTransaction;
+use transaction_scenarios::scenario::{NextAction, ScenarioCore};
+use transaction_scenarios::scenarios::get_builder_for_every_scenario;
+
+fn run_every_scenario() -> (
+    InMemorySubstateDatabase,
+    Vec<(RawNotarizedTransaction, TransactionReceipt)>,
+) {
+    let network = NetworkDefinition::simulator();
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let database_before = test_runner.substate_db().clone();
+
+    let mut transactions = Vec::new();
+    let mut next_nonce: u32 = 0;
+    for scenario_builder in get_builder_for_every_scenario() {
+        let epoch = test_runner.get_current_epoch();
+        let mut scenario = scenario_builder(ScenarioCore::new(network.clone(), epoch, next_nonce));
+        let mut previous = None;
+        loop {
+            let next = scenario
+                .next(previous.as_ref())
+                .map_err(|err| err.into_full(&scenario))
+                .unwrap();
+            match next {
+                NextAction::Transaction(next) => {
+                    let receipt =
+                        test_runner.execute_raw_transaction(&network, &next.raw_transaction);
+                    transactions.push((next.raw_transaction.clone(), receipt.clone()));
+                    previous = Some(receipt);
+                }
+                NextAction::Completed(end_state) => {
+                    next_nonce = end_state.next_unused_nonce;
+                    break;
+                }
+            }
+        }
+    }
+
+    (database_before, transactions)
+}
+
+#[test]
+fn replaying_committed_transactions_reproduces_their_receipts() {
+    // Arrange
+    let (mut database, transactions) = run_every_scenario();
+    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let transaction_count = transactions.len();
+
+    // Act
+    let result = replay_transactions(
+        &mut database,
+        Vm::new(&scrypto_vm, DefaultNativeVm::new()),
+        &NetworkDefinition::simulator(),
+        transactions,
+    );
+
+    // Assert
+    assert_eq!(result.unwrap().len(), transaction_count);
+}
+
+#[test]
+fn replay_reports_the_first_transaction_with_a_different_receipt() {
+    // Arrange
+    let (mut database, mut transactions) = run_every_scenario();
+    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let other_receipt = transactions[0].1.clone();
+    transactions[1].1 = other_receipt;
+
+    // Act
+    let result = replay_transactions(
+        &mut database,
+        Vm::new(&scrypto_vm, DefaultNativeVm::new()),
+        &NetworkDefinition::simulator(),
+        transactions,
+    );
+
+    // Assert
+    assert!(matches!(result, Err(ReplayError::ReceiptMismatch { index: 1, .. })));
+}
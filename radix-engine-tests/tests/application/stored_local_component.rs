@@ -216,3 +216,25 @@ fn should_be_able_to_call_write_method_on_a_kv_stored_component_in_global_compon
     let rtn: u32 = receipt.expect_commit(true).output(2);
     assert_eq!(rtn, 8888u32);
 }
+
+#[test]
+fn should_be_able_to_call_methods_on_a_stored_own_through_a_typed_stub() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("local_component"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "StoredOwn",
+            "call_write_on_stored_own_in_owned_component",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
@@ -216,8 +216,11 @@ fn transaction_processor_produces_expected_error_for_undecodable_instructions()
             intent_hash: TransactionIntentHash::NotToCheck {
                 intent_hash: Hash([0; 32]),
             },
+            subintent_hashes: vec![],
             epoch_range: Default::default(),
             pre_allocated_addresses: Default::default(),
+            worktop_leftovers_recipient: None,
+            message: None,
             payload_size: 4,
             num_of_signature_validations: 0,
             auth_zone_params: Default::default(),
@@ -1705,11 +1705,7 @@ impl AccessControllerTestRunner {
     pub fn create_proof(&mut self, as_role: Role) -> TransactionReceipt {
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
-                self.access_controller_address,
-                "create_proof",
-                AccessControllerCreateProofInput {},
-            )
+            .create_proof_from_access_controller(self.access_controller_address)
             .pop_from_auth_zone("ignored_proof")
             .build();
         self.execute_manifest(manifest)
@@ -1723,27 +1719,27 @@ impl AccessControllerTestRunner {
         proposed_confirmation_role: AccessRule,
         timed_recovery_delay_in_minutes: Option<u32>,
     ) -> TransactionReceipt {
-        let method_name = match as_role {
-            Role::Primary => ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT,
-            Role::Recovery => ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
-            Role::Confirmation => panic!("Confirmation Role can't initiate recovery!"),
+        let rule_set = RuleSet {
+            primary_role: proposed_primary_role,
+            recovery_role: proposed_recovery_role,
+            confirmation_role: proposed_confirmation_role,
         };
 
-        let manifest = self
-            .manifest_builder(as_role)
-            .call_method(
+        let manifest_builder = self.manifest_builder(as_role);
+        let manifest = match as_role {
+            Role::Primary => manifest_builder.initiate_recovery_as_primary(
                 self.access_controller_address,
-                method_name,
-                AccessControllerInitiateRecoveryAsPrimaryInput {
-                    rule_set: RuleSet {
-                        primary_role: proposed_primary_role,
-                        recovery_role: proposed_recovery_role,
-                        confirmation_role: proposed_confirmation_role,
-                    },
-                    timed_recovery_delay_in_minutes,
-                },
-            )
-            .build();
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            ),
+            Role::Recovery => manifest_builder.initiate_recovery_as_recovery(
+                self.access_controller_address,
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            ),
+            Role::Confirmation => panic!("Confirmation Role can't initiate recovery!"),
+        }
+        .build();
         self.execute_manifest(manifest)
     }
 
@@ -1752,25 +1748,20 @@ impl AccessControllerTestRunner {
         as_role: Role,
         create_proof: bool,
     ) -> TransactionReceipt {
-        let method_name = match as_role {
-            Role::Primary => ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_PRIMARY_IDENT,
-            Role::Recovery => ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_RECOVERY_IDENT,
-            Role::Confirmation => panic!("Confirmation Role can't initiate recovery!"),
-        };
-
         let manifest_builder = if create_proof {
             self.manifest_builder(as_role)
         } else {
             ManifestBuilder::new()
         };
 
-        let manifest = manifest_builder
-            .call_method(
-                self.access_controller_address,
-                method_name,
-                AccessControllerInitiateBadgeWithdrawAttemptAsPrimaryInput {},
-            )
-            .build();
+        let manifest = match as_role {
+            Role::Primary => manifest_builder
+                .initiate_badge_withdraw_attempt_as_primary(self.access_controller_address),
+            Role::Recovery => manifest_builder
+                .initiate_badge_withdraw_attempt_as_recovery(self.access_controller_address),
+            Role::Confirmation => panic!("Confirmation Role can't initiate recovery!"),
+        }
+        .build();
         self.execute_manifest(manifest)
     }
 
@@ -1789,28 +1780,17 @@ impl AccessControllerTestRunner {
             Role::Confirmation => panic!("Confirmation is not a valid proposer"),
         };
 
-        let method_name = match proposer {
-            Proposer::Primary => {
-                ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT
-            }
-            Proposer::Recovery => {
-                ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT
-            }
-        };
-
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
+            .quick_confirm_recovery(
                 self.access_controller_address,
-                method_name,
-                AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInput {
-                    rule_set: RuleSet {
-                        primary_role: proposed_primary_role,
-                        recovery_role: proposed_recovery_role,
-                        confirmation_role: proposed_confirmation_role,
-                    },
-                    timed_recovery_delay_in_minutes,
+                proposer,
+                RuleSet {
+                    primary_role: proposed_primary_role,
+                    recovery_role: proposed_recovery_role,
+                    confirmation_role: proposed_confirmation_role,
                 },
+                timed_recovery_delay_in_minutes,
             )
             .build();
         self.execute_manifest(manifest)
@@ -1827,22 +1807,9 @@ impl AccessControllerTestRunner {
             Role::Confirmation => panic!("Confirmation is not a valid proposer"),
         };
 
-        let method_name = match proposer {
-            Proposer::Primary => {
-                ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT
-            }
-            Proposer::Recovery => {
-                ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT
-            }
-        };
-
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
-                self.access_controller_address,
-                method_name,
-                AccessControllerQuickConfirmPrimaryRoleBadgeWithdrawAttemptInput {},
-            )
+            .quick_confirm_badge_withdraw_attempt(self.access_controller_address, proposer)
             .build();
         self.execute_manifest(manifest)
     }
@@ -1857,54 +1824,43 @@ impl AccessControllerTestRunner {
     ) -> TransactionReceipt {
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
+            .timed_confirm_recovery(
                 self.access_controller_address,
-                ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
-                AccessControllerTimedConfirmRecoveryInput {
-                    rule_set: RuleSet {
-                        primary_role: proposed_primary_role,
-                        recovery_role: proposed_recovery_role,
-                        confirmation_role: proposed_confirmation_role,
-                    },
-                    timed_recovery_delay_in_minutes,
+                RuleSet {
+                    primary_role: proposed_primary_role,
+                    recovery_role: proposed_recovery_role,
+                    confirmation_role: proposed_confirmation_role,
                 },
+                timed_recovery_delay_in_minutes,
             )
             .build();
         self.execute_manifest(manifest)
     }
 
     pub fn cancel_recovery_attempt(&mut self, as_role: Role) -> TransactionReceipt {
-        let method_name = match as_role {
-            Role::Primary => ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
-            Role::Recovery => ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+        let proposer = match as_role {
+            Role::Primary => Proposer::Primary,
+            Role::Recovery => Proposer::Recovery,
             Role::Confirmation => panic!("No method for the given role"),
         };
 
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
-                self.access_controller_address,
-                method_name,
-                AccessControllerCancelPrimaryRoleRecoveryProposalInput,
-            )
+            .cancel_recovery_proposal(self.access_controller_address, proposer)
             .build();
         self.execute_manifest(manifest)
     }
 
     pub fn cancel_badge_withdraw_attempt(&mut self, as_role: Role) -> TransactionReceipt {
-        let method_name = match as_role {
-            Role::Primary => ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT,
-            Role::Recovery => ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT,
+        let proposer = match as_role {
+            Role::Primary => Proposer::Primary,
+            Role::Recovery => Proposer::Recovery,
             Role::Confirmation => panic!("No method for the given role"),
         };
 
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
-                self.access_controller_address,
-                method_name,
-                AccessControllerCancelPrimaryRoleBadgeWithdrawAttemptInput,
-            )
+            .cancel_badge_withdraw_attempt(self.access_controller_address, proposer)
             .build();
         self.execute_manifest(manifest)
     }
@@ -1912,11 +1868,7 @@ impl AccessControllerTestRunner {
     pub fn lock_primary_role(&mut self, as_role: Role) -> TransactionReceipt {
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
-                self.access_controller_address,
-                "lock_primary_role",
-                AccessControllerLockPrimaryRoleInput {},
-            )
+            .lock_primary_role(self.access_controller_address)
             .build();
         self.execute_manifest(manifest)
     }
@@ -1924,11 +1876,7 @@ impl AccessControllerTestRunner {
     pub fn unlock_primary_role(&mut self, as_role: Role) -> TransactionReceipt {
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
-                self.access_controller_address,
-                "unlock_primary_role",
-                AccessControllerUnlockPrimaryRoleInput {},
-            )
+            .unlock_primary_role(self.access_controller_address)
             .build();
         self.execute_manifest(manifest)
     }
@@ -1943,17 +1891,14 @@ impl AccessControllerTestRunner {
     ) -> TransactionReceipt {
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
+            .stop_timed_recovery(
                 self.access_controller_address,
-                "stop_timed_recovery",
-                AccessControllerStopTimedRecoveryInput {
-                    rule_set: RuleSet {
-                        primary_role: proposed_primary_role,
-                        recovery_role: proposed_recovery_role,
-                        confirmation_role: proposed_confirmation_role,
-                    },
-                    timed_recovery_delay_in_minutes,
+                RuleSet {
+                    primary_role: proposed_primary_role,
+                    recovery_role: proposed_recovery_role,
+                    confirmation_role: proposed_confirmation_role,
                 },
+                timed_recovery_delay_in_minutes,
             )
             .build();
         self.execute_manifest(manifest)
@@ -1966,13 +1911,7 @@ impl AccessControllerTestRunner {
     ) -> TransactionReceipt {
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
-                self.access_controller_address,
-                ACCESS_CONTROLLER_MINT_RECOVERY_BADGES_IDENT,
-                AccessControllerMintRecoveryBadgesInput {
-                    non_fungible_local_ids,
-                },
-            )
+            .mint_recovery_badges(self.access_controller_address, non_fungible_local_ids)
             .try_deposit_entire_worktop_or_abort(self.account.0, None)
             .build();
         self.execute_manifest(manifest)
@@ -137,6 +137,48 @@ fn can_withdraw_non_fungible_from_my_virtual_account() {
     can_withdraw_non_fungible_from_my_account_internal(true)
 }
 
+fn can_lock_fee_and_withdraw_non_fungibles_from_my_account_internal(use_virtual: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_account(use_virtual);
+    let (_, _, other_account) = test_runner.new_account(use_virtual);
+    let resource_address = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_and_withdraw_non_fungibles(
+            account,
+            500,
+            resource_address,
+            [NonFungibleLocalId::integer(2)],
+        )
+        .try_deposit_entire_worktop_or_refund(other_account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    let xrd_vault = test_runner.get_component_vaults(account, XRD)[0];
+    assert!(commit.fee_source.paying_vaults.contains_key(&xrd_vault));
+    let vault = test_runner.get_component_vaults(other_account, resource_address)[0];
+    let (amount, ids) = test_runner.inspect_non_fungible_vault(vault).unwrap();
+    assert_eq!(amount, dec!("1"));
+    assert_eq!(ids.collect::<Vec<_>>(), vec![NonFungibleLocalId::integer(2)]);
+}
+
+#[test]
+fn can_lock_fee_and_withdraw_non_fungibles_from_my_allocated_account() {
+    can_lock_fee_and_withdraw_non_fungibles_from_my_account_internal(false)
+}
+
+#[test]
+fn can_lock_fee_and_withdraw_non_fungibles_from_my_virtual_account() {
+    can_lock_fee_and_withdraw_non_fungibles_from_my_account_internal(true)
+}
+
 fn cannot_withdraw_from_other_account_internal(is_virtual: bool) {
     // Arrange
     let mut test_runner = TestRunnerBuilder::new().build();
@@ -942,6 +942,101 @@ fn is_account_not_an_authorized_depositor_error(error: &RuntimeError) -> bool {
     )
 }
 
+#[test]
+fn authorized_depositors_can_be_queried() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (pk, _, account) = test_runner.new_account(true);
+    let badge = ResourceOrNonFungible::Resource(XRD);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            account,
+            ACCOUNT_IS_AUTHORIZED_DEPOSITOR_IDENT,
+            AccountIsAuthorizedDepositorInput {
+                badge: badge.clone(),
+            },
+        )
+        .call_method(
+            account,
+            ACCOUNT_ADD_AUTHORIZED_DEPOSITOR,
+            AccountAddAuthorizedDepositorInput {
+                badge: badge.clone(),
+            },
+        )
+        .call_method(
+            account,
+            ACCOUNT_IS_AUTHORIZED_DEPOSITOR_IDENT,
+            AccountIsAuthorizedDepositorInput { badge },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&pk)],
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert!(!result.output::<bool>(1));
+    assert!(result.output::<bool>(3));
+}
+
+#[test]
+fn deposit_rules_can_be_queried() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (pk, _, account) = test_runner.new_account(true);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            account,
+            ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+            AccountSetDefaultDepositRuleInput {
+                default: DefaultDepositRule::Reject,
+            },
+        )
+        .call_method(
+            account,
+            ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT,
+            AccountSetResourcePreferenceInput {
+                resource_address: XRD,
+                resource_preference: ResourcePreference::Allowed,
+            },
+        )
+        .call_method(
+            account,
+            ACCOUNT_GET_DEFAULT_DEPOSIT_RULE_IDENT,
+            AccountGetDefaultDepositRuleInput {},
+        )
+        .call_method(
+            account,
+            ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT,
+            AccountGetResourcePreferenceInput {
+                resource_address: XRD,
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&pk)],
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(
+        result.output::<DefaultDepositRule>(3),
+        DefaultDepositRule::Reject
+    );
+    assert_eq!(
+        result.output::<Option<ResourcePreference>>(4),
+        Some(ResourcePreference::Allowed)
+    );
+}
+
 fn test_depositors_operation_method_auth(
     operation: DepositorsOperation,
     sign: bool,
@@ -0,0 +1,204 @@
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+const WITHDRAW_LIMIT: Decimal = dec!("10");
+
+#[test]
+fn owner_key_can_withdraw_within_limit_of_authorizer() {
+    // Arrange
+    let mut env = AccountAuthorizerTestEnvironment::new();
+    env.set_authorizer(Some(env.authorizer))
+        .expect_commit_success();
+
+    // Act
+    let receipt = env.withdraw(WITHDRAW_LIMIT, vec![env.owner_public_key]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn owner_key_cannot_withdraw_over_limit_of_authorizer() {
+    // Arrange
+    let mut env = AccountAuthorizerTestEnvironment::new();
+    env.set_authorizer(Some(env.authorizer))
+        .expect_commit_success();
+
+    // Act
+    let receipt = env.withdraw(WITHDRAW_LIMIT + dec!("1"), vec![env.owner_public_key]);
+
+    // Assert
+    receipt.expect_specific_failure(is_authorizer_rejection);
+}
+
+#[test]
+fn owner_key_with_override_key_can_withdraw_over_limit_of_authorizer() {
+    // Arrange
+    let mut env = AccountAuthorizerTestEnvironment::new();
+    env.set_authorizer(Some(env.authorizer))
+        .expect_commit_success();
+
+    // Act
+    let receipt = env.withdraw(
+        WITHDRAW_LIMIT + dec!("1"),
+        vec![env.owner_public_key, env.override_public_key],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn override_key_cannot_withdraw_without_owner_key() {
+    // Arrange
+    let mut env = AccountAuthorizerTestEnvironment::new();
+    env.set_authorizer(Some(env.authorizer))
+        .expect_commit_success();
+
+    // Act
+    let receipt = env.withdraw(WITHDRAW_LIMIT, vec![env.override_public_key]);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn owner_key_can_withdraw_over_limit_once_authorizer_is_removed() {
+    // Arrange
+    let mut env = AccountAuthorizerTestEnvironment::new();
+    env.set_authorizer(Some(env.authorizer))
+        .expect_commit_success();
+    env.set_authorizer(None).expect_commit_success();
+
+    // Act
+    let receipt = env.withdraw(WITHDRAW_LIMIT + dec!("1"), vec![env.owner_public_key]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn owner_key_can_lock_fee_without_asking_authorizer() {
+    // Arrange
+    let mut env = AccountAuthorizerTestEnvironment::new();
+    env.set_authorizer(Some(env.authorizer))
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new().lock_fee(env.account, 500).build();
+    let receipt = env.execute_as(manifest, vec![env.owner_public_key]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn owner_key_cannot_lock_fee_and_withdraw_from_account_with_authorizer() {
+    // Arrange
+    let mut env = AccountAuthorizerTestEnvironment::new();
+    env.set_authorizer(Some(env.authorizer))
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .lock_fee_and_withdraw(env.account, 500, XRD, WITHDRAW_LIMIT)
+        .try_deposit_entire_worktop_or_abort(env.account, None)
+        .build();
+    let receipt = env.execute_as(manifest, vec![env.owner_public_key]);
+
+    // Assert
+    receipt.expect_specific_failure(is_authorizer_rejection);
+}
+
+fn is_authorizer_rejection(e: &RuntimeError) -> bool {
+    matches!(
+        e,
+        RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+            AuthError::AuthorizerRejected(..)
+        ))
+    )
+}
+
+struct AccountAuthorizerTestEnvironment {
+    test_runner: DefaultTestRunner,
+    owner_public_key: Secp256k1PublicKey,
+    override_public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    authorizer: ComponentAddress,
+}
+
+impl AccountAuthorizerTestEnvironment {
+    fn new() -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (owner_public_key, _, account) = test_runner.new_allocated_account();
+        let (override_public_key, _) = test_runner.new_key_pair();
+        let package_address =
+            test_runner.publish_package_simple(PackageLoader::get("account_authorizer"));
+        let authorizer = test_runner
+            .execute_manifest(
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .call_function(
+                        package_address,
+                        "SpendingLimitAuthorizer",
+                        "new",
+                        manifest_args!(
+                            NonFungibleGlobalId::from_public_key(&override_public_key),
+                            WITHDRAW_LIMIT
+                        ),
+                    )
+                    .build(),
+                vec![],
+            )
+            .expect_commit_success()
+            .new_component_addresses()[0];
+
+        Self {
+            test_runner,
+            owner_public_key,
+            override_public_key,
+            account,
+            authorizer,
+        }
+    }
+
+    fn execute_as(
+        &mut self,
+        manifest: TransactionManifestV1,
+        public_keys: Vec<Secp256k1PublicKey>,
+    ) -> TransactionReceipt {
+        self.test_runner.execute_manifest(
+            manifest,
+            public_keys
+                .iter()
+                .map(NonFungibleGlobalId::from_public_key)
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn set_authorizer(&mut self, authorizer: Option<ComponentAddress>) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .set_account_authorizer(self.account, authorizer)
+            .build();
+        self.execute_as(manifest, vec![self.owner_public_key])
+    }
+
+    fn withdraw(
+        &mut self,
+        amount: Decimal,
+        public_keys: Vec<Secp256k1PublicKey>,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(self.account, XRD, amount)
+            .try_deposit_entire_worktop_or_abort(self.account, None)
+            .build();
+        self.execute_as(manifest, public_keys)
+    }
+}
@@ -1,3 +1,4 @@
+use radix_engine::blueprints::account::DepositBatchResultEvent;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
 use radix_engine::system::system_modules::auth::AuthError;
 use radix_engine::transaction::TransactionReceipt;
@@ -119,6 +120,58 @@ fn account_try_deposit_batch_or_refund_method_is_callable_with_array_of_resource
     receipt.expect_commit_success();
 }
 
+#[test]
+fn try_deposit_batch_or_refund_only_refunds_disallowed_resources() {
+    // Arrange
+    for is_virtual in [true, false] {
+        let mut test_runner = AccountDepositModesTestRunner::new(is_virtual);
+        let resource_address = test_runner.freely_mintable_resource();
+        test_runner
+            .add_to_deny_list(resource_address, true)
+            .expect_commit_success();
+        let xrd_balance = test_runner
+            .test_runner
+            .get_component_balance(test_runner.component_address, XRD);
+
+        // Act
+        let manifest = ManifestBuilder::new()
+            .get_free_xrd_from_faucet()
+            .mint_fungible(resource_address, 1)
+            .take_all_from_worktop(XRD, "xrd")
+            .take_all_from_worktop(resource_address, "token")
+            .try_deposit_batch_or_refund(test_runner.component_address, ["xrd", "token"], None)
+            .assert_worktop_contains(resource_address, 1)
+            .burn_all_from_worktop(resource_address)
+            .build();
+        let receipt = test_runner.execute_manifest(manifest, false);
+
+        // Assert
+        let commit_result = receipt.expect_commit_success();
+        assert_eq!(
+            test_runner
+                .test_runner
+                .get_component_balance(test_runner.component_address, XRD),
+            xrd_balance + dec!("10000")
+        );
+        let result_event = commit_result
+            .application_events
+            .iter()
+            .find(|(event_type_identifier, _)| {
+                test_runner.test_runner.event_name(event_type_identifier)
+                    == DepositBatchResultEvent::EVENT_NAME
+            })
+            .map(|(_, data)| scrypto_decode::<DepositBatchResultEvent>(data).unwrap())
+            .unwrap();
+        assert_eq!(
+            result_event,
+            DepositBatchResultEvent {
+                accepted: indexset!(XRD),
+                refused: indexset!(resource_address),
+            }
+        );
+    }
+}
+
 #[test]
 fn account_try_deposit_or_abort_method_is_callable_without_owner_signature() {
     // Arrange
@@ -458,6 +511,30 @@ fn disallow_all_permits_deposit_of_resource_in_allow_list() {
     }
 }
 
+#[test]
+fn funded_virtual_accounts_can_be_created_with_deposit_rule_in_a_single_transaction() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+
+    // Act
+    let accounts = test_runner.new_funded_virtual_accounts(4, DefaultDepositRule::Reject);
+
+    // Assert
+    for (_, _, account) in accounts {
+        assert_eq!(test_runner.get_component_balance(account, XRD), dec!(2500));
+
+        let receipt = test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .get_free_xrd_from_faucet()
+                .try_deposit_entire_worktop_or_abort(account, None)
+                .build(),
+            vec![],
+        );
+        receipt.expect_specific_failure(is_account_deposit_not_allowed_error);
+    }
+}
+
 struct AccountDepositModesTestRunner {
     test_runner: DefaultTestRunner,
     public_key: PublicKey,
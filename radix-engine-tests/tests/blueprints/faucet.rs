@@ -0,0 +1,84 @@
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::types::*;
+use radix_engine_interface::api::node_modules::auth::AuthAddresses;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn claim(test_runner: &mut DefaultTestRunner, account: ComponentAddress) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(FAUCET, "claim", manifest_args!(account))
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn account_can_claim_once_per_claim_interval() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let balance = test_runner.get_component_balance(account, XRD);
+    let epoch = test_runner.get_current_epoch();
+
+    // Act
+    let first_receipt = claim(&mut test_runner, account);
+    let second_receipt = claim(&mut test_runner, account);
+    test_runner.set_current_epoch(epoch.next().unwrap());
+    let third_receipt = claim(&mut test_runner, account);
+
+    // Assert
+    first_receipt.expect_commit_success();
+    second_receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PanicMessage(..))
+        )
+    });
+    third_receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, XRD),
+        balance + dec!(20000)
+    );
+}
+
+#[test]
+fn owner_can_change_drip_amount() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let balance = test_runner.get_component_balance(account, XRD);
+
+    // Act
+    let instructions = ManifestBuilder::new()
+        .call_method(FAUCET, "set_drip_amount", manifest_args!(dec!(5)))
+        .build()
+        .instructions;
+    let receipt = test_runner.execute_system_transaction(
+        instructions,
+        btreeset![AuthAddresses::system_role()],
+    );
+    claim(&mut test_runner, account).expect_commit_success();
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, XRD),
+        balance + dec!(5)
+    );
+}
+
+#[test]
+fn non_owner_cannot_change_drip_amount() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(FAUCET, "set_drip_amount", manifest_args!(dec!(1000000)))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
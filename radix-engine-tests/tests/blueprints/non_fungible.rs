@@ -326,6 +326,30 @@ fn test_take_non_fungibles() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn test_take_non_fungibles_matching() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("non_fungible"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "NonFungibleTest",
+            "take_non_fungibles_matching_from_bucket_and_vault",
+            manifest_args!(),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
 #[test]
 fn can_update_non_fungible_when_mutable() {
     let mut test_runner = TestRunnerBuilder::new().build();
@@ -955,6 +979,104 @@ fn cannot_get_total_supply_when_track_total_supply_disable() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn nft_collection_has_standard_metadata_and_enumerates_in_mint_order() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.publish_package_simple(PackageLoader::get("non_fungible"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "create_nft_collection",
+            manifest_args!(),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let resource_address = receipt.expect_commit_success().new_resource_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            resource_address,
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_MINTED_IDENT,
+            manifest_args!(),
+        )
+        .call_method(
+            resource_address,
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_MINTED_IDS_IDENT,
+            manifest_args!(1u64, 10u32),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(result.output::<Option<u64>>(1), Some(3));
+    assert_eq!(
+        result.output::<Vec<NonFungibleLocalId>>(2),
+        vec![NonFungibleLocalId::integer(1), NonFungibleLocalId::integer(2)]
+    );
+    assert_eq!(
+        test_runner.get_metadata(resource_address.into(), NFT_COLLECTION_NAME_METADATA_KEY),
+        Some(MetadataValue::String("Katz's Sandwiches".to_string()))
+    );
+    assert_eq!(
+        test_runner.get_metadata(
+            resource_address.into(),
+            NFT_COLLECTION_BASE_URL_METADATA_KEY
+        ),
+        Some(MetadataValue::Url(UncheckedUrl::of(
+            "https://www.katzsdelicatessen.com/sandwiches/"
+        )))
+    );
+    assert_eq!(
+        test_runner.get_metadata(
+            resource_address.into(),
+            NFT_COLLECTION_ITEM_FIELDS_METADATA_KEY
+        ),
+        Some(MetadataValue::StringArray(
+            ["name", "available", "tastes_great", "reference", "own"]
+                .iter()
+                .map(|field| field.to_string())
+                .collect()
+        ))
+    );
+}
+
+#[test]
+fn enumerating_minted_ids_of_non_collection_resource_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            resource_address,
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_MINTED_IDS_IDENT,
+            manifest_args!(0u64, 10u32),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::NotACollection
+            ))
+        )
+    });
+}
+
 #[test]
 fn can_mint_ruid_non_fungible_in_scrypto() {
     // Arrange
@@ -1397,3 +1519,306 @@ fn cant_create_non_fungible_with_id_type_does_not_match() {
         )
     });
 }
+
+fn create_resource_with_integer_ids_one_to_three(
+    test_runner: &mut DefaultTestRunner,
+    account: ComponentAddress,
+) -> ResourceAddress {
+    test_runner.create_freely_mintable_and_burnable_non_fungible_resource(
+        OwnerRole::None,
+        NonFungibleIdType::Integer,
+        Some(vec![
+            (NonFungibleLocalId::integer(1), EmptyNonFungibleData {}),
+            (NonFungibleLocalId::integer(2), EmptyNonFungibleData {}),
+            (NonFungibleLocalId::integer(3), EmptyNonFungibleData {}),
+        ]),
+        account,
+    )
+}
+
+#[test]
+fn can_reserve_integer_ids_and_mint_into_reservation() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_resource_with_integer_ids_one_to_three(&mut test_runner, account);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .reserve_integer_ids(resource_address, 5)
+            .reserve_integer_ids(resource_address, 2)
+            .build(),
+        vec![],
+    );
+    let result = receipt.expect_commit_success();
+    let reservation: NonFungibleIdReservation = result.output(1);
+    let other_reservation: NonFungibleIdReservation = result.output(2);
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .mint_reserved_non_fungible(
+                resource_address,
+                reservation,
+                [
+                    (NonFungibleLocalId::integer(4), EmptyNonFungibleData {}),
+                    (NonFungibleLocalId::integer(8), EmptyNonFungibleData {}),
+                ],
+            )
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    assert_eq!(reservation, NonFungibleIdReservation { start: 4, end: 9 });
+    assert_eq!(
+        other_reservation,
+        NonFungibleIdReservation { start: 9, end: 11 }
+    );
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, resource_address),
+        dec!(5)
+    );
+}
+
+#[test]
+fn plain_mint_moves_future_reservations_past_minted_ids() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_resource_with_integer_ids_one_to_three(&mut test_runner, account);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .mint_non_fungible(
+                resource_address,
+                [(NonFungibleLocalId::integer(20), EmptyNonFungibleData {})],
+            )
+            .reserve_integer_ids(resource_address, 1)
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let reservation: NonFungibleIdReservation = receipt.expect_commit_success().output(2);
+    assert_eq!(reservation, NonFungibleIdReservation { start: 21, end: 22 });
+}
+
+#[test]
+fn cannot_mint_id_outside_of_reservation() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_resource_with_integer_ids_one_to_three(&mut test_runner, account);
+    let reservation: NonFungibleIdReservation = test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .reserve_integer_ids(resource_address, 2)
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .output(1);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .mint_reserved_non_fungible(
+                resource_address,
+                reservation,
+                [(NonFungibleLocalId::integer(6), EmptyNonFungibleData {})],
+            )
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::NonFungibleLocalIdNotReserved(id)
+            )) if id.eq(&NonFungibleLocalId::integer(6))
+        )
+    });
+}
+
+#[test]
+fn cannot_mint_into_reservation_which_was_never_made() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_resource_with_integer_ids_one_to_three(&mut test_runner, account);
+    let reservation = NonFungibleIdReservation { start: 4, end: 6 };
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .mint_reserved_non_fungible(
+                resource_address,
+                reservation,
+                [(NonFungibleLocalId::integer(4), EmptyNonFungibleData {})],
+            )
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::IdReservationNotFound(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn total_supply_is_none_when_built_without_tracking() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.publish_package_simple(PackageLoader::get("non_fungible"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "get_total_supply_when_built_without_tracking",
+            manifest_args!(),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+fn create_burnable_nft_collection(
+    test_runner: &mut DefaultTestRunner,
+    account: ComponentAddress,
+) -> ResourceAddress {
+    let package = test_runner.publish_package_simple(PackageLoader::get("non_fungible"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "create_burnable_nft_collection",
+            manifest_args!(),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().new_resource_addresses()[0]
+}
+
+#[test]
+fn total_supply_recount_finishes_over_several_calls() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_burnable_nft_collection(&mut test_runner, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recount_total_supply(resource_address, 2)
+        .recount_total_supply(resource_address, 2)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(result.output::<Option<Decimal>>(1), None);
+    assert_eq!(result.output::<Option<Decimal>>(2), Some(dec!(3)));
+}
+
+#[test]
+fn burn_during_total_supply_recount_restarts_it() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_burnable_nft_collection(&mut test_runner, account);
+    test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .recount_total_supply(resource_address, 2)
+                .build(),
+            vec![],
+        )
+        .expect_commit_success();
+    test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .withdraw_non_fungibles_from_account(
+                    account,
+                    resource_address,
+                    [NonFungibleLocalId::integer(1)],
+                )
+                .burn_all_from_worktop(resource_address)
+                .build(),
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recount_total_supply(resource_address, 10)
+        .call_method(
+            resource_address,
+            RESOURCE_MANAGER_GET_TOTAL_SUPPLY_IDENT,
+            manifest_args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(result.output::<Option<Decimal>>(1), Some(dec!(2)));
+    assert_eq!(result.output::<Option<Decimal>>(2), Some(dec!(2)));
+}
+
+#[test]
+fn total_supply_recount_of_untracked_resource_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recount_total_supply(resource_address, 10)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::TotalSupplyNotTracked
+            ))
+        )
+    });
+}
@@ -0,0 +1,285 @@
+use radix_engine::blueprints::order_book::OrderBookError;
+use radix_engine_interface::blueprints::order_book::*;
+use scrypto_test::prelude::*;
+
+#[test]
+fn resting_asks_are_matched_from_the_lowest_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let env = &mut TestEnvironment::new();
+    let mut order_book = OrderBook::instantiate(dec!("0.5"), env)?;
+    let expensive = order_book.insert_order(OrderSide::Ask, dec!("12"), dec!("1"), env)?;
+    let cheap = order_book.insert_order(OrderSide::Ask, dec!("10"), dec!("1"), env)?;
+    let middle = order_book.insert_order(OrderSide::Ask, dec!("10.5"), dec!("1"), env)?;
+
+    // Act
+    let fills = order_book.match_best(OrderSide::Bid, dec!("3"), None, 10, env)?;
+
+    // Assert
+    assert_eq!(filled_order_ids(&fills), vec![cheap, middle, expensive]);
+    assert_eq!(
+        fills.iter().map(|fill| fill.price).collect::<Vec<_>>(),
+        vec![dec!("10"), dec!("10.5"), dec!("12")]
+    );
+    Ok(())
+}
+
+#[test]
+fn resting_bids_are_matched_from_the_highest_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let env = &mut TestEnvironment::new();
+    let mut order_book = OrderBook::instantiate(dec!("1"), env)?;
+    let low = order_book.insert_order(OrderSide::Bid, dec!("8"), dec!("1"), env)?;
+    let high = order_book.insert_order(OrderSide::Bid, dec!("9"), dec!("1"), env)?;
+
+    // Act
+    let fills = order_book.match_best(OrderSide::Ask, dec!("2"), None, 10, env)?;
+
+    // Assert
+    assert_eq!(filled_order_ids(&fills), vec![high, low]);
+    Ok(())
+}
+
+#[test]
+fn orders_at_the_same_price_are_matched_in_insertion_order() -> Result<(), RuntimeError> {
+    // Arrange
+    let env = &mut TestEnvironment::new();
+    let mut order_book = OrderBook::instantiate(dec!("1"), env)?;
+    let order_ids = (0..5)
+        .map(|_| order_book.insert_order(OrderSide::Ask, dec!("10"), dec!("1"), env))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Act
+    let fills = order_book.match_best(OrderSide::Bid, dec!("5"), None, 10, env)?;
+
+    // Assert
+    assert_eq!(filled_order_ids(&fills), order_ids);
+    Ok(())
+}
+
+#[test]
+fn partially_filled_order_keeps_its_priority() -> Result<(), RuntimeError> {
+    // Arrange
+    let env = &mut TestEnvironment::new();
+    let mut order_book = OrderBook::instantiate(dec!("1"), env)?;
+    let first = order_book.insert_order(OrderSide::Ask, dec!("10"), dec!("10"), env)?;
+    let second = order_book.insert_order(OrderSide::Ask, dec!("10"), dec!("10"), env)?;
+    order_book.match_best(OrderSide::Bid, dec!("4"), None, 10, env)?;
+
+    // Act
+    let fills = order_book.match_best(OrderSide::Bid, dec!("10"), None, 10, env)?;
+
+    // Assert
+    assert_eq!(
+        fills,
+        vec![
+            OrderFill {
+                order_id: first,
+                price: dec!("10"),
+                quantity: dec!("6"),
+                remaining_quantity: Decimal::ZERO,
+            },
+            OrderFill {
+                order_id: second,
+                price: dec!("10"),
+                quantity: dec!("4"),
+                remaining_quantity: dec!("6"),
+            },
+        ]
+    );
+    assert_eq!(order_book.get_order(first, env)?, None);
+    Ok(())
+}
+
+#[test]
+fn matching_stops_at_the_limit_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let env = &mut TestEnvironment::new();
+    let mut order_book = OrderBook::instantiate(dec!("1"), env)?;
+    let within_limit = order_book.insert_order(OrderSide::Ask, dec!("10"), dec!("1"), env)?;
+    let beyond_limit = order_book.insert_order(OrderSide::Ask, dec!("11"), dec!("1"), env)?;
+
+    // Act
+    let fills = order_book.match_best(OrderSide::Bid, dec!("2"), Some(dec!("10")), 10, env)?;
+
+    // Assert
+    assert_eq!(filled_order_ids(&fills), vec![within_limit]);
+    assert!(order_book.get_order(beyond_limit, env)?.is_some());
+    Ok(())
+}
+
+#[test]
+fn matching_stops_after_max_fills_and_resumes_where_it_stopped() -> Result<(), RuntimeError> {
+    // Arrange
+    let env = &mut TestEnvironment::new();
+    let mut order_book = OrderBook::instantiate(dec!("1"), env)?;
+    let order_ids = (0..5)
+        .map(|_| order_book.insert_order(OrderSide::Ask, dec!("10"), dec!("1"), env))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Act
+    let first_fills = order_book.match_best(OrderSide::Bid, dec!("5"), None, 2, env)?;
+    let second_fills = order_book.match_best(OrderSide::Bid, dec!("5"), None, 10, env)?;
+
+    // Assert
+    assert_eq!(filled_order_ids(&first_fills), order_ids[..2]);
+    assert_eq!(filled_order_ids(&second_fills), order_ids[2..]);
+    Ok(())
+}
+
+#[test]
+fn cancelled_order_is_not_matched() -> Result<(), RuntimeError> {
+    // Arrange
+    let env = &mut TestEnvironment::new();
+    let mut order_book = OrderBook::instantiate(dec!("1"), env)?;
+    let first = order_book.insert_order(OrderSide::Ask, dec!("10"), dec!("1"), env)?;
+    let cancelled = order_book.insert_order(OrderSide::Ask, dec!("10"), dec!("2"), env)?;
+    let last = order_book.insert_order(OrderSide::Ask, dec!("10"), dec!("1"), env)?;
+
+    // Act
+    let order = order_book.cancel_order(cancelled, env)?;
+    let fills = order_book.match_best(OrderSide::Bid, dec!("4"), None, 10, env)?;
+
+    // Assert
+    assert_eq!(
+        order,
+        Order {
+            side: OrderSide::Ask,
+            price: dec!("10"),
+            quantity: dec!("2"),
+        }
+    );
+    assert_eq!(filled_order_ids(&fills), vec![first, last]);
+    Ok(())
+}
+
+#[test]
+fn cancelling_the_only_order_at_a_price_removes_the_price_level() -> Result<(), RuntimeError> {
+    // Arrange
+    let env = &mut TestEnvironment::new();
+    let mut order_book = OrderBook::instantiate(dec!("1"), env)?;
+    let cancelled = order_book.insert_order(OrderSide::Bid, dec!("10"), dec!("1"), env)?;
+    let remaining = order_book.insert_order(OrderSide::Bid, dec!("9"), dec!("1"), env)?;
+
+    // Act
+    order_book.cancel_order(cancelled, env)?;
+    let fills = order_book.match_best(OrderSide::Ask, dec!("1"), None, 10, env)?;
+
+    // Assert
+    assert_eq!(filled_order_ids(&fills), vec![remaining]);
+    Ok(())
+}
+
+#[test]
+fn order_price_must_be_a_multiple_of_the_tick_size() -> Result<(), RuntimeError> {
+    // Arrange
+    let env = &mut TestEnvironment::new();
+    let mut order_book = OrderBook::instantiate(dec!("0.5"), env)?;
+
+    // Act
+    let rtn = order_book.insert_order(OrderSide::Bid, dec!("10.25"), dec!("1"), env);
+
+    // Assert
+    assert_eq!(
+        rtn,
+        Err(RuntimeError::ApplicationError(
+            ApplicationError::OrderBookError(OrderBookError::InvalidPrice {
+                price: dec!("10.25")
+            })
+        ))
+    );
+    Ok(())
+}
+
+fn filled_order_ids(fills: &[OrderFill]) -> Vec<u64> {
+    fills.iter().map(|fill| fill.order_id).collect()
+}
+
+struct OrderBook(NodeId);
+
+impl OrderBook {
+    fn instantiate<Y>(tick_size: Decimal, api: &mut Y) -> Result<Self, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let rtn = api.call_function(
+            ORDER_BOOK_PACKAGE,
+            ORDER_BOOK_BLUEPRINT,
+            ORDER_BOOK_INSTANTIATE_IDENT,
+            scrypto_encode(&OrderBookInstantiateInput { tick_size }).unwrap(),
+        )?;
+        let order_book = scrypto_decode::<OrderBookInstantiateOutput>(&rtn).unwrap();
+        Ok(Self(order_book.0.into_node_id()))
+    }
+
+    fn insert_order<Y>(
+        &mut self,
+        side: OrderSide,
+        price: Decimal,
+        quantity: Decimal,
+        api: &mut Y,
+    ) -> Result<u64, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let rtn = api.call_method(
+            &self.0,
+            ORDER_BOOK_INSERT_ORDER_IDENT,
+            scrypto_encode(&OrderBookInsertOrderInput {
+                side,
+                price,
+                quantity,
+            })
+            .unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
+    fn cancel_order<Y>(&mut self, order_id: u64, api: &mut Y) -> Result<Order, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let rtn = api.call_method(
+            &self.0,
+            ORDER_BOOK_CANCEL_ORDER_IDENT,
+            scrypto_encode(&OrderBookCancelOrderInput { order_id }).unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
+    fn match_best<Y>(
+        &mut self,
+        side: OrderSide,
+        quantity: Decimal,
+        limit_price: Option<Decimal>,
+        max_fills: u32,
+        api: &mut Y,
+    ) -> Result<Vec<OrderFill>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let rtn = api.call_method(
+            &self.0,
+            ORDER_BOOK_MATCH_BEST_IDENT,
+            scrypto_encode(&OrderBookMatchBestInput {
+                side,
+                quantity,
+                limit_price,
+                max_fills,
+            })
+            .unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
+    fn get_order<Y>(&self, order_id: u64, api: &mut Y) -> Result<Option<Order>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let rtn = api.call_method(
+            &self.0,
+            ORDER_BOOK_GET_ORDER_IDENT,
+            scrypto_encode(&OrderBookGetOrderInput { order_id }).unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+}
@@ -0,0 +1,333 @@
+use radix_engine::blueprints::payment_scheduler::*;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::payment_scheduler::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+const INCENTIVE_PER_PAYMENT: Decimal = dec!("1");
+
+#[test]
+fn due_payment_is_paid_to_recipient_and_caller_receives_incentive() {
+    // Arrange
+    let mut env = PaymentSchedulerTestEnvironment::new(dec!("100"));
+    let first_payment_epoch = env.epoch_after(5);
+    let schedule_id = env.create_schedule(dec!("10"), 10, first_payment_epoch);
+    env.test_runner.set_current_epoch(first_payment_epoch);
+
+    // Act
+    let receipt = env.process_due_payments(10);
+
+    // Assert
+    let PaymentEvent {
+        schedule_id: paid_schedule_id,
+        amount,
+        installments,
+        ..
+    } = env.find_event(&receipt, "PaymentEvent");
+    assert_eq!(paid_schedule_id, schedule_id);
+    assert_eq!(amount, dec!("10"));
+    assert_eq!(installments, 1);
+    assert_eq!(env.balance(env.recipient_account), dec!("10"));
+    assert_eq!(env.balance(env.processor_account), INCENTIVE_PER_PAYMENT);
+    assert_eq!(
+        env.get_schedule(schedule_id).unwrap().next_payment_epoch,
+        first_payment_epoch.after(10).unwrap()
+    );
+}
+
+#[test]
+fn payment_is_not_made_before_it_is_due() {
+    // Arrange
+    let mut env = PaymentSchedulerTestEnvironment::new(dec!("100"));
+    let first_payment_epoch = env.epoch_after(5);
+    env.create_schedule(dec!("10"), 10, first_payment_epoch);
+    env.test_runner
+        .set_current_epoch(first_payment_epoch.previous().unwrap());
+
+    // Act
+    env.process_due_payments(10);
+
+    // Assert
+    assert_eq!(env.balance(env.recipient_account), Decimal::ZERO);
+    assert_eq!(env.balance(env.processor_account), Decimal::ZERO);
+}
+
+#[test]
+fn matured_installments_are_paid_in_a_single_payment() {
+    // Arrange
+    let mut env = PaymentSchedulerTestEnvironment::new(dec!("100"));
+    let first_payment_epoch = env.epoch_after(5);
+    let schedule_id = env.create_schedule(dec!("10"), 10, first_payment_epoch);
+    env.test_runner
+        .set_current_epoch(first_payment_epoch.after(25).unwrap());
+
+    // Act
+    env.process_due_payments(10);
+
+    // Assert
+    assert_eq!(env.balance(env.recipient_account), dec!("30"));
+    assert_eq!(env.balance(env.processor_account), INCENTIVE_PER_PAYMENT);
+    assert_eq!(
+        env.get_schedule(schedule_id).unwrap().next_payment_epoch,
+        first_payment_epoch.after(30).unwrap()
+    );
+}
+
+#[test]
+fn only_installments_that_can_be_funded_are_paid() {
+    // Arrange
+    let mut env = PaymentSchedulerTestEnvironment::new(dec!("25"));
+    let first_payment_epoch = env.epoch_after(5);
+    let schedule_id = env.create_schedule(dec!("10"), 10, first_payment_epoch);
+    env.test_runner
+        .set_current_epoch(first_payment_epoch.after(25).unwrap());
+
+    // Act
+    env.process_due_payments(10);
+    env.process_due_payments(10);
+
+    // Assert
+    assert_eq!(env.balance(env.recipient_account), dec!("20"));
+    assert_eq!(env.balance(env.processor_account), INCENTIVE_PER_PAYMENT);
+    assert_eq!(
+        env.get_schedule(schedule_id).unwrap().next_payment_epoch,
+        first_payment_epoch.after(20).unwrap()
+    );
+}
+
+#[test]
+fn cancelled_schedule_is_not_paid() {
+    // Arrange
+    let mut env = PaymentSchedulerTestEnvironment::new(dec!("100"));
+    let first_payment_epoch = env.epoch_after(5);
+    let schedule_id = env.create_schedule(dec!("10"), 10, first_payment_epoch);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.payment_scheduler,
+            PAYMENT_SCHEDULER_CANCEL_SCHEDULE_IDENT,
+            PaymentSchedulerCancelScheduleManifestInput { schedule_id },
+        )
+        .build();
+    env.execute_as_payer(manifest).expect_commit_success();
+    env.test_runner.set_current_epoch(first_payment_epoch);
+
+    // Act
+    env.process_due_payments(10);
+
+    // Assert
+    assert_eq!(env.get_schedule(schedule_id), None);
+    assert_eq!(env.balance(env.recipient_account), Decimal::ZERO);
+}
+
+#[test]
+fn schedule_cannot_be_created_without_owner_badge() {
+    // Arrange
+    let mut env = PaymentSchedulerTestEnvironment::new(dec!("100"));
+    let first_payment_epoch = env.epoch_after(5);
+
+    // Act
+    let manifest =
+        env.create_schedule_manifest(env.recipient_account, dec!("10"), 10, first_payment_epoch);
+    let receipt = env.test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn schedule_cannot_pay_to_a_non_account_recipient() {
+    // Arrange
+    let mut env = PaymentSchedulerTestEnvironment::new(dec!("100"));
+    let first_payment_epoch = env.epoch_after(5);
+
+    // Act
+    let manifest =
+        env.create_schedule_manifest(env.payment_scheduler, dec!("10"), 10, first_payment_epoch);
+    let receipt = env.execute_as_payer(manifest);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PaymentSchedulerError(
+                PaymentSchedulerError::RecipientIsNotAnAccount { .. }
+            ))
+        )
+    });
+}
+
+struct PaymentSchedulerTestEnvironment {
+    test_runner: DefaultTestRunner,
+    payer_public_key: Secp256k1PublicKey,
+    recipient_account: ComponentAddress,
+    processor_account: ComponentAddress,
+    resource: ResourceAddress,
+    payment_scheduler: ComponentAddress,
+}
+
+impl PaymentSchedulerTestEnvironment {
+    fn new(funds: Decimal) -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (payer_public_key, _, payer_account) = test_runner.new_allocated_account();
+        let (_, _, recipient_account) = test_runner.new_allocated_account();
+        let (_, _, processor_account) = test_runner.new_allocated_account();
+        let resource = test_runner.create_fungible_resource(funds, 18, payer_account);
+
+        let receipt = test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(
+                    PAYMENT_SCHEDULER_PACKAGE,
+                    PAYMENT_SCHEDULER_BLUEPRINT,
+                    PAYMENT_SCHEDULER_INSTANTIATE_IDENT,
+                    PaymentSchedulerInstantiateManifestInput {
+                        owner_role: OwnerRole::Fixed(rule!(require(
+                            NonFungibleGlobalId::from_public_key(&payer_public_key)
+                        ))),
+                        resource_address: resource,
+                        incentive_per_payment: INCENTIVE_PER_PAYMENT,
+                        address_reservation: None,
+                    },
+                )
+                .build(),
+            vec![],
+        );
+        let payment_scheduler = receipt.expect_commit_success().new_component_addresses()[0];
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(payer_account, resource, funds)
+            .take_all_from_worktop(resource, "funds")
+            .with_bucket("funds", |builder, bucket| {
+                builder.call_method(
+                    payment_scheduler,
+                    PAYMENT_SCHEDULER_DEPOSIT_IDENT,
+                    PaymentSchedulerDepositManifestInput { bucket },
+                )
+            })
+            .build();
+        test_runner
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&payer_public_key)],
+            )
+            .expect_commit_success();
+
+        Self {
+            test_runner,
+            payer_public_key,
+            recipient_account,
+            processor_account,
+            resource,
+            payment_scheduler,
+        }
+    }
+
+    fn epoch_after(&mut self, epoch_count: u64) -> Epoch {
+        self.test_runner
+            .get_current_epoch()
+            .after(epoch_count)
+            .unwrap()
+    }
+
+    fn balance(&mut self, account: ComponentAddress) -> Decimal {
+        self.test_runner
+            .get_component_balance(account, self.resource)
+    }
+
+    fn execute_as_payer(&mut self, manifest: TransactionManifestV1) -> TransactionReceipt {
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &self.payer_public_key,
+            )],
+        )
+    }
+
+    fn create_schedule_manifest(
+        &self,
+        recipient: ComponentAddress,
+        amount: Decimal,
+        interval_epochs: u64,
+        first_payment_epoch: Epoch,
+    ) -> TransactionManifestV1 {
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.payment_scheduler,
+                PAYMENT_SCHEDULER_CREATE_SCHEDULE_IDENT,
+                PaymentSchedulerCreateScheduleManifestInput {
+                    recipient,
+                    amount,
+                    interval_epochs,
+                    first_payment_epoch,
+                },
+            )
+            .build()
+    }
+
+    fn create_schedule(
+        &mut self,
+        amount: Decimal,
+        interval_epochs: u64,
+        first_payment_epoch: Epoch,
+    ) -> u64 {
+        let manifest = self.create_schedule_manifest(
+            self.recipient_account,
+            amount,
+            interval_epochs,
+            first_payment_epoch,
+        );
+        self.execute_as_payer(manifest)
+            .expect_commit_success()
+            .output(1)
+    }
+
+    fn get_schedule(&mut self, schedule_id: u64) -> Option<PaymentSchedule> {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.payment_scheduler,
+                PAYMENT_SCHEDULER_GET_SCHEDULE_IDENT,
+                PaymentSchedulerGetScheduleManifestInput { schedule_id },
+            )
+            .build();
+        self.test_runner
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .output(1)
+    }
+
+    fn process_due_payments(&mut self, max_payments: u32) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.payment_scheduler,
+                PAYMENT_SCHEDULER_PROCESS_DUE_PAYMENTS_IDENT,
+                PaymentSchedulerProcessDuePaymentsManifestInput { max_payments },
+            )
+            .try_deposit_entire_worktop_or_abort(self.processor_account, None)
+            .build();
+        let receipt = self.test_runner.execute_manifest(manifest, vec![]);
+        receipt.expect_commit_success();
+        receipt
+    }
+
+    fn find_event<T: ScryptoDecode>(&self, receipt: &TransactionReceipt, name: &str) -> T {
+        receipt
+            .expect_commit_success()
+            .application_events
+            .iter()
+            .find_map(|(event_type_identifier, event_data)| {
+                if self.test_runner.event_name(event_type_identifier) == name {
+                    Some(scrypto_decode(event_data).unwrap())
+                } else {
+                    None
+                }
+            })
+            .unwrap()
+    }
+}
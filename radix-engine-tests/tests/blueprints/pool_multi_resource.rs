@@ -798,6 +798,240 @@ fn get_redemption_value_should_not_panic_on_large_values() {
     });
 }
 
+#[test]
+fn owner_can_add_a_resource_that_existing_pool_unit_holders_can_redeem() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<2>::new_with_signer_as_owner([18, 18]);
+    let contributions = indexmap!(
+        test_runner.pool_resources[0] => dec!("100"),
+        test_runner.pool_resources[1] => dec!("100"),
+    );
+    test_runner
+        .contribute(contributions, true)
+        .expect_commit_success();
+    let resource_address = test_runner
+        .test_runner
+        .create_freely_mintable_and_burnable_fungible_resource(
+            OwnerRole::None,
+            None,
+            18,
+            test_runner.account_component_address,
+        );
+
+    // Act
+    test_runner
+        .add_resource(resource_address, Decimal::ONE, true)
+        .expect_commit_success();
+    test_runner
+        .protected_deposit(resource_address, dec!("50"), true)
+        .expect_commit_success();
+
+    // Assert
+    let redemption_value = test_runner.get_redemption_value(dec!("50"), true);
+    assert_eq!(
+        redemption_value,
+        indexmap!(
+            test_runner.pool_resources[0] => dec!("50"),
+            test_runner.pool_resources[1] => dec!("50"),
+            resource_address => dec!("25"),
+        )
+    );
+}
+
+#[test]
+fn cant_add_a_resource_without_owner_proof() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<2>::new_with_signer_as_owner([18, 18]);
+    let resource_address = test_runner
+        .test_runner
+        .create_freely_mintable_and_burnable_fungible_resource(
+            OwnerRole::None,
+            None,
+            18,
+            test_runner.account_component_address,
+        );
+
+    // Act
+    let receipt = test_runner.add_resource(resource_address, Decimal::ONE, false);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error)
+}
+
+#[test]
+fn cant_add_a_resource_already_in_the_pool() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<2>::new_with_signer_as_owner([18, 18]);
+
+    // Act
+    let receipt = test_runner.add_resource(test_runner.pool_resources[0], Decimal::ONE, true);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::MultiResourcePoolError(
+                MultiResourcePoolError::ResourceAlreadyInPool { .. }
+            ))
+        )
+    })
+}
+
+#[test]
+fn contributing_a_retired_resource_fails() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<3>::new_with_signer_as_owner([18, 18, 18]);
+    let contributions = indexmap!(
+        test_runner.pool_resources[0] => dec!("100"),
+        test_runner.pool_resources[1] => dec!("100"),
+        test_runner.pool_resources[2] => dec!("100")
+    );
+    test_runner
+        .contribute(contributions.clone(), true)
+        .expect_commit_success();
+    test_runner
+        .retire_resource(test_runner.pool_resources[2], true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.contribute(contributions, true);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::MultiResourcePoolError(
+                MultiResourcePoolError::ResourceIsRetired { .. }
+            ))
+        )
+    })
+}
+
+#[test]
+fn reserves_of_a_retired_resource_can_still_be_redeemed() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<2>::new_with_signer_as_owner([18, 18]);
+    let contributions = indexmap!(
+        test_runner.pool_resources[0] => dec!("100"),
+        test_runner.pool_resources[1] => dec!("100"),
+    );
+    test_runner
+        .contribute(contributions, true)
+        .expect_commit_success();
+
+    // Act
+    test_runner
+        .retire_resource(test_runner.pool_resources[1], true)
+        .expect_commit_success();
+    test_runner
+        .contribute(
+            indexmap!(test_runner.pool_resources[0] => dec!("100")),
+            true,
+        )
+        .expect_commit_success();
+
+    // Assert
+    let redemption_value = test_runner.get_redemption_value(dec!("100"), true);
+    assert_eq!(
+        redemption_value,
+        indexmap!(
+            test_runner.pool_resources[0] => dec!("100"),
+            test_runner.pool_resources[1] => dec!("50"),
+        )
+    );
+}
+
+#[test]
+fn cant_retire_the_last_active_resource() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<2>::new_with_signer_as_owner([18, 18]);
+    test_runner
+        .retire_resource(test_runner.pool_resources[0], true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.retire_resource(test_runner.pool_resources[1], true);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::MultiResourcePoolError(
+                MultiResourcePoolError::CantRetireLastActiveResource
+            ))
+        )
+    })
+}
+
+#[test]
+fn resource_weight_scales_the_redeemed_amount() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<2>::new_with_signer_as_owner([18, 18]);
+    let contributions = indexmap!(
+        test_runner.pool_resources[0] => dec!("100"),
+        test_runner.pool_resources[1] => dec!("100"),
+    );
+    test_runner
+        .contribute(contributions, true)
+        .expect_commit_success();
+
+    // Act
+    test_runner
+        .set_resource_weight(test_runner.pool_resources[1], dec!("0.5"), true)
+        .expect_commit_success();
+
+    // Assert
+    let redemption_value = test_runner.get_redemption_value(dec!("50"), true);
+    assert_eq!(
+        redemption_value,
+        indexmap!(
+            test_runner.pool_resources[0] => dec!("50"),
+            test_runner.pool_resources[1] => dec!("25"),
+        )
+    );
+}
+
+#[test]
+fn redeeming_the_entire_pool_unit_supply_ignores_weights() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<2>::new_with_signer_as_owner([18, 18]);
+    let contributions = indexmap!(
+        test_runner.pool_resources[0] => dec!("100"),
+        test_runner.pool_resources[1] => dec!("100"),
+    );
+    test_runner
+        .contribute(contributions.clone(), true)
+        .expect_commit_success();
+    test_runner
+        .set_resource_weight(test_runner.pool_resources[1], Decimal::ZERO, true)
+        .expect_commit_success();
+
+    // Act
+    let redemption_value = test_runner.get_redemption_value(dec!("100"), true);
+
+    // Assert
+    assert_eq!(redemption_value, contributions);
+}
+
+#[test]
+fn cant_set_a_resource_weight_above_one() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<2>::new_with_signer_as_owner([18, 18]);
+
+    // Act
+    let receipt = test_runner.set_resource_weight(test_runner.pool_resources[0], dec!("1.5"), true);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::MultiResourcePoolError(
+                MultiResourcePoolError::InvalidResourceWeight { .. }
+            ))
+        )
+    })
+}
+
 fn is_pool_emitter(event_type_identifier: &EventTypeIdentifier) -> bool {
     match event_type_identifier.0 {
         Emitter::Method(node_id, ModuleId::Main) => match node_id.entity_type() {
@@ -830,9 +1064,23 @@ impl<const N: usize> TestEnvironment<N> {
     }
 
     pub fn new_with_owner(divisibility: [u8; N], owner_role: OwnerRole) -> Self {
+        Self::new_with_owner_fn(divisibility, |_| owner_role)
+    }
+
+    pub fn new_with_signer_as_owner(divisibility: [u8; N]) -> Self {
+        Self::new_with_owner_fn(divisibility, |virtual_signature_badge| {
+            OwnerRole::Fixed(rule!(require(virtual_signature_badge.clone())))
+        })
+    }
+
+    fn new_with_owner_fn<F>(divisibility: [u8; N], owner_role: F) -> Self
+    where
+        F: FnOnce(&NonFungibleGlobalId) -> OwnerRole,
+    {
         let mut test_runner = TestRunnerBuilder::new().without_trace().build();
         let (public_key, _, account) = test_runner.new_account(false);
         let virtual_signature_badge = NonFungibleGlobalId::from_public_key(&public_key);
+        let owner_role = owner_role(&virtual_signature_badge);
 
         let resource_addresses = divisibility.map(|divisibility| {
             test_runner.create_freely_mintable_and_burnable_fungible_resource(
@@ -886,10 +1134,9 @@ impl<const N: usize> TestEnvironment<N> {
             manifest_builder = manifest_builder.mint_fungible(*resource_address, *amount)
         }
         let manifest = manifest_builder
-            .call_method(
+            .contribute_to_multi_resource_pool(
                 self.pool_component_address,
-                MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT,
-                manifest_args!(ManifestExpression::EntireWorktop),
+                ManifestExpression::EntireWorktop,
             )
             .try_deposit_entire_worktop_or_abort(self.account_component_address, None)
             .build();
@@ -904,15 +1151,7 @@ impl<const N: usize> TestEnvironment<N> {
                 amount.into(),
             )
             .take_all_from_worktop(self.pool_unit_resource_address, "pool_unit")
-            .with_name_lookup(|builder, lookup| {
-                builder.call_method(
-                    self.pool_component_address,
-                    MULTI_RESOURCE_POOL_REDEEM_IDENT,
-                    MultiResourcePoolRedeemManifestInput {
-                        bucket: lookup.bucket("pool_unit"),
-                    },
-                )
-            })
+            .redeem_from_multi_resource_pool(self.pool_component_address, "pool_unit")
             .try_deposit_entire_worktop_or_abort(self.account_component_address, None)
             .build();
         self.execute_manifest(manifest, sign)
@@ -961,6 +1200,59 @@ impl<const N: usize> TestEnvironment<N> {
         self.execute_manifest(manifest, sign)
     }
 
+    fn add_resource(
+        &mut self,
+        resource_address: ResourceAddress,
+        weight: Decimal,
+        sign: bool,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.pool_component_address,
+                MULTI_RESOURCE_POOL_ADD_RESOURCE_IDENT,
+                MultiResourcePoolAddResourceManifestInput {
+                    resource_address,
+                    weight,
+                },
+            )
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
+    fn retire_resource(
+        &mut self,
+        resource_address: ResourceAddress,
+        sign: bool,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.pool_component_address,
+                MULTI_RESOURCE_POOL_RETIRE_RESOURCE_IDENT,
+                MultiResourcePoolRetireResourceManifestInput { resource_address },
+            )
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
+    fn set_resource_weight(
+        &mut self,
+        resource_address: ResourceAddress,
+        weight: Decimal,
+        sign: bool,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.pool_component_address,
+                MULTI_RESOURCE_POOL_SET_RESOURCE_WEIGHT_IDENT,
+                MultiResourcePoolSetResourceWeightManifestInput {
+                    resource_address,
+                    weight,
+                },
+            )
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
     fn execute_manifest(
         &mut self,
         manifest: TransactionManifestV1,
@@ -684,6 +684,193 @@ fn get_redemption_value_should_not_panic_on_large_values() {
     });
 }
 
+#[test]
+fn interest_bearing_pool_starts_with_an_accrual_index_of_one() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_interest_bearing(18);
+
+    // Act
+    let accrual = test_runner.get_accrual();
+
+    // Assert
+    assert_eq!(
+        accrual,
+        Some(PoolAccrual {
+            accrual_index: PreciseDecimal::ONE,
+            principal: Decimal::ZERO,
+        })
+    );
+}
+
+#[test]
+fn pool_that_is_not_interest_bearing_has_no_accrual() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new(18);
+
+    // Act
+    let accrual = test_runner.get_accrual();
+
+    // Assert
+    assert_eq!(accrual, None);
+}
+
+#[test]
+fn donation_raises_the_accrual_index_without_minting_pool_units() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_interest_bearing(18);
+    test_runner.contribute(100, true).expect_commit_success();
+
+    // Act
+    let receipt = test_runner.donate(10, false);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_accrual(),
+        Some(PoolAccrual {
+            accrual_index: pdec!("1.1"),
+            principal: dec!("100"),
+        })
+    );
+    assert_eq!(test_runner.get_redemption_value(100, true), dec!("110"));
+    assert_eq!(
+        test_runner.test_runner.get_component_balance(
+            test_runner.account_component_address,
+            test_runner.pool_unit_resource_address
+        ),
+        dec!("100")
+    );
+}
+
+#[test]
+fn contribution_after_donation_mints_pool_units_at_the_accrual_index() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_interest_bearing(18);
+    test_runner.contribute(100, true).expect_commit_success();
+    test_runner.donate(100, false).expect_commit_success();
+
+    // Act
+    let receipt = test_runner.contribute(50, true);
+
+    // Assert
+    let ContributionEvent { pool_units_minted, .. } = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find_map(|(event_type_identifier, event_data)| {
+            if test_runner.test_runner.event_name(event_type_identifier) == "ContributionEvent"
+                && is_pool_emitter(event_type_identifier)
+            {
+                Some(scrypto_decode(event_data).unwrap())
+            } else {
+                None
+            }
+        })
+        .unwrap();
+    assert_eq!(pool_units_minted, dec!("25"));
+    assert_eq!(
+        test_runner.get_accrual(),
+        Some(PoolAccrual {
+            accrual_index: pdec!("2"),
+            principal: dec!("150"),
+        })
+    );
+}
+
+#[test]
+fn redemption_from_interest_bearing_pool_separates_principal_from_yield() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_interest_bearing(18);
+    test_runner.contribute(100, true).expect_commit_success();
+    test_runner.donate(20, false).expect_commit_success();
+
+    // Act
+    let receipt = test_runner.redeem(50, true);
+
+    // Assert
+    let YieldRedemptionEvent {
+        principal_redeemed,
+        yield_redeemed,
+    } = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find_map(|(event_type_identifier, event_data)| {
+            if test_runner.test_runner.event_name(event_type_identifier) == "YieldRedemptionEvent"
+                && is_pool_emitter(event_type_identifier)
+            {
+                Some(scrypto_decode(event_data).unwrap())
+            } else {
+                None
+            }
+        })
+        .unwrap();
+    assert_eq!(principal_redeemed, dec!("50"));
+    assert_eq!(yield_redeemed, dec!("10"));
+    assert_eq!(
+        test_runner.get_accrual(),
+        Some(PoolAccrual {
+            accrual_index: pdec!("1.2"),
+            principal: dec!("50"),
+        })
+    );
+}
+
+#[test]
+fn redemption_from_interest_bearing_pool_fails_if_reserves_were_withdrawn() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_interest_bearing(18);
+    test_runner.contribute(100, true).expect_commit_success();
+    test_runner
+        .protected_withdraw(60, WithdrawStrategy::Exact, true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.redeem(100, true);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        error
+            == &RuntimeError::ApplicationError(ApplicationError::OneResourcePoolError(
+                OneResourcePoolError::InsufficientReservesForRedemption {
+                    required: dec!("100"),
+                    available: dec!("40"),
+                },
+            ))
+    });
+}
+
+#[test]
+fn donation_to_pool_that_is_not_interest_bearing_is_shared_by_pool_unit_holders() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new(18);
+    test_runner.contribute(100, true).expect_commit_success();
+
+    // Act
+    let receipt = test_runner.donate(50, false);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_runner.get_redemption_value(100, true), dec!("150"));
+}
+
+#[test]
+fn donation_fails_when_there_are_no_pool_units_in_circulation() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_interest_bearing(18);
+
+    // Act
+    let receipt = test_runner.donate(50, false);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        error
+            == &RuntimeError::ApplicationError(ApplicationError::OneResourcePoolError(
+                OneResourcePoolError::DonationWithNoPoolUnitsInCirculation,
+            ))
+    });
+}
+
 fn is_pool_emitter(event_type_identifier: &EventTypeIdentifier) -> bool {
     match event_type_identifier.0 {
         Emitter::Method(node_id, ModuleId::Main) => match node_id.entity_type() {
@@ -717,6 +904,18 @@ impl TestEnvironment {
     }
 
     fn new_with_owner(divisibility: u8, owner_role: OwnerRole) -> Self {
+        Self::new_internal(divisibility, owner_role, ONE_RESOURCE_POOL_INSTANTIATE_IDENT)
+    }
+
+    fn new_interest_bearing(divisibility: u8) -> Self {
+        Self::new_internal(
+            divisibility,
+            OwnerRole::None,
+            ONE_RESOURCE_POOL_INSTANTIATE_INTEREST_BEARING_IDENT,
+        )
+    }
+
+    fn new_internal(divisibility: u8, owner_role: OwnerRole, instantiate_ident: &str) -> Self {
         let mut test_runner = TestRunnerBuilder::new().without_trace().build();
         let (public_key, _, account) = test_runner.new_account(false);
         let virtual_signature_badge = NonFungibleGlobalId::from_public_key(&public_key);
@@ -733,7 +932,7 @@ impl TestEnvironment {
                 .call_function(
                     POOL_PACKAGE,
                     ONE_RESOURCE_POOL_BLUEPRINT_IDENT,
-                    ONE_RESOURCE_POOL_INSTANTIATE_IDENT,
+                    instantiate_ident,
                     OneResourcePoolInstantiateManifestInput {
                         resource_address,
                         pool_manager_rule: rule!(require(virtual_signature_badge)),
@@ -765,15 +964,7 @@ impl TestEnvironment {
         let manifest = ManifestBuilder::new()
             .mint_fungible(self.resource_address, amount.into())
             .take_all_from_worktop(self.resource_address, "contribution")
-            .with_name_lookup(|builder, lookup| {
-                builder.call_method(
-                    self.pool_component_address,
-                    ONE_RESOURCE_POOL_CONTRIBUTE_IDENT,
-                    OneResourcePoolContributeManifestInput {
-                        bucket: lookup.bucket("contribution"),
-                    },
-                )
-            })
+            .contribute_to_one_resource_pool(self.pool_component_address, "contribution")
             .try_deposit_entire_worktop_or_abort(self.account_component_address, None)
             .build();
         self.execute_manifest(manifest, sign)
@@ -787,15 +978,7 @@ impl TestEnvironment {
                 amount.into(),
             )
             .take_all_from_worktop(self.pool_unit_resource_address, "pool_unit")
-            .with_name_lookup(|builder, lookup| {
-                builder.call_method(
-                    self.pool_component_address,
-                    ONE_RESOURCE_POOL_REDEEM_IDENT,
-                    OneResourcePoolRedeemManifestInput {
-                        bucket: lookup.bucket("pool_unit"),
-                    },
-                )
-            })
+            .redeem_from_one_resource_pool(self.pool_component_address, "pool_unit")
             .try_deposit_entire_worktop_or_abort(self.account_component_address, None)
             .build();
         self.execute_manifest(manifest, sign)
@@ -864,6 +1047,35 @@ impl TestEnvironment {
         self.execute_manifest(manifest, sign)
     }
 
+    fn donate<D: Into<Decimal>>(&mut self, amount: D, sign: bool) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .mint_fungible(self.resource_address, amount.into())
+            .take_all_from_worktop(self.resource_address, "donation")
+            .with_name_lookup(|builder, lookup| {
+                builder.call_method(
+                    self.pool_component_address,
+                    ONE_RESOURCE_POOL_DONATE_IDENT,
+                    OneResourcePoolDonateManifestInput {
+                        bucket: lookup.bucket("donation"),
+                    },
+                )
+            })
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
+    fn get_accrual(&mut self) -> Option<PoolAccrual> {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.pool_component_address,
+                ONE_RESOURCE_POOL_GET_ACCRUAL_IDENT,
+                OneResourcePoolGetAccrualManifestInput,
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest, false);
+        receipt.expect_commit_success().output(1)
+    }
+
     fn get_vault_amount(&mut self, sign: bool) -> Decimal {
         let manifest = ManifestBuilder::new()
             .call_method(
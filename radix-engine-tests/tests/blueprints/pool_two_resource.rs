@@ -1106,17 +1106,10 @@ impl TestEnvironment {
             .mint_fungible(resource_address2, amount2.into())
             .take_all_from_worktop(resource_address1, "resource_1")
             .take_all_from_worktop(resource_address2, "resource_2")
-            .with_name_lookup(|builder, lookup| {
-                let bucket1 = lookup.bucket("resource_1");
-                let bucket2 = lookup.bucket("resource_2");
-                builder.call_method(
-                    self.pool_component_address,
-                    TWO_RESOURCE_POOL_CONTRIBUTE_IDENT,
-                    TwoResourcePoolContributeManifestInput {
-                        buckets: (bucket1, bucket2),
-                    },
-                )
-            })
+            .contribute_to_two_resource_pool(
+                self.pool_component_address,
+                ("resource_1", "resource_2"),
+            )
             .try_deposit_entire_worktop_or_abort(self.account_component_address, None)
             .build();
         self.execute_manifest(manifest, sign)
@@ -1130,14 +1123,7 @@ impl TestEnvironment {
                 amount.into(),
             )
             .take_all_from_worktop(self.pool_unit_resource_address, "pool_units")
-            .with_name_lookup(|builder, lookup| {
-                let bucket = lookup.bucket("pool_units");
-                builder.call_method(
-                    self.pool_component_address,
-                    TWO_RESOURCE_POOL_REDEEM_IDENT,
-                    TwoResourcePoolRedeemManifestInput { bucket },
-                )
-            })
+            .redeem_from_two_resource_pool(self.pool_component_address, "pool_units")
             .try_deposit_entire_worktop_or_abort(self.account_component_address, None)
             .build();
         self.execute_manifest(manifest, sign)
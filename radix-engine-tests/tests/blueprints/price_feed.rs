@@ -0,0 +1,267 @@
+use radix_engine::blueprints::price_feed::PriceFeedError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::oracle::*;
+use radix_engine_tests::common::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+const MAX_PRICE_AGE_SECONDS: i64 = 60;
+
+#[test]
+fn published_price_can_be_read_from_price_feed() {
+    // Arrange
+    let mut env = PriceFeedTestEnvironment::new();
+    env.test_runner
+        .advance_to_round_at_timestamp(Round::of(1), 1_000_000);
+
+    // Act
+    let receipt = env.publish_price(env.base, env.quote, dec!("12.5"), true);
+    receipt.expect_commit_success();
+    let receipt = env.get_price(env.base, env.quote);
+
+    // Assert
+    let (price, published_at): OracleGetPriceOutput = receipt.expect_commit_success().output(1);
+    assert_eq!(price, dec!("12.5"));
+    assert_eq!(published_at, Instant::new(1_000));
+}
+
+#[test]
+fn price_cannot_be_published_without_publisher_badge() {
+    // Arrange
+    let mut env = PriceFeedTestEnvironment::new();
+
+    // Act
+    let receipt = env.publish_price(env.base, env.quote, dec!("12.5"), false);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn non_positive_price_cannot_be_published() {
+    // Arrange
+    let mut env = PriceFeedTestEnvironment::new();
+
+    // Act
+    let receipt = env.publish_price(env.base, env.quote, Decimal::ZERO, true);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PriceFeedError(
+                PriceFeedError::InvalidPrice { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn reading_price_of_unknown_pair_fails() {
+    // Arrange
+    let mut env = PriceFeedTestEnvironment::new();
+    env.publish_price(env.base, env.quote, dec!("12.5"), true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = env.get_price(env.quote, env.base);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PriceFeedError(
+                PriceFeedError::PriceNotAvailable { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn stale_price_is_not_returned_by_price_feed() {
+    // Arrange
+    let mut env = PriceFeedTestEnvironment::new();
+    env.test_runner
+        .advance_to_round_at_timestamp(Round::of(1), 1_000_000);
+    env.publish_price(env.base, env.quote, dec!("12.5"), true)
+        .expect_commit_success();
+
+    // Act
+    env.test_runner.advance_to_round_at_timestamp(
+        Round::of(2),
+        1_000_000 + MAX_PRICE_AGE_SECONDS * 1_000,
+    );
+    let fresh_receipt = env.get_price(env.base, env.quote);
+    env.test_runner.advance_to_round_at_timestamp(
+        Round::of(3),
+        1_000_000 + (MAX_PRICE_AGE_SECONDS + 1) * 1_000,
+    );
+    let stale_receipt = env.get_price(env.base, env.quote);
+
+    // Assert
+    fresh_receipt.expect_commit_success();
+    stale_receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PriceFeedError(
+                PriceFeedError::StalePrice { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn oracle_consumer_can_use_price_feed_or_mock_oracle() {
+    // Arrange
+    let mut env = PriceFeedTestEnvironment::new();
+    env.publish_price(env.base, env.quote, dec!("12.5"), true)
+        .expect_commit_success();
+    let package_address = env
+        .test_runner
+        .publish_package_simple(PackageLoader::get("oracle"));
+    let mock_oracle = {
+        let receipt = env.test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(package_address, "MockOracle", "new", manifest_args!())
+                .build(),
+            vec![],
+        );
+        receipt.expect_commit_success().new_component_addresses()[0]
+    };
+    env.test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(
+                    mock_oracle,
+                    "set_price",
+                    manifest_args!(env.base, env.quote, dec!("3")),
+                )
+                .build(),
+            vec![],
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = env.test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "OracleConsumer",
+                "value_of",
+                manifest_args!(env.price_feed, env.base, env.quote, dec!("2")),
+            )
+            .call_function(
+                package_address,
+                "OracleConsumer",
+                "value_of",
+                manifest_args!(mock_oracle, env.base, env.quote, dec!("2")),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(result.output::<Decimal>(1), dec!("25"));
+    assert_eq!(result.output::<Decimal>(2), dec!("6"));
+}
+
+struct PriceFeedTestEnvironment {
+    test_runner: DefaultTestRunner,
+    price_feed: ComponentAddress,
+    publisher_account: ComponentAddress,
+    publisher_public_key: Secp256k1PublicKey,
+    publisher_badge: ResourceAddress,
+    base: ResourceAddress,
+    quote: ResourceAddress,
+}
+
+impl PriceFeedTestEnvironment {
+    fn new() -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (publisher_public_key, _, publisher_account) = test_runner.new_allocated_account();
+        let base = test_runner.create_fungible_resource(dec!("100"), 18, publisher_account);
+        let quote = test_runner.create_fungible_resource(dec!("100"), 18, publisher_account);
+
+        let receipt = test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(
+                    PRICE_FEED_PACKAGE,
+                    PRICE_FEED_BLUEPRINT,
+                    PRICE_FEED_INSTANTIATE_IDENT,
+                    PriceFeedInstantiateManifestInput {
+                        owner_role: OwnerRole::None,
+                        max_price_age_seconds: MAX_PRICE_AGE_SECONDS,
+                        publisher_badge_amount: dec!("1"),
+                        address_reservation: None,
+                    },
+                )
+                .try_deposit_entire_worktop_or_abort(publisher_account, None)
+                .build(),
+            vec![],
+        );
+        let result = receipt.expect_commit_success();
+        let price_feed = result.new_component_addresses()[0];
+        let publisher_badge = result.new_resource_addresses()[0];
+
+        Self {
+            test_runner,
+            price_feed,
+            publisher_account,
+            publisher_public_key,
+            publisher_badge,
+            base,
+            quote,
+        }
+    }
+
+    fn publish_price(
+        &mut self,
+        base: ResourceAddress,
+        quote: ResourceAddress,
+        price: Decimal,
+        with_badge: bool,
+    ) -> TransactionReceipt {
+        let mut manifest_builder = ManifestBuilder::new().lock_fee_from_faucet();
+        if with_badge {
+            manifest_builder = manifest_builder.create_proof_from_account_of_amount(
+                self.publisher_account,
+                self.publisher_badge,
+                dec!("1"),
+            );
+        }
+        let manifest = manifest_builder
+            .call_method(
+                self.price_feed,
+                PRICE_FEED_PUBLISH_PRICE_IDENT,
+                PriceFeedPublishPriceManifestInput { base, quote, price },
+            )
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &self.publisher_public_key,
+            )],
+        )
+    }
+
+    fn get_price(&mut self, base: ResourceAddress, quote: ResourceAddress) -> TransactionReceipt {
+        self.test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(
+                    self.price_feed,
+                    ORACLE_GET_PRICE_IDENT,
+                    PriceFeedGetPriceManifestInput { base, quote },
+                )
+                .build(),
+            vec![],
+        )
+    }
+}
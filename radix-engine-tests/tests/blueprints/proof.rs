@@ -171,6 +171,80 @@ fn can_use_bucket_for_authorization() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn can_use_scoped_bucket_proof_for_authorization() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (auth_resource_address, burnable_resource_address) =
+        test_runner.create_restricted_burn_token(account);
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("proof"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, auth_resource_address, 1)
+        .withdraw_from_account(account, burnable_resource_address, 1)
+        .take_all_from_worktop(auth_resource_address, "auth_bucket")
+        .take_all_from_worktop(burnable_resource_address, "burnable_bucket")
+        .with_name_lookup(|builder, lookup| {
+            let auth_bucket = lookup.bucket("auth_bucket");
+            let burnable_bucket = lookup.bucket("burnable_bucket");
+            builder.call_function(
+                package_address,
+                "BucketProof",
+                "use_scoped_bucket_proof_for_auth",
+                manifest_args!(auth_bucket, burnable_bucket),
+            )
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn scoped_bucket_proof_is_removed_from_auth_zone_after_closure() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (auth_resource_address, burnable_resource_address) =
+        test_runner.create_restricted_burn_token(account);
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("proof"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, auth_resource_address, 1)
+        .withdraw_from_account(account, burnable_resource_address, 1)
+        .take_all_from_worktop(auth_resource_address, "auth_bucket")
+        .take_all_from_worktop(burnable_resource_address, "burnable_bucket")
+        .with_name_lookup(|builder, lookup| {
+            let auth_bucket = lookup.bucket("auth_bucket");
+            let burnable_bucket = lookup.bucket("burnable_bucket");
+            builder.call_function(
+                package_address,
+                "BucketProof",
+                "burn_after_scoped_bucket_proof",
+                manifest_args!(auth_bucket, burnable_bucket),
+            )
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
 #[test]
 fn can_use_vault_for_authorization() {
     // Arrange
@@ -1,5 +1,5 @@
 use radix_engine_common::types::{Epoch, Round};
-use radix_engine_interface::blueprints::consensus_manager::{TimePrecision};
+use radix_engine_interface::blueprints::consensus_manager::{TimePrecision, TimeRoundingMode};
 use radix_engine_interface::time::UtcDateTime;
 use scrypto_unit::*;
 
@@ -52,3 +52,55 @@ fn advancing_round_changes_internal_milli_timestamp() {
     // Assert
     assert_eq!(test_runner.get_current_proposer_timestamp_ms(), epoch_milli);
 }
+
+#[test]
+fn rounded_precisions_round_milli_timestamp_in_each_mode() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(CustomGenesis::default(
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config(),
+        ))
+        .build();
+    let epoch_minute_in_seconds = UtcDateTime::new(2022, 1, 1, 0, 0, 0)
+        .unwrap()
+        .to_instant()
+        .seconds_since_unix_epoch;
+    let epoch_milli = (epoch_minute_in_seconds + 13) * 1000 + 637;
+
+    // Act
+    test_runner
+        .advance_to_round_at_timestamp(Round::of(1), epoch_milli)
+        .expect_commit_success();
+
+    // Assert
+    let mut seconds_since_unix_epoch = |precision| {
+        test_runner
+            .get_current_time(precision)
+            .seconds_since_unix_epoch
+    };
+    assert_eq!(
+        seconds_since_unix_epoch(TimePrecision::RoundedSecond(TimeRoundingMode::Down)),
+        epoch_minute_in_seconds + 13
+    );
+    assert_eq!(
+        seconds_since_unix_epoch(TimePrecision::RoundedSecond(TimeRoundingMode::Nearest)),
+        epoch_minute_in_seconds + 14
+    );
+    assert_eq!(
+        seconds_since_unix_epoch(TimePrecision::RoundedSecond(TimeRoundingMode::Up)),
+        epoch_minute_in_seconds + 14
+    );
+    assert_eq!(
+        seconds_since_unix_epoch(TimePrecision::RoundedMinute(TimeRoundingMode::Down)),
+        epoch_minute_in_seconds
+    );
+    assert_eq!(
+        seconds_since_unix_epoch(TimePrecision::RoundedMinute(TimeRoundingMode::Nearest)),
+        epoch_minute_in_seconds
+    );
+    assert_eq!(
+        seconds_since_unix_epoch(TimePrecision::RoundedMinute(TimeRoundingMode::Up)),
+        epoch_minute_in_seconds + 60
+    );
+}
@@ -1,3 +1,4 @@
+use radix_engine::blueprints::transaction_tracker::{TransactionStatusV1, EPOCHS_PER_PARTITION};
 use radix_engine::errors::RejectionReason;
 use radix_engine::track::{BatchPartitionStateUpdate, NodeStateUpdates, PartitionStateUpdates};
 use radix_engine::transaction::{CostingParameters, ExecutionConfig};
@@ -94,6 +95,55 @@ fn test_transaction_replay_protection() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn test_intent_status_is_queryable_until_expiry() {
+    let init_epoch = Epoch::of(1);
+    let rounds_per_epoch = 5;
+    let genesis = CustomGenesis::default(
+        init_epoch,
+        CustomGenesis::default_consensus_manager_config().with_epoch_change_condition(
+            EpochChangeCondition {
+                min_round_count: rounds_per_epoch,
+                max_round_count: rounds_per_epoch,
+                target_duration_millis: 1000,
+            },
+        ),
+    );
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(genesis)
+        .build();
+
+    // 1. Run a notarized transaction
+    let transaction = create_notarized_transaction(TransactionParams {
+        start_epoch_inclusive: init_epoch,
+        end_epoch_exclusive: init_epoch.after(10).unwrap(),
+    });
+    let intent_hash = transaction.prepare().unwrap().intent_hash();
+    assert_eq!(test_runner.get_intent_status(&intent_hash), None);
+    let validated = get_validated(&transaction).unwrap();
+    let receipt = test_runner.execute_transaction(
+        validated.get_executable(),
+        CostingParameters::default(),
+        ExecutionConfig::for_notarized_transaction(NetworkDefinition::simulator()),
+    );
+    receipt.expect_commit_success();
+
+    // 2. The intent is tracked as committed
+    assert_eq!(
+        test_runner.get_intent_status(&intent_hash),
+        Some(TransactionStatusV1::CommittedSuccess)
+    );
+
+    // 3. Advance past the epochs covered by the first partition
+    test_runner.set_current_epoch(init_epoch.after(EPOCHS_PER_PARTITION - 1).unwrap());
+    test_runner
+        .advance_to_round(Round::of(rounds_per_epoch))
+        .expect_commit_success();
+
+    // 4. The intent has expired and is no longer tracked
+    assert_eq!(test_runner.get_intent_status(&intent_hash), None);
+}
+
 fn get_validated(
     transaction: &NotarizedTransactionV1,
 ) -> Result<ValidatedNotarizedTransactionV1, TransactionValidationError> {
@@ -15,6 +15,7 @@ pub struct ManifestTransactionProcessorRunInput {
     pub global_address_reservations: Vec<()>,
     pub references: Vec<()>,
     pub blobs: IndexMap<Hash, Vec<u8>>,
+    pub worktop_leftovers_recipient: Option<ComponentAddress>,
 }
 
 #[test]
@@ -36,6 +37,7 @@ fn should_not_be_able_to_call_tx_processor_in_tx_processor() {
                 global_address_reservations: vec![],
                 references: vec![],
                 blobs: index_map_new(),
+                worktop_leftovers_recipient: None,
             },
         )
         .build();
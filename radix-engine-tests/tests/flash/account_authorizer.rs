@@ -0,0 +1,76 @@
+use radix_engine::blueprints::account::AccountField;
+use radix_engine::protocol_updates::*;
+use radix_engine_interface::blueprints::account::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn withdraw_from_account_predating_authorizer_without_protocol_update_should_succeed() {
+    run_flash_test(false, false, true);
+}
+
+#[test]
+fn set_authorizer_of_account_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, true, false);
+}
+
+#[test]
+fn set_authorizer_of_account_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, set_authorizer: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    // Simulate an Account package published, and an account created, before the authorizer was
+    // introduced
+    test_runner.update_blueprint_definition(&ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT, |definition| {
+        for function in [ACCOUNT_SET_AUTHORIZER_IDENT, ACCOUNT_GET_AUTHORIZER_IDENT] {
+            definition.interface.functions.swap_remove(function);
+            definition.function_exports.swap_remove(function);
+        }
+        definition.interface.state.fields.as_mut().unwrap().1.pop();
+    });
+    let state_updates = StateUpdates {
+        by_node: indexmap!(
+            account.into_node_id() => NodeStateUpdates::Delta {
+                by_partition: indexmap! {
+                    MAIN_BASE_PARTITION => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            SubstateKey::Field(AccountField::Authorizer.field_index()) => DatabaseUpdate::Delete
+                        }
+                    },
+                }
+            }
+        ),
+    };
+    test_runner
+        .substate_db_mut()
+        .commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let mut manifest_builder = ManifestBuilder::new().lock_fee_from_faucet();
+    if set_authorizer {
+        manifest_builder = manifest_builder.set_account_authorizer(account, None);
+    }
+    let manifest = manifest_builder
+        .withdraw_from_account(account, XRD, 1)
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
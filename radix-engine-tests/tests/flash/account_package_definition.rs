@@ -0,0 +1,55 @@
+use radix_engine::protocol_updates::*;
+use radix_engine_interface::blueprints::account::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn get_default_deposit_rule_of_account_package_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn get_default_deposit_rule_of_account_package_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Simulate an Account package published before the deposit configuration getters were
+    // introduced
+    test_runner.update_blueprint_definition(&ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT, |definition| {
+        for function in [
+            ACCOUNT_GET_DEFAULT_DEPOSIT_RULE_IDENT,
+            ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT,
+            ACCOUNT_IS_AUTHORIZED_DEPOSITOR_IDENT,
+        ] {
+            definition.interface.functions.swap_remove(function);
+            definition.function_exports.swap_remove(function);
+        }
+    });
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            account,
+            ACCOUNT_GET_DEFAULT_DEPOSIT_RULE_IDENT,
+            AccountGetDefaultDepositRuleInput {},
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        let rule: DefaultDepositRule = receipt.expect_commit_success().output(1);
+        assert_eq!(rule, DefaultDepositRule::Accept);
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
@@ -0,0 +1,69 @@
+use radix_engine::protocol_updates::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn execute_deferred_manifest_on_ledger_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn execute_deferred_manifest_on_ledger_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    // Simulate a ledger bootstrapped before the Deferred Execution Queue was added to genesis
+    test_runner.remove_blueprint_definition(
+        &TRANSACTION_PROCESSOR_PACKAGE,
+        DEFERRED_EXECUTION_QUEUE_BLUEPRINT,
+    );
+    test_runner.delete_node(DEFERRED_EXECUTION_QUEUE.as_node_id());
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let execute_at_epoch = test_runner.get_current_epoch().after(1).unwrap();
+    let expiry_epoch = test_runner.get_current_epoch().after(10).unwrap();
+    let deferred = ManifestBuilder::new()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!("10"))
+        .take_from_worktop(XRD, dec!("10"), "payment")
+        .enqueue_deferred_manifest(
+            deferred,
+            execute_at_epoch,
+            expiry_epoch,
+            "payment",
+            rule!(allow_all),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    if expect_success {
+        let intent_id: u64 = receipt.expect_commit_success().output(3);
+        test_runner.set_current_epoch(execute_at_epoch);
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .execute_deferred_manifest(intent_id)
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+        test_runner
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success();
+    } else {
+        receipt.expect_not_success();
+    }
+}
@@ -0,0 +1,67 @@
+use radix_engine::blueprints::resource::FungibleResourceManagerFeature;
+use radix_engine::protocol_updates::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn is_holder_denied_of_resource_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn is_holder_denied_of_resource_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(10u32.into(), 0u8, account);
+
+    // Simulate a Resource package published, and a resource created, before deny lists were
+    // introduced
+    test_runner.update_blueprint_definition(
+        &RESOURCE_PACKAGE,
+        FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+        |definition| {
+            for function in [
+                RESOURCE_MANAGER_DENY_HOLDER_IDENT,
+                RESOURCE_MANAGER_ALLOW_HOLDER_IDENT,
+                RESOURCE_MANAGER_IS_HOLDER_DENIED_IDENT,
+            ] {
+                definition.interface.functions.swap_remove(function);
+                definition.function_exports.swap_remove(function);
+            }
+            definition
+                .interface
+                .feature_set
+                .swap_remove(FungibleResourceManagerFeature::DenyList.feature_name());
+            let state = &mut definition.interface.state;
+            state.collections.pop();
+            state.num_logical_partitions -= 1;
+        },
+    );
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            resource_address,
+            RESOURCE_MANAGER_IS_HOLDER_DENIED_IDENT,
+            manifest_args!(GlobalAddress::from(account)),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        let is_denied: bool = receipt.expect_commit_success().output(1);
+        assert!(!is_denied);
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
@@ -0,0 +1,79 @@
+use radix_engine::protocol_updates::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn epoch_change_with_consensus_manager_predating_hooks_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn epoch_change_with_consensus_manager_predating_hooks_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(CustomGenesis::default(
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config().with_epoch_change_condition(
+                EpochChangeCondition {
+                    min_round_count: 1,
+                    max_round_count: 1,
+                    target_duration_millis: 0,
+                },
+            ),
+        ))
+        .build();
+
+    // Simulate a Consensus Manager package published, and a Consensus Manager created, before the
+    // epoch change hooks were introduced
+    test_runner.update_blueprint_definition(
+        &CONSENSUS_MANAGER_PACKAGE,
+        CONSENSUS_MANAGER_BLUEPRINT,
+        |definition| {
+            for function in [
+                CONSENSUS_MANAGER_REGISTER_EPOCH_CHANGE_HOOK_IDENT,
+                CONSENSUS_MANAGER_DEREGISTER_EPOCH_CHANGE_HOOK_IDENT,
+                CONSENSUS_MANAGER_INVOKE_EPOCH_CHANGE_HOOK_IDENT,
+            ] {
+                definition.interface.functions.swap_remove(function);
+                definition.function_exports.swap_remove(function);
+            }
+            let state = &mut definition.interface.state;
+            state.fields.as_mut().unwrap().1.pop();
+            state.collections.pop();
+            state.num_logical_partitions -= 1;
+        },
+    );
+    let state_updates = StateUpdates {
+        by_node: indexmap!(
+            CONSENSUS_MANAGER.into_node_id() => NodeStateUpdates::Delta {
+                by_partition: indexmap! {
+                    MAIN_BASE_PARTITION => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            SubstateKey::Field(ConsensusManagerField::EpochChangeHooks.field_index()) => DatabaseUpdate::Delete
+                        }
+                    },
+                }
+            }
+        ),
+    };
+    test_runner
+        .substate_db_mut()
+        .commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let receipt = test_runner.advance_to_round(Round::of(1));
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
@@ -0,0 +1,68 @@
+use radix_engine::protocol_updates::*;
+use radix_engine_tests::common::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn lock_fee_with_refund_of_vault_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn lock_fee_with_refund_of_vault_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("fee"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 1000)
+        .take_all_from_worktop(XRD, "bucket")
+        .with_bucket("bucket", |builder, bucket| {
+            builder.call_function(package_address, "Fee", "new", manifest_args!(bucket))
+        })
+        .build();
+    let component_address = test_runner
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    // Simulate a Resource package published, and a vault created, before fee refunds to other
+    // vaults were introduced
+    test_runner.update_blueprint_definition(
+        &RESOURCE_PACKAGE,
+        FUNGIBLE_VAULT_BLUEPRINT,
+        |definition| {
+            let function = FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT;
+            definition.interface.functions.swap_remove(function);
+            definition.function_exports.swap_remove(function);
+        },
+    );
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            component_address,
+            "lock_fee_with_refund_to_empty_vault",
+            manifest_args!(dec!(500)),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_rejection();
+    }
+}
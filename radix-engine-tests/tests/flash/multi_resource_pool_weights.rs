@@ -0,0 +1,86 @@
+use radix_engine::blueprints::pool::v1::constants::*;
+use radix_engine::protocol_updates::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn get_resource_weights_of_pool_predating_them_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn get_resource_weights_of_pool_predating_them_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let fungible1 = test_runner.create_fungible_resource(dec!(200), 18, account);
+    let fungible2 = test_runner.create_fungible_resource(dec!(200), 18, account);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            POOL_PACKAGE,
+            MULTI_RESOURCE_POOL_BLUEPRINT_IDENT,
+            MULTI_RESOURCE_POOL_INSTANTIATE_IDENT,
+            MultiResourcePoolInstantiateManifestInput {
+                owner_role: OwnerRole::None,
+                pool_manager_rule: rule!(allow_all),
+                resource_addresses: indexset! {fungible1, fungible2},
+                address_reservation: None,
+            },
+        )
+        .build();
+    let pool_address = test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    // Simulate a Pool package published, and a pool created, before resources could be weighted
+    test_runner.update_blueprint_definition(
+        &POOL_PACKAGE,
+        MULTI_RESOURCE_POOL_BLUEPRINT_IDENT,
+        |definition| {
+            for function in [
+                MULTI_RESOURCE_POOL_ADD_RESOURCE_IDENT,
+                MULTI_RESOURCE_POOL_RETIRE_RESOURCE_IDENT,
+                MULTI_RESOURCE_POOL_SET_RESOURCE_WEIGHT_IDENT,
+                MULTI_RESOURCE_POOL_GET_RESOURCE_WEIGHTS_IDENT,
+            ] {
+                definition.interface.functions.swap_remove(function);
+                definition.function_exports.swap_remove(function);
+            }
+            let state = &mut definition.interface.state;
+            state.collections.pop();
+            state.num_logical_partitions -= 1;
+        },
+    );
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            pool_address,
+            MULTI_RESOURCE_POOL_GET_RESOURCE_WEIGHTS_IDENT,
+            MultiResourcePoolGetResourceWeightsManifestInput,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        // Resources of pools created before weights were introduced keep their full share
+        let weights: IndexMap<ResourceAddress, Decimal> = receipt.expect_commit_success().output(1);
+        assert_eq!(
+            weights,
+            indexmap! {fungible1 => Decimal::ONE, fungible2 => Decimal::ONE}
+        );
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
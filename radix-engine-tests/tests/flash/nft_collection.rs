@@ -0,0 +1,60 @@
+use radix_engine::protocol_updates::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn get_total_minted_of_resource_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn get_total_minted_of_resource_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+
+    // Simulate a Resource package published, and a resource created, before NFT collections were
+    // introduced
+    test_runner.update_blueprint_definition(
+        &RESOURCE_PACKAGE,
+        NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+        |definition| {
+            for function in [
+                NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_COLLECTION_IDENT,
+                NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_MINTED_IDENT,
+                NON_FUNGIBLE_RESOURCE_MANAGER_GET_MINTED_IDS_IDENT,
+            ] {
+                definition.interface.functions.swap_remove(function);
+                definition.function_exports.swap_remove(function);
+            }
+        },
+    );
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            resource_address,
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_MINTED_IDENT,
+            manifest_args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        // The collection isn't tracked for resources created before it was introduced
+        let total_minted: Option<u64> = receipt.expect_commit_success().output(1);
+        assert_eq!(total_minted, None);
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
@@ -0,0 +1,81 @@
+use radix_engine::blueprints::resource::NonFungibleResourceManagerField;
+use radix_engine::protocol_updates::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn reserve_integer_ids_of_resource_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn reserve_integer_ids_of_resource_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_freely_mintable_and_burnable_non_fungible_resource(
+        OwnerRole::None,
+        NonFungibleIdType::Integer,
+        Some(vec![
+            (NonFungibleLocalId::integer(1), EmptyNonFungibleData {}),
+            (NonFungibleLocalId::integer(2), EmptyNonFungibleData {}),
+            (NonFungibleLocalId::integer(3), EmptyNonFungibleData {}),
+        ]),
+        account,
+    );
+
+    // Simulate a Resource package published, and a resource created, before integer ids could be
+    // reserved
+    test_runner.update_blueprint_definition(
+        &RESOURCE_PACKAGE,
+        NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+        |definition| {
+            for function in [
+                NON_FUNGIBLE_RESOURCE_MANAGER_RESERVE_INTEGER_IDS_IDENT,
+                NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RESERVED_IDENT,
+            ] {
+                definition.interface.functions.swap_remove(function);
+                definition.function_exports.swap_remove(function);
+            }
+        },
+    );
+    let state_updates = StateUpdates {
+        by_node: indexmap!(
+            resource_address.into_node_id() => NodeStateUpdates::Delta {
+                by_partition: indexmap! {
+                    MAIN_BASE_PARTITION => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            SubstateKey::Field(NonFungibleResourceManagerField::NextReservableId.field_index()) => DatabaseUpdate::Delete
+                        }
+                    },
+                }
+            }
+        ),
+    };
+    test_runner
+        .substate_db_mut()
+        .commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .reserve_integer_ids(resource_address, 2)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        // Reservations of resources created before they were introduced skip the ids minted so far
+        let reservation: NonFungibleIdReservation = receipt.expect_commit_success().output(1);
+        assert_eq!(reservation, NonFungibleIdReservation { start: 4, end: 6 });
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
@@ -0,0 +1,85 @@
+use radix_engine::blueprints::pool::v1::constants::*;
+use radix_engine::blueprints::pool::v1::substates::one_resource_pool::OneResourcePoolFeature;
+use radix_engine::protocol_updates::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn get_accrual_of_pool_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn get_accrual_of_pool_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let fungible = test_runner.create_fungible_resource(dec!(200), 18, account);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            POOL_PACKAGE,
+            ONE_RESOURCE_POOL_BLUEPRINT_IDENT,
+            ONE_RESOURCE_POOL_INSTANTIATE_IDENT,
+            OneResourcePoolInstantiateManifestInput {
+                owner_role: OwnerRole::None,
+                pool_manager_rule: rule!(allow_all),
+                resource_address: fungible,
+                address_reservation: None,
+            },
+        )
+        .build();
+    let pool_address = test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    // Simulate a Pool package published, and a pool created, before interest-bearing pools were
+    // introduced
+    test_runner.update_blueprint_definition(
+        &POOL_PACKAGE,
+        ONE_RESOURCE_POOL_BLUEPRINT_IDENT,
+        |definition| {
+            for function in [
+                ONE_RESOURCE_POOL_INSTANTIATE_INTEREST_BEARING_IDENT,
+                ONE_RESOURCE_POOL_DONATE_IDENT,
+                ONE_RESOURCE_POOL_GET_ACCRUAL_IDENT,
+            ] {
+                definition.interface.functions.swap_remove(function);
+                definition.function_exports.swap_remove(function);
+            }
+            definition
+                .interface
+                .feature_set
+                .swap_remove(OneResourcePoolFeature::InterestBearing.feature_name());
+            definition.interface.state.fields.as_mut().unwrap().1.pop();
+        },
+    );
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            pool_address,
+            ONE_RESOURCE_POOL_GET_ACCRUAL_IDENT,
+            OneResourcePoolGetAccrualManifestInput,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        // Pools created before interest-bearing pools were introduced aren't interest-bearing
+        let accrual: Option<PoolAccrual> = receipt.expect_commit_success().output(1);
+        assert_eq!(accrual, None);
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
@@ -0,0 +1,36 @@
+use radix_engine::protocol_updates::*;
+use radix_engine::system::system_db_reader::SystemDatabaseReader;
+use radix_engine_interface::blueprints::order_book::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn order_book_blueprint_on_ledger_predating_it_without_protocol_update_should_not_exist() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn order_book_blueprint_on_ledger_predating_it_with_protocol_update_should_exist() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_exists: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Simulate a ledger bootstrapped before the Order Book package was added to genesis
+    test_runner.delete_node(ORDER_BOOK_PACKAGE.as_node_id());
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+
+    // Assert
+    // Order books are owned by the components using them, so the blueprint is only checked to be
+    // available on ledger
+    let reader = SystemDatabaseReader::new(test_runner.substate_db());
+    let definition = reader
+        .get_blueprint_definition(&BlueprintId::new(&ORDER_BOOK_PACKAGE, ORDER_BOOK_BLUEPRINT));
+    assert_eq!(definition.is_ok(), expect_exists);
+}
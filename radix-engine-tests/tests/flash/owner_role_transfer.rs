@@ -0,0 +1,57 @@
+use radix_engine::protocol_updates::*;
+use radix_engine_interface::api::node_modules::auth::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn transfer_owner_role_of_component_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn transfer_owner_role_of_component_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let account = test_runner.new_account_advanced(OwnerRole::Updatable(rule!(allow_all)));
+
+    // Simulate a Role Assignment module package published, and a component created, before owner
+    // role transfers were introduced
+    test_runner.update_blueprint_definition(
+        &ROLE_ASSIGNMENT_MODULE_PACKAGE,
+        ROLE_ASSIGNMENT_BLUEPRINT,
+        |definition| {
+            for function in [
+                ROLE_ASSIGNMENT_PROPOSE_OWNER_IDENT,
+                ROLE_ASSIGNMENT_ACCEPT_OWNER_IDENT,
+            ] {
+                definition.interface.functions.swap_remove(function);
+                definition.function_exports.swap_remove(function);
+            }
+            let state = &mut definition.interface.state;
+            state.collections.pop();
+            state.num_logical_partitions -= 1;
+        },
+    );
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .propose_owner_role(account, rule!(allow_all))
+        .accept_owner_role(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
@@ -0,0 +1,58 @@
+use radix_engine::protocol_updates::*;
+use radix_engine_tests::common::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn add_royalty_exemption_of_package_package_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn add_royalty_exemption_of_package_package_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.publish_package_with_owner(
+        PackageLoader::get("royalty"),
+        NonFungibleGlobalId::from_public_key(&public_key),
+    );
+
+    // Simulate a Package package published before royalty exemptions were introduced
+    test_runner.update_blueprint_definition(&PACKAGE_PACKAGE, PACKAGE_BLUEPRINT, |definition| {
+        for function in [
+            PACKAGE_ADD_ROYALTY_EXEMPTION_IDENT,
+            PACKAGE_REMOVE_ROYALTY_EXEMPTION_IDENT,
+        ] {
+            definition.interface.functions.swap_remove(function);
+            definition.function_exports.swap_remove(function);
+        }
+        let state = &mut definition.interface.state;
+        state.collections.pop();
+        state.num_logical_partitions -= 1;
+    });
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .add_package_royalty_exemption(package_address, account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
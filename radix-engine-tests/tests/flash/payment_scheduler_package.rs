@@ -0,0 +1,49 @@
+use radix_engine::protocol_updates::*;
+use radix_engine_interface::blueprints::payment_scheduler::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn instantiate_payment_scheduler_on_ledger_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn instantiate_payment_scheduler_on_ledger_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Simulate a ledger bootstrapped before the Payment Scheduler package was added to genesis
+    test_runner.delete_node(PAYMENT_SCHEDULER_PACKAGE.as_node_id());
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            PAYMENT_SCHEDULER_PACKAGE,
+            PAYMENT_SCHEDULER_BLUEPRINT,
+            PAYMENT_SCHEDULER_INSTANTIATE_IDENT,
+            PaymentSchedulerInstantiateManifestInput {
+                owner_role: OwnerRole::None,
+                resource_address: XRD,
+                incentive_per_payment: dec!("1"),
+                address_reservation: None,
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_not_success();
+    }
+}
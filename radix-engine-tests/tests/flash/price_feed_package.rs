@@ -0,0 +1,51 @@
+use radix_engine::protocol_updates::*;
+use radix_engine_interface::blueprints::oracle::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn instantiate_price_feed_on_ledger_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn instantiate_price_feed_on_ledger_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Simulate a ledger bootstrapped before the Price Feed package was added to genesis
+    test_runner.delete_node(PRICE_FEED_PACKAGE.as_node_id());
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            PRICE_FEED_PACKAGE,
+            PRICE_FEED_BLUEPRINT,
+            PRICE_FEED_INSTANTIATE_IDENT,
+            PriceFeedInstantiateManifestInput {
+                owner_role: OwnerRole::None,
+                max_price_age_seconds: 60,
+                publisher_badge_amount: dec!("1"),
+                address_reservation: None,
+            },
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_not_success();
+    }
+}
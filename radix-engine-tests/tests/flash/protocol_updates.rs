@@ -0,0 +1,124 @@
+use radix_engine::protocol_updates::*;
+use radix_engine::system::bootstrap::Bootstrapper;
+use radix_engine::track::StateUpdates;
+use radix_engine::types::*;
+use radix_engine::vm::wasm::DefaultWasmEngine;
+use radix_engine::vm::*;
+use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+
+#[test]
+fn executing_protocol_updates_should_apply_all_migrations_in_order() {
+    // Arrange
+    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let native_vm = DefaultNativeVm::new();
+    let vm = Vm::new(&scrypto_vm, native_vm);
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    Bootstrapper::new(NetworkDefinition::simulator(), &mut substate_db, vm, false)
+        .bootstrap_test_default()
+        .unwrap();
+
+    // Act
+    let receipts = execute_protocol_updates(
+        &mut substate_db,
+        ProtocolVersion::GENESIS,
+        ProtocolVersion::LATEST,
+    );
+
+    // Assert
+    assert_eq!(receipts.len(), 3);
+    assert_eq!(receipts[0].version, ProtocolVersion::Anemone);
+    assert_eq!(receipts[1].version, ProtocolVersion::Bottlenose);
+    assert_eq!(receipts[2].version, ProtocolVersion::Cuttlefish);
+    let migration_names: Vec<_> = receipts[0]
+        .migration_receipts
+        .iter()
+        .map(|receipt| receipt.name.as_str())
+        .collect();
+    assert_eq!(
+        migration_names,
+        vec![
+            "seconds_precision",
+            "vm_boot_scrypto_minor_version",
+            "validator_fee_fix",
+            "pools_v1_1"
+        ]
+    );
+    assert_eq!(receipts[1].migration_receipts.len(), 1);
+    assert_eq!(receipts[1].migration_receipts[0].name, "rounded_time");
+    let migration_names: Vec<_> = receipts[2]
+        .migration_receipts
+        .iter()
+        .map(|receipt| receipt.name.as_str())
+        .collect();
+    assert_eq!(
+        migration_names,
+        vec![
+            "vm_boot_cuttlefish_minor_version",
+            "account_package_definition",
+            "consensus_manager_package_definition",
+            "package_package_definition",
+            "pool_package_definition",
+            "resource_package_definition",
+            "role_assignment_package_definition",
+            "transaction_processor_package_definition",
+            "validator_fee_split",
+            "epoch_change_hooks",
+            "non_fungible_next_reservable_id",
+            "account_authorizer",
+            "price_feed_package",
+            "payment_scheduler_package",
+            "order_book_package",
+            "deferred_execution_queue"
+        ]
+    );
+    // Apart from the VM boot update and the package definition rewrites, the Cuttlefish migrations
+    // only fill in state which ledgers bootstrapped before it lack, and which a fresh genesis
+    // already has
+    assert!(receipts[..2]
+        .iter()
+        .flat_map(|receipt| receipt.migration_receipts.iter())
+        .all(|receipt| !receipt.state_updates.by_node.is_empty()));
+    assert!(receipts[2]
+        .migration_receipts
+        .iter()
+        .filter(|receipt| {
+            receipt.name != "vm_boot_cuttlefish_minor_version"
+                && !receipt.name.ends_with("_package_definition")
+        })
+        .all(|receipt| receipt.state_updates.by_node.is_empty()));
+}
+
+#[test]
+fn executing_protocol_updates_to_same_version_should_do_nothing() {
+    // Arrange
+    let mut substate_db = InMemorySubstateDatabase::standard();
+
+    // Act
+    let receipts = execute_protocol_updates(
+        &mut substate_db,
+        ProtocolVersion::LATEST,
+        ProtocolVersion::LATEST,
+    );
+
+    // Assert
+    assert!(receipts.is_empty());
+}
+
+#[test]
+fn custom_protocol_update_should_record_its_migrations() {
+    // Arrange
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    let protocol_update = ProtocolUpdate::empty(ProtocolVersion::Anemone).with_migration(
+        StateMigration::Custom {
+            name: "noop".to_string(),
+            state_updates: StateUpdates::default(),
+        },
+    );
+
+    // Act
+    let receipt = protocol_update.execute(&mut substate_db);
+
+    // Assert
+    assert_eq!(receipt.migration_receipts.len(), 1);
+    assert_eq!(receipt.migration_receipts[0].name, "noop");
+}
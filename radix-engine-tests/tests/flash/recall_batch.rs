@@ -0,0 +1,63 @@
+use radix_engine::protocol_updates::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn recall_batch_from_vaults_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn recall_batch_from_vaults_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let recallable_token = test_runner.create_recallable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, recallable_token)[0];
+
+    // Simulate a Resource package published, and a vault created, before batched recalls were
+    // introduced
+    test_runner.update_blueprint_definition(
+        &RESOURCE_PACKAGE,
+        FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+        |definition| {
+            let function = FUNGIBLE_RESOURCE_MANAGER_RECALL_BATCH_IDENT;
+            definition.interface.functions.swap_remove(function);
+            definition.function_exports.swap_remove(function);
+        },
+    );
+    test_runner.update_blueprint_definition(
+        &RESOURCE_PACKAGE,
+        FUNGIBLE_VAULT_BLUEPRINT,
+        |definition| {
+            let function = FUNGIBLE_VAULT_BATCH_RECALL_IDENT;
+            definition.interface.functions.swap_remove(function);
+            definition.function_exports.swap_remove(function);
+        },
+    );
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall_batch(
+            recallable_token,
+            [(InternalAddress::new_or_panic(vault_id.into()), dec!(1))],
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
@@ -0,0 +1,55 @@
+use radix_engine::protocol_updates::*;
+use radix_engine_tests::common::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn recall_protected_vault_of_resource_package_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn recall_protected_vault_of_resource_package_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(10u32.into(), 0u8, account);
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("recall"));
+
+    // Simulate a Resource package published before recall protected vaults were introduced
+    test_runner.update_blueprint_definition(
+        &RESOURCE_PACKAGE,
+        FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+        |definition| {
+            let function = RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_IDENT;
+            definition.interface.functions.swap_remove(function);
+            definition.function_exports.swap_remove(function);
+        },
+    );
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "RecallTest",
+            "new_with_recall_protected_vault",
+            manifest_args!(resource_address),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
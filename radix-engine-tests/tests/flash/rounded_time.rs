@@ -0,0 +1,80 @@
+use radix_engine::errors::{RuntimeError, SystemError};
+use radix_engine::system::system_type_checker::TypeCheckError;
+use radix_engine::utils::generate_rounded_time_state_updates;
+use radix_engine_common::constants::CONSENSUS_MANAGER;
+use radix_engine_common::prelude::{Epoch, Round};
+use radix_engine_interface::api::node_modules::auth::AuthAddresses;
+use radix_engine_interface::blueprints::consensus_manager::{
+    ConsensusManagerGetCurrentTimeInputV3, ConsensusManagerNextRoundInput, TimePrecision,
+    TimeRoundingMode, CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT, CONSENSUS_MANAGER_NEXT_ROUND_IDENT,
+};
+use radix_engine_interface::time::Instant;
+use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
+use radix_engine_store_interface::interface::CommittableSubstateDatabase;
+use scrypto_unit::{CustomGenesis, TestRunnerBuilder};
+use transaction::builder::ManifestBuilder;
+
+#[test]
+fn get_current_time_rounded_to_nearest_second_without_state_flash_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn get_current_time_rounded_to_nearest_second_with_state_flash_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(flash_substates: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .without_rounded_time_update()
+        .with_custom_genesis(CustomGenesis::default(
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config(),
+        ))
+        .build();
+
+    // Act
+    if flash_substates {
+        let state_updates = generate_rounded_time_state_updates(test_runner.substate_db());
+        let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+        test_runner.substate_db_mut().commit(&db_updates);
+    }
+
+    let time_to_set_ms = 1669663688996;
+    let expected_unix_time_rounded_to_nearest_second = time_to_set_ms / 1000 + 1;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            CONSENSUS_MANAGER,
+            CONSENSUS_MANAGER_NEXT_ROUND_IDENT,
+            ConsensusManagerNextRoundInput::successful(Round::of(1), 0, time_to_set_ms),
+        )
+        .call_method(
+            CONSENSUS_MANAGER,
+            CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT,
+            ConsensusManagerGetCurrentTimeInputV3 {
+                precision: TimePrecision::RoundedSecond(TimeRoundingMode::Nearest),
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![AuthAddresses::validator_role()]);
+
+    // Assert
+    if expect_success {
+        let current_time: Instant = receipt.expect_commit_success().output(2);
+        assert_eq!(
+            current_time.seconds_since_unix_epoch,
+            expected_unix_time_rounded_to_nearest_second,
+        );
+    } else {
+        receipt.expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::SystemError(SystemError::TypeCheckError(
+                    TypeCheckError::BlueprintPayloadValidationError(..)
+                ))
+            )
+        });
+    }
+}
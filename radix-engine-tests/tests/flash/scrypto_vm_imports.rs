@@ -0,0 +1,118 @@
+use radix_engine::blueprints::package::PackageError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::protocol_updates::*;
+use radix_engine::utils::generate_vm_boot_scrypto_minor_version_state_updates;
+use radix_engine_tests::common::PackageLoader;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn publish_package_importing_transaction_signatories_without_protocol_update_should_fail() {
+    run_flash_test("transaction_runtime", false, false);
+}
+
+#[test]
+fn publish_package_importing_transaction_signatories_with_protocol_update_should_succeed() {
+    run_flash_test("transaction_runtime", true, true);
+}
+
+#[test]
+fn publish_package_importing_event_subscriptions_without_protocol_update_should_fail() {
+    run_flash_test("event_subscriptions", false, false);
+}
+
+#[test]
+fn publish_package_importing_event_subscriptions_with_protocol_update_should_succeed() {
+    run_flash_test("event_subscriptions", true, true);
+}
+
+#[test]
+fn publish_package_importing_state_size_without_protocol_update_should_fail() {
+    run_flash_test("state_size", false, false);
+}
+
+#[test]
+fn publish_package_importing_state_size_with_protocol_update_should_succeed() {
+    run_flash_test("state_size", true, true);
+}
+
+#[test]
+fn publish_package_importing_log_record_without_protocol_update_should_fail() {
+    run_flash_test("logger", false, false);
+}
+
+#[test]
+fn publish_package_importing_log_record_with_protocol_update_should_succeed() {
+    run_flash_test("logger", true, true);
+}
+
+#[test]
+fn publish_package_importing_blueprint_interface_without_protocol_update_should_fail() {
+    run_flash_test("scrypto_env", false, false);
+}
+
+#[test]
+fn publish_package_importing_blueprint_interface_with_protocol_update_should_succeed() {
+    run_flash_test("scrypto_env", true, true);
+}
+
+#[test]
+fn publish_package_importing_fee_balance_without_protocol_update_should_fail() {
+    run_flash_test("fee_reserve_states", false, false);
+}
+
+#[test]
+fn publish_package_importing_fee_balance_with_protocol_update_should_succeed() {
+    run_flash_test("fee_reserve_states", true, true);
+}
+
+#[test]
+fn publish_package_importing_rate_limit_check_without_protocol_update_should_fail() {
+    run_flash_test("rate_limit", false, false);
+}
+
+#[test]
+fn publish_package_importing_rate_limit_check_with_protocol_update_should_succeed() {
+    run_flash_test("rate_limit", true, true);
+}
+
+#[test]
+fn publish_package_importing_batch_key_value_store_access_without_protocol_update_should_fail() {
+    run_flash_test("kv_store", false, false);
+}
+
+#[test]
+fn publish_package_importing_batch_key_value_store_access_with_protocol_update_should_succeed() {
+    run_flash_test("kv_store", true, true);
+}
+
+fn run_flash_test(package_name: &str, apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Simulate a Scrypto VM which predates the imports added in the Cuttlefish update
+    let state_updates = generate_vm_boot_scrypto_minor_version_state_updates();
+    test_runner
+        .substate_db_mut()
+        .commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let receipt = test_runner.try_publish_package(PackageLoader::get(package_name));
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::ApplicationError(ApplicationError::PackageError(
+                    PackageError::InvalidWasm(..)
+                ))
+            )
+        });
+    }
+}
@@ -0,0 +1,76 @@
+use radix_engine::blueprints::resource::FungibleResourceManagerFeature;
+use radix_engine::protocol_updates::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn create_soulbound_resource_of_package_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn create_soulbound_resource_of_package_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Simulate a Resource package published before soulbound resources were introduced
+    test_runner.update_blueprint_definition(
+        &RESOURCE_PACKAGE,
+        FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+        |definition| {
+            for function in [
+                FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_IDENT,
+                FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
+            ] {
+                definition.interface.functions.swap_remove(function);
+                definition.function_exports.swap_remove(function);
+            }
+            definition
+                .interface
+                .feature_set
+                .swap_remove(FungibleResourceManagerFeature::Soulbound.feature_name());
+        },
+    );
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            RESOURCE_PACKAGE,
+            FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
+            FungibleResourceManagerCreateWithInitialSupplyV2ManifestInput {
+                owner_role: OwnerRole::None,
+                track_total_supply: true,
+                divisibility: DIVISIBILITY_NONE,
+                initial_supply: dec!("10"),
+                resource_roles: FungibleResourceRolesV2 {
+                    soulbound_roles: soulbound_roles! {
+                        transfer_exemptor => rule!(allow_all);
+                        transfer_exemptor_updater => rule!(deny_all);
+                    },
+                    ..Default::default()
+                },
+                metadata: metadata!(),
+                address_reservation: None,
+            },
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
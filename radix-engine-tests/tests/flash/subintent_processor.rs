@@ -0,0 +1,62 @@
+use radix_engine::protocol_updates::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn execute_subintent_on_ledger_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn execute_subintent_on_ledger_predating_it_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, maker_private_key, maker_account) = test_runner.new_allocated_account();
+    let (_, _, taker_account) = test_runner.new_allocated_account();
+
+    // Simulate a Transaction Processor package published before subintents were introduced
+    test_runner.remove_blueprint_definition(
+        &TRANSACTION_PROCESSOR_PACKAGE,
+        SUBINTENT_PROCESSOR_BLUEPRINT,
+    );
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let subintent = SubintentV1 {
+        network_id: NetworkDefinition::simulator().id,
+        start_epoch_inclusive: Epoch::zero(),
+        end_epoch_exclusive: Epoch::of(99),
+        nonce: 0,
+        instructions: ManifestBuilder::new()
+            .take_from_worktop(XRD, dec!("10"), "payment")
+            .deposit(maker_account, "payment")
+            .build()
+            .instructions,
+        constraints: SubintentConstraints {
+            required_inputs: indexmap!(XRD => dec!("10")),
+            declared_outputs: indexmap!(),
+        },
+    }
+    .sign(&[&maker_private_key]);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .take_from_worktop(XRD, dec!("10"), "payment")
+        .execute_subintent(subintent, ["payment"])
+        .try_deposit_entire_worktop_or_abort(taker_account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_not_success();
+    }
+}
@@ -0,0 +1,69 @@
+use radix_engine::blueprints::resource::NonFungibleResourceManagerError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::protocol_updates::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn recount_total_supply_of_resource_predating_it_without_protocol_update_should_fail() {
+    run_flash_test(false);
+}
+
+#[test]
+fn recount_total_supply_of_resource_predating_it_with_protocol_update_should_be_rejected() {
+    run_flash_test(true);
+}
+
+fn run_flash_test(apply_protocol_update: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_freely_mintable_and_burnable_non_fungible_resource(
+        OwnerRole::Fixed(rule!(allow_all)),
+        NonFungibleIdType::Integer,
+        Some(vec![(
+            NonFungibleLocalId::integer(1),
+            EmptyNonFungibleData {},
+        )]),
+        account,
+    );
+
+    // Simulate a Resource package published, and a resource created, before total supplies could
+    // be recounted
+    test_runner.update_blueprint_definition(
+        &RESOURCE_PACKAGE,
+        NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+        |definition| {
+            let function = NON_FUNGIBLE_RESOURCE_MANAGER_RECOUNT_TOTAL_SUPPLY_IDENT;
+            definition.interface.functions.swap_remove(function);
+            definition.function_exports.swap_remove(function);
+            definition.interface.state.fields.as_mut().unwrap().1.pop();
+        },
+    );
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recount_total_supply(resource_address, 10)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    if apply_protocol_update {
+        // Resources created before collections were introduced don't record their mint order, so
+        // there is nothing to recount their supply from
+        receipt.expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::NotACollection
+                ))
+            )
+        });
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
@@ -0,0 +1,86 @@
+use radix_engine::protocol_updates::*;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+#[test]
+fn epoch_change_with_validator_predating_fee_split_without_protocol_update_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn epoch_change_with_validator_predating_fee_split_with_protocol_update_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(apply_protocol_update: bool, expect_success: bool) {
+    // Arrange
+    let validator_key = Secp256k1PrivateKey::from_u64(2u64).unwrap().public_key();
+    let validator_account = ComponentAddress::virtual_account_from_public_key(&validator_key);
+    let genesis = CustomGenesis::single_validator_and_staker(
+        validator_key,
+        dec!("4000.0"),
+        Decimal::ZERO,
+        validator_account,
+        Epoch::of(7),
+        CustomGenesis::default_consensus_manager_config().with_epoch_change_condition(
+            EpochChangeCondition {
+                min_round_count: 1,
+                max_round_count: 1,
+                target_duration_millis: 0,
+            },
+        ),
+    );
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(genesis)
+        .build();
+    let validator_address = test_runner.get_active_validator_with_key(&validator_key);
+
+    // Simulate a Consensus Manager package published, and a validator created, before the fee
+    // split was introduced
+    test_runner.update_blueprint_definition(
+        &CONSENSUS_MANAGER_PACKAGE,
+        VALIDATOR_BLUEPRINT,
+        |definition| {
+            for function in [
+                VALIDATOR_UPDATE_FEE_SPLIT_IDENT,
+                VALIDATOR_CLAIM_FEE_SPLIT_IDENT,
+            ] {
+                definition.interface.functions.swap_remove(function);
+                definition.function_exports.swap_remove(function);
+            }
+            let state = &mut definition.interface.state;
+            state.fields.as_mut().unwrap().1.pop();
+            state.collections.pop();
+            state.num_logical_partitions -= 1;
+        },
+    );
+    let state_updates = StateUpdates {
+        by_node: indexmap!(
+            validator_address.into_node_id() => NodeStateUpdates::Delta {
+                by_partition: indexmap! {
+                    MAIN_BASE_PARTITION => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            SubstateKey::Field(ValidatorField::FeeSplit.field_index()) => DatabaseUpdate::Delete
+                        }
+                    },
+                }
+            }
+        ),
+    };
+    test_runner
+        .substate_db_mut()
+        .commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+
+    // Act
+    if apply_protocol_update {
+        ProtocolUpdate::of(ProtocolVersion::Cuttlefish).execute(test_runner.substate_db_mut());
+    }
+    let receipt = test_runner.advance_to_round(Round::of(1));
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_commit_failure();
+    }
+}
@@ -61,6 +61,7 @@ impl KernelCallbackObject for TestCallbackObject {
         _pre_allocated_addresses: &Vec<PreAllocatedAddress>,
         _references: &IndexSet<Reference>,
         _blobs: &IndexMap<Hash, Vec<u8>>,
+        _worktop_leftovers_recipient: Option<&ComponentAddress>,
     ) -> Result<Vec<u8>, RuntimeError>
     where
         Y: KernelApi<Self>,
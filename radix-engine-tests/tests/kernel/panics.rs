@@ -79,6 +79,15 @@ impl KernelSubstateApi<SystemLockData> for MockKernel {
         panic1!()
     }
 
+    fn kernel_prefetch_substates(
+        &mut self,
+        _: &NodeId,
+        _: PartitionNumber,
+        _: &[SubstateKey],
+    ) -> Result<(), RuntimeError> {
+        panic1!()
+    }
+
     fn kernel_open_substate_with_default<F: FnOnce() -> IndexedScryptoValue>(
         &mut self,
         _: &NodeId,
@@ -192,4 +201,12 @@ impl<'g> KernelInternalApi<SystemConfig<Vm<'g, DefaultWasmEngine, NoExtension>>>
     fn kernel_read_proof(&mut self, _: &NodeId) -> Option<ProofSnapshot> {
         panic1!()
     }
+
+    fn kernel_read_worktop(&mut self, _: &NodeId) -> Option<Vec<BucketSnapshot>> {
+        panic1!()
+    }
+
+    fn kernel_read_auth_zone(&mut self, _: &NodeId) -> Option<Vec<ProofSnapshot>> {
+        panic1!()
+    }
 }
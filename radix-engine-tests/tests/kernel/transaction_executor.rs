@@ -5,6 +5,8 @@ use radix_engine::transaction::{CostingParameters, ExecutionConfig};
 use radix_engine::types::*;
 use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
 use radix_engine::vm::{DefaultNativeVm, ScryptoVm, Vm};
+use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
+use radix_engine_store_interface::interface::SubstateDatabase;
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
 use scrypto_unit::*;
 use transaction::errors::TransactionValidationError;
@@ -150,6 +152,51 @@ fn test_normal_transaction_flow() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn substate_reads_are_recorded_only_when_enabled() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .drop_auth_zone_proofs()
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest.clone(), vec![]);
+
+    // Assert
+    assert!(receipt.substate_reads.is_none());
+
+    // Act
+    let nonce = test_runner.next_transaction_nonce();
+    let receipt = test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        CostingParameters::default(),
+        ExecutionConfig::for_test_transaction().with_substate_read_recording(true),
+    );
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    let substate_reads = receipt.substate_reads.as_ref().unwrap();
+    let faucet = test_runner.faucet_component();
+    assert!(substate_reads.iter().any(|read| {
+        read.node_id_and_partition::<SpreadPrefixKeyMapper>().0 == *faucet.as_node_id()
+    }));
+    for read in substate_reads {
+        let (node_id, _) = read.node_id_and_partition::<SpreadPrefixKeyMapper>();
+        if commit.state_updates.by_node.contains_key(&node_id) {
+            continue;
+        }
+        let value = test_runner
+            .substate_db()
+            .get_substate(&read.partition_key, &read.sort_key);
+        assert_eq!(read.value_hash, value.map(hash));
+    }
+}
+
 fn get_validated(
     transaction: &NotarizedTransactionV1,
 ) -> Result<ValidatedNotarizedTransactionV1, TransactionValidationError> {
@@ -0,0 +1,129 @@
+use radix_engine::transaction::*;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn execute_transfer() -> TransactionReceipt {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!("10"))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    )
+}
+
+#[test]
+fn latest_receipt_round_trips_through_versioned_encoding() {
+    // Arrange
+    let receipt = execute_transfer();
+
+    // Act
+    let bytes = receipt.to_versioned_bytes().unwrap();
+    let decoded = TransactionReceipt::from_versioned_bytes(&bytes).unwrap();
+
+    // Assert
+    assert_eq!(
+        scrypto_encode(&decoded.result).unwrap(),
+        scrypto_encode(&receipt.result).unwrap()
+    );
+    assert_eq!(
+        scrypto_encode(&decoded.fee_summary).unwrap(),
+        scrypto_encode(&receipt.fee_summary).unwrap()
+    );
+}
+
+#[test]
+fn receipt_encoded_as_first_version_is_upgraded_when_decoded() {
+    // Arrange
+    let receipt = execute_transfer();
+    let commit = receipt.expect_commit_success().clone();
+    let fee_summary = receipt.fee_summary.clone();
+    let receipt_v1 = TransactionReceiptV1 {
+        costing_parameters: receipt.costing_parameters.clone(),
+        transaction_costing_parameters: receipt.transaction_costing_parameters.clone(),
+        fee_summary: TransactionFeeSummaryV1 {
+            total_execution_cost_units_consumed: fee_summary.total_execution_cost_units_consumed,
+            total_finalization_cost_units_consumed: fee_summary
+                .total_finalization_cost_units_consumed,
+            total_execution_cost_in_xrd: fee_summary.total_execution_cost_in_xrd,
+            total_finalization_cost_in_xrd: fee_summary.total_finalization_cost_in_xrd,
+            total_tipping_cost_in_xrd: fee_summary.total_tipping_cost_in_xrd,
+            total_storage_cost_in_xrd: fee_summary.total_storage_cost_in_xrd,
+            total_royalty_cost_in_xrd: fee_summary.total_royalty_cost_in_xrd,
+        },
+        fee_details: None,
+        result: TransactionResultV1::Commit(CommitResultV1 {
+            state_updates: commit.state_updates.clone(),
+            state_update_summary: commit.state_update_summary.clone(),
+            fee_source: commit.fee_source.clone(),
+            fee_destination: commit.fee_destination.clone(),
+            outcome: commit.outcome.clone(),
+            application_events: commit.application_events.clone(),
+            application_logs: vec![(Level::Info, "Hello".to_string())],
+            system_structure: commit.system_structure.clone(),
+            execution_trace: None,
+        }),
+        resources_usage: None,
+    };
+    let bytes = scrypto_encode(&VersionedTransactionReceipt::from(receipt_v1)).unwrap();
+
+    // Act
+    let decoded = TransactionReceipt::from_versioned_bytes(&bytes).unwrap();
+
+    // Assert
+    let decoded_commit = decoded.expect_commit_success();
+    assert_eq!(
+        scrypto_encode(&decoded_commit.state_updates).unwrap(),
+        scrypto_encode(&commit.state_updates).unwrap()
+    );
+    assert_eq!(
+        decoded_commit.application_logs,
+        vec![LogRecord::new(Level::Info, "Hello".to_string())]
+    );
+    assert!(decoded_commit.worktop_leftovers.is_empty());
+    assert_eq!(
+        decoded.fee_summary.total_storage_refund_in_xrd,
+        Decimal::ZERO
+    );
+    assert_eq!(
+        decoded.fee_summary.total_execution_cost_units_consumed,
+        fee_summary.total_execution_cost_units_consumed
+    );
+    assert!(decoded.substate_reads.is_none());
+}
+
+#[test]
+fn commit_result_encoded_as_first_version_is_upgraded_when_decoded() {
+    // Arrange
+    let receipt = execute_transfer();
+    let commit = receipt.expect_commit_success();
+    let commit_v1 = CommitResultV1 {
+        state_updates: commit.state_updates.clone(),
+        state_update_summary: commit.state_update_summary.clone(),
+        fee_source: commit.fee_source.clone(),
+        fee_destination: commit.fee_destination.clone(),
+        outcome: commit.outcome.clone(),
+        application_events: commit.application_events.clone(),
+        application_logs: vec![],
+        system_structure: commit.system_structure.clone(),
+        execution_trace: None,
+    };
+    let bytes = scrypto_encode(&VersionedCommitResult::from(commit_v1)).unwrap();
+
+    // Act
+    let decoded = scrypto_decode::<VersionedCommitResult>(&bytes)
+        .unwrap()
+        .into_latest();
+
+    // Assert
+    assert_eq!(
+        scrypto_encode(&decoded.application_events).unwrap(),
+        scrypto_encode(&commit.application_events).unwrap()
+    );
+    assert!(decoded.outcome.is_success());
+}
@@ -0,0 +1,74 @@
+use radix_engine::system::system_modules::auth::AccessRuleCacheStats;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn repeated_calls_to_identically_protected_method_hit_the_cache() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let single_withdraw = withdraw_from_account(&mut test_runner, &public_key, account, 1);
+    let repeated_withdraws = withdraw_from_account(&mut test_runner, &public_key, account, 3);
+
+    // Assert
+    let single_withdraw = cache_stats(&single_withdraw);
+    let repeated_withdraws = cache_stats(&repeated_withdraws);
+    assert!(repeated_withdraws.hits >= single_withdraw.hits + 2);
+}
+
+#[test]
+fn dropping_auth_zone_proofs_invalidates_cached_results() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (admin_badge, resource) = test_runner.create_mintable_burnable_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            account,
+            admin_badge,
+            [NonFungibleLocalId::integer(1)],
+        )
+        .mint_fungible(resource, 1)
+        .drop_auth_zone_proofs()
+        .mint_fungible(resource, 1)
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+fn withdraw_from_account(
+    test_runner: &mut DefaultTestRunner,
+    public_key: &Secp256k1PublicKey,
+    account: ComponentAddress,
+    withdraws: usize,
+) -> TransactionReceipt {
+    let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+    for _ in 0..withdraws {
+        builder = builder.withdraw_from_account(account, XRD, 1);
+    }
+    let manifest = builder
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(public_key)],
+    )
+}
+
+fn cache_stats(receipt: &TransactionReceipt) -> AccessRuleCacheStats {
+    receipt.expect_commit_success();
+    receipt.access_rule_cache_stats.clone().unwrap()
+}
@@ -1,6 +1,8 @@
 use radix_engine_tests::common::*;
 use radix_engine::{
-    errors::{CannotGlobalizeError, KernelError, RuntimeError, SystemError},
+    errors::{CallFrameError, CannotGlobalizeError, KernelError, RuntimeError, SystemError},
+    kernel::call_frame::{MovePartitionError, PersistNodeError},
+    transaction::{CostingParameters, ExecutionConfig},
     types::*,
 };
 use scrypto_unit::*;
@@ -171,6 +173,37 @@ fn test_create_and_store_in_key_value_store() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn test_address_reservation_cannot_be_stored_in_substate() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package = test_runner.publish_package_simple(PackageLoader::get("allocated_address"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package,
+            "ReservationStore",
+            "store_reservation",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::CallFrameError(
+                CallFrameError::MovePartitionError(MovePartitionError::PersistNodeError(
+                    PersistNodeError::CannotPersistPinnedNode(..)
+                ))
+            ))
+        )
+    });
+}
+
 #[test]
 fn test_create_and_store_in_metadata() {
     // Arrange
@@ -192,3 +225,97 @@ fn test_create_and_store_in_metadata() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn test_consumed_address_reservation_is_listed_in_receipt() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package = test_runner.publish_package_simple(PackageLoader::get("allocated_address"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package,
+            "AllocatedAddressTest",
+            "create_and_consume_within_frame",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+    assert_eq!(receipt.address_reservations.len(), 1);
+    let record = &receipt.address_reservations[0];
+    assert_eq!(
+        record.global_address,
+        GlobalAddress::from(component_address)
+    );
+    assert_eq!(
+        record.blueprint_id,
+        BlueprintId::new(&package, "AllocatedAddressTest")
+    );
+    assert!(record.consumed_by.is_some());
+}
+
+#[test]
+fn test_unused_address_reservation_is_listed_in_receipt() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package = test_runner.publish_package_simple(PackageLoader::get("allocated_address"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package,
+            "AllocatedAddressTest",
+            "create_and_return",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::KernelError(KernelError::OrphanedNodes(_)))
+    });
+    assert_eq!(receipt.address_reservations.len(), 1);
+    assert_eq!(receipt.address_reservations[0].consumed_by, None);
+}
+
+#[test]
+fn test_unused_address_reservation_fails_with_dedicated_error_when_enabled() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package = test_runner.publish_package_simple(PackageLoader::get("allocated_address"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package,
+            "AllocatedAddressTest",
+            "create_and_return",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, 1)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        CostingParameters::default(),
+        ExecutionConfig::for_test_transaction().with_fail_on_unused_address_reservations(true),
+    );
+
+    // Assert
+    let reserved_address = receipt.address_reservations[0].global_address;
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::SystemError(SystemError::UnusedAddressReservations(addresses)) => {
+            addresses.eq(&vec![reserved_address])
+        }
+        _ => false,
+    });
+}
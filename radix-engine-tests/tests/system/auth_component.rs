@@ -1,4 +1,6 @@
 use radix_engine_tests::common::*;
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::{require, FromPublicKey};
 use radix_engine_interface::rule;
@@ -81,6 +83,44 @@ fn cannot_make_cross_component_call_without_correct_global_caller_authorization(
     receipt.expect_specific_failure(is_auth_error);
 }
 
+#[test]
+fn unauthorized_cross_component_call_should_report_missing_badge() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("component"));
+    let badge =
+        NonFungibleGlobalId::global_caller_badge(GlobalCaller::GlobalObject(account.into()));
+    let secured_component =
+        create_secured_component(&mut test_runner, badge.clone(), package_address);
+    let my_component = create_component(&mut test_runner, package_address);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            my_component,
+            "cross_component_call",
+            manifest_args!(secured_component),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+            unauthorized,
+        ))) => {
+            unauthorized.fn_identifier.ident.eq("get_component_state")
+                && unauthorized.unsatisfied_proof_rules
+                    == vec![ProofRule::Require(ResourceOrNonFungible::NonFungible(
+                        badge.clone(),
+                    ))]
+        }
+        _ => false,
+    });
+}
+
 #[test]
 fn can_make_cross_component_call_with_correct_global_caller_authorization() {
     // Arrange
@@ -0,0 +1,90 @@
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::types::*;
+use radix_engine_interface::types::{Level, LogRecord};
+use radix_engine_tests::common::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn dropping_a_component_invokes_its_on_drop_hook() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("hooks"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Ticket",
+            "issue_and_redeem",
+            manifest_args!(5u32),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert_eq!(commit.output::<u32>(1), 5u32);
+    assert_eq!(
+        commit.application_logs,
+        vec![LogRecord::new(Level::Info, "Ticket 5 dropped".to_owned())]
+    );
+}
+
+#[test]
+fn moving_a_component_invokes_its_on_move_hook() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("hooks"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Ticket",
+            "issue_and_pass_down",
+            manifest_args!(7u32, true),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert_eq!(commit.output::<u32>(1), 7u32);
+    assert_eq!(
+        commit.application_logs,
+        vec![
+            LogRecord::new(Level::Info, "Ticket 7 moved down".to_owned()),
+            LogRecord::new(Level::Info, "Ticket 7 dropped".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn on_move_hook_can_veto_moving_a_component() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("hooks"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Ticket",
+            "issue_and_pass_down",
+            manifest_args!(7u32, false),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::ApplicationError(ApplicationError::PanicMessage(message)) => {
+            message.contains("Ticket 7 can't be moved")
+        }
+        _ => false,
+    });
+}
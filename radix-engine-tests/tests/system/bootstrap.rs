@@ -14,6 +14,7 @@ use radix_engine_interface::api::node_modules::metadata::{MetadataValue, Uncheck
 use radix_engine_queries::typed_substate_layout::*;
 use radix_engine_store_interface::db_key_mapper::{MappedSubstateDatabase, SpreadPrefixKeyMapper};
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use radix_engine_tests::common::*;
 use scrypto_test::prelude::KeyValueEntrySubstate;
 use scrypto_unit::{CustomGenesis, SubtreeVaults, TestRunnerBuilder};
 use transaction::prelude::*;
@@ -79,6 +80,11 @@ fn test_bootstrap_receipt_should_match_constants() {
         .new_component_addresses()
         .contains(&TRANSACTION_TRACKER));
 
+    assert!(system_bootstrap_receipt
+        .expect_commit_success()
+        .new_component_addresses()
+        .contains(&DEFERRED_EXECUTION_QUEUE));
+
     assert!(system_bootstrap_receipt
         .expect_commit_success()
         .new_component_addresses()
@@ -542,6 +548,56 @@ fn test_genesis_time() {
     assert_eq!(timestamp.epoch_minute, 123);
 }
 
+#[test]
+fn test_genesis_builder() {
+    // Arrange
+    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let native_vm = DefaultNativeVm::new();
+    let vm = Vm::new(&scrypto_vm, native_vm);
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    let validator_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(2).unwrap().public_key(),
+    );
+    let (code, definition) = PackageLoader::get("package");
+    let genesis_builder = GenesisBuilder::new()
+        .with_validator(validator_key)
+        .with_stake(validator_key, account, dec!("10"))
+        .with_xrd_allocation(account, dec!("100"))
+        .with_initial_package(GenesisPackage {
+            code,
+            definition,
+            metadata: metadata_init!(),
+            owner_role: OwnerRole::None,
+        });
+
+    // Act
+    let mut bootstrapper =
+        Bootstrapper::new(NetworkDefinition::simulator(), &mut substate_db, vm, false);
+    let GenesisReceipts {
+        data_ingestion_receipts,
+        wrap_up_receipt,
+        ..
+    } = bootstrapper
+        .bootstrap_with_genesis_builder(genesis_builder)
+        .unwrap();
+
+    // Assert
+    assert_eq!(data_ingestion_receipts.len(), 4);
+    assert_eq!(
+        data_ingestion_receipts[3]
+            .expect_commit_success()
+            .new_package_addresses()
+            .len(),
+        1
+    );
+    let next_epoch = wrap_up_receipt
+        .expect_commit_success()
+        .next_epoch()
+        .unwrap();
+    assert_eq!(next_epoch.validator_set.validator_count(), 1);
+}
+
 #[test]
 fn should_not_be_able_to_create_genesis_helper() {
     // Arrange
@@ -278,3 +278,67 @@ fn pass_vault_and_proof_to_other_component() {
     // verify if manifest executed with success
     receipt.expect_commit_success();
 }
+
+#[test]
+fn state_version_component_stores_state_as_latest_version() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("component"));
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(package_address, "StateCounter", "new", manifest_args!())
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+    assert_eq!(state_discriminator(&test_runner, component_address), 1);
+}
+
+#[test]
+fn state_version_component_upgrades_previous_version_of_state() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("component"));
+    let component_address = test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(
+                    package_address,
+                    "StateCounter",
+                    "new_at_first_version",
+                    manifest_args!(5u32),
+                )
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .new_component_addresses()[0];
+    assert_eq!(state_discriminator(&test_runner, component_address), 0);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component_address, "increment", manifest_args!())
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let count: u64 = receipt.expect_commit_success().output(1);
+    assert_eq!(count, 6);
+    assert_eq!(state_discriminator(&test_runner, component_address), 1);
+}
+
+fn state_discriminator(test_runner: &DefaultTestRunner, component_address: ComponentAddress) -> u8 {
+    match test_runner.component_state::<ScryptoValue>(component_address) {
+        ScryptoValue::Enum { discriminator, .. } => discriminator,
+        _ => panic!("Expected the state to be stored as a versioned enum"),
+    }
+}
@@ -0,0 +1,284 @@
+use radix_engine_tests::common::*;
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
+use radix_engine::system::attached_modules::role_assignment::RoleAssignmentError;
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::api::node_modules::auth::{
+    RoleAssignmentIsPausedInput, ROLE_ASSIGNMENT_IS_PAUSED_IDENT,
+};
+use radix_engine_interface::api::ModuleId;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn public_method_of_paused_component_is_rejected() {
+    // Arrange
+    let mut test = PauseTest::new();
+    test.pause(true).expect_commit_success();
+
+    // Act
+    let receipt = test.call_public_method();
+
+    // Assert
+    let component_address = test.component_address;
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::SystemModuleError(
+            SystemModuleError::AuthError(AuthError::ObjectPaused(component_address.into())),
+        ))
+    });
+}
+
+#[test]
+fn role_protected_method_of_paused_component_can_still_be_called() {
+    // Arrange
+    let mut test = PauseTest::new();
+    test.pause(true).expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(test.component_address, "owner_method", manifest_args!())
+        .build();
+    let receipt = test.test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&test.public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn public_method_of_unpaused_component_succeeds() {
+    // Arrange
+    let mut test = PauseTest::new();
+    test.pause(true).expect_commit_success();
+    test.unpause().expect_commit_success();
+
+    // Act
+    let receipt = test.call_public_method();
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cannot_pause_component_without_pauser_role() {
+    // Arrange
+    let mut test = PauseTest::new();
+
+    // Act
+    let receipt = test.pause(false);
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn pauser_role_set_by_owner_can_pause_component() {
+    // Arrange
+    let mut test = PauseTest::new();
+    let badge =
+        test.test_runner
+            .create_fungible_resource(dec!("1"), DIVISIBILITY_NONE, test.other_account);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .set_pauser_role(test.component_address, rule!(require(badge)))
+        .build();
+    test.test_runner
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&test.public_key)],
+        )
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(test.other_account, badge, 1)
+        .pause(test.component_address)
+        .build();
+    let receipt = test.test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&test.other_public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(test.is_paused());
+}
+
+#[test]
+fn is_paused_reflects_pause_state() {
+    // Arrange
+    let mut test = PauseTest::new();
+    assert!(!test.is_paused());
+
+    // Act
+    test.pause(true).expect_commit_success();
+
+    // Assert
+    assert!(test.is_paused());
+}
+
+#[test]
+fn cannot_pause_component_which_did_not_opt_in() {
+    // Arrange
+    let mut test = PauseTest::new();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .pause(test.account)
+        .build();
+    let receipt = test.test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&test.public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::ApplicationError(
+            ApplicationError::RoleAssignmentError(RoleAssignmentError::ObjectNotPausable),
+        ))
+    });
+}
+
+#[test]
+fn cannot_set_pauser_role_of_component_which_did_not_opt_in() {
+    // Arrange
+    let mut test = PauseTest::new();
+    let component_address = PauseTest::new_component(
+        &mut test.test_runner,
+        test.package_address,
+        &test.public_key,
+        false,
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .set_pauser_role(component_address, rule!(allow_all))
+        .build();
+    let receipt = test.test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&test.public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::ApplicationError(
+            ApplicationError::RoleAssignmentError(RoleAssignmentError::ObjectNotPausable),
+        ))
+    });
+}
+
+struct PauseTest {
+    test_runner: DefaultTestRunner,
+    public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    other_public_key: Secp256k1PublicKey,
+    other_account: ComponentAddress,
+    package_address: PackageAddress,
+    component_address: ComponentAddress,
+}
+
+impl PauseTest {
+    fn new() -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (public_key, _, account) = test_runner.new_allocated_account();
+        let (other_public_key, _, other_account) = test_runner.new_allocated_account();
+        let package_address =
+            test_runner.publish_package_simple(PackageLoader::get("role_assignment"));
+        let component_address =
+            Self::new_component(&mut test_runner, package_address, &public_key, true);
+
+        Self {
+            test_runner,
+            public_key,
+            account,
+            other_public_key,
+            other_account,
+            package_address,
+            component_address,
+        }
+    }
+
+    fn new_component(
+        test_runner: &mut DefaultTestRunner,
+        package_address: PackageAddress,
+        owner_public_key: &Secp256k1PublicKey,
+        pausable: bool,
+    ) -> ComponentAddress {
+        let owner_rule = rule!(require(NonFungibleGlobalId::from_public_key(
+            owner_public_key
+        )));
+        let builder = ManifestBuilder::new().lock_fee_from_faucet();
+        let builder = if pausable {
+            builder.call_function(
+                package_address,
+                "PausableComponent",
+                "new",
+                manifest_args!(OwnerRole::Fixed(owner_rule.clone()), owner_rule),
+            )
+        } else {
+            builder.call_function(
+                package_address,
+                "PausableComponent",
+                "new_unpausable",
+                manifest_args!(OwnerRole::Fixed(owner_rule)),
+            )
+        };
+        test_runner
+            .execute_manifest(builder.build(), vec![])
+            .expect_commit_success()
+            .new_component_addresses()[0]
+    }
+
+    fn pause(&mut self, as_owner: bool) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .pause(self.component_address)
+            .build();
+        let initial_proofs = if as_owner {
+            vec![NonFungibleGlobalId::from_public_key(&self.public_key)]
+        } else {
+            vec![]
+        };
+        self.test_runner.execute_manifest(manifest, initial_proofs)
+    }
+
+    fn unpause(&mut self) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .unpause(self.component_address)
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.public_key)],
+        )
+    }
+
+    fn call_public_method(&mut self) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component_address, "public_method", manifest_args!())
+            .build();
+        self.test_runner.execute_manifest(manifest, vec![])
+    }
+
+    fn is_paused(&mut self) -> bool {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_module_method(
+                self.component_address,
+                ModuleId::RoleAssignment,
+                ROLE_ASSIGNMENT_IS_PAUSED_IDENT,
+                RoleAssignmentIsPausedInput {},
+            )
+            .build();
+        let receipt = self.test_runner.execute_manifest(manifest, vec![]);
+        receipt.expect_commit_success().output(1)
+    }
+}
@@ -1,7 +1,7 @@
 use radix_engine_tests::common::*;
 use radix_engine::blueprints::consensus_manager::UnstakeData;
 use radix_engine::blueprints::consensus_manager::{
-    Validator, ValidatorEmissionAppliedEvent, ValidatorError,
+    Validator, ValidatorEmissionAppliedEvent, ValidatorError, EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD,
 };
 use radix_engine::blueprints::resource::BucketError;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemError, SystemModuleError};
@@ -244,6 +244,243 @@ fn next_round_causes_epoch_change_on_reaching_max_rounds() {
     assert_eq!(next_epoch, initial_epoch.next().unwrap());
 }
 
+fn set_up_epoch_change_hook(
+    method_name: &str,
+    bond_amount: Decimal,
+) -> (DefaultTestRunner, ComponentAddress, Epoch) {
+    let genesis_epoch = Epoch::of(5);
+    let initial_epoch = genesis_epoch.next().unwrap();
+    let genesis = CustomGenesis::default(
+        genesis_epoch,
+        CustomGenesis::default_consensus_manager_config().with_epoch_change_condition(
+            EpochChangeCondition {
+                min_round_count: 1,
+                max_round_count: 1,
+                target_duration_millis: 0,
+            },
+        ),
+    );
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(genesis)
+        .build();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("consensus_manager"));
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "EpochChangeHookTest",
+                "new",
+                manifest_args!(),
+            )
+            .build(),
+        vec![],
+    );
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+    let (_, _, account) = test_runner.new_account(false);
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .take_from_worktop(XRD, bond_amount, "bond")
+            .call_method_with_name_lookup(component_address, "register_method", |lookup| {
+                (method_name.to_owned(), lookup.bucket("bond"))
+            })
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        vec![],
+    );
+    receipt.expect_commit_success();
+    (test_runner, component_address, initial_epoch)
+}
+
+#[test]
+fn epoch_change_hook_is_invoked_until_bond_is_exhausted() {
+    // Arrange: register with a bond covering a single invocation fee
+    let (mut test_runner, component_address, initial_epoch) =
+        set_up_epoch_change_hook("on_epoch_change", EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD);
+
+    // Act: change epoch twice, invoking the hook after each change
+    let mut invoke_receipts = Vec::new();
+    for _ in 0..2 {
+        test_runner
+            .advance_to_round(Round::of(1))
+            .expect_commit_success();
+        invoke_receipts.push(test_runner.invoke_epoch_change_hook(component_address));
+    }
+
+    // Assert: the first invocation was paid from the bond, which then no longer covered the fee
+    invoke_receipts[0].expect_commit_success();
+    invoke_receipts[1].expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ConsensusManagerError(
+                ConsensusManagerError::EpochChangeHookNotPending(..)
+            ))
+        )
+    });
+    let (invocations, last_epoch): (u32, Option<Epoch>) =
+        test_runner.component_state(component_address);
+    assert_eq!(invocations, 1);
+    assert_eq!(last_epoch, initial_epoch.next());
+
+    // Assert: the remaining bond can still be withdrawn
+    let (_, _, account) = test_runner.new_account(false);
+    let balance_before = test_runner.get_component_balance(account, XRD);
+    test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(component_address, "deregister", manifest_args!())
+                .try_deposit_entire_worktop_or_abort(account, None)
+                .build(),
+            vec![],
+        )
+        .expect_commit_success();
+    let remaining_bond = test_runner
+        .get_component_balance(account, XRD)
+        .checked_sub(balance_before)
+        .unwrap();
+    assert!(remaining_bond.is_positive());
+    assert!(remaining_bond < EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD);
+}
+
+#[test]
+fn epoch_change_hook_cannot_be_invoked_twice_for_same_epoch() {
+    // Arrange
+    let (mut test_runner, component_address, _) =
+        set_up_epoch_change_hook("on_epoch_change", EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD);
+    test_runner
+        .advance_to_round(Round::of(1))
+        .expect_commit_success();
+    test_runner
+        .invoke_epoch_change_hook(component_address)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.invoke_epoch_change_hook(component_address);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ConsensusManagerError(
+                ConsensusManagerError::EpochChangeHookNotPending(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn failing_epoch_change_hook_does_not_fail_epoch_change() {
+    // Arrange
+    let (mut test_runner, component_address, initial_epoch) = set_up_epoch_change_hook(
+        "panic_on_epoch_change",
+        EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD,
+    );
+
+    // Act
+    let epoch_change_receipt = test_runner.advance_to_round(Round::of(1));
+    let invoke_receipt = test_runner.invoke_epoch_change_hook(component_address);
+
+    // Assert
+    let result = epoch_change_receipt.expect_commit_success();
+    assert_eq!(
+        result.next_epoch().unwrap().epoch,
+        initial_epoch.next().unwrap()
+    );
+    invoke_receipt.expect_commit_failure();
+}
+
+#[test]
+fn epoch_change_hook_exceeding_cost_unit_limit_should_fail() {
+    // Arrange
+    let (mut test_runner, component_address, _) =
+        set_up_epoch_change_hook("spin_on_epoch_change", EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD);
+    test_runner
+        .advance_to_round(Round::of(1))
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.invoke_epoch_change_hook(component_address);
+
+    // Assert
+    receipt.expect_specific_failure(|e| format!("{e:?}").contains("FeeReserveError(LimitExceeded"));
+}
+
+#[test]
+fn epoch_change_hook_cannot_be_invoked_by_anyone_but_validators() {
+    // Arrange
+    let (mut test_runner, component_address, _) =
+        set_up_epoch_change_hook("on_epoch_change", EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD);
+    test_runner
+        .advance_to_round(Round::of(1))
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                CONSENSUS_MANAGER,
+                CONSENSUS_MANAGER_INVOKE_EPOCH_CHANGE_HOOK_IDENT,
+                ConsensusManagerInvokeEpochChangeHookInput { component_address },
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+}
+
+#[test]
+fn epoch_change_hook_cannot_be_registered_for_another_component() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_account(false);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .take_from_worktop(XRD, EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD, "bond")
+            .with_name_lookup(|builder, lookup| {
+                let bond = lookup.bucket("bond");
+                builder.call_method(
+                    CONSENSUS_MANAGER,
+                    CONSENSUS_MANAGER_REGISTER_EPOCH_CHANGE_HOOK_IDENT,
+                    ConsensusManagerRegisterEpochChangeHookManifestInput {
+                        component_address: account,
+                        method_name: "deposit".to_owned(),
+                        bond,
+                    },
+                )
+            })
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+}
+
 #[test]
 fn next_round_fails_if_time_moves_backward() {
     // Arrange
@@ -1476,6 +1713,170 @@ fn increasing_validator_fee_takes_effect_after_configured_epochs_delay() {
     assert_eq!(event.proposals_missed, 0);
 }
 
+#[test]
+fn validator_fee_is_split_among_configured_recipients() {
+    // Arrange
+    let initial_stake_amount = dec!("4000.0");
+    let emission_xrd_per_epoch = dec!("1000.0");
+    let validator_key = Secp256k1PrivateKey::from_u64(2u64).unwrap().public_key();
+    let validator_account = ComponentAddress::virtual_account_from_public_key(&validator_key);
+    let genesis = CustomGenesis::single_validator_and_staker(
+        validator_key,
+        initial_stake_amount,
+        Decimal::ZERO,
+        validator_account,
+        Epoch::of(7),
+        CustomGenesis::default_consensus_manager_config()
+            .with_total_emission_xrd_per_epoch(emission_xrd_per_epoch)
+            .with_epoch_change_condition(EpochChangeCondition {
+                min_round_count: 1,
+                max_round_count: 1,
+                target_duration_millis: 0,
+            }),
+    );
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(genesis)
+        .build();
+    let validator_address = test_runner.get_active_validator_with_key(&validator_key);
+    let (_, _, recipient_account) = test_runner.new_account(false);
+
+    // Act: configure half of the fee to go to the recipient
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            validator_account,
+            VALIDATOR_OWNER_BADGE,
+            [NonFungibleLocalId::bytes(validator_address.as_node_id().0).unwrap()],
+        )
+        .call_method(
+            validator_address,
+            VALIDATOR_UPDATE_FEE_SPLIT_IDENT,
+            ValidatorUpdateFeeSplitInput {
+                recipients: vec![ValidatorFeeRecipient {
+                    account: recipient_account,
+                    share: dec!("0.5"),
+                }],
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&validator_key)],
+    );
+    receipt.expect_commit_success();
+
+    // Act: change epoch
+    let receipt = test_runner.advance_to_round(Round::of(1));
+
+    // Assert: the recipient's share is only credited, not deposited
+    receipt.expect_commit_success();
+    let validator_substate = test_runner.get_active_validator_info_by_key(&validator_key);
+    assert_eq!(
+        test_runner
+            .get_component_balance(recipient_account, validator_substate.stake_unit_resource),
+        Decimal::ZERO
+    );
+
+    // Act: claim the recipient's share
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            validator_address,
+            VALIDATOR_CLAIM_FEE_SPLIT_IDENT,
+            ValidatorClaimFeeSplitInput {
+                account: recipient_account,
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert: the default `fee_factor = 1.0` takes the entire emission as fee, and the stake unit
+    // exchange rate stays at 1:1, so the recipient gets stake units worth half of the emission
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .get_component_balance(recipient_account, validator_substate.stake_unit_resource),
+        emission_xrd_per_epoch.checked_mul(dec!("0.5")).unwrap()
+    );
+}
+
+#[test]
+fn claiming_fee_split_of_account_without_credited_share_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (pub_key, _, account) = test_runner.new_account(false);
+    let validator_address = test_runner.new_validator_with_pub_key(pub_key, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            validator_address,
+            VALIDATOR_CLAIM_FEE_SPLIT_IDENT,
+            ValidatorClaimFeeSplitInput { account },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ValidatorError(
+                ValidatorError::NoFeeSplitToClaim { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn validator_fee_split_with_shares_exceeding_one_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (pub_key, _, account) = test_runner.new_account(false);
+    let validator_address = test_runner.new_validator_with_pub_key(pub_key, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            account,
+            VALIDATOR_OWNER_BADGE,
+            [NonFungibleLocalId::bytes(validator_address.as_node_id().0).unwrap()],
+        )
+        .call_method(
+            validator_address,
+            VALIDATOR_UPDATE_FEE_SPLIT_IDENT,
+            ValidatorUpdateFeeSplitInput {
+                recipients: vec![
+                    ValidatorFeeRecipient {
+                        account,
+                        share: dec!("0.6"),
+                    },
+                    ValidatorFeeRecipient {
+                        account,
+                        share: dec!("0.6"),
+                    },
+                ],
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&pub_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ValidatorError(
+                ValidatorError::InvalidValidatorFeeSplit
+            ))
+        )
+    });
+}
+
 fn create_custom_genesis(
     initial_epoch: Epoch,
     rounds_per_epoch: u64,
@@ -3052,6 +3453,67 @@ fn test_tips_and_fee_distribution_single_validator() {
     );
 }
 
+#[test]
+fn test_tips_are_paid_to_proposer_at_epoch_change() {
+    let genesis_epoch = Epoch::of(5);
+    let initial_epoch = genesis_epoch.next().unwrap();
+    let validator_key = Secp256k1PrivateKey::from_u64(2u64).unwrap().public_key();
+    let validator_account = ComponentAddress::virtual_account_from_public_key(&validator_key);
+    let genesis = CustomGenesis::single_validator_and_staker(
+        validator_key,
+        dec!("100"),
+        Decimal::ZERO,
+        validator_account,
+        genesis_epoch,
+        CustomGenesis::default_consensus_manager_config()
+            .with_total_emission_xrd_per_epoch(Decimal::ZERO)
+            .with_epoch_change_condition(EpochChangeCondition {
+                min_round_count: 1,
+                max_round_count: 1, // deliberate, to go through rounds/epoch without gaps
+                target_duration_millis: 0,
+            }),
+    );
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(genesis)
+        .build();
+
+    // Do some transaction with a tip
+    let receipt1 = test_runner.execute_manifest_with_tip_percentage(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .drop_auth_zone_proofs()
+            .build(),
+        vec![],
+        50,
+    );
+    let result1 = receipt1.expect_commit_success();
+
+    // Advance epoch
+    let receipt2 = test_runner.advance_to_round(Round::of(1));
+    let result2 = receipt2.expect_commit_success();
+
+    // Assert: tip is charged on top of execution and finalization costs
+    let fee_summary = &receipt1.fee_summary;
+    assert_eq!(receipt1.transaction_costing_parameters.tip_percentage, 50);
+    assert_close_to!(
+        fee_summary.total_tipping_cost_in_xrd,
+        fee_summary
+            .total_execution_cost_in_xrd
+            .checked_add(fee_summary.total_finalization_cost_in_xrd)
+            .unwrap()
+            .checked_div(dec!(2))
+            .unwrap()
+    );
+    assert!(result1.fee_destination.to_proposer >= fee_summary.total_tipping_cost_in_xrd);
+
+    // Assert: tip ends up in the validator rewards
+    let event = test_runner
+        .extract_events_of_type::<ValidatorRewardAppliedEvent>(result2)
+        .remove(0);
+    assert_eq!(event.epoch, initial_epoch);
+    assert_close_to!(event.amount, fee_summary.expected_reward_if_single_validator());
+}
+
 #[test]
 fn test_tips_and_fee_distribution_two_validators() {
     let genesis_epoch = Epoch::of(5);
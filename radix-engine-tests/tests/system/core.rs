@@ -43,6 +43,25 @@ fn inspect_component_state() {
     );
 }
 
+#[test]
+#[should_panic(expected = "could not be decoded as")]
+fn inspect_component_state_as_mismatching_type_reports_blueprint() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("core"));
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(package_address, "Compo", "new", manifest_args!())
+            .build(),
+        vec![],
+    );
+    let component_address = receipt.expect_commit(true).new_component_addresses()[0];
+
+    // Act
+    test_runner.component_state::<(u32, u32)>(component_address);
+}
+
 #[test]
 fn test_globalize_with_unflushed_invalid_own() {
     let mut test_runner = TestRunnerBuilder::new().build();
@@ -0,0 +1,141 @@
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::debugger::*;
+use radix_engine::system::system_modules::execution_trace::{BucketSnapshot, ProofSnapshot};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+struct RecordingDebugger {
+    break_on_invoke: bool,
+    abort_at_instruction: Option<usize>,
+    breakpoints: Rc<RefCell<Vec<BreakpointContext>>>,
+}
+
+impl TransactionDebugger for RecordingDebugger {
+    fn break_on_invoke(&self) -> bool {
+        self.break_on_invoke
+    }
+
+    fn on_breakpoint(&mut self, context: &BreakpointContext) -> DebuggerAction {
+        self.breakpoints.borrow_mut().push(context.clone());
+        match context.breakpoint {
+            Breakpoint::Instruction(index) if Some(index) == self.abort_at_instruction => {
+                DebuggerAction::Abort
+            }
+            _ => DebuggerAction::Resume,
+        }
+    }
+}
+
+fn run_with_debugger(
+    break_on_invoke: bool,
+    abort_at_instruction: Option<usize>,
+) -> (TransactionReceipt, Vec<BreakpointContext>, ComponentAddress) {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let breakpoints = Rc::new(RefCell::new(Vec::new()));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(account, XRD, dec!("1"))
+        .withdraw_from_account(account, XRD, dec!("10"))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest_with_debugger(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        Box::new(RecordingDebugger {
+            break_on_invoke,
+            abort_at_instruction,
+            breakpoints: breakpoints.clone(),
+        }),
+    );
+
+    let breakpoints = breakpoints.borrow().clone();
+    (receipt, breakpoints, account)
+}
+
+#[test]
+fn debugger_pauses_before_each_manifest_instruction() {
+    // Act
+    let (receipt, breakpoints, _) = run_with_debugger(false, None);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        breakpoints
+            .iter()
+            .map(|context| context.breakpoint.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            Breakpoint::Instruction(0),
+            Breakpoint::Instruction(1),
+            Breakpoint::Instruction(2),
+            Breakpoint::Instruction(3),
+        ]
+    );
+    for context in &breakpoints {
+        // The root frame and the transaction processor frame
+        assert_eq!(context.call_frames.len(), 2);
+    }
+}
+
+#[test]
+fn debugger_observes_worktop_and_auth_zone() {
+    // Act
+    let (receipt, breakpoints, _) = run_with_debugger(false, None);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(breakpoints[0].worktop.is_empty());
+    assert!(breakpoints[0].auth_zone.is_empty());
+    assert_eq!(
+        breakpoints[3].worktop,
+        vec![BucketSnapshot::Fungible {
+            resource_address: XRD,
+            liquid: dec!("10"),
+        }]
+    );
+    assert_eq!(
+        breakpoints[3].auth_zone,
+        vec![ProofSnapshot::Fungible {
+            resource_address: XRD,
+            total_locked: dec!("1"),
+        }]
+    );
+}
+
+#[test]
+fn debugger_can_pause_before_each_kernel_invoke() {
+    // Act
+    let (receipt, breakpoints, account) = run_with_debugger(true, None);
+
+    // Assert
+    receipt.expect_commit_success();
+    let invoked_account = breakpoints.iter().any(|context| match &context.breakpoint {
+        Breakpoint::Invoke(actor) => actor.node_id() == Some(*account.as_node_id()),
+        _ => false,
+    });
+    assert!(invoked_account);
+    assert!(breakpoints.iter().any(|context| context.call_frames.len() > 2));
+}
+
+#[test]
+fn debugger_can_abort_transaction() {
+    // Act
+    let (receipt, breakpoints, _) = run_with_debugger(false, Some(2));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::DebuggerError(
+                DebuggerError::TransactionAborted {
+                    instruction_index: Some(2)
+                }
+            ))
+        )
+    });
+    assert_eq!(breakpoints.last().unwrap().breakpoint, Breakpoint::Instruction(2));
+}
@@ -0,0 +1,265 @@
+use radix_engine::blueprints::transaction_processor::*;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+const PAYMENT: Decimal = dec!("10");
+
+#[test]
+fn due_deferred_manifest_is_executed_and_executor_receives_payment() {
+    // Arrange
+    let mut env = DeferredExecutionTestEnvironment::new();
+    let execute_at_epoch = env.epoch_after(5);
+    let expiry_epoch = env.epoch_after(10);
+    let intent_id = env.enqueue_faucet_withdrawal(execute_at_epoch, expiry_epoch);
+    env.test_runner.set_current_epoch(execute_at_epoch);
+    let recipient_balance = env.balance(env.recipient_account);
+    let executor_balance = env.balance(env.executor_account);
+
+    // Act
+    let receipt = env.execute(intent_id);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        env.balance(env.recipient_account),
+        recipient_balance + dec!("10000")
+    );
+    assert_eq!(
+        env.balance(env.executor_account),
+        executor_balance + PAYMENT
+    );
+}
+
+#[test]
+fn deferred_manifest_can_only_be_executed_once() {
+    // Arrange
+    let mut env = DeferredExecutionTestEnvironment::new();
+    let execute_at_epoch = env.epoch_after(5);
+    let expiry_epoch = env.epoch_after(10);
+    let intent_id = env.enqueue_faucet_withdrawal(execute_at_epoch, expiry_epoch);
+    env.test_runner.set_current_epoch(execute_at_epoch);
+    env.execute(intent_id).expect_commit_success();
+
+    // Act
+    let receipt = env.execute(intent_id);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::DeferredExecutionQueueError(
+                DeferredExecutionQueueError::IntentNotFound { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn deferred_manifest_cannot_be_executed_before_it_is_due() {
+    // Arrange
+    let mut env = DeferredExecutionTestEnvironment::new();
+    let execute_at_epoch = env.epoch_after(5);
+    let expiry_epoch = env.epoch_after(10);
+    let intent_id = env.enqueue_faucet_withdrawal(execute_at_epoch, expiry_epoch);
+    env.test_runner
+        .set_current_epoch(execute_at_epoch.previous().unwrap());
+
+    // Act
+    let receipt = env.execute(intent_id);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::DeferredExecutionQueueError(
+                DeferredExecutionQueueError::IntentNotDue { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn deferred_manifest_cannot_be_executed_once_expired() {
+    // Arrange
+    let mut env = DeferredExecutionTestEnvironment::new();
+    let execute_at_epoch = env.epoch_after(5);
+    let expiry_epoch = env.epoch_after(10);
+    let intent_id = env.enqueue_faucet_withdrawal(execute_at_epoch, expiry_epoch);
+    env.test_runner.set_current_epoch(expiry_epoch);
+
+    // Act
+    let receipt = env.execute(intent_id);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::DeferredExecutionQueueError(
+                DeferredExecutionQueueError::IntentExpired { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn canceller_can_cancel_expired_deferred_manifest_and_reclaim_payment() {
+    // Arrange
+    let mut env = DeferredExecutionTestEnvironment::new();
+    let execute_at_epoch = env.epoch_after(5);
+    let expiry_epoch = env.epoch_after(10);
+    let intent_id = env.enqueue_faucet_withdrawal(execute_at_epoch, expiry_epoch);
+    env.test_runner.set_current_epoch(expiry_epoch);
+    let payer_balance = env.balance(env.payer_account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .cancel_deferred_manifest(intent_id)
+        .try_deposit_entire_worktop_or_abort(env.payer_account, None)
+        .build();
+    let receipt = env.execute_as_payer(manifest);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.balance(env.payer_account), payer_balance + PAYMENT);
+}
+
+#[test]
+fn deferred_manifest_cannot_be_cancelled_without_satisfying_canceller() {
+    // Arrange
+    let mut env = DeferredExecutionTestEnvironment::new();
+    let execute_at_epoch = env.epoch_after(5);
+    let expiry_epoch = env.epoch_after(10);
+    let intent_id = env.enqueue_faucet_withdrawal(execute_at_epoch, expiry_epoch);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .cancel_deferred_manifest(intent_id)
+        .try_deposit_entire_worktop_or_abort(env.executor_account, None)
+        .build();
+    let receipt = env.test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn deferred_manifest_cannot_enqueue_further_deferred_manifests() {
+    // Arrange
+    let mut env = DeferredExecutionTestEnvironment::new();
+    let execute_at_epoch = env.epoch_after(5);
+    let expiry_epoch = env.epoch_after(10);
+    let nested = ManifestBuilder::new()
+        .get_free_xrd_from_faucet()
+        .take_from_worktop(XRD, PAYMENT, "payment")
+        .enqueue_deferred_manifest(
+            ManifestBuilder::new().build(),
+            execute_at_epoch,
+            expiry_epoch,
+            "payment",
+            AccessRule::AllowAll,
+        )
+        .build();
+
+    // Act
+    let receipt = env.enqueue(nested, execute_at_epoch, expiry_epoch);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::DeferredExecutionQueueError(
+                DeferredExecutionQueueError::InvalidInstructions(..)
+            ))
+        )
+    });
+}
+
+struct DeferredExecutionTestEnvironment {
+    test_runner: DefaultTestRunner,
+    payer_public_key: Secp256k1PublicKey,
+    payer_account: ComponentAddress,
+    recipient_account: ComponentAddress,
+    executor_account: ComponentAddress,
+}
+
+impl DeferredExecutionTestEnvironment {
+    fn new() -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (payer_public_key, _, payer_account) = test_runner.new_allocated_account();
+        let (_, _, recipient_account) = test_runner.new_allocated_account();
+        let (_, _, executor_account) = test_runner.new_allocated_account();
+
+        Self {
+            test_runner,
+            payer_public_key,
+            payer_account,
+            recipient_account,
+            executor_account,
+        }
+    }
+
+    fn epoch_after(&mut self, epoch_count: u64) -> Epoch {
+        self.test_runner
+            .get_current_epoch()
+            .after(epoch_count)
+            .unwrap()
+    }
+
+    fn balance(&mut self, account: ComponentAddress) -> Decimal {
+        self.test_runner.get_component_balance(account, XRD)
+    }
+
+    fn execute_as_payer(&mut self, manifest: TransactionManifestV1) -> TransactionReceipt {
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.payer_public_key)],
+        )
+    }
+
+    fn enqueue(
+        &mut self,
+        deferred: TransactionManifestV1,
+        execute_at_epoch: Epoch,
+        expiry_epoch: Epoch,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(self.payer_account, XRD, PAYMENT)
+            .take_from_worktop(XRD, PAYMENT, "payment")
+            .enqueue_deferred_manifest(
+                deferred,
+                execute_at_epoch,
+                expiry_epoch,
+                "payment",
+                rule!(require(NonFungibleGlobalId::from_public_key(
+                    &self.payer_public_key
+                ))),
+            )
+            .build();
+        self.execute_as_payer(manifest)
+    }
+
+    /// Enqueues a manifest depositing XRD from the faucet into the recipient account.
+    fn enqueue_faucet_withdrawal(&mut self, execute_at_epoch: Epoch, expiry_epoch: Epoch) -> u64 {
+        let deferred = ManifestBuilder::new()
+            .get_free_xrd_from_faucet()
+            .try_deposit_entire_worktop_or_abort(self.recipient_account, None)
+            .build();
+        let receipt = self.enqueue(deferred, execute_at_epoch, expiry_epoch);
+        receipt.expect_commit_success().output(3)
+    }
+
+    fn execute(&mut self, intent_id: u64) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .execute_deferred_manifest(intent_id)
+            .try_deposit_entire_worktop_or_abort(self.executor_account, None)
+            .build();
+        self.test_runner.execute_manifest(manifest, vec![])
+    }
+}
@@ -0,0 +1,84 @@
+use radix_engine::blueprints::consensus_manager::UnstakeData;
+use radix_engine::transaction::{CostingParameters, ExecutionConfig, TransactionReceipt};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn mint_ruid_non_fungible(state_version: u64) -> TransactionReceipt {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_ruid_non_fungible_resource(
+            OwnerRole::None,
+            false,
+            metadata!(),
+            NonFungibleResourceRoles::single_locked_rule(rule!(allow_all)),
+            Some(vec![UnstakeData {
+                name: "Replayed".to_owned(),
+                claim_epoch: Epoch::of(1),
+                claim_amount: dec!(1),
+            }]),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    let nonce = test_runner.next_transaction_nonce();
+    test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!(NonFungibleGlobalId::from_public_key(&public_key))),
+        CostingParameters::default(),
+        ExecutionConfig::for_test_transaction()
+            .with_span_trace(true)
+            .with_deterministic_simulation(state_version),
+    )
+}
+
+#[test]
+fn replaying_at_the_same_state_version_gives_identical_receipts() {
+    // Act
+    let receipt = mint_ruid_non_fungible(100);
+    let replayed_receipt = mint_ruid_non_fungible(100);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        scrypto_encode(&receipt.result).unwrap(),
+        scrypto_encode(&replayed_receipt.result).unwrap()
+    );
+    assert_eq!(
+        scrypto_encode(&receipt.span_trace).unwrap(),
+        scrypto_encode(&replayed_receipt.span_trace).unwrap()
+    );
+    assert!(receipt.resources_usage.is_none());
+}
+
+#[test]
+fn deterministic_simulation_does_not_record_span_timestamps() {
+    // Act
+    let receipt = mint_ruid_non_fungible(100);
+
+    // Assert
+    receipt.expect_commit_success();
+    let trace = receipt.span_trace.as_ref().unwrap();
+    assert!(!trace.spans.is_empty());
+    for span in &trace.spans {
+        assert_eq!(span.start_time_unix_nano, 0);
+        assert_eq!(span.end_time_unix_nano, 0);
+    }
+}
+
+#[test]
+fn ruids_depend_on_the_state_version() {
+    // Act
+    let receipt = mint_ruid_non_fungible(100);
+    let other_receipt = mint_ruid_non_fungible(101);
+
+    // Assert
+    assert_ne!(
+        scrypto_encode(&receipt.expect_commit_success().state_updates).unwrap(),
+        scrypto_encode(&other_receipt.expect_commit_success().state_updates).unwrap()
+    );
+}
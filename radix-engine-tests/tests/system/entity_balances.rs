@@ -0,0 +1,39 @@
+use radix_engine::types::*;
+use radix_engine_queries::query::*;
+use scrypto_unit::*;
+
+#[test]
+fn balances_of_account_include_fungible_and_non_fungible_vaults() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let non_fungible_resource = test_runner.create_non_fungible_resource(account);
+    let xrd_balance = test_runner.get_component_balance(account, XRD);
+
+    // Act
+    let balances = get_entity_balances(test_runner.substate_db(), account.as_node_id());
+
+    // Assert
+    assert_eq!(
+        balances,
+        indexmap!(
+            XRD => xrd_balance,
+            non_fungible_resource => dec!("3"),
+        )
+    );
+}
+
+#[test]
+fn balances_of_vault_are_its_own_balance() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let vault_id = test_runner.get_component_vaults(account, XRD)[0];
+    let vault_balance = test_runner.inspect_vault_balance(vault_id).unwrap();
+
+    // Act
+    let balances = get_entity_balances(test_runner.substate_db(), &vault_id);
+
+    // Assert
+    assert_eq!(balances, indexmap!(XRD => vault_balance));
+}
@@ -0,0 +1,88 @@
+use radix_engine::system::system_db_reader::SystemReaderError;
+use radix_engine::system::type_info::TypeInfoSubstate;
+use radix_engine::types::*;
+use radix_engine_queries::query::*;
+use radix_engine_queries::typed_substate_layout::*;
+use scrypto_unit::*;
+
+#[test]
+fn dump_of_account_contains_typed_substates() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let dump = EntityDumper::new(test_runner.substate_db())
+        .dump_entity(account.as_node_id())
+        .unwrap();
+
+    // Assert
+    assert_eq!(dump.entity_type, EntityType::GlobalAccount);
+    let partition_numbers: Vec<PartitionNumber> = dump
+        .partitions
+        .iter()
+        .map(|partition| partition.partition_number)
+        .collect();
+    assert!(partition_numbers.contains(&TYPE_INFO_FIELD_PARTITION));
+    assert!(partition_numbers.contains(&MAIN_BASE_PARTITION));
+    for partition in &dump.partitions {
+        assert!(!partition.substates.is_empty());
+        for substate in &partition.substates {
+            assert!(substate.typed_key.is_ok(), "{:?}", substate.typed_key);
+            assert!(substate.typed_value.is_ok(), "{:?}", substate.typed_value);
+        }
+    }
+    let type_info = &dump
+        .partitions
+        .iter()
+        .find(|partition| partition.partition_number == TYPE_INFO_FIELD_PARTITION)
+        .unwrap()
+        .substates[0];
+    assert!(matches!(
+        type_info.typed_value,
+        Ok(TypedSubstateValue::TypeInfoModule(
+            TypedTypeInfoModuleSubstateValue::TypeInfo(TypeInfoSubstate::Object(..))
+        ))
+    ));
+}
+
+#[test]
+fn dump_of_vault_contains_typed_balance() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let vault_id = test_runner.get_component_vaults(account, XRD)[0];
+
+    // Act
+    let dump = EntityDumper::new(test_runner.substate_db())
+        .dump_entity(&vault_id)
+        .unwrap();
+
+    // Assert
+    assert_eq!(dump.entity_type, EntityType::InternalFungibleVault);
+    assert!(dump
+        .partitions
+        .iter()
+        .flat_map(|partition| partition.substates.iter())
+        .any(|substate| matches!(
+            substate.typed_value,
+            Ok(TypedSubstateValue::MainModule(
+                TypedMainModuleSubstateValue::FungibleVault(..)
+            ))
+        )));
+}
+
+#[test]
+fn dump_of_missing_entity_fails() {
+    // Arrange
+    let test_runner = TestRunnerBuilder::new().build();
+    let component_address =
+        ComponentAddress::new_or_panic([EntityType::GlobalGenericComponent as u8; NodeId::LENGTH]);
+
+    // Act
+    let result =
+        EntityDumper::new(test_runner.substate_db()).dump_entity(component_address.as_node_id());
+
+    // Assert
+    assert!(matches!(result, Err(SystemReaderError::NodeIdDoesNotExist)));
+}
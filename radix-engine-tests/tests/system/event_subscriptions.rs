@@ -0,0 +1,163 @@
+use radix_engine::errors::{EventError, RuntimeError, SystemModuleError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_tests::common::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn subscriber_hook_is_called_when_subscribed_event_is_emitted() {
+    // Arrange
+    let mut env = EventSubscriptionsTestEnvironment::new(OwnerRole::Fixed(rule!(allow_all)));
+    env.call_method(env.mirror, "subscribe", manifest_args!("on_value_changed"))
+        .expect_commit_success();
+
+    // Act
+    let receipt = env.call_method(env.emitter, "set_value", manifest_args!(dec!("5")));
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.mirrored(), (dec!("5"), 1));
+}
+
+#[test]
+fn subscription_requires_owner_role_of_emitter() {
+    // Arrange
+    let mut env = EventSubscriptionsTestEnvironment::new(OwnerRole::None);
+
+    // Act
+    let receipt = env.call_method(env.mirror, "subscribe", manifest_args!("on_value_changed"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::EventError(err))
+                if matches!(**err, EventError::SubscriptionNotAuthorized)
+        )
+    });
+}
+
+#[test]
+fn subscription_to_missing_hook_method_fails() {
+    // Arrange
+    let mut env = EventSubscriptionsTestEnvironment::new(OwnerRole::Fixed(rule!(allow_all)));
+
+    // Act
+    let receipt = env.call_method(env.mirror, "subscribe", manifest_args!("missing_hook"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::EventError(err))
+                if matches!(**err, EventError::HookMethodNotFound(..))
+        )
+    });
+}
+
+#[test]
+fn unsubscribed_component_is_not_notified() {
+    // Arrange
+    let mut env = EventSubscriptionsTestEnvironment::new(OwnerRole::Fixed(rule!(allow_all)));
+    env.call_method(env.mirror, "subscribe", manifest_args!("on_value_changed"))
+        .expect_commit_success();
+    env.call_method(env.mirror, "unsubscribe", manifest_args!())
+        .expect_commit_success();
+
+    // Act
+    let receipt = env.call_method(env.emitter, "set_value", manifest_args!(dec!("5")));
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.mirrored(), (Decimal::ZERO, 0));
+}
+
+#[test]
+fn hook_method_cannot_be_called_by_anyone_but_the_emitter() {
+    // Arrange
+    let mut env = EventSubscriptionsTestEnvironment::new(OwnerRole::Fixed(rule!(allow_all)));
+    // Manifest encoding of an `EventNotification`
+    let notification = (
+        env.emitter,
+        "ValueChangedEvent",
+        scrypto_encode(&dec!("5")).unwrap(),
+    );
+
+    // Act
+    let receipt = env.call_method(
+        env.mirror,
+        "on_value_changed",
+        manifest_args!(notification),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+struct EventSubscriptionsTestEnvironment {
+    test_runner: DefaultTestRunner,
+    emitter: ComponentAddress,
+    mirror: ComponentAddress,
+}
+
+impl EventSubscriptionsTestEnvironment {
+    fn new(emitter_owner_role: OwnerRole) -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let package_address =
+            test_runner.publish_package_simple(PackageLoader::get("event_subscriptions"));
+
+        let emitter = test_runner
+            .execute_manifest(
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .call_function(
+                        package_address,
+                        "Emitter",
+                        "new",
+                        manifest_args!(emitter_owner_role),
+                    )
+                    .build(),
+                vec![],
+            )
+            .expect_commit_success()
+            .new_component_addresses()[0];
+        let mirror = test_runner
+            .execute_manifest(
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .call_function(package_address, "Mirror", "new", manifest_args!(emitter))
+                    .build(),
+                vec![],
+            )
+            .expect_commit_success()
+            .new_component_addresses()[0];
+
+        Self {
+            test_runner,
+            emitter,
+            mirror,
+        }
+    }
+
+    fn call_method(
+        &mut self,
+        component_address: ComponentAddress,
+        method_name: &str,
+        args: ManifestArgs,
+    ) -> TransactionReceipt {
+        self.test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(component_address, method_name, args)
+                .build(),
+            vec![],
+        )
+    }
+
+    fn mirrored(&mut self) -> (Decimal, u32) {
+        self.call_method(self.mirror, "mirrored", manifest_args!())
+            .expect_commit_success()
+            .output(1)
+    }
+}
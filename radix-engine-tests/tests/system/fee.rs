@@ -1,5 +1,5 @@
 use radix_engine_tests::common::*;
-use radix_engine::blueprints::resource::WorktopError;
+use radix_engine::blueprints::resource::{VaultError, WorktopError};
 use radix_engine::errors::RuntimeError;
 use radix_engine::errors::{ApplicationError, CallFrameError, KernelError};
 use radix_engine::kernel::call_frame::OpenSubstateError;
@@ -569,3 +569,96 @@ fn regular_and_contingent_fee_locks_are_correct_in_execution_trace() {
         }
     )
 }
+
+#[test]
+fn deleting_state_refunds_part_of_the_storage_cost() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("kv_store"));
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "Basic",
+                "new_with_entry",
+                manifest_args!("key".to_string(), "a".repeat(10000)),
+            )
+            .build(),
+        vec![],
+    );
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component_address, "remove", manifest_args!("key".to_string()))
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let fee_summary = &receipt.fee_summary;
+    assert!(fee_summary.total_storage_refund_in_xrd.is_positive());
+    assert!(!fee_summary.total_storage_cost_in_xrd.is_negative());
+}
+
+#[test]
+fn unused_fee_is_refunded_to_refund_vault() {
+    // Arrange
+    let (mut test_runner, component_address) = setup_test_runner();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .call_method(
+                component_address,
+                "lock_fee_with_refund_to_empty_vault",
+                manifest_args!(dec!(500)),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let fee_paid = receipt.fee_summary.total_cost();
+    let balances: (Decimal, Decimal) = test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(component_address, "xrd_balances", manifest_args!())
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .output(1);
+    assert_eq!(balances, (dec!(500), dec!(500) - fee_paid));
+}
+
+#[test]
+fn cannot_refund_fee_to_non_xrd_vault() {
+    // Act
+    let receipt = run_manifest(|component_address| {
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                component_address,
+                "lock_fee_with_refund_to_doge",
+                manifest_args!(dec!(500)),
+            )
+            .build()
+    });
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(
+                VaultError::InvalidFeeRefundVault(..)
+            ))
+        )
+    });
+}
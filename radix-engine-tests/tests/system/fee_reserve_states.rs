@@ -60,3 +60,67 @@ fn test_fee_states() {
     assert!(fee_locked < remaining_fee_balance);
     assert!(remaining_fee_balance < fee_locked.checked_add(loan_in_xrd).unwrap());
 }
+
+#[test]
+fn test_execution_cost_units_consumed() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("fee_reserve_states"));
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "FeeReserveChecker",
+                "check_execution_cost_units_consumed",
+                manifest_args!(),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let (before, after) = receipt.expect_commit_success().output::<(u32, u32)>(1);
+    assert!(before > 0);
+    assert!(before < after);
+    assert!(after <= receipt.fee_summary.total_execution_cost_units_consumed);
+}
+
+#[test]
+fn test_royalty_amount_in_xrd() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("fee_reserve_states"));
+
+    for (royalty_amount, expected) in [
+        (RoyaltyAmount::Free, Decimal::ZERO),
+        (RoyaltyAmount::Xrd(dec!("3")), dec!("3")),
+        (
+            RoyaltyAmount::Usd(dec!("2")),
+            dec!("2")
+                .checked_mul(Decimal::try_from(USD_PRICE_IN_XRD).unwrap())
+                .unwrap(),
+        ),
+    ] {
+        // Act
+        let receipt = test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(
+                    package_address,
+                    "FeeReserveChecker",
+                    "royalty_amount_in_xrd",
+                    manifest_args!(royalty_amount),
+                )
+                .build(),
+            vec![],
+        );
+
+        // Assert
+        assert_eq!(receipt.expect_commit_success().output::<Decimal>(1), expected);
+    }
+}
@@ -477,6 +477,84 @@ fn remove_from_stored_map_when_not_empty_should_work() {
     outputs[1].expect_return_value(&expected);
 }
 
+#[test]
+fn get_many_returns_entries_inserted_with_insert_many() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("kv_store"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Basic",
+            "new_with_entry",
+            manifest_args!("key".to_string(), "value".to_string()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component = receipt.expect_commit_success().new_component_addresses()[0];
+    let entries = vec![
+        ("key".to_string(), "new_value".to_string()),
+        ("key2".to_string(), "value2".to_string()),
+        ("key3".to_string(), "value3".to_string()),
+        ("key3".to_string(), "last_value3".to_string()),
+    ];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component, "insert_many", manifest_args!(entries))
+        .call_method(
+            component,
+            "get_many",
+            manifest_args!(vec![
+                "key3".to_string(),
+                "non_existent_key".to_string(),
+                "key".to_string(),
+                "key2".to_string(),
+            ]),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let outputs = result.outcome.expect_success();
+    let expected: Vec<Option<String>> = vec![
+        Some("last_value3".to_string()),
+        None,
+        Some("new_value".to_string()),
+        Some("value2".to_string()),
+    ];
+    outputs[2].expect_return_value(&expected);
+}
+
+#[test]
+fn get_many_of_no_keys_returns_nothing() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("kv_store"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Basic", "new", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component = receipt.expect_commit_success().new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component, "get_many", manifest_args!(Vec::<String>::new()))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let outputs = result.outcome.expect_success();
+    let expected: Vec<Option<String>> = vec![];
+    outputs[1].expect_return_value(&expected);
+}
+
 #[test]
 fn remove_from_stored_map_when_contain_vault_should_not_work() {
     // Arrange
@@ -0,0 +1,193 @@
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::system::attached_modules::role_assignment::RoleAssignmentError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_tests::common::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn owner_role_is_transferred_once_proposal_is_accepted() {
+    // Arrange
+    let mut test = OwnerRoleTransferTest::new();
+    let new_owner_rule = test.rule_of(&test.new_owner_public_key);
+    test.propose(new_owner_rule, test.owner_public_key)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.accept(test.new_owner_public_key);
+
+    // Assert
+    receipt.expect_commit_success();
+    test.set_owner(AccessRule::AllowAll, test.owner_public_key)
+        .expect_auth_failure();
+    test.set_owner(AccessRule::AllowAll, test.new_owner_public_key)
+        .expect_commit_success();
+}
+
+#[test]
+fn owner_role_is_unchanged_until_proposal_is_accepted() {
+    // Arrange
+    let mut test = OwnerRoleTransferTest::new();
+    let new_owner_rule = test.rule_of(&test.new_owner_public_key);
+
+    // Act
+    let receipt = test.propose(new_owner_rule, test.owner_public_key);
+
+    // Assert
+    receipt.expect_commit_success();
+    test.set_owner(AccessRule::AllowAll, test.new_owner_public_key)
+        .expect_auth_failure();
+    test.set_owner(AccessRule::AllowAll, test.owner_public_key)
+        .expect_commit_success();
+}
+
+#[test]
+fn cannot_propose_owner_role_without_being_owner() {
+    // Arrange
+    let mut test = OwnerRoleTransferTest::new();
+    let new_owner_rule = test.rule_of(&test.new_owner_public_key);
+
+    // Act
+    let receipt = test.propose(new_owner_rule, test.new_owner_public_key);
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn cannot_accept_owner_role_without_proving_proposed_rule() {
+    // Arrange
+    let mut test = OwnerRoleTransferTest::new();
+    let new_owner_rule = test.rule_of(&test.new_owner_public_key);
+    test.propose(new_owner_rule, test.owner_public_key)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.accept(test.owner_public_key);
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn cannot_accept_owner_role_without_proposal() {
+    // Arrange
+    let mut test = OwnerRoleTransferTest::new();
+
+    // Act
+    let receipt = test.accept(test.new_owner_public_key);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::RoleAssignmentError(
+                RoleAssignmentError::NoOwnerRoleProposed
+            ))
+        )
+    });
+}
+
+#[test]
+fn proposal_lapses_once_owner_role_is_changed() {
+    // Arrange
+    let mut test = OwnerRoleTransferTest::new();
+    let new_owner_rule = test.rule_of(&test.new_owner_public_key);
+    test.propose(new_owner_rule.clone(), test.owner_public_key)
+        .expect_commit_success();
+    test.set_owner(new_owner_rule, test.owner_public_key)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.accept(test.new_owner_public_key);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::RoleAssignmentError(
+                RoleAssignmentError::OwnerRoleProposalOutdated
+            ))
+        )
+    });
+}
+
+struct OwnerRoleTransferTest {
+    test_runner: DefaultTestRunner,
+    component_address: ComponentAddress,
+    owner_public_key: Secp256k1PublicKey,
+    new_owner_public_key: Secp256k1PublicKey,
+}
+
+impl OwnerRoleTransferTest {
+    fn new() -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (owner_public_key, _, _) = test_runner.new_allocated_account();
+        let (new_owner_public_key, _, _) = test_runner.new_allocated_account();
+        let package_address =
+            test_runner.publish_package_simple(PackageLoader::get("role_assignment"));
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "MutableAccessRulesComponent",
+                "new_with_owner",
+                manifest_args!(OwnerRole::Updatable(rule!(require(
+                    NonFungibleGlobalId::from_public_key(&owner_public_key)
+                )))),
+            )
+            .build();
+        let component_address = test_runner
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .new_component_addresses()[0];
+
+        Self {
+            test_runner,
+            component_address,
+            owner_public_key,
+            new_owner_public_key,
+        }
+    }
+
+    fn rule_of(&self, public_key: &Secp256k1PublicKey) -> AccessRule {
+        rule!(require(NonFungibleGlobalId::from_public_key(public_key)))
+    }
+
+    fn propose(&mut self, rule: AccessRule, signer: Secp256k1PublicKey) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .propose_owner_role(self.component_address, rule)
+            .build();
+        self.execute_as(manifest, signer)
+    }
+
+    fn accept(&mut self, signer: Secp256k1PublicKey) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .accept_owner_role(self.component_address)
+            .build();
+        self.execute_as(manifest, signer)
+    }
+
+    fn set_owner(&mut self, rule: AccessRule, signer: Secp256k1PublicKey) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .set_owner_role(self.component_address, rule)
+            .build();
+        self.execute_as(manifest, signer)
+    }
+
+    fn execute_as(
+        &mut self,
+        manifest: TransactionManifestV1,
+        signer: Secp256k1PublicKey,
+    ) -> TransactionReceipt {
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&signer)],
+        )
+    }
+}
@@ -1,11 +1,13 @@
 use radix_engine_tests::common::*;
 use radix_engine::blueprints::package::*;
+use radix_engine::blueprints::transaction_processor::TransactionProcessorError;
 use radix_engine::errors::*;
 use radix_engine::system::system_modules::auth::*;
 use radix_engine::types::*;
 use radix_engine::vm::wasm::PrepareError;
 use radix_engine::vm::wasm::*;
 use radix_engine_common::constants::MAX_NUMBER_OF_BLUEPRINT_FIELDS;
+use radix_engine_interface::blueprints::package::CodeHash;
 use radix_engine_interface::*;
 use sbor::basic_well_known_types::*;
 use scrypto_unit::*;
@@ -52,6 +54,56 @@ fn missing_memory_should_cause_error() {
     });
 }
 
+#[test]
+fn publishing_code_with_static_violations_should_report_all_of_them() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Act
+    let code = wat2wasm(
+        r#"
+            (module
+                (func $init)
+                (func (export "test") (result f32)
+                    f32.const 1
+                )
+                (start $init)
+                (memory $0 1)
+                (export "memory" (memory $0))
+            )
+            "#,
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .publish_package_advanced(
+            None,
+            code,
+            PackageDefinition::default(),
+            BTreeMap::new(),
+            OwnerRole::None,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::ApplicationError(ApplicationError::PackageError(
+            PackageError::InvalidWasm(PrepareError::StaticValidationFailed(diagnostics)),
+        )) => {
+            diagnostics
+                .iter()
+                .map(|d| d.violation.clone())
+                .collect::<Vec<_>>()
+                == vec![
+                    WasmViolation::FloatingPointNotAllowed,
+                    WasmViolation::StartFunctionNotAllowed,
+                    WasmViolation::FloatingPointNotAllowed,
+                ]
+        }
+        _ => false,
+    });
+}
+
 #[test]
 fn large_return_len_should_cause_memory_access_error() {
     // Arrange
@@ -174,6 +226,7 @@ fn test_basic_package_missing_export() {
                             export: "not_exist".to_string(),
                         }
                     ),
+                    post_conditions: index_map_new(),
                 },
                 hooks: BlueprintHooksInit::default(),
             },
@@ -1521,3 +1574,47 @@ fn test_publishing_of_packages_with_invalid_names(name: &str) {
 fn name(len: usize, chr: char) -> String {
     (0..len).map(|_| chr).collect()
 }
+
+#[test]
+fn asserting_the_code_hash_of_a_package_should_succeed() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (code, definition) = PackageLoader::get("package");
+    let code_hash = CodeHash::from_hash(hash(&code));
+    let package_address = test_runner.publish_package_simple((code, definition));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .assert_package_code_hash(package_address, code_hash)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn asserting_a_wrong_code_hash_of_a_package_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("package"));
+    let code_hash = CodeHash::from_hash(hash("not the package code"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .assert_package_code_hash(package_address, code_hash)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::PackageCodeHashAssertionFailed { .. }
+            ))
+        )
+    });
+}
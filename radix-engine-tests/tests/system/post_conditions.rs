@@ -0,0 +1,181 @@
+use radix_engine::blueprints::package::PackageError;
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemUpstreamError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn function_satisfying_post_conditions_succeeds() {
+    // Arrange
+    let mut test = PostConditionsTest::new(vec![
+        FunctionPostCondition::ReturnsResource(XRD),
+        FunctionPostCondition::ReturnsResourceOfArgument(1),
+        FunctionPostCondition::ReturnsAtLeastAmountOfArgument(2),
+    ]);
+
+    // Act
+    let receipt = test.exchange(XRD, dec!("10"), XRD, dec!("10"));
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn function_returning_unexpected_resource_fails() {
+    // Arrange
+    let mut test = PostConditionsTest::new(vec![FunctionPostCondition::ReturnsResource(XRD)]);
+    let token = test.token;
+
+    // Act
+    let receipt = test.exchange(token, dec!("10"), XRD, dec!("10"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemUpstreamError(SystemUpstreamError::PostConditionNotMet(
+                _,
+                FunctionPostCondition::ReturnsResource(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn function_returning_resource_other_than_argument_fails() {
+    // Arrange
+    let mut test =
+        PostConditionsTest::new(vec![FunctionPostCondition::ReturnsResourceOfArgument(1)]);
+    let token = test.token;
+
+    // Act
+    let receipt = test.exchange(XRD, dec!("10"), token, dec!("10"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemUpstreamError(SystemUpstreamError::PostConditionNotMet(
+                _,
+                FunctionPostCondition::ReturnsResourceOfArgument(1)
+            ))
+        )
+    });
+}
+
+#[test]
+fn function_returning_less_than_argument_amount_fails() {
+    // Arrange
+    let mut test =
+        PostConditionsTest::new(vec![FunctionPostCondition::ReturnsAtLeastAmountOfArgument(
+            2,
+        )]);
+
+    // Act
+    let receipt = test.exchange(XRD, dec!("10"), XRD, dec!("11"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemUpstreamError(SystemUpstreamError::PostConditionNotMet(
+                _,
+                FunctionPostCondition::ReturnsAtLeastAmountOfArgument(2)
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_publish_post_conditions_for_unknown_function() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (code, mut definition) = PackageLoader::get("post_conditions");
+    definition
+        .blueprints
+        .get_mut("Exchange")
+        .unwrap()
+        .schema
+        .functions
+        .post_conditions
+        .insert(
+            "unknown".to_string(),
+            vec![FunctionPostCondition::ReturnsResource(XRD)],
+        );
+
+    // Act
+    let receipt = test_runner.try_publish_package((code, definition));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::MissingPostConditionFunction { .. }
+            ))
+        )
+    });
+}
+
+struct PostConditionsTest {
+    test_runner: DefaultTestRunner,
+    package_address: PackageAddress,
+    public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    token: ResourceAddress,
+}
+
+impl PostConditionsTest {
+    fn new(post_conditions: Vec<FunctionPostCondition>) -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (public_key, _, account) = test_runner.new_allocated_account();
+        let token = test_runner.create_fungible_resource(dec!("100"), 18, account);
+
+        let (code, mut definition) = PackageLoader::get("post_conditions");
+        definition
+            .blueprints
+            .get_mut("Exchange")
+            .unwrap()
+            .schema
+            .functions
+            .post_conditions
+            .insert("exchange".to_string(), post_conditions);
+        let package_address = test_runner.publish_package_simple((code, definition));
+
+        Self {
+            test_runner,
+            package_address,
+            public_key,
+            account,
+            token,
+        }
+    }
+
+    fn exchange(
+        &mut self,
+        withdrawn_resource: ResourceAddress,
+        withdrawn_amount: Decimal,
+        resource_argument: ResourceAddress,
+        amount_argument: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(self.account, withdrawn_resource, withdrawn_amount)
+            .take_all_from_worktop(withdrawn_resource, "bucket")
+            .with_name_lookup(|builder, lookup| {
+                builder.call_function(
+                    self.package_address,
+                    "Exchange",
+                    "exchange",
+                    manifest_args!(lookup.bucket("bucket"), resource_argument, amount_argument),
+                )
+            })
+            .try_deposit_entire_worktop_or_abort(self.account, None)
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.public_key)],
+        )
+    }
+}
@@ -0,0 +1,171 @@
+use radix_engine::errors::{RateLimitError, RuntimeError, SystemError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_tests::common::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn global_rate_limit_refuses_calls_beyond_max() {
+    // Arrange
+    let mut env = RateLimitTestEnvironment::new();
+
+    // Act
+    let receipt = env.claim(env.faucet, 3, 2, 10);
+
+    // Assert
+    let claims: Vec<bool> = (1..=3)
+        .map(|i| receipt.expect_commit_success().output(i))
+        .collect();
+    assert_eq!(claims, vec![true, true, false]);
+}
+
+#[test]
+fn refused_calls_are_not_counted() {
+    // Arrange
+    let mut env = RateLimitTestEnvironment::new();
+    env.claim(env.faucet, 1, 1, 2);
+    env.advance_epochs(1);
+    let refused = env.claim(env.faucet, 1, 1, 2);
+    env.advance_epochs(1);
+
+    // Act
+    let receipt = env.claim(env.faucet, 1, 1, 2);
+
+    // Assert
+    assert!(!refused.expect_commit_success().output::<bool>(1));
+    assert!(receipt.expect_commit_success().output::<bool>(1));
+}
+
+#[test]
+fn counted_calls_leave_the_window_as_epochs_pass() {
+    // Arrange
+    let mut env = RateLimitTestEnvironment::new();
+    env.claim(env.faucet, 1, 1, 3);
+
+    // Act
+    env.advance_epochs(2);
+    let within_window = env.claim(env.faucet, 1, 1, 3);
+    env.advance_epochs(1);
+    let after_window = env.claim(env.faucet, 1, 1, 3);
+
+    // Assert
+    assert!(!within_window.expect_commit_success().output::<bool>(1));
+    assert!(after_window.expect_commit_success().output::<bool>(1));
+}
+
+#[test]
+fn per_caller_rate_limit_counts_each_caller_separately() {
+    // Arrange
+    let mut env = RateLimitTestEnvironment::new();
+    let claimer1 = env.new_claimer();
+    let claimer2 = env.new_claimer();
+
+    // Act
+    let first = env.claim(claimer1, 1, 1, 10);
+    let second = env.claim(claimer1, 1, 1, 10);
+    let other_caller = env.claim(claimer2, 1, 1, 10);
+
+    // Assert
+    assert!(first.expect_commit_success().output::<bool>(1));
+    assert!(!second.expect_commit_success().output::<bool>(1));
+    assert!(other_caller.expect_commit_success().output::<bool>(1));
+}
+
+#[test]
+fn per_caller_and_global_rate_limits_of_the_same_name_are_independent() {
+    // Arrange
+    let mut env = RateLimitTestEnvironment::new();
+    let claimer = env.new_claimer();
+    env.claim(env.faucet, 1, 1, 10);
+
+    // Act
+    let receipt = env.claim(claimer, 1, 1, 10);
+
+    // Assert
+    assert!(receipt.expect_commit_success().output::<bool>(1));
+}
+
+#[test]
+fn rate_limit_with_zero_window_fails() {
+    // Arrange
+    let mut env = RateLimitTestEnvironment::new();
+
+    // Act
+    let receipt = env.claim(env.faucet, 1, 1, 0);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::SystemError(SystemError::RateLimitError(
+            RateLimitError::ZeroWindow,
+        )))
+    });
+}
+
+struct RateLimitTestEnvironment {
+    test_runner: DefaultTestRunner,
+    package_address: PackageAddress,
+    faucet: ComponentAddress,
+}
+
+impl RateLimitTestEnvironment {
+    fn new() -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let package_address = test_runner.publish_package_simple(PackageLoader::get("rate_limit"));
+        let faucet = test_runner
+            .execute_manifest(
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .call_function(package_address, "Faucet", "new", manifest_args!())
+                    .build(),
+                vec![],
+            )
+            .expect_commit_success()
+            .new_component_addresses()[0];
+
+        Self {
+            test_runner,
+            package_address,
+            faucet,
+        }
+    }
+
+    fn new_claimer(&mut self) -> ComponentAddress {
+        self.test_runner
+            .execute_manifest(
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .call_function(
+                        self.package_address,
+                        "Claimer",
+                        "new",
+                        manifest_args!(self.faucet),
+                    )
+                    .build(),
+                vec![],
+            )
+            .expect_commit_success()
+            .new_component_addresses()[0]
+    }
+
+    /// Calls `claim` on the given component `calls` times in a single transaction
+    fn claim(
+        &mut self,
+        component: ComponentAddress,
+        calls: usize,
+        max: u32,
+        window_epochs: u64,
+    ) -> TransactionReceipt {
+        let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+        for _ in 0..calls {
+            builder = builder.call_method(component, "claim", manifest_args!(max, window_epochs));
+        }
+        self.test_runner.execute_manifest(builder.build(), vec![])
+    }
+
+    fn advance_epochs(&mut self, epochs: u64) {
+        let epoch = self.test_runner.get_current_epoch();
+        self.test_runner
+            .set_current_epoch(epoch.after(epochs).unwrap());
+    }
+}
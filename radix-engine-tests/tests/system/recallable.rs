@@ -1,9 +1,12 @@
 use radix_engine_tests::common::*;
+use radix_engine::blueprints::resource::{FungibleResourceManagerError, VaultError};
 use radix_engine::errors::{
-    CallFrameError, KernelError, RejectionReason, RuntimeError, SystemModuleError,
+    ApplicationError, CallFrameError, KernelError, RejectionReason, RuntimeError,
+    SystemModuleError,
 };
 use radix_engine::kernel::call_frame::{CreateFrameError, PassMessageError};
 use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use scrypto::prelude::FromPublicKey;
 use scrypto_unit::*;
@@ -182,6 +185,44 @@ fn test_recall_on_received_direct_access_reference() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn test_recall_on_direct_access_vault() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let recallable_token_address = test_runner.create_recallable_token(account);
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("recall"));
+    let vault_id = test_runner.get_component_vaults(account, recallable_token_address)[0];
+    let balance = test_runner.get_component_balance(account, recallable_token_address);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_function(
+                package_address,
+                "RecallTest",
+                "recall_on_direct_access_vault",
+                manifest_args!(InternalAddress::new_or_panic(vault_id.into()), dec!("2")),
+            )
+            .try_deposit_entire_worktop_or_abort(other_account, None)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, recallable_token_address),
+        balance - dec!("2")
+    );
+    assert_eq!(
+        test_runner.get_component_balance(other_account, recallable_token_address),
+        dec!("2")
+    );
+}
+
 #[test]
 fn test_recall_on_received_direct_access_reference_which_is_same_as_self() {
     // Arrange
@@ -224,3 +265,210 @@ fn test_recall_on_received_direct_access_reference_which_is_same_as_self() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn recall_protected_vault_can_be_created_for_non_recallable_resource() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(10u32.into(), 0u8, account);
+
+    // Act
+    let receipt = create_recall_protected_vault(&mut test_runner, resource_address);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn recall_protected_vault_can_be_created_for_resource_with_locked_deny_all_recall() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let resource_address = test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .create_fungible_resource(
+                    OwnerRole::None,
+                    true,
+                    0,
+                    FungibleResourceRoles {
+                        recall_roles: recall_roles! {
+                            recaller => rule!(deny_all);
+                            recaller_updater => rule!(deny_all);
+                        },
+                        ..Default::default()
+                    },
+                    metadata!(),
+                    None,
+                )
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .new_resource_addresses()[0];
+
+    // Act
+    let receipt = create_recall_protected_vault(&mut test_runner, resource_address);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn recall_protected_vault_cannot_be_created_for_recallable_resource() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let recallable_token = test_runner.create_recallable_token(account);
+
+    // Act
+    let receipt = create_recall_protected_vault(&mut test_runner, recallable_token);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(
+                VaultError::RecallNotLocked(address)
+            )) if address.eq(&recallable_token)
+        )
+    });
+}
+
+#[test]
+fn can_recall_batch_from_multiple_vaults() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let (_, _, recipient) = test_runner.new_allocated_account();
+    let recallable_token = test_runner.create_recallable_token(account);
+    test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .withdraw_from_account(account, recallable_token, 2)
+                .try_deposit_entire_worktop_or_abort(other_account, None)
+                .build(),
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+    let vault_id = test_runner.get_component_vaults(account, recallable_token)[0];
+    let other_vault_id = test_runner.get_component_vaults(other_account, recallable_token)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall_batch(
+            recallable_token,
+            [
+                (InternalAddress::new_or_panic(vault_id.into()), dec!(1)),
+                (
+                    InternalAddress::new_or_panic(other_vault_id.into()),
+                    dec!(2),
+                ),
+            ],
+        )
+        .try_deposit_entire_worktop_or_abort(recipient, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, recallable_token),
+        dec!(2)
+    );
+    assert_eq!(
+        test_runner.get_component_balance(other_account, recallable_token),
+        dec!(0)
+    );
+    assert_eq!(
+        test_runner.get_component_balance(recipient, recallable_token),
+        dec!(3)
+    );
+}
+
+#[test]
+fn cannot_recall_batch_on_non_recallable_resource() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(10u32.into(), 0u8, account);
+    let vault_id = test_runner.get_component_vaults(account, resource_address)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall_batch(
+            resource_address,
+            [(InternalAddress::new_or_panic(vault_id.into()), dec!(1))],
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                AuthError::Unauthorized { .. },
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_recall_batch_from_vault_of_other_resource() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let recallable_token = test_runner.create_recallable_token(account);
+    let other_recallable_token = test_runner.create_recallable_token(account);
+    let other_vault_id = test_runner.get_component_vaults(account, other_recallable_token)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall_batch(
+            recallable_token,
+            [(
+                InternalAddress::new_or_panic(other_vault_id.into()),
+                dec!(1),
+            )],
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::FungibleResourceManagerError(
+                FungibleResourceManagerError::InvalidRecallBatchVault(node_id)
+            )) if node_id.eq(&other_vault_id)
+        )
+    });
+}
+
+fn create_recall_protected_vault(
+    test_runner: &mut DefaultTestRunner,
+    resource_address: ResourceAddress,
+) -> TransactionReceipt {
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("recall"));
+    test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "RecallTest",
+                "new_with_recall_protected_vault",
+                manifest_args!(resource_address),
+            )
+            .build(),
+        vec![],
+    )
+}
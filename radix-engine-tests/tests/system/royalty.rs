@@ -287,6 +287,98 @@ fn test_claim_royalty() {
     );
 }
 
+#[test]
+fn test_package_royalty_exemption() {
+    let (
+        mut test_runner,
+        account,
+        public_key,
+        package_address,
+        component_address,
+        owner_badge_resource,
+    ) = set_up_package_and_component();
+
+    // Exempt calls made directly from the transaction processor
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .create_proof_from_account_of_non_fungibles(
+                account,
+                owner_badge_resource,
+                [NonFungibleLocalId::integer(1)],
+            )
+            .add_package_royalty_exemption(package_address, TRANSACTION_PROCESSOR_PACKAGE)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit(true);
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_method(component_address, "paid_method", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit(true);
+    assert_eq!(receipt.fee_summary.total_royalty_cost_in_xrd, dec!(1));
+    assert_eq!(
+        test_runner.inspect_package_royalty(package_address),
+        Some(dec!("0"))
+    );
+
+    // Remove the exemption again
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .create_proof_from_account_of_non_fungibles(
+                account,
+                owner_badge_resource,
+                [NonFungibleLocalId::integer(1)],
+            )
+            .remove_package_royalty_exemption(package_address, TRANSACTION_PROCESSOR_PACKAGE)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit(true);
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_method(component_address, "paid_method", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit(true);
+    assert_eq!(receipt.fee_summary.total_royalty_cost_in_xrd, dec!("3"));
+    assert_eq!(
+        test_runner.inspect_package_royalty(package_address),
+        Some(dec!("2"))
+    );
+}
+
+#[test]
+fn cannot_add_package_royalty_exemption_without_owner_badge() {
+    let (
+        mut test_runner,
+        account,
+        public_key,
+        package_address,
+        _component_address,
+        _owner_badge_resource,
+    ) = set_up_package_and_component();
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .add_package_royalty_exemption(package_address, account)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    receipt.expect_specific_failure(is_auth_error);
+}
+
 fn cannot_initialize_package_royalty_if_greater_than_allowed(royalty_amount: RoyaltyAmount) {
     // Arrange
     let mut test_runner = TestRunnerBuilder::new().build();
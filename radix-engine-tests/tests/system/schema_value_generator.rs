@@ -0,0 +1,132 @@
+use radix_engine::types::*;
+use radix_engine_tests::common::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn generated_method_args_are_accepted_by_blueprint() {
+    // Arrange
+    let (mut test_runner, package_address, component_address) = setup_fuzz_target();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let address_pool = test_runner.address_pool([*account.as_node_id(), *XRD.as_node_id()]);
+    let blueprint_id = BlueprintId::new(&package_address, "FuzzTarget");
+
+    for seed in 0..20 {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let args = test_runner
+            .generate_function_args(&blueprint_id, "accept", &address_pool, &mut rng)
+            .unwrap();
+
+        // Act
+        let receipt = test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method_raw(component_address, "accept", args)
+                .build(),
+            vec![],
+        );
+
+        // Assert
+        receipt.expect_commit_success();
+    }
+}
+
+#[test]
+fn generating_args_is_deterministic_for_a_given_seed() {
+    // Arrange
+    let (mut test_runner, package_address, _) = setup_fuzz_target();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let address_pool = test_runner.address_pool([*account.as_node_id(), *XRD.as_node_id()]);
+    let blueprint_id = BlueprintId::new(&package_address, "FuzzTarget");
+    let (local_type_id, schema) = test_runner.function_input_schema(&blueprint_id, "accept");
+    let generator = ManifestValueGenerator::new(schema.v1(), &address_pool);
+
+    // Act
+    let first = generator.generate(local_type_id, &mut ChaCha8Rng::seed_from_u64(7));
+    let second = generator.generate(local_type_id, &mut ChaCha8Rng::seed_from_u64(7));
+
+    // Assert
+    assert_eq!(first, second);
+}
+
+#[test]
+fn generating_args_with_owned_input_fails() {
+    // Arrange
+    let (test_runner, package_address, _) = setup_fuzz_target();
+    let address_pool = test_runner.address_pool([*XRD.as_node_id()]);
+
+    // Act
+    let result = test_runner.generate_function_args(
+        &BlueprintId::new(&package_address, "FuzzTarget"),
+        "take",
+        &address_pool,
+        &mut ChaCha8Rng::seed_from_u64(0),
+    );
+
+    // Assert
+    assert!(matches!(result, Err(ValueGenerationError::UnsupportedOwnedType(..))));
+}
+
+#[test]
+fn generating_args_without_matching_address_fails() {
+    // Arrange
+    let (test_runner, package_address, _) = setup_fuzz_target();
+    let address_pool = test_runner.address_pool([*XRD.as_node_id()]);
+
+    // Act
+    let result = test_runner.generate_function_args(
+        &BlueprintId::new(&package_address, "FuzzTarget"),
+        "accept",
+        &address_pool,
+        &mut ChaCha8Rng::seed_from_u64(0),
+    );
+
+    // Assert
+    assert!(matches!(result, Err(ValueGenerationError::NoMatchingAddress(..))));
+}
+
+#[test]
+fn generated_values_respect_length_validation() {
+    // Arrange
+    let (local_type_id, mut schema) =
+        generate_full_schema_from_single_type::<Vec<String>, ScryptoCustomSchema>();
+    let LocalTypeId::SchemaLocalIndex(index) = local_type_id else {
+        panic!("Vec<String> is not expected to be a well known type");
+    };
+    schema.v1_mut().type_validations[index] = TypeValidation::Array(LengthValidation {
+        min: Some(3),
+        max: Some(5),
+    });
+    let address_pool = AddressPool::new();
+    let generator = ManifestValueGenerator::new(schema.v1(), &address_pool);
+
+    for seed in 0..20 {
+        // Act
+        let value = generator
+            .generate(local_type_id, &mut ChaCha8Rng::seed_from_u64(seed))
+            .unwrap();
+
+        // Assert
+        let strings: Vec<String> = manifest_decode(&manifest_encode(&value).unwrap()).unwrap();
+        assert!(strings.len() >= 3 && strings.len() <= 5);
+    }
+}
+
+fn setup_fuzz_target() -> (DefaultTestRunner, PackageAddress, ComponentAddress) {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("fuzz_target"));
+    let component_address = test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(package_address, "FuzzTarget", "new", manifest_args!())
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    (test_runner, package_address, component_address)
+}
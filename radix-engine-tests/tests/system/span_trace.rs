@@ -0,0 +1,112 @@
+use radix_engine::system::system_modules::span_trace::*;
+use radix_engine::transaction::{CostingParameters, ExecutionConfig, TransactionReceipt};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn execute_with_span_trace(enabled: bool) -> (TransactionReceipt, ComponentAddress) {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!("10"))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    let nonce = test_runner.next_transaction_nonce();
+    let receipt = test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!(NonFungibleGlobalId::from_public_key(&public_key))),
+        CostingParameters::default(),
+        ExecutionConfig::for_test_transaction().with_span_trace(enabled),
+    );
+    (receipt, account)
+}
+
+#[test]
+fn span_trace_is_only_recorded_when_enabled() {
+    // Act
+    let (receipt, _) = execute_with_span_trace(false);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(receipt.span_trace.is_none());
+}
+
+#[test]
+fn span_trace_nests_call_frames_and_kernel_operations() {
+    // Act
+    let (receipt, account) = execute_with_span_trace(true);
+
+    // Assert
+    receipt.expect_commit_success();
+    let trace = receipt.span_trace.as_ref().unwrap();
+
+    // The transaction processor is the only top-level span
+    let roots: Vec<_> = trace
+        .spans
+        .iter()
+        .filter(|span| span.parent_span_id.is_none())
+        .collect();
+    assert_eq!(roots.len(), 1);
+    assert_eq!(roots[0].kind, SpanKind::CallFrame);
+    assert_eq!(roots[0].name, "TransactionProcessor::run");
+
+    let withdraw = trace
+        .spans
+        .iter()
+        .find(|span| {
+            span.name == "Account::withdraw"
+                && span.attribute(SPAN_ATTRIBUTE_NODE_ID)
+                    == Some(&SpanAttributeValue::String(account.as_node_id().to_hex()))
+        })
+        .unwrap();
+    assert!(trace
+        .children(withdraw.span_id)
+        .any(|span| span.kind == SpanKind::CallFrame));
+    assert!(trace
+        .children(withdraw.span_id)
+        .any(|span| span.kind == SpanKind::KernelOperation && span.name == "open_substate"));
+
+    for span in &trace.spans {
+        assert!(span.start_time_unix_nano <= span.end_time_unix_nano);
+        if let Some(parent_span_id) = span.parent_span_id {
+            assert!(parent_span_id < span.span_id);
+        }
+    }
+}
+
+#[test]
+fn span_trace_attributes_execution_cost_to_call_frames() {
+    // Act
+    let (receipt, _) = execute_with_span_trace(true);
+
+    // Assert
+    receipt.expect_commit_success();
+    let trace = receipt.span_trace.as_ref().unwrap();
+    let cost_units = |span: &TraceSpan| match span.attribute(SPAN_ATTRIBUTE_EXECUTION_COST_UNITS) {
+        Some(SpanAttributeValue::Int(cost_units)) => *cost_units,
+        _ => panic!("Span {} has no execution cost attribute", span.name),
+    };
+    for span in &trace.spans {
+        if span.kind == SpanKind::CallFrame {
+            let children_cost_units: i64 = trace
+                .children(span.span_id)
+                .filter(|child| child.kind == SpanKind::CallFrame)
+                .map(cost_units)
+                .sum();
+            assert!(cost_units(span) >= children_cost_units);
+        }
+    }
+    let root = &trace.spans[0];
+    assert!(cost_units(root) > 0);
+    assert!(
+        cost_units(root)
+            <= receipt
+                .fee_summary
+                .total_execution_cost_units_consumed
+                .into()
+    );
+}
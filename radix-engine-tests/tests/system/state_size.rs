@@ -0,0 +1,174 @@
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::limits::TransactionLimitsError;
+use radix_engine::transaction::{CostingParameters, ExecutionConfig, TransactionReceipt};
+use radix_engine::types::*;
+use radix_engine_store_interface::db_key_mapper::{MappedSubstateDatabase, SpreadPrefixKeyMapper};
+use radix_engine_tests::common::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn component_can_query_its_committed_state_size() {
+    // Arrange
+    let (mut test_runner, component_address) = setup_state_size_component();
+
+    // Act
+    let state_size: NodeStateSize = test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(component_address, "state_size", manifest_args!())
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .output(1);
+
+    // Assert
+    assert!(state_size.substate_count > 0);
+    assert_eq!(
+        state_size,
+        committed_state_size(&test_runner, component_address.as_node_id())
+    );
+}
+
+#[test]
+fn state_size_accounts_for_written_bytes() {
+    // Arrange
+    let (mut test_runner, component_address) = setup_state_size_component();
+    let before = committed_state_size(&test_runner, component_address.as_node_id());
+
+    // Act
+    test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(component_address, "grow", manifest_args!(1000usize))
+                .build(),
+            vec![],
+        )
+        .expect_commit_success();
+
+    // Assert
+    let after = committed_state_size(&test_runner, component_address.as_node_id());
+    assert_eq!(after.substate_count, before.substate_count);
+    assert!(after.total_bytes >= before.total_bytes + 1000);
+}
+
+#[test]
+fn exceeding_node_substate_size_limit_fails() {
+    // Arrange
+    let (mut test_runner, component_address) = setup_state_size_component();
+    let mut execution_config = ExecutionConfig::for_test_transaction();
+    execution_config.max_node_substate_total_bytes = 64 * 1024;
+
+    // Act
+    let receipt = execute_with_config(
+        &mut test_runner,
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component_address, "grow", manifest_args!(100 * 1024usize))
+            .build(),
+        execution_config,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+            TransactionLimitsError::NodeSubstateSizeExceeded { node_id, max, .. },
+        )) => node_id.eq(component_address.as_node_id()) && *max == 64 * 1024,
+        _ => false,
+    });
+}
+
+#[test]
+fn exceeding_node_substate_count_limit_fails() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("transaction_limits"));
+    let component_address = test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(
+                    package_address,
+                    "TransactionLimitTest",
+                    "new",
+                    manifest_args!(),
+                )
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .new_component_addresses()[0];
+    let mut execution_config = ExecutionConfig::for_test_transaction();
+    execution_config.max_node_substate_count = 32;
+
+    // Act
+    let receipt = execute_with_config(
+        &mut test_runner,
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                component_address,
+                "write_entries_to_kv_store",
+                manifest_args!(64u32),
+            )
+            .build(),
+        execution_config,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::NodeSubstateCountExceeded { max: 32, .. }
+            ))
+        )
+    });
+}
+
+fn setup_state_size_component() -> (DefaultTestRunner, ComponentAddress) {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("state_size"));
+    let component_address = test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(package_address, "StateSize", "new", manifest_args!())
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    (test_runner, component_address)
+}
+
+fn committed_state_size(test_runner: &DefaultTestRunner, node_id: &NodeId) -> NodeStateSize {
+    test_runner
+        .substate_db()
+        .get_mapped::<SpreadPrefixKeyMapper, NodeStateSize>(
+            node_id,
+            STATE_SIZE_PARTITION,
+            &StateSizeField::StateSize.into(),
+        )
+        .unwrap()
+}
+
+fn execute_with_config(
+    test_runner: &mut DefaultTestRunner,
+    manifest: TransactionManifestV1,
+    execution_config: ExecutionConfig,
+) -> TransactionReceipt {
+    let prepared = TestTransaction::new_from_nonce(manifest, 10)
+        .prepare()
+        .unwrap();
+    test_runner.execute_transaction(
+        prepared.get_executable(btreeset!()),
+        CostingParameters::default(),
+        execution_config,
+    )
+}
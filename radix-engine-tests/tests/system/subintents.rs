@@ -0,0 +1,285 @@
+use radix_engine::blueprints::transaction_processor::TransactionProcessorError;
+use radix_engine::errors::{ApplicationError, RejectionReason, RuntimeError};
+use radix_engine::transaction::{CostingParameters, ExecutionConfig, TransactionReceipt};
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::transaction_processor::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+const OFFERED_AMOUNT: Decimal = dec!("100");
+const PRICE: Decimal = dec!("50");
+
+#[test]
+fn taker_can_fill_subintent_by_providing_required_inputs() {
+    // Arrange
+    let mut env = SubintentTestEnvironment::new();
+    let subintent = env.maker_subintent(PRICE, OFFERED_AMOUNT);
+    let maker_xrd_balance = env.balance(env.maker_account, XRD);
+
+    // Act
+    let receipt = env.fill(subintent, PRICE);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        env.balance(env.maker_account, XRD),
+        maker_xrd_balance + PRICE
+    );
+    assert_eq!(env.balance(env.taker_account, env.token), OFFERED_AMOUNT);
+}
+
+#[test]
+fn subintent_fails_if_required_inputs_are_not_provided() {
+    // Arrange
+    let mut env = SubintentTestEnvironment::new();
+    let subintent = env.maker_subintent(PRICE, OFFERED_AMOUNT);
+
+    // Act
+    let receipt = env.fill(subintent, PRICE - dec!("1"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::SubintentInputNotProvided { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn subintent_fails_if_declared_outputs_are_not_produced() {
+    // Arrange
+    let mut env = SubintentTestEnvironment::new();
+    let subintent = env.maker_subintent_advanced(
+        PRICE,
+        OFFERED_AMOUNT,
+        SubintentConstraints {
+            required_inputs: indexmap!(XRD => PRICE),
+            declared_outputs: indexmap!(env.token => OFFERED_AMOUNT + dec!("1")),
+        },
+    );
+
+    // Act
+    let receipt = env.fill(subintent, PRICE);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::SubintentOutputNotProduced { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn taker_cannot_use_authority_of_subintent_signers() {
+    // Arrange
+    let mut env = SubintentTestEnvironment::new();
+    let subintent = env.maker_subintent(PRICE, OFFERED_AMOUNT);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.taker_account, XRD, PRICE)
+        .take_from_worktop(XRD, PRICE, "payment")
+        .execute_subintent(subintent, ["payment"])
+        .withdraw_from_account(env.maker_account, env.token, dec!("1"))
+        .try_deposit_entire_worktop_or_abort(env.taker_account, None)
+        .build();
+    let receipt = env.execute_as_taker(manifest);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn altered_subintent_has_no_signer_authority() {
+    // Arrange
+    let mut env = SubintentTestEnvironment::new();
+    let mut subintent = env.maker_subintent(PRICE, OFFERED_AMOUNT);
+    subintent.subintent.instructions = env
+        .maker_subintent(PRICE, dec!("1000"))
+        .subintent
+        .instructions;
+    subintent
+        .subintent
+        .constraints
+        .declared_outputs
+        .insert(env.token, dec!("1000"));
+
+    // Act
+    let receipt = env.fill(subintent, PRICE);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn subintent_processor_cannot_be_called_directly() {
+    // Arrange
+    let mut env = SubintentTestEnvironment::new();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            TRANSACTION_PROCESSOR_PACKAGE,
+            SUBINTENT_PROCESSOR_BLUEPRINT,
+            SUBINTENT_PROCESSOR_RUN_IDENT,
+            manifest_args!(
+                manifest_encode(&Vec::<InstructionV1>::new()).unwrap(),
+                Vec::<Reference>::new(),
+                Vec::<ManifestBucket>::new(),
+                SubintentConstraints::default()
+            ),
+        )
+        .build();
+    let receipt = env.execute_as_taker(manifest);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::SubintentProcessorCalledDirectly
+            ))
+        )
+    });
+}
+
+#[test]
+fn subintent_cannot_be_executed_by_two_transactions() {
+    // Arrange
+    let mut env = SubintentTestEnvironment::new();
+    let subintent = env.maker_subintent(PRICE, OFFERED_AMOUNT);
+    env.fill_notarized(subintent.clone(), PRICE)
+        .expect_commit_success();
+
+    // Act
+    let receipt = env.fill_notarized(subintent, PRICE);
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        matches!(e, RejectionReason::SubintentHashPreviouslyCommitted)
+    });
+}
+
+struct SubintentTestEnvironment {
+    test_runner: DefaultTestRunner,
+    maker_private_key: Secp256k1PrivateKey,
+    maker_account: ComponentAddress,
+    taker_public_key: Secp256k1PublicKey,
+    taker_private_key: Secp256k1PrivateKey,
+    taker_account: ComponentAddress,
+    token: ResourceAddress,
+}
+
+impl SubintentTestEnvironment {
+    fn new() -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (_, maker_private_key, maker_account) = test_runner.new_allocated_account();
+        let (taker_public_key, taker_private_key, taker_account) =
+            test_runner.new_allocated_account();
+        let token = test_runner.create_fungible_resource(dec!("1000"), 18, maker_account);
+
+        Self {
+            test_runner,
+            maker_private_key,
+            maker_account,
+            taker_public_key,
+            taker_private_key,
+            taker_account,
+            token,
+        }
+    }
+
+    fn balance(&mut self, account: ComponentAddress, resource: ResourceAddress) -> Decimal {
+        self.test_runner.get_component_balance(account, resource)
+    }
+
+    /// A subintent selling `amount` of the token for `price` XRD.
+    fn maker_subintent(&self, price: Decimal, amount: Decimal) -> SignedSubintentV1 {
+        self.maker_subintent_advanced(
+            price,
+            amount,
+            SubintentConstraints {
+                required_inputs: indexmap!(XRD => price),
+                declared_outputs: indexmap!(self.token => amount),
+            },
+        )
+    }
+
+    fn maker_subintent_advanced(
+        &self,
+        price: Decimal,
+        amount: Decimal,
+        constraints: SubintentConstraints,
+    ) -> SignedSubintentV1 {
+        let manifest = ManifestBuilder::new()
+            .take_from_worktop(XRD, price, "payment")
+            .deposit(self.maker_account, "payment")
+            .withdraw_from_account(self.maker_account, self.token, amount)
+            .build();
+        SubintentV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: Epoch::zero(),
+            end_epoch_exclusive: Epoch::of(99),
+            nonce: 0,
+            instructions: manifest.instructions,
+            constraints,
+        }
+        .sign(&[&self.maker_private_key])
+    }
+
+    fn fill_manifest(
+        &self,
+        subintent: SignedSubintentV1,
+        payment: Decimal,
+    ) -> TransactionManifestV1 {
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(self.taker_account, XRD, payment)
+            .take_from_worktop(XRD, payment, "payment")
+            .execute_subintent(subintent, ["payment"])
+            .try_deposit_entire_worktop_or_abort(self.taker_account, None)
+            .build()
+    }
+
+    fn fill(&mut self, subintent: SignedSubintentV1, payment: Decimal) -> TransactionReceipt {
+        let manifest = self.fill_manifest(subintent, payment);
+        self.execute_as_taker(manifest)
+    }
+
+    fn fill_notarized(
+        &mut self,
+        subintent: SignedSubintentV1,
+        payment: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = self.fill_manifest(subintent, payment);
+        let network = NetworkDefinition::simulator();
+        let transaction = create_notarized_transaction_advanced(
+            &mut self.test_runner,
+            &network,
+            manifest,
+            vec![&self.taker_private_key],
+            &self.taker_private_key,
+            false,
+        );
+        self.test_runner.execute_transaction(
+            validate_notarized_transaction(&network, &transaction).get_executable(),
+            CostingParameters::default(),
+            ExecutionConfig::for_notarized_transaction(network.clone()),
+        )
+    }
+
+    fn execute_as_taker(&mut self, manifest: TransactionManifestV1) -> TransactionReceipt {
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.taker_public_key)],
+        )
+    }
+}
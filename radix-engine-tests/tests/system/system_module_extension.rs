@@ -0,0 +1,205 @@
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::kernel::kernel_api::KernelInvocation;
+use radix_engine::kernel::kernel_callback_api::ReadSubstateEvent;
+use radix_engine::system::actor::Actor;
+use radix_engine::system::system_modules::extension::*;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Record {
+    BeforeInvoke { extension: String, depth: usize },
+    AfterInvoke { extension: String, depth: usize },
+    ReadSubstate { extension: String },
+    LockFee { amount: Decimal, contingent: bool },
+}
+
+struct RecordingExtension {
+    name: String,
+    order: i32,
+    enabled: Rc<RefCell<bool>>,
+    abort_on_invoke_of: Option<ComponentAddress>,
+    records: Rc<RefCell<Vec<Record>>>,
+}
+
+impl RecordingExtension {
+    fn new(name: &str, order: i32, records: Rc<RefCell<Vec<Record>>>) -> Self {
+        Self {
+            name: name.to_string(),
+            order,
+            enabled: Rc::new(RefCell::new(true)),
+            abort_on_invoke_of: None,
+            records,
+        }
+    }
+}
+
+impl SystemModuleExtension for RecordingExtension {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn order(&self) -> i32 {
+        self.order
+    }
+
+    fn is_enabled(&self) -> bool {
+        *self.enabled.borrow()
+    }
+
+    fn before_invoke(
+        &mut self,
+        invocation: &KernelInvocation<Actor>,
+        depth: usize,
+    ) -> Result<(), String> {
+        if let Some(component) = self.abort_on_invoke_of {
+            if invocation.call_frame_data.node_id() == Some(*component.as_node_id()) {
+                return Err("component is blocked".to_string());
+            }
+        }
+        self.records.borrow_mut().push(Record::BeforeInvoke {
+            extension: self.name.clone(),
+            depth,
+        });
+        Ok(())
+    }
+
+    fn after_invoke(&mut self, _output: &IndexedScryptoValue, depth: usize) -> Result<(), String> {
+        self.records.borrow_mut().push(Record::AfterInvoke {
+            extension: self.name.clone(),
+            depth,
+        });
+        Ok(())
+    }
+
+    fn on_read_substate(&mut self, _event: &ReadSubstateEvent) -> Result<(), String> {
+        self.records.borrow_mut().push(Record::ReadSubstate {
+            extension: self.name.clone(),
+        });
+        Ok(())
+    }
+
+    fn on_lock_fee(&mut self, _vault_id: &NodeId, amount: Decimal, contingent: bool) {
+        self.records
+            .borrow_mut()
+            .push(Record::LockFee { amount, contingent });
+    }
+}
+
+fn transfer_with_extensions(extensions: Vec<RecordingExtension>) -> TransactionReceipt {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, dec!("50"))
+        .withdraw_from_account(account, XRD, dec!("10"))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    test_runner.execute_manifest_with_extensions(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        extensions
+            .into_iter()
+            .map(|extension| Box::new(extension) as Box<dyn SystemModuleExtension>)
+            .collect(),
+    )
+}
+
+#[test]
+fn extension_observes_invocations_substate_reads_and_fee_locks() {
+    // Arrange
+    let records = Rc::new(RefCell::new(Vec::new()));
+    let extension = RecordingExtension::new("recorder", 0, records.clone());
+
+    // Act
+    let receipt = transfer_with_extensions(vec![extension]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let records = records.borrow();
+    let count = |f: fn(&Record) -> bool| records.iter().filter(|record| f(record)).count();
+    let before_invokes = count(|record| matches!(record, Record::BeforeInvoke { .. }));
+    let after_invokes = count(|record| matches!(record, Record::AfterInvoke { .. }));
+    assert!(before_invokes > 0);
+    assert_eq!(before_invokes, after_invokes);
+    assert!(count(|record| matches!(record, Record::ReadSubstate { .. })) > 0);
+    assert!(records.contains(&Record::LockFee {
+        amount: dec!("50"),
+        contingent: false,
+    }));
+}
+
+#[test]
+fn extensions_are_called_by_ascending_order() {
+    // Arrange
+    let records = Rc::new(RefCell::new(Vec::new()));
+    let late = RecordingExtension::new("late", 10, records.clone());
+    let early = RecordingExtension::new("early", -10, records.clone());
+    let middle = RecordingExtension::new("middle", 0, records.clone());
+
+    // Act
+    let receipt = transfer_with_extensions(vec![late, early, middle]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let first_invoke: Vec<_> = records
+        .borrow()
+        .iter()
+        .filter_map(|record| match record {
+            Record::BeforeInvoke { extension, .. } => Some(extension.clone()),
+            _ => None,
+        })
+        .take(3)
+        .collect();
+    assert_eq!(first_invoke, vec!["early", "middle", "late"]);
+}
+
+#[test]
+fn disabled_extension_is_not_called() {
+    // Arrange
+    let records = Rc::new(RefCell::new(Vec::new()));
+    let extension = RecordingExtension::new("recorder", 0, records.clone());
+    *extension.enabled.borrow_mut() = false;
+
+    // Act
+    let receipt = transfer_with_extensions(vec![extension]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(records.borrow().is_empty());
+}
+
+#[test]
+fn extension_can_abort_transaction() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let records = Rc::new(RefCell::new(Vec::new()));
+    let mut extension = RecordingExtension::new("firewall", 0, records);
+    extension.abort_on_invoke_of = Some(other_account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!("10"))
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    let receipt = test_runner.execute_manifest_with_extensions(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        vec![Box::new(extension)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::SystemModuleError(
+            SystemModuleError::ExtensionError(ExtensionError::TransactionAborted {
+                extension: "firewall".to_string(),
+                reason: "component is blocked".to_string(),
+            }),
+        ))
+    });
+}
@@ -1,5 +1,6 @@
 use radix_engine::errors::ApplicationError;
 use radix_engine::errors::RuntimeError;
+use radix_engine::track::{NodeStateUpdates, PartitionStateUpdates};
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use radix_engine_queries::typed_substate_layout::VaultError;
@@ -32,3 +33,73 @@ fn test_lock_fee_and_then_withdraw_failure() {
         )
     });
 }
+
+#[test]
+fn read_write_set_covers_every_state_update() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    for (node_id, node_updates) in &commit.state_updates.by_node {
+        let NodeStateUpdates::Delta { by_partition } = node_updates;
+        for (partition_num, partition_updates) in by_partition {
+            if let PartitionStateUpdates::Delta { by_substate } = partition_updates {
+                for substate_key in by_substate.keys() {
+                    assert!(commit.read_write_set.writes.contains(&(
+                        *node_id,
+                        *partition_num,
+                        substate_key.clone()
+                    )));
+                }
+            }
+        }
+    }
+    let faucet_vault = test_runner.get_component_vaults(FAUCET, XRD)[0];
+    assert!(commit
+        .read_write_set
+        .reads
+        .iter()
+        .any(|(node_id, _, _)| node_id.eq(&faucet_vault)));
+}
+
+#[test]
+fn transactions_depositing_into_the_same_account_conflict() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let deposit = || {
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build()
+    };
+
+    // Act
+    let first = test_runner.execute_manifest(deposit(), vec![]);
+    let second = test_runner.execute_manifest(deposit(), vec![]);
+
+    // Assert
+    let account_vault = test_runner.get_component_vaults(account, XRD)[0];
+    let first = &first.expect_commit_success().read_write_set;
+    let second = &second.expect_commit_success().read_write_set;
+    assert!(first.conflicts_with(second));
+    assert!(first
+        .writes
+        .iter()
+        .any(|(node_id, _, _)| node_id.eq(&account_vault)));
+    assert!(second
+        .reads
+        .iter()
+        .any(|(node_id, _, _)| node_id.eq(&account_vault)));
+}
@@ -2,7 +2,7 @@ use radix_engine_tests::common::*;
 use radix_engine::{
     errors::{RuntimeError, SystemModuleError, VmError},
     system::system_modules::limits::TransactionLimitsError,
-    transaction::{CostingParameters, ExecutionConfig},
+    transaction::{CostingParameters, ExecutionConfig, TransactionReceipt},
     types::*,
     vm::wasm::WasmRuntimeError,
 };
@@ -260,8 +260,56 @@ fn test_default_invoke_payload_size_limit() {
     // Assert #2
     receipt.expect_specific_failure(|e| match e {
         RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
-            TransactionLimitsError::MaxInvokePayloadSizeExceeded(_),
-        )) => true,
+            TransactionLimitsError::InvokeInputSizeExceeded {
+                fn_identifier: Some(fn_identifier),
+                ..
+            },
+        )) => fn_identifier.ident == "callee",
+        _ => false,
+    })
+}
+
+#[test]
+fn test_invoke_output_size_exceeding_limit() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("transaction_limits"));
+    let mut execution_config = ExecutionConfig::for_test_transaction();
+    execution_config.max_invoke_output_size = 64 * 1024;
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "InvokeLimitsTest",
+            "return_of_size",
+            manifest_args!(128 * 1024usize),
+        )
+        .build();
+    let transactions = TestTransaction::new_from_nonce(manifest, 10);
+    let prepared = transactions.prepare().unwrap();
+    let receipt = test_runner.execute_transaction(
+        prepared.get_executable(btreeset!()),
+        CostingParameters::default(),
+        execution_config,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+            TransactionLimitsError::InvokeOutputSizeExceeded {
+                fn_identifier: Some(fn_identifier),
+                actual,
+                max,
+            },
+        )) => {
+            fn_identifier.blueprint_id == BlueprintId::new(&package_address, "InvokeLimitsTest")
+                && fn_identifier.ident == "return_of_size"
+                && *actual > 128 * 1024
+                && *max == 64 * 1024
+        }
         _ => false,
     })
 }
@@ -383,3 +431,111 @@ fn test_allocating_buffers_exceeding_limit() {
         )
     });
 }
+
+fn call_recursive_with_touched_memory(
+    n: u32,
+    m: usize,
+    execution_config: ExecutionConfig,
+) -> TransactionReceipt {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("transaction_limits"));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionLimitTest",
+            "recursive_with_touched_memory",
+            manifest_args!(n, m),
+        )
+        .build();
+    let transactions = TestTransaction::new_from_nonce(manifest, 10);
+    let prepared = transactions.prepare().unwrap();
+    test_runner.execute_transaction(
+        prepared.get_executable(btreeset!()),
+        CostingParameters::default(),
+        execution_config,
+    )
+}
+
+#[test]
+fn test_wasm_memory_within_default_limits() {
+    // Act
+    let receipt =
+        call_recursive_with_touched_memory(4, 1024 * 1024, ExecutionConfig::for_test_transaction());
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_wasm_memory_per_call_frame_exceeding_limit() {
+    // Arrange
+    let mut execution_config = ExecutionConfig::for_test_transaction();
+    execution_config.max_wasm_memory_pages_per_call_frame = 32;
+
+    // Act
+    let receipt = call_recursive_with_touched_memory(1, 2 * 1024 * 1024, execution_config);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::WasmMemoryPagesPerCallFrameExceeded { max: 32, .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn test_wasm_memory_per_transaction_exceeding_limit() {
+    // Arrange
+    let mut execution_config = ExecutionConfig::for_test_transaction();
+    execution_config.max_wasm_memory_pages_per_transaction = 96;
+
+    // Act
+    let receipt = call_recursive_with_touched_memory(4, 1024 * 1024, execution_config);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::WasmMemoryPagesPerTransactionExceeded { max: 96, .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn test_wasm_memory_of_returned_call_frames_is_released() {
+    // Arrange
+    let mut execution_config = ExecutionConfig::for_test_transaction();
+    execution_config.max_wasm_memory_pages_per_transaction = 96;
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("transaction_limits"));
+
+    // Act
+    let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+    for _ in 0..4 {
+        builder = builder.call_function(
+            package_address,
+            "TransactionLimitTest",
+            "recursive_with_touched_memory",
+            manifest_args!(1u32, 1024 * 1024usize),
+        );
+    }
+    let transactions = TestTransaction::new_from_nonce(builder.build(), 10);
+    let prepared = transactions.prepare().unwrap();
+    let receipt = test_runner.execute_transaction(
+        prepared.get_executable(btreeset!()),
+        CostingParameters::default(),
+        execution_config,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
@@ -0,0 +1,98 @@
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn plaintext_message_is_recorded_in_receipt() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let message = PlaintextMessageV1 {
+        mime_type: "text/plain".to_owned(),
+        message: MessageContentsV1::String("Invoice #42".to_string()),
+    };
+
+    // Act
+    let receipt = execute_with_message(&mut test_runner, MessageV1::Plaintext(message.clone()));
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        receipt.message,
+        Some(TransactionMessageMetadata::Plaintext(message))
+    );
+}
+
+#[test]
+fn encrypted_message_is_recorded_in_receipt_by_its_decryptors() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let decryptor: PublicKey = Secp256k1PrivateKey::from_u64(2)
+        .unwrap()
+        .public_key()
+        .into();
+    let message = EncryptedMessageV1 {
+        encrypted: AesGcmPayload(vec![0; 64]),
+        decryptors_by_curve: indexmap!(
+            CurveType::Secp256k1 => DecryptorsByCurve::Secp256k1 {
+                dh_ephemeral_public_key: Secp256k1PrivateKey::from_u64(3).unwrap().public_key(),
+                decryptors: indexmap!(
+                    PublicKeyFingerprint::from(decryptor) => AesWrapped128BitKey([0; 24]),
+                ),
+            },
+        ),
+    };
+
+    // Act
+    let receipt = execute_with_message(&mut test_runner, MessageV1::Encrypted(message));
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        receipt.message,
+        Some(TransactionMessageMetadata::Encrypted {
+            encrypted_length: 64,
+            decryptors_by_curve: indexmap!(
+                CurveType::Secp256k1 => vec![PublicKeyFingerprint::from(decryptor)],
+            ),
+        })
+    );
+}
+
+#[test]
+fn transaction_without_message_has_none_in_receipt() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Act
+    let receipt = execute_with_message(&mut test_runner, MessageV1::None);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(receipt.message, None);
+}
+
+fn execute_with_message(
+    test_runner: &mut DefaultTestRunner,
+    message: MessageV1,
+) -> TransactionReceipt {
+    let network = NetworkDefinition::simulator();
+    let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+    let current_epoch = test_runner.get_current_epoch();
+    let transaction = TransactionBuilder::new()
+        .header(TransactionHeaderV1 {
+            network_id: network.id,
+            start_epoch_inclusive: current_epoch,
+            end_epoch_exclusive: current_epoch.after(10).unwrap(),
+            nonce: test_runner.next_transaction_nonce(),
+            notary_public_key: notary.public_key().into(),
+            notary_is_signatory: true,
+            tip_percentage: 0,
+        })
+        .manifest(ManifestBuilder::new().lock_fee_from_faucet().build())
+        .message(message)
+        .notarize(&notary)
+        .build();
+
+    test_runner.execute_raw_transaction(&network, &transaction.to_raw().unwrap())
+}
@@ -1,4 +1,5 @@
 use radix_engine_tests::common::*;
+use radix_engine::transaction::{CostingParameters, ExecutionConfig};
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto_unit::*;
@@ -66,6 +67,95 @@ fn test_generate_ruid() {
     assert_ne!(ruid1, ruid2);
 }
 
+#[test]
+fn test_get_transaction_signatories() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let (other_public_key, _, _) = test_runner.new_allocated_account();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("transaction_runtime"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionRuntimeTest",
+            "signatories",
+            manifest_args!(),
+        )
+        .call_function(
+            package_address,
+            "TransactionRuntimeTest",
+            "is_signed_by",
+            manifest_args!(PublicKeyHash::from(public_key.get_hash())),
+        )
+        .call_function(
+            package_address,
+            "TransactionRuntimeTest",
+            "is_signed_by",
+            manifest_args!(PublicKeyHash::from(other_public_key.get_hash())),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let signatories: Vec<NonFungibleGlobalId> = result.output(1);
+    assert_eq!(
+        signatories,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)]
+    );
+    assert!(result.output::<bool>(2));
+    assert!(!result.output::<bool>(3));
+}
+
+#[test]
+fn test_get_transaction_signatories_of_notarized_transaction() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let (public_key, private_key, _) = test_runner.new_allocated_account();
+    let (_, notary_private_key, _) = test_runner.new_allocated_account();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("transaction_runtime"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionRuntimeTest",
+            "signatories",
+            manifest_args!(),
+        )
+        .build();
+    let transaction = create_notarized_transaction_advanced(
+        &mut test_runner,
+        &network,
+        manifest,
+        vec![&private_key],
+        &notary_private_key,
+        false,
+    );
+    let receipt = test_runner.execute_transaction(
+        validate_notarized_transaction(&network, &transaction).get_executable(),
+        CostingParameters::default(),
+        ExecutionConfig::for_notarized_transaction(network.clone()),
+    );
+
+    // Assert
+    let signatories: Vec<NonFungibleGlobalId> = receipt.expect_commit_success().output(1);
+    assert_eq!(
+        signatories,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)]
+    );
+}
+
 #[test]
 fn test_instance_of_and_blueprint_id() {
     // Arrange
@@ -3,11 +3,14 @@ use radix_engine::system::bootstrap::{
     Bootstrapper, GenesisDataChunk, GenesisReceipts, GenesisResource, GenesisResourceAllocation,
     GenesisStakeAllocation,
 };
+use radix_engine::system::system_db_reader::SystemDatabaseReader;
 use radix_engine::transaction::TransactionResult;
 use radix_engine::types::*;
 use radix_engine::vm::wasm::DefaultWasmEngine;
 use radix_engine::vm::*;
+use radix_engine_queries::query::EntityDumper;
 use radix_engine_queries::typed_native_events::TypedNativeEvent;
+use radix_engine_queries::typed_substate_layout::*;
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
 use sbor::rust::ops::Deref;
 use scrypto_unit::*;
@@ -62,6 +65,96 @@ fn test_bootstrap_receipt_should_have_substate_changes_which_can_be_typed() {
     assert_receipt_substate_changes_can_be_typed(wrap_up_receipt.expect_commit_success());
 }
 
+#[test]
+fn test_every_substate_of_genesis_ledger_can_be_typed() {
+    // Arrange
+    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let native_vm = DefaultNativeVm::new();
+    let vm = Vm::new(&scrypto_vm, native_vm);
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    let validator_key = Secp256k1PublicKey([0; 33]);
+    let staker_address = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+    );
+    let stake = GenesisStakeAllocation {
+        account_index: 0,
+        xrd_amount: Decimal::one(),
+    };
+    let genesis_data_chunks = vec![
+        GenesisDataChunk::Validators(vec![validator_key.clone().into()]),
+        GenesisDataChunk::Stakes {
+            accounts: vec![staker_address],
+            allocations: vec![(validator_key, vec![stake])],
+        },
+        GenesisDataChunk::XrdBalances(vec![(staker_address, dec!("10"))]),
+    ];
+    Bootstrapper::new(NetworkDefinition::simulator(), &mut substate_db, vm, true)
+        .bootstrap_with_genesis_data(
+            genesis_data_chunks,
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config(),
+            1,
+            Some(0),
+            Decimal::zero(),
+        )
+        .unwrap();
+
+    // Act
+    let node_ids: IndexSet<NodeId> = SystemDatabaseReader::new(&substate_db)
+        .partitions_iter()
+        .map(|(node_id, _)| node_id)
+        .collect();
+    let dumper = EntityDumper::new(&substate_db);
+    let mut substate_count = 0;
+    let mut undecodable_substates = Vec::new();
+    for node_id in &node_ids {
+        let dump = dumper.dump_entity(node_id).unwrap();
+        for partition in dump.partitions {
+            for substate in partition.substates {
+                substate_count += 1;
+                let decoded = match &substate.typed_key {
+                    Ok(typed_key) if !typed_key.value_is_mappable() => true,
+                    Ok(_) => substate.typed_value.is_ok(),
+                    Err(_) => false,
+                };
+                if !decoded {
+                    undecodable_substates.push((
+                        *node_id,
+                        partition.partition_number,
+                        substate.substate_key,
+                    ));
+                }
+            }
+        }
+    }
+
+    // Assert
+    assert!(node_ids.contains(DEFERRED_EXECUTION_QUEUE.as_node_id()));
+    assert!(substate_count > 0);
+    assert_eq!(undecodable_substates, vec![]);
+}
+
+#[test]
+fn deferred_execution_queue_substates_are_typed_by_its_own_layout() {
+    // Arrange
+    let test_runner = TestRunnerBuilder::new().build();
+    let node_id = DEFERRED_EXECUTION_QUEUE.as_node_id();
+    let (substate_key, data) = SystemDatabaseReader::new(test_runner.substate_db())
+        .substates_iter::<FieldKey>(node_id, MAIN_BASE_PARTITION)
+        .next()
+        .unwrap();
+
+    // Act
+    let (_, typed_value) =
+        to_typed_substate(node_id, MAIN_BASE_PARTITION, &substate_key, &data).unwrap();
+
+    // Assert
+    assert!(matches!(
+        typed_value,
+        TypedSubstateValue::MainModule(TypedMainModuleSubstateValue::DeferredExecutionQueue(..))
+    ));
+}
+
 #[test]
 fn test_bootstrap_receipt_should_have_events_that_can_be_typed() {
     let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
@@ -0,0 +1,274 @@
+use radix_engine_tests::common::*;
+use radix_engine::blueprints::resource::VaultError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn grantee_can_take_from_fungible_vault_within_allowance() {
+    // Arrange
+    let mut test = AllowanceTest::new(false);
+    test.grant(AllowanceLimit::Amount(dec!("30")), None)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.take_amount(dec!("20"), true);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test.test_runner
+            .inspect_fungible_vault(test.vault_id.into_node_id())
+            .unwrap(),
+        dec!("80")
+    );
+    assert_eq!(
+        test.allowance(),
+        Some(WithdrawAllowance {
+            limit: AllowanceLimit::Amount(dec!("10")),
+            expiry_epoch: None,
+        })
+    );
+}
+
+#[test]
+fn grantee_cannot_take_more_than_allowance() {
+    // Arrange
+    let mut test = AllowanceTest::new(false);
+    test.grant(AllowanceLimit::Amount(dec!("30")), None)
+        .expect_commit_success();
+    test.take_amount(dec!("20"), true).expect_commit_success();
+
+    // Act
+    let receipt = test.take_amount(dec!("20"), true);
+
+    // Assert
+    receipt.expect_specific_failure(|e| is_vault_error(e, VaultError::AllowanceExceeded));
+}
+
+#[test]
+fn grantee_cannot_take_without_presenting_badge() {
+    // Arrange
+    let mut test = AllowanceTest::new(false);
+    test.grant(AllowanceLimit::Amount(dec!("30")), None)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.take_amount(dec!("20"), false);
+
+    // Assert
+    receipt.expect_auth_assertion_failure();
+}
+
+#[test]
+fn grantee_cannot_take_after_allowance_expires() {
+    // Arrange
+    let mut test = AllowanceTest::new(false);
+    let expiry_epoch = test.test_runner.get_current_epoch().next().unwrap();
+    test.grant(AllowanceLimit::Amount(dec!("30")), Some(expiry_epoch))
+        .expect_commit_success();
+    test.test_runner.set_current_epoch(expiry_epoch);
+
+    // Act
+    let receipt = test.take_amount(dec!("20"), true);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_vault_error(e, VaultError::AllowanceExpired { expiry_epoch })
+    });
+}
+
+#[test]
+fn revoked_allowance_cannot_be_used() {
+    // Arrange
+    let mut test = AllowanceTest::new(false);
+    test.grant(AllowanceLimit::Amount(dec!("30")), None)
+        .expect_commit_success();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            test.component_address,
+            "revoke",
+            manifest_args!(ResourceOrNonFungible::Resource(test.badge)),
+        )
+        .build();
+    test.test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.take_amount(dec!("20"), true);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_vault_error(e, VaultError::NoAllowance(ResourceOrNonFungible::Resource(test.badge)))
+    });
+}
+
+#[test]
+fn fungible_vault_rejects_non_fungible_allowance() {
+    // Arrange
+    let mut test = AllowanceTest::new(false);
+
+    // Act
+    let receipt = test.grant(
+        AllowanceLimit::NonFungibles(indexset!(NonFungibleLocalId::integer(1))),
+        None,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(
+                VaultError::InvalidAllowance(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn grantee_can_only_take_allowed_non_fungibles() {
+    // Arrange
+    let mut test = AllowanceTest::new(true);
+    test.grant(
+        AllowanceLimit::NonFungibles(indexset!(NonFungibleLocalId::integer(1))),
+        None,
+    )
+    .expect_commit_success();
+
+    // Act
+    let not_allowed = test.take_non_fungibles(NonFungibleLocalId::integer(2));
+    let allowed = test.take_non_fungibles(NonFungibleLocalId::integer(1));
+
+    // Assert
+    not_allowed.expect_specific_failure(|e| is_vault_error(e, VaultError::AllowanceExceeded));
+    allowed.expect_commit_success();
+    assert_eq!(
+        test.allowance(),
+        Some(WithdrawAllowance {
+            limit: AllowanceLimit::NonFungibles(indexset!()),
+            expiry_epoch: None,
+        })
+    );
+}
+
+struct AllowanceTest {
+    test_runner: DefaultTestRunner,
+    public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    badge: ResourceAddress,
+    component_address: ComponentAddress,
+    vault_id: InternalAddress,
+}
+
+impl AllowanceTest {
+    fn new(non_fungible: bool) -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (public_key, _, account) = test_runner.new_allocated_account();
+        let package_address = test_runner.publish_package_simple(PackageLoader::get("vault"));
+        let badge = test_runner.create_fungible_resource(1.into(), 0, account);
+        let (resource_address, amount) = if non_fungible {
+            (test_runner.create_non_fungible_resource(account), 3)
+        } else {
+            (test_runner.create_fungible_resource(100.into(), 18, account), 100)
+        };
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, resource_address, amount)
+            .take_all_from_worktop(resource_address, "bucket")
+            .with_bucket("bucket", |builder, bucket| {
+                builder.call_function(
+                    package_address,
+                    "VaultAllowance",
+                    "new",
+                    manifest_args!(bucket),
+                )
+            })
+            .build();
+        let component_address = test_runner
+            .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&public_key)])
+            .expect_commit_success()
+            .new_component_addresses()[0];
+        let vault_id = test_runner.get_component_vaults(component_address, resource_address)[0];
+
+        Self {
+            test_runner,
+            public_key,
+            account,
+            badge,
+            component_address,
+            vault_id: InternalAddress::new_or_panic(vault_id.into()),
+        }
+    }
+
+    fn grant(&mut self, limit: AllowanceLimit, expiry_epoch: Option<Epoch>) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.component_address,
+                "grant",
+                manifest_args!(
+                    ResourceOrNonFungible::Resource(self.badge),
+                    WithdrawAllowance {
+                        limit,
+                        expiry_epoch,
+                    }
+                ),
+            )
+            .build();
+        self.test_runner.execute_manifest(manifest, vec![])
+    }
+
+    fn take_amount(&mut self, amount: Decimal, with_badge: bool) -> TransactionReceipt {
+        let builder = ManifestBuilder::new().lock_fee_from_faucet();
+        let builder = if with_badge {
+            builder.create_proof_from_account_of_amount(self.account, self.badge, 1)
+        } else {
+            builder
+        };
+        let manifest = builder
+            .take_from_vault_with_allowance(self.vault_id, self.badge, amount)
+            .try_deposit_entire_worktop_or_abort(self.account, None)
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.public_key)],
+        )
+    }
+
+    fn take_non_fungibles(&mut self, id: NonFungibleLocalId) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(self.account, self.badge, 1)
+            .take_non_fungibles_from_vault_with_allowance(self.vault_id, self.badge, [id])
+            .try_deposit_entire_worktop_or_abort(self.account, None)
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.public_key)],
+        )
+    }
+
+    fn allowance(&mut self) -> Option<WithdrawAllowance> {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.component_address,
+                "allowance",
+                manifest_args!(ResourceOrNonFungible::Resource(self.badge)),
+            )
+            .build();
+        self.test_runner
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .output(1)
+    }
+}
+
+fn is_vault_error(error: &RuntimeError, expected: VaultError) -> bool {
+    error.eq(&RuntimeError::ApplicationError(ApplicationError::VaultError(expected)))
+}
@@ -0,0 +1,331 @@
+use radix_engine_tests::common::*;
+use radix_engine::blueprints::resource::VaultError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn deposit_of_fungible_into_denied_account_fails() {
+    // Arrange
+    let mut test = DenyListTest::new_fungible();
+    test.deny_holder(test.other_account, true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.transfer_to_other_account();
+
+    // Assert
+    let other_account = test.other_account;
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::ApplicationError(
+            ApplicationError::VaultError(VaultError::HolderDenied(other_account.into())),
+        ))
+    });
+}
+
+#[test]
+fn deposit_of_non_fungible_into_denied_account_fails() {
+    // Arrange
+    let mut test = DenyListTest::new_non_fungible();
+    test.deny_holder(test.other_account, true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.transfer_to_other_account();
+
+    // Assert
+    let other_account = test.other_account;
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::ApplicationError(
+            ApplicationError::VaultError(VaultError::HolderDenied(other_account.into())),
+        ))
+    });
+}
+
+#[test]
+fn deposit_into_account_succeeds_once_allowed_again() {
+    // Arrange
+    let mut test = DenyListTest::new_fungible();
+    test.deny_holder(test.other_account, true)
+        .expect_commit_success();
+    test.allow_holder(test.other_account)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.transfer_to_other_account();
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test.test_runner
+            .get_component_balance(test.other_account, test.resource),
+        dec!("1")
+    );
+}
+
+#[test]
+fn denied_holder_can_still_withdraw() {
+    // Arrange
+    let mut test = DenyListTest::new_fungible();
+    test.deny_holder(test.holder, true).expect_commit_success();
+
+    // Act
+    let receipt = test.transfer_to_other_account();
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cannot_deny_holder_without_deny_lister_role() {
+    // Arrange
+    let mut test = DenyListTest::new_fungible();
+
+    // Act
+    let receipt = test.deny_holder(test.other_account, false);
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn is_holder_denied_reflects_the_deny_list() {
+    // Arrange
+    let mut test = DenyListTest::new_fungible();
+    test.deny_holder(test.other_account, true)
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            test.resource,
+            RESOURCE_MANAGER_IS_HOLDER_DENIED_IDENT,
+            manifest_args!(GlobalAddress::from(test.other_account)),
+        )
+        .call_method(
+            test.resource,
+            RESOURCE_MANAGER_IS_HOLDER_DENIED_IDENT,
+            manifest_args!(GlobalAddress::from(test.holder)),
+        )
+        .build();
+    let receipt = test.test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert!(commit.output::<bool>(1));
+    assert!(!commit.output::<bool>(2));
+}
+
+#[test]
+fn denied_component_cannot_store_resource_in_new_vault() {
+    // Arrange
+    let mut test = DenyListTest::new_fungible();
+    let component_address = test.new_component();
+    test.deny_holder(component_address, true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.store_in_new_vault(component_address);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::ApplicationError(
+            ApplicationError::VaultError(VaultError::HolderDenied(component_address.into())),
+        ))
+    });
+}
+
+#[test]
+fn component_can_store_resource_in_new_vault_when_not_denied() {
+    // Arrange
+    let mut test = DenyListTest::new_non_fungible();
+    let component_address = test.new_component();
+    test.deny_holder(test.other_account, true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.store_in_new_vault(component_address);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+struct DenyListTest {
+    test_runner: DefaultTestRunner,
+    issuer_key: Secp256k1PublicKey,
+    issuer: ComponentAddress,
+    badge: ResourceAddress,
+    holder_key: Secp256k1PublicKey,
+    holder: ComponentAddress,
+    other_account: ComponentAddress,
+    resource: ResourceAddress,
+}
+
+impl DenyListTest {
+    fn new_fungible() -> Self {
+        Self::new(|builder, badge| {
+            builder.call_function(
+                RESOURCE_PACKAGE,
+                FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
+                FungibleResourceManagerCreateWithInitialSupplyV2ManifestInput {
+                    owner_role: OwnerRole::None,
+                    track_total_supply: true,
+                    divisibility: DIVISIBILITY_NONE,
+                    initial_supply: dec!("10"),
+                    resource_roles: FungibleResourceRolesV2 {
+                        deny_list_roles: deny_list_roles! {
+                            deny_lister => rule!(require(badge));
+                            deny_lister_updater => rule!(deny_all);
+                        },
+                        ..Default::default()
+                    },
+                    metadata: metadata!(),
+                    address_reservation: None,
+                },
+            )
+        })
+    }
+
+    fn new_non_fungible() -> Self {
+        Self::new(|builder, badge| {
+            let non_fungible_schema =
+                NonFungibleDataSchema::new_local_without_self_package_replacement::<
+                    EmptyNonFungibleData,
+                >();
+            let entries = indexmap!(
+                NonFungibleLocalId::integer(1) => (to_manifest_value_and_unwrap!(&EmptyNonFungibleData {}),)
+            );
+            builder.call_function(
+                RESOURCE_PACKAGE,
+                NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
+                NonFungibleResourceManagerCreateWithInitialSupplyV2ManifestInput {
+                    owner_role: OwnerRole::None,
+                    id_type: NonFungibleIdType::Integer,
+                    track_total_supply: true,
+                    non_fungible_schema,
+                    entries,
+                    resource_roles: NonFungibleResourceRolesV2 {
+                        deny_list_roles: deny_list_roles! {
+                            deny_lister => rule!(require(badge));
+                            deny_lister_updater => rule!(deny_all);
+                        },
+                        ..Default::default()
+                    },
+                    metadata: metadata!(),
+                    address_reservation: None,
+                },
+            )
+        })
+    }
+
+    fn new<F>(create: F) -> Self
+    where
+        F: FnOnce(ManifestBuilder, ResourceAddress) -> ManifestBuilder,
+    {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (issuer_key, _, issuer) = test_runner.new_allocated_account();
+        let (holder_key, _, holder) = test_runner.new_allocated_account();
+        let (_, _, other_account) = test_runner.new_allocated_account();
+        let badge = test_runner.create_fungible_resource(dec!("1"), DIVISIBILITY_NONE, issuer);
+
+        let manifest = create(ManifestBuilder::new().lock_fee_from_faucet(), badge)
+            .try_deposit_entire_worktop_or_abort(holder, None)
+            .build();
+        let receipt = test_runner.execute_manifest(manifest, vec![]);
+        let resource = receipt.expect_commit_success().new_resource_addresses()[0];
+
+        Self {
+            test_runner,
+            issuer_key,
+            issuer,
+            badge,
+            holder_key,
+            holder,
+            other_account,
+            resource,
+        }
+    }
+
+    fn deny_holder(&mut self, holder: ComponentAddress, as_issuer: bool) -> TransactionReceipt {
+        self.update_deny_list(RESOURCE_MANAGER_DENY_HOLDER_IDENT, holder, as_issuer)
+    }
+
+    fn allow_holder(&mut self, holder: ComponentAddress) -> TransactionReceipt {
+        self.update_deny_list(RESOURCE_MANAGER_ALLOW_HOLDER_IDENT, holder, true)
+    }
+
+    fn update_deny_list(
+        &mut self,
+        method_name: &str,
+        holder: ComponentAddress,
+        as_issuer: bool,
+    ) -> TransactionReceipt {
+        let builder = ManifestBuilder::new().lock_fee_from_faucet();
+        let builder = if as_issuer {
+            builder.create_proof_from_account_of_amount(self.issuer, self.badge, 1)
+        } else {
+            builder
+        };
+        let manifest = builder
+            .call_method(
+                self.resource,
+                method_name,
+                manifest_args!(GlobalAddress::from(holder)),
+            )
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.issuer_key)],
+        )
+    }
+
+    fn new_component(&mut self) -> ComponentAddress {
+        let package_address = self
+            .test_runner
+            .publish_package_simple(PackageLoader::get("vault"));
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(package_address, "VaultDenyList", "new", manifest_args!())
+            .build();
+        self.test_runner
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .new_component_addresses()[0]
+    }
+
+    fn store_in_new_vault(&mut self, component_address: ComponentAddress) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(self.holder, self.resource, 1)
+            .take_all_from_worktop(self.resource, "bucket")
+            .with_bucket("bucket", |builder, bucket| {
+                builder.call_method(
+                    component_address,
+                    "store_in_new_vault",
+                    manifest_args!(bucket),
+                )
+            })
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.holder_key)],
+        )
+    }
+
+    fn transfer_to_other_account(&mut self) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(self.holder, self.resource, 1)
+            .try_deposit_entire_worktop_or_abort(self.other_account, None)
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.holder_key)],
+        )
+    }
+}
@@ -1,6 +1,7 @@
 use radix_engine::blueprints::resource::{NonFungibleResourceManagerError, VaultError};
 use radix_engine::errors::{ApplicationError, RuntimeError};
 use radix_engine::types::*;
+use radix_engine_tests::common::*;
 use scrypto::prelude::FromPublicKey;
 use scrypto_unit::*;
 use transaction::prelude::*;
@@ -37,6 +38,67 @@ fn cannot_burn_frozen_burn_fungible_vault() {
     });
 }
 
+#[test]
+fn can_freeze_and_unfreeze_withdraw_from_scrypto_with_direct_access_vault() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (key, _priv, account) = test_runner.new_account(true);
+    let token_address = test_runner.create_freezeable_token(account);
+    let vault_address = InternalAddress::new_or_panic(
+        test_runner.get_component_vaults(account, token_address)[0].into(),
+    );
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("recall"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "RecallTest",
+            "freeze_withdraw_on_direct_access_vault",
+            manifest_args!(vault_address),
+        )
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, token_address, 1)
+        .deposit_batch(account)
+        .build();
+    let frozen_receipt =
+        test_runner.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&key)]);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "RecallTest",
+            "unfreeze_withdraw_on_direct_access_vault",
+            manifest_args!(vault_address),
+        )
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, token_address, 1)
+        .deposit_batch(account)
+        .build();
+    let unfrozen_receipt =
+        test_runner.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&key)]);
+
+    // Assert
+    frozen_receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::VaultIsFrozen))
+        )
+    });
+    unfrozen_receipt.expect_commit_success();
+}
+
 #[test]
 fn cannot_deposit_into_frozen_deposit_fungible_vault() {
     // Arrange
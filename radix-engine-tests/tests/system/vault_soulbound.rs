@@ -0,0 +1,210 @@
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn holder_cannot_withdraw_soulbound_fungible() {
+    // Arrange
+    let mut test = SoulboundTest::new_fungible();
+
+    // Act
+    let receipt = test.transfer_to_other_account(false);
+
+    // Assert
+    receipt.expect_auth_assertion_failure();
+}
+
+#[test]
+fn soulbound_fungible_can_be_transferred_with_exemption() {
+    // Arrange
+    let mut test = SoulboundTest::new_fungible();
+
+    // Act
+    let receipt = test.transfer_to_other_account(true);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test.test_runner
+            .get_component_balance(test.other_account, test.resource),
+        dec!("1")
+    );
+}
+
+#[test]
+fn holder_cannot_withdraw_soulbound_non_fungible() {
+    // Arrange
+    let mut test = SoulboundTest::new_non_fungible();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(
+            test.holder,
+            test.resource,
+            [NonFungibleLocalId::integer(1)],
+        )
+        .try_deposit_entire_worktop_or_abort(test.other_account, None)
+        .build();
+    let receipt = test.test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&test.holder_key)],
+    );
+
+    // Assert
+    receipt.expect_auth_assertion_failure();
+}
+
+#[test]
+fn recalled_soulbound_fungible_cannot_be_deposited_without_exemption() {
+    // Arrange
+    let mut test = SoulboundTest::new_fungible();
+    let vault_id = test
+        .test_runner
+        .get_component_vaults(test.holder, test.resource)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall(InternalAddress::new_or_panic(vault_id.into()), 1)
+        .try_deposit_entire_worktop_or_abort(test.other_account, None)
+        .build();
+    let receipt = test.test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_auth_assertion_failure();
+}
+
+struct SoulboundTest {
+    test_runner: DefaultTestRunner,
+    issuer_key: Secp256k1PublicKey,
+    issuer: ComponentAddress,
+    badge: ResourceAddress,
+    holder_key: Secp256k1PublicKey,
+    holder: ComponentAddress,
+    other_account: ComponentAddress,
+    resource: ResourceAddress,
+}
+
+impl SoulboundTest {
+    fn new_fungible() -> Self {
+        Self::new(|builder, badge| {
+            builder.call_function(
+                RESOURCE_PACKAGE,
+                FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
+                FungibleResourceManagerCreateWithInitialSupplyV2ManifestInput {
+                    owner_role: OwnerRole::None,
+                    track_total_supply: true,
+                    divisibility: DIVISIBILITY_NONE,
+                    initial_supply: dec!("10"),
+                    resource_roles: FungibleResourceRolesV2 {
+                        recall_roles: recall_roles! {
+                            recaller => rule!(allow_all);
+                            recaller_updater => rule!(deny_all);
+                        },
+                        soulbound_roles: soulbound_roles! {
+                            transfer_exemptor => rule!(require(badge));
+                            transfer_exemptor_updater => rule!(deny_all);
+                        },
+                        ..Default::default()
+                    },
+                    metadata: metadata!(),
+                    address_reservation: None,
+                },
+            )
+        })
+    }
+
+    fn new_non_fungible() -> Self {
+        Self::new(|builder, badge| {
+            let non_fungible_schema =
+                NonFungibleDataSchema::new_local_without_self_package_replacement::<
+                    EmptyNonFungibleData,
+                >();
+            let entries = indexmap!(
+                NonFungibleLocalId::integer(1) => (to_manifest_value_and_unwrap!(&EmptyNonFungibleData {}),)
+            );
+            builder.call_function(
+                RESOURCE_PACKAGE,
+                NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
+                NonFungibleResourceManagerCreateWithInitialSupplyV2ManifestInput {
+                    owner_role: OwnerRole::None,
+                    id_type: NonFungibleIdType::Integer,
+                    track_total_supply: true,
+                    non_fungible_schema,
+                    entries,
+                    resource_roles: NonFungibleResourceRolesV2 {
+                        soulbound_roles: soulbound_roles! {
+                            transfer_exemptor => rule!(require(badge));
+                            transfer_exemptor_updater => rule!(deny_all);
+                        },
+                        ..Default::default()
+                    },
+                    metadata: metadata!(),
+                    address_reservation: None,
+                },
+            )
+        })
+    }
+
+    fn new<F>(create: F) -> Self
+    where
+        F: FnOnce(ManifestBuilder, ResourceAddress) -> ManifestBuilder,
+    {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (issuer_key, _, issuer) = test_runner.new_allocated_account();
+        let (holder_key, _, holder) = test_runner.new_allocated_account();
+        let (_, _, other_account) = test_runner.new_allocated_account();
+        let badge = test_runner.create_fungible_resource(dec!("1"), DIVISIBILITY_NONE, issuer);
+
+        // Issuing into the holder's account is itself an exempt deposit
+        let builder = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(issuer, badge, 1);
+        let manifest = create(builder, badge)
+            .try_deposit_entire_worktop_or_abort(holder, None)
+            .build();
+        let receipt = test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&issuer_key)],
+        );
+        let resource = receipt.expect_commit_success().new_resource_addresses()[0];
+
+        Self {
+            test_runner,
+            issuer_key,
+            issuer,
+            badge,
+            holder_key,
+            holder,
+            other_account,
+            resource,
+        }
+    }
+
+    fn transfer_to_other_account(&mut self, with_exemption: bool) -> TransactionReceipt {
+        let builder = ManifestBuilder::new().lock_fee_from_faucet();
+        let (builder, signers) = if with_exemption {
+            (
+                builder.create_proof_from_account_of_amount(self.issuer, self.badge, 1),
+                vec![
+                    NonFungibleGlobalId::from_public_key(&self.holder_key),
+                    NonFungibleGlobalId::from_public_key(&self.issuer_key),
+                ],
+            )
+        } else {
+            (
+                builder,
+                vec![NonFungibleGlobalId::from_public_key(&self.holder_key)],
+            )
+        };
+        let manifest = builder
+            .withdraw_from_account(self.holder, self.resource, 1)
+            .try_deposit_entire_worktop_or_abort(self.other_account, None)
+            .build();
+        self.test_runner.execute_manifest(manifest, signers)
+    }
+}
@@ -0,0 +1,249 @@
+use radix_engine_tests::common::*;
+use radix_engine::blueprints::resource::NonFungibleResourceManagerError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn non_fungibles_can_be_withdrawn_freely_until_a_royalty_is_set() {
+    // Arrange
+    let mut test = TransferRoyaltyTest::new();
+
+    // Act
+    let receipt = test.withdraw_from_account(false);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn withdrawal_without_royalty_exemption_fails_once_a_royalty_is_set() {
+    // Arrange
+    let mut test = TransferRoyaltyTest::new();
+    test.set_royalty(dec!("10")).expect_commit_success();
+
+    // Act
+    let receipt = test.withdraw_from_account(false);
+
+    // Assert
+    receipt.expect_auth_assertion_failure();
+}
+
+#[test]
+fn approved_marketplace_can_withdraw_without_paying_royalty() {
+    // Arrange
+    let mut test = TransferRoyaltyTest::new();
+    test.set_royalty(dec!("10")).expect_commit_success();
+
+    // Act
+    let receipt = test.withdraw_from_account(true);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn royalty_paid_when_taking_non_fungibles_can_be_claimed_by_owner() {
+    // Arrange
+    let mut test = TransferRoyaltyTest::new();
+    test.set_royalty(dec!("10")).expect_commit_success();
+    test.take_with_royalty(dec!("15")).expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            test.resource,
+            NON_FUNGIBLE_RESOURCE_MANAGER_CLAIM_TRANSFER_ROYALTIES_IDENT,
+            manifest_args!(XRD),
+        )
+        .assert_worktop_contains(XRD, dec!("10"))
+        .try_deposit_entire_worktop_or_abort(test.account, None)
+        .build();
+    let receipt = test.test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&test.public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test.test_runner
+            .get_component_balance(test.account, test.resource),
+        dec!("2")
+    );
+}
+
+#[test]
+fn taking_non_fungibles_with_insufficient_royalty_payment_fails() {
+    // Arrange
+    let mut test = TransferRoyaltyTest::new();
+    test.set_royalty(dec!("10")).expect_commit_success();
+
+    // Act
+    let receipt = test.take_with_royalty(dec!("5"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::ApplicationError(
+            ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::InsufficientTransferRoyalty {
+                    required: dec!("10"),
+                    paid: dec!("5"),
+                },
+            ),
+        ))
+    });
+}
+
+struct TransferRoyaltyTest {
+    test_runner: DefaultTestRunner,
+    public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    marketplace_badge: ResourceAddress,
+    resource: ResourceAddress,
+    component_address: ComponentAddress,
+}
+
+impl TransferRoyaltyTest {
+    fn new() -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (public_key, _, account) = test_runner.new_allocated_account();
+        let package_address = test_runner.publish_package_simple(PackageLoader::get("vault"));
+        let marketplace_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+        let owner = NonFungibleGlobalId::from_public_key(&public_key);
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                RESOURCE_PACKAGE,
+                NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
+                NonFungibleResourceManagerCreateWithInitialSupplyV2ManifestInput {
+                    owner_role: OwnerRole::Fixed(rule!(require(owner.clone()))),
+                    id_type: NonFungibleIdType::Integer,
+                    track_total_supply: true,
+                    non_fungible_schema:
+                        NonFungibleDataSchema::new_local_without_self_package_replacement::<
+                            EmptyNonFungibleData,
+                        >(),
+                    entries: (1..=3)
+                        .map(|i| {
+                            (
+                                NonFungibleLocalId::integer(i),
+                                (to_manifest_value_and_unwrap!(&EmptyNonFungibleData {}),),
+                            )
+                        })
+                        .collect(),
+                    resource_roles: NonFungibleResourceRolesV2 {
+                        transfer_royalty_roles: transfer_royalty_roles! {
+                            royalty_exemptor => rule!(require(marketplace_badge));
+                            royalty_exemptor_updater => rule!(deny_all);
+                        },
+                        ..Default::default()
+                    },
+                    metadata: metadata!(),
+                    address_reservation: None,
+                },
+            )
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+        let resource = test_runner
+            .execute_manifest(manifest, vec![owner.clone()])
+            .expect_commit_success()
+            .new_resource_addresses()[0];
+
+        // Non-fungibles 1 and 2 are held by a component, non-fungible 3 by the account
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_non_fungibles_from_account(
+                account,
+                resource,
+                [NonFungibleLocalId::integer(1), NonFungibleLocalId::integer(2)],
+            )
+            .take_all_from_worktop(resource, "bucket")
+            .with_bucket("bucket", |builder, bucket| {
+                builder.call_function(
+                    package_address,
+                    "VaultTransferRoyalty",
+                    "new",
+                    manifest_args!(bucket),
+                )
+            })
+            .build();
+        let component_address = test_runner
+            .execute_manifest(manifest, vec![owner])
+            .expect_commit_success()
+            .new_component_addresses()[0];
+
+        Self {
+            test_runner,
+            public_key,
+            account,
+            marketplace_badge,
+            resource,
+            component_address,
+        }
+    }
+
+    fn set_royalty(&mut self, amount_per_non_fungible: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.resource,
+                NON_FUNGIBLE_RESOURCE_MANAGER_SET_TRANSFER_ROYALTY_IDENT,
+                manifest_args!(Some(TransferRoyaltyConfig {
+                    resource_address: XRD,
+                    amount_per_non_fungible,
+                })),
+            )
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.public_key)],
+        )
+    }
+
+    fn withdraw_from_account(&mut self, as_marketplace: bool) -> TransactionReceipt {
+        let builder = ManifestBuilder::new().lock_fee_from_faucet();
+        let builder = if as_marketplace {
+            builder.create_proof_from_account_of_amount(self.account, self.marketplace_badge, 1)
+        } else {
+            builder
+        };
+        let manifest = builder
+            .withdraw_non_fungibles_from_account(
+                self.account,
+                self.resource,
+                [NonFungibleLocalId::integer(3)],
+            )
+            .try_deposit_entire_worktop_or_abort(self.account, None)
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.public_key)],
+        )
+    }
+
+    fn take_with_royalty(&mut self, payment: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(self.account, XRD, payment)
+            .take_all_from_worktop(XRD, "payment")
+            .with_bucket("payment", |builder, bucket| {
+                builder.call_method(
+                    self.component_address,
+                    "take_with_royalty",
+                    manifest_args!(indexset!(NonFungibleLocalId::integer(1)), bucket),
+                )
+            })
+            .try_deposit_entire_worktop_or_abort(self.account, None)
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.public_key)],
+        )
+    }
+}
@@ -1,4 +1,4 @@
-use radix_engine::blueprints::resource::FungibleResourceManagerError;
+use radix_engine::blueprints::resource::{FungibleResourceManagerError, WorktopError};
 use radix_engine::errors::ApplicationError;
 use radix_engine::errors::RuntimeError;
 use radix_engine::types::*;
@@ -33,6 +33,118 @@ fn test_worktop_resource_leak() {
     });
 }
 
+#[test]
+fn test_worktop_leftovers_are_deposited_into_recipient() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, recipient) = test_runner.new_allocated_account();
+    let initial_balance = test_runner.get_component_balance(recipient, XRD);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 1)
+        .build();
+    let receipt = test_runner.execute_manifest_with_worktop_leftovers_recipient(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        recipient,
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(result.worktop_leftovers, indexmap!(XRD => dec!("1")));
+    assert_eq!(
+        test_runner.get_component_balance(recipient, XRD),
+        initial_balance + dec!("1")
+    );
+}
+
+#[test]
+fn test_empty_worktop_has_no_leftovers() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, recipient) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 1)
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest_with_worktop_leftovers_recipient(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        recipient,
+    );
+
+    // Assert
+    assert!(receipt.expect_commit_success().worktop_leftovers.is_empty());
+}
+
+#[test]
+fn test_assert_worktop_contains_non_fungibles() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let ids = [NonFungibleLocalId::integer(1), NonFungibleLocalId::integer(2)];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(account, resource_address, ids.clone())
+        .assert_worktop_contains_non_fungibles(resource_address, ids)
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_assert_worktop_contains_non_fungibles_fails_if_any_id_is_missing() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(
+            account,
+            resource_address,
+            [NonFungibleLocalId::integer(1), NonFungibleLocalId::integer(2)],
+        )
+        .assert_worktop_contains_non_fungibles(
+            resource_address,
+            [NonFungibleLocalId::integer(1), NonFungibleLocalId::integer(3)],
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::WorktopError(
+                WorktopError::AssertionFailed
+            ))
+        )
+    });
+}
+
 #[test]
 fn test_many_current_auth_zone_call() {
     // Arrange
@@ -4,7 +4,7 @@ use radix_engine::{
     transaction::TransactionReceipt,
     types::*,
 };
-use radix_engine_interface::types::Level;
+use radix_engine_interface::types::{Level, LogRecord};
 use scrypto_unit::*;
 use transaction::prelude::*;
 
@@ -39,12 +39,47 @@ fn test_emit_log() {
         receipt.expect_commit_success();
 
         let logs = receipt.expect_commit(true).application_logs.clone();
-        let expected_logs = vec![(Level::Info, message.to_owned())];
+        let expected_logs = vec![LogRecord::new(Level::Info, message.to_owned())];
 
         assert_eq!(expected_logs, logs)
     }
 }
 
+#[test]
+fn test_emit_log_with_fields() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("logger"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            package_address,
+            "Logger",
+            "emit_log_with_fields",
+            manifest_args!("Swapped".to_owned(), dec!("1.5"), "xrd".to_owned()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert_eq!(
+        commit.application_logs,
+        vec![
+            LogRecord::new(Level::Info, "Swapped".to_owned())
+                .with_field("amount", "1.5")
+                .with_field("tag", "xrd"),
+            LogRecord::new(Level::Debug, "Done".to_owned()),
+        ]
+    );
+    let info_logs: Vec<_> = commit.logs_at_level(Level::Info).collect();
+    assert_eq!(info_logs.len(), 1);
+    assert_eq!(info_logs[0].field("amount"), Some("1.5"));
+    assert_eq!(commit.logs_with_field("tag", "xrd").count(), 1);
+    assert_eq!(commit.logs_with_field("tag", "btc").count(), 0);
+}
+
 #[test]
 fn test_rust_panic() {
     // Arrange
@@ -110,3 +145,66 @@ fn test_assert_length_5() {
         })
     }
 }
+
+#[test]
+fn test_panic_message_is_metered_by_size() {
+    // Arrange
+    let function_name = "scrypto_panic";
+    let panic_cost = |receipt: &TransactionReceipt| {
+        receipt
+            .fee_details
+            .as_ref()
+            .unwrap()
+            .execution_cost_breakdown
+            .get("Panic")
+            .cloned()
+            .unwrap()
+    };
+
+    // Act
+    let short_receipt = call(function_name, "Hi");
+    let long_receipt = call(function_name, "Hi".repeat(1000));
+
+    // Assert
+    assert!(panic_cost(&long_receipt) > panic_cost(&short_receipt));
+    long_receipt.expect_specific_failure(|e| match e {
+        RuntimeError::ApplicationError(ApplicationError::PanicMessage(e)) => e.len() == 2000,
+        _ => false,
+    });
+}
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+enum LoggerError {
+    MessageTooLong { length: u32, max: u32 },
+}
+
+#[test]
+fn test_scrypto_abort() {
+    // Arrange
+    let function_name = "abort_if_longer_than_5";
+    let message = "Hello world";
+
+    // Act
+    let receipt = call(function_name, message);
+
+    // Assert
+    let logs = receipt.expect_commit(false).application_logs.clone();
+    assert!(logs.is_empty());
+    assert_eq!(
+        receipt.expect_abort_error::<LoggerError>(),
+        LoggerError::MessageTooLong { length: 11, max: 5 }
+    );
+}
+
+#[test]
+fn test_scrypto_abort_not_triggered() {
+    // Arrange
+    let function_name = "abort_if_longer_than_5";
+    let message = "Hello";
+
+    // Act
+    let receipt = call(function_name, message);
+
+    // Assert
+    receipt.expect_commit_success();
+}
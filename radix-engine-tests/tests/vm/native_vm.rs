@@ -1,9 +1,41 @@
 #![cfg(feature = "std")]
 
+use radix_engine::blueprints::access_controller::{
+    AccessControllerNativeExport, AccessControllerNativePackage,
+};
+use radix_engine::blueprints::account::{AccountNativeExport, AccountNativePackage};
+use radix_engine::blueprints::consensus_manager::{
+    ConsensusManagerNativeExport, ConsensusManagerNativePackage,
+};
+use radix_engine::blueprints::identity::{IdentityNativeExport, IdentityNativePackage};
+use radix_engine::blueprints::order_book::{OrderBookNativeExport, OrderBookNativePackage};
+use radix_engine::blueprints::package::{PackageNativeExport, PackageNativePackage};
+use radix_engine::blueprints::payment_scheduler::{
+    PaymentSchedulerNativeExport, PaymentSchedulerNativePackage,
+};
+use radix_engine::blueprints::pool::v1::package::{
+    PoolNativeExport, PoolNativePackage, PoolV1MinorVersion,
+};
+use radix_engine::blueprints::price_feed::{PriceFeedNativeExport, PriceFeedNativePackage};
+use radix_engine::blueprints::resource::{ResourceNativeExport, ResourceNativePackage};
+use radix_engine::blueprints::test_utils::{TestUtilsNativeExport, TestUtilsNativePackage};
+use radix_engine::blueprints::transaction_processor::{
+    TransactionProcessorNativeExport, TransactionProcessorNativePackage,
+};
+use radix_engine::blueprints::transaction_tracker::{
+    TransactionTrackerNativeExport, TransactionTrackerNativePackage,
+};
 use radix_engine::errors::*;
 use radix_engine::kernel::id_allocator::*;
 use radix_engine::kernel::kernel::*;
 use radix_engine::kernel::kernel_api::*;
+use radix_engine::system::attached_modules::metadata::{
+    MetadataNativeExport, MetadataNativePackage,
+};
+use radix_engine::system::attached_modules::role_assignment::{
+    RoleAssignmentNativeExport, RoleAssignmentNativePackage,
+};
+use radix_engine::system::attached_modules::royalty::{RoyaltyNativeExport, RoyaltyNativePackage};
 use radix_engine::system::bootstrap::*;
 use radix_engine::system::system::*;
 use radix_engine::system::system_callback::*;
@@ -79,7 +111,10 @@ fn panics_can_be_caught_in_the_native_vm_and_converted_into_results() {
             intent_hash,
             AuthZoneParams {
                 initial_proofs: Default::default(),
+                signatories: Default::default(),
                 virtual_resources: Default::default(),
+                virtual_resource_amounts: Default::default(),
+                subintent_initial_proofs: Default::default(),
             },
             SystemLoanFeeReserve::default(),
             FeeTable::new(),
@@ -153,7 +188,10 @@ fn any_panics_can_be_caught_in_the_native_vm_and_converted_into_results() {
             intent_hash,
             AuthZoneParams {
                 initial_proofs: Default::default(),
+                signatories: Default::default(),
                 virtual_resources: Default::default(),
+                virtual_resource_amounts: Default::default(),
+                subintent_initial_proofs: Default::default(),
             },
             SystemLoanFeeReserve::default(),
             FeeTable::new(),
@@ -195,6 +233,74 @@ fn any_panics_can_be_caught_in_the_native_vm_and_converted_into_results() {
     ))
 }
 
+#[test]
+fn every_export_of_a_native_package_resolves_to_its_own_dispatch_entry() {
+    macro_rules! assert_exports_resolve {
+        ($definition:expr, $export:ty) => {{
+            for blueprint in $definition.blueprints.values() {
+                for export_name in blueprint.schema.exports() {
+                    let export = <$export>::from_export_name(&export_name).unwrap();
+                    assert_eq!(export.export_name(), export_name);
+                }
+            }
+            for export in <$export>::ALL {
+                assert_eq!(
+                    <$export>::from_export_name(export.export_name()).ok(),
+                    Some(*export)
+                );
+            }
+        }};
+    }
+
+    assert_exports_resolve!(PackageNativePackage::definition(), PackageNativeExport);
+    assert_exports_resolve!(ResourceNativePackage::definition(), ResourceNativeExport);
+    assert_exports_resolve!(
+        ConsensusManagerNativePackage::definition(),
+        ConsensusManagerNativeExport
+    );
+    assert_exports_resolve!(IdentityNativePackage::definition(), IdentityNativeExport);
+    assert_exports_resolve!(AccountNativePackage::definition(), AccountNativeExport);
+    assert_exports_resolve!(
+        AccessControllerNativePackage::definition(),
+        AccessControllerNativeExport
+    );
+    assert_exports_resolve!(
+        TransactionProcessorNativePackage::definition(),
+        TransactionProcessorNativeExport
+    );
+    assert_exports_resolve!(MetadataNativePackage::definition(), MetadataNativeExport);
+    assert_exports_resolve!(RoyaltyNativePackage::definition(), RoyaltyNativeExport);
+    assert_exports_resolve!(
+        RoleAssignmentNativePackage::definition(),
+        RoleAssignmentNativeExport
+    );
+    assert_exports_resolve!(
+        PoolNativePackage::definition(PoolV1MinorVersion::One),
+        PoolNativeExport
+    );
+    assert_exports_resolve!(PriceFeedNativePackage::definition(), PriceFeedNativeExport);
+    assert_exports_resolve!(
+        PaymentSchedulerNativePackage::definition(),
+        PaymentSchedulerNativeExport
+    );
+    assert_exports_resolve!(OrderBookNativePackage::definition(), OrderBookNativeExport);
+    assert_exports_resolve!(
+        TransactionTrackerNativePackage::definition(),
+        TransactionTrackerNativeExport
+    );
+    assert_exports_resolve!(TestUtilsNativePackage::definition(), TestUtilsNativeExport);
+}
+
+#[test]
+fn unknown_native_export_name_is_rejected() {
+    assert!(matches!(
+        ResourceNativeExport::from_export_name("take_UnknownBlueprint"),
+        Err(RuntimeError::ApplicationError(
+            ApplicationError::ExportDoesNotExist(export_name)
+        )) if export_name == "take_UnknownBlueprint"
+    ));
+}
+
 #[derive(Clone)]
 pub struct Extension;
 
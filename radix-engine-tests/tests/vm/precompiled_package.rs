@@ -0,0 +1,120 @@
+use radix_engine::errors::RuntimeError;
+use radix_engine::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
+use radix_engine::system::system_callback::SystemLockData;
+use radix_engine::types::*;
+use radix_engine::vm::{PrecompiledPackageCode, VmApi, VmInvoke};
+use radix_engine_interface::api::ClientApi;
+use radix_engine_interface::blueprints::package::CodeHash;
+use radix_engine_tests::common::*;
+use scrypto_unit::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use transaction::prelude::*;
+
+/// A native implementation of the `arguments` package, whose functions all return their argument.
+#[derive(Clone, Default)]
+struct PrecompiledArguments {
+    invocations: Arc<AtomicUsize>,
+}
+
+impl VmInvoke for PrecompiledArguments {
+    fn invoke<Y, V>(
+        &mut self,
+        _export_name: &str,
+        input: &IndexedScryptoValue,
+        _api: &mut Y,
+        _vm_api: &V,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError> + KernelNodeApi + KernelSubstateApi<SystemLockData>,
+        V: VmApi,
+    {
+        self.invocations.fetch_add(1, Ordering::SeqCst);
+        let (arg,): (ScryptoValue,) = input.as_typed().unwrap();
+        Ok(IndexedScryptoValue::from_typed(&arg))
+    }
+}
+
+fn option_argument_manifest(package_address: PackageAddress) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Arguments",
+            "option_argument",
+            manifest_args!(Option::<ManifestBucket>::None),
+        )
+        .build()
+}
+
+#[test]
+fn precompiled_package_code_is_invoked_in_place_of_wasm() {
+    // Arrange
+    let (code, definition) = PackageLoader::get("arguments");
+    let precompiled = PrecompiledArguments::default();
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_extension(PrecompiledPackageCode::new(
+            CodeHash::from_hash(hash(&code)),
+            precompiled.clone(),
+        ))
+        .build();
+    let package_address = test_runner.publish_package_simple((code, definition));
+
+    // Act
+    let receipt = test_runner.execute_manifest(option_argument_manifest(package_address), vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(precompiled.invocations.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn precompiled_package_code_of_other_code_hash_is_not_invoked() {
+    // Arrange
+    let precompiled = PrecompiledArguments::default();
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_extension(PrecompiledPackageCode::new(
+            CodeHash::from_hash(hash("other code")),
+            precompiled.clone(),
+        ))
+        .build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("arguments"));
+
+    // Act
+    let receipt = test_runner.execute_manifest(option_argument_manifest(package_address), vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(precompiled.invocations.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn precompiled_package_code_produces_same_outcome_as_wasm() {
+    // Arrange
+    let (code, definition) = PackageLoader::get("arguments");
+    let mut wasm_test_runner = TestRunnerBuilder::new().build();
+    let mut precompiled_test_runner = TestRunnerBuilder::new()
+        .with_custom_extension(PrecompiledPackageCode::new(
+            CodeHash::from_hash(hash(&code)),
+            PrecompiledArguments::default(),
+        ))
+        .build();
+    let wasm_package_address =
+        wasm_test_runner.publish_package_simple((code.clone(), definition.clone()));
+    let precompiled_package_address =
+        precompiled_test_runner.publish_package_simple((code, definition));
+
+    // Act
+    let wasm_receipt =
+        wasm_test_runner.execute_manifest(option_argument_manifest(wasm_package_address), vec![]);
+    let precompiled_receipt = precompiled_test_runner.execute_manifest(
+        option_argument_manifest(precompiled_package_address),
+        vec![],
+    );
+
+    // Assert
+    assert_eq!(
+        wasm_receipt.expect_commit_success().outcome,
+        precompiled_receipt.expect_commit_success().outcome
+    );
+}
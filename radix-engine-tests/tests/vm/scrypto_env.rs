@@ -1,7 +1,8 @@
 use radix_engine_tests::common::*;
 use radix_engine::blueprints::resource::ProofError;
 use radix_engine::errors::{
-    ApplicationError, CallFrameError, KernelError, RuntimeError, SystemError,
+    ApplicationError, CallFrameError, EventError, KernelError, RuntimeError, SystemError,
+    SystemModuleError,
 };
 use radix_engine::kernel::call_frame::OpenSubstateError;
 use radix_engine::types::*;
@@ -213,3 +214,104 @@ fn should_be_able_to_bech32_encode_address() {
     let result = receipt.expect_commit_success();
     let _bech32_encoded: String = result.output(1);
 }
+
+#[test]
+fn should_be_able_to_get_blueprint_id_of_global_address() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("scrypto_env"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "ScryptoEnvTest",
+            "get_blueprint_id",
+            manifest_args!(FAUCET),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let blueprint_id: BlueprintId = result.output(1);
+    assert_eq!(blueprint_id, BlueprintId::new(&FAUCET_PACKAGE, FAUCET_BLUEPRINT));
+}
+
+#[test]
+fn should_be_able_to_get_blueprint_interface_of_global_address() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("scrypto_env"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "ScryptoEnvTest",
+            "get_blueprint_function_names",
+            manifest_args!(FAUCET),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let function_names: Vec<String> = result.output(1);
+    assert!(function_names.contains(&"free".to_owned()));
+    assert!(function_names.contains(&"lock_fee".to_owned()));
+}
+
+#[test]
+fn should_be_able_to_get_event_schema_of_blueprint() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("scrypto_env"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "ScryptoEnvTest",
+            "get_event_type_name",
+            manifest_args!(RESOURCE_PACKAGE, FUNGIBLE_VAULT_BLUEPRINT, "WithdrawEvent"),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let type_name: Option<String> = result.output(1);
+    assert_eq!(type_name, Some("WithdrawEvent".to_owned()));
+}
+
+#[test]
+fn getting_schema_of_unknown_event_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("scrypto_env"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "ScryptoEnvTest",
+            "get_event_type_name",
+            manifest_args!(RESOURCE_PACKAGE, FUNGIBLE_VAULT_BLUEPRINT, "UnknownEvent"),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::EventError(error))
+                if matches!(**error, EventError::SchemaNotFoundError { .. })
+        )
+    });
+}
@@ -2,7 +2,11 @@ use radix_engine_tests::common::*;
 use radix_engine::{
     errors::{RuntimeError, VmError},
     types::*,
-    vm::wasm::WasmRuntimeError,
+    utils::audit_wasm_metering,
+    vm::wasm::{
+        InstructionCountingRules, ScryptoV1WasmValidator, WasmModule, WasmRuntimeError,
+        WasmValidatorConfigV1,
+    },
 };
 use scrypto_unit::*;
 use transaction::prelude::*;
@@ -210,3 +214,80 @@ fn test_grow_memory_by_more_than_65536() {
         _ => false,
     })
 }
+
+#[test]
+fn test_function_cost_table() {
+    // Arrange
+    let code = wat2wasm(&include_local_wasm_str!("loop.wat").replace("${n}", "1000"));
+
+    // Act
+    let instruction_counts = WasmModule::init(&code)
+        .unwrap()
+        .function_cost_table(&InstructionCountingRules)
+        .unwrap();
+    let weighted_costs = WasmModule::init(&code)
+        .unwrap()
+        .function_cost_table(&WasmValidatorConfigV1::new())
+        .unwrap();
+
+    // Assert
+    assert_eq!(instruction_counts.len(), 1);
+    assert_eq!(instruction_counts[0].function_index, 0);
+    assert_eq!(instruction_counts[0].export_name, Some("Test_f".to_string()));
+    assert_eq!(instruction_counts[0].instruction_count, 21);
+    assert_eq!(instruction_counts[0].static_cost, 21);
+    assert_eq!(weighted_costs[0].instruction_count, 21);
+    assert!(weighted_costs[0].static_cost > instruction_counts[0].static_cost);
+}
+
+#[test]
+fn test_validate_with_cost_table_instruments_identically() {
+    // Arrange
+    let code = wat2wasm(&include_local_wasm_str!("loop.wat").replace("${n}", "1000"));
+    let definition = single_function_package_definition("Test", "f");
+    let validator = ScryptoV1WasmValidator::new(0u64);
+
+    // Act
+    let (instrumented_code, function_exports) = validator
+        .validate(&code, definition.blueprints.values())
+        .unwrap();
+    let (audited_code, audited_exports, cost_table) = validator
+        .validate_with_cost_table(&code, definition.blueprints.values())
+        .unwrap();
+
+    // Assert
+    assert_eq!(instrumented_code, audited_code);
+    assert_eq!(function_exports, audited_exports);
+    assert_eq!(
+        cost_table,
+        WasmModule::init(&code)
+            .unwrap()
+            .function_cost_table(&WasmValidatorConfigV1::new())
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_metered_units_against_executed_instructions() {
+    // Arrange
+    let audit = |n: u32| {
+        let code = wat2wasm(&include_local_wasm_str!("loop.wat").replace("${n}", &n.to_string()));
+        audit_wasm_metering(&code, "Test_f", &[0]).unwrap()
+    };
+
+    // Act
+    let audits = [audit(1000), audit(2000), audit(3000)];
+
+    // Assert
+    // Every loop iteration executes the same instructions, so both measures grow linearly
+    assert_eq!(
+        audits[2].executed_instructions - audits[1].executed_instructions,
+        audits[1].executed_instructions - audits[0].executed_instructions
+    );
+    assert_eq!(
+        audits[2].metered_execution_units - audits[1].metered_execution_units,
+        audits[1].metered_execution_units - audits[0].metered_execution_units
+    );
+    assert!(audits[0].executed_instructions >= 8 * 1000);
+    assert!(audits[0].metered_execution_units > audits[0].executed_instructions * 1000);
+}
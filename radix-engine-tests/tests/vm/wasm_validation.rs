@@ -1,5 +1,7 @@
 use radix_engine_tests::common::*;
-use radix_engine::vm::wasm::{InvalidMemory, PrepareError, ScryptoV1WasmValidator};
+use radix_engine::vm::wasm::{
+    InvalidImport, InvalidMemory, PrepareError, ScryptoV1WasmValidator, WasmViolation,
+};
 use radix_engine_queries::typed_substate_layout::PackageDefinition;
 use scrypto_unit::*;
 
@@ -76,3 +78,59 @@ fn invalid_export_name_should_fail() {
         }
     }
 }
+
+#[test]
+fn diagnose_should_report_all_violations_with_offsets() {
+    // Arrange
+    let code = wat2wasm(
+        r#"
+            (module
+                (import "env" "not_allowed" (func $not_allowed))
+                (func $init)
+                (func (export "Test_f") (param i64) (result i64)
+                    (local f64)
+                    f64.const 1
+                    drop
+                    local.get 0
+                )
+                (start $init)
+                (memory $0 1)
+                (export "memory" (memory $0))
+            )
+            "#,
+    );
+
+    // Act
+    let diagnostics = ScryptoV1WasmValidator::new(0u64).diagnose(&code).unwrap();
+
+    // Assert
+    let violations: Vec<WasmViolation> =
+        diagnostics.iter().map(|d| d.violation.clone()).collect();
+    assert_eq!(
+        violations,
+        vec![
+            WasmViolation::InvalidImport(InvalidImport::ImportNotAllowed(
+                "not_allowed".to_string()
+            )),
+            WasmViolation::StartFunctionNotAllowed,
+            WasmViolation::FloatingPointNotAllowed,
+            WasmViolation::FloatingPointNotAllowed,
+        ]
+    );
+    assert!(diagnostics
+        .windows(2)
+        .all(|pair| pair[0].offset < pair[1].offset));
+    assert!(diagnostics.iter().all(|d| d.offset < code.len()));
+}
+
+#[test]
+fn diagnose_should_report_nothing_for_valid_code() {
+    // Arrange
+    let code = wat2wasm(&include_local_wasm_str!("basic_package.wat"));
+
+    // Act
+    let diagnostics = ScryptoV1WasmValidator::new(0u64).diagnose(&code).unwrap();
+
+    // Assert
+    assert!(diagnostics.is_empty());
+}
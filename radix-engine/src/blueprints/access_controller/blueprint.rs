@@ -451,7 +451,10 @@ impl AccessControllerBlueprint {
                 state,
                 events,
                 types: BlueprintTypeSchemaInit::default(),
-                functions: BlueprintFunctionsSchemaInit { functions },
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
                 hooks: BlueprintHooksInit::default(),
             },
 
@@ -1102,20 +1105,10 @@ impl AccessControllerBlueprint {
             .as_typed()
             .map_err(|e| RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e)))?;
 
-        let resource_address = {
-            let handle = api.actor_open_field(
-                ACTOR_STATE_SELF,
-                AccessControllerField::State.field_index(),
-                LockFlags::read_only(),
-            )?;
-
-            let access_controller = {
-                let access_controller: AccessControllerStateFieldPayload =
-                    api.field_read_typed(handle)?;
-                access_controller.into_latest()
-            };
-            access_controller.recovery_badge
-        };
+        let resource_address = AccessControllerStateApi::new(api)
+            .read_state()?
+            .into_latest()
+            .recovery_badge;
 
         let non_fungibles: IndexMap<NonFungibleLocalId, (ScryptoValue,)> = non_fungible_local_ids
             .into_iter()
@@ -1,6 +1,7 @@
 use crate::blueprints::access_controller::AccessControllerBlueprint;
 use crate::errors::{ApplicationError, RuntimeError};
 use crate::kernel::kernel_api::KernelNodeApi;
+use crate::native_exports;
 use crate::types::*;
 use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::*;
@@ -9,6 +10,30 @@ use radix_engine_interface::blueprints::package::PackageDefinition;
 use radix_engine_interface::*;
 use sbor::rust::prelude::*;
 
+native_exports! {
+    pub enum AccessControllerNativeExport {
+        Create => ACCESS_CONTROLLER_CREATE_IDENT,
+        CreateProof => ACCESS_CONTROLLER_CREATE_PROOF_IDENT,
+        InitiateRecoveryAsPrimary => ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT,
+        InitiateRecoveryAsRecovery => ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+        QuickConfirmPrimaryRoleRecoveryProposal => ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
+        QuickConfirmRecoveryRoleRecoveryProposal => ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+        TimedConfirmRecovery => ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
+        CancelPrimaryRoleRecoveryProposal => ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
+        CancelRecoveryRoleRecoveryProposal => ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+        LockPrimaryRole => ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT,
+        UnlockPrimaryRole => ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT,
+        StopTimedRecovery => ACCESS_CONTROLLER_STOP_TIMED_RECOVERY_IDENT,
+        InitiateBadgeWithdrawAttemptAsPrimary => ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_PRIMARY_IDENT,
+        InitiateBadgeWithdrawAttemptAsRecovery => ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_RECOVERY_IDENT,
+        QuickConfirmPrimaryRoleBadgeWithdrawAttempt => ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT,
+        QuickConfirmRecoveryRoleBadgeWithdrawAttempt => ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT,
+        CancelPrimaryRoleBadgeWithdrawAttempt => ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT,
+        CancelRecoveryRoleBadgeWithdrawAttempt => ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT,
+        MintRecoveryBadges => ACCESS_CONTROLLER_MINT_RECOVERY_BADGES_IDENT,
+    }
+}
+
 pub struct AccessControllerNativePackage;
 
 impl AccessControllerNativePackage {
@@ -28,78 +53,75 @@ impl AccessControllerNativePackage {
     where
         Y: KernelNodeApi + ClientApi<RuntimeError>,
     {
-        match export_name {
-            ACCESS_CONTROLLER_CREATE_IDENT => AccessControllerBlueprint::create(input, api),
-            ACCESS_CONTROLLER_CREATE_PROOF_IDENT => {
+        match AccessControllerNativeExport::from_export_name(export_name)? {
+            AccessControllerNativeExport::Create => AccessControllerBlueprint::create(input, api),
+            AccessControllerNativeExport::CreateProof => {
                 AccessControllerBlueprint::create_proof(input, api)
             }
-            ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT => {
+            AccessControllerNativeExport::InitiateRecoveryAsPrimary => {
                 AccessControllerBlueprint::initiate_recovery_as_primary(input, api)
             }
-            ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT => {
+            AccessControllerNativeExport::InitiateRecoveryAsRecovery => {
                 AccessControllerBlueprint::initiate_recovery_as_recovery(input, api)
             }
-            ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT => {
+            AccessControllerNativeExport::QuickConfirmPrimaryRoleRecoveryProposal => {
                 let receiver = Runtime::get_node_id(api)?;
                 AccessControllerBlueprint::quick_confirm_primary_role_recovery_proposal(
                     &receiver, input, api,
                 )
             }
-            ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT => {
+            AccessControllerNativeExport::QuickConfirmRecoveryRoleRecoveryProposal => {
                 let receiver = Runtime::get_node_id(api)?;
                 AccessControllerBlueprint::quick_confirm_recovery_role_recovery_proposal(
                     &receiver, input, api,
                 )
             }
-            ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT => {
+            AccessControllerNativeExport::TimedConfirmRecovery => {
                 let receiver = Runtime::get_node_id(api)?;
                 AccessControllerBlueprint::timed_confirm_recovery(&receiver, input, api)
             }
-            ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT => {
+            AccessControllerNativeExport::CancelPrimaryRoleRecoveryProposal => {
                 AccessControllerBlueprint::cancel_primary_role_recovery_proposal(input, api)
             }
-            ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT => {
+            AccessControllerNativeExport::CancelRecoveryRoleRecoveryProposal => {
                 AccessControllerBlueprint::cancel_recovery_role_recovery_proposal(input, api)
             }
-            ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT => {
+            AccessControllerNativeExport::LockPrimaryRole => {
                 AccessControllerBlueprint::lock_primary_role(input, api)
             }
-            ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT => {
+            AccessControllerNativeExport::UnlockPrimaryRole => {
                 AccessControllerBlueprint::unlock_primary_role(input, api)
             }
-            ACCESS_CONTROLLER_STOP_TIMED_RECOVERY_IDENT => {
+            AccessControllerNativeExport::StopTimedRecovery => {
                 AccessControllerBlueprint::stop_timed_recovery(input, api)
             }
-            ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_PRIMARY_IDENT => {
+            AccessControllerNativeExport::InitiateBadgeWithdrawAttemptAsPrimary => {
                 AccessControllerBlueprint::initiate_badge_withdraw_attempt_as_primary(input, api)
             }
-            ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_RECOVERY_IDENT => {
+            AccessControllerNativeExport::InitiateBadgeWithdrawAttemptAsRecovery => {
                 AccessControllerBlueprint::initiate_badge_withdraw_attempt_as_recovery(input, api)
             }
-            ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT => {
+            AccessControllerNativeExport::QuickConfirmPrimaryRoleBadgeWithdrawAttempt => {
                 let receiver = Runtime::get_node_id(api)?;
                 AccessControllerBlueprint::quick_confirm_primary_role_badge_withdraw_attempt(
                     &receiver, input, api,
                 )
             }
-            ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT => {
+            AccessControllerNativeExport::QuickConfirmRecoveryRoleBadgeWithdrawAttempt => {
                 let receiver = Runtime::get_node_id(api)?;
                 AccessControllerBlueprint::quick_confirm_recovery_role_badge_withdraw_attempt(
                     &receiver, input, api,
                 )
             }
-            ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT => {
+            AccessControllerNativeExport::CancelPrimaryRoleBadgeWithdrawAttempt => {
                 AccessControllerBlueprint::cancel_primary_role_badge_withdraw_attempt(input, api)
             }
-            ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT => {
+            AccessControllerNativeExport::CancelRecoveryRoleBadgeWithdrawAttempt => {
                 AccessControllerBlueprint::cancel_recovery_role_badge_withdraw_attempt(input, api)
             }
-            ACCESS_CONTROLLER_MINT_RECOVERY_BADGES_IDENT => {
+            AccessControllerNativeExport::MintRecoveryBadges => {
                 AccessControllerBlueprint::mint_recovery_badges(input, api)
             }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
         }
     }
 }
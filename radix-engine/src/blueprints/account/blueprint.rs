@@ -35,6 +35,13 @@ pub struct AccountSubstate {
     pub default_deposit_rule: DefaultDepositRule,
 }
 
+#[derive(Debug, PartialEq, Eq, ScryptoSbor, Clone)]
+pub struct AccountAuthorizerSubstate {
+    /// The component which authorizes the owner protected methods of the account on top of the
+    /// owner role, if any.
+    pub authorizer: Option<ComponentAddress>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum AccountError {
     VaultDoesNotExist { resource_address: ResourceAddress },
@@ -73,6 +80,13 @@ declare_native_blueprint_state! {
                 kind: StaticSingleVersioned,
             },
             condition: Condition::Always,
+        },
+        authorizer:  {
+            ident: Authorizer,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
         }
     },
     collections: {
@@ -113,6 +127,7 @@ declare_native_blueprint_state! {
 }
 
 pub type AccountDepositRuleV1 = AccountSubstate;
+pub type AccountAuthorizerV1 = AccountAuthorizerSubstate;
 pub type AccountResourceVaultV1 = Vault;
 pub type AccountResourcePreferenceV1 = ResourcePreference;
 pub type AccountAuthorizedDepositorV1 = ();
@@ -480,17 +495,95 @@ impl AccountBlueprint {
             },
         );
 
+        functions.insert(
+            ACCOUNT_GET_DEFAULT_DEPOSIT_RULE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountGetDefaultDepositRuleInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountGetDefaultDepositRuleOutput>(),
+                ),
+                export: ACCOUNT_GET_DEFAULT_DEPOSIT_RULE_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountGetResourcePreferenceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountGetResourcePreferenceOutput>(),
+                ),
+                export: ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_IS_AUTHORIZED_DEPOSITOR_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountIsAuthorizedDepositorInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountIsAuthorizedDepositorOutput>(),
+                ),
+                export: ACCOUNT_IS_AUTHORIZED_DEPOSITOR_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_SET_AUTHORIZER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountSetAuthorizerInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountSetAuthorizerOutput>(),
+                ),
+                export: ACCOUNT_SET_AUTHORIZER_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_GET_AUTHORIZER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountGetAuthorizerInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountGetAuthorizerOutput>(),
+                ),
+                export: ACCOUNT_GET_AUTHORIZER_IDENT.to_string(),
+            },
+        );
+
         let events = event_schema! {
             aggregator,
             [
                 WithdrawEvent,
                 DepositEvent,
                 RejectedDepositEvent,
+                DepositBatchResultEvent,
                 SetResourcePreferenceEvent,
                 RemoveResourcePreferenceEvent,
                 SetDefaultDepositRuleEvent,
                 AddAuthorizedDepositorEvent,
                 RemoveAuthorizedDepositorEvent,
+                SetAuthorizerEvent,
             ]
         };
 
@@ -513,7 +606,10 @@ impl AccountBlueprint {
                 state,
                 events,
                 types: BlueprintTypeSchemaInit::default(),
-                functions: BlueprintFunctionsSchemaInit { functions },
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
                 hooks: BlueprintHooksInit {
                     hooks: indexmap!(BlueprintHook::OnVirtualize => ACCOUNT_ON_VIRTUALIZE_EXPORT_NAME.to_string()),
                 },
@@ -546,11 +642,17 @@ impl AccountBlueprint {
                         ACCOUNT_BURN_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
                         ACCOUNT_ADD_AUTHORIZED_DEPOSITOR => [OWNER_ROLE];
                         ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR => [OWNER_ROLE];
+                        ACCOUNT_SET_AUTHORIZER_IDENT => [OWNER_ROLE];
 
                         ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT => MethodAccessibility::Public;
                         ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => MethodAccessibility::Public;
                         ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT => MethodAccessibility::Public;
                         ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT => MethodAccessibility::Public;
+
+                        ACCOUNT_GET_DEFAULT_DEPOSIT_RULE_IDENT => MethodAccessibility::Public;
+                        ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT => MethodAccessibility::Public;
+                        ACCOUNT_IS_AUTHORIZED_DEPOSITOR_IDENT => MethodAccessibility::Public;
+                        ACCOUNT_GET_AUTHORIZER_IDENT => MethodAccessibility::Public;
                     }
                 )),
             },
@@ -729,6 +831,9 @@ impl AccountBlueprint {
             indexmap! {
                 AccountField::DepositRule.field_index() => FieldValue::new(&AccountDepositRuleFieldPayload::from_content_source(AccountDepositRuleV1 {
                     default_deposit_rule: DefaultDepositRule::Accept,
+                })),
+                AccountField::Authorizer.field_index() => FieldValue::new(&AccountAuthorizerFieldPayload::from_content_source(AccountAuthorizerV1 {
+                    authorizer: None,
                 }))
             },
             indexmap!(),
@@ -843,6 +948,8 @@ impl AccountBlueprint {
         }
     }
 
+    /// Method is public to all - deposits the buckets allowed by the account's deposit rules and
+    /// refunds the others, rather than refunding the whole batch.
     pub fn try_deposit_batch_or_refund<Y>(
         buckets: Vec<Bucket>,
         authorized_depositor_badge: Option<ResourceOrNonFungible>,
@@ -851,47 +958,39 @@ impl AccountBlueprint {
     where
         Y: ClientApi<RuntimeError>,
     {
-        let offending_buckets = buckets
-            .iter()
-            .map(|bucket| {
-                bucket
-                    .resource_address(api)
-                    .and_then(|resource_address| Self::is_deposit_allowed(&resource_address, api))
-                    .map(|can_be_deposited| (bucket, can_be_deposited))
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .filter_map(|(bucket, can_be_deposited)| {
-                if !can_be_deposited {
-                    Some(Bucket(bucket.0))
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<_>>();
+        let (accepted_buckets, refused_buckets) =
+            Self::partition_batch_deposit(buckets, authorized_depositor_badge, api)?;
 
-        if offending_buckets.is_empty() {
-            Self::deposit_batch(buckets, api)?;
-            Ok(None)
-        } else if let Some(badge) = authorized_depositor_badge {
-            Self::validate_badge_is_authorized_depositor(&badge, api)??;
-            Self::validate_badge_is_present(badge, api)?;
-            Self::deposit_batch(buckets, api)?;
+        let mut accepted = index_set_new();
+        for (resource_address, bucket) in accepted_buckets {
+            accepted.insert(resource_address);
+            Self::deposit(bucket, api)?;
+        }
+
+        let mut refused = index_set_new();
+        for (resource_address, bucket) in refused_buckets.iter() {
+            refused.insert(*resource_address);
+            let event = if resource_address.is_fungible() {
+                RejectedDepositEvent::Fungible(*resource_address, bucket.amount(api)?)
+            } else {
+                RejectedDepositEvent::NonFungible(
+                    *resource_address,
+                    bucket.non_fungible_local_ids(api)?,
+                )
+            };
+            Runtime::emit_event(api, event)?;
+        }
+        Runtime::emit_event(api, DepositBatchResultEvent { accepted, refused })?;
+
+        if refused_buckets.is_empty() {
             Ok(None)
         } else {
-            for bucket in offending_buckets {
-                let resource_address = bucket.resource_address(api)?;
-                let event = if resource_address.is_fungible() {
-                    RejectedDepositEvent::Fungible(resource_address, bucket.amount(api)?)
-                } else {
-                    RejectedDepositEvent::NonFungible(
-                        resource_address,
-                        bucket.non_fungible_local_ids(api)?,
-                    )
-                };
-                Runtime::emit_event(api, event)?;
-            }
-            Ok(Some(buckets))
+            Ok(Some(
+                refused_buckets
+                    .into_iter()
+                    .map(|(_, bucket)| bucket)
+                    .collect(),
+            ))
         }
     }
 
@@ -922,12 +1021,54 @@ impl AccountBlueprint {
     where
         Y: ClientApi<RuntimeError>,
     {
-        let buckets = Self::try_deposit_batch_or_refund(buckets, authorized_depositor_badge, api)?;
-        if let Some(_) = buckets {
-            Err(AccountError::NotAllBucketsCouldBeDeposited.into())
-        } else {
-            Ok(())
+        let (accepted_buckets, refused_buckets) =
+            Self::partition_batch_deposit(buckets, authorized_depositor_badge, api)?;
+        if !refused_buckets.is_empty() {
+            return Err(AccountError::NotAllBucketsCouldBeDeposited.into());
+        }
+        for (_, bucket) in accepted_buckets {
+            Self::deposit(bucket, api)?;
+        }
+        Ok(())
+    }
+
+    /// Splits the buckets into the ones which can be deposited and the ones which can't, keyed by
+    /// their resource address. An authorized depositor badge, if given and needed, allows the
+    /// whole batch to be deposited.
+    fn partition_batch_deposit<Y>(
+        buckets: Vec<Bucket>,
+        authorized_depositor_badge: Option<ResourceOrNonFungible>,
+        api: &mut Y,
+    ) -> Result<
+        (
+            Vec<(ResourceAddress, Bucket)>,
+            Vec<(ResourceAddress, Bucket)>,
+        ),
+        RuntimeError,
+    >
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let mut accepted = Vec::new();
+        let mut refused = Vec::new();
+        for bucket in buckets {
+            let resource_address = bucket.resource_address(api)?;
+            if Self::is_deposit_allowed(&resource_address, api)? {
+                accepted.push((resource_address, bucket));
+            } else {
+                refused.push((resource_address, bucket));
+            }
         }
+
+        if !refused.is_empty() {
+            if let Some(badge) = authorized_depositor_badge {
+                Self::validate_badge_is_authorized_depositor(&badge, api)??;
+                Self::validate_badge_is_present(badge, api)?;
+                accepted.extend(refused.drain(..));
+            }
+        }
+
+        Ok((accepted, refused))
     }
 
     // Returns a result of a result. The outer result's error type is [`RuntimeError`] and it's for
@@ -1271,7 +1412,17 @@ impl AccountBlueprint {
         Ok(())
     }
 
-    fn get_default_deposit_rule<Y>(api: &mut Y) -> Result<DefaultDepositRule, RuntimeError>
+    pub fn is_authorized_depositor<Y>(
+        badge: ResourceOrNonFungible,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Ok(Self::validate_badge_is_authorized_depositor(&badge, api)?.is_ok())
+    }
+
+    pub fn get_default_deposit_rule<Y>(api: &mut Y) -> Result<DefaultDepositRule, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
@@ -1289,6 +1440,49 @@ impl AccountBlueprint {
         Ok(default)
     }
 
+    /// Sets the component which authorizes the owner protected methods of the account on top of
+    /// the owner role. Setting the authorizer itself only requires the owner role.
+    pub fn set_authorizer<Y>(
+        authorizer: Option<ComponentAddress>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            AccountField::Authorizer.field_index(),
+            LockFlags::MUTABLE,
+        )?;
+        api.field_write_typed(
+            handle,
+            &AccountAuthorizerFieldPayload::from_content_source(AccountAuthorizerV1 { authorizer }),
+        )?;
+        api.field_close(handle)?;
+
+        Runtime::emit_event(api, SetAuthorizerEvent { authorizer })?;
+
+        Ok(())
+    }
+
+    pub fn get_authorizer<Y>(api: &mut Y) -> Result<Option<ComponentAddress>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            AccountField::Authorizer.field_index(),
+            LockFlags::read_only(),
+        )?;
+        let authorizer = api
+            .field_read_typed::<AccountAuthorizerFieldPayload>(handle)?
+            .into_latest()
+            .authorizer;
+        api.field_close(handle)?;
+
+        Ok(authorizer)
+    }
+
     fn get_vault<F, Y, R>(
         resource_address: ResourceAddress,
         vault_fn: F,
@@ -1408,7 +1602,7 @@ impl AccountBlueprint {
         Ok(does_vault_exist)
     }
 
-    fn get_resource_preference<Y>(
+    pub fn get_resource_preference<Y>(
         resource_address: &ResourceAddress,
         api: &mut Y,
     ) -> Result<Option<ResourcePreference>, RuntimeError>
@@ -22,6 +22,14 @@ pub enum RejectedDepositEvent {
     NonFungible(ResourceAddress, IndexSet<NonFungibleLocalId>),
 }
 
+/// Summarizes a batch deposit which refunds the buckets it can't deposit, by the resources that
+/// were accepted and the ones that were refused.
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct DepositBatchResultEvent {
+    pub accepted: IndexSet<ResourceAddress>,
+    pub refused: IndexSet<ResourceAddress>,
+}
+
 #[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SetResourcePreferenceEvent {
     pub resource_address: ResourceAddress,
@@ -47,3 +55,8 @@ pub struct AddAuthorizedDepositorEvent {
 pub struct RemoveAuthorizedDepositorEvent {
     pub authorized_depositor_badge: ResourceOrNonFungible,
 }
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SetAuthorizerEvent {
+    pub authorizer: Option<ComponentAddress>,
+}
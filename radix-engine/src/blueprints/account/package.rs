@@ -1,6 +1,7 @@
 use crate::blueprints::account::AccountBlueprint;
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
+use crate::native_exports;
 use crate::types::*;
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::account::*;
@@ -9,6 +10,41 @@ use radix_engine_interface::hooks::OnVirtualizeInput;
 
 pub const ACCOUNT_ON_VIRTUALIZE_EXPORT_NAME: &str = "on_virtualize";
 
+native_exports! {
+    pub enum AccountNativeExport {
+        OnVirtualize => ACCOUNT_ON_VIRTUALIZE_EXPORT_NAME,
+        CreateAdvanced => ACCOUNT_CREATE_ADVANCED_IDENT,
+        Create => ACCOUNT_CREATE_IDENT,
+        Securify => ACCOUNT_SECURIFY_IDENT,
+        LockFee => ACCOUNT_LOCK_FEE_IDENT,
+        LockContingentFee => ACCOUNT_LOCK_CONTINGENT_FEE_IDENT,
+        Deposit => ACCOUNT_DEPOSIT_IDENT,
+        DepositBatch => ACCOUNT_DEPOSIT_BATCH_IDENT,
+        TryDepositOrRefund => ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT,
+        TryDepositBatchOrRefund => ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT,
+        TryDepositOrAbort => ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT,
+        TryDepositBatchOrAbort => ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT,
+        Withdraw => ACCOUNT_WITHDRAW_IDENT,
+        WithdrawNonFungibles => ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT,
+        Burn => ACCOUNT_BURN_IDENT,
+        BurnNonFungibles => ACCOUNT_BURN_NON_FUNGIBLES_IDENT,
+        LockFeeAndWithdraw => ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT,
+        LockFeeAndWithdrawNonFungibles => ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT,
+        CreateProofOfAmount => ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT,
+        CreateProofOfNonFungibles => ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT,
+        SetDefaultDepositRule => ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+        SetResourcePreference => ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT,
+        RemoveResourcePreference => ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT,
+        AddAuthorizedDepositor => ACCOUNT_ADD_AUTHORIZED_DEPOSITOR,
+        RemoveAuthorizedDepositor => ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR,
+        GetDefaultDepositRule => ACCOUNT_GET_DEFAULT_DEPOSIT_RULE_IDENT,
+        GetResourcePreference => ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT,
+        IsAuthorizedDepositor => ACCOUNT_IS_AUTHORIZED_DEPOSITOR_IDENT,
+        SetAuthorizer => ACCOUNT_SET_AUTHORIZER_IDENT,
+        GetAuthorizer => ACCOUNT_GET_AUTHORIZER_IDENT,
+    }
+}
+
 pub struct AccountNativePackage;
 
 impl AccountNativePackage {
@@ -28,8 +64,8 @@ impl AccountNativePackage {
     where
         Y: ClientApi<RuntimeError>,
     {
-        match export_name {
-            ACCOUNT_ON_VIRTUALIZE_EXPORT_NAME => {
+        match AccountNativeExport::from_export_name(export_name)? {
+            AccountNativeExport::OnVirtualize => {
                 let input: OnVirtualizeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -38,7 +74,7 @@ impl AccountNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_CREATE_ADVANCED_IDENT => {
+            AccountNativeExport::CreateAdvanced => {
                 let input: AccountCreateAdvancedInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -51,7 +87,7 @@ impl AccountNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_CREATE_IDENT => {
+            AccountNativeExport::Create => {
                 let _input: AccountCreateInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -60,7 +96,7 @@ impl AccountNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_SECURIFY_IDENT => {
+            AccountNativeExport::Securify => {
                 let _input: AccountSecurifyInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -68,14 +104,14 @@ impl AccountNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_LOCK_FEE_IDENT => {
+            AccountNativeExport::LockFee => {
                 let input: AccountLockFeeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = AccountBlueprint::lock_fee(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
+            AccountNativeExport::LockContingentFee => {
                 let input: AccountLockContingentFeeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -83,7 +119,7 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::lock_contingent_fee(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_DEPOSIT_IDENT => {
+            AccountNativeExport::Deposit => {
                 let input: AccountDepositInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -91,7 +127,7 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::deposit(input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_DEPOSIT_BATCH_IDENT => {
+            AccountNativeExport::DepositBatch => {
                 let input: AccountDepositBatchInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -99,7 +135,7 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::deposit_batch(input.buckets, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT => {
+            AccountNativeExport::TryDepositOrRefund => {
                 let AccountTryDepositOrRefundInput {
                     bucket,
                     authorized_depositor_badge,
@@ -114,7 +150,7 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => {
+            AccountNativeExport::TryDepositBatchOrRefund => {
                 let AccountTryDepositBatchOrRefundInput {
                     buckets,
                     authorized_depositor_badge,
@@ -129,7 +165,7 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT => {
+            AccountNativeExport::TryDepositOrAbort => {
                 let AccountTryDepositOrAbortInput {
                     bucket,
                     authorized_depositor_badge,
@@ -144,7 +180,7 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT => {
+            AccountNativeExport::TryDepositBatchOrAbort => {
                 let AccountTryDepositBatchOrAbortInput {
                     buckets,
                     authorized_depositor_badge,
@@ -159,7 +195,7 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_WITHDRAW_IDENT => {
+            AccountNativeExport::Withdraw => {
                 let input: AccountWithdrawInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -167,7 +203,7 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::withdraw(input.resource_address, input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+            AccountNativeExport::WithdrawNonFungibles => {
                 let input: AccountWithdrawNonFungiblesInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -178,7 +214,7 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_BURN_IDENT => {
+            AccountNativeExport::Burn => {
                 let input: AccountBurnInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -186,7 +222,7 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::burn(input.resource_address, input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_BURN_NON_FUNGIBLES_IDENT => {
+            AccountNativeExport::BurnNonFungibles => {
                 let input: AccountBurnNonFungiblesInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -194,7 +230,7 @@ impl AccountNativePackage {
                     AccountBlueprint::burn_non_fungibles(input.resource_address, input.ids, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+            AccountNativeExport::LockFeeAndWithdraw => {
                 let input: AccountLockFeeAndWithdrawInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -206,7 +242,7 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+            AccountNativeExport::LockFeeAndWithdrawNonFungibles => {
                 let input: AccountLockFeeAndWithdrawNonFungiblesInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -219,7 +255,7 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => {
+            AccountNativeExport::CreateProofOfAmount => {
                 let input: AccountCreateProofOfAmountInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -230,7 +266,7 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => {
+            AccountNativeExport::CreateProofOfNonFungibles => {
                 let input: AccountCreateProofOfNonFungiblesInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -242,7 +278,7 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT => {
+            AccountNativeExport::SetDefaultDepositRule => {
                 let AccountSetDefaultDepositRuleInput { default } =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -250,7 +286,7 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::set_default_deposit_rule(default, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT => {
+            AccountNativeExport::SetResourcePreference => {
                 let AccountSetResourcePreferenceInput {
                     resource_address,
                     resource_preference,
@@ -264,7 +300,7 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT => {
+            AccountNativeExport::RemoveResourcePreference => {
                 let AccountRemoveResourcePreferenceInput { resource_address } =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -272,7 +308,7 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::remove_resource_preference(resource_address, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_ADD_AUTHORIZED_DEPOSITOR => {
+            AccountNativeExport::AddAuthorizedDepositor => {
                 let AccountAddAuthorizedDepositorInput { badge } =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -280,7 +316,7 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::add_authorized_depositor(badge, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR => {
+            AccountNativeExport::RemoveAuthorizedDepositor => {
                 let AccountRemoveAuthorizedDepositorInput { badge } =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -288,10 +324,43 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::remove_authorized_depositor(badge, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
+            AccountNativeExport::GetDefaultDepositRule => {
+                let _input: AccountGetDefaultDepositRuleInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountBlueprint::get_default_deposit_rule(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            AccountNativeExport::GetResourcePreference => {
+                let AccountGetResourcePreferenceInput { resource_address } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = AccountBlueprint::get_resource_preference(&resource_address, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            AccountNativeExport::IsAuthorizedDepositor => {
+                let AccountIsAuthorizedDepositorInput { badge } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = AccountBlueprint::is_authorized_depositor(badge, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            AccountNativeExport::SetAuthorizer => {
+                let AccountSetAuthorizerInput { authorizer } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountBlueprint::set_authorizer(authorizer, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            AccountNativeExport::GetAuthorizer => {
+                let _input: AccountGetAuthorizerInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountBlueprint::get_authorizer(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
         }
     }
 }
@@ -8,7 +8,7 @@ use crate::types::*;
 use native_sdk::modules::metadata::Metadata;
 use native_sdk::modules::role_assignment::RoleAssignment;
 use native_sdk::resource::NativeVault;
-use native_sdk::resource::{NativeBucket, ResourceManager};
+use native_sdk::resource::{NativeBucket, NativeFungibleVault, ResourceManager};
 use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::field_api::LockFlags;
 use radix_engine_interface::api::node_modules::auth::AuthAddresses;
@@ -26,6 +26,18 @@ const MILLIS_IN_SECOND: i64 = 1000;
 const SECONDS_IN_MINUTE: i64 = 60;
 const MILLIS_IN_MINUTE: i64 = MILLIS_IN_SECOND * SECONDS_IN_MINUTE;
 
+/// A limit on the number of simultaneously registered epoch change hooks, so that the work done
+/// during an epoch change stays bounded.
+pub const EPOCH_CHANGE_HOOKS_LIMIT: usize = 16;
+
+/// An XRD fee locked from a hook's bond to pay for each of its invocations.
+/// Only the actually consumed part is charged - the rest returns to the bond. A hook whose bond
+/// drops below this amount is removed on the next epoch change.
+pub const EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD: Decimal = dec!(10);
+
+/// A limit on the execution cost units of the transaction invoking a hook.
+pub const EPOCH_CHANGE_HOOK_EXECUTION_COST_UNIT_LIMIT: u32 = 10_000_000;
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct ConsensusManagerConfigSubstate {
     pub config: ConsensusManagerConfig,
@@ -65,6 +77,22 @@ pub struct ValidatorRewardsSubstate {
     pub rewards_vault: Vault,
 }
 
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct EpochChangeHooksSubstate {
+    /// The registered hooks, in the order of their registration.
+    pub hooks: IndexMap<ComponentAddress, EpochChangeHook>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct EpochChangeHook {
+    /// The name of the component's method to invoke (with the new [`Epoch`] as its argument).
+    pub method_name: String,
+    /// The epoch which this hook is to be invoked with, set on every epoch change.
+    /// Note: the hooks are not invoked by the epoch change itself, but by separate validator
+    /// transactions (one per hook), so that a failing (or costly) hook cannot halt consensus.
+    pub pending_epoch: Option<Epoch>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct CurrentValidatorSetSubstate {
     pub validator_set: ActiveValidatorSet,
@@ -217,6 +245,20 @@ pub enum ConsensusManagerError {
         current: u32,
         max: u32,
     },
+    EpochChangeHookAlreadyRegistered(ComponentAddress),
+    EpochChangeHookNotRegistered(ComponentAddress),
+    ExceededEpochChangeHookCount {
+        limit: usize,
+    },
+    InsufficientEpochChangeHookBond {
+        required: Decimal,
+        actual: Decimal,
+    },
+    EpochChangeHookNotPending(ComponentAddress),
+    EpochChangeHookCostUnitLimitTooHigh {
+        limit: u32,
+        actual: u32,
+    },
 }
 
 declare_native_blueprint_state! {
@@ -274,6 +316,13 @@ declare_native_blueprint_state! {
             },
             condition: Condition::Always,
         },
+        epoch_change_hooks: {
+            ident: EpochChangeHooks,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
+        },
     },
     collections: {
         registered_validators_by_stake: SortedIndex {
@@ -291,6 +340,17 @@ declare_native_blueprint_state! {
             },
             allow_ownership: false,
         },
+        epoch_change_hook_bonds: KeyValue {
+            entry_ident: EpochChangeHookBond,
+            key_type: {
+                kind: Static,
+                content_type: ComponentAddress,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: true,
+        },
     }
 }
 
@@ -337,7 +397,9 @@ pub type ConsensusManagerCurrentValidatorSetV1 = CurrentValidatorSetSubstate;
 pub type ConsensusManagerCurrentProposalStatisticV1 = CurrentProposalStatisticSubstate;
 pub type ConsensusManagerProposerMinuteTimestampV1 = ProposerMinuteTimestampSubstate;
 pub type ConsensusManagerProposerMilliTimestampV1 = ProposerMilliTimestampSubstate;
+pub type ConsensusManagerEpochChangeHooksV1 = EpochChangeHooksSubstate;
 pub type ConsensusManagerRegisteredValidatorByStakeV1 = Validator;
+pub type ConsensusManagerEpochChangeHookBondV1 = Vault;
 
 pub const CONSENSUS_MANAGER_REGISTERED_VALIDATORS_BY_STAKE_INDEX: CollectionIndex = 0u8;
 
@@ -452,6 +514,39 @@ impl ConsensusManagerBlueprint {
                 export: CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT.to_string(),
             },
         );
+        functions.insert(
+            CONSENSUS_MANAGER_REGISTER_EPOCH_CHANGE_HOOK_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<ConsensusManagerRegisterEpochChangeHookInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<ConsensusManagerRegisterEpochChangeHookOutput>()),
+                export: CONSENSUS_MANAGER_REGISTER_EPOCH_CHANGE_HOOK_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            CONSENSUS_MANAGER_DEREGISTER_EPOCH_CHANGE_HOOK_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<ConsensusManagerDeregisterEpochChangeHookInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<ConsensusManagerDeregisterEpochChangeHookOutput>()),
+                export: CONSENSUS_MANAGER_DEREGISTER_EPOCH_CHANGE_HOOK_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            CONSENSUS_MANAGER_INVOKE_EPOCH_CHANGE_HOOK_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<ConsensusManagerInvokeEpochChangeHookInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<ConsensusManagerInvokeEpochChangeHookOutput>()),
+                export: CONSENSUS_MANAGER_INVOKE_EPOCH_CHANGE_HOOK_IDENT.to_string(),
+            },
+        );
 
         let event_schema = event_schema! {
             aggregator,
@@ -479,7 +574,10 @@ impl ConsensusManagerBlueprint {
                 state,
                 events: event_schema,
                 types: BlueprintTypeSchemaInit::default(),
-                functions: BlueprintFunctionsSchemaInit { functions },
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
                 hooks: BlueprintHooksInit::default(),
             },
 
@@ -495,11 +593,15 @@ impl ConsensusManagerBlueprint {
                     methods {
                         CONSENSUS_MANAGER_START_IDENT => []; // Genesis is able to call this by skipping auth
                         CONSENSUS_MANAGER_NEXT_ROUND_IDENT => [VALIDATOR_ROLE];
+                        CONSENSUS_MANAGER_INVOKE_EPOCH_CHANGE_HOOK_IDENT => [VALIDATOR_ROLE];
 
                         CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT => MethodAccessibility::Public;
                         CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT => MethodAccessibility::Public;
                         CONSENSUS_MANAGER_COMPARE_CURRENT_TIME_IDENT => MethodAccessibility::Public;
                         CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT => MethodAccessibility::Public;
+                        // The hooked component's authorization is asserted by the methods themselves
+                        CONSENSUS_MANAGER_REGISTER_EPOCH_CHANGE_HOOK_IDENT => MethodAccessibility::Public;
+                        CONSENSUS_MANAGER_DEREGISTER_EPOCH_CHANGE_HOOK_IDENT => MethodAccessibility::Public;
                     }
                 )),
             },
@@ -592,6 +694,9 @@ impl ConsensusManagerBlueprint {
             let milli_timestamp = ProposerMilliTimestampSubstate {
                 epoch_milli: initial_time_milli,
             };
+            let epoch_change_hooks = EpochChangeHooksSubstate {
+                hooks: index_map_new(),
+            };
 
             api.new_simple_object(
                 CONSENSUS_MANAGER_BLUEPRINT,
@@ -603,6 +708,7 @@ impl ConsensusManagerBlueprint {
                     ConsensusManagerField::CurrentProposalStatistic.field_index() => FieldValue::new(&ConsensusManagerCurrentProposalStatisticFieldPayload::from_content_source(current_proposal_statistic)),
                     ConsensusManagerField::ProposerMinuteTimestamp.field_index() => FieldValue::new(&ConsensusManagerProposerMinuteTimestampFieldPayload::from_content_source(minute_timestamp)),
                     ConsensusManagerField::ProposerMilliTimestamp.field_index() => FieldValue::new(&ConsensusManagerProposerMilliTimestampFieldPayload::from_content_source(milli_timestamp)),
+                    ConsensusManagerField::EpochChangeHooks.field_index() => FieldValue::new(&ConsensusManagerEpochChangeHooksFieldPayload::from_content_source(epoch_change_hooks)),
                 },
             )?
         };
@@ -895,6 +1001,114 @@ impl ConsensusManagerBlueprint {
         }
     }
 
+    pub(crate) fn get_current_time_v3<Y>(
+        precision: TimePrecisionV3,
+        api: &mut Y,
+    ) -> Result<Instant, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match precision {
+            TimePrecisionV3::Minute => Self::get_current_time_v2(TimePrecisionV2::Minute, api),
+            TimePrecisionV3::Second => Self::get_current_time_v2(TimePrecisionV2::Second, api),
+            TimePrecisionV3::RoundedMinute(rounding) => {
+                let epoch_milli = Self::read_proposer_epoch_milli(api)?;
+                Ok(Self::round_epoch_milli(
+                    epoch_milli,
+                    MILLIS_IN_MINUTE,
+                    rounding,
+                ))
+            }
+            TimePrecisionV3::RoundedSecond(rounding) => {
+                let epoch_milli = Self::read_proposer_epoch_milli(api)?;
+                Ok(Self::round_epoch_milli(
+                    epoch_milli,
+                    MILLIS_IN_SECOND,
+                    rounding,
+                ))
+            }
+        }
+    }
+
+    pub(crate) fn compare_current_time_v3<Y>(
+        other_arbitrary_precision_instant: Instant,
+        precision: TimePrecisionV3,
+        operator: TimeComparisonOperator,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (unit_millis, rounding) = match precision {
+            TimePrecisionV3::Minute => {
+                return Self::compare_current_time_v2(
+                    other_arbitrary_precision_instant,
+                    TimePrecisionV2::Minute,
+                    operator,
+                    api,
+                );
+            }
+            TimePrecisionV3::Second => {
+                return Self::compare_current_time_v2(
+                    other_arbitrary_precision_instant,
+                    TimePrecisionV2::Second,
+                    operator,
+                    api,
+                );
+            }
+            TimePrecisionV3::RoundedMinute(rounding) => (MILLIS_IN_MINUTE, rounding),
+            TimePrecisionV3::RoundedSecond(rounding) => (MILLIS_IN_SECOND, rounding),
+        };
+
+        // Both sides are rounded the same way. Saturating is fine here, as the extremes still
+        // compare correctly against any realistic current time.
+        let other_epoch_milli = other_arbitrary_precision_instant
+            .seconds_since_unix_epoch
+            .saturating_mul(MILLIS_IN_SECOND);
+        let other_instant = Self::round_epoch_milli(other_epoch_milli, unit_millis, rounding);
+
+        let epoch_milli = Self::read_proposer_epoch_milli(api)?;
+        let proposer_instant = Self::round_epoch_milli(epoch_milli, unit_millis, rounding);
+
+        Ok(proposer_instant.compare(other_instant, operator))
+    }
+
+    fn read_proposer_epoch_milli<Y>(api: &mut Y) -> Result<i64, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            ConsensusManagerField::ProposerMilliTimestamp.into(),
+            LockFlags::read_only(),
+        )?;
+        let proposer_milli_timestamp = api
+            .field_read_typed::<ConsensusManagerProposerMilliTimestampFieldPayload>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+
+        Ok(proposer_milli_timestamp.epoch_milli)
+    }
+
+    /// Rounds a millisecond timestamp to a whole number of `unit_millis`, which must be a
+    /// multiple of a second.
+    fn round_epoch_milli(
+        epoch_milli: i64,
+        unit_millis: i64,
+        rounding: TimeRoundingMode,
+    ) -> Instant {
+        let units = match rounding {
+            TimeRoundingMode::Down => epoch_milli.div_euclid(unit_millis),
+            TimeRoundingMode::Nearest => epoch_milli
+                .saturating_add(unit_millis / 2)
+                .div_euclid(unit_millis),
+            TimeRoundingMode::Up => epoch_milli
+                .saturating_add(unit_millis - 1)
+                .div_euclid(unit_millis),
+        };
+        Instant::new(units * (unit_millis / MILLIS_IN_SECOND))
+    }
+
     fn epoch_minute_to_instant(epoch_minute: i32) -> Instant {
         Instant::new(epoch_minute as i64 * SECONDS_IN_MINUTE)
     }
@@ -951,6 +1165,7 @@ impl ConsensusManagerBlueprint {
         Self::update_proposal_statistics(progressed_rounds, proposal_history, api)?;
 
         let config = &config_substate.config;
+        let mut changed_to_epoch = None;
         let should_epoch_change = config.epoch_change_condition.should_epoch_change(
             manager_substate.effective_epoch_start_milli,
             proposer_timestamp_milli,
@@ -978,6 +1193,7 @@ impl ConsensusManagerBlueprint {
                 manager_substate.round = Round::zero();
                 manager_substate.actual_epoch_start_milli = proposer_timestamp_milli;
                 manager_substate.effective_epoch_start_milli = next_epoch_effective_start;
+                changed_to_epoch = Some(next_epoch);
             }
         }
         manager_substate.current_leader = Some(current_leader);
@@ -988,6 +1204,315 @@ impl ConsensusManagerBlueprint {
         )?;
         api.field_close(manager_handle)?;
 
+        if let Some(next_epoch) = changed_to_epoch {
+            Self::mark_epoch_change_hooks_pending(next_epoch, api)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn register_epoch_change_hook<Y>(
+        component_address: ComponentAddress,
+        method_name: String,
+        bond: Bucket,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Runtime::assert_access_rule(rule!(require(global_caller(component_address))), api)?;
+
+        if !bond.resource_address(api)?.eq(&XRD) {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(ConsensusManagerError::NotXrd),
+            ));
+        }
+        let bond_xrd = bond.amount(api)?;
+        if bond_xrd < EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::InsufficientEpochChangeHookBond {
+                        required: EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD,
+                        actual: bond_xrd,
+                    },
+                ),
+            ));
+        }
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            ConsensusManagerField::EpochChangeHooks.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut hooks_substate = api
+            .field_read_typed::<ConsensusManagerEpochChangeHooksFieldPayload>(handle)?
+            .into_latest();
+
+        if hooks_substate.hooks.contains_key(&component_address) {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::EpochChangeHookAlreadyRegistered(component_address),
+                ),
+            ));
+        }
+        if hooks_substate.hooks.len() >= EPOCH_CHANGE_HOOKS_LIMIT {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::ExceededEpochChangeHookCount {
+                        limit: EPOCH_CHANGE_HOOKS_LIMIT,
+                    },
+                ),
+            ));
+        }
+
+        // A bond vault cannot be dropped, so the one of a previous registration gets reused
+        let bond_handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            ConsensusManagerCollection::EpochChangeHookBondKeyValue.collection_index(),
+            &scrypto_encode(&component_address).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        let bond_vault = api
+            .key_value_entry_get_typed::<ConsensusManagerEpochChangeHookBondEntryPayload>(
+                bond_handle,
+            )?
+            .map(|bond_vault| bond_vault.into_latest());
+        match bond_vault {
+            Some(mut bond_vault) => bond_vault.put(bond, api)?,
+            None => {
+                let mut bond_vault = Vault::create(XRD, api)?;
+                bond_vault.put(bond, api)?;
+                api.key_value_entry_set_typed(
+                    bond_handle,
+                    &ConsensusManagerEpochChangeHookBondEntryPayload::from_content_source(
+                        bond_vault,
+                    ),
+                )?;
+            }
+        }
+        api.key_value_entry_close(bond_handle)?;
+
+        hooks_substate.hooks.insert(
+            component_address,
+            EpochChangeHook {
+                method_name,
+                pending_epoch: None,
+            },
+        );
+
+        api.field_write_typed(
+            handle,
+            &ConsensusManagerEpochChangeHooksFieldPayload::from_content_source(hooks_substate),
+        )?;
+        api.field_close(handle)?;
+
+        Ok(())
+    }
+
+    /// Removes the given component's hook (if still registered) and returns its remaining bond.
+    /// Note: this is also how the bond of a hook removed due to its exhaustion can be withdrawn.
+    pub(crate) fn deregister_epoch_change_hook<Y>(
+        component_address: ComponentAddress,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Runtime::assert_access_rule(rule!(require(global_caller(component_address))), api)?;
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            ConsensusManagerField::EpochChangeHooks.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut hooks_substate = api
+            .field_read_typed::<ConsensusManagerEpochChangeHooksFieldPayload>(handle)?
+            .into_latest();
+        hooks_substate.hooks.shift_remove(&component_address);
+        api.field_write_typed(
+            handle,
+            &ConsensusManagerEpochChangeHooksFieldPayload::from_content_source(hooks_substate),
+        )?;
+        api.field_close(handle)?;
+
+        // Every registration creates a bond vault, so a missing one means there never was a hook
+        let bond_handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            ConsensusManagerCollection::EpochChangeHookBondKeyValue.collection_index(),
+            &scrypto_encode(&component_address).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let bond_vault = api
+            .key_value_entry_get_typed::<ConsensusManagerEpochChangeHookBondEntryPayload>(
+                bond_handle,
+            )?
+            .map(|bond_vault| bond_vault.into_latest());
+        let Some(mut bond_vault) = bond_vault else {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::EpochChangeHookNotRegistered(component_address),
+                ),
+            ));
+        };
+        let remaining_bond = bond_vault.take_all(api)?;
+        api.key_value_entry_close(bond_handle)?;
+
+        Ok(remaining_bond)
+    }
+
+    /// Invokes the given component's hook with the epoch it is pending for, paying for the
+    /// invoking transaction from the hook's bond.
+    /// The node does this in a separate transaction per hook after every epoch change, with an
+    /// execution cost unit limit of at most [`EPOCH_CHANGE_HOOK_EXECUTION_COST_UNIT_LIMIT`] - so
+    /// that a failing hook only fails its own transaction (while still paying for it).
+    pub(crate) fn invoke_epoch_change_hook<Y>(
+        component_address: ComponentAddress,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let cost_unit_limit = api.execution_cost_unit_limit()?;
+        if cost_unit_limit > EPOCH_CHANGE_HOOK_EXECUTION_COST_UNIT_LIMIT {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::EpochChangeHookCostUnitLimitTooHigh {
+                        limit: EPOCH_CHANGE_HOOK_EXECUTION_COST_UNIT_LIMIT,
+                        actual: cost_unit_limit,
+                    },
+                ),
+            ));
+        }
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            ConsensusManagerField::EpochChangeHooks.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut hooks_substate = api
+            .field_read_typed::<ConsensusManagerEpochChangeHooksFieldPayload>(handle)?
+            .into_latest();
+
+        let (method_name, epoch) = hooks_substate
+            .hooks
+            .get_mut(&component_address)
+            .and_then(|hook| {
+                hook.pending_epoch
+                    .take()
+                    .map(|epoch| (hook.method_name.clone(), epoch))
+            })
+            .ok_or(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::EpochChangeHookNotPending(component_address),
+                ),
+            ))?;
+
+        let bond_handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            ConsensusManagerCollection::EpochChangeHookBondKeyValue.collection_index(),
+            &scrypto_encode(&component_address).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let bond_vault = api
+            .key_value_entry_get_typed::<ConsensusManagerEpochChangeHookBondEntryPayload>(
+                bond_handle,
+            )?
+            .map(|bond_vault| bond_vault.into_latest());
+        let Some(mut bond_vault) = bond_vault else {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::EpochChangeHookNotRegistered(component_address),
+                ),
+            ));
+        };
+        let bond_xrd = bond_vault.amount(api)?;
+        if bond_xrd < EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::InsufficientEpochChangeHookBond {
+                        required: EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD,
+                        actual: bond_xrd,
+                    },
+                ),
+            ));
+        }
+        bond_vault.lock_fee(api, EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD)?;
+        api.key_value_entry_close(bond_handle)?;
+
+        // The hook is invoked only after the state lock is released, so that the hooked component
+        // may freely query the consensus manager (e.g. for the current time).
+        api.field_write_typed(
+            handle,
+            &ConsensusManagerEpochChangeHooksFieldPayload::from_content_source(hooks_substate),
+        )?;
+        api.field_close(handle)?;
+
+        api.call_method(
+            component_address.as_node_id(),
+            &method_name,
+            scrypto_encode(&(epoch,)).unwrap(),
+        )?;
+
+        Ok(())
+    }
+
+    /// Marks every registered hook as pending an invocation with the new epoch, removing the ones
+    /// whose bonds can no longer pay for it (their remaining bonds stay withdrawable).
+    /// Note: nothing is charged here - a hook pays only when it is actually invoked.
+    fn mark_epoch_change_hooks_pending<Y>(
+        next_epoch: Epoch,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            ConsensusManagerField::EpochChangeHooks.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut hooks_substate = api
+            .field_read_typed::<ConsensusManagerEpochChangeHooksFieldPayload>(handle)?
+            .into_latest();
+
+        if hooks_substate.hooks.is_empty() {
+            api.field_close(handle)?;
+            return Ok(());
+        }
+
+        let mut exhausted = Vec::new();
+        for (component_address, hook) in hooks_substate.hooks.iter_mut() {
+            let bond_handle = api.actor_open_key_value_entry(
+                ACTOR_STATE_SELF,
+                ConsensusManagerCollection::EpochChangeHookBondKeyValue.collection_index(),
+                &scrypto_encode(component_address).unwrap(),
+                LockFlags::read_only(),
+            )?;
+            let bond_xrd = match api
+                .key_value_entry_get_typed::<ConsensusManagerEpochChangeHookBondEntryPayload>(
+                    bond_handle,
+                )? {
+                Some(bond_vault) => bond_vault.into_latest().amount(api)?,
+                None => Decimal::zero(),
+            };
+            api.key_value_entry_close(bond_handle)?;
+
+            if bond_xrd < EPOCH_CHANGE_HOOK_INVOCATION_FEE_XRD {
+                exhausted.push(*component_address);
+            } else {
+                hook.pending_epoch = Some(next_epoch);
+            }
+        }
+        for component_address in exhausted {
+            hooks_substate.hooks.shift_remove(&component_address);
+        }
+
+        api.field_write_typed(
+            handle,
+            &ConsensusManagerEpochChangeHooksFieldPayload::from_content_source(hooks_substate),
+        )?;
+        api.field_close(handle)?;
+
         Ok(())
     }
 
@@ -1,6 +1,7 @@
 use crate::blueprints::consensus_manager::{ConsensusManagerBlueprint, ValidatorBlueprint};
 use crate::errors::{ApplicationError, RuntimeError};
 use crate::kernel::kernel_api::KernelNodeApi;
+use crate::native_exports;
 use crate::types::*;
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::consensus_manager::*;
@@ -8,6 +9,43 @@ use radix_engine_interface::blueprints::package::PackageDefinition;
 
 pub const VALIDATOR_ROLE: &str = "validator";
 
+native_exports! {
+    pub enum ConsensusManagerNativeExport {
+        ConsensusManagerCreate => CONSENSUS_MANAGER_CREATE_IDENT,
+        ConsensusManagerGetCurrentEpoch => CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT,
+        ConsensusManagerStart => CONSENSUS_MANAGER_START_IDENT,
+        ConsensusManagerGetCurrentTime => CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT,
+        ConsensusManagerCompareCurrentTime => CONSENSUS_MANAGER_COMPARE_CURRENT_TIME_IDENT,
+        ConsensusManagerNextRound => CONSENSUS_MANAGER_NEXT_ROUND_IDENT,
+        ConsensusManagerCreateValidator => CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT,
+        ConsensusManagerRegisterEpochChangeHook => CONSENSUS_MANAGER_REGISTER_EPOCH_CHANGE_HOOK_IDENT,
+        ConsensusManagerDeregisterEpochChangeHook => CONSENSUS_MANAGER_DEREGISTER_EPOCH_CHANGE_HOOK_IDENT,
+        ConsensusManagerInvokeEpochChangeHook => CONSENSUS_MANAGER_INVOKE_EPOCH_CHANGE_HOOK_IDENT,
+        ValidatorRegister => VALIDATOR_REGISTER_IDENT,
+        ValidatorUnregister => VALIDATOR_UNREGISTER_IDENT,
+        ValidatorStakeAsOwner => VALIDATOR_STAKE_AS_OWNER_IDENT,
+        ValidatorStake => VALIDATOR_STAKE_IDENT,
+        ValidatorUnstake => VALIDATOR_UNSTAKE_IDENT,
+        ValidatorClaimXrd => VALIDATOR_CLAIM_XRD_IDENT,
+        ValidatorUpdateKey => VALIDATOR_UPDATE_KEY_IDENT,
+        ValidatorUpdateFee => VALIDATOR_UPDATE_FEE_IDENT,
+        ValidatorUpdateFeeSplit => VALIDATOR_UPDATE_FEE_SPLIT_IDENT,
+        ValidatorClaimFeeSplit => VALIDATOR_CLAIM_FEE_SPLIT_IDENT,
+        ValidatorUpdateAcceptDelegatedStake => VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT,
+        ValidatorAcceptsDelegatedStake => VALIDATOR_ACCEPTS_DELEGATED_STAKE_IDENT,
+        ValidatorTotalStakeXrdAmount => VALIDATOR_TOTAL_STAKE_XRD_AMOUNT_IDENT,
+        ValidatorTotalStakeUnitSupply => VALIDATOR_TOTAL_STAKE_UNIT_SUPPLY_IDENT,
+        ValidatorGetRedemptionValue => VALIDATOR_GET_REDEMPTION_VALUE_IDENT,
+        ValidatorSignalProtocolUpdateReadiness => VALIDATOR_SIGNAL_PROTOCOL_UPDATE_READINESS,
+        ValidatorGetProtocolUpdateReadiness => VALIDATOR_GET_PROTOCOL_UPDATE_READINESS_IDENT,
+        ValidatorLockOwnerStakeUnits => VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT,
+        ValidatorStartUnlockOwnerStakeUnits => VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT,
+        ValidatorFinishUnlockOwnerStakeUnits => VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT,
+        ValidatorApplyEmission => VALIDATOR_APPLY_EMISSION_IDENT,
+        ValidatorApplyReward => VALIDATOR_APPLY_REWARD_IDENT,
+    }
+}
+
 pub struct ConsensusManagerNativePackage;
 
 impl ConsensusManagerNativePackage {
@@ -28,8 +66,8 @@ impl ConsensusManagerNativePackage {
     where
         Y: KernelNodeApi + ClientApi<RuntimeError>,
     {
-        match export_name {
-            CONSENSUS_MANAGER_CREATE_IDENT => {
+        match ConsensusManagerNativeExport::from_export_name(export_name)? {
+            ConsensusManagerNativeExport::ConsensusManagerCreate => {
                 let input: ConsensusManagerCreateInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -44,7 +82,7 @@ impl ConsensusManagerNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT => {
+            ConsensusManagerNativeExport::ConsensusManagerGetCurrentEpoch => {
                 let _input: ConsensusManagerGetCurrentEpochInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -53,7 +91,7 @@ impl ConsensusManagerNativePackage {
                 let rtn = ConsensusManagerBlueprint::get_current_epoch(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            CONSENSUS_MANAGER_START_IDENT => {
+            ConsensusManagerNativeExport::ConsensusManagerStart => {
                 let _input: ConsensusManagerStartInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -61,7 +99,7 @@ impl ConsensusManagerNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT => {
+            ConsensusManagerNativeExport::ConsensusManagerGetCurrentTime => {
                 let input: ConsensusManagerGetCurrentTimeInputV1 =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -70,7 +108,7 @@ impl ConsensusManagerNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            CONSENSUS_MANAGER_COMPARE_CURRENT_TIME_IDENT => {
+            ConsensusManagerNativeExport::ConsensusManagerCompareCurrentTime => {
                 let input: ConsensusManagerCompareCurrentTimeInputV1 =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -84,7 +122,7 @@ impl ConsensusManagerNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            CONSENSUS_MANAGER_NEXT_ROUND_IDENT => {
+            ConsensusManagerNativeExport::ConsensusManagerNextRound => {
                 let input: ConsensusManagerNextRoundInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -97,7 +135,7 @@ impl ConsensusManagerNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT => {
+            ConsensusManagerNativeExport::ConsensusManagerCreateValidator => {
                 let input: ConsensusManagerCreateValidatorInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -111,63 +149,115 @@ impl ConsensusManagerNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_REGISTER_IDENT => {
+            ConsensusManagerNativeExport::ConsensusManagerRegisterEpochChangeHook => {
+                let input: ConsensusManagerRegisterEpochChangeHookInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = ConsensusManagerBlueprint::register_epoch_change_hook(
+                    input.component_address,
+                    input.method_name,
+                    input.bond,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ConsensusManagerNativeExport::ConsensusManagerDeregisterEpochChangeHook => {
+                let input: ConsensusManagerDeregisterEpochChangeHookInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = ConsensusManagerBlueprint::deregister_epoch_change_hook(
+                    input.component_address,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ConsensusManagerNativeExport::ConsensusManagerInvokeEpochChangeHook => {
+                let input: ConsensusManagerInvokeEpochChangeHookInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = ConsensusManagerBlueprint::invoke_epoch_change_hook(
+                    input.component_address,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ConsensusManagerNativeExport::ValidatorRegister => {
                 let _input: ValidatorRegisterInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::register(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_UNREGISTER_IDENT => {
+            ConsensusManagerNativeExport::ValidatorUnregister => {
                 let _input: ValidatorUnregisterInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::unregister(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_STAKE_AS_OWNER_IDENT => {
+            ConsensusManagerNativeExport::ValidatorStakeAsOwner => {
                 let input: ValidatorStakeAsOwnerInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::stake_as_owner(input.stake, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_STAKE_IDENT => {
+            ConsensusManagerNativeExport::ValidatorStake => {
                 let input: ValidatorStakeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::stake(input.stake, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_UNSTAKE_IDENT => {
+            ConsensusManagerNativeExport::ValidatorUnstake => {
                 let input: ValidatorUnstakeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::unstake(input.stake_unit_bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_CLAIM_XRD_IDENT => {
+            ConsensusManagerNativeExport::ValidatorClaimXrd => {
                 let input: ValidatorClaimXrdInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::claim_xrd(input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_UPDATE_KEY_IDENT => {
+            ConsensusManagerNativeExport::ValidatorUpdateKey => {
                 let input: ValidatorUpdateKeyInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::update_key(input.key, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_UPDATE_FEE_IDENT => {
+            ConsensusManagerNativeExport::ValidatorUpdateFee => {
                 let input: ValidatorUpdateFeeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::update_fee(input.new_fee_factor, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT => {
+            ConsensusManagerNativeExport::ValidatorUpdateFeeSplit => {
+                let input: ValidatorUpdateFeeSplitInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ValidatorBlueprint::update_fee_split(input.recipients, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ConsensusManagerNativeExport::ValidatorClaimFeeSplit => {
+                let input: ValidatorClaimFeeSplitInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ValidatorBlueprint::claim_fee_split(input.account, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ConsensusManagerNativeExport::ValidatorUpdateAcceptDelegatedStake => {
                 let input: ValidatorUpdateAcceptDelegatedStakeInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -178,28 +268,28 @@ impl ConsensusManagerNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_ACCEPTS_DELEGATED_STAKE_IDENT => {
+            ConsensusManagerNativeExport::ValidatorAcceptsDelegatedStake => {
                 let _: ValidatorAcceptsDelegatedStakeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::accepts_delegated_stake(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_TOTAL_STAKE_XRD_AMOUNT_IDENT => {
+            ConsensusManagerNativeExport::ValidatorTotalStakeXrdAmount => {
                 let _: ValidatorTotalStakeXrdAmountInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::total_stake_xrd_amount(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_TOTAL_STAKE_UNIT_SUPPLY_IDENT => {
+            ConsensusManagerNativeExport::ValidatorTotalStakeUnitSupply => {
                 let _: ValidatorTotalStakeUnitSupplyInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::total_stake_unit_supply(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_GET_REDEMPTION_VALUE_IDENT => {
+            ConsensusManagerNativeExport::ValidatorGetRedemptionValue => {
                 let input: ValidatorGetRedemptionValueInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -207,7 +297,7 @@ impl ConsensusManagerNativePackage {
                     ValidatorBlueprint::get_redemption_value(input.amount_of_stake_units, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_SIGNAL_PROTOCOL_UPDATE_READINESS => {
+            ConsensusManagerNativeExport::ValidatorSignalProtocolUpdateReadiness => {
                 let input: ValidatorSignalProtocolUpdateReadinessInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -215,7 +305,7 @@ impl ConsensusManagerNativePackage {
                 let rtn = ValidatorBlueprint::signal_protocol_update_readiness(input.vote, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_GET_PROTOCOL_UPDATE_READINESS_IDENT => {
+            ConsensusManagerNativeExport::ValidatorGetProtocolUpdateReadiness => {
                 let _input: ValidatorGetProtocolUpdateReadinessInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -223,14 +313,14 @@ impl ConsensusManagerNativePackage {
                 let rtn = ValidatorBlueprint::get_protocol_update_readiness(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT => {
+            ConsensusManagerNativeExport::ValidatorLockOwnerStakeUnits => {
                 let input: ValidatorLockOwnerStakeUnitsInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::lock_owner_stake_units(input.stake_unit_bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT => {
+            ConsensusManagerNativeExport::ValidatorStartUnlockOwnerStakeUnits => {
                 let input: ValidatorStartUnlockOwnerStakeUnitsInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -241,7 +331,7 @@ impl ConsensusManagerNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT => {
+            ConsensusManagerNativeExport::ValidatorFinishUnlockOwnerStakeUnits => {
                 let _input: ValidatorFinishUnlockOwnerStakeUnitsInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -249,7 +339,7 @@ impl ConsensusManagerNativePackage {
                 let rtn = ValidatorBlueprint::finish_unlock_owner_stake_units(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_APPLY_EMISSION_IDENT => {
+            ConsensusManagerNativeExport::ValidatorApplyEmission => {
                 let input: ValidatorApplyEmissionInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -262,20 +352,24 @@ impl ConsensusManagerNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            VALIDATOR_APPLY_REWARD_IDENT => {
+            ConsensusManagerNativeExport::ValidatorApplyReward => {
                 let input: ValidatorApplyRewardInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ValidatorBlueprint::apply_reward(input.xrd_bucket, input.epoch, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
         }
     }
 }
 
+native_exports! {
+    pub enum ConsensusManagerSecondsPrecisionNativeExport {
+        GetCurrentTime => CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT,
+        CompareCurrentTime => CONSENSUS_MANAGER_COMPARE_CURRENT_TIME_IDENT,
+    }
+}
+
 pub struct ConsensusManagerSecondsPrecisionNativeCode;
 
 impl ConsensusManagerSecondsPrecisionNativeCode {
@@ -287,8 +381,8 @@ impl ConsensusManagerSecondsPrecisionNativeCode {
     where
         Y: KernelNodeApi + ClientApi<RuntimeError>,
     {
-        match export_name {
-            CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT => {
+        match ConsensusManagerSecondsPrecisionNativeExport::from_export_name(export_name)? {
+            ConsensusManagerSecondsPrecisionNativeExport::GetCurrentTime => {
                 let input: ConsensusManagerGetCurrentTimeInputV2 =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -297,7 +391,7 @@ impl ConsensusManagerSecondsPrecisionNativeCode {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            CONSENSUS_MANAGER_COMPARE_CURRENT_TIME_IDENT => {
+            ConsensusManagerSecondsPrecisionNativeExport::CompareCurrentTime => {
                 let input: ConsensusManagerCompareCurrentTimeInputV2 =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -311,9 +405,52 @@ impl ConsensusManagerSecondsPrecisionNativeCode {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
+        }
+    }
+}
+
+native_exports! {
+    pub enum ConsensusManagerRoundedTimeNativeExport {
+        GetCurrentTime => CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT,
+        CompareCurrentTime => CONSENSUS_MANAGER_COMPARE_CURRENT_TIME_IDENT,
+    }
+}
+
+pub struct ConsensusManagerRoundedTimeNativeCode;
+
+impl ConsensusManagerRoundedTimeNativeCode {
+    pub fn invoke_export<Y>(
+        export_name: &str,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        match ConsensusManagerRoundedTimeNativeExport::from_export_name(export_name)? {
+            ConsensusManagerRoundedTimeNativeExport::GetCurrentTime => {
+                let input: ConsensusManagerGetCurrentTimeInputV3 =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = ConsensusManagerBlueprint::get_current_time_v3(input.precision, api)?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ConsensusManagerRoundedTimeNativeExport::CompareCurrentTime => {
+                let input: ConsensusManagerCompareCurrentTimeInputV3 =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = ConsensusManagerBlueprint::compare_current_time_v3(
+                    input.instant,
+                    input.precision,
+                    input.operator,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
         }
     }
 }
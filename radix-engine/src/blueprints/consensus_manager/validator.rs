@@ -5,6 +5,7 @@ use crate::errors::RuntimeError;
 use crate::internal_prelude::*;
 use crate::types::*;
 use crate::{event_schema, roles_template};
+use native_sdk::account::Account;
 use native_sdk::modules::metadata::Metadata;
 use native_sdk::resource::NativeVault;
 use native_sdk::resource::ResourceManager;
@@ -35,6 +36,10 @@ pub const VALIDATOR_PROTOCOL_VERSION_NAME_LEN: usize = 32;
 /// operations on any validator's owner's stake units vault.
 pub const OWNER_STAKE_UNITS_PENDING_WITHDRAWALS_LIMIT: usize = 100;
 
+/// A limit on the number of recipients among which a validator fee may be split (see
+/// [`ValidatorFeeSplitSubstate`]), so that applying the emission has bounded costs.
+pub const VALIDATOR_FEE_SPLIT_RECIPIENTS_LIMIT: usize = 10;
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct ValidatorSubstate {
     /// A key used internally for storage of registered validators sorted by their stake descending.
@@ -120,6 +125,17 @@ pub struct ValidatorProtocolUpdateReadinessSignalSubstate {
     pub protocol_version_name: Option<String>,
 }
 
+/// A configuration of automatic splitting of the validator fee.
+/// On every emission, each recipient gets its share of the stake units minted for the validator
+/// fee credited to its claim vault on this validator, from which anyone may deposit them into the
+/// recipient's account (see [`ValidatorBlueprint::claim_fee_split`]). Whatever is left (i.e. the
+/// unallocated remainder) goes to the owner's "public display" vault, as usual.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+#[sbor(transparent)]
+pub struct ValidatorFeeSplitSubstate {
+    pub recipients: Vec<ValidatorFeeRecipient>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
 pub struct UnstakeData {
     pub name: String,
@@ -159,9 +175,12 @@ pub enum ValidatorError {
     EpochUnlockHasNotOccurredYet,
     PendingOwnerStakeWithdrawalLimitReached,
     InvalidValidatorFeeFactor,
+    InvalidValidatorFeeSplit,
+    ValidatorFeeSplitRecipientLimitExceeded { limit: usize, actual: usize },
     ValidatorIsNotAcceptingDelegatedStake,
     InvalidProtocolVersionNameLength { expected: usize, actual: usize },
     EpochMathOverflow,
+    NoFeeSplitToClaim { account: ComponentAddress },
 }
 
 declare_native_blueprint_state! {
@@ -184,13 +203,33 @@ declare_native_blueprint_state! {
             },
             condition: Condition::Always,
         },
+        fee_split: {
+            ident: FeeSplit,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
+        },
     },
     collections: {
+        fee_split_claims: KeyValue {
+            entry_ident: FeeSplitClaim,
+            key_type: {
+                kind: Static,
+                content_type: ComponentAddress,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: true,
+        },
     }
 }
 
 pub type ValidatorStateV1 = ValidatorSubstate;
 pub type ValidatorProtocolUpdateReadinessSignalV1 = ValidatorProtocolUpdateReadinessSignalSubstate;
+pub type ValidatorFeeSplitV1 = ValidatorFeeSplitSubstate;
+pub type ValidatorFeeSplitClaimV1 = Vault;
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
 enum UpdateSecondaryIndex {
@@ -327,6 +366,32 @@ impl ValidatorBlueprint {
                 export: VALIDATOR_UPDATE_FEE_IDENT.to_string(),
             },
         );
+        functions.insert(
+            VALIDATOR_UPDATE_FEE_SPLIT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ValidatorUpdateFeeSplitInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ValidatorUpdateFeeSplitOutput>(),
+                ),
+                export: VALIDATOR_UPDATE_FEE_SPLIT_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            VALIDATOR_CLAIM_FEE_SPLIT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ValidatorClaimFeeSplitInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ValidatorClaimFeeSplitOutput>(),
+                ),
+                export: VALIDATOR_CLAIM_FEE_SPLIT_IDENT.to_string(),
+            },
+        );
         functions.insert(
             VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT.to_string(),
             FunctionSchemaInit {
@@ -513,7 +578,10 @@ impl ValidatorBlueprint {
                 state,
                 events: event_schema,
                 types: BlueprintTypeSchemaInit::default(),
-                functions: BlueprintFunctionsSchemaInit { functions },
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
                 hooks: BlueprintHooksInit::default(),
             },
             royalty_config: PackageRoyaltyConfig::default(),
@@ -528,11 +596,13 @@ impl ValidatorBlueprint {
                         VALIDATOR_TOTAL_STAKE_XRD_AMOUNT_IDENT => MethodAccessibility::Public;
                         VALIDATOR_TOTAL_STAKE_UNIT_SUPPLY_IDENT => MethodAccessibility::Public;
                         VALIDATOR_GET_REDEMPTION_VALUE_IDENT => MethodAccessibility::Public;
+                        VALIDATOR_CLAIM_FEE_SPLIT_IDENT => MethodAccessibility::Public;
                         VALIDATOR_STAKE_AS_OWNER_IDENT => [OWNER_ROLE];
                         VALIDATOR_REGISTER_IDENT => [OWNER_ROLE];
                         VALIDATOR_UNREGISTER_IDENT => [OWNER_ROLE];
                         VALIDATOR_UPDATE_KEY_IDENT => [OWNER_ROLE];
                         VALIDATOR_UPDATE_FEE_IDENT => [OWNER_ROLE];
+                        VALIDATOR_UPDATE_FEE_SPLIT_IDENT => [OWNER_ROLE];
                         VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT => [OWNER_ROLE];
                         VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT => [OWNER_ROLE];
                         VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT => [OWNER_ROLE];
@@ -1056,6 +1126,32 @@ impl ValidatorBlueprint {
         Ok(())
     }
 
+    pub fn update_fee_split<Y>(
+        recipients: Vec<ValidatorFeeRecipient>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        // check if the new split is valid
+        check_validator_fee_split(&recipients)?;
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            ValidatorField::FeeSplit.into(),
+            LockFlags::MUTABLE,
+        )?;
+        api.field_write_typed(
+            handle,
+            &ValidatorFeeSplitFieldPayload::from_content_source(ValidatorFeeSplitSubstate {
+                recipients,
+            }),
+        )?;
+        api.field_close(handle)?;
+
+        Ok(())
+    }
+
     pub fn accepts_delegated_stake<Y>(api: &mut Y) -> Result<bool, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
@@ -1439,7 +1535,15 @@ impl ValidatorBlueprint {
         let fee_stake_unit_bucket = stake_unit_resman.mint_fungible(stake_unit_mint_amount, api)?;
         stake_xrd_vault.put(fee_xrd_bucket, api)?;
 
-        // - immediately lock these new stake units in the internal owner's "public display" vault
+        // - credit the configured shares of these new stake units to the fee split recipients
+        Self::credit_fee_split(
+            &fee_stake_unit_bucket,
+            stake_unit_mint_amount,
+            substate.stake_unit_resource,
+            api,
+        )?;
+
+        // - immediately lock the rest in the internal owner's "public display" vault
         Vault(substate.locked_owner_stake_unit_vault_id).put(fee_stake_unit_bucket, api)?;
 
         // - update the index, since the stake increased (because of net emission + staking of the validator fee)
@@ -1540,6 +1644,94 @@ impl ValidatorBlueprint {
         Ok(())
     }
 
+    /// Puts each fee split recipient's share of the given validator fee stake units into the
+    /// recipient's claim vault, creating it on the first credit.
+    /// Note: no calls are made to the recipients' accounts here, so that applying the emission
+    /// can't be affected by them.
+    fn credit_fee_split<Y>(
+        fee_stake_unit_bucket: &Bucket,
+        fee_stake_unit_amount: Decimal,
+        stake_unit_resource: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            ValidatorField::FeeSplit.into(),
+            LockFlags::read_only(),
+        )?;
+        let fee_split = api
+            .field_read_typed::<ValidatorFeeSplitFieldPayload>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+
+        for recipient in fee_split.recipients {
+            let share_amount = fee_stake_unit_amount.checked_mul(recipient.share).ok_or(
+                RuntimeError::ApplicationError(ApplicationError::ValidatorError(
+                    ValidatorError::UnexpectedDecimalComputationError,
+                )),
+            )?;
+            if share_amount.is_zero() {
+                continue;
+            }
+            let share_bucket = fee_stake_unit_bucket.take(share_amount, api)?;
+
+            let handle = api.actor_open_key_value_entry(
+                ACTOR_STATE_SELF,
+                ValidatorCollection::FeeSplitClaimKeyValue.collection_index(),
+                &scrypto_encode(&recipient.account).unwrap(),
+                LockFlags::MUTABLE,
+            )?;
+            let claim_vault = api
+                .key_value_entry_get_typed::<ValidatorFeeSplitClaimEntryPayload>(handle)?
+                .map(|claim_vault| claim_vault.into_latest());
+            match claim_vault {
+                Some(mut claim_vault) => claim_vault.put(share_bucket, api)?,
+                None => {
+                    let mut claim_vault = Vault::create(stake_unit_resource, api)?;
+                    claim_vault.put(share_bucket, api)?;
+                    api.key_value_entry_set_typed(
+                        handle,
+                        &ValidatorFeeSplitClaimEntryPayload::from_content_source(claim_vault),
+                    )?;
+                }
+            }
+            api.key_value_entry_close(handle)?;
+        }
+
+        Ok(())
+    }
+
+    /// Deposits all stake units credited to the given fee split recipient into its account.
+    /// Anyone may claim on behalf of a recipient, since the stake units can only go to it.
+    pub fn claim_fee_split<Y>(account: ComponentAddress, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            ValidatorCollection::FeeSplitClaimKeyValue.collection_index(),
+            &scrypto_encode(&account).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let claim_vault = api
+            .key_value_entry_get_typed::<ValidatorFeeSplitClaimEntryPayload>(handle)?
+            .map(|claim_vault| claim_vault.into_latest());
+        let Some(mut claim_vault) = claim_vault else {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ValidatorError(ValidatorError::NoFeeSplitToClaim { account }),
+            ));
+        };
+        let bucket = claim_vault.take_all(api)?;
+        api.key_value_entry_close(handle)?;
+
+        Account(account).try_deposit_or_abort(bucket, api)?;
+
+        Ok(())
+    }
+
     fn to_sorted_key(
         registered: bool,
         stake: Decimal,
@@ -1687,6 +1879,49 @@ fn check_validator_fee_factor(fee_factor: Decimal) -> Result<(), RuntimeError> {
     Ok(())
 }
 
+fn check_validator_fee_split(recipients: &Vec<ValidatorFeeRecipient>) -> Result<(), RuntimeError> {
+    if recipients.len() > VALIDATOR_FEE_SPLIT_RECIPIENTS_LIMIT {
+        return Err(RuntimeError::ApplicationError(
+            ApplicationError::ValidatorError(
+                ValidatorError::ValidatorFeeSplitRecipientLimitExceeded {
+                    limit: VALIDATOR_FEE_SPLIT_RECIPIENTS_LIMIT,
+                    actual: recipients.len(),
+                },
+            ),
+        ));
+    }
+
+    let mut total_share = Decimal::zero();
+    for recipient in recipients {
+        let is_account = matches!(
+            recipient.account.as_node_id().entity_type(),
+            Some(
+                EntityType::GlobalAccount
+                    | EntityType::GlobalVirtualSecp256k1Account
+                    | EntityType::GlobalVirtualEd25519Account
+            )
+        );
+        if !is_account || !recipient.share.is_positive() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ValidatorError(ValidatorError::InvalidValidatorFeeSplit),
+            ));
+        }
+        total_share =
+            total_share
+                .checked_add(recipient.share)
+                .ok_or(RuntimeError::ApplicationError(
+                    ApplicationError::ValidatorError(ValidatorError::InvalidValidatorFeeSplit),
+                ))?;
+    }
+    if total_share > Decimal::one() {
+        return Err(RuntimeError::ApplicationError(
+            ApplicationError::ValidatorError(ValidatorError::InvalidValidatorFeeSplit),
+        ));
+    }
+
+    Ok(())
+}
+
 fn create_sort_prefix_from_stake(stake: Decimal) -> Result<[u8; 2], RuntimeError> {
     // Note: XRD max supply is 24bn
     // 24bn / MAX::16 = 366210.9375 - so 100k as a divisor here is sensible.
@@ -1845,11 +2080,14 @@ impl ValidatorCreator {
             protocol_version_name: None,
         };
 
+        let fee_split = ValidatorFeeSplitSubstate { recipients: vec![] };
+
         let validator_id = api.new_simple_object(
             VALIDATOR_BLUEPRINT,
             indexmap! {
                 ValidatorField::State.field_index() => FieldValue::new(&ValidatorStateFieldPayload::from_content_source(substate)),
                 ValidatorField::ProtocolUpdateReadinessSignal.field_index() => FieldValue::new(&ValidatorProtocolUpdateReadinessSignalFieldPayload::from_content_source(protocol_update_readiness_signal)),
+                ValidatorField::FeeSplit.field_index() => FieldValue::new(&ValidatorFeeSplitFieldPayload::from_content_source(fee_split)),
             },
         )?;
 
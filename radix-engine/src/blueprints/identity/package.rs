@@ -1,5 +1,6 @@
 use crate::blueprints::util::{PresecurifiedRoleAssignment, SecurifiedRoleAssignment};
 use crate::errors::{ApplicationError, RuntimeError};
+use crate::native_exports;
 use crate::roles_template;
 use crate::types::*;
 use native_sdk::modules::metadata::Metadata;
@@ -27,6 +28,15 @@ pub const IDENTITY_ON_VIRTUALIZE_EXPORT_NAME: &str = "on_virtualize";
 pub const IDENTITY_CREATE_VIRTUAL_SECP256K1_ID: u8 = 0u8;
 pub const IDENTITY_CREATE_VIRTUAL_ED25519_ID: u8 = 1u8;
 
+native_exports! {
+    pub enum IdentityNativeExport {
+        CreateAdvanced => IDENTITY_CREATE_ADVANCED_IDENT,
+        Create => IDENTITY_CREATE_IDENT,
+        Securify => IDENTITY_SECURIFY_IDENT,
+        OnVirtualize => IDENTITY_ON_VIRTUALIZE_EXPORT_NAME,
+    }
+}
+
 pub struct IdentityNativePackage;
 
 impl IdentityNativePackage {
@@ -101,6 +111,7 @@ impl IdentityNativePackage {
                     types: BlueprintTypeSchemaInit::default(),
                     functions: BlueprintFunctionsSchemaInit {
                         functions,
+                        post_conditions: index_map_new(),
                     },
                     hooks: BlueprintHooksInit {
                         hooks: indexmap!(BlueprintHook::OnVirtualize => IDENTITY_ON_VIRTUALIZE_EXPORT_NAME.to_string())
@@ -132,8 +143,8 @@ impl IdentityNativePackage {
     where
         Y: ClientApi<RuntimeError>,
     {
-        match export_name {
-            IDENTITY_CREATE_ADVANCED_IDENT => {
+        match IdentityNativeExport::from_export_name(export_name)? {
+            IdentityNativeExport::CreateAdvanced => {
                 let input: IdentityCreateAdvancedInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -142,7 +153,7 @@ impl IdentityNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            IDENTITY_CREATE_IDENT => {
+            IdentityNativeExport::Create => {
                 let _input: IdentityCreateInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -151,7 +162,7 @@ impl IdentityNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            IDENTITY_SECURIFY_IDENT => {
+            IdentityNativeExport::Securify => {
                 let _input: IdentitySecurifyToSingleBadgeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -160,7 +171,7 @@ impl IdentityNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            IDENTITY_ON_VIRTUALIZE_EXPORT_NAME => {
+            IdentityNativeExport::OnVirtualize => {
                 let input: OnVirtualizeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -169,9 +180,6 @@ impl IdentityNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
         }
     }
 }
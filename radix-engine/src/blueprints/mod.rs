@@ -4,8 +4,11 @@ pub mod consensus_manager;
 pub mod identity;
 pub mod models;
 pub mod native_schema;
+pub mod order_book;
 pub mod package;
+pub mod payment_scheduler;
 pub mod pool;
+pub mod price_feed;
 pub mod resource;
 pub mod test_utils;
 pub mod transaction_processor;
@@ -17,6 +17,10 @@ use crate::internal_prelude::*;
 /// For each collection key, the following types will be created:
 /// * `<BlueprintIdent><CollectionIdent>KeyPayload` - a new type for the key payload (eg includes the u16 for a sorted index key)
 ///
+/// A `<BlueprintIdent>StateApi<'a, Y>` wrapper is also created, with `read_<field>` / `write_<field>`
+/// methods for each field and `read_<collection>_entry` / `write_<collection>_entry` methods for each
+/// KeyValue collection, which open, read or write, and close the substate on the actor.
+///
 /// The content of each of the above can take a number of forms. This is configured via specifying the type as one of the following.
 /// Only Static is supported for keys at present. By default, you should choose StaticSingleVersioned for fields and collection values.
 /// ```ignore
@@ -31,11 +35,13 @@ use crate::internal_prelude::*;
 ///         kind: Generic,
 ///         ident: BlueprintGenericParameterIdent,
 ///     },
-///     // In future
 ///     {
 ///         kind: StaticMultiVersioned,
-///         previous_versions: [V1, V2],
-///         latest: V3,
+///         previous_versions: [
+///             1 => { updates_to: 2 },
+///             2 => { updates_to: 3 },
+///         ],
+///         latest_version: 3,
 ///     }
 /// ```
 ///
@@ -50,6 +56,15 @@ use crate::internal_prelude::*;
 /// and generate the following types:
 /// * `<BlueprintIdent><CollectionIdent>` - a type alias for the latest version (V1).
 /// * `Versioned<BlueprintIdent><CollectionIdent>` - the enum wrapper with a single version. This will be the content of `<BlueprintIdent><CollectionIdent>EntryPayload`.
+///
+/// Choosing `StaticMultiVersioned` creates the same types, but the enum wrapper has a variant
+/// for each listed version. It assumes the existence of `<BlueprintIdent><FieldIdent>V<N>`
+/// (or `<BlueprintIdent><CollectionIdent>V<N>`) for every version N, and requires a `From`
+/// implementation from each previous version into the version it `updates_to`. In addition:
+/// * The payload gets a `from_v<N>` constructor for each previous version.
+/// * Each version can be used as a content source for the payload.
+/// * `into_latest` on the payload walks the update chain to the latest version.
+/// * The schema of the payload registers every version, as each is a variant of the enum wrapper.
 #[allow(unused)]
 macro_rules! declare_native_blueprint_state {
     (
@@ -399,6 +414,61 @@ macro_rules! declare_native_blueprint_state {
                     }
                 }
 
+                //--------------------------------------------------------
+                // Typed - State API
+                //--------------------------------------------------------
+
+                /// A typed wrapper around the actor state API, which takes care of opening,
+                /// reading/writing and closing the substates of this blueprint.
+                pub struct [<$blueprint_ident StateApi>]<'a, Y: ClientApi<RuntimeError>> {
+                    api: &'a mut Y,
+                }
+
+                impl<'a, Y: ClientApi<RuntimeError>> [<$blueprint_ident StateApi>]<'a, Y> {
+                    pub fn new(api: &'a mut Y) -> Self {
+                        Self { api }
+                    }
+
+                    $(
+                        pub fn [<read_ $field_property_name>](
+                            &mut self,
+                        ) -> Result<[<$blueprint_ident $field_ident FieldPayload>], RuntimeError> {
+                            let handle = self.api.actor_open_field(
+                                ACTOR_STATE_SELF,
+                                [<$blueprint_ident Field>]::$field_ident.field_index(),
+                                LockFlags::read_only(),
+                            )?;
+                            let payload = self.api.field_read_typed(handle)?;
+                            self.api.field_close(handle)?;
+                            Ok(payload)
+                        }
+
+                        pub fn [<write_ $field_property_name>](
+                            &mut self,
+                            content: impl FieldContentSource<[<$blueprint_ident $field_ident FieldPayload>]>,
+                        ) -> Result<(), RuntimeError> {
+                            let handle = self.api.actor_open_field(
+                                ACTOR_STATE_SELF,
+                                [<$blueprint_ident Field>]::$field_ident.field_index(),
+                                LockFlags::MUTABLE,
+                            )?;
+                            self.api.field_write_typed(handle, &content.into_payload())?;
+                            self.api.field_close(handle)?;
+                            Ok(())
+                        }
+                    )*
+
+                    $(
+                        generate_collection_state_api_methods!(
+                            $collection_type,
+                            $collection_property_name,
+                            [<$blueprint_ident Collection>]::[<$collection_ident $collection_type>],
+                            [<$blueprint_ident $collection_ident KeyContent>],
+                            [<$blueprint_ident $collection_ident EntryPayload>],
+                        );
+                    )*
+                }
+
                 //---------------------------------
                 // Typed - Substate Keys and Values
                 //---------------------------------
@@ -660,7 +730,80 @@ mod helper_macros {
                 impl [<$ident_core ContentMarker>] for RawScryptoValue<'_> {}
             }
         };
-        // TODO - Add support for some kind of StaticMultiVersioned type here
+        (
+            content_trait: $content_trait:ident,
+            payload_trait: $payload_trait:ident,
+            ident_core: $ident_core:ident,
+            $(#[$attributes:meta])*
+            struct $payload_type_name:ident = {
+                kind: StaticMultiVersioned,
+                previous_versions: [
+                    $($version_num:tt => { updates_to: $update_to_version_num:tt }),*
+                    $(,)?
+                ],
+                latest_version: $latest_version:tt
+                $(,)?
+            }$(,)?
+        ) => {
+            paste::paste! {
+                sbor::define_versioned!(
+                    $(#[$attributes])*
+                    pub enum [<Versioned $ident_core>] {
+                        previous_versions: [
+                            $($version_num => [<$ident_core V $version_num>]: { updates_to: $update_to_version_num },)*
+                        ],
+                        latest_version: {
+                            $latest_version => $ident_core = [<$ident_core V $latest_version>],
+                        },
+                    }
+                );
+                declare_payload_new_type!(
+                    content_trait: $content_trait,
+                    payload_trait: $payload_trait,
+                    ----
+                    $(#[$attributes])*
+                    pub struct $payload_type_name([<Versioned $ident_core>]);
+                );
+
+                impl HasLatestVersion for $payload_type_name
+                {
+                    type Latest = <[<Versioned $ident_core>] as HasLatestVersion>::Latest;
+                    fn into_latest(self) -> Self::Latest {
+                        self.into_content().into_latest()
+                    }
+
+                    fn as_latest_ref(&self) -> Option<&Self::Latest> {
+                        self.as_ref().as_latest_ref()
+                    }
+                }
+
+                impl $payload_type_name {
+                    $(
+                        pub fn [<from_v $version_num>](content: [<$ident_core V $version_num>]) -> Self {
+                            Self::from([<Versioned $ident_core>]::from(content))
+                        }
+                    )*
+                }
+
+                // Now implement other relevant content traits, for:
+                // > The "latest" type: $ident_core
+                impl $content_trait<$payload_type_name> for $ident_core {
+                    fn into_content(self) -> [<Versioned $ident_core>] {
+                        self.into()
+                    }
+                }
+
+                // > Each of the previous versions, which are stored as-is and only
+                //   updated when read via `into_latest`
+                $(
+                    impl $content_trait<$payload_type_name> for [<$ident_core V $version_num>] {
+                        fn into_content(self) -> [<Versioned $ident_core>] {
+                            self.into()
+                        }
+                    }
+                )*
+            }
+        };
     }
 
     #[allow(unused)]
@@ -681,6 +824,20 @@ mod helper_macros {
                 "A StaticSingleVersioned key is not supported, because keys cannot be lazily updated, because they need to be static"
             );
         };
+        (
+            content_trait: $content_trait:ident,
+            payload_trait: $payload_trait:ident,
+            $(full_key_content: $full_key_content:tt,)?
+            $(#[$attributes:meta])*
+            struct $payload_type_name:ident = {
+                kind: StaticMultiVersioned,
+                $($ignored:tt)*
+            }$(,)?
+        ) => {
+            compile_error!(
+                "A StaticMultiVersioned key is not supported, because keys cannot be lazily updated, because they need to be static"
+            );
+        };
         (
             content_trait: $content_trait:ident,
             payload_trait: $payload_trait:ident,
@@ -805,6 +962,57 @@ mod helper_macros {
     #[allow(unused)]
     pub(crate) use map_collection_schema;
 
+    macro_rules! generate_collection_state_api_methods {
+        (KeyValue, $collection_property_name:ident, $collection:expr, $key_content:ty, $entry_payload:ty$(,)?) => {
+            paste::paste! {
+                pub fn [<read_ $collection_property_name _entry>](
+                    &mut self,
+                    key: &$key_content,
+                ) -> Result<Option<$entry_payload>, RuntimeError> {
+                    let handle = self.api.actor_open_key_value_entry(
+                        ACTOR_STATE_SELF,
+                        $collection.collection_index(),
+                        &scrypto_encode(key).unwrap(),
+                        LockFlags::read_only(),
+                    )?;
+                    let entry = self.api.key_value_entry_get_typed(handle)?;
+                    self.api.key_value_entry_close(handle)?;
+                    Ok(entry)
+                }
+
+                pub fn [<write_ $collection_property_name _entry>](
+                    &mut self,
+                    key: &$key_content,
+                    content: impl KeyValueEntryContentSource<$entry_payload>,
+                ) -> Result<(), RuntimeError> {
+                    let handle = self.api.actor_open_key_value_entry(
+                        ACTOR_STATE_SELF,
+                        $collection.collection_index(),
+                        &scrypto_encode(key).unwrap(),
+                        LockFlags::MUTABLE,
+                    )?;
+                    self.api.key_value_entry_set_typed(handle, content.into_payload())?;
+                    self.api.key_value_entry_close(handle)?;
+                    Ok(())
+                }
+            }
+        };
+        // Index and SortedIndex entries are iterated / scanned rather than accessed by key,
+        // so no typed accessors are generated for them at present
+        (Index, $($ignored:tt)*) => {};
+        (SortedIndex, $($ignored:tt)*) => {};
+        ($unknown_system_substate_type:ident, $($ignored:tt)*) => {
+            compile_error!(concat!(
+                "Unrecognized system collection substate type: `",
+                stringify!($unknown_system_substate_type),
+                "` - expected `KeyValue`, `Index` or `SortedIndex`"
+            ));
+        };
+    }
+
+    #[allow(unused)]
+    pub(crate) use generate_collection_state_api_methods;
+
     macro_rules! map_type_ref {
         (
             $blueprint_ident:ident,
@@ -842,7 +1050,20 @@ mod helper_macros {
             paste::paste! {
                 TypeRef::Generic([<$blueprint_ident Generic>]::$generic_ident.generic_index())
             }
-        }; // TODO - Add support for some kind of StaticMultiVersioned type here
+        };
+        (
+            $blueprint_ident:ident,
+            $aggregator:ident,
+            {
+                kind: StaticMultiVersioned,
+                $($ignored:tt)*
+            },
+            $payload_alias:ident$(,)?
+        ) => {
+            // Each version is a variant of the versioned enum, so registering the payload
+            // also registers the schema of every previous version
+            TypeRef::Static($aggregator.add_child_type_and_descendents::<$payload_alias>())
+        };
     }
 
     #[allow(unused)]
@@ -958,6 +1179,26 @@ mod tests {
     #[derive(Debug, PartialEq, Eq, Sbor)]
     pub struct TestBlueprintRoyaltyV1;
 
+    #[derive(Debug, PartialEq, Eq, Sbor)]
+    pub struct TestBlueprintConfigV1 {
+        pub limit: u32,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Sbor)]
+    pub struct TestBlueprintConfigV2 {
+        pub limit: u64,
+        pub enabled: bool,
+    }
+
+    impl From<TestBlueprintConfigV1> for TestBlueprintConfigV2 {
+        fn from(value: TestBlueprintConfigV1) -> Self {
+            Self {
+                limit: value.limit.into(),
+                enabled: true,
+            }
+        }
+    }
+
     #[derive(Debug, PartialEq, Eq, Sbor)]
     pub struct TestBlueprintMyCoolKeyValueStoreV1;
 
@@ -1014,6 +1255,16 @@ mod tests {
                     kind: Generic,
                     ident: Abc,
                 },
+            },
+            config:  {
+                ident: Config,
+                field_type: {
+                    kind: StaticMultiVersioned,
+                    previous_versions: [
+                        1 => { updates_to: 2 },
+                    ],
+                    latest_version: 2,
+                },
             }
         },
         collections: {
@@ -1130,6 +1381,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_multi_versioned_field_payload_updates_to_latest() {
+        let payload = TestBlueprintConfigFieldPayload::from_v1(TestBlueprintConfigV1 { limit: 5 });
+        assert_eq!(
+            &VersionedTestBlueprintConfig::V1(TestBlueprintConfigV1 { limit: 5 }),
+            payload.as_ref()
+        );
+        assert!(payload.as_latest_ref().is_none());
+        assert_eq!(
+            TestBlueprintConfig {
+                limit: 5,
+                enabled: true
+            },
+            payload.into_latest()
+        );
+
+        let payload = TestBlueprintConfigFieldPayload::from_content_source(TestBlueprintConfigV2 {
+            limit: 7,
+            enabled: false,
+        });
+        assert!(payload.as_latest_ref().is_some());
+        assert_eq!(
+            &LockStatus::Unlocked,
+            TestBlueprintConfigV1 { limit: 1 }
+                .into_unlocked_substate()
+                .lock_status()
+        );
+    }
+
+    #[test]
+    fn validate_multi_versioned_field_schema_includes_all_versions() {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let schema_init = TestBlueprintStateSchemaInit::create_schema_init(&mut aggregator);
+        assert_eq!(3, schema_init.fields.len());
+
+        let schema: VersionedScryptoSchema = generate_full_schema(aggregator);
+        let type_names: Vec<_> = schema
+            .v1()
+            .type_metadata
+            .iter()
+            .filter_map(|metadata| metadata.get_name())
+            .collect();
+        assert!(type_names.contains(&"TestBlueprintConfigV1"));
+        assert!(type_names.contains(&"TestBlueprintConfigV2"));
+    }
+
     #[test]
     fn validate_key_value_store_entry_payload_mutability() {
         fn create_payload() -> TestBlueprintMyCoolKeyValueStoreEntryPayload {
@@ -5,8 +5,11 @@ use crate::blueprints::access_controller::*;
 use crate::blueprints::account::*;
 use crate::blueprints::consensus_manager::*;
 use crate::blueprints::identity::*;
+use crate::blueprints::order_book::*;
 use crate::blueprints::package::*;
+use crate::blueprints::payment_scheduler::*;
 use crate::blueprints::pool::v1::package::*;
+use crate::blueprints::price_feed::*;
 use crate::blueprints::resource::*;
 use crate::blueprints::transaction_processor::*;
 use crate::blueprints::transaction_tracker::TransactionTrackerNativePackage;
@@ -27,6 +30,12 @@ lazy_static! {
         PoolNativePackage::definition(PoolV1MinorVersion::Zero);
     pub static ref POOL_PACKAGE_DEFINITION_V1_1: PackageDefinition =
         PoolNativePackage::definition(PoolV1MinorVersion::One);
+    pub static ref PRICE_FEED_PACKAGE_DEFINITION: PackageDefinition =
+        PriceFeedNativePackage::definition();
+    pub static ref PAYMENT_SCHEDULER_PACKAGE_DEFINITION: PackageDefinition =
+        PaymentSchedulerNativePackage::definition();
+    pub static ref ORDER_BOOK_PACKAGE_DEFINITION: PackageDefinition =
+        OrderBookNativePackage::definition();
     pub static ref TRANSACTION_TRACKER_PACKAGE_DEFINITION: PackageDefinition =
         TransactionTrackerNativePackage::definition();
     pub static ref RESOURCE_PACKAGE_DEFINITION: PackageDefinition =
@@ -0,0 +1,601 @@
+use crate::internal_prelude::*;
+use radix_engine_interface::blueprints::order_book::*;
+use radix_engine_interface::prelude::*;
+use radix_engine_interface::*;
+
+declare_native_blueprint_state! {
+    blueprint_ident: OrderBook,
+    blueprint_snake_case: order_book,
+    features: {
+    },
+    fields: {
+        state:  {
+            ident: State,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
+        }
+    },
+    collections: {
+        orders: KeyValue {
+            entry_ident: Order,
+            key_type: {
+                kind: Static,
+                content_type: u64,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+        bids: SortedIndex {
+            entry_ident: BidLevel,
+            key_type: {
+                kind: Static,
+                content_type: u16,
+            },
+            full_key_content: {
+                full_content_type: PriceLevelKey,
+                sort_prefix_property_name: sort_prefix,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+        asks: SortedIndex {
+            entry_ident: AskLevel,
+            key_type: {
+                kind: Static,
+                content_type: u16,
+            },
+            full_key_content: {
+                full_content_type: PriceLevelKey,
+                sort_prefix_property_name: sort_prefix,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+    }
+}
+
+pub type OrderBookStateV1 = OrderBookSubstate;
+pub type OrderBookOrderV1 = RestingOrder;
+pub type OrderBookBidLevelV1 = PriceLevel;
+pub type OrderBookAskLevelV1 = PriceLevel;
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct OrderBookSubstate {
+    /// The price increment between two adjacent price levels.
+    pub tick_size: Decimal,
+
+    /// The id of the next order to be inserted.
+    pub next_order_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct RestingOrder {
+    pub side: OrderSide,
+    /// The price of the order, as a multiple of the tick size.
+    pub price_tick: u16,
+    pub quantity: Decimal,
+    /// The order ahead of this one in the queue of its price level.
+    pub previous: Option<u64>,
+    /// The order behind this one in the queue of its price level.
+    pub next: Option<u64>,
+}
+
+/// The orders resting at a price level, queued in the order they were inserted in and linked
+/// together through the `previous` and `next` ids of the orders themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct PriceLevel {
+    pub head: u64,
+    pub tail: u64,
+}
+
+/// The key of a price level in the bids or asks index.
+///
+/// The sort prefix is the price tick for asks and its complement for bids, so the first entry of
+/// either index is always the best price level of its side.
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct PriceLevelKey {
+    pub sort_prefix: u16,
+    pub price_tick: u16,
+}
+
+macro_rules! impl_price_level_key {
+    ($payload:ty) => {
+        impl SortedIndexKeyContentSource<$payload> for PriceLevelKey {
+            fn sort_key(&self) -> u16 {
+                self.sort_prefix
+            }
+
+            fn into_content(self) -> u16 {
+                self.price_tick
+            }
+        }
+
+        impl SortedIndexKeyFullContent<$payload> for PriceLevelKey {
+            fn from_sort_key_and_content(sort_prefix: u16, price_tick: u16) -> Self {
+                Self {
+                    sort_prefix,
+                    price_tick,
+                }
+            }
+
+            fn as_content(&self) -> &u16 {
+                &self.price_tick
+            }
+        }
+    };
+}
+
+impl_price_level_key!(OrderBookBidLevelKeyPayload);
+impl_price_level_key!(OrderBookAskLevelKeyPayload);
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum OrderBookError {
+    InvalidTickSize { tick_size: Decimal },
+    InvalidPrice { price: Decimal },
+    InvalidQuantity { quantity: Decimal },
+    OrderNotFound { order_id: u64 },
+    DecimalOverflow,
+}
+
+impl From<OrderBookError> for RuntimeError {
+    fn from(error: OrderBookError) -> Self {
+        Self::ApplicationError(ApplicationError::OrderBookError(error))
+    }
+}
+
+pub struct OrderBookBlueprint;
+
+impl OrderBookBlueprint {
+    pub fn instantiate<Y>(
+        tick_size: Decimal,
+        api: &mut Y,
+    ) -> Result<OrderBookInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        // The tick size must leave room for every price level to be represented.
+        if !tick_size.is_positive() || tick_size.checked_mul(u16::MAX).is_none() {
+            return Err(OrderBookError::InvalidTickSize { tick_size }.into());
+        }
+
+        let substate = OrderBookSubstate {
+            tick_size,
+            next_order_id: 0,
+        };
+        let object_id = api.new_simple_object(
+            ORDER_BOOK_BLUEPRINT,
+            indexmap! {
+                OrderBookField::State.field_index() => FieldValue::new(OrderBookStateFieldPayload::from_content_source(substate)),
+            },
+        )?;
+
+        Ok(Owned::new(InternalAddress::new_or_panic(object_id.0)))
+    }
+
+    /// Inserts an order at the back of the queue of its price level and returns its id.
+    pub fn insert_order<Y>(
+        side: OrderSide,
+        price: Decimal,
+        quantity: Decimal,
+        api: &mut Y,
+    ) -> Result<u64, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !quantity.is_positive() {
+            return Err(OrderBookError::InvalidQuantity { quantity }.into());
+        }
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            OrderBookField::State.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate = api
+            .field_read_typed::<VersionedOrderBookState>(handle)?
+            .into_latest();
+        let price_tick = Self::price_tick(price, substate.tick_size)?;
+        let order_id = substate.next_order_id;
+        substate.next_order_id += 1;
+        api.field_write_typed(
+            handle,
+            &OrderBookStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+
+        let (head, previous) = match Self::remove_price_level(side, price_tick, api)? {
+            Some(level) => {
+                Self::update_order(level.tail, api, |order| order.next = Some(order_id))?;
+                (level.head, Some(level.tail))
+            }
+            None => (order_id, None),
+        };
+        let level = PriceLevel {
+            head,
+            tail: order_id,
+        };
+        Self::insert_price_level(side, price_tick, level, api)?;
+        Self::write_order(
+            order_id,
+            RestingOrder {
+                side,
+                price_tick,
+                quantity,
+                previous,
+                next: None,
+            },
+            api,
+        )?;
+
+        Ok(order_id)
+    }
+
+    /// Removes an order from the book and returns what was left of it.
+    pub fn cancel_order<Y>(order_id: u64, api: &mut Y) -> Result<Order, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let tick_size = Self::tick_size(api)?;
+        let order = api
+            .actor_remove_key_value_entry_typed::<OrderBookOrderEntryPayload>(
+                ACTOR_STATE_SELF,
+                OrderBookCollection::OrderKeyValue.collection_index(),
+                &scrypto_encode(&order_id).unwrap(),
+            )?
+            .ok_or(OrderBookError::OrderNotFound { order_id })?
+            .into_latest();
+
+        if let Some(previous) = order.previous {
+            Self::update_order(previous, api, |previous| previous.next = order.next)?;
+        }
+        if let Some(next) = order.next {
+            Self::update_order(next, api, |next| next.previous = order.previous)?;
+        }
+        let level = Self::remove_price_level(order.side, order.price_tick, api)?.unwrap();
+        let head = if level.head == order_id {
+            order.next
+        } else {
+            Some(level.head)
+        };
+        let tail = if level.tail == order_id {
+            order.previous
+        } else {
+            Some(level.tail)
+        };
+        // The price level is only left out of the index once its last order is gone.
+        if let (Some(head), Some(tail)) = (head, tail) {
+            let level = PriceLevel { head, tail };
+            Self::insert_price_level(order.side, order.price_tick, level, api)?;
+        }
+
+        Ok(Order {
+            side: order.side,
+            price: Self::price(order.price_tick, tick_size),
+            quantity: order.quantity,
+        })
+    }
+
+    /// Matches an incoming order of the given side against the best orders resting on the
+    /// opposite side, in price-time priority, and returns the fills made.
+    ///
+    /// Matching stops once the whole `quantity` is filled, once the best resting price is worse
+    /// than `limit_price`, or once `max_fills` fills are made. At most `max_fills` price levels
+    /// and orders are read, which bounds the cost of a call regardless of the size of the book.
+    /// Whatever quantity is left unfilled is not added to the book.
+    pub fn match_best<Y>(
+        side: OrderSide,
+        quantity: Decimal,
+        limit_price: Option<Decimal>,
+        max_fills: u32,
+        api: &mut Y,
+    ) -> Result<Vec<OrderFill>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !quantity.is_positive() {
+            return Err(OrderBookError::InvalidQuantity { quantity }.into());
+        }
+
+        let tick_size = Self::tick_size(api)?;
+        let resting_side = side.opposite();
+        let max_fills = max_fills as usize;
+        let mut remaining = quantity;
+        let mut fills = Vec::new();
+
+        while !remaining.is_zero() && fills.len() < max_fills {
+            let (price_tick, level) = match Self::best_price_level(resting_side, api)? {
+                Some(best_price_level) => best_price_level,
+                None => break,
+            };
+            let price = Self::price(price_tick, tick_size);
+            let crosses = match (side, limit_price) {
+                (_, None) => true,
+                (OrderSide::Bid, Some(limit_price)) => price <= limit_price,
+                (OrderSide::Ask, Some(limit_price)) => price >= limit_price,
+            };
+            if !crosses {
+                break;
+            }
+
+            let mut head = Some(level.head);
+            let mut head_written = false;
+            while let Some(order_id) = head {
+                if remaining.is_zero() || fills.len() >= max_fills {
+                    break;
+                }
+
+                let handle = api.actor_open_key_value_entry(
+                    ACTOR_STATE_SELF,
+                    OrderBookCollection::OrderKeyValue.collection_index(),
+                    &scrypto_encode(&order_id).unwrap(),
+                    LockFlags::MUTABLE,
+                )?;
+                let mut order = api
+                    .key_value_entry_get_typed::<OrderBookOrderEntryPayload>(handle)?
+                    .unwrap()
+                    .into_latest();
+                let fill_quantity = remaining.min(order.quantity);
+                remaining = remaining
+                    .checked_sub(fill_quantity)
+                    .ok_or(OrderBookError::DecimalOverflow)?;
+                order.quantity = order
+                    .quantity
+                    .checked_sub(fill_quantity)
+                    .ok_or(OrderBookError::DecimalOverflow)?;
+                fills.push(OrderFill {
+                    order_id,
+                    price,
+                    quantity: fill_quantity,
+                    remaining_quantity: order.quantity,
+                });
+
+                if order.quantity.is_zero() {
+                    api.key_value_entry_remove(handle)?;
+                    head = order.next;
+                } else {
+                    // A partially filled order is always at the head of its price level.
+                    order.previous = None;
+                    api.key_value_entry_set_typed(
+                        handle,
+                        &OrderBookOrderEntryPayload::from_content_source(order),
+                    )?;
+                    head_written = true;
+                }
+                api.key_value_entry_close(handle)?;
+            }
+
+            match head {
+                Some(head) if head != level.head => {
+                    // The new head may be an order the fills didn't reach, which still links back
+                    // to the removed orders.
+                    if !head_written {
+                        Self::update_order(head, api, |order| order.previous = None)?;
+                    }
+                    Self::insert_price_level(
+                        resting_side,
+                        price_tick,
+                        PriceLevel {
+                            head,
+                            tail: level.tail,
+                        },
+                        api,
+                    )?;
+                }
+                Some(_) => {}
+                None => {
+                    Self::remove_price_level(resting_side, price_tick, api)?;
+                }
+            }
+        }
+
+        Ok(fills)
+    }
+
+    pub fn get_order<Y>(order_id: u64, api: &mut Y) -> Result<Option<Order>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let tick_size = Self::tick_size(api)?;
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            OrderBookCollection::OrderKeyValue.collection_index(),
+            &scrypto_encode(&order_id).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let order = api
+            .key_value_entry_get_typed::<OrderBookOrderEntryPayload>(handle)?
+            .map(|order| {
+                let order = order.into_latest();
+                Order {
+                    side: order.side,
+                    price: Self::price(order.price_tick, tick_size),
+                    quantity: order.quantity,
+                }
+            });
+        api.key_value_entry_close(handle)?;
+
+        Ok(order)
+    }
+
+    //================
+    // Helper Methods
+    //================
+
+    fn price_tick(price: Decimal, tick_size: Decimal) -> Result<u16, OrderBookError> {
+        price
+            .checked_div(tick_size)
+            .and_then(|price_tick| u16::try_from(price_tick).ok())
+            .filter(|price_tick| *price_tick > 0)
+            .ok_or(OrderBookError::InvalidPrice { price })
+    }
+
+    fn price(price_tick: u16, tick_size: Decimal) -> Decimal {
+        // Can't overflow, as the tick size is checked against the largest tick on instantiation.
+        tick_size * price_tick
+    }
+
+    fn price_level_key(side: OrderSide, price_tick: u16) -> SortedKey {
+        let sort_prefix = match side {
+            OrderSide::Bid => u16::MAX - price_tick,
+            OrderSide::Ask => price_tick,
+        };
+        (
+            sort_prefix.to_be_bytes(),
+            scrypto_encode(&price_tick).unwrap(),
+        )
+    }
+
+    fn best_price_level<Y>(
+        side: OrderSide,
+        api: &mut Y,
+    ) -> Result<Option<(u16, PriceLevel)>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let best_price_level = match side {
+            OrderSide::Bid => api
+                .actor_sorted_index_scan_typed::<u16, OrderBookBidLevelEntryPayload>(
+                    ACTOR_STATE_SELF,
+                    OrderBookCollection::BidLevelSortedIndex.collection_index(),
+                    1,
+                )?
+                .pop()
+                .map(|(price_tick, level)| (price_tick, level.into_latest())),
+            OrderSide::Ask => api
+                .actor_sorted_index_scan_typed::<u16, OrderBookAskLevelEntryPayload>(
+                    ACTOR_STATE_SELF,
+                    OrderBookCollection::AskLevelSortedIndex.collection_index(),
+                    1,
+                )?
+                .pop()
+                .map(|(price_tick, level)| (price_tick, level.into_latest())),
+        };
+
+        Ok(best_price_level)
+    }
+
+    fn insert_price_level<Y>(
+        side: OrderSide,
+        price_tick: u16,
+        level: PriceLevel,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let sorted_key = Self::price_level_key(side, price_tick);
+        match side {
+            OrderSide::Bid => api.actor_sorted_index_insert_typed(
+                ACTOR_STATE_SELF,
+                OrderBookCollection::BidLevelSortedIndex.collection_index(),
+                sorted_key,
+                OrderBookBidLevelEntryPayload::from_content_source(level),
+            ),
+            OrderSide::Ask => api.actor_sorted_index_insert_typed(
+                ACTOR_STATE_SELF,
+                OrderBookCollection::AskLevelSortedIndex.collection_index(),
+                sorted_key,
+                OrderBookAskLevelEntryPayload::from_content_source(level),
+            ),
+        }
+    }
+
+    fn remove_price_level<Y>(
+        side: OrderSide,
+        price_tick: u16,
+        api: &mut Y,
+    ) -> Result<Option<PriceLevel>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let sorted_key = Self::price_level_key(side, price_tick);
+        let level = match side {
+            OrderSide::Bid => api
+                .actor_sorted_index_remove_typed::<OrderBookBidLevelEntryPayload>(
+                    ACTOR_STATE_SELF,
+                    OrderBookCollection::BidLevelSortedIndex.collection_index(),
+                    &sorted_key,
+                )?
+                .map(|level| level.into_latest()),
+            OrderSide::Ask => api
+                .actor_sorted_index_remove_typed::<OrderBookAskLevelEntryPayload>(
+                    ACTOR_STATE_SELF,
+                    OrderBookCollection::AskLevelSortedIndex.collection_index(),
+                    &sorted_key,
+                )?
+                .map(|level| level.into_latest()),
+        };
+
+        Ok(level)
+    }
+
+    fn write_order<Y>(order_id: u64, order: RestingOrder, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            OrderBookCollection::OrderKeyValue.collection_index(),
+            &scrypto_encode(&order_id).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            handle,
+            &OrderBookOrderEntryPayload::from_content_source(order),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(())
+    }
+
+    fn update_order<Y, F>(order_id: u64, api: &mut Y, update: F) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+        F: FnOnce(&mut RestingOrder),
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            OrderBookCollection::OrderKeyValue.collection_index(),
+            &scrypto_encode(&order_id).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut order = api
+            .key_value_entry_get_typed::<OrderBookOrderEntryPayload>(handle)?
+            .unwrap()
+            .into_latest();
+        update(&mut order);
+        api.key_value_entry_set_typed(
+            handle,
+            &OrderBookOrderEntryPayload::from_content_source(order),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(())
+    }
+
+    fn tick_size<Y>(api: &mut Y) -> Result<Decimal, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            OrderBookField::State.into(),
+            LockFlags::read_only(),
+        )?;
+        let tick_size = api
+            .field_read_typed::<VersionedOrderBookState>(handle)?
+            .into_latest()
+            .tick_size;
+        api.field_close(handle)?;
+
+        Ok(tick_size)
+    }
+}
@@ -0,0 +1,192 @@
+use super::*;
+use crate::internal_prelude::*;
+use crate::native_exports;
+use radix_engine_interface::blueprints::order_book::*;
+use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::prelude::*;
+
+native_exports! {
+    pub enum OrderBookNativeExport {
+        Instantiate => ORDER_BOOK_INSTANTIATE_EXPORT_NAME,
+        InsertOrder => ORDER_BOOK_INSERT_ORDER_EXPORT_NAME,
+        CancelOrder => ORDER_BOOK_CANCEL_ORDER_EXPORT_NAME,
+        MatchBest => ORDER_BOOK_MATCH_BEST_EXPORT_NAME,
+        GetOrder => ORDER_BOOK_GET_ORDER_EXPORT_NAME,
+    }
+}
+
+pub struct OrderBookNativePackage;
+
+impl OrderBookNativePackage {
+    pub fn definition() -> PackageDefinition {
+        let blueprints = indexmap!(
+            ORDER_BOOK_BLUEPRINT.to_string() => Self::order_book_blueprint_definition()
+        );
+
+        PackageDefinition { blueprints }
+    }
+
+    pub fn invoke_export<Y>(
+        export_name: &str,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match OrderBookNativeExport::from_export_name(export_name)? {
+            OrderBookNativeExport::Instantiate => {
+                let OrderBookInstantiateInput { tick_size } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = OrderBookBlueprint::instantiate(tick_size, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            OrderBookNativeExport::InsertOrder => {
+                let OrderBookInsertOrderInput {
+                    side,
+                    price,
+                    quantity,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = OrderBookBlueprint::insert_order(side, price, quantity, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            OrderBookNativeExport::CancelOrder => {
+                let OrderBookCancelOrderInput { order_id } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = OrderBookBlueprint::cancel_order(order_id, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            OrderBookNativeExport::MatchBest => {
+                let OrderBookMatchBestInput {
+                    side,
+                    quantity,
+                    limit_price,
+                    max_fills,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn =
+                    OrderBookBlueprint::match_best(side, quantity, limit_price, max_fills, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            OrderBookNativeExport::GetOrder => {
+                let OrderBookGetOrderInput { order_id } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = OrderBookBlueprint::get_order(order_id, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+        }
+    }
+
+    fn order_book_blueprint_definition() -> BlueprintDefinitionInit {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let feature_set = OrderBookFeatureSet::all_features();
+        let state = OrderBookStateSchemaInit::create_schema_init(&mut aggregator);
+        let mut functions = index_map_new();
+
+        functions.insert(
+            ORDER_BOOK_INSTANTIATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OrderBookInstantiateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OrderBookInstantiateOutput>(),
+                ),
+                export: ORDER_BOOK_INSTANTIATE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            ORDER_BOOK_INSERT_ORDER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OrderBookInsertOrderInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OrderBookInsertOrderOutput>(),
+                ),
+                export: ORDER_BOOK_INSERT_ORDER_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            ORDER_BOOK_CANCEL_ORDER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OrderBookCancelOrderInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OrderBookCancelOrderOutput>(),
+                ),
+                export: ORDER_BOOK_CANCEL_ORDER_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            ORDER_BOOK_MATCH_BEST_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OrderBookMatchBestInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OrderBookMatchBestOutput>(),
+                ),
+                export: ORDER_BOOK_MATCH_BEST_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            ORDER_BOOK_GET_ORDER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OrderBookGetOrderInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OrderBookGetOrderOutput>(),
+                ),
+                export: ORDER_BOOK_GET_ORDER_EXPORT_NAME.to_string(),
+            },
+        );
+
+        let schema = generate_full_schema(aggregator);
+
+        BlueprintDefinitionInit {
+            blueprint_type: BlueprintType::default(),
+            is_transient: false,
+            dependencies: indexset!(),
+            feature_set,
+
+            schema: BlueprintSchemaInit {
+                generics: vec![],
+                schema,
+                state,
+                events: BlueprintEventSchemaInit::default(),
+                types: BlueprintTypeSchemaInit::default(),
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
+                hooks: BlueprintHooksInit::default(),
+            },
+
+            royalty_config: PackageRoyaltyConfig::default(),
+            // Order books are never globalized - only the component owning one can reach it, and
+            // access to it is controlled by that component.
+            auth_config: AuthConfig {
+                function_auth: FunctionAuth::AllowAll,
+                method_auth: MethodAuthTemplate::AllowAll,
+            },
+        }
+    }
+}
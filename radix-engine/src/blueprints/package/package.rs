@@ -1,7 +1,7 @@
 use super::substates::*;
 use crate::blueprints::util::{check_name, InvalidNameError, SecurifiedRoleAssignment};
 use crate::internal_prelude::*;
-use crate::kernel::kernel_api::{KernelApi, KernelSubstateApi};
+use crate::kernel::kernel_api::{KernelApi, KernelInternalApi, KernelSubstateApi};
 use crate::system::attached_modules::metadata::MetadataNativePackage;
 use crate::system::node_init::type_info_partition;
 use crate::system::system_modules::costing::{apply_royalty_cost, RoyaltyRecipient};
@@ -22,6 +22,7 @@ use radix_engine_interface::schema::*;
 use sbor::LocalTypeId;
 
 // Import and re-export substate types
+use crate::native_exports;
 use crate::roles_template;
 use crate::system::attached_modules::role_assignment::*;
 use crate::system::attached_modules::royalty::RoyaltyUtil;
@@ -98,7 +99,10 @@ pub enum PackageError {
         limit: usize,
         actual: usize,
     },
-    MissingRole(RoleKey),
+    MissingRole {
+        blueprint: String,
+        role: RoleKey,
+    },
     UnexpectedNumberOfMethodAuth {
         blueprint: String,
         expected: usize,
@@ -128,6 +132,11 @@ pub enum PackageError {
         blueprint: String,
         ident: String,
     },
+
+    MissingPostConditionFunction {
+        blueprint: String,
+        ident: String,
+    },
     RoyaltyAmountIsGreaterThanAllowed {
         max: RoyaltyAmount,
         actual: RoyaltyAmount,
@@ -408,6 +417,37 @@ where
     Ok(())
 }
 
+/// Native packages don't go through [`validate_auth`] on publish, so `roles_template!` calls this
+/// to fail loudly when a native blueprint's template references a role it doesn't declare, rather
+/// than leaving the method unreachable at runtime.
+pub fn assert_referenced_roles_are_declared(definition: &StaticRoleDefinition) {
+    let RoleSpecification::Normal(roles) = &definition.roles else {
+        return;
+    };
+
+    let assert_declared = |list: &RoleList, referenced_by: String| {
+        for role_key in &list.list {
+            if !RoleAssignmentNativePackage::is_reserved_role_key(role_key)
+                && !roles.contains_key(role_key)
+            {
+                panic!(
+                    "Role `{}` is referenced by {} but is not declared in the roles template",
+                    role_key.key, referenced_by
+                );
+            }
+        }
+    };
+
+    for (role_key, updaters) in roles {
+        assert_declared(updaters, format!("the updaters of role `{}`", role_key.key));
+    }
+    for (method_key, accessibility) in &definition.methods {
+        if let MethodAccessibility::RoleProtected(list) = accessibility {
+            assert_declared(list, format!("method `{}`", method_key.ident));
+        }
+    }
+}
+
 fn validate_auth(definition: &PackageDefinition) -> Result<(), PackageError> {
     for (blueprint, definition_init) in &definition.blueprints {
         match &definition_init.auth_config.function_auth {
@@ -483,7 +523,10 @@ fn validate_auth(definition: &PackageDefinition) -> Result<(), PackageError> {
                             continue;
                         }
                         if !role_specification.contains_key(role_key) {
-                            return Err(PackageError::MissingRole(role_key.clone()));
+                            return Err(PackageError::MissingRole {
+                                blueprint: blueprint.clone(),
+                                role: role_key.clone(),
+                            });
                         }
                     }
                     Ok(())
@@ -553,6 +596,22 @@ fn validate_auth(definition: &PackageDefinition) -> Result<(), PackageError> {
     Ok(())
 }
 
+fn validate_post_conditions(definition: &PackageDefinition) -> Result<(), PackageError> {
+    for (blueprint, definition_init) in &definition.blueprints {
+        let functions = &definition_init.schema.functions;
+        for ident in functions.post_conditions.keys() {
+            if !functions.functions.contains_key(ident) {
+                return Err(PackageError::MissingPostConditionFunction {
+                    blueprint: blueprint.clone(),
+                    ident: ident.clone(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_names(definition: &PackageDefinition) -> Result<(), PackageError> {
     for (bp_name, bp_init) in definition.blueprints.iter() {
         check_name(bp_name)?;
@@ -817,6 +876,17 @@ pub struct PackageStructure {
     pub package_royalties: IndexMap<String, PackageBlueprintVersionRoyaltyConfigEntryPayload>,
 }
 
+native_exports! {
+    pub enum PackageNativeExport {
+        PublishNative => PACKAGE_PUBLISH_NATIVE_IDENT,
+        PublishWasm => PACKAGE_PUBLISH_WASM_IDENT,
+        PublishWasmAdvanced => PACKAGE_PUBLISH_WASM_ADVANCED_IDENT,
+        ClaimRoyalties => PACKAGE_CLAIM_ROYALTIES_IDENT,
+        AddRoyaltyExemption => PACKAGE_ADD_ROYALTY_EXEMPTION_IDENT,
+        RemoveRoyaltyExemption => PACKAGE_REMOVE_ROYALTY_EXEMPTION_IDENT,
+    }
+}
+
 pub struct PackageNativePackage;
 
 impl PackageNativePackage {
@@ -878,6 +948,34 @@ impl PackageNativePackage {
                 export: PACKAGE_CLAIM_ROYALTIES_IDENT.to_string(),
             },
         );
+        functions.insert(
+            PACKAGE_ADD_ROYALTY_EXEMPTION_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PackageAddRoyaltyExemptionInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PackageAddRoyaltyExemptionOutput>(),
+                ),
+                export: PACKAGE_ADD_ROYALTY_EXEMPTION_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            PACKAGE_REMOVE_ROYALTY_EXEMPTION_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PackageRemoveRoyaltyExemptionInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PackageRemoveRoyaltyExemptionOutput>(),
+                ),
+                export: PACKAGE_REMOVE_ROYALTY_EXEMPTION_IDENT.to_string(),
+            },
+        );
 
         let schema = generate_full_schema(aggregator);
         let blueprints = indexmap!(
@@ -897,6 +995,7 @@ impl PackageNativePackage {
                     types: BlueprintTypeSchemaInit::default(),
                     functions: BlueprintFunctionsSchemaInit {
                         functions,
+                        post_conditions: index_map_new(),
                     },
                     hooks: BlueprintHooksInit::default(),
                 },
@@ -916,6 +1015,8 @@ impl PackageNativePackage {
                             },
                             methods {
                                 PACKAGE_CLAIM_ROYALTIES_IDENT => [SECURIFY_OWNER_ROLE];
+                                PACKAGE_ADD_ROYALTY_EXEMPTION_IDENT => [SECURIFY_OWNER_ROLE];
+                                PACKAGE_REMOVE_ROYALTY_EXEMPTION_IDENT => [SECURIFY_OWNER_ROLE];
                             }
                         },
                     ),
@@ -936,8 +1037,8 @@ impl PackageNativePackage {
         Y: ClientApi<RuntimeError>,
         V: VmApi,
     {
-        match export_name {
-            PACKAGE_PUBLISH_NATIVE_IDENT => {
+        match PackageNativeExport::from_export_name(export_name)? {
+            PackageNativeExport::PublishNative => {
                 let input: PackagePublishNativeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -953,7 +1054,7 @@ impl PackageNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            PACKAGE_PUBLISH_WASM_IDENT => {
+            PackageNativeExport::PublishWasm => {
                 let input: PackagePublishWasmInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -963,7 +1064,7 @@ impl PackageNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            PACKAGE_PUBLISH_WASM_ADVANCED_IDENT => {
+            PackageNativeExport::PublishWasmAdvanced => {
                 let input: PackagePublishWasmAdvancedInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -980,16 +1081,28 @@ impl PackageNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            PACKAGE_CLAIM_ROYALTIES_IDENT => {
+            PackageNativeExport::ClaimRoyalties => {
                 let _input: PackageClaimRoyaltiesInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = PackageRoyaltyNativeBlueprint::claim_royalties(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
+            PackageNativeExport::AddRoyaltyExemption => {
+                let input: PackageAddRoyaltyExemptionInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PackageRoyaltyNativeBlueprint::add_royalty_exemption(input.caller, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PackageNativeExport::RemoveRoyaltyExemption => {
+                let input: PackageRemoveRoyaltyExemptionInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn =
+                    PackageRoyaltyNativeBlueprint::remove_royalty_exemption(input.caller, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
         }
     }
 
@@ -1153,6 +1266,8 @@ impl PackageNativePackage {
             .map_err(|e| RuntimeError::ApplicationError(ApplicationError::PackageError(e)))?;
         validate_auth(&definition)
             .map_err(|e| RuntimeError::ApplicationError(ApplicationError::PackageError(e)))?;
+        validate_post_conditions(&definition)
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::PackageError(e)))?;
         validate_names(&definition)
             .map_err(|e| RuntimeError::ApplicationError(ApplicationError::PackageError(e)))?;
 
@@ -1199,6 +1314,7 @@ impl PackageNativePackage {
 
                 let mut functions = index_map_new();
                 let mut function_exports = index_map_new();
+                let mut post_conditions = definition_init.schema.functions.post_conditions;
                 for (function, function_schema_init) in definition_init.schema.functions.functions {
                     let input = match function_schema_init.input {
                         TypeRef::Static(input_type_id) => input_type_id,
@@ -1226,6 +1342,9 @@ impl PackageNativePackage {
                             receiver: function_schema_init.receiver,
                             input: BlueprintPayloadDef::Static(ScopedTypeId(schema_hash, input)),
                             output: BlueprintPayloadDef::Static(ScopedTypeId(schema_hash, output)),
+                            post_conditions: post_conditions
+                                .swap_remove(&function)
+                                .unwrap_or_default(),
                         },
                     );
                     let export = PackageExport {
@@ -1495,7 +1614,7 @@ impl PackageRoyaltyNativeBlueprint {
         // we check for negative royalties at the instantiation time of the royalty module.
         assert!(!royalty_charge.is_negative());
 
-        if royalty_charge.is_non_zero() {
+        if royalty_charge.is_non_zero() && !Self::is_caller_exempt(receiver, api)? {
             let handle = api.kernel_open_substate(
                 receiver,
                 MAIN_BASE_PARTITION,
@@ -1521,6 +1640,91 @@ impl PackageRoyaltyNativeBlueprint {
         Ok(())
     }
 
+    /// Whether the caller's package, or the global component making the call, is on the
+    /// royalty exemption list of the package.
+    fn is_caller_exempt<Y, V>(receiver: &NodeId, api: &mut Y) -> Result<bool, RuntimeError>
+    where
+        V: SystemCallbackObject,
+        Y: KernelApi<SystemConfig<V>>,
+    {
+        let callers: Vec<GlobalAddress> = {
+            let caller = api.kernel_get_system_state().current_call_frame;
+            let caller_component = caller
+                .node_id()
+                .filter(|node_id| node_id.is_global())
+                .map(|node_id| GlobalAddress::new_or_panic(node_id.0));
+            caller
+                .package_address()
+                .map(GlobalAddress::from)
+                .into_iter()
+                .chain(caller_component)
+                .collect()
+        };
+
+        for caller in callers {
+            let handle = api.kernel_open_substate_with_default(
+                receiver,
+                MAIN_BASE_PARTITION
+                    .at_offset(PACKAGE_ROYALTY_EXEMPTIONS_PARTITION_OFFSET)
+                    .unwrap(),
+                &SubstateKey::Map(scrypto_encode(&caller).unwrap()),
+                LockFlags::read_only(),
+                Some(|| {
+                    let kv_entry = KeyValueEntrySubstate::<()>::default();
+                    IndexedScryptoValue::from_typed(&kv_entry)
+                }),
+                SystemLockData::default(),
+            )?;
+            let substate: PackageRoyaltyExemptionEntrySubstate =
+                api.kernel_read_substate(handle)?.as_typed().unwrap();
+            api.kernel_close_substate(handle)?;
+
+            if substate.into_value().is_some() {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    pub(crate) fn add_royalty_exemption<Y>(
+        caller: GlobalAddress,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            PackageCollection::RoyaltyExemptionKeyValue.collection_index(),
+            &scrypto_encode(&caller).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            handle,
+            &PackageRoyaltyExemptionEntryPayload::from_content_source(()),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(())
+    }
+
+    pub(crate) fn remove_royalty_exemption<Y>(
+        caller: GlobalAddress,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        api.actor_remove_key_value_entry(
+            ACTOR_STATE_SELF,
+            PackageCollection::RoyaltyExemptionKeyValue.collection_index(),
+            &scrypto_encode(&caller).unwrap(),
+        )?;
+
+        Ok(())
+    }
+
     pub(crate) fn claim_royalties<Y>(api: &mut Y) -> Result<Bucket, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
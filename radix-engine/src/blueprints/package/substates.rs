@@ -110,6 +110,17 @@ declare_native_blueprint_state! {
             },
             allow_ownership: false,
         },
+        royalty_exemptions: KeyValue {
+            entry_ident: RoyaltyExemption,
+            key_type: {
+                kind: Static,
+                content_type: GlobalAddress,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
     }
 }
 
@@ -169,3 +180,9 @@ impl Debug for PackageCodeInstrumentedCodeV1 {
             .finish()
     }
 }
+
+//---------------------------------------
+// Collection models - By caller
+//---------------------------------------
+
+pub type PackageRoyaltyExemptionV1 = ();
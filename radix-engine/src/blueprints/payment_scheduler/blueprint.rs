@@ -0,0 +1,556 @@
+use super::events::*;
+use crate::internal_prelude::*;
+use native_sdk::modules::metadata::*;
+use native_sdk::modules::role_assignment::*;
+use native_sdk::modules::royalty::*;
+use native_sdk::resource::*;
+use native_sdk::runtime::*;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::payment_scheduler::*;
+use radix_engine_interface::prelude::*;
+use radix_engine_interface::*;
+
+declare_native_blueprint_state! {
+    blueprint_ident: PaymentScheduler,
+    blueprint_snake_case: payment_scheduler,
+    features: {
+    },
+    fields: {
+        state:  {
+            ident: State,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
+        }
+    },
+    collections: {
+        schedules: KeyValue {
+            entry_ident: Schedule,
+            key_type: {
+                kind: Static,
+                content_type: u64,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+        due_payments: SortedIndex {
+            entry_ident: DuePayment,
+            key_type: {
+                kind: Static,
+                content_type: u64,
+            },
+            full_key_content: {
+                full_content_type: DuePaymentKey,
+                sort_prefix_property_name: epochs_from_origin,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+    }
+}
+
+pub type PaymentSchedulerStateV1 = PaymentSchedulerSubstate;
+pub type PaymentSchedulerScheduleV1 = PaymentSchedule;
+pub type PaymentSchedulerDuePaymentV1 = DuePayment;
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct PaymentSchedulerSubstate {
+    /// The funds that installments and incentives are paid from.
+    pub vault: Vault,
+
+    /// The amount paid to whoever processes a due payment.
+    pub incentive_per_payment: Decimal,
+
+    /// The epoch at which the scheduler was instantiated, which the due payments are sorted
+    /// relative to.
+    pub origin_epoch: Epoch,
+
+    /// The id of the next schedule to be created.
+    pub next_schedule_id: u64,
+}
+
+/// The key of a schedule in the due payments index.
+///
+/// The sort prefix is the number of epochs between the origin epoch of the scheduler and the due
+/// epoch of the schedule, saturated at [`u16::MAX`]. Payments due within that many epochs of the
+/// origin are therefore scanned in due order, while all payments due later share the last prefix.
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct DuePaymentKey {
+    pub epochs_from_origin: u16,
+    pub schedule_id: u64,
+}
+
+impl SortedIndexKeyContentSource<PaymentSchedulerDuePaymentKeyPayload> for DuePaymentKey {
+    fn sort_key(&self) -> u16 {
+        self.epochs_from_origin
+    }
+
+    fn into_content(
+        self,
+    ) -> <PaymentSchedulerDuePaymentKeyPayload as SortedIndexKeyPayload>::Content {
+        self.schedule_id
+    }
+}
+
+impl SortedIndexKeyFullContent<PaymentSchedulerDuePaymentKeyPayload> for DuePaymentKey {
+    fn from_sort_key_and_content(sort_key: u16, schedule_id: u64) -> Self {
+        Self {
+            epochs_from_origin: sort_key,
+            schedule_id,
+        }
+    }
+
+    fn as_content(&self) -> &u64 {
+        &self.schedule_id
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct DuePayment {
+    /// The exact epoch the payment is due at, which can't be recovered from a saturated key.
+    pub due_epoch: Epoch,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum PaymentSchedulerError {
+    InvalidIncentive { incentive_per_payment: Decimal },
+    InvalidAmount { amount: Decimal },
+    InvalidInterval { interval_epochs: u64 },
+    RecipientIsNotAnAccount { recipient: ComponentAddress },
+    ScheduleNotFound { schedule_id: u64 },
+    EpochOverflow,
+    DecimalOverflow,
+}
+
+impl From<PaymentSchedulerError> for RuntimeError {
+    fn from(error: PaymentSchedulerError) -> Self {
+        Self::ApplicationError(ApplicationError::PaymentSchedulerError(error))
+    }
+}
+
+pub struct PaymentSchedulerBlueprint;
+
+impl PaymentSchedulerBlueprint {
+    pub fn instantiate<Y>(
+        owner_role: OwnerRole,
+        resource_address: ResourceAddress,
+        incentive_per_payment: Decimal,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<PaymentSchedulerInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if incentive_per_payment.is_negative() {
+            return Err(PaymentSchedulerError::InvalidIncentive {
+                incentive_per_payment,
+            }
+            .into());
+        }
+
+        let (address_reservation, address) = {
+            if let Some(address_reservation) = address_reservation {
+                let address = api.get_reservation_address(address_reservation.0.as_node_id())?;
+                (address_reservation, address)
+            } else {
+                api.allocate_global_address(BlueprintId {
+                    package_address: PAYMENT_SCHEDULER_PACKAGE,
+                    blueprint_name: PAYMENT_SCHEDULER_BLUEPRINT.to_string(),
+                })?
+            }
+        };
+
+        let role_assignment = RoleAssignment::create(owner_role, indexmap!(), api)?.0;
+        let metadata = Metadata::create(api)?;
+        let royalty = ComponentRoyalty::create(ComponentRoyaltyConfig::default(), api)?;
+        let object_id = {
+            let substate = PaymentSchedulerSubstate {
+                vault: Vault::create(resource_address, api)?,
+                incentive_per_payment,
+                origin_epoch: Runtime::current_epoch(api)?,
+                next_schedule_id: 0,
+            };
+            api.new_simple_object(
+                PAYMENT_SCHEDULER_BLUEPRINT,
+                indexmap! {
+                    PaymentSchedulerField::State.field_index() => FieldValue::new(PaymentSchedulerStateFieldPayload::from_content_source(substate)),
+                },
+            )?
+        };
+
+        api.globalize(
+            object_id,
+            indexmap!(
+                AttachedModuleId::RoleAssignment => role_assignment.0,
+                AttachedModuleId::Metadata => metadata.0,
+                AttachedModuleId::Royalty => royalty.0,
+            ),
+            Some(address_reservation),
+        )?;
+
+        Ok(Global::new(ComponentAddress::new_or_panic(
+            address.as_node_id().0,
+        )))
+    }
+
+    pub fn deposit<Y>(bucket: Bucket, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::with_state(api, |mut substate, api| substate.vault.put(bucket, api))
+    }
+
+    pub fn withdraw<Y>(amount: Decimal, api: &mut Y) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::with_state(api, |mut substate, api| substate.vault.take(amount, api))
+    }
+
+    pub fn create_schedule<Y>(
+        recipient: ComponentAddress,
+        amount: Decimal,
+        interval_epochs: u64,
+        first_payment_epoch: Epoch,
+        api: &mut Y,
+    ) -> Result<u64, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !amount.is_positive() {
+            return Err(PaymentSchedulerError::InvalidAmount { amount }.into());
+        }
+        if interval_epochs == 0 {
+            return Err(PaymentSchedulerError::InvalidInterval { interval_epochs }.into());
+        }
+        // Payments are made through `try_deposit_or_refund`, which only accounts are known to
+        // have - any other recipient would make every processing of the schedule fail.
+        if !matches!(
+            recipient.as_node_id().entity_type(),
+            Some(
+                EntityType::GlobalAccount
+                    | EntityType::GlobalVirtualSecp256k1Account
+                    | EntityType::GlobalVirtualEd25519Account
+            )
+        ) {
+            return Err(PaymentSchedulerError::RecipientIsNotAnAccount { recipient }.into());
+        }
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            PaymentSchedulerField::State.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate = api
+            .field_read_typed::<VersionedPaymentSchedulerState>(handle)?
+            .into_latest();
+        let schedule_id = substate.next_schedule_id;
+        substate.next_schedule_id += 1;
+        let origin_epoch = substate.origin_epoch;
+        api.field_write_typed(
+            handle,
+            &PaymentSchedulerStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+
+        let schedule = PaymentSchedule {
+            recipient,
+            amount,
+            interval_epochs,
+            next_payment_epoch: first_payment_epoch,
+        };
+        Self::insert_schedule(schedule_id, schedule.clone(), origin_epoch, api)?;
+
+        Runtime::emit_event(
+            api,
+            ScheduleCreatedEvent {
+                schedule_id,
+                schedule,
+            },
+        )?;
+
+        Ok(schedule_id)
+    }
+
+    pub fn cancel_schedule<Y>(schedule_id: u64, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let origin_epoch = Self::with_state(api, |substate, _| Ok(substate.origin_epoch))?;
+        let schedule = api
+            .actor_remove_key_value_entry_typed::<PaymentSchedulerScheduleEntryPayload>(
+                ACTOR_STATE_SELF,
+                PaymentSchedulerCollection::ScheduleKeyValue.collection_index(),
+                &scrypto_encode(&schedule_id).unwrap(),
+            )?
+            .ok_or(PaymentSchedulerError::ScheduleNotFound { schedule_id })?
+            .into_latest();
+        api.actor_sorted_index_remove(
+            ACTOR_STATE_SELF,
+            PaymentSchedulerCollection::DuePaymentSortedIndex.collection_index(),
+            &Self::due_payment_key(origin_epoch, schedule.next_payment_epoch, schedule_id),
+        )?;
+
+        Runtime::emit_event(api, ScheduleCancelledEvent { schedule_id })?;
+
+        Ok(())
+    }
+
+    pub fn get_schedule<Y>(
+        schedule_id: u64,
+        api: &mut Y,
+    ) -> Result<Option<PaymentSchedule>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            PaymentSchedulerCollection::ScheduleKeyValue.collection_index(),
+            &scrypto_encode(&schedule_id).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let schedule = api
+            .key_value_entry_get_typed::<PaymentSchedulerScheduleEntryPayload>(handle)?
+            .map(|schedule| schedule.into_latest());
+        api.key_value_entry_close(handle)?;
+
+        Ok(schedule)
+    }
+
+    /// Scans up to `max_payments` schedules in the order they become due, pays out the matured
+    /// installments of the due ones and returns the incentive for each schedule paid.
+    ///
+    /// All the installments of a schedule which matured by the current epoch are paid in a single
+    /// deposit. Processing stops at the first schedule the vault can't pay at least one
+    /// installment (and its incentive) of, so that later schedules don't get ahead of it.
+    pub fn process_due_payments<Y>(max_payments: u32, api: &mut Y) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let current_epoch = Runtime::current_epoch(api)?;
+
+        Self::with_state(api, |mut substate, api| {
+            let due_payments = api.actor_sorted_index_scan(
+                ACTOR_STATE_SELF,
+                PaymentSchedulerCollection::DuePaymentSortedIndex.collection_index(),
+                max_payments,
+            )?;
+
+            let mut incentives = Decimal::ZERO;
+            for (sorted_key, value) in due_payments {
+                let due_epoch = scrypto_decode::<PaymentSchedulerDuePaymentEntryPayload>(&value)
+                    .unwrap()
+                    .into_latest()
+                    .due_epoch;
+                if due_epoch > current_epoch {
+                    // Below the saturated prefix, the prefix determines the due epoch, so none of
+                    // the remaining entries are due either.
+                    if u16::from_be_bytes(sorted_key.0) < u16::MAX {
+                        break;
+                    }
+                    continue;
+                }
+                let schedule_id: u64 = scrypto_decode(&sorted_key.1).unwrap();
+
+                let handle = api.actor_open_key_value_entry(
+                    ACTOR_STATE_SELF,
+                    PaymentSchedulerCollection::ScheduleKeyValue.collection_index(),
+                    &sorted_key.1,
+                    LockFlags::MUTABLE,
+                )?;
+                let mut schedule = api
+                    .key_value_entry_get_typed::<PaymentSchedulerScheduleEntryPayload>(handle)?
+                    .unwrap()
+                    .into_latest();
+
+                let available = substate
+                    .vault
+                    .amount(api)?
+                    .checked_sub(incentives)
+                    .ok_or(PaymentSchedulerError::DecimalOverflow)?;
+                let installments = Self::payable_installments(
+                    &schedule,
+                    substate.incentive_per_payment,
+                    available,
+                    current_epoch,
+                )?;
+                if installments == 0 {
+                    api.key_value_entry_close(handle)?;
+                    break;
+                }
+
+                let amount = schedule
+                    .amount
+                    .checked_mul(installments)
+                    .ok_or(PaymentSchedulerError::DecimalOverflow)?;
+                let payment = substate.vault.take(amount, api)?;
+                let refund = api.call_method(
+                    schedule.recipient.as_node_id(),
+                    ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT,
+                    scrypto_encode(&AccountTryDepositOrRefundInput {
+                        bucket: payment,
+                        authorized_depositor_badge: None,
+                    })
+                    .unwrap(),
+                )?;
+                match scrypto_decode::<AccountTryDepositOrRefundOutput>(&refund).unwrap() {
+                    // A recipient refusing the payment doesn't block the schedule - the funds are
+                    // kept and the installments are skipped.
+                    Some(refund) => {
+                        substate.vault.put(refund, api)?;
+                        Runtime::emit_event(
+                            api,
+                            PaymentRejectedEvent {
+                                schedule_id,
+                                recipient: schedule.recipient,
+                                amount,
+                            },
+                        )?;
+                    }
+                    None => {
+                        Runtime::emit_event(
+                            api,
+                            PaymentEvent {
+                                schedule_id,
+                                recipient: schedule.recipient,
+                                amount,
+                                installments,
+                            },
+                        )?;
+                    }
+                }
+                incentives = incentives
+                    .checked_add(substate.incentive_per_payment)
+                    .ok_or(PaymentSchedulerError::DecimalOverflow)?;
+
+                api.actor_sorted_index_remove(
+                    ACTOR_STATE_SELF,
+                    PaymentSchedulerCollection::DuePaymentSortedIndex.collection_index(),
+                    &sorted_key,
+                )?;
+                schedule.next_payment_epoch = installments
+                    .checked_mul(schedule.interval_epochs)
+                    .and_then(|epochs| schedule.next_payment_epoch.after(epochs))
+                    .ok_or(PaymentSchedulerError::EpochOverflow)?;
+                api.actor_sorted_index_insert_typed(
+                    ACTOR_STATE_SELF,
+                    PaymentSchedulerCollection::DuePaymentSortedIndex.collection_index(),
+                    Self::due_payment_key(
+                        substate.origin_epoch,
+                        schedule.next_payment_epoch,
+                        schedule_id,
+                    ),
+                    PaymentSchedulerDuePaymentEntryPayload::from_content_source(DuePayment {
+                        due_epoch: schedule.next_payment_epoch,
+                    }),
+                )?;
+                api.key_value_entry_set_typed(
+                    handle,
+                    &PaymentSchedulerScheduleEntryPayload::from_content_source(schedule),
+                )?;
+                api.key_value_entry_close(handle)?;
+            }
+
+            substate.vault.take(incentives, api)
+        })
+    }
+
+    //================
+    // Helper Methods
+    //================
+
+    fn insert_schedule<Y>(
+        schedule_id: u64,
+        schedule: PaymentSchedule,
+        origin_epoch: Epoch,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        api.actor_sorted_index_insert_typed(
+            ACTOR_STATE_SELF,
+            PaymentSchedulerCollection::DuePaymentSortedIndex.collection_index(),
+            Self::due_payment_key(origin_epoch, schedule.next_payment_epoch, schedule_id),
+            PaymentSchedulerDuePaymentEntryPayload::from_content_source(DuePayment {
+                due_epoch: schedule.next_payment_epoch,
+            }),
+        )?;
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            PaymentSchedulerCollection::ScheduleKeyValue.collection_index(),
+            &scrypto_encode(&schedule_id).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            handle,
+            &PaymentSchedulerScheduleEntryPayload::from_content_source(schedule),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(())
+    }
+
+    fn due_payment_key(origin_epoch: Epoch, due_epoch: Epoch, schedule_id: u64) -> SortedKey {
+        let epochs_from_origin = due_epoch.number().saturating_sub(origin_epoch.number());
+        let sort_prefix = u16::try_from(epochs_from_origin).unwrap_or(u16::MAX);
+        (
+            sort_prefix.to_be_bytes(),
+            scrypto_encode(&schedule_id).unwrap(),
+        )
+    }
+
+    /// The number of installments of a due schedule which have matured by the current epoch and
+    /// can be paid, along with the incentive, from the available funds.
+    fn payable_installments(
+        schedule: &PaymentSchedule,
+        incentive_per_payment: Decimal,
+        available: Decimal,
+        current_epoch: Epoch,
+    ) -> Result<u64, PaymentSchedulerError> {
+        let matured = (current_epoch.number() - schedule.next_payment_epoch.number())
+            / schedule.interval_epochs
+            + 1;
+
+        let minimum_cost = schedule
+            .amount
+            .checked_add(incentive_per_payment)
+            .ok_or(PaymentSchedulerError::DecimalOverflow)?;
+        if available < minimum_cost {
+            return Ok(0);
+        }
+        let affordable = available
+            .checked_sub(incentive_per_payment)
+            .and_then(|spendable| spendable.checked_div(schedule.amount))
+            .and_then(|installments| installments.checked_floor())
+            .ok_or(PaymentSchedulerError::DecimalOverflow)?;
+        let affordable = u64::try_from(affordable).unwrap_or(u64::MAX);
+
+        Ok(matured.min(affordable))
+    }
+
+    fn with_state<Y, F, O>(api: &mut Y, callback: F) -> Result<O, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+        F: FnOnce(PaymentSchedulerSubstate, &mut Y) -> Result<O, RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            PaymentSchedulerField::State.into(),
+            LockFlags::read_only(),
+        )?;
+        let substate = api
+            .field_read_typed::<VersionedPaymentSchedulerState>(handle)?
+            .into_latest();
+        let rtn = callback(substate, api)?;
+        api.field_close(handle)?;
+
+        Ok(rtn)
+    }
+}
@@ -0,0 +1,31 @@
+use radix_engine_interface::blueprints::payment_scheduler::*;
+use radix_engine_interface::prelude::*;
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct ScheduleCreatedEvent {
+    pub schedule_id: u64,
+    pub schedule: PaymentSchedule,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct ScheduleCancelledEvent {
+    pub schedule_id: u64,
+}
+
+/// Emitted when the matured installments of a schedule are deposited into its recipient.
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct PaymentEvent {
+    pub schedule_id: u64,
+    pub recipient: ComponentAddress,
+    pub amount: Decimal,
+    pub installments: u64,
+}
+
+/// Emitted when the recipient of a schedule refuses a payment, in which case the installments are
+/// skipped and the funds stay with the scheduler.
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct PaymentRejectedEvent {
+    pub schedule_id: u64,
+    pub recipient: ComponentAddress,
+    pub amount: Decimal,
+}
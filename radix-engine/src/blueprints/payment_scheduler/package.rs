@@ -0,0 +1,278 @@
+use super::*;
+use crate::internal_prelude::*;
+use crate::native_exports;
+use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::blueprints::payment_scheduler::*;
+use radix_engine_interface::prelude::*;
+
+native_exports! {
+    pub enum PaymentSchedulerNativeExport {
+        Instantiate => PAYMENT_SCHEDULER_INSTANTIATE_EXPORT_NAME,
+        Deposit => PAYMENT_SCHEDULER_DEPOSIT_EXPORT_NAME,
+        Withdraw => PAYMENT_SCHEDULER_WITHDRAW_EXPORT_NAME,
+        CreateSchedule => PAYMENT_SCHEDULER_CREATE_SCHEDULE_EXPORT_NAME,
+        CancelSchedule => PAYMENT_SCHEDULER_CANCEL_SCHEDULE_EXPORT_NAME,
+        GetSchedule => PAYMENT_SCHEDULER_GET_SCHEDULE_EXPORT_NAME,
+        ProcessDuePayments => PAYMENT_SCHEDULER_PROCESS_DUE_PAYMENTS_EXPORT_NAME,
+    }
+}
+
+pub struct PaymentSchedulerNativePackage;
+
+impl PaymentSchedulerNativePackage {
+    pub fn definition() -> PackageDefinition {
+        let blueprints = indexmap!(
+            PAYMENT_SCHEDULER_BLUEPRINT.to_string() => Self::payment_scheduler_blueprint_definition()
+        );
+
+        PackageDefinition { blueprints }
+    }
+
+    pub fn invoke_export<Y>(
+        export_name: &str,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match PaymentSchedulerNativeExport::from_export_name(export_name)? {
+            PaymentSchedulerNativeExport::Instantiate => {
+                let PaymentSchedulerInstantiateInput {
+                    owner_role,
+                    resource_address,
+                    incentive_per_payment,
+                    address_reservation,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PaymentSchedulerBlueprint::instantiate(
+                    owner_role,
+                    resource_address,
+                    incentive_per_payment,
+                    address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PaymentSchedulerNativeExport::Deposit => {
+                let PaymentSchedulerDepositInput { bucket } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PaymentSchedulerBlueprint::deposit(bucket, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PaymentSchedulerNativeExport::Withdraw => {
+                let PaymentSchedulerWithdrawInput { amount } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PaymentSchedulerBlueprint::withdraw(amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PaymentSchedulerNativeExport::CreateSchedule => {
+                let PaymentSchedulerCreateScheduleInput {
+                    recipient,
+                    amount,
+                    interval_epochs,
+                    first_payment_epoch,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PaymentSchedulerBlueprint::create_schedule(
+                    recipient,
+                    amount,
+                    interval_epochs,
+                    first_payment_epoch,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PaymentSchedulerNativeExport::CancelSchedule => {
+                let PaymentSchedulerCancelScheduleInput { schedule_id } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = PaymentSchedulerBlueprint::cancel_schedule(schedule_id, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PaymentSchedulerNativeExport::GetSchedule => {
+                let PaymentSchedulerGetScheduleInput { schedule_id } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = PaymentSchedulerBlueprint::get_schedule(schedule_id, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PaymentSchedulerNativeExport::ProcessDuePayments => {
+                let PaymentSchedulerProcessDuePaymentsInput { max_payments } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = PaymentSchedulerBlueprint::process_due_payments(max_payments, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+        }
+    }
+
+    fn payment_scheduler_blueprint_definition() -> BlueprintDefinitionInit {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let feature_set = PaymentSchedulerFeatureSet::all_features();
+        let state = PaymentSchedulerStateSchemaInit::create_schema_init(&mut aggregator);
+        let mut functions = index_map_new();
+
+        functions.insert(
+            PAYMENT_SCHEDULER_INSTANTIATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentSchedulerInstantiateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PaymentSchedulerInstantiateOutput>(),
+                ),
+                export: PAYMENT_SCHEDULER_INSTANTIATE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            PAYMENT_SCHEDULER_DEPOSIT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentSchedulerDepositInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentSchedulerDepositOutput>(),
+                ),
+                export: PAYMENT_SCHEDULER_DEPOSIT_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            PAYMENT_SCHEDULER_WITHDRAW_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentSchedulerWithdrawInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentSchedulerWithdrawOutput>(),
+                ),
+                export: PAYMENT_SCHEDULER_WITHDRAW_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            PAYMENT_SCHEDULER_CREATE_SCHEDULE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PaymentSchedulerCreateScheduleInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PaymentSchedulerCreateScheduleOutput>(),
+                ),
+                export: PAYMENT_SCHEDULER_CREATE_SCHEDULE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            PAYMENT_SCHEDULER_CANCEL_SCHEDULE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PaymentSchedulerCancelScheduleInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PaymentSchedulerCancelScheduleOutput>(),
+                ),
+                export: PAYMENT_SCHEDULER_CANCEL_SCHEDULE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            PAYMENT_SCHEDULER_GET_SCHEDULE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentSchedulerGetScheduleInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PaymentSchedulerGetScheduleOutput>(),
+                ),
+                export: PAYMENT_SCHEDULER_GET_SCHEDULE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            PAYMENT_SCHEDULER_PROCESS_DUE_PAYMENTS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PaymentSchedulerProcessDuePaymentsInput>(
+                        ),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PaymentSchedulerProcessDuePaymentsOutput>(
+                        ),
+                ),
+                export: PAYMENT_SCHEDULER_PROCESS_DUE_PAYMENTS_EXPORT_NAME.to_string(),
+            },
+        );
+
+        let event_schema = event_schema! {
+            aggregator,
+            [
+                ScheduleCreatedEvent,
+                ScheduleCancelledEvent,
+                PaymentEvent,
+                PaymentRejectedEvent
+            ]
+        };
+
+        let schema = generate_full_schema(aggregator);
+
+        BlueprintDefinitionInit {
+            blueprint_type: BlueprintType::default(),
+            is_transient: false,
+            dependencies: indexset!(),
+            feature_set,
+
+            schema: BlueprintSchemaInit {
+                generics: vec![],
+                schema,
+                state,
+                events: event_schema,
+                types: BlueprintTypeSchemaInit::default(),
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
+                hooks: BlueprintHooksInit::default(),
+            },
+
+            royalty_config: PackageRoyaltyConfig::default(),
+            auth_config: AuthConfig {
+                function_auth: FunctionAuth::AllowAll,
+                method_auth: MethodAuthTemplate::StaticRoleDefinition(roles_template! {
+                    methods {
+                        PAYMENT_SCHEDULER_DEPOSIT_IDENT => MethodAccessibility::Public;
+                        PAYMENT_SCHEDULER_GET_SCHEDULE_IDENT => MethodAccessibility::Public;
+                        PAYMENT_SCHEDULER_PROCESS_DUE_PAYMENTS_IDENT => MethodAccessibility::Public;
+                        PAYMENT_SCHEDULER_WITHDRAW_IDENT => [OWNER_ROLE];
+                        PAYMENT_SCHEDULER_CREATE_SCHEDULE_IDENT => [OWNER_ROLE];
+                        PAYMENT_SCHEDULER_CANCEL_SCHEDULE_IDENT => [OWNER_ROLE];
+                    }
+                }),
+            },
+        }
+    }
+}
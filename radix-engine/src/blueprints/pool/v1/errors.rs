@@ -22,6 +22,11 @@ pub mod one_resource_pool {
         ResourceDoesNotBelongToPool {
             resource_address: ResourceAddress,
         },
+        DonationWithNoPoolUnitsInCirculation,
+        InsufficientReservesForRedemption {
+            required: Decimal,
+            available: Decimal,
+        },
     }
 
     impl From<Error> for RuntimeError {
@@ -89,6 +94,16 @@ pub mod multi_resource_pool {
         NoMinimumRatio,
         ZeroPoolUnitsMinted,
         LargerContributionRequiredToMeetRatio,
+        ResourceAlreadyInPool {
+            resource_address: ResourceAddress,
+        },
+        ResourceIsRetired {
+            resource_address: ResourceAddress,
+        },
+        InvalidResourceWeight {
+            weight: Decimal,
+        },
+        CantRetireLastActiveResource,
     }
 
     impl From<Error> for RuntimeError {
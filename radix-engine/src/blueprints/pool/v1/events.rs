@@ -24,6 +24,22 @@ pub mod one_resource_pool {
     pub struct DepositEvent {
         pub amount: Decimal,
     }
+
+    #[derive(ScryptoSbor, ScryptoEvent, Debug)]
+    pub struct DonationEvent {
+        pub amount: Decimal,
+    }
+
+    #[derive(ScryptoSbor, ScryptoEvent, Debug)]
+    pub struct AccrualIndexUpdatedEvent {
+        pub accrual_index: PreciseDecimal,
+    }
+
+    #[derive(ScryptoSbor, ScryptoEvent, Debug)]
+    pub struct YieldRedemptionEvent {
+        pub principal_redeemed: Decimal,
+        pub yield_redeemed: Decimal,
+    }
 }
 
 pub mod two_resource_pool {
@@ -80,4 +96,22 @@ pub mod multi_resource_pool {
         pub resource_address: ResourceAddress,
         pub amount: Decimal,
     }
+
+    #[derive(ScryptoSbor, ScryptoEvent, Debug)]
+    pub struct ResourceAddedEvent {
+        pub resource_address: ResourceAddress,
+        pub weight: Decimal,
+    }
+
+    #[derive(ScryptoSbor, ScryptoEvent, Debug)]
+    pub struct ResourceRetiredEvent {
+        pub resource_address: ResourceAddress,
+        pub remaining_reserves: Decimal,
+    }
+
+    #[derive(ScryptoSbor, ScryptoEvent, Debug)]
+    pub struct ResourceWeightSetEvent {
+        pub resource_address: ResourceAddress,
+        pub weight: Decimal,
+    }
 }
@@ -19,6 +19,39 @@ pub enum PoolV1MinorVersion {
     One,
 }
 
+native_exports! {
+    pub enum PoolNativeExport {
+        OneResourcePoolInstantiate => ONE_RESOURCE_POOL_INSTANTIATE_EXPORT_NAME,
+        OneResourcePoolContribute => ONE_RESOURCE_POOL_CONTRIBUTE_EXPORT_NAME,
+        OneResourcePoolRedeem => ONE_RESOURCE_POOL_REDEEM_EXPORT_NAME,
+        OneResourcePoolProtectedDeposit => ONE_RESOURCE_POOL_PROTECTED_DEPOSIT_EXPORT_NAME,
+        OneResourcePoolProtectedWithdraw => ONE_RESOURCE_POOL_PROTECTED_WITHDRAW_EXPORT_NAME,
+        OneResourcePoolGetRedemptionValue => ONE_RESOURCE_POOL_GET_REDEMPTION_VALUE_EXPORT_NAME,
+        OneResourcePoolGetVaultAmount => ONE_RESOURCE_POOL_GET_VAULT_AMOUNT_EXPORT_NAME,
+        OneResourcePoolInstantiateInterestBearing => ONE_RESOURCE_POOL_INSTANTIATE_INTEREST_BEARING_EXPORT_NAME,
+        OneResourcePoolDonate => ONE_RESOURCE_POOL_DONATE_EXPORT_NAME,
+        OneResourcePoolGetAccrual => ONE_RESOURCE_POOL_GET_ACCRUAL_EXPORT_NAME,
+        TwoResourcePoolInstantiate => TWO_RESOURCE_POOL_INSTANTIATE_EXPORT_NAME,
+        TwoResourcePoolContribute => TWO_RESOURCE_POOL_CONTRIBUTE_EXPORT_NAME,
+        TwoResourcePoolRedeem => TWO_RESOURCE_POOL_REDEEM_EXPORT_NAME,
+        TwoResourcePoolProtectedDeposit => TWO_RESOURCE_POOL_PROTECTED_DEPOSIT_EXPORT_NAME,
+        TwoResourcePoolProtectedWithdraw => TWO_RESOURCE_POOL_PROTECTED_WITHDRAW_EXPORT_NAME,
+        TwoResourcePoolGetRedemptionValue => TWO_RESOURCE_POOL_GET_REDEMPTION_VALUE_EXPORT_NAME,
+        TwoResourcePoolGetVaultAmounts => TWO_RESOURCE_POOL_GET_VAULT_AMOUNTS_EXPORT_NAME,
+        MultiResourcePoolInstantiate => MULTI_RESOURCE_POOL_INSTANTIATE_EXPORT_NAME,
+        MultiResourcePoolContribute => MULTI_RESOURCE_POOL_CONTRIBUTE_EXPORT_NAME,
+        MultiResourcePoolRedeem => MULTI_RESOURCE_POOL_REDEEM_EXPORT_NAME,
+        MultiResourcePoolProtectedDeposit => MULTI_RESOURCE_POOL_PROTECTED_DEPOSIT_EXPORT_NAME,
+        MultiResourcePoolProtectedWithdraw => MULTI_RESOURCE_POOL_PROTECTED_WITHDRAW_EXPORT_NAME,
+        MultiResourcePoolGetRedemptionValue => MULTI_RESOURCE_POOL_GET_REDEMPTION_VALUE_EXPORT_NAME,
+        MultiResourcePoolGetVaultAmounts => MULTI_RESOURCE_POOL_GET_VAULT_AMOUNTS_EXPORT_NAME,
+        MultiResourcePoolAddResource => MULTI_RESOURCE_POOL_ADD_RESOURCE_EXPORT_NAME,
+        MultiResourcePoolRetireResource => MULTI_RESOURCE_POOL_RETIRE_RESOURCE_EXPORT_NAME,
+        MultiResourcePoolSetResourceWeight => MULTI_RESOURCE_POOL_SET_RESOURCE_WEIGHT_EXPORT_NAME,
+        MultiResourcePoolGetResourceWeights => MULTI_RESOURCE_POOL_GET_RESOURCE_WEIGHTS_EXPORT_NAME,
+    }
+}
+
 pub struct PoolNativePackage;
 impl PoolNativePackage {
     pub fn invoke_export<Y>(
@@ -30,8 +63,8 @@ impl PoolNativePackage {
     where
         Y: KernelNodeApi + KernelSubstateApi<SystemLockData> + ClientApi<RuntimeError>,
     {
-        match export_name {
-            ONE_RESOURCE_POOL_INSTANTIATE_EXPORT_NAME => {
+        match PoolNativeExport::from_export_name(export_name)? {
+            PoolNativeExport::OneResourcePoolInstantiate => {
                 let OneResourcePoolInstantiateInput {
                     resource_address,
                     pool_manager_rule,
@@ -60,7 +93,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            ONE_RESOURCE_POOL_CONTRIBUTE_EXPORT_NAME => {
+            PoolNativeExport::OneResourcePoolContribute => {
                 let OneResourcePoolContributeInput { bucket } = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -75,7 +108,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            ONE_RESOURCE_POOL_REDEEM_EXPORT_NAME => {
+            PoolNativeExport::OneResourcePoolRedeem => {
                 let OneResourcePoolRedeemInput { bucket } = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -90,7 +123,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            ONE_RESOURCE_POOL_PROTECTED_DEPOSIT_EXPORT_NAME => {
+            PoolNativeExport::OneResourcePoolProtectedDeposit => {
                 let OneResourcePoolProtectedDepositInput { bucket } =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -106,7 +139,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            ONE_RESOURCE_POOL_PROTECTED_WITHDRAW_EXPORT_NAME => {
+            PoolNativeExport::OneResourcePoolProtectedWithdraw => {
                 let OneResourcePoolProtectedWithdrawInput {
                     amount,
                     withdraw_strategy,
@@ -132,7 +165,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            ONE_RESOURCE_POOL_GET_REDEMPTION_VALUE_EXPORT_NAME => {
+            PoolNativeExport::OneResourcePoolGetRedemptionValue => {
                 let OneResourcePoolGetRedemptionValueInput {
                     amount_of_pool_units,
                 } = input.as_typed().map_err(|e| {
@@ -155,7 +188,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            ONE_RESOURCE_POOL_GET_VAULT_AMOUNT_EXPORT_NAME => {
+            PoolNativeExport::OneResourcePoolGetVaultAmount => {
                 let OneResourcePoolGetVaultAmountInput {} = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -170,7 +203,44 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            TWO_RESOURCE_POOL_INSTANTIATE_EXPORT_NAME => {
+            // Interest-bearing pools and donations were introduced in v1.1, so the following
+            // exports are not versioned.
+            PoolNativeExport::OneResourcePoolInstantiateInterestBearing => {
+                let OneResourcePoolInstantiateInterestBearingInput {
+                    resource_address,
+                    pool_manager_rule,
+                    owner_role,
+                    address_reservation,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = super::v1_1::OneResourcePoolBlueprint::instantiate_interest_bearing(
+                    resource_address,
+                    owner_role,
+                    pool_manager_rule,
+                    address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            PoolNativeExport::OneResourcePoolDonate => {
+                let OneResourcePoolDonateInput { bucket } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = super::v1_1::OneResourcePoolBlueprint::donate(bucket, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            PoolNativeExport::OneResourcePoolGetAccrual => {
+                let OneResourcePoolGetAccrualInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = super::v1_1::OneResourcePoolBlueprint::get_accrual(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            PoolNativeExport::TwoResourcePoolInstantiate => {
                 let TwoResourcePoolInstantiateInput {
                     resource_addresses,
                     pool_manager_rule,
@@ -199,7 +269,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            TWO_RESOURCE_POOL_CONTRIBUTE_EXPORT_NAME => {
+            PoolNativeExport::TwoResourcePoolContribute => {
                 let TwoResourcePoolContributeInput { buckets } = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -214,7 +284,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            TWO_RESOURCE_POOL_REDEEM_EXPORT_NAME => {
+            PoolNativeExport::TwoResourcePoolRedeem => {
                 let TwoResourcePoolRedeemInput { bucket } = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -229,7 +299,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            TWO_RESOURCE_POOL_PROTECTED_DEPOSIT_EXPORT_NAME => {
+            PoolNativeExport::TwoResourcePoolProtectedDeposit => {
                 let TwoResourcePoolProtectedDepositInput { bucket } =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -245,7 +315,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            TWO_RESOURCE_POOL_PROTECTED_WITHDRAW_EXPORT_NAME => {
+            PoolNativeExport::TwoResourcePoolProtectedWithdraw => {
                 let TwoResourcePoolProtectedWithdrawInput {
                     amount,
                     resource_address,
@@ -274,7 +344,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            TWO_RESOURCE_POOL_GET_REDEMPTION_VALUE_EXPORT_NAME => {
+            PoolNativeExport::TwoResourcePoolGetRedemptionValue => {
                 let TwoResourcePoolGetRedemptionValueInput {
                     amount_of_pool_units,
                 } = input.as_typed().map_err(|e| {
@@ -297,7 +367,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            TWO_RESOURCE_POOL_GET_VAULT_AMOUNTS_EXPORT_NAME => {
+            PoolNativeExport::TwoResourcePoolGetVaultAmounts => {
                 let TwoResourcePoolGetVaultAmountsInput {} = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -312,7 +382,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            MULTI_RESOURCE_POOL_INSTANTIATE_EXPORT_NAME => {
+            PoolNativeExport::MultiResourcePoolInstantiate => {
                 let MultiResourcePoolInstantiateInput {
                     resource_addresses,
                     owner_role,
@@ -345,7 +415,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            MULTI_RESOURCE_POOL_CONTRIBUTE_EXPORT_NAME => {
+            PoolNativeExport::MultiResourcePoolContribute => {
                 let MultiResourcePoolContributeInput { buckets } =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -361,7 +431,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            MULTI_RESOURCE_POOL_REDEEM_EXPORT_NAME => {
+            PoolNativeExport::MultiResourcePoolRedeem => {
                 let MultiResourcePoolRedeemInput { bucket } = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -376,7 +446,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            MULTI_RESOURCE_POOL_PROTECTED_DEPOSIT_EXPORT_NAME => {
+            PoolNativeExport::MultiResourcePoolProtectedDeposit => {
                 let MultiResourcePoolProtectedDepositInput { bucket } =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -392,7 +462,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            MULTI_RESOURCE_POOL_PROTECTED_WITHDRAW_EXPORT_NAME => {
+            PoolNativeExport::MultiResourcePoolProtectedWithdraw => {
                 let MultiResourcePoolProtectedWithdrawInput {
                     amount,
                     resource_address,
@@ -421,7 +491,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            MULTI_RESOURCE_POOL_GET_REDEMPTION_VALUE_EXPORT_NAME => {
+            PoolNativeExport::MultiResourcePoolGetRedemptionValue => {
                 let MultiResourcePoolGetRedemptionValueInput {
                     amount_of_pool_units,
                 } = input.as_typed().map_err(|e| {
@@ -444,7 +514,7 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            MULTI_RESOURCE_POOL_GET_VAULT_AMOUNTS_EXPORT_NAME => {
+            PoolNativeExport::MultiResourcePoolGetVaultAmounts => {
                 let MultiResourcePoolGetVaultAmountsInput {} = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -459,9 +529,58 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
+            // Resources can only be managed after creation from v1.1 onwards, so the following
+            // exports are not versioned.
+            PoolNativeExport::MultiResourcePoolAddResource => {
+                let MultiResourcePoolAddResourceInput {
+                    resource_address,
+                    weight,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = super::v1_1::MultiResourcePoolBlueprint::add_resource(
+                    resource_address,
+                    weight,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            PoolNativeExport::MultiResourcePoolRetireResource => {
+                let MultiResourcePoolRetireResourceInput { resource_address } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = super::v1_1::MultiResourcePoolBlueprint::retire_resource(
+                    resource_address,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            PoolNativeExport::MultiResourcePoolSetResourceWeight => {
+                let MultiResourcePoolSetResourceWeightInput {
+                    resource_address,
+                    weight,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = super::v1_1::MultiResourcePoolBlueprint::set_resource_weight(
+                    resource_address,
+                    weight,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            PoolNativeExport::MultiResourcePoolGetResourceWeights => {
+                let MultiResourcePoolGetResourceWeightsInput {} =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = super::v1_1::MultiResourcePoolBlueprint::get_resource_weights(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
         }
     }
 
@@ -593,13 +712,58 @@ impl PoolNativePackage {
             },
         );
 
+        functions.insert(
+            ONE_RESOURCE_POOL_INSTANTIATE_INTEREST_BEARING_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                    OneResourcePoolInstantiateInterestBearingInput,
+                >()),
+                output: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                    OneResourcePoolInstantiateInterestBearingOutput,
+                >()),
+                export: ONE_RESOURCE_POOL_INSTANTIATE_INTEREST_BEARING_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            ONE_RESOURCE_POOL_DONATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OneResourcePoolDonateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OneResourcePoolDonateOutput>(),
+                ),
+                export: ONE_RESOURCE_POOL_DONATE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            ONE_RESOURCE_POOL_GET_ACCRUAL_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OneResourcePoolGetAccrualInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<OneResourcePoolGetAccrualOutput>(),
+                ),
+                export: ONE_RESOURCE_POOL_GET_ACCRUAL_EXPORT_NAME.to_string(),
+            },
+        );
+
         let event_schema = event_schema! {
             aggregator,
             [
                 super::events::one_resource_pool::ContributionEvent,
                 super::events::one_resource_pool::RedemptionEvent,
                 super::events::one_resource_pool::WithdrawEvent,
-                super::events::one_resource_pool::DepositEvent
+                super::events::one_resource_pool::DepositEvent,
+                super::events::one_resource_pool::DonationEvent,
+                super::events::one_resource_pool::AccrualIndexUpdatedEvent,
+                super::events::one_resource_pool::YieldRedemptionEvent
             ]
         };
 
@@ -617,7 +781,10 @@ impl PoolNativePackage {
                 state,
                 events: event_schema,
                 types: BlueprintTypeSchemaInit::default(),
-                functions: BlueprintFunctionsSchemaInit { functions },
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
                 hooks: BlueprintHooksInit::default(),
             },
 
@@ -633,6 +800,8 @@ impl PoolNativePackage {
                         ONE_RESOURCE_POOL_REDEEM_IDENT => MethodAccessibility::Public;
                         ONE_RESOURCE_POOL_GET_REDEMPTION_VALUE_IDENT => MethodAccessibility::Public;
                         ONE_RESOURCE_POOL_GET_VAULT_AMOUNT_IDENT => MethodAccessibility::Public;
+                        ONE_RESOURCE_POOL_DONATE_IDENT => MethodAccessibility::Public;
+                        ONE_RESOURCE_POOL_GET_ACCRUAL_IDENT => MethodAccessibility::Public;
                         ONE_RESOURCE_POOL_CONTRIBUTE_IDENT => [POOL_MANAGER_ROLE];
                         ONE_RESOURCE_POOL_PROTECTED_DEPOSIT_IDENT => [POOL_MANAGER_ROLE];
                         ONE_RESOURCE_POOL_PROTECTED_WITHDRAW_IDENT => [POOL_MANAGER_ROLE];
@@ -782,7 +951,10 @@ impl PoolNativePackage {
                 state,
                 events: event_schema,
                 types: BlueprintTypeSchemaInit::default(),
-                functions: BlueprintFunctionsSchemaInit { functions },
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
                 hooks: BlueprintHooksInit::default(),
             },
 
@@ -927,13 +1099,82 @@ impl PoolNativePackage {
             },
         );
 
+        functions.insert(
+            MULTI_RESOURCE_POOL_ADD_RESOURCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<MultiResourcePoolAddResourceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<MultiResourcePoolAddResourceOutput>(),
+                ),
+                export: MULTI_RESOURCE_POOL_ADD_RESOURCE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            MULTI_RESOURCE_POOL_RETIRE_RESOURCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<MultiResourcePoolRetireResourceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<MultiResourcePoolRetireResourceOutput>(),
+                ),
+                export: MULTI_RESOURCE_POOL_RETIRE_RESOURCE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            MULTI_RESOURCE_POOL_SET_RESOURCE_WEIGHT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<MultiResourcePoolSetResourceWeightInput>(
+                        ),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<MultiResourcePoolSetResourceWeightOutput>(
+                        ),
+                ),
+                export: MULTI_RESOURCE_POOL_SET_RESOURCE_WEIGHT_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            MULTI_RESOURCE_POOL_GET_RESOURCE_WEIGHTS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<MultiResourcePoolGetResourceWeightsInput>(
+                        ),
+                ),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<MultiResourcePoolGetResourceWeightsOutput>(
+                    )),
+                export: MULTI_RESOURCE_POOL_GET_RESOURCE_WEIGHTS_EXPORT_NAME.to_string(),
+            },
+        );
+
         let event_schema = event_schema! {
             aggregator,
             [
                 super::events::multi_resource_pool::ContributionEvent,
                 super::events::multi_resource_pool::RedemptionEvent,
                 super::events::multi_resource_pool::WithdrawEvent,
-                super::events::multi_resource_pool::DepositEvent
+                super::events::multi_resource_pool::DepositEvent,
+                super::events::multi_resource_pool::ResourceAddedEvent,
+                super::events::multi_resource_pool::ResourceRetiredEvent,
+                super::events::multi_resource_pool::ResourceWeightSetEvent
             ]
         };
 
@@ -951,7 +1192,10 @@ impl PoolNativePackage {
                 state,
                 events: event_schema,
                 types: BlueprintTypeSchemaInit::default(),
-                functions: BlueprintFunctionsSchemaInit { functions },
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
                 hooks: BlueprintHooksInit::default(),
             },
             royalty_config: PackageRoyaltyConfig::default(),
@@ -968,6 +1212,10 @@ impl PoolNativePackage {
                         MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT => [POOL_MANAGER_ROLE];
                         MULTI_RESOURCE_POOL_PROTECTED_DEPOSIT_IDENT => [POOL_MANAGER_ROLE];
                         MULTI_RESOURCE_POOL_PROTECTED_WITHDRAW_IDENT => [POOL_MANAGER_ROLE];
+                        MULTI_RESOURCE_POOL_GET_RESOURCE_WEIGHTS_IDENT => MethodAccessibility::Public;
+                        MULTI_RESOURCE_POOL_ADD_RESOURCE_IDENT => [OWNER_ROLE];
+                        MULTI_RESOURCE_POOL_RETIRE_RESOURCE_IDENT => [OWNER_ROLE];
+                        MULTI_RESOURCE_POOL_SET_RESOURCE_WEIGHT_IDENT => [OWNER_ROLE];
                     }
                 }),
             },
@@ -4,11 +4,16 @@ use radix_engine_interface::prelude::*;
 
 pub mod one_resource_pool {
     use super::*;
+    use radix_engine_interface::blueprints::pool::PoolAccrual;
 
     declare_native_blueprint_state! {
         blueprint_ident: OneResourcePool,
         blueprint_snake_case: one_resource_pool,
         features: {
+            interest_bearing: {
+                ident: InterestBearing,
+                description: "Enables accounting of principal and yield through an accrual index",
+            },
         },
         fields: {
             state:  {
@@ -17,6 +22,13 @@ pub mod one_resource_pool {
                     kind: StaticSingleVersioned,
                 },
                 condition: Condition::Always,
+            },
+            accrual:  {
+                ident: Accrual,
+                field_type: {
+                    kind: StaticSingleVersioned,
+                },
+                condition: Condition::if_feature(OneResourcePoolFeature::InterestBearing),
             }
         },
         collections: {
@@ -24,6 +36,7 @@ pub mod one_resource_pool {
     }
 
     pub type OneResourcePoolStateV1 = Substate;
+    pub type OneResourcePoolAccrualV1 = PoolAccrual;
 
     #[derive(Debug, PartialEq, Eq, ScryptoSbor)]
     #[sbor(type_name = "OneResourcePoolSubstate")]
@@ -99,10 +112,22 @@ pub mod multi_resource_pool {
             }
         },
         collections: {
+            resource_configs: KeyValue {
+                entry_ident: ResourceConfig,
+                key_type: {
+                    kind: Static,
+                    content_type: ResourceAddress,
+                },
+                value_type: {
+                    kind: StaticSingleVersioned,
+                },
+                allow_ownership: false,
+            },
         }
     }
 
     pub type MultiResourcePoolStateV1 = Substate;
+    pub type MultiResourcePoolResourceConfigV1 = ResourceConfig;
 
     #[derive(Debug, PartialEq, Eq, ScryptoSbor)]
     #[sbor(type_name = "MultiResourcePoolSubstate")]
@@ -133,4 +158,30 @@ pub mod multi_resource_pool {
         /// The resource manager of the pool unit resource that the pool works with.
         pub pool_unit_resource_manager: ResourceManager,
     }
+
+    /// The configuration of one of the resources of the pool. Resources that have no entry in the
+    /// collection use the default configuration, which is how pools created before resources
+    /// could be configured behave.
+    #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+    #[sbor(type_name = "MultiResourcePoolResourceConfig")]
+    pub struct ResourceConfig {
+        /// The fraction, between zero and one, of a redeemer's pro-rata share of this resource that
+        /// is paid out on redemption. Whatever is not paid out stays in the pool and is shared by
+        /// the remaining pool unit holders. Redeeming the entire pool unit supply ignores weights.
+        pub weight: Decimal,
+
+        /// Whether the resource has been retired from the pool. A retired resource keeps its vault,
+        /// so that pool unit holders can still redeem their share of it, but it no longer takes
+        /// part in contributions and can't be deposited into.
+        pub is_retired: bool,
+    }
+
+    impl Default for ResourceConfig {
+        fn default() -> Self {
+            Self {
+                weight: Decimal::ONE,
+                is_retired: false,
+            }
+        }
+    }
 }
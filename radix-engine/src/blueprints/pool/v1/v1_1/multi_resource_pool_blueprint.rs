@@ -207,22 +207,24 @@ impl MultiResourcePoolBlueprint {
             let pool_unit_total_supply = PreciseDecimal::from(pool_unit_total_supply);
 
             let contribution_information = {
-                let mut information = substate
-                    .vaults
-                    .iter()
-                    .map(|(resource_address, vault)| -> Result<_, RuntimeError> {
-                        Ok((
-                            *resource_address,
-                            ContributionInformation {
-                                resource_address: *resource_address,
-                                vault: Vault(vault.0),
-                                bucket: Bucket::create(*resource_address, api)?,
-                                reserves: vault.amount(api)?.into(),
-                                contribution: PreciseDecimal::ZERO,
-                            },
-                        ))
-                    })
-                    .collect::<Result<IndexMap<_, _>, _>>()?;
+                // Retired resources take no part in contributions, neither in the ratio nor in the
+                // amount of pool units minted.
+                let mut information = index_map_new();
+                for (resource_address, vault) in substate.vaults.iter() {
+                    if Self::resource_config(*resource_address, api)?.is_retired {
+                        continue;
+                    }
+                    information.insert(
+                        *resource_address,
+                        ContributionInformation {
+                            resource_address: *resource_address,
+                            vault: Vault(vault.0),
+                            bucket: Bucket::create(*resource_address, api)?,
+                            reserves: vault.amount(api)?.into(),
+                            contribution: PreciseDecimal::ZERO,
+                        },
+                    );
+                }
 
                 for bucket in buckets {
                     let resource_address = bucket.resource_address(api)?;
@@ -232,6 +234,8 @@ impl MultiResourcePoolBlueprint {
                             .checked_add(bucket.amount(api)?)
                             .ok_or(Error::DecimalOverflowError)?;
                         information.bucket.put(bucket, api)?;
+                    } else if substate.vaults.contains_key(&resource_address) {
+                        return Err(Error::ResourceIsRetired { resource_address }.into());
                     } else {
                         return Err(Error::ResourceDoesNotBelongToPool { resource_address }.into());
                     }
@@ -411,6 +415,12 @@ impl MultiResourcePoolBlueprint {
             let mut reserves = index_map_new();
             for (resource_address, vault) in substate.vaults.iter() {
                 let amount = vault.amount(api)?;
+                let weight = Self::redemption_weight(
+                    *resource_address,
+                    pool_units_to_redeem,
+                    pool_units_total_supply,
+                    api,
+                )?;
                 let divisibility = ResourceManager(*resource_address).resource_type(api)
                 .map(|resource_type| {
                     if let ResourceType::Fungible { divisibility } = resource_type {
@@ -425,6 +435,7 @@ impl MultiResourcePoolBlueprint {
                     ReserveResourceInformation {
                         reserves: amount,
                         divisibility,
+                        weight,
                     },
                 );
             }
@@ -469,6 +480,9 @@ impl MultiResourcePoolBlueprint {
             let resource_address = bucket.resource_address(api)?;
             let vault = substate.vaults.get_mut(&resource_address);
             if let Some(vault) = vault {
+                if Self::resource_config(resource_address, api)?.is_retired {
+                    return Err(Error::ResourceIsRetired { resource_address }.into());
+                }
                 let event = DepositEvent {
                     amount: bucket.amount(api)?,
                     resource_address,
@@ -537,6 +551,12 @@ impl MultiResourcePoolBlueprint {
             let mut reserves = index_map_new();
             for (resource_address, vault) in substate.vaults.into_iter() {
                 let amount = vault.amount(api)?;
+                let weight = Self::redemption_weight(
+                    resource_address,
+                    pool_units_to_redeem,
+                    pool_units_total_supply,
+                    api,
+                )?;
                 let divisibility = ResourceManager(resource_address).resource_type(api)
                 .map(|resource_type| {
                     if let ResourceType::Fungible { divisibility } = resource_type {
@@ -551,6 +571,7 @@ impl MultiResourcePoolBlueprint {
                     ReserveResourceInformation {
                         reserves: amount,
                         divisibility,
+                        weight,
                     },
                 );
             }
@@ -576,6 +597,158 @@ impl MultiResourcePoolBlueprint {
         })
     }
 
+    /**
+    Adds a resource to the pool with the given redemption weight, or reinstates a resource that was
+    previously retired from it.
+
+    A newly added resource starts out with an empty vault. Existing pool unit holders get a claim on
+    it in proportion to their pool units, which only becomes worth something once the pool manager
+    deposits some of the resource through a protected deposit. Until then the pool treats it like
+    any other resource with empty reserves, and contributions of it are returned as change.
+
+    Note that the `pool_resources` and `pool_vault_number` metadata entries are locked when the pool
+    is instantiated and keep describing the pool as it was created; the vault amounts are the
+    source of truth for the resources of the pool.
+    */
+    pub fn add_resource<Y>(
+        resource_address: ResourceAddress,
+        weight: Decimal,
+        api: &mut Y,
+    ) -> Result<MultiResourcePoolAddResourceOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::validate_weight(weight)?;
+        if let ResourceType::NonFungible { .. } =
+            ResourceManager(resource_address).resource_type(api)?
+        {
+            return Err(Error::NonFungibleResourcesAreNotAccepted { resource_address }.into());
+        }
+
+        Self::with_state_mut(api, |substate, api| {
+            if substate.vaults.contains_key(&resource_address) {
+                if !Self::resource_config(resource_address, api)?.is_retired {
+                    return Err(Error::ResourceAlreadyInPool { resource_address }.into());
+                }
+            } else {
+                let vault = Vault::create(resource_address, api)?;
+                substate.vaults.insert(resource_address, vault);
+            }
+
+            Self::set_resource_config(
+                resource_address,
+                ResourceConfig {
+                    weight,
+                    is_retired: false,
+                },
+                api,
+            )?;
+            Runtime::emit_event(
+                api,
+                ResourceAddedEvent {
+                    resource_address,
+                    weight,
+                },
+            )
+        })
+    }
+
+    /**
+    Retires a resource from the pool so that it can no longer be contributed or deposited.
+
+    Vaults can't be removed from the pool, and pool unit holders still have a claim on whatever is
+    left in the vault of a retired resource. Their claims are therefore kept as they are: the
+    remaining reserves of the resource keep being paid out on redemption according to its weight,
+    until they are drained by redemptions or moved out by the pool manager through a protected
+    withdraw. The pool must be left with at least one resource that has not been retired.
+    */
+    pub fn retire_resource<Y>(
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<MultiResourcePoolRetireResourceOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::with_state(api, |substate, api| {
+            let vault = substate
+                .vaults
+                .get(&resource_address)
+                .ok_or(Error::ResourceDoesNotBelongToPool { resource_address })?;
+            let mut config = Self::resource_config(resource_address, api)?;
+            if config.is_retired {
+                return Err(Error::ResourceIsRetired { resource_address }.into());
+            }
+
+            let mut has_other_active_resource = false;
+            for other_resource_address in substate.vaults.keys() {
+                if *other_resource_address != resource_address
+                    && !Self::resource_config(*other_resource_address, api)?.is_retired
+                {
+                    has_other_active_resource = true;
+                    break;
+                }
+            }
+            if !has_other_active_resource {
+                return Err(Error::CantRetireLastActiveResource.into());
+            }
+
+            config.is_retired = true;
+            Self::set_resource_config(resource_address, config, api)?;
+            Runtime::emit_event(
+                api,
+                ResourceRetiredEvent {
+                    resource_address,
+                    remaining_reserves: vault.amount(api)?,
+                },
+            )
+        })
+    }
+
+    pub fn set_resource_weight<Y>(
+        resource_address: ResourceAddress,
+        weight: Decimal,
+        api: &mut Y,
+    ) -> Result<MultiResourcePoolSetResourceWeightOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::validate_weight(weight)?;
+        Self::with_state(api, |substate, api| {
+            if !substate.vaults.contains_key(&resource_address) {
+                return Err(Error::ResourceDoesNotBelongToPool { resource_address }.into());
+            }
+
+            let mut config = Self::resource_config(resource_address, api)?;
+            config.weight = weight;
+            Self::set_resource_config(resource_address, config, api)?;
+            Runtime::emit_event(
+                api,
+                ResourceWeightSetEvent {
+                    resource_address,
+                    weight,
+                },
+            )
+        })
+    }
+
+    pub fn get_resource_weights<Y>(
+        api: &mut Y,
+    ) -> Result<MultiResourcePoolGetResourceWeightsOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::with_state(api, |substate, api| {
+            substate
+                .vaults
+                .into_keys()
+                .map(|resource_address| {
+                    Self::resource_config(resource_address, api)
+                        .map(|config| (resource_address, config.weight))
+                })
+                .collect::<Result<IndexMap<_, _>, _>>()
+        })
+    }
+
     //===================
     // Utility Functions
     //===================
@@ -603,6 +776,102 @@ impl MultiResourcePoolBlueprint {
         rtn
     }
 
+    fn with_state_mut<Y, F, O>(api: &mut Y, callback: F) -> Result<O, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+        F: FnOnce(&mut Substate, &mut Y) -> Result<O, RuntimeError>,
+    {
+        // Open
+        let substate_key = MultiResourcePoolField::State.into();
+        let handle = api.actor_open_field(ACTOR_STATE_SELF, substate_key, LockFlags::MUTABLE)?;
+        let mut substate = api
+            .field_read_typed::<VersionedMultiResourcePoolState>(handle)?
+            .into_latest();
+
+        // Op
+        let rtn = callback(&mut substate, api)?;
+
+        // Write & Close
+        api.field_write_typed(
+            handle,
+            &MultiResourcePoolStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+        Ok(rtn)
+    }
+
+    fn resource_config<Y>(
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<ResourceConfig, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            MultiResourcePoolCollection::ResourceConfigKeyValue.collection_index(),
+            &scrypto_encode(&resource_address).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let config = api
+            .key_value_entry_get_typed::<MultiResourcePoolResourceConfigEntryPayload>(handle)?
+            .map(|config| config.into_latest())
+            .unwrap_or_default();
+        api.key_value_entry_close(handle)?;
+
+        Ok(config)
+    }
+
+    fn set_resource_config<Y>(
+        resource_address: ResourceAddress,
+        config: ResourceConfig,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            MultiResourcePoolCollection::ResourceConfigKeyValue.collection_index(),
+            &scrypto_encode(&resource_address).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            handle,
+            &MultiResourcePoolResourceConfigEntryPayload::from_content_source(config),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(())
+    }
+
+    /// The weight to apply to a resource when redeeming the given amount of pool units. Weights are
+    /// ignored when the entire supply of pool units is redeemed, so that nothing is left behind in
+    /// a pool that no one has a claim on anymore.
+    fn redemption_weight<Y>(
+        resource_address: ResourceAddress,
+        pool_units_to_redeem: Decimal,
+        pool_units_total_supply: Decimal,
+        api: &mut Y,
+    ) -> Result<Decimal, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if pool_units_to_redeem == pool_units_total_supply {
+            Ok(Decimal::ONE)
+        } else {
+            Self::resource_config(resource_address, api).map(|config| config.weight)
+        }
+    }
+
+    fn validate_weight(weight: Decimal) -> Result<(), RuntimeError> {
+        if weight.is_negative() || weight > Decimal::ONE {
+            Err(Error::InvalidResourceWeight { weight }.into())
+        } else {
+            Ok(())
+        }
+    }
+
     fn calculate_amount_owed(
         pool_units_to_redeem: Decimal,
         pool_units_total_supply: Decimal,
@@ -619,12 +888,14 @@ impl MultiResourcePoolBlueprint {
                     ReserveResourceInformation {
                         divisibility,
                         reserves,
+                        weight,
                     },
                 )| {
                     let reserves = PreciseDecimal::from(reserves);
                     let amount_owed = pool_units_to_redeem
                         .checked_div(pool_units_total_supply)
                         .and_then(|d| d.checked_mul(reserves))
+                        .and_then(|d| d.checked_mul(PreciseDecimal::from(weight)))
                         .ok_or(Error::DecimalOverflowError)?;
 
                     let amount_owed = Decimal::try_from(amount_owed)
@@ -644,6 +915,7 @@ impl MultiResourcePoolBlueprint {
 struct ReserveResourceInformation {
     reserves: Decimal,
     divisibility: u8,
+    weight: Decimal,
 }
 
 #[derive(Debug)]
@@ -23,6 +23,52 @@ impl OneResourcePoolBlueprint {
         address_reservation: Option<GlobalAddressReservation>,
         api: &mut Y,
     ) -> Result<OneResourcePoolInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError> + KernelNodeApi,
+    {
+        Self::instantiate_internal(
+            resource_address,
+            owner_role,
+            pool_manager_rule,
+            address_reservation,
+            OneResourcePoolFeatureSet::default(),
+            api,
+        )
+    }
+
+    /// Instantiates a pool that keeps track of the principal contributed to it separately from the
+    /// yield that accrues on top of it. Pool units of such a pool are minted and redeemed at the
+    /// accrual index, which only grows through donations.
+    pub fn instantiate_interest_bearing<Y>(
+        resource_address: ResourceAddress,
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<OneResourcePoolInstantiateInterestBearingOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError> + KernelNodeApi,
+    {
+        Self::instantiate_internal(
+            resource_address,
+            owner_role,
+            pool_manager_rule,
+            address_reservation,
+            OneResourcePoolFeatureSet {
+                interest_bearing: true,
+            },
+            api,
+        )
+    }
+
+    fn instantiate_internal<Y>(
+        resource_address: ResourceAddress,
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        address_reservation: Option<GlobalAddressReservation>,
+        features: OneResourcePoolFeatureSet,
+        api: &mut Y,
+    ) -> Result<Global<OneResourcePoolObjectTypeInfo>, RuntimeError>
     where
         Y: ClientApi<RuntimeError> + KernelNodeApi,
     {
@@ -98,11 +144,27 @@ impl OneResourcePoolBlueprint {
                 vault,
                 pool_unit_resource_manager,
             };
-            api.new_simple_object(
+            let mut fields = indexmap! {
+                OneResourcePoolField::State.field_index() => FieldValue::immutable(OneResourcePoolStateFieldPayload::from_content_source(substate)),
+            };
+            if features.interest_bearing {
+                let accrual = PoolAccrual {
+                    accrual_index: PreciseDecimal::ONE,
+                    principal: Decimal::ZERO,
+                };
+                fields.insert(
+                    OneResourcePoolField::Accrual.field_index(),
+                    FieldValue::new(OneResourcePoolAccrualFieldPayload::from_content_source(
+                        accrual,
+                    )),
+                );
+            }
+            api.new_object(
                 ONE_RESOURCE_POOL_BLUEPRINT_IDENT,
-                indexmap! {
-                    OneResourcePoolField::State.field_index() => FieldValue::immutable(OneResourcePoolStateFieldPayload::from_content_source(substate)),
-                },
+                features.feature_names_str(),
+                GenericArgs::default(),
+                fields,
+                indexmap!(),
             )?
         };
 
@@ -185,21 +247,37 @@ impl OneResourcePoolBlueprint {
             let amount_of_contributed_resources =
                 PreciseDecimal::from(amount_of_contributed_resources_decimal);
 
-            let pool_units_to_mint = match (
-                initial_pool_unit_total_supply > PreciseDecimal::ZERO,
-                initial_reserves > PreciseDecimal::ZERO,
-            ) {
-                (false, false) => Ok(amount_of_contributed_resources),
-                (false, true) => amount_of_contributed_resources
-                    .checked_add(initial_reserves)
-                    .ok_or(Error::DecimalOverflowError),
-                (true, false) => Err(Error::NonZeroPoolUnitSupplyButZeroReserves),
-                // Note: we do the division first to make it harder for the calculation to overflow.
-                (true, true) => amount_of_contributed_resources
-                    .checked_div(initial_reserves)
-                    .and_then(|d| d.checked_mul(initial_pool_unit_total_supply))
-                    .ok_or(Error::DecimalOverflowError),
-            }?;
+            // Interest-bearing pools don't look at the reserves at all. Pool units are minted at
+            // the accrual index so that the yield accrued so far stays with the existing holders,
+            // and reserves lent out through a protected withdraw don't affect the rate.
+            let pool_units_to_mint = if Self::is_interest_bearing(api)? {
+                Self::with_accrual(api, |accrual| {
+                    accrual.principal = accrual
+                        .principal
+                        .checked_add(amount_of_contributed_resources_decimal)
+                        .ok_or(Error::DecimalOverflowError)?;
+                    amount_of_contributed_resources
+                        .checked_div(accrual.accrual_index)
+                        .ok_or(Error::DecimalOverflowError.into())
+                })?
+            } else {
+                match (
+                    initial_pool_unit_total_supply > PreciseDecimal::ZERO,
+                    initial_reserves > PreciseDecimal::ZERO,
+                ) {
+                    (false, false) => Ok(amount_of_contributed_resources),
+                    (false, true) => amount_of_contributed_resources
+                        .checked_add(initial_reserves)
+                        .ok_or(Error::DecimalOverflowError),
+                    (true, false) => Err(Error::NonZeroPoolUnitSupplyButZeroReserves),
+                    // Note: we do the division first to make it harder for the calculation to
+                    // overflow.
+                    (true, true) => amount_of_contributed_resources
+                        .checked_div(initial_reserves)
+                        .and_then(|d| d.checked_mul(initial_pool_unit_total_supply))
+                        .ok_or(Error::DecimalOverflowError),
+                }?
+            };
             let pool_units_to_mint =
                 Decimal::try_from(pool_units_to_mint).map_err(|_| Error::DecimalOverflowError)?;
             if pool_units_to_mint == Decimal::ZERO {
@@ -256,12 +334,22 @@ impl OneResourcePoolBlueprint {
                 }
             })?;
 
-            let amount_owed = Self::calculate_amount_owed(
-                pool_units_to_redeem,
-                initial_pool_units_total_supply,
-                initial_pool_resource_reserves,
-                reserves_divisibility,
-            )?;
+            let amount_owed = if Self::is_interest_bearing(api)? {
+                Self::redeem_accrued(
+                    pool_units_to_redeem,
+                    initial_pool_units_total_supply,
+                    initial_pool_resource_reserves,
+                    reserves_divisibility,
+                    api,
+                )?
+            } else {
+                Self::calculate_amount_owed(
+                    pool_units_to_redeem,
+                    initial_pool_units_total_supply,
+                    initial_pool_resource_reserves,
+                    reserves_divisibility,
+                )?
+            };
 
             // Return an error if the amount owed to them is zero. This is to guard from cases where
             // the amount owed is zero due to the divisibility. As an example. Imagine a pool with
@@ -364,15 +452,82 @@ impl OneResourcePoolBlueprint {
                 }
             })?;
 
-            Self::calculate_amount_owed(
-                pool_units_to_redeem,
-                pool_units_total_supply,
-                pool_resource_reserves,
-                pool_resource_divisibility,
-            )
+            if Self::is_interest_bearing(api)? {
+                let accrual = Self::read_accrual(api)?;
+                Self::calculate_accrued_amount_owed(
+                    pool_units_to_redeem,
+                    accrual.accrual_index,
+                    pool_resource_divisibility,
+                )
+            } else {
+                Self::calculate_amount_owed(
+                    pool_units_to_redeem,
+                    pool_units_total_supply,
+                    pool_resource_reserves,
+                    pool_resource_divisibility,
+                )
+            }
         })
     }
 
+    pub fn donate<Y>(
+        bucket: Bucket,
+        api: &mut Y,
+    ) -> Result<OneResourcePoolDonateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let amount = bucket.amount(api)?;
+
+        Self::with_state(api, |mut substate, api| {
+            let input_resource_address = bucket.resource_address(api)?;
+            if input_resource_address != substate.vault.resource_address(api)? {
+                return Err(Error::ResourceDoesNotBelongToPool {
+                    resource_address: input_resource_address,
+                }
+                .into());
+            }
+
+            // A donation is shared between the holders of the pool units, so there must be some.
+            // Otherwise the donation would go to whoever contributes first.
+            let pool_units_total_supply = substate
+                .pool_unit_resource_manager
+                .total_supply(api)?
+                .expect("Total supply is always enabled for pool unit resource.");
+            if pool_units_total_supply.is_zero() {
+                return Err(Error::DonationWithNoPoolUnitsInCirculation.into());
+            }
+
+            if Self::is_interest_bearing(api)? {
+                let accrual_index = Self::with_accrual(api, |accrual| {
+                    accrual.accrual_index = PreciseDecimal::from(amount)
+                        .checked_div(PreciseDecimal::from(pool_units_total_supply))
+                        .and_then(|d| d.checked_add(accrual.accrual_index))
+                        .ok_or(Error::DecimalOverflowError)?;
+                    Ok(accrual.accrual_index)
+                })?;
+                Runtime::emit_event(api, AccrualIndexUpdatedEvent { accrual_index })?;
+            }
+
+            substate.vault.put(bucket, api)
+        })?;
+
+        Runtime::emit_event(api, DonationEvent { amount })?;
+
+        Ok(())
+    }
+
+    pub fn get_accrual<Y>(api: &mut Y) -> Result<OneResourcePoolGetAccrualOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if Self::is_interest_bearing(api)? {
+            Self::read_accrual(api).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     pub fn get_vault_amount<Y>(
         api: &mut Y,
     ) -> Result<OneResourcePoolGetVaultAmountOutput, RuntimeError>
@@ -409,6 +564,132 @@ impl OneResourcePoolBlueprint {
             .ok_or(Error::DecimalOverflowError.into())
     }
 
+    fn calculate_accrued_amount_owed(
+        pool_units_to_redeem: Decimal,
+        accrual_index: PreciseDecimal,
+        reserves_divisibility: u8,
+    ) -> Result<Decimal, RuntimeError> {
+        let amount_owed = PreciseDecimal::from(pool_units_to_redeem)
+            .checked_mul(accrual_index)
+            .ok_or(Error::DecimalOverflowError)?;
+
+        Decimal::try_from(amount_owed)
+            .ok()
+            .and_then(|value| {
+                value.checked_round(reserves_divisibility, RoundingMode::ToNegativeInfinity)
+            })
+            .ok_or(Error::DecimalOverflowError.into())
+    }
+
+    /// Calculates the amount owed for pool units of an interest-bearing pool and splits it into
+    /// principal and yield. The principal share of the redeemed pool units is taken out of the
+    /// pool's principal.
+    fn redeem_accrued<Y>(
+        pool_units_to_redeem: Decimal,
+        pool_units_total_supply: Decimal,
+        reserves_amount: Decimal,
+        reserves_divisibility: u8,
+        api: &mut Y,
+    ) -> Result<Decimal, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (amount_owed, principal_redeemed) = Self::with_accrual(api, |accrual| {
+            let amount_owed = Self::calculate_accrued_amount_owed(
+                pool_units_to_redeem,
+                accrual.accrual_index,
+                reserves_divisibility,
+            )?;
+
+            // Some of the reserves might have been withdrawn by the pool manager, in which case
+            // the pool might not be able to honour the accrual index until they're returned.
+            if amount_owed > reserves_amount {
+                return Err(Error::InsufficientReservesForRedemption {
+                    required: amount_owed,
+                    available: reserves_amount,
+                }
+                .into());
+            }
+
+            let principal_redeemed = PreciseDecimal::from(pool_units_to_redeem)
+                .checked_div(PreciseDecimal::from(pool_units_total_supply))
+                .and_then(|d| d.checked_mul(PreciseDecimal::from(accrual.principal)))
+                .and_then(|d| Decimal::try_from(d).ok())
+                .ok_or(Error::DecimalOverflowError)?
+                .min(amount_owed);
+            accrual.principal = accrual
+                .principal
+                .checked_sub(principal_redeemed)
+                .ok_or(Error::DecimalOverflowError)?;
+
+            Ok((amount_owed, principal_redeemed))
+        })?;
+
+        let yield_redeemed = amount_owed
+            .checked_sub(principal_redeemed)
+            .ok_or(Error::DecimalOverflowError)?;
+        Runtime::emit_event(
+            api,
+            YieldRedemptionEvent {
+                principal_redeemed,
+                yield_redeemed,
+            },
+        )?;
+
+        Ok(amount_owed)
+    }
+
+    fn is_interest_bearing<Y>(api: &mut Y) -> Result<bool, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            OneResourcePoolFeature::InterestBearing.feature_name(),
+        )
+    }
+
+    fn read_accrual<Y>(api: &mut Y) -> Result<PoolAccrual, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            OneResourcePoolField::Accrual.into(),
+            LockFlags::read_only(),
+        )?;
+        let accrual = api
+            .field_read_typed::<VersionedOneResourcePoolAccrual>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+        Ok(accrual)
+    }
+
+    /// Opens the accrual substate for writing, executes the callback on it, and writes it back.
+    fn with_accrual<Y, F, O>(api: &mut Y, callback: F) -> Result<O, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+        F: FnOnce(&mut PoolAccrual) -> Result<O, RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            OneResourcePoolField::Accrual.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut accrual = api
+            .field_read_typed::<VersionedOneResourcePoolAccrual>(handle)?
+            .into_latest();
+
+        let rtn = callback(&mut accrual)?;
+
+        api.field_write_typed(
+            handle,
+            &OneResourcePoolAccrualFieldPayload::from_content_source(accrual),
+        )?;
+        api.field_close(handle)?;
+        Ok(rtn)
+    }
+
     /// Opens the substate, executes the callback, and closes the substate.
     fn with_state<Y, F, O>(api: &mut Y, callback: F) -> Result<O, RuntimeError>
     where
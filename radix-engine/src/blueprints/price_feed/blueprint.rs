@@ -0,0 +1,261 @@
+use crate::internal_prelude::*;
+use native_sdk::modules::metadata::*;
+use native_sdk::modules::role_assignment::*;
+use native_sdk::modules::royalty::*;
+use native_sdk::resource::*;
+use native_sdk::runtime::*;
+use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
+use radix_engine_interface::blueprints::oracle::*;
+use radix_engine_interface::prelude::*;
+use radix_engine_interface::time::TimeComparisonOperator;
+use radix_engine_interface::*;
+
+declare_native_blueprint_state! {
+    blueprint_ident: PriceFeed,
+    blueprint_snake_case: price_feed,
+    features: {
+    },
+    fields: {
+        state:  {
+            ident: State,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
+        }
+    },
+    collections: {
+    }
+}
+
+pub type PriceFeedStateV1 = PriceFeedSubstate;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct PriceFeedSubstate {
+    /// The maximum number of seconds that can pass since a price was published before it is
+    /// considered stale and is no longer returned by the feed.
+    pub max_price_age_seconds: i64,
+
+    /// The resource that publishers must present to publish prices.
+    pub publisher_badge: ResourceAddress,
+
+    /// The latest published price of each pair. A feed is expected to track a small number of
+    /// pairs and every read needs the entry of a single pair, so this is kept in the state field
+    /// instead of a separate collection.
+    pub prices: IndexMap<PricePair, PublishedPrice>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ScryptoSbor)]
+pub struct PricePair {
+    pub base: ResourceAddress,
+    pub quote: ResourceAddress,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct PublishedPrice {
+    pub price: Decimal,
+    pub published_at: Instant,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum PriceFeedError {
+    InvalidMaxPriceAge {
+        max_price_age_seconds: i64,
+    },
+    InvalidPrice {
+        price: Decimal,
+    },
+    PriceNotAvailable {
+        base: ResourceAddress,
+        quote: ResourceAddress,
+    },
+    StalePrice {
+        base: ResourceAddress,
+        quote: ResourceAddress,
+        published_at: Instant,
+    },
+    TimeOverflow,
+}
+
+impl From<PriceFeedError> for RuntimeError {
+    fn from(error: PriceFeedError) -> Self {
+        Self::ApplicationError(ApplicationError::PriceFeedError(error))
+    }
+}
+
+pub struct PriceFeedBlueprint;
+
+impl PriceFeedBlueprint {
+    pub fn instantiate<Y>(
+        owner_role: OwnerRole,
+        max_price_age_seconds: i64,
+        publisher_badge_amount: Decimal,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<PriceFeedInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if max_price_age_seconds <= 0 {
+            return Err(PriceFeedError::InvalidMaxPriceAge {
+                max_price_age_seconds,
+            }
+            .into());
+        }
+
+        let (address_reservation, address) = {
+            if let Some(address_reservation) = address_reservation {
+                let address = api.get_reservation_address(address_reservation.0.as_node_id())?;
+                (address_reservation, address)
+            } else {
+                api.allocate_global_address(BlueprintId {
+                    package_address: PRICE_FEED_PACKAGE,
+                    blueprint_name: PRICE_FEED_BLUEPRINT.to_string(),
+                })?
+            }
+        };
+
+        // The publisher badges have a fixed supply - the owner can hand out the minted badges to
+        // the publishers or change the publisher role altogether through the role assignment.
+        let (publisher_badge, publisher_badges) =
+            ResourceManager::new_fungible_with_initial_supply(
+                owner_role.clone(),
+                true,
+                0,
+                publisher_badge_amount,
+                FungibleResourceRoles::default(),
+                metadata_init! {
+                    "name" => "Price Feed Publisher Badge".to_owned(), locked;
+                    "price_feed" => address, locked;
+                },
+                None,
+                api,
+            )?;
+
+        let role_assignment = RoleAssignment::create(
+            owner_role,
+            indexmap! {
+                ModuleId::Main => roles_init! {
+                    RoleKey { key: PRICE_FEED_PUBLISHER_ROLE.to_owned() } => rule!(require(publisher_badge.0));
+                }
+            },
+            api,
+        )?
+        .0;
+        let metadata = Metadata::create_with_data(
+            metadata_init! {
+                "publisher_badge" => GlobalAddress::from(publisher_badge.0), locked;
+            },
+            api,
+        )?;
+        let royalty = ComponentRoyalty::create(ComponentRoyaltyConfig::default(), api)?;
+        let object_id = {
+            let substate = PriceFeedSubstate {
+                max_price_age_seconds,
+                publisher_badge: publisher_badge.0,
+                prices: index_map_new(),
+            };
+            api.new_simple_object(
+                PRICE_FEED_BLUEPRINT,
+                indexmap! {
+                    PriceFeedField::State.field_index() => FieldValue::new(PriceFeedStateFieldPayload::from_content_source(substate)),
+                },
+            )?
+        };
+
+        api.globalize(
+            object_id,
+            indexmap!(
+                AttachedModuleId::RoleAssignment => role_assignment.0,
+                AttachedModuleId::Metadata => metadata.0,
+                AttachedModuleId::Royalty => royalty.0,
+            ),
+            Some(address_reservation),
+        )?;
+
+        Ok((
+            Global::new(ComponentAddress::new_or_panic(address.as_node_id().0)),
+            publisher_badges,
+        ))
+    }
+
+    pub fn publish_price<Y>(
+        base: ResourceAddress,
+        quote: ResourceAddress,
+        price: Decimal,
+        api: &mut Y,
+    ) -> Result<PriceFeedPublishPriceOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !price.is_positive() {
+            return Err(PriceFeedError::InvalidPrice { price }.into());
+        }
+
+        let published_at = Runtime::current_time(api, TimePrecision::Second)?;
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            PriceFeedField::State.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate = api
+            .field_read_typed::<VersionedPriceFeedState>(handle)?
+            .into_latest();
+        substate.prices.insert(
+            PricePair { base, quote },
+            PublishedPrice {
+                price,
+                published_at,
+            },
+        );
+        api.field_write_typed(
+            handle,
+            &PriceFeedStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+
+        Ok(())
+    }
+
+    pub fn get_price<Y>(
+        base: ResourceAddress,
+        quote: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<PriceFeedGetPriceOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            PriceFeedField::State.into(),
+            LockFlags::read_only(),
+        )?;
+        let substate = api
+            .field_read_typed::<VersionedPriceFeedState>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+
+        let published_price = substate
+            .prices
+            .get(&PricePair { base, quote })
+            .copied()
+            .ok_or(PriceFeedError::PriceNotAvailable { base, quote })?;
+
+        let expires_at = published_price
+            .published_at
+            .add_seconds(substate.max_price_age_seconds)
+            .ok_or(PriceFeedError::TimeOverflow)?;
+        let current_time = Runtime::current_time(api, TimePrecision::Second)?;
+        if current_time.compare(expires_at, TimeComparisonOperator::Gt) {
+            return Err(PriceFeedError::StalePrice {
+                base,
+                quote,
+                published_at: published_price.published_at,
+            }
+            .into());
+        }
+
+        Ok((published_price.price, published_price.published_at))
+    }
+}
@@ -0,0 +1,5 @@
+mod blueprint;
+mod package;
+
+pub use blueprint::*;
+pub use package::*;
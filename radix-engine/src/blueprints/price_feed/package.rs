@@ -0,0 +1,156 @@
+use super::*;
+use crate::internal_prelude::*;
+use crate::native_exports;
+use radix_engine_interface::blueprints::oracle::*;
+use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::prelude::*;
+
+native_exports! {
+    pub enum PriceFeedNativeExport {
+        Instantiate => PRICE_FEED_INSTANTIATE_EXPORT_NAME,
+        PublishPrice => PRICE_FEED_PUBLISH_PRICE_EXPORT_NAME,
+        GetPrice => PRICE_FEED_GET_PRICE_EXPORT_NAME,
+    }
+}
+
+pub struct PriceFeedNativePackage;
+
+impl PriceFeedNativePackage {
+    pub fn definition() -> PackageDefinition {
+        let blueprints = indexmap!(
+            PRICE_FEED_BLUEPRINT.to_string() => Self::price_feed_blueprint_definition()
+        );
+
+        PackageDefinition { blueprints }
+    }
+
+    pub fn invoke_export<Y>(
+        export_name: &str,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match PriceFeedNativeExport::from_export_name(export_name)? {
+            PriceFeedNativeExport::Instantiate => {
+                let PriceFeedInstantiateInput {
+                    owner_role,
+                    max_price_age_seconds,
+                    publisher_badge_amount,
+                    address_reservation,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PriceFeedBlueprint::instantiate(
+                    owner_role,
+                    max_price_age_seconds,
+                    publisher_badge_amount,
+                    address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PriceFeedNativeExport::PublishPrice => {
+                let PriceFeedPublishPriceInput { base, quote, price } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = PriceFeedBlueprint::publish_price(base, quote, price, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PriceFeedNativeExport::GetPrice => {
+                let PriceFeedGetPriceInput { base, quote } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PriceFeedBlueprint::get_price(base, quote, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+        }
+    }
+
+    fn price_feed_blueprint_definition() -> BlueprintDefinitionInit {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let feature_set = PriceFeedFeatureSet::all_features();
+        let state = PriceFeedStateSchemaInit::create_schema_init(&mut aggregator);
+        let mut functions = index_map_new();
+
+        functions.insert(
+            PRICE_FEED_INSTANTIATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PriceFeedInstantiateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PriceFeedInstantiateOutput>(),
+                ),
+                export: PRICE_FEED_INSTANTIATE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            PRICE_FEED_PUBLISH_PRICE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PriceFeedPublishPriceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PriceFeedPublishPriceOutput>(),
+                ),
+                export: PRICE_FEED_PUBLISH_PRICE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            PRICE_FEED_GET_PRICE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PriceFeedGetPriceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PriceFeedGetPriceOutput>(),
+                ),
+                export: PRICE_FEED_GET_PRICE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        let schema = generate_full_schema(aggregator);
+
+        BlueprintDefinitionInit {
+            blueprint_type: BlueprintType::default(),
+            is_transient: false,
+            dependencies: indexset!(),
+            feature_set,
+
+            schema: BlueprintSchemaInit {
+                generics: vec![],
+                schema,
+                state,
+                events: BlueprintEventSchemaInit::default(),
+                types: BlueprintTypeSchemaInit::default(),
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
+                hooks: BlueprintHooksInit::default(),
+            },
+
+            royalty_config: PackageRoyaltyConfig::default(),
+            auth_config: AuthConfig {
+                function_auth: FunctionAuth::AllowAll,
+                method_auth: MethodAuthTemplate::StaticRoleDefinition(roles_template! {
+                    roles {
+                        PRICE_FEED_PUBLISHER_ROLE;
+                    },
+                    methods {
+                        PRICE_FEED_GET_PRICE_IDENT => MethodAccessibility::Public;
+                        PRICE_FEED_PUBLISH_PRICE_IDENT => [PRICE_FEED_PUBLISHER_ROLE];
+                    }
+                }),
+            },
+        }
+    }
+}
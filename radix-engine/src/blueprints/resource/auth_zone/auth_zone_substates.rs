@@ -8,6 +8,7 @@ pub struct AuthZone {
     // Virtualized resources, note that one cannot create proofs with virtual resources but only be used for AuthZone checks
     pub virtual_resources: BTreeSet<ResourceAddress>,
     pub virtual_non_fungibles: BTreeSet<NonFungibleGlobalId>,
+    pub virtual_resource_amounts: BTreeMap<ResourceAddress, Decimal>,
 
     pub local_caller_package_address: Option<PackageAddress>,
     pub global_caller: Option<(GlobalCaller, Reference)>,
@@ -20,6 +21,7 @@ impl AuthZone {
         proofs: Vec<Proof>,
         virtual_resources: BTreeSet<ResourceAddress>,
         virtual_non_fungibles: BTreeSet<NonFungibleGlobalId>,
+        virtual_resource_amounts: BTreeMap<ResourceAddress, Decimal>,
         local_caller_package_address: Option<PackageAddress>,
         global_caller: Option<(GlobalCaller, Reference)>,
         parent: Option<Reference>,
@@ -28,6 +30,7 @@ impl AuthZone {
             proofs,
             virtual_resources,
             virtual_non_fungibles,
+            virtual_resource_amounts,
             local_caller_package_address,
             global_caller,
             parent,
@@ -46,6 +49,10 @@ impl AuthZone {
         &self.virtual_non_fungibles
     }
 
+    pub fn virtual_resource_amounts(&self) -> &BTreeMap<ResourceAddress, Decimal> {
+        &self.virtual_resource_amounts
+    }
+
     pub fn local_virtual_non_fungibles(&self) -> BTreeSet<NonFungibleGlobalId> {
         let mut virtual_proofs = BTreeSet::new();
 
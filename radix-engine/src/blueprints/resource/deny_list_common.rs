@@ -0,0 +1,127 @@
+use crate::blueprints::resource::*;
+use crate::errors::{RuntimeError, SystemError};
+use crate::types::*;
+use native_sdk::runtime::Runtime;
+use radix_engine_interface::api::field_api::LockFlags;
+use radix_engine_interface::api::{
+    ActorStateHandle, ClientApi, CollectionIndex, ACTOR_REF_CALLER, ACTOR_REF_GLOBAL,
+    ACTOR_STATE_OUTER_OBJECT, ACTOR_STATE_SELF,
+};
+
+/// Shared logic for the deny list of holders, which both resource managers keep in a collection
+/// keyed by the global address of the denied holder.
+pub struct DenyListUtil;
+
+impl DenyListUtil {
+    /// Adds a holder to the deny list of the resource manager being called.
+    pub fn deny_holder<Y, P>(
+        collection_index: CollectionIndex,
+        holder: GlobalAddress,
+        entry_payload: &P,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+        P: ScryptoEncode,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            collection_index,
+            &scrypto_encode(&holder).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(handle, entry_payload)?;
+        api.key_value_entry_close(handle)?;
+
+        Runtime::emit_event(api, DenyHolderEvent { holder })
+    }
+
+    /// Removes a holder from the deny list of the resource manager being called.
+    pub fn allow_holder<Y>(
+        collection_index: CollectionIndex,
+        holder: GlobalAddress,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        api.actor_remove_key_value_entry(
+            ACTOR_STATE_SELF,
+            collection_index,
+            &scrypto_encode(&holder).unwrap(),
+        )?;
+
+        Runtime::emit_event(api, AllowHolderEvent { holder })
+    }
+
+    pub fn is_holder_denied<Y>(
+        object_handle: ActorStateHandle,
+        collection_index: CollectionIndex,
+        holder: GlobalAddress,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            object_handle,
+            collection_index,
+            &scrypto_encode(&holder).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let entry = api.key_value_entry_get_typed::<ScryptoValue>(handle)?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(entry.is_some())
+    }
+
+    /// Checks that the global entity holding the vault being called is not on the deny list of
+    /// its resource.
+    ///
+    /// The holder is the global ancestor of the vault. Vaults which don't have one yet, such as
+    /// one created with a bucket before being stored, are held by the global component calling
+    /// them instead, so a denied component can't fill a fresh vault and attach it to itself
+    /// afterwards. Vaults put into from a function, such as those of a component which is still
+    /// being instantiated, are let through as there is nothing to check them against.
+    ///
+    /// This gap is accepted: a denied holder can only receive the resource this way while it is
+    /// being instantiated, and every deposit made after it is globalized is checked against it.
+    pub fn assert_holder_not_denied<Y>(
+        is_deny_listed: bool,
+        collection_index: CollectionIndex,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !is_deny_listed {
+            return Ok(());
+        }
+
+        let holder = match Self::get_holder_node_id(api)? {
+            Some(node_id) => GlobalAddress::new_or_panic(node_id.into()),
+            None => return Ok(()),
+        };
+
+        if Self::is_holder_denied(ACTOR_STATE_OUTER_OBJECT, collection_index, holder, api)? {
+            return Err(VaultError::HolderDenied(holder).into());
+        }
+
+        Ok(())
+    }
+
+    fn get_holder_node_id<Y>(api: &mut Y) -> Result<Option<NodeId>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        for actor_ref in [ACTOR_REF_GLOBAL, ACTOR_REF_CALLER] {
+            match api.actor_get_node_id(actor_ref) {
+                Ok(node_id) => return Ok(Some(node_id)),
+                Err(RuntimeError::SystemError(SystemError::GlobalAddressDoesNotExist)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(None)
+    }
+}
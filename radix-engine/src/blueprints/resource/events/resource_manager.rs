@@ -24,3 +24,18 @@ pub struct MintNonFungibleResourceEvent {
 pub struct BurnNonFungibleResourceEvent {
     pub ids: IndexSet<NonFungibleLocalId>,
 }
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
+pub struct RecallBatchEvent {
+    pub vaults: Vec<(NodeId, Decimal)>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
+pub struct DenyHolderEvent {
+    pub holder: GlobalAddress,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
+pub struct AllowHolderEvent {
+    pub holder: GlobalAddress,
+}
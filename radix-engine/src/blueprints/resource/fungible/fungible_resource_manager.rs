@@ -11,7 +11,9 @@ use num_traits::pow::Pow;
 use radix_engine_interface::api::field_api::LockFlags;
 use radix_engine_interface::api::node_modules::metadata::MetadataInit;
 use radix_engine_interface::api::node_modules::ModuleConfig;
-use radix_engine_interface::api::{ClientApi, FieldValue, GenericArgs, ACTOR_STATE_SELF};
+use radix_engine_interface::api::{
+    ClientApi, FieldValue, GenericArgs, ACTOR_REF_SELF, ACTOR_STATE_SELF,
+};
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::*;
@@ -46,6 +48,14 @@ declare_native_blueprint_state! {
             ident: Burn,
             description: "Enabled if the resource can ever support burning",
         },
+        soulbound: {
+            ident: Soulbound,
+            description: "Enabled if transfers in and out of vaults need the transfer exemptor",
+        },
+        deny_list: {
+            ident: DenyList,
+            description: "Enabled if vaults of holders on the deny list reject deposits",
+        },
     },
     fields: {
         divisibility: {
@@ -62,11 +72,24 @@ declare_native_blueprint_state! {
             condition: Condition::if_feature(FungibleResourceManagerFeature::TrackTotalSupply),
         },
     },
-    collections: {}
+    collections: {
+        denied_holders: KeyValue {
+            entry_ident: DeniedHolder,
+            key_type: {
+                kind: Static,
+                content_type: GlobalAddress,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+    }
 }
 
 pub type FungibleResourceManagerDivisibilityV1 = u8;
 pub type FungibleResourceManagerTotalSupplyV1 = Decimal;
+pub type FungibleResourceManagerDeniedHolderV1 = ();
 
 /// Represents an error when accessing a bucket.
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -77,6 +100,8 @@ pub enum FungibleResourceManagerError {
     DropNonEmptyBucket,
     NotMintable,
     NotBurnable,
+    NotRecallable,
+    InvalidRecallBatchVault(NodeId),
     UnexpectedDecimalComputationError,
 }
 
@@ -114,7 +139,7 @@ fn check_mint_amount(divisibility: u8, amount: Decimal) -> Result<(), RuntimeErr
 
 fn to_features_and_roles(
     track_total_supply: bool,
-    role_init: FungibleResourceRoles,
+    role_init: FungibleResourceRolesV2,
 ) -> (FungibleResourceManagerFeatureSet, RoleAssignmentInit) {
     let mut roles = RoleAssignmentInit::new();
 
@@ -124,6 +149,8 @@ fn to_features_and_roles(
         vault_recall: role_init.recall_roles.is_some(),
         mint: role_init.mint_roles.is_some(),
         burn: role_init.burn_roles.is_some(),
+        soulbound: role_init.soulbound_roles.is_some(),
+        deny_list: role_init.deny_list_roles.is_some(),
     };
 
     roles
@@ -160,6 +187,20 @@ fn to_features_and_roles(
             .to_role_init()
             .data,
     );
+    roles.data.extend(
+        role_init
+            .soulbound_roles
+            .unwrap_or_default()
+            .to_role_init()
+            .data,
+    );
+    roles.data.extend(
+        role_init
+            .deny_list_roles
+            .unwrap_or_default()
+            .to_role_init()
+            .data,
+    );
 
     (features, roles)
 }
@@ -199,6 +240,32 @@ impl FungibleResourceManagerBlueprint {
                 export: FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<FungibleResourceManagerCreateV2Input>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<FungibleResourceManagerCreateV2Output>(),
+                ),
+                export: FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<FungibleResourceManagerCreateWithInitialSupplyV2Input>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<FungibleResourceManagerCreateWithInitialSupplyV2Output>()),
+                export: FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_EXPORT_NAME.to_string(),
+            },
+        );
 
         functions.insert(
             FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT.to_string(),
@@ -256,6 +323,20 @@ impl FungibleResourceManagerBlueprint {
                 export: FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_VAULT_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                    ResourceManagerCreateEmptyRecallProtectedVaultInput,
+                >()),
+                output: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                    ResourceManagerCreateEmptyRecallProtectedVaultOutput,
+                >()),
+                export: FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_EXPORT_NAME
+                    .to_string(),
+            },
+        );
         functions.insert(
             RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_IDENT.to_string(),
             FunctionSchemaInit {
@@ -333,13 +414,74 @@ impl FungibleResourceManagerBlueprint {
                 export: FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            RESOURCE_MANAGER_DENY_HOLDER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ResourceManagerDenyHolderInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ResourceManagerDenyHolderOutput>(),
+                ),
+                export: FUNGIBLE_RESOURCE_MANAGER_DENY_HOLDER_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            RESOURCE_MANAGER_ALLOW_HOLDER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ResourceManagerAllowHolderInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ResourceManagerAllowHolderOutput>(),
+                ),
+                export: FUNGIBLE_RESOURCE_MANAGER_ALLOW_HOLDER_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            RESOURCE_MANAGER_IS_HOLDER_DENIED_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerIsHolderDeniedInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerIsHolderDeniedOutput>(),
+                ),
+                export: FUNGIBLE_RESOURCE_MANAGER_IS_HOLDER_DENIED_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            FUNGIBLE_RESOURCE_MANAGER_RECALL_BATCH_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<FungibleResourceManagerRecallBatchInput>(
+                        ),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<FungibleResourceManagerRecallBatchOutput>(
+                        ),
+                ),
+                export: FUNGIBLE_RESOURCE_MANAGER_RECALL_BATCH_EXPORT_NAME.to_string(),
+            },
+        );
 
         let event_schema = event_schema! {
             aggregator,
             [
                 VaultCreationEvent,
                 MintFungibleResourceEvent,
-                BurnFungibleResourceEvent
+                BurnFungibleResourceEvent,
+                DenyHolderEvent,
+                AllowHolderEvent,
+                RecallBatchEvent
             ]
         };
 
@@ -356,7 +498,10 @@ impl FungibleResourceManagerBlueprint {
                 state,
                 events: event_schema,
                 types: BlueprintTypeSchemaInit::default(),
-                functions: BlueprintFunctionsSchemaInit { functions },
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
                 hooks: BlueprintHooksInit::default(),
             },
             royalty_config: PackageRoyaltyConfig::default(),
@@ -376,6 +521,10 @@ impl FungibleResourceManagerBlueprint {
                         RECALLER_UPDATER_ROLE => updaters: [RECALLER_UPDATER_ROLE];
                         FREEZER_ROLE => updaters: [FREEZER_UPDATER_ROLE];
                         FREEZER_UPDATER_ROLE => updaters: [FREEZER_UPDATER_ROLE];
+                        TRANSFER_EXEMPTOR_ROLE => updaters: [TRANSFER_EXEMPTOR_UPDATER_ROLE];
+                        TRANSFER_EXEMPTOR_UPDATER_ROLE => updaters: [TRANSFER_EXEMPTOR_UPDATER_ROLE];
+                        DENY_LISTER_ROLE => updaters: [DENY_LISTER_UPDATER_ROLE];
+                        DENY_LISTER_UPDATER_ROLE => updaters: [DENY_LISTER_UPDATER_ROLE];
                     },
                     methods {
                         FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT => [MINTER_ROLE];
@@ -383,10 +532,15 @@ impl FungibleResourceManagerBlueprint {
                         RESOURCE_MANAGER_PACKAGE_BURN_IDENT => MethodAccessibility::OwnPackageOnly;
                         RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_CREATE_EMPTY_VAULT_IDENT => MethodAccessibility::Public;
+                        RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_TOTAL_SUPPLY_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_AMOUNT_FOR_WITHDRAWAL_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_DROP_EMPTY_BUCKET_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_RESOURCE_TYPE_IDENT => MethodAccessibility::Public;
+                        RESOURCE_MANAGER_DENY_HOLDER_IDENT => [DENY_LISTER_ROLE];
+                        RESOURCE_MANAGER_ALLOW_HOLDER_IDENT => [DENY_LISTER_ROLE];
+                        RESOURCE_MANAGER_IS_HOLDER_DENIED_IDENT => MethodAccessibility::Public;
+                        FUNGIBLE_RESOURCE_MANAGER_RECALL_BATCH_IDENT => [RECALLER_ROLE];
                     }
                 }),
             },
@@ -397,7 +551,7 @@ impl FungibleResourceManagerBlueprint {
         owner_role: OwnerRole,
         track_total_supply: bool,
         divisibility: u8,
-        resource_roles: FungibleResourceRoles,
+        resource_roles: FungibleResourceRolesV2,
         metadata: ModuleConfig<MetadataInit>,
         address_reservation: Option<GlobalAddressReservation>,
         api: &mut Y,
@@ -431,7 +585,7 @@ impl FungibleResourceManagerBlueprint {
         track_total_supply: bool,
         divisibility: u8,
         initial_supply: Decimal,
-        resource_roles: FungibleResourceRoles,
+        resource_roles: FungibleResourceRolesV2,
         metadata: ModuleConfig<MetadataInit>,
         address_reservation: Option<GlobalAddressReservation>,
         api: &mut Y,
@@ -503,7 +657,7 @@ impl FungibleResourceManagerBlueprint {
         initial_supply: Decimal,
         track_total_supply: bool,
         divisibility: u8,
-        resource_roles: FungibleResourceRoles,
+        resource_roles: FungibleResourceRolesV2,
         api: &mut Y,
     ) -> Result<(NodeId, RoleAssignmentInit), RuntimeError>
     where
@@ -717,6 +871,19 @@ impl FungibleResourceManagerBlueprint {
         Ok(Bucket(Own(bucket_id)))
     }
 
+    pub(crate) fn create_empty_recall_protected_vault<Y>(api: &mut Y) -> Result<Own, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let is_recallable = api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            FungibleResourceManagerFeature::VaultRecall.feature_name(),
+        )?;
+        VaultUtil::ensure_recall_locked(is_recallable, api)?;
+
+        Self::create_empty_vault(api)
+    }
+
     pub(crate) fn create_empty_vault<Y>(api: &mut Y) -> Result<Own, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
@@ -747,6 +914,127 @@ impl FungibleResourceManagerBlueprint {
         Ok(Own(vault_id))
     }
 
+    pub(crate) fn deny_holder<Y>(holder: GlobalAddress, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        DenyListUtil::deny_holder(
+            FungibleResourceManagerCollection::DeniedHolderKeyValue.collection_index(),
+            holder,
+            &FungibleResourceManagerDeniedHolderEntryPayload::from_content_source(()),
+            api,
+        )
+    }
+
+    pub(crate) fn allow_holder<Y>(holder: GlobalAddress, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        DenyListUtil::allow_holder(
+            FungibleResourceManagerCollection::DeniedHolderKeyValue.collection_index(),
+            holder,
+            api,
+        )
+    }
+
+    pub(crate) fn is_holder_denied<Y>(
+        holder: GlobalAddress,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        DenyListUtil::is_holder_denied(
+            ACTOR_STATE_SELF,
+            FungibleResourceManagerCollection::DeniedHolderKeyValue.collection_index(),
+            holder,
+            api,
+        )
+    }
+
+    /// Recalls from many vaults of this resource in a single call. Auth is checked once against
+    /// the recaller role of the resource manager and the recalled amounts are returned as one
+    /// bucket, with a single event listing every vault that was recalled from.
+    pub(crate) fn recall_batch<Y>(
+        vaults: Vec<(Reference, Decimal)>,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            FungibleResourceManagerFeature::VaultRecall.feature_name(),
+        )? {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::FungibleResourceManagerError(
+                    FungibleResourceManagerError::NotRecallable,
+                ),
+            ));
+        }
+
+        let divisibility = {
+            let divisibility_handle = api.actor_open_field(
+                ACTOR_STATE_SELF,
+                FungibleResourceManagerField::Divisibility.into(),
+                LockFlags::read_only(),
+            )?;
+            let divisibility: FungibleResourceManagerDivisibilityFieldPayload =
+                api.field_read_typed(divisibility_handle)?;
+            divisibility.into_latest()
+        };
+        let resource_manager = api.actor_get_node_id(ACTOR_REF_SELF)?;
+        let vault_blueprint_id = BlueprintId::new(&RESOURCE_PACKAGE, FUNGIBLE_VAULT_BLUEPRINT);
+
+        let mut total = Decimal::ZERO;
+        let mut recalled = Vec::with_capacity(vaults.len());
+        for (vault, amount) in vaults {
+            let vault_id = vault.0;
+
+            if !check_fungible_amount(&amount, divisibility) {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::FungibleResourceManagerError(
+                        FungibleResourceManagerError::InvalidAmount(amount, divisibility),
+                    ),
+                ));
+            }
+
+            // The vault method is only guarded by package, so ensure the vault actually
+            // holds this resource before taking from it
+            if api.get_blueprint_id(&vault_id)? != vault_blueprint_id
+                || api.get_outer_object(&vault_id)?.as_node_id() != &resource_manager
+            {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::FungibleResourceManagerError(
+                        FungibleResourceManagerError::InvalidRecallBatchVault(vault_id),
+                    ),
+                ));
+            }
+
+            let rtn = api.call_direct_access_method(
+                &vault_id,
+                FUNGIBLE_VAULT_BATCH_RECALL_IDENT,
+                scrypto_encode(&FungibleVaultBatchRecallInput { amount }).unwrap(),
+            )?;
+            let taken: FungibleVaultBatchRecallOutput = scrypto_decode(&rtn).unwrap();
+
+            total = total
+                .checked_add(taken)
+                .ok_or(RuntimeError::ApplicationError(
+                    ApplicationError::FungibleResourceManagerError(
+                        FungibleResourceManagerError::UnexpectedDecimalComputationError,
+                    ),
+                ))?;
+            recalled.push((vault_id, taken));
+        }
+
+        let bucket = Self::create_bucket(total, api)?;
+
+        Runtime::emit_event(api, RecallBatchEvent { vaults: recalled })?;
+
+        Ok(bucket)
+    }
+
     pub(crate) fn get_resource_type<Y>(api: &mut Y) -> Result<ResourceType, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
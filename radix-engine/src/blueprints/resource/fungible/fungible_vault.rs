@@ -39,12 +39,25 @@ declare_native_blueprint_state! {
             condition: Condition::if_outer_feature(FungibleResourceManagerFeature::VaultFreeze),
         },
     },
-    collections: {}
+    collections: {
+        allowances: KeyValue {
+            entry_ident: Allowance,
+            key_type: {
+                kind: Static,
+                content_type: ResourceOrNonFungible,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+    }
 }
 
 type FungibleVaultBalanceV1 = LiquidFungibleResource;
 type FungibleVaultLockedBalanceV1 = LockedFungibleResource;
 type FungibleVaultFreezeStatusV1 = VaultFrozenFlag;
+type FungibleVaultAllowanceV1 = WithdrawAllowance;
 
 pub struct FungibleVaultBlueprint;
 
@@ -119,6 +132,21 @@ impl FungibleVaultBlueprint {
                 export: FUNGIBLE_VAULT_LOCK_FEE_IDENT.to_string(),
             },
         );
+        functions.insert(
+            FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<FungibleVaultLockFeeWithRefundInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<FungibleVaultLockFeeWithRefundOutput>(),
+                ),
+                export: FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT.to_string(),
+            },
+        );
         functions.insert(
             VAULT_RECALL_IDENT.to_string(),
             FunctionSchemaInit {
@@ -135,6 +163,22 @@ impl FungibleVaultBlueprint {
                 export: FUNGIBLE_VAULT_RECALL_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            FUNGIBLE_VAULT_BATCH_RECALL_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo {
+                    receiver: Receiver::SelfRefMut,
+                    ref_types: RefTypes::DIRECT_ACCESS,
+                }),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<FungibleVaultBatchRecallInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<FungibleVaultBatchRecallOutput>(),
+                ),
+                export: FUNGIBLE_VAULT_BATCH_RECALL_EXPORT_NAME.to_string(),
+            },
+        );
         functions.insert(
             VAULT_FREEZE_IDENT.to_string(),
             FunctionSchemaInit {
@@ -226,6 +270,61 @@ impl FungibleVaultBlueprint {
                 export: FUNGIBLE_VAULT_BURN_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            VAULT_GRANT_ALLOWANCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultGrantAllowanceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultGrantAllowanceOutput>(),
+                ),
+                export: FUNGIBLE_VAULT_GRANT_ALLOWANCE_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            VAULT_REVOKE_ALLOWANCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultRevokeAllowanceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultRevokeAllowanceOutput>(),
+                ),
+                export: FUNGIBLE_VAULT_REVOKE_ALLOWANCE_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            VAULT_GET_ALLOWANCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultGetAllowanceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultGetAllowanceOutput>(),
+                ),
+                export: FUNGIBLE_VAULT_GET_ALLOWANCE_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            VAULT_TAKE_WITH_ALLOWANCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo {
+                    receiver: Receiver::SelfRefMut,
+                    ref_types: RefTypes::DIRECT_ACCESS,
+                }),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultTakeWithAllowanceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultTakeWithAllowanceOutput>(),
+                ),
+                export: FUNGIBLE_VAULT_TAKE_WITH_ALLOWANCE_EXPORT_NAME.to_string(),
+            },
+        );
 
         let event_schema = event_schema! {
             aggregator,
@@ -253,7 +352,10 @@ impl FungibleVaultBlueprint {
                 state,
                 events: event_schema,
                 types: BlueprintTypeSchemaInit::default(),
-                functions: BlueprintFunctionsSchemaInit { functions },
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
                 hooks: BlueprintHooksInit::default(),
             },
             royalty_config: PackageRoyaltyConfig::default(),
@@ -269,11 +371,17 @@ impl FungibleVaultBlueprint {
                         VAULT_TAKE_IDENT => [WITHDRAWER_ROLE];
                         VAULT_TAKE_ADVANCED_IDENT => [WITHDRAWER_ROLE];
                         FUNGIBLE_VAULT_LOCK_FEE_IDENT => [WITHDRAWER_ROLE];
+                        FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT => [WITHDRAWER_ROLE];
                         VAULT_RECALL_IDENT => [RECALLER_ROLE];
                         VAULT_PUT_IDENT => [DEPOSITOR_ROLE];
                         VAULT_BURN_IDENT => [BURNER_ROLE];
+                        VAULT_GRANT_ALLOWANCE_IDENT => [WITHDRAWER_ROLE];
+                        VAULT_REVOKE_ALLOWANCE_IDENT => [WITHDRAWER_ROLE];
+                        VAULT_GET_ALLOWANCE_IDENT => MethodAccessibility::Public;
+                        VAULT_TAKE_WITH_ALLOWANCE_IDENT => MethodAccessibility::Public;
                         FUNGIBLE_VAULT_LOCK_FUNGIBLE_AMOUNT_IDENT => MethodAccessibility::OwnPackageOnly;
                         FUNGIBLE_VAULT_UNLOCK_FUNGIBLE_AMOUNT_IDENT => MethodAccessibility::OwnPackageOnly;
+                        FUNGIBLE_VAULT_BATCH_RECALL_IDENT => MethodAccessibility::OwnPackageOnly;
                     },
                 }),
             },
@@ -312,6 +420,7 @@ impl FungibleVaultBlueprint {
         Y: ClientApi<RuntimeError>,
     {
         Self::assert_not_frozen(VaultFreezeFlags::WITHDRAW, api)?;
+        Self::assert_transfer_exempt(api)?;
 
         // Apply withdraw strategy
         let taken = {
@@ -349,6 +458,8 @@ impl FungibleVaultBlueprint {
         Y: ClientApi<RuntimeError>,
     {
         Self::assert_not_frozen(VaultFreezeFlags::DEPOSIT, api)?;
+        Self::assert_transfer_exempt(api)?;
+        Self::assert_holder_not_denied(api)?;
 
         // This will fail if bucket is not an inner object of the current fungible resource
         let other_bucket = drop_fungible_bucket(bucket.0.as_node_id(), api)?;
@@ -374,6 +485,41 @@ impl FungibleVaultBlueprint {
     }
 
     pub fn lock_fee<Y>(amount: Decimal, contingent: bool, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::lock_fee_internal(amount, contingent, None, api)
+    }
+
+    pub fn lock_fee_with_refund<Y>(
+        amount: Decimal,
+        contingent: bool,
+        refund_vault_id: NodeId,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        // The unused fee is deposited at settlement without calling into the refund vault,
+        // so it has to be checked to be an XRD vault up front
+        if api.get_blueprint_id(&refund_vault_id)?
+            != BlueprintId::new(&RESOURCE_PACKAGE, FUNGIBLE_VAULT_BLUEPRINT)
+            || api.get_outer_object(&refund_vault_id)?.as_node_id() != XRD.as_node_id()
+        {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::InvalidFeeRefundVault(refund_vault_id)),
+            ));
+        }
+
+        Self::lock_fee_internal(amount, contingent, Some(refund_vault_id), api)
+    }
+
+    fn lock_fee_internal<Y>(
+        amount: Decimal,
+        contingent: bool,
+        refund_vault_id: Option<NodeId>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
@@ -435,7 +581,10 @@ impl FungibleVaultBlueprint {
         // At this point the vault fee take is guaranteed to be force-written
         // so we must take care not to error out before crediting the cost units
         // and emitting an event
-        api.lock_fee(fee, contingent);
+        match refund_vault_id {
+            Some(refund_vault_id) => api.lock_fee_with_refund(fee, contingent, refund_vault_id),
+            None => api.lock_fee(fee, contingent),
+        }
 
         Ok(())
     }
@@ -462,6 +611,18 @@ impl FungibleVaultBlueprint {
         Ok(bucket)
     }
 
+    /// Only callable by the resource manager as part of a batched recall. The recall feature,
+    /// amount divisibility and auth are checked once by the caller, and the resource manager
+    /// emits a single event covering every vault, so this only moves the balance out.
+    pub fn batch_recall<Y>(amount: Decimal, api: &mut Y) -> Result<Decimal, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let taken = Self::internal_take(amount, api)?;
+
+        Ok(taken.amount())
+    }
+
     pub fn freeze<Y>(to_freeze: VaultFreezeFlags, api: &mut Y) -> Result<(), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
@@ -556,6 +717,109 @@ impl FungibleVaultBlueprint {
         Ok(())
     }
 
+    pub fn grant_allowance<Y>(
+        grantee: ResourceOrNonFungible,
+        allowance: WithdrawAllowance,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match allowance.limit {
+            AllowanceLimit::Amount(amount) if !amount.is_negative() => {}
+            _ => return Err(VaultError::InvalidAllowance(allowance).into()),
+        }
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            FungibleVaultCollection::AllowanceKeyValue.collection_index(),
+            &scrypto_encode(&grantee).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            handle,
+            &FungibleVaultAllowanceEntryPayload::from_content_source(allowance),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(())
+    }
+
+    pub fn revoke_allowance<Y>(
+        grantee: ResourceOrNonFungible,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        api.actor_remove_key_value_entry(
+            ACTOR_STATE_SELF,
+            FungibleVaultCollection::AllowanceKeyValue.collection_index(),
+            &scrypto_encode(&grantee).unwrap(),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn get_allowance<Y>(
+        grantee: ResourceOrNonFungible,
+        api: &mut Y,
+    ) -> Result<Option<WithdrawAllowance>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            FungibleVaultCollection::AllowanceKeyValue.collection_index(),
+            &scrypto_encode(&grantee).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let allowance = api
+            .key_value_entry_get_typed::<FungibleVaultAllowanceEntryPayload>(handle)?
+            .map(|allowance| allowance.into_latest());
+        api.key_value_entry_close(handle)?;
+
+        Ok(allowance)
+    }
+
+    /// Withdraws on behalf of a grantee, who must present their badge, deducting the amount
+    /// from their allowance.
+    pub fn take_with_allowance<Y>(
+        grantee: ResourceOrNonFungible,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Runtime::assert_access_rule(
+            AccessRule::Protected(AccessRuleNode::ProofRule(ProofRule::Require(grantee.clone()))),
+            api,
+        )?;
+        let current_epoch = Runtime::current_epoch(api)?;
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            FungibleVaultCollection::AllowanceKeyValue.collection_index(),
+            &scrypto_encode(&grantee).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut allowance = match api
+            .key_value_entry_get_typed::<FungibleVaultAllowanceEntryPayload>(handle)?
+        {
+            Some(allowance) => allowance.into_latest(),
+            None => return Err(VaultError::NoAllowance(grantee).into()),
+        };
+        VaultUtil::spend_allowance(&mut allowance, amount, None, current_epoch)?;
+        api.key_value_entry_set_typed(
+            handle,
+            &FungibleVaultAllowanceEntryPayload::from_content_source(allowance),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Self::take(&amount, api)
+    }
+
     //===================
     // Protected methods
     //===================
@@ -665,6 +929,32 @@ impl FungibleVaultBlueprint {
         Ok(())
     }
 
+    fn assert_transfer_exempt<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let is_soulbound = api.actor_is_feature_enabled(
+            ACTOR_STATE_OUTER_OBJECT,
+            FungibleResourceManagerFeature::Soulbound.feature_name(),
+        )?;
+        VaultUtil::assert_transfer_exempt(is_soulbound, api)
+    }
+
+    fn assert_holder_not_denied<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let is_deny_listed = api.actor_is_feature_enabled(
+            ACTOR_STATE_OUTER_OBJECT,
+            FungibleResourceManagerFeature::DenyList.feature_name(),
+        )?;
+        DenyListUtil::assert_holder_not_denied(
+            is_deny_listed,
+            FungibleResourceManagerCollection::DeniedHolderKeyValue.collection_index(),
+            api,
+        )
+    }
+
     fn assert_freezable<Y>(api: &mut Y) -> Result<(), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
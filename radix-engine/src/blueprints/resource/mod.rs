@@ -1,5 +1,6 @@
 mod auth_zone;
 mod bucket_common;
+mod deny_list_common;
 mod events;
 mod fungible;
 mod non_fungible;
@@ -10,6 +11,7 @@ mod worktop;
 
 pub use auth_zone::*;
 pub use bucket_common::*;
+pub use deny_list_common::*;
 pub use events::*;
 pub use fungible::*;
 pub use non_fungible::*;
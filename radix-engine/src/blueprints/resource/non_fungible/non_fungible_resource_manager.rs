@@ -5,6 +5,7 @@ use crate::internal_prelude::*;
 use crate::types::*;
 use core::ops::Neg;
 use native_sdk::component::{globalize_object, globalize_object_with_inner_object_and_event};
+use native_sdk::resource::{NativeBucket, NativeVault, ResourceManager};
 use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::field_api::LockFlags;
 use radix_engine_interface::api::node_modules::metadata::MetadataInit;
@@ -46,6 +47,22 @@ declare_native_blueprint_state! {
             ident: Burn,
             description: "Enabled if the resource can ever support burning",
         },
+        track_collection: {
+            ident: TrackCollection,
+            description: "Enabled if the resource is an NFT collection, which records its mint order",
+        },
+        soulbound: {
+            ident: Soulbound,
+            description: "Enabled if transfers in and out of vaults need the transfer exemptor",
+        },
+        transfer_royalty: {
+            ident: TransferRoyalty,
+            description: "Enabled if transfers out of vaults can be charged a royalty",
+        },
+        deny_list: {
+            ident: DenyList,
+            description: "Enabled if vaults of holders on the deny list reject deposits",
+        },
     },
     fields: {
         id_type: {
@@ -67,6 +84,34 @@ declare_native_blueprint_state! {
             },
             condition: Condition::if_feature(NonFungibleResourceManagerFeature::TrackTotalSupply),
         },
+        total_minted: {
+            ident: TotalMinted,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(NonFungibleResourceManagerFeature::TrackCollection),
+        },
+        transfer_royalty: {
+            ident: TransferRoyalty,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(NonFungibleResourceManagerFeature::TransferRoyalty),
+        },
+        next_reservable_id: {
+            ident: NextReservableId,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(NonFungibleResourceManagerFeature::Mint),
+        },
+        supply_recount: {
+            ident: SupplyRecount,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(NonFungibleResourceManagerFeature::TrackCollection),
+        },
     },
     collections: {
         data: KeyValue {
@@ -81,11 +126,72 @@ declare_native_blueprint_state! {
             },
             allow_ownership: false,
         },
+        minted_ids: KeyValue {
+            entry_ident: MintedId,
+            key_type: {
+                kind: Static,
+                content_type: u64,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+        royalty_vaults: KeyValue {
+            entry_ident: RoyaltyVault,
+            key_type: {
+                kind: Static,
+                content_type: ResourceAddress,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: true,
+        },
+        denied_holders: KeyValue {
+            entry_ident: DeniedHolder,
+            key_type: {
+                kind: Static,
+                content_type: GlobalAddress,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+        id_reservations: KeyValue {
+            entry_ident: IdReservation,
+            key_type: {
+                kind: Static,
+                content_type: u64,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
     }
 }
 
 pub type NonFungibleResourceManagerIdTypeV1 = NonFungibleIdType;
 pub type NonFungibleResourceManagerTotalSupplyV1 = Decimal;
+pub type NonFungibleResourceManagerTotalMintedV1 = u64;
+pub type NonFungibleResourceManagerMintedIdV1 = NonFungibleLocalId;
+pub type NonFungibleResourceManagerTransferRoyaltyV1 = Option<TransferRoyaltyConfig>;
+pub type NonFungibleResourceManagerRoyaltyVaultV1 = Vault;
+pub type NonFungibleResourceManagerDeniedHolderV1 = ();
+/// The first integer id a new reservation may hand out. Kept above every integer id minted so far.
+pub type NonFungibleResourceManagerNextReservableIdV1 = u64;
+/// The exclusive end of a reservation, keyed by its start.
+pub type NonFungibleResourceManagerIdReservationV1 = u64;
+/// How far an unfinished total supply recount has got through the mint order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerSupplyRecountV1 {
+    /// The next position in mint order to look at
+    pub next_index: u64,
+    /// The number of non-fungibles before `next_index` which have not been burnt
+    pub live_count: u64,
+}
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct NonFungibleResourceManagerMutableFieldsV1 {
     pub mutable_field_index: IndexMap<String, usize>,
@@ -105,6 +211,15 @@ pub enum NonFungibleResourceManagerError {
     NotMintable,
     NotBurnable,
     UnexpectedDecimalComputationError,
+    NotACollection,
+    TransferRoyaltyNotEnabled,
+    InvalidTransferRoyalty(TransferRoyaltyConfig),
+    TransferRoyaltyPaidInWrongResource(ResourceAddress),
+    InsufficientTransferRoyalty { required: Decimal, paid: Decimal },
+    InvalidIdReservationCount(u64),
+    IdReservationNotFound(NonFungibleIdReservation),
+    NonFungibleLocalIdNotReserved(NonFungibleLocalId),
+    TotalSupplyNotTracked,
 }
 
 /// Represents an error when accessing a bucket.
@@ -175,6 +290,18 @@ where
     Ok(ids)
 }
 
+/// The id a new reservation should start from so that it cannot hand out any of the given ids.
+pub(crate) fn next_reservable_id_after<'a>(
+    ids: impl Iterator<Item = &'a NonFungibleLocalId>,
+    current: u64,
+) -> u64 {
+    ids.filter_map(|id| match id {
+        NonFungibleLocalId::Integer(id) => Some(id.value().saturating_add(1)),
+        _ => None,
+    })
+    .fold(current, u64::max)
+}
+
 pub struct NonFungibleResourceManagerBlueprint;
 
 impl NonFungibleResourceManagerBlueprint {
@@ -221,6 +348,56 @@ impl NonFungibleResourceManagerBlueprint {
                 export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_IDENT.to_string(),
             },
         );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<NonFungibleResourceManagerCreateV2Input>(
+                        ),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<NonFungibleResourceManagerCreateV2Output>(
+                        ),
+                ),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateWithInitialSupplyV2Input>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateWithInitialSupplyV2Output>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_V2_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateRuidWithInitialSupplyV2Input>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateRuidWithInitialSupplyV2Output>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_V2_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_COLLECTION_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateCollectionInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateCollectionOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_COLLECTION_IDENT.to_string(),
+            },
+        );
 
         functions.insert(
             NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT.to_string(),
@@ -276,6 +453,83 @@ impl NonFungibleResourceManagerBlueprint {
                 export: NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT.to_string(),
             },
         );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_MINTED_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerGetTotalMintedInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerGetTotalMintedOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_MINTED_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_MINTED_IDS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerGetMintedIdsInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerGetMintedIdsOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_GET_MINTED_IDS_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_RECOUNT_TOTAL_SUPPLY_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerRecountTotalSupplyInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerRecountTotalSupplyOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_RECOUNT_TOTAL_SUPPLY_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_SET_TRANSFER_ROYALTY_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerSetTransferRoyaltyInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerSetTransferRoyaltyOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_SET_TRANSFER_ROYALTY_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_TRANSFER_ROYALTY_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerGetTransferRoyaltyInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerGetTransferRoyaltyOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_GET_TRANSFER_ROYALTY_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_CLAIM_TRANSFER_ROYALTIES_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerClaimTransferRoyaltiesInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerClaimTransferRoyaltiesOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_CLAIM_TRANSFER_ROYALTIES_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_COLLECT_TRANSFER_ROYALTY_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCollectTransferRoyaltyInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCollectTransferRoyaltyOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_COLLECT_TRANSFER_ROYALTY_IDENT.to_string(),
+            },
+        );
 
         functions.insert(
             NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT.to_string(),
@@ -305,6 +559,28 @@ impl NonFungibleResourceManagerBlueprint {
                 export: NON_FUNGIBLE_RESOURCE_MANAGER_MINT_SINGLE_RUID_IDENT.to_string(),
             },
         );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_RESERVE_INTEGER_IDS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerReserveIntegerIdsInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerReserveIntegerIdsOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_RESERVE_INTEGER_IDS_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RESERVED_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerMintReservedInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerMintReservedOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RESERVED_IDENT.to_string(),
+            },
+        );
 
         functions.insert(
             RESOURCE_MANAGER_PACKAGE_BURN_IDENT.to_string(),
@@ -347,6 +623,20 @@ impl NonFungibleResourceManagerBlueprint {
                 export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_VAULT_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                    ResourceManagerCreateEmptyRecallProtectedVaultInput,
+                >()),
+                output: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                    ResourceManagerCreateEmptyRecallProtectedVaultOutput,
+                >()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_EXPORT_NAME
+                    .to_string(),
+            },
+        );
         functions.insert(
             RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_IDENT.to_string(),
             FunctionSchemaInit {
@@ -424,13 +714,56 @@ impl NonFungibleResourceManagerBlueprint {
                 export: NON_FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            RESOURCE_MANAGER_DENY_HOLDER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ResourceManagerDenyHolderInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ResourceManagerDenyHolderOutput>(),
+                ),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_DENY_HOLDER_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            RESOURCE_MANAGER_ALLOW_HOLDER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ResourceManagerAllowHolderInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ResourceManagerAllowHolderOutput>(),
+                ),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_ALLOW_HOLDER_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            RESOURCE_MANAGER_IS_HOLDER_DENIED_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerIsHolderDeniedInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerIsHolderDeniedOutput>(),
+                ),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_IS_HOLDER_DENIED_EXPORT_NAME.to_string(),
+            },
+        );
 
         let event_schema = event_schema! {
             aggregator,
             [
                 VaultCreationEvent,
                 MintNonFungibleResourceEvent,
-                BurnNonFungibleResourceEvent
+                BurnNonFungibleResourceEvent,
+                DenyHolderEvent,
+                AllowHolderEvent
             ]
         };
 
@@ -447,7 +780,10 @@ impl NonFungibleResourceManagerBlueprint {
                 state,
                 events: event_schema,
                 types: BlueprintTypeSchemaInit::default(),
-                functions: BlueprintFunctionsSchemaInit { functions },
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
                 hooks: BlueprintHooksInit::default(),
             },
 
@@ -468,6 +804,12 @@ impl NonFungibleResourceManagerBlueprint {
                         RECALLER_UPDATER_ROLE => updaters: [RECALLER_UPDATER_ROLE];
                         FREEZER_ROLE => updaters: [FREEZER_UPDATER_ROLE];
                         FREEZER_UPDATER_ROLE => updaters: [FREEZER_UPDATER_ROLE];
+                        TRANSFER_EXEMPTOR_ROLE => updaters: [TRANSFER_EXEMPTOR_UPDATER_ROLE];
+                        TRANSFER_EXEMPTOR_UPDATER_ROLE => updaters: [TRANSFER_EXEMPTOR_UPDATER_ROLE];
+                        ROYALTY_EXEMPTOR_ROLE => updaters: [ROYALTY_EXEMPTOR_UPDATER_ROLE];
+                        ROYALTY_EXEMPTOR_UPDATER_ROLE => updaters: [ROYALTY_EXEMPTOR_UPDATER_ROLE];
+                        DENY_LISTER_ROLE => updaters: [DENY_LISTER_UPDATER_ROLE];
+                        DENY_LISTER_UPDATER_ROLE => updaters: [DENY_LISTER_UPDATER_ROLE];
                         NON_FUNGIBLE_DATA_UPDATER_ROLE => updaters: [NON_FUNGIBLE_DATA_UPDATER_UPDATER_ROLE];
                         NON_FUNGIBLE_DATA_UPDATER_UPDATER_ROLE => updaters: [NON_FUNGIBLE_DATA_UPDATER_UPDATER_ROLE];
                     },
@@ -475,17 +817,30 @@ impl NonFungibleResourceManagerBlueprint {
                         NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT => [MINTER_ROLE];
                         NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT => [MINTER_ROLE];
                         NON_FUNGIBLE_RESOURCE_MANAGER_MINT_SINGLE_RUID_IDENT => [MINTER_ROLE];
+                        NON_FUNGIBLE_RESOURCE_MANAGER_RESERVE_INTEGER_IDS_IDENT => [MINTER_ROLE];
+                        NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RESERVED_IDENT => [MINTER_ROLE];
                         RESOURCE_MANAGER_BURN_IDENT => [BURNER_ROLE];
                         RESOURCE_MANAGER_PACKAGE_BURN_IDENT => MethodAccessibility::OwnPackageOnly;
                         NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_IDENT => [NON_FUNGIBLE_DATA_UPDATER_ROLE];
                         RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_CREATE_EMPTY_VAULT_IDENT => MethodAccessibility::Public;
+                        RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_TOTAL_SUPPLY_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_AMOUNT_FOR_WITHDRAWAL_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_DROP_EMPTY_BUCKET_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_RESOURCE_TYPE_IDENT => MethodAccessibility::Public;
+                        RESOURCE_MANAGER_DENY_HOLDER_IDENT => [DENY_LISTER_ROLE];
+                        RESOURCE_MANAGER_ALLOW_HOLDER_IDENT => [DENY_LISTER_ROLE];
+                        RESOURCE_MANAGER_IS_HOLDER_DENIED_IDENT => MethodAccessibility::Public;
                         NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_IDENT => MethodAccessibility::Public;
                         NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT => MethodAccessibility::Public;
+                        NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_MINTED_IDENT => MethodAccessibility::Public;
+                        NON_FUNGIBLE_RESOURCE_MANAGER_GET_MINTED_IDS_IDENT => MethodAccessibility::Public;
+                        NON_FUNGIBLE_RESOURCE_MANAGER_RECOUNT_TOTAL_SUPPLY_IDENT => [OWNER_ROLE];
+                        NON_FUNGIBLE_RESOURCE_MANAGER_SET_TRANSFER_ROYALTY_IDENT => [OWNER_ROLE];
+                        NON_FUNGIBLE_RESOURCE_MANAGER_GET_TRANSFER_ROYALTY_IDENT => MethodAccessibility::Public;
+                        NON_FUNGIBLE_RESOURCE_MANAGER_CLAIM_TRANSFER_ROYALTIES_IDENT => [OWNER_ROLE];
+                        NON_FUNGIBLE_RESOURCE_MANAGER_COLLECT_TRANSFER_ROYALTY_IDENT => MethodAccessibility::OwnPackageOnly;
                     }
                 }),
             },
@@ -630,12 +985,51 @@ impl NonFungibleResourceManagerBlueprint {
         Ok(mutable_field_index)
     }
 
+    fn non_fungible_field_names<Y>(
+        schema: &NonFungibleDataSchema,
+        api: &mut Y,
+    ) -> Result<Vec<String>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let child_names = match schema {
+            NonFungibleDataSchema::Local {
+                schema,
+                type_id,
+                ..
+            } => schema
+                .v1()
+                .resolve_type_metadata(*type_id)
+                .and_then(|metadata| metadata.child_names.clone()),
+            NonFungibleDataSchema::Remote { type_id, .. } => {
+                let (schema, scoped_type_id) = api.resolve_blueprint_type(type_id)?;
+                schema
+                    .v1()
+                    .resolve_type_metadata(scoped_type_id.1)
+                    .and_then(|metadata| metadata.child_names.clone())
+            }
+        };
+
+        match child_names {
+            Some(ChildNames::NamedFields(names)) => {
+                Ok(names.iter().map(|name| name.to_string()).collect())
+            }
+            _ => Err(RuntimeError::ApplicationError(
+                ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::InvalidNonFungibleSchema(
+                        InvalidNonFungibleSchema::MissingFieldNames,
+                    ),
+                ),
+            )),
+        }
+    }
+
     pub(crate) fn create<Y>(
         owner_role: OwnerRole,
         id_type: NonFungibleIdType,
         track_total_supply: bool,
         non_fungible_schema: NonFungibleDataSchema,
-        resource_roles: NonFungibleResourceRoles,
+        resource_roles: NonFungibleResourceRolesV2,
         metadata: ModuleConfig<MetadataInit>,
         address_reservation: Option<GlobalAddressReservation>,
         api: &mut Y,
@@ -647,6 +1041,7 @@ impl NonFungibleResourceManagerBlueprint {
             id_type,
             indexmap!(),
             track_total_supply,
+            false,
             non_fungible_schema,
             resource_roles,
             api,
@@ -681,7 +1076,7 @@ impl NonFungibleResourceManagerBlueprint {
         track_total_supply: bool,
         non_fungible_schema: NonFungibleDataSchema,
         entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
-        resource_roles: NonFungibleResourceRoles,
+        resource_roles: NonFungibleResourceRolesV2,
         metadata: ModuleConfig<MetadataInit>,
         address_reservation: Option<GlobalAddressReservation>,
         api: &mut Y,
@@ -704,6 +1099,7 @@ impl NonFungibleResourceManagerBlueprint {
             id_type,
             entries,
             track_total_supply,
+            false,
             non_fungible_schema,
             resource_roles,
             api,
@@ -747,7 +1143,7 @@ impl NonFungibleResourceManagerBlueprint {
         track_total_supply: bool,
         non_fungible_schema: NonFungibleDataSchema,
         entries: Vec<(ScryptoValue,)>,
-        resource_roles: NonFungibleResourceRoles,
+        resource_roles: NonFungibleResourceRolesV2,
         metadata: ModuleConfig<MetadataInit>,
         address_reservation: Option<GlobalAddressReservation>,
         api: &mut Y,
@@ -771,6 +1167,7 @@ impl NonFungibleResourceManagerBlueprint {
             NonFungibleIdType::RUID,
             entries,
             track_total_supply,
+            false,
             non_fungible_schema,
             resource_roles,
             api,
@@ -809,6 +1206,61 @@ impl NonFungibleResourceManagerBlueprint {
         ))
     }
 
+    pub(crate) fn create_collection<Y>(
+        owner_role: OwnerRole,
+        id_type: NonFungibleIdType,
+        non_fungible_schema: NonFungibleDataSchema,
+        collection: NonFungibleCollectionInit,
+        resource_roles: NonFungibleResourceRolesV2,
+        mut metadata: ModuleConfig<MetadataInit>,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<ResourceAddress, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let item_fields = Self::non_fungible_field_names(&non_fungible_schema, api)?;
+
+        // The standard entries override any the caller set under the same keys, so that every
+        // collection can be read in the same way.
+        let init = &mut metadata.init;
+        init.set_metadata(NFT_COLLECTION_NAME_METADATA_KEY, collection.name);
+        init.set_metadata(NFT_COLLECTION_BASE_URL_METADATA_KEY, collection.base_url);
+        init.set_and_lock_metadata(NFT_COLLECTION_ITEM_FIELDS_METADATA_KEY, item_fields);
+
+        let (object_id, roles) = Self::create_object(
+            id_type,
+            indexmap!(),
+            true,
+            true,
+            non_fungible_schema,
+            resource_roles,
+            api,
+        )?;
+
+        let address_reservation = match address_reservation {
+            Some(address_reservation) => address_reservation,
+            None => {
+                let (reservation, _) = api.allocate_global_address(BlueprintId {
+                    package_address: RESOURCE_PACKAGE,
+                    blueprint_name: NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT.to_string(),
+                })?;
+                reservation
+            }
+        };
+
+        let address = globalize_object(
+            object_id,
+            owner_role,
+            address_reservation,
+            roles,
+            metadata,
+            api,
+        )?;
+
+        Ok(ResourceAddress::new_or_panic(address.into()))
+    }
+
     pub(crate) fn mint_non_fungible<Y>(
         entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
         api: &mut Y,
@@ -826,6 +1278,10 @@ impl NonFungibleResourceManagerBlueprint {
             let non_fungibles = entries.into_iter().map(|(k, v)| (k, v.0)).collect();
             create_non_fungibles(resource_address, id_type, non_fungibles, true, api)?
         };
+        Self::record_minted(&ids, api)?;
+        if id_type == NonFungibleIdType::Integer {
+            Self::move_next_reservable_id_past(&ids, api)?;
+        }
 
         let bucket = Self::create_bucket(ids.clone(), api)?;
         Runtime::emit_event(api, MintNonFungibleResourceEvent { ids })?;
@@ -860,6 +1316,7 @@ impl NonFungibleResourceManagerBlueprint {
                 api,
             )?
         };
+        Self::record_minted(&ids, api)?;
 
         let bucket = Self::create_bucket(ids.clone(), api)?;
         Runtime::emit_event(api, MintNonFungibleResourceEvent { ids })?;
@@ -894,6 +1351,7 @@ impl NonFungibleResourceManagerBlueprint {
 
             (id, ids)
         };
+        Self::record_minted(&ids, api)?;
 
         let bucket = Self::create_bucket(ids.clone(), api)?;
         Runtime::emit_event(api, MintNonFungibleResourceEvent { ids })?;
@@ -901,16 +1359,134 @@ impl NonFungibleResourceManagerBlueprint {
         Ok((bucket, id))
     }
 
-    pub(crate) fn update_non_fungible_data<Y>(
-        id: NonFungibleLocalId,
-        field_name: String,
-        data: ScryptoValue,
+    /// Sets aside the next `count` integer ids for a later [`Self::mint_reserved`], so that
+    /// minters can prepare non-fungibles in parallel without racing each other for ids.
+    /// Reserved ids are guarded against other reservations, but not against a plain mint
+    /// which names them explicitly.
+    pub(crate) fn reserve_integer_ids<Y>(
+        count: u64,
         api: &mut Y,
-    ) -> Result<(), RuntimeError>
+    ) -> Result<NonFungibleIdReservation, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
-        let field_index = {
+        Self::assert_mintable(api)?;
+        if Self::assert_is_not_ruid(api)? != NonFungibleIdType::Integer {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::InvalidNonFungibleIdType,
+                ),
+            ));
+        }
+
+        let next_reservable_id_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerField::NextReservableId.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let start = api
+            .field_read_typed::<NonFungibleResourceManagerNextReservableIdFieldPayload>(
+                next_reservable_id_handle,
+            )?
+            .into_latest();
+        let end = match start.checked_add(count) {
+            Some(end) if count > 0 => end,
+            _ => {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::NonFungibleResourceManagerError(
+                        NonFungibleResourceManagerError::InvalidIdReservationCount(count),
+                    ),
+                ));
+            }
+        };
+        api.field_write_typed(
+            next_reservable_id_handle,
+            &NonFungibleResourceManagerNextReservableIdFieldPayload::from_content_source(end),
+        )?;
+        api.field_close(next_reservable_id_handle)?;
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerCollection::IdReservationKeyValue.collection_index(),
+            &scrypto_encode(&start).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            handle,
+            &NonFungibleResourceManagerIdReservationEntryPayload::from_content_source(end),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(NonFungibleIdReservation { start, end })
+    }
+
+    /// Mints non-fungibles whose ids all fall within a reservation made earlier. Ids which were
+    /// already minted are rejected as with a plain mint, so a reservation can be filled across
+    /// several transactions.
+    pub(crate) fn mint_reserved<Y>(
+        reservation: NonFungibleIdReservation,
+        entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_mintable(api)?;
+        let id_type = Self::assert_is_not_ruid(api)?;
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerCollection::IdReservationKeyValue.collection_index(),
+            &scrypto_encode(&reservation.start).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let end = api
+            .key_value_entry_get_typed::<NonFungibleResourceManagerIdReservationEntryPayload>(
+                handle,
+            )?
+            .map(|end| end.into_latest());
+        api.key_value_entry_close(handle)?;
+        if end != Some(reservation.end) {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::IdReservationNotFound(reservation),
+                ),
+            ));
+        }
+        if let Some(id) = entries.keys().find(|id| !reservation.contains(id)) {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::NonFungibleLocalIdNotReserved(id.clone()),
+                ),
+            ));
+        }
+
+        Self::update_total_supply(api, entries.len().into())?;
+
+        let ids = {
+            let resource_address =
+                ResourceAddress::new_or_panic(api.actor_get_node_id(ACTOR_REF_GLOBAL)?.into());
+            let non_fungibles = entries.into_iter().map(|(k, v)| (k, v.0)).collect();
+            create_non_fungibles(resource_address, id_type, non_fungibles, true, api)?
+        };
+        Self::record_minted(&ids, api)?;
+
+        let bucket = Self::create_bucket(ids.clone(), api)?;
+        Runtime::emit_event(api, MintNonFungibleResourceEvent { ids })?;
+
+        Ok(bucket)
+    }
+
+    pub(crate) fn update_non_fungible_data<Y>(
+        id: NonFungibleLocalId,
+        field_name: String,
+        data: ScryptoValue,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let field_index = {
             let data_schema_handle = api.actor_open_field(
                 ACTOR_STATE_SELF,
                 NonFungibleResourceManagerField::MutableFields.into(),
@@ -1073,6 +1649,7 @@ impl NonFungibleResourceManagerBlueprint {
         let other_bucket = drop_non_fungible_bucket(bucket.0.as_node_id(), api)?;
 
         Self::update_total_supply(api, other_bucket.liquid.amount().neg())?;
+        Self::restart_supply_recount(api)?;
 
         Runtime::emit_event(
             api,
@@ -1118,6 +1695,19 @@ impl NonFungibleResourceManagerBlueprint {
         }
     }
 
+    pub(crate) fn create_empty_recall_protected_vault<Y>(api: &mut Y) -> Result<Own, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let is_recallable = api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerFeature::VaultRecall.feature_name(),
+        )?;
+        VaultUtil::ensure_recall_locked(is_recallable, api)?;
+
+        Self::create_empty_vault(api)
+    }
+
     pub(crate) fn create_empty_vault<Y>(api: &mut Y) -> Result<Own, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
@@ -1157,6 +1747,44 @@ impl NonFungibleResourceManagerBlueprint {
         Ok(Own(vault_id))
     }
 
+    pub(crate) fn deny_holder<Y>(holder: GlobalAddress, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        DenyListUtil::deny_holder(
+            NonFungibleResourceManagerCollection::DeniedHolderKeyValue.collection_index(),
+            holder,
+            &NonFungibleResourceManagerDeniedHolderEntryPayload::from_content_source(()),
+            api,
+        )
+    }
+
+    pub(crate) fn allow_holder<Y>(holder: GlobalAddress, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        DenyListUtil::allow_holder(
+            NonFungibleResourceManagerCollection::DeniedHolderKeyValue.collection_index(),
+            holder,
+            api,
+        )
+    }
+
+    pub(crate) fn is_holder_denied<Y>(
+        holder: GlobalAddress,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        DenyListUtil::is_holder_denied(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerCollection::DeniedHolderKeyValue.collection_index(),
+            holder,
+            api,
+        )
+    }
+
     pub(crate) fn get_resource_type<Y>(api: &mut Y) -> Result<ResourceType, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
@@ -1199,12 +1827,355 @@ impl NonFungibleResourceManagerBlueprint {
         }
     }
 
+    pub(crate) fn get_total_minted<Y>(api: &mut Y) -> Result<Option<u64>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerFeature::TrackCollection.feature_name(),
+        )? {
+            let handle = api.actor_open_field(
+                ACTOR_STATE_SELF,
+                NonFungibleResourceManagerField::TotalMinted.into(),
+                LockFlags::read_only(),
+            )?;
+            let total_minted = api
+                .field_read_typed::<NonFungibleResourceManagerTotalMintedFieldPayload>(handle)?
+                .into_latest();
+            api.field_close(handle)?;
+            Ok(Some(total_minted))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub(crate) fn get_minted_non_fungible_ids<Y>(
+        start_index: u64,
+        limit: u32,
+        api: &mut Y,
+    ) -> Result<Vec<NonFungibleLocalId>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let total_minted = match Self::get_total_minted(api)? {
+            Some(total_minted) => total_minted,
+            None => {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::NonFungibleResourceManagerError(
+                        NonFungibleResourceManagerError::NotACollection,
+                    ),
+                ));
+            }
+        };
+
+        let end_index = total_minted.min(start_index.saturating_add(limit.into()));
+        let mut ids = Vec::new();
+        for index in start_index..end_index {
+            let handle = api.actor_open_key_value_entry(
+                ACTOR_STATE_SELF,
+                NonFungibleResourceManagerCollection::MintedIdKeyValue.collection_index(),
+                &scrypto_encode(&index).unwrap(),
+                LockFlags::read_only(),
+            )?;
+            let id = api
+                .key_value_entry_get_typed::<NonFungibleResourceManagerMintedIdEntryPayload>(
+                    handle,
+                )?
+                .expect("Every index below the total minted is recorded")
+                .into_latest();
+            api.key_value_entry_close(handle)?;
+            ids.push(id);
+        }
+
+        Ok(ids)
+    }
+
+    /// Recomputes the total supply of an NFT collection from its mint order, looking at no more
+    /// than `limit` positions per call. The stored total supply is only replaced once the whole
+    /// mint order has been looked at.
+    pub(crate) fn recount_total_supply<Y>(
+        limit: u32,
+        api: &mut Y,
+    ) -> Result<Option<Decimal>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerFeature::TrackTotalSupply.feature_name(),
+        )? {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::TotalSupplyNotTracked,
+                ),
+            ));
+        }
+        let total_minted = match Self::get_total_minted(api)? {
+            Some(total_minted) => total_minted,
+            None => {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::NonFungibleResourceManagerError(
+                        NonFungibleResourceManagerError::NotACollection,
+                    ),
+                ));
+            }
+        };
+
+        let recount_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerField::SupplyRecount.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut recount = api
+            .field_read_typed::<NonFungibleResourceManagerSupplyRecountFieldPayload>(
+                recount_handle,
+            )?
+            .into_latest();
+
+        let end_index = total_minted.min(recount.next_index.saturating_add(limit.into()));
+        for index in recount.next_index..end_index {
+            let handle = api.actor_open_key_value_entry(
+                ACTOR_STATE_SELF,
+                NonFungibleResourceManagerCollection::MintedIdKeyValue.collection_index(),
+                &scrypto_encode(&index).unwrap(),
+                LockFlags::read_only(),
+            )?;
+            let id = api
+                .key_value_entry_get_typed::<NonFungibleResourceManagerMintedIdEntryPayload>(
+                    handle,
+                )?
+                .expect("Every index below the total minted is recorded")
+                .into_latest();
+            api.key_value_entry_close(handle)?;
+
+            let data_handle = api.actor_open_key_value_entry(
+                ACTOR_STATE_SELF,
+                NonFungibleResourceManagerCollection::DataKeyValue.collection_index(),
+                &id.to_key(),
+                LockFlags::read_only(),
+            )?;
+            let exists = api
+                .key_value_entry_get_typed::<NonFungibleResourceManagerDataEntryPayload>(
+                    data_handle,
+                )?
+                .is_some();
+            api.key_value_entry_close(data_handle)?;
+            if exists {
+                recount.live_count += 1;
+            }
+        }
+        recount.next_index = end_index;
+
+        let total_supply = if end_index == total_minted {
+            let total_supply = Decimal::from(recount.live_count);
+            // The field is immutable for resources which can't be minted or burnt, but their
+            // supply can't have drifted either.
+            if Self::get_total_supply(api)? != Some(total_supply) {
+                let total_supply_handle = api.actor_open_field(
+                    ACTOR_STATE_SELF,
+                    NonFungibleResourceManagerField::TotalSupply.into(),
+                    LockFlags::MUTABLE,
+                )?;
+                api.field_write_typed(
+                    total_supply_handle,
+                    &NonFungibleResourceManagerTotalSupplyFieldPayload::from_content_source(
+                        total_supply,
+                    ),
+                )?;
+                api.field_close(total_supply_handle)?;
+            }
+            recount = NonFungibleResourceManagerSupplyRecountV1::default();
+            Some(total_supply)
+        } else {
+            None
+        };
+
+        api.field_write_typed(
+            recount_handle,
+            &NonFungibleResourceManagerSupplyRecountFieldPayload::from_content_source(recount),
+        )?;
+        api.field_close(recount_handle)?;
+
+        Ok(total_supply)
+    }
+
+    pub(crate) fn set_transfer_royalty<Y>(
+        royalty: Option<TransferRoyaltyConfig>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerFeature::TransferRoyalty.feature_name(),
+        )? {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::TransferRoyaltyNotEnabled,
+                ),
+            ));
+        }
+
+        if let Some(royalty) = &royalty {
+            if !royalty.resource_address.is_fungible()
+                || royalty.amount_per_non_fungible.is_negative()
+            {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::NonFungibleResourceManagerError(
+                        NonFungibleResourceManagerError::InvalidTransferRoyalty(royalty.clone()),
+                    ),
+                ));
+            }
+        }
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerField::TransferRoyalty.into(),
+            LockFlags::MUTABLE,
+        )?;
+        api.field_write_typed(
+            handle,
+            &NonFungibleResourceManagerTransferRoyaltyFieldPayload::from_content_source(royalty),
+        )?;
+        api.field_close(handle)?;
+
+        Ok(())
+    }
+
+    pub(crate) fn get_transfer_royalty<Y>(
+        api: &mut Y,
+    ) -> Result<Option<TransferRoyaltyConfig>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerFeature::TransferRoyalty.feature_name(),
+        )? {
+            return Ok(None);
+        }
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerField::TransferRoyalty.into(),
+            LockFlags::read_only(),
+        )?;
+        let royalty = api
+            .field_read_typed::<NonFungibleResourceManagerTransferRoyaltyFieldPayload>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+
+        Ok(royalty)
+    }
+
+    /// Takes everything collected in the given resource, including royalties collected before the
+    /// royalty was last changed to another resource.
+    pub(crate) fn claim_transfer_royalties<Y>(
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerCollection::RoyaltyVaultKeyValue.collection_index(),
+            &scrypto_encode(&resource_address).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let vault = api
+            .key_value_entry_get_typed::<NonFungibleResourceManagerRoyaltyVaultEntryPayload>(
+                handle,
+            )?
+            .map(|vault| vault.into_latest());
+        let bucket = match vault {
+            Some(mut vault) => vault.take_all(api)?,
+            None => ResourceManager(resource_address).new_empty_bucket(api)?,
+        };
+        api.key_value_entry_close(handle)?;
+
+        Ok(bucket)
+    }
+
+    /// Takes the royalty for transferring the given number of non-fungibles out of the payment,
+    /// returning the change. Called by vaults, which have already checked the royalty exemptor.
+    pub(crate) fn collect_transfer_royalty<Y>(
+        amount: Decimal,
+        mut payment: Bucket,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let royalty = match Self::get_transfer_royalty(api)? {
+            Some(royalty) => royalty,
+            None => return Ok(payment),
+        };
+
+        let paid_in = payment.resource_address(api)?;
+        if paid_in != royalty.resource_address {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::TransferRoyaltyPaidInWrongResource(paid_in),
+                ),
+            ));
+        }
+
+        let required = royalty.amount_per_non_fungible.checked_mul(amount).ok_or(
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::UnexpectedDecimalComputationError,
+            )),
+        )?;
+        let paid = payment.amount(api)?;
+        if paid < required {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::InsufficientTransferRoyalty { required, paid },
+                ),
+            ));
+        }
+        let royalty_bucket = payment.take(required, api)?;
+
+        // Vaults are kept per resource, so that changing the royalty resource leaves whatever was
+        // collected before claimable.
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerCollection::RoyaltyVaultKeyValue.collection_index(),
+            &scrypto_encode(&paid_in).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        let vault = api
+            .key_value_entry_get_typed::<NonFungibleResourceManagerRoyaltyVaultEntryPayload>(
+                handle,
+            )?
+            .map(|vault| vault.into_latest());
+        let mut vault = match vault {
+            Some(vault) => vault,
+            None => {
+                let vault = Vault::create(paid_in, api)?;
+                let own = vault.0;
+                api.key_value_entry_set_typed(
+                    handle,
+                    &NonFungibleResourceManagerRoyaltyVaultEntryPayload::from_content_source(vault),
+                )?;
+                Vault(own)
+            }
+        };
+        vault.put(royalty_bucket, api)?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(payment)
+    }
+
     fn create_object<Y>(
         id_type: NonFungibleIdType,
         entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
         track_total_supply: bool,
+        track_collection: bool,
         non_fungible_schema: NonFungibleDataSchema,
-        resource_roles: NonFungibleResourceRoles,
+        resource_roles: NonFungibleResourceRolesV2,
         api: &mut Y,
     ) -> Result<(NodeId, RoleAssignmentInit), RuntimeError>
     where
@@ -1218,6 +2189,7 @@ impl NonFungibleResourceManagerBlueprint {
         };
 
         let supply: Decimal = Decimal::from(entries.len());
+        let next_reservable_id = next_reservable_id_after(entries.keys(), 1);
 
         let mut non_fungibles = index_map_new();
         for (id, (value,)) in entries {
@@ -1242,6 +2214,7 @@ impl NonFungibleResourceManagerBlueprint {
 
         let (mut features, roles) = to_features_and_roles(resource_roles);
         features.track_total_supply = track_total_supply;
+        features.track_collection = track_collection;
 
         let mut fields = indexmap! {
             NonFungibleResourceManagerField::IdType.into() => FieldValue::immutable(
@@ -1271,6 +2244,46 @@ impl NonFungibleResourceManagerBlueprint {
             );
         }
 
+        if track_collection {
+            fields.insert(
+                NonFungibleResourceManagerField::TotalMinted.into(),
+                FieldValue::new(
+                    &NonFungibleResourceManagerTotalMintedFieldPayload::from_content_source(0),
+                ),
+            );
+        }
+
+        if track_collection {
+            fields.insert(
+                NonFungibleResourceManagerField::SupplyRecount.into(),
+                FieldValue::new(
+                    &NonFungibleResourceManagerSupplyRecountFieldPayload::from_content_source(
+                        NonFungibleResourceManagerSupplyRecountV1::default(),
+                    ),
+                ),
+            );
+        }
+
+        if features.mint {
+            fields.insert(
+                NonFungibleResourceManagerField::NextReservableId.into(),
+                FieldValue::new(
+                    &NonFungibleResourceManagerNextReservableIdFieldPayload::from_content_source(
+                        next_reservable_id,
+                    ),
+                ),
+            );
+        }
+
+        if features.transfer_royalty {
+            fields.insert(
+                NonFungibleResourceManagerField::TransferRoyalty.into(),
+                FieldValue::new(
+                    &NonFungibleResourceManagerTransferRoyaltyFieldPayload::from_content_source(None),
+                ),
+            );
+        }
+
         let object_id = api.new_object(
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
             features.feature_names_str(),
@@ -1412,6 +2425,116 @@ impl NonFungibleResourceManagerBlueprint {
         Ok(())
     }
 
+    /// Appends newly minted non-fungibles to the mint order of an NFT collection.
+    fn record_minted<Y>(ids: &IndexSet<NonFungibleLocalId>, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerFeature::TrackCollection.feature_name(),
+        )? {
+            return Ok(());
+        }
+
+        let total_minted_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerField::TotalMinted.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut total_minted = api
+            .field_read_typed::<NonFungibleResourceManagerTotalMintedFieldPayload>(
+                total_minted_handle,
+            )?
+            .into_latest();
+        for id in ids {
+            let handle = api.actor_open_key_value_entry(
+                ACTOR_STATE_SELF,
+                NonFungibleResourceManagerCollection::MintedIdKeyValue.collection_index(),
+                &scrypto_encode(&total_minted).unwrap(),
+                LockFlags::MUTABLE,
+            )?;
+            api.key_value_entry_set_typed(
+                handle,
+                &NonFungibleResourceManagerMintedIdEntryPayload::from_content_source(id.clone()),
+            )?;
+            api.key_value_entry_close(handle)?;
+            total_minted += 1;
+        }
+        api.field_write_typed(
+            total_minted_handle,
+            &NonFungibleResourceManagerTotalMintedFieldPayload::from_content_source(total_minted),
+        )?;
+        api.field_close(total_minted_handle)?;
+
+        Ok(())
+    }
+
+    /// Throws away the progress of an unfinished supply recount, as a burn may have removed a
+    /// non-fungible it has already counted. Mints need no such care since they are appended to the
+    /// mint order after the point the recount has reached.
+    fn restart_supply_recount<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerFeature::TrackCollection.feature_name(),
+        )? {
+            return Ok(());
+        }
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerField::SupplyRecount.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let recount = api
+            .field_read_typed::<NonFungibleResourceManagerSupplyRecountFieldPayload>(handle)?
+            .into_latest();
+        if recount.next_index != 0 {
+            api.field_write_typed(
+                handle,
+                &NonFungibleResourceManagerSupplyRecountFieldPayload::from_content_source(
+                    NonFungibleResourceManagerSupplyRecountV1::default(),
+                ),
+            )?;
+        }
+        api.field_close(handle)?;
+
+        Ok(())
+    }
+
+    /// Keeps future reservations clear of integer ids which were minted without one.
+    fn move_next_reservable_id_past<Y>(
+        ids: &IndexSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerField::NextReservableId.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let current = api
+            .field_read_typed::<NonFungibleResourceManagerNextReservableIdFieldPayload>(handle)?
+            .into_latest();
+        let next_reservable_id = next_reservable_id_after(ids.iter(), current);
+        if next_reservable_id != current {
+            api.field_write_typed(
+                handle,
+                &NonFungibleResourceManagerNextReservableIdFieldPayload::from_content_source(
+                    next_reservable_id,
+                ),
+            )?;
+        }
+        api.field_close(handle)?;
+
+        Ok(())
+    }
+
     pub(crate) fn amount_for_withdrawal<Y>(
         _api: &mut Y,
         amount: Decimal,
@@ -1431,16 +2554,20 @@ impl NonFungibleResourceManagerBlueprint {
 }
 
 fn to_features_and_roles(
-    role_init: NonFungibleResourceRoles,
+    role_init: NonFungibleResourceRolesV2,
 ) -> (NonFungibleResourceManagerFeatureSet, RoleAssignmentInit) {
     let mut roles = RoleAssignmentInit::new();
 
     let features = NonFungibleResourceManagerFeatureSet {
         track_total_supply: false, // Will be set later
+        track_collection: false,   // Will be set later
         vault_freeze: role_init.freeze_roles.is_some(),
         vault_recall: role_init.recall_roles.is_some(),
         mint: role_init.mint_roles.is_some(),
         burn: role_init.burn_roles.is_some(),
+        soulbound: role_init.soulbound_roles.is_some(),
+        transfer_royalty: role_init.transfer_royalty_roles.is_some(),
+        deny_list: role_init.deny_list_roles.is_some(),
     };
 
     roles
@@ -1484,6 +2611,27 @@ fn to_features_and_roles(
             .to_role_init()
             .data,
     );
+    roles.data.extend(
+        role_init
+            .soulbound_roles
+            .unwrap_or_default()
+            .to_role_init()
+            .data,
+    );
+    roles.data.extend(
+        role_init
+            .transfer_royalty_roles
+            .unwrap_or_default()
+            .to_role_init()
+            .data,
+    );
+    roles.data.extend(
+        role_init
+            .deny_list_roles
+            .unwrap_or_default()
+            .to_role_init()
+            .data,
+    );
 
     (features, roles)
 }
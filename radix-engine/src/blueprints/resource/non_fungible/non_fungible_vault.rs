@@ -6,7 +6,7 @@ use crate::types::*;
 use native_sdk::resource::NativeBucket;
 use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::{
-    ClientApi, FieldValue, LockFlags, ACTOR_STATE_OUTER_OBJECT, ACTOR_STATE_SELF,
+    ClientApi, FieldValue, LockFlags, ACTOR_REF_OUTER, ACTOR_STATE_OUTER_OBJECT, ACTOR_STATE_SELF,
 };
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::types::*;
@@ -57,6 +57,17 @@ declare_native_blueprint_state! {
             },
             allow_ownership: false,
         },
+        allowances: KeyValue {
+            entry_ident: Allowance,
+            key_type: {
+                kind: Static,
+                content_type: ResourceOrNonFungible,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
     },
 }
 
@@ -64,6 +75,7 @@ type NonFungibleVaultBalanceV1 = LiquidNonFungibleVault;
 type NonFungibleVaultLockedResourceV1 = LockedNonFungibleResource;
 type NonFungibleVaultFreezeStatusV1 = VaultFrozenFlag;
 type NonFungibleVaultNonFungibleV1 = ();
+type NonFungibleVaultAllowanceV1 = WithdrawAllowance;
 
 pub struct NonFungibleVaultBlueprint;
 
@@ -115,6 +127,19 @@ impl NonFungibleVaultBlueprint {
                 export: NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_IDENT.to_string(),
             },
         );
+        functions.insert(
+            NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_WITH_ROYALTY_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                    NonFungibleVaultTakeNonFungiblesWithRoyaltyInput,
+                >()),
+                output: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                    NonFungibleVaultTakeNonFungiblesWithRoyaltyOutput,
+                >()),
+                export: NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_WITH_ROYALTY_IDENT.to_string(),
+            },
+        );
         functions.insert(
             VAULT_RECALL_IDENT.to_string(),
             FunctionSchemaInit {
@@ -302,6 +327,77 @@ impl NonFungibleVaultBlueprint {
                 export: NON_FUNGIBLE_VAULT_BURN_NON_FUNGIBLES_IDENT.to_string(),
             },
         );
+        functions.insert(
+            VAULT_GRANT_ALLOWANCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultGrantAllowanceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultGrantAllowanceOutput>(),
+                ),
+                export: NON_FUNGIBLE_VAULT_GRANT_ALLOWANCE_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            VAULT_REVOKE_ALLOWANCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultRevokeAllowanceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultRevokeAllowanceOutput>(),
+                ),
+                export: NON_FUNGIBLE_VAULT_REVOKE_ALLOWANCE_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            VAULT_GET_ALLOWANCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultGetAllowanceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultGetAllowanceOutput>(),
+                ),
+                export: NON_FUNGIBLE_VAULT_GET_ALLOWANCE_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            VAULT_TAKE_WITH_ALLOWANCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo {
+                    receiver: Receiver::SelfRefMut,
+                    ref_types: RefTypes::DIRECT_ACCESS,
+                }),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultTakeWithAllowanceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VaultTakeWithAllowanceOutput>(),
+                ),
+                export: NON_FUNGIBLE_VAULT_TAKE_WITH_ALLOWANCE_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_WITH_ALLOWANCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo {
+                    receiver: Receiver::SelfRefMut,
+                    ref_types: RefTypes::DIRECT_ACCESS,
+                }),
+                input: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                    NonFungibleVaultTakeNonFungiblesWithAllowanceInput,
+                >()),
+                output: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                    NonFungibleVaultTakeNonFungiblesWithAllowanceOutput,
+                >()),
+                export: NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_WITH_ALLOWANCE_IDENT.to_string(),
+            },
+        );
 
         let event_schema = event_schema! {
             aggregator,
@@ -328,7 +424,10 @@ impl NonFungibleVaultBlueprint {
                 state,
                 events: event_schema,
                 types: BlueprintTypeSchemaInit::default(),
-                functions: BlueprintFunctionsSchemaInit { functions },
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
                 hooks: BlueprintHooksInit::default(),
             },
 
@@ -346,6 +445,7 @@ impl NonFungibleVaultBlueprint {
                         VAULT_TAKE_IDENT => [WITHDRAWER_ROLE];
                         VAULT_TAKE_ADVANCED_IDENT => [WITHDRAWER_ROLE];
                         NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_IDENT => [WITHDRAWER_ROLE];
+                        NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_WITH_ROYALTY_IDENT => [WITHDRAWER_ROLE];
                         VAULT_RECALL_IDENT => [RECALLER_ROLE];
                         VAULT_FREEZE_IDENT => [FREEZER_ROLE];
                         VAULT_UNFREEZE_IDENT => [FREEZER_ROLE];
@@ -353,6 +453,11 @@ impl NonFungibleVaultBlueprint {
                         VAULT_PUT_IDENT => [DEPOSITOR_ROLE];
                         VAULT_BURN_IDENT => [BURNER_ROLE];
                         NON_FUNGIBLE_VAULT_BURN_NON_FUNGIBLES_IDENT => [BURNER_ROLE];
+                        VAULT_GRANT_ALLOWANCE_IDENT => [WITHDRAWER_ROLE];
+                        VAULT_REVOKE_ALLOWANCE_IDENT => [WITHDRAWER_ROLE];
+                        VAULT_GET_ALLOWANCE_IDENT => MethodAccessibility::Public;
+                        VAULT_TAKE_WITH_ALLOWANCE_IDENT => MethodAccessibility::Public;
+                        NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_WITH_ALLOWANCE_IDENT => MethodAccessibility::Public;
 
                         NON_FUNGIBLE_VAULT_LOCK_NON_FUNGIBLES_IDENT => MethodAccessibility::OwnPackageOnly;
                         NON_FUNGIBLE_VAULT_UNLOCK_NON_FUNGIBLES_IDENT => MethodAccessibility::OwnPackageOnly;
@@ -377,31 +482,8 @@ impl NonFungibleVaultBlueprint {
     where
         Y: ClientApi<RuntimeError>,
     {
-        Self::assert_not_frozen(VaultFreezeFlags::WITHDRAW, api)?;
-
-        let taken = {
-            let amount = amount.for_withdrawal(0, withdraw_strategy).ok_or(
-                RuntimeError::ApplicationError(ApplicationError::NonFungibleVaultError(
-                    NonFungibleVaultError::DecimalOverflow,
-                )),
-            )?;
-
-            let n = check_non_fungible_amount(&amount).map_err(|_| {
-                RuntimeError::ApplicationError(ApplicationError::VaultError(
-                    VaultError::InvalidAmount(amount),
-                ))
-            })?;
-
-            Self::internal_take_by_amount(n, api)?
-        };
-
-        // Create node
-        let ids = taken.into_ids();
-        let bucket = NonFungibleResourceManagerBlueprint::create_bucket(ids.clone(), api)?;
-
-        Runtime::emit_event(api, events::non_fungible_vault::WithdrawEvent { ids })?;
-
-        Ok(bucket)
+        Self::assert_royalty_exempt(api)?;
+        Self::withdraw_advanced(amount, withdraw_strategy, api)
     }
 
     pub fn take_non_fungibles<Y>(
@@ -411,18 +493,36 @@ impl NonFungibleVaultBlueprint {
     where
         Y: ClientApi<RuntimeError>,
     {
-        Self::assert_not_frozen(VaultFreezeFlags::WITHDRAW, api)?;
-
-        // Take
-        let taken = Self::internal_take_non_fungibles(non_fungible_local_ids, api)?;
-
-        // Create node
-        let ids = taken.into_ids();
-        let bucket = NonFungibleResourceManagerBlueprint::create_bucket(ids.clone(), api)?;
+        Self::assert_royalty_exempt(api)?;
+        Self::withdraw_non_fungibles(non_fungible_local_ids, api)
+    }
 
-        Runtime::emit_event(api, events::non_fungible_vault::WithdrawEvent { ids })?;
+    /// Withdraws specific non-fungibles, paying the resource's transfer royalty out of the given
+    /// payment. The change is returned alongside the non-fungibles.
+    pub fn take_non_fungibles_with_royalty<Y>(
+        non_fungible_local_ids: &IndexSet<NonFungibleLocalId>,
+        payment: Bucket,
+        api: &mut Y,
+    ) -> Result<(Bucket, Bucket), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let bucket = Self::withdraw_non_fungibles(non_fungible_local_ids, api)?;
+
+        let resource_address = api.actor_get_node_id(ACTOR_REF_OUTER)?;
+        let rtn = api.call_method(
+            &resource_address,
+            NON_FUNGIBLE_RESOURCE_MANAGER_COLLECT_TRANSFER_ROYALTY_IDENT,
+            scrypto_encode(&NonFungibleResourceManagerCollectTransferRoyaltyInput {
+                amount: Decimal::from(non_fungible_local_ids.len()),
+                payment,
+            })
+            .unwrap(),
+        )?;
+        let change: NonFungibleResourceManagerCollectTransferRoyaltyOutput =
+            scrypto_decode(&rtn).unwrap();
 
-        Ok(bucket)
+        Ok((bucket, change))
     }
 
     pub fn put<Y>(bucket: Bucket, api: &mut Y) -> Result<(), RuntimeError>
@@ -430,6 +530,8 @@ impl NonFungibleVaultBlueprint {
         Y: ClientApi<RuntimeError>,
     {
         Self::assert_not_frozen(VaultFreezeFlags::DEPOSIT, api)?;
+        Self::assert_transfer_exempt(api)?;
+        Self::assert_holder_not_denied(api)?;
 
         // Drop other bucket
         // This will fail if bucket is not an inner object of the current non-fungible resource
@@ -629,7 +731,8 @@ impl NonFungibleVaultBlueprint {
     {
         Self::assert_not_frozen(VaultFreezeFlags::BURN, api)?;
 
-        Self::take(&amount, api)?.package_burn(api)?;
+        // Burning is not a transfer, so no royalty is due
+        Self::withdraw_advanced(&amount, WithdrawStrategy::Exact, api)?.package_burn(api)?;
         Ok(())
     }
 
@@ -642,10 +745,107 @@ impl NonFungibleVaultBlueprint {
     {
         Self::assert_not_frozen(VaultFreezeFlags::BURN, api)?;
 
-        Self::take_non_fungibles(non_fungible_local_ids, api)?.package_burn(api)?;
+        Self::withdraw_non_fungibles(non_fungible_local_ids, api)?.package_burn(api)?;
+        Ok(())
+    }
+
+    pub fn grant_allowance<Y>(
+        grantee: ResourceOrNonFungible,
+        allowance: WithdrawAllowance,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if let AllowanceLimit::Amount(amount) = allowance.limit {
+            if amount.is_negative() {
+                return Err(VaultError::InvalidAllowance(allowance).into());
+            }
+        }
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            NonFungibleVaultCollection::AllowanceKeyValue.collection_index(),
+            &scrypto_encode(&grantee).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            handle,
+            &NonFungibleVaultAllowanceEntryPayload::from_content_source(allowance),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(())
+    }
+
+    pub fn revoke_allowance<Y>(
+        grantee: ResourceOrNonFungible,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        api.actor_remove_key_value_entry(
+            ACTOR_STATE_SELF,
+            NonFungibleVaultCollection::AllowanceKeyValue.collection_index(),
+            &scrypto_encode(&grantee).unwrap(),
+        )?;
+
         Ok(())
     }
 
+    pub fn get_allowance<Y>(
+        grantee: ResourceOrNonFungible,
+        api: &mut Y,
+    ) -> Result<Option<WithdrawAllowance>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            NonFungibleVaultCollection::AllowanceKeyValue.collection_index(),
+            &scrypto_encode(&grantee).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let allowance = api
+            .key_value_entry_get_typed::<NonFungibleVaultAllowanceEntryPayload>(handle)?
+            .map(|allowance| allowance.into_latest());
+        api.key_value_entry_close(handle)?;
+
+        Ok(allowance)
+    }
+
+    /// Withdraws an amount on behalf of a grantee, who must present their badge.
+    pub fn take_with_allowance<Y>(
+        grantee: ResourceOrNonFungible,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::spend_allowance(grantee, amount, None, api)?;
+        Self::take(&amount, api)
+    }
+
+    /// Withdraws specific non-fungibles on behalf of a grantee, who must present their badge.
+    pub fn take_non_fungibles_with_allowance<Y>(
+        grantee: ResourceOrNonFungible,
+        non_fungible_local_ids: &IndexSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::spend_allowance(
+            grantee,
+            Decimal::from(non_fungible_local_ids.len()),
+            Some(non_fungible_local_ids),
+            api,
+        )?;
+        Self::take_non_fungibles(non_fungible_local_ids, api)
+    }
+
     //===================
     // Protected methods
     //===================
@@ -729,6 +929,101 @@ impl NonFungibleVaultBlueprint {
     // Helper methods
     //===================
 
+    fn withdraw_advanced<Y>(
+        amount: &Decimal,
+        withdraw_strategy: WithdrawStrategy,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_not_frozen(VaultFreezeFlags::WITHDRAW, api)?;
+        Self::assert_transfer_exempt(api)?;
+
+        let taken = {
+            let amount = amount.for_withdrawal(0, withdraw_strategy).ok_or(
+                RuntimeError::ApplicationError(ApplicationError::NonFungibleVaultError(
+                    NonFungibleVaultError::DecimalOverflow,
+                )),
+            )?;
+
+            let n = check_non_fungible_amount(&amount).map_err(|_| {
+                RuntimeError::ApplicationError(ApplicationError::VaultError(
+                    VaultError::InvalidAmount(amount),
+                ))
+            })?;
+
+            Self::internal_take_by_amount(n, api)?
+        };
+
+        // Create node
+        let ids = taken.into_ids();
+        let bucket = NonFungibleResourceManagerBlueprint::create_bucket(ids.clone(), api)?;
+
+        Runtime::emit_event(api, events::non_fungible_vault::WithdrawEvent { ids })?;
+
+        Ok(bucket)
+    }
+
+    fn withdraw_non_fungibles<Y>(
+        non_fungible_local_ids: &IndexSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_not_frozen(VaultFreezeFlags::WITHDRAW, api)?;
+        Self::assert_transfer_exempt(api)?;
+
+        // Take
+        let taken = Self::internal_take_non_fungibles(non_fungible_local_ids, api)?;
+
+        // Create node
+        let ids = taken.into_ids();
+        let bucket = NonFungibleResourceManagerBlueprint::create_bucket(ids.clone(), api)?;
+
+        Runtime::emit_event(api, events::non_fungible_vault::WithdrawEvent { ids })?;
+
+        Ok(bucket)
+    }
+
+    fn spend_allowance<Y>(
+        grantee: ResourceOrNonFungible,
+        amount: Decimal,
+        non_fungible_local_ids: Option<&IndexSet<NonFungibleLocalId>>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Runtime::assert_access_rule(
+            AccessRule::Protected(AccessRuleNode::ProofRule(ProofRule::Require(grantee.clone()))),
+            api,
+        )?;
+        let current_epoch = Runtime::current_epoch(api)?;
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            NonFungibleVaultCollection::AllowanceKeyValue.collection_index(),
+            &scrypto_encode(&grantee).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut allowance = match api
+            .key_value_entry_get_typed::<NonFungibleVaultAllowanceEntryPayload>(handle)?
+        {
+            Some(allowance) => allowance.into_latest(),
+            None => return Err(VaultError::NoAllowance(grantee).into()),
+        };
+        VaultUtil::spend_allowance(&mut allowance, amount, non_fungible_local_ids, current_epoch)?;
+        api.key_value_entry_set_typed(
+            handle,
+            &NonFungibleVaultAllowanceEntryPayload::from_content_source(allowance),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(())
+    }
+
     fn assert_not_frozen<Y>(flags: VaultFreezeFlags, api: &mut Y) -> Result<(), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
@@ -759,6 +1054,61 @@ impl NonFungibleVaultBlueprint {
         Ok(())
     }
 
+    fn assert_transfer_exempt<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let is_soulbound = api.actor_is_feature_enabled(
+            ACTOR_STATE_OUTER_OBJECT,
+            NonFungibleResourceManagerFeature::Soulbound.feature_name(),
+        )?;
+        VaultUtil::assert_transfer_exempt(is_soulbound, api)
+    }
+
+    fn assert_holder_not_denied<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let is_deny_listed = api.actor_is_feature_enabled(
+            ACTOR_STATE_OUTER_OBJECT,
+            NonFungibleResourceManagerFeature::DenyList.feature_name(),
+        )?;
+        DenyListUtil::assert_holder_not_denied(
+            is_deny_listed,
+            NonFungibleResourceManagerCollection::DeniedHolderKeyValue.collection_index(),
+            api,
+        )
+    }
+
+    /// Plain withdrawals of a resource which charges a transfer royalty are only allowed to those
+    /// satisfying the royalty exemptor, such as approved marketplaces.
+    fn assert_royalty_exempt<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_OUTER_OBJECT,
+            NonFungibleResourceManagerFeature::TransferRoyalty.feature_name(),
+        )? {
+            return Ok(());
+        }
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_OUTER_OBJECT,
+            NonFungibleResourceManagerField::TransferRoyalty.into(),
+            LockFlags::read_only(),
+        )?;
+        let royalty = api
+            .field_read_typed::<NonFungibleResourceManagerTransferRoyaltyFieldPayload>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+
+        match royalty {
+            Some(_) => VaultUtil::assert_resource_role(ROYALTY_EXEMPTOR_ROLE, api),
+            None => Ok(()),
+        }
+    }
+
     fn assert_freezable<Y>(api: &mut Y) -> Result<(), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
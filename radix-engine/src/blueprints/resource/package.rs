@@ -6,6 +6,7 @@ use crate::errors::RuntimeError;
 use crate::errors::SystemUpstreamError;
 use crate::internal_prelude::*;
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
+use crate::native_exports;
 use crate::system::system_callback::SystemLockData;
 use crate::types::*;
 use radix_engine_interface::api::ClientApi;
@@ -21,12 +22,18 @@ pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME: &str =
     "create_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME: &str =
     "create_with_initial_supply_and_address_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_EXPORT_NAME: &str =
+    "create_v2_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_EXPORT_NAME: &str =
+    "create_with_initial_supply_v2_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_BURN_EXPORT_NAME: &str = "burn_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_PACKAGE_BURN_EXPORT_NAME: &str =
     "package_burn_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_MINT_EXPORT_NAME: &str = "mint_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_VAULT_EXPORT_NAME: &str =
     "create_empty_vault_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_EXPORT_NAME: &str =
+    "create_empty_recall_protected_vault_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_EXPORT_NAME: &str =
     "create_empty_bucket_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_GET_RESOURCE_TYPE_EXPORT_NAME: &str =
@@ -37,11 +44,23 @@ pub(crate) const FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME: &s
     "amount_for_withdrawal_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME: &str =
     "drop_empty_bucket_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_DENY_HOLDER_EXPORT_NAME: &str =
+    "deny_holder_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_ALLOW_HOLDER_EXPORT_NAME: &str =
+    "allow_holder_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_IS_HOLDER_DENIED_EXPORT_NAME: &str =
+    "is_holder_denied_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_RECALL_BATCH_EXPORT_NAME: &str =
+    "recall_batch_FungibleResourceManager";
 
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME: &str =
     "create_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME: &str =
     "create_with_initial_supply_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_EXPORT_NAME: &str =
+    "create_v2_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_EXPORT_NAME: &str =
+    "create_with_initial_supply_v2_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_BURN_EXPORT_NAME: &str =
     "burn_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_PACKAGE_BURN_EXPORT_NAME: &str =
@@ -52,6 +71,8 @@ pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_EXPORT_NAME: &str =
     "mint_ruid_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_VAULT_EXPORT_NAME: &str =
     "create_empty_vault_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_EXPORT_NAME:
+    &str = "create_empty_recall_protected_vault_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_EXPORT_NAME: &str =
     "create_empty_bucket_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_GET_RESOURCE_TYPE_EXPORT_NAME: &str =
@@ -62,12 +83,19 @@ pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME
     "amount_for_withdrawal_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME: &str =
     "drop_empty_bucket_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_DENY_HOLDER_EXPORT_NAME: &str =
+    "deny_holder_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_ALLOW_HOLDER_EXPORT_NAME: &str =
+    "allow_holder_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_IS_HOLDER_DENIED_EXPORT_NAME: &str =
+    "is_holder_denied_NonFungibleResourceManager";
 
 pub(crate) const FUNGIBLE_VAULT_TAKE_EXPORT_NAME: &str = "take_FungibleVault";
 pub(crate) const FUNGIBLE_VAULT_TAKE_ADVANCED_EXPORT_NAME: &str = "take_advanced_FungibleVault";
 pub(crate) const FUNGIBLE_VAULT_PUT_EXPORT_NAME: &str = "put_FungibleVault";
 pub(crate) const FUNGIBLE_VAULT_GET_AMOUNT_EXPORT_NAME: &str = "get_amount_FungibleVault";
 pub(crate) const FUNGIBLE_VAULT_RECALL_EXPORT_NAME: &str = "recall_FungibleVault";
+pub(crate) const FUNGIBLE_VAULT_BATCH_RECALL_EXPORT_NAME: &str = "batch_recall_FungibleVault";
 pub(crate) const FUNGIBLE_VAULT_FREEZE_EXPORT_NAME: &str = "freeze_FungibleVault";
 pub(crate) const FUNGIBLE_VAULT_UNFREEZE_EXPORT_NAME: &str = "unfreeze_FungibleVault";
 pub(crate) const FUNGIBLE_VAULT_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME: &str =
@@ -75,6 +103,12 @@ pub(crate) const FUNGIBLE_VAULT_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME: &str =
 pub(crate) const FUNGIBLE_VAULT_LOCK_AMOUNT_EXPORT_NAME: &str = "lock_amount_FungibleVault";
 pub(crate) const FUNGIBLE_VAULT_UNLOCK_AMOUNT_EXPORT_NAME: &str = "unlock_amount_FungibleVault";
 pub(crate) const FUNGIBLE_VAULT_BURN_EXPORT_NAME: &str = "burn_FungibleVault";
+pub(crate) const FUNGIBLE_VAULT_GRANT_ALLOWANCE_EXPORT_NAME: &str = "grant_allowance_FungibleVault";
+pub(crate) const FUNGIBLE_VAULT_REVOKE_ALLOWANCE_EXPORT_NAME: &str =
+    "revoke_allowance_FungibleVault";
+pub(crate) const FUNGIBLE_VAULT_GET_ALLOWANCE_EXPORT_NAME: &str = "get_allowance_FungibleVault";
+pub(crate) const FUNGIBLE_VAULT_TAKE_WITH_ALLOWANCE_EXPORT_NAME: &str =
+    "take_with_allowance_FungibleVault";
 
 pub(crate) const NON_FUNGIBLE_VAULT_TAKE_EXPORT_NAME: &str = "take_NonFungibleVault";
 pub(crate) const NON_FUNGIBLE_VAULT_TAKE_ADVANCED_EXPORT_NAME: &str =
@@ -89,6 +123,14 @@ pub(crate) const NON_FUNGIBLE_VAULT_LOCK_NON_FUNGIBLES_EXPORT_NAME: &str =
 pub(crate) const NON_FUNGIBLE_VAULT_UNLOCK_NON_FUNGIBLES_EXPORT_NAME: &str =
     "unlock_non_fungibles_NonFungibleVault";
 pub(crate) const NON_FUNGIBLE_VAULT_BURN_EXPORT_NAME: &str = "burn_NonFungibleVault";
+pub(crate) const NON_FUNGIBLE_VAULT_GRANT_ALLOWANCE_EXPORT_NAME: &str =
+    "grant_allowance_NonFungibleVault";
+pub(crate) const NON_FUNGIBLE_VAULT_REVOKE_ALLOWANCE_EXPORT_NAME: &str =
+    "revoke_allowance_NonFungibleVault";
+pub(crate) const NON_FUNGIBLE_VAULT_GET_ALLOWANCE_EXPORT_NAME: &str =
+    "get_allowance_NonFungibleVault";
+pub(crate) const NON_FUNGIBLE_VAULT_TAKE_WITH_ALLOWANCE_EXPORT_NAME: &str =
+    "take_with_allowance_NonFungibleVault";
 
 pub(crate) const FUNGIBLE_BUCKET_TAKE_EXPORT_NAME: &str = "take_FungibleBucket";
 pub(crate) const FUNGIBLE_BUCKET_TAKE_ADVANCED_EXPORT_NAME: &str = "take_advanced_FungibleBucket";
@@ -155,6 +197,156 @@ pub(crate) const AUTH_ZONE_DROP_PROOFS_EXPORT_NAME: &str = "AuthZone_drop_proofs
 pub(crate) const AUTH_ZONE_DRAIN_EXPORT_NAME: &str = "AuthZone_drain";
 pub(crate) const AUTH_ZONE_ASSERT_ACCESS_RULE_EXPORT_NAME: &str = "AuthZone_assert_access_rule";
 
+native_exports! {
+    pub enum ResourceNativeExport {
+        FungibleResourceManagerCreate => FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME,
+        FungibleResourceManagerCreateWithInitialSupply => FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME,
+        FungibleResourceManagerCreateV2 => FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_EXPORT_NAME,
+        FungibleResourceManagerCreateWithInitialSupplyV2 => FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_EXPORT_NAME,
+        FungibleResourceManagerMint => FUNGIBLE_RESOURCE_MANAGER_MINT_EXPORT_NAME,
+        FungibleResourceManagerBurn => FUNGIBLE_RESOURCE_MANAGER_BURN_EXPORT_NAME,
+        FungibleResourceManagerPackageBurn => FUNGIBLE_RESOURCE_MANAGER_PACKAGE_BURN_EXPORT_NAME,
+        FungibleResourceManagerDenyHolder => FUNGIBLE_RESOURCE_MANAGER_DENY_HOLDER_EXPORT_NAME,
+        FungibleResourceManagerAllowHolder => FUNGIBLE_RESOURCE_MANAGER_ALLOW_HOLDER_EXPORT_NAME,
+        FungibleResourceManagerIsHolderDenied => FUNGIBLE_RESOURCE_MANAGER_IS_HOLDER_DENIED_EXPORT_NAME,
+        FungibleResourceManagerRecallBatch => FUNGIBLE_RESOURCE_MANAGER_RECALL_BATCH_EXPORT_NAME,
+        FungibleResourceManagerDropEmptyBucket => FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME,
+        FungibleResourceManagerCreateEmptyVault => FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_VAULT_EXPORT_NAME,
+        FungibleResourceManagerCreateEmptyRecallProtectedVault => FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_EXPORT_NAME,
+        FungibleResourceManagerCreateEmptyBucket => FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_EXPORT_NAME,
+        FungibleResourceManagerGetResourceType => FUNGIBLE_RESOURCE_MANAGER_GET_RESOURCE_TYPE_EXPORT_NAME,
+        FungibleResourceManagerGetTotalSupply => FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_SUPPLY_EXPORT_NAME,
+        FungibleResourceManagerAmountForWithdrawal => FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME,
+        NonFungibleResourceManagerCreate => NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME,
+        NonFungibleResourceManagerCreateWithInitialSupply => NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME,
+        NonFungibleResourceManagerCreateRuidWithInitialSupply => NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_IDENT,
+        NonFungibleResourceManagerCreateV2 => NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_EXPORT_NAME,
+        NonFungibleResourceManagerCreateWithInitialSupplyV2 => NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_EXPORT_NAME,
+        NonFungibleResourceManagerCreateRuidWithInitialSupplyV2 => NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_V2_IDENT,
+        NonFungibleResourceManagerCreateCollection => NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_COLLECTION_IDENT,
+        NonFungibleResourceManagerMint => NON_FUNGIBLE_RESOURCE_MANAGER_MINT_EXPORT_NAME,
+        NonFungibleResourceManagerMintRuid => NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_EXPORT_NAME,
+        NonFungibleResourceManagerMintSingleRuid => NON_FUNGIBLE_RESOURCE_MANAGER_MINT_SINGLE_RUID_IDENT,
+        NonFungibleResourceManagerReserveIntegerIds => NON_FUNGIBLE_RESOURCE_MANAGER_RESERVE_INTEGER_IDS_IDENT,
+        NonFungibleResourceManagerMintReserved => NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RESERVED_IDENT,
+        NonFungibleResourceManagerBurn => NON_FUNGIBLE_RESOURCE_MANAGER_BURN_EXPORT_NAME,
+        NonFungibleResourceManagerPackageBurn => NON_FUNGIBLE_RESOURCE_MANAGER_PACKAGE_BURN_EXPORT_NAME,
+        NonFungibleResourceManagerDenyHolder => NON_FUNGIBLE_RESOURCE_MANAGER_DENY_HOLDER_EXPORT_NAME,
+        NonFungibleResourceManagerAllowHolder => NON_FUNGIBLE_RESOURCE_MANAGER_ALLOW_HOLDER_EXPORT_NAME,
+        NonFungibleResourceManagerIsHolderDenied => NON_FUNGIBLE_RESOURCE_MANAGER_IS_HOLDER_DENIED_EXPORT_NAME,
+        NonFungibleResourceManagerDropEmptyBucket => NON_FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME,
+        NonFungibleResourceManagerCreateEmptyBucket => NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_EXPORT_NAME,
+        NonFungibleResourceManagerCreateEmptyVault => NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_VAULT_EXPORT_NAME,
+        NonFungibleResourceManagerCreateEmptyRecallProtectedVault => NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_EXPORT_NAME,
+        NonFungibleResourceManagerUpdateData => NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_IDENT,
+        NonFungibleResourceManagerExists => NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT,
+        NonFungibleResourceManagerGetTotalMinted => NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_MINTED_IDENT,
+        NonFungibleResourceManagerGetMintedIds => NON_FUNGIBLE_RESOURCE_MANAGER_GET_MINTED_IDS_IDENT,
+        NonFungibleResourceManagerRecountTotalSupply => NON_FUNGIBLE_RESOURCE_MANAGER_RECOUNT_TOTAL_SUPPLY_IDENT,
+        NonFungibleResourceManagerSetTransferRoyalty => NON_FUNGIBLE_RESOURCE_MANAGER_SET_TRANSFER_ROYALTY_IDENT,
+        NonFungibleResourceManagerGetTransferRoyalty => NON_FUNGIBLE_RESOURCE_MANAGER_GET_TRANSFER_ROYALTY_IDENT,
+        NonFungibleResourceManagerClaimTransferRoyalties => NON_FUNGIBLE_RESOURCE_MANAGER_CLAIM_TRANSFER_ROYALTIES_IDENT,
+        NonFungibleResourceManagerCollectTransferRoyalty => NON_FUNGIBLE_RESOURCE_MANAGER_COLLECT_TRANSFER_ROYALTY_IDENT,
+        NonFungibleResourceManagerGetResourceType => NON_FUNGIBLE_RESOURCE_MANAGER_GET_RESOURCE_TYPE_EXPORT_NAME,
+        NonFungibleResourceManagerGetTotalSupply => NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_SUPPLY_EXPORT_NAME,
+        NonFungibleResourceManagerAmountForWithdrawal => NON_FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME,
+        NonFungibleResourceManagerGetNonFungible => NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_IDENT,
+        FungibleVaultLockFee => FUNGIBLE_VAULT_LOCK_FEE_IDENT,
+        FungibleVaultLockFeeWithRefund => FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT,
+        FungibleVaultTake => FUNGIBLE_VAULT_TAKE_EXPORT_NAME,
+        FungibleVaultTakeAdvanced => FUNGIBLE_VAULT_TAKE_ADVANCED_EXPORT_NAME,
+        FungibleVaultRecall => FUNGIBLE_VAULT_RECALL_EXPORT_NAME,
+        FungibleVaultBatchRecall => FUNGIBLE_VAULT_BATCH_RECALL_EXPORT_NAME,
+        FungibleVaultFreeze => FUNGIBLE_VAULT_FREEZE_EXPORT_NAME,
+        FungibleVaultUnfreeze => FUNGIBLE_VAULT_UNFREEZE_EXPORT_NAME,
+        FungibleVaultPut => FUNGIBLE_VAULT_PUT_EXPORT_NAME,
+        FungibleVaultGetAmount => FUNGIBLE_VAULT_GET_AMOUNT_EXPORT_NAME,
+        FungibleVaultCreateProofOfAmount => FUNGIBLE_VAULT_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME,
+        FungibleVaultLockAmount => FUNGIBLE_VAULT_LOCK_AMOUNT_EXPORT_NAME,
+        FungibleVaultUnlockAmount => FUNGIBLE_VAULT_UNLOCK_AMOUNT_EXPORT_NAME,
+        FungibleVaultBurn => FUNGIBLE_VAULT_BURN_EXPORT_NAME,
+        FungibleVaultGrantAllowance => FUNGIBLE_VAULT_GRANT_ALLOWANCE_EXPORT_NAME,
+        FungibleVaultRevokeAllowance => FUNGIBLE_VAULT_REVOKE_ALLOWANCE_EXPORT_NAME,
+        FungibleVaultGetAllowance => FUNGIBLE_VAULT_GET_ALLOWANCE_EXPORT_NAME,
+        FungibleVaultTakeWithAllowance => FUNGIBLE_VAULT_TAKE_WITH_ALLOWANCE_EXPORT_NAME,
+        NonFungibleVaultTakeAdvanced => NON_FUNGIBLE_VAULT_TAKE_ADVANCED_EXPORT_NAME,
+        NonFungibleVaultTake => NON_FUNGIBLE_VAULT_TAKE_EXPORT_NAME,
+        NonFungibleVaultTakeNonFungibles => NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_IDENT,
+        NonFungibleVaultRecall => NON_FUNGIBLE_VAULT_RECALL_EXPORT_NAME,
+        NonFungibleVaultFreeze => NON_FUNGIBLE_VAULT_FREEZE_EXPORT_NAME,
+        NonFungibleVaultUnfreeze => NON_FUNGIBLE_VAULT_UNFREEZE_EXPORT_NAME,
+        NonFungibleVaultRecallNonFungibles => NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT,
+        NonFungibleVaultPut => NON_FUNGIBLE_VAULT_PUT_EXPORT_NAME,
+        NonFungibleVaultGetAmount => NON_FUNGIBLE_VAULT_GET_AMOUNT_EXPORT_NAME,
+        NonFungibleVaultGetNonFungibleLocalIds => NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT,
+        NonFungibleVaultContainsNonFungible => NON_FUNGIBLE_VAULT_CONTAINS_NON_FUNGIBLE_IDENT,
+        NonFungibleVaultCreateProofOfNonFungibles => NON_FUNGIBLE_VAULT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT,
+        NonFungibleVaultLockNonFungibles => NON_FUNGIBLE_VAULT_LOCK_NON_FUNGIBLES_EXPORT_NAME,
+        NonFungibleVaultUnlockNonFungibles => NON_FUNGIBLE_VAULT_UNLOCK_NON_FUNGIBLES_EXPORT_NAME,
+        NonFungibleVaultBurn => NON_FUNGIBLE_VAULT_BURN_EXPORT_NAME,
+        NonFungibleVaultBurnNonFungibles => NON_FUNGIBLE_VAULT_BURN_NON_FUNGIBLES_IDENT,
+        NonFungibleVaultGrantAllowance => NON_FUNGIBLE_VAULT_GRANT_ALLOWANCE_EXPORT_NAME,
+        NonFungibleVaultRevokeAllowance => NON_FUNGIBLE_VAULT_REVOKE_ALLOWANCE_EXPORT_NAME,
+        NonFungibleVaultGetAllowance => NON_FUNGIBLE_VAULT_GET_ALLOWANCE_EXPORT_NAME,
+        NonFungibleVaultTakeWithAllowance => NON_FUNGIBLE_VAULT_TAKE_WITH_ALLOWANCE_EXPORT_NAME,
+        NonFungibleVaultTakeNonFungiblesWithAllowance => NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_WITH_ALLOWANCE_IDENT,
+        NonFungibleVaultTakeNonFungiblesWithRoyalty => NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_WITH_ROYALTY_IDENT,
+        FungibleProofClone => FUNGIBLE_PROOF_CLONE_EXPORT_NAME,
+        FungibleProofGetAmount => FUNGIBLE_PROOF_GET_AMOUNT_EXPORT_NAME,
+        FungibleProofGetResourceAddress => FUNGIBLE_PROOF_GET_RESOURCE_ADDRESS_EXPORT_NAME,
+        FungibleProofDrop => FUNGIBLE_PROOF_DROP_EXPORT_NAME,
+        FungibleProofOnDrop => FUNGIBLE_PROOF_ON_DROP_EXPORT_NAME,
+        FungibleProofOnMove => FUNGIBLE_PROOF_ON_MOVE_EXPORT_NAME,
+        NonFungibleProofClone => NON_FUNGIBLE_PROOF_CLONE_EXPORT_NAME,
+        NonFungibleProofGetAmount => NON_FUNGIBLE_PROOF_GET_AMOUNT_EXPORT_NAME,
+        NonFungibleProofGetLocalIds => NON_FUNGIBLE_PROOF_GET_LOCAL_IDS_IDENT,
+        NonFungibleProofGetResourceAddress => NON_FUNGIBLE_PROOF_GET_RESOURCE_ADDRESS_EXPORT_NAME,
+        NonFungibleProofDrop => NON_FUNGIBLE_PROOF_DROP_EXPORT_NAME,
+        NonFungibleProofOnDrop => NON_FUNGIBLE_PROOF_ON_DROP_EXPORT_NAME,
+        NonFungibleProofOnMove => NON_FUNGIBLE_PROOF_ON_MOVE_EXPORT_NAME,
+        FungibleBucketPut => FUNGIBLE_BUCKET_PUT_EXPORT_NAME,
+        FungibleBucketTake => FUNGIBLE_BUCKET_TAKE_EXPORT_NAME,
+        FungibleBucketTakeAdvanced => FUNGIBLE_BUCKET_TAKE_ADVANCED_EXPORT_NAME,
+        FungibleBucketGetAmount => FUNGIBLE_BUCKET_GET_AMOUNT_EXPORT_NAME,
+        FungibleBucketGetResourceAddress => FUNGIBLE_BUCKET_GET_RESOURCE_ADDRESS_EXPORT_NAME,
+        FungibleBucketCreateProofOfAmount => FUNGIBLE_BUCKET_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME,
+        FungibleBucketCreateProofOfAll => FUNGIBLE_BUCKET_CREATE_PROOF_OF_ALL_EXPORT_NAME,
+        FungibleBucketLockAmount => FUNGIBLE_BUCKET_LOCK_AMOUNT_EXPORT_NAME,
+        FungibleBucketUnlockAmount => FUNGIBLE_BUCKET_UNLOCK_AMOUNT_EXPORT_NAME,
+        NonFungibleBucketPut => NON_FUNGIBLE_BUCKET_PUT_EXPORT_NAME,
+        NonFungibleBucketTake => NON_FUNGIBLE_BUCKET_TAKE_EXPORT_NAME,
+        NonFungibleBucketTakeAdvanced => NON_FUNGIBLE_BUCKET_TAKE_ADVANCED_EXPORT_NAME,
+        NonFungibleBucketTakeNonFungibles => NON_FUNGIBLE_BUCKET_TAKE_NON_FUNGIBLES_EXPORT_NAME,
+        NonFungibleBucketGetAmount => NON_FUNGIBLE_BUCKET_GET_AMOUNT_EXPORT_NAME,
+        NonFungibleBucketGetResourceAddress => NON_FUNGIBLE_BUCKET_GET_RESOURCE_ADDRESS_EXPORT_NAME,
+        NonFungibleBucketCreateProofOfNonFungibles => NON_FUNGIBLE_BUCKET_CREATE_PROOF_OF_NON_FUNGIBLES_EXPORT_NAME,
+        NonFungibleBucketCreateProofOfAll => NON_FUNGIBLE_BUCKET_CREATE_PROOF_OF_ALL_EXPORT_NAME,
+        NonFungibleBucketGetNonFungibleLocalIds => NON_FUNGIBLE_BUCKET_GET_NON_FUNGIBLE_LOCAL_IDS_EXPORT_NAME,
+        NonFungibleBucketContainsNonFungible => NON_FUNGIBLE_BUCKET_CONTAINS_NON_FUNGIBLE_EXPORT_NAME,
+        NonFungibleBucketLockNonFungibles => NON_FUNGIBLE_BUCKET_LOCK_NON_FUNGIBLES_EXPORT_NAME,
+        NonFungibleBucketUnlockNonFungibles => NON_FUNGIBLE_BUCKET_UNLOCK_NON_FUNGIBLES_EXPORT_NAME,
+        WorktopDrop => WORKTOP_DROP_IDENT,
+        WorktopPut => WORKTOP_PUT_IDENT,
+        WorktopTake => WORKTOP_TAKE_IDENT,
+        WorktopTakeNonFungibles => WORKTOP_TAKE_NON_FUNGIBLES_IDENT,
+        WorktopTakeAll => WORKTOP_TAKE_ALL_IDENT,
+        WorktopAssertContains => WORKTOP_ASSERT_CONTAINS_IDENT,
+        WorktopAssertContainsAmount => WORKTOP_ASSERT_CONTAINS_AMOUNT_IDENT,
+        WorktopAssertContainsNonFungibles => WORKTOP_ASSERT_CONTAINS_NON_FUNGIBLES_IDENT,
+        WorktopDrain => WORKTOP_DRAIN_IDENT,
+        AuthZonePop => AUTH_ZONE_POP_EXPORT_NAME,
+        AuthZonePush => AUTH_ZONE_PUSH_EXPORT_NAME,
+        AuthZoneCreateProofOfAmount => AUTH_ZONE_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME,
+        AuthZoneCreateProofOfNonFungibles => AUTH_ZONE_CREATE_PROOF_OF_NON_FUNGIBLES_EXPORT_NAME,
+        AuthZoneCreateProofOfAll => AUTH_ZONE_CREATE_PROOF_OF_ALL_EXPORT_NAME,
+        AuthZoneDropProofs => AUTH_ZONE_DROP_PROOFS_EXPORT_NAME,
+        AuthZoneDropSignatureProofs => AUTH_ZONE_DROP_SIGNATURE_PROOFS_EXPORT_NAME,
+        AuthZoneDropRegularProofs => AUTH_ZONE_DROP_REGULAR_PROOFS_EXPORT_NAME,
+        AuthZoneDrain => AUTH_ZONE_DRAIN_EXPORT_NAME,
+        AuthZoneAssertAccessRule => AUTH_ZONE_ASSERT_ACCESS_RULE_EXPORT_NAME,
+    }
+}
+
 pub struct ResourceNativePackage;
 
 impl ResourceNativePackage {
@@ -328,7 +520,10 @@ impl ResourceNativePackage {
                     },
                     events: BlueprintEventSchemaInit::default(),
                     types: BlueprintTypeSchemaInit::default(),
-                    functions: BlueprintFunctionsSchemaInit { functions },
+                    functions: BlueprintFunctionsSchemaInit {
+                        functions,
+                        post_conditions: index_map_new(),
+                    },
                     hooks: BlueprintHooksInit::default(),
                 },
 
@@ -543,7 +738,10 @@ impl ResourceNativePackage {
                     },
                     events: BlueprintEventSchemaInit::default(),
                     types: BlueprintTypeSchemaInit::default(),
-                    functions: BlueprintFunctionsSchemaInit { functions },
+                    functions: BlueprintFunctionsSchemaInit {
+                        functions,
+                        post_conditions: index_map_new(),
+                    },
                     hooks: BlueprintHooksInit::default(),
                 },
 
@@ -657,7 +855,10 @@ impl ResourceNativePackage {
                     },
                     events: BlueprintEventSchemaInit::default(),
                     types: BlueprintTypeSchemaInit::default(),
-                    functions: BlueprintFunctionsSchemaInit { functions },
+                    functions: BlueprintFunctionsSchemaInit {
+                        functions,
+                        post_conditions: index_map_new(),
+                    },
                     hooks: BlueprintHooksInit {
                         hooks: indexmap!(
                             BlueprintHook::OnDrop => FUNGIBLE_PROOF_ON_DROP_EXPORT_NAME.to_string(),
@@ -775,7 +976,10 @@ impl ResourceNativePackage {
                     },
                     events: BlueprintEventSchemaInit::default(),
                     types: BlueprintTypeSchemaInit::default(),
-                    functions: BlueprintFunctionsSchemaInit { functions },
+                    functions: BlueprintFunctionsSchemaInit {
+                        functions,
+                        post_conditions: index_map_new(),
+                    },
                     hooks: BlueprintHooksInit {
                         hooks: indexmap!(
                             BlueprintHook::OnDrop => NON_FUNGIBLE_PROOF_ON_DROP_EXPORT_NAME.to_string(),
@@ -937,7 +1141,10 @@ impl ResourceNativePackage {
                     },
                     events: BlueprintEventSchemaInit::default(),
                     types: BlueprintTypeSchemaInit::default(),
-                    functions: BlueprintFunctionsSchemaInit { functions },
+                    functions: BlueprintFunctionsSchemaInit {
+                        functions,
+                        post_conditions: index_map_new(),
+                    },
                     hooks: BlueprintHooksInit::default(),
                 },
 
@@ -1116,7 +1323,10 @@ impl ResourceNativePackage {
                     state: auth_zone_blueprint,
                     events: BlueprintEventSchemaInit::default(),
                     types: BlueprintTypeSchemaInit::default(),
-                    functions: BlueprintFunctionsSchemaInit { functions },
+                    functions: BlueprintFunctionsSchemaInit {
+                        functions,
+                        post_conditions: index_map_new(),
+                    },
                     hooks: BlueprintHooksInit::default(),
                 },
 
@@ -1152,8 +1362,8 @@ impl ResourceNativePackage {
     where
         Y: KernelNodeApi + KernelSubstateApi<SystemLockData> + ClientApi<RuntimeError>,
     {
-        match export_name {
-            FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME => {
+        match ResourceNativeExport::from_export_name(export_name)? {
+            ResourceNativeExport::FungibleResourceManagerCreate => {
                 let input: FungibleResourceManagerCreateInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1161,18 +1371,51 @@ impl ResourceNativePackage {
                     input.owner_role,
                     input.track_total_supply,
                     input.divisibility,
-                    input.resource_roles,
+                    input.resource_roles.into(),
                     input.metadata,
                     input.address_reservation,
                     api,
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME => {
+            ResourceNativeExport::FungibleResourceManagerCreateWithInitialSupply => {
                 let input: FungibleResourceManagerCreateWithInitialSupplyInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                     })?;
+                let rtn = FungibleResourceManagerBlueprint::create_with_initial_supply(
+                    input.owner_role,
+                    input.track_total_supply,
+                    input.divisibility,
+                    input.initial_supply,
+                    input.resource_roles.into(),
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::FungibleResourceManagerCreateV2 => {
+                let input: FungibleResourceManagerCreateV2Input =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = FungibleResourceManagerBlueprint::create(
+                    input.owner_role,
+                    input.track_total_supply,
+                    input.divisibility,
+                    input.resource_roles,
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::FungibleResourceManagerCreateWithInitialSupplyV2 => {
+                let input: FungibleResourceManagerCreateWithInitialSupplyV2Input =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
                 let rtn = FungibleResourceManagerBlueprint::create_with_initial_supply(
                     input.owner_role,
                     input.track_total_supply,
@@ -1185,35 +1428,64 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_RESOURCE_MANAGER_MINT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleResourceManagerMint => {
                 let input: FungibleResourceManagerMintInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleResourceManagerBlueprint::mint(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_RESOURCE_MANAGER_BURN_EXPORT_NAME => {
+            ResourceNativeExport::FungibleResourceManagerBurn => {
                 let input: ResourceManagerBurnInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleResourceManagerBlueprint::burn(input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_RESOURCE_MANAGER_PACKAGE_BURN_EXPORT_NAME => {
+            ResourceNativeExport::FungibleResourceManagerPackageBurn => {
                 let input: ResourceManagerPackageBurnInput = input.as_typed().map_err(|e| {
                     RuntimeError::SystemUpstreamError(SystemUpstreamError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleResourceManagerBlueprint::package_burn(input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME => {
+            ResourceNativeExport::FungibleResourceManagerDenyHolder => {
+                let input: ResourceManagerDenyHolderInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = FungibleResourceManagerBlueprint::deny_holder(input.holder, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::FungibleResourceManagerAllowHolder => {
+                let input: ResourceManagerAllowHolderInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = FungibleResourceManagerBlueprint::allow_holder(input.holder, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::FungibleResourceManagerIsHolderDenied => {
+                let input: ResourceManagerIsHolderDeniedInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = FungibleResourceManagerBlueprint::is_holder_denied(input.holder, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::FungibleResourceManagerRecallBatch => {
+                let input: FungibleResourceManagerRecallBatchInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = FungibleResourceManagerBlueprint::recall_batch(input.vaults, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::FungibleResourceManagerDropEmptyBucket => {
                 let input: ResourceManagerDropEmptyBucketInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleResourceManagerBlueprint::drop_empty_bucket(input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_VAULT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleResourceManagerCreateEmptyVault => {
                 let _input: ResourceManagerCreateEmptyVaultInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1221,7 +1493,16 @@ impl ResourceNativePackage {
                 let rtn = FungibleResourceManagerBlueprint::create_empty_vault(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_EXPORT_NAME => {
+            ResourceNativeExport::FungibleResourceManagerCreateEmptyRecallProtectedVault => {
+                let _input: ResourceManagerCreateEmptyRecallProtectedVaultInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn =
+                    FungibleResourceManagerBlueprint::create_empty_recall_protected_vault(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::FungibleResourceManagerCreateEmptyBucket => {
                 let _input: ResourceManagerCreateEmptyBucketInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1230,7 +1511,7 @@ impl ResourceNativePackage {
                 let rtn = FungibleResourceManagerBlueprint::create_empty_bucket(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_RESOURCE_MANAGER_GET_RESOURCE_TYPE_EXPORT_NAME => {
+            ResourceNativeExport::FungibleResourceManagerGetResourceType => {
                 let _input: ResourceManagerGetResourceTypeInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1238,14 +1519,14 @@ impl ResourceNativePackage {
                 let rtn = FungibleResourceManagerBlueprint::get_resource_type(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_SUPPLY_EXPORT_NAME => {
+            ResourceNativeExport::FungibleResourceManagerGetTotalSupply => {
                 let _input: ResourceManagerGetTotalSupplyInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleResourceManagerBlueprint::get_total_supply(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME => {
+            ResourceNativeExport::FungibleResourceManagerAmountForWithdrawal => {
                 let input: ResourceManagerGetAmountForWithdrawalInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1257,7 +1538,7 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleResourceManagerCreate => {
                 let input: NonFungibleResourceManagerCreateInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1267,14 +1548,14 @@ impl ResourceNativePackage {
                     input.id_type,
                     input.track_total_supply,
                     input.non_fungible_schema,
-                    input.resource_roles,
+                    input.resource_roles.into(),
                     input.metadata,
                     input.address_reservation,
                     api,
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleResourceManagerCreateWithInitialSupply => {
                 let input: NonFungibleResourceManagerCreateWithInitialSupplyInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1285,14 +1566,14 @@ impl ResourceNativePackage {
                     input.track_total_supply,
                     input.non_fungible_schema,
                     input.entries,
-                    input.resource_roles,
+                    input.resource_roles.into(),
                     input.metadata,
                     input.address_reservation,
                     api,
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_IDENT => {
+            ResourceNativeExport::NonFungibleResourceManagerCreateRuidWithInitialSupply => {
                 let input: NonFungibleResourceManagerCreateRuidWithInitialSupplyInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1303,7 +1584,7 @@ impl ResourceNativePackage {
                     input.track_total_supply,
                     input.non_fungible_schema,
                     input.entries,
-                    input.resource_roles,
+                    input.resource_roles.into(),
                     input.metadata,
                     input.address_reservation,
                     api,
@@ -1311,7 +1592,76 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_MINT_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleResourceManagerCreateV2 => {
+                let input: NonFungibleResourceManagerCreateV2Input =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::create(
+                    input.owner_role,
+                    input.id_type,
+                    input.track_total_supply,
+                    input.non_fungible_schema,
+                    input.resource_roles,
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerCreateWithInitialSupplyV2 => {
+                let input: NonFungibleResourceManagerCreateWithInitialSupplyV2Input =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::create_with_initial_supply(
+                    input.owner_role,
+                    input.id_type,
+                    input.track_total_supply,
+                    input.non_fungible_schema,
+                    input.entries,
+                    input.resource_roles,
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerCreateRuidWithInitialSupplyV2 => {
+                let input: NonFungibleResourceManagerCreateRuidWithInitialSupplyV2Input =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::create_ruid_with_initial_supply(
+                    input.owner_role,
+                    input.track_total_supply,
+                    input.non_fungible_schema,
+                    input.entries,
+                    input.resource_roles,
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerCreateCollection => {
+                let input: NonFungibleResourceManagerCreateCollectionInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::create_collection(
+                    input.owner_role,
+                    input.id_type,
+                    input.non_fungible_schema,
+                    input.collection,
+                    input.resource_roles,
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerMint => {
                 let input: NonFungibleResourceManagerMintInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1319,7 +1669,7 @@ impl ResourceNativePackage {
                     NonFungibleResourceManagerBlueprint::mint_non_fungible(input.entries, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleResourceManagerMintRuid => {
                 let input: NonFungibleResourceManagerMintRuidInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1330,7 +1680,7 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_MINT_SINGLE_RUID_IDENT => {
+            ResourceNativeExport::NonFungibleResourceManagerMintSingleRuid => {
                 let input: NonFungibleResourceManagerMintSingleRuidInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1341,21 +1691,63 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_BURN_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleResourceManagerReserveIntegerIds => {
+                let input: NonFungibleResourceManagerReserveIntegerIdsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn =
+                    NonFungibleResourceManagerBlueprint::reserve_integer_ids(input.count, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerMintReserved => {
+                let input: NonFungibleResourceManagerMintReservedInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::mint_reserved(
+                    input.reservation,
+                    input.entries,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerBurn => {
                 let input: ResourceManagerBurnInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleResourceManagerBlueprint::burn(input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_PACKAGE_BURN_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleResourceManagerPackageBurn => {
                 let input: ResourceManagerPackageBurnInput = input.as_typed().map_err(|e| {
                     RuntimeError::SystemUpstreamError(SystemUpstreamError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleResourceManagerBlueprint::package_burn(input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleResourceManagerDenyHolder => {
+                let input: ResourceManagerDenyHolderInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = NonFungibleResourceManagerBlueprint::deny_holder(input.holder, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerAllowHolder => {
+                let input: ResourceManagerAllowHolderInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = NonFungibleResourceManagerBlueprint::allow_holder(input.holder, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerIsHolderDenied => {
+                let input: ResourceManagerIsHolderDeniedInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = NonFungibleResourceManagerBlueprint::is_holder_denied(input.holder, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerDropEmptyBucket => {
                 let input: ResourceManagerDropEmptyBucketInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1363,7 +1755,7 @@ impl ResourceNativePackage {
                     NonFungibleResourceManagerBlueprint::drop_empty_bucket(input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleResourceManagerCreateEmptyBucket => {
                 let _input: ResourceManagerCreateEmptyBucketInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1372,7 +1764,7 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleResourceManagerBlueprint::create_empty_bucket(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_VAULT_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleResourceManagerCreateEmptyVault => {
                 let _input: ResourceManagerCreateEmptyVaultInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1381,7 +1773,17 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_IDENT => {
+            ResourceNativeExport::NonFungibleResourceManagerCreateEmptyRecallProtectedVault => {
+                let _input: ResourceManagerCreateEmptyRecallProtectedVaultInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn =
+                    NonFungibleResourceManagerBlueprint::create_empty_recall_protected_vault(api)?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerUpdateData => {
                 let input: NonFungibleResourceManagerUpdateDataInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1394,7 +1796,7 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT => {
+            ResourceNativeExport::NonFungibleResourceManagerExists => {
                 let input: NonFungibleResourceManagerExistsInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1402,7 +1804,76 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleResourceManagerBlueprint::non_fungible_exists(input.id, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_GET_RESOURCE_TYPE_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleResourceManagerGetTotalMinted => {
+                let _input: NonFungibleResourceManagerGetTotalMintedInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::get_total_minted(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerGetMintedIds => {
+                let input: NonFungibleResourceManagerGetMintedIdsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::get_minted_non_fungible_ids(
+                    input.start_index,
+                    input.limit,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerRecountTotalSupply => {
+                let input: NonFungibleResourceManagerRecountTotalSupplyInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn =
+                    NonFungibleResourceManagerBlueprint::recount_total_supply(input.limit, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerSetTransferRoyalty => {
+                let input: NonFungibleResourceManagerSetTransferRoyaltyInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn =
+                    NonFungibleResourceManagerBlueprint::set_transfer_royalty(input.royalty, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerGetTransferRoyalty => {
+                let _input: NonFungibleResourceManagerGetTransferRoyaltyInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::get_transfer_royalty(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerClaimTransferRoyalties => {
+                let input: NonFungibleResourceManagerClaimTransferRoyaltiesInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::claim_transfer_royalties(
+                    input.resource_address,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerCollectTransferRoyalty => {
+                let input: NonFungibleResourceManagerCollectTransferRoyaltyInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::collect_transfer_royalty(
+                    input.amount,
+                    input.payment,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleResourceManagerGetResourceType => {
                 let _input: ResourceManagerGetResourceTypeInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1410,14 +1881,14 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleResourceManagerBlueprint::get_resource_type(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_SUPPLY_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleResourceManagerGetTotalSupply => {
                 let _input: ResourceManagerGetTotalSupplyInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleResourceManagerBlueprint::get_total_supply(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleResourceManagerAmountForWithdrawal => {
                 let input: ResourceManagerGetAmountForWithdrawalInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1429,7 +1900,7 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_IDENT => {
+            ResourceNativeExport::NonFungibleResourceManagerGetNonFungible => {
                 let input: NonFungibleResourceManagerGetNonFungibleInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1438,21 +1909,33 @@ impl ResourceNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            FUNGIBLE_VAULT_LOCK_FEE_IDENT => {
+            ResourceNativeExport::FungibleVaultLockFee => {
                 let input: FungibleVaultLockFeeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleVaultBlueprint::lock_fee(input.amount, input.contingent, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_VAULT_TAKE_EXPORT_NAME => {
+            ResourceNativeExport::FungibleVaultLockFeeWithRefund => {
+                let input: FungibleVaultLockFeeWithRefundInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = FungibleVaultBlueprint::lock_fee_with_refund(
+                    input.amount,
+                    input.contingent,
+                    input.refund_vault.0,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::FungibleVaultTake => {
                 let input: VaultTakeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleVaultBlueprint::take(&input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_VAULT_TAKE_ADVANCED_EXPORT_NAME => {
+            ResourceNativeExport::FungibleVaultTakeAdvanced => {
                 let input: VaultTakeAdvancedInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1463,42 +1946,49 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_VAULT_RECALL_EXPORT_NAME => {
+            ResourceNativeExport::FungibleVaultRecall => {
                 let input: VaultRecallInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleVaultBlueprint::recall(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_VAULT_FREEZE_EXPORT_NAME => {
+            ResourceNativeExport::FungibleVaultBatchRecall => {
+                let input: FungibleVaultBatchRecallInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = FungibleVaultBlueprint::batch_recall(input.amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::FungibleVaultFreeze => {
                 let input: VaultFreezeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleVaultBlueprint::freeze(input.to_freeze, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_VAULT_UNFREEZE_EXPORT_NAME => {
+            ResourceNativeExport::FungibleVaultUnfreeze => {
                 let input: VaultUnfreezeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleVaultBlueprint::unfreeze(input.to_unfreeze, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_VAULT_PUT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleVaultPut => {
                 let input: VaultPutInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleVaultBlueprint::put(input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_VAULT_GET_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleVaultGetAmount => {
                 let _input: VaultGetAmountInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleVaultBlueprint::get_amount(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_VAULT_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleVaultCreateProofOfAmount => {
                 let input: FungibleVaultCreateProofOfAmountInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1506,7 +1996,7 @@ impl ResourceNativePackage {
                 let rtn = FungibleVaultBlueprint::create_proof_of_amount(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_VAULT_LOCK_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleVaultLockAmount => {
                 let input: FungibleVaultLockFungibleAmountInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1514,7 +2004,7 @@ impl ResourceNativePackage {
                 let rtn = FungibleVaultBlueprint::lock_amount(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_VAULT_UNLOCK_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleVaultUnlockAmount => {
                 let input: FungibleVaultUnlockFungibleAmountInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1522,15 +2012,45 @@ impl ResourceNativePackage {
                 let rtn = FungibleVaultBlueprint::unlock_amount(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_VAULT_BURN_EXPORT_NAME => {
+            ResourceNativeExport::FungibleVaultBurn => {
                 let input: VaultBurnInput = input.as_typed().map_err(|e| {
                     RuntimeError::SystemUpstreamError(SystemUpstreamError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleVaultBlueprint::burn(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ResourceNativeExport::FungibleVaultGrantAllowance => {
+                let input: VaultGrantAllowanceInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn =
+                    FungibleVaultBlueprint::grant_allowance(input.grantee, input.allowance, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::FungibleVaultRevokeAllowance => {
+                let input: VaultRevokeAllowanceInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = FungibleVaultBlueprint::revoke_allowance(input.grantee, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::FungibleVaultGetAllowance => {
+                let input: VaultGetAllowanceInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = FungibleVaultBlueprint::get_allowance(input.grantee, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::FungibleVaultTakeWithAllowance => {
+                let input: VaultTakeWithAllowanceInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn =
+                    FungibleVaultBlueprint::take_with_allowance(input.grantee, input.amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
 
-            NON_FUNGIBLE_VAULT_TAKE_ADVANCED_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleVaultTakeAdvanced => {
                 let input: VaultTakeAdvancedInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1541,14 +2061,14 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_TAKE_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleVaultTake => {
                 let input: VaultTakeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleVaultBlueprint::take(&input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_IDENT => {
+            ResourceNativeExport::NonFungibleVaultTakeNonFungibles => {
                 let input: NonFungibleVaultTakeNonFungiblesInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1559,28 +2079,28 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_RECALL_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleVaultRecall => {
                 let input: VaultRecallInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleVaultBlueprint::recall(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_FREEZE_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleVaultFreeze => {
                 let input: VaultFreezeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleVaultBlueprint::freeze(input.to_freeze, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_UNFREEZE_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleVaultUnfreeze => {
                 let input: VaultUnfreezeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleVaultBlueprint::unfreeze(input.to_unfreeze, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT => {
+            ResourceNativeExport::NonFungibleVaultRecallNonFungibles => {
                 let input: NonFungibleVaultRecallNonFungiblesInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1591,21 +2111,21 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_PUT_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleVaultPut => {
                 let input: VaultPutInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleVaultBlueprint::put(input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_GET_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleVaultGetAmount => {
                 let _input: VaultGetAmountInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleVaultBlueprint::get_amount(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT => {
+            ResourceNativeExport::NonFungibleVaultGetNonFungibleLocalIds => {
                 let input: NonFungibleVaultGetNonFungibleLocalIdsInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1613,7 +2133,7 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleVaultBlueprint::get_non_fungible_local_ids(input.limit, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_CONTAINS_NON_FUNGIBLE_IDENT => {
+            ResourceNativeExport::NonFungibleVaultContainsNonFungible => {
                 let input: NonFungibleVaultContainsNonFungibleInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1621,7 +2141,7 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleVaultBlueprint::contains_non_fungible(input.id, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => {
+            ResourceNativeExport::NonFungibleVaultCreateProofOfNonFungibles => {
                 let input: NonFungibleVaultCreateProofOfNonFungiblesInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1629,7 +2149,7 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleVaultBlueprint::create_proof_of_non_fungibles(input.ids, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_LOCK_NON_FUNGIBLES_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleVaultLockNonFungibles => {
                 let input: NonFungibleVaultLockNonFungiblesInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1637,7 +2157,7 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleVaultBlueprint::lock_non_fungibles(&input.local_ids, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_UNLOCK_NON_FUNGIBLES_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleVaultUnlockNonFungibles => {
                 let input: NonFungibleVaultUnlockNonFungiblesInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1645,14 +2165,14 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleVaultBlueprint::unlock_non_fungibles(input.local_ids, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_BURN_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleVaultBurn => {
                 let input: VaultBurnInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleVaultBlueprint::burn(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_VAULT_BURN_NON_FUNGIBLES_IDENT => {
+            ResourceNativeExport::NonFungibleVaultBurnNonFungibles => {
                 let input: NonFungibleVaultBurnNonFungiblesInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1663,22 +2183,82 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ResourceNativeExport::NonFungibleVaultGrantAllowance => {
+                let input: VaultGrantAllowanceInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = NonFungibleVaultBlueprint::grant_allowance(
+                    input.grantee,
+                    input.allowance,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleVaultRevokeAllowance => {
+                let input: VaultRevokeAllowanceInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = NonFungibleVaultBlueprint::revoke_allowance(input.grantee, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleVaultGetAllowance => {
+                let input: VaultGetAllowanceInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = NonFungibleVaultBlueprint::get_allowance(input.grantee, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleVaultTakeWithAllowance => {
+                let input: VaultTakeWithAllowanceInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = NonFungibleVaultBlueprint::take_with_allowance(
+                    input.grantee,
+                    input.amount,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleVaultTakeNonFungiblesWithAllowance => {
+                let input: NonFungibleVaultTakeNonFungiblesWithAllowanceInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleVaultBlueprint::take_non_fungibles_with_allowance(
+                    input.grantee,
+                    &input.non_fungible_local_ids,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ResourceNativeExport::NonFungibleVaultTakeNonFungiblesWithRoyalty => {
+                let input: NonFungibleVaultTakeNonFungiblesWithRoyaltyInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleVaultBlueprint::take_non_fungibles_with_royalty(
+                    &input.non_fungible_local_ids,
+                    input.payment,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
 
-            FUNGIBLE_PROOF_CLONE_EXPORT_NAME => {
+            ResourceNativeExport::FungibleProofClone => {
                 let _input: ProofCloneInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleProofBlueprint::clone(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_PROOF_GET_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleProofGetAmount => {
                 let _input: ProofGetAmountInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleProofBlueprint::get_amount(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_PROOF_GET_RESOURCE_ADDRESS_EXPORT_NAME => {
+            ResourceNativeExport::FungibleProofGetResourceAddress => {
                 let _input: ProofGetResourceAddressInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1686,21 +2266,21 @@ impl ResourceNativePackage {
                 let rtn = FungibleProofBlueprint::get_resource_address(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_PROOF_DROP_EXPORT_NAME => {
+            ResourceNativeExport::FungibleProofDrop => {
                 let input: ProofDropInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleProofBlueprint::drop(input.proof, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_PROOF_ON_DROP_EXPORT_NAME => {
+            ResourceNativeExport::FungibleProofOnDrop => {
                 let _input: OnDropInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleProofBlueprint::on_drop(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_PROOF_ON_MOVE_EXPORT_NAME => {
+            ResourceNativeExport::FungibleProofOnMove => {
                 let input: OnMoveInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1712,21 +2292,21 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_PROOF_CLONE_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleProofClone => {
                 let _input: ProofCloneInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleProofBlueprint::clone(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_PROOF_GET_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleProofGetAmount => {
                 let _input: ProofGetAmountInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleProofBlueprint::get_amount(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_PROOF_GET_LOCAL_IDS_IDENT => {
+            ResourceNativeExport::NonFungibleProofGetLocalIds => {
                 let _input: NonFungibleProofGetLocalIdsInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1734,7 +2314,7 @@ impl ResourceNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            NON_FUNGIBLE_PROOF_GET_RESOURCE_ADDRESS_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleProofGetResourceAddress => {
                 let _input: ProofGetResourceAddressInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1742,21 +2322,21 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleProofBlueprint::get_resource_address(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_PROOF_DROP_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleProofDrop => {
                 let input: ProofDropInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleProofBlueprint::drop(input.proof, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_PROOF_ON_DROP_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleProofOnDrop => {
                 let _input: OnDropInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleProofBlueprint::on_drop(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_PROOF_ON_MOVE_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleProofOnMove => {
                 let input: OnMoveInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1769,21 +2349,21 @@ impl ResourceNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            FUNGIBLE_BUCKET_PUT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleBucketPut => {
                 let input: BucketPutInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleBucketBlueprint::put(input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_BUCKET_TAKE_EXPORT_NAME => {
+            ResourceNativeExport::FungibleBucketTake => {
                 let input: BucketTakeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleBucketBlueprint::take(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_BUCKET_TAKE_ADVANCED_EXPORT_NAME => {
+            ResourceNativeExport::FungibleBucketTakeAdvanced => {
                 let input: BucketTakeAdvancedInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1794,7 +2374,7 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_BUCKET_GET_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleBucketGetAmount => {
                 let _input: BucketGetAmountInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1803,14 +2383,14 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&amount))
             }
-            FUNGIBLE_BUCKET_GET_RESOURCE_ADDRESS_EXPORT_NAME => {
+            ResourceNativeExport::FungibleBucketGetResourceAddress => {
                 let _input: BucketGetResourceAddressInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleBucketBlueprint::get_resource_address(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_BUCKET_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleBucketCreateProofOfAmount => {
                 let input: FungibleBucketCreateProofOfAmountInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1818,42 +2398,42 @@ impl ResourceNativePackage {
                 let rtn = FungibleBucketBlueprint::create_proof_of_amount(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_BUCKET_CREATE_PROOF_OF_ALL_EXPORT_NAME => {
+            ResourceNativeExport::FungibleBucketCreateProofOfAll => {
                 let _input: BucketCreateProofOfAllInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleBucketBlueprint::create_proof_of_all(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_BUCKET_LOCK_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleBucketLockAmount => {
                 let input: FungibleBucketLockAmountInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleBucketBlueprint::lock_amount(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            FUNGIBLE_BUCKET_UNLOCK_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::FungibleBucketUnlockAmount => {
                 let input: FungibleBucketLockAmountInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = FungibleBucketBlueprint::unlock_amount(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_BUCKET_PUT_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleBucketPut => {
                 let input: BucketPutInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleBucketBlueprint::put(input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_BUCKET_TAKE_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleBucketTake => {
                 let input: BucketTakeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleBucketBlueprint::take(&input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_BUCKET_TAKE_ADVANCED_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleBucketTakeAdvanced => {
                 let input: BucketTakeAdvancedInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1864,14 +2444,14 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_BUCKET_TAKE_NON_FUNGIBLES_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleBucketTakeNonFungibles => {
                 let input: BucketTakeNonFungiblesInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleBucketBlueprint::take_non_fungibles(&input.ids, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_BUCKET_GET_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleBucketGetAmount => {
                 let _input: BucketGetAmountInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1880,14 +2460,14 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&amount))
             }
-            NON_FUNGIBLE_BUCKET_GET_RESOURCE_ADDRESS_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleBucketGetResourceAddress => {
                 let _input: BucketGetResourceAddressInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let address = NonFungibleBucketBlueprint::get_resource_address(api)?;
                 Ok(IndexedScryptoValue::from_typed(&address))
             }
-            NON_FUNGIBLE_BUCKET_CREATE_PROOF_OF_NON_FUNGIBLES_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleBucketCreateProofOfNonFungibles => {
                 let input: NonFungibleBucketCreateProofOfNonFungiblesInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1896,7 +2476,7 @@ impl ResourceNativePackage {
                     NonFungibleBucketBlueprint::create_proof_of_non_fungibles(input.ids, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_BUCKET_CREATE_PROOF_OF_ALL_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleBucketCreateProofOfAll => {
                 let _input: BucketCreateProofOfAllInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1904,14 +2484,14 @@ impl ResourceNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            NON_FUNGIBLE_BUCKET_GET_NON_FUNGIBLE_LOCAL_IDS_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleBucketGetNonFungibleLocalIds => {
                 let _input: BucketGetNonFungibleLocalIdsInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = NonFungibleBucketBlueprint::get_non_fungible_local_ids(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_BUCKET_CONTAINS_NON_FUNGIBLE_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleBucketContainsNonFungible => {
                 let input: NonFungibleVaultContainsNonFungibleInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1919,7 +2499,7 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleBucketBlueprint::contains_non_fungible(input.id, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_BUCKET_LOCK_NON_FUNGIBLES_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleBucketLockNonFungibles => {
                 let input: NonFungibleBucketLockNonFungiblesInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1927,7 +2507,7 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleBucketBlueprint::lock_non_fungibles(&input.local_ids, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            NON_FUNGIBLE_BUCKET_UNLOCK_NON_FUNGIBLES_EXPORT_NAME => {
+            ResourceNativeExport::NonFungibleBucketUnlockNonFungibles => {
                 let input: NonFungibleBucketUnlockNonFungiblesInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1936,20 +2516,24 @@ impl ResourceNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
-            WORKTOP_DROP_IDENT => WorktopBlueprint::drop(input, api),
-            WORKTOP_PUT_IDENT => WorktopBlueprint::put(input, api),
-            WORKTOP_TAKE_IDENT => WorktopBlueprint::take(input, api),
-            WORKTOP_TAKE_NON_FUNGIBLES_IDENT => WorktopBlueprint::take_non_fungibles(input, api),
-            WORKTOP_TAKE_ALL_IDENT => WorktopBlueprint::take_all(input, api),
-            WORKTOP_ASSERT_CONTAINS_IDENT => WorktopBlueprint::assert_contains(input, api),
-            WORKTOP_ASSERT_CONTAINS_AMOUNT_IDENT => {
+            ResourceNativeExport::WorktopDrop => WorktopBlueprint::drop(input, api),
+            ResourceNativeExport::WorktopPut => WorktopBlueprint::put(input, api),
+            ResourceNativeExport::WorktopTake => WorktopBlueprint::take(input, api),
+            ResourceNativeExport::WorktopTakeNonFungibles => {
+                WorktopBlueprint::take_non_fungibles(input, api)
+            }
+            ResourceNativeExport::WorktopTakeAll => WorktopBlueprint::take_all(input, api),
+            ResourceNativeExport::WorktopAssertContains => {
+                WorktopBlueprint::assert_contains(input, api)
+            }
+            ResourceNativeExport::WorktopAssertContainsAmount => {
                 WorktopBlueprint::assert_contains_amount(input, api)
             }
-            WORKTOP_ASSERT_CONTAINS_NON_FUNGIBLES_IDENT => {
+            ResourceNativeExport::WorktopAssertContainsNonFungibles => {
                 WorktopBlueprint::assert_contains_non_fungibles(input, api)
             }
-            WORKTOP_DRAIN_IDENT => WorktopBlueprint::drain(input, api),
-            AUTH_ZONE_POP_EXPORT_NAME => {
+            ResourceNativeExport::WorktopDrain => WorktopBlueprint::drain(input, api),
+            ResourceNativeExport::AuthZonePop => {
                 let _input: AuthZonePopInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1958,7 +2542,7 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&proof))
             }
-            AUTH_ZONE_PUSH_EXPORT_NAME => {
+            ResourceNativeExport::AuthZonePush => {
                 let input: AuthZonePushInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1967,7 +2551,7 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&()))
             }
-            AUTH_ZONE_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME => {
+            ResourceNativeExport::AuthZoneCreateProofOfAmount => {
                 let input: AuthZoneCreateProofOfAmountInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -1980,7 +2564,7 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&proof))
             }
-            AUTH_ZONE_CREATE_PROOF_OF_NON_FUNGIBLES_EXPORT_NAME => {
+            ResourceNativeExport::AuthZoneCreateProofOfNonFungibles => {
                 let input: AuthZoneCreateProofOfNonFungiblesInput =
                     input.as_typed().map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1994,7 +2578,7 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&proof))
             }
-            AUTH_ZONE_CREATE_PROOF_OF_ALL_EXPORT_NAME => {
+            ResourceNativeExport::AuthZoneCreateProofOfAll => {
                 let input: AuthZoneCreateProofOfAllInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -2003,7 +2587,7 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&proof))
             }
-            AUTH_ZONE_DROP_PROOFS_EXPORT_NAME => {
+            ResourceNativeExport::AuthZoneDropProofs => {
                 let _input: AuthZoneDropProofsInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -2012,7 +2596,7 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&()))
             }
-            AUTH_ZONE_DROP_SIGNATURE_PROOFS_EXPORT_NAME => {
+            ResourceNativeExport::AuthZoneDropSignatureProofs => {
                 let _input: AuthZoneDropProofsInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -2021,7 +2605,7 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&()))
             }
-            AUTH_ZONE_DROP_REGULAR_PROOFS_EXPORT_NAME => {
+            ResourceNativeExport::AuthZoneDropRegularProofs => {
                 let _input: AuthZoneDropProofsInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -2030,7 +2614,7 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&()))
             }
-            AUTH_ZONE_DRAIN_EXPORT_NAME => {
+            ResourceNativeExport::AuthZoneDrain => {
                 let _input: AuthZoneDrainInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -2039,7 +2623,7 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&proofs))
             }
-            AUTH_ZONE_ASSERT_ACCESS_RULE_EXPORT_NAME => {
+            ResourceNativeExport::AuthZoneAssertAccessRule => {
                 let input: AuthZoneAssertAccessRuleInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -2048,9 +2632,6 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
         }
     }
 }
@@ -1,6 +1,13 @@
 use crate::blueprints::resource::*;
 use crate::errors::{ApplicationError, RuntimeError};
 use crate::types::*;
+use radix_engine_interface::api::node_modules::auth::{
+    RoleAssignmentGetInput, RoleAssignmentGetOutput, ROLE_ASSIGNMENT_GET_IDENT,
+};
+use native_sdk::runtime::Runtime;
+use radix_engine_interface::api::{
+    AttachedModuleId, ClientApi, ModuleId, ACTOR_REF_GLOBAL, ACTOR_REF_OUTER,
+};
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::types::*;
 
@@ -11,10 +18,17 @@ pub enum VaultError {
     InvalidAmount(Decimal),
     NotFreezable,
     NotRecallable,
+    RecallNotLocked(ResourceAddress),
     VaultIsFrozen,
     LockFeeNotRadixToken,
     LockFeeInsufficientBalance { requested: Decimal, actual: Decimal },
+    InvalidFeeRefundVault(NodeId),
     DecimalOverflow,
+    InvalidAllowance(WithdrawAllowance),
+    NoAllowance(ResourceOrNonFungible),
+    AllowanceExpired { expiry_epoch: Epoch },
+    AllowanceExceeded,
+    HolderDenied(GlobalAddress),
 }
 
 impl From<VaultError> for RuntimeError {
@@ -31,4 +45,113 @@ impl VaultUtil {
             && (blueprint.blueprint_name.eq(NON_FUNGIBLE_VAULT_BLUEPRINT)
                 || blueprint.blueprint_name.eq(FUNGIBLE_VAULT_BLUEPRINT))
     }
+
+    /// Checks that vaults of the resource manager being called can never be recalled from,
+    /// meaning that either recall is disabled or both the recaller role and its updater are
+    /// locked to `DenyAll`.
+    pub fn ensure_recall_locked<Y>(is_recallable: bool, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !is_recallable {
+            return Ok(());
+        }
+
+        let resource_address = api.actor_get_node_id(ACTOR_REF_GLOBAL)?;
+        for role in [RECALLER_ROLE, RECALLER_UPDATER_ROLE] {
+            let rtn = api.call_module_method(
+                &resource_address,
+                AttachedModuleId::RoleAssignment,
+                ROLE_ASSIGNMENT_GET_IDENT,
+                scrypto_encode(&RoleAssignmentGetInput {
+                    module: ModuleId::Main,
+                    role_key: RoleKey::new(role),
+                })
+                .unwrap(),
+            )?;
+            let rule: RoleAssignmentGetOutput = scrypto_decode(&rtn).unwrap();
+            if !matches!(rule, Some(AccessRule::DenyAll)) {
+                return Err(VaultError::RecallNotLocked(ResourceAddress::new_or_panic(
+                    resource_address.into(),
+                ))
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the transfer exemptor rule of a soulbound resource is satisfied, which the
+    /// vaults of such a resource require for every withdrawal and deposit.
+    pub fn assert_transfer_exempt<Y>(is_soulbound: bool, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !is_soulbound {
+            return Ok(());
+        }
+
+        Self::assert_resource_role(TRANSFER_EXEMPTOR_ROLE, api)
+    }
+
+    /// Checks the rule of one of the vault's resource roles against the current auth zone. A role
+    /// which was never set is satisfied by nothing, rather than falling back to the owner like it
+    /// would for method auth.
+    pub fn assert_resource_role<Y>(role: &str, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let resource_address = api.actor_get_node_id(ACTOR_REF_OUTER)?;
+        let rtn = api.call_module_method(
+            &resource_address,
+            AttachedModuleId::RoleAssignment,
+            ROLE_ASSIGNMENT_GET_IDENT,
+            scrypto_encode(&RoleAssignmentGetInput {
+                module: ModuleId::Main,
+                role_key: RoleKey::new(role),
+            })
+            .unwrap(),
+        )?;
+        let rule: RoleAssignmentGetOutput = scrypto_decode(&rtn).unwrap();
+        Runtime::assert_access_rule(rule.unwrap_or(AccessRule::DenyAll), api)
+    }
+
+    /// Deducts a withdrawal of either an amount or the given non-fungibles from an allowance.
+    ///
+    /// Non-fungibles can be withdrawn under either kind of limit, whereas an amount can only be
+    /// withdrawn under an amount limit.
+    pub fn spend_allowance(
+        allowance: &mut WithdrawAllowance,
+        amount: Decimal,
+        non_fungible_local_ids: Option<&IndexSet<NonFungibleLocalId>>,
+        current_epoch: Epoch,
+    ) -> Result<(), VaultError> {
+        if let Some(expiry_epoch) = allowance.expiry_epoch {
+            if current_epoch >= expiry_epoch {
+                return Err(VaultError::AllowanceExpired { expiry_epoch });
+            }
+        }
+
+        match (&mut allowance.limit, non_fungible_local_ids) {
+            (AllowanceLimit::Amount(remaining), _) => {
+                if amount > *remaining {
+                    return Err(VaultError::AllowanceExceeded);
+                }
+                *remaining = remaining
+                    .checked_sub(amount)
+                    .ok_or(VaultError::DecimalOverflow)?;
+            }
+            (AllowanceLimit::NonFungibles(allowed), Some(ids)) => {
+                if !ids.is_subset(allowed) {
+                    return Err(VaultError::AllowanceExceeded);
+                }
+                allowed.retain(|id| !ids.contains(id));
+            }
+            (AllowanceLimit::NonFungibles(..), None) => {
+                return Err(VaultError::AllowanceExceeded);
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -41,7 +41,10 @@ impl TestUtilsBlueprint {
                     event_schema: Default::default(),
                 },
                 types: BlueprintTypeSchemaInit::default(),
-                functions: BlueprintFunctionsSchemaInit { functions },
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
                 hooks: BlueprintHooksInit {
                     hooks: Default::default(),
                 },
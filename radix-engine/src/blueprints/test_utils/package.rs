@@ -1,5 +1,6 @@
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
+use crate::native_exports;
 use crate::types::*;
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::package::PackageDefinition;
@@ -7,6 +8,12 @@ use radix_engine_interface::blueprints::test_utils::invocations::*;
 
 use super::TestUtilsBlueprint;
 
+native_exports! {
+    pub enum TestUtilsNativeExport {
+        Panic => TEST_UTILS_PANIC_IDENT,
+    }
+}
+
 pub struct TestUtilsNativePackage;
 
 impl TestUtilsNativePackage {
@@ -26,17 +33,14 @@ impl TestUtilsNativePackage {
     where
         Y: ClientApi<RuntimeError>,
     {
-        match export_name {
-            TEST_UTILS_PANIC_IDENT => {
+        match TestUtilsNativeExport::from_export_name(export_name)? {
+            TestUtilsNativeExport::Panic => {
                 let TestUtilsPanicInput(input) = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = TestUtilsBlueprint::panic(input.as_str(), api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
         }
     }
 }
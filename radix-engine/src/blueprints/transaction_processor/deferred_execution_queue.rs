@@ -0,0 +1,341 @@
+use crate::internal_prelude::*;
+use native_sdk::modules::metadata::*;
+use native_sdk::modules::role_assignment::*;
+use native_sdk::resource::*;
+use native_sdk::runtime::*;
+use radix_engine_interface::blueprints::transaction_processor::*;
+use radix_engine_interface::prelude::*;
+use transaction::model::InstructionV1;
+use transaction::validation::NotarizedTransactionValidator;
+
+declare_native_blueprint_state! {
+    blueprint_ident: DeferredExecutionQueue,
+    blueprint_snake_case: deferred_execution_queue,
+    features: {
+    },
+    fields: {
+        state:  {
+            ident: State,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
+        }
+    },
+    collections: {
+        intents: KeyValue {
+            entry_ident: Intent,
+            key_type: {
+                kind: Static,
+                content_type: u64,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+    }
+}
+
+pub type DeferredExecutionQueueStateV1 = DeferredExecutionQueueSubstate;
+pub type DeferredExecutionQueueIntentV1 = DeferredIntent;
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct DeferredExecutionQueueSubstate {
+    /// The XRD paid for the queued intents.
+    pub vault: Vault,
+
+    /// The id of the next intent to be enqueued.
+    pub next_intent_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum DeferredExecutionQueueError {
+    InvalidInstructions(String),
+    InstructionsDecodeError(DecodeError),
+    PaymentNotXrd,
+    InvalidEpochRange {
+        execute_at_epoch: Epoch,
+        expiry_epoch: Epoch,
+    },
+    IntentNotFound {
+        intent_id: u64,
+    },
+    IntentNotDue {
+        intent_id: u64,
+        execute_at_epoch: Epoch,
+    },
+    IntentExpired {
+        intent_id: u64,
+        expiry_epoch: Epoch,
+    },
+}
+
+impl From<DeferredExecutionQueueError> for RuntimeError {
+    fn from(error: DeferredExecutionQueueError) -> Self {
+        Self::ApplicationError(ApplicationError::DeferredExecutionQueueError(error))
+    }
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct IntentEnqueuedEvent {
+    pub intent_id: u64,
+    pub execute_at_epoch: Epoch,
+    pub expiry_epoch: Epoch,
+    pub payment: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct IntentCancelledEvent {
+    pub intent_id: u64,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct IntentExecutedEvent {
+    pub intent_id: u64,
+}
+
+pub struct DeferredExecutionQueueBlueprint;
+
+impl DeferredExecutionQueueBlueprint {
+    pub fn create<Y>(
+        address_reservation: GlobalAddressReservation,
+        api: &mut Y,
+    ) -> Result<ComponentAddress, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let role_assignment = RoleAssignment::create(OwnerRole::None, indexmap!(), api)?.0;
+        let metadata = Metadata::create(api)?;
+        let object_id = {
+            let substate = DeferredExecutionQueueSubstate {
+                vault: Vault::create(XRD, api)?,
+                next_intent_id: 0,
+            };
+            api.new_simple_object(
+                DEFERRED_EXECUTION_QUEUE_BLUEPRINT,
+                indexmap! {
+                    DeferredExecutionQueueField::State.field_index() => FieldValue::new(DeferredExecutionQueueStateFieldPayload::from_content_source(substate)),
+                },
+            )?
+        };
+
+        let address = api.globalize(
+            object_id,
+            indexmap!(
+                AttachedModuleId::RoleAssignment => role_assignment.0,
+                AttachedModuleId::Metadata => metadata.0,
+            ),
+            Some(address_reservation),
+        )?;
+
+        Ok(ComponentAddress::new_or_panic(address.as_node_id().0))
+    }
+
+    pub fn enqueue<Y>(
+        manifest_encoded_instructions: Vec<u8>,
+        references: Vec<Reference>,
+        execute_at_epoch: Epoch,
+        expiry_epoch: Epoch,
+        payment: Bucket,
+        canceller: AccessRule,
+        api: &mut Y,
+    ) -> Result<u64, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        // The instructions are checked here rather than only by the enqueuing transaction, as
+        // the method can be called with any bytes by application code.
+        let instructions = manifest_decode::<Vec<InstructionV1>>(&manifest_encoded_instructions)
+            .map_err(DeferredExecutionQueueError::InstructionsDecodeError)?;
+        NotarizedTransactionValidator::validate_deferred_instructions_v1(&instructions).map_err(
+            |error| DeferredExecutionQueueError::InvalidInstructions(format!("{:?}", error)),
+        )?;
+
+        let current_epoch = Runtime::current_epoch(api)?;
+        if expiry_epoch <= execute_at_epoch || expiry_epoch <= current_epoch {
+            return Err(DeferredExecutionQueueError::InvalidEpochRange {
+                execute_at_epoch,
+                expiry_epoch,
+            }
+            .into());
+        }
+        if payment.resource_address(api)? != XRD {
+            return Err(DeferredExecutionQueueError::PaymentNotXrd.into());
+        }
+        let amount = payment.amount(api)?;
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            DeferredExecutionQueueField::State.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate = api
+            .field_read_typed::<VersionedDeferredExecutionQueueState>(handle)?
+            .into_latest();
+        substate.vault.put(payment, api)?;
+        let intent_id = substate.next_intent_id;
+        substate.next_intent_id += 1;
+        api.field_write_typed(
+            handle,
+            &DeferredExecutionQueueStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            DeferredExecutionQueueCollection::IntentKeyValue.collection_index(),
+            &scrypto_encode(&intent_id).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            handle,
+            &DeferredExecutionQueueIntentEntryPayload::from_content_source(DeferredIntent {
+                manifest_encoded_instructions,
+                references,
+                execute_at_epoch,
+                expiry_epoch,
+                payment: amount,
+                canceller,
+            }),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Runtime::emit_event(
+            api,
+            IntentEnqueuedEvent {
+                intent_id,
+                execute_at_epoch,
+                expiry_epoch,
+                payment: amount,
+            },
+        )?;
+
+        Ok(intent_id)
+    }
+
+    /// Removes the intent and returns its payment, provided the caller satisfies its canceller
+    /// rule. Expired intents can only be cleared this way.
+    pub fn cancel<Y>(intent_id: u64, api: &mut Y) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let intent = Self::remove_intent(intent_id, api)?;
+        Runtime::assert_access_rule(intent.canceller, api)?;
+
+        let payment = Self::take_payment(intent.payment, api)?;
+
+        Runtime::emit_event(api, IntentCancelledEvent { intent_id })?;
+
+        Ok(payment)
+    }
+
+    /// Runs the instructions of a due intent and pays its payment to the caller.
+    ///
+    /// The intent is removed before it is run, so it is executed at most once, and a failure of
+    /// the instructions fails only the calling transaction.
+    pub fn execute<Y>(intent_id: u64, api: &mut Y) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let current_epoch = Runtime::current_epoch(api)?;
+        let intent = Self::remove_intent(intent_id, api)?;
+        if current_epoch < intent.execute_at_epoch {
+            return Err(DeferredExecutionQueueError::IntentNotDue {
+                intent_id,
+                execute_at_epoch: intent.execute_at_epoch,
+            }
+            .into());
+        }
+        if current_epoch >= intent.expiry_epoch {
+            return Err(DeferredExecutionQueueError::IntentExpired {
+                intent_id,
+                expiry_epoch: intent.expiry_epoch,
+            }
+            .into());
+        }
+
+        api.call_function(
+            TRANSACTION_PROCESSOR_PACKAGE,
+            DEFERRED_EXECUTION_QUEUE_BLUEPRINT,
+            DEFERRED_EXECUTION_QUEUE_RUN_INTENT_IDENT,
+            scrypto_encode(&DeferredExecutionQueueRunIntentInput {
+                manifest_encoded_instructions: intent.manifest_encoded_instructions,
+                references: intent.references,
+            })
+            .unwrap(),
+        )?;
+
+        let payment = Self::take_payment(intent.payment, api)?;
+
+        Runtime::emit_event(api, IntentExecutedEvent { intent_id })?;
+
+        Ok(payment)
+    }
+
+    pub fn get_intent<Y>(
+        intent_id: u64,
+        api: &mut Y,
+    ) -> Result<Option<DeferredIntent>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            DeferredExecutionQueueCollection::IntentKeyValue.collection_index(),
+            &scrypto_encode(&intent_id).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let intent = api
+            .key_value_entry_get_typed::<DeferredExecutionQueueIntentEntryPayload>(handle)?
+            .map(|intent| intent.into_latest());
+        api.key_value_entry_close(handle)?;
+
+        Ok(intent)
+    }
+
+    //================
+    // Helper Methods
+    //================
+
+    /// Reads the intent before removing it, which makes the entities it references visible to
+    /// the current frame so that they can be passed on to the intent frame.
+    fn remove_intent<Y>(intent_id: u64, api: &mut Y) -> Result<DeferredIntent, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            DeferredExecutionQueueCollection::IntentKeyValue.collection_index(),
+            &scrypto_encode(&intent_id).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        let intent = api
+            .key_value_entry_get_typed::<DeferredExecutionQueueIntentEntryPayload>(handle)?
+            .map(|intent| intent.into_latest());
+        if intent.is_some() {
+            api.key_value_entry_remove(handle)?;
+        }
+        api.key_value_entry_close(handle)?;
+
+        intent.ok_or(DeferredExecutionQueueError::IntentNotFound { intent_id }.into())
+    }
+
+    fn take_payment<Y>(amount: Decimal, api: &mut Y) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            DeferredExecutionQueueField::State.into(),
+            LockFlags::read_only(),
+        )?;
+        let mut substate = api
+            .field_read_typed::<VersionedDeferredExecutionQueueState>(handle)?
+            .into_latest();
+        let payment = substate.vault.take(amount, api)?;
+        api.field_close(handle)?;
+
+        Ok(payment)
+    }
+}
@@ -1,5 +1,7 @@
+mod deferred_execution_queue;
 mod package;
 mod tx_processor;
 
+pub use deferred_execution_queue::*;
 pub use package::*;
 pub use tx_processor::*;
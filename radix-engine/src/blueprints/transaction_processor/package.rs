@@ -1,8 +1,11 @@
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
+use crate::event_schema;
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
+use crate::native_exports;
 use crate::system::system_callback::SystemLockData;
 use crate::types::*;
+use radix_engine_interface::api::node_modules::auth::AuthAddresses;
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::package::{
     AuthConfig, BlueprintDefinitionInit, BlueprintType, FunctionAuth, MethodAuthTemplate,
@@ -11,11 +14,23 @@ use radix_engine_interface::blueprints::package::{
 use radix_engine_interface::blueprints::transaction_processor::*;
 use radix_engine_interface::schema::{
     BlueprintEventSchemaInit, BlueprintFunctionsSchemaInit, BlueprintSchemaInit,
-    BlueprintStateSchemaInit, FunctionSchemaInit, TypeRef,
+    BlueprintStateSchemaInit, FunctionSchemaInit, ReceiverInfo, TypeRef,
 };
 
-use super::TransactionProcessorBlueprint;
-use super::TransactionProcessorRunInput;
+use super::*;
+
+native_exports! {
+    pub enum TransactionProcessorNativeExport {
+        Run => TRANSACTION_PROCESSOR_RUN_IDENT,
+        DeferredExecutionQueueCreate => DEFERRED_EXECUTION_QUEUE_CREATE_EXPORT_NAME,
+        DeferredExecutionQueueEnqueue => DEFERRED_EXECUTION_QUEUE_ENQUEUE_EXPORT_NAME,
+        DeferredExecutionQueueCancel => DEFERRED_EXECUTION_QUEUE_CANCEL_EXPORT_NAME,
+        DeferredExecutionQueueExecute => DEFERRED_EXECUTION_QUEUE_EXECUTE_EXPORT_NAME,
+        DeferredExecutionQueueGetIntent => DEFERRED_EXECUTION_QUEUE_GET_INTENT_EXPORT_NAME,
+        DeferredExecutionQueueRunIntent => DEFERRED_EXECUTION_QUEUE_RUN_INTENT_EXPORT_NAME,
+        SubintentProcessorRun => SUBINTENT_PROCESSOR_RUN_EXPORT_NAME,
+    }
+}
 
 pub struct TransactionProcessorNativePackage;
 
@@ -56,6 +71,7 @@ impl TransactionProcessorNativePackage {
                     },
                     functions: BlueprintFunctionsSchemaInit {
                         functions,
+                        post_conditions: index_map_new(),
                     },
                     events: BlueprintEventSchemaInit::default(),
                     types: BlueprintTypeSchemaInit::default(),
@@ -69,7 +85,9 @@ impl TransactionProcessorNativePackage {
                     function_auth: FunctionAuth::RootOnly,
                     method_auth: MethodAuthTemplate::AllowAll,
                 },
-            }
+            },
+            DEFERRED_EXECUTION_QUEUE_BLUEPRINT.to_string() => Self::deferred_execution_queue_blueprint_definition(),
+            SUBINTENT_PROCESSOR_BLUEPRINT.to_string() => Self::subintent_processor_blueprint_definition(),
         );
 
         PackageDefinition { blueprints }
@@ -83,8 +101,8 @@ impl TransactionProcessorNativePackage {
     where
         Y: KernelNodeApi + KernelSubstateApi<SystemLockData> + ClientApi<RuntimeError>,
     {
-        match export_name {
-            TRANSACTION_PROCESSOR_RUN_IDENT => {
+        match TransactionProcessorNativeExport::from_export_name(export_name)? {
+            TransactionProcessorNativeExport::Run => {
                 let input: TransactionProcessorRunInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -94,14 +112,301 @@ impl TransactionProcessorNativePackage {
                     input.global_address_reservations,
                     input.references,
                     input.blobs,
+                    input.worktop_leftovers_recipient,
                     api,
                 )?;
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
+            TransactionProcessorNativeExport::DeferredExecutionQueueCreate => {
+                let DeferredExecutionQueueCreateInput {
+                    address_reservation,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = DeferredExecutionQueueBlueprint::create(address_reservation, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            TransactionProcessorNativeExport::DeferredExecutionQueueEnqueue => {
+                let DeferredExecutionQueueEnqueueInput {
+                    manifest_encoded_instructions,
+                    references,
+                    execute_at_epoch,
+                    expiry_epoch,
+                    payment,
+                    canceller,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = DeferredExecutionQueueBlueprint::enqueue(
+                    manifest_encoded_instructions,
+                    references,
+                    execute_at_epoch,
+                    expiry_epoch,
+                    payment,
+                    canceller,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            TransactionProcessorNativeExport::DeferredExecutionQueueCancel => {
+                let DeferredExecutionQueueCancelInput { intent_id } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = DeferredExecutionQueueBlueprint::cancel(intent_id, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            TransactionProcessorNativeExport::DeferredExecutionQueueExecute => {
+                let DeferredExecutionQueueExecuteInput { intent_id } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = DeferredExecutionQueueBlueprint::execute(intent_id, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            TransactionProcessorNativeExport::DeferredExecutionQueueGetIntent => {
+                let DeferredExecutionQueueGetIntentInput { intent_id } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = DeferredExecutionQueueBlueprint::get_intent(intent_id, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            TransactionProcessorNativeExport::DeferredExecutionQueueRunIntent => {
+                let DeferredExecutionQueueRunIntentInput {
+                    manifest_encoded_instructions,
+                    references,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = TransactionProcessorBlueprint::run_deferred(
+                    manifest_encoded_instructions,
+                    references,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            TransactionProcessorNativeExport::SubintentProcessorRun => {
+                let SubintentProcessorRunInput {
+                    manifest_encoded_instructions,
+                    references,
+                    inputs,
+                    constraints,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = TransactionProcessorBlueprint::run_subintent(
+                    manifest_encoded_instructions,
+                    references,
+                    inputs,
+                    constraints,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+        }
+    }
+
+    fn deferred_execution_queue_blueprint_definition() -> BlueprintDefinitionInit {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let feature_set = DeferredExecutionQueueFeatureSet::all_features();
+        let state = DeferredExecutionQueueStateSchemaInit::create_schema_init(&mut aggregator);
+        let mut functions = index_map_new();
+
+        functions.insert(
+            DEFERRED_EXECUTION_QUEUE_CREATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<DeferredExecutionQueueCreateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<DeferredExecutionQueueCreateOutput>(),
+                ),
+                export: DEFERRED_EXECUTION_QUEUE_CREATE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            DEFERRED_EXECUTION_QUEUE_ENQUEUE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<DeferredExecutionQueueEnqueueInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<DeferredExecutionQueueEnqueueOutput>(),
+                ),
+                export: DEFERRED_EXECUTION_QUEUE_ENQUEUE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            DEFERRED_EXECUTION_QUEUE_CANCEL_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<DeferredExecutionQueueCancelInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<DeferredExecutionQueueCancelOutput>(),
+                ),
+                export: DEFERRED_EXECUTION_QUEUE_CANCEL_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            DEFERRED_EXECUTION_QUEUE_EXECUTE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<DeferredExecutionQueueExecuteInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<DeferredExecutionQueueExecuteOutput>(),
+                ),
+                export: DEFERRED_EXECUTION_QUEUE_EXECUTE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            DEFERRED_EXECUTION_QUEUE_GET_INTENT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<DeferredExecutionQueueGetIntentInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<DeferredExecutionQueueGetIntentOutput>(),
+                ),
+                export: DEFERRED_EXECUTION_QUEUE_GET_INTENT_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            DEFERRED_EXECUTION_QUEUE_RUN_INTENT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<DeferredExecutionQueueRunIntentInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<DeferredExecutionQueueRunIntentOutput>(),
+                ),
+                export: DEFERRED_EXECUTION_QUEUE_RUN_INTENT_EXPORT_NAME.to_string(),
+            },
+        );
+
+        let event_schema = event_schema! {
+            aggregator,
+            [
+                IntentEnqueuedEvent,
+                IntentCancelledEvent,
+                IntentExecutedEvent
+            ]
+        };
+
+        let schema = generate_full_schema(aggregator);
+
+        BlueprintDefinitionInit {
+            blueprint_type: BlueprintType::default(),
+            is_transient: false,
+            dependencies: indexset!(XRD.into()),
+            feature_set,
+
+            schema: BlueprintSchemaInit {
+                generics: vec![],
+                schema,
+                state,
+                events: event_schema,
+                types: BlueprintTypeSchemaInit::default(),
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
+                hooks: BlueprintHooksInit::default(),
+            },
+
+            royalty_config: PackageRoyaltyConfig::default(),
+            auth_config: AuthConfig {
+                function_auth: FunctionAuth::AccessRules(indexmap!(
+                    DEFERRED_EXECUTION_QUEUE_CREATE_IDENT.to_string() => rule!(require(AuthAddresses::system_role())),
+                    // Intents are only run by the queue, once they have been removed from it.
+                    DEFERRED_EXECUTION_QUEUE_RUN_INTENT_IDENT.to_string() => rule!(require(global_caller(DEFERRED_EXECUTION_QUEUE))),
+                )),
+                // Cancellation is checked against the canceller rule of each intent.
+                method_auth: MethodAuthTemplate::AllowAll,
+            },
+        }
+    }
+
+    fn subintent_processor_blueprint_definition() -> BlueprintDefinitionInit {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let mut functions = index_map_new();
+
+        functions.insert(
+            SUBINTENT_PROCESSOR_RUN_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<SubintentProcessorRunInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<SubintentProcessorRunOutput>(),
+                ),
+                export: SUBINTENT_PROCESSOR_RUN_EXPORT_NAME.to_string(),
+            },
+        );
+
+        let schema = generate_full_schema(aggregator);
+
+        BlueprintDefinitionInit {
+            blueprint_type: BlueprintType::default(),
+            is_transient: true,
+            dependencies: indexset!(),
+            feature_set: indexset!(),
+
+            schema: BlueprintSchemaInit {
+                generics: vec![],
+                schema,
+                state: BlueprintStateSchemaInit {
+                    fields: vec![],
+                    collections: vec![],
+                },
+                events: BlueprintEventSchemaInit::default(),
+                types: BlueprintTypeSchemaInit::default(),
+                functions: BlueprintFunctionsSchemaInit {
+                    functions,
+                    post_conditions: index_map_new(),
+                },
+                hooks: BlueprintHooksInit::default(),
+            },
+
+            royalty_config: PackageRoyaltyConfig::default(),
+            auth_config: AuthConfig {
+                // The auth module hands the proofs of the next subintent signers to any call from
+                // the root transaction processor, which only makes such calls for the subintents
+                // of the transaction.
+                function_auth: FunctionAuth::AccessRules(indexmap!(
+                    SUBINTENT_PROCESSOR_RUN_IDENT.to_string() => rule!(require(global_caller(
+                        BlueprintId::new(&TRANSACTION_PROCESSOR_PACKAGE, TRANSACTION_PROCESSOR_BLUEPRINT)
+                    ))),
+                )),
+                method_auth: MethodAuthTemplate::AllowAll,
+            },
         }
     }
 }
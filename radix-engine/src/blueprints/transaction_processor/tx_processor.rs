@@ -1,3 +1,4 @@
+use crate::blueprints::package::PackageCodeVmTypeEntrySubstate;
 use crate::blueprints::resource::WorktopSubstate;
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
@@ -5,6 +6,7 @@ use crate::internal_prelude::*;
 use crate::kernel::kernel_api::KernelNodeApi;
 use crate::kernel::kernel_api::KernelSubstateApi;
 use crate::system::node_init::type_info_partition;
+use crate::system::system_substates::KeyValueEntrySubstate;
 use crate::system::type_info::TypeInfoBlueprint;
 use crate::system::type_info::TypeInfoSubstate;
 use crate::types::*;
@@ -13,7 +15,12 @@ use native_sdk::resource::NativeNonFungibleBucket;
 use native_sdk::resource::{NativeBucket, NativeProof, Worktop};
 use native_sdk::runtime::LocalAuthZone;
 use radix_engine_interface::api::{AttachedModuleId, ClientApi};
-use radix_engine_interface::blueprints::package::BlueprintVersion;
+use radix_engine_interface::blueprints::account::{
+    AccountTryDepositBatchOrAbortInput, ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT,
+};
+use radix_engine_interface::blueprints::package::{
+    BlueprintVersion, CodeHash, PACKAGE_VM_TYPE_PARTITION_OFFSET,
+};
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::blueprints::transaction_processor::*;
 use sbor::rust::prelude::*;
@@ -28,6 +35,7 @@ pub struct TransactionProcessorRunInput {
     pub global_address_reservations: Vec<GlobalAddressReservation>,
     pub references: Vec<Reference>, // Required so that the kernel passes the references to the processor frame
     pub blobs: IndexMap<Hash, Vec<u8>>,
+    pub worktop_leftovers_recipient: Option<ComponentAddress>,
 }
 
 // This needs to match the above, but is easily encodable to avoid cloning from the transaction payload to encode
@@ -37,6 +45,7 @@ pub struct TransactionProcessorRunInputEfficientEncodable<'a> {
     pub global_address_reservations: Vec<GlobalAddressReservation>,
     pub references: &'a IndexSet<Reference>,
     pub blobs: &'a IndexMap<Hash, Vec<u8>>,
+    pub worktop_leftovers_recipient: Option<ComponentAddress>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -53,6 +62,21 @@ pub enum TransactionProcessorError {
     AuthZoneIsEmpty,
     InvocationOutputDecodeError(DecodeError),
     ArgsEncodeError(EncodeError),
+    PackageCodeHashAssertionFailed {
+        package_address: PackageAddress,
+        code_hash: CodeHash,
+    },
+    SubintentProcessorCalledDirectly,
+    SubintentInputNotProvided {
+        resource_address: ResourceAddress,
+        required_amount: Decimal,
+        provided_amount: Decimal,
+    },
+    SubintentOutputNotProduced {
+        resource_address: ResourceAddress,
+        declared_amount: Decimal,
+        produced_amount: Decimal,
+    },
 }
 
 impl From<TransactionProcessorError> for RuntimeError {
@@ -98,12 +122,130 @@ impl TransactionProcessorBlueprint {
         global_address_reservations: Vec<GlobalAddressReservation>,
         _references: Vec<Reference>, // Required so that the kernel passes the references to the processor frame
         blobs: IndexMap<Hash, Vec<u8>>,
+        worktop_leftovers_recipient: Option<ComponentAddress>,
         api: &mut Y,
-    ) -> Result<Vec<InstructionOutput>, RuntimeError>
+    ) -> Result<TransactionProcessorRunOutput, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        let mut worktop = Self::create_worktop(api)?;
+        let outputs = Self::process_instructions(
+            manifest_encoded_instructions,
+            global_address_reservations,
+            blobs,
+            &mut worktop,
+            true,
+            api,
+        )?;
+
+        let worktop_leftovers = match worktop_leftovers_recipient {
+            Some(recipient) => Self::deposit_worktop_leftovers(&worktop, recipient, api)?,
+            None => index_map_new(),
+        };
+
+        worktop.drop(api)?;
+
+        Ok(TransactionProcessorRunOutput {
+            outputs,
+            worktop_leftovers,
+        })
+    }
+
+    /// Runs the instructions of a deferred intent on behalf of the deferred execution queue.
+    ///
+    /// The intent runs in its own frame with its own worktop, which has to be emptied by the
+    /// instructions themselves, and isn't tracked as part of the transaction manifest.
+    pub(crate) fn run_deferred<Y, L: Default>(
+        manifest_encoded_instructions: Vec<u8>,
+        _references: Vec<Reference>, // Required so that the kernel passes the references to the intent frame
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        let mut worktop = Self::create_worktop(api)?;
+        Self::process_instructions(
+            manifest_encoded_instructions,
+            Vec::new(),
+            index_map_new(),
+            &mut worktop,
+            false,
+            api,
+        )?;
+        worktop.drop(api)?;
+
+        Ok(())
+    }
+
+    /// Runs the instructions of a subintent, with the inputs on its worktop, and returns
+    /// everything left on the worktop.
+    ///
+    /// Fails if the inputs don't cover the required inputs of the subintent, or if what is left
+    /// doesn't cover its declared outputs.
+    pub(crate) fn run_subintent<Y, L: Default>(
+        manifest_encoded_instructions: Vec<u8>,
+        _references: Vec<Reference>, // Required so that the kernel passes the references to the subintent frame
+        inputs: Vec<Bucket>,
+        constraints: SubintentConstraints,
+        api: &mut Y,
+    ) -> Result<Vec<Bucket>, RuntimeError>
     where
         Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
-        // Create a worktop
+        let provided_amounts = Self::amounts_by_resource(&inputs, api)?;
+        for (resource_address, required_amount) in constraints.required_inputs {
+            let provided_amount = provided_amounts
+                .get(&resource_address)
+                .cloned()
+                .unwrap_or_default();
+            if provided_amount < required_amount {
+                return Err(TransactionProcessorError::SubintentInputNotProvided {
+                    resource_address,
+                    required_amount,
+                    provided_amount,
+                }
+                .into());
+            }
+        }
+
+        let mut worktop = Self::create_worktop(api)?;
+        for input in inputs {
+            worktop.put(input, api)?;
+        }
+        Self::process_instructions(
+            manifest_encoded_instructions,
+            Vec::new(),
+            index_map_new(),
+            &mut worktop,
+            false,
+            api,
+        )?;
+        let outputs = worktop.drain(api)?;
+        worktop.drop(api)?;
+
+        let produced_amounts = Self::amounts_by_resource(&outputs, api)?;
+        for (resource_address, declared_amount) in constraints.declared_outputs {
+            let produced_amount = produced_amounts
+                .get(&resource_address)
+                .cloned()
+                .unwrap_or_default();
+            if produced_amount < declared_amount {
+                return Err(TransactionProcessorError::SubintentOutputNotProduced {
+                    resource_address,
+                    declared_amount,
+                    produced_amount,
+                }
+                .into());
+            }
+        }
+
+        Ok(outputs)
+    }
+
+    fn create_worktop<Y>(api: &mut Y) -> Result<Worktop, RuntimeError>
+    where
+        Y: KernelNodeApi,
+    {
         let worktop_node_id = api.kernel_allocate_node_id(EntityType::InternalGenericComponent)?;
         api.kernel_create_node(
             worktop_node_id,
@@ -127,7 +269,20 @@ impl TransactionProcessorBlueprint {
         )?;
         api.kernel_pin_node(worktop_node_id)?;
 
-        let mut worktop = Worktop(Own(worktop_node_id));
+        Ok(Worktop(Own(worktop_node_id)))
+    }
+
+    fn process_instructions<Y, L: Default>(
+        manifest_encoded_instructions: Vec<u8>,
+        global_address_reservations: Vec<GlobalAddressReservation>,
+        blobs: IndexMap<Hash, Vec<u8>>,
+        worktop: &mut Worktop,
+        is_root_manifest: bool,
+        api: &mut Y,
+    ) -> Result<Vec<InstructionOutput>, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
         let instructions = manifest_decode::<Vec<InstructionV1>>(&manifest_encoded_instructions)
             .map_err(|e| {
                 // This error should never occur if being called from root since this is constructed
@@ -138,7 +293,9 @@ impl TransactionProcessorBlueprint {
         let mut processor = TransactionProcessor::new(blobs, global_address_reservations);
         let mut outputs = Vec::new();
         for (index, inst) in instructions.into_iter().enumerate() {
-            api.update_instruction_index(index)?;
+            if is_root_manifest {
+                api.update_instruction_index(index)?;
+            }
 
             let result = match inst {
                 InstructionV1::TakeAllFromWorktop { resource_address } => {
@@ -269,7 +426,7 @@ impl TransactionProcessorBlueprint {
                     let rtn = bucket.burn(api)?;
 
                     let result = IndexedScryptoValue::from_typed(&rtn);
-                    processor.handle_call_return_data(&result, &worktop, api)?;
+                    processor.handle_call_return_data(&result, worktop, api)?;
                     InstructionOutput::CallReturn(result.into())
                 }
                 InstructionV1::CloneProof { proof_id } => {
@@ -290,7 +447,16 @@ impl TransactionProcessorBlueprint {
                     args,
                 } => {
                     let package_address = processor.resolve_package_address(package_address)?;
-                    handle_invocation(api, &mut processor, &mut worktop, args, |api, args| {
+                    // Subintent processor calls receive the proofs of subintent signers, so they
+                    // can only be made through the subintent instruction.
+                    if package_address == TRANSACTION_PROCESSOR_PACKAGE
+                        && blueprint_name == SUBINTENT_PROCESSOR_BLUEPRINT
+                    {
+                        return Err(
+                            TransactionProcessorError::SubintentProcessorCalledDirectly.into()
+                        );
+                    }
+                    handle_invocation(api, &mut processor, worktop, args, |api, args| {
                         api.call_function(
                             package_address,
                             &blueprint_name,
@@ -306,7 +472,7 @@ impl TransactionProcessorBlueprint {
                     args,
                 } => {
                     let address = processor.resolve_global_address(address)?;
-                    handle_invocation(api, &mut processor, &mut worktop, args, |api, args| {
+                    handle_invocation(api, &mut processor, worktop, args, |api, args| {
                         api.call_method(
                             address.as_node_id(),
                             &method_name,
@@ -321,7 +487,7 @@ impl TransactionProcessorBlueprint {
                     args,
                 } => {
                     let address = processor.resolve_global_address(address)?;
-                    handle_invocation(api, &mut processor, &mut worktop, args, |api, args| {
+                    handle_invocation(api, &mut processor, worktop, args, |api, args| {
                         api.call_module_method(
                             address.as_node_id(),
                             AttachedModuleId::Royalty,
@@ -337,7 +503,7 @@ impl TransactionProcessorBlueprint {
                     args,
                 } => {
                     let address = processor.resolve_global_address(address)?;
-                    handle_invocation(api, &mut processor, &mut worktop, args, |api, args| {
+                    handle_invocation(api, &mut processor, worktop, args, |api, args| {
                         api.call_module_method(
                             address.as_node_id(),
                             AttachedModuleId::Metadata,
@@ -353,7 +519,7 @@ impl TransactionProcessorBlueprint {
                     args,
                 } => {
                     let address = processor.resolve_global_address(address)?;
-                    handle_invocation(api, &mut processor, &mut worktop, args, |api, args| {
+                    handle_invocation(api, &mut processor, worktop, args, |api, args| {
                         api.call_module_method(
                             address.as_node_id(),
                             AttachedModuleId::RoleAssignment,
@@ -367,7 +533,7 @@ impl TransactionProcessorBlueprint {
                     address,
                     method_name,
                     args,
-                } => handle_invocation(api, &mut processor, &mut worktop, args, |api, args| {
+                } => handle_invocation(api, &mut processor, worktop, args, |api, args| {
                     api.call_direct_access_method(
                         address.as_node_id(),
                         &method_name,
@@ -402,14 +568,185 @@ impl TransactionProcessorBlueprint {
 
                     InstructionOutput::None
                 }
+                InstructionV1::AssertPackageCodeHash {
+                    package_address,
+                    code_hash,
+                } => {
+                    Self::assert_package_code_hash(package_address, code_hash, api)?;
+                    InstructionOutput::None
+                }
+                InstructionV1::EnqueueDeferredManifest {
+                    instructions,
+                    execute_at_epoch,
+                    expiry_epoch,
+                    payment,
+                    canceller,
+                } => {
+                    let (manifest_encoded_instructions, references) =
+                        Self::encode_instructions(&instructions)?;
+                    let payment = processor.take_bucket(&payment)?;
+
+                    let rtn = api.call_method(
+                        DEFERRED_EXECUTION_QUEUE.as_node_id(),
+                        DEFERRED_EXECUTION_QUEUE_ENQUEUE_IDENT,
+                        scrypto_encode(&DeferredExecutionQueueEnqueueInput {
+                            manifest_encoded_instructions,
+                            references,
+                            execute_at_epoch,
+                            expiry_epoch,
+                            payment,
+                            canceller,
+                        })
+                        .map_err(TransactionProcessorError::ArgsEncodeError)?,
+                    )?;
+
+                    let result = IndexedScryptoValue::from_vec(rtn)
+                        .map_err(TransactionProcessorError::InvocationOutputDecodeError)?;
+                    processor.handle_call_return_data(&result, worktop, api)?;
+                    InstructionOutput::CallReturn(result.into())
+                }
+                InstructionV1::ExecuteSubintent { subintent, inputs } => {
+                    let SubintentV1 {
+                        instructions,
+                        constraints,
+                        ..
+                    } = subintent.subintent;
+                    let (manifest_encoded_instructions, references) =
+                        Self::encode_instructions(&instructions)?;
+                    let mut buckets = Vec::new();
+                    for input in inputs {
+                        buckets.push(processor.take_bucket(&input)?);
+                    }
+
+                    let rtn = api.call_function(
+                        TRANSACTION_PROCESSOR_PACKAGE,
+                        SUBINTENT_PROCESSOR_BLUEPRINT,
+                        SUBINTENT_PROCESSOR_RUN_IDENT,
+                        scrypto_encode(&SubintentProcessorRunInput {
+                            manifest_encoded_instructions,
+                            references,
+                            inputs: buckets,
+                            constraints,
+                        })
+                        .map_err(TransactionProcessorError::ArgsEncodeError)?,
+                    )?;
+
+                    let result = IndexedScryptoValue::from_vec(rtn)
+                        .map_err(TransactionProcessorError::InvocationOutputDecodeError)?;
+                    processor.handle_call_return_data(&result, worktop, api)?;
+                    InstructionOutput::CallReturn(result.into())
+                }
             };
             outputs.push(result);
         }
 
-        worktop.drop(api)?;
-
         Ok(outputs)
     }
+
+    /// Encodes instructions to be run in another frame, along with the references they need to
+    /// be passed on to that frame.
+    fn encode_instructions(
+        instructions: &Vec<InstructionV1>,
+    ) -> Result<(Vec<u8>, Vec<Reference>), RuntimeError> {
+        let manifest_encoded_instructions =
+            manifest_encode(instructions).map_err(TransactionProcessorError::ArgsEncodeError)?;
+        let references = extract_references(
+            &manifest_encoded_instructions,
+            sbor::traversal::ExpectedStart::Value,
+        )
+        .into_iter()
+        .collect();
+        Ok((manifest_encoded_instructions, references))
+    }
+
+    fn amounts_by_resource<Y>(
+        buckets: &[Bucket],
+        api: &mut Y,
+    ) -> Result<IndexMap<ResourceAddress, Decimal>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let mut amounts = index_map_new();
+        for bucket in buckets {
+            let amount = bucket.amount(api)?;
+            let total = amounts
+                .entry(bucket.resource_address(api)?)
+                .or_insert(Decimal::ZERO);
+            *total = total.checked_add(amount).unwrap_or(Decimal::MAX);
+        }
+        Ok(amounts)
+    }
+
+    /// Drains the worktop into the given account, returning the amount of each resource which
+    /// was left on it
+    fn deposit_worktop_leftovers<Y>(
+        worktop: &Worktop,
+        recipient: ComponentAddress,
+        api: &mut Y,
+    ) -> Result<IndexMap<ResourceAddress, Decimal>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let buckets = worktop.drain(api)?;
+        if buckets.is_empty() {
+            return Ok(index_map_new());
+        }
+
+        let mut worktop_leftovers = index_map_new();
+        for bucket in &buckets {
+            worktop_leftovers.insert(bucket.resource_address(api)?, bucket.amount(api)?);
+        }
+
+        api.call_method(
+            recipient.as_node_id(),
+            ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT,
+            scrypto_encode(&AccountTryDepositBatchOrAbortInput {
+                buckets,
+                authorized_depositor_badge: None,
+            })
+            .unwrap(),
+        )?;
+
+        Ok(worktop_leftovers)
+    }
+
+    /// Checks that the package has code with the given hash, by looking it up in the code
+    /// VM type collection of the package
+    fn assert_package_code_hash<Y, L: Default>(
+        package_address: PackageAddress,
+        code_hash: CodeHash,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: KernelSubstateApi<L>,
+    {
+        let handle = api.kernel_open_substate_with_default(
+            package_address.as_node_id(),
+            MAIN_BASE_PARTITION
+                .at_offset(PACKAGE_VM_TYPE_PARTITION_OFFSET)
+                .unwrap(),
+            &SubstateKey::Map(scrypto_encode(&code_hash).unwrap()),
+            LockFlags::read_only(),
+            Some(|| {
+                let kv_entry = KeyValueEntrySubstate::<()>::default();
+                IndexedScryptoValue::from_typed(&kv_entry)
+            }),
+            L::default(),
+        )?;
+        let vm_type: PackageCodeVmTypeEntrySubstate =
+            api.kernel_read_substate(handle)?.as_typed().unwrap();
+        api.kernel_close_substate(handle)?;
+
+        if vm_type.into_value().is_none() {
+            return Err(TransactionProcessorError::PackageCodeHashAssertionFailed {
+                package_address,
+                code_hash,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
 }
 
 struct TransactionProcessor {
@@ -1,3 +1,5 @@
 mod package;
+mod store;
 
 pub use package::*;
+pub use store::*;
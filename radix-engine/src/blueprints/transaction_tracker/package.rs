@@ -1,4 +1,5 @@
 use crate::errors::{ApplicationError, RuntimeError};
+use crate::native_exports;
 use crate::types::*;
 use native_sdk::modules::metadata::Metadata;
 use native_sdk::modules::role_assignment::RoleAssignment;
@@ -33,6 +34,12 @@ pub struct TransactionTrackerCreateManifestInput {
 
 pub type TransactionTrackerCreateOutput = ComponentAddress;
 
+native_exports! {
+    pub enum TransactionTrackerNativeExport {
+        Create => TRANSACTION_TRACKER_CREATE_EXPORT_NAME,
+    }
+}
+
 pub struct TransactionTrackerNativePackage;
 
 pub const PARTITION_RANGE_START: u8 = MAIN_BASE_PARTITION.0 + 1;
@@ -95,6 +102,7 @@ impl TransactionTrackerNativePackage {
                     types: BlueprintTypeSchemaInit::default(),
                     functions: BlueprintFunctionsSchemaInit {
                         functions,
+                        post_conditions: index_map_new(),
                     },
                     hooks: BlueprintHooksInit::default(),
                 },
@@ -122,8 +130,8 @@ impl TransactionTrackerNativePackage {
     where
         Y: ClientApi<RuntimeError>,
     {
-        match export_name {
-            TRANSACTION_TRACKER_CREATE_EXPORT_NAME => {
+        match TransactionTrackerNativeExport::from_export_name(export_name)? {
+            TransactionTrackerNativeExport::Create => {
                 let input: TransactionTrackerCreateInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -132,10 +140,6 @@ impl TransactionTrackerNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
         }
     }
 }
@@ -153,7 +157,7 @@ impl TransactionStatus {
     }
 }
 
-#[derive(Debug, Clone, ScryptoSbor)]
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum TransactionStatusV1 {
     CommittedSuccess,
     CommittedFailure,
@@ -224,6 +228,12 @@ impl TransactionTrackerSubstateV1 {
         Some(partition_number as u8)
     }
 
+    /// The partitions currently in use, from the one covering the earliest expiry epochs.
+    pub fn partitions(&self) -> impl Iterator<Item = u8> {
+        (self.start_partition..=self.partition_range_end_inclusive)
+            .chain(self.partition_range_start_inclusive..self.start_partition)
+    }
+
     /// This method will shift the start partition by 1, considering the partition range as a buffer.
     /// Protocol-specific implementation is within transaction executor.
     pub fn advance(&mut self) -> u8 {
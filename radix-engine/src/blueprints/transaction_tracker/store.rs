@@ -0,0 +1,296 @@
+use super::*;
+use crate::system::system_substates::{
+    FieldSubstate, KeyValueEntrySubstate, KeyValueEntrySubstateV1, LockStatus,
+};
+use crate::track::interface::{BootStore, CommitableSubstateStore};
+use crate::track::Track;
+use crate::types::*;
+use radix_engine_store_interface::db_key_mapper::DatabaseKeyMapper;
+use radix_engine_store_interface::interface::SubstateDatabase;
+
+/// A store of the statuses of committed intents, used to reject intents which are submitted
+/// again before they expire.
+///
+/// Statuses are bucketed by the epoch their intent expires at, with the buckets rotated as
+/// described by [`TransactionTrackerSubstateV1`]. Once every intent of the oldest bucket has
+/// expired, the whole bucket is discarded at once and reused for later expiry epochs.
+///
+/// Implementations only provide the storage of the tracker and of the buckets; the bucketing
+/// itself is handled by the provided methods.
+pub trait IntentStatusStore {
+    fn read_tracker(&self) -> TransactionTrackerSubstateV1;
+
+    fn write_tracker(&mut self, tracker: TransactionTrackerSubstateV1);
+
+    fn read_status(&self, partition: u8, intent_hash: &Hash) -> Option<TransactionStatusV1>;
+
+    fn write_status(&mut self, partition: u8, intent_hash: &Hash, status: TransactionStatusV1);
+
+    fn discard_partition(&mut self, partition: u8);
+
+    /// Returns the status of an intent expiring at the given epoch.
+    ///
+    /// This only reads the bucket covering the expiry epoch, so should be preferred over
+    /// [`IntentStatusStore::intent_status`] when the expiry epoch is known.
+    fn intent_status_for_expiry(
+        &self,
+        intent_hash: &Hash,
+        expiry_epoch: Epoch,
+    ) -> Option<TransactionStatusV1> {
+        self.read_tracker()
+            .partition_for_expiry_epoch(expiry_epoch)
+            .and_then(|partition| self.read_status(partition, intent_hash))
+    }
+
+    /// Returns the status of an intent, if it has been committed or cancelled and its bucket has
+    /// not yet been discarded.
+    fn intent_status(&self, intent_hash: &Hash) -> Option<TransactionStatusV1> {
+        self.read_tracker()
+            .partitions()
+            .find_map(|partition| self.read_status(partition, intent_hash))
+    }
+
+    fn record_intent_status(
+        &mut self,
+        intent_hash: &Hash,
+        expiry_epoch: Epoch,
+        status: TransactionStatusV1,
+    ) {
+        let partition = self
+            .read_tracker()
+            .partition_for_expiry_epoch(expiry_epoch)
+            .expect("No partition for an expiry epoch");
+        self.write_status(partition, intent_hash, status);
+    }
+
+    /// Discards the oldest bucket if all of its intents have expired by the given epoch.
+    ///
+    /// The bucket range covers more epochs than the max epoch range of a transaction, so only a
+    /// single bucket can expire at a time, provided that epochs don't jump by a large distance.
+    fn advance_to_epoch(&mut self, next_epoch: Epoch) {
+        let mut tracker = self.read_tracker();
+        if next_epoch.number() >= tracker.start_epoch + tracker.epochs_per_partition {
+            let discarded_partition = tracker.advance();
+            self.discard_partition(discarded_partition);
+        }
+        self.write_tracker(tracker);
+    }
+}
+
+/// The ledger store of intent statuses, kept in the partitions of the transaction tracker.
+impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> IntentStatusStore
+    for Track<'s, S, M>
+{
+    fn read_tracker(&self) -> TransactionTrackerSubstateV1 {
+        self.read_substate(
+            TRANSACTION_TRACKER.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &TransactionTrackerField::TransactionTracker.into(),
+        )
+        .unwrap()
+        .as_typed::<FieldSubstate<TransactionTrackerSubstate>>()
+        .unwrap()
+        .into_payload()
+        .into_v1()
+    }
+
+    fn write_tracker(&mut self, tracker: TransactionTrackerSubstateV1) {
+        self.set_substate(
+            TRANSACTION_TRACKER.into_node_id(),
+            MAIN_BASE_PARTITION,
+            TransactionTrackerField::TransactionTracker.into(),
+            IndexedScryptoValue::from_typed(&FieldSubstate::new_unlocked_field(
+                TransactionTrackerSubstate::V1(tracker),
+            )),
+            &mut |_| -> Result<(), ()> { Ok(()) },
+        )
+        .unwrap();
+    }
+
+    fn read_status(&self, partition: u8, intent_hash: &Hash) -> Option<TransactionStatusV1> {
+        self.read_substate(
+            TRANSACTION_TRACKER.as_node_id(),
+            PartitionNumber(partition),
+            &SubstateKey::Map(scrypto_encode(intent_hash).unwrap()),
+        )
+        .and_then(|value| {
+            value
+                .as_typed::<KeyValueEntrySubstate<TransactionStatus>>()
+                .unwrap()
+                .into_value()
+        })
+        .map(|status| status.into_v1())
+    }
+
+    fn write_status(&mut self, partition: u8, intent_hash: &Hash, status: TransactionStatusV1) {
+        self.set_substate(
+            TRANSACTION_TRACKER.into_node_id(),
+            PartitionNumber(partition),
+            SubstateKey::Map(scrypto_encode(intent_hash).unwrap()),
+            IndexedScryptoValue::from_typed(&KeyValueEntrySubstate::V1(KeyValueEntrySubstateV1 {
+                value: Some(TransactionStatus::V1(status)),
+                // TODO: maybe make it immutable, but how does this affect partition deletion?
+                lock_status: LockStatus::Unlocked,
+            })),
+            &mut |_| -> Result<(), ()> { Ok(()) },
+        )
+        .unwrap();
+    }
+
+    fn discard_partition(&mut self, partition: u8) {
+        self.delete_partition(TRANSACTION_TRACKER.as_node_id(), PartitionNumber(partition));
+    }
+}
+
+/// An in-memory store of intent statuses, for hosts tracking intents outside of a ledger and for
+/// simulating the expiry of intents in tests.
+#[derive(Debug, Clone)]
+pub struct InMemoryIntentStatusStore {
+    tracker: TransactionTrackerSubstateV1,
+    partitions: IndexMap<u8, IndexMap<Hash, TransactionStatusV1>>,
+}
+
+impl InMemoryIntentStatusStore {
+    /// Creates a store starting at the given epoch, with the same bucketing as the ledger.
+    pub fn new(start_epoch: Epoch) -> Self {
+        Self::with_tracker(TransactionTrackerSubstateV1 {
+            start_epoch: start_epoch.number(),
+            start_partition: PARTITION_RANGE_START,
+            partition_range_start_inclusive: PARTITION_RANGE_START,
+            partition_range_end_inclusive: PARTITION_RANGE_END,
+            epochs_per_partition: EPOCHS_PER_PARTITION,
+        })
+    }
+
+    /// Creates a store with custom bucketing, e.g. with few epochs per bucket so that expiry
+    /// can be exercised quickly.
+    pub fn with_tracker(tracker: TransactionTrackerSubstateV1) -> Self {
+        Self {
+            tracker,
+            partitions: index_map_new(),
+        }
+    }
+}
+
+impl IntentStatusStore for InMemoryIntentStatusStore {
+    fn read_tracker(&self) -> TransactionTrackerSubstateV1 {
+        self.tracker.clone()
+    }
+
+    fn write_tracker(&mut self, tracker: TransactionTrackerSubstateV1) {
+        self.tracker = tracker;
+    }
+
+    fn read_status(&self, partition: u8, intent_hash: &Hash) -> Option<TransactionStatusV1> {
+        self.partitions
+            .get(&partition)
+            .and_then(|statuses| statuses.get(intent_hash))
+            .cloned()
+    }
+
+    fn write_status(&mut self, partition: u8, intent_hash: &Hash, status: TransactionStatusV1) {
+        self.partitions
+            .entry(partition)
+            .or_default()
+            .insert(*intent_hash, status);
+    }
+
+    fn discard_partition(&mut self, partition: u8) {
+        self.partitions.swap_remove(&partition);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> InMemoryIntentStatusStore {
+        InMemoryIntentStatusStore::with_tracker(TransactionTrackerSubstateV1 {
+            start_epoch: 10,
+            start_partition: 1,
+            partition_range_start_inclusive: 1,
+            partition_range_end_inclusive: 3,
+            epochs_per_partition: 5,
+        })
+    }
+
+    #[test]
+    fn recorded_intent_status_can_be_queried() {
+        let mut store = store();
+        let intent_hash = hash("intent");
+
+        store.record_intent_status(
+            &intent_hash,
+            Epoch::of(17),
+            TransactionStatusV1::CommittedSuccess,
+        );
+
+        assert_eq!(
+            store.intent_status_for_expiry(&intent_hash, Epoch::of(17)),
+            Some(TransactionStatusV1::CommittedSuccess)
+        );
+        assert_eq!(
+            store.intent_status(&intent_hash),
+            Some(TransactionStatusV1::CommittedSuccess)
+        );
+        assert_eq!(store.intent_status(&hash("other intent")), None);
+    }
+
+    #[test]
+    fn intent_status_is_discarded_once_expired() {
+        let mut store = store();
+        let expired_intent_hash = hash("expired intent");
+        let live_intent_hash = hash("live intent");
+        store.record_intent_status(
+            &expired_intent_hash,
+            Epoch::of(14),
+            TransactionStatusV1::CommittedSuccess,
+        );
+        store.record_intent_status(
+            &live_intent_hash,
+            Epoch::of(15),
+            TransactionStatusV1::CommittedFailure,
+        );
+
+        store.advance_to_epoch(Epoch::of(14));
+        assert!(store.intent_status(&expired_intent_hash).is_some());
+        store.advance_to_epoch(Epoch::of(15));
+
+        assert_eq!(store.intent_status(&expired_intent_hash), None);
+        assert_eq!(
+            store.intent_status(&live_intent_hash),
+            Some(TransactionStatusV1::CommittedFailure)
+        );
+    }
+
+    #[test]
+    fn discarded_bucket_is_reused_for_later_expiry_epochs() {
+        let mut store = store();
+        let intent_hash = hash("intent");
+        store.record_intent_status(&intent_hash, Epoch::of(10), TransactionStatusV1::Cancelled);
+        assert_eq!(
+            store
+                .read_tracker()
+                .partition_for_expiry_epoch(Epoch::of(25)),
+            None
+        );
+
+        store.advance_to_epoch(Epoch::of(15));
+        store.record_intent_status(
+            &intent_hash,
+            Epoch::of(25),
+            TransactionStatusV1::CommittedSuccess,
+        );
+
+        assert_eq!(
+            store
+                .read_tracker()
+                .partition_for_expiry_epoch(Epoch::of(25)),
+            Some(1)
+        );
+        assert_eq!(
+            store.intent_status_for_expiry(&intent_hash, Epoch::of(25)),
+            Some(TransactionStatusV1::CommittedSuccess)
+        );
+    }
+}
@@ -1,18 +1,23 @@
 use crate::blueprints::access_controller::AccessControllerError;
 use crate::blueprints::account::AccountError;
 use crate::blueprints::consensus_manager::{ConsensusManagerError, ValidatorError};
+use crate::blueprints::order_book::OrderBookError;
 use crate::blueprints::package::PackageError;
+use crate::blueprints::payment_scheduler::PaymentSchedulerError;
 use crate::blueprints::pool::v1::errors::{
     multi_resource_pool::Error as MultiResourcePoolError,
     one_resource_pool::Error as OneResourcePoolError,
     two_resource_pool::Error as TwoResourcePoolError,
 };
+use crate::blueprints::price_feed::PriceFeedError;
 use crate::blueprints::resource::{AuthZoneError, NonFungibleVaultError};
 use crate::blueprints::resource::{
     BucketError, FungibleResourceManagerError, NonFungibleResourceManagerError, ProofError,
     VaultError, WorktopError,
 };
-use crate::blueprints::transaction_processor::TransactionProcessorError;
+use crate::blueprints::transaction_processor::{
+    DeferredExecutionQueueError, TransactionProcessorError,
+};
 use crate::kernel::call_frame::{
     CallFrameDrainSubstatesError, CallFrameRemoveSubstateError, CallFrameScanKeysError,
     CallFrameScanSortedSubstatesError, CallFrameSetSubstateError, CloseSubstateError,
@@ -25,6 +30,8 @@ use crate::system::attached_modules::role_assignment::RoleAssignmentError;
 use crate::system::attached_modules::royalty::ComponentRoyaltyError;
 use crate::system::system_modules::auth::AuthError;
 use crate::system::system_modules::costing::CostingError;
+use crate::system::system_modules::debugger::DebuggerError;
+use crate::system::system_modules::extension::ExtensionError;
 use crate::system::system_modules::limits::TransactionLimitsError;
 use crate::system::system_type_checker::TypeCheckError;
 use crate::transaction::AbortReason;
@@ -58,6 +65,7 @@ pub enum RejectionReason {
     },
     IntentHashPreviouslyCommitted,
     IntentHashPreviouslyCancelled,
+    SubintentHashPreviouslyCommitted,
 }
 
 impl fmt::Display for RejectionReason {
@@ -256,6 +264,12 @@ pub enum SystemError {
     BlsError(String),
     InputDataEmpty,
 
+    RateLimitError(RateLimitError),
+
+    /// A call frame returned while still owning these reservations, see
+    /// `ExecutionConfig::fail_on_unused_address_reservations`
+    UnusedAddressReservations(Vec<GlobalAddress>),
+
     /// A panic that's occurred in the system-layer or below. We're calling it system panic since
     /// we're treating the system as a black-box here.
     #[cfg(feature = "std")]
@@ -271,6 +285,26 @@ pub enum EventError {
     EventSchemaNotMatch(String),
     NoAssociatedPackage,
     InvalidActor,
+    InvalidSubscriber,
+    HookMethodNotFound(String),
+    SubscriptionNotAuthorized,
+    TooManySubscribers {
+        emitter: GlobalAddress,
+        event_name: String,
+    },
+    SubscriptionNotFound {
+        emitter: GlobalAddress,
+        event_name: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum RateLimitError {
+    /// Rate limits can only be checked by the main module of a global component
+    InvalidActor,
+    ZeroWindow,
+    /// A per caller rate limit was checked in a call frame without a global caller
+    NoGlobalCaller,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -286,6 +320,8 @@ pub enum SystemUpstreamError {
 
     OutputDecodeError(DecodeError),
     OutputSchemaNotMatch(String, String),
+
+    PostConditionNotMet(String, FunctionPostCondition),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -322,6 +358,8 @@ pub enum SystemModuleError {
     CostingError(CostingError),
     TransactionLimitsError(TransactionLimitsError),
     EventError(Box<EventError>),
+    DebuggerError(DebuggerError),
+    ExtensionError(ExtensionError),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -424,6 +462,9 @@ pub enum ApplicationError {
     /// A panic.
     PanicMessage(String),
 
+    /// An abort, carrying the error value provided by the application.
+    Aborted(ScryptoValue),
+
     //===================
     // Node module errors
     //===================
@@ -469,6 +510,14 @@ pub enum ApplicationError {
     TwoResourcePoolError(TwoResourcePoolError),
 
     MultiResourcePoolError(MultiResourcePoolError),
+
+    PriceFeedError(PriceFeedError),
+
+    PaymentSchedulerError(PaymentSchedulerError),
+
+    OrderBookError(OrderBookError),
+
+    DeferredExecutionQueueError(DeferredExecutionQueueError),
 }
 
 impl From<TransactionProcessorError> for ApplicationError {
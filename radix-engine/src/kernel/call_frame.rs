@@ -715,6 +715,22 @@ impl<C, L: Clone> CallFrame<C, L> {
         Ok(())
     }
 
+    /// Prefetches substates of a node in the store. Nodes which are not visible or live on the
+    /// heap are ignored, as prefetching is only a hint.
+    pub fn prefetch_substates<S: CommitableSubstateStore>(
+        &self,
+        substate_io: &mut SubstateIO<S>,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        substate_keys: &[SubstateKey],
+    ) {
+        if let Some((_, SubstateDevice::Store)) = self.get_node_ref(node_id) {
+            substate_io
+                .store
+                .prefetch_substates(node_id, partition_num, substate_keys);
+        }
+    }
+
     pub fn create_node<'f, S: CommitableSubstateStore, E>(
         &mut self,
         substate_io: &mut SubstateIO<S>,
@@ -1,6 +1,7 @@
 use crate::track::interface::IOAccess;
 use crate::track::interface::{CallbackError, CanonicalSubstateKey, NodeSubstates};
 use crate::types::*;
+use sbor::rust::mem;
 
 pub struct Heap {
     nodes: NonIterMap<NodeId, NodeSubstates>,
@@ -194,18 +195,16 @@ impl Heap {
             .get_mut(node_id)
             .and_then(|n| n.get_mut(&partition_number));
         if let Some(substates) = node_substates {
-            let keys: Vec<SubstateKey> = substates
-                .iter()
-                .map(|(key, _)| key.clone())
-                .take(count.try_into().unwrap())
-                .collect();
-
-            let mut items = Vec::new();
-
-            for key in keys {
-                let value = substates.remove(&key).unwrap();
-                items.push((key, value));
-            }
+            // Split off the remaining substates, rather than cloning the drained keys and removing
+            // them one by one
+            let drained = match substates.keys().nth(count.try_into().unwrap()).cloned() {
+                Some(first_remaining_key) => {
+                    let remaining = substates.split_off(&first_remaining_key);
+                    mem::replace(substates, remaining)
+                }
+                None => mem::take(substates),
+            };
+            let items: Vec<(SubstateKey, IndexedScryptoValue)> = drained.into_iter().collect();
 
             for (key, value) in &items {
                 on_io_access(
@@ -237,30 +236,23 @@ impl Heap {
     ) -> Result<(), E> {
         assert!(!self.nodes.contains_key(&node_id));
 
-        let sizes: IndexMap<PartitionNumber, IndexMap<SubstateKey, usize>> = substates
-            .iter()
-            .map(|(k, v)| {
-                (
-                    k.clone(),
-                    v.iter().map(|(k, v)| (k.clone(), v.len())).collect(),
-                )
-            })
-            .collect();
-
         self.nodes.insert(node_id, substates);
 
-        for (partition_number, partition) in sizes {
-            for (substate_key, substate_size) in partition {
+        // Report the sizes straight from the inserted node rather than collecting them up front,
+        // as nodes such as buckets and proofs are created on the heap many times per transaction
+        let substates = self.nodes.get(&node_id).unwrap();
+        for (partition_number, partition) in substates {
+            for (substate_key, substate_value) in partition {
                 on_io_access(
                     self,
                     IOAccess::HeapSubstateUpdated {
                         canonical_substate_key: CanonicalSubstateKey {
                             node_id,
-                            partition_number,
+                            partition_number: *partition_number,
                             substate_key: substate_key.clone(),
                         },
                         old_size: None,
-                        new_size: Some(substate_size),
+                        new_size: Some(substate_value.len()),
                     },
                 )?;
             }
@@ -373,4 +365,39 @@ mod tests {
         heap.remove_node(&node_id, &mut on_io_access).unwrap();
         assert_eq!(total_size, 0);
     }
+
+    #[test]
+    fn test_drain_substates_drains_in_key_order() {
+        let mut heap = Heap::new();
+        let mut on_io_access = |_: &_, _: IOAccess| Result::<(), ()>::Ok(());
+
+        let node_id = NodeId([0u8; NodeId::LENGTH]);
+        let partition_number = PartitionNumber(5);
+        let key = |i: u8| SubstateKey::Map(scrypto_encode(&i).unwrap());
+        heap.create_node(
+            node_id,
+            btreemap!(
+                partition_number => (0u8..4)
+                    .map(|i| (key(i), IndexedScryptoValue::from_typed(&i)))
+                    .collect()
+            ),
+            &mut on_io_access,
+        )
+        .unwrap();
+
+        let drained = heap
+            .drain_substates(&node_id, partition_number, 3, &mut on_io_access)
+            .unwrap();
+        assert_eq!(
+            drained.into_iter().map(|(k, _)| k).collect::<Vec<_>>(),
+            vec![key(0), key(1), key(2)]
+        );
+        assert_eq!(heap.scan_keys(&node_id, partition_number, 10), vec![key(3)]);
+
+        let drained = heap
+            .drain_substates(&node_id, partition_number, 3, &mut on_io_access)
+            .unwrap();
+        assert_eq!(drained.len(), 1);
+        assert!(heap.scan_keys(&node_id, partition_number, 10).is_empty());
+    }
 }
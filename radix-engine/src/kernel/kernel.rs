@@ -70,6 +70,7 @@ impl<'g, M: KernelCallbackObject, S: CommitableSubstateStore + BootStore> BootLo
         pre_allocated_addresses: &'a Vec<PreAllocatedAddress>,
         references: &'a IndexSet<Reference>,
         blobs: &'a IndexMap<Hash, Vec<u8>>,
+        worktop_leftovers_recipient: Option<&'a ComponentAddress>,
     ) -> Result<Vec<u8>, RuntimeError> {
         #[cfg(feature = "resource_tracker")]
         radix_engine_profiling::QEMU_PLUGIN_CALIBRATOR.with(|v| {
@@ -147,6 +148,7 @@ impl<'g, M: KernelCallbackObject, S: CommitableSubstateStore + BootStore> BootLo
             pre_allocated_addresses,
             references,
             blobs,
+            worktop_leftovers_recipient,
         )?;
 
         // Sanity check call frame
@@ -475,6 +477,16 @@ where
         let mut read_only = as_read_only!(self);
         read_only.kernel_read_proof(proof_id)
     }
+
+    fn kernel_read_worktop(&mut self, worktop_id: &NodeId) -> Option<Vec<BucketSnapshot>> {
+        let mut read_only = as_read_only!(self);
+        read_only.kernel_read_worktop(worktop_id)
+    }
+
+    fn kernel_read_auth_zone(&mut self, auth_zone_id: &NodeId) -> Option<Vec<ProofSnapshot>> {
+        let mut read_only = as_read_only!(self);
+        read_only.kernel_read_auth_zone(auth_zone_id)
+    }
 }
 
 struct KernelReadOnly<'g, M>
@@ -663,6 +675,42 @@ where
             })
         }
     }
+
+    fn kernel_read_worktop(&mut self, worktop_id: &NodeId) -> Option<Vec<BucketSnapshot>> {
+        let substate = self.heap.get_substate(
+            worktop_id,
+            MAIN_BASE_PARTITION,
+            &WorktopField::Worktop.into(),
+        )?;
+        let worktop: FieldSubstate<WorktopSubstate> = substate.as_typed().ok()?;
+
+        Some(
+            worktop
+                .into_payload()
+                .resources
+                .values()
+                .filter_map(|bucket| self.kernel_read_bucket(bucket.as_node_id()))
+                .collect(),
+        )
+    }
+
+    fn kernel_read_auth_zone(&mut self, auth_zone_id: &NodeId) -> Option<Vec<ProofSnapshot>> {
+        let substate = self.heap.get_substate(
+            auth_zone_id,
+            MAIN_BASE_PARTITION,
+            &AuthZoneField::AuthZone.into(),
+        )?;
+        let auth_zone: FieldSubstate<AuthZone> = substate.as_typed().ok()?;
+
+        Some(
+            auth_zone
+                .into_payload()
+                .proofs()
+                .iter()
+                .filter_map(|proof| self.kernel_read_proof(proof.0.as_node_id()))
+                .collect(),
+        )
+    }
 }
 
 impl<'g, M, S> KernelSubstateApi<M::LockData> for Kernel<'g, M, S>
@@ -689,6 +737,23 @@ where
             })
     }
 
+    #[trace_resources]
+    fn kernel_prefetch_substates(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        substate_keys: &[SubstateKey],
+    ) -> Result<(), RuntimeError> {
+        self.current_frame.prefetch_substates(
+            &mut self.substate_io,
+            node_id,
+            partition_num,
+            substate_keys,
+        );
+
+        Ok(())
+    }
+
     #[trace_resources]
     fn kernel_open_substate_with_default<F: FnOnce() -> IndexedScryptoValue>(
         &mut self,
@@ -53,6 +53,15 @@ pub trait KernelSubstateApi<L> {
         key: SubstateKey,
     ) -> Result<(), RuntimeError>;
 
+    /// Hints that the given substates are about to be read, so that those not yet loaded can be
+    /// fetched from the store in one batch
+    fn kernel_prefetch_substates(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        substate_keys: &[SubstateKey],
+    ) -> Result<(), RuntimeError>;
+
     /// Locks a substate to make available for reading and/or writing
     fn kernel_open_substate_with_default<F: FnOnce() -> IndexedScryptoValue>(
         &mut self,
@@ -199,6 +208,8 @@ pub trait KernelInternalApi<M: KernelCallbackObject> {
     /* Super unstable interface, specifically for `ExecutionTrace` kernel module */
     fn kernel_read_bucket(&mut self, bucket_id: &NodeId) -> Option<BucketSnapshot>;
     fn kernel_read_proof(&mut self, proof_id: &NodeId) -> Option<ProofSnapshot>;
+    fn kernel_read_worktop(&mut self, worktop_id: &NodeId) -> Option<Vec<BucketSnapshot>>;
+    fn kernel_read_auth_zone(&mut self, auth_zone_id: &NodeId) -> Option<Vec<ProofSnapshot>>;
 }
 
 pub trait KernelApi<M: KernelCallbackObject>:
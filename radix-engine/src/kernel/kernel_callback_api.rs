@@ -141,6 +141,7 @@ pub trait KernelCallbackObject: Sized {
         pre_allocated_addresses: &Vec<PreAllocatedAddress>,
         references: &IndexSet<Reference>,
         blobs: &IndexMap<Hash, Vec<u8>>,
+        worktop_leftovers_recipient: Option<&ComponentAddress>,
     ) -> Result<Vec<u8>, RuntimeError>
     where
         Y: KernelApi<Self>;
@@ -23,6 +23,9 @@ pub mod blueprints;
 
 pub mod track;
 
+/// Protocol updates, applying deterministic state migrations between engine versions.
+pub mod protocol_updates;
+
 /// Scrypto/SBOR types required by Radix Engine.
 pub mod types;
 
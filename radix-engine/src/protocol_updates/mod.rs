@@ -0,0 +1,5 @@
+mod protocol_update;
+mod state_migration;
+
+pub use protocol_update::*;
+pub use state_migration::*;
@@ -0,0 +1,141 @@
+use super::*;
+use crate::internal_prelude::*;
+use radix_engine_store_interface::interface::*;
+
+/// The protocol versions of the engine, in the order they were enacted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, ScryptoSbor)]
+pub enum ProtocolVersion {
+    Babylon,
+    Anemone,
+    Bottlenose,
+    Cuttlefish,
+}
+
+impl ProtocolVersion {
+    pub const GENESIS: Self = ProtocolVersion::Babylon;
+    pub const LATEST: Self = ProtocolVersion::Cuttlefish;
+
+    pub const VARIANTS: [Self; 4] = [
+        ProtocolVersion::Babylon,
+        ProtocolVersion::Anemone,
+        ProtocolVersion::Bottlenose,
+        ProtocolVersion::Cuttlefish,
+    ];
+
+    pub fn logical_name(&self) -> &'static str {
+        match self {
+            ProtocolVersion::Babylon => "babylon",
+            ProtocolVersion::Anemone => "anemone",
+            ProtocolVersion::Bottlenose => "bottlenose",
+            ProtocolVersion::Cuttlefish => "cuttlefish",
+        }
+    }
+
+    /// The state migrations which need to be applied to move into this version from the
+    /// previous one.
+    pub fn state_migrations(&self) -> Vec<StateMigration> {
+        match self {
+            ProtocolVersion::Babylon => vec![],
+            ProtocolVersion::Anemone => vec![
+                StateMigration::SecondsPrecision,
+                StateMigration::VmBootScryptoMinorVersion,
+                StateMigration::ValidatorFeeFix,
+                StateMigration::PoolsV1_1,
+            ],
+            ProtocolVersion::Bottlenose => vec![StateMigration::RoundedTime],
+            ProtocolVersion::Cuttlefish => vec![
+                StateMigration::VmBootCuttlefishMinorVersion,
+                StateMigration::AccountPackageDefinition,
+                StateMigration::ConsensusManagerPackageDefinition,
+                StateMigration::PackagePackageDefinition,
+                StateMigration::PoolPackageDefinition,
+                StateMigration::ResourcePackageDefinition,
+                StateMigration::RoleAssignmentPackageDefinition,
+                StateMigration::TransactionProcessorPackageDefinition,
+                StateMigration::ValidatorFeeSplit,
+                StateMigration::EpochChangeHooks,
+                StateMigration::NonFungibleNextReservableId,
+                StateMigration::AccountAuthorizer,
+                StateMigration::PriceFeedPackage,
+                StateMigration::PaymentSchedulerPackage,
+                StateMigration::OrderBookPackage,
+                StateMigration::DeferredExecutionQueue,
+            ],
+        }
+    }
+}
+
+/// A batch of state migrations which are enacted together as a single protocol update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtocolUpdate {
+    pub version: ProtocolVersion,
+    pub state_migrations: Vec<StateMigration>,
+}
+
+impl ProtocolUpdate {
+    /// The anointed update into the given protocol version.
+    pub fn of(version: ProtocolVersion) -> Self {
+        Self {
+            version,
+            state_migrations: version.state_migrations(),
+        }
+    }
+
+    /// An update with no migrations, to which migrations can be added with the builder methods.
+    pub fn empty(version: ProtocolVersion) -> Self {
+        Self {
+            version,
+            state_migrations: vec![],
+        }
+    }
+
+    pub fn with_migration(mut self, state_migration: StateMigration) -> Self {
+        self.state_migrations.push(state_migration);
+        self
+    }
+
+    pub fn without_migration(mut self, state_migration: &StateMigration) -> Self {
+        self.state_migrations
+            .retain(|existing| existing != state_migration);
+        self
+    }
+
+    /// Applies every migration in order, committing each before the next one is generated, as
+    /// later migrations may read state written by earlier ones.
+    pub fn execute<S: SubstateDatabase + CommittableSubstateDatabase + ListableSubstateDatabase>(
+        &self,
+        db: &mut S,
+    ) -> ProtocolUpdateReceipt {
+        let migration_receipts = self
+            .state_migrations
+            .iter()
+            .map(|state_migration| state_migration.apply(db))
+            .collect();
+        ProtocolUpdateReceipt {
+            version: self.version,
+            migration_receipts,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct ProtocolUpdateReceipt {
+    pub version: ProtocolVersion,
+    pub migration_receipts: Vec<StateMigrationReceipt>,
+}
+
+/// Executes the anointed protocol updates for every version after `from` up to and including
+/// `to`, returning a receipt per version.
+pub fn execute_protocol_updates<
+    S: SubstateDatabase + CommittableSubstateDatabase + ListableSubstateDatabase,
+>(
+    db: &mut S,
+    from: ProtocolVersion,
+    to: ProtocolVersion,
+) -> Vec<ProtocolUpdateReceipt> {
+    ProtocolVersion::VARIANTS
+        .into_iter()
+        .filter(|version| *version > from && *version <= to)
+        .map(|version| ProtocolUpdate::of(version).execute(db))
+        .collect()
+}
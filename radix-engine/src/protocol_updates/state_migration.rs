@@ -0,0 +1,167 @@
+use crate::internal_prelude::*;
+use crate::track::StateUpdates;
+use crate::utils::*;
+use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
+use radix_engine_store_interface::interface::*;
+
+/// A deterministic batch of state edits, applied directly to the substate database as part of
+/// a protocol update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateMigration {
+    /// Updates the Consensus Manager blueprint to use seconds precision.
+    SecondsPrecision,
+    /// Bumps the Scrypto VM minor version, enabling the crypto utils.
+    VmBootScryptoMinorVersion,
+    /// Fixes the validator fee change request logic.
+    ValidatorFeeFix,
+    /// Updates the pool blueprints to v1.1.
+    PoolsV1_1,
+    /// Updates the Consensus Manager blueprint to accept the rounded time precisions.
+    RoundedTime,
+    /// Updates the Scrypto VM to allow the imports added in the Cuttlefish update.
+    VmBootCuttlefishMinorVersion,
+    /// Rewrites the Account package to its current definition.
+    AccountPackageDefinition,
+    /// Rewrites the Consensus Manager package to its current definition.
+    ConsensusManagerPackageDefinition,
+    /// Rewrites the Package package to its current definition.
+    PackagePackageDefinition,
+    /// Rewrites the Pool package to its current v1.1 definition.
+    PoolPackageDefinition,
+    /// Rewrites the Resource package to its current definition.
+    ResourcePackageDefinition,
+    /// Rewrites the Role Assignment module package to its current definition.
+    RoleAssignmentPackageDefinition,
+    /// Rewrites the Transaction Processor package to its current definition.
+    TransactionProcessorPackageDefinition,
+    /// Initializes the fee split of the validators created before it was introduced.
+    ValidatorFeeSplit,
+    /// Initializes the epoch change hooks of the Consensus Manager.
+    EpochChangeHooks,
+    /// Initializes the next reservable id of the mintable non-fungible resources created before it
+    /// was introduced.
+    NonFungibleNextReservableId,
+    /// Initializes the authorizer of the accounts created before it was introduced.
+    AccountAuthorizer,
+    /// Publishes the Price Feed package on ledgers bootstrapped before it was added to genesis.
+    PriceFeedPackage,
+    /// Publishes the Payment Scheduler package on ledgers bootstrapped before it was added to
+    /// genesis.
+    PaymentSchedulerPackage,
+    /// Publishes the Order Book package on ledgers bootstrapped before it was added to genesis.
+    OrderBookPackage,
+    /// Creates the Deferred Execution Queue on ledgers bootstrapped before it was added to genesis.
+    DeferredExecutionQueue,
+    /// An arbitrary set of pre-computed state updates, e.g. patching substates in tests.
+    Custom {
+        name: String,
+        state_updates: StateUpdates,
+    },
+}
+
+impl StateMigration {
+    pub fn name(&self) -> &str {
+        match self {
+            StateMigration::SecondsPrecision => "seconds_precision",
+            StateMigration::VmBootScryptoMinorVersion => "vm_boot_scrypto_minor_version",
+            StateMigration::ValidatorFeeFix => "validator_fee_fix",
+            StateMigration::PoolsV1_1 => "pools_v1_1",
+            StateMigration::RoundedTime => "rounded_time",
+            StateMigration::VmBootCuttlefishMinorVersion => "vm_boot_cuttlefish_minor_version",
+            StateMigration::AccountPackageDefinition => "account_package_definition",
+            StateMigration::ConsensusManagerPackageDefinition => {
+                "consensus_manager_package_definition"
+            }
+            StateMigration::PackagePackageDefinition => "package_package_definition",
+            StateMigration::PoolPackageDefinition => "pool_package_definition",
+            StateMigration::ResourcePackageDefinition => "resource_package_definition",
+            StateMigration::RoleAssignmentPackageDefinition => "role_assignment_package_definition",
+            StateMigration::TransactionProcessorPackageDefinition => {
+                "transaction_processor_package_definition"
+            }
+            StateMigration::ValidatorFeeSplit => "validator_fee_split",
+            StateMigration::EpochChangeHooks => "epoch_change_hooks",
+            StateMigration::NonFungibleNextReservableId => "non_fungible_next_reservable_id",
+            StateMigration::AccountAuthorizer => "account_authorizer",
+            StateMigration::PriceFeedPackage => "price_feed_package",
+            StateMigration::PaymentSchedulerPackage => "payment_scheduler_package",
+            StateMigration::OrderBookPackage => "order_book_package",
+            StateMigration::DeferredExecutionQueue => "deferred_execution_queue",
+            StateMigration::Custom { name, .. } => name.as_str(),
+        }
+    }
+
+    /// Generates the state updates of this migration against the current state of the database.
+    pub fn generate_state_updates<S: SubstateDatabase + ListableSubstateDatabase>(
+        &self,
+        db: &S,
+    ) -> StateUpdates {
+        match self {
+            StateMigration::SecondsPrecision => generate_seconds_precision_state_updates(db),
+            StateMigration::VmBootScryptoMinorVersion => {
+                generate_vm_boot_scrypto_minor_version_state_updates()
+            }
+            StateMigration::ValidatorFeeFix => generate_validator_fee_fix_state_updates(db),
+            StateMigration::PoolsV1_1 => generate_pools_v1_1_state_updates(db),
+            StateMigration::RoundedTime => generate_rounded_time_state_updates(db),
+            StateMigration::VmBootCuttlefishMinorVersion => {
+                generate_vm_boot_cuttlefish_minor_version_state_updates()
+            }
+            StateMigration::AccountPackageDefinition => {
+                generate_account_package_definition_state_updates(db)
+            }
+            StateMigration::ConsensusManagerPackageDefinition => {
+                generate_consensus_manager_package_definition_state_updates(db)
+            }
+            StateMigration::PackagePackageDefinition => {
+                generate_package_package_definition_state_updates(db)
+            }
+            StateMigration::PoolPackageDefinition => {
+                generate_pool_package_definition_state_updates(db)
+            }
+            StateMigration::ResourcePackageDefinition => {
+                generate_resource_package_definition_state_updates(db)
+            }
+            StateMigration::RoleAssignmentPackageDefinition => {
+                generate_role_assignment_package_definition_state_updates(db)
+            }
+            StateMigration::TransactionProcessorPackageDefinition => {
+                generate_transaction_processor_package_definition_state_updates(db)
+            }
+            StateMigration::ValidatorFeeSplit => generate_validator_fee_split_state_updates(db),
+            StateMigration::EpochChangeHooks => generate_epoch_change_hooks_state_updates(db),
+            StateMigration::NonFungibleNextReservableId => {
+                generate_non_fungible_next_reservable_id_state_updates(db)
+            }
+            StateMigration::AccountAuthorizer => generate_account_authorizer_state_updates(db),
+            StateMigration::PriceFeedPackage => generate_price_feed_package_state_updates(db),
+            StateMigration::PaymentSchedulerPackage => {
+                generate_payment_scheduler_package_state_updates(db)
+            }
+            StateMigration::OrderBookPackage => generate_order_book_package_state_updates(db),
+            StateMigration::DeferredExecutionQueue => {
+                generate_deferred_execution_queue_state_updates(db)
+            }
+            StateMigration::Custom { state_updates, .. } => state_updates.clone(),
+        }
+    }
+
+    /// Generates the state updates of this migration and commits them to the database.
+    pub fn apply<S: SubstateDatabase + CommittableSubstateDatabase + ListableSubstateDatabase>(
+        &self,
+        db: &mut S,
+    ) -> StateMigrationReceipt {
+        let state_updates = self.generate_state_updates(db);
+        db.commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+        StateMigrationReceipt {
+            name: self.name().to_string(),
+            state_updates,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct StateMigrationReceipt {
+    pub name: String,
+    pub state_updates: StateUpdates,
+}
@@ -235,6 +235,30 @@ impl Actor {
         }
     }
 
+    pub fn fn_identifier(&self) -> Option<FnIdentifier> {
+        match self {
+            Actor::Method(actor) => Some(FnIdentifier {
+                blueprint_id: actor.get_blueprint_id(),
+                ident: actor.ident.clone(),
+            }),
+            Actor::Function(FunctionActor {
+                blueprint_id,
+                ident,
+                ..
+            }) => Some(FnIdentifier {
+                blueprint_id: blueprint_id.clone(),
+                ident: ident.clone(),
+            }),
+            Actor::BlueprintHook(BlueprintHookActor {
+                blueprint_id, hook, ..
+            }) => Some(FnIdentifier {
+                blueprint_id: blueprint_id.clone(),
+                ident: format!("{:?}", hook),
+            }),
+            Actor::Root => None,
+        }
+    }
+
     pub fn package_address(&self) -> Option<PackageAddress> {
         self.blueprint_id().map(|id| id.package_address)
     }
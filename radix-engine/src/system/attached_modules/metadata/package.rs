@@ -1,4 +1,5 @@
 use crate::internal_prelude::*;
+use crate::native_exports;
 use crate::types::*;
 use crate::{errors::*, event_schema, roles_template};
 use native_sdk::runtime::Runtime;
@@ -47,6 +48,17 @@ declare_native_blueprint_state! {
 
 pub type MetadataEntryV1 = MetadataValue;
 
+native_exports! {
+    pub enum MetadataNativeExport {
+        Create => METADATA_CREATE_IDENT,
+        CreateWithData => METADATA_CREATE_WITH_DATA_IDENT,
+        Set => METADATA_SET_IDENT,
+        Lock => METADATA_LOCK_IDENT,
+        Get => METADATA_GET_IDENT,
+        Remove => METADATA_REMOVE_IDENT,
+    }
+}
+
 pub struct MetadataNativePackage;
 
 impl MetadataNativePackage {
@@ -156,6 +168,7 @@ impl MetadataNativePackage {
                     types: BlueprintTypeSchemaInit::default(),
                     functions: BlueprintFunctionsSchemaInit {
                         functions,
+                        post_conditions: index_map_new(),
                     },
                     hooks: BlueprintHooksInit::default(),
                 },
@@ -194,8 +207,8 @@ impl MetadataNativePackage {
     where
         Y: ClientApi<RuntimeError>,
     {
-        match export_name {
-            METADATA_CREATE_IDENT => {
+        match MetadataNativeExport::from_export_name(export_name)? {
+            MetadataNativeExport::Create => {
                 let _input: MetadataCreateInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -204,7 +217,7 @@ impl MetadataNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            METADATA_CREATE_WITH_DATA_IDENT => {
+            MetadataNativeExport::CreateWithData => {
                 let input: MetadataCreateWithDataInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -213,7 +226,7 @@ impl MetadataNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            METADATA_SET_IDENT => {
+            MetadataNativeExport::Set => {
                 let input: MetadataSetInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -222,7 +235,7 @@ impl MetadataNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            METADATA_LOCK_IDENT => {
+            MetadataNativeExport::Lock => {
                 let input: MetadataLockInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -231,7 +244,7 @@ impl MetadataNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            METADATA_GET_IDENT => {
+            MetadataNativeExport::Get => {
                 let input: MetadataGetInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -240,7 +253,7 @@ impl MetadataNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            METADATA_REMOVE_IDENT => {
+            MetadataNativeExport::Remove => {
                 let input: MetadataRemoveInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -248,9 +261,6 @@ impl MetadataNativePackage {
                 let rtn = Self::remove(input.key, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
         }
     }
 
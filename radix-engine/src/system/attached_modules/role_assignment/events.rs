@@ -14,3 +14,19 @@ pub struct SetOwnerRoleEvent {
 
 #[derive(ScryptoSbor, ScryptoEvent, Debug)]
 pub struct LockOwnerRoleEvent {}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct ProposeOwnerRoleEvent {
+    pub rule: AccessRule,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct AcceptOwnerRoleEvent {
+    pub rule: AccessRule,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct PauseEvent {}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct UnpauseEvent {}
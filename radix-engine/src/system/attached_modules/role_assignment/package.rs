@@ -2,7 +2,10 @@ use crate::blueprints::models::*;
 use crate::blueprints::package::PackageAuthNativeBlueprint;
 use crate::blueprints::util::*;
 use crate::kernel::kernel_api::{KernelApi, KernelSubstateApi};
-use crate::system::attached_modules::role_assignment::{LockOwnerRoleEvent, SetOwnerRoleEvent};
+use crate::system::attached_modules::role_assignment::{
+    AcceptOwnerRoleEvent, LockOwnerRoleEvent, PauseEvent, ProposeOwnerRoleEvent, SetOwnerRoleEvent,
+    UnpauseEvent,
+};
 use crate::system::system::SystemService;
 use crate::system::system_callback::{SystemConfig, SystemLockData};
 use crate::system::system_callback_api::SystemCallbackObject;
@@ -10,7 +13,8 @@ use crate::system::system_modules::auth::{AuthError, ResolvedPermission};
 use crate::types::*;
 use crate::{errors::*, event_schema};
 
-use crate::system::system_substates::FieldSubstate;
+use crate::native_exports;
+use crate::system::system_substates::{FieldSubstate, KeyValueEntrySubstate};
 use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::field_api::LockFlags;
 use radix_engine_interface::api::node_modules::auth::*;
@@ -39,6 +43,24 @@ pub enum RoleAssignmentError {
     InvalidName(InvalidNameError),
     ExceededMaxRoles,
     CannotSetRoleIfNotAttached,
+    NoOwnerRoleProposed,
+    OwnerRoleProposalOutdated,
+    ObjectNotPausable,
+}
+
+native_exports! {
+    pub enum RoleAssignmentNativeExport {
+        Create => ROLE_ASSIGNMENT_CREATE_IDENT,
+        SetOwner => ROLE_ASSIGNMENT_SET_OWNER_IDENT,
+        LockOwner => ROLE_ASSIGNMENT_LOCK_OWNER_IDENT,
+        ProposeOwner => ROLE_ASSIGNMENT_PROPOSE_OWNER_IDENT,
+        AcceptOwner => ROLE_ASSIGNMENT_ACCEPT_OWNER_IDENT,
+        Set => ROLE_ASSIGNMENT_SET_IDENT,
+        Get => ROLE_ASSIGNMENT_GET_IDENT,
+        Pause => ROLE_ASSIGNMENT_PAUSE_IDENT,
+        Unpause => ROLE_ASSIGNMENT_UNPAUSE_IDENT,
+        IsPaused => ROLE_ASSIGNMENT_IS_PAUSED_IDENT,
+    }
 }
 
 pub struct RoleAssignmentNativePackage;
@@ -89,6 +111,32 @@ impl RoleAssignmentNativePackage {
                 export: ROLE_ASSIGNMENT_LOCK_OWNER_IDENT.to_string(),
             },
         );
+        functions.insert(
+            ROLE_ASSIGNMENT_PROPOSE_OWNER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentProposeOwnerInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentProposeOwnerOutput>(),
+                ),
+                export: ROLE_ASSIGNMENT_PROPOSE_OWNER_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            ROLE_ASSIGNMENT_ACCEPT_OWNER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentAcceptOwnerInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentAcceptOwnerOutput>(),
+                ),
+                export: ROLE_ASSIGNMENT_ACCEPT_OWNER_IDENT.to_string(),
+            },
+        );
         functions.insert(
             ROLE_ASSIGNMENT_SET_IDENT.to_string(),
             FunctionSchemaInit {
@@ -115,13 +163,56 @@ impl RoleAssignmentNativePackage {
                 export: ROLE_ASSIGNMENT_GET_IDENT.to_string(),
             },
         );
+        functions.insert(
+            ROLE_ASSIGNMENT_PAUSE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentPauseInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentPauseOutput>(),
+                ),
+                export: ROLE_ASSIGNMENT_PAUSE_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            ROLE_ASSIGNMENT_UNPAUSE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentUnpauseInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentUnpauseOutput>(),
+                ),
+                export: ROLE_ASSIGNMENT_UNPAUSE_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            ROLE_ASSIGNMENT_IS_PAUSED_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentIsPausedInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentIsPausedOutput>(),
+                ),
+                export: ROLE_ASSIGNMENT_IS_PAUSED_IDENT.to_string(),
+            },
+        );
 
         let events = event_schema! {
             aggregator,
             [
                 SetOwnerRoleEvent,
                 SetRoleEvent,
-                LockOwnerRoleEvent
+                LockOwnerRoleEvent,
+                ProposeOwnerRoleEvent,
+                AcceptOwnerRoleEvent,
+                PauseEvent,
+                UnpauseEvent
             ]
         };
 
@@ -130,7 +221,7 @@ impl RoleAssignmentNativePackage {
             ROLE_ASSIGNMENT_BLUEPRINT.to_string() => BlueprintDefinitionInit {
                 blueprint_type: BlueprintType::default(),
                 is_transient: true,
-                feature_set: indexset!(),
+                feature_set: indexset!(PAUSABLE_FEATURE.to_string()),
                 dependencies: indexset!(),
 
                 schema: BlueprintSchemaInit {
@@ -141,6 +232,7 @@ impl RoleAssignmentNativePackage {
                     types: BlueprintTypeSchemaInit::default(),
                     functions: BlueprintFunctionsSchemaInit {
                         functions,
+                        post_conditions: index_map_new(),
                     },
                     hooks: BlueprintHooksInit::default(),
                 },
@@ -167,6 +259,9 @@ impl RoleAssignmentNativePackage {
                 let input: RoleAssignmentSetInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
+                if Self::is_pauser_role_key(input.module, &input.role_key) {
+                    Self::assert_pausable(global_address, api)?;
+                }
                 let role_list = Self::resolve_update_role_method_permission(
                     global_address.as_node_id(),
                     input.module,
@@ -185,7 +280,22 @@ impl RoleAssignmentNativePackage {
             ROLE_ASSIGNMENT_LOCK_OWNER_IDENT => {
                 Self::resolve_update_owner_role_method_permission(global_address.as_node_id(), api)?
             }
+            ROLE_ASSIGNMENT_PROPOSE_OWNER_IDENT => {
+                Self::resolve_update_owner_role_method_permission(global_address.as_node_id(), api)?
+            }
+            ROLE_ASSIGNMENT_ACCEPT_OWNER_IDENT => {
+                Self::resolve_accept_owner_role_method_permission(global_address.as_node_id(), api)?
+            }
             ROLE_ASSIGNMENT_GET_IDENT => ResolvedPermission::AllowAll,
+            ROLE_ASSIGNMENT_PAUSE_IDENT | ROLE_ASSIGNMENT_UNPAUSE_IDENT => {
+                Self::assert_pausable(global_address, api)?;
+                ResolvedPermission::RoleList {
+                    role_assignment_of: global_address.clone(),
+                    role_list: RoleList::from([PAUSER_ROLE]),
+                    module_id: ModuleId::Main,
+                }
+            }
+            ROLE_ASSIGNMENT_IS_PAUSED_IDENT => ResolvedPermission::AllowAll,
             _ => {
                 return Err(RuntimeError::SystemModuleError(
                     SystemModuleError::AuthError(AuthError::NoMethodMapping(FnIdentifier {
@@ -210,8 +320,8 @@ impl RoleAssignmentNativePackage {
     where
         Y: ClientApi<RuntimeError>,
     {
-        match export_name {
-            ROLE_ASSIGNMENT_CREATE_IDENT => {
+        match RoleAssignmentNativeExport::from_export_name(export_name)? {
+            RoleAssignmentNativeExport::Create => {
                 let input: RoleAssignmentCreateInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -219,7 +329,7 @@ impl RoleAssignmentNativePackage {
                 let rtn = Self::create(input.owner_role, input.roles, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ROLE_ASSIGNMENT_SET_OWNER_IDENT => {
+            RoleAssignmentNativeExport::SetOwner => {
                 let input: RoleAssignmentSetOwnerInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -227,7 +337,7 @@ impl RoleAssignmentNativePackage {
                 let rtn = Self::set_owner_role(input.rule, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ROLE_ASSIGNMENT_LOCK_OWNER_IDENT => {
+            RoleAssignmentNativeExport::LockOwner => {
                 let _input: RoleAssignmentLockOwnerInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -235,7 +345,23 @@ impl RoleAssignmentNativePackage {
                 let rtn = Self::lock_owner_role(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ROLE_ASSIGNMENT_SET_IDENT => {
+            RoleAssignmentNativeExport::ProposeOwner => {
+                let input: RoleAssignmentProposeOwnerInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = Self::propose_owner_role(input.rule, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            RoleAssignmentNativeExport::AcceptOwner => {
+                let _input: RoleAssignmentAcceptOwnerInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = Self::accept_owner_role(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            RoleAssignmentNativeExport::Set => {
                 let input: RoleAssignmentSetInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -243,7 +369,7 @@ impl RoleAssignmentNativePackage {
                 let rtn = Self::set_role(input.module, input.role_key, input.rule, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            ROLE_ASSIGNMENT_GET_IDENT => {
+            RoleAssignmentNativeExport::Get => {
                 let input: RoleAssignmentGetInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -251,9 +377,30 @@ impl RoleAssignmentNativePackage {
                 let rtn = Self::get_role(input.module, input.role_key, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
+            RoleAssignmentNativeExport::Pause => {
+                let _input: RoleAssignmentPauseInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = Self::pause(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            RoleAssignmentNativeExport::Unpause => {
+                let _input: RoleAssignmentUnpauseInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = Self::unpause(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            RoleAssignmentNativeExport::IsPaused => {
+                let _input: RoleAssignmentIsPausedInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = Self::is_paused(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
         }
     }
 
@@ -261,6 +408,59 @@ impl RoleAssignmentNativePackage {
         return role_key.key.starts_with("_");
     }
 
+    /// The pauser role is the only reserved role which may be assigned, and only on the main
+    /// module.
+    fn is_pauser_role_key(module: ModuleId, role_key: &RoleKey) -> bool {
+        module.eq(&ModuleId::Main) && role_key.key.eq(PAUSER_ROLE)
+    }
+
+    /// Whether the given global object opted in to pausing when it was created.
+    pub fn is_pausable<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
+        global_address: &GlobalAddress,
+        api: &mut SystemService<Y, V>,
+    ) -> Result<bool, RuntimeError> {
+        api.is_feature_enabled(global_address.as_node_id(), None, PAUSABLE_FEATURE)
+    }
+
+    fn assert_pausable<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
+        global_address: &GlobalAddress,
+        api: &mut SystemService<Y, V>,
+    ) -> Result<(), RuntimeError> {
+        if !Self::is_pausable(global_address, api)? {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::RoleAssignmentError(RoleAssignmentError::ObjectNotPausable),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Whether public methods of the main module of the given global object are currently
+    /// rejected by the system.
+    pub fn is_main_module_paused<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
+        global_address: &GlobalAddress,
+        api: &mut SystemService<Y, V>,
+    ) -> Result<bool, RuntimeError> {
+        let handle = api.kernel_open_substate_with_default(
+            global_address.as_node_id(),
+            ROLE_ASSIGNMENT_BASE_PARTITION
+                .at_offset(ROLE_ASSIGNMENT_PAUSE_PARTITION_OFFSET)
+                .unwrap(),
+            &SubstateKey::Map(scrypto_encode(&ModuleId::Main).unwrap()),
+            LockFlags::read_only(),
+            Some(|| {
+                let kv_entry = KeyValueEntrySubstate::<()>::default();
+                IndexedScryptoValue::from_typed(&kv_entry)
+            }),
+            SystemLockData::default(),
+        )?;
+        let substate: KeyValueEntrySubstate<RoleAssignmentPausedModuleEntryPayload> =
+            api.kernel_read_substate(handle)?.as_typed().unwrap();
+        api.kernel_close_substate(handle)?;
+
+        Ok(substate.into_value().is_some())
+    }
+
     pub fn verify_access_rule(access_rule: &AccessRule) -> Result<(), RoleAssignmentError> {
         pub struct AccessRuleVerifier(usize);
         impl AccessRuleVisitor for AccessRuleVerifier {
@@ -318,6 +518,42 @@ impl RoleAssignmentNativePackage {
         Ok(ResolvedPermission::AccessRule(rule))
     }
 
+    /// Accepting an owner role proposal requires proving the proposed rule. Without a proposal
+    /// anyone may call the method, which then fails.
+    fn resolve_accept_owner_role_method_permission<
+        Y: KernelApi<SystemConfig<V>>,
+        V: SystemCallbackObject,
+    >(
+        receiver: &NodeId,
+        api: &mut SystemService<Y, V>,
+    ) -> Result<ResolvedPermission, RuntimeError> {
+        let handle = api.kernel_open_substate_with_default(
+            receiver,
+            ROLE_ASSIGNMENT_BASE_PARTITION
+                .at_offset(ROLE_ASSIGNMENT_PENDING_OWNER_PARTITION_OFFSET)
+                .unwrap(),
+            &SubstateKey::Map(scrypto_encode(&()).unwrap()),
+            LockFlags::read_only(),
+            Some(|| {
+                let kv_entry = KeyValueEntrySubstate::<()>::default();
+                IndexedScryptoValue::from_typed(&kv_entry)
+            }),
+            SystemLockData::default(),
+        )?;
+        let substate: KeyValueEntrySubstate<RoleAssignmentPendingOwnerEntryPayload> =
+            api.kernel_read_substate(handle)?.as_typed().unwrap();
+        api.kernel_close_substate(handle)?;
+
+        let permission = match substate.into_value() {
+            Some(pending_owner_role) => {
+                ResolvedPermission::AccessRule(pending_owner_role.into_latest().rule)
+            }
+            None => ResolvedPermission::AllowAll,
+        };
+
+        Ok(permission)
+    }
+
     fn resolve_update_role_method_permission<
         Y: KernelApi<SystemConfig<V>>,
         V: SystemCallbackObject,
@@ -327,6 +563,9 @@ impl RoleAssignmentNativePackage {
         role_key: &RoleKey,
         api: &mut SystemService<Y, V>,
     ) -> Result<RoleList, RuntimeError> {
+        if Self::is_pauser_role_key(module, role_key) {
+            return Ok(RoleList::from([OWNER_ROLE]));
+        }
         if Self::is_reserved_role_key(&role_key) || module.eq(&ModuleId::RoleAssignment) {
             return Ok(RoleList::none());
         }
@@ -391,7 +630,9 @@ impl RoleAssignmentNativePackage {
             }
 
             for (role_key, role_def) in roles.data {
-                if Self::is_reserved_role_key(&role_key) {
+                if Self::is_reserved_role_key(&role_key)
+                    && !Self::is_pauser_role_key(module, &role_key)
+                {
                     return Err(RoleAssignmentError::UsedReservedRole(
                         role_key.key.to_string(),
                     ));
@@ -441,13 +682,21 @@ impl RoleAssignmentNativePackage {
     where
         Y: ClientApi<RuntimeError>,
     {
+        // Defining the pauser role on creation is how objects opt in to pausing
+        let features = match roles.get(&ModuleId::Main) {
+            Some(main_roles) if main_roles.data.contains_key(&RoleKey::new(PAUSER_ROLE)) => {
+                vec![PAUSABLE_FEATURE]
+            }
+            _ => vec![],
+        };
+
         let (fields, kv_entries) = Self::init_system_struct(owner_role, roles).map_err(|e| {
             RuntimeError::ApplicationError(ApplicationError::RoleAssignmentError(e))
         })?;
 
         let component_id = api.new_object(
             ROLE_ASSIGNMENT_BLUEPRINT,
-            vec![],
+            features,
             GenericArgs::default(),
             fields,
             kv_entries,
@@ -502,6 +751,88 @@ impl RoleAssignmentNativePackage {
         Ok(())
     }
 
+    fn propose_owner_role<Y>(rule: AccessRule, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::verify_access_rule(&rule).map_err(|e| {
+            RuntimeError::ApplicationError(ApplicationError::RoleAssignmentError(e))
+        })?;
+
+        let handle = api.actor_open_field(ACTOR_STATE_SELF, 0u8, LockFlags::read_only())?;
+        let owner_role = api
+            .field_read_typed::<RoleAssignmentOwnerFieldPayload>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+
+        // Replaces any earlier proposal
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            RoleAssignmentCollection::PendingOwnerKeyValue.collection_index(),
+            &scrypto_encode(&()).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            handle,
+            RoleAssignmentPendingOwnerEntryPayload::from_content_source(PendingOwnerRoleSubstate {
+                rule: rule.clone(),
+                proposed_by: owner_role.owner_role_entry,
+            }),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Runtime::emit_event(api, ProposeOwnerRoleEvent { rule })?;
+
+        Ok(())
+    }
+
+    fn accept_owner_role<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let pending_owner_role = api
+            .actor_remove_key_value_entry_typed::<RoleAssignmentPendingOwnerEntryPayload>(
+                ACTOR_STATE_SELF,
+                RoleAssignmentCollection::PendingOwnerKeyValue.collection_index(),
+                &scrypto_encode(&()).unwrap(),
+            )?
+            .ok_or(RuntimeError::ApplicationError(
+                ApplicationError::RoleAssignmentError(RoleAssignmentError::NoOwnerRoleProposed),
+            ))?
+            .into_latest();
+
+        let handle = api.actor_open_field(ACTOR_STATE_SELF, 0u8, LockFlags::read_only())?;
+        let mut owner_role = api
+            .field_read_typed::<RoleAssignmentOwnerFieldPayload>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+
+        if owner_role.owner_role_entry != pending_owner_role.proposed_by {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::RoleAssignmentError(
+                    RoleAssignmentError::OwnerRoleProposalOutdated,
+                ),
+            ));
+        }
+
+        let handle = api.actor_open_field(ACTOR_STATE_SELF, 0u8, LockFlags::MUTABLE)?;
+        owner_role.owner_role_entry.rule = pending_owner_role.rule.clone();
+        api.field_write_typed(
+            handle,
+            &RoleAssignmentOwnerFieldPayload::from_content_source(owner_role),
+        )?;
+        api.field_close(handle)?;
+
+        Runtime::emit_event(
+            api,
+            AcceptOwnerRoleEvent {
+                rule: pending_owner_role.rule,
+            },
+        )?;
+
+        Ok(())
+    }
+
     fn set_role<Y>(
         module: ModuleId,
         role_key: RoleKey,
@@ -516,7 +847,7 @@ impl RoleAssignmentNativePackage {
                 ApplicationError::RoleAssignmentError(RoleAssignmentError::UsedReservedSpace),
             ));
         }
-        if Self::is_reserved_role_key(&role_key) {
+        if Self::is_reserved_role_key(&role_key) && !Self::is_pauser_role_key(module, &role_key) {
             return Err(RuntimeError::ApplicationError(
                 ApplicationError::RoleAssignmentError(RoleAssignmentError::UsedReservedRole(
                     role_key.key.to_string(),
@@ -600,4 +931,57 @@ impl RoleAssignmentNativePackage {
 
         Ok(rule.map(|v| v.into_latest()))
     }
+
+    fn pause<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            RoleAssignmentCollection::PausedModuleKeyValue.collection_index(),
+            &scrypto_encode(&ModuleId::Main).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            handle,
+            RoleAssignmentPausedModuleEntryPayload::from_content_source(()),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Runtime::emit_event(api, PauseEvent {})?;
+
+        Ok(())
+    }
+
+    fn unpause<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        api.actor_remove_key_value_entry(
+            ACTOR_STATE_SELF,
+            RoleAssignmentCollection::PausedModuleKeyValue.collection_index(),
+            &scrypto_encode(&ModuleId::Main).unwrap(),
+        )?;
+
+        Runtime::emit_event(api, UnpauseEvent {})?;
+
+        Ok(())
+    }
+
+    fn is_paused<Y>(api: &mut Y) -> Result<bool, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            RoleAssignmentCollection::PausedModuleKeyValue.collection_index(),
+            &scrypto_encode(&ModuleId::Main).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let paused =
+            api.key_value_entry_get_typed::<RoleAssignmentPausedModuleEntryPayload>(handle)?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(paused.is_some())
+    }
 }
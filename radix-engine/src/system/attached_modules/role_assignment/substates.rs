@@ -10,6 +10,16 @@ pub struct OwnerRoleSubstate {
     pub owner_role_entry: OwnerRoleEntry,
 }
 
+/// An owner role proposed by the current owner, which only replaces the owner role once its
+/// rule has been proven by an `accept_owner` call.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct PendingOwnerRoleSubstate {
+    pub rule: AccessRule,
+    /// The owner role at the time of the proposal. The proposal lapses if the owner role is
+    /// changed or locked before it is accepted.
+    pub proposed_by: OwnerRoleEntry,
+}
+
 declare_native_blueprint_state! {
     blueprint_ident: RoleAssignment,
     blueprint_snake_case: role_assignment,
@@ -36,8 +46,32 @@ declare_native_blueprint_state! {
             },
             allow_ownership: false,
         },
+        paused_modules: KeyValue {
+            entry_ident: PausedModule,
+            key_type: {
+                kind: Static,
+                content_type: ModuleId,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+        pending_owner: KeyValue {
+            entry_ident: PendingOwner,
+            key_type: {
+                kind: Static,
+                content_type: (),
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
     }
 }
 
 pub type RoleAssignmentOwnerV1 = OwnerRoleSubstate;
 pub type RoleAssignmentAccessRuleV1 = AccessRule;
+pub type RoleAssignmentPausedModuleV1 = ();
+pub type RoleAssignmentPendingOwnerV1 = PendingOwnerRoleSubstate;
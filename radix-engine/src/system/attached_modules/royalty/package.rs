@@ -14,6 +14,7 @@ use radix_engine_interface::schema::{
 use crate::blueprints::package::PackageError;
 use crate::internal_prelude::*;
 use crate::kernel::kernel_api::KernelApi;
+use crate::native_exports;
 use crate::roles_template;
 use crate::system::system_callback::{SystemConfig, SystemLockData};
 use crate::system::system_callback_api::SystemCallbackObject;
@@ -56,6 +57,15 @@ declare_native_blueprint_state! {
 pub type ComponentRoyaltyAccumulatorV1 = ComponentRoyaltySubstate;
 pub type ComponentRoyaltyMethodAmountV1 = RoyaltyAmount;
 
+native_exports! {
+    pub enum RoyaltyNativeExport {
+        Create => COMPONENT_ROYALTY_CREATE_IDENT,
+        SetRoyalty => COMPONENT_ROYALTY_SET_ROYALTY_IDENT,
+        LockRoyalty => COMPONENT_ROYALTY_LOCK_ROYALTY_IDENT,
+        ClaimRoyalties => COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT,
+    }
+}
+
 pub struct RoyaltyNativePackage;
 impl RoyaltyNativePackage {
     pub fn definition() -> PackageDefinition {
@@ -134,6 +144,7 @@ impl RoyaltyNativePackage {
                     types: BlueprintTypeSchemaInit::default(),
                     functions: BlueprintFunctionsSchemaInit {
                         functions,
+                        post_conditions: index_map_new(),
                     },
                     hooks: BlueprintHooksInit::default(),
                 },
@@ -173,15 +184,15 @@ impl RoyaltyNativePackage {
     where
         Y: ClientApi<RuntimeError>,
     {
-        match export_name {
-            COMPONENT_ROYALTY_CREATE_IDENT => {
+        match RoyaltyNativeExport::from_export_name(export_name)? {
+            RoyaltyNativeExport::Create => {
                 let input: ComponentRoyaltyCreateInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ComponentRoyaltyBlueprint::create(input.royalty_config, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            COMPONENT_ROYALTY_SET_ROYALTY_IDENT => {
+            RoyaltyNativeExport::SetRoyalty => {
                 let input: ComponentRoyaltySetInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -189,7 +200,7 @@ impl RoyaltyNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            COMPONENT_ROYALTY_LOCK_ROYALTY_IDENT => {
+            RoyaltyNativeExport::LockRoyalty => {
                 let input: ComponentRoyaltyLockInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
@@ -197,16 +208,13 @@ impl RoyaltyNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT => {
+            RoyaltyNativeExport::ClaimRoyalties => {
                 let _input: ComponentClaimRoyaltiesInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
                 let rtn = ComponentRoyaltyBlueprint::claim_royalties(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::ExportDoesNotExist(export_name.to_string()),
-            )),
         }
     }
 }
@@ -2,11 +2,14 @@ use crate::blueprints::access_controller::*;
 use crate::blueprints::account::{AccountNativePackage, AccountOwnerBadgeData};
 use crate::blueprints::consensus_manager::ConsensusManagerNativePackage;
 use crate::blueprints::identity::{IdentityNativePackage, IdentityOwnerBadgeData};
+use crate::blueprints::order_book::OrderBookNativePackage;
 use crate::blueprints::package::{
     create_package_partition_substates, PackageCollection, PackageNativePackage,
     PackageOwnerBadgeData, SystemInstruction,
 };
+use crate::blueprints::payment_scheduler::PaymentSchedulerNativePackage;
 use crate::blueprints::pool::v1::package::{PoolNativePackage, PoolV1MinorVersion};
+use crate::blueprints::price_feed::PriceFeedNativePackage;
 use crate::blueprints::resource::ResourceNativePackage;
 use crate::blueprints::test_utils::TestUtilsNativePackage;
 use crate::blueprints::transaction_processor::TransactionProcessorNativePackage;
@@ -42,6 +45,7 @@ use radix_engine_interface::blueprints::consensus_manager::{
 };
 use radix_engine_interface::blueprints::package::*;
 use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::blueprints::transaction_processor::*;
 use radix_engine_interface::math::traits::*;
 use radix_engine_interface::{
     burn_roles, metadata, metadata_init, mint_roles, rule, withdraw_roles,
@@ -172,6 +176,216 @@ pub struct ManifestGenesisResource {
     pub owner: Option<ComponentAddress>,
 }
 
+//==========================================================================================
+// GENESIS BUILDER
+// Allows custom networks to configure genesis programmatically, instead of patching the
+// bootstrap logic
+//==========================================================================================
+
+/// A WASM package which is published (as the system) as part of genesis.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct GenesisPackage {
+    pub code: Vec<u8>,
+    pub definition: PackageDefinition,
+    pub metadata: MetadataInit,
+    pub owner_role: OwnerRole,
+}
+
+#[derive(Debug, Clone)]
+pub struct GenesisTransactions {
+    pub system_bootstrap: SystemTransactionV1,
+    pub data_ingestion: Vec<SystemTransactionV1>,
+    pub initial_packages: Vec<SystemTransactionV1>,
+    pub wrap_up: SystemTransactionV1,
+}
+
+#[derive(Debug, Clone)]
+pub struct GenesisBuilder {
+    genesis_epoch: Epoch,
+    initial_config: ConsensusManagerConfig,
+    initial_time_ms: i64,
+    initial_current_leader: Option<ValidatorIndex>,
+    faucet_supply: Decimal,
+    data_chunks: Vec<GenesisDataChunk>,
+    validators: Vec<GenesisValidator>,
+    stake_accounts: Vec<ComponentAddress>,
+    stake_allocations: IndexMap<Secp256k1PublicKey, Vec<GenesisStakeAllocation>>,
+    xrd_balances: Vec<(ComponentAddress, Decimal)>,
+    initial_packages: Vec<GenesisPackage>,
+}
+
+impl Default for GenesisBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GenesisBuilder {
+    pub fn new() -> Self {
+        Self {
+            genesis_epoch: Epoch::of(1),
+            initial_config: ConsensusManagerConfig {
+                max_validators: 10,
+                epoch_change_condition: EpochChangeCondition {
+                    min_round_count: 1,
+                    max_round_count: 1,
+                    target_duration_millis: 0,
+                },
+                num_unstake_epochs: 1,
+                total_emission_xrd_per_epoch: Decimal::one(),
+                min_validator_reliability: Decimal::one(),
+                num_owner_stake_units_unlock_epochs: 2,
+                num_fee_increase_delay_epochs: 1,
+                validator_creation_usd_cost: *DEFAULT_VALIDATOR_USD_COST,
+            },
+            initial_time_ms: 1,
+            initial_current_leader: Some(0),
+            faucet_supply: *DEFAULT_TESTING_FAUCET_SUPPLY,
+            data_chunks: vec![],
+            validators: vec![],
+            stake_accounts: vec![],
+            stake_allocations: index_map_new(),
+            xrd_balances: vec![],
+            initial_packages: vec![],
+        }
+    }
+
+    pub fn with_genesis_epoch(mut self, genesis_epoch: Epoch) -> Self {
+        self.genesis_epoch = genesis_epoch;
+        self
+    }
+
+    pub fn with_consensus_manager_config(mut self, initial_config: ConsensusManagerConfig) -> Self {
+        self.initial_config = initial_config;
+        self
+    }
+
+    pub fn with_initial_time_ms(mut self, initial_time_ms: i64) -> Self {
+        self.initial_time_ms = initial_time_ms;
+        self
+    }
+
+    pub fn with_initial_current_leader(
+        mut self,
+        initial_current_leader: Option<ValidatorIndex>,
+    ) -> Self {
+        self.initial_current_leader = initial_current_leader;
+        self
+    }
+
+    pub fn with_faucet_supply(mut self, faucet_supply: Decimal) -> Self {
+        self.faucet_supply = faucet_supply;
+        self
+    }
+
+    /// Adds a raw data chunk, which is ingested before any of the chunks generated from the
+    /// validators, stakes and XRD balances configured on this builder.
+    pub fn with_data_chunk(mut self, chunk: GenesisDataChunk) -> Self {
+        self.data_chunks.push(chunk);
+        self
+    }
+
+    pub fn with_data_chunks(mut self, chunks: Vec<GenesisDataChunk>) -> Self {
+        self.data_chunks.extend(chunks);
+        self
+    }
+
+    pub fn with_validator(mut self, validator: impl Into<GenesisValidator>) -> Self {
+        self.validators.push(validator.into());
+        self
+    }
+
+    /// Stakes the given amount of XRD to the validator with the given key, depositing the
+    /// stake units into the given staker account.
+    pub fn with_stake(
+        mut self,
+        validator_key: Secp256k1PublicKey,
+        staker_account: ComponentAddress,
+        xrd_amount: Decimal,
+    ) -> Self {
+        let account_index = match self
+            .stake_accounts
+            .iter()
+            .position(|account| account.eq(&staker_account))
+        {
+            Some(index) => index,
+            None => {
+                self.stake_accounts.push(staker_account);
+                self.stake_accounts.len() - 1
+            }
+        };
+        self.stake_allocations
+            .entry(validator_key)
+            .or_default()
+            .push(GenesisStakeAllocation {
+                account_index: account_index as u32,
+                xrd_amount,
+            });
+        self
+    }
+
+    pub fn with_xrd_allocation(mut self, account: ComponentAddress, amount: Decimal) -> Self {
+        self.xrd_balances.push((account, amount));
+        self
+    }
+
+    pub fn with_initial_package(mut self, package: GenesisPackage) -> Self {
+        self.initial_packages.push(package);
+        self
+    }
+
+    pub fn genesis_data_chunks(&self) -> Vec<GenesisDataChunk> {
+        let mut chunks = self.data_chunks.clone();
+        if !self.validators.is_empty() {
+            chunks.push(GenesisDataChunk::Validators(self.validators.clone()));
+        }
+        if !self.stake_allocations.is_empty() {
+            chunks.push(GenesisDataChunk::Stakes {
+                accounts: self.stake_accounts.clone(),
+                allocations: self
+                    .stake_allocations
+                    .iter()
+                    .map(|(key, allocations)| (key.clone(), allocations.clone()))
+                    .collect(),
+            });
+        }
+        if !self.xrd_balances.is_empty() {
+            chunks.push(GenesisDataChunk::XrdBalances(self.xrd_balances.clone()));
+        }
+        chunks
+    }
+
+    /// Produces the genesis transactions, in the order they should be executed.
+    pub fn build_transactions(&self) -> GenesisTransactions {
+        GenesisTransactions {
+            system_bootstrap: create_system_bootstrap_transaction(
+                self.genesis_epoch,
+                self.initial_config.clone(),
+                self.initial_time_ms,
+                self.initial_current_leader,
+                self.faucet_supply,
+            ),
+            data_ingestion: self
+                .genesis_data_chunks()
+                .into_iter()
+                .enumerate()
+                .map(|(chunk_number, chunk)| {
+                    create_genesis_data_ingestion_transaction(&GENESIS_HELPER, chunk, chunk_number)
+                })
+                .collect(),
+            initial_packages: self
+                .initial_packages
+                .iter()
+                .enumerate()
+                .map(|(package_number, package)| {
+                    create_genesis_package_publish_transaction(package.clone(), package_number)
+                })
+                .collect(),
+            wrap_up: create_genesis_wrap_up_transaction(),
+        }
+    }
+}
+
 //==========================================================================================
 // BOOTSTRAPPER
 // Various helper utilities for constructing and executing genesis
@@ -180,6 +394,7 @@ pub struct ManifestGenesisResource {
 #[derive(Debug, Clone, ScryptoSbor)]
 pub struct GenesisReceipts {
     pub system_bootstrap_receipt: TransactionReceipt,
+    /// The receipts of the genesis data chunks, followed by those of the initial packages
     pub data_ingestion_receipts: Vec<TransactionReceipt>,
     pub wrap_up_receipt: TransactionReceipt,
 }
@@ -313,27 +528,7 @@ where
     }
 
     pub fn bootstrap_test_default(&mut self) -> Option<GenesisReceipts> {
-        self.bootstrap_with_genesis_data(
-            vec![],
-            Epoch::of(1),
-            ConsensusManagerConfig {
-                max_validators: 10,
-                epoch_change_condition: EpochChangeCondition {
-                    min_round_count: 1,
-                    max_round_count: 1,
-                    target_duration_millis: 0,
-                },
-                num_unstake_epochs: 1,
-                total_emission_xrd_per_epoch: Decimal::one(),
-                min_validator_reliability: Decimal::one(),
-                num_owner_stake_units_unlock_epochs: 2,
-                num_fee_increase_delay_epochs: 1,
-                validator_creation_usd_cost: *DEFAULT_VALIDATOR_USD_COST,
-            },
-            1,
-            Some(0),
-            *DEFAULT_TESTING_FAUCET_SUPPLY,
-        )
+        self.bootstrap_with_genesis_builder(GenesisBuilder::new())
     }
 
     pub fn bootstrap_with_genesis_data(
@@ -344,6 +539,21 @@ where
         initial_time_ms: i64,
         initial_current_leader: Option<ValidatorIndex>,
         faucet_supply: Decimal,
+    ) -> Option<GenesisReceipts> {
+        self.bootstrap_with_genesis_builder(
+            GenesisBuilder::new()
+                .with_data_chunks(genesis_data_chunks)
+                .with_genesis_epoch(genesis_epoch)
+                .with_consensus_manager_config(initial_config)
+                .with_initial_time_ms(initial_time_ms)
+                .with_initial_current_leader(initial_current_leader)
+                .with_faucet_supply(faucet_supply),
+        )
+    }
+
+    pub fn bootstrap_with_genesis_builder(
+        &mut self,
+        genesis_builder: GenesisBuilder,
     ) -> Option<GenesisReceipts> {
         let flash_receipt = create_substate_flash_for_genesis();
         let first_package = flash_receipt.state_update_summary.new_packages[0];
@@ -362,24 +572,42 @@ where
                     .create_database_updates::<SpreadPrefixKeyMapper>(),
             );
 
-            let mut system_bootstrap_receipt = self.execute_system_bootstrap(
-                genesis_epoch,
-                initial_config,
-                initial_time_ms,
-                initial_current_leader,
-                faucet_supply,
+            let GenesisTransactions {
+                system_bootstrap,
+                data_ingestion,
+                initial_packages,
+                wrap_up,
+            } = genesis_builder.build_transactions();
+
+            let mut system_bootstrap_receipt = self.execute_genesis_transaction(
+                system_bootstrap,
+                "Expected system bootstrap transaction to be preparable",
             );
 
             flash_receipt
                 .merge_genesis_flash_into_transaction_receipt(&mut system_bootstrap_receipt);
 
             let mut data_ingestion_receipts = vec![];
-            for (chunk_index, chunk) in genesis_data_chunks.into_iter().enumerate() {
-                let receipt = self.ingest_genesis_data_chunk(chunk, chunk_index);
+            for transaction in data_ingestion {
+                let receipt = self.execute_genesis_transaction(
+                    transaction,
+                    "Expected genesis data chunk transaction to be preparable",
+                );
                 data_ingestion_receipts.push(receipt);
             }
 
-            let genesis_wrap_up_receipt = self.execute_genesis_wrap_up();
+            for transaction in initial_packages {
+                let receipt = self.execute_genesis_transaction(
+                    transaction,
+                    "Expected genesis package publish transaction to be preparable",
+                );
+                data_ingestion_receipts.push(receipt);
+            }
+
+            let genesis_wrap_up_receipt = self.execute_genesis_transaction(
+                wrap_up,
+                "Expected genesis wrap up transaction to be preparable",
+            );
 
             Some(GenesisReceipts {
                 system_bootstrap_receipt,
@@ -391,77 +619,11 @@ where
         }
     }
 
-    fn execute_system_bootstrap(
-        &mut self,
-        genesis_epoch: Epoch,
-        initial_config: ConsensusManagerConfig,
-        initial_time_ms: i64,
-        initial_current_leader: Option<ValidatorIndex>,
-        faucet_supply: Decimal,
-    ) -> TransactionReceipt {
-        let transaction = create_system_bootstrap_transaction(
-            genesis_epoch,
-            initial_config,
-            initial_time_ms,
-            initial_current_leader,
-            faucet_supply,
-        );
-
-        let receipt = execute_transaction(
-            self.substate_db,
-            self.vm.clone(),
-            &CostingParameters::default(),
-            &ExecutionConfig::for_genesis_transaction(self.network_definition.clone())
-                .with_kernel_trace(self.trace),
-            &transaction
-                .prepare()
-                .expect("Expected system bootstrap transaction to be preparable")
-                .get_executable(btreeset![AuthAddresses::system_role()]),
-        );
-
-        let commit_result = receipt.expect_commit(true);
-
-        self.substate_db.commit(
-            &commit_result
-                .state_updates
-                .create_database_updates::<SpreadPrefixKeyMapper>(),
-        );
-
-        receipt
-    }
-
-    fn ingest_genesis_data_chunk(
+    fn execute_genesis_transaction(
         &mut self,
-        chunk: GenesisDataChunk,
-        chunk_number: usize,
+        transaction: SystemTransactionV1,
+        preparation_expectation: &str,
     ) -> TransactionReceipt {
-        let transaction =
-            create_genesis_data_ingestion_transaction(&GENESIS_HELPER, chunk, chunk_number);
-        let receipt = execute_transaction(
-            self.substate_db,
-            self.vm.clone(),
-            &CostingParameters::default(),
-            &ExecutionConfig::for_genesis_transaction(self.network_definition.clone())
-                .with_kernel_trace(self.trace),
-            &transaction
-                .prepare()
-                .expect("Expected genesis data chunk transaction to be preparable")
-                .get_executable(btreeset![AuthAddresses::system_role()]),
-        );
-
-        let commit_result = receipt.expect_commit(true);
-        self.substate_db.commit(
-            &commit_result
-                .state_updates
-                .create_database_updates::<SpreadPrefixKeyMapper>(),
-        );
-
-        receipt
-    }
-
-    fn execute_genesis_wrap_up(&mut self) -> TransactionReceipt {
-        let transaction = create_genesis_wrap_up_transaction();
-
         let receipt = execute_transaction(
             self.substate_db,
             self.vm.clone(),
@@ -470,7 +632,7 @@ where
                 .with_kernel_trace(self.trace),
             &transaction
                 .prepare()
-                .expect("Expected genesis wrap up transaction to be preparable")
+                .expect(preparation_expectation)
                 .get_executable(btreeset![AuthAddresses::system_role()]),
         );
 
@@ -1075,6 +1237,72 @@ pub fn create_system_bootstrap_transaction(
         });
     }
 
+    // Price Feed Package
+    {
+        pre_allocated_addresses.push((
+            BlueprintId::new(&PACKAGE_PACKAGE, PACKAGE_BLUEPRINT),
+            GlobalAddress::from(PRICE_FEED_PACKAGE),
+        ));
+        instructions.push(InstructionV1::CallFunction {
+            package_address: PACKAGE_PACKAGE.into(),
+            blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+            function_name: PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackagePublishNativeManifestInput {
+                package_address: Some(id_allocator.new_address_reservation_id()),
+                definition: PriceFeedNativePackage::definition(),
+                metadata: metadata_init! {
+                    "name" => "Price Feed Package".to_owned(), locked;
+                    "description" => "A native package that defines the logic of price feed oracle components.".to_owned(), locked;
+                },
+                native_package_code_id: PRICE_FEED_CODE_ID,
+            }),
+        });
+    }
+
+    // Payment Scheduler Package
+    {
+        pre_allocated_addresses.push((
+            BlueprintId::new(&PACKAGE_PACKAGE, PACKAGE_BLUEPRINT),
+            GlobalAddress::from(PAYMENT_SCHEDULER_PACKAGE),
+        ));
+        instructions.push(InstructionV1::CallFunction {
+            package_address: PACKAGE_PACKAGE.into(),
+            blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+            function_name: PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackagePublishNativeManifestInput {
+                package_address: Some(id_allocator.new_address_reservation_id()),
+                definition: PaymentSchedulerNativePackage::definition(),
+                metadata: metadata_init! {
+                    "name" => "Payment Scheduler Package".to_owned(), locked;
+                    "description" => "A native package that defines the logic of recurring payment scheduler components.".to_owned(), locked;
+                },
+                native_package_code_id: PAYMENT_SCHEDULER_CODE_ID,
+            }),
+        });
+    }
+
+    // Order Book Package
+    {
+        pre_allocated_addresses.push((
+            BlueprintId::new(&PACKAGE_PACKAGE, PACKAGE_BLUEPRINT),
+            GlobalAddress::from(ORDER_BOOK_PACKAGE),
+        ));
+        instructions.push(InstructionV1::CallFunction {
+            package_address: PACKAGE_PACKAGE.into(),
+            blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+            function_name: PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackagePublishNativeManifestInput {
+                package_address: Some(id_allocator.new_address_reservation_id()),
+                definition: OrderBookNativePackage::definition(),
+                metadata: metadata_init! {
+                    "name" => "Order Book Package".to_owned(), locked;
+                    "description" => "A native package that defines the logic of price-time priority order books.".to_owned(), locked;
+                },
+                native_package_code_id: ORDER_BOOK_CODE_ID,
+            }),
+        });
+    }
+
     // ECDSA Secp256k1
     {
         pre_allocated_addresses.push((
@@ -1299,6 +1527,25 @@ pub fn create_system_bootstrap_transaction(
         });
     }
 
+    // Deferred Execution Queue component
+    {
+        pre_allocated_addresses.push((
+            BlueprintId::new(
+                &TRANSACTION_PROCESSOR_PACKAGE,
+                DEFERRED_EXECUTION_QUEUE_BLUEPRINT,
+            ),
+            GlobalAddress::from(DEFERRED_EXECUTION_QUEUE),
+        ));
+        instructions.push(InstructionV1::CallFunction {
+            package_address: TRANSACTION_PROCESSOR_PACKAGE.into(),
+            blueprint_name: DEFERRED_EXECUTION_QUEUE_BLUEPRINT.to_string(),
+            function_name: DEFERRED_EXECUTION_QUEUE_CREATE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&DeferredExecutionQueueCreateManifestInput {
+                address_reservation: id_allocator.new_address_reservation_id(),
+            }),
+        });
+    }
+
     // Faucet
     // Note - the faucet is now created as part of bootstrap instead of wrap-up, to enable
     // transaction scenarios to be injected into the ledger in the node before genesis wrap-up occurs
@@ -1424,6 +1671,34 @@ fn map_address_allocations_for_manifest(
     }
 }
 
+pub fn create_genesis_package_publish_transaction(
+    package: GenesisPackage,
+    package_number: usize,
+) -> SystemTransactionV1 {
+    let code_hash = hash(&package.code);
+    let instructions = vec![InstructionV1::CallFunction {
+        package_address: PACKAGE_PACKAGE.into(),
+        blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+        function_name: PACKAGE_PUBLISH_WASM_ADVANCED_IDENT.to_string(),
+        args: to_manifest_value_and_unwrap!(&PackagePublishWasmAdvancedManifestInput {
+            package_address: None,
+            code: ManifestBlobRef(code_hash.0),
+            definition: package.definition,
+            metadata: package.metadata,
+            owner_role: package.owner_role,
+        }),
+    }];
+
+    SystemTransactionV1 {
+        instructions: InstructionsV1(instructions),
+        pre_allocated_addresses: vec![],
+        blobs: BlobsV1 {
+            blobs: vec![BlobV1(package.code)],
+        },
+        hash_for_execution: hash(format!("Genesis Initial Package: {}", package_number)),
+    }
+}
+
 pub fn create_genesis_wrap_up_transaction() -> SystemTransactionV1 {
     let mut instructions = Vec::new();
 
@@ -201,6 +201,7 @@ impl ApplicationChecker for ResourceDatabaseChecker {
                             .checked_add(Decimal::one())
                             .unwrap();
                     }
+                    NonFungibleVaultCollection::AllowanceKeyValue => {}
                 }
             }
             _ => {}
@@ -107,6 +107,15 @@ pub enum SystemPartitionCheckError {
     InvalidSchemaKey,
     InvalidSchemaValue,
     InvalidBootLoaderPartition,
+    InvalidEventSubscriptionsPartition,
+    InvalidEventSubscriptionsKey,
+    InvalidEventSubscriptionsValue,
+    InvalidStateSizePartition,
+    InvalidStateSizeKey,
+    InvalidStateSizeValue,
+    InvalidRateLimitsPartition,
+    InvalidRateLimitsKey,
+    InvalidRateLimitsValue,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -495,6 +504,72 @@ impl<A: ApplicationChecker> SystemDatabaseChecker<A> {
                         substate_count += 1;
                     }
                 }
+                SystemPartitionDescriptor::EventSubscriptions => {
+                    if !node_checker_state.node_id.is_global() {
+                        return Err(SystemPartitionCheckError::InvalidEventSubscriptionsPartition);
+                    }
+
+                    for (key, value) in reader
+                        .substates_iter::<MapKey>(&node_checker_state.node_id, partition_number)
+                    {
+                        let map_key = match key {
+                            SubstateKey::Map(map_key) => map_key,
+                            _ => {
+                                return Err(SystemPartitionCheckError::InvalidEventSubscriptionsKey)
+                            }
+                        };
+                        let _event_name: String = scrypto_decode(&map_key)
+                            .map_err(|_| SystemPartitionCheckError::InvalidEventSubscriptionsKey)?;
+
+                        let _subscriptions: EventSubscriptionsSubstate =
+                            scrypto_decode(&value).map_err(|_| {
+                                SystemPartitionCheckError::InvalidEventSubscriptionsValue
+                            })?;
+
+                        substate_count += 1;
+                    }
+                }
+                SystemPartitionDescriptor::StateSize => {
+                    if node_checker_state
+                        .node_id
+                        .eq(TRANSACTION_TRACKER.as_node_id())
+                    {
+                        return Err(SystemPartitionCheckError::InvalidStateSizePartition);
+                    }
+
+                    for (key, value) in reader
+                        .substates_iter::<FieldKey>(&node_checker_state.node_id, partition_number)
+                    {
+                        StateSizeField::try_from(&key)
+                            .map_err(|_| SystemPartitionCheckError::InvalidStateSizeKey)?;
+
+                        let _state_size: NodeStateSize = scrypto_decode(&value)
+                            .map_err(|_| SystemPartitionCheckError::InvalidStateSizeValue)?;
+
+                        substate_count += 1;
+                    }
+                }
+                SystemPartitionDescriptor::RateLimits => {
+                    if !node_checker_state.node_id.is_global() {
+                        return Err(SystemPartitionCheckError::InvalidRateLimitsPartition);
+                    }
+
+                    for (key, value) in reader
+                        .substates_iter::<MapKey>(&node_checker_state.node_id, partition_number)
+                    {
+                        let map_key = match key {
+                            SubstateKey::Map(map_key) => map_key,
+                            _ => return Err(SystemPartitionCheckError::InvalidRateLimitsKey),
+                        };
+                        let _key: RateLimitKey = scrypto_decode(&map_key)
+                            .map_err(|_| SystemPartitionCheckError::InvalidRateLimitsKey)?;
+
+                        let _rate_limit: RateLimitSubstate = scrypto_decode(&value)
+                            .map_err(|_| SystemPartitionCheckError::InvalidRateLimitsValue)?;
+
+                        substate_count += 1;
+                    }
+                }
                 SystemPartitionDescriptor::TypeInfo => {
                     for (key, value) in reader
                         .substates_iter::<FieldKey>(&node_checker_state.node_id, partition_number)
@@ -2,11 +2,12 @@ use super::id_allocation::IDAllocation;
 use super::system_modules::costing::ExecutionCostingEntry;
 use crate::blueprints::package::PackageBlueprintVersionDefinitionEntrySubstate;
 use crate::blueprints::resource::fungible_vault::LockFeeEvent;
+use crate::blueprints::resource::AuthZone;
 use crate::errors::{
     ApplicationError, CannotGlobalizeError, CreateObjectError, InvalidDropAccess,
     InvalidGlobalizeAccess, InvalidModuleType, RuntimeError, SystemError, SystemModuleError,
 };
-use crate::errors::{EventError, SystemUpstreamError};
+use crate::errors::{EventError, RateLimitError, SystemUpstreamError};
 use crate::internal_prelude::*;
 use crate::internal_prelude::{IndexEntrySubstate, SortedIndexEntrySubstate};
 use crate::kernel::call_frame::{NodeVisibility, ReferenceOrigin};
@@ -17,17 +18,22 @@ use crate::system::system_callback::{
     FieldLockData, KeyValueEntryLockData, SystemConfig, SystemLockData,
 };
 use crate::system::system_callback_api::SystemCallbackObject;
+use crate::system::system_modules::auth::{Authorization, AuthorizationCheckResult};
+use crate::system::system_modules::debugger::DebuggerModule;
 use crate::system::system_modules::execution_trace::{BucketSnapshot, ProofSnapshot};
-use crate::system::system_modules::transaction_runtime::Event;
+use crate::system::system_modules::transaction_runtime::{Event, PendingEventNotification};
 use crate::system::system_modules::{EnabledModules, SystemModuleMixer};
-use crate::system::system_substates::{KeyValueEntrySubstate, LockStatus};
+use crate::system::system_substates::{
+    EventSubscriptionsSubstate, KeyValueEntrySubstate, LockStatus, RateLimitKey, RateLimitSubstate,
+    EVENT_SUBSCRIBERS_FEATURE,
+};
 use crate::system::system_type_checker::{
     BlueprintTypeTarget, KVStoreTypeTarget, SchemaValidationMeta, SystemMapper,
 };
 use crate::system::type_info::{TypeInfoBlueprint, TypeInfoSubstate};
 use crate::track::interface::NodeSubstates;
 use crate::types::*;
-use radix_engine_interface::api::actor_api::EventFlags;
+use radix_engine_interface::api::actor_api::{EventFlags, EventNotification, RateLimitScope};
 use radix_engine_interface::api::actor_index_api::ClientActorIndexApi;
 use radix_engine_interface::api::field_api::{FieldHandle, LockFlags};
 use radix_engine_interface::api::key_value_entry_api::{
@@ -38,6 +44,9 @@ use radix_engine_interface::api::key_value_store_api::{
 };
 use radix_engine_interface::api::object_api::ModuleId;
 use radix_engine_interface::api::*;
+use radix_engine_interface::blueprints::consensus_manager::{
+    ConsensusManagerGetCurrentEpochInput, CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT,
+};
 use radix_engine_interface::blueprints::package::*;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::schema::{Condition, KeyValueStoreGenericSubstitutions};
@@ -76,6 +85,7 @@ enum ActorObjectRef {
     SELF,
     Outer,
     Global,
+    Caller,
     AuthZone,
 }
 
@@ -86,6 +96,7 @@ impl TryFrom<ActorRefHandle> for ActorObjectRef {
             ACTOR_REF_SELF => Ok(ActorObjectRef::SELF),
             ACTOR_REF_OUTER => Ok(ActorObjectRef::Outer),
             ACTOR_REF_GLOBAL => Ok(ActorObjectRef::Global),
+            ACTOR_REF_CALLER => Ok(ActorObjectRef::Caller),
             ACTOR_REF_AUTH_ZONE => Ok(ActorObjectRef::AuthZone),
             _ => Err(RuntimeError::SystemError(
                 SystemError::InvalidActorRefHandle,
@@ -387,7 +398,7 @@ where
             btreemap!(
                 TYPE_INFO_FIELD_PARTITION => type_info_partition(
                     TypeInfoSubstate::GlobalAddressPhantom(GlobalAddressPhantom {
-                        blueprint_id,
+                        blueprint_id: blueprint_id.clone(),
                     })
                 )
             ),
@@ -408,6 +419,11 @@ where
 
         self.api.kernel_pin_node(global_address_reservation)?;
 
+        self.api
+            .kernel_get_system()
+            .modules
+            .add_address_reservation(global_address_reservation, global_address, blueprint_id);
+
         Ok(GlobalAddressReservation(Own(global_address_reservation)))
     }
 
@@ -574,6 +590,8 @@ where
             &event_data,
         )?;
 
+        let is_current_actor = matches!(actor, EmitterActor::CurrentActor);
+
         // Construct the event type identifier based on the current actor
         let event_type_identifier = match actor {
             EmitterActor::AsObject(node_id, module_id, ..) => Ok(EventTypeIdentifier(
@@ -599,6 +617,23 @@ where
             },
         }?;
 
+        // Queue notifications for the subscribers of the event, which are delivered once the
+        // current method returns. Only objects flagged by a subscription are looked up, so the
+        // events of all other objects cost nothing extra.
+        let has_subscribers = validation_target
+            .blueprint_info
+            .features
+            .contains(EVENT_SUBSCRIBERS_FEATURE);
+        if is_current_actor && has_subscribers {
+            if let EventTypeIdentifier(Emitter::Method(node_id, ModuleId::Main), event_name) =
+                &event_type_identifier
+            {
+                if node_id.is_global() {
+                    self.queue_event_notifications(node_id, event_name, &event_data)?;
+                }
+            }
+        }
+
         let event = Event {
             type_identifier: event_type_identifier,
             payload: event_data,
@@ -614,6 +649,226 @@ where
         Ok(())
     }
 
+    /// Queues a notification for each subscriber of an event emitted by a global object. Objects
+    /// are only flagged by subscribing to their events, which Scrypto can do from the Cuttlefish
+    /// Scrypto VM minor version on, so ledgers which predate it never look up subscriptions.
+    fn queue_event_notifications(
+        &mut self,
+        emitter: &NodeId,
+        event_name: &str,
+        event_data: &[u8],
+    ) -> Result<(), RuntimeError> {
+        if !self
+            .api
+            .kernel_get_system()
+            .modules
+            .enabled_modules
+            .contains(EnabledModules::TRANSACTION_RUNTIME)
+        {
+            return Ok(());
+        }
+
+        let handle = self.open_event_subscriptions(emitter, event_name, LockFlags::read_only())?;
+        let subscriptions: EventSubscriptionsSubstate =
+            self.api.kernel_read_substate(handle)?.as_typed().unwrap();
+        self.api.kernel_close_substate(handle)?;
+
+        let depth = self.api.kernel_get_current_depth();
+        for (subscriber, hook_method) in subscriptions.subscribers {
+            self.api
+                .kernel_get_system()
+                .modules
+                .add_event_notification(PendingEventNotification {
+                    depth,
+                    subscriber,
+                    hook_method,
+                    notification: EventNotification {
+                        emitter: GlobalAddress::new_or_panic(emitter.0),
+                        event_name: event_name.to_string(),
+                        event_data: event_data.to_vec(),
+                    },
+                });
+        }
+
+        Ok(())
+    }
+
+    /// Calls the hook method of each subscriber notified by the current call frame. This is
+    /// done from the emitter's frame, after its method has returned. The hooks are charged to
+    /// the transaction's fee reserve like any other call, so delivery is paid by the
+    /// transaction's fee payer, for at most `MAX_EVENT_SUBSCRIBERS` hooks per event.
+    pub fn deliver_event_notifications(&mut self) -> Result<(), RuntimeError> {
+        let depth = self.api.kernel_get_current_depth();
+        let notifications = self
+            .api
+            .kernel_get_system()
+            .modules
+            .take_event_notifications(depth);
+
+        for PendingEventNotification {
+            subscriber,
+            hook_method,
+            notification,
+            ..
+        } in notifications
+        {
+            self.call_method(
+                subscriber.as_node_id(),
+                hook_method.as_str(),
+                scrypto_args!(notification),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks the output of a function against the post-conditions declared in its schema.
+    pub fn check_function_post_conditions(
+        &mut self,
+        ident: &str,
+        post_conditions: &[FunctionPostCondition],
+        input: &IndexedScryptoValue,
+        output: &IndexedScryptoValue,
+    ) -> Result<(), RuntimeError> {
+        let Some(first) = post_conditions.first() else {
+            return Ok(());
+        };
+        let not_met = |post_condition: &FunctionPostCondition| {
+            RuntimeError::SystemUpstreamError(SystemUpstreamError::PostConditionNotMet(
+                ident.to_string(),
+                post_condition.clone(),
+            ))
+        };
+
+        let bucket: Own = output.as_typed().map_err(|_| not_met(first))?;
+        let info = self.get_object_info(bucket.as_node_id())?;
+        let is_bucket = info.blueprint_info.blueprint_id.eq(&BlueprintId::new(
+            &RESOURCE_PACKAGE,
+            FUNGIBLE_BUCKET_BLUEPRINT,
+        )) || info.blueprint_info.blueprint_id.eq(&BlueprintId::new(
+            &RESOURCE_PACKAGE,
+            NON_FUNGIBLE_BUCKET_BLUEPRINT,
+        ));
+        let resource_address = match info.try_get_outer_object() {
+            Some(outer_object) if is_bucket => outer_object,
+            _ => return Err(not_met(first)),
+        };
+
+        for post_condition in post_conditions {
+            let is_met = match post_condition {
+                FunctionPostCondition::ReturnsResource(expected) => {
+                    resource_address.as_node_id().eq(expected.as_node_id())
+                }
+                FunctionPostCondition::ReturnsResourceOfArgument(index) => input
+                    .as_typed_at_path::<ResourceAddress>(&[*index as usize])
+                    .map_or(false, |expected| {
+                        resource_address.as_node_id().eq(expected.as_node_id())
+                    }),
+                FunctionPostCondition::ReturnsAtLeastAmountOfArgument(index) => {
+                    match input.as_typed_at_path::<Decimal>(&[*index as usize]) {
+                        Ok(expected) => {
+                            let rtn = self.call_method(
+                                bucket.as_node_id(),
+                                BUCKET_GET_AMOUNT_IDENT,
+                                scrypto_encode(&BucketGetAmountInput {}).unwrap(),
+                            )?;
+                            let amount: Decimal = scrypto_decode(&rtn).unwrap();
+                            amount >= expected
+                        }
+                        Err(_) => false,
+                    }
+                }
+            };
+            if !is_met {
+                return Err(not_met(post_condition));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn open_event_subscriptions(
+        &mut self,
+        emitter: &NodeId,
+        event_name: &str,
+        flags: LockFlags,
+    ) -> Result<SubstateHandle, RuntimeError> {
+        self.api.kernel_open_substate_with_default(
+            emitter,
+            EVENT_SUBSCRIPTIONS_PARTITION,
+            &SubstateKey::Map(scrypto_encode(event_name).unwrap()),
+            flags,
+            Some(|| IndexedScryptoValue::from_typed(&EventSubscriptionsSubstate::default())),
+            SystemLockData::default(),
+        )
+    }
+
+    /// Flags an object as having event subscribers, so that its events are looked up for them.
+    /// The flag is kept in the object's features, which its actor already holds, and takes
+    /// effect from the next call to the object.
+    fn flag_event_subscribers(&mut self, emitter: &NodeId) -> Result<(), RuntimeError> {
+        let handle = self.api.kernel_open_substate(
+            emitter,
+            TYPE_INFO_FIELD_PARTITION,
+            &TypeInfoField::TypeInfo.into(),
+            LockFlags::MUTABLE,
+            SystemLockData::default(),
+        )?;
+        let mut type_info: TypeInfoSubstate =
+            self.api.kernel_read_substate(handle)?.as_typed().unwrap();
+        if let TypeInfoSubstate::Object(object_info) = &mut type_info {
+            if object_info
+                .blueprint_info
+                .features
+                .insert(EVENT_SUBSCRIBERS_FEATURE.to_string())
+            {
+                self.api
+                    .kernel_write_substate(handle, IndexedScryptoValue::from_typed(&type_info))?;
+            }
+        }
+        self.api.kernel_close_substate(handle)?;
+
+        Ok(())
+    }
+
+    /// Returns the address of the current actor if it may subscribe to events, together with
+    /// its auth zone
+    fn get_event_subscriber(&mut self) -> Result<(GlobalAddress, NodeId), RuntimeError> {
+        match self.current_actor() {
+            Actor::Method(MethodActor {
+                method_type: MethodType::Main,
+                node_id,
+                auth_zone,
+                ..
+            }) if node_id.is_global() => Ok((GlobalAddress::new_or_panic(node_id.0), auth_zone)),
+            _ => Err(RuntimeError::SystemModuleError(
+                SystemModuleError::EventError(Box::new(EventError::InvalidSubscriber)),
+            )),
+        }
+    }
+
+    /// Returns the global caller of the current call frame, as recorded in its auth zone
+    fn get_global_caller(
+        &mut self,
+        auth_zone: &NodeId,
+    ) -> Result<Option<GlobalCaller>, RuntimeError> {
+        let handle = self.api.kernel_open_substate(
+            auth_zone,
+            MAIN_BASE_PARTITION,
+            &AuthZoneField::AuthZone.into(),
+            LockFlags::read_only(),
+            SystemLockData::default(),
+        )?;
+        let auth_zone: FieldSubstate<AuthZone> =
+            self.api.kernel_read_substate(handle)?.as_typed().unwrap();
+        self.api.kernel_close_substate(handle)?;
+
+        Ok(auth_zone
+            .into_payload()
+            .global_caller
+            .map(|(global_caller, _)| global_caller))
+    }
+
     /// Internal, handle must be checked or from trusted sources
     fn key_value_entry_remove_and_close_substate(
         &mut self,
@@ -861,6 +1116,59 @@ where
         Ok((node_id, info, partition_num, field_schema.transience))
     }
 
+    /// Prefetches the fields of the object a method is called on, since most methods read most
+    /// of their object's fields. Transient fields and fields behind a feature which isn't
+    /// enabled are skipped.
+    pub fn prefetch_actor_fields(
+        &mut self,
+        definition: &BlueprintDefinition,
+    ) -> Result<(), RuntimeError> {
+        let (node_id, module_id, features) = match self.current_actor() {
+            Actor::Method(MethodActor {
+                node_id,
+                method_type,
+                object_info,
+                ..
+            }) => {
+                let module_id = method_type.module_id();
+                let features = match module_id {
+                    ModuleId::Main => object_info.blueprint_info.features,
+                    _ => index_set_new(),
+                };
+                (node_id, module_id, features)
+            }
+            _ => return Ok(()),
+        };
+
+        let (partition_description, field_schemas) = match &definition.interface.state.fields {
+            Some(fields) => fields,
+            None => return Ok(()),
+        };
+        let partition_num = match partition_description {
+            PartitionDescription::Physical(partition_num) => *partition_num,
+            PartitionDescription::Logical(offset) => module_id
+                .base_partition_num()
+                .at_offset(*offset)
+                .expect("Module number overflow"),
+        };
+        let substate_keys: Vec<SubstateKey> = field_schemas
+            .iter()
+            .enumerate()
+            .filter(|(_, field_schema)| {
+                let exists = match &field_schema.condition {
+                    Condition::Always => true,
+                    Condition::IfFeature(feature) => features.contains(feature),
+                    Condition::IfOuterFeature(..) => false,
+                };
+                exists && matches!(field_schema.transience, FieldTransience::NotTransient)
+            })
+            .map(|(field_index, _)| SubstateKey::Field(field_index as u8))
+            .collect();
+
+        self.api
+            .kernel_prefetch_substates(&node_id, partition_num, &substate_keys)
+    }
+
     /// ASSUMPTIONS:
     /// Assumes the caller has already checked that the entity type on the GlobalAddress is valid
     /// against the given self module.
@@ -889,6 +1197,10 @@ where
                 }
             }
         };
+        self.api
+            .kernel_get_system()
+            .modules
+            .consume_address_reservation(global_address_reservation.0.as_node_id(), node_id);
 
         // Check blueprint id
         let reserved_blueprint_id = {
@@ -998,7 +1310,8 @@ where
                 AttachedModuleId::RoleAssignment
                 | AttachedModuleId::Metadata
                 | AttachedModuleId::Royalty => {
-                    let blueprint_id = self.get_object_info(node_id)?.blueprint_info.blueprint_id;
+                    let module_blueprint_info = self.get_object_info(node_id)?.blueprint_info;
+                    let blueprint_id = module_blueprint_info.blueprint_id;
                     let expected_blueprint = module_id.static_blueprint();
                     if !blueprint_id.eq(&expected_blueprint) {
                         return Err(RuntimeError::SystemError(SystemError::InvalidModuleType(
@@ -1009,6 +1322,16 @@ where
                         )));
                     }
 
+                    // The role assignment module records whether the object opted in to pausing
+                    if module_id.eq(&AttachedModuleId::RoleAssignment)
+                        && module_blueprint_info.features.contains(PAUSABLE_FEATURE)
+                    {
+                        object_info
+                            .blueprint_info
+                            .features
+                            .insert(PAUSABLE_FEATURE.to_string());
+                    }
+
                     self.api
                         .kernel_get_system_state()
                         .system
@@ -1073,6 +1396,51 @@ where
         Ok(global_address)
     }
 
+    #[cfg_attr(feature = "std", catch_unwind_ignore)]
+    fn lock_fee_internal(
+        &mut self,
+        locked_fee: LiquidFungibleResource,
+        contingent: bool,
+        refund_vault_id: Option<NodeId>,
+    ) {
+        // Credit cost units
+        let vault_id = self
+            .current_actor()
+            .node_id()
+            .expect("Caller should only be fungible vault method");
+        self.api.kernel_get_system().modules.lock_fee(
+            vault_id,
+            locked_fee.clone(),
+            contingent,
+            refund_vault_id,
+        );
+
+        // Emit Locked Fee event
+        {
+            let type_identifier = EventTypeIdentifier(
+                Emitter::Method(vault_id, ObjectModuleId::Main),
+                LockFeeEvent::EVENT_NAME.to_string(),
+            );
+
+            let lock_fee_event = LockFeeEvent {
+                amount: locked_fee.amount(),
+            };
+            let payload = scrypto_encode(&lock_fee_event).unwrap();
+
+            let event = Event {
+                type_identifier,
+                payload,
+                flags: EventFlags::FORCE_WRITE,
+            };
+
+            self.api
+                .kernel_get_system()
+                .modules
+                .add_event_unchecked(event)
+                .expect("Event should never exceed size.");
+        }
+    }
+
     #[cfg_attr(feature = "std", catch_unwind_ignore)]
     pub fn current_actor(&mut self) -> Actor {
         self.api
@@ -1128,10 +1496,10 @@ where
             }
         }
 
-        self.api.kernel_read_substate(handle).map(|v| {
-            let wrapper: FieldSubstate<ScryptoValue> = v.as_typed().unwrap();
-            scrypto_encode(&wrapper.into_payload()).unwrap()
-        })
+        // The payload is the first field of `FieldSubstate::V1`
+        self.api
+            .kernel_read_substate(handle)
+            .map(|v| v.to_payload_at_path(&[0, 0]).unwrap())
     }
 
     // Costing through kernel
@@ -1577,10 +1945,7 @@ where
             if let Some(user_substates) = dropped_node.substates.remove(&MAIN_BASE_PARTITION) {
                 user_substates
                     .into_iter()
-                    .map(|(_key, v)| {
-                        let substate: FieldSubstate<ScryptoValue> = v.as_typed().unwrap();
-                        scrypto_encode(&substate.into_payload()).unwrap()
-                    })
+                    .map(|(_key, v)| v.to_payload_at_path(&[0, 0]).unwrap())
                     .collect()
             } else {
                 vec![]
@@ -1612,10 +1977,10 @@ where
             ));
         }
 
-        self.api.kernel_read_substate(handle).map(|v| {
-            let wrapper: KeyValueEntrySubstate<ScryptoValue> = v.as_typed().unwrap();
-            scrypto_encode(&wrapper.into_value()).unwrap()
-        })
+        // The optional value is the first field of `KeyValueEntrySubstate::V1`
+        self.api
+            .kernel_read_substate(handle)
+            .map(|v| v.to_payload_at_path(&[0, 0]).unwrap())
     }
 
     // Costing through kernel
@@ -1859,10 +2224,10 @@ where
         )?;
 
         if flags.contains(LockFlags::MUTABLE) {
-            let lock_status = self.api.kernel_read_substate(handle).map(|v| {
-                let kv_entry: KeyValueEntrySubstate<ScryptoValue> = v.as_typed().unwrap();
-                kv_entry.lock_status()
-            })?;
+            let lock_status = self
+                .api
+                .kernel_read_substate(handle)
+                .map(|v| v.as_typed_at_path::<LockStatus>(&[0, 1]).unwrap())?;
 
             if let LockStatus::Locked = lock_status {
                 return Err(RuntimeError::SystemError(SystemError::KeyValueEntryLocked));
@@ -2183,6 +2548,22 @@ where
     ) -> Result<(Rc<VersionedScryptoSchema>, ScopedTypeId), RuntimeError> {
         self.get_blueprint_type_schema(blueprint_type_id)
     }
+
+    fn get_blueprint_interface(
+        &mut self,
+        blueprint_id: &BlueprintId,
+    ) -> Result<BlueprintInterface, RuntimeError> {
+        let definition = self.get_blueprint_default_definition(blueprint_id.clone())?;
+        Ok(definition.interface.clone())
+    }
+
+    fn get_blueprint_event_schema(
+        &mut self,
+        blueprint_id: &BlueprintId,
+        event_name: &str,
+    ) -> Result<(Rc<VersionedScryptoSchema>, ScopedTypeId), RuntimeError> {
+        self.get_event_schema(blueprint_id, event_name)
+    }
 }
 
 #[cfg_attr(
@@ -2204,32 +2585,48 @@ where
             return Ok(());
         }
 
+        let execution_costing_entry = match costing_entry {
+            ClientCostingEntry::RunNativeCode {
+                package_address,
+                export_name,
+                input_size,
+            } => ExecutionCostingEntry::RunNativeCode {
+                package_address,
+                export_name,
+                input_size,
+            },
+            ClientCostingEntry::RunWasmCode {
+                package_address,
+                export_name,
+                wasm_execution_units,
+            } => ExecutionCostingEntry::RunWasmCode {
+                package_address,
+                export_name,
+                wasm_execution_units,
+            },
+            ClientCostingEntry::PrepareWasmCode { size } => {
+                ExecutionCostingEntry::PrepareWasmCode { size }
+            }
+            ClientCostingEntry::GrowWasmMemory {
+                total_pages,
+                grown_pages,
+            } => {
+                let depth = self.api.kernel_get_current_depth();
+                self.api
+                    .kernel_get_system()
+                    .modules
+                    .check_wasm_memory(depth, total_pages)?;
+                ExecutionCostingEntry::GrowWasmMemory { pages: grown_pages }
+            }
+            ClientCostingEntry::ProcessKeyValueEntryBatch { size } => {
+                ExecutionCostingEntry::ProcessKeyValueEntryBatch { size }
+            }
+        };
+
         self.api
             .kernel_get_system()
             .modules
-            .apply_execution_cost(match costing_entry {
-                ClientCostingEntry::RunNativeCode {
-                    package_address,
-                    export_name,
-                    input_size,
-                } => ExecutionCostingEntry::RunNativeCode {
-                    package_address,
-                    export_name,
-                    input_size,
-                },
-                ClientCostingEntry::RunWasmCode {
-                    package_address,
-                    export_name,
-                    wasm_execution_units,
-                } => ExecutionCostingEntry::RunWasmCode {
-                    package_address,
-                    export_name,
-                    wasm_execution_units,
-                },
-                ClientCostingEntry::PrepareWasmCode { size } => {
-                    ExecutionCostingEntry::PrepareWasmCode { size }
-                }
-            })
+            .apply_execution_cost(execution_costing_entry)
     }
 
     #[trace_resources]
@@ -2279,40 +2676,18 @@ where
     #[trace_resources]
     #[cfg_attr(feature = "std", catch_unwind_ignore)]
     fn lock_fee(&mut self, locked_fee: LiquidFungibleResource, contingent: bool) {
-        // Credit cost units
-        let vault_id = self
-            .current_actor()
-            .node_id()
-            .expect("Caller should only be fungible vault method");
-        self.api
-            .kernel_get_system()
-            .modules
-            .lock_fee(vault_id, locked_fee.clone(), contingent);
-
-        // Emit Locked Fee event
-        {
-            let type_identifier = EventTypeIdentifier(
-                Emitter::Method(vault_id, ObjectModuleId::Main),
-                LockFeeEvent::EVENT_NAME.to_string(),
-            );
-
-            let lock_fee_event = LockFeeEvent {
-                amount: locked_fee.amount(),
-            };
-            let payload = scrypto_encode(&lock_fee_event).unwrap();
-
-            let event = Event {
-                type_identifier,
-                payload,
-                flags: EventFlags::FORCE_WRITE,
-            };
+        self.lock_fee_internal(locked_fee, contingent, None);
+    }
 
-            self.api
-                .kernel_get_system()
-                .modules
-                .add_event_unchecked(event)
-                .expect("Event should never exceed size.");
-        }
+    #[trace_resources]
+    #[cfg_attr(feature = "std", catch_unwind_ignore)]
+    fn lock_fee_with_refund(
+        &mut self,
+        locked_fee: LiquidFungibleResource,
+        contingent: bool,
+        refund_vault_id: NodeId,
+    ) {
+        self.lock_fee_internal(locked_fee, contingent, Some(refund_vault_id));
     }
 
     fn execution_cost_unit_limit(&mut self) -> Result<u32, RuntimeError> {
@@ -2345,6 +2720,21 @@ where
         }
     }
 
+    fn execution_cost_units_consumed(&mut self) -> Result<u32, RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::QueryFeeReserve)?;
+
+        if let Some(fee_reserve) = self.api.kernel_get_system().modules.fee_reserve() {
+            Ok(fee_reserve.execution_cost_units_consumed())
+        } else {
+            Err(RuntimeError::SystemError(
+                SystemError::CostingModuleNotEnabled,
+            ))
+        }
+    }
+
     fn finalization_cost_unit_limit(&mut self) -> Result<u32, RuntimeError> {
         self.api
             .kernel_get_system()
@@ -2508,6 +2898,18 @@ where
                     ));
                 }
             }
+            ActorObjectRef::Caller => match self.api.kernel_get_system_state().caller_call_frame {
+                Actor::Method(MethodActor {
+                    node_id,
+                    object_info,
+                    ..
+                }) if object_info.is_global() => *node_id,
+                _ => {
+                    return Err(RuntimeError::SystemError(
+                        SystemError::GlobalAddressDoesNotExist,
+                    ));
+                }
+            },
             ActorObjectRef::AuthZone => self
                 .current_actor()
                 .self_auth_zone()
@@ -2603,10 +3005,10 @@ where
         };
 
         if flags.contains(LockFlags::MUTABLE) {
-            let lock_status = self.api.kernel_read_substate(handle).map(|v| {
-                let field: FieldSubstate<ScryptoValue> = v.as_typed().unwrap();
-                field.into_lock_status()
-            })?;
+            let lock_status = self
+                .api
+                .kernel_read_substate(handle)
+                .map(|v| v.as_typed_at_path::<LockStatus>(&[0, 1]).unwrap())?;
 
             if let LockStatus::Locked = lock_status {
                 return Err(RuntimeError::SystemError(SystemError::FieldLocked(
@@ -2645,6 +3047,221 @@ where
             event_flags,
         )
     }
+
+    #[trace_resources]
+    fn actor_subscribe_to_event(
+        &mut self,
+        emitter: GlobalAddress,
+        event_name: String,
+        hook_method: String,
+    ) -> Result<(), RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::SubscribeToEvent)?;
+
+        let (subscriber, auth_zone) = self.get_event_subscriber()?;
+
+        // The hook must be a method of the subscriber
+        let subscriber_blueprint = self.actor_get_blueprint_id()?;
+        let definition = self.load_blueprint_definition(
+            subscriber_blueprint.package_address,
+            &BlueprintVersionKey::new_default(subscriber_blueprint.blueprint_name.as_str()),
+        )?;
+        match definition.interface.functions.get(&hook_method) {
+            Some(function_schema) if function_schema.receiver.is_some() => {}
+            _ => {
+                return Err(RuntimeError::SystemModuleError(
+                    SystemModuleError::EventError(Box::new(EventError::HookMethodNotFound(
+                        hook_method,
+                    ))),
+                ));
+            }
+        }
+
+        // The event must be defined by the emitter's blueprint
+        let emitter_blueprint = self
+            .get_object_info(emitter.as_node_id())?
+            .blueprint_info
+            .blueprint_id;
+        let definition = self.load_blueprint_definition(
+            emitter_blueprint.package_address,
+            &BlueprintVersionKey::new_default(emitter_blueprint.blueprint_name.as_str()),
+        )?;
+        if !definition.interface.events.contains_key(&event_name) {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::EventError(Box::new(EventError::SchemaNotFoundError {
+                    blueprint: emitter_blueprint,
+                    event_name,
+                })),
+            ));
+        }
+
+        // Subscribers run as part of every emitting call, so the emitter's owner must consent
+        let auth_result = Authorization::check_authorization_against_role_key_internal(
+            &auth_zone,
+            &emitter,
+            &ModuleRoleKey::new(ModuleId::Main, OWNER_ROLE),
+            self,
+        )?;
+        if let AuthorizationCheckResult::Failed(..) = auth_result {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::EventError(Box::new(EventError::SubscriptionNotAuthorized)),
+            ));
+        }
+
+        let handle =
+            self.open_event_subscriptions(emitter.as_node_id(), &event_name, LockFlags::MUTABLE)?;
+        let mut subscriptions: EventSubscriptionsSubstate =
+            self.api.kernel_read_substate(handle)?.as_typed().unwrap();
+        if !subscriptions.subscribers.contains_key(&subscriber)
+            && subscriptions.subscribers.len() >= MAX_EVENT_SUBSCRIBERS
+        {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::EventError(Box::new(EventError::TooManySubscribers {
+                    emitter,
+                    event_name,
+                })),
+            ));
+        }
+        subscriptions.subscribers.insert(subscriber, hook_method);
+        self.api
+            .kernel_write_substate(handle, IndexedScryptoValue::from_typed(&subscriptions))?;
+        self.api.kernel_close_substate(handle)?;
+
+        self.flag_event_subscribers(emitter.as_node_id())?;
+
+        Ok(())
+    }
+
+    #[trace_resources]
+    fn actor_unsubscribe_from_event(
+        &mut self,
+        emitter: GlobalAddress,
+        event_name: String,
+    ) -> Result<(), RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::UnsubscribeFromEvent)?;
+
+        let (subscriber, _) = self.get_event_subscriber()?;
+
+        let handle =
+            self.open_event_subscriptions(emitter.as_node_id(), &event_name, LockFlags::MUTABLE)?;
+        let mut subscriptions: EventSubscriptionsSubstate =
+            self.api.kernel_read_substate(handle)?.as_typed().unwrap();
+        if subscriptions.subscribers.remove(&subscriber).is_none() {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::EventError(Box::new(EventError::SubscriptionNotFound {
+                    emitter,
+                    event_name,
+                })),
+            ));
+        }
+        self.api
+            .kernel_write_substate(handle, IndexedScryptoValue::from_typed(&subscriptions))?;
+        self.api.kernel_close_substate(handle)?;
+
+        Ok(())
+    }
+
+    #[trace_resources]
+    fn actor_get_state_size(&mut self) -> Result<NodeStateSize, RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::QueryActor)?;
+
+        let node_id = self
+            .current_actor()
+            .node_id()
+            .ok_or(RuntimeError::SystemError(
+                SystemError::ActorNodeIdDoesNotExist,
+            ))?;
+
+        // Nodes which have not been committed yet have no accounting
+        let handle = self.api.kernel_open_substate_with_default(
+            &node_id,
+            STATE_SIZE_PARTITION,
+            &StateSizeField::StateSize.into(),
+            LockFlags::read_only(),
+            Some(|| IndexedScryptoValue::from_typed(&NodeStateSize::default())),
+            SystemLockData::default(),
+        )?;
+        let state_size: NodeStateSize = self.api.kernel_read_substate(handle)?.as_typed().unwrap();
+        self.api.kernel_close_substate(handle)?;
+
+        Ok(state_size)
+    }
+
+    #[trace_resources]
+    fn actor_rate_limit_check(
+        &mut self,
+        name: String,
+        scope: RateLimitScope,
+        max: u32,
+        window_epochs: u64,
+    ) -> Result<bool, RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::RateLimitCheck)?;
+
+        let (node_id, auth_zone) = match self.current_actor() {
+            Actor::Method(MethodActor {
+                method_type: MethodType::Main,
+                node_id,
+                auth_zone,
+                ..
+            }) if node_id.is_global() => (node_id, auth_zone),
+            _ => {
+                return Err(RuntimeError::SystemError(SystemError::RateLimitError(
+                    RateLimitError::InvalidActor,
+                )))
+            }
+        };
+        if window_epochs == 0 {
+            return Err(RuntimeError::SystemError(SystemError::RateLimitError(
+                RateLimitError::ZeroWindow,
+            )));
+        }
+
+        let caller = match scope {
+            RateLimitScope::Global => None,
+            RateLimitScope::PerCaller => Some(self.get_global_caller(&auth_zone)?.ok_or(
+                RuntimeError::SystemError(SystemError::RateLimitError(
+                    RateLimitError::NoGlobalCaller,
+                )),
+            )?),
+        };
+
+        let current_epoch: Epoch = scrypto_decode(&self.call_method(
+            CONSENSUS_MANAGER.as_node_id(),
+            CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT,
+            scrypto_encode(&ConsensusManagerGetCurrentEpochInput).unwrap(),
+        )?)
+        .unwrap();
+
+        let handle = self.api.kernel_open_substate_with_default(
+            &node_id,
+            RATE_LIMITS_PARTITION,
+            &SubstateKey::Map(scrypto_encode(&RateLimitKey { name, caller }).unwrap()),
+            LockFlags::MUTABLE,
+            Some(|| IndexedScryptoValue::from_typed(&RateLimitSubstate::default())),
+            SystemLockData::default(),
+        )?;
+        let mut rate_limit: RateLimitSubstate =
+            self.api.kernel_read_substate(handle)?.as_typed().unwrap();
+        let allowed = rate_limit.try_count_call(current_epoch, max, window_epochs);
+        if allowed {
+            self.api
+                .kernel_write_substate(handle, IndexedScryptoValue::from_typed(&rate_limit))?;
+        }
+        self.api.kernel_close_substate(handle)?;
+
+        Ok(allowed)
+    }
 }
 
 #[cfg_attr(
@@ -2756,7 +3373,7 @@ where
             .kernel_get_system()
             .modules
             .update_instruction_index(new_index);
-        Ok(())
+        DebuggerModule::on_instruction::<Y, V>(self.api, new_index)
     }
 }
 
@@ -2785,6 +3402,27 @@ where
         }
     }
 
+    #[trace_resources]
+    fn get_transaction_signatories(&mut self) -> Result<Vec<NonFungibleGlobalId>, RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::QueryTransactionSignatories)?;
+
+        let signatories = self
+            .api
+            .kernel_get_system()
+            .modules
+            .auth
+            .params
+            .signatories
+            .iter()
+            .cloned()
+            .collect();
+
+        Ok(signatories)
+    }
+
     #[trace_resources]
     fn generate_ruid(&mut self) -> Result<[u8; 32], RuntimeError> {
         self.api
@@ -2817,16 +3455,18 @@ where
 
     #[trace_resources]
     fn emit_log(&mut self, level: Level, message: String) -> Result<(), RuntimeError> {
+        self.emit_log_record(LogRecord::new(level, message))
+    }
+
+    #[trace_resources]
+    fn emit_log_record(&mut self, record: LogRecord) -> Result<(), RuntimeError> {
         self.api.kernel_get_system().modules.apply_execution_cost(
             ExecutionCostingEntry::EmitLog {
-                size: message.len(),
+                size: record.size(),
             },
         )?;
 
-        self.api
-            .kernel_get_system()
-            .modules
-            .add_log(level, message)?;
+        self.api.kernel_get_system().modules.add_log(record)?;
 
         Ok(())
     }
@@ -2847,6 +3487,22 @@ where
             ApplicationError::PanicMessage(message),
         ))
     }
+
+    fn abort(&mut self, error: ScryptoValue) -> Result<(), RuntimeError> {
+        let size = scrypto_encode(&error)
+            .expect("Decoded value can be encoded")
+            .len();
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::Panic { size })?;
+
+        self.api.kernel_get_system().modules.set_abort_error(size)?;
+
+        Err(RuntimeError::ApplicationError(ApplicationError::Aborted(
+            error,
+        )))
+    }
 }
 
 #[cfg_attr(
@@ -3009,6 +3665,16 @@ where
             .kernel_mark_substate_as_transient(node_id, partition_num, key)
     }
 
+    fn kernel_prefetch_substates(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        substate_keys: &[SubstateKey],
+    ) -> Result<(), RuntimeError> {
+        self.api
+            .kernel_prefetch_substates(node_id, partition_num, substate_keys)
+    }
+
     fn kernel_open_substate_with_default<F: FnOnce() -> IndexedScryptoValue>(
         &mut self,
         node_id: &NodeId,
@@ -3130,4 +3796,12 @@ where
     fn kernel_read_proof(&mut self, proof_id: &NodeId) -> Option<ProofSnapshot> {
         self.api.kernel_read_proof(proof_id)
     }
+
+    fn kernel_read_worktop(&mut self, worktop_id: &NodeId) -> Option<Vec<BucketSnapshot>> {
+        self.api.kernel_read_worktop(worktop_id)
+    }
+
+    fn kernel_read_auth_zone(&mut self, auth_zone_id: &NodeId) -> Option<Vec<ProofSnapshot>> {
+        self.api.kernel_read_auth_zone(auth_zone_id)
+    }
 }
@@ -118,6 +118,7 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
         pre_allocated_addresses: &Vec<PreAllocatedAddress>,
         references: &IndexSet<Reference>,
         blobs: &IndexMap<Hash, Vec<u8>>,
+        worktop_leftovers_recipient: Option<&ComponentAddress>,
     ) -> Result<Vec<u8>, RuntimeError>
     where
         Y: KernelApi<Self>,
@@ -146,6 +147,7 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
                 global_address_reservations,
                 references,
                 blobs,
+                worktop_leftovers_recipient: worktop_leftovers_recipient.cloned(),
             })
             .unwrap(),
         )?;
@@ -387,6 +389,8 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
                     }
                 }
 
+                system.prefetch_actor_fields(&definition)?;
+
                 // Execute
                 let export = definition
                     .function_exports
@@ -396,6 +400,12 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
                 let output =
                     { C::invoke(&blueprint_id.package_address, export, input, &mut system)? };
 
+                // Check output size
+                system
+                    .kernel_get_system()
+                    .modules
+                    .check_invoke_output_size(actor, output.len())?;
+
                 // Validate output
                 system.validate_blueprint_payload(
                     &target,
@@ -403,6 +413,17 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
                     output.as_vec_ref(),
                 )?;
 
+                // Check post-conditions
+                system.check_function_post_conditions(
+                    ident,
+                    &function_schema.post_conditions,
+                    input,
+                    &output,
+                )?;
+
+                // Notify subscribers of the events emitted by this call
+                system.deliver_event_notifications()?;
+
                 Ok(output)
             }
             Actor::BlueprintHook(BlueprintHookActor {
@@ -431,6 +452,12 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
                     &mut system,
                 )?;
 
+                // Check output size
+                system
+                    .kernel_get_system()
+                    .modules
+                    .check_invoke_output_size(&actor, output.len())?;
+
                 // Check output against well-known schema
                 match hook {
                     BlueprintHook::OnVirtualize => {
@@ -457,6 +484,8 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
     where
         Y: KernelApi<Self>,
     {
+        let mut unused_address_reservations = Vec::new();
+
         // Round 1 - drop all proofs
         for node_id in nodes {
             let type_info = TypeInfoBlueprint::get_type(&node_id, api)?;
@@ -499,10 +528,25 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
                         }
                     }
                 }
+                TypeInfoSubstate::GlobalAddressReservation(global_address) => {
+                    unused_address_reservations.push(global_address);
+                }
                 _ => {}
             }
         }
 
+        // Reservations can't be dropped, so they would otherwise be reported as orphaned nodes
+        if !unused_address_reservations.is_empty()
+            && api
+                .kernel_get_system()
+                .modules
+                .fail_on_unused_address_reservations()
+        {
+            return Err(RuntimeError::SystemError(
+                SystemError::UnusedAddressReservations(unused_address_reservations),
+            ));
+        }
+
         Ok(())
     }
 
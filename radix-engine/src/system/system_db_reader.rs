@@ -55,6 +55,9 @@ pub enum ObjectPartitionDescriptor {
 #[derive(Clone, Debug)]
 pub enum SystemPartitionDescriptor {
     BootLoader,
+    EventSubscriptions,
+    StateSize,
+    RateLimits,
     TypeInfo,
     Schema,
     KeyValueStore,
@@ -953,6 +956,18 @@ impl<'a, S: SubstateDatabase> SystemDatabaseReader<'a, S> {
             descriptors.push(SystemPartitionDescriptor::BootLoader);
         }
 
+        if partition_num.eq(&EVENT_SUBSCRIPTIONS_PARTITION) {
+            descriptors.push(SystemPartitionDescriptor::EventSubscriptions);
+        }
+
+        if partition_num.eq(&STATE_SIZE_PARTITION) {
+            descriptors.push(SystemPartitionDescriptor::StateSize);
+        }
+
+        if partition_num.eq(&RATE_LIMITS_PARTITION) {
+            descriptors.push(SystemPartitionDescriptor::RateLimits);
+        }
+
         if partition_num.eq(&TYPE_INFO_FIELD_PARTITION) {
             descriptors.push(SystemPartitionDescriptor::TypeInfo);
         }
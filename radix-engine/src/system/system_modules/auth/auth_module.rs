@@ -14,12 +14,17 @@ use crate::system::system_callback::{SystemConfig, SystemLockData};
 use crate::system::system_callback_api::SystemCallbackObject;
 use crate::system::type_info::TypeInfoSubstate;
 use crate::types::*;
-use radix_engine_interface::api::{AttachedModuleId, ClientBlueprintApi, LockFlags, ModuleId};
+use radix_engine_interface::api::{
+    AttachedModuleId, ClientBlueprintApi, ClientObjectApi, LockFlags, ModuleId,
+};
+use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::package::{
     BlueprintVersion, BlueprintVersionKey, MethodAuthTemplate, RoleSpecification,
 };
 use radix_engine_interface::blueprints::resource::*;
-use radix_engine_interface::blueprints::transaction_processor::TRANSACTION_PROCESSOR_BLUEPRINT;
+use radix_engine_interface::blueprints::transaction_processor::{
+    SUBINTENT_PROCESSOR_BLUEPRINT, TRANSACTION_PROCESSOR_BLUEPRINT,
+};
 use radix_engine_interface::types::*;
 use transaction::model::AuthZoneParams;
 
@@ -31,6 +36,8 @@ pub enum AuthError {
     Unauthorized(Box<Unauthorized>),
     InnerBlueprintDoesNotExist(String),
     InvalidOuterObjectMapping,
+    ObjectPaused(GlobalAddress),
+    AuthorizerRejected(FnIdentifier),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -43,21 +50,39 @@ pub enum FailedAccessRules {
 pub struct Unauthorized {
     pub failed_access_rules: FailedAccessRules,
     pub fn_identifier: FnIdentifier,
+    /// The proof rules which the auth zone could not satisfy, in evaluation order
+    pub unsatisfied_proof_rules: Vec<ProofRule>,
 }
 
 #[derive(Debug, Clone)]
 pub struct AuthModule {
     pub params: AuthZoneParams,
+    /// Bumped whenever a method is called on an auth zone, as that may change the proofs seen by
+    /// any later access rule check
+    auth_zone_generation: u64,
+    /// The number of subintents which have been given the proofs of their signers
+    executed_subintent_count: usize,
+    /// Results of access rule checks, keyed by the hash of the rule, the hash of the auth zone
+    /// the rule was checked against and the auth zone generation at the time of the check
+    access_rule_cache: NonIterMap<(Hash, Hash, u64), AuthorizationCheckResult>,
+    pub access_rule_cache_stats: AccessRuleCacheStats,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct AccessRuleCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+#[derive(Debug, Clone)]
 pub enum AuthorizationCheckResult {
     Authorized,
-    Failed(Vec<AccessRule>),
+    Failed(Vec<AccessRule>, Vec<ProofRule>),
 }
 
 pub enum AuthorityListAuthorizationResult {
     Authorized,
-    Failed(Vec<(RoleKey, Vec<AccessRule>)>),
+    Failed(Vec<(RoleKey, Vec<AccessRule>)>, Vec<ProofRule>),
 }
 
 pub enum ResolvedPermission {
@@ -71,6 +96,16 @@ pub enum ResolvedPermission {
 }
 
 impl AuthModule {
+    pub fn new(params: AuthZoneParams) -> Self {
+        Self {
+            params,
+            auth_zone_generation: 0,
+            executed_subintent_count: 0,
+            access_rule_cache: NonIterMap::new(),
+            access_rule_cache_stats: AccessRuleCacheStats::default(),
+        }
+    }
+
     pub fn on_call_function<V, Y>(
         api: &mut SystemService<Y, V>,
         blueprint_id: &BlueprintId,
@@ -81,7 +116,7 @@ impl AuthModule {
         Y: KernelApi<SystemConfig<V>>,
     {
         // Create AuthZone
-        let auth_zone = {
+        let (auth_zone, auth_zone_hash) = {
             // TODO: Remove special casing use of transaction processor and just have virtual resources
             // stored in root call frame
             let is_transaction_processor_blueprint = blueprint_id
@@ -91,18 +126,44 @@ impl AuthModule {
                     .blueprint_name
                     .eq(TRANSACTION_PROCESSOR_BLUEPRINT);
             let is_at_root = api.kernel_get_current_depth() == 0;
-            let (virtual_resources, virtual_non_fungibles) =
+            // Subintents are run by the root transaction processor in the order in which they
+            // were validated, which is the only caller allowed by the subintent processor.
+            let is_subintent_processor_blueprint = blueprint_id
+                .package_address
+                .eq(&TRANSACTION_PROCESSOR_PACKAGE)
+                && blueprint_id
+                    .blueprint_name
+                    .eq(SUBINTENT_PROCESSOR_BLUEPRINT);
+            let is_called_by_root_processor = api.kernel_get_current_depth() == 1;
+            let (virtual_resources, virtual_non_fungibles, virtual_resource_amounts) =
                 if is_transaction_processor_blueprint && is_at_root {
                     let auth_module = &api.kernel_get_system().modules.auth;
                     (
                         auth_module.params.virtual_resources.clone(),
                         auth_module.params.initial_proofs.clone(),
+                        auth_module.params.virtual_resource_amounts.clone(),
                     )
+                } else if is_subintent_processor_blueprint && is_called_by_root_processor {
+                    let auth_module = &mut api.kernel_get_system().modules.auth;
+                    let subintent_proofs = auth_module
+                        .params
+                        .subintent_initial_proofs
+                        .get(auth_module.executed_subintent_count)
+                        .cloned()
+                        .unwrap_or_default();
+                    auth_module.executed_subintent_count += 1;
+                    (BTreeSet::new(), subintent_proofs, BTreeMap::new())
                 } else {
-                    (BTreeSet::new(), BTreeSet::new())
+                    (BTreeSet::new(), BTreeSet::new(), BTreeMap::new())
                 };
 
-            Self::create_auth_zone(api, None, virtual_resources, virtual_non_fungibles)?
+            Self::create_auth_zone(
+                api,
+                None,
+                virtual_resources,
+                virtual_non_fungibles,
+                virtual_resource_amounts,
+            )?
         };
 
         // Check authorization
@@ -120,7 +181,7 @@ impl AuthModule {
                 blueprint_id: blueprint_id.clone(),
                 ident: ident.to_string(),
             };
-            Self::check_permission(&auth_zone, permission, fn_identifier, api)?;
+            Self::check_permission(&auth_zone, auth_zone_hash, permission, fn_identifier, api)?;
         }
 
         Ok(auth_zone)
@@ -149,11 +210,12 @@ impl AuthModule {
         V: SystemCallbackObject,
         Y: KernelApi<SystemConfig<V>>,
     {
-        let auth_zone = AuthModule::create_auth_zone(
+        let (auth_zone, auth_zone_hash) = AuthModule::create_auth_zone(
             api,
             Some((receiver, direct_access)),
             btreeset!(),
             btreeset!(),
+            btreemap!(),
         )?;
 
         // Step 1: Resolve method to permission
@@ -168,15 +230,52 @@ impl AuthModule {
             .get_blueprint_info(receiver, attached_module_id)?
             .blueprint_id;
 
+        if blueprint_id.package_address.eq(&RESOURCE_PACKAGE)
+            && blueprint_id.blueprint_name.eq(AUTH_ZONE_BLUEPRINT)
+        {
+            api.kernel_get_system().modules.auth.auth_zone_generation += 1;
+        }
+
         let permission =
             Self::resolve_method_permission(api, &blueprint_id, receiver, &module_id, ident, args)?;
 
-        // Step 2: Check permission
+        // Step 2: Reject public methods of paused objects, role protected methods stay callable
+        if let ResolvedPermission::AllowAll = permission {
+            Self::check_not_paused(api, receiver, &module_id)?;
+        }
+
+        // Step 3: Check permission
         let fn_identifier = FnIdentifier {
             blueprint_id: blueprint_id.clone(),
             ident: ident.to_string(),
         };
-        Self::check_permission(&auth_zone, permission, fn_identifier, api)?;
+        let authorizer = Self::resolve_account_authorizer(
+            api,
+            &blueprint_id,
+            receiver,
+            &module_id,
+            ident,
+            &permission,
+        )?;
+        Self::check_permission(
+            &auth_zone,
+            auth_zone_hash,
+            permission,
+            fn_identifier.clone(),
+            api,
+        )?;
+
+        // Step 4: Additionally ask the authorizer of an account, if it has one
+        if let Some(authorizer) = authorizer {
+            Self::check_account_authorizer(
+                api,
+                &auth_zone,
+                authorizer,
+                receiver,
+                args,
+                fn_identifier,
+            )?;
+        }
 
         Ok(auth_zone)
     }
@@ -203,7 +302,14 @@ impl AuthModule {
         V: SystemCallbackObject,
         Y: KernelApi<SystemConfig<V>>,
     {
-        Self::create_auth_zone(system, receiver, virtual_resources, virtual_non_fungibles)
+        Self::create_auth_zone(
+            system,
+            receiver,
+            virtual_resources,
+            virtual_non_fungibles,
+            btreemap!(),
+        )
+        .map(|(auth_zone, _)| auth_zone)
     }
 
     fn copy_global_caller<V, Y>(
@@ -229,12 +335,18 @@ impl AuthModule {
         Ok((auth_zone.into_payload().global_caller, Some(handle)))
     }
 
+    /// Creates the auth zone of the next call frame, returning it together with the hash of its
+    /// contents.
+    ///
+    /// The contents reference the auth zones of the caller's frames, so two auth zones with the
+    /// same hash see the same proofs for as long as none of those auth zones is modified.
     fn create_auth_zone<V, Y>(
         system: &mut SystemService<Y, V>,
         receiver: Option<(&NodeId, bool)>,
         virtual_resources: BTreeSet<ResourceAddress>,
         virtual_non_fungibles: BTreeSet<NonFungibleGlobalId>,
-    ) -> Result<NodeId, RuntimeError>
+        virtual_resource_amounts: BTreeMap<ResourceAddress, Decimal>,
+    ) -> Result<(NodeId, Hash), RuntimeError>
     where
         V: SystemCallbackObject,
         Y: KernelApi<SystemConfig<V>>,
@@ -309,6 +421,7 @@ impl AuthModule {
                 vec![],
                 virtual_resources,
                 virtual_non_fungibles,
+                virtual_resource_amounts,
                 local_package_address,
                 global_caller,
                 auth_zone_parent,
@@ -321,12 +434,15 @@ impl AuthModule {
         let new_auth_zone = system
             .api
             .kernel_allocate_node_id(EntityType::InternalGenericComponent)?;
+        let auth_zone_substate =
+            IndexedScryptoValue::from_typed(&FieldSubstate::new_unlocked_field(auth_zone));
+        let auth_zone_hash = hash(auth_zone_substate.as_slice());
 
         system.api.kernel_create_node(
             new_auth_zone,
             btreemap!(
                 MAIN_BASE_PARTITION => btreemap!(
-                    AuthZoneField::AuthZone.into() => IndexedScryptoValue::from_typed(&FieldSubstate::new_unlocked_field(auth_zone))
+                    AuthZoneField::AuthZone.into() => auth_zone_substate
                 ),
                 TYPE_INFO_FIELD_PARTITION => type_info_partition(TypeInfoSubstate::Object(ObjectInfo {
                     blueprint_info: BlueprintInfo {
@@ -346,7 +462,7 @@ impl AuthModule {
             system.kernel_close_substate(parent_lock_handle)?;
         }
 
-        Ok(new_auth_zone)
+        Ok((new_auth_zone, auth_zone_hash))
     }
 
     pub fn teardown_auth_zone<V, Y>(
@@ -396,6 +512,7 @@ impl AuthModule {
 
     fn check_permission<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
         auth_zone: &NodeId,
+        auth_zone_hash: Hash,
         resolved_permission: ResolvedPermission,
         fn_identifier: FnIdentifier,
         api: &mut SystemService<Y, V>,
@@ -403,18 +520,18 @@ impl AuthModule {
         match resolved_permission {
             ResolvedPermission::AllowAll => return Ok(()),
             ResolvedPermission::AccessRule(rule) => {
-                let result =
-                    Authorization::check_authorization_against_access_rule(api, &auth_zone, &rule)?;
+                let result = Self::check_access_rule(api, auth_zone, auth_zone_hash, &rule)?;
 
                 match result {
                     AuthorizationCheckResult::Authorized => Ok(()),
-                    AuthorizationCheckResult::Failed(access_rule_stack) => Err(
+                    AuthorizationCheckResult::Failed(access_rule_stack, proof_rules) => Err(
                         RuntimeError::SystemModuleError(SystemModuleError::AuthError(
                             AuthError::Unauthorized(Box::new(Unauthorized {
                                 failed_access_rules: FailedAccessRules::AccessRule(
                                     access_rule_stack,
                                 ),
                                 fn_identifier,
+                                unsatisfied_proof_rules: proof_rules,
                             })),
                         )),
                     ),
@@ -426,20 +543,21 @@ impl AuthModule {
                 module_id,
             } => {
                 let result = Authorization::check_authorization_against_role_list(
-                    &auth_zone,
                     &role_assignment_of,
                     module_id,
                     &role_list,
                     api,
+                    |api, rule| Self::check_access_rule(api, auth_zone, auth_zone_hash, rule),
                 )?;
 
                 match result {
                     AuthorityListAuthorizationResult::Authorized => Ok(()),
-                    AuthorityListAuthorizationResult::Failed(auth_list_fail) => Err(
+                    AuthorityListAuthorizationResult::Failed(auth_list_fail, proof_rules) => Err(
                         RuntimeError::SystemModuleError(SystemModuleError::AuthError(
                             AuthError::Unauthorized(Box::new(Unauthorized {
                                 failed_access_rules: FailedAccessRules::RoleList(auth_list_fail),
                                 fn_identifier,
+                                unsatisfied_proof_rules: proof_rules,
                             })),
                         )),
                     ),
@@ -448,6 +566,167 @@ impl AuthModule {
         }
     }
 
+    /// Checks an access rule against the auth zone of the next call frame, reusing the result of
+    /// an earlier check of the same rule against an identical auth zone if no auth zone has been
+    /// modified since.
+    fn check_access_rule<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
+        api: &mut SystemService<Y, V>,
+        auth_zone: &NodeId,
+        auth_zone_hash: Hash,
+        rule: &AccessRule,
+    ) -> Result<AuthorizationCheckResult, RuntimeError> {
+        // Only protected rules require walking the auth zones
+        if !matches!(rule, AccessRule::Protected(..)) {
+            return Authorization::check_authorization_against_access_rule(api, auth_zone, rule);
+        }
+
+        let auth_module = &mut api.kernel_get_system().modules.auth;
+        let key = (
+            hash(scrypto_encode(rule).unwrap()),
+            auth_zone_hash,
+            auth_module.auth_zone_generation,
+        );
+        if let Some(result) = auth_module.access_rule_cache.get(&key) {
+            let result = result.clone();
+            auth_module.access_rule_cache_stats.hits += 1;
+            return Ok(result);
+        }
+
+        let result = Authorization::check_authorization_against_access_rule(api, auth_zone, rule)?;
+        let auth_module = &mut api.kernel_get_system().modules.auth;
+        auth_module.access_rule_cache_stats.misses += 1;
+        auth_module.access_rule_cache.insert(key, result.clone());
+
+        Ok(result)
+    }
+
+    /// Returns the authorizer of the account whose owner protected method is being called, if it
+    /// has one. Setting the authorizer is checked against the owner role only, so that the owner
+    /// can't be locked out by an authorizer. So are the plain fee locks, since the authorizer
+    /// would run before any fee is locked.
+    fn resolve_account_authorizer<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
+        api: &mut SystemService<Y, V>,
+        blueprint_id: &BlueprintId,
+        receiver: &NodeId,
+        module_id: &ModuleId,
+        ident: &str,
+        permission: &ResolvedPermission,
+    ) -> Result<Option<ComponentAddress>, RuntimeError> {
+        let is_account_main_module = blueprint_id.package_address.eq(&ACCOUNT_PACKAGE)
+            && blueprint_id.blueprint_name.eq(ACCOUNT_BLUEPRINT)
+            && module_id.eq(&ModuleId::Main);
+        let is_owner_protected = match permission {
+            ResolvedPermission::RoleList { role_list, .. } => {
+                role_list.list.contains(&RoleKey::new(OWNER_ROLE))
+            }
+            _ => false,
+        };
+        let is_owner_only = matches!(
+            ident,
+            ACCOUNT_SET_AUTHORIZER_IDENT
+                | ACCOUNT_LOCK_FEE_IDENT
+                | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT
+        );
+        if !is_account_main_module || !is_owner_protected || is_owner_only {
+            return Ok(None);
+        }
+
+        // Accounts whose blueprint definition predates the authorizer have none
+        let definition = api.get_blueprint_default_definition(blueprint_id.clone())?;
+        if !definition
+            .interface
+            .functions
+            .contains_key(ACCOUNT_GET_AUTHORIZER_IDENT)
+        {
+            return Ok(None);
+        }
+
+        let rtn = api.call_method(
+            receiver,
+            ACCOUNT_GET_AUTHORIZER_IDENT,
+            scrypto_encode(&AccountGetAuthorizerInput {}).unwrap(),
+        )?;
+        let authorizer: AccountGetAuthorizerOutput = scrypto_decode(&rtn).map_err(|e| {
+            RuntimeError::SystemUpstreamError(SystemUpstreamError::OutputDecodeError(e))
+        })?;
+
+        Ok(authorizer)
+    }
+
+    /// Calls the authorizer of an account with a snapshot of the auth zone of the next call frame.
+    /// The fee locks which also withdraw are rejected instead, since the authorizer would run
+    /// before any fee is locked.
+    fn check_account_authorizer<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
+        api: &mut SystemService<Y, V>,
+        auth_zone: &NodeId,
+        authorizer: ComponentAddress,
+        receiver: &NodeId,
+        args: &IndexedScryptoValue,
+        fn_identifier: FnIdentifier,
+    ) -> Result<(), RuntimeError> {
+        if matches!(
+            fn_identifier.ident.as_str(),
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT | ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT
+        ) {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::AuthError(AuthError::AuthorizerRejected(fn_identifier)),
+            ));
+        }
+
+        let auth_zone_snapshot = Authorization::auth_zone_snapshot(auth_zone, api)?;
+        let input = AccountAuthorizerAuthorizeInput {
+            manifest_summary: ManifestSummary {
+                account: ComponentAddress::new_or_panic(receiver.0),
+                method_name: fn_identifier.ident.clone(),
+                args: args.as_slice().to_vec(),
+            },
+            auth_zone_snapshot,
+        };
+
+        let rtn = api.call_method(
+            authorizer.as_node_id(),
+            ACCOUNT_AUTHORIZER_AUTHORIZE_IDENT,
+            scrypto_encode(&input).unwrap(),
+        )?;
+        let authorized: AccountAuthorizerAuthorizeOutput = scrypto_decode(&rtn).map_err(|e| {
+            RuntimeError::SystemUpstreamError(SystemUpstreamError::OutputDecodeError(e))
+        })?;
+
+        if authorized {
+            Ok(())
+        } else {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::AuthError(AuthError::AuthorizerRejected(fn_identifier)),
+            ))
+        }
+    }
+
+    fn check_not_paused<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
+        api: &mut SystemService<Y, V>,
+        receiver: &NodeId,
+        module_id: &ModuleId,
+    ) -> Result<(), RuntimeError> {
+        if !module_id.eq(&ModuleId::Main) {
+            return Ok(());
+        }
+
+        // Only objects which opted in to pausing have their pause state read
+        let object_info = api.get_object_info(receiver)?;
+        let features = &object_info.blueprint_info.features;
+        if !object_info.is_global() || !features.contains(PAUSABLE_FEATURE) {
+            return Ok(());
+        }
+
+        let global_address = GlobalAddress::new_or_panic(receiver.0);
+        if RoleAssignmentNativePackage::is_main_module_paused(&global_address, api)? {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::AuthError(AuthError::ObjectPaused(global_address)),
+            ));
+        }
+
+        Ok(())
+    }
+
     fn resolve_method_permission<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
         api: &mut SystemService<Y, V>,
         blueprint_id: &BlueprintId,
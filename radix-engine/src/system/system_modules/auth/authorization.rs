@@ -13,7 +13,9 @@ use crate::types::*;
 use native_sdk::resource::{NativeNonFungibleProof, NativeProof};
 use num_traits::Zero;
 use radix_engine_interface::api::{ClientObjectApi, LockFlags, ModuleId};
+use radix_engine_interface::blueprints::account::AuthZoneSnapshot;
 use radix_engine_interface::blueprints::resource::*;
+use sbor::rust::cell::RefCell;
 use sbor::rust::ops::Fn;
 
 pub struct Authorization;
@@ -51,6 +53,7 @@ impl Authorization {
         P: Fn(
             &[Proof],
             &BTreeSet<ResourceAddress>,
+            &BTreeMap<ResourceAddress, Decimal>,
             BTreeSet<NonFungibleGlobalId>,
             &mut Y,
         ) -> Result<bool, RuntimeError>,
@@ -77,6 +80,7 @@ impl Authorization {
             {
                 let mut virtual_non_fungible_global_ids = BTreeSet::new();
                 let virtual_resources = auth_zone.virtual_resources();
+                let virtual_resource_amounts = auth_zone.virtual_resource_amounts();
 
                 virtual_non_fungible_global_ids.extend(auth_zone.virtual_non_fungibles().clone());
 
@@ -86,6 +90,7 @@ impl Authorization {
                 if check(
                     proofs,
                     virtual_resources,
+                    virtual_resource_amounts,
                     virtual_non_fungible_global_ids,
                     api,
                 )? {
@@ -119,6 +124,7 @@ impl Authorization {
         P: Fn(
             &[Proof],
             &BTreeSet<ResourceAddress>,
+            &BTreeMap<ResourceAddress, Decimal>,
             BTreeSet<NonFungibleGlobalId>,
             &mut Y,
         ) -> Result<bool, RuntimeError>,
@@ -144,7 +150,7 @@ impl Authorization {
             // Check Local virtual non fungibles
             let virtual_proofs = auth_zone.local_virtual_non_fungibles();
             if !virtual_proofs.is_empty() {
-                if check(&[], &btreeset!(), virtual_proofs, api)? {
+                if check(&[], &btreeset!(), &btreemap!(), virtual_proofs, api)? {
                     return Ok(true);
                 }
             }
@@ -181,7 +187,15 @@ impl Authorization {
         amount: Decimal,
         api: &mut Y,
     ) -> Result<bool, RuntimeError> {
-        Self::auth_zone_stack_matches(auth_zone, api, |proofs, _, _, api| {
+        Self::auth_zone_stack_matches(auth_zone, api, |proofs, _, virtual_amounts, _, api| {
+            if virtual_amounts
+                .get(resource)
+                .map(|virtual_amount| *virtual_amount >= amount)
+                .unwrap_or(false)
+            {
+                return Ok(true);
+            }
+
             // TODO: revisit this and decide if we need to check the composite max amount rather than just each proof individually
             for p in proofs {
                 if Self::proof_matches(&ResourceOrNonFungible::Resource(*resource), p, api)?
@@ -206,14 +220,26 @@ impl Authorization {
         Self::auth_zone_stack_matches(
             auth_zone,
             api,
-            |proofs, virtual_resources, virtual_non_fungibles, api| {
-                if let ResourceOrNonFungible::NonFungible(non_fungible_global_id) = resource_rule {
-                    if virtual_non_fungibles.contains(non_fungible_global_id) {
-                        return Ok(true);
-                    }
+            |proofs, virtual_resources, virtual_amounts, virtual_non_fungibles, api| {
+                match resource_rule {
+                    ResourceOrNonFungible::NonFungible(non_fungible_global_id) => {
+                        if virtual_non_fungibles.contains(non_fungible_global_id) {
+                            return Ok(true);
+                        }
 
-                    if virtual_resources.contains(&non_fungible_global_id.resource_address()) {
-                        return Ok(true);
+                        if virtual_resources.contains(&non_fungible_global_id.resource_address())
+                        {
+                            return Ok(true);
+                        }
+                    }
+                    ResourceOrNonFungible::Resource(resource_address) => {
+                        if virtual_amounts
+                            .get(resource_address)
+                            .map(|virtual_amount| virtual_amount.is_positive())
+                            .unwrap_or(false)
+                        {
+                            return Ok(true);
+                        }
                     }
                 }
 
@@ -228,6 +254,62 @@ impl Authorization {
         )
     }
 
+    /// Collects the proofs which access rule checks against the given auth zone can see.
+    ///
+    /// Virtual resources prove any amount and any non-fungible of the resource, so they are
+    /// recorded with the maximum amount.
+    pub fn auth_zone_snapshot<
+        Y: KernelSubstateApi<L> + ClientObjectApi<RuntimeError>,
+        L: Default,
+    >(
+        auth_zone: &NodeId,
+        api: &mut Y,
+    ) -> Result<AuthZoneSnapshot, RuntimeError> {
+        let snapshot = RefCell::new(AuthZoneSnapshot::default());
+        Self::auth_zone_stack_matches(
+            auth_zone,
+            api,
+            |proofs, virtual_resources, virtual_amounts, virtual_non_fungibles, api| {
+                fn prove_amount(
+                    snapshot: &mut AuthZoneSnapshot,
+                    resource_address: ResourceAddress,
+                    amount: Decimal,
+                ) {
+                    let proven = snapshot.resources.entry(resource_address).or_default();
+                    if amount > *proven {
+                        *proven = amount;
+                    }
+                }
+
+                let mut snapshot = snapshot.borrow_mut();
+                for resource_address in virtual_resources {
+                    prove_amount(&mut snapshot, *resource_address, Decimal::MAX);
+                }
+                for (resource_address, amount) in virtual_amounts {
+                    prove_amount(&mut snapshot, *resource_address, *amount);
+                }
+                snapshot.non_fungibles.extend(virtual_non_fungibles);
+
+                for proof in proofs {
+                    let resource_address = proof.resource_address(api)?;
+                    prove_amount(&mut snapshot, resource_address, proof.amount(api)?);
+                    if !resource_address.is_fungible() {
+                        for local_id in proof.non_fungible_local_ids(api)? {
+                            snapshot
+                                .non_fungibles
+                                .insert(NonFungibleGlobalId::new(resource_address, local_id));
+                        }
+                    }
+                }
+
+                // Never matches, so that every auth zone of the stack is visited
+                Ok(false)
+            },
+        )?;
+
+        Ok(snapshot.into_inner())
+    }
+
     pub fn verify_proof_rule<
         Y: KernelSubstateApi<L> + ClientObjectApi<RuntimeError>,
         L: Default,
@@ -298,17 +380,22 @@ impl Authorization {
                 if Self::verify_proof_rule(auth_zone, rule, api)? {
                     Ok(AuthorizationCheckResult::Authorized)
                 } else {
-                    Ok(AuthorizationCheckResult::Failed(vec![]))
+                    Ok(AuthorizationCheckResult::Failed(vec![], vec![rule.clone()]))
                 }
             }
             AccessRuleNode::AnyOf(rules) => {
+                let mut unsatisfied_proof_rules = Vec::new();
                 for r in rules {
-                    let rtn = Self::verify_auth_rule(auth_zone, r, api)?;
-                    if matches!(rtn, AuthorizationCheckResult::Authorized) {
-                        return Ok(rtn);
+                    match Self::verify_auth_rule(auth_zone, r, api)? {
+                        AuthorizationCheckResult::Authorized => {
+                            return Ok(AuthorizationCheckResult::Authorized)
+                        }
+                        AuthorizationCheckResult::Failed(_, proof_rules) => {
+                            unsatisfied_proof_rules.extend(proof_rules);
+                        }
                     }
                 }
-                Ok(AuthorizationCheckResult::Failed(vec![]))
+                Ok(AuthorizationCheckResult::Failed(vec![], unsatisfied_proof_rules))
             }
             AccessRuleNode::AllOf(rules) => {
                 for r in rules {
@@ -332,6 +419,17 @@ impl Authorization {
         key: &ModuleRoleKey,
         api: &mut Y,
     ) -> Result<AuthorizationCheckResult, RuntimeError> {
+        let access_rule = Self::resolve_role_key_access_rule(role_assignment_of, key, api)?;
+        Self::check_authorization_against_access_rule(api, auth_zone, &access_rule)
+    }
+
+    /// Looks up the access rule assigned to a role, falling back to the owner role if the role
+    /// has not been assigned.
+    fn resolve_role_key_access_rule<Y: KernelSubstateApi<L>, L: Default>(
+        role_assignment_of: &GlobalAddress,
+        key: &ModuleRoleKey,
+        api: &mut Y,
+    ) -> Result<AccessRule, RuntimeError> {
         let access_rule = if key.key.key.eq(SELF_ROLE) {
             rule!(require(global_caller(role_assignment_of.clone())))
         } else {
@@ -377,7 +475,7 @@ impl Authorization {
             }
         };
 
-        Self::check_authorization_against_access_rule(api, auth_zone, &access_rule)
+        Ok(access_rule)
     }
 
     pub fn check_authorization_against_access_rule<
@@ -393,47 +491,50 @@ impl Authorization {
                 let mut rtn = Self::verify_auth_rule(auth_zone, rule_node, api)?;
                 match &mut rtn {
                     AuthorizationCheckResult::Authorized => {}
-                    AuthorizationCheckResult::Failed(stack) => {
+                    AuthorizationCheckResult::Failed(stack, _) => {
                         stack.push(rule.clone());
                     }
                 }
                 Ok(rtn)
             }
             AccessRule::AllowAll => Ok(AuthorizationCheckResult::Authorized),
-            AccessRule::DenyAll => Ok(AuthorizationCheckResult::Failed(vec![rule.clone()])),
+            AccessRule::DenyAll => Ok(AuthorizationCheckResult::Failed(vec![rule.clone()], vec![])),
         }
     }
 
-    pub fn check_authorization_against_role_list<
-        Y: KernelSubstateApi<L> + ClientObjectApi<RuntimeError>,
-        L: Default,
-    >(
-        auth_zone: &NodeId,
+    /// Checks the roles of a role list in turn, evaluating the access rule of each role with
+    /// `check_access_rule`.
+    pub fn check_authorization_against_role_list<Y, L, C>(
         role_assignment_of: &GlobalAddress,
         module: ModuleId,
         role_list: &RoleList,
         api: &mut Y,
-    ) -> Result<AuthorityListAuthorizationResult, RuntimeError> {
+        mut check_access_rule: C,
+    ) -> Result<AuthorityListAuthorizationResult, RuntimeError>
+    where
+        Y: KernelSubstateApi<L> + ClientObjectApi<RuntimeError>,
+        L: Default,
+        C: FnMut(&mut Y, &AccessRule) -> Result<AuthorizationCheckResult, RuntimeError>,
+    {
         let mut failed = Vec::new();
+        let mut unsatisfied_proof_rules = Vec::new();
 
         for key in &role_list.list {
             let module_role_key = ModuleRoleKey::new(module, key.key.as_str());
-            let result = Self::check_authorization_against_role_key_internal(
-                &auth_zone,
-                role_assignment_of,
-                &module_role_key,
-                api,
-            )?;
+            let access_rule =
+                Self::resolve_role_key_access_rule(role_assignment_of, &module_role_key, api)?;
+            let result = check_access_rule(api, &access_rule)?;
             match result {
                 AuthorizationCheckResult::Authorized => {
                     return Ok(AuthorityListAuthorizationResult::Authorized)
                 }
-                AuthorizationCheckResult::Failed(stack) => {
+                AuthorizationCheckResult::Failed(stack, proof_rules) => {
                     failed.push((key.clone(), stack));
+                    unsatisfied_proof_rules.extend(proof_rules);
                 }
             }
         }
 
-        Ok(AuthorityListAuthorizationResult::Failed(failed))
+        Ok(AuthorityListAuthorizationResult::Failed(failed, unsatisfied_proof_rules))
     }
 }
@@ -34,6 +34,12 @@ pub enum ExecutionCostingEntry<'a> {
     PrepareWasmCode {
         size: usize,
     },
+    GrowWasmMemory {
+        pages: u32,
+    },
+    ProcessKeyValueEntryBatch {
+        size: usize,
+    },
 
     /* invoke */
     BeforeInvoke {
@@ -100,10 +106,14 @@ pub enum ExecutionCostingEntry<'a> {
     QueryFeeReserve,
     QueryActor,
     QueryTransactionHash,
+    QueryTransactionSignatories,
     GenerateRuid,
     EmitEvent {
         size: usize,
     },
+    SubscribeToEvent,
+    UnsubscribeFromEvent,
+    RateLimitCheck,
     EmitLog {
         size: usize,
     },
@@ -134,7 +144,7 @@ pub enum ExecutionCostingEntry<'a> {
 pub enum FinalizationCostingEntry<'a> {
     CommitStateUpdates { store_commit: &'a StoreCommit },
     CommitEvents { events: &'a Vec<Event> },
-    CommitLogs { logs: &'a Vec<(Level, String)> },
+    CommitLogs { logs: &'a Vec<LogRecord> },
 }
 
 impl<'a> ExecutionCostingEntry<'a> {
@@ -155,6 +165,10 @@ impl<'a> ExecutionCostingEntry<'a> {
                 wasm_execution_units,
             } => ft.run_wasm_code_cost(package_address, export_name, *wasm_execution_units),
             ExecutionCostingEntry::PrepareWasmCode { size } => ft.instantiate_wasm_code_cost(*size),
+            ExecutionCostingEntry::GrowWasmMemory { pages } => ft.grow_wasm_memory_cost(*pages),
+            ExecutionCostingEntry::ProcessKeyValueEntryBatch { size } => {
+                ft.process_key_value_entry_batch_cost(*size)
+            }
             ExecutionCostingEntry::BeforeInvoke { actor, input_size } => {
                 ft.before_invoke_cost(actor, *input_size)
             }
@@ -186,8 +200,14 @@ impl<'a> ExecutionCostingEntry<'a> {
             ExecutionCostingEntry::QueryFeeReserve => ft.query_fee_reserve_cost(),
             ExecutionCostingEntry::QueryActor => ft.query_actor_cost(),
             ExecutionCostingEntry::QueryTransactionHash => ft.query_transaction_hash_cost(),
+            ExecutionCostingEntry::QueryTransactionSignatories => {
+                ft.query_transaction_signatories_cost()
+            }
             ExecutionCostingEntry::GenerateRuid => ft.generate_ruid_cost(),
             ExecutionCostingEntry::EmitEvent { size } => ft.emit_event_cost(*size),
+            ExecutionCostingEntry::SubscribeToEvent => ft.subscribe_to_event_cost(),
+            ExecutionCostingEntry::UnsubscribeFromEvent => ft.unsubscribe_from_event_cost(),
+            ExecutionCostingEntry::RateLimitCheck => ft.rate_limit_check_cost(),
             ExecutionCostingEntry::EmitLog { size } => ft.emit_log_cost(*size),
             ExecutionCostingEntry::Panic { size } => ft.panic_cost(*size),
             ExecutionCostingEntry::Bls12381V1Verify { size } => ft.bls12381_v1_verify_cost(*size),
@@ -224,13 +224,34 @@ impl CostingModule {
         Ok(())
     }
 
+    pub fn apply_storage_refund(&mut self, size_decrease: usize) -> Result<(), RuntimeError> {
+        self.on_apply_cost.on_call()?;
+
+        self.fee_reserve
+            .refund_state_storage(size_decrease)
+            .map_err(|e| {
+                RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+                    CostingError::FeeReserveError(e),
+                ))
+            })
+    }
+
     pub fn lock_fee(
         &mut self,
         vault_id: NodeId,
         locked_fee: LiquidFungibleResource,
         contingent: bool,
+        refund_vault_id: Option<NodeId>,
     ) {
-        self.fee_reserve.lock_fee(vault_id, locked_fee, contingent);
+        match refund_vault_id {
+            Some(refund_vault_id) => self.fee_reserve.lock_fee_with_refund(
+                vault_id,
+                locked_fee,
+                contingent,
+                refund_vault_id,
+            ),
+            None => self.fee_reserve.lock_fee(vault_id, locked_fee, contingent),
+        }
     }
 }
 
@@ -65,6 +65,8 @@ pub trait ExecutionFeeReserve {
         size_increase: usize,
     ) -> Result<(), FeeReserveError>;
 
+    fn refund_state_storage(&mut self, size_decrease: usize) -> Result<(), FeeReserveError>;
+
     fn consume_royalty(
         &mut self,
         royalty_amount: RoyaltyAmount,
@@ -72,6 +74,14 @@ pub trait ExecutionFeeReserve {
     ) -> Result<(), FeeReserveError>;
 
     fn lock_fee(&mut self, vault_id: NodeId, fee: LiquidFungibleResource, contingent: bool);
+
+    fn lock_fee_with_refund(
+        &mut self,
+        vault_id: NodeId,
+        fee: LiquidFungibleResource,
+        contingent: bool,
+        refund_vault_id: NodeId,
+    );
 }
 
 pub trait FinalizingFeeReserve {
@@ -137,9 +147,12 @@ pub struct SystemLoanFeeReserve {
     /// Storage Costs
     storage_cost_committed: Decimal,
     storage_cost_deferred: IndexMap<StorageType, usize>,
+    state_storage_cost_committed: Decimal,
+    /// Refunds for deleted state, capped at the state storage cost of the transaction
+    storage_refund_committed: Decimal,
 
     /// Payments made during the execution of a transaction.
-    locked_fees: Vec<(NodeId, LiquidFungibleResource, bool)>,
+    locked_fees: Vec<(NodeId, LiquidFungibleResource, bool, Option<NodeId>)>,
 }
 
 impl Default for SystemLoanFeeReserve {
@@ -245,6 +258,8 @@ impl SystemLoanFeeReserve {
 
             storage_cost_committed: Decimal::ZERO,
             storage_cost_deferred: index_map_new(),
+            state_storage_cost_committed: Decimal::ZERO,
+            storage_refund_committed: Decimal::ZERO,
 
             locked_fees: Vec::new(),
         }
@@ -254,6 +269,10 @@ impl SystemLoanFeeReserve {
         self.execution_cost_unit_limit
     }
 
+    pub fn execution_cost_units_consumed(&self) -> u32 {
+        self.execution_cost_units_committed
+    }
+
     pub fn execution_cost_unit_price(&self) -> Decimal {
         self.execution_cost_unit_price
     }
@@ -507,11 +526,52 @@ impl ExecutionFeeReserve for SystemLoanFeeReserve {
         } else {
             self.xrd_balance -= amount;
             self.storage_cost_committed += amount;
+            if storage_type == StorageType::State {
+                self.state_storage_cost_committed += amount;
+            }
             Ok(())
         }
     }
 
-    fn lock_fee(&mut self, vault_id: NodeId, mut fee: LiquidFungibleResource, contingent: bool) {
+    fn refund_state_storage(&mut self, size_decrease: usize) -> Result<(), FeeReserveError> {
+        let amount = self
+            .state_storage_price
+            .checked_mul(size_decrease)
+            .and_then(|x| x.checked_mul(STATE_STORAGE_REFUND_PERCENTAGE))
+            .and_then(|x| x.checked_mul(Decimal::ONE_HUNDREDTH))
+            .ok_or(FeeReserveError::Overflow)?;
+
+        // A transaction can't be paid for deleting more than it has paid for new state
+        let amount = min(amount, self.state_storage_cost_committed - self.storage_refund_committed);
+        self.xrd_balance += amount;
+        self.storage_cost_committed -= amount;
+        self.storage_refund_committed += amount;
+        Ok(())
+    }
+
+    fn lock_fee(&mut self, vault_id: NodeId, fee: LiquidFungibleResource, contingent: bool) {
+        self.lock_fee_internal(vault_id, fee, contingent, None);
+    }
+
+    fn lock_fee_with_refund(
+        &mut self,
+        vault_id: NodeId,
+        fee: LiquidFungibleResource,
+        contingent: bool,
+        refund_vault_id: NodeId,
+    ) {
+        self.lock_fee_internal(vault_id, fee, contingent, Some(refund_vault_id));
+    }
+}
+
+impl SystemLoanFeeReserve {
+    fn lock_fee_internal(
+        &mut self,
+        vault_id: NodeId,
+        mut fee: LiquidFungibleResource,
+        contingent: bool,
+        refund_vault_id: Option<NodeId>,
+    ) {
         // Update balance
         if !contingent {
             self.xrd_balance = self
@@ -522,7 +582,7 @@ impl ExecutionFeeReserve for SystemLoanFeeReserve {
 
         // Move resource
         self.locked_fees
-            .push((vault_id, fee.take_all(), contingent));
+            .push((vault_id, fee.take_all(), contingent, refund_vault_id));
     }
 }
 
@@ -560,6 +620,7 @@ impl FinalizingFeeReserve for SystemLoanFeeReserve {
             total_tipping_cost_in_xrd,
             total_royalty_cost_in_xrd: self.royalty_cost_committed,
             total_storage_cost_in_xrd: self.storage_cost_committed,
+            total_storage_refund_in_xrd: self.storage_refund_committed,
             total_bad_debt_in_xrd: self.xrd_owed,
             locked_fees: self.locked_fees,
             royalty_cost_breakdown: self.royalty_cost_breakdown,
@@ -667,7 +728,10 @@ mod tests {
         assert_eq!(summary.total_execution_cost_in_xrd, dec!("0"));
         assert_eq!(summary.total_royalty_cost_in_xrd, dec!("0"));
         assert_eq!(summary.total_bad_debt_in_xrd, dec!("0"));
-        assert_eq!(summary.locked_fees, vec![(TEST_VAULT_ID, xrd(100), false)],);
+        assert_eq!(
+            summary.locked_fees,
+            vec![(TEST_VAULT_ID, xrd(100), false, None)],
+        );
     }
 
     #[test]
@@ -714,7 +778,10 @@ mod tests {
         assert_eq!(summary.total_tipping_cost_in_xrd, dec!("0.1"));
         assert_eq!(summary.total_royalty_cost_in_xrd, dec!("16"));
         assert_eq!(summary.total_bad_debt_in_xrd, dec!("0"));
-        assert_eq!(summary.locked_fees, vec![(TEST_VAULT_ID, xrd(100), false)]);
+        assert_eq!(
+            summary.locked_fees,
+            vec![(TEST_VAULT_ID, xrd(100), false, None)]
+        );
         assert_eq!(summary.total_execution_cost_units_consumed, 2);
         assert_eq!(
             summary.royalty_cost_breakdown,
@@ -746,4 +813,31 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn test_state_storage_refund() {
+        let mut fee_reserve =
+            create_test_fee_reserve(dec!(1), dec!(1), dec!(1), 0, 1000, 50, false);
+        fee_reserve.lock_fee(TEST_VAULT_ID, xrd(100), false);
+        fee_reserve.consume_storage(StorageType::State, 10).unwrap();
+        fee_reserve.refund_state_storage(4).unwrap();
+        fee_reserve.repay_all().unwrap();
+        let summary = fee_reserve.finalize();
+        assert_eq!(summary.total_storage_cost_in_xrd, dec!("8"));
+        assert_eq!(summary.total_storage_refund_in_xrd, dec!("2"));
+    }
+
+    #[test]
+    fn test_state_storage_refund_is_capped_at_state_storage_cost() {
+        let mut fee_reserve =
+            create_test_fee_reserve(dec!(1), dec!(1), dec!(1), 0, 1000, 50, false);
+        fee_reserve.lock_fee(TEST_VAULT_ID, xrd(100), false);
+        fee_reserve.consume_storage(StorageType::State, 2).unwrap();
+        fee_reserve.consume_storage(StorageType::Archive, 5).unwrap();
+        fee_reserve.refund_state_storage(100).unwrap();
+        fee_reserve.repay_all().unwrap();
+        let summary = fee_reserve.finalize();
+        assert_eq!(summary.total_storage_cost_in_xrd, dec!("5"));
+        assert_eq!(summary.total_storage_refund_in_xrd, dec!("2"));
+    }
 }
@@ -16,15 +16,18 @@ pub struct FeeReserveFinalizationSummary {
     pub total_finalization_cost_in_xrd: Decimal,
     /// The total cost for tipping
     pub total_tipping_cost_in_xrd: Decimal,
-    /// The total cost for storage
+    /// The total cost for storage, net of refunds
     pub total_storage_cost_in_xrd: Decimal,
+    /// The total refund for deleted state
+    pub total_storage_refund_in_xrd: Decimal,
     /// The total cost for royalty
     pub total_royalty_cost_in_xrd: Decimal,
 
     /// The (non-negative) amount of bad debt due to transaction unable to repay loan.
     pub total_bad_debt_in_xrd: Decimal,
-    /// The vaults locked for XRD payment
-    pub locked_fees: Vec<(NodeId, LiquidFungibleResource, bool)>,
+    /// The vaults locked for XRD payment, along with the vault any unused fee is refunded to
+    /// if it is not the locking vault
+    pub locked_fees: Vec<(NodeId, LiquidFungibleResource, bool, Option<NodeId>)>,
     /// The royalty cost breakdown
     pub royalty_cost_breakdown: IndexMap<RoyaltyRecipient, Decimal>,
 }
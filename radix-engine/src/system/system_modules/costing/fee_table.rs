@@ -171,6 +171,19 @@ impl FeeTable {
         mul(cast(size), 2)
     }
 
+    #[inline]
+    pub fn grow_wasm_memory_cost(&self, pages: u32) -> u32 {
+        // Growing zero-fills 64 KiB per page, but the pages stay allocated until the call frame
+        // returns. Charge a flat amount per page, rather than per byte.
+
+        mul(pages, 1000)
+    }
+
+    #[inline]
+    pub fn process_key_value_entry_batch_cost(&self, size: usize) -> u32 {
+        Self::data_processing_cost(size)
+    }
+
     #[inline]
     pub fn before_invoke_cost(&self, _actor: &Actor, input_size: usize) -> u32 {
         Self::data_processing_cost(input_size)
@@ -361,6 +374,11 @@ impl FeeTable {
         500
     }
 
+    #[inline]
+    pub fn query_transaction_signatories_cost(&self) -> u32 {
+        500
+    }
+
     #[inline]
     pub fn generate_ruid_cost(&self) -> u32 {
         500
@@ -371,6 +389,21 @@ impl FeeTable {
         500 + Self::data_processing_cost(size)
     }
 
+    #[inline]
+    pub fn subscribe_to_event_cost(&self) -> u32 {
+        500
+    }
+
+    #[inline]
+    pub fn unsubscribe_from_event_cost(&self) -> u32 {
+        500
+    }
+
+    #[inline]
+    pub fn rate_limit_check_cost(&self) -> u32 {
+        500
+    }
+
     #[inline]
     pub fn emit_log_cost(&self, size: usize) -> u32 {
         500 + Self::data_processing_cost(size)
@@ -532,10 +565,10 @@ impl FeeTable {
     }
 
     #[inline]
-    pub fn commit_logs_cost(&self, logs: &Vec<(Level, String)>) -> u32 {
+    pub fn commit_logs_cost(&self, logs: &Vec<LogRecord>) -> u32 {
         let mut sum = 0;
         for log in logs {
-            sum += add(cast(log.1.len()) / 4, 1_000)
+            sum += add(cast(log.size()) / 4, 1_000)
         }
         sum
     }
@@ -0,0 +1,210 @@
+use crate::errors::{RuntimeError, SystemModuleError};
+use crate::kernel::kernel_api::{KernelApi, KernelInternalApi, KernelInvocation};
+use crate::kernel::kernel_callback_api::CreateNodeEvent;
+use crate::system::actor::Actor;
+use crate::system::module::{InitSystemModule, SystemModule};
+use crate::system::system_callback::SystemConfig;
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::system::system_modules::execution_trace::{BucketSnapshot, ProofSnapshot};
+use crate::system::type_info::TypeInfoSubstate;
+use crate::types::*;
+use radix_engine_interface::blueprints::resource::WORKTOP_BLUEPRINT;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum DebuggerError {
+    /// The debugger aborted the transaction, while paused during the given manifest instruction
+    /// (or before the first one).
+    TransactionAborted { instruction_index: Option<usize> },
+}
+
+/// The point of execution at which the debugger is paused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Before the manifest instruction with the given index is executed.
+    Instruction(usize),
+    /// Before the kernel invokes the given callee.
+    Invoke(Actor),
+}
+
+/// The engine state exposed to the debugger at a breakpoint.
+#[derive(Debug, Clone)]
+pub struct BreakpointContext {
+    pub breakpoint: Breakpoint,
+    /// The manifest instruction currently being executed, if any.
+    pub instruction_index: Option<usize>,
+    /// The actors of all active call frames, starting from the root frame.
+    pub call_frames: Vec<Actor>,
+    /// The contents of the transaction worktop.
+    pub worktop: Vec<BucketSnapshot>,
+    /// The proofs in the auth zone of the current call frame.
+    pub auth_zone: Vec<ProofSnapshot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebuggerAction {
+    Resume,
+    Abort,
+}
+
+/// A hook which is called whenever execution reaches a breakpoint, and decides whether the
+/// transaction continues.
+pub trait TransactionDebugger {
+    /// Whether execution should also pause before every kernel invocation, on top of pausing
+    /// before every manifest instruction.
+    fn break_on_invoke(&self) -> bool {
+        false
+    }
+
+    fn on_breakpoint(&mut self, context: &BreakpointContext) -> DebuggerAction;
+}
+
+pub struct DebuggerModule {
+    debugger: Box<dyn TransactionDebugger>,
+    current_instruction_index: Option<usize>,
+    call_frames: Vec<Actor>,
+    worktop: Option<NodeId>,
+}
+
+impl DebuggerModule {
+    pub fn new(debugger: Box<dyn TransactionDebugger>) -> Self {
+        Self {
+            debugger,
+            current_instruction_index: None,
+            call_frames: vec![Actor::Root],
+            worktop: None,
+        }
+    }
+
+    pub fn on_instruction<Y: KernelInternalApi<SystemConfig<V>>, V: SystemCallbackObject>(
+        api: &mut Y,
+        instruction_index: usize,
+    ) -> Result<(), RuntimeError> {
+        match &mut api.kernel_get_system().modules.debugger {
+            Some(debugger) => debugger.current_instruction_index = Some(instruction_index),
+            None => return Ok(()),
+        }
+
+        Self::pause::<Y, V>(api, Breakpoint::Instruction(instruction_index))
+    }
+
+    fn pause<Y: KernelInternalApi<SystemConfig<V>>, V: SystemCallbackObject>(
+        api: &mut Y,
+        breakpoint: Breakpoint,
+    ) -> Result<(), RuntimeError> {
+        let (instruction_index, call_frames, worktop_id) = {
+            let debugger = api.kernel_get_system().modules.debugger.as_ref().unwrap();
+            (
+                debugger.current_instruction_index,
+                debugger.call_frames.clone(),
+                debugger.worktop,
+            )
+        };
+        let auth_zone_id = api
+            .kernel_get_system_state()
+            .current_call_frame
+            .self_auth_zone();
+
+        let context = BreakpointContext {
+            breakpoint,
+            instruction_index,
+            call_frames,
+            worktop: worktop_id
+                .and_then(|worktop_id| api.kernel_read_worktop(&worktop_id))
+                .unwrap_or_default(),
+            auth_zone: auth_zone_id
+                .and_then(|auth_zone_id| api.kernel_read_auth_zone(&auth_zone_id))
+                .unwrap_or_default(),
+        };
+
+        let action = api
+            .kernel_get_system()
+            .modules
+            .debugger
+            .as_mut()
+            .unwrap()
+            .debugger
+            .on_breakpoint(&context);
+
+        match action {
+            DebuggerAction::Resume => Ok(()),
+            DebuggerAction::Abort => Err(RuntimeError::SystemModuleError(
+                SystemModuleError::DebuggerError(DebuggerError::TransactionAborted {
+                    instruction_index,
+                }),
+            )),
+        }
+    }
+}
+
+impl InitSystemModule for DebuggerModule {}
+
+impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for DebuggerModule {
+    fn before_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        invocation: &KernelInvocation<Actor>,
+    ) -> Result<(), RuntimeError> {
+        let break_on_invoke = api
+            .kernel_get_system()
+            .modules
+            .debugger
+            .as_ref()
+            .unwrap()
+            .debugger
+            .break_on_invoke();
+        if break_on_invoke {
+            Self::pause::<Y, V>(api, Breakpoint::Invoke(invocation.call_frame_data.clone()))?;
+        }
+
+        api.kernel_get_system()
+            .modules
+            .debugger
+            .as_mut()
+            .unwrap()
+            .call_frames
+            .push(invocation.call_frame_data.clone());
+
+        Ok(())
+    }
+
+    fn after_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _output: &IndexedScryptoValue,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .debugger
+            .as_mut()
+            .unwrap()
+            .call_frames
+            .pop();
+
+        Ok(())
+    }
+
+    fn on_create_node<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &CreateNodeEvent,
+    ) -> Result<(), RuntimeError> {
+        if let CreateNodeEvent::Start(node_id, node_substates) = event {
+            let type_info = node_substates
+                .get(&TYPE_INFO_FIELD_PARTITION)
+                .and_then(|partition| partition.get(&SubstateKey::from(TypeInfoField::TypeInfo)))
+                .and_then(|substate| substate.as_typed::<TypeInfoSubstate>().ok());
+
+            if let Some(TypeInfoSubstate::Object(info)) = type_info {
+                if info.blueprint_info.blueprint_id
+                    == BlueprintId::new(&RESOURCE_PACKAGE, WORKTOP_BLUEPRINT)
+                {
+                    api.kernel_get_system()
+                        .modules
+                        .debugger
+                        .as_mut()
+                        .unwrap()
+                        .worktop = Some(**node_id);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
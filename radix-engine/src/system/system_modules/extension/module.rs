@@ -0,0 +1,110 @@
+use crate::errors::{RuntimeError, SystemModuleError};
+use crate::kernel::kernel_api::KernelInvocation;
+use crate::kernel::kernel_callback_api::{
+    CloseSubstateEvent, OpenSubstateEvent, ReadSubstateEvent, WriteSubstateEvent,
+};
+use crate::system::actor::Actor;
+use crate::types::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum ExtensionError {
+    /// The named extension aborted the transaction, for the given reason.
+    TransactionAborted { extension: String, reason: String },
+}
+
+/// A custom system module, supplied by the code embedding the engine (e.g. a simulator or an
+/// analytics tool), which observes execution without having to patch the kernel.
+///
+/// Extensions are called after all built-in modules have processed a hook, in ascending
+/// [`order`](SystemModuleExtension::order); extensions of equal order are called in the order
+/// they were registered. Returning an error from a hook aborts the transaction with an
+/// [`ExtensionError`].
+pub trait SystemModuleExtension {
+    /// The name reported in an [`ExtensionError`] raised by this extension.
+    fn name(&self) -> &str;
+
+    /// The position of this extension relative to the other registered extensions.
+    fn order(&self) -> i32 {
+        0
+    }
+
+    /// Whether the hooks of this extension are currently called. Checked before every hook, so
+    /// the embedder may toggle an extension in the middle of a transaction.
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    fn before_invoke(
+        &mut self,
+        _invocation: &KernelInvocation<Actor>,
+        _depth: usize,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn after_invoke(&mut self, _output: &IndexedScryptoValue, _depth: usize) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_open_substate(&mut self, _event: &OpenSubstateEvent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_read_substate(&mut self, _event: &ReadSubstateEvent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_write_substate(&mut self, _event: &WriteSubstateEvent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_close_substate(&mut self, _event: &CloseSubstateEvent) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Called whenever a vault locks XRD to pay the transaction fee.
+    fn on_lock_fee(&mut self, _vault_id: &NodeId, _amount: Decimal, _contingent: bool) {}
+}
+
+/// The extensions registered for a transaction, kept sorted by their order.
+#[derive(Default)]
+pub struct SystemModuleExtensions {
+    extensions: Vec<Box<dyn SystemModuleExtension>>,
+}
+
+impl SystemModuleExtensions {
+    pub fn add(&mut self, extension: Box<dyn SystemModuleExtension>) {
+        let index = self
+            .extensions
+            .partition_point(|existing| existing.order() <= extension.order());
+        self.extensions.insert(index, extension);
+    }
+
+    pub fn dispatch<F>(&mut self, mut hook: F) -> Result<(), RuntimeError>
+    where
+        F: FnMut(&mut dyn SystemModuleExtension) -> Result<(), String>,
+    {
+        for extension in self.extensions.iter_mut() {
+            if !extension.is_enabled() {
+                continue;
+            }
+            hook(extension.as_mut()).map_err(|reason| {
+                RuntimeError::SystemModuleError(SystemModuleError::ExtensionError(
+                    ExtensionError::TransactionAborted {
+                        extension: extension.name().to_string(),
+                        reason,
+                    },
+                ))
+            })?;
+        }
+        Ok(())
+    }
+
+    pub fn on_lock_fee(&mut self, vault_id: &NodeId, amount: Decimal, contingent: bool) {
+        for extension in self.extensions.iter_mut() {
+            if extension.is_enabled() {
+                extension.on_lock_fee(vault_id, amount, contingent);
+            }
+        }
+    }
+}
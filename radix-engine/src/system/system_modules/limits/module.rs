@@ -16,10 +16,23 @@ use crate::{errors::RuntimeError, errors::SystemModuleError, kernel::kernel_api:
 pub enum TransactionLimitsError {
     MaxSubstateKeySizeExceeded(usize),
     MaxSubstateSizeExceeded(usize),
-    MaxInvokePayloadSizeExceeded(usize),
+    InvokeInputSizeExceeded {
+        fn_identifier: Option<FnIdentifier>,
+        actual: usize,
+        max: usize,
+    },
+    InvokeOutputSizeExceeded {
+        fn_identifier: Option<FnIdentifier>,
+        actual: usize,
+        max: usize,
+    },
     MaxCallDepthLimitReached,
     TrackSubstateSizeExceeded { actual: usize, max: usize },
     HeapSubstateSizeExceeded { actual: usize, max: usize },
+    NodeSubstateCountExceeded { node_id: NodeId, actual: u64, max: u64 },
+    NodeSubstateSizeExceeded { node_id: NodeId, actual: u64, max: u64 },
+    WasmMemoryPagesPerCallFrameExceeded { actual: u32, max: u32 },
+    WasmMemoryPagesPerTransactionExceeded { actual: u32, max: u32 },
     LogSizeTooLarge { actual: usize, max: usize },
     EventSizeTooLarge { actual: usize, max: usize },
     PanicMessageSizeTooLarge { actual: usize, max: usize },
@@ -30,9 +43,14 @@ pub enum TransactionLimitsError {
 pub struct TransactionLimitsConfig {
     pub max_heap_substate_total_bytes: usize,
     pub max_track_substate_total_bytes: usize,
+    pub max_node_substate_count: u64,
+    pub max_node_substate_total_bytes: u64,
+    pub max_wasm_memory_pages_per_call_frame: u32,
+    pub max_wasm_memory_pages_per_transaction: u32,
     pub max_substate_key_size: usize,
     pub max_substate_value_size: usize,
-    pub max_invoke_payload_size: usize,
+    pub max_invoke_input_size: usize,
+    pub max_invoke_output_size: usize,
     pub max_event_size: usize,
     pub max_log_size: usize,
     pub max_panic_message_size: usize,
@@ -48,6 +66,8 @@ pub struct LimitsModule {
     config: TransactionLimitsConfig,
     heap_substate_total_bytes: usize,
     track_substate_total_bytes: usize,
+    /// The linear memory size of the WASM instance of each call frame, by depth
+    wasm_memory_pages: IndexMap<usize, u32>,
 }
 
 impl LimitsModule {
@@ -56,6 +76,7 @@ impl LimitsModule {
             config: limits_config,
             heap_substate_total_bytes: 0,
             track_substate_total_bytes: 0,
+            wasm_memory_pages: index_map_new(),
         }
     }
 
@@ -93,6 +114,113 @@ impl LimitsModule {
         Ok(())
     }
 
+    pub fn process_invoke_input_size(
+        &self,
+        actor: &Actor,
+        input_size: usize,
+    ) -> Result<(), RuntimeError> {
+        if input_size > self.config.max_invoke_input_size {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::InvokeInputSizeExceeded {
+                        fn_identifier: actor.fn_identifier(),
+                        actual: input_size,
+                        max: self.config.max_invoke_input_size,
+                    },
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn process_invoke_output_size(
+        &self,
+        actor: &Actor,
+        output_size: usize,
+    ) -> Result<(), RuntimeError> {
+        if output_size > self.config.max_invoke_output_size {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::InvokeOutputSizeExceeded {
+                        fn_identifier: actor.fn_identifier(),
+                        actual: output_size,
+                        max: self.config.max_invoke_output_size,
+                    },
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks the state size of a node, as it will be after the transaction is committed.
+    pub fn process_node_state_size(
+        &self,
+        node_id: &NodeId,
+        state_size: &NodeStateSize,
+    ) -> Result<(), RuntimeError> {
+        if state_size.substate_count > self.config.max_node_substate_count {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::NodeSubstateCountExceeded {
+                        node_id: *node_id,
+                        actual: state_size.substate_count,
+                        max: self.config.max_node_substate_count,
+                    },
+                ),
+            ));
+        }
+
+        if state_size.total_bytes > self.config.max_node_substate_total_bytes {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::NodeSubstateSizeExceeded {
+                        node_id: *node_id,
+                        actual: state_size.total_bytes,
+                        max: self.config.max_node_substate_total_bytes,
+                    },
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Records the linear memory size of the WASM instance running in the call frame at `depth`.
+    /// The transaction limit applies to the memory of all live call frames combined.
+    pub fn process_wasm_memory(&mut self, depth: usize, pages: u32) -> Result<(), RuntimeError> {
+        if pages > self.config.max_wasm_memory_pages_per_call_frame {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::WasmMemoryPagesPerCallFrameExceeded {
+                        actual: pages,
+                        max: self.config.max_wasm_memory_pages_per_call_frame,
+                    },
+                ),
+            ));
+        }
+
+        self.wasm_memory_pages.insert(depth, pages);
+
+        let total_pages = self
+            .wasm_memory_pages
+            .values()
+            .fold(0u32, |total, pages| total.saturating_add(*pages));
+        if total_pages > self.config.max_wasm_memory_pages_per_transaction {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::WasmMemoryPagesPerTransactionExceeded {
+                        actual: total_pages,
+                        max: self.config.max_wasm_memory_pages_per_transaction,
+                    },
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
     pub fn process_io_access(&mut self, io_access: &IOAccess) -> Result<(), RuntimeError> {
         match io_access {
             IOAccess::ReadFromDb(..) | IOAccess::ReadFromDbNotFound(..) => {}
@@ -173,15 +301,23 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for LimitsModule {
         }
 
         // Check input size
-        let limits = &mut api.kernel_get_system().modules.limits.config;
-        let input_size = invocation.len();
-        if input_size > limits.max_invoke_payload_size {
-            return Err(RuntimeError::SystemModuleError(
-                SystemModuleError::TransactionLimitsError(
-                    TransactionLimitsError::MaxInvokePayloadSizeExceeded(input_size),
-                ),
-            ));
-        }
+        api.kernel_get_system()
+            .modules
+            .limits
+            .process_invoke_input_size(&invocation.call_frame_data, invocation.len())
+    }
+
+    fn after_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _output: &IndexedScryptoValue,
+    ) -> Result<(), RuntimeError> {
+        // The callee frame has been popped, release the memory of its WASM instance
+        let current_depth = api.kernel_get_current_depth();
+        api.kernel_get_system()
+            .modules
+            .limits
+            .wasm_memory_pages
+            .retain(|depth, _| *depth <= current_depth);
 
         Ok(())
     }
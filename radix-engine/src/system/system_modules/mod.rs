@@ -1,8 +1,11 @@
 pub mod auth;
 pub mod costing;
+pub mod debugger;
 pub mod execution_trace;
+pub mod extension;
 pub mod kernel_trace;
 pub mod limits;
+pub mod span_trace;
 pub mod transaction_runtime;
 
 mod module_mixer;
@@ -18,10 +18,15 @@ use crate::system::system_modules::auth::AuthModule;
 use crate::system::system_modules::costing::CostingModule;
 use crate::system::system_modules::costing::FeeTable;
 use crate::system::system_modules::costing::SystemLoanFeeReserve;
+use crate::system::system_modules::debugger::{DebuggerModule, TransactionDebugger};
 use crate::system::system_modules::execution_trace::ExecutionTraceModule;
+use crate::system::system_modules::extension::{SystemModuleExtension, SystemModuleExtensions};
 use crate::system::system_modules::kernel_trace::KernelTraceModule;
 use crate::system::system_modules::limits::{LimitsModule, TransactionLimitsConfig};
-use crate::system::system_modules::transaction_runtime::{Event, TransactionRuntimeModule};
+use crate::system::system_modules::span_trace::SpanTraceModule;
+use crate::system::system_modules::transaction_runtime::{
+    Event, PendingEventNotification, TransactionRuntimeModule,
+};
 use crate::transaction::ExecutionConfig;
 use crate::types::*;
 use bitflags::bitflags;
@@ -46,6 +51,9 @@ bitflags! {
 
         // Execution trace, for preview only
         const EXECUTION_TRACE = 0x01 << 6;
+
+        // Span trace, for profiling in developer tooling only
+        const SPAN_TRACE = 0x01 << 7;
     }
 }
 
@@ -88,6 +96,9 @@ pub struct SystemModuleMixer {
     pub(super) auth: AuthModule,
     pub(crate) transaction_runtime: TransactionRuntimeModule,
     pub(super) execution_trace: ExecutionTraceModule,
+    pub(super) span_trace: SpanTraceModule,
+    pub(super) debugger: Option<DebuggerModule>,
+    pub(super) extensions: SystemModuleExtensions,
 }
 
 // Macro generates default modules dispatches call based on passed function name and arguments.
@@ -114,6 +125,9 @@ macro_rules! internal_call_dispatch {
             if modules.contains(EnabledModules::EXECUTION_TRACE) {
                 ExecutionTraceModule::[< $fn >]($($param, )*)?;
             }
+            if modules.contains(EnabledModules::SPAN_TRACE) {
+                SpanTraceModule::[< $fn >]($($param, )*)?;
+            }
             Ok(())
         }
     }};
@@ -147,15 +161,20 @@ impl SystemModuleMixer {
                 storage_cost_breakdown: index_map_new(),
                 on_apply_cost: Default::default(),
             },
-            auth: AuthModule {
-                params: auth_zone_params.clone(),
-            },
+            auth: AuthModule::new(auth_zone_params.clone()),
             limits: LimitsModule::new(TransactionLimitsConfig {
                 max_heap_substate_total_bytes: execution_config.max_heap_substate_total_bytes,
                 max_track_substate_total_bytes: execution_config.max_track_substate_total_bytes,
+                max_node_substate_count: execution_config.max_node_substate_count,
+                max_node_substate_total_bytes: execution_config.max_node_substate_total_bytes,
+                max_wasm_memory_pages_per_call_frame: execution_config
+                    .max_wasm_memory_pages_per_call_frame,
+                max_wasm_memory_pages_per_transaction: execution_config
+                    .max_wasm_memory_pages_per_transaction,
                 max_substate_key_size: execution_config.max_substate_key_size,
                 max_substate_value_size: execution_config.max_substate_value_size,
-                max_invoke_payload_size: execution_config.max_invoke_input_size,
+                max_invoke_input_size: execution_config.max_invoke_input_size,
+                max_invoke_output_size: execution_config.max_invoke_output_size,
                 max_number_of_logs: execution_config.max_number_of_logs,
                 max_number_of_events: execution_config.max_number_of_events,
                 max_event_size: execution_config.max_event_size,
@@ -163,25 +182,52 @@ impl SystemModuleMixer {
                 max_panic_message_size: execution_config.max_panic_message_size,
             }),
             execution_trace: ExecutionTraceModule::new(execution_config.max_execution_trace_depth),
+            span_trace: SpanTraceModule::new(
+                tx_hash,
+                execution_config.deterministic_state_version.is_none(),
+            ),
             transaction_runtime: TransactionRuntimeModule {
                 network_definition,
                 tx_hash,
+                deterministic_state_version: execution_config.deterministic_state_version,
                 next_id: 0,
                 logs: Vec::new(),
                 events: Vec::new(),
+                event_notifications: Vec::new(),
                 replacements: index_map_new(),
+                address_reservations: index_map_new(),
+                fail_on_unused_address_reservations: execution_config
+                    .fail_on_unused_address_reservations,
             },
+            debugger: None,
+            extensions: SystemModuleExtensions::default(),
         }
     }
 
+    pub fn set_debugger(&mut self, debugger: Box<dyn TransactionDebugger>) {
+        self.debugger = Some(DebuggerModule::new(debugger));
+    }
+
+    pub fn add_extension(&mut self, extension: Box<dyn SystemModuleExtension>) {
+        self.extensions.add(extension);
+    }
+
     pub fn unpack(
         self,
     ) -> (
         CostingModule,
         TransactionRuntimeModule,
         ExecutionTraceModule,
+        AuthModule,
+        SpanTraceModule,
     ) {
-        (self.costing, self.transaction_runtime, self.execution_trace)
+        (
+            self.costing,
+            self.transaction_runtime,
+            self.execution_trace,
+            self.auth,
+            self.span_trace,
+        )
     }
 }
 
@@ -195,6 +241,11 @@ impl InitSystemModule for SystemModuleMixer {
     fn on_init(&mut self) -> Result<(), RuntimeError> {
         let modules: EnabledModules = self.enabled_modules;
 
+        // Enable span trace
+        if modules.contains(EnabledModules::SPAN_TRACE) {
+            self.span_trace.on_init()?;
+        }
+
         // Enable execution trace
         if modules.contains(EnabledModules::EXECUTION_TRACE) {
             self.execution_trace.on_init()?;
@@ -240,7 +291,15 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SystemModuleMixe
         api: &mut Y,
         invocation: &KernelInvocation<Actor>,
     ) -> Result<(), RuntimeError> {
-        internal_call_dispatch!(api.kernel_get_system(), before_invoke(api, invocation))
+        if api.kernel_get_system().modules.debugger.is_some() {
+            DebuggerModule::before_invoke(api, invocation)?;
+        }
+        internal_call_dispatch!(api.kernel_get_system(), before_invoke(api, invocation))?;
+        let depth = api.kernel_get_current_depth();
+        api.kernel_get_system()
+            .modules
+            .extensions
+            .dispatch(|extension| extension.before_invoke(invocation, depth))
     }
 
     #[trace_resources]
@@ -261,7 +320,15 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SystemModuleMixe
         api: &mut Y,
         output: &IndexedScryptoValue,
     ) -> Result<(), RuntimeError> {
-        internal_call_dispatch!(api.kernel_get_system(), after_invoke(api, output))
+        internal_call_dispatch!(api.kernel_get_system(), after_invoke(api, output))?;
+        if api.kernel_get_system().modules.debugger.is_some() {
+            DebuggerModule::after_invoke(api, output)?;
+        }
+        let depth = api.kernel_get_current_depth();
+        api.kernel_get_system()
+            .modules
+            .extensions
+            .dispatch(|extension| extension.after_invoke(output, depth))
     }
 
     #[trace_resources(log=entity_type)]
@@ -280,7 +347,11 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SystemModuleMixe
         api: &mut Y,
         event: &CreateNodeEvent,
     ) -> Result<(), RuntimeError> {
-        internal_call_dispatch!(api.kernel_get_system(), on_create_node(api, event))
+        internal_call_dispatch!(api.kernel_get_system(), on_create_node(api, event))?;
+        if api.kernel_get_system().modules.debugger.is_some() {
+            DebuggerModule::on_create_node(api, event)?;
+        }
+        Ok(())
     }
 
     #[trace_resources]
@@ -309,7 +380,11 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SystemModuleMixe
         api: &mut Y,
         event: &OpenSubstateEvent,
     ) -> Result<(), RuntimeError> {
-        internal_call_dispatch!(api.kernel_get_system(), on_open_substate(api, event))
+        internal_call_dispatch!(api.kernel_get_system(), on_open_substate(api, event))?;
+        api.kernel_get_system()
+            .modules
+            .extensions
+            .dispatch(|extension| extension.on_open_substate(event))
     }
 
     #[trace_resources]
@@ -330,7 +405,11 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SystemModuleMixe
         api: &mut Y,
         event: &ReadSubstateEvent,
     ) -> Result<(), RuntimeError> {
-        internal_call_dispatch!(api.kernel_get_system(), on_read_substate(api, event))
+        internal_call_dispatch!(api.kernel_get_system(), on_read_substate(api, event))?;
+        api.kernel_get_system()
+            .modules
+            .extensions
+            .dispatch(|extension| extension.on_read_substate(event))
     }
 
     #[trace_resources]
@@ -338,7 +417,11 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SystemModuleMixe
         api: &mut Y,
         event: &WriteSubstateEvent,
     ) -> Result<(), RuntimeError> {
-        internal_call_dispatch!(api.kernel_get_system(), on_write_substate(api, event))
+        internal_call_dispatch!(api.kernel_get_system(), on_write_substate(api, event))?;
+        api.kernel_get_system()
+            .modules
+            .extensions
+            .dispatch(|extension| extension.on_write_substate(event))
     }
 
     #[trace_resources]
@@ -346,7 +429,11 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SystemModuleMixe
         api: &mut Y,
         event: &CloseSubstateEvent,
     ) -> Result<(), RuntimeError> {
-        internal_call_dispatch!(api.kernel_get_system(), on_close_substate(api, event))
+        internal_call_dispatch!(api.kernel_get_system(), on_close_substate(api, event))?;
+        api.kernel_get_system()
+            .modules
+            .extensions
+            .dispatch(|extension| extension.on_close_substate(event))
     }
 
     #[trace_resources]
@@ -473,18 +560,18 @@ impl SystemModuleMixer {
         AuthModule::on_call_function_finish(api, auth_zone)
     }
 
-    pub fn add_log(&mut self, level: Level, message: String) -> Result<(), RuntimeError> {
+    pub fn add_log(&mut self, record: LogRecord) -> Result<(), RuntimeError> {
         if self.enabled_modules.contains(EnabledModules::LIMITS) {
             if self.transaction_runtime.logs.len() >= self.limits.config().max_number_of_logs {
                 return Err(RuntimeError::SystemModuleError(
                     SystemModuleError::TransactionLimitsError(TransactionLimitsError::TooManyLogs),
                 ));
             }
-            if message.len() > self.limits.config().max_log_size {
+            if record.size() > self.limits.config().max_log_size {
                 return Err(RuntimeError::SystemModuleError(
                     SystemModuleError::TransactionLimitsError(
                         TransactionLimitsError::LogSizeTooLarge {
-                            actual: message.len(),
+                            actual: record.size(),
                             max: self.limits.config().max_log_size,
                         },
                     ),
@@ -496,7 +583,7 @@ impl SystemModuleMixer {
             .enabled_modules
             .contains(EnabledModules::TRANSACTION_RUNTIME)
         {
-            self.transaction_runtime.add_log(level, message);
+            self.transaction_runtime.add_log(record);
         }
 
         Ok(())
@@ -563,6 +650,43 @@ impl SystemModuleMixer {
         Ok(())
     }
 
+    pub fn set_abort_error(&mut self, size: usize) -> Result<(), RuntimeError> {
+        if self.enabled_modules.contains(EnabledModules::LIMITS) {
+            if size > self.limits.config().max_panic_message_size {
+                return Err(RuntimeError::SystemModuleError(
+                    SystemModuleError::TransactionLimitsError(
+                        TransactionLimitsError::PanicMessageSizeTooLarge {
+                            actual: size,
+                            max: self.limits.config().max_panic_message_size,
+                        },
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn add_event_notification(&mut self, notification: PendingEventNotification) {
+        if self
+            .enabled_modules
+            .contains(EnabledModules::TRANSACTION_RUNTIME)
+        {
+            self.transaction_runtime.add_event_notification(notification)
+        }
+    }
+
+    pub fn take_event_notifications(&mut self, depth: usize) -> Vec<PendingEventNotification> {
+        if self
+            .enabled_modules
+            .contains(EnabledModules::TRANSACTION_RUNTIME)
+        {
+            self.transaction_runtime.take_event_notifications(depth)
+        } else {
+            Vec::new()
+        }
+    }
+
     pub fn add_replacement(&mut self, old: (NodeId, ModuleId), new: (NodeId, ModuleId)) {
         if self
             .enabled_modules
@@ -572,6 +696,40 @@ impl SystemModuleMixer {
         }
     }
 
+    pub fn add_address_reservation(
+        &mut self,
+        reservation: NodeId,
+        global_address: GlobalAddress,
+        blueprint_id: BlueprintId,
+    ) {
+        if self
+            .enabled_modules
+            .contains(EnabledModules::TRANSACTION_RUNTIME)
+        {
+            self.transaction_runtime.add_address_reservation(
+                reservation,
+                global_address,
+                blueprint_id,
+            )
+        }
+    }
+
+    pub fn consume_address_reservation(&mut self, reservation: &NodeId, consumed_by: NodeId) {
+        if self
+            .enabled_modules
+            .contains(EnabledModules::TRANSACTION_RUNTIME)
+        {
+            self.transaction_runtime
+                .consume_address_reservation(reservation, consumed_by)
+        }
+    }
+
+    pub fn fail_on_unused_address_reservations(&self) -> bool {
+        self.enabled_modules
+            .contains(EnabledModules::TRANSACTION_RUNTIME)
+            && self.transaction_runtime.fail_on_unused_address_reservations
+    }
+
     pub fn fee_reserve(&mut self) -> Option<&SystemLoanFeeReserve> {
         if self.enabled_modules.contains(EnabledModules::COSTING) {
             Some(&self.costing.fee_reserve)
@@ -669,14 +827,58 @@ impl SystemModuleMixer {
         }
     }
 
+    pub fn apply_storage_refund(&mut self, size_decrease: usize) -> Result<(), RuntimeError> {
+        if self.enabled_modules.contains(EnabledModules::COSTING) {
+            self.costing.apply_storage_refund(size_decrease)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn check_node_state_size(
+        &self,
+        node_id: &NodeId,
+        state_size: &NodeStateSize,
+    ) -> Result<(), RuntimeError> {
+        if self.enabled_modules.contains(EnabledModules::LIMITS) {
+            self.limits.process_node_state_size(node_id, state_size)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn check_invoke_output_size(
+        &self,
+        actor: &Actor,
+        output_size: usize,
+    ) -> Result<(), RuntimeError> {
+        if self.enabled_modules.contains(EnabledModules::LIMITS) {
+            self.limits.process_invoke_output_size(actor, output_size)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn check_wasm_memory(&mut self, depth: usize, pages: u32) -> Result<(), RuntimeError> {
+        if self.enabled_modules.contains(EnabledModules::LIMITS) {
+            self.limits.process_wasm_memory(depth, pages)
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn lock_fee(
         &mut self,
         vault_id: NodeId,
         locked_fee: LiquidFungibleResource,
         contingent: bool,
+        refund_vault_id: Option<NodeId>,
     ) {
         if self.enabled_modules.contains(EnabledModules::COSTING) {
-            self.costing.lock_fee(vault_id, locked_fee, contingent);
+            self.extensions
+                .on_lock_fee(&vault_id, locked_fee.amount(), contingent);
+            self.costing
+                .lock_fee(vault_id, locked_fee, contingent, refund_vault_id);
         } else {
             panic!("Fungible Vault Application layer should prevent call to credit if costing not enabled");
         }
@@ -686,7 +888,7 @@ impl SystemModuleMixer {
         &self.transaction_runtime.events
     }
 
-    pub fn logs(&self) -> &Vec<(Level, String)> {
+    pub fn logs(&self) -> &Vec<LogRecord> {
         &self.transaction_runtime.logs
     }
 }
@@ -0,0 +1,423 @@
+use crate::errors::RuntimeError;
+use crate::kernel::kernel_api::{KernelApi, KernelInternalApi, KernelInvocation};
+use crate::kernel::kernel_callback_api::{
+    CloseSubstateEvent, CreateNodeEvent, DropNodeEvent, OpenSubstateEvent, ReadSubstateEvent,
+    WriteSubstateEvent,
+};
+use crate::system::actor::{Actor, BlueprintHookActor, FunctionActor, MethodActor};
+use crate::system::module::{InitSystemModule, SystemModule};
+use crate::system::system_callback::SystemConfig;
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::types::*;
+use radix_engine_interface::crypto::Hash;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub enum SpanKind {
+    /// Covers a kernel invocation, from the moment the callee is invoked until it returns.
+    CallFrame,
+    /// Covers a single kernel operation of the enclosing call frame. Node creation and removal
+    /// and substate opening have a duration; reads, writes and closes are recorded as instants.
+    KernelOperation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum SpanAttributeValue {
+    String(String),
+    Int(i64),
+}
+
+/// A single span, laid out like an OTLP `Span` so that it maps field-by-field onto the
+/// OpenTelemetry protocol.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct TraceSpan {
+    /// Unique within the trace and never zero, which OTLP reserves for "no span".
+    pub span_id: u64,
+    pub parent_span_id: Option<u64>,
+    pub name: String,
+    pub kind: SpanKind,
+    /// Wall clock times, only available when compiled with `std` (zero otherwise).
+    pub start_time_unix_nano: u64,
+    pub end_time_unix_nano: u64,
+    pub attributes: Vec<(String, SpanAttributeValue)>,
+}
+
+impl TraceSpan {
+    pub fn attribute(&self, key: &str) -> Option<&SpanAttributeValue> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+}
+
+/// The spans recorded while executing a transaction, ordered by start time (so that every span
+/// comes after its parent).
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct SpanTrace {
+    /// The first 16 bytes of the transaction intent hash, as OTLP trace ids are 16 bytes long.
+    pub trace_id: [u8; 16],
+    pub spans: Vec<TraceSpan>,
+}
+
+impl SpanTrace {
+    pub fn children(&self, span_id: u64) -> impl Iterator<Item = &TraceSpan> {
+        self.spans
+            .iter()
+            .filter(move |span| span.parent_span_id == Some(span_id))
+    }
+}
+
+pub const SPAN_ATTRIBUTE_CALL_DEPTH: &str = "radix.call_depth";
+pub const SPAN_ATTRIBUTE_NODE_ID: &str = "radix.node_id";
+pub const SPAN_ATTRIBUTE_INPUT_SIZE: &str = "radix.input_size";
+pub const SPAN_ATTRIBUTE_OUTPUT_SIZE: &str = "radix.output_size";
+pub const SPAN_ATTRIBUTE_SUBSTATE_SIZE: &str = "radix.substate_size";
+/// The execution cost units consumed while the span was open. For instant kernel operations,
+/// these are the units consumed since any previous span started or ended, which is where the
+/// operation itself gets charged.
+pub const SPAN_ATTRIBUTE_EXECUTION_COST_UNITS: &str = "radix.execution_cost_units";
+
+struct OpenSpan {
+    index: usize,
+    start_cost_units: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpanTraceModule {
+    trace_id: [u8; 16],
+    spans: Vec<TraceSpan>,
+    open_spans: Vec<(usize, u32)>,
+    last_cost_units: u32,
+    /// When false (e.g. in deterministic simulation), all span timestamps are left at zero.
+    record_timestamps: bool,
+}
+
+impl SpanTraceModule {
+    pub fn new(tx_hash: Hash, record_timestamps: bool) -> Self {
+        let mut trace_id = [0u8; 16];
+        trace_id.copy_from_slice(&tx_hash.0[..16]);
+        Self {
+            trace_id,
+            spans: Vec::new(),
+            open_spans: Vec::new(),
+            last_cost_units: 0,
+            record_timestamps,
+        }
+    }
+
+    pub fn finalize(mut self) -> SpanTrace {
+        let now = self.now();
+        let cost_units = self.last_cost_units;
+        while let Some(open_span) = self.pop() {
+            self.close(open_span, now, cost_units, Vec::new());
+        }
+        SpanTrace {
+            trace_id: self.trace_id,
+            spans: self.spans,
+        }
+    }
+
+    fn open(
+        &mut self,
+        name: String,
+        kind: SpanKind,
+        cost_units: u32,
+        attributes: Vec<(String, SpanAttributeValue)>,
+    ) {
+        let index = self.spans.len();
+        let parent_span_id = self
+            .open_spans
+            .last()
+            .map(|(parent, _)| self.spans[*parent].span_id);
+        self.spans.push(TraceSpan {
+            span_id: index as u64 + 1,
+            parent_span_id,
+            name,
+            kind,
+            start_time_unix_nano: self.now(),
+            end_time_unix_nano: 0,
+            attributes,
+        });
+        self.open_spans.push((index, cost_units));
+        self.last_cost_units = cost_units;
+    }
+
+    fn pop(&mut self) -> Option<OpenSpan> {
+        self.open_spans
+            .pop()
+            .map(|(index, start_cost_units)| OpenSpan {
+                index,
+                start_cost_units,
+            })
+    }
+
+    fn close(
+        &mut self,
+        open_span: OpenSpan,
+        end_time_unix_nano: u64,
+        cost_units: u32,
+        attributes: Vec<(String, SpanAttributeValue)>,
+    ) {
+        let span = &mut self.spans[open_span.index];
+        span.end_time_unix_nano = end_time_unix_nano;
+        span.attributes.extend(attributes);
+        span.attributes.push((
+            SPAN_ATTRIBUTE_EXECUTION_COST_UNITS.to_string(),
+            SpanAttributeValue::Int(cost_units.saturating_sub(open_span.start_cost_units) as i64),
+        ));
+        self.last_cost_units = cost_units;
+    }
+
+    /// Closes the innermost open span of the given kind, along with any kernel operation span left
+    /// open above it (e.g. by an operation which failed half way). A kernel operation never closes
+    /// the span of its call frame.
+    fn close_innermost(
+        &mut self,
+        kind: SpanKind,
+        cost_units: u32,
+        attributes: Vec<(String, SpanAttributeValue)>,
+    ) {
+        let now = self.now();
+        while let Some((index, _)) = self.open_spans.last() {
+            let open_kind = self.spans[*index].kind;
+            if open_kind == SpanKind::CallFrame && kind == SpanKind::KernelOperation {
+                return;
+            }
+            let open_span = self.pop().unwrap();
+            if open_kind == kind {
+                self.close(open_span, now, cost_units, attributes);
+                return;
+            }
+            self.close(open_span, now, cost_units, Vec::new());
+        }
+    }
+
+    fn instant(
+        &mut self,
+        name: &str,
+        cost_units: u32,
+        attributes: Vec<(String, SpanAttributeValue)>,
+    ) {
+        let start_cost_units = self.last_cost_units;
+        self.open(
+            name.to_string(),
+            SpanKind::KernelOperation,
+            start_cost_units,
+            attributes,
+        );
+        let open_span = self.pop().unwrap();
+        let now = self.spans[open_span.index].start_time_unix_nano;
+        self.close(open_span, now, cost_units, Vec::new());
+    }
+
+    fn now(&self) -> u64 {
+        if self.record_timestamps {
+            now_unix_nano()
+        } else {
+            0
+        }
+    }
+}
+
+fn now_unix_nano() -> u64 {
+    #[cfg(feature = "std")]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or_default()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        0
+    }
+}
+
+fn span_name(actor: &Actor) -> String {
+    match actor {
+        Actor::Root => "root".to_string(),
+        Actor::Method(MethodActor { ident, .. }) | Actor::Function(FunctionActor { ident, .. }) => {
+            format!(
+                "{}::{}",
+                actor.blueprint_id().unwrap().blueprint_name,
+                ident
+            )
+        }
+        Actor::BlueprintHook(BlueprintHookActor {
+            blueprint_id, hook, ..
+        }) => format!("{}::{:?}", blueprint_id.blueprint_name, hook),
+    }
+}
+
+fn execution_cost_units<Y: KernelInternalApi<SystemConfig<V>>, V: SystemCallbackObject>(
+    api: &mut Y,
+) -> u32 {
+    api.kernel_get_system()
+        .modules
+        .costing()
+        .map(|costing| costing.fee_reserve.execution_cost_units_consumed())
+        .unwrap_or_default()
+}
+
+fn int_attribute(key: &str, value: usize) -> (String, SpanAttributeValue) {
+    (key.to_string(), SpanAttributeValue::Int(value as i64))
+}
+
+fn node_id_attribute(node_id: &NodeId) -> (String, SpanAttributeValue) {
+    (
+        SPAN_ATTRIBUTE_NODE_ID.to_string(),
+        SpanAttributeValue::String(node_id.to_hex()),
+    )
+}
+
+impl InitSystemModule for SpanTraceModule {}
+
+impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SpanTraceModule {
+    fn before_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        invocation: &KernelInvocation<Actor>,
+    ) -> Result<(), RuntimeError> {
+        let cost_units = execution_cost_units(api);
+        let mut attributes = vec![
+            int_attribute(
+                SPAN_ATTRIBUTE_CALL_DEPTH,
+                api.kernel_get_current_depth() + 1,
+            ),
+            int_attribute(SPAN_ATTRIBUTE_INPUT_SIZE, invocation.len()),
+        ];
+        attributes.extend(
+            invocation
+                .call_frame_data
+                .node_id()
+                .map(|node_id| node_id_attribute(&node_id)),
+        );
+        api.kernel_get_system().modules.span_trace.open(
+            span_name(&invocation.call_frame_data),
+            SpanKind::CallFrame,
+            cost_units,
+            attributes,
+        );
+        Ok(())
+    }
+
+    fn after_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        output: &IndexedScryptoValue,
+    ) -> Result<(), RuntimeError> {
+        let cost_units = execution_cost_units(api);
+        api.kernel_get_system().modules.span_trace.close_innermost(
+            SpanKind::CallFrame,
+            cost_units,
+            vec![int_attribute(SPAN_ATTRIBUTE_OUTPUT_SIZE, output.len())],
+        );
+        Ok(())
+    }
+
+    fn on_create_node<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &CreateNodeEvent,
+    ) -> Result<(), RuntimeError> {
+        let cost_units = execution_cost_units(api);
+        let span_trace = &mut api.kernel_get_system().modules.span_trace;
+        match event {
+            CreateNodeEvent::Start(node_id, _) => span_trace.open(
+                "create_node".to_string(),
+                SpanKind::KernelOperation,
+                cost_units,
+                vec![node_id_attribute(node_id)],
+            ),
+            CreateNodeEvent::IOAccess(..) => {}
+            CreateNodeEvent::End(..) => {
+                span_trace.close_innermost(SpanKind::KernelOperation, cost_units, Vec::new())
+            }
+        }
+        Ok(())
+    }
+
+    fn on_drop_node<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &DropNodeEvent,
+    ) -> Result<(), RuntimeError> {
+        let cost_units = execution_cost_units(api);
+        let span_trace = &mut api.kernel_get_system().modules.span_trace;
+        match event {
+            DropNodeEvent::Start(node_id) => span_trace.open(
+                "drop_node".to_string(),
+                SpanKind::KernelOperation,
+                cost_units,
+                vec![node_id_attribute(node_id)],
+            ),
+            DropNodeEvent::IOAccess(..) => {}
+            DropNodeEvent::End(..) => {
+                span_trace.close_innermost(SpanKind::KernelOperation, cost_units, Vec::new())
+            }
+        }
+        Ok(())
+    }
+
+    fn on_open_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &OpenSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        let cost_units = execution_cost_units(api);
+        let span_trace = &mut api.kernel_get_system().modules.span_trace;
+        match event {
+            OpenSubstateEvent::Start { node_id, .. } => span_trace.open(
+                "open_substate".to_string(),
+                SpanKind::KernelOperation,
+                cost_units,
+                vec![node_id_attribute(node_id)],
+            ),
+            OpenSubstateEvent::IOAccess(..) => {}
+            OpenSubstateEvent::End { size, .. } => span_trace.close_innermost(
+                SpanKind::KernelOperation,
+                cost_units,
+                vec![int_attribute(SPAN_ATTRIBUTE_SUBSTATE_SIZE, *size)],
+            ),
+        }
+        Ok(())
+    }
+
+    fn on_read_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &ReadSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        if let ReadSubstateEvent::OnRead { value, .. } = event {
+            let cost_units = execution_cost_units(api);
+            api.kernel_get_system().modules.span_trace.instant(
+                "read_substate",
+                cost_units,
+                vec![int_attribute(SPAN_ATTRIBUTE_SUBSTATE_SIZE, value.len())],
+            );
+        }
+        Ok(())
+    }
+
+    fn on_write_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &WriteSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        if let WriteSubstateEvent::Start { value, .. } = event {
+            let cost_units = execution_cost_units(api);
+            api.kernel_get_system().modules.span_trace.instant(
+                "write_substate",
+                cost_units,
+                vec![int_attribute(SPAN_ATTRIBUTE_SUBSTATE_SIZE, value.len())],
+            );
+        }
+        Ok(())
+    }
+
+    fn on_close_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _event: &CloseSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        let cost_units = execution_cost_units(api);
+        api.kernel_get_system().modules.span_trace.instant(
+            "close_substate",
+            cost_units,
+            Vec::new(),
+        );
+        Ok(())
+    }
+}
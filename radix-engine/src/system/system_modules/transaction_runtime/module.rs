@@ -1,7 +1,7 @@
 use crate::kernel::kernel_callback_api::KernelCallbackObject;
 use crate::system::module::{InitSystemModule, SystemModule};
 use crate::types::*;
-use radix_engine_interface::api::actor_api::EventFlags;
+use radix_engine_interface::api::actor_api::{EventFlags, EventNotification};
 use radix_engine_interface::api::ModuleId;
 use radix_engine_interface::crypto::Hash;
 
@@ -21,20 +21,59 @@ impl Event {
     }
 }
 
+/// An event notification waiting to be delivered to a subscriber, once the call which emitted
+/// the event returns.
+#[derive(Debug, Clone)]
+pub struct PendingEventNotification {
+    /// The call frame depth of the emitter
+    pub depth: usize,
+    pub subscriber: GlobalAddress,
+    pub hook_method: String,
+    pub notification: EventNotification,
+}
+
+/// A global address reservation allocated during a transaction, and what became of it.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct AddressReservationRecord {
+    /// The owned reservation object
+    pub reservation: NodeId,
+    pub global_address: GlobalAddress,
+    pub blueprint_id: BlueprintId,
+    /// The node globalized at the reserved address, if the reservation has been consumed
+    pub consumed_by: Option<NodeId>,
+}
+
 #[derive(Debug, Clone)]
 pub struct TransactionRuntimeModule {
     pub network_definition: NetworkDefinition,
     pub tx_hash: Hash,
+    /// Set in deterministic simulation mode, in which RUIDs are also derived from the state
+    /// version the transaction is executed against.
+    pub deterministic_state_version: Option<u64>,
     pub next_id: u32,
-    pub logs: Vec<(Level, String)>,
+    pub logs: Vec<LogRecord>,
     pub events: Vec<Event>,
+    pub event_notifications: Vec<PendingEventNotification>,
     pub replacements: IndexMap<(NodeId, ModuleId), (NodeId, ModuleId)>,
+    /// Keyed by the reservation object, in allocation order
+    pub address_reservations: IndexMap<NodeId, AddressReservationRecord>,
+    pub fail_on_unused_address_reservations: bool,
 }
 
 impl TransactionRuntimeModule {
     pub fn generate_ruid(&mut self) -> [u8; 32] {
+        let seed = match self.deterministic_state_version {
+            Some(state_version) => {
+                let mut seed = [0u8; 40];
+                (&mut seed[..32]).copy_from_slice(self.tx_hash.as_slice());
+                seed[32..].copy_from_slice(&state_version.to_le_bytes());
+                hash(seed)
+            }
+            None => self.tx_hash,
+        };
+
         let mut bytes = [0u8; 36];
-        (&mut bytes[..32]).copy_from_slice(self.tx_hash.as_slice());
+        (&mut bytes[..32]).copy_from_slice(seed.as_slice());
         bytes[32..].copy_from_slice(&self.next_id.to_le_bytes());
 
         self.next_id += 1;
@@ -42,22 +81,67 @@ impl TransactionRuntimeModule {
         hash(bytes).0
     }
 
-    pub fn add_log(&mut self, level: Level, message: String) {
-        self.logs.push((level, message))
+    pub fn add_log(&mut self, record: LogRecord) {
+        self.logs.push(record)
     }
 
     pub fn add_event(&mut self, event: Event) {
         self.events.push(event)
     }
 
+    pub fn add_event_notification(&mut self, notification: PendingEventNotification) {
+        self.event_notifications.push(notification)
+    }
+
+    pub fn take_event_notifications(&mut self, depth: usize) -> Vec<PendingEventNotification> {
+        let (taken, remaining) = self
+            .event_notifications
+            .drain(..)
+            .partition(|notification| notification.depth == depth);
+        self.event_notifications = remaining;
+        taken
+    }
+
     pub fn add_replacement(&mut self, old: (NodeId, ModuleId), new: (NodeId, ModuleId)) {
         self.replacements.insert(old, new);
     }
 
+    pub fn add_address_reservation(
+        &mut self,
+        reservation: NodeId,
+        global_address: GlobalAddress,
+        blueprint_id: BlueprintId,
+    ) {
+        self.address_reservations.insert(
+            reservation,
+            AddressReservationRecord {
+                reservation,
+                global_address,
+                blueprint_id,
+                consumed_by: None,
+            },
+        );
+    }
+
+    pub fn consume_address_reservation(&mut self, reservation: &NodeId, consumed_by: NodeId) {
+        if let Some(record) = self.address_reservations.get_mut(reservation) {
+            record.consumed_by = Some(consumed_by);
+        }
+    }
+
+    /// Unlike events and logs, reservations are reported whether or not the transaction succeeds,
+    /// as failed transactions are where unused reservations show up.
+    pub fn take_address_reservations(&mut self) -> Vec<AddressReservationRecord> {
+        core::mem::take(&mut self.address_reservations)
+            .into_iter()
+            .map(|(_, record)| record)
+            .collect()
+    }
+
     pub fn finalize(
         self,
         is_success: bool,
-    ) -> (Vec<(EventTypeIdentifier, Vec<u8>)>, Vec<(Level, String)>) {
+    ) -> (Vec<(EventTypeIdentifier, Vec<u8>)>, Vec<LogRecord>) {
         let mut results = Vec::new();
 
         for Event {
@@ -107,10 +191,14 @@ mod tests {
                 "71f26aab5eec6679f67c71211aba9a3486cc8d24194d339385ee91ee5ca7b30d",
             )
             .unwrap(),
+            deterministic_state_version: None,
             next_id: 5,
             logs: Vec::new(),
             events: Vec::new(),
+            event_notifications: Vec::new(),
             replacements: index_map_new(),
+            address_reservations: index_map_new(),
+            fail_on_unused_address_reservations: false,
         };
         assert_eq!(
             NonFungibleLocalId::ruid(id.generate_ruid()).to_string(),
@@ -120,10 +208,14 @@ mod tests {
         let mut id = TransactionRuntimeModule {
             network_definition: NetworkDefinition::simulator(),
             tx_hash: Hash([0u8; 32]),
+            deterministic_state_version: None,
             next_id: 5,
             logs: Vec::new(),
             events: Vec::new(),
+            event_notifications: Vec::new(),
             replacements: index_map_new(),
+            address_reservations: index_map_new(),
+            fail_on_unused_address_reservations: false,
         };
         assert_eq!(
             NonFungibleLocalId::ruid(id.generate_ruid()).to_string(),
@@ -133,14 +225,41 @@ mod tests {
         let mut id = TransactionRuntimeModule {
             network_definition: NetworkDefinition::simulator(),
             tx_hash: Hash([255u8; 32]),
+            deterministic_state_version: None,
             next_id: 5,
             logs: Vec::new(),
             events: Vec::new(),
+            event_notifications: Vec::new(),
             replacements: index_map_new(),
+            address_reservations: index_map_new(),
+            fail_on_unused_address_reservations: false,
         };
         assert_eq!(
             NonFungibleLocalId::ruid(id.generate_ruid()).to_string(),
             "{04660ebc8e2a2b36-44a6553bd6a17a3a-ef14ce1fae4cb5bc-000811f979007003}"
         );
     }
+
+    #[test]
+    fn test_deterministic_ruid_gen_depends_on_state_version() {
+        let new_module = |deterministic_state_version| TransactionRuntimeModule {
+            network_definition: NetworkDefinition::simulator(),
+            tx_hash: Hash([0u8; 32]),
+            deterministic_state_version,
+            next_id: 5,
+            logs: Vec::new(),
+            events: Vec::new(),
+            event_notifications: Vec::new(),
+            replacements: index_map_new(),
+            address_reservations: index_map_new(),
+            fail_on_unused_address_reservations: false,
+        };
+
+        let ruid = new_module(None).generate_ruid();
+        let ruid_at_1 = new_module(Some(1)).generate_ruid();
+        let ruid_at_2 = new_module(Some(2)).generate_ruid();
+        assert_ne!(ruid_at_1, ruid);
+        assert_ne!(ruid_at_1, ruid_at_2);
+        assert_eq!(new_module(Some(1)).generate_ruid(), ruid_at_1);
+    }
 }
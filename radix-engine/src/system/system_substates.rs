@@ -209,3 +209,56 @@ impl<V> SortedIndexEntrySubstate<V> {
         }
     }
 }
+
+/// The feature which flags a global object as having event subscribers. It's added by the
+/// system on the first subscription to any of the object's events.
+pub const EVENT_SUBSCRIBERS_FEATURE: &str = "__event_subscribers";
+
+/// The subscribers of a single event of a global object, stored under the event name in the
+/// object's `EVENT_SUBSCRIPTIONS_PARTITION`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, ScryptoSbor)]
+pub struct EventSubscriptionsSubstate {
+    /// Maps each subscriber to the hook method called on it
+    pub subscribers: IndexMap<GlobalAddress, String>,
+}
+
+/// Identifies a rate limit counter in a global object's `RATE_LIMITS_PARTITION`.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct RateLimitKey {
+    pub name: String,
+    /// The caller whose calls are counted, if the rate limit is scoped per caller
+    pub caller: Option<GlobalCaller>,
+}
+
+/// The calls counted against a rate limit, stored under its [`RateLimitKey`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, ScryptoSbor)]
+pub struct RateLimitSubstate {
+    /// The number of calls counted in each epoch, oldest first. Epochs without calls are omitted,
+    /// so there are never more entries than the limit's maximum.
+    pub calls_per_epoch: Vec<(Epoch, u32)>,
+}
+
+impl RateLimitSubstate {
+    /// Forgets the epochs which have left the window ending at `current_epoch`, then counts a
+    /// call in `current_epoch` unless the window already holds `max` calls.
+    pub fn try_count_call(&mut self, current_epoch: Epoch, max: u32, window_epochs: u64) -> bool {
+        self.calls_per_epoch.retain(|(epoch, _)| {
+            epoch.number().saturating_add(window_epochs) > current_epoch.number()
+        });
+
+        let calls: u64 = self
+            .calls_per_epoch
+            .iter()
+            .map(|(_, calls)| *calls as u64)
+            .sum();
+        if calls >= max as u64 {
+            return false;
+        }
+
+        match self.calls_per_epoch.last_mut() {
+            Some((epoch, calls)) if *epoch == current_epoch => *calls += 1,
+            _ => self.calls_per_epoch.push((current_epoch, 1)),
+        }
+        true
+    }
+}
@@ -1,5 +1,5 @@
 use super::payload_validation::*;
-use crate::errors::{RuntimeError, SystemError};
+use crate::errors::{EventError, RuntimeError, SystemError, SystemModuleError};
 use crate::kernel::kernel_api::KernelApi;
 use crate::system::system::SystemService;
 use crate::system::system_callback::{SystemConfig, SystemLockData};
@@ -439,6 +439,27 @@ where
             scoped_type_id.clone(),
         ))
     }
+
+    pub fn get_event_schema(
+        &mut self,
+        blueprint_id: &BlueprintId,
+        event_name: &str,
+    ) -> Result<(Rc<VersionedScryptoSchema>, ScopedTypeId), RuntimeError> {
+        let blueprint_definition = self.get_blueprint_default_definition(blueprint_id.clone())?;
+        match blueprint_definition.interface.events.get(event_name) {
+            Some(BlueprintPayloadDef::Static(scoped_type_id)) => Ok((
+                self.get_schema(blueprint_id.package_address.as_node_id(), &scoped_type_id.0)?,
+                scoped_type_id.clone(),
+            )),
+            // The schema of a generic event is only known to the instance emitting it
+            Some(BlueprintPayloadDef::Generic(..)) | None => Err(RuntimeError::SystemModuleError(
+                SystemModuleError::EventError(Box::new(EventError::SchemaNotFoundError {
+                    blueprint: blueprint_id.clone(),
+                    event_name: event_name.to_string(),
+                })),
+            )),
+        }
+    }
 }
 
 pub struct SystemMapper;
@@ -98,6 +98,18 @@ pub trait CommitableSubstateStore {
         .unwrap()
     }
 
+    /// Loads the given substates from the database in a single batched access, ahead of them
+    /// being read.
+    ///
+    /// This is a hint only: reads of prefetched substates behave (and report IO access) exactly
+    /// as if they went to the database. Substates which are already tracked are skipped.
+    fn prefetch_substates(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        substate_keys: &[SubstateKey],
+    );
+
     fn get_substate<E, F: FnMut(IOAccess) -> Result<(), E>>(
         &mut self,
         node_id: &NodeId,
@@ -322,7 +334,24 @@ impl StoreCommit {
                     0
                 }
             }
-            StoreCommit::Delete { .. } => 0, // TODO: refund?
+            StoreCommit::Delete { .. } => 0,
+        }
+    }
+
+    pub fn len_decrease(&self) -> usize {
+        match self {
+            StoreCommit::Insert { .. } => 0,
+            StoreCommit::Update { size, old_size, .. } => {
+                if *old_size > *size {
+                    *old_size - *size
+                } else {
+                    0
+                }
+            }
+            StoreCommit::Delete {
+                canonical_substate_key,
+                old_size,
+            } => canonical_substate_key.len() + *old_size,
         }
     }
 }
@@ -1,6 +1,8 @@
 pub mod interface;
 pub mod legacy_state_updates;
+pub mod read_write_set;
 pub mod state_updates;
+pub mod substate_reads;
 pub mod track;
 pub mod utils;
 
@@ -9,5 +11,7 @@ mod test;
 
 pub use interface::*;
 pub use legacy_state_updates::*;
+pub use read_write_set::*;
 pub use state_updates::*;
+pub use substate_reads::*;
 pub use track::*;
@@ -0,0 +1,83 @@
+use crate::track::{TrackedNode, TrackedSubstateValue};
+use crate::types::*;
+
+/// The substates a committed transaction read and wrote, at node/partition/key granularity.
+///
+/// Two transactions can be executed in either order with the same outcome if neither one
+/// writes anything the other one reads or writes (see [`ReadWriteSet::conflicts_with`]).
+#[derive(Debug, Clone, PartialEq, Eq, Default, ScryptoSbor)]
+pub struct ReadWriteSet {
+    /// Substates read from the store, whether or not they existed
+    pub reads: IndexSet<(NodeId, PartitionNumber, SubstateKey)>,
+    /// Partitions iterated over, so any write to them may change what was read
+    pub partition_reads: IndexSet<(NodeId, PartitionNumber)>,
+    /// Substates set or deleted
+    pub writes: IndexSet<(NodeId, PartitionNumber, SubstateKey)>,
+    /// Partitions deleted as a whole
+    pub partition_deletions: IndexSet<(NodeId, PartitionNumber)>,
+}
+
+impl ReadWriteSet {
+    pub fn from_tracked_nodes(
+        tracked_nodes: &IndexMap<NodeId, TrackedNode>,
+        partition_deletions: &IndexSet<(NodeId, PartitionNumber)>,
+    ) -> Self {
+        let mut read_write_set = Self {
+            partition_deletions: partition_deletions.clone(),
+            ..Default::default()
+        };
+        for (node_id, tracked_node) in tracked_nodes {
+            for (partition_num, tracked_partition) in &tracked_node.tracked_partitions {
+                if tracked_partition.range_read > 0 && !tracked_node.is_new {
+                    read_write_set
+                        .partition_reads
+                        .insert((*node_id, *partition_num));
+                }
+                for tracked in tracked_partition.substates.values() {
+                    let (read, written) = match &tracked.substate_value {
+                        TrackedSubstateValue::ReadOnly(..) => (true, false),
+                        TrackedSubstateValue::ReadExistAndWrite(..)
+                        | TrackedSubstateValue::ReadNonExistAndWrite(..) => (true, true),
+                        TrackedSubstateValue::New(..) | TrackedSubstateValue::WriteOnly(..) => {
+                            (false, true)
+                        }
+                        TrackedSubstateValue::Garbage => (false, false),
+                    };
+                    let key = (*node_id, *partition_num, tracked.substate_key.clone());
+                    if read {
+                        read_write_set.reads.insert(key.clone());
+                    }
+                    if written {
+                        read_write_set.writes.insert(key);
+                    }
+                }
+            }
+        }
+        read_write_set
+    }
+
+    /// Whether either transaction writes something the other one reads or writes.
+    pub fn conflicts_with(&self, other: &ReadWriteSet) -> bool {
+        self.writes_overlap(other) || other.writes_overlap(self)
+    }
+
+    fn writes_overlap(&self, other: &ReadWriteSet) -> bool {
+        let touches_partition = |node_id: &NodeId, partition_num: &PartitionNumber| {
+            other.partition_reads.contains(&(*node_id, *partition_num))
+                || other.partition_deletions.contains(&(*node_id, *partition_num))
+        };
+
+        self.writes.iter().any(|key| {
+            other.reads.contains(key)
+                || other.writes.contains(key)
+                || touches_partition(&key.0, &key.1)
+        }) || self.partition_deletions.iter().any(|(node_id, partition_num)| {
+            touches_partition(node_id, partition_num)
+                || other
+                    .reads
+                    .iter()
+                    .chain(other.writes.iter())
+                    .any(|(n, p, _)| n == node_id && p == partition_num)
+        })
+    }
+}
@@ -0,0 +1,100 @@
+use crate::types::*;
+use radix_engine_store_interface::db_key_mapper::DatabaseKeyMapper;
+use radix_engine_store_interface::interface::{
+    DbPartitionKey, DbSortKey, DbSubstateKey, DbSubstateValue, PartitionEntry, SubstateDatabase,
+};
+use sbor::rust::cell::RefCell;
+
+/// A substate read from the database, identified by the same keys the state hash tree uses.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct SubstateRead {
+    pub partition_key: DbPartitionKey,
+    pub sort_key: DbSortKey,
+    /// The hash of the value which was read, or `None` if the substate did not exist
+    pub value_hash: Option<Hash>,
+}
+
+impl SubstateRead {
+    pub fn node_id_and_partition<M: DatabaseKeyMapper>(&self) -> (NodeId, PartitionNumber) {
+        M::from_db_partition_key(&self.partition_key)
+    }
+}
+
+/// A [`SubstateDatabase`] which records every substate read from the underlying database.
+///
+/// Partition iteration is recorded entry by entry, so only the entries actually consumed by
+/// the reader show up. Substates prefetched at the start of a method call are recorded even if
+/// the method ends up not reading them.
+pub struct SubstateReadRecorder<'s, S: SubstateDatabase> {
+    substate_db: &'s S,
+    reads: RefCell<IndexMap<(DbPartitionKey, DbSortKey), Option<Hash>>>,
+}
+
+impl<'s, S: SubstateDatabase> SubstateReadRecorder<'s, S> {
+    pub fn new(substate_db: &'s S) -> Self {
+        Self {
+            substate_db,
+            reads: RefCell::new(index_map_new()),
+        }
+    }
+
+    /// Returns the distinct substates read so far, in the order they were first read.
+    pub fn into_reads(self) -> Vec<SubstateRead> {
+        self.reads
+            .into_inner()
+            .into_iter()
+            .map(|((partition_key, sort_key), value_hash)| SubstateRead {
+                partition_key,
+                sort_key,
+                value_hash,
+            })
+            .collect()
+    }
+
+    fn record(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+        value: Option<&DbSubstateValue>,
+    ) {
+        self.reads
+            .borrow_mut()
+            .entry((partition_key.clone(), sort_key.clone()))
+            .or_insert_with(|| value.map(hash));
+    }
+}
+
+impl<'s, S: SubstateDatabase> SubstateDatabase for SubstateReadRecorder<'s, S> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        let value = self.substate_db.get_substate(partition_key, sort_key);
+        self.record(partition_key, sort_key, value.as_ref());
+        value
+    }
+
+    fn get_substates(&self, keys: &[DbSubstateKey]) -> Vec<Option<DbSubstateValue>> {
+        let values = self.substate_db.get_substates(keys);
+        for ((partition_key, sort_key), value) in keys.iter().zip(values.iter()) {
+            self.record(partition_key, sort_key, value.as_ref());
+        }
+        values
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        let partition_key = partition_key.clone();
+        Box::new(
+            self.substate_db
+                .list_entries_from(&partition_key, from_sort_key)
+                .inspect(move |(sort_key, value)| {
+                    self.record(&partition_key, sort_key, Some(value));
+                }),
+        )
+    }
+}
@@ -1,5 +1,13 @@
 use super::utils::OverlayingIterator;
+use crate::track::interface::{CommitableSubstateStore, IOAccess};
+use crate::track::track::Track;
 use crate::track::utils::OverlayingResultIterator;
+use crate::types::*;
+use radix_engine_store_interface::db_key_mapper::{DatabaseKeyMapper, SpreadPrefixKeyMapper};
+use radix_engine_store_interface::interface::{
+    DbPartitionKey, DbSortKey, DbSubstateKey, DbSubstateValue, PartitionEntry, SubstateDatabase,
+};
+use sbor::rust::cell::Cell;
 use sbor::rust::vec;
 use sbor::rust::vec::Vec;
 
@@ -137,3 +145,114 @@ fn underlying_error_returns_error() {
         vec![Ok((0, "bee")), Ok((1, "cat")), Err(()),]
     );
 }
+
+#[test]
+fn prefetched_substate_is_read_through_one_batched_access() {
+    let (database, node_id) = CountingDatabase::with_field(5u32);
+    let mut track = Track::<_, SpreadPrefixKeyMapper>::new(&database);
+
+    track.prefetch_substates(
+        &node_id,
+        MAIN_BASE_PARTITION,
+        &[SubstateKey::Field(0u8), SubstateKey::Field(1u8)],
+    );
+    let (value, io_accesses) = read_field(&mut track, &node_id);
+
+    assert_eq!(value, Some(5u32));
+    assert_eq!(database.batched_reads.get(), 1);
+    assert_eq!(database.single_reads.get(), 0);
+
+    // Reads of prefetched substates must report the same IO access as reads from the database,
+    // as costing depends on it
+    let (uncached_database, _) = CountingDatabase::with_field(5u32);
+    let mut uncached_track = Track::<_, SpreadPrefixKeyMapper>::new(&uncached_database);
+    let (_, uncached_io_accesses) = read_field(&mut uncached_track, &node_id);
+    assert_eq!(io_accesses, uncached_io_accesses);
+    assert_eq!(uncached_database.single_reads.get(), 1);
+}
+
+#[test]
+fn prefetch_skips_substates_which_are_already_tracked() {
+    let (database, node_id) = CountingDatabase::with_field(5u32);
+    let mut track = Track::<_, SpreadPrefixKeyMapper>::new(&database);
+    read_field(&mut track, &node_id);
+
+    track.prefetch_substates(&node_id, MAIN_BASE_PARTITION, &[SubstateKey::Field(0u8)]);
+
+    assert_eq!(database.batched_reads.get(), 0);
+    assert_eq!(database.single_reads.get(), 1);
+}
+
+fn read_field<S: SubstateDatabase>(
+    track: &mut Track<S, SpreadPrefixKeyMapper>,
+    node_id: &NodeId,
+) -> (Option<u32>, Vec<String>) {
+    let mut io_accesses = Vec::new();
+    let value = track
+        .get_substate(
+            node_id,
+            MAIN_BASE_PARTITION,
+            &SubstateKey::Field(0u8),
+            &mut |io_access: IOAccess| -> Result<(), ()> {
+                io_accesses.push(format!("{:?}", io_access));
+                Ok(())
+            },
+        )
+        .unwrap()
+        .map(|value| value.as_typed::<u32>().unwrap());
+    (value, io_accesses)
+}
+
+/// A database holding a single field substate, which counts how it is accessed
+struct CountingDatabase {
+    substates: IndexMap<DbSubstateKey, DbSubstateValue>,
+    single_reads: Cell<usize>,
+    batched_reads: Cell<usize>,
+}
+
+impl CountingDatabase {
+    fn with_field(value: u32) -> (Self, NodeId) {
+        let node_id = NodeId::new(
+            EntityType::InternalGenericComponent as u8,
+            &[1u8; NodeId::RID_LENGTH],
+        );
+        let key = (
+            SpreadPrefixKeyMapper::to_db_partition_key(&node_id, MAIN_BASE_PARTITION),
+            SpreadPrefixKeyMapper::to_db_sort_key(&SubstateKey::Field(0u8)),
+        );
+        let database = Self {
+            substates: indexmap!(key => scrypto_encode(&value).unwrap()),
+            single_reads: Cell::new(0),
+            batched_reads: Cell::new(0),
+        };
+        (database, node_id)
+    }
+}
+
+impl SubstateDatabase for CountingDatabase {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        self.single_reads.set(self.single_reads.get() + 1);
+        self.substates
+            .get(&(partition_key.clone(), sort_key.clone()))
+            .cloned()
+    }
+
+    fn get_substates(&self, keys: &[DbSubstateKey]) -> Vec<Option<DbSubstateValue>> {
+        self.batched_reads.set(self.batched_reads.get() + 1);
+        keys.iter()
+            .map(|key| self.substates.get(key).cloned())
+            .collect()
+    }
+
+    fn list_entries_from(
+        &self,
+        _partition_key: &DbPartitionKey,
+        _from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        Box::new(sbor::rust::iter::empty())
+    }
+}
@@ -11,7 +11,7 @@ use radix_engine_store_interface::db_key_mapper::SubstateKeyContent;
 use radix_engine_store_interface::interface::DbPartitionKey;
 use radix_engine_store_interface::{
     db_key_mapper::DatabaseKeyMapper,
-    interface::{DbSortKey, PartitionEntry, SubstateDatabase},
+    interface::{DbSortKey, DbSubstateKey, DbSubstateValue, PartitionEntry, SubstateDatabase},
 };
 use sbor::rust::collections::btree_map::Entry;
 use sbor::rust::iter::empty;
@@ -36,6 +36,10 @@ pub struct Track<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> {
 
     transient_substates: TransientSubstates,
 
+    /// Substates loaded ahead of their first read by `prefetch_substates`, consumed by
+    /// `get_substate_from_db`
+    prefetched_substates: IndexMap<DbSubstateKey, Option<DbSubstateValue>>,
+
     phantom_data: PhantomData<M>,
 }
 
@@ -47,6 +51,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> Track<'s, S, M> {
             tracked_nodes: index_map_new(),
             deleted_partitions: index_set_new(),
             transient_substates: TransientSubstates::new(),
+            prefetched_substates: index_map_new(),
             phantom_data: PhantomData::default(),
         }
     }
@@ -54,13 +59,17 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> Track<'s, S, M> {
     // TODO cleanup interface to avoid redundant information
     fn get_substate_from_db<E, F: FnMut(IOAccess) -> Result<(), E>>(
         substate_db: &'s S,
+        prefetched_substates: &mut IndexMap<DbSubstateKey, Option<DbSubstateValue>>,
         partition_key: &DbPartitionKey,
         sort_key: &DbSortKey,
         on_io_access: &mut F,
         canonical_substate_key: CanonicalSubstateKey,
     ) -> Result<Option<IndexedScryptoValue>, E> {
-        let result = substate_db
-            .get_substate(partition_key, sort_key)
+        // A prefetched value is what the database would have returned, so the IO access reported
+        // below (and therefore costing) is the same either way.
+        let result = prefetched_substates
+            .swap_remove(&(partition_key.clone(), sort_key.clone()))
+            .unwrap_or_else(|| substate_db.get_substate(partition_key, sort_key))
             .map(|e| IndexedScryptoValue::from_vec(e).expect("Failed to decode substate"));
         if let Some(x) = &result {
             on_io_access(IOAccess::ReadFromDb(canonical_substate_key, x.len()))?;
@@ -269,6 +278,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> Track<'s, S, M> {
                     let db_partition_key = M::to_db_partition_key(node_id, partition_number);
                     let substate_value = Self::get_substate_from_db(
                         self.substate_db,
+                        &mut self.prefetched_substates,
                         &db_partition_key,
                         &M::to_db_sort_key(&substate_key),
                         on_io_access,
@@ -419,6 +429,41 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
         info
     }
 
+    fn prefetch_substates(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        substate_keys: &[SubstateKey],
+    ) {
+        let tracked_partition = match self.tracked_nodes.get(node_id) {
+            Some(tracked_node) if tracked_node.is_new => return,
+            Some(tracked_node) => tracked_node.tracked_partitions.get(&partition_num),
+            None => None,
+        };
+
+        let db_partition_key = M::to_db_partition_key(node_id, partition_num);
+        let keys: Vec<DbSubstateKey> = substate_keys
+            .iter()
+            .filter(|substate_key| {
+                !self
+                    .transient_substates
+                    .is_transient(node_id, partition_num, substate_key)
+            })
+            .map(|substate_key| (db_partition_key.clone(), M::to_db_sort_key(substate_key)))
+            .filter(|(_, db_sort_key)| {
+                !tracked_partition.map_or(false, |p| p.substates.contains_key(db_sort_key))
+            })
+            .filter(|key| !self.prefetched_substates.contains_key(key))
+            .collect();
+        if keys.is_empty() {
+            return;
+        }
+
+        let values = self.substate_db.get_substates(&keys);
+        self.prefetched_substates
+            .extend(keys.into_iter().zip(values));
+    }
+
     fn get_substate<E, F: FnMut(IOAccess) -> Result<(), E>>(
         &mut self,
         node_id: &NodeId,
@@ -1,4 +1,5 @@
 mod preview_executor;
+mod replay;
 mod state_update_summary;
 mod system_structure;
 mod transaction_executor;
@@ -6,6 +7,7 @@ mod transaction_receipt;
 mod transaction_reconciler;
 
 pub use preview_executor::*;
+pub use replay::*;
 pub use state_update_summary::*;
 pub use system_structure::*;
 pub use transaction_executor::*;
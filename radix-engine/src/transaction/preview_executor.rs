@@ -4,7 +4,7 @@ use crate::transaction::*;
 use radix_engine_interface::network::NetworkDefinition;
 use radix_engine_store_interface::interface::*;
 use transaction::errors::TransactionValidationError;
-use transaction::model::PreviewIntentV1;
+use transaction::model::{PreviewIntentV1, PreviewIntentV2};
 use transaction::validation::NotarizedTransactionValidator;
 use transaction::validation::ValidationConfig;
 
@@ -19,13 +19,29 @@ pub fn execute_preview<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
     network: &NetworkDefinition,
     preview_intent: PreviewIntentV1,
     with_kernel_trace: bool,
+) -> Result<TransactionReceipt, PreviewError> {
+    execute_preview_v2(
+        substate_db,
+        vm,
+        network,
+        preview_intent.into(),
+        with_kernel_trace,
+    )
+}
+
+pub fn execute_preview_v2<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
+    substate_db: &S,
+    vm: V,
+    network: &NetworkDefinition,
+    preview_intent: PreviewIntentV2,
+    with_kernel_trace: bool,
 ) -> Result<TransactionReceipt, PreviewError> {
     let validation_config = ValidationConfig::default(network.id);
 
     let validator = NotarizedTransactionValidator::new(validation_config);
 
     let validated = validator
-        .validate_preview_intent_v1(preview_intent)
+        .validate_preview_intent_v2(preview_intent)
         .map_err(PreviewError::TransactionValidationError)?;
 
     Ok(execute_transaction(
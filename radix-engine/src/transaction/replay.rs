@@ -0,0 +1,84 @@
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::transaction::*;
+use crate::types::*;
+use radix_engine_interface::network::NetworkDefinition;
+use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
+use radix_engine_store_interface::interface::*;
+use transaction::errors::TransactionValidationError;
+use transaction::model::RawNotarizedTransaction;
+use transaction::validation::{
+    NotarizedTransactionValidator, TransactionValidator, ValidationConfig,
+};
+
+#[derive(Debug)]
+pub enum ReplayError {
+    TransactionValidationError {
+        index: usize,
+        error: TransactionValidationError,
+    },
+    ReceiptMismatch {
+        index: usize,
+        expected: Box<TransactionReceipt>,
+        actual: Box<TransactionReceipt>,
+    },
+}
+
+/// Re-executes committed user transactions, in order, against a database at the state version
+/// right before the first of them, and checks that each one produces the receipt it was
+/// originally committed with.
+///
+/// The state updates of every replayed transaction are committed before the next one is
+/// executed, so on success the database ends up at the state after the last transaction.
+pub fn replay_transactions<S, V, T>(
+    substate_db: &mut S,
+    vm: V,
+    network: &NetworkDefinition,
+    transactions: T,
+) -> Result<Vec<TransactionReceipt>, ReplayError>
+where
+    S: SubstateDatabase + CommittableSubstateDatabase,
+    V: SystemCallbackObject + Clone,
+    T: IntoIterator<Item = (RawNotarizedTransaction, TransactionReceipt)>,
+{
+    let validator = NotarizedTransactionValidator::new(ValidationConfig::default(network.id));
+    let mut receipts = Vec::new();
+    for (index, (raw_transaction, expected)) in transactions.into_iter().enumerate() {
+        let validated = validator
+            .validate_from_raw(&raw_transaction)
+            .map_err(|error| ReplayError::TransactionValidationError { index, error })?;
+        let actual = execute_transaction(
+            substate_db,
+            vm.clone(),
+            &CostingParameters::default(),
+            &ExecutionConfig::for_notarized_transaction(network.clone()),
+            &validated.get_executable(),
+        );
+
+        if !receipts_match(&expected, &actual) {
+            return Err(ReplayError::ReceiptMismatch {
+                index,
+                expected: Box::new(expected),
+                actual: Box::new(actual),
+            });
+        }
+
+        if let TransactionResult::Commit(commit) = &actual.result {
+            substate_db.commit(
+                &commit
+                    .state_updates
+                    .create_database_updates::<SpreadPrefixKeyMapper>(),
+            );
+        }
+        receipts.push(actual);
+    }
+
+    Ok(receipts)
+}
+
+/// Whether two receipts record the same result and fees.
+///
+/// Measurements of the executing machine, such as resource usage, are not compared.
+pub fn receipts_match(left: &TransactionReceipt, right: &TransactionReceipt) -> bool {
+    scrypto_encode(&left.result).unwrap() == scrypto_encode(&right.result).unwrap()
+        && scrypto_encode(&left.fee_summary).unwrap() == scrypto_encode(&right.fee_summary).unwrap()
+}
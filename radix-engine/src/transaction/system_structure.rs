@@ -20,6 +20,9 @@ pub enum SubstateSystemStructure {
     ObjectKeyValuePartitionEntry(KeyValuePartitionEntryStructure),
     ObjectIndexPartitionEntry(IndexPartitionEntryStructure),
     ObjectSortedIndexPartitionEntry(SortedIndexPartitionEntryStructure),
+    // Appended to keep the encoding of receipts with earlier variants stable
+    SystemEventSubscriptions,
+    SystemRateLimits,
 }
 
 #[derive(Debug, Clone, ScryptoSbor, PartialEq, Eq)]
@@ -31,6 +34,7 @@ pub struct SystemFieldStructure {
 pub enum SystemFieldKind {
     TypeInfo,
     BootLoader,
+    StateSize,
 }
 
 #[derive(Debug, Clone, ScryptoSbor, PartialEq, Eq)]
@@ -240,7 +244,16 @@ impl<'a, S: SubstateDatabase> SubstateSchemaMapper<'a, S> {
                     field_kind: SystemFieldKind::TypeInfo,
                 })
             }
+            SystemPartitionDescriptor::StateSize => {
+                SubstateSystemStructure::SystemField(SystemFieldStructure {
+                    field_kind: SystemFieldKind::StateSize,
+                })
+            }
             SystemPartitionDescriptor::Schema => SubstateSystemStructure::SystemSchema,
+            SystemPartitionDescriptor::EventSubscriptions => {
+                SubstateSystemStructure::SystemEventSubscriptions
+            }
+            SystemPartitionDescriptor::RateLimits => SubstateSystemStructure::SystemRateLimits,
             SystemPartitionDescriptor::KeyValueStore => {
                 let info = self
                     .system_reader
@@ -7,31 +7,33 @@ use crate::blueprints::resource::{
     fungible_vault::DepositEvent, fungible_vault::PayFeeEvent, BurnFungibleResourceEvent,
     FungibleVaultBalanceFieldPayload, FungibleVaultBalanceFieldSubstate, FungibleVaultField,
 };
-use crate::blueprints::transaction_tracker::{
-    TransactionStatus, TransactionStatusV1, TransactionTrackerSubstate,
-};
+use crate::blueprints::transaction_tracker::{IntentStatusStore, TransactionStatusV1};
 use crate::errors::*;
-use crate::internal_prelude::KeyValueEntrySubstateV1;
 use crate::kernel::id_allocator::IdAllocator;
 use crate::kernel::kernel::BootLoader;
 use crate::kernel::kernel_callback_api::*;
 use crate::system::system_callback::SystemConfig;
 use crate::system::system_callback_api::SystemCallbackObject;
 use crate::system::system_db_reader::SystemDatabaseReader;
+use crate::system::system_modules::auth::AuthModule;
 use crate::system::system_modules::costing::*;
+use crate::system::system_modules::debugger::TransactionDebugger;
 use crate::system::system_modules::execution_trace::ExecutionTraceModule;
+use crate::system::system_modules::extension::SystemModuleExtension;
+use crate::system::system_modules::span_trace::SpanTraceModule;
 use crate::system::system_modules::transaction_runtime::TransactionRuntimeModule;
 use crate::system::system_modules::{EnabledModules, SystemModuleMixer};
-use crate::system::system_substates::KeyValueEntrySubstate;
-use crate::system::system_substates::{FieldSubstate, LockStatus};
-use crate::track::interface::CommitableSubstateStore;
-use crate::track::{to_state_updates, Track, TrackFinalizeError};
+use crate::system::system_substates::FieldSubstate;
+use crate::track::interface::{CommitableSubstateStore, StoreCommit};
+use crate::track::{
+    to_state_updates, ReadWriteSet, SubstateReadRecorder, Track, TrackFinalizeError,
+};
 use crate::transaction::*;
 use crate::types::*;
 use radix_engine_common::constants::*;
 use radix_engine_interface::api::ModuleId;
 use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
-use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
+use radix_engine_interface::blueprints::transaction_processor::TransactionProcessorRunOutput;
 use radix_engine_store_interface::{db_key_mapper::SpreadPrefixKeyMapper, interface::*};
 use transaction::model::*;
 
@@ -100,19 +102,32 @@ pub struct ExecutionConfig {
     pub enabled_modules: EnabledModules,
     pub abort_when_loan_repaid: bool,
     pub enable_cost_breakdown: bool,
+    pub enable_substate_read_recording: bool,
     pub max_execution_trace_depth: usize,
     pub max_call_depth: usize,
     pub max_heap_substate_total_bytes: usize,
     pub max_track_substate_total_bytes: usize,
+    pub max_node_substate_count: u64,
+    pub max_node_substate_total_bytes: u64,
+    pub max_wasm_memory_pages_per_call_frame: u32,
+    pub max_wasm_memory_pages_per_transaction: u32,
     pub max_substate_key_size: usize,
     pub max_substate_value_size: usize,
     pub max_invoke_input_size: usize,
+    pub max_invoke_output_size: usize,
     pub max_event_size: usize,
     pub max_log_size: usize,
     pub max_panic_message_size: usize,
     pub max_number_of_logs: usize,
     pub max_number_of_events: usize,
     pub max_per_function_royalty_in_xrd: Decimal,
+    /// The state version the transaction is executed against, when running a deterministic
+    /// simulation. See [`ExecutionConfig::with_deterministic_simulation`].
+    pub deterministic_state_version: Option<u64>,
+    /// Whether a call frame leaving global address reservations unused fails with
+    /// [`SystemError::UnusedAddressReservations`], naming the reserved addresses, rather than
+    /// with a generic orphaned nodes error.
+    pub fail_on_unused_address_reservations: bool,
 }
 
 impl ExecutionConfig {
@@ -124,13 +139,19 @@ impl ExecutionConfig {
             enabled_modules: EnabledModules::for_notarized_transaction(),
             abort_when_loan_repaid: false,
             enable_cost_breakdown: false,
+            enable_substate_read_recording: false,
             max_execution_trace_depth: MAX_EXECUTION_TRACE_DEPTH,
             max_call_depth: MAX_CALL_DEPTH,
             max_heap_substate_total_bytes: MAX_HEAP_SUBSTATE_TOTAL_BYTES,
             max_track_substate_total_bytes: MAX_TRACK_SUBSTATE_TOTAL_BYTES,
+            max_node_substate_count: MAX_NODE_SUBSTATE_COUNT,
+            max_node_substate_total_bytes: MAX_NODE_SUBSTATE_TOTAL_BYTES,
+            max_wasm_memory_pages_per_call_frame: MAX_WASM_MEMORY_PAGES_PER_CALL_FRAME,
+            max_wasm_memory_pages_per_transaction: MAX_WASM_MEMORY_PAGES_PER_TRANSACTION,
             max_substate_key_size: MAX_SUBSTATE_KEY_SIZE,
             max_substate_value_size: MAX_SUBSTATE_VALUE_SIZE,
             max_invoke_input_size: MAX_INVOKE_PAYLOAD_SIZE,
+            max_invoke_output_size: MAX_INVOKE_OUTPUT_SIZE,
             max_event_size: MAX_EVENT_SIZE,
             max_log_size: MAX_LOG_SIZE,
             max_panic_message_size: MAX_PANIC_MESSAGE_SIZE,
@@ -138,6 +159,8 @@ impl ExecutionConfig {
             max_number_of_events: MAX_NUMBER_OF_EVENTS,
             max_per_function_royalty_in_xrd: Decimal::try_from(MAX_PER_FUNCTION_ROYALTY_IN_XRD)
                 .unwrap(),
+            deterministic_state_version: None,
+            fail_on_unused_address_reservations: false,
         }
     }
 
@@ -195,6 +218,35 @@ impl ExecutionConfig {
         self
     }
 
+    pub fn with_span_trace(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.enabled_modules.insert(EnabledModules::SPAN_TRACE);
+        } else {
+            self.enabled_modules.remove(EnabledModules::SPAN_TRACE);
+        }
+        self
+    }
+
+    pub fn with_substate_read_recording(mut self, enabled: bool) -> Self {
+        self.enable_substate_read_recording = enabled;
+        self
+    }
+
+    pub fn with_fail_on_unused_address_reservations(mut self, enabled: bool) -> Self {
+        self.fail_on_unused_address_reservations = enabled;
+        self
+    }
+
+    /// Makes the receipt a pure function of the executed transactions and the state version.
+    ///
+    /// RUIDs are derived from the transaction hash and `state_version`, span timestamps are
+    /// zeroed and no hardware resource usage is reported. Proposer timestamps need no special
+    /// handling, as they only enter the ledger as arguments of round change transactions.
+    pub fn with_deterministic_simulation(mut self, state_version: u64) -> Self {
+        self.deterministic_state_version = Some(state_version);
+        self
+    }
+
     pub fn up_to_loan_repayment(mut self, enabled: bool) -> Self {
         self.abort_when_loan_repaid = enabled;
         self
@@ -226,6 +278,8 @@ where
 {
     substate_db: &'s S,
     vm: V,
+    debugger: Option<Box<dyn TransactionDebugger>>,
+    extensions: Vec<Box<dyn SystemModuleExtension>>,
 }
 
 impl<'s, S, V> TransactionExecutor<'s, S, V>
@@ -234,7 +288,22 @@ where
     V: SystemCallbackObject + Clone,
 {
     pub fn new(substate_db: &'s S, vm: V) -> Self {
-        Self { substate_db, vm }
+        Self {
+            substate_db,
+            vm,
+            debugger: None,
+            extensions: Vec::new(),
+        }
+    }
+
+    pub fn with_debugger(mut self, debugger: Box<dyn TransactionDebugger>) -> Self {
+        self.debugger = Some(debugger);
+        self
+    }
+
+    pub fn with_extensions(mut self, extensions: Vec<Box<dyn SystemModuleExtension>>) -> Self {
+        self.extensions = extensions;
+        self
     }
 
     pub fn execute<T: WrappedSystem<V>>(
@@ -286,6 +355,7 @@ where
                         range.end_epoch_exclusive,
                     )
                 })
+                .and_then(|_| Self::validate_subintent_hashes(&mut track, executable))
             } else {
                 Ok(())
             }
@@ -294,17 +364,29 @@ where
         };
 
         // Run manifest
-        let (fee_summary, fee_details, result) = match validation_result {
+        let debugger = self.debugger.take();
+        let extensions = core::mem::take(&mut self.extensions);
+        let mut span_trace = None;
+        let mut address_reservations = Vec::new();
+        let (fee_summary, fee_details, result, access_rule_cache_stats) = match validation_result {
             Ok(()) => {
                 let (
                     interpretation_result,
-                    (mut costing_module, runtime_module, execution_trace_module),
+                    (
+                        mut costing_module,
+                        mut runtime_module,
+                        execution_trace_module,
+                        auth_module,
+                        span_trace_module,
+                    ),
                 ) = self.interpret_manifest::<T>(
                     &mut track,
                     executable,
                     execution_config,
                     fee_reserve,
                     fee_table,
+                    debugger,
+                    extensions,
                     init,
                 );
 
@@ -336,6 +418,24 @@ where
                     None
                 };
 
+                let access_rule_cache_stats = if execution_config
+                    .enabled_modules
+                    .contains(EnabledModules::AUTH)
+                {
+                    Some(auth_module.access_rule_cache_stats)
+                } else {
+                    None
+                };
+
+                if execution_config
+                    .enabled_modules
+                    .contains(EnabledModules::SPAN_TRACE)
+                {
+                    span_trace = Some(span_trace_module.finalize());
+                }
+
+                address_reservations = runtime_module.take_address_reservations();
+
                 // Panic if an error is encountered in the system layer or below. The following code
                 // is only enabled when compiling with the standard library since the panic catching
                 // machinery and `SystemPanic` errors are only implemented in `std`.
@@ -350,7 +450,7 @@ where
                     interpretation_result,
                     &mut costing_module.fee_reserve,
                 );
-                match result_type {
+                let (fee_summary, fee_details, result) = match result_type {
                     TransactionResultType::Commit(outcome) => {
                         let is_success = outcome.is_ok();
 
@@ -382,7 +482,8 @@ where
                             Self::update_transaction_tracker(
                                 &mut track,
                                 next_epoch,
-                                executable.intent_hash(),
+                                sbor::rust::iter::once(executable.intent_hash())
+                                    .chain(executable.subintent_hashes()),
                                 is_success,
                             );
                         }
@@ -429,23 +530,30 @@ where
                             );
                         }
 
+                        let read_write_set =
+                            ReadWriteSet::from_tracked_nodes(&tracked_nodes, &deleted_partitions);
+
                         let state_updates = to_state_updates::<SpreadPrefixKeyMapper>(
                             tracked_nodes,
                             deleted_partitions,
                         );
 
+                        let (outcome, worktop_leftovers) = match outcome {
+                            Ok(o) => (TransactionOutcome::Success(o.outputs), o.worktop_leftovers),
+                            Err(e) => (TransactionOutcome::Failure(e), index_map_new()),
+                        };
+
                         (
                             fee_reserve_finalization.into(),
                             fee_details,
                             TransactionResult::Commit(CommitResult {
                                 state_updates,
+                                read_write_set,
                                 state_update_summary,
                                 fee_source: FeeSource { paying_vaults },
                                 fee_destination,
-                                outcome: match outcome {
-                                    Ok(o) => TransactionOutcome::Success(o),
-                                    Err(e) => TransactionOutcome::Failure(e),
-                                },
+                                outcome,
+                                worktop_leftovers,
                                 application_events,
                                 application_logs,
                                 system_structure,
@@ -470,7 +578,9 @@ where
                         fee_details,
                         TransactionResult::Abort(AbortResult { reason }),
                     ),
-                }
+                };
+
+                (fee_summary, fee_details, result, access_rule_cache_stats)
             }
             Err(reason) => (
                 // No execution is done, so add empty fee summary and details
@@ -481,6 +591,7 @@ where
                     None
                 },
                 TransactionResult::Reject(RejectResult { reason }),
+                None,
             ),
         };
 
@@ -489,7 +600,8 @@ where
             #[cfg(not(all(target_os = "linux", feature = "std", feature = "cpu_ram_metrics")))]
             () => None,
             #[cfg(all(target_os = "linux", feature = "std", feature = "cpu_ram_metrics"))]
-            () => Some(resources_tracker.end_measurement()),
+            () => Some(resources_tracker.end_measurement())
+                .filter(|_| execution_config.deterministic_state_version.is_none()),
         };
 
         // Produce final receipt
@@ -500,6 +612,11 @@ where
             fee_details,
             result,
             resources_usage,
+            substate_reads: None,
+            access_rule_cache_stats,
+            span_trace,
+            address_reservations,
+            message: executable.message().cloned(),
         };
 
         // Dump summary
@@ -557,45 +674,31 @@ where
         intent_hash: Hash,
         expiry_epoch: Epoch,
     ) -> Result<(), RejectionReason> {
-        let substate: FieldSubstate<TransactionTrackerSubstate> = track
-            .read_substate(
-                TRANSACTION_TRACKER.as_node_id(),
-                MAIN_BASE_PARTITION,
-                &TransactionTrackerField::TransactionTracker.into(),
-            )
-            .unwrap()
-            .as_typed()
-            .unwrap();
-
-        let partition_number = substate
-            .into_payload()
-            .v1()
-            .partition_for_expiry_epoch(expiry_epoch)
-            .expect("Transaction tracker should cover all valid epoch ranges");
-
-        let substate = track.read_substate(
-            TRANSACTION_TRACKER.as_node_id(),
-            PartitionNumber(partition_number),
-            &SubstateKey::Map(scrypto_encode(&intent_hash).unwrap()),
-        );
+        match track.intent_status_for_expiry(&intent_hash, expiry_epoch) {
+            Some(TransactionStatusV1::CommittedSuccess)
+            | Some(TransactionStatusV1::CommittedFailure) => {
+                Err(RejectionReason::IntentHashPreviouslyCommitted)
+            }
+            Some(TransactionStatusV1::Cancelled) => {
+                Err(RejectionReason::IntentHashPreviouslyCancelled)
+            }
+            None => Ok(()),
+        }
+    }
 
-        match substate {
-            Some(value) => {
-                let substate: KeyValueEntrySubstate<TransactionStatus> = value.as_typed().unwrap();
-                match substate.into_value() {
-                    Some(status) => match status.into_v1() {
-                        TransactionStatusV1::CommittedSuccess
-                        | TransactionStatusV1::CommittedFailure => {
-                            return Err(RejectionReason::IntentHashPreviouslyCommitted);
-                        }
-                        TransactionStatusV1::Cancelled => {
-                            return Err(RejectionReason::IntentHashPreviouslyCancelled);
-                        }
-                    },
-                    None => {}
-                }
+    fn validate_subintent_hashes(
+        track: &mut Track<S, SpreadPrefixKeyMapper>,
+        executable: &Executable,
+    ) -> Result<(), RejectionReason> {
+        for subintent_hash in executable.subintent_hashes() {
+            if let TransactionIntentHash::ToCheck {
+                intent_hash,
+                expiry_epoch,
+            } = subintent_hash
+            {
+                Self::validate_intent_hash(track, *intent_hash, *expiry_epoch)
+                    .map_err(|_| RejectionReason::SubintentHashPreviouslyCommitted)?;
             }
-            None => {}
         }
 
         Ok(())
@@ -608,17 +711,21 @@ where
         execution_config: &ExecutionConfig,
         fee_reserve: SystemLoanFeeReserve,
         fee_table: FeeTable,
+        debugger: Option<Box<dyn TransactionDebugger>>,
+        extensions: Vec<Box<dyn SystemModuleExtension>>,
         init: T::Init,
     ) -> (
-        Result<Vec<InstructionOutput>, RuntimeError>,
+        Result<TransactionProcessorRunOutput, RuntimeError>,
         (
             CostingModule,
             TransactionRuntimeModule,
             ExecutionTraceModule,
+            AuthModule,
+            SpanTraceModule,
         ),
     ) {
         let mut id_allocator = IdAllocator::new(executable.intent_hash().to_hash());
-        let system = SystemConfig {
+        let mut system = SystemConfig {
             blueprint_cache: NonIterMap::new(),
             auth_cache: NonIterMap::new(),
             schema_cache: NonIterMap::new(),
@@ -635,6 +742,12 @@ where
                 execution_config,
             ),
         };
+        if let Some(debugger) = debugger {
+            system.modules.set_debugger(debugger);
+        }
+        for extension in extensions {
+            system.modules.add_extension(extension);
+        }
 
         let mut wrapped_system = T::create(system, init);
 
@@ -650,10 +763,14 @@ where
                 executable.pre_allocated_addresses(),
                 executable.references(),
                 executable.blobs(),
+                executable.worktop_leftovers_recipient(),
             )
             .and_then(|x| {
                 let system = wrapped_system.system_mut();
 
+                /* node state size accounting and limits */
+                Self::update_node_state_sizes(track, &system.modules)?;
+
                 // Note that if a transactions fails during this phase, the costing is
                 // done as if it would succeed.
 
@@ -681,6 +798,8 @@ where
                         .modules
                         .apply_storage_cost(StorageType::State, store_commit.len_increase())?;
                 }
+                let total_len_decrease = info.iter().map(|x| x.len_decrease()).sum();
+                system.modules.apply_storage_refund(total_len_decrease)?;
 
                 /* archive storage costs */
                 let total_event_size = system.modules.events().iter().map(|x| x.len()).sum();
@@ -688,7 +807,7 @@ where
                     .modules
                     .apply_storage_cost(StorageType::Archive, total_event_size)?;
 
-                let total_log_size = system.modules.logs().iter().map(|x| x.1.len()).sum();
+                let total_log_size = system.modules.logs().iter().map(|x| x.size()).sum();
                 system
                     .modules
                     .apply_storage_cost(StorageType::Archive, total_log_size)?;
@@ -705,7 +824,7 @@ where
                 Err(e)
             })
             .map(|rtn| {
-                let output: Vec<InstructionOutput> = scrypto_decode(&rtn).unwrap();
+                let output: TransactionProcessorRunOutput = scrypto_decode(&rtn).unwrap();
                 output
             });
 
@@ -713,8 +832,83 @@ where
         (interpretation_result, system.modules.unpack())
     }
 
+    /// Applies the state updates of the transaction to the state size accounting of each node
+    /// written to, and checks the resulting sizes against the per-node limits.
+    ///
+    /// The transaction tracker is not accounted for, as its partitions are deleted wholesale
+    /// once expired. Nodes which pre-date the accounting start out as empty.
+    fn update_node_state_sizes(
+        track: &mut Track<S, SpreadPrefixKeyMapper>,
+        modules: &SystemModuleMixer,
+    ) -> Result<(), RuntimeError> {
+        let mut deltas: IndexMap<NodeId, (i64, i64)> = index_map_new();
+        for store_commit in track.get_commit_info() {
+            let (canonical_substate_key, count_delta, bytes_delta) = match store_commit {
+                StoreCommit::Insert {
+                    canonical_substate_key,
+                    size,
+                } => {
+                    let bytes = (canonical_substate_key.len() + size) as i64;
+                    (canonical_substate_key, 1, bytes)
+                }
+                StoreCommit::Update {
+                    canonical_substate_key,
+                    size,
+                    old_size,
+                } => (canonical_substate_key, 0, size as i64 - old_size as i64),
+                StoreCommit::Delete {
+                    canonical_substate_key,
+                    old_size,
+                } => {
+                    let bytes = (canonical_substate_key.len() + old_size) as i64;
+                    (canonical_substate_key, -1, -bytes)
+                }
+            };
+            if canonical_substate_key.partition_number == STATE_SIZE_PARTITION
+                || canonical_substate_key.node_id == TRANSACTION_TRACKER.into_node_id()
+            {
+                continue;
+            }
+
+            let delta = deltas
+                .entry(canonical_substate_key.node_id)
+                .or_insert((0, 0));
+            delta.0 += count_delta;
+            delta.1 += bytes_delta;
+        }
+
+        for (node_id, (count_delta, bytes_delta)) in deltas {
+            if count_delta == 0 && bytes_delta == 0 {
+                continue;
+            }
+
+            let substate_key = SubstateKey::from(StateSizeField::StateSize);
+            let current_size: NodeStateSize = track
+                .read_substate(&node_id, STATE_SIZE_PARTITION, &substate_key)
+                .map(|value| value.as_typed().unwrap())
+                .unwrap_or_default();
+            let new_size = NodeStateSize {
+                substate_count: current_size.substate_count.saturating_add_signed(count_delta),
+                total_bytes: current_size.total_bytes.saturating_add_signed(bytes_delta),
+            };
+            modules.check_node_state_size(&node_id, &new_size)?;
+
+            track
+                .set_substate(
+                    node_id,
+                    STATE_SIZE_PARTITION,
+                    substate_key,
+                    IndexedScryptoValue::from_typed(&new_size),
+                    &mut |_| -> Result<(), ()> { Ok(()) },
+                )
+                .unwrap();
+        }
+
+        Ok(())
+    }
+
     fn determine_result_type(
-        mut interpretation_result: Result<Vec<InstructionOutput>, RuntimeError>,
+        mut interpretation_result: Result<TransactionProcessorRunOutput, RuntimeError>,
         fee_reserve: &mut SystemLoanFeeReserve,
     ) -> TransactionResultType {
         // A `SuccessButFeeLoanNotRepaid` error is issued if a transaction finishes before
@@ -805,7 +999,7 @@ where
         let mut fee_payments: IndexMap<NodeId, Decimal> = index_map_new();
         let mut required = fee_reserve_finalization.total_cost();
         let mut collected_fees = LiquidFungibleResource::new(Decimal::ZERO);
-        for (vault_id, mut locked, contingent) in
+        for (vault_id, mut locked, contingent, refund_vault_id) in
             fee_reserve_finalization.locked_fees.iter().cloned().rev()
         {
             let amount = if contingent {
@@ -825,10 +1019,23 @@ where
             collected_fees.put(locked.take_by_amount(amount).unwrap());
             required = required.checked_sub(amount).unwrap();
 
-            // Refund overpayment
+            // Refund overpayment, to the locking vault unless another vault was named.
+            // A refund vault created by a failed transaction no longer exists at this point.
+            let refund_vault_id = refund_vault_id
+                .filter(|refund_vault_id| {
+                    track
+                        .read_substate(
+                            refund_vault_id,
+                            MAIN_BASE_PARTITION,
+                            &FungibleVaultField::Balance.into(),
+                        )
+                        .is_some()
+                })
+                .unwrap_or(vault_id);
+            let refund = locked.amount();
             let mut vault_balance = track
                 .read_substate(
-                    &vault_id,
+                    &refund_vault_id,
                     MAIN_BASE_PARTITION,
                     &FungibleVaultField::Balance.into(),
                 )
@@ -843,7 +1050,7 @@ where
                     .into_unlocked_substate();
             track
                 .set_substate(
-                    vault_id,
+                    refund_vault_id,
                     MAIN_BASE_PARTITION,
                     FungibleVaultField::Balance.into(),
                     IndexedScryptoValue::from_typed(&updated_substate_content),
@@ -862,6 +1069,15 @@ where
                 ),
                 scrypto_encode(&PayFeeEvent { amount }).unwrap(),
             ));
+            if refund_vault_id != vault_id && refund.is_positive() {
+                events.push((
+                    EventTypeIdentifier(
+                        Emitter::Method(refund_vault_id, ModuleId::Main),
+                        DepositEvent::EVENT_NAME.to_string(),
+                    ),
+                    scrypto_encode(&DepositEvent { amount: refund }).unwrap(),
+                ));
+            }
         }
         // Free credit is locked first and thus used last
         if free_credit.is_positive() {
@@ -997,86 +1213,30 @@ where
         (fee_reserve_finalization, fee_payments, events)
     }
 
-    fn update_transaction_tracker(
+    fn update_transaction_tracker<'a>(
         track: &mut Track<S, SpreadPrefixKeyMapper>,
         next_epoch: Epoch,
-        intent_hash: &TransactionIntentHash,
+        intent_hashes: impl Iterator<Item = &'a TransactionIntentHash>,
         is_success: bool,
     ) {
-        // Read the intent hash store
-        let transaction_tracker = track
-            .read_substate(
-                TRANSACTION_TRACKER.as_node_id(),
-                MAIN_BASE_PARTITION,
-                &TransactionTrackerField::TransactionTracker.into(),
-            )
-            .unwrap()
-            .as_typed::<FieldSubstate<TransactionTrackerSubstate>>()
-            .unwrap()
-            .into_payload();
-
-        let mut transaction_tracker = transaction_tracker.into_v1();
-
-        // Update the status of the intent hash
-        if let TransactionIntentHash::ToCheck {
-            expiry_epoch,
-            intent_hash,
-        } = intent_hash
-        {
-            if let Some(partition_number) =
-                transaction_tracker.partition_for_expiry_epoch(*expiry_epoch)
+        // Update the status of the intent hash, and of any subintent hashes
+        let status = if is_success {
+            TransactionStatusV1::CommittedSuccess
+        } else {
+            TransactionStatusV1::CommittedFailure
+        };
+        for intent_hash in intent_hashes {
+            if let TransactionIntentHash::ToCheck {
+                expiry_epoch,
+                intent_hash,
+            } = intent_hash
             {
-                track
-                    .set_substate(
-                        TRANSACTION_TRACKER.into_node_id(),
-                        PartitionNumber(partition_number),
-                        SubstateKey::Map(scrypto_encode(intent_hash).unwrap()),
-                        IndexedScryptoValue::from_typed(&KeyValueEntrySubstate::V1(
-                            KeyValueEntrySubstateV1 {
-                                value: Some(if is_success {
-                                    TransactionStatus::V1(TransactionStatusV1::CommittedSuccess)
-                                } else {
-                                    TransactionStatus::V1(TransactionStatusV1::CommittedFailure)
-                                }),
-                                // TODO: maybe make it immutable, but how does this affect partition deletion?
-                                lock_status: LockStatus::Unlocked,
-                            },
-                        )),
-                        &mut |_| -> Result<(), ()> { Ok(()) },
-                    )
-                    .unwrap();
-            } else {
-                panic!("No partition for an expiry epoch")
+                track.record_intent_status(intent_hash, *expiry_epoch, status.clone());
             }
         }
 
-        // Check if all intent hashes in the first epoch have expired, based on the `next_epoch`.
-        //
-        // In this particular implementation, because the transaction tracker coverage is greater than
-        // the max epoch range in transaction header, we must check epoch range first to
-        // ensure we don't store intent hash too far into the future.
-        //
-        // Also, we need to make sure epoch doesn't jump by a large distance.
-        if next_epoch.number()
-            >= transaction_tracker.start_epoch + transaction_tracker.epochs_per_partition
-        {
-            let discarded_partition = transaction_tracker.advance();
-            track.delete_partition(
-                TRANSACTION_TRACKER.as_node_id(),
-                PartitionNumber(discarded_partition),
-            );
-        }
-        track
-            .set_substate(
-                TRANSACTION_TRACKER.into_node_id(),
-                MAIN_BASE_PARTITION,
-                TransactionTrackerField::TransactionTracker.into(),
-                IndexedScryptoValue::from_typed(&FieldSubstate::new_unlocked_field(
-                    TransactionTrackerSubstate::V1(transaction_tracker),
-                )),
-                &mut |_| -> Result<(), ()> { Ok(()) },
-            )
-            .unwrap();
+        // Discard the intent hashes which have all expired by the `next_epoch`
+        track.advance_to_epoch(next_epoch);
     }
 
     #[cfg(not(feature = "alloc"))]
@@ -1152,6 +1312,11 @@ where
             "Storage Cost in XRD",
             receipt.fee_summary.total_storage_cost_in_xrd.to_string()
         );
+        println!(
+            "{:<40}: {:>25}",
+            "Storage Refund in XRD",
+            receipt.fee_summary.total_storage_refund_in_xrd.to_string()
+        );
         println!(
             "{:<40}: {:>25}",
             "Royalty Costs in XRD",
@@ -1161,8 +1326,8 @@ where
         match &receipt.result {
             TransactionResult::Commit(commit) => {
                 println!("{:-^120}", "Application Logs");
-                for (level, message) in &commit.application_logs {
-                    println!("[{}] {}", level, message);
+                for record in &commit.application_logs {
+                    println!("[{}] {}", record.level, record);
                 }
 
                 println!("{:-^120}", "Outcome");
@@ -1243,16 +1408,76 @@ pub fn execute_transaction_with_system<
     transaction: &Executable,
     init: T::Init,
 ) -> TransactionReceipt {
-    TransactionExecutor::new(substate_db, vm).execute::<T>(
-        transaction,
-        costing_parameters,
-        execution_config,
-        init,
-    )
+    if execution_config.enable_substate_read_recording {
+        let recorder = SubstateReadRecorder::new(substate_db);
+        let mut receipt = TransactionExecutor::new(&recorder, vm).execute::<T>(
+            transaction,
+            costing_parameters,
+            execution_config,
+            init,
+        );
+        receipt.substate_reads = Some(recorder.into_reads());
+        receipt
+    } else {
+        TransactionExecutor::new(substate_db, vm).execute::<T>(
+            transaction,
+            costing_parameters,
+            execution_config,
+            init,
+        )
+    }
+}
+
+/// Executes a transaction with the given debugger attached, which is paused before every manifest
+/// instruction (and optionally every kernel invocation) and may abort the transaction.
+pub fn execute_transaction_with_debugger<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
+    substate_db: &S,
+    vm: V,
+    costing_parameters: &CostingParameters,
+    execution_config: &ExecutionConfig,
+    transaction: &Executable,
+    debugger: Box<dyn TransactionDebugger>,
+) -> TransactionReceipt {
+    if execution_config.enable_substate_read_recording {
+        let recorder = SubstateReadRecorder::new(substate_db);
+        let mut receipt = TransactionExecutor::new(&recorder, vm)
+            .with_debugger(debugger)
+            .execute::<SystemConfig<V>>(transaction, costing_parameters, execution_config, ());
+        receipt.substate_reads = Some(recorder.into_reads());
+        receipt
+    } else {
+        TransactionExecutor::new(substate_db, vm)
+            .with_debugger(debugger)
+            .execute::<SystemConfig<V>>(transaction, costing_parameters, execution_config, ())
+    }
+}
+
+/// Executes a transaction with the given custom system modules installed next to the built-in
+/// ones, so that embedders can observe invocations, substate accesses and fee locks.
+pub fn execute_transaction_with_extensions<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
+    substate_db: &S,
+    vm: V,
+    costing_parameters: &CostingParameters,
+    execution_config: &ExecutionConfig,
+    transaction: &Executable,
+    extensions: Vec<Box<dyn SystemModuleExtension>>,
+) -> TransactionReceipt {
+    if execution_config.enable_substate_read_recording {
+        let recorder = SubstateReadRecorder::new(substate_db);
+        let mut receipt = TransactionExecutor::new(&recorder, vm)
+            .with_extensions(extensions)
+            .execute::<SystemConfig<V>>(transaction, costing_parameters, execution_config, ());
+        receipt.substate_reads = Some(recorder.into_reads());
+        receipt
+    } else {
+        TransactionExecutor::new(substate_db, vm)
+            .with_extensions(extensions)
+            .execute::<SystemConfig<V>>(transaction, costing_parameters, execution_config, ())
+    }
 }
 
 enum TransactionResultType {
-    Commit(Result<Vec<InstructionOutput>, RuntimeError>),
+    Commit(Result<TransactionProcessorRunOutput, RuntimeError>),
     Reject(RejectionReason),
     Abort(AbortReason),
 }
@@ -2,30 +2,62 @@ use super::{BalanceChange, CostingParameters, StateUpdateSummary};
 use crate::blueprints::consensus_manager::EpochChangeEvent;
 use crate::errors::*;
 use crate::internal_prelude::*;
+use crate::system::system_modules::auth::AccessRuleCacheStats;
 use crate::system::system_modules::costing::*;
 use crate::system::system_modules::execution_trace::*;
+use crate::system::system_modules::span_trace::SpanTrace;
+use crate::system::system_modules::transaction_runtime::AddressReservationRecord;
 use crate::track::BatchPartitionStateUpdate;
 use crate::track::NodeStateUpdates;
 use crate::track::PartitionStateUpdates;
+use crate::track::ReadWriteSet;
 use crate::track::StateUpdates;
+use crate::track::SubstateRead;
 use crate::transaction::SystemStructure;
 use colored::*;
 use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
 use radix_engine_store_interface::interface::DatabaseUpdate;
 use sbor::representations::*;
-use transaction::prelude::TransactionCostingParameters;
+use transaction::prelude::{TransactionCostingParameters, TransactionMessageMetadata};
 
-define_single_versioned! {
-    /// We define a versioned transaction receipt for encoding in the preview API.
+define_versioned! {
+    /// We define a versioned transaction receipt for encoding in the preview API and for
+    /// long-term storage by downstream services.
     /// This allows a new toolkit build to be able to handle both current and future
     /// receipt versions, allowing us to release a wallet ahead-of-time which is forward
     /// compatible with a new version of the engine (and so a new transaction receipt).
+    ///
+    /// Receipts encoded by an older engine keep decoding into their original version, which is
+    /// then updated to the latest one. To keep this working, the types nested in a receipt must
+    /// only ever be extended by appending new enum variants; any other change requires a new
+    /// receipt version.
     #[derive(Clone, ScryptoSbor)]
-    pub enum VersionedTransactionReceipt => TransactionReceipt = TransactionReceiptV1
+    pub enum VersionedTransactionReceipt {
+        previous_versions: [
+            1 => TransactionReceiptV1: { updates_to: 2 },
+        ],
+        latest_version: {
+            2 => TransactionReceipt = TransactionReceiptV2,
+        },
+    }
+}
+
+define_versioned! {
+    /// A versioned commit result, for services which store the commit results of transactions
+    /// without the rest of their receipts.
+    #[derive(Debug, Clone, ScryptoSbor)]
+    pub enum VersionedCommitResult {
+        previous_versions: [
+            1 => CommitResultV1: { updates_to: 2 },
+        ],
+        latest_version: {
+            2 => CommitResult = CommitResultV2,
+        },
+    }
 }
 
 #[derive(Clone, ScryptoSbor)]
-pub struct TransactionReceiptV1 {
+pub struct TransactionReceiptV2 {
     /// Costing parameters
     pub costing_parameters: CostingParameters,
     /// Transaction costing parameters
@@ -40,6 +72,20 @@ pub struct TransactionReceiptV1 {
     /// Hardware resources usage report
     /// Available if `resources_usage` feature flag is enabled
     pub resources_usage: Option<ResourcesUsage>,
+    /// The substates read from the database while executing and summarizing the transaction
+    /// Available if `ExecutionConfig::enable_substate_read_recording` is enabled
+    pub substate_reads: Option<Vec<SubstateRead>>,
+    /// The number of access rule checks answered from and added to the auth module's cache
+    /// Available if the auth module is enabled
+    pub access_rule_cache_stats: Option<AccessRuleCacheStats>,
+    /// Spans per call frame and kernel operation, with timing and cost attributes
+    /// Available if `ExecutionConfig::with_span_trace` is enabled
+    pub span_trace: Option<SpanTrace>,
+    /// The global address reservations allocated during execution, in allocation order, along
+    /// with the nodes which consumed them
+    pub address_reservations: Vec<AddressReservationRecord>,
+    /// The message attached to the transaction, if any
+    pub message: Option<TransactionMessageMetadata>,
 }
 
 #[derive(Default, Debug, Clone, ScryptoSbor)]
@@ -55,8 +101,10 @@ pub struct TransactionFeeSummary {
     pub total_finalization_cost_in_xrd: Decimal,
     /// Total tipping cost in XRD.
     pub total_tipping_cost_in_xrd: Decimal,
-    /// Total storage cost in XRD.
+    /// Total storage cost in XRD, net of refunds.
     pub total_storage_cost_in_xrd: Decimal,
+    /// Total storage refund in XRD, for state deleted by the transaction.
+    pub total_storage_refund_in_xrd: Decimal,
     /// Total royalty cost in XRD.
     pub total_royalty_cost_in_xrd: Decimal,
 }
@@ -78,9 +126,11 @@ pub enum TransactionResult {
 }
 
 #[derive(Debug, Clone, ScryptoSbor)]
-pub struct CommitResult {
+pub struct CommitResultV2 {
     /// Substate updates
     pub state_updates: StateUpdates,
+    /// The substates read and written, for detecting conflicts with other transactions
+    pub read_write_set: ReadWriteSet,
     /// Information extracted from the substate updates
     pub state_update_summary: StateUpdateSummary,
     /// The source of transaction fee
@@ -89,10 +139,13 @@ pub struct CommitResult {
     pub fee_destination: FeeDestination,
     /// Transaction execution outcome
     pub outcome: TransactionOutcome,
+    /// The resources left on the worktop at the end of the transaction, which were deposited
+    /// into the worktop leftovers recipient
+    pub worktop_leftovers: IndexMap<ResourceAddress, Decimal>,
     /// Events emitted
     pub application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
     /// Logs emitted
-    pub application_logs: Vec<(Level, String)>,
+    pub application_logs: Vec<LogRecord>,
     /// Additional annotation on substates and events
     pub system_structure: SystemStructure,
     /// Transaction execution traces
@@ -178,10 +231,12 @@ impl CommitResult {
     pub fn empty_with_outcome(outcome: TransactionOutcome) -> Self {
         Self {
             state_updates: Default::default(),
+            read_write_set: Default::default(),
             state_update_summary: Default::default(),
             fee_source: Default::default(),
             fee_destination: Default::default(),
             outcome,
+            worktop_leftovers: Default::default(),
             application_events: Default::default(),
             application_logs: Default::default(),
             system_structure: Default::default(),
@@ -215,6 +270,24 @@ impl CommitResult {
         None
     }
 
+    /// Returns the logs emitted at the given level.
+    pub fn logs_at_level(&self, level: Level) -> impl Iterator<Item = &LogRecord> {
+        self.application_logs
+            .iter()
+            .filter(move |record| record.level == level)
+    }
+
+    /// Returns the logs which have a field with the given key and value.
+    pub fn logs_with_field<'a>(
+        &'a self,
+        key: &'a str,
+        value: &'a str,
+    ) -> impl Iterator<Item = &'a LogRecord> {
+        self.application_logs
+            .iter()
+            .filter(move |record| record.field(key) == Some(value))
+    }
+
     pub fn new_package_addresses(&self) -> &IndexSet<PackageAddress> {
         &self.state_update_summary.new_packages
     }
@@ -364,9 +437,25 @@ impl TransactionReceipt {
             fee_details: Default::default(),
             result: TransactionResult::Commit(commit_result),
             resources_usage: Default::default(),
+            substate_reads: Default::default(),
+            access_rule_cache_stats: Default::default(),
+            span_trace: None,
+            address_reservations: Default::default(),
+            message: None,
         }
     }
 
+    /// Encodes the receipt, tagged with its version, for long-term storage.
+    pub fn to_versioned_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        scrypto_encode(&VersionedTransactionReceipt::from(self.clone()))
+    }
+
+    /// Decodes a receipt encoded by [`TransactionReceipt::to_versioned_bytes`] of this or any
+    /// earlier engine version, updating it to the latest version.
+    pub fn from_versioned_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        scrypto_decode::<VersionedTransactionReceipt>(bytes).map(|receipt| receipt.into_latest())
+    }
+
     pub fn is_commit_success(&self) -> bool {
         matches!(
             self.result,
@@ -501,6 +590,17 @@ impl TransactionReceipt {
         }
     }
 
+    /// Decodes the error value of a transaction which failed through an application abort.
+    pub fn expect_abort_error<T: ScryptoDecode>(&self) -> T {
+        match self.expect_failure() {
+            RuntimeError::ApplicationError(ApplicationError::Aborted(error)) => {
+                scrypto_decode(&scrypto_encode(error).unwrap())
+                    .expect("Abort error can't be converted")
+            }
+            error => panic!("Expected abort but was different error:\n{:?}", error),
+        }
+    }
+
     pub fn expect_auth_failure(&self) {
         self.expect_specific_failure(|e| {
             matches!(
@@ -713,6 +813,12 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
             "Network Storage:".bold().green(),
             self.fee_summary.total_storage_cost_in_xrd
         )?;
+        write!(
+            f,
+            "\n├─ {} {} XRD",
+            "Network Storage Refund:".bold().green(),
+            self.fee_summary.total_storage_refund_in_xrd
+        )?;
         write!(
             f,
             "\n└─ {} {} XRD",
@@ -727,8 +833,9 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
                 "Logs:".bold().green(),
                 c.application_logs.len()
             )?;
-            for (i, (level, msg)) in c.application_logs.iter().enumerate() {
-                let (l, m) = match level {
+            for (i, record) in c.application_logs.iter().enumerate() {
+                let msg = record.to_string();
+                let (l, m) = match record.level {
                     Level::Error => ("ERROR".red(), msg.red()),
                     Level::Warn => ("WARN".yellow(), msg.yellow()),
                     Level::Info => ("INFO".green(), msg.green()),
@@ -935,6 +1042,7 @@ impl From<FeeReserveFinalizationSummary> for TransactionFeeSummary {
             total_finalization_cost_in_xrd: value.total_finalization_cost_in_xrd,
             total_tipping_cost_in_xrd: value.total_tipping_cost_in_xrd,
             total_storage_cost_in_xrd: value.total_storage_cost_in_xrd,
+            total_storage_refund_in_xrd: value.total_storage_refund_in_xrd,
             total_royalty_cost_in_xrd: value.total_royalty_cost_in_xrd,
         }
     }
@@ -1019,3 +1127,114 @@ impl TransactionFeeSummary {
             .unwrap()
     }
 }
+
+//========================================================================
+// Previous versions
+//
+// These are frozen copies of the receipt types as encoded by earlier engine versions, and must
+// never be changed. Only the top-level structures which changed shape are copied; nested types
+// are shared with the latest version.
+//========================================================================
+
+#[derive(Clone, ScryptoSbor)]
+pub struct TransactionReceiptV1 {
+    pub costing_parameters: CostingParameters,
+    pub transaction_costing_parameters: TransactionCostingParameters,
+    pub fee_summary: TransactionFeeSummaryV1,
+    pub fee_details: Option<TransactionFeeDetails>,
+    pub result: TransactionResultV1,
+    pub resources_usage: Option<ResourcesUsage>,
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct TransactionFeeSummaryV1 {
+    pub total_execution_cost_units_consumed: u32,
+    pub total_finalization_cost_units_consumed: u32,
+    pub total_execution_cost_in_xrd: Decimal,
+    pub total_finalization_cost_in_xrd: Decimal,
+    pub total_tipping_cost_in_xrd: Decimal,
+    pub total_storage_cost_in_xrd: Decimal,
+    pub total_royalty_cost_in_xrd: Decimal,
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub enum TransactionResultV1 {
+    Commit(CommitResultV1),
+    Reject(RejectResult),
+    Abort(AbortResult),
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct CommitResultV1 {
+    pub state_updates: StateUpdates,
+    pub state_update_summary: StateUpdateSummary,
+    pub fee_source: FeeSource,
+    pub fee_destination: FeeDestination,
+    pub outcome: TransactionOutcome,
+    pub application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
+    pub application_logs: Vec<(Level, String)>,
+    pub system_structure: SystemStructure,
+    pub execution_trace: Option<TransactionExecutionTrace>,
+}
+
+impl From<TransactionReceiptV1> for TransactionReceiptV2 {
+    fn from(value: TransactionReceiptV1) -> Self {
+        Self {
+            costing_parameters: value.costing_parameters,
+            transaction_costing_parameters: value.transaction_costing_parameters,
+            fee_summary: value.fee_summary.into(),
+            fee_details: value.fee_details,
+            result: match value.result {
+                TransactionResultV1::Commit(commit) => TransactionResult::Commit(commit.into()),
+                TransactionResultV1::Reject(reject) => TransactionResult::Reject(reject),
+                TransactionResultV1::Abort(abort) => TransactionResult::Abort(abort),
+            },
+            resources_usage: value.resources_usage,
+            substate_reads: None,
+            access_rule_cache_stats: None,
+            span_trace: None,
+            address_reservations: Default::default(),
+            message: None,
+        }
+    }
+}
+
+impl From<TransactionFeeSummaryV1> for TransactionFeeSummary {
+    fn from(value: TransactionFeeSummaryV1) -> Self {
+        Self {
+            total_execution_cost_units_consumed: value.total_execution_cost_units_consumed,
+            total_finalization_cost_units_consumed: value.total_finalization_cost_units_consumed,
+            total_execution_cost_in_xrd: value.total_execution_cost_in_xrd,
+            total_finalization_cost_in_xrd: value.total_finalization_cost_in_xrd,
+            total_tipping_cost_in_xrd: value.total_tipping_cost_in_xrd,
+            total_storage_cost_in_xrd: value.total_storage_cost_in_xrd,
+            // Storage refunds did not exist yet
+            total_storage_refund_in_xrd: Decimal::ZERO,
+            total_royalty_cost_in_xrd: value.total_royalty_cost_in_xrd,
+        }
+    }
+}
+
+impl From<CommitResultV1> for CommitResultV2 {
+    fn from(value: CommitResultV1) -> Self {
+        Self {
+            state_updates: value.state_updates,
+            // Not recorded by earlier versions
+            read_write_set: Default::default(),
+            state_update_summary: value.state_update_summary,
+            fee_source: value.fee_source,
+            fee_destination: value.fee_destination,
+            outcome: value.outcome,
+            // Worktop leftovers were not allowed by earlier versions
+            worktop_leftovers: index_map_new(),
+            application_events: value.application_events,
+            application_logs: value
+                .application_logs
+                .into_iter()
+                .map(|(level, message)| LogRecord::new(level, message))
+                .collect(),
+            system_structure: value.system_structure,
+            execution_trace: value.execution_trace,
+        }
+    }
+}
@@ -1,10 +1,31 @@
+use crate::blueprints::account::*;
 use crate::blueprints::consensus_manager::*;
 use crate::blueprints::models::KeyValueEntryContentSource;
+use crate::blueprints::order_book::OrderBookNativePackage;
 use crate::blueprints::package::*;
+use crate::blueprints::payment_scheduler::PaymentSchedulerNativePackage;
 use crate::blueprints::pool::v1::constants::*;
+use crate::blueprints::pool::v1::package::{PoolNativePackage, PoolV1MinorVersion};
+use crate::blueprints::price_feed::PriceFeedNativePackage;
+use crate::blueprints::resource::{
+    next_reservable_id_after, FungibleVaultBalanceFieldPayload, FungibleVaultField,
+    NonFungibleResourceManagerCollection, NonFungibleResourceManagerFeature,
+    NonFungibleResourceManagerField, NonFungibleResourceManagerNextReservableIdFieldPayload,
+    ResourceNativePackage,
+};
+use crate::blueprints::transaction_processor::{
+    DeferredExecutionQueueField, DeferredExecutionQueueStateFieldPayload,
+    DeferredExecutionQueueSubstate, TransactionProcessorNativePackage,
+};
 use crate::internal_prelude::*;
+use crate::system::attached_modules::role_assignment::{
+    OwnerRoleSubstate, RoleAssignmentField, RoleAssignmentNativePackage,
+    RoleAssignmentOwnerFieldPayload,
+};
 use crate::system::system_db_reader::{ObjectCollectionKey, SystemDatabaseReader};
+use crate::system::type_info::TypeInfoSubstate;
 use crate::track::{NodeStateUpdates, PartitionStateUpdates, StateUpdates};
+use crate::vm::wasm::{SCRPYTO_VM_CRYPTO_UTILS_MINOR_VERSION, SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION};
 use crate::vm::*;
 use radix_engine_common::constants::*;
 use radix_engine_common::crypto::hash;
@@ -14,16 +35,28 @@ use radix_engine_common::prelude::{scrypto_encode, ScryptoCustomTypeKind};
 use radix_engine_common::types::SubstateKey;
 use radix_engine_interface::api::ObjectModuleId;
 use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_interface::blueprints::transaction_processor::*;
 use radix_engine_interface::prelude::*;
 use radix_engine_interface::types::CollectionDescriptor;
+use radix_engine_store_interface::db_key_mapper::{DatabaseKeyMapper, SpreadPrefixKeyMapper};
 use radix_engine_store_interface::interface::*;
 use sbor::HasLatestVersion;
 use sbor::{generate_full_schema, TypeAggregator};
 use utils::indexmap;
 
 pub fn generate_vm_boot_scrypto_minor_version_state_updates() -> StateUpdates {
+    generate_vm_boot_state_updates(SCRPYTO_VM_CRYPTO_UTILS_MINOR_VERSION)
+}
+
+/// Generates the state updates required for allowing Scrypto packages to import the functions
+/// added to the Scrypto VM in the Cuttlefish update
+pub fn generate_vm_boot_cuttlefish_minor_version_state_updates() -> StateUpdates {
+    generate_vm_boot_state_updates(SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION)
+}
+
+fn generate_vm_boot_state_updates(scrypto_v1_minor_version: u64) -> StateUpdates {
     let substate = scrypto_encode(&VmBoot::V1 {
-        scrypto_v1_minor_version: 1u64,
+        scrypto_v1_minor_version,
     })
     .unwrap();
 
@@ -45,6 +78,33 @@ pub fn generate_vm_boot_scrypto_minor_version_state_updates() -> StateUpdates {
 /// Generates the state updates required for updating the Consensus Manager blueprint
 /// to use seconds precision
 pub fn generate_seconds_precision_state_updates<S: SubstateDatabase>(db: &S) -> StateUpdates {
+    generate_consensus_manager_time_state_updates::<
+        S,
+        ConsensusManagerGetCurrentTimeInputV2,
+        ConsensusManagerCompareCurrentTimeInputV2,
+    >(db, CONSENSUS_MANAGER_SECONDS_PRECISION_CODE_ID)
+}
+
+/// Generates the state updates required for updating the Consensus Manager blueprint to accept
+/// the rounded time precisions. Builds on the seconds precision update.
+pub fn generate_rounded_time_state_updates<S: SubstateDatabase>(db: &S) -> StateUpdates {
+    generate_consensus_manager_time_state_updates::<
+        S,
+        ConsensusManagerGetCurrentTimeInputV3,
+        ConsensusManagerCompareCurrentTimeInputV3,
+    >(db, CONSENSUS_MANAGER_ROUNDED_TIME_CODE_ID)
+}
+
+/// Points the time methods of the Consensus Manager blueprint at the given native code, and
+/// their inputs at the given types.
+fn generate_consensus_manager_time_state_updates<
+    S: SubstateDatabase,
+    GetCurrentTimeInput: ScryptoDescribe,
+    CompareCurrentTimeInput: ScryptoDescribe,
+>(
+    db: &S,
+    code_id: u64,
+) -> StateUpdates {
     let reader = SystemDatabaseReader::new(db);
     let consensus_mgr_pkg_node_id = CONSENSUS_MANAGER_PACKAGE.into_node_id();
     let bp_version_key = BlueprintVersionKey {
@@ -54,9 +114,7 @@ pub fn generate_seconds_precision_state_updates<S: SubstateDatabase>(db: &S) ->
 
     // Generate the new code substates
     let (new_code_substate, new_vm_type_substate, code_hash) = {
-        let original_code = CONSENSUS_MANAGER_SECONDS_PRECISION_CODE_ID
-            .to_be_bytes()
-            .to_vec();
+        let original_code = code_id.to_be_bytes().to_vec();
 
         let code_hash = CodeHash::from_hash(hash(&original_code));
         let versioned_code = VersionedPackageCodeOriginalCode::V1(PackageCodeOriginalCodeV1 {
@@ -79,22 +137,22 @@ pub fn generate_seconds_precision_state_updates<S: SubstateDatabase>(db: &S) ->
     // Generate the new schema substate
     let (
         new_schema_substate,
-        get_current_time_input_v2_type_id,
-        compare_current_time_input_v2_type_id,
+        get_current_time_input_type_id,
+        compare_current_time_input_type_id,
         new_schema_hash,
     ) = {
         let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
-        let get_current_time_input_v2 =
-            aggregator.add_child_type_and_descendents::<ConsensusManagerGetCurrentTimeInputV2>();
-        let compare_current_time_input_v2 = aggregator
-            .add_child_type_and_descendents::<ConsensusManagerCompareCurrentTimeInputV2>();
+        let get_current_time_input =
+            aggregator.add_child_type_and_descendents::<GetCurrentTimeInput>();
+        let compare_current_time_input =
+            aggregator.add_child_type_and_descendents::<CompareCurrentTimeInput>();
         let schema = generate_full_schema(aggregator);
         let schema_hash = schema.generate_schema_hash();
         let schema_substate = schema.into_locked_substate();
         (
             scrypto_encode(&schema_substate).unwrap(),
-            get_current_time_input_v2,
-            compare_current_time_input_v2,
+            get_current_time_input,
+            compare_current_time_input,
             schema_hash,
         )
     };
@@ -127,7 +185,7 @@ pub fn generate_seconds_precision_state_updates<S: SubstateDatabase>(db: &S) ->
             .unwrap();
         function_schema.input = BlueprintPayloadDef::Static(ScopedTypeId(
             new_schema_hash,
-            get_current_time_input_v2_type_id,
+            get_current_time_input_type_id,
         ));
 
         let export = definition
@@ -142,7 +200,7 @@ pub fn generate_seconds_precision_state_updates<S: SubstateDatabase>(db: &S) ->
             .unwrap();
         function_schema.input = BlueprintPayloadDef::Static(ScopedTypeId(
             new_schema_hash,
-            compare_current_time_input_v2_type_id,
+            compare_current_time_input_type_id,
         ));
 
         scrypto_encode(
@@ -383,3 +441,608 @@ pub fn generate_validator_fee_fix_state_updates<S: SubstateDatabase>(db: &S) ->
         ),
     }
 }
+
+/// Generates the state updates required for initializing the fee split of the validators created
+/// before it was introduced, to no recipients.
+pub fn generate_validator_fee_split_state_updates<
+    S: SubstateDatabase + ListableSubstateDatabase,
+>(
+    db: &S,
+) -> StateUpdates {
+    let fee_split = ValidatorFeeSplitFieldPayload::from_content_source(ValidatorFeeSplitSubstate {
+        recipients: vec![],
+    });
+
+    generate_missing_field_state_updates(
+        db,
+        |entity_type| entity_type == EntityType::GlobalValidator,
+        ValidatorField::FeeSplit.field_index(),
+        scrypto_encode(&fee_split.into_unlocked_substate()).unwrap(),
+    )
+}
+
+/// Generates the state updates required for initializing the epoch change hooks of a Consensus
+/// Manager created before they were introduced, to no hooks.
+pub fn generate_epoch_change_hooks_state_updates<S: SubstateDatabase + ListableSubstateDatabase>(
+    db: &S,
+) -> StateUpdates {
+    let epoch_change_hooks = ConsensusManagerEpochChangeHooksFieldPayload::from_content_source(
+        EpochChangeHooksSubstate {
+            hooks: index_map_new(),
+        },
+    );
+
+    generate_missing_field_state_updates(
+        db,
+        |entity_type| entity_type == EntityType::GlobalConsensusManager,
+        ConsensusManagerField::EpochChangeHooks.field_index(),
+        scrypto_encode(&epoch_change_hooks.into_unlocked_substate()).unwrap(),
+    )
+}
+
+/// Generates the state updates required for initializing the authorizer of the accounts created
+/// before it was introduced, to no authorizer.
+pub fn generate_account_authorizer_state_updates<S: SubstateDatabase + ListableSubstateDatabase>(
+    db: &S,
+) -> StateUpdates {
+    let authorizer = AccountAuthorizerFieldPayload::from_content_source(AccountAuthorizerV1 {
+        authorizer: None,
+    });
+
+    generate_missing_field_state_updates(
+        db,
+        |entity_type| {
+            matches!(
+                entity_type,
+                EntityType::GlobalAccount
+                    | EntityType::GlobalVirtualSecp256k1Account
+                    | EntityType::GlobalVirtualEd25519Account
+            )
+        },
+        AccountField::Authorizer.field_index(),
+        scrypto_encode(&authorizer.into_unlocked_substate()).unwrap(),
+    )
+}
+
+/// Generates the state updates required for initializing the next reservable id of the mintable
+/// non-fungible resources created before it was introduced, to the id following the largest
+/// integer id they ever minted (burnt ones included).
+pub fn generate_non_fungible_next_reservable_id_state_updates<
+    S: SubstateDatabase + ListableSubstateDatabase,
+>(
+    db: &S,
+) -> StateUpdates {
+    let reader = SystemDatabaseReader::new(db);
+    let field_index = NonFungibleResourceManagerField::NextReservableId.field_index();
+
+    let mut node_ids: Vec<NodeId> = db
+        .list_partition_keys()
+        .map(|partition_key| SpreadPrefixKeyMapper::from_db_partition_key(&partition_key))
+        .filter(|(node_id, partition_num)| {
+            *partition_num == MAIN_BASE_PARTITION
+                && node_id.entity_type() == Some(EntityType::GlobalNonFungibleResourceManager)
+        })
+        .map(|(node_id, _)| node_id)
+        .filter(|node_id| {
+            let object_info = reader.get_object_info(*node_id).unwrap();
+            object_info
+                .blueprint_info
+                .features
+                .contains(NonFungibleResourceManagerFeature::Mint.feature_name())
+                && db
+                    .get_substate(
+                        &SpreadPrefixKeyMapper::to_db_partition_key(node_id, MAIN_BASE_PARTITION),
+                        &SpreadPrefixKeyMapper::to_db_sort_key(&SubstateKey::Field(field_index)),
+                    )
+                    .is_none()
+        })
+        .collect();
+    node_ids.sort();
+
+    StateUpdates {
+        by_node: node_ids
+            .into_iter()
+            .map(|node_id| {
+                let data_partition_num = reader
+                    .get_partition_of_collection(
+                        &node_id,
+                        ObjectModuleId::Main,
+                        NonFungibleResourceManagerCollection::DataKeyValue.collection_index(),
+                    )
+                    .unwrap();
+                let ids: Vec<NonFungibleLocalId> = db
+                    .list_entries(&SpreadPrefixKeyMapper::to_db_partition_key(
+                        &node_id,
+                        data_partition_num,
+                    ))
+                    .map(|(db_sort_key, _)| {
+                        scrypto_decode(&SpreadPrefixKeyMapper::map_from_db_sort_key(&db_sort_key))
+                            .unwrap()
+                    })
+                    .collect();
+                let next_reservable_id =
+                    NonFungibleResourceManagerNextReservableIdFieldPayload::from_content_source(
+                        next_reservable_id_after(ids.iter(), 1),
+                    );
+
+                (
+                    node_id,
+                    NodeStateUpdates::Delta {
+                        by_partition: indexmap! {
+                            MAIN_BASE_PARTITION => PartitionStateUpdates::Delta {
+                                by_substate: indexmap! {
+                                    SubstateKey::Field(field_index) => DatabaseUpdate::Set(
+                                        scrypto_encode(&next_reservable_id.into_unlocked_substate()).unwrap()
+                                    )
+                                }
+                            },
+                        },
+                    },
+                )
+            })
+            .collect(),
+    }
+}
+
+/// Generates the state updates required for rewriting the Account package to its current
+/// definition.
+pub fn generate_account_package_definition_state_updates<S: SubstateDatabase>(
+    db: &S,
+) -> StateUpdates {
+    generate_native_package_definition_state_updates(
+        db,
+        ACCOUNT_PACKAGE,
+        AccountNativePackage::definition(),
+        ACCOUNT_CODE_ID,
+        btreemap!(),
+    )
+}
+
+/// Generates the state updates required for rewriting the Consensus Manager package to its
+/// current definition.
+pub fn generate_consensus_manager_package_definition_state_updates<S: SubstateDatabase>(
+    db: &S,
+) -> StateUpdates {
+    generate_native_package_definition_state_updates(
+        db,
+        CONSENSUS_MANAGER_PACKAGE,
+        ConsensusManagerNativePackage::definition(),
+        CONSENSUS_MANAGER_CODE_ID,
+        btreemap!(),
+    )
+}
+
+/// Generates the state updates required for rewriting the Package package to its current
+/// definition.
+pub fn generate_package_package_definition_state_updates<S: SubstateDatabase>(
+    db: &S,
+) -> StateUpdates {
+    generate_native_package_definition_state_updates(
+        db,
+        PACKAGE_PACKAGE,
+        PackageNativePackage::definition(),
+        PACKAGE_CODE_ID,
+        // Maps the application layer schema collection index to the system layer schema partition
+        btreemap! {
+            PACKAGE_BLUEPRINT.to_string() => vec![SystemInstruction::MapCollectionToPhysicalPartition {
+                collection_index: PackageCollection::SchemaKeyValue.collection_index(),
+                partition_num: SCHEMAS_PARTITION,
+            }],
+        },
+    )
+}
+
+/// Generates the state updates required for rewriting the Pool package to its current v1.1
+/// definition.
+pub fn generate_pool_package_definition_state_updates<S: SubstateDatabase>(db: &S) -> StateUpdates {
+    generate_native_package_definition_state_updates(
+        db,
+        POOL_PACKAGE,
+        PoolNativePackage::definition(PoolV1MinorVersion::One),
+        POOL_V1_1_CODE_ID,
+        btreemap!(),
+    )
+}
+
+/// Generates the state updates required for rewriting the Resource package to its current
+/// definition.
+pub fn generate_resource_package_definition_state_updates<S: SubstateDatabase>(
+    db: &S,
+) -> StateUpdates {
+    generate_native_package_definition_state_updates(
+        db,
+        RESOURCE_PACKAGE,
+        ResourceNativePackage::definition(),
+        RESOURCE_CODE_ID,
+        btreemap!(),
+    )
+}
+
+/// Generates the state updates required for rewriting the Role Assignment module package to its
+/// current definition.
+pub fn generate_role_assignment_package_definition_state_updates<S: SubstateDatabase>(
+    db: &S,
+) -> StateUpdates {
+    generate_native_package_definition_state_updates(
+        db,
+        ROLE_ASSIGNMENT_MODULE_PACKAGE,
+        RoleAssignmentNativePackage::definition(),
+        ROLE_ASSIGNMENT_CODE_ID,
+        btreemap!(),
+    )
+}
+
+/// Generates the state updates required for rewriting the Transaction Processor package to its
+/// current definition.
+pub fn generate_transaction_processor_package_definition_state_updates<S: SubstateDatabase>(
+    db: &S,
+) -> StateUpdates {
+    generate_native_package_definition_state_updates(
+        db,
+        TRANSACTION_PROCESSOR_PACKAGE,
+        TransactionProcessorNativePackage::definition(),
+        TRANSACTION_PROCESSOR_CODE_ID,
+        btreemap!(),
+    )
+}
+
+/// Generates the state updates required for publishing the Price Feed package on a ledger
+/// bootstrapped before it was added to genesis.
+pub fn generate_price_feed_package_state_updates<S: SubstateDatabase>(db: &S) -> StateUpdates {
+    generate_native_package_publish_state_updates(
+        db,
+        PRICE_FEED_PACKAGE,
+        PriceFeedNativePackage::definition(),
+        PRICE_FEED_CODE_ID,
+        metadata_init! {
+            "name" => "Price Feed Package".to_owned(), locked;
+            "description" => "A native package that defines the logic of price feed oracle components.".to_owned(), locked;
+        },
+    )
+}
+
+/// Generates the state updates required for publishing the Payment Scheduler package on a ledger
+/// bootstrapped before it was added to genesis.
+pub fn generate_payment_scheduler_package_state_updates<S: SubstateDatabase>(
+    db: &S,
+) -> StateUpdates {
+    generate_native_package_publish_state_updates(
+        db,
+        PAYMENT_SCHEDULER_PACKAGE,
+        PaymentSchedulerNativePackage::definition(),
+        PAYMENT_SCHEDULER_CODE_ID,
+        metadata_init! {
+            "name" => "Payment Scheduler Package".to_owned(), locked;
+            "description" => "A native package that defines the logic of recurring payment scheduler components.".to_owned(), locked;
+        },
+    )
+}
+
+/// Generates the state updates required for publishing the Order Book package on a ledger
+/// bootstrapped before it was added to genesis.
+pub fn generate_order_book_package_state_updates<S: SubstateDatabase>(db: &S) -> StateUpdates {
+    generate_native_package_publish_state_updates(
+        db,
+        ORDER_BOOK_PACKAGE,
+        OrderBookNativePackage::definition(),
+        ORDER_BOOK_CODE_ID,
+        metadata_init! {
+            "name" => "Order Book Package".to_owned(), locked;
+            "description" => "A native package that defines the logic of price-time priority order books.".to_owned(), locked;
+        },
+    )
+}
+
+/// Generates the state updates required for creating the Deferred Execution Queue on a ledger
+/// bootstrapped before it was added to genesis.
+///
+/// The queue is created the way its `create` function creates it, i.e. with no owner, no
+/// metadata and an empty XRD vault. As there is no transaction to allocate the id of the vault
+/// from, it is derived from the address of the queue.
+pub fn generate_deferred_execution_queue_state_updates<S: SubstateDatabase>(
+    db: &S,
+) -> StateUpdates {
+    let queue_node_id = DEFERRED_EXECUTION_QUEUE.into_node_id();
+    if type_info_exists(db, &queue_node_id) {
+        return StateUpdates::default();
+    }
+
+    let vault_node_id = NodeId::new(
+        EntityType::InternalFungibleVault as u8,
+        &hash(queue_node_id.0).lower_bytes(),
+    );
+
+    let queue_type_info = TypeInfoSubstate::Object(ObjectInfo {
+        blueprint_info: BlueprintInfo {
+            blueprint_id: BlueprintId::new(
+                &TRANSACTION_PROCESSOR_PACKAGE,
+                DEFERRED_EXECUTION_QUEUE_BLUEPRINT,
+            ),
+            blueprint_version: BlueprintVersion::default(),
+            outer_obj_info: OuterObjectInfo::None,
+            features: index_set_new(),
+            generic_substitutions: vec![],
+        },
+        object_type: ObjectType::Global {
+            modules: indexmap!(
+                AttachedModuleId::RoleAssignment => BlueprintVersion::default(),
+                AttachedModuleId::Metadata => BlueprintVersion::default(),
+            ),
+        },
+    });
+    let queue_state = DeferredExecutionQueueStateFieldPayload::from_content_source(
+        DeferredExecutionQueueSubstate {
+            vault: Vault(Own(vault_node_id)),
+            next_intent_id: 0,
+        },
+    );
+    // An owner role which can't be updated is stored locked, as in `RoleAssignment::create`
+    let owner_role = RoleAssignmentOwnerFieldPayload::from_content_source(OwnerRoleSubstate {
+        owner_role_entry: OwnerRole::None.into(),
+    });
+
+    let vault_type_info = TypeInfoSubstate::Object(ObjectInfo {
+        blueprint_info: BlueprintInfo {
+            blueprint_id: BlueprintId::new(&RESOURCE_PACKAGE, FUNGIBLE_VAULT_BLUEPRINT),
+            blueprint_version: BlueprintVersion::default(),
+            outer_obj_info: OuterObjectInfo::Some {
+                outer_object: XRD.into(),
+            },
+            features: index_set_new(),
+            generic_substitutions: vec![],
+        },
+        object_type: ObjectType::Owned,
+    });
+    let vault_balance =
+        FungibleVaultBalanceFieldPayload::from_content_source(LiquidFungibleResource::default());
+
+    StateUpdates {
+        by_node: indexmap!(
+            queue_node_id => NodeStateUpdates::Delta {
+                by_partition: indexmap! {
+                    TYPE_INFO_FIELD_PARTITION => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            TypeInfoField::TypeInfo.into() => DatabaseUpdate::Set(
+                                scrypto_encode(&queue_type_info).unwrap()
+                            )
+                        }
+                    },
+                    MAIN_BASE_PARTITION => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            SubstateKey::Field(DeferredExecutionQueueField::State.field_index()) => DatabaseUpdate::Set(
+                                scrypto_encode(&queue_state.into_unlocked_substate()).unwrap()
+                            )
+                        }
+                    },
+                    ROLE_ASSIGNMENT_FIELDS_PARTITION => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            SubstateKey::Field(RoleAssignmentField::Owner.field_index()) => DatabaseUpdate::Set(
+                                scrypto_encode(&owner_role.into_locked_substate()).unwrap()
+                            )
+                        }
+                    },
+                }
+            },
+            vault_node_id => NodeStateUpdates::Delta {
+                by_partition: indexmap! {
+                    TYPE_INFO_FIELD_PARTITION => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            TypeInfoField::TypeInfo.into() => DatabaseUpdate::Set(
+                                scrypto_encode(&vault_type_info).unwrap()
+                            )
+                        }
+                    },
+                    MAIN_BASE_PARTITION => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            SubstateKey::Field(FungibleVaultField::Balance.field_index()) => DatabaseUpdate::Set(
+                                scrypto_encode(&vault_balance.into_unlocked_substate()).unwrap()
+                            )
+                        }
+                    },
+                }
+            }
+        ),
+    }
+}
+
+/// Rewrites the blueprint definitions of a native package, along with their dependencies,
+/// schemas, auth and royalty configs and code, to the ones of its current definition, making the
+/// functions, fields and collections added to its blueprints after it was published available on
+/// ledger.
+///
+/// Functions which an earlier migration pointed at different native code, such as the time
+/// methods of the Consensus Manager, keep their existing export and schema.
+fn generate_native_package_definition_state_updates<S: SubstateDatabase>(
+    db: &S,
+    package_address: PackageAddress,
+    definition: PackageDefinition,
+    native_package_code_id: u64,
+    system_instructions: BTreeMap<String, Vec<SystemInstruction>>,
+) -> StateUpdates {
+    let reader = SystemDatabaseReader::new(db);
+    let package_node_id = package_address.into_node_id();
+    let code_hash = CodeHash::from_hash(hash(native_package_code_id.to_be_bytes()));
+
+    let mut package_structure =
+        build_native_package_structure(definition, native_package_code_id, system_instructions);
+
+    for (blueprint, definition_payload) in package_structure.definitions.iter_mut() {
+        let Some(existing_definition) = reader
+            .read_object_collection_entry::<_, VersionedPackageBlueprintVersionDefinition>(
+                &package_node_id,
+                ObjectModuleId::Main,
+                ObjectCollectionKey::KeyValue(
+                    PackageCollection::BlueprintVersionDefinitionKeyValue.collection_index(),
+                    &BlueprintVersionKey::new_default(blueprint.clone()),
+                ),
+            )
+            .unwrap()
+        else {
+            continue;
+        };
+        let existing_definition = existing_definition.into_latest();
+
+        let mut definition = definition_payload.clone().into_latest();
+        for (function, export) in existing_definition.function_exports {
+            if export.code_hash == code_hash || !definition.function_exports.contains_key(&function)
+            {
+                continue;
+            }
+            let function_schema = existing_definition
+                .interface
+                .functions
+                .get(&function)
+                .unwrap()
+                .clone();
+            definition
+                .interface
+                .functions
+                .insert(function.clone(), function_schema);
+            definition.function_exports.insert(function, export);
+        }
+        *definition_payload = definition.into_payload();
+    }
+
+    // Only the collections of the package are rewritten, leaving its royalty accumulator,
+    // metadata and role assignment as they are
+    let by_partition =
+        create_package_partition_substates(package_structure, metadata_init!(), None)
+            .into_iter()
+            .filter(|(partition_num, _)| {
+                *partition_num == SCHEMAS_PARTITION || *partition_num > MAIN_BASE_PARTITION
+            })
+            .map(|(partition_num, substates)| {
+                (
+                    partition_num,
+                    PartitionStateUpdates::Delta {
+                        by_substate: substates
+                            .into_iter()
+                            .map(|(key, value)| (key, DatabaseUpdate::Set(value.into())))
+                            .collect(),
+                    },
+                )
+            })
+            .collect();
+
+    StateUpdates {
+        by_node: indexmap!(
+            package_node_id => NodeStateUpdates::Delta { by_partition }
+        ),
+    }
+}
+
+/// Publishes a native package at its reserved address, the way the genesis flash publishes the
+/// system packages. Ledgers which already have the package, i.e. the ones bootstrapped after it
+/// was added to genesis, are left as they are.
+fn generate_native_package_publish_state_updates<S: SubstateDatabase>(
+    db: &S,
+    package_address: PackageAddress,
+    definition: PackageDefinition,
+    native_package_code_id: u64,
+    metadata_init: MetadataInit,
+) -> StateUpdates {
+    let package_node_id = package_address.into_node_id();
+    if type_info_exists(db, &package_node_id) {
+        return StateUpdates::default();
+    }
+
+    let package_structure =
+        build_native_package_structure(definition, native_package_code_id, btreemap!());
+    let by_partition = create_package_partition_substates(package_structure, metadata_init, None)
+        .into_iter()
+        .map(|(partition_num, substates)| {
+            (
+                partition_num,
+                PartitionStateUpdates::Delta {
+                    by_substate: substates
+                        .into_iter()
+                        .map(|(key, value)| (key, DatabaseUpdate::Set(value.into())))
+                        .collect(),
+                },
+            )
+        })
+        .collect();
+
+    StateUpdates {
+        by_node: indexmap!(
+            package_node_id => NodeStateUpdates::Delta { by_partition }
+        ),
+    }
+}
+
+fn type_info_exists<S: SubstateDatabase>(db: &S, node_id: &NodeId) -> bool {
+    db.get_substate(
+        &SpreadPrefixKeyMapper::to_db_partition_key(node_id, TYPE_INFO_FIELD_PARTITION),
+        &SpreadPrefixKeyMapper::to_db_sort_key(&TypeInfoField::TypeInfo.into()),
+    )
+    .is_some()
+}
+
+fn build_native_package_structure(
+    definition: PackageDefinition,
+    native_package_code_id: u64,
+    system_instructions: BTreeMap<String, Vec<SystemInstruction>>,
+) -> PackageStructure {
+    PackageNativePackage::validate_and_build_package_structure(
+        definition,
+        VmType::Native,
+        native_package_code_id.to_be_bytes().to_vec(),
+        system_instructions,
+        &VmVersion::default(),
+    )
+    .unwrap_or_else(|err| {
+        panic!(
+            "Invalid flashed Package definition with native_code_id {}: {:?}",
+            native_package_code_id, err
+        )
+    })
+}
+
+/// Sets a main module field, added to a blueprint after objects of it were created, on every
+/// object of the matching entity types which doesn't have the field yet.
+fn generate_missing_field_state_updates<S: SubstateDatabase + ListableSubstateDatabase>(
+    db: &S,
+    is_matching_entity_type: impl Fn(EntityType) -> bool,
+    field_index: FieldIndex,
+    substate_value: Vec<u8>,
+) -> StateUpdates {
+    let mut node_ids: Vec<NodeId> = db
+        .list_partition_keys()
+        .map(|partition_key| SpreadPrefixKeyMapper::from_db_partition_key(&partition_key))
+        .filter(|(node_id, partition_num)| {
+            *partition_num == MAIN_BASE_PARTITION
+                && node_id
+                    .entity_type()
+                    .map_or(false, &is_matching_entity_type)
+        })
+        .map(|(node_id, _)| node_id)
+        .filter(|node_id| {
+            db.get_substate(
+                &SpreadPrefixKeyMapper::to_db_partition_key(node_id, MAIN_BASE_PARTITION),
+                &SpreadPrefixKeyMapper::to_db_sort_key(&SubstateKey::Field(field_index)),
+            )
+            .is_none()
+        })
+        .collect();
+    node_ids.sort();
+
+    StateUpdates {
+        by_node: node_ids
+            .into_iter()
+            .map(|node_id| {
+                (
+                    node_id,
+                    NodeStateUpdates::Delta {
+                        by_partition: indexmap! {
+                            MAIN_BASE_PARTITION => PartitionStateUpdates::Delta {
+                                by_substate: indexmap! {
+                                    SubstateKey::Field(field_index) => DatabaseUpdate::Set(substate_value.clone())
+                                }
+                            },
+                        },
+                    },
+                )
+            })
+            .collect(),
+    }
+}
@@ -19,13 +19,32 @@ macro_rules! event_schema {
 #[macro_export]
 macro_rules! add_role {
     ($roles:expr, $role:expr) => {{
-        $roles.insert(
-            $role.into(),
-            radix_engine_interface::blueprints::resource::RoleList::none(),
-        );
+        if $roles
+            .insert(
+                $role.into(),
+                radix_engine_interface::blueprints::resource::RoleList::none(),
+            )
+            .is_some()
+        {
+            panic!("Role `{}` is declared more than once", stringify!($role));
+        }
     }};
     ($roles:expr, $role:expr => updaters: $updaters:expr) => {{
-        $roles.insert($role.into(), $updaters.into());
+        if $roles.insert($role.into(), $updaters.into()).is_some() {
+            panic!("Role `{}` is declared more than once", stringify!($role));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! add_method_accessibility {
+    ($methods:expr, $method:expr => $entry:expr) => {{
+        if $methods.insert($method.into(), $entry.into()).is_some() {
+            panic!(
+                "Method `{}` is declared more than once in the method auth template",
+                stringify!($method)
+            );
+        }
     }};
 }
 
@@ -40,7 +59,7 @@ macro_rules! method_auth_template {
         let mut methods: IndexMap<radix_engine_interface::blueprints::resource::MethodKey, radix_engine_interface::blueprints::resource::MethodAccessibility>
             = index_map_new();
         $(
-            methods.insert($method.into(), $entry.into());
+            crate::add_method_accessibility!(methods, $method => $entry);
         )*
         methods
     });
@@ -61,7 +80,7 @@ macro_rules! roles_template {
         let mut methods: IndexMap<radix_engine_interface::blueprints::resource::MethodKey, radix_engine_interface::blueprints::resource::MethodAccessibility>
             = index_map_new();
         $(
-            methods.insert($method.into(), $entry.into());
+            crate::add_method_accessibility!(methods, $method => $entry);
         )*
 
         let mut roles: IndexMap<radix_engine_interface::blueprints::resource::RoleKey, radix_engine_interface::blueprints::resource::RoleList> = index_map_new();
@@ -69,21 +88,149 @@ macro_rules! roles_template {
             crate::add_role!(roles, $role $( => updaters: $updaters)?);
         )*
 
-        radix_engine_interface::blueprints::package::StaticRoleDefinition {
+        let definition = radix_engine_interface::blueprints::package::StaticRoleDefinition {
             roles: radix_engine_interface::blueprints::package::RoleSpecification::Normal(roles),
             methods,
-        }
+        };
+        crate::blueprints::package::assert_referenced_roles_are_declared(&definition);
+        definition
     });
     ( methods { $($method:expr => $entry:expr;)* }) => ({
         let mut methods: IndexMap<radix_engine_interface::blueprints::resource::MethodKey, radix_engine_interface::blueprints::resource::MethodAccessibility>
             = index_map_new();
         $(
-            methods.insert($method.into(), $entry.into());
+            crate::add_method_accessibility!(methods, $method => $entry);
         )*
 
-        radix_engine_interface::blueprints::package::StaticRoleDefinition {
+        let definition = radix_engine_interface::blueprints::package::StaticRoleDefinition {
             roles: radix_engine_interface::blueprints::package::RoleSpecification::Normal(index_map_new()),
             methods,
-        }
+        };
+        crate::blueprints::package::assert_referenced_roles_are_declared(&definition);
+        definition
     });
 }
+
+/// Declares an enum with a variant for each export of a native package.
+///
+/// `from_export_name` resolves an export name through a hash table which is built once on first
+/// use, so `invoke_export` can dispatch with a single lookup and an integer `match` instead of
+/// comparing the export name against every export of the package in turn.
+#[macro_export]
+macro_rules! native_exports {
+    (
+        $vis:vis enum $name:ident {
+            $($variant:ident => $export_name:expr,)*
+        }
+    ) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($variant,)*
+        }
+
+        impl $name {
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)*];
+
+            pub fn export_name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $export_name,)*
+                }
+            }
+
+            pub fn from_export_name(
+                export_name: &str,
+            ) -> Result<Self, $crate::errors::RuntimeError> {
+                lazy_static::lazy_static! {
+                    static ref EXPORTS: sbor::rust::collections::NonIterMap<&'static str, $name> =
+                        $name::ALL
+                            .iter()
+                            .map(|export| (export.export_name(), *export))
+                            .collect();
+                }
+
+                EXPORTS.get(export_name).copied().ok_or_else(|| {
+                    $crate::errors::RuntimeError::ApplicationError(
+                        $crate::errors::ApplicationError::ExportDoesNotExist(
+                            export_name.to_string(),
+                        ),
+                    )
+                })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::*;
+
+    #[test]
+    fn roles_template_accepts_declared_and_reserved_roles() {
+        let definition = roles_template! {
+            roles {
+                "minter" => updaters: ["minter_updater"];
+                "minter_updater" => updaters: [SELF_ROLE];
+            },
+            methods {
+                "mint" => ["minter"];
+                "configure" => [OWNER_ROLE];
+                "get" => MethodAccessibility::Public;
+            }
+        };
+
+        assert_eq!(definition.methods.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Role `\"minter\"` is declared more than once")]
+    fn roles_template_rejects_duplicate_roles() {
+        roles_template! {
+            roles {
+                "minter";
+                "minter";
+            },
+            methods {
+                "mint" => ["minter"];
+            }
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "Method `\"mint\"` is declared more than once")]
+    fn roles_template_rejects_duplicate_methods() {
+        roles_template! {
+            roles {
+                "minter";
+            },
+            methods {
+                "mint" => ["minter"];
+                "mint" => MethodAccessibility::Public;
+            }
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "Role `burner` is referenced by method `burn`")]
+    fn roles_template_rejects_undeclared_method_roles() {
+        roles_template! {
+            methods {
+                "burn" => ["burner"];
+            }
+        };
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Role `minter_updater` is referenced by the updaters of role `minter`"
+    )]
+    fn roles_template_rejects_undeclared_updater_roles() {
+        roles_template! {
+            roles {
+                "minter" => updaters: ["minter_updater"];
+            },
+            methods {
+                "mint" => ["minter"];
+            }
+        };
+    }
+}
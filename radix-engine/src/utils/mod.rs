@@ -5,6 +5,7 @@ mod macros;
 mod native_blueprint_call_validator;
 mod package_extractor;
 mod panics;
+mod wasm_metering_audit;
 
 #[cfg(feature = "coverage")]
 pub use coverage::*;
@@ -12,3 +13,4 @@ pub use flash_state_generator::*;
 pub use native_blueprint_call_validator::*;
 pub use package_extractor::*;
 pub use panics::*;
+pub use wasm_metering_audit::*;
@@ -165,6 +165,22 @@ fn get_arguments_schema<'s>(
             get_blueprint_schema(&POOL_PACKAGE_DEFINITION_V1_0, package_address, blueprint)
                 .map(Some)?
         }
+        Invocation::Function(package_address @ PRICE_FEED_PACKAGE, ref blueprint, _) => {
+            get_blueprint_schema(&PRICE_FEED_PACKAGE_DEFINITION, package_address, blueprint)
+                .map(Some)?
+        }
+        Invocation::Function(package_address @ PAYMENT_SCHEDULER_PACKAGE, ref blueprint, _) => {
+            get_blueprint_schema(
+                &PAYMENT_SCHEDULER_PACKAGE_DEFINITION,
+                package_address,
+                blueprint,
+            )
+            .map(Some)?
+        }
+        Invocation::Function(package_address @ ORDER_BOOK_PACKAGE, ref blueprint, _) => {
+            get_blueprint_schema(&ORDER_BOOK_PACKAGE_DEFINITION, package_address, blueprint)
+                .map(Some)?
+        }
         Invocation::Function(package_address @ TRANSACTION_PROCESSOR_PACKAGE, ref blueprint, _) => {
             get_blueprint_schema(
                 &TRANSACTION_PROCESSOR_PACKAGE_DEFINITION,
@@ -0,0 +1,91 @@
+use crate::system::system_modules::costing::SystemLoanFeeReserve;
+use crate::types::*;
+use crate::vm::wasm::*;
+use crate::vm::wasm_runtime::NoOpWasmRuntime;
+use crate::{errors::InvokeError, transaction::CostingParameters};
+use sbor::rust::iter;
+use transaction::prelude::TransactionCostingParameters;
+
+/// The execution units an export consumed under the regular metering, next to the number of
+/// instructions it actually executed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmMeteringAudit {
+    pub metered_execution_units: u64,
+    pub executed_instructions: u64,
+}
+
+#[derive(Debug)]
+pub enum WasmMeteringAuditError {
+    InvalidWasm(PrepareError),
+    InvokeError(InvokeError<WasmRuntimeError>),
+}
+
+impl From<PrepareError> for WasmMeteringAuditError {
+    fn from(value: PrepareError) -> Self {
+        WasmMeteringAuditError::InvalidWasm(value)
+    }
+}
+
+/// Runs an export of the given code twice, once instrumented with the regular metering rules
+/// and once with [`InstructionCountingRules`], and reports what each run consumed.
+///
+/// Calls to the engine made by the export are handled by a [`NoOpWasmRuntime`], so the
+/// workload should be self-contained.
+pub fn audit_wasm_metering(
+    code: &[u8],
+    export_name: &str,
+    args: &[u64],
+) -> Result<WasmMeteringAudit, WasmMeteringAuditError> {
+    let metered_code = ScryptoV1WasmValidator::new(SCRYPTO_V1_LATEST_MINOR_VERSION)
+        .validate(code, iter::empty())?
+        .0;
+    let instruction_counting_code = WasmModule::init(code)?
+        .enforce_memory_limit_and_inject_max(MAX_MEMORY_SIZE_IN_PAGES)?
+        .inject_instruction_metering(&InstructionCountingRules)?
+        .inject_stack_metering(WasmValidatorConfigV1::new().max_stack_size())?
+        .to_bytes()?
+        .0;
+
+    Ok(WasmMeteringAudit {
+        metered_execution_units: execute_and_count_units(&metered_code, export_name, args)?,
+        executed_instructions: execute_and_count_units(
+            &instruction_counting_code,
+            export_name,
+            args,
+        )?,
+    })
+}
+
+fn execute_and_count_units(
+    instrumented_code: &[u8],
+    export_name: &str,
+    args: &[u64],
+) -> Result<u64, WasmMeteringAuditError> {
+    let wasm_engine = DefaultWasmEngine::default();
+    let fee_reserve = SystemLoanFeeReserve::new(
+        &CostingParameters::default(),
+        &TransactionCostingParameters {
+            tip_percentage: 0,
+            free_credit_in_xrd: Decimal::try_from(PREVIEW_CREDIT_IN_XRD).unwrap(),
+        },
+        false,
+    );
+    let mut wasm_execution_units_consumed = 0;
+    {
+        let mut runtime: Box<dyn WasmRuntime> = Box::new(NoOpWasmRuntime::new(
+            fee_reserve,
+            &mut wasm_execution_units_consumed,
+        ));
+        let mut instance =
+            wasm_engine.instantiate(CodeHash(hash(instrumented_code)), instrumented_code);
+        instance
+            .invoke_export(
+                export_name,
+                args.iter().map(|arg| Buffer(*arg)).collect(),
+                &mut runtime,
+            )
+            .map_err(WasmMeteringAuditError::InvokeError)?;
+    }
+
+    Ok(wasm_execution_units_consumed)
+}
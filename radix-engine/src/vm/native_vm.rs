@@ -1,11 +1,15 @@
 use crate::blueprints::access_controller::AccessControllerNativePackage;
 use crate::blueprints::account::AccountNativePackage;
 use crate::blueprints::consensus_manager::{
-    ConsensusManagerNativePackage, ConsensusManagerSecondsPrecisionNativeCode,
+    ConsensusManagerNativePackage, ConsensusManagerRoundedTimeNativeCode,
+    ConsensusManagerSecondsPrecisionNativeCode,
 };
 use crate::blueprints::identity::IdentityNativePackage;
+use crate::blueprints::order_book::OrderBookNativePackage;
 use crate::blueprints::package::PackageNativePackage;
+use crate::blueprints::payment_scheduler::PaymentSchedulerNativePackage;
 use crate::blueprints::pool::v1::package::*;
+use crate::blueprints::price_feed::PriceFeedNativePackage;
 use crate::blueprints::resource::ResourceNativePackage;
 use crate::blueprints::test_utils::TestUtilsNativePackage;
 use crate::blueprints::transaction_processor::TransactionProcessorNativePackage;
@@ -62,6 +66,17 @@ impl<E: NativeVmExtension> NativeVm<E> {
 
         Ok(instance)
     }
+
+    /// Returns the precompiled implementation of the Scrypto package code with the given hash,
+    /// if the extension provides one.
+    pub fn try_create_precompiled_instance(
+        &self,
+        code_hash: &CodeHash,
+    ) -> Option<NativeVmInstance<E::Instance>> {
+        self.extension
+            .try_create_precompiled_instance(code_hash)
+            .map(NativeVmInstance::Extension)
+    }
 }
 
 pub enum NativeVmInstance<I: VmInvoke> {
@@ -130,6 +145,13 @@ impl<I: VmInvoke> VmInvoke for NativeVmInstance<I> {
                             api,
                         )
                     }
+                    CONSENSUS_MANAGER_ROUNDED_TIME_CODE_ID => {
+                        ConsensusManagerRoundedTimeNativeCode::invoke_export(
+                            export_name,
+                            input,
+                            api,
+                        )
+                    }
                     IDENTITY_CODE_ID => {
                         IdentityNativePackage::invoke_export(export_name, input, api)
                     }
@@ -159,6 +181,15 @@ impl<I: VmInvoke> VmInvoke for NativeVmInstance<I> {
                         PoolV1MinorVersion::One,
                         api,
                     ),
+                    PRICE_FEED_CODE_ID => {
+                        PriceFeedNativePackage::invoke_export(export_name, input, api)
+                    }
+                    PAYMENT_SCHEDULER_CODE_ID => {
+                        PaymentSchedulerNativePackage::invoke_export(export_name, input, api)
+                    }
+                    ORDER_BOOK_CODE_ID => {
+                        OrderBookNativePackage::invoke_export(export_name, input, api)
+                    }
                     TRANSACTION_TRACKER_CODE_ID => {
                         TransactionTrackerNativePackage::invoke_export(export_name, input, api)
                     }
@@ -210,6 +241,16 @@ pub trait NativeVmExtension: Clone {
     type Instance: VmInvoke + Clone;
 
     fn try_create_instance(&self, code: &[u8]) -> Option<Self::Instance>;
+
+    /// Provides a native implementation of the Scrypto package code with the given hash, which is
+    /// then invoked in place of the Wasm code.
+    ///
+    /// The implementation must behave exactly as the Wasm code does. Only the preparation of the
+    /// Wasm code is charged for, so the execution fees of a precompiled package differ from the
+    /// ones of the Wasm code, unless the implementation consumes cost units itself.
+    fn try_create_precompiled_instance(&self, _code_hash: &CodeHash) -> Option<Self::Instance> {
+        None
+    }
 }
 
 #[derive(Clone)]
@@ -282,3 +323,35 @@ impl<C: VmInvoke + Clone> NativeVmExtension for OverridePackageCode<C> {
         }
     }
 }
+
+/// Executes the Scrypto package code with the given hash natively, using the given implementation.
+#[derive(Clone)]
+pub struct PrecompiledPackageCode<C: VmInvoke + Clone> {
+    code_hash: CodeHash,
+    precompiled_invoke: C,
+}
+
+impl<C: VmInvoke + Clone> PrecompiledPackageCode<C> {
+    pub fn new(code_hash: CodeHash, precompiled_invoke: C) -> Self {
+        Self {
+            code_hash,
+            precompiled_invoke,
+        }
+    }
+}
+
+impl<C: VmInvoke + Clone> NativeVmExtension for PrecompiledPackageCode<C> {
+    type Instance = C;
+
+    fn try_create_instance(&self, _code: &[u8]) -> Option<C> {
+        None
+    }
+
+    fn try_create_precompiled_instance(&self, code_hash: &CodeHash) -> Option<C> {
+        if self.code_hash == *code_hash {
+            Some(self.precompiled_invoke.clone())
+        } else {
+            None
+        }
+    }
+}
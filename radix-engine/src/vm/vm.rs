@@ -6,7 +6,7 @@ use crate::system::system_callback_api::SystemCallbackObject;
 use crate::system::system_substates::KeyValueEntrySubstate;
 use crate::track::BootStore;
 use crate::types::*;
-use crate::vm::wasm::{ScryptoV1WasmValidator, WasmEngine};
+use crate::vm::wasm::{PrepareError, ScryptoV1WasmValidator, WasmEngine};
 use crate::vm::{NativeVm, NativeVmExtension, ScryptoVm};
 use radix_engine_interface::api::field_api::LockFlags;
 use radix_engine_interface::api::ClientApi;
@@ -183,26 +183,40 @@ impl<'g, W: WasmEngine + 'g, E: NativeVmExtension> SystemCallbackObject for Vm<'
                         .into_latest()
                 };
 
-                let mut scrypto_vm_instance = {
-                    api.kernel_get_system()
-                        .callback_obj
-                        .scrypto_vm
-                        .create_instance(
-                            address,
-                            export.code_hash,
-                            &instrumented_code.instrumented_code,
-                        )
-                };
-
                 api.consume_cost_units(ClientCostingEntry::PrepareWasmCode {
                     size: instrumented_code.instrumented_code.len(),
                 })?;
 
-                let output = {
-                    scrypto_vm_instance.invoke(export.export_name.as_str(), input, api, &vm_api)?
-                };
-
-                output
+                let precompiled_instance = api
+                    .kernel_get_system()
+                    .callback_obj
+                    .native_vm
+                    .try_create_precompiled_instance(&export.code_hash);
+                if let Some(mut precompiled_instance) = precompiled_instance {
+                    precompiled_instance.invoke(export.export_name.as_str(), input, api, &vm_api)?
+                } else {
+                    let mut scrypto_vm_instance = {
+                        api.kernel_get_system()
+                            .callback_obj
+                            .scrypto_vm
+                            .create_instance(
+                                address,
+                                export.code_hash,
+                                &instrumented_code.instrumented_code,
+                            )
+                    };
+
+                    let output = {
+                        scrypto_vm_instance.invoke(
+                            export.export_name.as_str(),
+                            input,
+                            api,
+                            &vm_api,
+                        )?
+                    };
+
+                    output
+                }
             }
         };
 
@@ -238,8 +252,23 @@ impl VmPackageValidation {
             VmType::ScryptoV1 => {
                 let minor_version = vm_api.get_scrypto_minor_version();
 
+                // Report all static violations at once, before the first-failure validation
+                let validator = ScryptoV1WasmValidator::new(minor_version);
+                let diagnostics = validator.diagnose(&code).map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::PackageError(
+                        PackageError::InvalidWasm(e),
+                    ))
+                })?;
+                if !diagnostics.is_empty() {
+                    return Err(RuntimeError::ApplicationError(
+                        ApplicationError::PackageError(PackageError::InvalidWasm(
+                            PrepareError::StaticValidationFailed(diagnostics),
+                        )),
+                    ));
+                }
+
                 // Validate WASM
-                let instrumented_code = ScryptoV1WasmValidator::new(minor_version)
+                let instrumented_code = validator
                     .validate(&code, definition.blueprints.values())
                     .map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::PackageError(
@@ -326,10 +355,10 @@ impl VmPackageValidation {
                         }
                     }
 
-                    if !hooks.hooks.is_empty() {
+                    if hooks.hooks.contains_key(&BlueprintHook::OnVirtualize) {
                         return Err(RuntimeError::ApplicationError(
                             ApplicationError::PackageError(PackageError::WasmUnsupported(
-                                "Hooks not supported".to_string(),
+                                "OnVirtualize hook not supported".to_string(),
                             )),
                         ));
                     }
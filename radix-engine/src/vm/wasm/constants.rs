@@ -2,6 +2,8 @@
 // Blueprint
 //=================
 pub const BLUEPRINT_CALL_FUNCTION_NAME: &str = "blueprint_call";
+pub const BLUEPRINT_GET_INTERFACE_FUNCTION_NAME: &str = "blueprint_get_interface";
+pub const BLUEPRINT_GET_EVENT_SCHEMA_FUNCTION_NAME: &str = "blueprint_get_event_schema";
 
 //=================
 // Address
@@ -17,6 +19,7 @@ pub const OBJECT_GLOBALIZE_FUNCTION_NAME: &str = "object_globalize";
 pub const OBJECT_INSTANCE_OF_FUNCTION_NAME: &str = "object_instance_of";
 pub const OBJECT_GET_BLUEPRINT_ID_FUNCTION_NAME: &str = "object_get_blueprint_id";
 pub const OBJECT_GET_OUTER_OBJECT_FUNCTION_NAME: &str = "object_get_outer_object";
+pub const OBJECT_DROP_FUNCTION_NAME: &str = "object_drop";
 pub const OBJECT_CALL_FUNCTION_NAME: &str = "object_call";
 pub const OBJECT_CALL_DIRECT_FUNCTION_NAME: &str = "object_call_direct";
 pub const OBJECT_CALL_MODULE_FUNCTION_NAME: &str = "object_call_module";
@@ -29,6 +32,10 @@ pub const ACTOR_GET_BLUEPRINT_NAME_FUNCTION_NAME: &str = "actor_get_blueprint_na
 pub const ACTOR_OPEN_FIELD_FUNCTION_NAME: &str = "actor_open_field";
 pub const ACTOR_GET_OBJECT_ID_FUNCTION_NAME: &str = "actor_get_object_id";
 pub const ACTOR_EMIT_EVENT_FUNCTION_NAME: &str = "actor_emit_event";
+pub const ACTOR_SUBSCRIBE_TO_EVENT_FUNCTION_NAME: &str = "actor_subscribe_to_event";
+pub const ACTOR_UNSUBSCRIBE_FROM_EVENT_FUNCTION_NAME: &str = "actor_unsubscribe_from_event";
+pub const ACTOR_GET_STATE_SIZE_FUNCTION_NAME: &str = "actor_get_state_size";
+pub const ACTOR_RATE_LIMIT_CHECK_FUNCTION_NAME: &str = "actor_rate_limit_check";
 
 //=================
 // Key Value Store
@@ -36,6 +43,8 @@ pub const ACTOR_EMIT_EVENT_FUNCTION_NAME: &str = "actor_emit_event";
 pub const KEY_VALUE_STORE_NEW_FUNCTION_NAME: &str = "kv_store_new";
 pub const KEY_VALUE_STORE_OPEN_ENTRY_FUNCTION_NAME: &str = "kv_store_open_entry";
 pub const KEY_VALUE_STORE_REMOVE_ENTRY_FUNCTION_NAME: &str = "kv_store_remove_entry";
+pub const KEY_VALUE_STORE_READ_ENTRIES_FUNCTION_NAME: &str = "kv_store_read_entries";
+pub const KEY_VALUE_STORE_WRITE_ENTRIES_FUNCTION_NAME: &str = "kv_store_write_entries";
 
 //=================
 // KV Entry
@@ -67,15 +76,20 @@ pub const COSTING_GET_FINALIZATION_COST_UNIT_PRICE_FUNCTION_NAME: &str =
 pub const COSTING_GET_USD_PRICE_FUNCTION_NAME: &str = "costing_get_usd_price";
 pub const COSTING_GET_TIP_PERCENTAGE_FUNCTION_NAME: &str = "costing_get_tip_percentage";
 pub const COSTING_GET_FEE_BALANCE_FUNCTION_NAME: &str = "costing_get_fee_balance";
+pub const COSTING_GET_EXECUTION_COST_UNITS_CONSUMED_FUNCTION_NAME: &str =
+    "costing_get_execution_cost_units_consumed";
 
 //=================
 // System
 //=================
 pub const SYS_LOG_FUNCTION_NAME: &str = "sys_log";
+pub const SYS_LOG_RECORD_FUNCTION_NAME: &str = "sys_log_record";
 pub const SYS_BECH32_ENCODE_ADDRESS_FUNCTION_NAME: &str = "sys_bech32_encode_address";
 pub const SYS_GET_TRANSACTION_HASH_FUNCTION_NAME: &str = "sys_get_transaction_hash";
+pub const SYS_GET_TRANSACTION_SIGNATORIES_FUNCTION_NAME: &str = "sys_get_transaction_signatories";
 pub const SYS_GENERATE_RUID_FUNCTION_NAME: &str = "sys_generate_ruid";
 pub const SYS_PANIC_FUNCTION_NAME: &str = "sys_panic";
+pub const SYS_ABORT_FUNCTION_NAME: &str = "sys_abort";
 
 //=================
 // Crypto Utils
@@ -0,0 +1,254 @@
+use crate::types::*;
+use crate::vm::wasm::*;
+use syn::Ident;
+use wasm_instrument::utils::module_info::ModuleInfo;
+use wasmparser::{
+    BinaryReaderError, FunctionBody, Operator, Parser, Payload, Type, TypeRef, ValType,
+};
+
+/// Walks a WASM module section by section and records every static violation, rather than
+/// stopping at the first one like [`WasmModule`] does.
+pub struct WasmDiagnostics<'a> {
+    validator: &'a ScryptoV1WasmValidator,
+    module: ModuleInfo,
+    types: Vec<Type>,
+    diagnostics: Vec<WasmDiagnostic>,
+}
+
+impl<'a> WasmDiagnostics<'a> {
+    pub fn collect(
+        validator: &'a ScryptoV1WasmValidator,
+        code: &[u8],
+    ) -> Result<Vec<WasmDiagnostic>, PrepareError> {
+        let module = ModuleInfo::new(code).map_err(|_| PrepareError::DeserializationError)?;
+        let mut collector = Self {
+            validator,
+            module,
+            types: vec![],
+            diagnostics: vec![],
+        };
+
+        for payload in Parser::new(0).parse_all(code) {
+            match payload.map_err(parser_error)? {
+                Payload::TypeSection(reader) => {
+                    for entry in reader.into_iter_with_offsets() {
+                        let (offset, ty) = entry.map_err(parser_error)?;
+                        if let Type::Func(func_type) = &ty {
+                            if func_type
+                                .params()
+                                .iter()
+                                .chain(func_type.results())
+                                .any(|t| is_floating_point(*t))
+                            {
+                                collector.report(offset, WasmViolation::FloatingPointNotAllowed);
+                            }
+                        }
+                        collector.types.push(ty);
+                    }
+                }
+                Payload::ImportSection(reader) => {
+                    for entry in reader.into_iter_with_offsets() {
+                        let (offset, import) = entry.map_err(parser_error)?;
+                        if let Err(e) = WasmModule::check_import(
+                            &collector.module,
+                            &import,
+                            collector.validator.minor_version,
+                        ) {
+                            collector.report(offset, WasmViolation::InvalidImport(e));
+                        }
+                        if let TypeRef::Global(global_type) = import.ty {
+                            if is_floating_point(global_type.content_type) {
+                                collector.report(offset, WasmViolation::FloatingPointNotAllowed);
+                            }
+                        }
+                    }
+                }
+                Payload::FunctionSection(reader) => {
+                    let max = collector.validator.max_number_of_functions;
+                    if reader.count() > max {
+                        collector.report(
+                            reader.range().start,
+                            WasmViolation::TooManyFunctions {
+                                max,
+                                actual: reader.count(),
+                            },
+                        );
+                    }
+                    for entry in reader.into_iter_with_offsets() {
+                        let (offset, type_index) = entry.map_err(parser_error)?;
+                        collector.check_function_params(offset, type_index);
+                    }
+                }
+                Payload::GlobalSection(reader) => {
+                    for entry in reader.into_iter_with_offsets() {
+                        let (offset, global) = entry.map_err(parser_error)?;
+                        if is_floating_point(global.ty.content_type) {
+                            collector.report(offset, WasmViolation::FloatingPointNotAllowed);
+                        }
+                    }
+                }
+                Payload::ExportSection(reader) => {
+                    for entry in reader.into_iter_with_offsets() {
+                        let (offset, export) = entry.map_err(parser_error)?;
+                        // Any exported name should follow Rust Identifier specification
+                        if syn::parse_str::<Ident>(export.name).is_err() {
+                            collector.report(
+                                offset,
+                                WasmViolation::InvalidExportName(export.name.to_string()),
+                            );
+                        }
+                    }
+                }
+                Payload::StartSection { range, .. } => {
+                    collector.report(range.start, WasmViolation::StartFunctionNotAllowed);
+                }
+                Payload::CodeSectionEntry(body) => {
+                    collector.check_function_body(&body)?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(collector.diagnostics)
+    }
+
+    fn check_function_params(&mut self, offset: usize, type_index: u32) {
+        let max = self.validator.max_number_of_function_params;
+        if let Some(Type::Func(func_type)) = self.types.get(type_index as usize) {
+            let actual = func_type.params().len() as u32;
+            if actual > max {
+                self.report(offset, WasmViolation::TooManyFunctionParams { max, actual });
+            }
+        }
+    }
+
+    fn check_function_body(&mut self, body: &FunctionBody) -> Result<(), PrepareError> {
+        let mut locals_reader = body.get_locals_reader().map_err(parser_error)?;
+        let mut locals_count: u32 = 0;
+        for _ in 0..locals_reader.get_count() {
+            let offset = locals_reader.original_position();
+            let (count, ty) = locals_reader.read().map_err(parser_error)?;
+            if is_floating_point(ty) {
+                self.report(offset, WasmViolation::FloatingPointNotAllowed);
+            }
+            locals_count = locals_count
+                .checked_add(count)
+                .ok_or(PrepareError::Overflow)?;
+        }
+
+        let max = self.validator.max_number_of_function_locals;
+        if locals_count > max {
+            self.report(
+                body.range().start,
+                WasmViolation::TooManyFunctionLocals {
+                    max,
+                    actual: locals_count,
+                },
+            );
+        }
+
+        let operators_reader = body.get_operators_reader().map_err(parser_error)?;
+        for entry in operators_reader.into_iter_with_offsets() {
+            let (op, offset) = entry.map_err(parser_error)?;
+            if is_floating_point_operator(&op) {
+                self.report(offset, WasmViolation::FloatingPointNotAllowed);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn report(&mut self, offset: usize, violation: WasmViolation) {
+        self.diagnostics.push(WasmDiagnostic { offset, violation });
+    }
+}
+
+fn parser_error(err: BinaryReaderError) -> PrepareError {
+    PrepareError::WasmParserError(err.to_string())
+}
+
+fn is_floating_point(ty: ValType) -> bool {
+    matches!(ty, ValType::F32 | ValType::F64)
+}
+
+fn is_floating_point_operator(op: &Operator) -> bool {
+    matches!(
+        op,
+        Operator::F32Load { .. }
+            | Operator::F64Load { .. }
+            | Operator::F32Store { .. }
+            | Operator::F64Store { .. }
+            | Operator::F32Const { .. }
+            | Operator::F64Const { .. }
+            | Operator::F32Eq
+            | Operator::F32Ne
+            | Operator::F32Lt
+            | Operator::F32Gt
+            | Operator::F32Le
+            | Operator::F32Ge
+            | Operator::F64Eq
+            | Operator::F64Ne
+            | Operator::F64Lt
+            | Operator::F64Gt
+            | Operator::F64Le
+            | Operator::F64Ge
+            | Operator::F32Abs
+            | Operator::F32Neg
+            | Operator::F32Ceil
+            | Operator::F32Floor
+            | Operator::F32Trunc
+            | Operator::F32Nearest
+            | Operator::F32Sqrt
+            | Operator::F32Add
+            | Operator::F32Sub
+            | Operator::F32Mul
+            | Operator::F32Div
+            | Operator::F32Min
+            | Operator::F32Max
+            | Operator::F32Copysign
+            | Operator::F64Abs
+            | Operator::F64Neg
+            | Operator::F64Ceil
+            | Operator::F64Floor
+            | Operator::F64Trunc
+            | Operator::F64Nearest
+            | Operator::F64Sqrt
+            | Operator::F64Add
+            | Operator::F64Sub
+            | Operator::F64Mul
+            | Operator::F64Div
+            | Operator::F64Min
+            | Operator::F64Max
+            | Operator::F64Copysign
+            | Operator::I32TruncF32S
+            | Operator::I32TruncF32U
+            | Operator::I32TruncF64S
+            | Operator::I32TruncF64U
+            | Operator::I64TruncF32S
+            | Operator::I64TruncF32U
+            | Operator::I64TruncF64S
+            | Operator::I64TruncF64U
+            | Operator::F32ConvertI32S
+            | Operator::F32ConvertI32U
+            | Operator::F32ConvertI64S
+            | Operator::F32ConvertI64U
+            | Operator::F32DemoteF64
+            | Operator::F64ConvertI32S
+            | Operator::F64ConvertI32U
+            | Operator::F64ConvertI64S
+            | Operator::F64ConvertI64U
+            | Operator::F64PromoteF32
+            | Operator::I32ReinterpretF32
+            | Operator::I64ReinterpretF64
+            | Operator::F32ReinterpretI32
+            | Operator::F64ReinterpretI64
+            | Operator::I32TruncSatF32S
+            | Operator::I32TruncSatF32U
+            | Operator::I32TruncSatF64S
+            | Operator::I32TruncSatF64U
+            | Operator::I64TruncSatF32S
+            | Operator::I64TruncSatF32U
+            | Operator::I64TruncSatF64S
+            | Operator::I64TruncSatF64U
+    )
+}
@@ -58,6 +58,34 @@ pub enum PrepareError {
     WasmParserError(String),
     /// An overflow occurred in some of the internal math
     Overflow,
+    /// The static validation pass found one or more violations
+    StaticValidationFailed(Vec<WasmDiagnostic>),
+}
+
+/// A violation found by the static validation pass, along with where it is in the code.
+#[derive(Debug, PartialEq, Eq, Clone, Sbor)]
+pub struct WasmDiagnostic {
+    /// The byte offset within the WASM code at which the violation was found
+    pub offset: usize,
+    pub violation: WasmViolation,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Sbor)]
+pub enum WasmViolation {
+    /// The wasm module contains a start function.
+    StartFunctionNotAllowed,
+    /// Invalid import
+    InvalidImport(InvalidImport),
+    /// Invalid export symbol name
+    InvalidExportName(String),
+    /// A floating point type or instruction is used
+    FloatingPointNotAllowed,
+    /// Too many functions
+    TooManyFunctions { max: u32, actual: u32 },
+    /// Too many function parameters
+    TooManyFunctionParams { max: u32, actual: u32 },
+    /// Too many function local variables
+    TooManyFunctionLocals { max: u32, actual: u32 },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Sbor)]
@@ -139,17 +167,27 @@ pub enum WasmRuntimeError {
 
     InvalidKeyValueStoreSchema(DecodeError),
 
+    InvalidKeyValueStoreEntries(DecodeError),
+
     /// Invalid component address
     InvalidLockFlags,
 
     /// Invalid log level
     InvalidLogLevel(DecodeError),
 
+    /// Invalid log record
+    InvalidLogRecord(DecodeError),
+
+    /// Invalid abort error value
+    InvalidAbortError(DecodeError),
+
     /// Costing error (no-op runtime only!)
     FeeReserveError(FeeReserveError),
 
     InvalidEventFlags(u32),
 
+    InvalidRateLimitScope(u32),
+
     InvalidPackageAddress,
 
     TooManyBuffers,
@@ -0,0 +1,38 @@
+use crate::types::*;
+use wasm_instrument::gas_metering::{MemoryGrowCost, Rules};
+use wasmparser::Operator;
+
+/// The cost the injected instruction metering statically attributes to a single function.
+#[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+pub struct FunctionCostAnnotation {
+    /// The index of the function, counting imported functions first
+    pub function_index: u32,
+    /// The name the function is exported under, if any
+    pub export_name: Option<String>,
+    /// The number of instructions in the function body
+    pub instruction_count: u32,
+    /// The cost of executing every instruction of the body once, plus the per-local cost
+    /// charged when the function is called
+    pub static_cost: u64,
+}
+
+/// Metering rules which charge a single unit for every instruction.
+///
+/// Code instrumented with these rules consumes exactly as many units as the instructions it
+/// executes, which makes it a baseline to compare the weighted metering against.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InstructionCountingRules;
+
+impl Rules for InstructionCountingRules {
+    fn instruction_cost(&self, _instruction: &Operator) -> Option<u32> {
+        Some(1)
+    }
+
+    fn memory_grow_cost(&self) -> MemoryGrowCost {
+        MemoryGrowCost::Free
+    }
+
+    fn call_per_local_cost(&self) -> u32 {
+        0
+    }
+}
@@ -1,5 +1,7 @@
 mod constants;
+mod diagnostics;
 mod errors;
+mod metering_audit;
 mod prepare;
 mod traits;
 mod wasm_validator;
@@ -13,7 +15,9 @@ mod weights;
 pub use self::wasmer::*;
 pub use self::wasmi::*;
 pub use constants::*;
+pub use diagnostics::*;
 pub use errors::*;
+pub use metering_audit::*;
 pub use prepare::*;
 pub use traits::*;
 pub use wasm_validator::*;
@@ -1,5 +1,5 @@
 use crate::types::*;
-use crate::vm::wasm::{constants::*, errors::*, PrepareError};
+use crate::vm::wasm::{constants::*, errors::*, FunctionCostAnnotation, PrepareError};
 use num_traits::CheckedAdd;
 use radix_engine_interface::blueprints::package::BlueprintDefinitionInit;
 use syn::Ident;
@@ -8,11 +8,12 @@ use wasm_instrument::{
     inject_stack_limiter,
     utils::module_info::ModuleInfo,
 };
-use wasmparser::{ExternalKind, FuncType, Operator, Type, TypeRef, ValType, WasmFeatures};
+use wasmparser::{ExternalKind, FuncType, Import, Operator, Type, TypeRef, ValType, WasmFeatures};
 
 use super::WasmiModule;
 
 pub const SCRPYTO_VM_CRYPTO_UTILS_MINOR_VERSION: u64 = 1u64;
+pub const SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION: u64 = 2u64;
 
 #[derive(Debug)]
 pub struct WasmModule {
@@ -70,801 +71,953 @@ impl WasmModule {
             .map_err(|err| PrepareError::ModuleInfoError(err.to_string()))?
             .unwrap_or(vec![])
         {
-            if entry.module == MODULE_ENV_NAME {
-                match entry.name {
-                    BUFFER_CONSUME_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32],
-                                vec![],
-                            ) {
-                                continue;
-                            }
+            Self::check_import(&self.module, &entry, minor_version)
+                .map_err(PrepareError::InvalidImport)?;
+        }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+        Ok(self)
+    }
+
+    pub(crate) fn check_import(
+        module: &ModuleInfo,
+        entry: &Import,
+        minor_version: u64,
+    ) -> Result<(), InvalidImport> {
+        if entry.module == MODULE_ENV_NAME {
+            match entry.name {
+                BUFFER_CONSUME_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32],
+                            vec![],
+                        ) {
+                            return Ok(());
                         }
+
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    OBJECT_CALL_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                ],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
+                }
+                OBJECT_CALL_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                            ],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
+                        }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                OBJECT_CALL_MODULE_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                            ],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    OBJECT_CALL_MODULE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                ],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
+                }
+                OBJECT_CALL_DIRECT_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                            ],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
+                        }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                BLUEPRINT_CALL_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                            ],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    OBJECT_CALL_DIRECT_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                ],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
+                }
+                KEY_VALUE_STORE_OPEN_ENTRY_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                            ],
+                            vec![ValType::I32],
+                        ) {
+                            return Ok(());
+                        }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                KEY_VALUE_ENTRY_READ_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    BLUEPRINT_CALL_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                ],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
+                }
+                KEY_VALUE_ENTRY_WRITE_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32, ValType::I32],
+                            vec![],
+                        ) {
+                            return Ok(());
+                        }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                KEY_VALUE_ENTRY_REMOVE_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    KEY_VALUE_STORE_OPEN_ENTRY_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                ],
-                                vec![ValType::I32],
-                            ) {
-                                continue;
-                            }
+                }
+                KEY_VALUE_ENTRY_CLOSE_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32],
+                            vec![],
+                        ) {
+                            return Ok(());
+                        }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                KEY_VALUE_STORE_REMOVE_ENTRY_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                KEY_VALUE_STORE_READ_ENTRIES_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
                     }
-                    KEY_VALUE_ENTRY_READ_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                KEY_VALUE_STORE_WRITE_ENTRIES_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
                     }
-                    KEY_VALUE_ENTRY_WRITE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32, ValType::I32],
-                                vec![],
-                            ) {
-                                continue;
-                            }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
+                            vec![],
+                        ) {
+                            return Ok(());
                         }
-                    }
-                    KEY_VALUE_ENTRY_REMOVE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                ACTOR_OPEN_FIELD_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32, ValType::I32],
+                            vec![ValType::I32],
+                        ) {
+                            return Ok(());
                         }
+
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    KEY_VALUE_ENTRY_CLOSE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32],
-                                vec![],
-                            ) {
-                                continue;
-                            }
+                }
+                FIELD_ENTRY_READ_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
+                        }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                FIELD_ENTRY_WRITE_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32, ValType::I32],
+                            vec![],
+                        ) {
+                            return Ok(());
                         }
+
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    KEY_VALUE_STORE_REMOVE_ENTRY_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
+                }
+                FIELD_ENTRY_CLOSE_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32],
+                            vec![],
+                        ) {
+                            return Ok(());
+                        }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                ACTOR_GET_OBJECT_ID_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    ACTOR_OPEN_FIELD_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32, ValType::I32],
-                                vec![ValType::I32],
-                            ) {
-                                continue;
-                            }
+                }
+                ACTOR_GET_PACKAGE_ADDRESS_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
+                        }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                ACTOR_GET_BLUEPRINT_NAME_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    FIELD_ENTRY_READ_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
+                }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                OBJECT_NEW_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    FIELD_ENTRY_WRITE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32, ValType::I32],
-                                vec![],
-                            ) {
-                                continue;
-                            }
+                }
 
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                COSTING_GET_EXECUTION_COST_UNIT_LIMIT_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I32],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    FIELD_ENTRY_CLOSE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32],
-                                vec![],
-                            ) {
-                                continue;
-                            }
-
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                COSTING_GET_EXECUTION_COST_UNIT_PRICE_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    ACTOR_GET_OBJECT_ID_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                COSTING_GET_FINALIZATION_COST_UNIT_LIMIT_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I32],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    ACTOR_GET_PACKAGE_ADDRESS_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                COSTING_GET_FINALIZATION_COST_UNIT_PRICE_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    ACTOR_GET_BLUEPRINT_NAME_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                COSTING_GET_USD_PRICE_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-
-                    OBJECT_NEW_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                COSTING_GET_TIP_PERCENTAGE_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I32],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                COSTING_GET_FEE_BALANCE_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
                     }
 
-                    COSTING_GET_EXECUTION_COST_UNIT_LIMIT_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![],
-                                vec![ValType::I32],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    COSTING_GET_EXECUTION_COST_UNIT_PRICE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                COSTING_GET_EXECUTION_COST_UNITS_CONSUMED_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
+                    }
+
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I32],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    COSTING_GET_FINALIZATION_COST_UNIT_LIMIT_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![],
-                                vec![ValType::I32],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                BLUEPRINT_GET_INTERFACE_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
+                    }
+
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    COSTING_GET_FINALIZATION_COST_UNIT_PRICE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                BLUEPRINT_GET_EVENT_SCHEMA_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
+                    }
+
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                            ],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    COSTING_GET_USD_PRICE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+
+                ADDRESS_ALLOCATE_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    COSTING_GET_TIP_PERCENTAGE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![],
-                                vec![ValType::I32],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                ADDRESS_GET_RESERVATION_ADDRESS_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    COSTING_GET_FEE_BALANCE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                OBJECT_GLOBALIZE_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                            ],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-
-                    ADDRESS_ALLOCATE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                KEY_VALUE_STORE_NEW_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    ADDRESS_GET_RESERVATION_ADDRESS_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                OBJECT_INSTANCE_OF_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                            ],
+                            vec![ValType::I32],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    OBJECT_GLOBALIZE_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                ],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                OBJECT_GET_BLUEPRINT_ID_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    KEY_VALUE_STORE_NEW_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                OBJECT_GET_OUTER_OBJECT_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    OBJECT_INSTANCE_OF_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                ],
-                                vec![ValType::I32],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                OBJECT_DROP_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    OBJECT_GET_BLUEPRINT_ID_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                ACTOR_EMIT_EVENT_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                            ],
+                            vec![],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    OBJECT_GET_OUTER_OBJECT_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                ACTOR_SUBSCRIBE_TO_EVENT_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
+                    }
+
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                            ],
+                            vec![],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    ACTOR_EMIT_EVENT_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                ],
-                                vec![],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                ACTOR_UNSUBSCRIBE_FROM_EVENT_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
+                    }
+
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
+                            vec![],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    SYS_LOG_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
-                                vec![],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                ACTOR_GET_STATE_SIZE_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
+                    }
+
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    SYS_BECH32_ENCODE_ADDRESS_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                ACTOR_RATE_LIMIT_CHECK_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
+                    }
+
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I64,
+                            ],
+                            vec![ValType::I32],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    SYS_PANIC_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32],
-                                vec![],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                SYS_LOG_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
+                            vec![],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    SYS_GET_TRANSACTION_HASH_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                SYS_LOG_RECORD_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
+                    }
+
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32],
+                            vec![],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    SYS_GENERATE_RUID_FUNCTION_NAME => {
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                }
+                SYS_BECH32_ENCODE_ADDRESS_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    CRYPTO_UTILS_BLS12381_V1_VERIFY_FUNCTION_NAME => {
-                        if minor_version < SCRPYTO_VM_CRYPTO_UTILS_MINOR_VERSION {
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::ImportNotAllowed(entry.name.to_string()),
-                            ));
+                }
+                SYS_PANIC_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32],
+                            vec![],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                SYS_ABORT_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
+                    }
 
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                ],
-                                vec![ValType::I32],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32],
+                            vec![],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    CRYPTO_UTILS_BLS12381_V1_AGGREGATE_VERIFY_FUNCTION_NAME => {
-                        if minor_version < 1 {
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::ImportNotAllowed(entry.name.to_string()),
-                            ));
+                }
+                SYS_GET_TRANSACTION_HASH_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                SYS_GET_TRANSACTION_SIGNATORIES_FUNCTION_NAME => {
+                    if minor_version < SCRYPTO_VM_CUTTLEFISH_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
+                    }
 
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
-                                vec![ValType::I32],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
                     }
-                    CRYPTO_UTILS_BLS12381_V1_FAST_AGGREGATE_VERIFY_FUNCTION_NAME => {
-                        if minor_version < SCRPYTO_VM_CRYPTO_UTILS_MINOR_VERSION {
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::ImportNotAllowed(entry.name.to_string()),
-                            ));
+                }
+                SYS_GENERATE_RUID_FUNCTION_NAME => {
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                CRYPTO_UTILS_BLS12381_V1_VERIFY_FUNCTION_NAME => {
+                    if minor_version < SCRPYTO_VM_CRYPTO_UTILS_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
+                    }
 
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                    ValType::I32,
-                                ],
-                                vec![ValType::I32],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                            ],
+                            vec![ValType::I32],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                CRYPTO_UTILS_BLS12381_V1_AGGREGATE_VERIFY_FUNCTION_NAME => {
+                    if minor_version < 1 {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
                     }
-                    CRYPTO_UTILS_BLS12381_G2_SIGNATURE_AGGREGATE_FUNCTION_NAME => {
-                        if minor_version < SCRPYTO_VM_CRYPTO_UTILS_MINOR_VERSION {
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::ImportNotAllowed(entry.name.to_string()),
-                            ));
-                        }
 
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
+                            vec![ValType::I32],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                CRYPTO_UTILS_BLS12381_V1_FAST_AGGREGATE_VERIFY_FUNCTION_NAME => {
+                    if minor_version < SCRPYTO_VM_CRYPTO_UTILS_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
                     }
-                    CRYPTO_UTILS_KECCAK256_HASH_FUNCTION_NAME => {
-                        if minor_version < SCRPYTO_VM_CRYPTO_UTILS_MINOR_VERSION {
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::ImportNotAllowed(entry.name.to_string()),
-                            ));
+
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                                ValType::I32,
+                            ],
+                            vec![ValType::I32],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                CRYPTO_UTILS_BLS12381_G2_SIGNATURE_AGGREGATE_FUNCTION_NAME => {
+                    if minor_version < SCRPYTO_VM_CRYPTO_UTILS_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
+                    }
 
-                        if let TypeRef::Func(type_index) = entry.ty {
-                            if Self::function_type_matches(
-                                &self.module,
-                                type_index,
-                                vec![ValType::I32, ValType::I32],
-                                vec![ValType::I64],
-                            ) {
-                                continue;
-                            }
-                            return Err(PrepareError::InvalidImport(
-                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
-                            ));
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
                         }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                CRYPTO_UTILS_KECCAK256_HASH_FUNCTION_NAME => {
+                    if minor_version < SCRPYTO_VM_CRYPTO_UTILS_MINOR_VERSION {
+                        return Err(InvalidImport::ImportNotAllowed(entry.name.to_string()));
                     }
-                    _ => {}
-                };
-            }
 
-            return Err(PrepareError::InvalidImport(
-                InvalidImport::ImportNotAllowed(entry.name.to_string()),
-            ));
+                    if let TypeRef::Func(type_index) = entry.ty {
+                        if Self::function_type_matches(
+                            module,
+                            type_index,
+                            vec![ValType::I32, ValType::I32],
+                            vec![ValType::I64],
+                        ) {
+                            return Ok(());
+                        }
+                        return Err(InvalidImport::InvalidFunctionType(entry.name.to_string()));
+                    }
+                }
+                _ => {}
+            };
         }
 
-        Ok(self)
+        Err(InvalidImport::ImportNotAllowed(entry.name.to_string()))
     }
 
     pub fn enforce_memory_limit_and_inject_max(
@@ -1121,6 +1274,76 @@ impl WasmModule {
         Ok(self)
     }
 
+    pub fn function_cost_table<R: Rules>(
+        &self,
+        rules: &R,
+    ) -> Result<Vec<FunctionCostAnnotation>, PrepareError> {
+        let imported_functions = self
+            .module
+            .import_section()
+            .map_err(|err| PrepareError::ModuleInfoError(err.to_string()))?
+            .unwrap_or(vec![])
+            .iter()
+            .filter(|entry| matches!(entry.ty, TypeRef::Func(_)))
+            .count() as u32;
+        let exports = self
+            .module
+            .export_section()
+            .map_err(|err| PrepareError::ModuleInfoError(err.to_string()))?
+            .unwrap_or(vec![]);
+
+        let mut cost_table = vec![];
+        for (i, func_body) in self
+            .module
+            .code_section()
+            .map_err(|err| PrepareError::ModuleInfoError(err.to_string()))?
+            .unwrap_or(vec![])
+            .into_iter()
+            .enumerate()
+        {
+            let function_index = imported_functions + i as u32;
+            let export_name = exports
+                .iter()
+                .find(|e| e.kind == ExternalKind::Func && e.index == function_index)
+                .map(|e| e.name.to_string());
+
+            let mut locals_count = 0u64;
+            for local in func_body
+                .get_locals_reader()
+                .map_err(|err| PrepareError::WasmParserError(err.to_string()))?
+            {
+                let (count, _ty) =
+                    local.map_err(|err| PrepareError::WasmParserError(err.to_string()))?;
+                locals_count += count as u64;
+            }
+
+            let mut instruction_count = 0u32;
+            let mut static_cost = locals_count * rules.call_per_local_cost() as u64;
+            for op in func_body
+                .get_operators_reader()
+                .map_err(|err| PrepareError::WasmParserError(err.to_string()))?
+            {
+                let inst = op.map_err(|err| PrepareError::WasmParserError(err.to_string()))?;
+                let cost = rules.instruction_cost(&inst).ok_or_else(|| {
+                    PrepareError::RejectedByInstructionMetering {
+                        reason: format!("Unsupported instruction: {:?}", inst),
+                    }
+                })?;
+                instruction_count += 1;
+                static_cost += cost as u64;
+            }
+
+            cost_table.push(FunctionCostAnnotation {
+                function_index,
+                export_name,
+                instruction_count,
+                static_cost,
+            });
+        }
+
+        Ok(cost_table)
+    }
+
     pub fn inject_stack_metering(mut self, wasm_max_stack_size: u32) -> Result<Self, PrepareError> {
         inject_stack_limiter(&mut self.module, wasm_max_stack_size).map_err(|err| {
             PrepareError::RejectedByStackMetering {
@@ -1371,12 +1594,16 @@ mod tests {
             OBJECT_CALL_MODULE_FUNCTION_NAME,
             OBJECT_CALL_DIRECT_FUNCTION_NAME,
             BLUEPRINT_CALL_FUNCTION_NAME,
+            BLUEPRINT_GET_INTERFACE_FUNCTION_NAME,
+            BLUEPRINT_GET_EVENT_SCHEMA_FUNCTION_NAME,
             KEY_VALUE_STORE_OPEN_ENTRY_FUNCTION_NAME,
             KEY_VALUE_ENTRY_READ_FUNCTION_NAME,
             KEY_VALUE_ENTRY_WRITE_FUNCTION_NAME,
             KEY_VALUE_ENTRY_REMOVE_FUNCTION_NAME,
             KEY_VALUE_ENTRY_CLOSE_FUNCTION_NAME,
             KEY_VALUE_STORE_REMOVE_ENTRY_FUNCTION_NAME,
+            KEY_VALUE_STORE_READ_ENTRIES_FUNCTION_NAME,
+            KEY_VALUE_STORE_WRITE_ENTRIES_FUNCTION_NAME,
             ACTOR_OPEN_FIELD_FUNCTION_NAME,
             FIELD_ENTRY_READ_FUNCTION_NAME,
             FIELD_ENTRY_WRITE_FUNCTION_NAME,
@@ -1392,6 +1619,7 @@ mod tests {
             COSTING_GET_USD_PRICE_FUNCTION_NAME,
             COSTING_GET_TIP_PERCENTAGE_FUNCTION_NAME,
             COSTING_GET_FEE_BALANCE_FUNCTION_NAME,
+            COSTING_GET_EXECUTION_COST_UNITS_CONSUMED_FUNCTION_NAME,
             ADDRESS_ALLOCATE_FUNCTION_NAME,
             ADDRESS_GET_RESERVATION_ADDRESS_FUNCTION_NAME,
             OBJECT_GLOBALIZE_FUNCTION_NAME,
@@ -1399,11 +1627,19 @@ mod tests {
             OBJECT_INSTANCE_OF_FUNCTION_NAME,
             OBJECT_GET_BLUEPRINT_ID_FUNCTION_NAME,
             OBJECT_GET_OUTER_OBJECT_FUNCTION_NAME,
+            OBJECT_DROP_FUNCTION_NAME,
             ACTOR_EMIT_EVENT_FUNCTION_NAME,
+            ACTOR_SUBSCRIBE_TO_EVENT_FUNCTION_NAME,
+            ACTOR_UNSUBSCRIBE_FROM_EVENT_FUNCTION_NAME,
+            ACTOR_GET_STATE_SIZE_FUNCTION_NAME,
+            ACTOR_RATE_LIMIT_CHECK_FUNCTION_NAME,
             SYS_LOG_FUNCTION_NAME,
+            SYS_LOG_RECORD_FUNCTION_NAME,
             SYS_BECH32_ENCODE_ADDRESS_FUNCTION_NAME,
             SYS_PANIC_FUNCTION_NAME,
+            SYS_ABORT_FUNCTION_NAME,
             SYS_GET_TRANSACTION_HASH_FUNCTION_NAME,
+            SYS_GET_TRANSACTION_SIGNATORIES_FUNCTION_NAME,
             SYS_GENERATE_RUID_FUNCTION_NAME,
         ] {
             assert_invalid_wasm!(
@@ -1601,6 +1837,7 @@ mod tests {
                                 export: "Test_f".to_string(),
                             }
                         ),
+                        post_conditions: index_map_new(),
                     },
                     hooks: BlueprintHooksInit::default(),
                 },
@@ -1,7 +1,7 @@
 use crate::errors::InvokeError;
 use crate::types::*;
 use crate::vm::wasm::errors::*;
-use radix_engine_interface::api::actor_api::EventFlags;
+use radix_engine_interface::api::actor_api::{EventFlags, RateLimitScope};
 use radix_engine_interface::api::ActorRefHandle;
 use radix_engine_interface::blueprints::package::CodeHash;
 use sbor::rust::boxed::Box;
@@ -47,6 +47,19 @@ pub trait WasmRuntime {
         args: Vec<u8>,
     ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
+    fn blueprint_get_interface(
+        &mut self,
+        package_address: Vec<u8>,
+        blueprint_name: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn blueprint_get_event_schema(
+        &mut self,
+        package_address: Vec<u8>,
+        blueprint_name: Vec<u8>,
+        event_name: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
     fn object_new(
         &mut self,
         blueprint_name: Vec<u8>,
@@ -105,6 +118,18 @@ pub trait WasmRuntime {
         key: Vec<u8>,
     ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
+    fn key_value_store_read_entries(
+        &mut self,
+        node_id: Vec<u8>,
+        keys: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn key_value_store_write_entries(
+        &mut self,
+        node_id: Vec<u8>,
+        entries: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
+
     fn instance_of(
         &mut self,
         object_id: Vec<u8>,
@@ -120,6 +145,8 @@ pub trait WasmRuntime {
         component_id: Vec<u8>,
     ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
+    fn drop_object(&mut self, node_id: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
     fn actor_open_field(
         &mut self,
         object_handle: u32,
@@ -155,6 +182,9 @@ pub trait WasmRuntime {
     fn consume_wasm_execution_units(&mut self, n: u32)
         -> Result<(), InvokeError<WasmRuntimeError>>;
 
+    fn update_wasm_memory_usage(&mut self, pages: u32)
+        -> Result<(), InvokeError<WasmRuntimeError>>;
+
     fn costing_get_execution_cost_unit_limit(
         &mut self,
     ) -> Result<u32, InvokeError<WasmRuntimeError>>;
@@ -177,6 +207,10 @@ pub trait WasmRuntime {
 
     fn costing_get_fee_balance(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
+    fn costing_get_execution_cost_units_consumed(
+        &mut self,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>>;
+
     fn actor_emit_event(
         &mut self,
         event_name: Vec<u8>,
@@ -184,12 +218,37 @@ pub trait WasmRuntime {
         event_flags: EventFlags,
     ) -> Result<(), InvokeError<WasmRuntimeError>>;
 
+    fn actor_subscribe_to_event(
+        &mut self,
+        emitter: Vec<u8>,
+        event_name: Vec<u8>,
+        hook_method: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
+
+    fn actor_unsubscribe_from_event(
+        &mut self,
+        emitter: Vec<u8>,
+        event_name: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
+
+    fn actor_get_state_size(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn actor_rate_limit_check(
+        &mut self,
+        name: Vec<u8>,
+        scope: RateLimitScope,
+        max: u32,
+        window_epochs: u64,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>>;
+
     fn sys_log(
         &mut self,
         level: Vec<u8>,
         message: Vec<u8>,
     ) -> Result<(), InvokeError<WasmRuntimeError>>;
 
+    fn sys_log_record(&mut self, record: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>>;
+
     fn sys_bech32_encode_address(
         &mut self,
         address: Vec<u8>,
@@ -197,10 +256,15 @@ pub trait WasmRuntime {
 
     fn sys_get_transaction_hash(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
+    fn sys_get_transaction_signatories(&mut self)
+        -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
     fn sys_generate_ruid(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
     fn sys_panic(&mut self, message: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>>;
 
+    fn sys_abort(&mut self, error: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>>;
+
     fn crypto_utils_bls12381_v1_verify(
         &mut self,
         message: Vec<u8>,
@@ -2,7 +2,7 @@ use crate::types::*;
 use crate::vm::wasm::*;
 use radix_engine_interface::blueprints::package::BlueprintDefinitionInit;
 
-pub const SCRYPTO_V1_LATEST_MINOR_VERSION: u64 = 1u64;
+pub const SCRYPTO_V1_LATEST_MINOR_VERSION: u64 = 2u64;
 
 pub struct ScryptoV1WasmValidator {
     pub max_memory_size_in_pages: u32,
@@ -58,6 +58,38 @@ impl ScryptoV1WasmValidator {
         code: &[u8],
         blueprints: I,
     ) -> Result<(Vec<u8>, Vec<String>), PrepareError> {
+        self.enforce_constraints(code, blueprints)?
+            .inject_instruction_metering(&self.instrumenter_config)?
+            .inject_stack_metering(self.instrumenter_config.max_stack_size())?
+            .ensure_instantiatable()?
+            .ensure_compilable()?
+            .to_bytes()
+    }
+
+    /// Validates and instruments the code like [`Self::validate`], additionally returning the
+    /// static cost which the injected metering attributes to each function of the module.
+    pub fn validate_with_cost_table<'a, I: Iterator<Item = &'a BlueprintDefinitionInit>>(
+        &self,
+        code: &[u8],
+        blueprints: I,
+    ) -> Result<(Vec<u8>, Vec<String>, Vec<FunctionCostAnnotation>), PrepareError> {
+        let module = self.enforce_constraints(code, blueprints)?;
+        let cost_table = module.function_cost_table(&self.instrumenter_config)?;
+        let (instrumented_code, function_exports) = module
+            .inject_instruction_metering(&self.instrumenter_config)?
+            .inject_stack_metering(self.instrumenter_config.max_stack_size())?
+            .ensure_instantiatable()?
+            .ensure_compilable()?
+            .to_bytes()?;
+
+        Ok((instrumented_code, function_exports, cost_table))
+    }
+
+    fn enforce_constraints<'a, I: Iterator<Item = &'a BlueprintDefinitionInit>>(
+        &self,
+        code: &[u8],
+        blueprints: I,
+    ) -> Result<WasmModule, PrepareError> {
         WasmModule::init(code)?
             .enforce_no_start_function()?
             .enforce_import_constraints(self.minor_version)?
@@ -71,12 +103,13 @@ impl ScryptoV1WasmValidator {
                 self.max_number_of_function_locals,
             )?
             .enforce_global_limit(self.max_number_of_globals)?
-            .enforce_export_constraints(blueprints)?
-            .inject_instruction_metering(&self.instrumenter_config)?
-            .inject_stack_metering(self.instrumenter_config.max_stack_size())?
-            .ensure_instantiatable()?
-            .ensure_compilable()?
-            .to_bytes()
+            .enforce_export_constraints(blueprints)
+    }
+
+    /// Runs the static checks of [`Self::validate`] which can be done without instrumenting
+    /// the code, and returns every violation found instead of just the first one.
+    pub fn diagnose(&self, code: &[u8]) -> Result<Vec<WasmDiagnostic>, PrepareError> {
+        WasmDiagnostics::collect(self, code)
     }
 }
 
@@ -5,13 +5,13 @@ use crate::utils::save_coverage_data;
 use crate::vm::wasm::constants::*;
 use crate::vm::wasm::errors::*;
 use crate::vm::wasm::traits::*;
-use radix_engine_interface::api::actor_api::EventFlags;
+use radix_engine_interface::api::actor_api::{EventFlags, RateLimitScope};
 use radix_engine_interface::blueprints::package::CodeHash;
 use sbor::rust::sync::{Arc, Mutex};
 #[cfg(feature = "radix_engine_tests")]
 use wasmer::ImportObject;
 use wasmer::{
-    imports, Function, HostEnvInitError, Instance, LazyInit, Module, RuntimeError, Store,
+    imports, Function, HostEnvInitError, Instance, LazyInit, Memory, Module, RuntimeError, Store,
     Universal, Val, WasmerEnv,
 };
 use wasmer_compiler_singlepass::Singlepass;
@@ -74,6 +74,8 @@ pub struct WasmerInstance {
 #[derive(Clone)]
 pub struct WasmerInstanceEnv {
     instance: LazyInit<Instance>,
+    /// The exported memory, kept at hand as its size is checked at every metered block
+    memory: LazyInit<Memory>,
     /// See notes on `WasmerInstance.runtime_ptr`
     runtime_ptr: Arc<Mutex<usize>>,
 }
@@ -144,6 +146,9 @@ pub fn get_memory_size(instance: &Instance) -> Result<usize, WasmRuntimeError> {
 impl WasmerEnv for WasmerInstanceEnv {
     fn init_with_instance(&mut self, instance: &Instance) -> Result<(), HostEnvInitError> {
         self.instance.initialize(instance.clone());
+        if let Ok(memory) = instance.exports.get_memory(EXPORT_MEMORY) {
+            self.memory.initialize(memory.clone());
+        }
         Ok(())
     }
 }
@@ -206,6 +211,43 @@ impl WasmerModule {
                 .map(|buffer| buffer.0)
         }
 
+        pub fn blueprint_get_interface(
+            env: &WasmerInstanceEnv,
+            package_address_ptr: u32,
+            package_address_len: u32,
+            blueprint_name_ptr: u32,
+            blueprint_name_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let package_address = read_memory(&instance, package_address_ptr, package_address_len)?;
+            let blueprint_name = read_memory(&instance, blueprint_name_ptr, blueprint_name_len)?;
+
+            runtime
+                .blueprint_get_interface(package_address, blueprint_name)
+                .map(|buffer| buffer.0)
+        }
+
+        pub fn blueprint_get_event_schema(
+            env: &WasmerInstanceEnv,
+            package_address_ptr: u32,
+            package_address_len: u32,
+            blueprint_name_ptr: u32,
+            blueprint_name_len: u32,
+            event_name_ptr: u32,
+            event_name_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let package_address = read_memory(&instance, package_address_ptr, package_address_len)?;
+            let blueprint_name = read_memory(&instance, blueprint_name_ptr, blueprint_name_len)?;
+            let event_name = read_memory(&instance, event_name_ptr, event_name_len)?;
+
+            runtime
+                .blueprint_get_event_schema(package_address, blueprint_name, event_name)
+                .map(|buffer| buffer.0)
+        }
+
         pub fn address_allocate(
             env: &WasmerInstanceEnv,
             package_address_ptr: u32,
@@ -375,6 +417,18 @@ impl WasmerModule {
                 .map(|buffer| buffer.0)
         }
 
+        pub fn object_drop(
+            env: &WasmerInstanceEnv,
+            node_id_ptr: u32,
+            node_id_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime
+                .drop_object(read_memory(&instance, node_id_ptr, node_id_len)?)
+                .map(|buffer| buffer.0)
+        }
+
         pub fn key_value_store_new(
             env: &WasmerInstanceEnv,
             schema_id_ptr: u32,
@@ -421,6 +475,38 @@ impl WasmerModule {
                 .map(|buffer| buffer.0)
         }
 
+        pub fn key_value_store_read_entries(
+            env: &WasmerInstanceEnv,
+            node_id_ptr: u32,
+            node_id_len: u32,
+            keys_ptr: u32,
+            keys_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime
+                .key_value_store_read_entries(
+                    read_memory(&instance, node_id_ptr, node_id_len)?,
+                    read_memory(&instance, keys_ptr, keys_len)?,
+                )
+                .map(|buffer| buffer.0)
+        }
+
+        pub fn key_value_store_write_entries(
+            env: &WasmerInstanceEnv,
+            node_id_ptr: u32,
+            node_id_len: u32,
+            entries_ptr: u32,
+            entries_len: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime.key_value_store_write_entries(
+                read_memory(&instance, node_id_ptr, node_id_len)?,
+                read_memory(&instance, entries_ptr, entries_len)?,
+            )
+        }
+
         pub fn key_value_entry_read(
             env: &WasmerInstanceEnv,
             handle: u32,
@@ -551,6 +637,65 @@ impl WasmerModule {
             runtime.actor_emit_event(event_name, event_data, event_flags)
         }
 
+        fn actor_subscribe_to_event(
+            env: &WasmerInstanceEnv,
+            emitter_ptr: u32,
+            emitter_len: u32,
+            event_name_ptr: u32,
+            event_name_len: u32,
+            hook_method_ptr: u32,
+            hook_method_len: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let emitter = read_memory(&instance, emitter_ptr, emitter_len)?;
+            let event_name = read_memory(&instance, event_name_ptr, event_name_len)?;
+            let hook_method = read_memory(&instance, hook_method_ptr, hook_method_len)?;
+
+            runtime.actor_subscribe_to_event(emitter, event_name, hook_method)
+        }
+
+        fn actor_unsubscribe_from_event(
+            env: &WasmerInstanceEnv,
+            emitter_ptr: u32,
+            emitter_len: u32,
+            event_name_ptr: u32,
+            event_name_len: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let emitter = read_memory(&instance, emitter_ptr, emitter_len)?;
+            let event_name = read_memory(&instance, event_name_ptr, event_name_len)?;
+
+            runtime.actor_unsubscribe_from_event(emitter, event_name)
+        }
+
+        pub fn actor_get_state_size(
+            env: &WasmerInstanceEnv,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (_instance, runtime) = grab_runtime!(env);
+
+            runtime.actor_get_state_size().map(|buffer| buffer.0)
+        }
+
+        pub fn actor_rate_limit_check(
+            env: &WasmerInstanceEnv,
+            name_ptr: u32,
+            name_len: u32,
+            scope: u32,
+            max: u32,
+            window_epochs: u64,
+        ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let name = read_memory(&instance, name_ptr, name_len)?;
+            let scope = RateLimitScope::from_repr(scope).ok_or(InvokeError::SelfError(
+                WasmRuntimeError::InvalidRateLimitScope(scope),
+            ))?;
+
+            runtime.actor_rate_limit_check(name, scope, max, window_epochs)
+        }
+
         pub fn costing_get_execution_cost_unit_limit(
             env: &WasmerInstanceEnv,
         ) -> Result<u32, InvokeError<WasmRuntimeError>> {
@@ -603,6 +748,14 @@ impl WasmerModule {
             runtime.costing_get_fee_balance().map(|buffer| buffer.0)
         }
 
+        pub fn costing_get_execution_cost_units_consumed(
+            env: &WasmerInstanceEnv,
+        ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+            let (_instance, runtime) = grab_runtime!(env);
+
+            runtime.costing_get_execution_cost_units_consumed()
+        }
+
         pub fn costing_get_usd_price(
             env: &WasmerInstanceEnv,
         ) -> Result<u64, InvokeError<WasmRuntimeError>> {
@@ -616,6 +769,12 @@ impl WasmerModule {
             n: u64,
         ) -> Result<(), InvokeError<WasmRuntimeError>> {
             let (_instance, runtime) = grab_runtime!(env);
+            // Memory can only grow between metered blocks, so this is where growth is observed
+            let memory = env
+                .memory
+                .get_ref()
+                .ok_or(WasmRuntimeError::MemoryAccessError)?;
+            runtime.update_wasm_memory_usage(memory.size().0)?;
             // TODO: wasm-instrument uses u64 for cost units. We need to decide if we want to move from u32
             // to u64 as well.
             runtime.consume_wasm_execution_units(n as u32)
@@ -636,6 +795,18 @@ impl WasmerModule {
             runtime.sys_log(level, message)
         }
 
+        fn sys_log_record(
+            env: &WasmerInstanceEnv,
+            record_ptr: u32,
+            record_len: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let record = read_memory(&instance, record_ptr, record_len)?;
+
+            runtime.sys_log_record(record)
+        }
+
         fn sys_bech32_encode_address(
             env: &WasmerInstanceEnv,
             address_ptr: u32,
@@ -662,6 +833,18 @@ impl WasmerModule {
             runtime.sys_panic(message)
         }
 
+        fn sys_abort(
+            env: &WasmerInstanceEnv,
+            error_ptr: u32,
+            error_len: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let error = read_memory(&instance, error_ptr, error_len)?;
+
+            runtime.sys_abort(error)
+        }
+
         pub fn sys_get_transaction_hash(
             env: &WasmerInstanceEnv,
         ) -> Result<u64, InvokeError<WasmRuntimeError>> {
@@ -670,6 +853,16 @@ impl WasmerModule {
             runtime.sys_get_transaction_hash().map(|buffer| buffer.0)
         }
 
+        pub fn sys_get_transaction_signatories(
+            env: &WasmerInstanceEnv,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (_instance, runtime) = grab_runtime!(env);
+
+            runtime
+                .sys_get_transaction_signatories()
+                .map(|buffer| buffer.0)
+        }
+
         pub fn sys_generate_ruid(
             env: &WasmerInstanceEnv,
         ) -> Result<u64, InvokeError<WasmRuntimeError>> {
@@ -817,6 +1010,7 @@ impl WasmerModule {
         // env
         let env = WasmerInstanceEnv {
             instance: LazyInit::new(),
+            memory: LazyInit::new(),
             runtime_ptr: Arc::new(Mutex::new(0)),
         };
 
@@ -824,6 +1018,8 @@ impl WasmerModule {
         let import_object = imports! {
             MODULE_ENV_NAME => {
                 BLUEPRINT_CALL_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), blueprint_call),
+                BLUEPRINT_GET_INTERFACE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), blueprint_get_interface),
+                BLUEPRINT_GET_EVENT_SCHEMA_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), blueprint_get_event_schema),
                 ADDRESS_ALLOCATE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), address_allocate),
                 ADDRESS_GET_RESERVATION_ADDRESS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), address_get_reservation_address),
                 OBJECT_NEW_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_new),
@@ -831,12 +1027,15 @@ impl WasmerModule {
                 OBJECT_INSTANCE_OF_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_instance_of),
                 OBJECT_GET_BLUEPRINT_ID_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_get_blueprint_id),
                 OBJECT_GET_OUTER_OBJECT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_get_outer_object),
+                OBJECT_DROP_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_drop),
                 OBJECT_CALL_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_call),
                 OBJECT_CALL_MODULE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_call_module),
                 OBJECT_CALL_DIRECT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_call_direct),
                 KEY_VALUE_STORE_NEW_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_store_new),
                 KEY_VALUE_STORE_OPEN_ENTRY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_store_open_entry),
                 KEY_VALUE_STORE_REMOVE_ENTRY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_store_remove_entry),
+                KEY_VALUE_STORE_READ_ENTRIES_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_store_read_entries),
+                KEY_VALUE_STORE_WRITE_ENTRIES_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_store_write_entries),
                 KEY_VALUE_ENTRY_READ_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_entry_read),
                 KEY_VALUE_ENTRY_WRITE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_entry_write),
                 KEY_VALUE_ENTRY_REMOVE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_entry_remove),
@@ -849,6 +1048,10 @@ impl WasmerModule {
                 ACTOR_GET_PACKAGE_ADDRESS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_get_package_address),
                 ACTOR_GET_BLUEPRINT_NAME_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_get_blueprint_name),
                 ACTOR_EMIT_EVENT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_emit_event),
+                ACTOR_SUBSCRIBE_TO_EVENT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_subscribe_to_event),
+                ACTOR_UNSUBSCRIBE_FROM_EVENT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_unsubscribe_from_event),
+                ACTOR_GET_STATE_SIZE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_get_state_size),
+                ACTOR_RATE_LIMIT_CHECK_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_rate_limit_check),
                 COSTING_CONSUME_WASM_EXECUTION_UNITS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), consume_wasm_execution_units),
                 COSTING_GET_EXECUTION_COST_UNIT_LIMIT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), costing_get_execution_cost_unit_limit),
                 COSTING_GET_EXECUTION_COST_UNIT_PRICE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), costing_get_execution_cost_unit_price),
@@ -857,10 +1060,14 @@ impl WasmerModule {
                 COSTING_GET_USD_PRICE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), costing_get_usd_price),
                 COSTING_GET_TIP_PERCENTAGE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), costing_get_tip_percentage),
                 COSTING_GET_FEE_BALANCE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), costing_get_fee_balance),
+                COSTING_GET_EXECUTION_COST_UNITS_CONSUMED_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), costing_get_execution_cost_units_consumed),
                 SYS_LOG_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_log),
+                SYS_LOG_RECORD_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_log_record),
                 SYS_BECH32_ENCODE_ADDRESS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_bech32_encode_address),
                 SYS_PANIC_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_panic),
+                SYS_ABORT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_abort),
                 SYS_GET_TRANSACTION_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_get_transaction_hash),
+                SYS_GET_TRANSACTION_SIGNATORIES_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_get_transaction_signatories),
                 SYS_GENERATE_RUID_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_generate_ruid),
                 BUFFER_CONSUME_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), buffer_consume),
                 CRYPTO_UTILS_BLS12381_V1_VERIFY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), bls12381_v1_verify),
@@ -1,4 +1,4 @@
-use radix_engine_interface::api::actor_api::EventFlags;
+use radix_engine_interface::api::actor_api::{EventFlags, RateLimitScope};
 use radix_engine_interface::blueprints::package::CodeHash;
 use sbor::rust::mem::transmute;
 use sbor::rust::mem::MaybeUninit;
@@ -206,6 +206,68 @@ fn call_function(
         .map(|buffer| buffer.0)
 }
 
+fn get_blueprint_interface(
+    mut caller: Caller<'_, HostState>,
+    package_address_ptr: u32,
+    package_address_len: u32,
+    blueprint_name_ptr: u32,
+    blueprint_name_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let package_address = read_memory(
+        caller.as_context_mut(),
+        memory,
+        package_address_ptr,
+        package_address_len,
+    )?;
+    let blueprint_name = read_memory(
+        caller.as_context_mut(),
+        memory,
+        blueprint_name_ptr,
+        blueprint_name_len,
+    )?;
+
+    runtime
+        .blueprint_get_interface(package_address, blueprint_name)
+        .map(|buffer| buffer.0)
+}
+
+fn get_blueprint_event_schema(
+    mut caller: Caller<'_, HostState>,
+    package_address_ptr: u32,
+    package_address_len: u32,
+    blueprint_name_ptr: u32,
+    blueprint_name_len: u32,
+    event_name_ptr: u32,
+    event_name_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let package_address = read_memory(
+        caller.as_context_mut(),
+        memory,
+        package_address_ptr,
+        package_address_len,
+    )?;
+    let blueprint_name = read_memory(
+        caller.as_context_mut(),
+        memory,
+        blueprint_name_ptr,
+        blueprint_name_len,
+    )?;
+    let event_name = read_memory(
+        caller.as_context_mut(),
+        memory,
+        event_name_ptr,
+        event_name_len,
+    )?;
+
+    runtime
+        .blueprint_get_event_schema(package_address, blueprint_name, event_name)
+        .map(|buffer| buffer.0)
+}
+
 fn new_object(
     mut caller: Caller<'_, HostState>,
     blueprint_name_ptr: u32,
@@ -348,6 +410,14 @@ fn fee_balance(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmRun
     runtime.costing_get_fee_balance().map(|buffer| buffer.0)
 }
 
+fn execution_cost_units_consumed(
+    caller: Caller<'_, HostState>,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (_memory, runtime) = grab_runtime!(caller);
+
+    runtime.costing_get_execution_cost_units_consumed()
+}
+
 fn globalize_object(
     mut caller: Caller<'_, HostState>,
     obj_id_ptr: u32,
@@ -435,6 +505,23 @@ fn get_outer_object(
         .map(|buffer| buffer.0)
 }
 
+fn drop_object(
+    mut caller: Caller<'_, HostState>,
+    node_id_ptr: u32,
+    node_id_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    runtime
+        .drop_object(read_memory(
+            caller.as_context_mut(),
+            memory,
+            node_id_ptr,
+            node_id_len,
+        )?)
+        .map(|buffer| buffer.0)
+}
+
 fn lock_key_value_store_entry(
     mut caller: Caller<'_, HostState>,
     node_id_ptr: u32,
@@ -504,6 +591,36 @@ fn key_value_store_remove(
         .map(|buffer| buffer.0)
 }
 
+fn key_value_store_read_entries(
+    mut caller: Caller<'_, HostState>,
+    node_id_ptr: u32,
+    node_id_len: u32,
+    keys_ptr: u32,
+    keys_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+    let node_id = read_memory(caller.as_context_mut(), memory, node_id_ptr, node_id_len)?;
+    let keys = read_memory(caller.as_context_mut(), memory, keys_ptr, keys_len)?;
+
+    runtime
+        .key_value_store_read_entries(node_id, keys)
+        .map(|buffer| buffer.0)
+}
+
+fn key_value_store_write_entries(
+    mut caller: Caller<'_, HostState>,
+    node_id_ptr: u32,
+    node_id_len: u32,
+    entries_ptr: u32,
+    entries_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+    let node_id = read_memory(caller.as_context_mut(), memory, node_id_ptr, node_id_len)?;
+    let entries = read_memory(caller.as_context_mut(), memory, entries_ptr, entries_len)?;
+
+    runtime.key_value_store_write_entries(node_id, entries)
+}
+
 fn lock_field(
     caller: Caller<'_, HostState>,
     object_handle: u32,
@@ -572,7 +689,10 @@ fn consume_wasm_execution_units(
     caller: Caller<'_, HostState>,
     n: u64,
 ) -> Result<(), InvokeError<WasmRuntimeError>> {
-    let (_memory, runtime) = grab_runtime!(caller);
+    let (memory, runtime) = grab_runtime!(caller);
+
+    // Memory can only grow between metered blocks, so this is where growth is observed
+    runtime.update_wasm_memory_usage(memory.current_pages(caller.as_context()).into())?;
 
     // TODO: wasm-instrument uses u64 for cost units. We need to decide if we want to move from u32
     // to u64 as well.
@@ -608,6 +728,78 @@ fn emit_event(
     runtime.actor_emit_event(event_name, event_data, event_flags)
 }
 
+fn subscribe_to_event(
+    mut caller: Caller<'_, HostState>,
+    emitter_ptr: u32,
+    emitter_len: u32,
+    event_name_ptr: u32,
+    event_name_len: u32,
+    hook_method_ptr: u32,
+    hook_method_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let emitter = read_memory(caller.as_context_mut(), memory, emitter_ptr, emitter_len)?;
+    let event_name = read_memory(
+        caller.as_context_mut(),
+        memory,
+        event_name_ptr,
+        event_name_len,
+    )?;
+    let hook_method = read_memory(
+        caller.as_context_mut(),
+        memory,
+        hook_method_ptr,
+        hook_method_len,
+    )?;
+
+    runtime.actor_subscribe_to_event(emitter, event_name, hook_method)
+}
+
+fn unsubscribe_from_event(
+    mut caller: Caller<'_, HostState>,
+    emitter_ptr: u32,
+    emitter_len: u32,
+    event_name_ptr: u32,
+    event_name_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let emitter = read_memory(caller.as_context_mut(), memory, emitter_ptr, emitter_len)?;
+    let event_name = read_memory(
+        caller.as_context_mut(),
+        memory,
+        event_name_ptr,
+        event_name_len,
+    )?;
+
+    runtime.actor_unsubscribe_from_event(emitter, event_name)
+}
+
+fn get_state_size(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (_memory, runtime) = grab_runtime!(caller);
+
+    runtime.actor_get_state_size().map(|buffer| buffer.0)
+}
+
+fn rate_limit_check(
+    mut caller: Caller<'_, HostState>,
+    name_ptr: u32,
+    name_len: u32,
+    scope: u32,
+    max: u32,
+    window_epochs: u64,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let name = read_memory(caller.as_context_mut(), memory, name_ptr, name_len)?;
+    let scope = RateLimitScope::from_repr(scope).ok_or(InvokeError::SelfError(
+        WasmRuntimeError::InvalidRateLimitScope(scope),
+    ))?;
+
+    runtime.actor_rate_limit_check(name, scope, max, window_epochs)
+}
+
 fn get_transaction_hash(
     caller: Caller<'_, HostState>,
 ) -> Result<u64, InvokeError<WasmRuntimeError>> {
@@ -616,6 +808,16 @@ fn get_transaction_hash(
     runtime.sys_get_transaction_hash().map(|buffer| buffer.0)
 }
 
+fn get_transaction_signatories(
+    caller: Caller<'_, HostState>,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (_, runtime) = grab_runtime!(caller);
+
+    runtime
+        .sys_get_transaction_signatories()
+        .map(|buffer| buffer.0)
+}
+
 fn generate_ruid(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmRuntimeError>> {
     let (_, runtime) = grab_runtime!(caller);
 
@@ -637,6 +839,18 @@ fn emit_log(
     runtime.sys_log(level, message)
 }
 
+fn emit_log_record(
+    mut caller: Caller<'_, HostState>,
+    record_ptr: u32,
+    record_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let record = read_memory(caller.as_context_mut(), memory, record_ptr, record_len)?;
+
+    runtime.sys_log_record(record)
+}
+
 fn bech32_encode_address(
     mut caller: Caller<'_, HostState>,
     address_ptr: u32,
@@ -663,6 +877,18 @@ fn panic(
     runtime.sys_panic(message)
 }
 
+fn abort(
+    mut caller: Caller<'_, HostState>,
+    error_ptr: u32,
+    error_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let error = read_memory(caller.as_context_mut(), memory, error_ptr, error_len)?;
+
+    runtime.sys_abort(error)
+}
+
 fn bls12381_v1_verify(
     mut caller: Caller<'_, HostState>,
     message_ptr: u32,
@@ -969,6 +1195,48 @@ impl WasmiModule {
             },
         );
 
+        let host_blueprint_get_interface = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             package_address_ptr: u32,
+             package_address_len: u32,
+             blueprint_name_ptr: u32,
+             blueprint_name_len: u32|
+             -> Result<u64, Trap> {
+                get_blueprint_interface(
+                    caller,
+                    package_address_ptr,
+                    package_address_len,
+                    blueprint_name_ptr,
+                    blueprint_name_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_blueprint_get_event_schema = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             package_address_ptr: u32,
+             package_address_len: u32,
+             blueprint_name_ptr: u32,
+             blueprint_name_len: u32,
+             event_name_ptr: u32,
+             event_name_len: u32|
+             -> Result<u64, Trap> {
+                get_blueprint_event_schema(
+                    caller,
+                    package_address_ptr,
+                    package_address_len,
+                    blueprint_name_ptr,
+                    blueprint_name_len,
+                    event_name_ptr,
+                    event_name_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
         let host_new_component = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -1076,6 +1344,13 @@ impl WasmiModule {
             },
         );
 
+        let host_execution_cost_units_consumed = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>| -> Result<u32, Trap> {
+                execution_cost_units_consumed(caller).map_err(|e| e.into())
+            },
+        );
+
         let host_globalize_object = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -1142,6 +1417,16 @@ impl WasmiModule {
             },
         );
 
+        let host_drop_object = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             node_id_ptr: u32,
+             node_id_len: u32|
+             -> Result<u64, Trap> {
+                drop_object(caller, node_id_ptr, node_id_len).map_err(|e| e.into())
+            },
+        );
+
         let host_lock_key_value_store_entry = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -1208,6 +1493,38 @@ impl WasmiModule {
             },
         );
 
+        let host_key_value_store_read_entries = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             node_id_ptr: u32,
+             node_id_len: u32,
+             keys_ptr: u32,
+             keys_len: u32|
+             -> Result<u64, Trap> {
+                key_value_store_read_entries(caller, node_id_ptr, node_id_len, keys_ptr, keys_len)
+                    .map_err(|e| e.into())
+            },
+        );
+
+        let host_key_value_store_write_entries = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             node_id_ptr: u32,
+             node_id_len: u32,
+             entries_ptr: u32,
+             entries_len: u32|
+             -> Result<(), Trap> {
+                key_value_store_write_entries(
+                    caller,
+                    node_id_ptr,
+                    node_id_len,
+                    entries_ptr,
+                    entries_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
         let host_lock_field = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -1293,6 +1610,69 @@ impl WasmiModule {
             },
         );
 
+        let host_subscribe_to_event = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             emitter_ptr: u32,
+             emitter_len: u32,
+             event_name_ptr: u32,
+             event_name_len: u32,
+             hook_method_ptr: u32,
+             hook_method_len: u32|
+             -> Result<(), Trap> {
+                subscribe_to_event(
+                    caller,
+                    emitter_ptr,
+                    emitter_len,
+                    event_name_ptr,
+                    event_name_len,
+                    hook_method_ptr,
+                    hook_method_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_unsubscribe_from_event = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             emitter_ptr: u32,
+             emitter_len: u32,
+             event_name_ptr: u32,
+             event_name_len: u32|
+             -> Result<(), Trap> {
+                unsubscribe_from_event(
+                    caller,
+                    emitter_ptr,
+                    emitter_len,
+                    event_name_ptr,
+                    event_name_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_get_state_size = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>| -> Result<u64, Trap> {
+                get_state_size(caller).map_err(|e| e.into())
+            },
+        );
+
+        let host_rate_limit_check = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             name_ptr: u32,
+             name_len: u32,
+             scope: u32,
+             max: u32,
+             window_epochs: u64|
+             -> Result<u32, Trap> {
+                rate_limit_check(caller, name_ptr, name_len, scope, max, window_epochs)
+                    .map_err(|e| e.into())
+            },
+        );
+
         let host_emit_log = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -1306,6 +1686,16 @@ impl WasmiModule {
             },
         );
 
+        let host_emit_log_record = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             record_ptr: u32,
+             record_len: u32|
+             -> Result<(), Trap> {
+                emit_log_record(caller, record_ptr, record_len).map_err(|e| e.into())
+            },
+        );
+
         let host_panic = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -1316,6 +1706,13 @@ impl WasmiModule {
             },
         );
 
+        let host_abort = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>, error_ptr: u32, error_len: u32| -> Result<(), Trap> {
+                abort(caller, error_ptr, error_len).map_err(|e| e.into())
+            },
+        );
+
         let host_bech32_encode_address = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -1333,6 +1730,13 @@ impl WasmiModule {
             },
         );
 
+        let host_get_transaction_signatories = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>| -> Result<u64, Trap> {
+                get_transaction_signatories(caller).map_err(|e| e.into())
+            },
+        );
+
         let host_generate_ruid = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>| -> Result<u64, Trap> {
@@ -1438,6 +1842,16 @@ impl WasmiModule {
             host_call_direct_method
         );
         linker_define!(linker, BLUEPRINT_CALL_FUNCTION_NAME, host_blueprint_call);
+        linker_define!(
+            linker,
+            BLUEPRINT_GET_INTERFACE_FUNCTION_NAME,
+            host_blueprint_get_interface
+        );
+        linker_define!(
+            linker,
+            BLUEPRINT_GET_EVENT_SCHEMA_FUNCTION_NAME,
+            host_blueprint_get_event_schema
+        );
         linker_define!(linker, OBJECT_NEW_FUNCTION_NAME, host_new_component);
 
         linker_define!(
@@ -1481,6 +1895,11 @@ impl WasmiModule {
             COSTING_GET_FEE_BALANCE_FUNCTION_NAME,
             host_fee_balance
         );
+        linker_define!(
+            linker,
+            COSTING_GET_EXECUTION_COST_UNITS_CONSUMED_FUNCTION_NAME,
+            host_execution_cost_units_consumed
+        );
         linker_define!(
             linker,
             OBJECT_GLOBALIZE_FUNCTION_NAME,
@@ -1497,6 +1916,7 @@ impl WasmiModule {
             OBJECT_GET_OUTER_OBJECT_FUNCTION_NAME,
             host_get_outer_object
         );
+        linker_define!(linker, OBJECT_DROP_FUNCTION_NAME, host_drop_object);
         linker_define!(linker, ACTOR_OPEN_FIELD_FUNCTION_NAME, host_lock_field);
 
         linker_define!(
@@ -1534,6 +1954,16 @@ impl WasmiModule {
             KEY_VALUE_STORE_REMOVE_ENTRY_FUNCTION_NAME,
             host_key_value_store_remove
         );
+        linker_define!(
+            linker,
+            KEY_VALUE_STORE_READ_ENTRIES_FUNCTION_NAME,
+            host_key_value_store_read_entries
+        );
+        linker_define!(
+            linker,
+            KEY_VALUE_STORE_WRITE_ENTRIES_FUNCTION_NAME,
+            host_key_value_store_write_entries
+        );
 
         linker_define!(linker, FIELD_ENTRY_READ_FUNCTION_NAME, host_field_lock_read);
         linker_define!(
@@ -1567,13 +1997,40 @@ impl WasmiModule {
             host_consume_wasm_execution_units
         );
         linker_define!(linker, ACTOR_EMIT_EVENT_FUNCTION_NAME, host_emit_event);
+        linker_define!(
+            linker,
+            ACTOR_SUBSCRIBE_TO_EVENT_FUNCTION_NAME,
+            host_subscribe_to_event
+        );
+        linker_define!(
+            linker,
+            ACTOR_UNSUBSCRIBE_FROM_EVENT_FUNCTION_NAME,
+            host_unsubscribe_from_event
+        );
+        linker_define!(
+            linker,
+            ACTOR_GET_STATE_SIZE_FUNCTION_NAME,
+            host_get_state_size
+        );
+        linker_define!(
+            linker,
+            ACTOR_RATE_LIMIT_CHECK_FUNCTION_NAME,
+            host_rate_limit_check
+        );
         linker_define!(linker, SYS_LOG_FUNCTION_NAME, host_emit_log);
+        linker_define!(linker, SYS_LOG_RECORD_FUNCTION_NAME, host_emit_log_record);
         linker_define!(linker, SYS_PANIC_FUNCTION_NAME, host_panic);
+        linker_define!(linker, SYS_ABORT_FUNCTION_NAME, host_abort);
         linker_define!(
             linker,
             SYS_GET_TRANSACTION_HASH_FUNCTION_NAME,
             host_get_transaction_hash
         );
+        linker_define!(
+            linker,
+            SYS_GET_TRANSACTION_SIGNATORIES_FUNCTION_NAME,
+            host_get_transaction_signatories
+        );
         linker_define!(
             linker,
             SYS_BECH32_ENCODE_ADDRESS_FUNCTION_NAME,
@@ -2,7 +2,7 @@ use crate::errors::InvokeError;
 use crate::system::system_modules::costing::*;
 use crate::types::*;
 use crate::vm::wasm::*;
-use radix_engine_interface::api::actor_api::EventFlags;
+use radix_engine_interface::api::actor_api::{EventFlags, RateLimitScope};
 use sbor::rust::vec::Vec;
 
 /// A `Nop` runtime accepts any external function calls by doing nothing and returning void.
@@ -79,6 +79,23 @@ impl<'a> WasmRuntime for NoOpWasmRuntime<'a> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn blueprint_get_interface(
+        &mut self,
+        package_address: Vec<u8>,
+        blueprint_name: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn blueprint_get_event_schema(
+        &mut self,
+        package_address: Vec<u8>,
+        blueprint_name: Vec<u8>,
+        event_name: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn object_new(
         &mut self,
         blueprint_name: Vec<u8>,
@@ -161,6 +178,22 @@ impl<'a> WasmRuntime for NoOpWasmRuntime<'a> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn key_value_store_read_entries(
+        &mut self,
+        node_id: Vec<u8>,
+        keys: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn key_value_store_write_entries(
+        &mut self,
+        node_id: Vec<u8>,
+        entries: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn actor_open_field(
         &mut self,
         object_handle: u32,
@@ -211,6 +244,13 @@ impl<'a> WasmRuntime for NoOpWasmRuntime<'a> {
             .map_err(|e| InvokeError::SelfError(WasmRuntimeError::FeeReserveError(e)))
     }
 
+    fn update_wasm_memory_usage(
+        &mut self,
+        _pages: u32,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        Ok(())
+    }
+
     fn instance_of(
         &mut self,
         component_id: Vec<u8>,
@@ -234,6 +274,10 @@ impl<'a> WasmRuntime for NoOpWasmRuntime<'a> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn drop_object(&mut self, node_id: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn actor_emit_event(
         &mut self,
         event_name: Vec<u8>,
@@ -243,6 +287,37 @@ impl<'a> WasmRuntime for NoOpWasmRuntime<'a> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn actor_subscribe_to_event(
+        &mut self,
+        emitter: Vec<u8>,
+        event_name: Vec<u8>,
+        hook_method: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn actor_unsubscribe_from_event(
+        &mut self,
+        emitter: Vec<u8>,
+        event_name: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn actor_get_state_size(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn actor_rate_limit_check(
+        &mut self,
+        name: Vec<u8>,
+        scope: RateLimitScope,
+        max: u32,
+        window_epochs: u64,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn sys_log(
         &mut self,
         level: Vec<u8>,
@@ -251,14 +326,28 @@ impl<'a> WasmRuntime for NoOpWasmRuntime<'a> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn sys_log_record(&mut self, record: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn sys_panic(&mut self, message: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn sys_abort(&mut self, error: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn sys_get_transaction_hash(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn sys_get_transaction_signatories(
+        &mut self,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn sys_bech32_encode_address(
         &mut self,
         address: Vec<u8>,
@@ -306,6 +395,12 @@ impl<'a> WasmRuntime for NoOpWasmRuntime<'a> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn costing_get_execution_cost_units_consumed(
+        &mut self,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn crypto_utils_bls12381_v1_verify(
         &mut self,
         message: Vec<u8>,
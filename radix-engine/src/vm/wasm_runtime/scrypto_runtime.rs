@@ -2,7 +2,7 @@ use crate::errors::InvokeError;
 use crate::errors::RuntimeError;
 use crate::types::*;
 use crate::vm::wasm::*;
-use radix_engine_interface::api::actor_api::EventFlags;
+use radix_engine_interface::api::actor_api::{EventFlags, RateLimitScope};
 use radix_engine_interface::api::field_api::LockFlags;
 use radix_engine_interface::api::key_value_store_api::KeyValueStoreDataSchema;
 use radix_engine_interface::api::{ActorRefHandle, AttachedModuleId, ClientApi, FieldValue};
@@ -21,6 +21,7 @@ where
     package_address: PackageAddress,
     export_name: String,
     wasm_execution_units_buffer: u32,
+    wasm_memory_pages: Option<u32>,
     max_number_of_buffers: usize,
 }
 
@@ -36,6 +37,7 @@ where
             package_address,
             export_name,
             wasm_execution_units_buffer: 0,
+            wasm_memory_pages: None,
             max_number_of_buffers: MAX_NUMBER_OF_BUFFERS,
         }
     }
@@ -166,6 +168,41 @@ where
         self.allocate_buffer(return_data)
     }
 
+    fn blueprint_get_interface(
+        &mut self,
+        package_address: Vec<u8>,
+        blueprint_name: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let (package_address, blueprint_name) =
+            Self::parse_blueprint_id(package_address, blueprint_name)?;
+        let interface = self
+            .api
+            .get_blueprint_interface(&BlueprintId::new(&package_address, blueprint_name))?;
+
+        let buffer = scrypto_encode(&interface).expect("Failed to encode blueprint interface");
+        self.allocate_buffer(buffer)
+    }
+
+    fn blueprint_get_event_schema(
+        &mut self,
+        package_address: Vec<u8>,
+        blueprint_name: Vec<u8>,
+        event_name: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let (package_address, blueprint_name) =
+            Self::parse_blueprint_id(package_address, blueprint_name)?;
+        let event_name =
+            String::from_utf8(event_name).map_err(|_| WasmRuntimeError::InvalidString)?;
+        let (schema, scoped_type_id) = self.api.get_blueprint_event_schema(
+            &BlueprintId::new(&package_address, blueprint_name),
+            &event_name,
+        )?;
+
+        let buffer = scrypto_encode(&(schema.as_ref(), scoped_type_id))
+            .expect("Failed to encode event schema");
+        self.allocate_buffer(buffer)
+    }
+
     fn object_new(
         &mut self,
         blueprint_name: Vec<u8>,
@@ -309,6 +346,65 @@ where
         self.allocate_buffer(rtn)
     }
 
+    fn key_value_store_read_entries(
+        &mut self,
+        node_id: Vec<u8>,
+        keys: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let node_id = NodeId(
+            TryInto::<[u8; NodeId::LENGTH]>::try_into(node_id.as_ref())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+        );
+        let keys = scrypto_decode::<Vec<Vec<u8>>>(&keys)
+            .map_err(WasmRuntimeError::InvalidKeyValueStoreEntries)?;
+
+        // Each entry goes through the same open/read/close path as a single read, so the
+        // substate costs and checks are unchanged and only the host calls are saved.
+        let mut values = Vec::with_capacity(keys.len());
+        for key in &keys {
+            let handle =
+                self.api
+                    .key_value_store_open_entry(&node_id, key, LockFlags::read_only())?;
+            values.push(self.api.key_value_entry_get(handle)?);
+            self.api.key_value_entry_close(handle)?;
+        }
+
+        let encoded = scrypto_encode(&values).expect("Entry values can be encoded");
+        self.api
+            .consume_cost_units(ClientCostingEntry::ProcessKeyValueEntryBatch {
+                size: keys.iter().map(|key| key.len()).sum::<usize>() + encoded.len(),
+            })?;
+
+        self.allocate_buffer(encoded)
+    }
+
+    fn key_value_store_write_entries(
+        &mut self,
+        node_id: Vec<u8>,
+        entries: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        let node_id = NodeId(
+            TryInto::<[u8; NodeId::LENGTH]>::try_into(node_id.as_ref())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+        );
+        self.api
+            .consume_cost_units(ClientCostingEntry::ProcessKeyValueEntryBatch {
+                size: entries.len(),
+            })?;
+        let entries = scrypto_decode::<Vec<(Vec<u8>, Vec<u8>)>>(&entries)
+            .map_err(WasmRuntimeError::InvalidKeyValueStoreEntries)?;
+
+        for (key, value) in entries {
+            let handle = self
+                .api
+                .key_value_store_open_entry(&node_id, &key, LockFlags::MUTABLE)?;
+            self.api.key_value_entry_set(handle, value)?;
+            self.api.key_value_entry_close(handle)?;
+        }
+
+        Ok(())
+    }
+
     fn actor_open_field(
         &mut self,
         object_handle: u32,
@@ -397,6 +493,30 @@ where
         Ok(())
     }
 
+    fn update_wasm_memory_usage(
+        &mut self,
+        pages: u32,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        if self.wasm_memory_pages == Some(pages) {
+            return Ok(());
+        }
+
+        // The initial memory of the instance is covered by the instantiation cost
+        let grown_pages = self
+            .wasm_memory_pages
+            .map(|previous_pages| pages.saturating_sub(previous_pages))
+            .unwrap_or(0);
+        self.api
+            .consume_cost_units(ClientCostingEntry::GrowWasmMemory {
+                total_pages: pages,
+                grown_pages,
+            })
+            .map_err(InvokeError::downstream)?;
+        self.wasm_memory_pages = Some(pages);
+
+        Ok(())
+    }
+
     fn instance_of(
         &mut self,
         object_id: Vec<u8>,
@@ -450,6 +570,16 @@ where
         self.allocate_buffer(address.to_vec())
     }
 
+    fn drop_object(&mut self, node_id: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let node_id = NodeId(
+            TryInto::<[u8; NodeId::LENGTH]>::try_into(node_id.as_ref())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+        );
+        let fields = self.api.drop_object(&node_id)?;
+
+        self.allocate_buffer(scrypto_encode(&fields).expect("Failed to encode object fields"))
+    }
+
     fn actor_emit_event(
         &mut self,
         event_name: Vec<u8>,
@@ -464,6 +594,56 @@ where
         Ok(())
     }
 
+    fn actor_subscribe_to_event(
+        &mut self,
+        emitter: Vec<u8>,
+        event_name: Vec<u8>,
+        hook_method: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.api.actor_subscribe_to_event(
+            GlobalAddress::try_from(emitter.as_slice())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+            String::from_utf8(event_name).map_err(|_| WasmRuntimeError::InvalidString)?,
+            String::from_utf8(hook_method).map_err(|_| WasmRuntimeError::InvalidString)?,
+        )?;
+        Ok(())
+    }
+
+    fn actor_unsubscribe_from_event(
+        &mut self,
+        emitter: Vec<u8>,
+        event_name: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.api.actor_unsubscribe_from_event(
+            GlobalAddress::try_from(emitter.as_slice())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+            String::from_utf8(event_name).map_err(|_| WasmRuntimeError::InvalidString)?,
+        )?;
+        Ok(())
+    }
+
+    fn actor_get_state_size(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let state_size = self.api.actor_get_state_size()?;
+
+        self.allocate_buffer(scrypto_encode(&state_size).expect("Failed to encode state_size"))
+    }
+
+    fn actor_rate_limit_check(
+        &mut self,
+        name: Vec<u8>,
+        scope: RateLimitScope,
+        max: u32,
+        window_epochs: u64,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let allowed = self.api.actor_rate_limit_check(
+            String::from_utf8(name).map_err(|_| WasmRuntimeError::InvalidString)?,
+            scope,
+            max,
+            window_epochs,
+        )?;
+        Ok(allowed.into())
+    }
+
     fn sys_log(
         &mut self,
         level: Vec<u8>,
@@ -476,6 +656,13 @@ where
         Ok(())
     }
 
+    fn sys_log_record(&mut self, record: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.api.emit_log_record(
+            scrypto_decode::<LogRecord>(&record).map_err(WasmRuntimeError::InvalidLogRecord)?,
+        )?;
+        Ok(())
+    }
+
     fn sys_bech32_encode_address(
         &mut self,
         address: Vec<u8>,
@@ -492,12 +679,27 @@ where
         Ok(())
     }
 
+    fn sys_abort(&mut self, error: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.api.abort(
+            scrypto_decode::<ScryptoValue>(&error).map_err(WasmRuntimeError::InvalidAbortError)?,
+        )?;
+        Ok(())
+    }
+
     fn sys_get_transaction_hash(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         let hash = self.api.get_transaction_hash()?;
 
         self.allocate_buffer(hash.to_vec())
     }
 
+    fn sys_get_transaction_signatories(
+        &mut self,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let signatories = self.api.get_transaction_signatories()?;
+
+        self.allocate_buffer(scrypto_encode(&signatories).expect("Failed to encode signatories"))
+    }
+
     fn sys_generate_ruid(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         let ruid = self.api.generate_ruid()?;
 
@@ -561,6 +763,14 @@ where
         self.allocate_buffer(scrypto_encode(&fee_balance).expect("Failed to encode fee_balance"))
     }
 
+    fn costing_get_execution_cost_units_consumed(
+        &mut self,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let execution_cost_units_consumed = self.api.execution_cost_units_consumed()?;
+
+        Ok(execution_cost_units_consumed)
+    }
+
     fn crypto_utils_bls12381_v1_verify(
         &mut self,
         message: Vec<u8>,
@@ -139,3 +139,51 @@ impl Parse for AliasableType {
         })
     }
 }
+
+pub struct HooksInner {
+    pub paren_token: Paren,
+    pub bindings: Punctuated<HookBinding, Token![,]>,
+}
+
+impl Parse for HooksInner {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(Self {
+            paren_token: parenthesized!(content in input),
+            bindings: content.parse_terminated(HookBinding::parse)?,
+        })
+    }
+}
+
+/// A hook bound to a function of the blueprint, e.g. `on_drop = release`
+pub struct HookBinding {
+    pub hook: Ident,
+    pub eq_token: Token![=],
+    pub function: Ident,
+}
+
+impl Parse for HookBinding {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            hook: input.parse()?,
+            eq_token: input.parse()?,
+            function: input.parse()?,
+        })
+    }
+}
+
+/// The previous versions of a blueprint's state, oldest first, e.g. `#[state_version(StateV1)]`
+pub struct StateVersionInner {
+    pub paren_token: Paren,
+    pub previous_versions: Punctuated<Path, Token![,]>,
+}
+
+impl Parse for StateVersionInner {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(Self {
+            paren_token: parenthesized!(content in input),
+            previous_versions: content.parse_terminated(Path::parse)?,
+        })
+    }
+}
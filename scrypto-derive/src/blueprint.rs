@@ -1,5 +1,5 @@
 use crate::ast;
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 use radix_engine_common::address::AddressBech32Decoder;
 use regex::Regex;
@@ -382,6 +382,20 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     let fn_idents = generated_schema_info.fn_idents;
     let method_idents = generated_schema_info.method_idents;
     let function_idents = generated_schema_info.function_idents;
+    let hook_bindings = parse_hook_bindings(bp_ident, &blueprint.attributes)?;
+
+    let state_versioning = parse_state_versioning(bp_strut)?;
+    let state_type = match &state_versioning {
+        Some(versioning) => versioning.versioned_ident.to_token_stream(),
+        None => bp_ident.to_token_stream(),
+    };
+    let (output_state_versioning, state_codec_fns) = match &state_versioning {
+        Some(versioning) => (
+            versioning.generate_versioned_enum(),
+            versioning.generate_codec_fns(),
+        ),
+        None => (quote! {}, quote! {}),
+    };
 
     let blueprint_name = bp_ident.to_string();
     let owned_typed_name = format!("Owned{}", blueprint_name);
@@ -737,6 +751,8 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
         let schema_ident = format_ident!("{}_schema", bp_ident);
         let fn_names = generated_schema_info.fn_names;
         let fn_schemas = generated_schema_info.fn_schemas;
+        let hook_variants: Vec<Ident> = hook_bindings.iter().map(|b| b.variant.clone()).collect();
+        let hook_exports: Vec<String> = hook_bindings.iter().map(|b| b.export_name()).collect();
 
         // Getting the event types and other named types from attribute
         let (event_type_names, event_type_paths, registered_type_names, registered_type_paths) = {
@@ -753,7 +769,7 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                             ));
                         }
                     }
-                } else if attribute.path.is_ident("types") {
+                } else if attribute.path.is_ident("types") || attribute.path.is_ident("hooks") {
                 }
                 // None of the attributes to apply at the top-level of blueprint macros matched. So,
                 // we provide an error to the user that they're using an incorrect attribute macro
@@ -806,7 +822,7 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
 
                     // Aggregate fields
                     let mut fields = Vec::new();
-                    let type_index = aggregator.add_child_type_and_descendents::<#bp_ident>();
+                    let type_index = aggregator.add_child_type_and_descendents::<#state_type>();
                     fields.push(FieldSchema::static_field(type_index));
 
                     let state = BlueprintStateSchemaInit {
@@ -823,6 +839,7 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
 
                         BlueprintFunctionsSchemaInit {
                             functions,
+                            post_conditions: index_map_new(),
                         }
                     };
 
@@ -850,6 +867,17 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                         }
                     };
 
+                    // Aggregate hooks
+                    let hooks = {
+                        let mut hooks = index_map_new();
+                        #(
+                            hooks.insert(BlueprintHook::#hook_variants, #hook_exports.to_owned());
+                        )*
+                        BlueprintHooksInit {
+                            hooks
+                        }
+                    };
+
                     let schema = generate_full_schema(aggregator);
 
                     BlueprintSchemaInit {
@@ -859,7 +887,7 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                         events,
                         types,
                         functions,
-                        hooks: BlueprintHooksInit::default(),
+                        hooks,
                     }
                 };
 
@@ -902,8 +930,11 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
 
         impl ::scrypto::component::ComponentState for #bp_ident {
             const BLUEPRINT_NAME: &'static str = #bp_name;
+            #state_codec_fns
         }
 
+        #output_state_versioning
+
         impl HasStub for #bp_ident {
             type Stub = #stub_ident;
         }
@@ -929,9 +960,11 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     let method_input_structs = generate_method_input_structs(bp_ident, bp_items)?;
 
     let functions = generate_dispatcher(bp_ident, bp_items)?;
+    let hook_functions = generate_hook_dispatcher(bp_ident, bp_items, &hook_bindings)?;
     let output_dispatcher = quote! {
         #(#method_input_structs)*
         #(#functions)*
+        #(#hook_functions)*
     };
 
     trace!("Generated dispatcher: \n{}", quote! { #output_dispatcher });
@@ -1367,6 +1400,245 @@ fn generate_dispatcher(bp_ident: &Ident, items: &[ImplItem]) -> Result<Vec<Token
     Ok(functions)
 }
 
+/// A blueprint function bound to one of the hooks the engine invokes on an object.
+struct HookBinding {
+    bp_ident: Ident,
+    variant: Ident,
+    function: Ident,
+}
+
+impl HookBinding {
+    fn export_name(&self) -> String {
+        format!("{}_{}", self.bp_ident, self.function)
+    }
+}
+
+/// The versioned enum a blueprint's state is stored as when declared with `#[state_version]`.
+struct StateVersioning {
+    bp_ident: Ident,
+    versioned_ident: Ident,
+    previous_versions: Vec<Path>,
+}
+
+impl StateVersioning {
+    fn generate_versioned_enum(&self) -> TokenStream {
+        let bp_ident = &self.bp_ident;
+        let versioned_ident = &self.versioned_ident;
+        let latest_alias = format_ident!("{}LatestVersion", bp_ident);
+        let previous_versions = &self.previous_versions;
+        let version_nums: Vec<Literal> = (1..=previous_versions.len())
+            .map(|n| Literal::usize_unsuffixed(n))
+            .collect();
+        let update_to_nums: Vec<Literal> = (2..=previous_versions.len() + 1)
+            .map(|n| Literal::usize_unsuffixed(n))
+            .collect();
+        let latest_num = Literal::usize_unsuffixed(previous_versions.len() + 1);
+
+        quote! {
+            ::sbor::define_versioned!(
+                #[derive(::scrypto::prelude::ScryptoSbor)]
+                pub enum #versioned_ident {
+                    previous_versions: [
+                        #(#version_nums => #previous_versions: { updates_to: #update_to_nums }),*
+                    ],
+                    latest_version: {
+                        #latest_num => #latest_alias = #bp_ident
+                    },
+                }
+            );
+        }
+    }
+
+    fn generate_codec_fns(&self) -> TokenStream {
+        let versioned_ident = &self.versioned_ident;
+        // SBOR discriminators are assigned by variant index, and the latest version comes last
+        let latest_discriminator = Literal::u8_unsuffixed(self.previous_versions.len() as u8);
+
+        quote! {
+            fn encode_state(&self) -> ::sbor::rust::vec::Vec<u8> {
+                ::scrypto::component::encode_state_version(#latest_discriminator, self)
+            }
+
+            fn decode_state(payload: &[u8]) -> Self {
+                let versioned: #versioned_ident =
+                    ::scrypto::data::scrypto::scrypto_decode(payload).unwrap();
+                ::sbor::HasLatestVersion::into_latest(versioned)
+            }
+        }
+    }
+}
+
+fn parse_state_versioning(bp_strut: &ItemStruct) -> Result<Option<StateVersioning>> {
+    let Some(attribute) = bp_strut
+        .attrs
+        .iter()
+        .find(|attribute| attribute.path.is_ident("state_version"))
+    else {
+        return Ok(None);
+    };
+
+    let previous_versions = if attribute.tokens.is_empty() {
+        Vec::new()
+    } else {
+        parse2::<ast::StateVersionInner>(attribute.tokens.clone())?
+            .previous_versions
+            .into_iter()
+            .collect()
+    };
+    if previous_versions.len() >= u8::MAX as usize {
+        return Err(Error::new(
+            attribute.span(),
+            "Too many previous versions of the blueprint state",
+        ));
+    }
+
+    Ok(Some(StateVersioning {
+        bp_ident: bp_strut.ident.clone(),
+        versioned_ident: format_ident!("Versioned{}", bp_strut.ident),
+        previous_versions,
+    }))
+}
+
+fn parse_hook_bindings(bp_ident: &Ident, attributes: &[Attribute]) -> Result<Vec<HookBinding>> {
+    let mut bindings = Vec::<HookBinding>::new();
+
+    for attribute in attributes {
+        if attribute.path.is_ident("hooks") {
+            let hooks_inner = parse2::<ast::HooksInner>(attribute.tokens.clone())?;
+            for binding in hooks_inner.bindings {
+                let variant = match binding.hook.to_string().as_str() {
+                    "on_drop" => format_ident!("OnDrop"),
+                    "on_move" => format_ident!("OnMove"),
+                    _ => {
+                        return Err(Error::new(
+                            binding.hook.span(),
+                            "Unsupported hook. Expected `on_drop` or `on_move`.",
+                        ))
+                    }
+                };
+                if bindings.iter().any(|b| b.variant == variant) {
+                    return Err(Error::new(
+                        binding.hook.span(),
+                        "A function has already been bound to this hook",
+                    ));
+                }
+                bindings.push(HookBinding {
+                    bp_ident: bp_ident.clone(),
+                    variant,
+                    function: binding.function,
+                });
+            }
+        }
+    }
+
+    Ok(bindings)
+}
+
+fn generate_hook_dispatcher(
+    bp_ident: &Ident,
+    items: &[ImplItem],
+    bindings: &[HookBinding],
+) -> Result<Vec<TokenStream>> {
+    let mut functions = Vec::new();
+
+    for binding in bindings {
+        let m = items
+            .iter()
+            .find_map(|item| match item {
+                ImplItem::Method(m) if m.sig.ident == binding.function => Some(m),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                Error::new(
+                    binding.function.span(),
+                    format!("Hook function `{}` does not exist", binding.function),
+                )
+            })?;
+
+        // Hooks are invoked by the engine only, so they must not be callable as methods
+        if let Visibility::Public(_) = &m.vis {
+            return Err(Error::new(m.sig.ident.span(), "Hook functions can't be public."));
+        }
+
+        let input_type: Path = match binding.variant.to_string().as_str() {
+            "OnDrop" => parse_quote! { ::scrypto::hooks::OnDropInput },
+            _ => parse_quote! { ::scrypto::hooks::OnMoveInput },
+        };
+
+        let mut dispatch_args: Vec<Expr> = vec![];
+        let mut stmts: Vec<Stmt> = vec![];
+        let mut get_state: Option<Stmt> = None;
+        let mut takes_input = false;
+        for input in m.sig.inputs.iter() {
+            match input {
+                FnArg::Receiver(ref r) => {
+                    if r.reference.is_none() {
+                        return Err(Error::new(r.span(), "Function input `self` is not supported. Try replacing it with `&self`."));
+                    }
+                    if r.mutability.is_some() {
+                        dispatch_args.push(parse_quote! { state.deref_mut() });
+                        get_state = Some(parse_quote! {
+                            let mut state: DataRefMut<#bp_ident> = component_data.get_mut();
+                        });
+                    } else {
+                        dispatch_args.push(parse_quote! { state.deref() });
+                        get_state = Some(parse_quote! {
+                            let state: DataRef<#bp_ident> = component_data.get();
+                        });
+                    }
+                }
+                FnArg::Typed(ref t) => {
+                    if takes_input {
+                        return Err(Error::new(
+                            t.span(),
+                            "Hook functions take at most one argument, the hook input.",
+                        ));
+                    }
+                    takes_input = true;
+                    dispatch_args.push(parse_quote! { input });
+                }
+            }
+        }
+
+        // parse args
+        let input_ident = if takes_input {
+            format_ident!("input")
+        } else {
+            format_ident!("_input")
+        };
+        stmts.push(parse_quote! {
+            let #input_ident: #input_type = ::scrypto::data::scrypto::scrypto_decode(&::scrypto::engine::wasm_api::copy_buffer(args)).unwrap();
+        });
+
+        if let Some(stmt) = get_state {
+            stmts.push(parse_quote! {
+                let mut component_data = ::scrypto::runtime::ComponentStatePointer::new();
+            });
+            stmts.push(stmt);
+        }
+
+        let ident = &m.sig.ident;
+        let fn_ident = format_ident!("{}", binding.export_name());
+        functions.push(quote! {
+            #[no_mangle]
+            pub extern "C" fn #fn_ident(args: ::scrypto::engine::wasm_api::Buffer) -> ::scrypto::engine::wasm_api::Slice {
+                use ::sbor::rust::ops::{Deref, DerefMut};
+
+                // Set up panic hook
+                ::scrypto::set_up_panic_hook();
+
+                #(#stmts)*
+
+                let return_data: () = #bp_ident::#ident(#(#dispatch_args),*);
+
+                return ::scrypto::engine::wasm_api::forget_vec(::scrypto::data::scrypto::scrypto_encode(&return_data).unwrap());
+            }
+        });
+    }
+
+    Ok(functions)
+}
+
 fn create_argument_ident(argument: &Pat, index: usize) -> Result<Ident> {
     Ok(match argument {
         // If we have a standard parameter name - use that
@@ -2045,6 +2317,7 @@ mod tests {
 
                                 BlueprintFunctionsSchemaInit {
                                     functions,
+                                    post_conditions: index_map_new(),
                                 }
                             };
 
@@ -2086,6 +2359,11 @@ mod tests {
                                 BlueprintTypeSchemaInit { type_schema }
                             };
 
+                            let hooks = {
+                                let mut hooks = index_map_new();
+                                BlueprintHooksInit { hooks }
+                            };
+
                             let schema = generate_full_schema(aggregator);
 
                             BlueprintSchemaInit {
@@ -2095,7 +2373,7 @@ mod tests {
                                 events,
                                 types,
                                 functions,
-                                hooks: BlueprintHooksInit::default(),
+                                hooks,
                             }
                         };
 
@@ -40,6 +40,11 @@ use proc_macro::TokenStream;
 ///     }
 /// }
 /// ```
+///
+/// Annotating the struct with `#[state_version]` stores the component state as the latest
+/// variant of a generated `Versioned<Struct>` enum, so that the state can be migrated by a later
+/// version of the blueprint. The types of previous versions are listed oldest first, e.g.
+/// `#[state_version(CounterV1)]`, and each must implement `From` into the next version.
 #[proc_macro_attribute]
 pub fn blueprint(_: TokenStream, input: TokenStream) -> TokenStream {
     blueprint::handle_blueprint(proc_macro2::TokenStream::from(input))
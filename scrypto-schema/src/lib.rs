@@ -83,9 +83,24 @@ pub struct FunctionSchemaInit {
     pub export: String,
 }
 
+/// A condition on the output of a function, which the system checks once the function returns.
+///
+/// Every condition requires the output to be a bucket.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub enum FunctionPostCondition {
+    /// The returned bucket holds the given resource.
+    ReturnsResource(ResourceAddress),
+    /// The returned bucket holds the resource passed as the input argument at the given index.
+    ReturnsResourceOfArgument(u8),
+    /// The returned bucket holds at least the amount passed as the input argument at the given
+    /// index.
+    ReturnsAtLeastAmountOfArgument(u8),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default, ScryptoSbor, ManifestSbor)]
 pub struct BlueprintFunctionsSchemaInit {
     pub functions: IndexMap<String, FunctionSchemaInit>,
+    pub post_conditions: IndexMap<String, Vec<FunctionPostCondition>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, ScryptoSbor, ManifestSbor)]
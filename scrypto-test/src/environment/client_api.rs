@@ -81,7 +81,26 @@ implement_client_api! {
             event_name: String,
             event_data: Vec<u8>,
             event_flags: EventFlags,
-        ) -> Result<(), RuntimeError>
+        ) -> Result<(), RuntimeError>,
+        actor_subscribe_to_event: (
+            &mut self,
+            emitter: GlobalAddress,
+            event_name: String,
+            hook_method: String,
+        ) -> Result<(), RuntimeError>,
+        actor_unsubscribe_from_event: (
+            &mut self,
+            emitter: GlobalAddress,
+            event_name: String,
+        ) -> Result<(), RuntimeError>,
+        actor_get_state_size: (&mut self) -> Result<NodeStateSize, RuntimeError>,
+        actor_rate_limit_check: (
+            &mut self,
+            name: String,
+            scope: RateLimitScope,
+            max: u32,
+            window_epochs: u64,
+        ) -> Result<bool, RuntimeError>
     },
     ClientActorIndexApi: {
         actor_index_insert: (
@@ -254,9 +273,11 @@ implement_client_api! {
     ClientTransactionRuntimeApi: {
         bech32_encode_address: (&mut self, address: GlobalAddress) -> Result<String, RuntimeError>,
         get_transaction_hash: (&mut self) -> Result<Hash, RuntimeError>,
+        get_transaction_signatories: (&mut self) -> Result<Vec<NonFungibleGlobalId>, RuntimeError>,
         generate_ruid: (&mut self) -> Result<[u8; 32], RuntimeError>,
         emit_log: (&mut self, level: Level, message: String) -> Result<(), RuntimeError>,
         panic: (&mut self, message: String) -> Result<(), RuntimeError>,
+        abort: (&mut self, error: ScryptoValue) -> Result<(), RuntimeError>,
     },
     ClientCostingApi: {
         start_lock_fee: (&mut self, amount: Decimal) -> Result<bool, RuntimeError>,
@@ -265,9 +286,16 @@ implement_client_api! {
             locked_fee: LiquidFungibleResource,
             contingent: bool,
         ) -> (),
+        lock_fee_with_refund: (
+            &mut self,
+            locked_fee: LiquidFungibleResource,
+            contingent: bool,
+            refund_vault_id: NodeId,
+        ) -> (),
         consume_cost_units: (&mut self, costing_entry: ClientCostingEntry) -> Result<(), RuntimeError>,
         execution_cost_unit_limit: (&mut self) -> Result<u32, RuntimeError>,
         execution_cost_unit_price: (&mut self) -> Result<Decimal, RuntimeError>,
+        execution_cost_units_consumed: (&mut self) -> Result<u32, RuntimeError>,
         finalization_cost_unit_limit: (&mut self) -> Result<u32, RuntimeError>,
         finalization_cost_unit_price: (&mut self) -> Result<Decimal, RuntimeError>,
         usd_price: (&mut self) -> Result<Decimal, RuntimeError>,
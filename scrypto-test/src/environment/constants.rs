@@ -2,7 +2,7 @@ use crate::prelude::*;
 
 /// Defines the set of Nodes that all test [`CallFrame`]s have visibility to when they're first
 /// created. This contains all of the well-known addresses of nodes.
-pub(super) const GLOBAL_VISIBLE_NODES: [NodeId; 28] = [
+pub(super) const GLOBAL_VISIBLE_NODES: [NodeId; 32] = [
     XRD.into_node_id(),
     SECP256K1_SIGNATURE_VIRTUAL_BADGE.into_node_id(),
     ED25519_SIGNATURE_VIRTUAL_BADGE.into_node_id(),
@@ -20,6 +20,9 @@ pub(super) const GLOBAL_VISIBLE_NODES: [NodeId; 28] = [
     CONSENSUS_MANAGER_PACKAGE.into_node_id(),
     ACCESS_CONTROLLER_PACKAGE.into_node_id(),
     POOL_PACKAGE.into_node_id(),
+    PRICE_FEED_PACKAGE.into_node_id(),
+    PAYMENT_SCHEDULER_PACKAGE.into_node_id(),
+    ORDER_BOOK_PACKAGE.into_node_id(),
     TRANSACTION_PROCESSOR_PACKAGE.into_node_id(),
     METADATA_MODULE_PACKAGE.into_node_id(),
     ROYALTY_MODULE_PACKAGE.into_node_id(),
@@ -31,4 +34,5 @@ pub(super) const GLOBAL_VISIBLE_NODES: [NodeId; 28] = [
     GENESIS_HELPER.into_node_id(),
     FAUCET.into_node_id(),
     TRANSACTION_TRACKER.into_node_id(),
+    DEFERRED_EXECUTION_QUEUE.into_node_id(),
 ];
@@ -59,6 +59,10 @@ impl EncapsulatedRadixEngine {
             let state_updates = generate_pools_v1_1_state_updates(&substate_db);
             let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
             substate_db.commit(&db_updates);
+
+            let state_updates = generate_vm_boot_cuttlefish_minor_version_state_updates();
+            let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+            substate_db.commit(&db_updates);
         }
 
         // Create the Id allocator we will be using throughout this test
@@ -96,7 +100,10 @@ impl EncapsulatedRadixEngine {
                 Self::DEFAULT_INTENT_HASH,
                 AuthZoneParams {
                     initial_proofs: Default::default(),
+                    signatories: Default::default(),
                     virtual_resources: Default::default(),
+                    virtual_resource_amounts: Default::default(),
+                    subintent_initial_proofs: Default::default(),
                 },
                 SystemLoanFeeReserve::default(),
                 FeeTable::new(),
@@ -113,7 +113,7 @@ pub struct FungibleResourceType {
     divisibility: u8,
 }
 impl AnyResourceType for FungibleResourceType {
-    type ResourceRoles = FungibleResourceRoles;
+    type ResourceRoles = FungibleResourceRolesV2;
 }
 impl Default for FungibleResourceType {
     fn default() -> Self {
@@ -130,7 +130,7 @@ pub struct NonFungibleResourceType<T: IsNonFungibleLocalId, D: NonFungibleData>(
 impl<T: IsNonFungibleLocalId, D: NonFungibleData> AnyResourceType
     for NonFungibleResourceType<T, D>
 {
-    type ResourceRoles = NonFungibleResourceRoles;
+    type ResourceRoles = NonFungibleResourceRolesV2;
 }
 impl<T: IsNonFungibleLocalId, D: NonFungibleData> Default for NonFungibleResourceType<T, D> {
     fn default() -> Self {
@@ -336,6 +336,59 @@ pub trait UpdateAuthBuilder {
     ///    });
     /// ```
     fn deposit_roles(self, deposit_roles: Option<DepositRoles<RoleDefinition>>) -> Self;
+
+    /// Makes the resource soulbound, so that its vaults can only be withdrawn from or deposited
+    /// into when the transfer exemptor rule is satisfied, on top of the withdrawer and depositor
+    /// roles. Recalling is not affected, so exempting a recovery badge lets an issuer move the
+    /// resource out of a lost account by recalling it and depositing it elsewhere.
+    ///
+    /// * The first parameter is the access rule which exempts a withdrawal or deposit.
+    /// * The second parameter is the mutability / access rule which controls if and how the access
+    ///   rule can be updated.
+    ///
+    /// Unlike other roles, a transfer exemptor left unset does not fall back to the owner and
+    /// exempts nothing. Burning from a vault counts as a withdrawal.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use scrypto_test::prelude::*;
+    ///
+    /// # let recovery_badge = XRD;
+    /// // Sets the resource to be soulbound, with transfers only possible with a recovery badge.
+    /// ResourceBuilder::new_fungible(OwnerRole::None)
+    ///    .soulbound_roles(soulbound_roles! {
+    ///        transfer_exemptor => rule!(require(recovery_badge));
+    ///        transfer_exemptor_updater => rule!(deny_all);
+    ///    });
+    /// ```
+    fn soulbound_roles(self, soulbound_roles: Option<SoulboundRoles<RoleDefinition>>) -> Self;
+
+    /// Gives the resource a deny list of global entities, such as accounts and components, whose
+    /// vaults reject any further deposits of it. Holders are put on and taken off the list with
+    /// the `deny_holder` and `allow_holder` methods of the resource manager.
+    ///
+    /// * The first parameter is the access rule which allows changing the deny list.
+    /// * The second parameter is the mutability / access rule which controls if and how the access
+    ///   rule can be updated.
+    ///
+    /// Withdrawals from the vaults of a denied holder are not affected, so what it already holds
+    /// can still be recalled or moved out.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use scrypto_test::prelude::*;
+    ///
+    /// # let compliance_badge = XRD;
+    /// // Sets the resource to have a deny list, which only a compliance badge can change.
+    /// ResourceBuilder::new_fungible(OwnerRole::None)
+    ///    .deny_list_roles(deny_list_roles! {
+    ///        deny_lister => rule!(require(compliance_badge));
+    ///        deny_lister_updater => rule!(deny_all);
+    ///    });
+    /// ```
+    fn deny_list_roles(self, deny_list_roles: Option<DenyListRoles<RoleDefinition>>) -> Self;
 }
 
 impl UpdateAuthBuilder for InProgressResourceBuilder<FungibleResourceType> {
@@ -368,6 +421,16 @@ impl UpdateAuthBuilder for InProgressResourceBuilder<FungibleResourceType> {
         self.resource_roles.deposit_roles = deposit_roles;
         self
     }
+
+    fn soulbound_roles(mut self, soulbound_roles: Option<SoulboundRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.soulbound_roles = soulbound_roles;
+        self
+    }
+
+    fn deny_list_roles(mut self, deny_list_roles: Option<DenyListRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.deny_list_roles = deny_list_roles;
+        self
+    }
 }
 
 impl<T: IsNonFungibleLocalId, D: NonFungibleData> UpdateAuthBuilder
@@ -402,6 +465,16 @@ impl<T: IsNonFungibleLocalId, D: NonFungibleData> UpdateAuthBuilder
         self.resource_roles.deposit_roles = deposit_roles;
         self
     }
+
+    fn soulbound_roles(mut self, soulbound_roles: Option<SoulboundRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.soulbound_roles = soulbound_roles;
+        self
+    }
+
+    fn deny_list_roles(mut self, deny_list_roles: Option<DenyListRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.deny_list_roles = deny_list_roles;
+        self
+    }
 }
 
 impl<T: IsNonFungibleLocalId, D: NonFungibleData>
@@ -450,6 +523,43 @@ impl<T: IsNonFungibleLocalId, D: NonFungibleData>
         self.resource_roles.non_fungible_data_update_roles = non_fungible_data_update_roles;
         self
     }
+
+    /// Lets the resource charge a royalty on transfers. Once the owner has set a royalty on the
+    /// resource manager, non-fungibles can only be taken out of a vault by paying it, unless the
+    /// royalty exemptor rule is satisfied.
+    ///
+    /// * The first parameter is the access rule which exempts a withdrawal from the royalty,
+    ///   typically met by approved marketplaces.
+    /// * The second parameter is the mutability / access rule which controls if and how the access
+    ///   rule can be updated.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use radix_engine_interface::transfer_royalty_roles;
+    /// use scrypto_test::prelude::*;
+    ///
+    /// # let marketplace_badge = XRD;
+    /// # let resource_address = XRD;
+    ///
+    /// #[derive(ScryptoSbor, NonFungibleData)]
+    /// struct NFData {
+    ///     pub name: String,
+    /// }
+    /// // Exempts the withdrawals made by a marketplace, which can be replaced by the second rule.
+    /// ResourceBuilder::new_integer_non_fungible::<NFData>(OwnerRole::None)
+    ///    .transfer_royalty_roles(transfer_royalty_roles! {
+    ///        royalty_exemptor => rule!(require(marketplace_badge));
+    ///        royalty_exemptor_updater => rule!(require(resource_address));
+    ///    });
+    /// ```
+    pub fn transfer_royalty_roles(
+        mut self,
+        transfer_royalty_roles: Option<TransferRoyaltyRoles<RoleDefinition>>,
+    ) -> Self {
+        self.resource_roles.transfer_royalty_roles = transfer_royalty_roles;
+        self
+    }
 }
 
 pub trait SetOwnerBuilder: private::CanAddOwner {
@@ -485,8 +595,8 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
                 let bytes = env.call_function(
                     RESOURCE_PACKAGE,
                     FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-                    FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
-                    scrypto_encode(&FungibleResourceManagerCreateInput {
+                    FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_IDENT,
+                    scrypto_encode(&FungibleResourceManagerCreateV2Input {
                         owner_role,
                         divisibility,
                         track_total_supply: true,
@@ -511,8 +621,8 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
                 let bytes = env.call_function(
                     RESOURCE_PACKAGE,
                     NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-                    NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
-                    scrypto_encode(&NonFungibleResourceManagerCreateInput {
+                    NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_IDENT,
+                    scrypto_encode(&NonFungibleResourceManagerCreateV2Input {
                         owner_role,
                         id_type,
                         track_total_supply: true,
@@ -585,8 +695,8 @@ impl InProgressResourceBuilder<FungibleResourceType> {
         let bytes = env.call_function(
             RESOURCE_PACKAGE,
             FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
-            scrypto_encode(&FungibleResourceManagerCreateWithInitialSupplyInput {
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
+            scrypto_encode(&FungibleResourceManagerCreateWithInitialSupplyV2Input {
                 owner_role: self.owner_role,
                 track_total_supply: true,
                 divisibility: self.resource_type.divisibility,
@@ -648,8 +758,8 @@ impl<D: NonFungibleData>
         let bytes = env.call_function(
             RESOURCE_PACKAGE,
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
-            scrypto_encode(&NonFungibleResourceManagerCreateWithInitialSupplyInput {
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
+            scrypto_encode(&NonFungibleResourceManagerCreateWithInitialSupplyV2Input {
                 owner_role: self.owner_role,
                 track_total_supply: true,
                 id_type: StringNonFungibleLocalId::id_type(),
@@ -708,8 +818,8 @@ impl<D: NonFungibleData>
         let bytes = env.call_function(
             RESOURCE_PACKAGE,
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
-            scrypto_encode(&NonFungibleResourceManagerCreateWithInitialSupplyInput {
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
+            scrypto_encode(&NonFungibleResourceManagerCreateWithInitialSupplyV2Input {
                 owner_role: self.owner_role,
                 track_total_supply: true,
                 id_type: IntegerNonFungibleLocalId::id_type(),
@@ -768,8 +878,8 @@ impl<D: NonFungibleData>
         let bytes = env.call_function(
             RESOURCE_PACKAGE,
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
-            scrypto_encode(&NonFungibleResourceManagerCreateWithInitialSupplyInput {
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
+            scrypto_encode(&NonFungibleResourceManagerCreateWithInitialSupplyV2Input {
                 owner_role: self.owner_role,
                 id_type: BytesNonFungibleLocalId::id_type(),
                 track_total_supply: true,
@@ -831,9 +941,9 @@ impl<D: NonFungibleData>
         let bytes = env.call_function(
             RESOURCE_PACKAGE,
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_IDENT,
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_V2_IDENT,
             scrypto_encode(
-                &NonFungibleResourceManagerCreateRuidWithInitialSupplyInput {
+                &NonFungibleResourceManagerCreateRuidWithInitialSupplyV2Input {
                     owner_role: self.owner_role,
                     non_fungible_schema,
                     track_total_supply: true,
@@ -979,7 +1089,7 @@ mod private {
         Fungible {
             owner_role: OwnerRole,
             divisibility: u8,
-            resource_roles: FungibleResourceRoles,
+            resource_roles: FungibleResourceRolesV2,
             metadata: Option<ModuleConfig<MetadataInit>>,
             address_reservation: Option<GlobalAddressReservation>,
         },
@@ -987,7 +1097,7 @@ mod private {
             owner_role: OwnerRole,
             id_type: NonFungibleIdType,
             non_fungible_schema: NonFungibleDataSchema,
-            resource_roles: NonFungibleResourceRoles,
+            resource_roles: NonFungibleResourceRolesV2,
             metadata: Option<ModuleConfig<MetadataInit>>,
             address_reservation: Option<GlobalAddressReservation>,
         },
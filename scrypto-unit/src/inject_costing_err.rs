@@ -109,6 +109,7 @@ impl<'a, K: KernelCallbackObject + 'a> KernelCallbackObject for InjectCostingErr
         pre_allocated_addresses: &Vec<PreAllocatedAddress>,
         references: &IndexSet<Reference>,
         blobs: &IndexMap<Hash, Vec<u8>>,
+        worktop_leftovers_recipient: Option<&ComponentAddress>,
     ) -> Result<Vec<u8>, RuntimeError>
     where
         Y: KernelApi<Self>,
@@ -120,6 +121,7 @@ impl<'a, K: KernelCallbackObject + 'a> KernelCallbackObject for InjectCostingErr
             pre_allocated_addresses,
             references,
             blobs,
+            worktop_leftovers_recipient,
         )
     }
 
@@ -403,6 +405,16 @@ impl<'a, M: KernelCallbackObject, Y: KernelApi<InjectCostingError<M>>>
             .kernel_mark_substate_as_transient(node_id, partition_num, key)
     }
 
+    fn kernel_prefetch_substates(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        substate_keys: &[SubstateKey],
+    ) -> Result<(), RuntimeError> {
+        self.api
+            .kernel_prefetch_substates(node_id, partition_num, substate_keys)
+    }
+
     fn kernel_open_substate_with_default<F: FnOnce() -> IndexedScryptoValue>(
         &mut self,
         node_id: &NodeId,
@@ -539,6 +551,14 @@ impl<'a, M: KernelCallbackObject, K: KernelApi<InjectCostingError<M>>> KernelInt
     fn kernel_read_proof(&mut self, proof_id: &NodeId) -> Option<ProofSnapshot> {
         self.api.kernel_read_proof(proof_id)
     }
+
+    fn kernel_read_worktop(&mut self, worktop_id: &NodeId) -> Option<Vec<BucketSnapshot>> {
+        self.api.kernel_read_worktop(worktop_id)
+    }
+
+    fn kernel_read_auth_zone(&mut self, auth_zone_id: &NodeId) -> Option<Vec<ProofSnapshot>> {
+        self.api.kernel_read_auth_zone(auth_zone_id)
+    }
 }
 
 impl<'a, M: KernelCallbackObject, K: KernelApi<InjectCostingError<M>>> KernelApi<M>
@@ -583,4 +603,12 @@ impl<'a, M: KernelCallbackObject, K: KernelInternalApi<InjectCostingError<M>>> K
     fn kernel_read_proof(&mut self, proof_id: &NodeId) -> Option<ProofSnapshot> {
         self.api.kernel_read_proof(proof_id)
     }
+
+    fn kernel_read_worktop(&mut self, worktop_id: &NodeId) -> Option<Vec<BucketSnapshot>> {
+        self.api.kernel_read_worktop(worktop_id)
+    }
+
+    fn kernel_read_auth_zone(&mut self, auth_zone_id: &NodeId) -> Option<Vec<ProofSnapshot>> {
+        self.api.kernel_read_auth_zone(auth_zone_id)
+    }
 }
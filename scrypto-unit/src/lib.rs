@@ -1,7 +1,9 @@
 mod inject_costing_err;
 mod test_runner;
 mod utils;
+mod value_generator;
 
 pub use crate::utils::*;
 pub use inject_costing_err::*;
 pub use test_runner::*;
+pub use value_generator::*;
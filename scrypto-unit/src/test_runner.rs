@@ -3,36 +3,45 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use crate::value_generator::{AddressPool, ManifestValueGenerator, ValueGenerationError};
+
 use radix_engine::blueprints::consensus_manager::*;
-use radix_engine::blueprints::models::FieldPayload;
+use radix_engine::blueprints::models::{FieldPayload, KeyValueEntryContentSource};
 use radix_engine::blueprints::pool::v1::constants::*;
 use radix_engine::errors::*;
+use radix_engine::protocol_updates::*;
 use radix_engine::system::bootstrap::*;
 use radix_engine::system::checkers::*;
 use radix_engine::system::system_callback::SystemConfig;
 use radix_engine::system::system_db_reader::{
     ObjectCollectionKey, SystemDatabaseReader, SystemDatabaseWriter,
 };
-use radix_engine::system::system_substates::FieldSubstate;
+use radix_engine::system::system_modules::debugger::TransactionDebugger;
+use radix_engine::system::system_modules::extension::SystemModuleExtension;
 use radix_engine::system::type_info::TypeInfoSubstate;
+use radix_engine::track::{
+    BatchPartitionStateUpdate, NodeStateUpdates, PartitionStateUpdates, StateUpdates, Track,
+};
 use radix_engine::transaction::{
-    execute_preview, execute_transaction_with_system, BalanceChange, CommitResult,
-    CostingParameters, ExecutionConfig, PreviewError, TransactionReceipt, TransactionResult,
-    WrappedSystem,
+    execute_preview, execute_preview_v2, execute_transaction, execute_transaction_with_debugger,
+    execute_transaction_with_extensions, execute_transaction_with_system, BalanceChange,
+    CommitResult, CostingParameters, ExecutionConfig, PreviewError, TransactionReceipt,
+    TransactionResult, WrappedSystem,
 };
 use radix_engine::types::*;
 use radix_engine::utils::*;
-use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
+use radix_engine::vm::wasm::{DefaultWasmEngine, WasmEngine, WasmValidatorConfigV1};
 use radix_engine::vm::{NativeVm, NativeVmExtension, NoExtension, ScryptoVm, Vm};
 use radix_engine_interface::api::node_modules::auth::*;
 use radix_engine_interface::api::ModuleId;
 use radix_engine_interface::blueprints::access_controller::*;
-use radix_engine_interface::blueprints::account::ACCOUNT_SECURIFY_IDENT;
+use radix_engine_interface::blueprints::account::{DefaultDepositRule, ACCOUNT_SECURIFY_IDENT};
 use radix_engine_interface::blueprints::consensus_manager::{
     ConsensusManagerConfig, ConsensusManagerGetCurrentEpochInput,
-    ConsensusManagerGetCurrentTimeInputV2, ConsensusManagerNextRoundInput, EpochChangeCondition,
-    LeaderProposalHistory, CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT,
-    CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT, CONSENSUS_MANAGER_NEXT_ROUND_IDENT,
+    ConsensusManagerGetCurrentTimeInputV3, ConsensusManagerInvokeEpochChangeHookInput,
+    ConsensusManagerNextRoundInput, EpochChangeCondition, LeaderProposalHistory,
+    CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT, CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT,
+    CONSENSUS_MANAGER_INVOKE_EPOCH_CHANGE_HOOK_IDENT, CONSENSUS_MANAGER_NEXT_ROUND_IDENT,
     VALIDATOR_STAKE_AS_OWNER_IDENT,
 };
 use radix_engine_interface::blueprints::package::*;
@@ -47,13 +56,14 @@ use radix_engine_interface::{dec, freeze_roles, rule};
 use radix_engine_queries::query::{ResourceAccounter, StateTreeTraverser, VaultFinder};
 use radix_engine_queries::typed_native_events::to_typed_native_event;
 use radix_engine_queries::typed_substate_layout::*;
+use radix_engine_store_interface::db_key_mapper::DatabaseKeyMapper;
 use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
-use radix_engine_store_interface::db_key_mapper::{DatabaseKeyMapper, MappedSubstateDatabase};
 use radix_engine_store_interface::interface::{
     CommittableSubstateDatabase, DatabaseUpdate, ListableSubstateDatabase, SubstateDatabase,
 };
 use radix_engine_stores::hash_tree_support::HashTreeUpdatingDatabase;
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use rand::Rng;
 use scrypto::prelude::*;
 use transaction::prelude::*;
 use transaction::validation::{
@@ -329,6 +339,18 @@ impl CustomGenesis {
     }
 }
 
+impl From<CustomGenesis> for GenesisBuilder {
+    fn from(genesis: CustomGenesis) -> Self {
+        GenesisBuilder::new()
+            .with_data_chunks(genesis.genesis_data_chunks)
+            .with_genesis_epoch(genesis.genesis_epoch)
+            .with_consensus_manager_config(genesis.initial_config)
+            .with_initial_time_ms(genesis.initial_time_ms)
+            .with_initial_current_leader(genesis.initial_current_leader)
+            .with_faucet_supply(genesis.faucet_supply)
+    }
+}
+
 pub trait TestDatabase:
     SubstateDatabase + CommittableSubstateDatabase + ListableSubstateDatabase
 {
@@ -341,16 +363,18 @@ impl<T: SubstateDatabase + CommittableSubstateDatabase + ListableSubstateDatabas
 pub type DefaultTestRunner = TestRunner<NoExtension, InMemorySubstateDatabase>;
 
 pub struct TestRunnerBuilder<E, D> {
-    custom_genesis: Option<CustomGenesis>,
+    custom_genesis: Option<GenesisBuilder>,
     custom_extension: E,
     custom_database: D,
     trace: bool,
     skip_receipt_check: bool,
+    seed: Option<u64>,
 
     // The following are protocol updates on mainnet
     with_seconds_precision_update: bool,
     with_crypto_utils_update: bool,
     with_pools_v1_1: bool,
+    with_rounded_time_update: bool,
 }
 
 impl TestRunnerBuilder<NoExtension, InMemorySubstateDatabase> {
@@ -361,9 +385,11 @@ impl TestRunnerBuilder<NoExtension, InMemorySubstateDatabase> {
             custom_database: InMemorySubstateDatabase::standard(),
             trace: true,
             skip_receipt_check: false,
+            seed: None,
             with_seconds_precision_update: true,
             with_crypto_utils_update: true,
             with_pools_v1_1: true,
+            with_rounded_time_update: true,
         }
     }
 }
@@ -381,14 +407,21 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             custom_database: HashTreeUpdatingDatabase::new(self.custom_database),
             trace: self.trace,
             skip_receipt_check: false,
+            seed: self.seed,
             with_seconds_precision_update: self.with_seconds_precision_update,
             with_crypto_utils_update: self.with_crypto_utils_update,
             with_pools_v1_1: self.with_pools_v1_1,
+            with_rounded_time_update: self.with_rounded_time_update,
         }
     }
 
     pub fn with_custom_genesis(mut self, genesis: CustomGenesis) -> Self {
-        self.custom_genesis = Some(genesis);
+        self.custom_genesis = Some(genesis.into());
+        self
+    }
+
+    pub fn with_genesis_builder(mut self, genesis_builder: GenesisBuilder) -> Self {
+        self.custom_genesis = Some(genesis_builder);
         self
     }
 
@@ -397,6 +430,13 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         self
     }
 
+    /// Derives the starting private key and transaction nonce from the given seed, so that all
+    /// generated keys, addresses and UUID non-fungible ids are reproducible for that seed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
     pub fn with_custom_extension<NE: NativeVmExtension>(
         self,
         extension: NE,
@@ -407,9 +447,11 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             custom_database: self.custom_database,
             trace: self.trace,
             skip_receipt_check: self.skip_receipt_check,
+            seed: self.seed,
             with_seconds_precision_update: self.with_seconds_precision_update,
             with_crypto_utils_update: self.with_crypto_utils_update,
             with_pools_v1_1: self.with_pools_v1_1,
+            with_rounded_time_update: self.with_rounded_time_update,
         }
     }
 
@@ -420,9 +462,11 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             custom_database: database,
             trace: self.trace,
             skip_receipt_check: self.skip_receipt_check,
+            seed: self.seed,
             with_seconds_precision_update: self.with_seconds_precision_update,
             with_crypto_utils_update: self.with_crypto_utils_update,
             with_pools_v1_1: self.with_pools_v1_1,
+            with_rounded_time_update: self.with_rounded_time_update,
         }
     }
 
@@ -441,6 +485,11 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         self
     }
 
+    pub fn without_rounded_time_update(mut self) -> Self {
+        self.with_rounded_time_update = false;
+        self
+    }
+
     pub fn build_from_snapshot(
         self,
         snapshot: TestRunnerSnapshot,
@@ -493,15 +542,8 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             data_ingestion_receipts,
             wrap_up_receipt,
         } = match self.custom_genesis {
-            Some(custom_genesis) => bootstrapper
-                .bootstrap_with_genesis_data(
-                    custom_genesis.genesis_data_chunks,
-                    custom_genesis.genesis_epoch,
-                    custom_genesis.initial_config,
-                    custom_genesis.initial_time_ms,
-                    custom_genesis.initial_current_leader,
-                    custom_genesis.faucet_supply,
-                )
+            Some(genesis_builder) => bootstrapper
+                .bootstrap_with_genesis_builder(genesis_builder)
                 .unwrap(),
             None => bootstrapper.bootstrap_test_default().unwrap(),
         };
@@ -524,37 +566,54 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
                 .clone(),
         );
 
-        // Note that 0 is not a valid private key
-        let next_private_key = 100;
-
-        // Starting from non-zero considering that bootstrap might have used a few.
-        let next_transaction_nonce = 100;
+        // Note that 0 is not a valid private key, and the nonce starts from non-zero
+        // considering that bootstrap might have used a few.
+        let (next_private_key, next_transaction_nonce) = match self.seed {
+            Some(seed) => {
+                let seed_hash = hash(seed.to_le_bytes());
+                let key_offset = u32::from_le_bytes(seed_hash.lower_bytes::<4>());
+                let nonce_offset = u16::from_le_bytes(seed_hash.0[0..2].try_into().unwrap());
+                // Each seed gets its own range of 2^16 private keys
+                (
+                    100 + ((key_offset as u64) << 16),
+                    100 + ((nonce_offset as u32) << 8),
+                )
+            }
+            None => (100, 100),
+        };
 
         // Protocol Updates
         {
-            if self.with_seconds_precision_update {
-                let state_updates = generate_seconds_precision_state_updates(&substate_db);
-                let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
-                substate_db.commit(&db_updates);
-            };
-
-            if self.with_crypto_utils_update {
-                let state_updates = generate_vm_boot_scrypto_minor_version_state_updates();
-                let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
-                substate_db.commit(&db_updates);
+            let mut protocol_update = ProtocolUpdate::of(ProtocolVersion::Anemone);
+            if !self.with_seconds_precision_update {
+                protocol_update =
+                    protocol_update.without_migration(&StateMigration::SecondsPrecision);
             }
-
-            {
-                let state_updates = generate_validator_fee_fix_state_updates(&substate_db);
-                let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
-                substate_db.commit(&db_updates);
+            if !self.with_crypto_utils_update {
+                protocol_update =
+                    protocol_update.without_migration(&StateMigration::VmBootScryptoMinorVersion);
             }
-        }
+            if !self.with_pools_v1_1 {
+                protocol_update = protocol_update.without_migration(&StateMigration::PoolsV1_1);
+            }
+            protocol_update.execute(&mut substate_db);
 
-        if self.with_pools_v1_1 {
-            let state_updates = generate_pools_v1_1_state_updates(&substate_db);
-            let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
-            substate_db.commit(&db_updates);
+            // The rounded time update rewrites the blueprint definition left behind by the
+            // seconds precision update, so it can't be applied without it.
+            let mut protocol_update = ProtocolUpdate::of(ProtocolVersion::Bottlenose);
+            if !self.with_rounded_time_update || !self.with_seconds_precision_update {
+                protocol_update = protocol_update.without_migration(&StateMigration::RoundedTime);
+            }
+            protocol_update.execute(&mut substate_db);
+
+            // The minor versions of the Scrypto VM are cumulative, so the Cuttlefish one can't be
+            // applied without the crypto utils one.
+            let mut protocol_update = ProtocolUpdate::of(ProtocolVersion::Cuttlefish);
+            if !self.with_crypto_utils_update {
+                protocol_update = protocol_update
+                    .without_migration(&StateMigration::VmBootCuttlefishMinorVersion);
+            }
+            protocol_update.execute(&mut substate_db);
         }
 
         let runner = TestRunner {
@@ -581,6 +640,112 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
     }
 }
 
+/// A Scrypto VM along with the costing parameters to execute a transaction under, for use with
+/// [`TestRunner::execute_manifest_side_by_side`].
+pub struct VmConfiguration<W: WasmEngine> {
+    pub scrypto_vm: ScryptoVm<W>,
+    pub costing_parameters: CostingParameters,
+}
+
+impl<W: WasmEngine + Default> Default for VmConfiguration<W> {
+    fn default() -> Self {
+        Self::new(W::default())
+    }
+}
+
+impl<W: WasmEngine> VmConfiguration<W> {
+    pub fn new(wasm_engine: W) -> Self {
+        Self {
+            scrypto_vm: ScryptoVm {
+                wasm_engine,
+                wasm_validator_config: WasmValidatorConfigV1::new(),
+            },
+            costing_parameters: CostingParameters::default(),
+        }
+    }
+
+    pub fn with_costing_parameters(mut self, costing_parameters: CostingParameters) -> Self {
+        self.costing_parameters = costing_parameters;
+        self
+    }
+}
+
+/// A part of a transaction receipt which differed between two executions of the same
+/// transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReceiptDifference {
+    /// The transaction was committed, rejected or aborted differently, or its outcome differed
+    Result,
+    StateUpdates,
+    ApplicationEvents,
+    ApplicationLogs,
+    FeeSummary,
+}
+
+/// The receipts of a transaction executed under two VM configurations against the same state.
+pub struct SideBySideReceipts {
+    pub left: TransactionReceipt,
+    pub right: TransactionReceipt,
+    pub differences: Vec<ReceiptDifference>,
+}
+
+impl SideBySideReceipts {
+    pub fn new(left: TransactionReceipt, right: TransactionReceipt) -> Self {
+        let differences = Self::diff(&left, &right);
+        Self {
+            left,
+            right,
+            differences,
+        }
+    }
+
+    /// Panics if the receipts differ, otherwise returns the receipt of the left configuration.
+    pub fn expect_no_differences(&self) -> &TransactionReceipt {
+        if !self.differences.is_empty() {
+            panic!(
+                "Receipts differ in {:?}\nLeft: {:?}\nRight: {:?}",
+                self.differences, self.left, self.right
+            );
+        }
+        &self.left
+    }
+
+    fn diff(left: &TransactionReceipt, right: &TransactionReceipt) -> Vec<ReceiptDifference> {
+        let mut differences = Vec::new();
+
+        match (&left.result, &right.result) {
+            (TransactionResult::Commit(left_commit), TransactionResult::Commit(right_commit)) => {
+                if scrypto_encode(&left_commit.outcome).unwrap()
+                    != scrypto_encode(&right_commit.outcome).unwrap()
+                {
+                    differences.push(ReceiptDifference::Result);
+                }
+                if left_commit.state_updates != right_commit.state_updates {
+                    differences.push(ReceiptDifference::StateUpdates);
+                }
+                if left_commit.application_events != right_commit.application_events {
+                    differences.push(ReceiptDifference::ApplicationEvents);
+                }
+                if left_commit.application_logs != right_commit.application_logs {
+                    differences.push(ReceiptDifference::ApplicationLogs);
+                }
+            }
+            (left_result, right_result) => {
+                if scrypto_encode(left_result).unwrap() != scrypto_encode(right_result).unwrap() {
+                    differences.push(ReceiptDifference::Result);
+                }
+            }
+        }
+
+        if scrypto_encode(&left.fee_summary).unwrap() != scrypto_encode(&right.fee_summary).unwrap()
+        {
+            differences.push(ReceiptDifference::FeeSummary);
+        }
+
+        differences
+    }
+}
+
 pub struct TestRunner<E: NativeVmExtension, D: TestDatabase> {
     scrypto_vm: ScryptoVm<DefaultWasmEngine>,
     native_vm: NativeVm<E>,
@@ -847,6 +1012,116 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
             .collect()
     }
 
+    /// Rewrites the on-ledger definition of the given blueprint, e.g. to recreate the definition
+    /// a native blueprint had before a protocol update extended it.
+    pub fn update_blueprint_definition(
+        &mut self,
+        package_address: &PackageAddress,
+        blueprint_name: &str,
+        update: impl FnOnce(&mut BlueprintDefinition),
+    ) {
+        let reader = SystemDatabaseReader::new(self.substate_db());
+        let key = BlueprintVersionKey::new_default(blueprint_name);
+        let mut definition = reader
+            .read_object_collection_entry::<_, PackageBlueprintVersionDefinitionEntryPayload>(
+                package_address.as_node_id(),
+                ModuleId::Main,
+                ObjectCollectionKey::KeyValue(
+                    PackageCollection::BlueprintVersionDefinitionKeyValue.collection_index(),
+                    &key,
+                ),
+            )
+            .unwrap()
+            .unwrap()
+            .into_latest();
+        let partition_num = reader
+            .get_partition_of_collection(
+                package_address.as_node_id(),
+                ModuleId::Main,
+                PackageCollection::BlueprintVersionDefinitionKeyValue.collection_index(),
+            )
+            .unwrap();
+
+        update(&mut definition);
+
+        let state_updates = StateUpdates {
+            by_node: indexmap!(
+                package_address.into_node_id() => NodeStateUpdates::Delta {
+                    by_partition: indexmap! {
+                        partition_num => PartitionStateUpdates::Delta {
+                            by_substate: indexmap! {
+                                SubstateKey::Map(scrypto_encode(&key).unwrap()) => DatabaseUpdate::Set(
+                                    scrypto_encode(&definition.into_locked_substate()).unwrap()
+                                )
+                            }
+                        },
+                    }
+                }
+            ),
+        };
+        self.database
+            .commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+    }
+
+    /// Drops the definition of the given blueprint, e.g. to recreate a native package published
+    /// before the blueprint was added to it.
+    pub fn remove_blueprint_definition(
+        &mut self,
+        package_address: &PackageAddress,
+        blueprint_name: &str,
+    ) {
+        let reader = SystemDatabaseReader::new(self.substate_db());
+        let key = BlueprintVersionKey::new_default(blueprint_name);
+        let partition_num = reader
+            .get_partition_of_collection(
+                package_address.as_node_id(),
+                ModuleId::Main,
+                PackageCollection::BlueprintVersionDefinitionKeyValue.collection_index(),
+            )
+            .unwrap();
+
+        let state_updates = StateUpdates {
+            by_node: indexmap!(
+                package_address.into_node_id() => NodeStateUpdates::Delta {
+                    by_partition: indexmap! {
+                        partition_num => PartitionStateUpdates::Delta {
+                            by_substate: indexmap! {
+                                SubstateKey::Map(scrypto_encode(&key).unwrap()) => DatabaseUpdate::Delete
+                            }
+                        },
+                    }
+                }
+            ),
+        };
+        self.database
+            .commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+    }
+
+    /// Drops all substates of the given node, e.g. to recreate a ledger bootstrapped before a
+    /// native package or component was added to genesis.
+    pub fn delete_node(&mut self, node_id: &NodeId) {
+        let by_partition = self
+            .database
+            .list_partition_keys()
+            .map(|partition_key| SpreadPrefixKeyMapper::from_db_partition_key(&partition_key))
+            .filter(|(partition_node_id, _)| partition_node_id == node_id)
+            .map(|(_, partition_num)| {
+                (
+                    partition_num,
+                    PartitionStateUpdates::Batch(BatchPartitionStateUpdate::Reset {
+                        new_substate_values: index_map_new(),
+                    }),
+                )
+            })
+            .collect();
+
+        let state_updates = StateUpdates {
+            by_node: indexmap!(*node_id => NodeStateUpdates::Delta { by_partition }),
+        };
+        self.database
+            .commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+    }
+
     pub fn sum_descendant_balance_changes(
         &mut self,
         commit: &CommitResult,
@@ -946,16 +1221,46 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         accounter.close().balances
     }
 
+    /// Reads the state of a component as `T`, which should mirror the blueprint's state struct.
+    ///
+    /// The state is first validated against the field schema of the component's blueprint, so a
+    /// mismatch between `T` and the blueprint is reported against the blueprint's own types.
     pub fn component_state<T: ScryptoDecode>(&self, component_address: ComponentAddress) -> T {
         let node_id: &NodeId = component_address.as_node_id();
-        let component_state = self
-            .substate_db()
-            .get_mapped::<SpreadPrefixKeyMapper, FieldSubstate<T>>(
-                node_id,
-                MAIN_BASE_PARTITION,
-                &ComponentField::State0.into(),
+        let reader = SystemDatabaseReader::new(self.substate_db());
+        let field_index = ComponentField::State0 as u8;
+        let state = reader
+            .read_object_field(node_id, ModuleId::Main, field_index)
+            .unwrap_or_else(|err| {
+                panic!("Could not read state of {:?}: {:?}", component_address, err)
+            });
+
+        let target = reader
+            .get_blueprint_type_target(node_id, ModuleId::Main)
+            .unwrap();
+        let schema = reader
+            .get_blueprint_payload_schema(&target, &BlueprintPayloadIdentifier::Field(field_index))
+            .unwrap();
+        if let Err(err) =
+            reader.validate_payload(state.as_slice(), &schema, BLUEPRINT_PAYLOAD_MAX_DEPTH)
+        {
+            panic!(
+                "State of {:?} does not match the schema of {:?}: {}",
+                component_address,
+                target.blueprint_info.blueprint_id,
+                err.error_message(schema.schema.v1())
             );
-        component_state.unwrap().into_payload()
+        }
+
+        scrypto_decode(state.as_slice()).unwrap_or_else(|err| {
+            panic!(
+                "State of {:?} matches the schema of {:?}, but could not be decoded as {}: {:?}",
+                component_address,
+                target.blueprint_info.blueprint_id,
+                core::any::type_name::<T>(),
+                err
+            )
+        })
     }
 
     pub fn get_non_fungible_data<T: NonFungibleData>(
@@ -1160,6 +1465,44 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         )
     }
 
+    pub fn new_accounts(
+        &mut self,
+        count: usize,
+    ) -> Vec<(Secp256k1PublicKey, Secp256k1PrivateKey, ComponentAddress)> {
+        (0..count).map(|_| self.new_allocated_account()).collect()
+    }
+
+    /// Creates and funds the given number of virtual accounts within a single transaction.
+    pub fn new_funded_virtual_accounts(
+        &mut self,
+        count: usize,
+        default_deposit_rule: DefaultDepositRule,
+    ) -> Vec<(Secp256k1PublicKey, Secp256k1PrivateKey, ComponentAddress)> {
+        let accounts: Vec<_> = (0..count)
+            .map(|_| {
+                let (public_key, private_key) = self.new_key_pair();
+                let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+                (public_key, private_key, account)
+            })
+            .collect();
+
+        let account_addresses: Vec<_> = accounts
+            .iter()
+            .map(|(_, _, account)| *account)
+            .collect();
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_funded_virtual_accounts(&account_addresses, default_deposit_rule)
+            .build();
+        let initial_proofs = accounts
+            .iter()
+            .map(|(public_key, _, _)| NonFungibleGlobalId::from_public_key(public_key));
+        self.execute_manifest(manifest, initial_proofs)
+            .expect_commit_success();
+
+        accounts
+    }
+
     pub fn new_account(
         &mut self,
         is_virtual: bool,
@@ -1602,6 +1945,50 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         )
     }
 
+    pub fn execute_manifest_with_worktop_leftovers_recipient<T>(
+        &mut self,
+        manifest: TransactionManifestV1,
+        initial_proofs: T,
+        worktop_leftovers_recipient: ComponentAddress,
+    ) -> TransactionReceipt
+    where
+        T: IntoIterator<Item = NonFungibleGlobalId>,
+    {
+        let nonce = self.next_transaction_nonce();
+        let prepared = TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .expect("expected transaction to be preparable");
+        let mut executable = prepared.get_executable(initial_proofs.into_iter().collect());
+        executable.set_worktop_leftovers_recipient(worktop_leftovers_recipient);
+        self.execute_transaction(
+            executable,
+            CostingParameters::default(),
+            ExecutionConfig::for_test_transaction(),
+        )
+    }
+
+    pub fn execute_manifest_with_tip_percentage<T>(
+        &mut self,
+        manifest: TransactionManifestV1,
+        initial_proofs: T,
+        tip_percentage: u16,
+    ) -> TransactionReceipt
+    where
+        T: IntoIterator<Item = NonFungibleGlobalId>,
+    {
+        let nonce = self.next_transaction_nonce();
+        let prepared = TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .expect("expected transaction to be preparable");
+        let mut executable = prepared.get_executable(initial_proofs.into_iter().collect());
+        executable.set_tip_percentage(tip_percentage);
+        self.execute_transaction(
+            executable,
+            CostingParameters::default(),
+            ExecutionConfig::for_test_transaction(),
+        )
+    }
+
     pub fn execute_manifest_with_execution_cost_unit_limit<T>(
         &mut self,
         manifest: TransactionManifestV1,
@@ -1667,6 +2054,127 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
             &executable,
             init,
         );
+        self.commit_receipt(&transaction_receipt);
+        transaction_receipt
+    }
+
+    pub fn execute_manifest_with_debugger<T>(
+        &mut self,
+        manifest: TransactionManifestV1,
+        initial_proofs: T,
+        debugger: Box<dyn TransactionDebugger>,
+    ) -> TransactionReceipt
+    where
+        T: IntoIterator<Item = NonFungibleGlobalId>,
+    {
+        let nonce = self.next_transaction_nonce();
+        let executable = TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .expect("expected transaction to be preparable")
+            .get_executable(initial_proofs.into_iter().collect());
+        let execution_config = ExecutionConfig::for_test_transaction().with_kernel_trace(self.trace);
+
+        let vm = Vm {
+            scrypto_vm: &self.scrypto_vm,
+            native_vm: self.native_vm.clone(),
+        };
+
+        let transaction_receipt = execute_transaction_with_debugger(
+            &mut self.database,
+            vm,
+            &CostingParameters::default(),
+            &execution_config,
+            &executable,
+            debugger,
+        );
+        self.commit_receipt(&transaction_receipt);
+        transaction_receipt
+    }
+
+    pub fn execute_manifest_with_extensions<T>(
+        &mut self,
+        manifest: TransactionManifestV1,
+        initial_proofs: T,
+        extensions: Vec<Box<dyn SystemModuleExtension>>,
+    ) -> TransactionReceipt
+    where
+        T: IntoIterator<Item = NonFungibleGlobalId>,
+    {
+        let nonce = self.next_transaction_nonce();
+        let executable = TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .expect("expected transaction to be preparable")
+            .get_executable(initial_proofs.into_iter().collect());
+        let execution_config = ExecutionConfig::for_test_transaction().with_kernel_trace(self.trace);
+
+        let vm = Vm {
+            scrypto_vm: &self.scrypto_vm,
+            native_vm: self.native_vm.clone(),
+        };
+
+        let transaction_receipt = execute_transaction_with_extensions(
+            &mut self.database,
+            vm,
+            &CostingParameters::default(),
+            &execution_config,
+            &executable,
+            extensions,
+        );
+        self.commit_receipt(&transaction_receipt);
+        transaction_receipt
+    }
+
+    /// Executes the manifest under both VM configurations against the current state and diffs
+    /// the receipts, to catch nondeterminism between WASM engines or costing versions.
+    ///
+    /// Only the receipt of the left configuration is committed.
+    pub fn execute_manifest_side_by_side<T, L, R>(
+        &mut self,
+        manifest: TransactionManifestV1,
+        initial_proofs: T,
+        left: &VmConfiguration<L>,
+        right: &VmConfiguration<R>,
+    ) -> SideBySideReceipts
+    where
+        T: IntoIterator<Item = NonFungibleGlobalId>,
+        L: WasmEngine,
+        R: WasmEngine,
+    {
+        let nonce = self.next_transaction_nonce();
+        let prepared = TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .expect("expected transaction to be preparable");
+        let executable = prepared.get_executable(initial_proofs.into_iter().collect());
+        let execution_config = ExecutionConfig::for_test_transaction().with_kernel_trace(self.trace);
+
+        if executable
+            .costing_parameters()
+            .free_credit_in_xrd
+            .is_positive()
+        {
+            self.xrd_free_credits_used = true;
+        }
+
+        let left_receipt = execute_transaction(
+            &self.database,
+            Vm::new(&left.scrypto_vm, self.native_vm.clone()),
+            &left.costing_parameters,
+            &execution_config,
+            &executable,
+        );
+        let right_receipt = execute_transaction(
+            &self.database,
+            Vm::new(&right.scrypto_vm, self.native_vm.clone()),
+            &right.costing_parameters,
+            &execution_config,
+            &executable,
+        );
+        self.commit_receipt(&left_receipt);
+
+        SideBySideReceipts::new(left_receipt, right_receipt)
+    }
+
+    fn commit_receipt(&mut self, transaction_receipt: &TransactionReceipt) {
         if let TransactionResult::Commit(commit) = &transaction_receipt.result {
             let database_updates = commit
                 .state_updates
@@ -1679,7 +2187,6 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
                 assert_receipt_substate_changes_can_be_typed(commit);
             }
         }
-        transaction_receipt
     }
 
     pub fn preview(
@@ -1695,6 +2202,19 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         execute_preview(&self.database, vm, network, preview_intent, self.trace)
     }
 
+    pub fn preview_v2(
+        &mut self,
+        preview_intent: PreviewIntentV2,
+        network: &NetworkDefinition,
+    ) -> Result<TransactionReceipt, PreviewError> {
+        let vm = Vm {
+            scrypto_vm: &self.scrypto_vm,
+            native_vm: self.native_vm.clone(),
+        };
+
+        execute_preview_v2(&self.database, vm, network, preview_intent, self.trace)
+    }
+
     pub fn preview_manifest(
         &mut self,
         manifest: TransactionManifestV1,
@@ -2113,6 +2633,18 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         receipt.expect_commit(true).new_resource_addresses()[0]
     }
 
+    pub fn create_fungible_resources(
+        &mut self,
+        count: usize,
+        amount: Decimal,
+        divisibility: u8,
+        account: ComponentAddress,
+    ) -> Vec<ResourceAddress> {
+        (0..count)
+            .map(|_| self.create_fungible_resource(amount, divisibility, account))
+            .collect()
+    }
+
     pub fn create_mintable_burnable_fungible_resource(
         &mut self,
         account: ComponentAddress,
@@ -2325,6 +2857,13 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         receipt.expect_commit(true).output(0)
     }
 
+    /// Returns the status of the given intent in the transaction tracker, if it has been
+    /// committed and has not yet expired.
+    pub fn get_intent_status(&self, intent_hash: &IntentHash) -> Option<TransactionStatusV1> {
+        Track::<_, SpreadPrefixKeyMapper>::new(&self.database)
+            .intent_status(&intent_hash.into_hash())
+    }
+
     pub fn execute_system_transaction_with_preallocation(
         &mut self,
         instructions: Vec<InstructionV1>,
@@ -2435,6 +2974,36 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         self.advance_to_round_at_timestamp(round, current_timestamp_ms)
     }
 
+    /// Executes the validator transaction which invokes the given component's epoch change hook,
+    /// as the node does for every registered hook after an epoch change: with costing enabled,
+    /// under the hooks' execution cost unit limit, and paid for from the hook's bond.
+    pub fn invoke_epoch_change_hook(
+        &mut self,
+        component_address: ComponentAddress,
+    ) -> TransactionReceipt {
+        let nonce = self.next_transaction_nonce();
+        self.execute_transaction(
+            SystemTransactionV1 {
+                instructions: InstructionsV1(vec![InstructionV1::CallMethod {
+                    address: CONSENSUS_MANAGER.into(),
+                    method_name: CONSENSUS_MANAGER_INVOKE_EPOCH_CHANGE_HOOK_IDENT.to_string(),
+                    args: to_manifest_value_and_unwrap!(
+                        &ConsensusManagerInvokeEpochChangeHookInput { component_address }
+                    ),
+                }]),
+                blobs: BlobsV1 { blobs: vec![] },
+                hash_for_execution: hash(format!("Test runner txn: {}", nonce)),
+                pre_allocated_addresses: vec![],
+            }
+            .prepare()
+            .expect("expected transaction to be preparable")
+            .get_executable(btreeset![AuthAddresses::validator_role()]),
+            CostingParameters::default()
+                .with_execution_cost_unit_limit(EPOCH_CHANGE_HOOK_EXECUTION_COST_UNIT_LIMIT),
+            ExecutionConfig::for_notarized_transaction(NetworkDefinition::simulator()),
+        )
+    }
+
     /// Reads out the substate holding the "epoch milli" timestamp reported by the proposer on the
     /// most recent round change.
     pub fn get_current_proposer_timestamp_ms(&mut self) -> i64 {
@@ -2467,7 +3036,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
             vec![InstructionV1::CallMethod {
                 address: CONSENSUS_MANAGER.into(),
                 method_name: CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT.to_string(),
-                args: to_manifest_value_and_unwrap!(&ConsensusManagerGetCurrentTimeInputV2 {
+                args: to_manifest_value_and_unwrap!(&ConsensusManagerGetCurrentTimeInputV3 {
                     precision
                 }),
             }],
@@ -2531,6 +3100,64 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         }
     }
 
+    pub fn function_input_schema(
+        &self,
+        blueprint_id: &BlueprintId,
+        function_name: &str,
+    ) -> (LocalTypeId, VersionedScryptoSchema) {
+        let reader = SystemDatabaseReader::new(self.substate_db());
+        let definition = reader.get_blueprint_definition(blueprint_id).unwrap();
+        let function_schema = definition
+            .interface
+            .functions
+            .get(function_name)
+            .expect("Function does not exist");
+
+        match &function_schema.input {
+            BlueprintPayloadDef::Static(type_identifier) => {
+                let schema = reader
+                    .get_schema(
+                        blueprint_id.package_address.as_node_id(),
+                        &type_identifier.0,
+                    )
+                    .unwrap();
+                (type_identifier.1, schema.as_ref().clone())
+            }
+            BlueprintPayloadDef::Generic(_instance_index) => {
+                panic!("Generic function inputs are not supported")
+            }
+        }
+    }
+
+    /// Creates an [`AddressPool`] of the given addresses, with the blueprint of each object
+    /// looked up from the database.
+    pub fn address_pool<T: Into<NodeId>>(
+        &self,
+        addresses: impl IntoIterator<Item = T>,
+    ) -> AddressPool {
+        let reader = SystemDatabaseReader::new(self.substate_db());
+        let mut address_pool = AddressPool::new();
+        for address in addresses {
+            let node_id = address.into();
+            let blueprint_id = reader.get_blueprint_id(&node_id, ModuleId::Main).ok();
+            address_pool.add(node_id, blueprint_id);
+        }
+        address_pool
+    }
+
+    /// Generates random but schema-valid arguments for the given blueprint function or method,
+    /// which can be passed to `call_function_raw` or `call_method_raw` for fuzzing.
+    pub fn generate_function_args<R: Rng>(
+        &self,
+        blueprint_id: &BlueprintId,
+        function_name: &str,
+        address_pool: &AddressPool,
+        rng: &mut R,
+    ) -> Result<ManifestValue, ValueGenerationError> {
+        let (local_type_id, schema) = self.function_input_schema(blueprint_id, function_name);
+        ManifestValueGenerator::new(schema.v1(), address_pool).generate(local_type_id, rng)
+    }
+
     pub fn event_name(&self, event_type_identifier: &EventTypeIdentifier) -> String {
         let (local_type_id, schema) = self.event_schema(event_type_identifier);
         schema
@@ -2844,7 +3471,7 @@ pub fn assert_receipt_substate_changes_can_be_typed(commit_result: &CommitResult
     for ((node_id, partition_num), partition_updates) in (&system_updates).into_iter() {
         for (substate_key, database_update) in partition_updates.into_iter() {
             let typed_substate_key =
-                to_typed_substate_key(node_id.entity_type().unwrap(), *partition_num, substate_key)
+                to_typed_node_substate_key(node_id, *partition_num, substate_key)
                     .expect("Substate key should be typeable");
             if !typed_substate_key.value_is_mappable() {
                 continue;
@@ -2874,6 +3501,9 @@ pub fn assert_receipt_events_can_be_typed(commit_result: &CommitResult) {
                 CONSENSUS_MANAGER_PACKAGE,
                 ACCESS_CONTROLLER_PACKAGE,
                 POOL_PACKAGE,
+                PRICE_FEED_PACKAGE,
+                PAYMENT_SCHEDULER_PACKAGE,
+                ORDER_BOOK_PACKAGE,
                 TRANSACTION_PROCESSOR_PACKAGE,
                 METADATA_MODULE_PACKAGE,
                 ROYALTY_MODULE_PACKAGE,
@@ -0,0 +1,463 @@
+use radix_engine::types::*;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use transaction::data::{from_decimal, from_precise_decimal};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueGenerationError {
+    TypeNotFound(LocalTypeId),
+    /// Owned values (buckets, proofs, vaults, ...) can't be conjured out of thin air, and have to
+    /// be passed in by the manifest author instead.
+    UnsupportedOwnedType(LocalTypeId),
+    NoMatchingAddress(LocalTypeId),
+    UnsatisfiableValidation(LocalTypeId),
+    MaxDepthExceeded,
+}
+
+/// The addresses which generated references are drawn from, together with the blueprint of
+/// each address (if it's an object), which typed references are matched against.
+#[derive(Debug, Clone, Default)]
+pub struct AddressPool {
+    entries: Vec<(NodeId, Option<BlueprintId>)>,
+}
+
+impl AddressPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add<T: Into<NodeId>>(&mut self, address: T, blueprint_id: Option<BlueprintId>) {
+        self.entries.push((address.into(), blueprint_id));
+    }
+
+    pub fn with<T: Into<NodeId>>(mut self, address: T, blueprint_id: Option<BlueprintId>) -> Self {
+        self.add(address, blueprint_id);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn matching(&self, validation: Option<&ReferenceValidation>) -> Vec<&NodeId> {
+        self.entries
+            .iter()
+            .filter(|(node_id, blueprint_id)| match validation {
+                None => true,
+                Some(ReferenceValidation::IsGlobal) => node_id.is_global(),
+                Some(ReferenceValidation::IsGlobalPackage) => node_id.is_global_package(),
+                Some(ReferenceValidation::IsGlobalComponent) => node_id.is_global_component(),
+                Some(ReferenceValidation::IsGlobalResourceManager) => {
+                    node_id.is_global_resource_manager()
+                }
+                Some(ReferenceValidation::IsGlobalTyped(package_address, blueprint_name)) => {
+                    node_id.is_global()
+                        && Self::is_blueprint(blueprint_id, package_address, blueprint_name)
+                }
+                Some(ReferenceValidation::IsInternal) => node_id.is_internal(),
+                Some(ReferenceValidation::IsInternalTyped(package_address, blueprint_name)) => {
+                    node_id.is_internal()
+                        && Self::is_blueprint(blueprint_id, package_address, blueprint_name)
+                }
+            })
+            .map(|(node_id, _)| node_id)
+            .collect()
+    }
+
+    fn is_blueprint(
+        blueprint_id: &Option<BlueprintId>,
+        package_address: &Option<PackageAddress>,
+        blueprint_name: &str,
+    ) -> bool {
+        match blueprint_id {
+            Some(blueprint_id) => {
+                blueprint_id.blueprint_name.eq(blueprint_name)
+                    && package_address
+                        .map(|package_address| blueprint_id.package_address.eq(&package_address))
+                        .unwrap_or(true)
+            }
+            None => false,
+        }
+    }
+}
+
+/// Generates arbitrary manifest values which are valid against a given type of a schema,
+/// e.g. for fuzzing the inputs of blueprint functions and methods.
+///
+/// References are drawn from the given [`AddressPool`], and all numeric and length
+/// validations of the schema are respected. Types which require owned values are rejected.
+pub struct ManifestValueGenerator<'a> {
+    schema: &'a Schema<ScryptoCustomSchema>,
+    address_pool: &'a AddressPool,
+    max_depth: usize,
+    max_collection_length: usize,
+}
+
+impl<'a> ManifestValueGenerator<'a> {
+    pub const DEFAULT_MAX_DEPTH: usize = 16;
+    pub const DEFAULT_MAX_COLLECTION_LENGTH: usize = 8;
+
+    pub fn new(schema: &'a Schema<ScryptoCustomSchema>, address_pool: &'a AddressPool) -> Self {
+        Self {
+            schema,
+            address_pool,
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+            max_collection_length: Self::DEFAULT_MAX_COLLECTION_LENGTH,
+        }
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the length of generated strings, arrays and maps, unless the schema demands
+    /// a longer minimum length.
+    pub fn with_max_collection_length(mut self, max_collection_length: usize) -> Self {
+        self.max_collection_length = max_collection_length;
+        self
+    }
+
+    pub fn generate<R: Rng>(
+        &self,
+        type_id: LocalTypeId,
+        rng: &mut R,
+    ) -> Result<ManifestValue, ValueGenerationError> {
+        self.generate_value(type_id, 0, rng)
+    }
+
+    fn generate_value<R: Rng>(
+        &self,
+        type_id: LocalTypeId,
+        depth: usize,
+        rng: &mut R,
+    ) -> Result<ManifestValue, ValueGenerationError> {
+        if depth > self.max_depth {
+            return Err(ValueGenerationError::MaxDepthExceeded);
+        }
+
+        let type_kind = self
+            .schema
+            .resolve_type_kind(type_id)
+            .ok_or(ValueGenerationError::TypeNotFound(type_id))?;
+        let validation = self
+            .schema
+            .resolve_type_validation(type_id)
+            .unwrap_or(&TypeValidation::None);
+
+        let value = match type_kind {
+            // Any value is valid, so the simplest one is used
+            TypeKind::Any => Value::Tuple { fields: vec![] },
+            TypeKind::Bool => Value::Bool { value: rng.gen() },
+            TypeKind::I8 => Value::I8 {
+                value: match validation {
+                    TypeValidation::I8(v) => {
+                        Self::gen_in_range(v, i8::MIN, i8::MAX, type_id, rng)?
+                    }
+                    _ => rng.gen(),
+                },
+            },
+            TypeKind::I16 => Value::I16 {
+                value: match validation {
+                    TypeValidation::I16(v) => {
+                        Self::gen_in_range(v, i16::MIN, i16::MAX, type_id, rng)?
+                    }
+                    _ => rng.gen(),
+                },
+            },
+            TypeKind::I32 => Value::I32 {
+                value: match validation {
+                    TypeValidation::I32(v) => {
+                        Self::gen_in_range(v, i32::MIN, i32::MAX, type_id, rng)?
+                    }
+                    _ => rng.gen(),
+                },
+            },
+            TypeKind::I64 => Value::I64 {
+                value: match validation {
+                    TypeValidation::I64(v) => {
+                        Self::gen_in_range(v, i64::MIN, i64::MAX, type_id, rng)?
+                    }
+                    _ => rng.gen(),
+                },
+            },
+            TypeKind::I128 => Value::I128 {
+                value: match validation {
+                    TypeValidation::I128(v) => {
+                        Self::gen_in_range(v, i128::MIN, i128::MAX, type_id, rng)?
+                    }
+                    _ => rng.gen(),
+                },
+            },
+            TypeKind::U8 => Value::U8 {
+                value: match validation {
+                    TypeValidation::U8(v) => {
+                        Self::gen_in_range(v, u8::MIN, u8::MAX, type_id, rng)?
+                    }
+                    _ => rng.gen(),
+                },
+            },
+            TypeKind::U16 => Value::U16 {
+                value: match validation {
+                    TypeValidation::U16(v) => {
+                        Self::gen_in_range(v, u16::MIN, u16::MAX, type_id, rng)?
+                    }
+                    _ => rng.gen(),
+                },
+            },
+            TypeKind::U32 => Value::U32 {
+                value: match validation {
+                    TypeValidation::U32(v) => {
+                        Self::gen_in_range(v, u32::MIN, u32::MAX, type_id, rng)?
+                    }
+                    _ => rng.gen(),
+                },
+            },
+            TypeKind::U64 => Value::U64 {
+                value: match validation {
+                    TypeValidation::U64(v) => {
+                        Self::gen_in_range(v, u64::MIN, u64::MAX, type_id, rng)?
+                    }
+                    _ => rng.gen(),
+                },
+            },
+            TypeKind::U128 => Value::U128 {
+                value: match validation {
+                    TypeValidation::U128(v) => {
+                        Self::gen_in_range(v, u128::MIN, u128::MAX, type_id, rng)?
+                    }
+                    _ => rng.gen(),
+                },
+            },
+            TypeKind::String => {
+                let length = self.gen_length(validation, depth, type_id, rng)?;
+                Value::String {
+                    value: Self::gen_alphanumeric(length, rng),
+                }
+            }
+            TypeKind::Array { element_type } => {
+                let length = self.gen_length(validation, depth, type_id, rng)?;
+                let element_value_kind = self.value_kind_of(*element_type)?;
+                let elements = (0..length)
+                    .map(|_| self.generate_value(*element_type, depth + 1, rng))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Value::Array {
+                    element_value_kind,
+                    elements,
+                }
+            }
+            TypeKind::Tuple { field_types } => Value::Tuple {
+                fields: field_types
+                    .iter()
+                    .map(|field_type| self.generate_value(*field_type, depth + 1, rng))
+                    .collect::<Result<Vec<_>, _>>()?,
+            },
+            TypeKind::Enum { variants } => {
+                if variants.is_empty() {
+                    return Err(ValueGenerationError::UnsatisfiableValidation(type_id));
+                }
+                // Once at the maximum depth, the variant with the fewest fields is chosen to
+                // give recursive types a chance to terminate.
+                let (discriminator, field_types) = if depth < self.max_depth {
+                    variants.get_index(rng.gen_range(0..variants.len())).unwrap()
+                } else {
+                    variants
+                        .iter()
+                        .min_by_key(|(_, field_types)| field_types.len())
+                        .unwrap()
+                };
+                Value::Enum {
+                    discriminator: *discriminator,
+                    fields: field_types
+                        .iter()
+                        .map(|field_type| self.generate_value(*field_type, depth + 1, rng))
+                        .collect::<Result<Vec<_>, _>>()?,
+                }
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => {
+                let length = self.gen_length(validation, depth, type_id, rng)?;
+                let key_value_kind = self.value_kind_of(*key_type)?;
+                let value_value_kind = self.value_kind_of(*value_type)?;
+
+                // Keys must be unique, so generation is retried a bounded number of times for
+                // key types with a small domain (e.g. `bool`).
+                let mut entries = Vec::new();
+                let mut encoded_keys = index_set_new();
+                for _ in 0..length * 4 {
+                    if entries.len() == length {
+                        break;
+                    }
+                    let key = self.generate_value(*key_type, depth + 1, rng)?;
+                    if encoded_keys.insert(manifest_encode(&key).unwrap()) {
+                        let value = self.generate_value(*value_type, depth + 1, rng)?;
+                        entries.push((key, value));
+                    }
+                }
+                if let TypeValidation::Map(length_validation) = validation {
+                    if !length_validation.is_valid(entries.len()) {
+                        return Err(ValueGenerationError::UnsatisfiableValidation(type_id));
+                    }
+                }
+
+                Value::Map {
+                    key_value_kind,
+                    value_value_kind,
+                    entries,
+                }
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Reference) => {
+                let reference_validation = match validation {
+                    TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(v)) => Some(v),
+                    _ => None,
+                };
+                let candidates = self.address_pool.matching(reference_validation);
+                if candidates.is_empty() {
+                    return Err(ValueGenerationError::NoMatchingAddress(type_id));
+                }
+                let node_id = candidates[rng.gen_range(0..candidates.len())];
+                Value::Custom {
+                    value: ManifestCustomValue::Address(ManifestAddress::Static(*node_id)),
+                }
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Own) => {
+                return Err(ValueGenerationError::UnsupportedOwnedType(type_id));
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Decimal) => Value::Custom {
+                value: ManifestCustomValue::Decimal(from_decimal(Decimal(I192::from(
+                    rng.gen::<i128>(),
+                )))),
+            },
+            TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => Value::Custom {
+                value: ManifestCustomValue::PreciseDecimal(from_precise_decimal(PreciseDecimal(
+                    I256::from(rng.gen::<i128>()),
+                ))),
+            },
+            TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => Value::Custom {
+                value: ManifestCustomValue::NonFungibleLocalId(Self::gen_non_fungible_local_id(
+                    rng,
+                )),
+            },
+        };
+
+        Ok(value)
+    }
+
+    fn gen_in_range<T, R>(
+        validation: &NumericValidation<T>,
+        min: T,
+        max: T,
+        type_id: LocalTypeId,
+        rng: &mut R,
+    ) -> Result<T, ValueGenerationError>
+    where
+        T: Copy + PartialOrd + rand::distributions::uniform::SampleUniform,
+        R: Rng,
+    {
+        let min = validation.min.unwrap_or(min);
+        let max = validation.max.unwrap_or(max);
+        if min > max {
+            return Err(ValueGenerationError::UnsatisfiableValidation(type_id));
+        }
+        Ok(rng.gen_range(min..=max))
+    }
+
+    fn gen_length<R: Rng>(
+        &self,
+        validation: &TypeValidation<ScryptoCustomTypeValidation>,
+        depth: usize,
+        type_id: LocalTypeId,
+        rng: &mut R,
+    ) -> Result<usize, ValueGenerationError> {
+        let length_validation = match validation {
+            TypeValidation::String(v) | TypeValidation::Array(v) | TypeValidation::Map(v) => *v,
+            _ => LengthValidation::none(),
+        };
+        let min = length_validation.min.unwrap_or(0) as usize;
+        let max = length_validation.max.unwrap_or(u32::MAX) as usize;
+        if min > max {
+            return Err(ValueGenerationError::UnsatisfiableValidation(type_id));
+        }
+
+        // Nested collections are kept as short as allowed once at the maximum depth
+        if depth >= self.max_depth {
+            return Ok(min);
+        }
+        let max = max.min(min.max(self.max_collection_length));
+        Ok(rng.gen_range(min..=max))
+    }
+
+    fn gen_alphanumeric<R: Rng>(length: usize, rng: &mut R) -> String {
+        (0..length)
+            .map(|_| char::from(rng.sample(Alphanumeric)))
+            .collect()
+    }
+
+    fn gen_non_fungible_local_id<R: Rng>(rng: &mut R) -> ManifestNonFungibleLocalId {
+        match rng.gen_range(0..4) {
+            0 => ManifestNonFungibleLocalId::integer(rng.gen()).unwrap(),
+            1 => {
+                let length = rng.gen_range(1..=16);
+                ManifestNonFungibleLocalId::string(Self::gen_alphanumeric(length, rng)).unwrap()
+            }
+            2 => {
+                let length = rng.gen_range(1..=16);
+                ManifestNonFungibleLocalId::bytes((0..length).map(|_| rng.gen()).collect())
+                    .unwrap()
+            }
+            _ => ManifestNonFungibleLocalId::ruid(rng.gen()),
+        }
+    }
+
+    fn value_kind_of(
+        &self,
+        type_id: LocalTypeId,
+    ) -> Result<ManifestValueKind, ValueGenerationError> {
+        let type_kind = self
+            .schema
+            .resolve_type_kind(type_id)
+            .ok_or(ValueGenerationError::TypeNotFound(type_id))?;
+
+        let value_kind = match type_kind {
+            TypeKind::Any | TypeKind::Tuple { .. } => ValueKind::Tuple,
+            TypeKind::Bool => ValueKind::Bool,
+            TypeKind::I8 => ValueKind::I8,
+            TypeKind::I16 => ValueKind::I16,
+            TypeKind::I32 => ValueKind::I32,
+            TypeKind::I64 => ValueKind::I64,
+            TypeKind::I128 => ValueKind::I128,
+            TypeKind::U8 => ValueKind::U8,
+            TypeKind::U16 => ValueKind::U16,
+            TypeKind::U32 => ValueKind::U32,
+            TypeKind::U64 => ValueKind::U64,
+            TypeKind::U128 => ValueKind::U128,
+            TypeKind::String => ValueKind::String,
+            TypeKind::Array { .. } => ValueKind::Array,
+            TypeKind::Enum { .. } => ValueKind::Enum,
+            TypeKind::Map { .. } => ValueKind::Map,
+            TypeKind::Custom(ScryptoCustomTypeKind::Reference) => {
+                ValueKind::Custom(ManifestCustomValueKind::Address)
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Own) => {
+                return Err(ValueGenerationError::UnsupportedOwnedType(type_id));
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Decimal) => {
+                ValueKind::Custom(ManifestCustomValueKind::Decimal)
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => {
+                ValueKind::Custom(ManifestCustomValueKind::PreciseDecimal)
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => {
+                ValueKind::Custom(ManifestCustomValueKind::NonFungibleLocalId)
+            }
+        };
+
+        Ok(value_kind)
+    }
+}
@@ -10,6 +10,7 @@ use radix_engine_common::prelude::{
     scrypto_decode, OwnValidation, ReferenceValidation, ScryptoCustomTypeValidation,
 };
 use radix_engine_derive::ScryptoSbor;
+use radix_engine_interface::api::node_modules::auth::ToRoleEntry;
 use radix_engine_interface::api::node_modules::metadata::{
     MetadataConversionError, MetadataInit, MetadataVal, METADATA_GET_IDENT, METADATA_REMOVE_IDENT,
     METADATA_SET_IDENT,
@@ -18,7 +19,7 @@ use radix_engine_interface::api::node_modules::ModuleConfig;
 use radix_engine_interface::api::object_api::ModuleId;
 use radix_engine_interface::api::{AttachedModuleId, FieldValue};
 use radix_engine_interface::blueprints::resource::{
-    AccessRule, Bucket, MethodAccessibility, OwnerRole, RoleAssignmentInit,
+    AccessRule, Bucket, MethodAccessibility, OwnerRole, RoleAssignmentInit, PAUSER_ROLE,
 };
 use radix_engine_interface::data::scrypto::{
     ScryptoCustomTypeKind, ScryptoCustomValueKind, ScryptoDecode, ScryptoEncode,
@@ -81,10 +82,27 @@ pub trait HasMethods {
 pub trait ComponentState: HasMethods + HasStub + ScryptoEncode + ScryptoDecode {
     const BLUEPRINT_NAME: &'static str;
 
+    /// Encodes the state into the payload of the component's state field.
+    ///
+    /// Blueprints whose state is declared with `#[state_version]` store it wrapped in the latest
+    /// variant of a generated versioned enum instead.
+    fn encode_state(&self) -> Vec<u8> {
+        scrypto_encode(self).unwrap()
+    }
+
+    /// Decodes the state from the payload of the component's state field, upgrading a versioned
+    /// state written by an older version of the blueprint to the latest version.
+    fn decode_state(payload: &[u8]) -> Self {
+        scrypto_decode(payload).unwrap()
+    }
+
     fn instantiate(self) -> Owned<Self> {
         let node_id = ScryptoVmV1Api::object_new(
             Self::BLUEPRINT_NAME,
-            indexmap![0u8 => FieldValue::new(&self)],
+            indexmap![0u8 => FieldValue {
+                value: self.encode_state(),
+                locked: false,
+            }],
         );
 
         let stub = Self::Stub::new(ObjectStubHandle::Own(Own(node_id)));
@@ -92,6 +110,39 @@ pub trait ComponentState: HasMethods + HasStub + ScryptoEncode + ScryptoDecode {
     }
 }
 
+/// Encodes a borrowed component state as the variant of its versioned enum with the given
+/// discriminator, which is what `#[state_version]` blueprints write back to their state field.
+pub fn encode_state_version<S: ScryptoEncode>(discriminator: u8, state: &S) -> Vec<u8> {
+    scrypto_encode(&StateVersionRef {
+        discriminator,
+        state,
+    })
+    .unwrap()
+}
+
+struct StateVersionRef<'a, S> {
+    discriminator: u8,
+    state: &'a S,
+}
+
+impl<'a, S, E> Encode<ScryptoCustomValueKind, E> for StateVersionRef<'a, S>
+where
+    S: Encode<ScryptoCustomValueKind, E>,
+    E: Encoder<ScryptoCustomValueKind>,
+{
+    #[inline]
+    fn encode_value_kind(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.write_value_kind(ValueKind::Enum)
+    }
+
+    #[inline]
+    fn encode_body(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.write_discriminator(self.discriminator)?;
+        encoder.write_size(1)?;
+        encoder.encode(self.state)
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct AnyComponent(pub(crate) ObjectStubHandle);
 
@@ -128,6 +179,14 @@ impl<C: HasStub> DerefMut for Owned<C> {
     }
 }
 
+/// Borrows the typed stub of the component, so its methods can be called while it stays owned,
+/// e.g. `child.as_ref().get_value()`.
+impl<C: HasStub> AsRef<C::Stub> for Owned<C> {
+    fn as_ref(&self) -> &C::Stub {
+        &self.0
+    }
+}
+
 impl<C: HasStub> Categorize<ScryptoCustomValueKind> for Owned<C> {
     #[inline]
     fn value_kind() -> ValueKind<ScryptoCustomValueKind> {
@@ -196,6 +255,16 @@ impl<C: HasStub + HasMethods> Owned<C> {
     }
 }
 
+impl<C: ComponentState> Owned<C> {
+    /// Drops the component and returns its state, running its `on_drop` hook if it has one.
+    ///
+    /// Only the component's own blueprint is allowed to drop it.
+    pub fn drop(self) -> C {
+        let fields = ScryptoVmV1Api::object_drop(self.0.handle().as_node_id());
+        scrypto_decode(&fields[0]).unwrap()
+    }
+}
+
 pub trait FnMapping<T> {
     fn to_mapping(self) -> Vec<(String, T)>;
 }
@@ -259,6 +328,13 @@ impl<C: HasStub + HasMethods> Globalizing<C> {
         self
     }
 
+    /// Opts the component in to pausing, with the rule of the role allowed to pause it. As
+    /// [`Self::roles`] replaces the roles of the main module, call this after it.
+    pub fn enable_pausing<R: ToRoleEntry>(mut self, pauser: R) -> Self {
+        self.roles.define_role(PAUSER_ROLE, pauser);
+        self
+    }
+
     pub fn metadata(mut self, metadata_config: ModuleConfig<MetadataInit>) -> Self {
         self.metadata_config = Some(metadata_config);
 
@@ -433,6 +509,14 @@ impl<O: HasStub> HasRoleAssignment for Global<O> {
         self.role_assignment().lock_owner_role()
     }
 
+    fn propose_owner_role<A: Into<AccessRule>>(&self, rule: A) {
+        self.role_assignment().propose_owner_role(rule)
+    }
+
+    fn accept_owner_role(&self) {
+        self.role_assignment().accept_owner_role()
+    }
+
     fn set_role<A: Into<AccessRule>>(&self, name: &str, rule: A) {
         self.role_assignment().set_role(name, rule);
     }
@@ -449,6 +533,22 @@ impl<O: HasStub> HasRoleAssignment for Global<O> {
         self.role_assignment()
             .set_component_royalties_role(name, rule);
     }
+
+    fn set_pauser_role<A: Into<AccessRule>>(&self, rule: A) {
+        self.role_assignment().set_pauser_role(rule);
+    }
+
+    fn pause(&self) {
+        self.role_assignment().pause()
+    }
+
+    fn unpause(&self) {
+        self.role_assignment().unpause()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.role_assignment().is_paused()
+    }
 }
 
 impl<O, S> HasComponentRoyalties for Global<O>
@@ -507,6 +607,22 @@ impl<O: HasStub + TypeCheckable> From<ComponentAddress> for Global<O> {
     }
 }
 
+impl<O: HasStub + TypeCheckable> From<Own> for Owned<O> {
+    fn from(value: Own) -> Self {
+        O::check(value.as_node_id()).unwrap();
+        Owned(ObjectStub::new(ObjectStubHandle::Own(value)))
+    }
+}
+
+impl<O: HasStub> From<Owned<O>> for Own {
+    fn from(value: Owned<O>) -> Self {
+        match value.0.handle() {
+            ObjectStubHandle::Own(own) => *own,
+            _ => panic!("Unexpected"),
+        }
+    }
+}
+
 impl<O: HasStub> Categorize<ScryptoCustomValueKind> for Global<O> {
     #[inline]
     fn value_kind() -> ValueKind<ScryptoCustomValueKind> {
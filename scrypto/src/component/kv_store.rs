@@ -11,6 +11,7 @@ use radix_engine_interface::prelude::{
     LocalKeyValueStoreDataSchema, KV_STORE_DATA_SCHEMA_VARIANT_LOCAL,
 };
 use sbor::rust::marker::PhantomData;
+use sbor::rust::vec::Vec;
 use sbor::*;
 
 /// A scalable key-value map which loads entries on demand.
@@ -115,6 +116,32 @@ impl<
 
         scrypto_decode(&rtn).unwrap()
     }
+
+    /// Returns the values associated with the given keys, in the same order, reading all of
+    /// them in a single call to the engine.
+    ///
+    /// Unlike [`Self::get`], the entries are not kept open, so the values are returned by value.
+    pub fn get_many(&self, keys: &[K]) -> Vec<Option<V>> {
+        let key_payloads = keys
+            .iter()
+            .map(|key| scrypto_encode(key).unwrap())
+            .collect();
+        ScryptoVmV1Api::kv_store_read_entries(self.id.as_node_id(), &key_payloads)
+            .into_iter()
+            .map(|raw_bytes| scrypto_decode(&raw_bytes).unwrap())
+            .collect()
+    }
+
+    /// Inserts the given key-value pairs into this map in a single call to the engine.
+    ///
+    /// The entries are written in order, so for a repeated key the last value is kept.
+    pub fn insert_many(&self, entries: Vec<(K, V)>) {
+        let entry_payloads = entries
+            .iter()
+            .map(|(key, value)| (scrypto_encode(key).unwrap(), scrypto_encode(value).unwrap()))
+            .collect();
+        ScryptoVmV1Api::kv_store_write_entries(self.id.as_node_id(), &entry_payloads);
+    }
 }
 
 //========
@@ -1,6 +1,9 @@
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_interface::blueprints::order_book::*;
+use radix_engine_interface::blueprints::payment_scheduler::*;
+use radix_engine_interface::blueprints::pool::PoolAccrual;
 
 use crate::prelude::*;
 
@@ -71,6 +74,13 @@ extern_blueprint_internal! {
             fee_factor: Decimal,
             xrd_payment: Bucket,
         ) -> (Global<Validator>, Bucket, Bucket);
+        fn register_epoch_change_hook(
+            &mut self,
+            component_address: ComponentAddress,
+            method_name: String,
+            bond: Bucket,
+        );
+        fn deregister_epoch_change_hook(&mut self, component_address: ComponentAddress) -> Bucket;
     }
 }
 extern_blueprint_internal! {
@@ -95,6 +105,8 @@ extern_blueprint_internal! {
         fn claim_xrd(&mut self, bucket: Bucket) -> Bucket;
         fn update_key(&mut self, key: Secp256k1PublicKey);
         fn update_fee(&mut self, new_fee_factor: Decimal);
+        fn update_fee_split(&mut self, recipients: Vec<ValidatorFeeRecipient>);
+        fn claim_fee_split(&mut self, account: ComponentAddress);
         fn update_accept_delegated_stake(&mut self, accept_delegated_stake: bool);
         fn accepts_delegated_stake(&mut self) -> bool;
         fn total_stake_xrd_amount(&self) -> Decimal;
@@ -217,6 +229,14 @@ extern_blueprint_internal! {
         );
         fn add_authorized_depositor(&mut self, badge: ResourceOrNonFungible);
         fn remove_authorized_depositor(&mut self, badge: ResourceOrNonFungible);
+        fn get_default_deposit_rule(&self) -> DefaultDepositRule;
+        fn get_resource_preference(
+            &self,
+            resource_address: ResourceAddress,
+        ) -> Option<ResourcePreference>;
+        fn is_authorized_depositor(&self, badge: ResourceOrNonFungible) -> bool;
+        fn set_authorizer(&self, authorizer: Option<ComponentAddress>);
+        fn get_authorizer(&self) -> Option<ComponentAddress>;
     }
 }
 
@@ -253,6 +273,10 @@ extern_blueprint_internal! {
             amount_of_pool_units: Decimal,
         ) -> IndexMap<ResourceAddress, Decimal>;
         fn get_vault_amounts(&self) -> IndexMap<ResourceAddress, Decimal>;
+        fn add_resource(&mut self, resource_address: ResourceAddress, weight: Decimal);
+        fn retire_resource(&mut self, resource_address: ResourceAddress);
+        fn set_resource_weight(&mut self, resource_address: ResourceAddress, weight: Decimal);
+        fn get_resource_weights(&self) -> IndexMap<ResourceAddress, Decimal>;
     }
 }
 extern_blueprint_internal! {
@@ -272,6 +296,12 @@ extern_blueprint_internal! {
             resource_address: ResourceAddress,
             address_reservation: Option<GlobalAddressReservation>,
         ) -> Global<OneResourcePool>;
+        fn instantiate_interest_bearing(
+            owner_role: OwnerRole,
+            pool_manager_rule: AccessRule,
+            resource_address: ResourceAddress,
+            address_reservation: Option<GlobalAddressReservation>,
+        ) -> Global<OneResourcePool>;
     },
     {
         fn contribute(&mut self, bucket: Bucket) -> Bucket;
@@ -284,6 +314,8 @@ extern_blueprint_internal! {
         ) -> Bucket;
         fn get_redemption_value(&self, amount_of_pool_units: Decimal) -> Decimal;
         fn get_vault_amount(&self) -> Decimal;
+        fn donate(&mut self, bucket: Bucket);
+        fn get_accrual(&self) -> Option<PoolAccrual>;
     }
 }
 extern_blueprint_internal! {
@@ -385,3 +417,85 @@ extern_blueprint_internal! {
         fn mint_recovery_badges(&mut self, non_fungible_local_ids: Vec<NonFungibleLocalId>) -> Bucket;
     }
 }
+extern_blueprint_internal! {
+    PackageAddress::new_or_panic([
+        13u8, 144u8, 99u8, 24u8, 198u8, 49u8, 140u8, 96u8, 143u8, 9u8, 203u8, 76u8, 99u8, 24u8,
+        198u8, 49u8, 140u8, 255u8, 107u8, 207u8, 243u8, 223u8, 74u8, 211u8, 198u8, 49u8, 140u8,
+        99u8, 24u8, 198u8,
+    ]),
+    PriceFeed,
+    "PriceFeed",
+    "OwnedPriceFeed",
+    "GlobalPriceFeed",
+    PriceFeedFunctions {
+        fn instantiate(
+            owner_role: OwnerRole,
+            max_price_age_seconds: i64,
+            publisher_badge_amount: Decimal,
+            address_reservation: Option<GlobalAddressReservation>,
+        ) -> (Global<PriceFeed>, Bucket);
+    },
+    {
+        fn publish_price(&mut self, base: ResourceAddress, quote: ResourceAddress, price: Decimal);
+        fn get_price(&self, base: ResourceAddress, quote: ResourceAddress) -> (Decimal, Instant);
+    }
+}
+extern_blueprint_internal! {
+    PackageAddress::new_or_panic([
+        13u8, 144u8, 99u8, 24u8, 198u8, 49u8, 140u8, 96u8, 244u8, 155u8, 154u8, 204u8, 99u8, 24u8,
+        198u8, 49u8, 140u8, 247u8, 170u8, 190u8, 63u8, 168u8, 173u8, 121u8, 230u8, 49u8, 140u8,
+        99u8, 24u8, 198u8,
+    ]),
+    PaymentScheduler,
+    "PaymentScheduler",
+    "OwnedPaymentScheduler",
+    "GlobalPaymentScheduler",
+    PaymentSchedulerFunctions {
+        fn instantiate(
+            owner_role: OwnerRole,
+            resource_address: ResourceAddress,
+            incentive_per_payment: Decimal,
+            address_reservation: Option<GlobalAddressReservation>,
+        ) -> Global<PaymentScheduler>;
+    },
+    {
+        fn deposit(&mut self, bucket: Bucket);
+        fn withdraw(&mut self, amount: Decimal) -> Bucket;
+        fn create_schedule(
+            &mut self,
+            recipient: ComponentAddress,
+            amount: Decimal,
+            interval_epochs: u64,
+            first_payment_epoch: Epoch,
+        ) -> u64;
+        fn cancel_schedule(&mut self, schedule_id: u64);
+        fn get_schedule(&self, schedule_id: u64) -> Option<PaymentSchedule>;
+        fn process_due_payments(&mut self, max_payments: u32) -> Bucket;
+    }
+}
+extern_blueprint_internal! {
+    PackageAddress::new_or_panic([
+        13u8, 144u8, 99u8, 24u8, 198u8, 49u8, 140u8, 109u8, 175u8, 23u8, 154u8, 12u8, 99u8, 24u8,
+        198u8, 49u8, 140u8, 247u8, 188u8, 250u8, 209u8, 202u8, 255u8, 61u8, 70u8, 49u8, 140u8, 99u8,
+        24u8, 198u8,
+    ]),
+    OrderBook,
+    "OrderBook",
+    "OwnedOrderBook",
+    "GlobalOrderBook",
+    OrderBookFunctions {
+        fn instantiate(tick_size: Decimal) -> Owned<OrderBook>;
+    },
+    {
+        fn insert_order(&mut self, side: OrderSide, price: Decimal, quantity: Decimal) -> u64;
+        fn cancel_order(&mut self, order_id: u64) -> Order;
+        fn match_best(
+            &mut self,
+            side: OrderSide,
+            quantity: Decimal,
+            limit_price: Option<Decimal>,
+            max_fills: u32,
+        ) -> Vec<OrderFill>;
+        fn get_order(&self, order_id: u64) -> Option<Order>;
+    }
+}
@@ -1,15 +1,17 @@
 use crate::engine::wasm_api::*;
 use radix_engine_common::math::Decimal;
 use radix_engine_common::types::GlobalAddressReservation;
-use radix_engine_interface::api::actor_api::EventFlags;
+use radix_engine_interface::api::actor_api::{EventFlags, NodeStateSize, RateLimitScope};
 use radix_engine_interface::api::key_value_entry_api::KeyValueEntryHandle;
 use radix_engine_interface::api::{ActorRefHandle, FieldValue};
 use radix_engine_interface::api::{AttachedModuleId, FieldIndex, LockFlags};
+use radix_engine_interface::blueprints::package::BlueprintInterface;
+use radix_engine_interface::blueprints::resource::NonFungibleGlobalId;
 use radix_engine_interface::crypto::Hash;
 use radix_engine_interface::data::scrypto::*;
-use radix_engine_interface::types::PackageAddress;
 use radix_engine_interface::types::{BlueprintId, GlobalAddress};
-use radix_engine_interface::types::{Level, NodeId, SubstateHandle};
+use radix_engine_interface::types::{Level, LogRecord, NodeId, SubstateHandle};
+use radix_engine_interface::types::{PackageAddress, ScopedTypeId};
 use radix_engine_interface::*;
 use sbor::rust::prelude::*;
 
@@ -36,6 +38,41 @@ impl ScryptoVmV1Api {
         })
     }
 
+    pub fn blueprint_get_interface(
+        package_address: PackageAddress,
+        blueprint_name: &str,
+    ) -> BlueprintInterface {
+        let bytes = copy_buffer(unsafe {
+            blueprint::blueprint_get_interface(
+                package_address.as_ref().as_ptr(),
+                package_address.as_ref().len(),
+                blueprint_name.as_ptr(),
+                blueprint_name.len(),
+            )
+        });
+
+        scrypto_decode(&bytes).unwrap()
+    }
+
+    pub fn blueprint_get_event_schema(
+        package_address: PackageAddress,
+        blueprint_name: &str,
+        event_name: &str,
+    ) -> (VersionedScryptoSchema, ScopedTypeId) {
+        let bytes = copy_buffer(unsafe {
+            blueprint::blueprint_get_event_schema(
+                package_address.as_ref().as_ptr(),
+                package_address.as_ref().len(),
+                blueprint_name.as_ptr(),
+                blueprint_name.len(),
+                event_name.as_ptr(),
+                event_name.len(),
+            )
+        });
+
+        scrypto_decode(&bytes).unwrap()
+    }
+
     pub fn object_new(
         blueprint_name: &str,
         object_states: IndexMap<FieldIndex, FieldValue>,
@@ -112,6 +149,14 @@ impl ScryptoVmV1Api {
         GlobalAddress::try_from(bytes.as_slice()).unwrap()
     }
 
+    pub fn object_drop(node_id: &NodeId) -> Vec<Vec<u8>> {
+        let bytes = copy_buffer(unsafe {
+            object::object_drop(node_id.as_ref().as_ptr(), node_id.as_ref().len())
+        });
+
+        scrypto_decode(&bytes).unwrap()
+    }
+
     pub fn object_call(receiver: &NodeId, method_name: &str, args: Vec<u8>) -> Vec<u8> {
         copy_buffer(unsafe {
             object::object_call(
@@ -194,6 +239,31 @@ impl ScryptoVmV1Api {
         removed
     }
 
+    pub fn kv_store_read_entries(node_id: &NodeId, keys: &Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+        let keys = scrypto_encode(keys).unwrap();
+        let values = copy_buffer(unsafe {
+            kv_store::kv_store_read_entries(
+                node_id.as_ref().as_ptr(),
+                node_id.as_ref().len(),
+                keys.as_ptr(),
+                keys.len(),
+            )
+        });
+        scrypto_decode(&values).unwrap()
+    }
+
+    pub fn kv_store_write_entries(node_id: &NodeId, entries: &Vec<(Vec<u8>, Vec<u8>)>) {
+        let entries = scrypto_encode(entries).unwrap();
+        unsafe {
+            kv_store::kv_store_write_entries(
+                node_id.as_ref().as_ptr(),
+                node_id.as_ref().len(),
+                entries.as_ptr(),
+                entries.len(),
+            )
+        };
+    }
+
     pub fn actor_open_field(object_handle: u32, field: u8, flags: LockFlags) -> SubstateHandle {
         let handle =
             unsafe { actor::actor_open_field(object_handle, u32::from(field), flags.bits()) };
@@ -230,6 +300,53 @@ impl ScryptoVmV1Api {
         };
     }
 
+    pub fn actor_subscribe_to_event(emitter: GlobalAddress, event_name: &str, hook_method: &str) {
+        unsafe {
+            actor::actor_subscribe_to_event(
+                emitter.as_node_id().as_ref().as_ptr(),
+                emitter.as_node_id().as_ref().len(),
+                event_name.as_ptr(),
+                event_name.len(),
+                hook_method.as_ptr(),
+                hook_method.len(),
+            )
+        };
+    }
+
+    pub fn actor_unsubscribe_from_event(emitter: GlobalAddress, event_name: &str) {
+        unsafe {
+            actor::actor_unsubscribe_from_event(
+                emitter.as_node_id().as_ref().as_ptr(),
+                emitter.as_node_id().as_ref().len(),
+                event_name.as_ptr(),
+                event_name.len(),
+            )
+        };
+    }
+
+    pub fn actor_get_state_size() -> NodeStateSize {
+        let state_size = copy_buffer(unsafe { actor::actor_get_state_size() });
+
+        scrypto_decode(&state_size).unwrap()
+    }
+
+    pub fn actor_rate_limit_check(
+        name: &str,
+        scope: RateLimitScope,
+        max: u32,
+        window_epochs: u64,
+    ) -> bool {
+        unsafe {
+            actor::actor_rate_limit_check(
+                name.as_ptr(),
+                name.len(),
+                scope as u32,
+                max,
+                window_epochs,
+            ) != 0
+        }
+    }
+
     pub fn field_entry_read(lock_handle: SubstateHandle) -> Vec<u8> {
         copy_buffer(unsafe { field_entry::field_entry_read(lock_handle) })
     }
@@ -290,6 +407,10 @@ impl ScryptoVmV1Api {
         scrypto_decode(&bytes).unwrap()
     }
 
+    pub fn costing_get_execution_cost_units_consumed() -> u32 {
+        unsafe { costing::costing_get_execution_cost_units_consumed() }
+    }
+
     pub fn sys_bech32_encode_address(address: GlobalAddress) -> String {
         let global_address = scrypto_encode(&address).unwrap();
         let encoded = copy_buffer(unsafe {
@@ -303,12 +424,23 @@ impl ScryptoVmV1Api {
         unsafe { system::sys_log(level.as_ptr(), level.len(), message.as_ptr(), message.len()) }
     }
 
+    pub fn sys_log_record(record: LogRecord) {
+        let record = scrypto_encode(&record).unwrap();
+        unsafe { system::sys_log_record(record.as_ptr(), record.len()) }
+    }
+
     pub fn sys_get_transaction_hash() -> Hash {
         let hash = copy_buffer(unsafe { system::sys_get_transaction_hash() });
 
         Hash(hash.try_into().unwrap())
     }
 
+    pub fn sys_get_transaction_signatories() -> Vec<NonFungibleGlobalId> {
+        let signatories = copy_buffer(unsafe { system::sys_get_transaction_signatories() });
+
+        scrypto_decode(&signatories).unwrap()
+    }
+
     pub fn sys_generate_ruid() -> [u8; 32] {
         let ruid = copy_buffer(unsafe { system::sys_generate_ruid() });
 
@@ -320,4 +452,10 @@ impl ScryptoVmV1Api {
             system::sys_panic(message.as_ptr(), message.len());
         };
     }
+
+    pub fn sys_abort(error: Vec<u8>) {
+        unsafe {
+            system::sys_abort(error.as_ptr(), error.len());
+        };
+    }
 }
@@ -42,6 +42,24 @@ pub mod blueprint {
             args_ptr: *const u8,
             args_len: usize,
         ) -> Buffer;
+
+        /// Returns the interface of a blueprint
+        pub fn blueprint_get_interface(
+            package_address_ptr: *const u8,
+            package_address_len: usize,
+            blueprint_name_ptr: *const u8,
+            blueprint_name_len: usize,
+        ) -> Buffer;
+
+        /// Returns the schema of an event of a blueprint
+        pub fn blueprint_get_event_schema(
+            package_address_ptr: *const u8,
+            package_address_len: usize,
+            blueprint_name_ptr: *const u8,
+            blueprint_name_len: usize,
+            event_name_ptr: *const u8,
+            event_name_len: usize,
+        ) -> Buffer;
     }
 }
 
@@ -109,6 +127,9 @@ pub mod object {
         /// Get the address of the outer object of a given object
         pub fn object_get_outer_object(obj_id_ptr: *const u8, obj_id_len: usize) -> Buffer;
 
+        /// Drops an owned object of the current blueprint, returning its encoded fields
+        pub fn object_drop(obj_id_ptr: *const u8, obj_id_len: usize) -> Buffer;
+
         /// Invokes a method on a visible object
         pub fn object_call(
             obj_id_ptr: *const u8,
@@ -173,6 +194,37 @@ pub mod actor {
             event_data_len: usize,
             event_flags: u32,
         );
+
+        /// Subscribe the current component to an event of a global object
+        pub fn actor_subscribe_to_event(
+            emitter_ptr: *const u8,
+            emitter_len: usize,
+            event_name_ptr: *const u8,
+            event_name_len: usize,
+            hook_method_ptr: *const u8,
+            hook_method_len: usize,
+        );
+
+        /// Unsubscribe the current component from an event of a global object
+        pub fn actor_unsubscribe_from_event(
+            emitter_ptr: *const u8,
+            emitter_len: usize,
+            event_name_ptr: *const u8,
+            event_name_len: usize,
+        );
+
+        /// Get the state size of the current actor's node
+        pub fn actor_get_state_size() -> Buffer;
+
+        /// Count a call against a rate limit of the current component, returning 0 if the limit
+        /// has been reached
+        pub fn actor_rate_limit_check(
+            name_ptr: *const u8,
+            name_len: usize,
+            scope: u32,
+            max: u32,
+            window_epochs: u64,
+        ) -> u32;
     }
 }
 
@@ -199,6 +251,24 @@ pub mod kv_store {
             key: *const u8,
             key_len: usize,
         ) -> Buffer;
+
+        /// Reads the values of multiple entries in a key value store, given the encoded list of
+        /// their keys
+        pub fn kv_store_read_entries(
+            key_value_store_id_ptr: *const u8,
+            key_value_store_id_len: usize,
+            keys_ptr: *const u8,
+            keys_len: usize,
+        ) -> Buffer;
+
+        /// Writes the values of multiple entries in a key value store, given the encoded list of
+        /// key and value pairs
+        pub fn kv_store_write_entries(
+            key_value_store_id_ptr: *const u8,
+            key_value_store_id_len: usize,
+            entries_ptr: *const u8,
+            entries_len: usize,
+        );
     }
 }
 
@@ -254,6 +324,8 @@ pub mod costing {
         pub fn costing_get_tip_percentage() -> u32;
 
         pub fn costing_get_fee_balance() -> Buffer;
+
+        pub fn costing_get_execution_cost_units_consumed() -> u32;
     }
 }
 
@@ -270,17 +342,26 @@ pub mod system {
             message_len: usize,
         );
 
+        /// Logs a structured log record
+        pub fn sys_log_record(record_ptr: *const u8, record_len: usize);
+
         /// Encode an address to bech32 encoding
         pub fn sys_bech32_encode_address(address_ptr: *const u8, address_len: usize) -> Buffer;
 
         /// Retrieves the current transaction hash
         pub fn sys_get_transaction_hash() -> Buffer;
 
+        /// Retrieves the virtual signature badges of the transaction signers
+        pub fn sys_get_transaction_signatories() -> Buffer;
+
         /// Generates a unique id
         pub fn sys_generate_ruid() -> Buffer;
 
         /// Panics and halts transaction execution
         pub fn sys_panic(message_ptr: *const u8, message_len: usize);
+
+        /// Aborts and halts transaction execution with an encoded error value
+        pub fn sys_abort(error_ptr: *const u8, error_len: usize);
     }
 }
 
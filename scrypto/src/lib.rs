@@ -42,7 +42,7 @@ pub use scrypto_derive::{blueprint, NonFungibleData};
 // Re-export Radix Engine Interface modules.
 extern crate radix_engine_interface;
 pub use radix_engine_interface::{
-    address, api, blueprints, constants, crypto, data, math, network, schema, time, types,
+    address, api, blueprints, constants, crypto, data, hooks, math, network, schema, time, types,
 };
 
 // This is to make derives work within this crate.
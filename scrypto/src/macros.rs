@@ -1,14 +1,28 @@
 /// Logs an `ERROR` message.
 ///
+/// Key-value fields can be attached after a semicolon, and are kept apart from the message
+/// in the transaction receipt.
+///
 /// # Example
 /// ```no_run
 /// use scrypto::prelude::*;
 ///
 /// error!("Input number: {}", 100);
+/// error!("Input received"; number = 100, unit = "XRD");
 /// ```
 #[cfg(feature = "log-error")]
 #[macro_export]
 macro_rules! error {
+    ($($args: expr),+ ; $($key: ident = $value: expr),+ $(,)?) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Error,
+            ::sbor::rust::format!($($args),+),
+            ::sbor::rust::vec![$((
+                ::sbor::rust::string::ToString::to_string(stringify!($key)),
+                ::sbor::rust::string::ToString::to_string(&$value),
+            )),+],
+        );
+    }};
     ($($args: expr),+) => {{
         $crate::runtime::Logger::error(::sbor::rust::format!($($args),+));
     }};
@@ -17,20 +31,35 @@ macro_rules! error {
 #[cfg(not(feature = "log-error"))]
 #[macro_export]
 macro_rules! error {
+    ($($args: expr),+ ; $($key: ident = $value: expr),+ $(,)?) => {{}};
     ($($args: expr),+) => {{}};
 }
 
 /// Logs a `WARN` message.
 ///
+/// Key-value fields can be attached after a semicolon, and are kept apart from the message
+/// in the transaction receipt.
+///
 /// # Example
 /// ```no_run
 /// use scrypto::prelude::*;
 ///
 /// warn!("Input number: {}", 100);
+/// warn!("Input received"; number = 100, unit = "XRD");
 /// ```
 #[cfg(feature = "log-warn")]
 #[macro_export]
 macro_rules! warn {
+    ($($args: expr),+ ; $($key: ident = $value: expr),+ $(,)?) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Warn,
+            ::sbor::rust::format!($($args),+),
+            ::sbor::rust::vec![$((
+                ::sbor::rust::string::ToString::to_string(stringify!($key)),
+                ::sbor::rust::string::ToString::to_string(&$value),
+            )),+],
+        );
+    }};
     ($($args: expr),+) => {{
         $crate::runtime::Logger::warn(::sbor::rust::format!($($args),+));
     }};
@@ -39,20 +68,35 @@ macro_rules! warn {
 #[cfg(not(feature = "log-warn"))]
 #[macro_export]
 macro_rules! warn {
+    ($($args: expr),+ ; $($key: ident = $value: expr),+ $(,)?) => {{}};
     ($($args: expr),+) => {{}};
 }
 
 /// Logs an `INFO` message.
 ///
+/// Key-value fields can be attached after a semicolon, and are kept apart from the message
+/// in the transaction receipt.
+///
 /// # Example
 /// ```no_run
 /// use scrypto::prelude::*;
 ///
 /// info!("Input number: {}", 100);
+/// info!("Input received"; number = 100, unit = "XRD");
 /// ```
 #[cfg(feature = "log-info")]
 #[macro_export]
 macro_rules! info {
+    ($($args: expr),+ ; $($key: ident = $value: expr),+ $(,)?) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Info,
+            ::sbor::rust::format!($($args),+),
+            ::sbor::rust::vec![$((
+                ::sbor::rust::string::ToString::to_string(stringify!($key)),
+                ::sbor::rust::string::ToString::to_string(&$value),
+            )),+],
+        );
+    }};
     ($($args: expr),+) => {{
         $crate::runtime::Logger::info(::sbor::rust::format!($($args),+));
     }};
@@ -61,20 +105,35 @@ macro_rules! info {
 #[cfg(not(feature = "log-info"))]
 #[macro_export]
 macro_rules! info {
+    ($($args: expr),+ ; $($key: ident = $value: expr),+ $(,)?) => {{}};
     ($($args: expr),+) => {{}};
 }
 
 /// Logs a `DEBUG` message.
 ///
+/// Key-value fields can be attached after a semicolon, and are kept apart from the message
+/// in the transaction receipt.
+///
 /// # Example
 /// ```no_run
 /// use scrypto::prelude::*;
 ///
 /// debug!("Input number: {}", 100);
+/// debug!("Input received"; number = 100, unit = "XRD");
 /// ```
 #[cfg(feature = "log-debug")]
 #[macro_export]
 macro_rules! debug {
+    ($($args: expr),+ ; $($key: ident = $value: expr),+ $(,)?) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Debug,
+            ::sbor::rust::format!($($args),+),
+            ::sbor::rust::vec![$((
+                ::sbor::rust::string::ToString::to_string(stringify!($key)),
+                ::sbor::rust::string::ToString::to_string(&$value),
+            )),+],
+        );
+    }};
     ($($args: expr),+) => {{
         $crate::runtime::Logger::debug(::sbor::rust::format!($($args),+));
     }};
@@ -83,20 +142,35 @@ macro_rules! debug {
 #[cfg(not(feature = "log-debug"))]
 #[macro_export]
 macro_rules! debug {
+    ($($args: expr),+ ; $($key: ident = $value: expr),+ $(,)?) => {{}};
     ($($args: expr),+) => {{}};
 }
 
 /// Logs a `TRACE` message.
 ///
+/// Key-value fields can be attached after a semicolon, and are kept apart from the message
+/// in the transaction receipt.
+///
 /// # Example
 /// ```no_run
 /// use scrypto::prelude::*;
 ///
 /// trace!("Input number: {}", 100);
+/// trace!("Input received"; number = 100, unit = "XRD");
 /// ```
 #[cfg(feature = "log-trace")]
 #[macro_export]
 macro_rules! trace {
+    ($($args: expr),+ ; $($key: ident = $value: expr),+ $(,)?) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Trace,
+            ::sbor::rust::format!($($args),+),
+            ::sbor::rust::vec![$((
+                ::sbor::rust::string::ToString::to_string(stringify!($key)),
+                ::sbor::rust::string::ToString::to_string(&$value),
+            )),+],
+        );
+    }};
     ($($args: expr),+) => {{
         $crate::runtime::Logger::trace(::sbor::rust::format!($($args),+));
     }};
@@ -105,6 +179,7 @@ macro_rules! trace {
 #[cfg(not(feature = "log-trace"))]
 #[macro_export]
 macro_rules! trace {
+    ($($args: expr),+ ; $($key: ident = $value: expr),+ $(,)?) => {{}};
     ($($args: expr),+) => {{}};
 }
 
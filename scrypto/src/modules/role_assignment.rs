@@ -4,14 +4,18 @@ use crate::modules::ModuleHandle;
 use crate::prelude::Attachable;
 use radix_engine_derive::*;
 use radix_engine_interface::api::node_modules::auth::{
-    RoleAssignmentCreateInput, RoleAssignmentGetInput, RoleAssignmentLockOwnerInput,
-    RoleAssignmentSetInput, RoleAssignmentSetOwnerInput, ROLE_ASSIGNMENT_BLUEPRINT,
-    ROLE_ASSIGNMENT_CREATE_IDENT, ROLE_ASSIGNMENT_GET_IDENT, ROLE_ASSIGNMENT_LOCK_OWNER_IDENT,
-    ROLE_ASSIGNMENT_SET_IDENT, ROLE_ASSIGNMENT_SET_OWNER_IDENT,
+    RoleAssignmentAcceptOwnerInput, RoleAssignmentCreateInput, RoleAssignmentGetInput,
+    RoleAssignmentIsPausedInput, RoleAssignmentLockOwnerInput, RoleAssignmentPauseInput,
+    RoleAssignmentProposeOwnerInput, RoleAssignmentSetInput, RoleAssignmentSetOwnerInput,
+    RoleAssignmentUnpauseInput, ROLE_ASSIGNMENT_ACCEPT_OWNER_IDENT, ROLE_ASSIGNMENT_BLUEPRINT,
+    ROLE_ASSIGNMENT_CREATE_IDENT, ROLE_ASSIGNMENT_GET_IDENT, ROLE_ASSIGNMENT_IS_PAUSED_IDENT,
+    ROLE_ASSIGNMENT_LOCK_OWNER_IDENT, ROLE_ASSIGNMENT_PAUSE_IDENT,
+    ROLE_ASSIGNMENT_PROPOSE_OWNER_IDENT, ROLE_ASSIGNMENT_SET_IDENT,
+    ROLE_ASSIGNMENT_SET_OWNER_IDENT, ROLE_ASSIGNMENT_UNPAUSE_IDENT,
 };
 use radix_engine_interface::api::*;
 use radix_engine_interface::blueprints::resource::{
-    AccessRule, OwnerRoleEntry, RoleAssignmentInit, RoleKey,
+    AccessRule, OwnerRoleEntry, RoleAssignmentInit, RoleKey, PAUSER_ROLE,
 };
 use radix_engine_interface::constants::ROLE_ASSIGNMENT_MODULE_PACKAGE;
 use radix_engine_interface::data::scrypto::model::*;
@@ -22,10 +26,16 @@ use sbor::rust::prelude::*;
 pub trait HasRoleAssignment {
     fn set_owner_role<A: Into<AccessRule>>(&self, rule: A);
     fn lock_owner_role(&self);
+    fn propose_owner_role<A: Into<AccessRule>>(&self, rule: A);
+    fn accept_owner_role(&self);
     fn set_role<A: Into<AccessRule>>(&self, name: &str, rule: A);
     fn get_role(&self, name: &str) -> Option<AccessRule>;
     fn set_metadata_role<A: Into<AccessRule>>(&self, name: &str, rule: A);
     fn set_component_royalties_role<A: Into<AccessRule>>(&self, name: &str, rule: A);
+    fn set_pauser_role<A: Into<AccessRule>>(&self, rule: A);
+    fn pause(&self);
+    fn unpause(&self);
+    fn is_paused(&self) -> bool;
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -64,6 +74,22 @@ impl RoleAssignment {
         );
     }
 
+    /// Proposes a new owner rule, which only replaces the current one once it is accepted by
+    /// a call to [`Self::accept_owner_role`] which proves it.
+    pub fn propose_owner_role<A: Into<AccessRule>>(&self, rule: A) {
+        self.call_ignore_rtn(
+            ROLE_ASSIGNMENT_PROPOSE_OWNER_IDENT,
+            &RoleAssignmentProposeOwnerInput { rule: rule.into() },
+        );
+    }
+
+    pub fn accept_owner_role(&self) {
+        self.call_ignore_rtn(
+            ROLE_ASSIGNMENT_ACCEPT_OWNER_IDENT,
+            &RoleAssignmentAcceptOwnerInput {},
+        );
+    }
+
     fn internal_set_role<A: Into<AccessRule>>(&self, module: ModuleId, name: &str, rule: A) {
         self.call_ignore_rtn(
             ROLE_ASSIGNMENT_SET_IDENT,
@@ -116,6 +142,32 @@ impl RoleAssignment {
     pub fn get_role_assignment_role(&self, name: &str) -> Option<AccessRule> {
         self.internal_get_role(ModuleId::RoleAssignment, name)
     }
+
+    /// Sets the rule of the role allowed to pause the object, which falls back to the owner
+    /// role until set.
+    pub fn set_pauser_role<A: Into<AccessRule>>(&self, rule: A) {
+        self.internal_set_role(ModuleId::Main, PAUSER_ROLE, rule);
+    }
+
+    /// Makes the system reject calls to public methods of the object until it is unpaused. Only
+    /// objects which opted in to pausing when they were globalized may be paused.
+    pub fn pause(&self) {
+        self.call_ignore_rtn(ROLE_ASSIGNMENT_PAUSE_IDENT, &RoleAssignmentPauseInput {});
+    }
+
+    pub fn unpause(&self) {
+        self.call_ignore_rtn(
+            ROLE_ASSIGNMENT_UNPAUSE_IDENT,
+            &RoleAssignmentUnpauseInput {},
+        );
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.call(
+            ROLE_ASSIGNMENT_IS_PAUSED_IDENT,
+            &RoleAssignmentIsPausedInput {},
+        )
+    }
 }
 
 impl Attachable for RoleAssignment {
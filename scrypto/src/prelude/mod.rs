@@ -28,17 +28,20 @@ pub use num_traits::{
 pub use radix_engine_interface::api::node_modules::auth::*;
 pub use radix_engine_interface::api::node_modules::metadata::*;
 pub use radix_engine_interface::api::node_modules::*;
-pub use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
+pub use radix_engine_interface::blueprints::account::{AuthZoneSnapshot, ManifestSummary};
+pub use radix_engine_interface::blueprints::consensus_manager::{TimePrecision, TimeRoundingMode};
+pub use radix_engine_interface::blueprints::order_book::{Order, OrderFill, OrderSide};
 pub use radix_engine_interface::blueprints::resource::*;
 pub use radix_engine_interface::crypto::*;
+pub use radix_engine_interface::hooks::{OnDropInput, OnMoveInput};
 pub use radix_engine_interface::math::*;
 pub use radix_engine_interface::prelude::*;
 pub use radix_engine_interface::{
-    access_and_or, access_rule_node, burn_roles, dec, deposit_roles, freeze_roles, i,
-    internal_roles, manifest_args, metadata, metadata_init, metadata_init_set_entry,
+    access_and_or, access_rule_node, burn_roles, dec, deny_list_roles, deposit_roles, freeze_roles,
+    i, internal_roles, manifest_args, metadata, metadata_init, metadata_init_set_entry,
     metadata_roles, mint_roles, non_fungible_data_update_roles, pdec, recall_roles, role_entry,
-    roles2, rule, scrypto_args, withdraw_roles, ScryptoCategorize, ScryptoDecode, ScryptoEncode,
-    ScryptoEvent, ScryptoSbor,
+    roles2, rule, scrypto_args, soulbound_roles, transfer_royalty_roles, withdraw_roles,
+    ScryptoCategorize, ScryptoDecode, ScryptoEncode, ScryptoEvent, ScryptoSbor,
 };
 
 //=======
@@ -76,6 +76,11 @@ pub trait ScryptoNonFungibleBucket {
 
     fn take_non_fungible(&mut self, non_fungible_local_id: &NonFungibleLocalId) -> Self;
 
+    fn take_non_fungibles_matching<T: NonFungibleData, F: Fn(&T) -> bool>(
+        &mut self,
+        predicate: F,
+    ) -> Self;
+
     fn create_proof_of_non_fungibles(
         &self,
         non_fungible_local_ids: &IndexSet<NonFungibleLocalId>,
@@ -217,12 +222,7 @@ impl ScryptoBucket for Bucket {
     }
 
     fn authorize_with_all<F: FnOnce() -> O, O>(&self, f: F) -> O {
-        LocalAuthZone::push(self.create_proof_of_all());
-        let output = f();
-        LocalAuthZone::pop()
-            .expect("Authorized closure changed auth zone proof stack")
-            .drop();
-        output
+        LocalAuthZone::with_proof(self.create_proof_of_all(), f)
     }
 }
 
@@ -307,12 +307,7 @@ impl ScryptoFungibleBucket for FungibleBucket {
     }
 
     fn authorize_with_amount<A: Into<Decimal>, F: FnOnce() -> O, O>(&self, amount: A, f: F) -> O {
-        LocalAuthZone::push(self.create_proof_of_amount(amount));
-        let output = f();
-        LocalAuthZone::pop()
-            .expect("Authorized closure changed auth zone proof stack")
-            .drop();
-        output
+        LocalAuthZone::with_proof(self.create_proof_of_amount(amount), f)
     }
 }
 
@@ -461,6 +456,23 @@ impl ScryptoNonFungibleBucket for NonFungibleBucket {
         scrypto_decode(&rtn).unwrap()
     }
 
+    /// Takes the non-fungibles whose data matches the given predicate from this bucket.
+    ///
+    /// # Panics
+    /// Panics if this is not a non-fungible bucket or the data isn't of type `T`.
+    fn take_non_fungibles_matching<T: NonFungibleData, F: Fn(&T) -> bool>(
+        &mut self,
+        predicate: F,
+    ) -> Self {
+        let non_fungible_local_ids = self
+            .non_fungibles::<T>()
+            .into_iter()
+            .filter(|non_fungible| predicate(&non_fungible.data()))
+            .map(|non_fungible| non_fungible.local_id().clone())
+            .collect();
+        self.take_non_fungibles(&non_fungible_local_ids)
+    }
+
     fn create_proof_of_non_fungibles(
         &self,
         ids: &IndexSet<NonFungibleLocalId>,
@@ -479,11 +491,9 @@ impl ScryptoNonFungibleBucket for NonFungibleBucket {
         non_fungible_local_ids: &IndexSet<NonFungibleLocalId>,
         f: F,
     ) -> O {
-        LocalAuthZone::push(self.create_proof_of_non_fungibles(non_fungible_local_ids));
-        let output = f();
-        LocalAuthZone::pop()
-            .expect("Authorized closure changed auth zone proof stack")
-            .drop();
-        output
+        LocalAuthZone::with_proof(
+            self.create_proof_of_non_fungibles(non_fungible_local_ids),
+            f,
+        )
     }
 }
@@ -209,12 +209,7 @@ impl ScryptoUncheckedProof for Proof {
     }
 
     fn authorize<F: FnOnce() -> O, O>(&self, f: F) -> O {
-        LocalAuthZone::push(self.clone());
-        let output = f();
-        LocalAuthZone::pop()
-            .expect("Authorized closure changed auth zone proof stack")
-            .drop();
-        output
+        LocalAuthZone::with_proof(self.clone(), f)
     }
 }
 
@@ -4,8 +4,8 @@ use crate::runtime::Runtime;
 use radix_engine_common::prelude::ScryptoCategorize;
 use radix_engine_common::prelude::ScryptoDecode;
 use radix_engine_common::prelude::ScryptoEncode;
-use radix_engine_interface::api::node_modules::auth::RoleDefinition;
-use radix_engine_interface::api::node_modules::metadata::MetadataInit;
+use radix_engine_interface::api::node_modules::auth::{RoleDefinition, OWNER};
+use radix_engine_interface::api::node_modules::metadata::{MetadataInit, UncheckedUrl};
 use radix_engine_interface::api::node_modules::ModuleConfig;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::constants::RESOURCE_PACKAGE;
@@ -46,6 +46,89 @@ impl ResourceBuilder {
         InProgressResourceBuilder::new(owner_role, FungibleResourceType::default())
     }
 
+    /// Starts a new builder to create a token whose supply is managed by its owner.
+    ///
+    /// The owner role is fixed to `owner_rule`, and the owner can mint and burn the token and
+    /// update who else can. The token has the maximum divisibility, and its metadata can be set
+    /// with [`metadata`](UpdateMetadataBuilder::metadata) before creating it.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// # let owner_badge = XRD;
+    /// let bucket = ResourceBuilder::new_standard_ownable_token(rule!(require(owner_badge)))
+    ///     .metadata(metadata! {
+    ///         init {
+    ///             "name" => "Token", locked;
+    ///             "symbol" => "TKN", locked;
+    ///         }
+    ///     })
+    ///     .mint_initial_supply(1000);
+    /// ```
+    pub fn new_standard_ownable_token(
+        owner_rule: AccessRule,
+    ) -> InProgressResourceBuilder<FungibleResourceType> {
+        Self::new_fungible(OwnerRole::Fixed(owner_rule))
+            .mint_roles(mint_roles! {
+                minter => OWNER;
+                minter_updater => OWNER;
+            })
+            .burn_roles(burn_roles! {
+                burner => OWNER;
+                burner_updater => OWNER;
+            })
+    }
+
+    /// Creates a token with the maximum divisibility, whose whole supply is minted at once.
+    ///
+    /// The token has no owner, so nothing about it can change afterwards: it can't be minted
+    /// or burnt, and its metadata is locked.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// let bucket = ResourceBuilder::new_fixed_supply_token(
+    ///     metadata! {
+    ///         init {
+    ///             "name" => "Token", locked;
+    ///             "symbol" => "TKN", locked;
+    ///         }
+    ///     },
+    ///     1000,
+    /// );
+    /// ```
+    pub fn new_fixed_supply_token<T: Into<Decimal>>(
+        metadata: ModuleConfig<MetadataInit>,
+        supply: T,
+    ) -> FungibleBucket {
+        Self::new_fungible(OwnerRole::None)
+            .metadata(metadata)
+            .mint_initial_supply(supply)
+    }
+
+    /// Starts a new builder to create a badge: an indivisible token with no owner, whose supply
+    /// isn't tracked. Badges are typically minted once and used in access rules.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// let admin_badge = ResourceBuilder::new_badge()
+    ///     .metadata(metadata! {
+    ///         init {
+    ///             "name" => "Admin Badge", locked;
+    ///         }
+    ///     })
+    ///     .mint_initial_supply(1);
+    /// ```
+    pub fn new_badge() -> InProgressResourceBuilder<FungibleResourceType> {
+        Self::new_fungible(OwnerRole::None)
+            .divisibility(DIVISIBILITY_NONE)
+            .track_total_supply(false)
+    }
+
     /// Starts a new builder to create a non-fungible resource with a `NonFungibleIdType::String`
     pub fn new_string_non_fungible<D: NonFungibleData>(
         owner_role: OwnerRole,
@@ -125,6 +208,53 @@ impl ResourceBuilder {
             }),
         )
     }
+
+    /// Starts a new builder to create an NFT collection: a non-fungible resource with a
+    /// `NonFungibleIdType::Integer`, laid out the same way as every other collection.
+    ///
+    /// The name and base URL are set as metadata, along with the field names of `D`. The resource
+    /// also records the order its non-fungibles are minted in, so that they can be enumerated with
+    /// [`minted_non_fungible_ids`](crate::resource::ResourceManagerStub::minted_non_fungible_ids).
+    /// A collection is always created with no initial supply.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// #[derive(ScryptoSbor, NonFungibleData)]
+    /// struct Artwork {
+    ///     pub title: String,
+    /// }
+    ///
+    /// let resource_manager = ResourceBuilder::new_nft_collection::<Artwork>(
+    ///     OwnerRole::None,
+    ///     "Sunsets",
+    ///     UncheckedUrl::of("https://www.example.com/sunsets/"),
+    /// )
+    /// .mint_roles(mint_roles! {
+    ///     minter => rule!(allow_all);
+    ///     minter_updater => rule!(deny_all);
+    /// })
+    /// .create_with_no_initial_supply();
+    /// ```
+    pub fn new_nft_collection<D: NonFungibleData>(
+        owner_role: OwnerRole,
+        name: &str,
+        base_url: UncheckedUrl,
+    ) -> InProgressResourceBuilder<NonFungibleCollectionResourceType<D>> {
+        InProgressResourceBuilder::new(
+            owner_role,
+            NonFungibleCollectionResourceType::new(
+                LocalNonFungibleDataSchema::new_with_self_package_replacement::<D>(
+                    Runtime::package_address(),
+                ),
+                NonFungibleCollectionInit {
+                    name: name.to_string(),
+                    base_url,
+                },
+            ),
+        )
+    }
 }
 
 /// Utility for setting up a new resource, which has building in progress.
@@ -148,6 +278,7 @@ pub struct InProgressResourceBuilder<T: AnyResourceType> {
     resource_roles: T::ResourceRoles,
     metadata_config: Option<ModuleConfig<MetadataInit>>,
     address_reservation: Option<GlobalAddressReservation>,
+    track_total_supply: bool,
 }
 
 impl<T: AnyResourceType> InProgressResourceBuilder<T> {
@@ -157,6 +288,7 @@ impl<T: AnyResourceType> InProgressResourceBuilder<T> {
             resource_type,
             metadata_config: None,
             address_reservation: None,
+            track_total_supply: true,
             resource_roles: T::ResourceRoles::default(),
         }
     }
@@ -171,7 +303,7 @@ pub struct FungibleResourceType {
     divisibility: u8,
 }
 impl AnyResourceType for FungibleResourceType {
-    type ResourceRoles = FungibleResourceRoles;
+    type ResourceRoles = FungibleResourceRolesV2;
 }
 impl Default for FungibleResourceType {
     fn default() -> Self {
@@ -192,7 +324,7 @@ impl<
         S: ScryptoCategorize + ScryptoEncode + ScryptoDecode,
     > AnyResourceType for NonFungibleResourceType<T, D, S>
 {
-    type ResourceRoles = NonFungibleResourceRoles;
+    type ResourceRoles = NonFungibleResourceRolesV2;
 }
 impl<
         T: IsNonFungibleLocalId,
@@ -205,6 +337,20 @@ impl<
     }
 }
 
+pub struct NonFungibleCollectionResourceType<D: NonFungibleData>(
+    FixedEnumVariant<NON_FUNGIBLE_DATA_SCHEMA_VARIANT_LOCAL, LocalNonFungibleDataSchema>,
+    NonFungibleCollectionInit,
+    PhantomData<D>,
+);
+impl<D: NonFungibleData> AnyResourceType for NonFungibleCollectionResourceType<D> {
+    type ResourceRoles = NonFungibleResourceRolesV2;
+}
+impl<D: NonFungibleData> NonFungibleCollectionResourceType<D> {
+    pub fn new(schema: LocalNonFungibleDataSchema, collection: NonFungibleCollectionInit) -> Self {
+        Self(FixedEnumVariant { fields: schema }, collection, PhantomData)
+    }
+}
+
 ////////////////////////////////////////////////////////////
 /// PUBLIC TRAITS AND METHODS
 /// All public methods first - these all need good rust docs
@@ -225,6 +371,26 @@ pub trait SetAddressReservationBuilder: private::CanSetAddressReservation {
 }
 impl<B: private::CanSetAddressReservation> SetAddressReservationBuilder for B {}
 
+pub trait SetTotalSupplyTrackingBuilder: private::CanSetTotalSupplyTracking {
+    /// Sets whether the resource keeps track of its total supply. Tracking is on by default.
+    ///
+    /// Without tracking, minting and burning no longer update a supply counter, and
+    /// [`total_supply`](crate::resource::ResourceManagerStub::total_supply) returns `None`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// let bucket = ResourceBuilder::new_fungible(OwnerRole::None)
+    ///     .track_total_supply(false)
+    ///     .mint_initial_supply(5);
+    /// ```
+    fn track_total_supply(self, track_total_supply: bool) -> Self::OutputBuilder {
+        self.set_track_total_supply(track_total_supply)
+    }
+}
+impl<B: private::CanSetTotalSupplyTracking> SetTotalSupplyTrackingBuilder for B {}
+
 pub trait UpdateAuthBuilder {
     /// Sets the resource to be mintable
     ///
@@ -397,6 +563,57 @@ pub trait UpdateAuthBuilder {
     ///    });
     /// ```
     fn deposit_roles(self, deposit_roles: Option<DepositRoles<RoleDefinition>>) -> Self;
+
+    /// Makes the resource soulbound, so that its vaults can only be withdrawn from or deposited
+    /// into when the transfer exemptor rule is satisfied, on top of the withdrawer and depositor
+    /// roles. Recalling is not affected, so exempting a recovery badge lets an issuer move the
+    /// resource out of a lost account by recalling it and depositing it elsewhere.
+    ///
+    /// * The first parameter is the access rule which exempts a withdrawal or deposit.
+    /// * The second parameter is the mutability / access rule which controls if and how the access rule can be updated.
+    ///
+    /// Unlike other roles, a transfer exemptor left unset does not fall back to the owner and
+    /// exempts nothing. Burning from a vault counts as a withdrawal.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// # let recovery_badge = XRD;
+    /// // Sets the resource to be soulbound, with transfers only possible with a recovery badge.
+    /// ResourceBuilder::new_fungible(OwnerRole::None)
+    ///    .soulbound_roles(soulbound_roles! {
+    ///        transfer_exemptor => rule!(require(recovery_badge));
+    ///        transfer_exemptor_updater => rule!(deny_all);
+    ///    });
+    /// ```
+    fn soulbound_roles(self, soulbound_roles: Option<SoulboundRoles<RoleDefinition>>) -> Self;
+
+    /// Gives the resource a deny list of global entities, such as accounts and components, whose
+    /// vaults reject any further deposits of it. Holders are put on and taken off the list with
+    /// the `deny_holder` and `allow_holder` methods of the resource manager.
+    ///
+    /// * The first parameter is the access rule which allows changing the deny list.
+    /// * The second parameter is the mutability / access rule which controls if and how the access rule can be updated.
+    ///
+    /// Withdrawals from the vaults of a denied holder are not affected, so what it already holds
+    /// can still be recalled or moved out.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// # let compliance_badge = XRD;
+    /// // Sets the resource to have a deny list, which only a compliance badge can change.
+    /// ResourceBuilder::new_fungible(OwnerRole::None)
+    ///    .deny_list_roles(deny_list_roles! {
+    ///        deny_lister => rule!(require(compliance_badge));
+    ///        deny_lister_updater => rule!(deny_all);
+    ///    });
+    /// ```
+    fn deny_list_roles(self, deny_list_roles: Option<DenyListRoles<RoleDefinition>>) -> Self;
 }
 
 impl UpdateAuthBuilder for InProgressResourceBuilder<FungibleResourceType> {
@@ -429,6 +646,16 @@ impl UpdateAuthBuilder for InProgressResourceBuilder<FungibleResourceType> {
         self.resource_roles.deposit_roles = deposit_roles;
         self
     }
+
+    fn soulbound_roles(mut self, soulbound_roles: Option<SoulboundRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.soulbound_roles = soulbound_roles;
+        self
+    }
+
+    fn deny_list_roles(mut self, deny_list_roles: Option<DenyListRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.deny_list_roles = deny_list_roles;
+        self
+    }
 }
 
 impl<
@@ -466,6 +693,60 @@ impl<
         self.resource_roles.deposit_roles = deposit_roles;
         self
     }
+
+    fn soulbound_roles(mut self, soulbound_roles: Option<SoulboundRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.soulbound_roles = soulbound_roles;
+        self
+    }
+
+    fn deny_list_roles(mut self, deny_list_roles: Option<DenyListRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.deny_list_roles = deny_list_roles;
+        self
+    }
+}
+
+impl<D: NonFungibleData> UpdateAuthBuilder
+    for InProgressResourceBuilder<NonFungibleCollectionResourceType<D>>
+{
+    fn mint_roles(mut self, mint_roles: Option<MintRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.mint_roles = mint_roles;
+        self
+    }
+
+    fn burn_roles(mut self, burn_roles: Option<BurnRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.burn_roles = burn_roles;
+        self
+    }
+
+    fn recall_roles(mut self, recall_roles: Option<RecallRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.recall_roles = recall_roles;
+        self
+    }
+
+    fn freeze_roles(mut self, freeze_roles: Option<FreezeRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.freeze_roles = freeze_roles;
+        self
+    }
+
+    fn withdraw_roles(mut self, withdraw_roles: Option<WithdrawRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.withdraw_roles = withdraw_roles;
+        self
+    }
+
+    fn deposit_roles(mut self, deposit_roles: Option<DepositRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.deposit_roles = deposit_roles;
+        self
+    }
+
+    fn soulbound_roles(mut self, soulbound_roles: Option<SoulboundRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.soulbound_roles = soulbound_roles;
+        self
+    }
+
+    fn deny_list_roles(mut self, deny_list_roles: Option<DenyListRoles<RoleDefinition>>) -> Self {
+        self.resource_roles.deny_list_roles = deny_list_roles;
+        self
+    }
 }
 
 impl<
@@ -515,6 +796,65 @@ impl<
         self.resource_roles.non_fungible_data_update_roles = non_fungible_data_update_roles;
         self
     }
+
+    /// Lets the resource charge a royalty on transfers. Once the owner has set a royalty on the
+    /// resource manager, non-fungibles can only be taken out of a vault by paying it, unless the
+    /// royalty exemptor rule is satisfied.
+    ///
+    /// * The first parameter is the access rule which exempts a withdrawal from the royalty, typically met by approved marketplaces.
+    /// * The second parameter is the mutability / access rule which controls if and how the access rule can be updated.
+    ///
+    /// A royalty exemptor left unset does not fall back to the owner and exempts nothing. Burning
+    /// and recalling are never charged.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// # let marketplace_badge = XRD;
+    /// # let resource_address = XRD;
+    ///
+    /// #[derive(ScryptoSbor, NonFungibleData)]
+    /// struct NFData {
+    ///     pub name: String,
+    /// }
+    /// // Exempts the withdrawals made by a marketplace, which can be replaced by the second rule.
+    /// ResourceBuilder::new_integer_non_fungible::<NFData>(OwnerRole::None)
+    ///    .transfer_royalty_roles(transfer_royalty_roles! {
+    ///        royalty_exemptor => rule!(require(marketplace_badge));
+    ///        royalty_exemptor_updater => rule!(require(resource_address));
+    ///    });
+    /// ```
+    pub fn transfer_royalty_roles(
+        mut self,
+        transfer_royalty_roles: Option<TransferRoyaltyRoles<RoleDefinition>>,
+    ) -> Self {
+        self.resource_roles.transfer_royalty_roles = transfer_royalty_roles;
+        self
+    }
+}
+
+impl<D: NonFungibleData> InProgressResourceBuilder<NonFungibleCollectionResourceType<D>> {
+    /// Sets how each non-fungible's mutable data can be updated, in the same way as for any other
+    /// non-fungible resource.
+    pub fn non_fungible_data_update_roles(
+        mut self,
+        non_fungible_data_update_roles: Option<NonFungibleDataUpdateRoles<RoleDefinition>>,
+    ) -> Self {
+        self.resource_roles.non_fungible_data_update_roles = non_fungible_data_update_roles;
+        self
+    }
+
+    /// Lets the collection charge a royalty on transfers, in the same way as for any other
+    /// non-fungible resource.
+    pub fn transfer_royalty_roles(
+        mut self,
+        transfer_royalty_roles: Option<TransferRoyaltyRoles<RoleDefinition>>,
+    ) -> Self {
+        self.resource_roles.transfer_royalty_roles = transfer_royalty_roles;
+        self
+    }
 }
 
 pub trait SetOwnerBuilder: private::CanAddOwner {
@@ -537,6 +877,7 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
             private::CreateWithNoSupply::Fungible {
                 owner_role,
                 divisibility,
+                track_total_supply,
                 resource_roles,
                 metadata,
                 address_reservation,
@@ -546,11 +887,11 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
                 let bytes = ScryptoVmV1Api::blueprint_call(
                     RESOURCE_PACKAGE,
                     FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-                    FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
-                    scrypto_encode(&FungibleResourceManagerCreateInput {
+                    FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_IDENT,
+                    scrypto_encode(&FungibleResourceManagerCreateV2Input {
                         owner_role,
                         divisibility,
-                        track_total_supply: true,
+                        track_total_supply,
                         metadata,
                         resource_roles,
                         address_reservation,
@@ -562,6 +903,7 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
             private::CreateWithNoSupply::NonFungible {
                 owner_role,
                 id_type,
+                track_total_supply,
                 non_fungible_schema,
                 resource_roles,
                 metadata,
@@ -572,11 +914,11 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
                 let bytes = ScryptoVmV1Api::blueprint_call(
                     RESOURCE_PACKAGE,
                     NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-                    NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
-                    scrypto_encode(&NonFungibleResourceManagerCreateGenericInput {
+                    NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_V2_IDENT,
+                    scrypto_encode(&NonFungibleResourceManagerCreateV2GenericInput {
                         owner_role,
                         id_type,
-                        track_total_supply: true,
+                        track_total_supply,
                         non_fungible_schema,
                         resource_roles,
                         metadata,
@@ -586,6 +928,34 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
                 );
                 scrypto_decode(&bytes).unwrap()
             }
+            private::CreateWithNoSupply::NonFungibleCollection {
+                owner_role,
+                id_type,
+                non_fungible_schema,
+                collection,
+                resource_roles,
+                metadata,
+                address_reservation,
+            } => {
+                let metadata = metadata.unwrap_or_else(|| Default::default());
+
+                let bytes = ScryptoVmV1Api::blueprint_call(
+                    RESOURCE_PACKAGE,
+                    NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                    NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_COLLECTION_IDENT,
+                    scrypto_encode(&NonFungibleResourceManagerCreateCollectionGenericInput {
+                        owner_role,
+                        id_type,
+                        non_fungible_schema,
+                        collection,
+                        resource_roles,
+                        metadata,
+                        address_reservation,
+                    })
+                    .unwrap(),
+                );
+                scrypto_decode(&bytes).unwrap()
+            }
         }
     }
 }
@@ -636,10 +1006,10 @@ impl InProgressResourceBuilder<FungibleResourceType> {
         let bytes = ScryptoVmV1Api::blueprint_call(
             RESOURCE_PACKAGE,
             FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
-            scrypto_encode(&FungibleResourceManagerCreateWithInitialSupplyInput {
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
+            scrypto_encode(&FungibleResourceManagerCreateWithInitialSupplyV2Input {
                 owner_role: self.owner_role,
-                track_total_supply: true,
+                track_total_supply: self.track_total_supply,
                 divisibility: self.resource_type.divisibility,
                 resource_roles: self.resource_roles,
                 metadata,
@@ -689,11 +1059,11 @@ impl<D: NonFungibleData, S: ScryptoCategorize + ScryptoEncode + ScryptoDecode>
         let bytes = ScryptoVmV1Api::blueprint_call(
             RESOURCE_PACKAGE,
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
             scrypto_encode(
-                &NonFungibleResourceManagerCreateWithInitialSupplyGenericInput {
+                &NonFungibleResourceManagerCreateWithInitialSupplyV2GenericInput {
                     owner_role: self.owner_role,
-                    track_total_supply: true,
+                    track_total_supply: self.track_total_supply,
                     id_type: StringNonFungibleLocalId::id_type(),
                     non_fungible_schema: self.resource_type.0,
                     resource_roles: self.resource_roles,
@@ -744,11 +1114,11 @@ impl<D: NonFungibleData, S: ScryptoCategorize + ScryptoEncode + ScryptoDecode>
         let bytes = ScryptoVmV1Api::blueprint_call(
             RESOURCE_PACKAGE,
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
             scrypto_encode(
-                &NonFungibleResourceManagerCreateWithInitialSupplyGenericInput {
+                &NonFungibleResourceManagerCreateWithInitialSupplyV2GenericInput {
                     owner_role: self.owner_role,
-                    track_total_supply: true,
+                    track_total_supply: self.track_total_supply,
                     id_type: IntegerNonFungibleLocalId::id_type(),
                     non_fungible_schema: self.resource_type.0,
                     resource_roles: self.resource_roles,
@@ -799,12 +1169,12 @@ impl<D: NonFungibleData, S: ScryptoCategorize + ScryptoEncode + ScryptoDecode>
         let bytes = ScryptoVmV1Api::blueprint_call(
             RESOURCE_PACKAGE,
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_V2_IDENT,
             scrypto_encode(
-                &NonFungibleResourceManagerCreateWithInitialSupplyGenericInput {
+                &NonFungibleResourceManagerCreateWithInitialSupplyV2GenericInput {
                     owner_role: self.owner_role,
                     id_type: BytesNonFungibleLocalId::id_type(),
-                    track_total_supply: true,
+                    track_total_supply: self.track_total_supply,
                     non_fungible_schema: self.resource_type.0,
                     resource_roles: self.resource_roles,
                     metadata,
@@ -858,12 +1228,12 @@ impl<D: NonFungibleData, S: ScryptoCategorize + ScryptoEncode + ScryptoDecode>
         let bytes = ScryptoVmV1Api::blueprint_call(
             RESOURCE_PACKAGE,
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_IDENT,
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_V2_IDENT,
             scrypto_encode(
-                &NonFungibleResourceManagerCreateRuidWithInitialSupplyGenericInput {
+                &NonFungibleResourceManagerCreateRuidWithInitialSupplyV2GenericInput {
                     owner_role: self.owner_role,
                     non_fungible_schema: self.resource_type.0,
-                    track_total_supply: true,
+                    track_total_supply: self.track_total_supply,
                     resource_roles: self.resource_roles,
                     metadata,
                     entries: entries.into_iter().map(|data| (data,)).collect(),
@@ -910,6 +1280,30 @@ impl<T: AnyResourceType> private::CanSetAddressReservation for InProgressResourc
     }
 }
 
+impl private::CanSetTotalSupplyTracking for InProgressResourceBuilder<FungibleResourceType> {
+    type OutputBuilder = Self;
+
+    fn set_track_total_supply(mut self, track_total_supply: bool) -> Self::OutputBuilder {
+        self.track_total_supply = track_total_supply;
+        self
+    }
+}
+
+impl<
+        Y: IsNonFungibleLocalId,
+        D: NonFungibleData,
+        S: ScryptoCategorize + ScryptoEncode + ScryptoDecode,
+    > private::CanSetTotalSupplyTracking
+    for InProgressResourceBuilder<NonFungibleResourceType<Y, D, S>>
+{
+    type OutputBuilder = Self;
+
+    fn set_track_total_supply(mut self, track_total_supply: bool) -> Self::OutputBuilder {
+        self.track_total_supply = track_total_supply;
+        self
+    }
+}
+
 impl private::CanCreateWithNoSupply for InProgressResourceBuilder<FungibleResourceType> {
     type NonFungibleDataSchema = NoNonFungibleDataSchema;
 
@@ -919,6 +1313,7 @@ impl private::CanCreateWithNoSupply for InProgressResourceBuilder<FungibleResour
         private::CreateWithNoSupply::Fungible {
             owner_role: self.owner_role,
             divisibility: self.resource_type.divisibility,
+            track_total_supply: self.track_total_supply,
             resource_roles: self.resource_roles,
             metadata: self.metadata_config,
             address_reservation: self.address_reservation,
@@ -939,6 +1334,7 @@ impl<
         private::CreateWithNoSupply::NonFungible {
             owner_role: self.owner_role,
             id_type: Y::id_type(),
+            track_total_supply: self.track_total_supply,
             non_fungible_schema: self.resource_type.0,
             resource_roles: self.resource_roles,
             metadata: self.metadata_config,
@@ -947,6 +1343,27 @@ impl<
     }
 }
 
+impl<D: NonFungibleData> private::CanCreateWithNoSupply
+    for InProgressResourceBuilder<NonFungibleCollectionResourceType<D>>
+{
+    type NonFungibleDataSchema =
+        FixedEnumVariant<NON_FUNGIBLE_DATA_SCHEMA_VARIANT_LOCAL, LocalNonFungibleDataSchema>;
+
+    fn into_create_with_no_supply_invocation(
+        self,
+    ) -> private::CreateWithNoSupply<Self::NonFungibleDataSchema> {
+        private::CreateWithNoSupply::NonFungibleCollection {
+            owner_role: self.owner_role,
+            id_type: IntegerNonFungibleLocalId::id_type(),
+            non_fungible_schema: self.resource_type.0,
+            collection: self.resource_type.1,
+            resource_roles: self.resource_roles,
+            metadata: self.metadata_config,
+            address_reservation: self.address_reservation,
+        }
+    }
+}
+
 /// This file was experiencing combinatorial explosion - as part of the clean-up, we've used private traits to keep things simple.
 ///
 /// Each public method has essentially one implementation, and one Rust doc (where there weren't clashes due to Rust trait issues -
@@ -975,6 +1392,12 @@ mod private {
         fn set_address(self, address_reservation: GlobalAddressReservation) -> Self::OutputBuilder;
     }
 
+    pub trait CanSetTotalSupplyTracking: Sized {
+        type OutputBuilder;
+
+        fn set_track_total_supply(self, track_total_supply: bool) -> Self::OutputBuilder;
+    }
+
     pub trait CanAddAuth: Sized {
         type OutputBuilder;
 
@@ -1005,15 +1428,26 @@ mod private {
         Fungible {
             owner_role: OwnerRole,
             divisibility: u8,
-            resource_roles: FungibleResourceRoles,
+            track_total_supply: bool,
+            resource_roles: FungibleResourceRolesV2,
             metadata: Option<ModuleConfig<MetadataInit>>,
             address_reservation: Option<GlobalAddressReservation>,
         },
         NonFungible {
+            owner_role: OwnerRole,
+            id_type: NonFungibleIdType,
+            track_total_supply: bool,
+            non_fungible_schema: S,
+            resource_roles: NonFungibleResourceRolesV2,
+            metadata: Option<ModuleConfig<MetadataInit>>,
+            address_reservation: Option<GlobalAddressReservation>,
+        },
+        NonFungibleCollection {
             owner_role: OwnerRole,
             id_type: NonFungibleIdType,
             non_fungible_schema: S,
-            resource_roles: NonFungibleResourceRoles,
+            collection: NonFungibleCollectionInit,
+            resource_roles: NonFungibleResourceRolesV2,
             metadata: Option<ModuleConfig<MetadataInit>>,
             address_reservation: Option<GlobalAddressReservation>,
         },
@@ -83,6 +83,14 @@ impl ResourceManager {
         self.0.set_role(FREEZER_ROLE, access_rule);
     }
 
+    pub fn set_transfer_exemption(&self, access_rule: AccessRule) {
+        self.0.set_role(TRANSFER_EXEMPTOR_ROLE, access_rule);
+    }
+
+    pub fn set_deny_listable(&self, access_rule: AccessRule) {
+        self.0.set_role(DENY_LISTER_ROLE, access_rule);
+    }
+
     pub fn set_updatable_non_fungible_data(&self, access_rule: AccessRule) {
         self.0.set_role(NON_FUNGIBLE_DATA_UPDATER_ROLE, access_rule);
     }
@@ -117,6 +125,16 @@ impl ResourceManager {
         self.0.set_role(FREEZER_UPDATER_ROLE, AccessRule::DenyAll);
     }
 
+    pub fn lock_transfer_exemption(&self) {
+        self.0
+            .set_role(TRANSFER_EXEMPTOR_UPDATER_ROLE, AccessRule::DenyAll);
+    }
+
+    pub fn lock_deny_listable(&self) {
+        self.0
+            .set_role(DENY_LISTER_UPDATER_ROLE, AccessRule::DenyAll);
+    }
+
     pub fn set_updatable_metadata(&self, access_rule: AccessRule) {
         self.0.set_metadata_role(METADATA_SETTER_ROLE, access_rule);
     }
@@ -154,6 +172,13 @@ impl ResourceManagerStub {
         )
     }
 
+    pub fn create_empty_recall_protected_vault(&self) -> Vault {
+        self.call(
+            RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_IDENT,
+            &ResourceManagerCreateEmptyRecallProtectedVaultInput {},
+        )
+    }
+
     pub fn create_empty_bucket(&self) -> Bucket {
         self.call(
             RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_IDENT,
@@ -175,6 +200,39 @@ impl ResourceManagerStub {
         )
     }
 
+    /// Puts a global entity on the deny list of this resource, so that its vaults reject deposits
+    /// of the resource until it is allowed again.
+    ///
+    /// # Panics
+    /// Panics if the resource was created without deny list roles.
+    pub fn deny_holder<A: Into<GlobalAddress>>(&self, holder: A) {
+        self.call(
+            RESOURCE_MANAGER_DENY_HOLDER_IDENT,
+            &ResourceManagerDenyHolderInput {
+                holder: holder.into(),
+            },
+        )
+    }
+
+    /// Takes a global entity off the deny list of this resource.
+    pub fn allow_holder<A: Into<GlobalAddress>>(&self, holder: A) {
+        self.call(
+            RESOURCE_MANAGER_ALLOW_HOLDER_IDENT,
+            &ResourceManagerAllowHolderInput {
+                holder: holder.into(),
+            },
+        )
+    }
+
+    pub fn is_holder_denied<A: Into<GlobalAddress>>(&self, holder: A) -> bool {
+        self.call(
+            RESOURCE_MANAGER_IS_HOLDER_DENIED_IDENT,
+            &ResourceManagerIsHolderDeniedInput {
+                holder: holder.into(),
+            },
+        )
+    }
+
     pub fn non_fungible_exists(&self, id: &NonFungibleLocalId) -> bool {
         self.call(
             NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT,
@@ -182,6 +240,67 @@ impl ResourceManagerStub {
         )
     }
 
+    /// Returns the number of non-fungibles ever minted, including burnt ones, or `None` if this is
+    /// not an NFT collection.
+    pub fn total_minted(&self) -> Option<u64> {
+        self.call(
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_MINTED_IDENT,
+            &NonFungibleResourceManagerGetTotalMintedInput {},
+        )
+    }
+
+    /// Returns up to `limit` ids of an NFT collection in the order they were minted, starting from
+    /// the `start_index`-th.
+    ///
+    /// # Panics
+    /// Panics if this is not an NFT collection.
+    pub fn minted_non_fungible_ids(&self, start_index: u64, limit: u32) -> Vec<NonFungibleLocalId> {
+        self.call(
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_MINTED_IDS_IDENT,
+            &NonFungibleResourceManagerGetMintedIdsInput { start_index, limit },
+        )
+    }
+
+    /// Recounts the total supply of an NFT collection, looking at up to `limit` positions in mint
+    /// order. Returns the new total supply once the recount has finished, or `None` if it needs to
+    /// be called again.
+    ///
+    /// # Panics
+    /// Panics if this is not an NFT collection which tracks its total supply.
+    pub fn recount_total_supply(&self, limit: u32) -> Option<Decimal> {
+        self.call(
+            NON_FUNGIBLE_RESOURCE_MANAGER_RECOUNT_TOTAL_SUPPLY_IDENT,
+            &NonFungibleResourceManagerRecountTotalSupplyInput { limit },
+        )
+    }
+
+    /// Sets the royalty charged for taking this resource's non-fungibles out of a vault, or
+    /// makes transfers free again with `None`.
+    ///
+    /// # Panics
+    /// Panics if the resource was created without transfer royalty roles.
+    pub fn set_transfer_royalty(&self, royalty: Option<TransferRoyaltyConfig>) {
+        self.call(
+            NON_FUNGIBLE_RESOURCE_MANAGER_SET_TRANSFER_ROYALTY_IDENT,
+            &NonFungibleResourceManagerSetTransferRoyaltyInput { royalty },
+        )
+    }
+
+    pub fn transfer_royalty(&self) -> Option<TransferRoyaltyConfig> {
+        self.call(
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_TRANSFER_ROYALTY_IDENT,
+            &NonFungibleResourceManagerGetTransferRoyaltyInput {},
+        )
+    }
+
+    /// Takes all transfer royalties collected in the given resource.
+    pub fn claim_transfer_royalties(&self, resource_address: ResourceAddress) -> Bucket {
+        self.call(
+            NON_FUNGIBLE_RESOURCE_MANAGER_CLAIM_TRANSFER_ROYALTIES_IDENT,
+            &NonFungibleResourceManagerClaimTransferRoyaltiesInput { resource_address },
+        )
+    }
+
     pub fn burn<B: Into<Bucket>>(&self, bucket: B) {
         self.call(
             RESOURCE_MANAGER_BURN_IDENT,
@@ -215,6 +334,39 @@ impl ResourceManagerStub {
         )
     }
 
+    /// Reserves the next `count` integer ids of this resource, to be minted later with
+    /// [`Self::mint_reserved_non_fungible`].
+    ///
+    /// # Panics
+    /// Panics if this is not a mintable resource with integer ids.
+    pub fn reserve_integer_ids(&self, count: u64) -> NonFungibleIdReservation {
+        self.call(
+            NON_FUNGIBLE_RESOURCE_MANAGER_RESERVE_INTEGER_IDS_IDENT,
+            &NonFungibleResourceManagerReserveIntegerIdsInput { count },
+        )
+    }
+
+    /// Mints a non-fungible whose id was reserved earlier.
+    ///
+    /// # Panics
+    /// Panics if the id is not part of the given reservation.
+    pub fn mint_reserved_non_fungible<T: NonFungibleData>(
+        &self,
+        reservation: NonFungibleIdReservation,
+        id: &NonFungibleLocalId,
+        data: T,
+    ) -> Bucket {
+        let mut entries = index_map_new();
+        entries.insert(id.clone(), (data,));
+        self.call(
+            NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RESERVED_IDENT,
+            &NonFungibleResourceManagerMintReservedGenericInput {
+                reservation,
+                entries,
+            },
+        )
+    }
+
     /// Mints ruid non-fungible resources
     pub fn mint_ruid_non_fungible<T: NonFungibleData>(&self, data: T) -> Bucket {
         let mut entries = Vec::new();
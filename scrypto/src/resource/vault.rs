@@ -22,6 +22,8 @@ pub trait ScryptoVault {
 
     fn new(resource_address: ResourceAddress) -> Self;
 
+    fn new_recall_protected(resource_address: ResourceAddress) -> Self;
+
     fn put(&mut self, bucket: Self::BucketType) -> ();
 
     fn amount(&self) -> Decimal;
@@ -49,6 +51,16 @@ pub trait ScryptoVault {
     fn as_non_fungible(&self) -> NonFungibleVault;
 
     fn burn<A: Into<Decimal>>(&mut self, amount: A);
+
+    fn grant_allowance<G: Into<ResourceOrNonFungible>>(
+        &mut self,
+        grantee: G,
+        allowance: WithdrawAllowance,
+    );
+
+    fn revoke_allowance<G: Into<ResourceOrNonFungible>>(&mut self, grantee: G);
+
+    fn allowance<G: Into<ResourceOrNonFungible>>(&self, grantee: G) -> Option<WithdrawAllowance>;
 }
 
 pub trait ScryptoFungibleVault {
@@ -56,6 +68,14 @@ pub trait ScryptoFungibleVault {
 
     fn lock_contingent_fee<A: Into<Decimal>>(&mut self, amount: A);
 
+    fn lock_fee_with_refund<A: Into<Decimal>>(&mut self, amount: A, refund_vault: &FungibleVault);
+
+    fn lock_contingent_fee_with_refund<A: Into<Decimal>>(
+        &mut self,
+        amount: A,
+        refund_vault: &FungibleVault,
+    );
+
     fn create_proof_of_amount<A: Into<Decimal>>(&self, amount: A) -> FungibleProof;
 
     fn authorize_with_amount<A: Into<Decimal>, F: FnOnce() -> O, O>(&self, amount: A, f: F) -> O;
@@ -82,6 +102,18 @@ pub trait ScryptoNonFungibleVault {
         non_fungible_local_ids: &IndexSet<NonFungibleLocalId>,
     ) -> NonFungibleBucket;
 
+    fn take_non_fungibles_matching<T: NonFungibleData, F: Fn(&T) -> bool>(
+        &mut self,
+        limit: u32,
+        predicate: F,
+    ) -> NonFungibleBucket;
+
+    fn take_non_fungibles_with_royalty(
+        &mut self,
+        non_fungible_local_ids: &IndexSet<NonFungibleLocalId>,
+        payment: Bucket,
+    ) -> (NonFungibleBucket, Bucket);
+
     fn burn_non_fungibles(&mut self, non_fungible_local_ids: &IndexSet<NonFungibleLocalId>);
 
     fn create_proof_of_non_fungibles(
@@ -121,6 +153,19 @@ impl ScryptoVault for Vault {
         scrypto_decode(&rtn).unwrap()
     }
 
+    /// Creates an empty vault for a resource which can never be recalled.
+    ///
+    /// # Panics
+    /// Panics if the resource is recallable or its recall rules can still be updated.
+    fn new_recall_protected(resource_address: ResourceAddress) -> Self {
+        let rtn = ScryptoVmV1Api::object_call(
+            resource_address.as_node_id(),
+            RESOURCE_MANAGER_CREATE_EMPTY_RECALL_PROTECTED_VAULT_IDENT,
+            scrypto_encode(&ResourceManagerCreateEmptyRecallProtectedVaultInput {}).unwrap(),
+        );
+        scrypto_decode(&rtn).unwrap()
+    }
+
     fn put(&mut self, bucket: Bucket) -> () {
         let rtn = ScryptoVmV1Api::object_call(
             self.0.as_node_id(),
@@ -211,6 +256,52 @@ impl ScryptoVault for Vault {
         );
         scrypto_decode(&rtn).unwrap()
     }
+
+    /// Allows the holder of a badge to withdraw from this vault, within the given allowance,
+    /// without this vault's withdrawer role.
+    ///
+    /// Any allowance previously granted to the same badge is replaced.
+    fn grant_allowance<G: Into<ResourceOrNonFungible>>(
+        &mut self,
+        grantee: G,
+        allowance: WithdrawAllowance,
+    ) {
+        let rtn = ScryptoVmV1Api::object_call(
+            self.0.as_node_id(),
+            VAULT_GRANT_ALLOWANCE_IDENT,
+            scrypto_encode(&VaultGrantAllowanceInput {
+                grantee: grantee.into(),
+                allowance,
+            })
+            .unwrap(),
+        );
+        scrypto_decode(&rtn).unwrap()
+    }
+
+    fn revoke_allowance<G: Into<ResourceOrNonFungible>>(&mut self, grantee: G) {
+        let rtn = ScryptoVmV1Api::object_call(
+            self.0.as_node_id(),
+            VAULT_REVOKE_ALLOWANCE_IDENT,
+            scrypto_encode(&VaultRevokeAllowanceInput {
+                grantee: grantee.into(),
+            })
+            .unwrap(),
+        );
+        scrypto_decode(&rtn).unwrap()
+    }
+
+    /// Returns what is left of the allowance granted to a badge, if any.
+    fn allowance<G: Into<ResourceOrNonFungible>>(&self, grantee: G) -> Option<WithdrawAllowance> {
+        let rtn = ScryptoVmV1Api::object_call(
+            self.0.as_node_id(),
+            VAULT_GET_ALLOWANCE_IDENT,
+            scrypto_encode(&VaultGetAllowanceInput {
+                grantee: grantee.into(),
+            })
+            .unwrap(),
+        );
+        scrypto_decode(&rtn).unwrap()
+    }
 }
 
 //================
@@ -233,6 +324,13 @@ impl ScryptoVault for FungibleVault {
         Self(Vault::new(resource_address))
     }
 
+    fn new_recall_protected(resource_address: ResourceAddress) -> Self {
+        assert!(resource_address
+            .as_node_id()
+            .is_global_fungible_resource_manager());
+        Self(Vault::new_recall_protected(resource_address))
+    }
+
     fn put(&mut self, bucket: Self::BucketType) -> () {
         self.0.put(bucket.0)
     }
@@ -276,6 +374,22 @@ impl ScryptoVault for FungibleVault {
     fn burn<A: Into<Decimal>>(&mut self, amount: A) {
         self.0.burn(amount)
     }
+
+    fn grant_allowance<G: Into<ResourceOrNonFungible>>(
+        &mut self,
+        grantee: G,
+        allowance: WithdrawAllowance,
+    ) {
+        self.0.grant_allowance(grantee, allowance)
+    }
+
+    fn revoke_allowance<G: Into<ResourceOrNonFungible>>(&mut self, grantee: G) {
+        self.0.revoke_allowance(grantee)
+    }
+
+    fn allowance<G: Into<ResourceOrNonFungible>>(&self, grantee: G) -> Option<WithdrawAllowance> {
+        self.0.allowance(grantee)
+    }
 }
 
 impl ScryptoFungibleVault for FungibleVault {
@@ -310,6 +424,42 @@ impl ScryptoFungibleVault for FungibleVault {
         );
     }
 
+    /// Locks the specified amount as transaction fee, refunding the unused fee to
+    /// `refund_vault` instead of this vault.
+    ///
+    /// The refund vault must be an XRD vault.
+    fn lock_fee_with_refund<A: Into<Decimal>>(&mut self, amount: A, refund_vault: &FungibleVault) {
+        let _rtn = ScryptoVmV1Api::object_call(
+            self.0 .0.as_node_id(),
+            FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT,
+            scrypto_encode(&FungibleVaultLockFeeWithRefundInput {
+                amount: amount.into(),
+                contingent: false,
+                refund_vault: Reference(refund_vault.0 .0 .0),
+            })
+            .unwrap(),
+        );
+    }
+
+    /// Locks the given amount of resource as contingent fee, refunding the unused amount to
+    /// `refund_vault` instead of this vault.
+    fn lock_contingent_fee_with_refund<A: Into<Decimal>>(
+        &mut self,
+        amount: A,
+        refund_vault: &FungibleVault,
+    ) {
+        let _rtn = ScryptoVmV1Api::object_call(
+            self.0 .0.as_node_id(),
+            FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT,
+            scrypto_encode(&FungibleVaultLockFeeWithRefundInput {
+                amount: amount.into(),
+                contingent: true,
+                refund_vault: Reference(refund_vault.0 .0 .0),
+            })
+            .unwrap(),
+        );
+    }
+
     fn create_proof_of_amount<A: Into<Decimal>>(&self, amount: A) -> FungibleProof {
         let rtn = ScryptoVmV1Api::object_call(
             self.0 .0.as_node_id(),
@@ -323,12 +473,7 @@ impl ScryptoFungibleVault for FungibleVault {
     }
 
     fn authorize_with_amount<A: Into<Decimal>, F: FnOnce() -> O, O>(&self, amount: A, f: F) -> O {
-        LocalAuthZone::push(self.create_proof_of_amount(amount));
-        let output = f();
-        LocalAuthZone::pop()
-            .expect("Authorized closure changed auth zone proof stack")
-            .drop();
-        output
+        LocalAuthZone::with_proof(self.create_proof_of_amount(amount), f)
     }
 }
 
@@ -352,6 +497,13 @@ impl ScryptoVault for NonFungibleVault {
         Self(Vault::new(resource_address))
     }
 
+    fn new_recall_protected(resource_address: ResourceAddress) -> Self {
+        assert!(resource_address
+            .as_node_id()
+            .is_global_non_fungible_resource_manager());
+        Self(Vault::new_recall_protected(resource_address))
+    }
+
     fn put(&mut self, bucket: Self::BucketType) -> () {
         self.0.put(bucket.0)
     }
@@ -395,6 +547,22 @@ impl ScryptoVault for NonFungibleVault {
     fn burn<A: Into<Decimal>>(&mut self, amount: A) {
         self.0.burn(amount)
     }
+
+    fn grant_allowance<G: Into<ResourceOrNonFungible>>(
+        &mut self,
+        grantee: G,
+        allowance: WithdrawAllowance,
+    ) {
+        self.0.grant_allowance(grantee, allowance)
+    }
+
+    fn revoke_allowance<G: Into<ResourceOrNonFungible>>(&mut self, grantee: G) {
+        self.0.revoke_allowance(grantee)
+    }
+
+    fn allowance<G: Into<ResourceOrNonFungible>>(&self, grantee: G) -> Option<WithdrawAllowance> {
+        self.0.allowance(grantee)
+    }
 }
 
 impl ScryptoNonFungibleVault for NonFungibleVault {
@@ -479,6 +647,47 @@ impl ScryptoNonFungibleVault for NonFungibleVault {
         scrypto_decode(&rtn).unwrap()
     }
 
+    /// Takes the non-fungibles whose data matches the given predicate, out of the first `limit`
+    /// non-fungibles in this vault.
+    ///
+    /// # Panics
+    /// Panics if this is not a non-fungible vault or the data isn't of type `T`.
+    fn take_non_fungibles_matching<T: NonFungibleData, F: Fn(&T) -> bool>(
+        &mut self,
+        limit: u32,
+        predicate: F,
+    ) -> NonFungibleBucket {
+        let non_fungible_local_ids = self
+            .non_fungibles::<T>(limit)
+            .into_iter()
+            .filter(|non_fungible| predicate(&non_fungible.data()))
+            .map(|non_fungible| non_fungible.local_id().clone())
+            .collect();
+        self.take_non_fungibles(&non_fungible_local_ids)
+    }
+
+    /// Takes specific non-fungibles, paying the resource's transfer royalty out of `payment`.
+    /// Returns the non-fungibles along with the change.
+    ///
+    /// # Panics
+    /// Panics if the payment is in the wrong resource or does not cover the royalty.
+    fn take_non_fungibles_with_royalty(
+        &mut self,
+        non_fungible_local_ids: &IndexSet<NonFungibleLocalId>,
+        payment: Bucket,
+    ) -> (NonFungibleBucket, Bucket) {
+        let rtn = ScryptoVmV1Api::object_call(
+            self.0 .0.as_node_id(),
+            NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_WITH_ROYALTY_IDENT,
+            scrypto_encode(&NonFungibleVaultTakeNonFungiblesWithRoyaltyInput {
+                non_fungible_local_ids: non_fungible_local_ids.clone(),
+                payment,
+            })
+            .unwrap(),
+        );
+        scrypto_decode(&rtn).unwrap()
+    }
+
     fn create_proof_of_non_fungibles(
         &self,
         ids: &IndexSet<NonFungibleLocalId>,
@@ -509,11 +718,80 @@ impl ScryptoNonFungibleVault for NonFungibleVault {
         non_fungible_local_ids: &IndexSet<NonFungibleLocalId>,
         f: F,
     ) -> O {
-        LocalAuthZone::push(self.create_proof_of_non_fungibles(non_fungible_local_ids));
-        let output = f();
-        LocalAuthZone::pop()
-            .expect("Authorized closure changed auth zone proof stack")
-            .drop();
-        output
+        LocalAuthZone::with_proof(
+            self.create_proof_of_non_fungibles(non_fungible_local_ids),
+            f,
+        )
+    }
+}
+
+//===============
+// Direct access
+//===============
+
+/// A vault held by another component, addressed by its internal address, on which the direct
+/// access methods guarded by the roles of its resource can be called.
+///
+/// This lets an issuer recall or freeze its resource wherever it is held, without the holding
+/// component taking part. The calls fail unless the resource is recallable or freezable and the
+/// caller satisfies the `recaller` or `freezer` role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ScryptoSbor)]
+#[sbor(transparent)]
+pub struct DirectAccessVault(pub InternalAddress);
+
+impl DirectAccessVault {
+    /// Takes the given amount out of the vault.
+    pub fn recall<A: Into<Decimal>>(&self, amount: A) -> Bucket {
+        let rtn = ScryptoVmV1Api::object_call_direct(
+            self.0.as_node_id(),
+            VAULT_RECALL_IDENT,
+            scrypto_encode(&VaultRecallInput {
+                amount: amount.into(),
+            })
+            .unwrap(),
+        );
+        scrypto_decode(&rtn).unwrap()
+    }
+
+    /// Takes the given non-fungibles out of the vault, which must be a non-fungible vault.
+    pub fn recall_non_fungibles(
+        &self,
+        non_fungible_local_ids: &IndexSet<NonFungibleLocalId>,
+    ) -> NonFungibleBucket {
+        let rtn = ScryptoVmV1Api::object_call_direct(
+            self.0.as_node_id(),
+            NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT,
+            scrypto_encode(&NonFungibleVaultRecallNonFungiblesInput {
+                non_fungible_local_ids: non_fungible_local_ids.clone(),
+            })
+            .unwrap(),
+        );
+        NonFungibleBucket(scrypto_decode(&rtn).unwrap())
+    }
+
+    /// Freezes the given operations on the vault.
+    pub fn freeze(&self, to_freeze: VaultFreezeFlags) {
+        let rtn = ScryptoVmV1Api::object_call_direct(
+            self.0.as_node_id(),
+            VAULT_FREEZE_IDENT,
+            scrypto_encode(&VaultFreezeInput { to_freeze }).unwrap(),
+        );
+        scrypto_decode(&rtn).unwrap()
+    }
+
+    /// Unfreezes the given operations on the vault.
+    pub fn unfreeze(&self, to_unfreeze: VaultFreezeFlags) {
+        let rtn = ScryptoVmV1Api::object_call_direct(
+            self.0.as_node_id(),
+            VAULT_UNFREEZE_IDENT,
+            scrypto_encode(&VaultUnfreezeInput { to_unfreeze }).unwrap(),
+        );
+        scrypto_decode(&rtn).unwrap()
+    }
+}
+
+impl From<InternalAddress> for DirectAccessVault {
+    fn from(address: InternalAddress) -> Self {
+        Self(address)
     }
 }
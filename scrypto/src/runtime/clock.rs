@@ -1,5 +1,5 @@
 use radix_engine_interface::blueprints::consensus_manager::{
-    ConsensusManagerCompareCurrentTimeInputV2, ConsensusManagerGetCurrentTimeInputV2,
+    ConsensusManagerCompareCurrentTimeInputV3, ConsensusManagerGetCurrentTimeInputV3,
     TimePrecision, CONSENSUS_MANAGER_COMPARE_CURRENT_TIME_IDENT,
     CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT,
 };
@@ -24,12 +24,17 @@ impl Clock {
         Self::current_time(TimePrecision::Minute)
     }
 
-    /// Returns the current timestamp (in seconds), rounded down to the specified precision
+    /// Returns the current timestamp (in seconds), rounded to the specified precision.
+    ///
+    /// `TimePrecision::Minute` and `TimePrecision::Second` round down, while the `Rounded*`
+    /// precisions round the proposer's millisecond timestamp with the given [`TimeRoundingMode`].
+    ///
+    /// [`TimeRoundingMode`]: radix_engine_interface::blueprints::consensus_manager::TimeRoundingMode
     pub fn current_time(precision: TimePrecision) -> Instant {
         let rtn = ScryptoVmV1Api::object_call(
             CONSENSUS_MANAGER.as_node_id(),
             CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT,
-            scrypto_encode(&ConsensusManagerGetCurrentTimeInputV2 { precision }).unwrap(),
+            scrypto_encode(&ConsensusManagerGetCurrentTimeInputV3 { precision }).unwrap(),
         );
         scrypto_decode(&rtn).unwrap()
     }
@@ -58,9 +63,9 @@ impl Clock {
         Self::current_time_comparison(instant, precision, TimeComparisonOperator::Gte)
     }
 
-    /// Returns true if current time, rounded down to a given precision,
+    /// Returns true if current time, rounded to a given precision,
     /// matches the given comparison operator against
-    /// the specified instant (also rounded down to a given precision), false otherwise.
+    /// the specified instant (rounded in the same way), false otherwise.
     pub fn current_time_comparison(
         instant: Instant,
         precision: TimePrecision,
@@ -69,7 +74,7 @@ impl Clock {
         let rtn = ScryptoVmV1Api::object_call(
             CONSENSUS_MANAGER.as_node_id(),
             CONSENSUS_MANAGER_COMPARE_CURRENT_TIME_IDENT,
-            scrypto_encode(&ConsensusManagerCompareCurrentTimeInputV2 {
+            scrypto_encode(&ConsensusManagerCompareCurrentTimeInputV3 {
                 instant,
                 precision,
                 operator,
@@ -1,13 +1,12 @@
 use radix_engine_interface::api::field_api::LockFlags;
 use radix_engine_interface::api::ACTOR_STATE_SELF;
-use radix_engine_interface::data::scrypto::{
-    scrypto_decode, scrypto_encode, ScryptoDecode, ScryptoEncode,
-};
+use radix_engine_interface::data::scrypto::{scrypto_encode, ScryptoEncode};
 use radix_engine_interface::types::*;
 use sbor::rust::fmt;
 use sbor::rust::marker::PhantomData;
 use sbor::rust::ops::{Deref, DerefMut};
 use sbor::rust::prelude::*;
+use scrypto::component::ComponentState;
 use scrypto::engine::scrypto_env::ScryptoVmV1Api;
 
 pub struct DataRef<V: ScryptoEncode> {
@@ -53,6 +52,7 @@ pub struct DataRefMut<V: ScryptoEncode> {
     lock_handle: SubstateHandle,
     origin: DataOrigin,
     value: V,
+    encode_state: fn(&V) -> Vec<u8>,
 }
 
 impl<V: fmt::Display + ScryptoEncode> fmt::Display for DataRefMut<V> {
@@ -67,6 +67,7 @@ impl<V: ScryptoEncode> DataRefMut<V> {
             lock_handle,
             origin,
             value,
+            encode_state: |value| scrypto_encode(value).unwrap(),
         }
     }
 }
@@ -75,7 +76,7 @@ impl<V: ScryptoEncode> Drop for DataRefMut<V> {
     fn drop(&mut self) {
         let substate = match &self.origin {
             DataOrigin::KeyValueStoreEntry => scrypto_encode(&Some(&self.value)).unwrap(),
-            DataOrigin::ComponentState => scrypto_encode(&self.value).unwrap(),
+            DataOrigin::ComponentState => (self.encode_state)(&self.value),
         };
         ScryptoVmV1Api::field_entry_write(self.lock_handle, substate);
         ScryptoVmV1Api::field_entry_close(self.lock_handle);
@@ -96,11 +97,11 @@ impl<V: ScryptoEncode> DerefMut for DataRefMut<V> {
     }
 }
 
-pub struct ComponentStatePointer<V: 'static + ScryptoEncode + ScryptoDecode> {
+pub struct ComponentStatePointer<V: 'static + ComponentState> {
     phantom_data: PhantomData<V>,
 }
 
-impl<V: 'static + ScryptoEncode + ScryptoDecode> ComponentStatePointer<V> {
+impl<V: 'static + ComponentState> ComponentStatePointer<V> {
     pub fn new() -> Self {
         Self {
             phantom_data: PhantomData,
@@ -111,7 +112,7 @@ impl<V: 'static + ScryptoEncode + ScryptoDecode> ComponentStatePointer<V> {
         let lock_handle =
             ScryptoVmV1Api::actor_open_field(ACTOR_STATE_SELF, 0u8, LockFlags::read_only());
         let raw_substate = ScryptoVmV1Api::field_entry_read(lock_handle);
-        let value: V = scrypto_decode(&raw_substate).unwrap();
+        let value = V::decode_state(&raw_substate);
         DataRef { lock_handle, value }
     }
 
@@ -119,11 +120,12 @@ impl<V: 'static + ScryptoEncode + ScryptoDecode> ComponentStatePointer<V> {
         let lock_handle =
             ScryptoVmV1Api::actor_open_field(ACTOR_STATE_SELF, 0u8, LockFlags::MUTABLE);
         let raw_substate = ScryptoVmV1Api::field_entry_read(lock_handle);
-        let value: V = scrypto_decode(&raw_substate).unwrap();
+        let value = V::decode_state(&raw_substate);
         DataRefMut {
             lock_handle,
             origin: DataOrigin::ComponentState,
             value,
+            encode_state: V::encode_state,
         }
     }
 }
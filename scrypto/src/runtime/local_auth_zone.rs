@@ -7,7 +7,7 @@ use radix_engine_interface::*;
 use sbor::rust::collections::IndexSet;
 use scrypto::engine::scrypto_env::ScryptoVmV1Api;
 
-use crate::resource::ScryptoAuthZone;
+use crate::resource::{ScryptoAuthZone, ScryptoUncheckedProof};
 
 /// Represents the auth zone, which is used by system for checking
 /// if this component is allowed to
@@ -28,6 +28,22 @@ impl LocalAuthZone {
         AuthZoneRef(node_id).pop()
     }
 
+    /// Pushes a proof onto the auth zone, returning a guard which pops and drops it once the
+    /// guard goes out of scope.
+    pub fn push_scoped<P: Into<Proof>>(proof: P) -> ScopedAuthZoneProof {
+        let proof: Proof = proof.into();
+        let proof_id = proof.0 .0;
+        LocalAuthZone::push(proof);
+        ScopedAuthZoneProof { proof_id }
+    }
+
+    /// Runs the closure with the proof on the auth zone. The proof is popped and dropped however
+    /// the closure returns, so it can't be used to authorize any later call.
+    pub fn with_proof<P: Into<Proof>, F: FnOnce() -> O, O>(proof: P, f: F) -> O {
+        let _scoped_proof = LocalAuthZone::push_scoped(proof);
+        f()
+    }
+
     pub fn create_proof_of_amount<A: Into<Decimal>>(
         amount: A,
         resource_address: ResourceAddress,
@@ -64,3 +80,23 @@ impl LocalAuthZone {
         AuthZoneRef(node_id).drop_regular_proofs()
     }
 }
+
+/// A proof pushed onto the auth zone by [`LocalAuthZone::push_scoped`], which is popped and
+/// dropped when this is dropped.
+///
+/// Panics on drop if the proof is no longer on top of the auth zone.
+#[must_use]
+pub struct ScopedAuthZoneProof {
+    proof_id: NodeId,
+}
+
+impl Drop for ScopedAuthZoneProof {
+    fn drop(&mut self) {
+        let proof = LocalAuthZone::pop().expect("Scoped proof was removed from the auth zone");
+        assert_eq!(
+            proof.0 .0, self.proof_id,
+            "Scoped proof is not on top of the auth zone"
+        );
+        proof.drop();
+    }
+}
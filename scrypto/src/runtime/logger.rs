@@ -1,6 +1,7 @@
 use crate::engine::scrypto_env::ScryptoVmV1Api;
-use radix_engine_interface::types::Level;
+use radix_engine_interface::types::{Level, LogRecord};
 use sbor::rust::string::String;
+use sbor::rust::vec::Vec;
 
 /// A utility for logging messages.
 #[derive(Debug)]
@@ -9,26 +10,36 @@ pub struct Logger {}
 impl Logger {
     /// Emits a TRACE message.
     pub fn trace(message: String) {
-        ScryptoVmV1Api::sys_log(Level::Trace, message);
+        Self::log(Level::Trace, message, Vec::new());
     }
 
     /// Emits a DEBUG message.
     pub fn debug(message: String) {
-        ScryptoVmV1Api::sys_log(Level::Debug, message);
+        Self::log(Level::Debug, message, Vec::new());
     }
 
     /// Emits an INFO message.
     pub fn info(message: String) {
-        ScryptoVmV1Api::sys_log(Level::Info, message);
+        Self::log(Level::Info, message, Vec::new());
     }
 
     /// Emits a WARN message.
     pub fn warn(message: String) {
-        ScryptoVmV1Api::sys_log(Level::Warn, message);
+        Self::log(Level::Warn, message, Vec::new());
     }
 
     /// Emits an ERROR message.
     pub fn error(message: String) {
-        ScryptoVmV1Api::sys_log(Level::Error, message);
+        Self::log(Level::Error, message, Vec::new());
+    }
+
+    /// Emits a message along with key-value fields, which are kept apart from the message in
+    /// the transaction receipt.
+    pub fn log(level: Level, message: String, fields: Vec<(String, String)>) {
+        ScryptoVmV1Api::sys_log_record(LogRecord {
+            level,
+            message,
+            fields,
+        });
     }
 }
@@ -2,6 +2,7 @@ mod clock;
 mod data;
 mod local_auth_zone;
 mod logger;
+mod rate_limit;
 mod runtime;
 
 pub use clock::*;
@@ -9,4 +10,5 @@ pub use data::*;
 pub use local_auth_zone::*;
 pub use logger::Logger;
 pub use radix_engine_interface::data::scrypto::model::*;
+pub use rate_limit::*;
 pub use runtime::*;
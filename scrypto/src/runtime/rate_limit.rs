@@ -0,0 +1,34 @@
+use radix_engine_interface::api::actor_api::RateLimitScope;
+
+/// A rate limit of a blueprint, checked with [`Runtime::rate_limit_check`].
+///
+/// Rate limits are usually declared as constants next to the blueprint:
+///
+/// ```ignore
+/// const CLAIM_LIMIT: RateLimit = RateLimit::per_caller("claim");
+/// ```
+///
+/// [`Runtime::rate_limit_check`]: crate::runtime::Runtime::rate_limit_check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub name: &'static str,
+    pub scope: RateLimitScope,
+}
+
+impl RateLimit {
+    /// A rate limit shared by all callers of the component
+    pub const fn global(name: &'static str) -> Self {
+        Self {
+            name,
+            scope: RateLimitScope::Global,
+        }
+    }
+
+    /// A rate limit counted separately for each global caller of the component
+    pub const fn per_caller(name: &'static str) -> Self {
+        Self {
+            name,
+            scope: RateLimitScope::PerCaller,
+        }
+    }
+}
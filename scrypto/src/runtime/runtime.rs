@@ -1,7 +1,8 @@
 use crate::component::ObjectStubHandle;
 use crate::engine::wasm_api::{addr, copy_buffer};
 use crate::prelude::{AnyComponent, Global};
-use radix_engine_common::math::Decimal;
+use crate::runtime::RateLimit;
+use radix_engine_common::math::{CheckedMul, Decimal};
 use radix_engine_common::prelude::RESOURCE_PACKAGE;
 use radix_engine_common::types::GlobalAddressReservation;
 use radix_engine_interface::api::actor_api::EventFlags;
@@ -9,14 +10,15 @@ use radix_engine_interface::api::*;
 use radix_engine_interface::blueprints::consensus_manager::{
     ConsensusManagerGetCurrentEpochInput, CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT,
 };
+use radix_engine_interface::blueprints::package::BlueprintInterface;
 use radix_engine_interface::blueprints::resource::{
-    AccessRule, AuthZoneAssertAccessRuleInput, NonFungibleGlobalId,
+    AccessRule, AuthZoneAssertAccessRuleInput, FromPublicKey, NonFungibleGlobalId,
     AUTH_ZONE_ASSERT_ACCESS_RULE_IDENT,
 };
 use radix_engine_interface::constants::CONSENSUS_MANAGER;
-use radix_engine_interface::crypto::Hash;
+use radix_engine_interface::crypto::{Hash, IsPublicKeyHash};
 use radix_engine_interface::data::scrypto::{
-    scrypto_decode, scrypto_encode, ScryptoDescribe, ScryptoEncode,
+    scrypto_decode, scrypto_encode, ScryptoDescribe, ScryptoEncode, VersionedScryptoSchema,
 };
 use radix_engine_interface::prelude::NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT;
 use radix_engine_interface::traits::ScryptoEvent;
@@ -90,6 +92,37 @@ impl Runtime {
         NonFungibleGlobalId::package_of_direct_caller_badge(Runtime::package_address())
     }
 
+    /// Returns the blueprint which the global object at the given address is an instance of.
+    pub fn get_blueprint_id<A: Into<GlobalAddress>>(address: A) -> BlueprintId {
+        ScryptoVmV1Api::object_get_blueprint_id(address.into().as_node_id())
+    }
+
+    /// Returns the interface of the blueprint of the global object at the given address.
+    ///
+    /// The interface lists every function and method of the blueprint along with the schema
+    /// hashes of their inputs and outputs, which is enough to check that a component is an
+    /// instance of an expected blueprint before calling into it.
+    pub fn get_blueprint_interface<A: Into<GlobalAddress>>(address: A) -> BlueprintInterface {
+        let blueprint_id = Self::get_blueprint_id(address);
+        ScryptoVmV1Api::blueprint_get_interface(
+            blueprint_id.package_address,
+            blueprint_id.blueprint_name.as_str(),
+        )
+    }
+
+    /// Returns the schema of an event of a blueprint, along with the id of the event type within
+    /// that schema.
+    ///
+    /// This allows a component to validate or decode events of a blueprint which is not known
+    /// at compile time, such as events passed on by a relay.
+    pub fn get_event_schema(
+        package_address: PackageAddress,
+        blueprint_name: &str,
+        event_name: &str,
+    ) -> (VersionedScryptoSchema, ScopedTypeId) {
+        ScryptoVmV1Api::blueprint_get_event_schema(package_address, blueprint_name, event_name)
+    }
+
     /// Emits an application event
     pub fn emit_event<T: ScryptoEncode + ScryptoDescribe + ScryptoEvent>(event: T) {
         ScryptoVmV1Api::actor_emit_event(
@@ -99,6 +132,49 @@ impl Runtime {
         );
     }
 
+    /// Subscribes the current component to events of type `T` emitted by a global object.
+    ///
+    /// Once each method of the emitter which emits the event returns, the given hook method is
+    /// called with an [`EventNotification`]. The owner role of the emitter must be present in
+    /// the auth zone of the current component.
+    pub fn subscribe_to_event<T: ScryptoEvent, A: Into<GlobalAddress>>(
+        emitter: A,
+        hook_method: &str,
+    ) {
+        ScryptoVmV1Api::actor_subscribe_to_event(emitter.into(), T::EVENT_NAME, hook_method);
+    }
+
+    /// Removes the subscription of the current component to events of type `T`.
+    pub fn unsubscribe_from_event<T: ScryptoEvent, A: Into<GlobalAddress>>(emitter: A) {
+        ScryptoVmV1Api::actor_unsubscribe_from_event(emitter.into(), T::EVENT_NAME);
+    }
+
+    /// Returns the number of substates and total bytes persisted under the current component.
+    ///
+    /// The size is accounted for when a transaction is committed, so changes made by the
+    /// current transaction are not included.
+    pub fn state_size() -> NodeStateSize {
+        ScryptoVmV1Api::actor_get_state_size()
+    }
+
+    /// Counts a call against a rate limit of the current component, returning false if `max`
+    /// calls have already been counted within the last `window_epochs` epochs.
+    ///
+    /// Calls which are refused are not counted, and the counts are kept by the system, so a
+    /// blueprint only has to decide what to do once the limit is reached:
+    ///
+    /// ```ignore
+    /// assert!(Runtime::rate_limit_check(CLAIM_LIMIT, 3, 10), "Too many claims");
+    /// ```
+    pub fn rate_limit_check(rate_limit: RateLimit, max: u32, window_epochs: u64) -> bool {
+        ScryptoVmV1Api::actor_rate_limit_check(
+            rate_limit.name,
+            rate_limit.scope,
+            max,
+            window_epochs,
+        )
+    }
+
     pub fn assert_access_rule(rule: AccessRule) {
         let object_id = ScryptoVmV1Api::actor_get_object_id(ACTOR_REF_AUTH_ZONE);
         ScryptoVmV1Api::object_call(
@@ -113,6 +189,17 @@ impl Runtime {
         ScryptoVmV1Api::sys_get_transaction_hash()
     }
 
+    /// Returns the virtual signature badges of the keys which signed the transaction.
+    pub fn signatories() -> Vec<NonFungibleGlobalId> {
+        ScryptoVmV1Api::sys_get_transaction_signatories()
+    }
+
+    /// Returns whether the transaction was signed by the key with the given public key hash.
+    pub fn is_signed_by<P: IsPublicKeyHash>(public_key_hash: P) -> bool {
+        let signatory = NonFungibleGlobalId::from_public_key_hash(public_key_hash);
+        Self::signatories().contains(&signatory)
+    }
+
     /// Returns the transaction hash.
     pub fn generate_ruid() -> [u8; 32] {
         ScryptoVmV1Api::sys_generate_ruid()
@@ -127,6 +214,15 @@ impl Runtime {
         loop {}
     }
 
+    /// Fails the transaction with the given error, which is recorded in the receipt as is.
+    ///
+    /// Unlike [`Runtime::panic`], this lets clients decode the failure into the application's
+    /// own error type.
+    pub fn abort<E: ScryptoEncode>(error: E) -> ! {
+        ScryptoVmV1Api::sys_abort(scrypto_encode(&error).unwrap());
+        loop {}
+    }
+
     /// Returns the current epoch
     pub fn current_epoch() -> Epoch {
         let rtn = ScryptoVmV1Api::object_call(
@@ -165,4 +261,21 @@ impl Runtime {
     pub fn get_fee_balance() -> Decimal {
         ScryptoVmV1Api::costing_get_fee_balance()
     }
+
+    /// Returns the execution cost units consumed so far in this transaction, which together with
+    /// [`Self::get_execution_cost_unit_limit`] tells how much execution budget is left.
+    pub fn get_execution_cost_units_consumed() -> u32 {
+        ScryptoVmV1Api::costing_get_execution_cost_units_consumed()
+    }
+
+    /// Converts a royalty amount to XRD, at the USD price used by the current transaction.
+    pub fn royalty_amount_in_xrd(royalty_amount: &RoyaltyAmount) -> Decimal {
+        match royalty_amount {
+            RoyaltyAmount::Free => Decimal::ZERO,
+            RoyaltyAmount::Xrd(amount) => *amount,
+            RoyaltyAmount::Usd(amount) => amount
+                .checked_mul(Self::get_usd_price())
+                .expect("Royalty amount overflows when converted to XRD"),
+        }
+    }
 }
@@ -106,18 +106,24 @@ impl PreparedRoundUpdateTransactionV1 {
                 intent_hash: TransactionIntentHash::NotToCheck {
                     intent_hash: self.summary.hash,
                 },
+                subintent_hashes: vec![],
                 epoch_range: None,
                 payload_size: 0,
                 num_of_signature_validations: 0,
                 auth_zone_params: AuthZoneParams {
                     initial_proofs: btreeset!(AuthAddresses::validator_role()),
+                    signatories: BTreeSet::new(),
                     virtual_resources: BTreeSet::new(),
+                    virtual_resource_amounts: BTreeMap::new(),
+                    subintent_initial_proofs: vec![],
                 },
                 costing_parameters: TransactionCostingParameters {
                     tip_percentage: 0,
                     free_credit_in_xrd: Decimal::ZERO,
                 },
                 pre_allocated_addresses: vec![],
+                worktop_leftovers_recipient: None,
+                message: None,
             },
         )
     }
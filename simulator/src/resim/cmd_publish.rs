@@ -188,6 +188,7 @@ impl Publish {
                     ),
                 );
 
+                let mut post_conditions = blueprint_definition.schema.functions.post_conditions;
                 for (function, setup) in blueprint_definition.schema.functions.functions {
                     functions.insert(
                         function.clone(),
@@ -195,6 +196,9 @@ impl Publish {
                             receiver: setup.receiver,
                             input: BlueprintPayloadDef::from_type_ref(setup.input, schema_hash),
                             output: BlueprintPayloadDef::from_type_ref(setup.output, schema_hash),
+                            post_conditions: post_conditions
+                                .swap_remove(&function)
+                                .unwrap_or_default(),
                         },
                     );
                     let export = PackageExport {
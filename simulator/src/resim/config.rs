@@ -70,6 +70,11 @@ impl SimulatorEnvironment {
             let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
             self.db.commit(&db_updates);
         }
+        {
+            let state_updates = generate_vm_boot_cuttlefish_minor_version_state_updates();
+            let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+            self.db.commit(&db_updates);
+        }
     }
 }
 
@@ -0,0 +1,247 @@
+use crate::internal_prelude::*;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::access_controller::*;
+
+pub struct AccessControllerScenarioConfig {
+    pub primary_account: VirtualAccount,
+    pub recovery_account: VirtualAccount,
+    pub confirmation_account: VirtualAccount,
+    pub recovered_primary_account: VirtualAccount,
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+}
+
+impl Default for AccessControllerScenarioConfig {
+    fn default() -> Self {
+        Self {
+            primary_account: secp256k1_account_1(),
+            recovery_account: secp256k1_account_2(),
+            confirmation_account: secp256k1_account_3(),
+            recovered_primary_account: ed25519_account_3(),
+            timed_recovery_delay_in_minutes: Some(10),
+        }
+    }
+}
+
+impl AccessControllerScenarioConfig {
+    fn signature_rule(account: &VirtualAccount) -> AccessRule {
+        rule!(require(NonFungibleGlobalId::from_public_key(
+            &account.public_key
+        )))
+    }
+
+    fn initial_rule_set(&self) -> RuleSet {
+        RuleSet {
+            primary_role: Self::signature_rule(&self.primary_account),
+            recovery_role: Self::signature_rule(&self.recovery_account),
+            confirmation_role: Self::signature_rule(&self.confirmation_account),
+        }
+    }
+
+    fn recovered_rule_set(&self) -> RuleSet {
+        RuleSet {
+            primary_role: Self::signature_rule(&self.recovered_primary_account),
+            ..self.initial_rule_set()
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct AccessControllerScenarioState {
+    controlled_badge: State<ResourceAddress>,
+    access_controller: State<ComponentAddress>,
+    recovery_badge: State<ResourceAddress>,
+}
+
+pub struct AccessControllerScenarioCreator;
+
+impl ScenarioCreator for AccessControllerScenarioCreator {
+    type Config = AccessControllerScenarioConfig;
+    type State = AccessControllerScenarioState;
+
+    fn create_with_config_and_state(
+        core: ScenarioCore,
+        config: Self::Config,
+        start_state: Self::State,
+    ) -> Box<dyn ScenarioInstance> {
+        let metadata = ScenarioMetadata {
+            logical_name: "access_controller",
+        };
+
+        #[allow(unused_variables)]
+        ScenarioBuilder::new(core, metadata, config, start_state)
+            .successful_transaction_with_result_handler(
+                |core, config, state| {
+                    core.next_transaction_with_faucet_lock_fee(
+                        "access-controller-create-controlled-badge",
+                        |builder| {
+                            builder
+                                .create_fungible_resource(
+                                    OwnerRole::None,
+                                    true,
+                                    0,
+                                    FungibleResourceRoles::default(),
+                                    metadata! {
+                                        init {
+                                            "name" => "Controlled Badge".to_owned(), locked;
+                                        }
+                                    },
+                                    Some(dec!("1")),
+                                )
+                                .try_deposit_entire_worktop_or_abort(
+                                    config.primary_account.address,
+                                    None,
+                                )
+                        },
+                        vec![],
+                    )
+                },
+                |core, config, state, result| {
+                    state
+                        .controlled_badge
+                        .set(result.new_resource_addresses()[0]);
+                    Ok(())
+                },
+            )
+            .successful_transaction_with_result_handler(
+                |core, config, state| {
+                    let controlled_badge = state.controlled_badge.get()?;
+                    core.next_transaction_with_faucet_lock_fee(
+                        "access-controller-create",
+                        |builder| {
+                            let rule_set = config.initial_rule_set();
+                            builder
+                                .withdraw_from_account(
+                                    config.primary_account.address,
+                                    controlled_badge,
+                                    dec!("1"),
+                                )
+                                .take_all_from_worktop(controlled_badge, "controlled_badge")
+                                .create_access_controller(
+                                    "controlled_badge",
+                                    rule_set.primary_role,
+                                    rule_set.recovery_role,
+                                    rule_set.confirmation_role,
+                                    config.timed_recovery_delay_in_minutes,
+                                )
+                        },
+                        vec![&config.primary_account.key],
+                    )
+                },
+                |core, config, state, result| {
+                    state
+                        .access_controller
+                        .set(result.new_component_addresses()[0]);
+                    state.recovery_badge.set(result.new_resource_addresses()[0]);
+                    Ok(())
+                },
+            )
+            .successful_transaction(|core, config, state| {
+                let access_controller = state.access_controller.get()?;
+                core.next_transaction_with_faucet_lock_fee(
+                    "access-controller-create-proof-as-primary",
+                    |builder| builder.create_proof_from_access_controller(access_controller),
+                    vec![&config.primary_account.key],
+                )
+            })
+            .successful_transaction(|core, config, state| {
+                let access_controller = state.access_controller.get()?;
+                core.next_transaction_with_faucet_lock_fee(
+                    "access-controller-lock-primary-role",
+                    |builder| builder.lock_primary_role(access_controller),
+                    vec![&config.recovery_account.key],
+                )
+            })
+            .failed_transaction_with_error_handler(
+                |core, config, state| {
+                    let access_controller = state.access_controller.get()?;
+                    core.next_transaction_with_faucet_lock_fee(
+                        "access-controller-create-proof-while-primary-locked",
+                        |builder| builder.create_proof_from_access_controller(access_controller),
+                        vec![&config.primary_account.key],
+                    )
+                },
+                |core, config, state, error| Ok(()),
+            )
+            .successful_transaction(|core, config, state| {
+                let access_controller = state.access_controller.get()?;
+                core.next_transaction_with_faucet_lock_fee(
+                    "access-controller-unlock-primary-role",
+                    |builder| builder.unlock_primary_role(access_controller),
+                    vec![&config.recovery_account.key],
+                )
+            })
+            .successful_transaction(|core, config, state| {
+                let access_controller = state.access_controller.get()?;
+                core.next_transaction_with_faucet_lock_fee(
+                    "access-controller-initiate-recovery-as-recovery",
+                    |builder| {
+                        builder.initiate_recovery_as_recovery(
+                            access_controller,
+                            config.recovered_rule_set(),
+                            config.timed_recovery_delay_in_minutes,
+                        )
+                    },
+                    vec![&config.recovery_account.key],
+                )
+            })
+            .successful_transaction(|core, config, state| {
+                let access_controller = state.access_controller.get()?;
+                core.next_transaction_with_faucet_lock_fee(
+                    "access-controller-quick-confirm-recovery",
+                    |builder| {
+                        builder.quick_confirm_recovery(
+                            access_controller,
+                            Proposer::Recovery,
+                            config.recovered_rule_set(),
+                            config.timed_recovery_delay_in_minutes,
+                        )
+                    },
+                    vec![&config.confirmation_account.key],
+                )
+            })
+            .successful_transaction(|core, config, state| {
+                let access_controller = state.access_controller.get()?;
+                core.next_transaction_with_faucet_lock_fee(
+                    "access-controller-create-proof-as-recovered-primary",
+                    |builder| builder.create_proof_from_access_controller(access_controller),
+                    vec![&config.recovered_primary_account.key],
+                )
+            })
+            .successful_transaction(|core, config, state| {
+                let access_controller = state.access_controller.get()?;
+                core.next_transaction_with_faucet_lock_fee(
+                    "access-controller-mint-recovery-badges",
+                    |builder| {
+                        builder
+                            .mint_recovery_badges(
+                                access_controller,
+                                [
+                                    NonFungibleLocalId::integer(1),
+                                    NonFungibleLocalId::integer(2),
+                                ],
+                            )
+                            .try_deposit_entire_worktop_or_abort(
+                                config.recovery_account.address,
+                                None,
+                            )
+                    },
+                    vec![&config.recovery_account.key],
+                )
+            })
+            .finalize(|core, config, state| {
+                Ok(ScenarioOutput {
+                    interesting_addresses: DescribedAddresses::new()
+                        .add("primary_account", &config.primary_account)
+                        .add("recovery_account", &config.recovery_account)
+                        .add("confirmation_account", &config.confirmation_account)
+                        .add(
+                            "recovered_primary_account",
+                            &config.recovered_primary_account,
+                        )
+                        .add("controlled_badge", state.controlled_badge.get()?)
+                        .add("access_controller", state.access_controller.get()?)
+                        .add("recovery_badge", state.recovery_badge.get()?),
+                })
+            })
+    }
+}
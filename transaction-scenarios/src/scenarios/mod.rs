@@ -1,5 +1,6 @@
 use crate::internal_prelude::*;
 
+pub mod access_controller;
 pub mod account_authorized_depositors;
 mod all_scenarios;
 pub mod fungible_resource;
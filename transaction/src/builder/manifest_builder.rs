@@ -11,7 +11,9 @@ use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
 use radix_engine_interface::blueprints::identity::*;
 use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::blueprints::pool::*;
 use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::blueprints::transaction_processor::*;
 
 /// A manifest builder for use in tests.
 ///
@@ -86,6 +88,8 @@ pub struct ManifestBuilder {
     instructions: Vec<InstructionV1>,
     /// Blobs
     blobs: BTreeMap<Hash, Vec<u8>>,
+    /// Addresses which can be referred to by alias
+    address_book: BTreeMap<String, GlobalAddress>,
 }
 
 pub struct NewSymbols {
@@ -102,6 +106,7 @@ impl ManifestBuilder {
             registrar: ManifestNameRegistrar::new(),
             instructions: Vec::new(),
             blobs: BTreeMap::default(),
+            address_book: BTreeMap::default(),
         }
     }
 
@@ -143,6 +148,31 @@ impl ManifestBuilder {
         self.name_lookup().address_reservation(name)
     }
 
+    /// Adds the given aliases to the address book, which the `_alias` call helpers resolve
+    /// against. An alias which is already in the address book is overwritten.
+    pub fn with_address_book<K: Into<String>, A: Into<GlobalAddress>>(
+        mut self,
+        address_book: impl IntoIterator<Item = (K, A)>,
+    ) -> Self {
+        self.address_book.extend(
+            address_book
+                .into_iter()
+                .map(|(alias, address)| (alias.into(), address.into())),
+        );
+        self
+    }
+
+    /// Resolves an alias against the address book, panicking if it is unknown.
+    pub fn aliased_address(&self, alias: impl AsRef<str>) -> GlobalAddress {
+        match self.address_book.get(alias.as_ref()) {
+            Some(address) => *address,
+            None => panic!(
+                "There is no address with alias \"{}\" in the address book",
+                alias.as_ref()
+            ),
+        }
+    }
+
     /// Generates an unused bucket name with the given prefix.
     /// This should be used when you are programmatically generating buckets,
     /// and need to generate bucket names which do not clash.
@@ -341,7 +371,7 @@ impl ManifestBuilder {
         self.add_instruction(InstructionV1::AssertWorktopContainsAny { resource_address })
     }
 
-    /// Asserts that worktop contains resource.
+    /// Asserts that worktop contains at least the non-fungibles with the given IDs.
     pub fn assert_worktop_contains_non_fungibles(
         self,
         resource_address: impl ResolvableResourceAddress,
@@ -484,6 +514,79 @@ impl ManifestBuilder {
         })
     }
 
+    /// Asserts that the package contains the code with the given hash, so that the transaction
+    /// fails if the package isn't running the code the signer expects.
+    pub fn assert_package_code_hash(
+        self,
+        package_address: impl ResolvablePackageAddress,
+        code_hash: CodeHash,
+    ) -> Self {
+        let package_address = package_address.resolve_static(&self.registrar);
+        self.add_instruction(InstructionV1::AssertPackageCodeHash {
+            package_address,
+            code_hash,
+        })
+    }
+
+    /// Enqueues the instructions of the given manifest into the deferred execution queue, to be
+    /// executed between the two epochs by whoever claims the payment.
+    ///
+    /// The manifest can't use blobs, and its buckets and proofs are separate from those of this
+    /// manifest.
+    pub fn enqueue_deferred_manifest(
+        self,
+        manifest: TransactionManifestV1,
+        execute_at_epoch: Epoch,
+        expiry_epoch: Epoch,
+        payment: impl ExistingManifestBucket,
+        canceller: AccessRule,
+    ) -> Self {
+        assert!(
+            manifest.blobs.is_empty(),
+            "Deferred manifests can't use blobs"
+        );
+        let payment = payment.mark_consumed(&self.registrar);
+        self.add_instruction(InstructionV1::EnqueueDeferredManifest {
+            instructions: manifest.instructions,
+            execute_at_epoch,
+            expiry_epoch,
+            payment,
+            canceller,
+        })
+    }
+
+    /// Executes a due deferred manifest, putting its payment on the worktop.
+    pub fn execute_deferred_manifest(self, intent_id: u64) -> Self {
+        self.call_method(
+            DEFERRED_EXECUTION_QUEUE,
+            DEFERRED_EXECUTION_QUEUE_EXECUTE_IDENT,
+            DeferredExecutionQueueExecuteManifestInput { intent_id },
+        )
+    }
+
+    /// Cancels a deferred manifest, putting its payment on the worktop.
+    pub fn cancel_deferred_manifest(self, intent_id: u64) -> Self {
+        self.call_method(
+            DEFERRED_EXECUTION_QUEUE,
+            DEFERRED_EXECUTION_QUEUE_CANCEL_IDENT,
+            DeferredExecutionQueueCancelManifestInput { intent_id },
+        )
+    }
+
+    /// Executes a signed subintent, passing the given buckets into it and putting everything it
+    /// leaves on its worktop onto the worktop.
+    pub fn execute_subintent<B: ExistingManifestBucket>(
+        self,
+        subintent: SignedSubintentV1,
+        inputs: impl IntoIterator<Item = B>,
+    ) -> Self {
+        let inputs = inputs
+            .into_iter()
+            .map(|input| input.mark_consumed(&self.registrar))
+            .collect();
+        self.add_instruction(InstructionV1::ExecuteSubintent { subintent, inputs })
+    }
+
     /// Drops a proof.
     pub fn drop_proof(self, proof: impl ExistingManifestProof) -> Self {
         let proof = proof.mark_consumed(&self.registrar);
@@ -800,6 +903,175 @@ impl ManifestBuilder {
         self.call_method(address, VALIDATOR_CLAIM_XRD_IDENT, (bucket,))
     }
 
+    pub fn update_validator_key(
+        self,
+        validator_address: impl ResolvableComponentAddress,
+        key: Secp256k1PublicKey,
+    ) -> Self {
+        let address = validator_address.resolve(&self.registrar);
+        self.call_method(address, VALIDATOR_UPDATE_KEY_IDENT, (key,))
+    }
+
+    pub fn update_validator_fee(
+        self,
+        validator_address: impl ResolvableComponentAddress,
+        new_fee_factor: impl ResolvableDecimal,
+    ) -> Self {
+        let address = validator_address.resolve(&self.registrar);
+        let new_fee_factor = new_fee_factor.resolve();
+        self.call_method(address, VALIDATOR_UPDATE_FEE_IDENT, (new_fee_factor,))
+    }
+
+    pub fn update_validator_accept_delegated_stake(
+        self,
+        validator_address: impl ResolvableComponentAddress,
+        accept_delegated_stake: bool,
+    ) -> Self {
+        let address = validator_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT,
+            (accept_delegated_stake,),
+        )
+    }
+
+    pub fn lock_owner_stake_units(
+        self,
+        validator_address: impl ResolvableComponentAddress,
+        stake_unit_bucket: impl ExistingManifestBucket,
+    ) -> Self {
+        let address = validator_address.resolve(&self.registrar);
+        let stake_unit_bucket = stake_unit_bucket.mark_consumed(&self.registrar);
+        self.call_method(
+            address,
+            VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT,
+            (stake_unit_bucket,),
+        )
+    }
+
+    pub fn start_unlock_owner_stake_units(
+        self,
+        validator_address: impl ResolvableComponentAddress,
+        requested_stake_unit_amount: impl ResolvableDecimal,
+    ) -> Self {
+        let address = validator_address.resolve(&self.registrar);
+        let requested_stake_unit_amount = requested_stake_unit_amount.resolve();
+        self.call_method(
+            address,
+            VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT,
+            (requested_stake_unit_amount,),
+        )
+    }
+
+    /// Puts the owner stake units whose unlock delay has passed on the worktop.
+    pub fn finish_unlock_owner_stake_units(
+        self,
+        validator_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = validator_address.resolve(&self.registrar);
+        self.call_method(address, VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT, ())
+    }
+
+    /// Contributes to a one-resource pool. This requires the pool manager role, and the
+    /// minted pool units are put on the worktop.
+    pub fn contribute_to_one_resource_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        bucket: impl ExistingManifestBucket,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let bucket = bucket.mark_consumed(&self.registrar);
+        self.call_method(
+            address,
+            ONE_RESOURCE_POOL_CONTRIBUTE_IDENT,
+            OneResourcePoolContributeManifestInput { bucket },
+        )
+    }
+
+    pub fn redeem_from_one_resource_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        pool_units: impl ExistingManifestBucket,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let bucket = pool_units.mark_consumed(&self.registrar);
+        self.call_method(
+            address,
+            ONE_RESOURCE_POOL_REDEEM_IDENT,
+            OneResourcePoolRedeemManifestInput { bucket },
+        )
+    }
+
+    /// Contributes to a two-resource pool. This requires the pool manager role. The minted pool
+    /// units, and any change which didn't fit the pool ratio, are put on the worktop.
+    pub fn contribute_to_two_resource_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        buckets: (impl ExistingManifestBucket, impl ExistingManifestBucket),
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let buckets = (
+            buckets.0.mark_consumed(&self.registrar),
+            buckets.1.mark_consumed(&self.registrar),
+        );
+        self.call_method(
+            address,
+            TWO_RESOURCE_POOL_CONTRIBUTE_IDENT,
+            TwoResourcePoolContributeManifestInput { buckets },
+        )
+    }
+
+    pub fn redeem_from_two_resource_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        pool_units: impl ExistingManifestBucket,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let bucket = pool_units.mark_consumed(&self.registrar);
+        self.call_method(
+            address,
+            TWO_RESOURCE_POOL_REDEEM_IDENT,
+            TwoResourcePoolRedeemManifestInput { bucket },
+        )
+    }
+
+    /// Contributes to a multi-resource pool. This requires the pool manager role. The minted pool
+    /// units, and any change which didn't fit the pool ratio, are put on the worktop.
+    ///
+    /// Note - the batch should either be:
+    /// * `ManifestExpression::EntireWorktop`,
+    /// * An array, vec, or btreeset of bucket names or ManifestBuckets, eg `["my_bucket_1", "my_bucket_2"]`
+    pub fn contribute_to_multi_resource_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        batch: impl ResolvableBucketBatch,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let batch = batch.resolve(&self.registrar);
+
+        self.registrar.consume_all_buckets();
+
+        self.call_method(
+            address,
+            MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT,
+            manifest_args!(batch),
+        )
+    }
+
+    pub fn redeem_from_multi_resource_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        pool_units: impl ExistingManifestBucket,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let bucket = pool_units.mark_consumed(&self.registrar);
+        self.call_method(
+            address,
+            MULTI_RESOURCE_POOL_REDEEM_IDENT,
+            MultiResourcePoolRedeemManifestInput { bucket },
+        )
+    }
+
     /// Calls a scrypto function where the arguments should be one of:
     /// * A tuple, such as `()`, `(x,)` or `(x, y, z)`
     ///   * IMPORTANT: If calling with a single argument, you must include a trailing comma
@@ -971,6 +1243,55 @@ impl ManifestBuilder {
         )
     }
 
+    /// Proposes a new owner rule, which takes effect once accepted with [`Self::accept_owner_role`].
+    pub fn propose_owner_role(
+        self,
+        address: impl ResolvableGlobalAddress,
+        rule: impl Into<AccessRule>,
+    ) -> Self {
+        self.call_module_method(
+            address,
+            ModuleId::RoleAssignment,
+            ROLE_ASSIGNMENT_PROPOSE_OWNER_IDENT,
+            RoleAssignmentProposeOwnerInput { rule: rule.into() },
+        )
+    }
+
+    pub fn accept_owner_role(self, address: impl ResolvableGlobalAddress) -> Self {
+        self.call_module_method(
+            address,
+            ModuleId::RoleAssignment,
+            ROLE_ASSIGNMENT_ACCEPT_OWNER_IDENT,
+            RoleAssignmentAcceptOwnerInput {},
+        )
+    }
+
+    pub fn pause(self, address: impl ResolvableGlobalAddress) -> Self {
+        self.call_module_method(
+            address,
+            ModuleId::RoleAssignment,
+            ROLE_ASSIGNMENT_PAUSE_IDENT,
+            RoleAssignmentPauseInput {},
+        )
+    }
+
+    pub fn unpause(self, address: impl ResolvableGlobalAddress) -> Self {
+        self.call_module_method(
+            address,
+            ModuleId::RoleAssignment,
+            ROLE_ASSIGNMENT_UNPAUSE_IDENT,
+            RoleAssignmentUnpauseInput {},
+        )
+    }
+
+    pub fn set_pauser_role(
+        self,
+        address: impl ResolvableGlobalAddress,
+        rule: impl Into<AccessRule>,
+    ) -> Self {
+        self.set_role(address, ModuleId::Main, PAUSER_ROLE, rule)
+    }
+
     pub fn set_main_role(
         self,
         address: impl ResolvableGlobalAddress,
@@ -1127,6 +1448,49 @@ impl ManifestBuilder {
         })
     }
 
+    /// Calls a scrypto function of the package with the given alias in the address book.
+    /// The arguments are as per `call_function`.
+    pub fn call_function_alias(
+        self,
+        package_alias: impl AsRef<str>,
+        blueprint_name: impl Into<String>,
+        function_name: impl Into<String>,
+        arguments: impl ResolvableArguments,
+    ) -> Self {
+        let address = self.aliased_address(package_alias.as_ref());
+        let package_address = PackageAddress::try_from(address).unwrap_or_else(|_| {
+            panic!(
+                "The address with alias \"{}\" is not a package address",
+                package_alias.as_ref()
+            )
+        });
+        self.call_function(package_address, blueprint_name, function_name, arguments)
+    }
+
+    /// Calls a scrypto method of the entity with the given alias in the address book.
+    /// The arguments are as per `call_method`.
+    pub fn call_method_alias(
+        self,
+        alias: impl AsRef<str>,
+        method_name: impl Into<String>,
+        arguments: impl ResolvableArguments,
+    ) -> Self {
+        let address = self.aliased_address(alias);
+        self.call_method(address, method_name, arguments)
+    }
+
+    /// Calls a scrypto method of the entity with the given alias in the address book.
+    /// The arguments are as per `call_method_with_name_lookup`.
+    pub fn call_method_alias_with_name_lookup<T: ResolvableArguments>(
+        self,
+        alias: impl AsRef<str>,
+        method_name: impl Into<String>,
+        arguments_creator: impl FnOnce(&ManifestNameLookup) -> T,
+    ) -> Self {
+        let address = self.aliased_address(alias);
+        self.call_method_with_name_lookup(address, method_name, arguments_creator)
+    }
+
     pub fn claim_package_royalties(self, package_address: impl ResolvablePackageAddress) -> Self {
         let address = package_address.resolve(&self.registrar);
         self.add_instruction(InstructionV1::CallMethod {
@@ -1136,6 +1500,34 @@ impl ManifestBuilder {
         })
     }
 
+    pub fn add_package_royalty_exemption(
+        self,
+        package_address: impl ResolvablePackageAddress,
+        caller: impl Into<GlobalAddress>,
+    ) -> Self {
+        let address = package_address.resolve(&self.registrar);
+        let caller = caller.into();
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: PACKAGE_ADD_ROYALTY_EXEMPTION_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackageAddRoyaltyExemptionInput { caller }),
+        })
+    }
+
+    pub fn remove_package_royalty_exemption(
+        self,
+        package_address: impl ResolvablePackageAddress,
+        caller: impl Into<GlobalAddress>,
+    ) -> Self {
+        let address = package_address.resolve(&self.registrar);
+        let caller = caller.into();
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: PACKAGE_REMOVE_ROYALTY_EXEMPTION_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackageRemoveRoyaltyExemptionInput { caller }),
+        })
+    }
+
     pub fn set_component_royalty(
         self,
         component_address: impl ResolvableComponentAddress,
@@ -1484,6 +1876,66 @@ impl ManifestBuilder {
         })
     }
 
+    pub fn recount_total_supply(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+        limit: u32,
+    ) -> Self {
+        let address = resource_address.resolve(&self.registrar);
+
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: NON_FUNGIBLE_RESOURCE_MANAGER_RECOUNT_TOTAL_SUPPLY_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(
+                &NonFungibleResourceManagerRecountTotalSupplyInput { limit }
+            ),
+        })
+    }
+
+    pub fn reserve_integer_ids(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+        count: u64,
+    ) -> Self {
+        let address = resource_address.resolve(&self.registrar);
+
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: NON_FUNGIBLE_RESOURCE_MANAGER_RESERVE_INTEGER_IDS_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(
+                &NonFungibleResourceManagerReserveIntegerIdsInput { count }
+            ),
+        })
+    }
+
+    pub fn mint_reserved_non_fungible<
+        T: IntoIterator<Item = (NonFungibleLocalId, V)>,
+        V: ManifestEncode,
+    >(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+        reservation: NonFungibleIdReservation,
+        entries: T,
+    ) -> Self {
+        let address = resource_address.resolve(&self.registrar);
+
+        let entries = entries
+            .into_iter()
+            .map(|(id, e)| (id, (to_manifest_value_and_unwrap!(&e),)))
+            .collect();
+
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RESERVED_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(
+                &NonFungibleResourceManagerMintReservedManifestInput {
+                    reservation,
+                    entries
+                }
+            ),
+        })
+    }
+
     pub fn recall(self, vault_address: InternalAddress, amount: impl ResolvableDecimal) -> Self {
         let amount = amount.resolve();
         self.add_instruction(InstructionV1::CallDirectVaultMethod {
@@ -1509,6 +1961,65 @@ impl ManifestBuilder {
         })
     }
 
+    /// Recalls from several vaults of the same fungible resource in one call, returning a
+    /// single bucket to the worktop.
+    pub fn recall_batch(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+        vaults: impl IntoIterator<Item = (InternalAddress, Decimal)>,
+    ) -> Self {
+        let address = resource_address.resolve(&self.registrar);
+        let args =
+            to_manifest_value_and_unwrap!(&FungibleResourceManagerRecallBatchManifestInput {
+                vaults: vaults.into_iter().collect(),
+            });
+
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: FUNGIBLE_RESOURCE_MANAGER_RECALL_BATCH_IDENT.to_string(),
+            args,
+        })
+    }
+
+    /// Withdraws from a vault under the allowance granted to a badge, which must be present
+    /// in the auth zone.
+    pub fn take_from_vault_with_allowance(
+        self,
+        vault_address: InternalAddress,
+        grantee: impl Into<ResourceOrNonFungible>,
+        amount: impl ResolvableDecimal,
+    ) -> Self {
+        let args = to_manifest_value_and_unwrap!(&VaultTakeWithAllowanceInput {
+            grantee: grantee.into(),
+            amount: amount.resolve(),
+        });
+
+        self.add_instruction(InstructionV1::CallDirectVaultMethod {
+            address: vault_address,
+            method_name: VAULT_TAKE_WITH_ALLOWANCE_IDENT.to_string(),
+            args,
+        })
+    }
+
+    pub fn take_non_fungibles_from_vault_with_allowance(
+        self,
+        vault_address: InternalAddress,
+        grantee: impl Into<ResourceOrNonFungible>,
+        non_fungible_local_ids: impl IntoIterator<Item = NonFungibleLocalId>,
+    ) -> Self {
+        let args =
+            to_manifest_value_and_unwrap!(&NonFungibleVaultTakeNonFungiblesWithAllowanceInput {
+                grantee: grantee.into(),
+                non_fungible_local_ids: non_fungible_local_ids.into_iter().collect(),
+            });
+
+        self.add_instruction(InstructionV1::CallDirectVaultMethod {
+            address: vault_address,
+            method_name: NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_WITH_ALLOWANCE_IDENT.to_string(),
+            args,
+        })
+    }
+
     pub fn freeze_withdraw(self, vault_id: InternalAddress) -> Self {
         self.add_instruction(InstructionV1::CallDirectVaultMethod {
             address: vault_id,
@@ -1701,6 +2212,66 @@ impl ManifestBuilder {
         self.call_method(FAUCET, "free", ())
     }
 
+    /// Creates the given virtual accounts in a single manifest by splitting the XRD handed out
+    /// by the faucet equally between them, and then sets their default deposit rule.
+    ///
+    /// As setting the deposit rule requires the owner role, the transaction needs to be signed
+    /// by the key behind each of the accounts.
+    pub fn create_funded_virtual_accounts(
+        self,
+        account_addresses: &[ComponentAddress],
+        default_deposit_rule: DefaultDepositRule,
+    ) -> Self {
+        let Some((last_account, other_accounts)) = account_addresses.split_last() else {
+            return self;
+        };
+        let amount_per_account = dec!(10000) / account_addresses.len();
+
+        let mut builder = self.get_free_xrd_from_faucet();
+        for account_address in other_accounts {
+            let bucket_name = builder.generate_bucket_name("funds");
+            builder = builder
+                .take_from_worktop(XRD, amount_per_account, &bucket_name)
+                .try_deposit_or_abort(*account_address, None, bucket_name);
+        }
+        builder = builder.try_deposit_entire_worktop_or_abort(*last_account, None);
+
+        for account_address in account_addresses {
+            builder = builder.set_default_deposit_rule(*account_address, default_deposit_rule);
+        }
+        builder
+    }
+
+    pub fn set_default_deposit_rule(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        default_deposit_rule: DefaultDepositRule,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+            AccountSetDefaultDepositRuleInput {
+                default: default_deposit_rule,
+            },
+        )
+    }
+
+    /// Sets the component which authorizes the owner protected methods of an account on top of
+    /// its owner role, or removes it if `None`.
+    pub fn set_account_authorizer(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        authorizer: Option<ComponentAddress>,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCOUNT_SET_AUTHORIZER_IDENT,
+            AccountSetAuthorizerInput { authorizer },
+        )
+    }
+
     /// Withdraws resource from an account.
     pub fn withdraw_from_account(
         self,
@@ -2017,6 +2588,220 @@ impl ManifestBuilder {
         )
     }
 
+    /// Creates a proof of the badge controlled by the access controller. This requires the
+    /// primary role, and the proof is put in the auth zone.
+    pub fn create_proof_from_access_controller(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_CREATE_PROOF_IDENT,
+            AccessControllerCreateProofInput {},
+        )
+    }
+
+    pub fn initiate_recovery_as_primary(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT,
+            AccessControllerInitiateRecoveryAsPrimaryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    pub fn initiate_recovery_as_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+            AccessControllerInitiateRecoveryAsRecoveryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    pub fn initiate_badge_withdraw_attempt_as_primary(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_PRIMARY_IDENT,
+            (),
+        )
+    }
+
+    pub fn initiate_badge_withdraw_attempt_as_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_RECOVERY_IDENT,
+            (),
+        )
+    }
+
+    /// Confirms the recovery proposed by `proposer`, without waiting for the timed recovery
+    /// delay. The rule set and delay must match the ones of the proposal.
+    pub fn quick_confirm_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        proposer: Proposer,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        let method_name = match proposer {
+            Proposer::Primary => {
+                ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT
+            }
+            Proposer::Recovery => {
+                ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT
+            }
+        };
+        self.call_method(
+            address,
+            method_name,
+            AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    /// Confirms the badge withdraw attempt of `proposer`. The badge is put on the worktop.
+    pub fn quick_confirm_badge_withdraw_attempt(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        proposer: Proposer,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        let method_name = match proposer {
+            Proposer::Primary => {
+                ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT
+            }
+            Proposer::Recovery => {
+                ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT
+            }
+        };
+        self.call_method(address, method_name, ())
+    }
+
+    pub fn timed_confirm_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
+            AccessControllerTimedConfirmRecoveryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    pub fn cancel_recovery_proposal(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        proposer: Proposer,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        let method_name = match proposer {
+            Proposer::Primary => ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
+            Proposer::Recovery => ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+        };
+        self.call_method(address, method_name, ())
+    }
+
+    pub fn cancel_badge_withdraw_attempt(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        proposer: Proposer,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        let method_name = match proposer {
+            Proposer::Primary => ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT,
+            Proposer::Recovery => {
+                ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT
+            }
+        };
+        self.call_method(address, method_name, ())
+    }
+
+    pub fn lock_primary_role(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(address, ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT, ())
+    }
+
+    pub fn unlock_primary_role(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(address, ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT, ())
+    }
+
+    /// Stops the timed recovery of the recovery role. The rule set and delay must match the
+    /// ones of the proposal.
+    pub fn stop_timed_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_STOP_TIMED_RECOVERY_IDENT,
+            AccessControllerStopTimedRecoveryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    /// Mints recovery badges with the given ids. The badges are put on the worktop.
+    pub fn mint_recovery_badges(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        non_fungible_local_ids: impl IntoIterator<Item = NonFungibleLocalId>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_MINT_RECOVERY_BADGES_IDENT,
+            AccessControllerMintRecoveryBadgesInput {
+                non_fungible_local_ids: non_fungible_local_ids.into_iter().collect(),
+            },
+        )
+    }
+
     /// Builds a transaction manifest.
     pub fn build(self) -> TransactionManifestV1 {
         let manifest = TransactionManifestV1 {
@@ -2123,6 +2908,33 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_manifest_builder_resolves_aliases_from_address_book() {
+        let builder = ManifestBuilder::new()
+            .with_address_book([("faucet", GlobalAddress::from(FAUCET))])
+            .with_address_book([("faucet_package", FAUCET_PACKAGE)])
+            .call_method_alias("faucet", "free", ())
+            .call_function_alias("faucet_package", "Faucet", "new", ());
+
+        let manifest = builder.build();
+        assert_eq!(
+            manifest.instructions,
+            ManifestBuilder::new()
+                .call_method(FAUCET, "free", ())
+                .call_function(FAUCET_PACKAGE, "Faucet", "new", ())
+                .build()
+                .instructions
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "There is no address with alias \"pool\" in the address book")]
+    fn test_manifest_builder_panics_on_unknown_alias() {
+        ManifestBuilder::new()
+            .with_address_book([("faucet", GlobalAddress::from(FAUCET))])
+            .call_method_alias("pool", "swap", ());
+    }
+
     #[test]
     fn test_manifest_builder_add_instruction_advanced_global_address() {
         let (builder, _, _) = get_builder_and_bucket_and_proof();
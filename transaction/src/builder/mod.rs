@@ -1,7 +1,9 @@
 mod manifest_builder;
 mod manifest_namer;
 mod transaction_builder;
+mod transaction_signing_coordinator;
 
 pub use manifest_builder::*;
 pub use manifest_namer::*;
 pub use transaction_builder::*;
+pub use transaction_signing_coordinator::*;
@@ -0,0 +1,252 @@
+use crate::internal_prelude::*;
+
+/// Coordinates the signing of a single transaction intent by several parties.
+///
+/// The set of parties expected to sign is fixed up front. Each party can be handed a partially
+/// signed intent, sign it on their own machine, and have the result merged back, until all of
+/// them have signed and the notary can produce the final notarized transaction.
+pub struct TransactionSigningCoordinator {
+    intent: IntentV1,
+    intent_hash: IntentHash,
+    signers: IndexSet<PublicKey>,
+    signatures: IndexMap<PublicKey, SignatureWithPublicKeyV1>,
+}
+
+impl TransactionSigningCoordinator {
+    /// Creates a coordinator for the given intent, to be signed by the given signers.
+    ///
+    /// If the notary is a signatory, its notarization already counts as a signature, so it must
+    /// not also be listed as a signer.
+    pub fn new(
+        intent: IntentV1,
+        signers: impl IntoIterator<Item = PublicKey>,
+    ) -> Result<Self, TransactionSigningError> {
+        let intent_hash = intent.prepare()?.intent_hash();
+
+        let mut signer_set = index_set_new();
+        for signer in signers {
+            if intent.header.notary_is_signatory && signer == intent.header.notary_public_key {
+                return Err(TransactionSigningError::NotaryIsSignatory(signer));
+            }
+            if !signer_set.insert(signer) {
+                return Err(TransactionSigningError::DuplicateSigner(signer));
+            }
+        }
+        if signer_set.len() > MAX_NUMBER_OF_INTENT_SIGNATURES {
+            return Err(TransactionSigningError::TooManySigners {
+                actual: signer_set.len(),
+                permitted: MAX_NUMBER_OF_INTENT_SIGNATURES,
+            });
+        }
+
+        Ok(Self {
+            intent,
+            intent_hash,
+            signers: signer_set,
+            signatures: index_map_new(),
+        })
+    }
+
+    pub fn intent_hash(&self) -> IntentHash {
+        self.intent_hash
+    }
+
+    /// The signers which have yet to sign the intent.
+    pub fn missing_signers(&self) -> Vec<PublicKey> {
+        self.signers
+            .iter()
+            .filter(|signer| !self.signatures.contains_key(*signer))
+            .cloned()
+            .collect()
+    }
+
+    pub fn sign<S: Signer>(&mut self, signer: &S) -> Result<(), TransactionSigningError> {
+        self.add_signature(signer.sign_with_public_key(&self.intent_hash))
+    }
+
+    /// Adds a signature of the intent, which must be from one of the expected signers.
+    ///
+    /// Adding a signature from a signer which has already signed replaces its signature.
+    pub fn add_signature(
+        &mut self,
+        signature: SignatureWithPublicKeyV1,
+    ) -> Result<(), TransactionSigningError> {
+        let intent_hash = self.intent_hash.into_hash();
+        let public_key = recover(&intent_hash, &signature)
+            .filter(|public_key| verify(&intent_hash, public_key, &signature.signature()))
+            .ok_or(TransactionSigningError::InvalidSignature)?;
+        if !self.signers.contains(&public_key) {
+            return Err(TransactionSigningError::UnexpectedSigner(public_key));
+        }
+
+        self.signatures.insert(public_key, signature);
+        Ok(())
+    }
+
+    /// Merges the signatures of an intent signed by another party.
+    pub fn merge(&mut self, signed_intent: &SignedIntentV1) -> Result<(), TransactionSigningError> {
+        if signed_intent.intent != self.intent {
+            return Err(TransactionSigningError::IntentMismatch);
+        }
+        for signature in &signed_intent.intent_signatures.signatures {
+            self.add_signature(signature.0)?;
+        }
+        Ok(())
+    }
+
+    /// The intent with the signatures collected so far, to be passed on to the remaining
+    /// signers.
+    pub fn partially_signed_intent(&self) -> SignedIntentV1 {
+        SignedIntentV1 {
+            intent: self.intent.clone(),
+            intent_signatures: IntentSignaturesV1 {
+                signatures: self
+                    .signatures
+                    .values()
+                    .map(|signature| IntentSignatureV1(*signature))
+                    .collect(),
+            },
+        }
+    }
+
+    /// Notarizes the intent once all the signers have signed it.
+    pub fn notarize<S: Signer>(
+        self,
+        notary: &S,
+    ) -> Result<NotarizedTransactionV1, TransactionSigningError> {
+        let signed_intent = self.signed_intent_for_notarization(&notary.public_key())?;
+        let signed_intent_hash = signed_intent.prepare()?.signed_intent_hash();
+        Ok(NotarizedTransactionV1 {
+            signed_intent,
+            notary_signature: NotarySignatureV1(
+                notary.sign_without_public_key(&signed_intent_hash),
+            ),
+        })
+    }
+
+    /// Returns the fully signed intent for the notary to sign, checking that all the signers
+    /// have signed it and that the given notary is the one declared in the header.
+    pub fn signed_intent_for_notarization(
+        &self,
+        notary_public_key: &PublicKey,
+    ) -> Result<SignedIntentV1, TransactionSigningError> {
+        let missing_signers = self.missing_signers();
+        if !missing_signers.is_empty() {
+            return Err(TransactionSigningError::MissingSignatures(missing_signers));
+        }
+        if !self.intent.header.notary_public_key.eq(notary_public_key) {
+            return Err(TransactionSigningError::UnexpectedNotary(
+                *notary_public_key,
+            ));
+        }
+        Ok(self.partially_signed_intent())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intent(notary: &Secp256k1PrivateKey, notary_is_signatory: bool) -> IntentV1 {
+        let (instructions, blobs) = ManifestBuilder::new()
+            .drop_auth_zone_proofs()
+            .build()
+            .for_intent();
+        IntentV1 {
+            header: TransactionHeaderV1 {
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: Epoch::zero(),
+                end_epoch_exclusive: Epoch::of(100),
+                nonce: 5,
+                notary_public_key: notary.public_key().into(),
+                notary_is_signatory,
+                tip_percentage: 0,
+            },
+            instructions,
+            blobs,
+            message: MessageV1::None,
+        }
+    }
+
+    #[test]
+    fn signatures_of_separate_parties_are_merged_into_notarized_transaction() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let alice = Secp256k1PrivateKey::from_u64(2).unwrap();
+        let bob = Ed25519PrivateKey::from_u64(3).unwrap();
+        let intent = intent(&notary, false);
+        let signers: Vec<PublicKey> = vec![alice.public_key().into(), bob.public_key().into()];
+        let mut coordinator =
+            TransactionSigningCoordinator::new(intent.clone(), signers.clone()).unwrap();
+
+        let mut bob_coordinator = TransactionSigningCoordinator::new(intent, signers).unwrap();
+        bob_coordinator.sign(&bob).unwrap();
+        coordinator.sign(&alice).unwrap();
+        coordinator
+            .merge(&bob_coordinator.partially_signed_intent())
+            .unwrap();
+        let transaction = coordinator.notarize(&notary).unwrap();
+
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        let prepared = transaction.prepare().unwrap();
+        let signers = validator.validate_signatures_v1(&prepared).unwrap();
+        assert_eq!(signers.len(), 2);
+    }
+
+    #[test]
+    fn cannot_notarize_before_all_signers_have_signed() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let alice = Secp256k1PrivateKey::from_u64(2).unwrap();
+        let bob = Secp256k1PrivateKey::from_u64(3).unwrap();
+        let mut coordinator = TransactionSigningCoordinator::new(
+            intent(&notary, false),
+            vec![alice.public_key().into(), bob.public_key().into()],
+        )
+        .unwrap();
+        coordinator.sign(&alice).unwrap();
+
+        let result = coordinator.notarize(&notary);
+
+        assert_eq!(
+            result,
+            Err(TransactionSigningError::MissingSignatures(vec![bob
+                .public_key()
+                .into()]))
+        );
+    }
+
+    #[test]
+    fn cannot_add_signature_of_unexpected_signer() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let alice = Secp256k1PrivateKey::from_u64(2).unwrap();
+        let mallory = Secp256k1PrivateKey::from_u64(3).unwrap();
+        let mut coordinator = TransactionSigningCoordinator::new(
+            intent(&notary, false),
+            vec![alice.public_key().into()],
+        )
+        .unwrap();
+
+        let result = coordinator.sign(&mallory);
+
+        assert_eq!(
+            result,
+            Err(TransactionSigningError::UnexpectedSigner(
+                mallory.public_key().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn notary_which_is_signatory_cannot_be_listed_as_signer() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+
+        let result = TransactionSigningCoordinator::new(
+            intent(&notary, true),
+            vec![notary.public_key().into()],
+        );
+
+        assert!(matches!(
+            result,
+            Err(TransactionSigningError::NotaryIsSignatory(..))
+        ));
+    }
+}
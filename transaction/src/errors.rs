@@ -12,10 +12,10 @@ pub enum HeaderValidationError {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SignatureValidationError {
-    TooManySignatures,
+    TooManySignatures { actual: usize, permitted: usize },
     InvalidIntentSignature,
     InvalidNotarySignature,
-    DuplicateSigner,
+    DuplicateSigner(PublicKey),
     SerializationError(EncodeError),
 }
 
@@ -40,6 +40,30 @@ pub enum CallDataValidationError {
     IdValidationError(ManifestIdValidationError),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeferredManifestValidationError {
+    /// A deferred manifest can't enqueue further deferred manifests.
+    NestedDeferredManifest,
+    /// A deferred manifest can't call vault methods directly, as it can only reference global
+    /// entities.
+    DirectVaultMethodCall,
+    /// A deferred manifest can't execute subintents, as their signatures are only checked for
+    /// the transaction executing them.
+    SubintentExecution,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubintentValidationError {
+    /// A subintent can't execute further subintents.
+    NestedSubintent,
+    /// The same subintent is executed more than once.
+    DuplicateSubintent,
+    InvalidNetwork,
+    /// The epoch range of the transaction isn't within the epoch range of the subintent.
+    InvalidEpochRange,
+    SignatureValidationError(SignatureValidationError),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionValidationError {
     TransactionTooLarge,
@@ -50,6 +74,8 @@ pub enum TransactionValidationError {
     IdValidationError(ManifestIdValidationError),
     CallDataValidationError(CallDataValidationError),
     InvalidMessage(InvalidMessageError),
+    DeferredManifestValidationError(DeferredManifestValidationError),
+    SubintentValidationError(SubintentValidationError),
 }
 
 impl From<PrepareError> for TransactionValidationError {
@@ -70,6 +96,25 @@ impl From<InvalidMessageError> for TransactionValidationError {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionSigningError {
+    PrepareError(PrepareError),
+    DuplicateSigner(PublicKey),
+    NotaryIsSignatory(PublicKey),
+    TooManySigners { actual: usize, permitted: usize },
+    InvalidSignature,
+    UnexpectedSigner(PublicKey),
+    IntentMismatch,
+    MissingSignatures(Vec<PublicKey>),
+    UnexpectedNotary(PublicKey),
+}
+
+impl From<PrepareError> for TransactionSigningError {
+    fn from(value: PrepareError) -> Self {
+        Self::PrepareError(value)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InvalidMessageError {
     PlaintextMessageTooLong {
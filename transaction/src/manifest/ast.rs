@@ -142,6 +142,24 @@ pub enum Instruction {
         named_address: Value,
     },
 
+    AssertPackageCodeHash {
+        package_address: Value,
+        code_hash: Value,
+    },
+
+    EnqueueDeferredManifest {
+        instructions: Value,
+        execute_at_epoch: Value,
+        expiry_epoch: Value,
+        payment: Value,
+        canceller: Value,
+    },
+
+    ExecuteSubintent {
+        subintent: Value,
+        inputs: Value,
+    },
+
     /* Call direct vault method aliases */
     RecallFromVault {
         vault_id: Value,
@@ -250,6 +268,10 @@ pub enum Instruction {
         address: Value,
         args: Vec<Value>,
     },
+    RecallFromVaults {
+        address: Value,
+        args: Vec<Value>,
+    },
     CreateValidator {
         args: Vec<Value>,
     },
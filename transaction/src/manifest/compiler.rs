@@ -14,6 +14,21 @@ pub fn compile<B>(
 ) -> Result<TransactionManifestV1, CompileError>
 where
     B: IsBlobProvider,
+{
+    compile_with_schemas(s, network, blobs, &EmptySchemaSource)
+}
+
+/// Compiles a manifest like [`compile`], additionally checking the arguments of every
+/// `CALL_FUNCTION` and `CALL_METHOD` whose input schema the schema source knows.
+pub fn compile_with_schemas<B, S>(
+    s: &str,
+    network: &NetworkDefinition,
+    blobs: B,
+    schema_source: &S,
+) -> Result<TransactionManifestV1, CompileError>
+where
+    B: IsBlobProvider,
+    S: IsSchemaSource,
 {
     let address_bech32_decoder = AddressBech32Decoder::new(network);
 
@@ -21,6 +36,11 @@ where
     let instructions = parser::Parser::new(tokens, parser::PARSER_MAX_DEPTH)
         .parse_manifest()
         .map_err(CompileError::ParserError)?;
-    generator::generate_manifest(&instructions, &address_bech32_decoder, blobs)
-        .map_err(CompileError::GeneratorError)
+    generator::generate_manifest_with_schemas(
+        &instructions,
+        &address_bech32_decoder,
+        blobs,
+        schema_source,
+    )
+    .map_err(CompileError::GeneratorError)
 }
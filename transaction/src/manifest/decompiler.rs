@@ -460,6 +460,12 @@ pub fn decompile_instruction<F: fmt::Write>(
                     fields.push(address.to_instruction_argument());
                     "MINT_RUID_NON_FUNGIBLE"
                 }
+                (address, FUNGIBLE_RESOURCE_MANAGER_RECALL_BATCH_IDENT)
+                    if address.is_static_global_fungible_resource_manager() =>
+                {
+                    fields.push(address.to_instruction_argument());
+                    "RECALL_FROM_VAULTS"
+                }
 
                 /* Validator */
                 (address, CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT)
@@ -658,6 +664,33 @@ pub fn decompile_instruction<F: fmt::Write>(
                 ))?,
             )
         }
+        InstructionV1::AssertPackageCodeHash {
+            package_address,
+            code_hash,
+        } => (
+            "ASSERT_PACKAGE_CODE_HASH",
+            to_manifest_value(&(package_address, code_hash))?,
+        ),
+        InstructionV1::EnqueueDeferredManifest {
+            instructions,
+            execute_at_epoch,
+            expiry_epoch,
+            payment,
+            canceller,
+        } => (
+            "ENQUEUE_DEFERRED_MANIFEST",
+            to_manifest_value(&(
+                instructions,
+                execute_at_epoch,
+                expiry_epoch,
+                payment,
+                canceller,
+            ))?,
+        ),
+        InstructionV1::ExecuteSubintent { subintent, inputs } => (
+            "EXECUTE_SUBINTENT",
+            to_manifest_value(&(subintent, inputs))?,
+        ),
     };
 
     write!(f, "{}", display_name)?;
@@ -124,6 +124,13 @@ TAKE_ALL_FROM_WORKTOP
 RETURN_TO_WORKTOP
     Bucket("bucket2")
 ;
+ASSERT_WORKTOP_CONTAINS_NON_FUNGIBLES
+    Address("${non_fungible_resource_address}")
+    Array<NonFungibleLocalId>(
+        NonFungibleLocalId("#1#"),
+        NonFungibleLocalId("#2#")
+    )
+;
 TAKE_NON_FUNGIBLES_FROM_WORKTOP
     Address("${non_fungible_resource_address}")
     Array<NonFungibleLocalId>(
@@ -1,4 +1,5 @@
 use super::blob_provider::*;
+use super::schema_source::*;
 use crate::data::*;
 use crate::errors::*;
 use crate::internal_prelude::TransactionManifestV1;
@@ -31,6 +32,7 @@ use radix_engine_interface::blueprints::consensus_manager::CONSENSUS_MANAGER_CRE
 use radix_engine_interface::blueprints::identity::{
     IDENTITY_BLUEPRINT, IDENTITY_CREATE_ADVANCED_IDENT, IDENTITY_CREATE_IDENT,
 };
+use radix_engine_interface::blueprints::package::CodeHash;
 use radix_engine_interface::blueprints::package::PACKAGE_BLUEPRINT;
 use radix_engine_interface::blueprints::package::PACKAGE_CLAIM_ROYALTIES_IDENT;
 use radix_engine_interface::blueprints::package::PACKAGE_PUBLISH_WASM_ADVANCED_IDENT;
@@ -112,6 +114,10 @@ pub enum GeneratorError {
         expected_length: usize,
         actual: usize,
     },
+    ArgumentsDoNotMatchSchema {
+        function_name: String,
+        error: String,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -228,19 +234,39 @@ pub fn generate_manifest<B>(
 ) -> Result<TransactionManifestV1, GeneratorError>
 where
     B: IsBlobProvider,
+{
+    generate_manifest_with_schemas(
+        instructions,
+        address_bech32_decoder,
+        blobs,
+        &EmptySchemaSource,
+    )
+}
+
+pub fn generate_manifest_with_schemas<B, S>(
+    instructions: &[ast::Instruction],
+    address_bech32_decoder: &AddressBech32Decoder,
+    blobs: B,
+    schema_source: &S,
+) -> Result<TransactionManifestV1, GeneratorError>
+where
+    B: IsBlobProvider,
+    S: IsSchemaSource,
 {
     let mut id_validator = ManifestValidator::new();
     let mut name_resolver = NameResolver::new();
     let mut output = Vec::new();
 
     for instruction in instructions {
-        output.push(generate_instruction(
+        let instruction = generate_instruction(
             instruction,
             &mut id_validator,
             &mut name_resolver,
             address_bech32_decoder,
             &blobs,
-        )?);
+        )?;
+        check_args_against_schema(&instruction, schema_source)?;
+        output.push(instruction);
     }
 
     Ok(TransactionManifestV1 {
@@ -249,6 +275,52 @@ where
     })
 }
 
+fn check_args_against_schema<S: IsSchemaSource>(
+    instruction: &InstructionV1,
+    schema_source: &S,
+) -> Result<(), GeneratorError> {
+    let (name, args, input_schema) = match instruction {
+        InstructionV1::CallFunction {
+            package_address: DynamicPackageAddress::Static(package_address),
+            blueprint_name,
+            function_name,
+            args,
+        } => (
+            function_name,
+            args,
+            schema_source.function_input_schema(package_address, blueprint_name, function_name),
+        ),
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(address),
+            method_name,
+            args,
+        } => (
+            method_name,
+            args,
+            schema_source.method_input_schema(address, method_name),
+        ),
+        _ => return Ok(()),
+    };
+    let input_schema = match input_schema {
+        Some(input_schema) => input_schema,
+        None => return Ok(()),
+    };
+
+    let payload = manifest_encode(args).map_err(GeneratorError::ArgumentEncodingError)?;
+    let schema = input_schema.schema.v1();
+    validate_payload_against_schema::<ManifestCustomExtension, _>(
+        &payload,
+        schema,
+        input_schema.type_id,
+        &(),
+        MANIFEST_SBOR_V1_MAX_DEPTH,
+    )
+    .map_err(|error| GeneratorError::ArgumentsDoNotMatchSchema {
+        function_name: name.clone(),
+        error: error.error_message(schema),
+    })
+}
+
 pub fn generate_instruction<B>(
     instruction: &ast::Instruction,
     id_validator: &mut ManifestValidator,
@@ -596,6 +668,70 @@ where
                 blueprint_name: generate_string(&blueprint_name)?,
             }
         }
+        ast::Instruction::AssertPackageCodeHash {
+            package_address,
+            code_hash,
+        } => InstructionV1::AssertPackageCodeHash {
+            package_address: generate_package_address(package_address, address_bech32_decoder)?,
+            code_hash: generate_code_hash(code_hash)?,
+        },
+        ast::Instruction::EnqueueDeferredManifest {
+            instructions,
+            execute_at_epoch,
+            expiry_epoch,
+            payment,
+            canceller,
+        } => {
+            let payment = generate_bucket(payment, resolver)?;
+            id_validator
+                .drop_bucket(&payment)
+                .map_err(GeneratorError::IdValidationError)?;
+            InstructionV1::EnqueueDeferredManifest {
+                instructions: generate_typed_value(
+                    instructions,
+                    resolver,
+                    address_bech32_decoder,
+                    blobs,
+                )?,
+                execute_at_epoch: generate_typed_value(
+                    execute_at_epoch,
+                    resolver,
+                    address_bech32_decoder,
+                    blobs,
+                )?,
+                expiry_epoch: generate_typed_value(
+                    expiry_epoch,
+                    resolver,
+                    address_bech32_decoder,
+                    blobs,
+                )?,
+                payment,
+                canceller: generate_typed_value(
+                    canceller,
+                    resolver,
+                    address_bech32_decoder,
+                    blobs,
+                )?,
+            }
+        }
+        ast::Instruction::ExecuteSubintent { subintent, inputs } => {
+            let inputs: Vec<ManifestBucket> =
+                generate_typed_value(inputs, resolver, address_bech32_decoder, blobs)?;
+            for input in &inputs {
+                id_validator
+                    .drop_bucket(input)
+                    .map_err(GeneratorError::IdValidationError)?;
+            }
+            InstructionV1::ExecuteSubintent {
+                subintent: generate_typed_value(
+                    subintent,
+                    resolver,
+                    address_bech32_decoder,
+                    blobs,
+                )?,
+                inputs,
+            }
+        }
 
         /* direct vault method aliases */
         ast::Instruction::RecallFromVault { vault_id, args } => {
@@ -792,6 +928,11 @@ where
             method_name: NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT.to_string(),
             args: generate_args(args, resolver, address_bech32_decoder, blobs)?,
         },
+        ast::Instruction::RecallFromVaults { address, args } => InstructionV1::CallMethod {
+            address: generate_dynamic_global_address(address, address_bech32_decoder, resolver)?,
+            method_name: FUNGIBLE_RESOURCE_MANAGER_RECALL_BATCH_IDENT.to_string(),
+            args: generate_args(args, resolver, address_bech32_decoder, blobs)?,
+        },
         ast::Instruction::ClaimPackageRoyalties { address, args } => InstructionV1::CallMethod {
             address: generate_dynamic_global_address(address, address_bech32_decoder, resolver)?,
             method_name: PACKAGE_CLAIM_ROYALTIES_IDENT.to_string(),
@@ -838,6 +979,22 @@ where
     Ok(ManifestValue::Tuple { fields })
 }
 
+/// Generates a value without dedicated manifest syntax, by decoding it from its manifest value.
+fn generate_typed_value<T, B>(
+    value: &ast::Value,
+    resolver: &mut NameResolver,
+    address_bech32_decoder: &AddressBech32Decoder,
+    blobs: &B,
+) -> Result<T, GeneratorError>
+where
+    T: ManifestDecode,
+    B: IsBlobProvider,
+{
+    let value = generate_value(value, None, resolver, address_bech32_decoder, blobs)?;
+    let payload = manifest_encode(&value).map_err(GeneratorError::ArgumentEncodingError)?;
+    manifest_decode(&payload).map_err(GeneratorError::ArgumentDecodingError)
+}
+
 fn generate_string(value: &ast::Value) -> Result<String, GeneratorError> {
     match value {
         ast::Value::String(s) => Ok(s.into()),
@@ -1231,6 +1388,22 @@ fn generate_non_fungible_local_ids(
     }
 }
 
+fn generate_code_hash(value: &ast::Value) -> Result<CodeHash, GeneratorError> {
+    match value {
+        ast::Value::Bytes(inner) => {
+            let bytes = generate_byte_vec_from_hex(inner)?;
+            let hash =
+                Hash::try_from(bytes.as_slice()).map_err(|_| GeneratorError::InvalidLength {
+                    value_type: ast::ValueKind::Bytes,
+                    expected_length: Hash::LENGTH,
+                    actual: bytes.len(),
+                })?;
+            Ok(CodeHash(hash))
+        }
+        v => invalid_type!(v, ast::ValueKind::Bytes),
+    }
+}
+
 fn generate_byte_vec_from_hex(value: &ast::Value) -> Result<Vec<u8>, GeneratorError> {
     let bytes = match value {
         ast::Value::String(s) => {
@@ -1488,7 +1661,7 @@ mod tests {
     use crate::manifest::parser::{Parser, ParserError, PARSER_MAX_DEPTH};
     use radix_engine_common::constants::CONSENSUS_MANAGER;
     use radix_engine_common::manifest_args;
-    use radix_engine_common::types::{ComponentAddress, PackageAddress};
+    use radix_engine_common::types::{ComponentAddress, Epoch, PackageAddress};
     use radix_engine_interface::address::AddressBech32Decoder;
     use radix_engine_interface::api::node_modules::metadata::MetadataValue;
     use radix_engine_interface::api::node_modules::ModuleConfig;
@@ -1693,6 +1866,16 @@ mod tests {
                 resource_address,
             },
         );
+        generate_instruction_ok!(
+            r##"ASSERT_WORKTOP_CONTAINS_NON_FUNGIBLES  Address("resource_sim1thvwu8dh6lk4y9mntemkvj25wllq8adq42skzufp4m8wxxuemugnez")  Array<NonFungibleLocalId>(NonFungibleLocalId("#1#"), NonFungibleLocalId("<hello>"));"##,
+            InstructionV1::AssertWorktopContainsNonFungibles {
+                resource_address,
+                ids: vec![
+                    NonFungibleLocalId::integer(1),
+                    NonFungibleLocalId::string("hello").unwrap(),
+                ],
+            },
+        );
         generate_instruction_ok!(
             r#"CALL_FUNCTION  Address("package_sim1p4r4955skdjq9swg8s5jguvcjvyj7tsxct87a9z6sw76cdfd2jg3zk")  "Airdrop"  "new"  500u32  PreciseDecimal("120");"#,
             InstructionV1::CallFunction {
@@ -1718,6 +1901,31 @@ mod tests {
                 args: manifest_args!(dec!("100")).into()
             },
         );
+        let vault_address = InternalAddress::try_from_bech32(
+            &address_bech32_decoder,
+            "internal_vault_sim1tqvgx33089ukm2pl97pv4max0x40ruvfy4lt60yvya744cvevp72ff",
+        )
+        .unwrap();
+        generate_instruction_ok!(
+            r#"RECALL_FROM_VAULTS Address("resource_sim1thvwu8dh6lk4y9mntemkvj25wllq8adq42skzufp4m8wxxuemugnez") Array<Tuple>(Tuple(Address("internal_vault_sim1tqvgx33089ukm2pl97pv4max0x40ruvfy4lt60yvya744cvevp72ff"), Decimal("5")));"#,
+            InstructionV1::CallMethod {
+                address: resource_address.into(),
+                method_name: "recall_batch".to_string(),
+                args: manifest_args!(vec![(vault_address, dec!("5"))]).into()
+            },
+        );
+        generate_instruction_ok!(
+            r#"ASSERT_PACKAGE_CODE_HASH Address("package_sim1p4r4955skdjq9swg8s5jguvcjvyj7tsxct87a9z6sw76cdfd2jg3zk") Bytes("2bd80a8f4df4bb3fb2a3a5c1d2a9e3e3c1ab4d4f9a72a8b5e0f5f8a1c1b4d6e7");"#,
+            InstructionV1::AssertPackageCodeHash {
+                package_address,
+                code_hash: CodeHash(
+                    Hash::from_str(
+                        "2bd80a8f4df4bb3fb2a3a5c1d2a9e3e3c1ab4d4f9a72a8b5e0f5f8a1c1b4d6e7"
+                    )
+                    .unwrap()
+                ),
+            },
+        );
     }
 
     #[test]
@@ -2119,6 +2327,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_enqueue_deferred_manifest_instruction() {
+        let tokens = tokenize(
+            r#"
+            ENQUEUE_DEFERRED_MANIFEST
+                Array<Enum>(
+                    Enum<0x41u8>(
+                        Address("consensusmanager_sim1scxxxxxxxxxxcnsmgrxxxxxxxxx000999665565xxxxxxxxxxc06cl"),
+                        "get_current_epoch",
+                        Tuple()
+                    )
+                )
+                10u64
+                20u64
+                Bucket("xrd_bucket")
+                Enum<0u8>();
+            "#
+        ).unwrap();
+        let instruction = Parser::new(tokens, PARSER_MAX_DEPTH)
+            .parse_instruction()
+            .unwrap();
+        let mut id_validator = ManifestValidator::new();
+        let mut resolver = NameResolver::new();
+        let bucket = id_validator.new_bucket();
+        resolver
+            .named_buckets
+            .insert("xrd_bucket".to_string(), bucket);
+        assert_eq!(
+            generate_instruction(
+                &instruction,
+                &mut id_validator,
+                &mut resolver,
+                &AddressBech32Decoder::new(&NetworkDefinition::simulator()),
+                &MockBlobProvider::default()
+            ),
+            Ok(InstructionV1::EnqueueDeferredManifest {
+                instructions: vec![InstructionV1::CallMethod {
+                    address: CONSENSUS_MANAGER.into(),
+                    method_name: "get_current_epoch".to_string(),
+                    args: manifest_args!().into(),
+                }],
+                execute_at_epoch: Epoch::of(10),
+                expiry_epoch: Epoch::of(20),
+                payment: bucket,
+                canceller: AccessRule::AllowAll,
+            })
+        );
+    }
+
     macro_rules! generate_manifest_input_with_given_depth {
         ( $depth:expr ) => {{
             let depth: usize = $depth;
@@ -2198,4 +2455,59 @@ mod tests {
         let result = manifest_encode(&compiled);
         assert_eq!(result, Err(expected));
     }
+
+    #[test]
+    fn test_call_function_args_are_checked_against_known_schema() {
+        use crate::manifest::*;
+        use radix_engine_interface::blueprints::package::{
+            BlueprintDefinitionInit, PackageDefinition,
+        };
+        use radix_engine_interface::data::scrypto::ScryptoCustomSchema;
+        use radix_engine_interface::schema::{FunctionSchemaInit, TypeRef};
+
+        let network = NetworkDefinition::simulator();
+        let package_address = PackageAddress::try_from_bech32(
+            &AddressBech32Decoder::new(&network),
+            "package_sim1p4r4955skdjq9swg8s5jguvcjvyj7tsxct87a9z6sw76cdfd2jg3zk",
+        )
+        .unwrap();
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<(u32, PreciseDecimal), ScryptoCustomSchema>();
+        let mut blueprint = BlueprintDefinitionInit::default();
+        blueprint.schema.schema = schema;
+        blueprint.schema.functions.functions.insert(
+            "new".to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(type_id),
+                output: TypeRef::Static(LocalTypeId::WellKnown(basic_well_known_types::UNIT_TYPE)),
+                export: "Airdrop_new".to_string(),
+            },
+        );
+        let mut blueprints = IndexMap::new();
+        blueprints.insert("Airdrop".to_string(), blueprint);
+        let mut schema_source = SchemaSource::new();
+        schema_source.add_package(package_address, &PackageDefinition { blueprints });
+
+        let result = compile_with_schemas(
+            r#"CALL_FUNCTION Address("package_sim1p4r4955skdjq9swg8s5jguvcjvyj7tsxct87a9z6sw76cdfd2jg3zk") "Airdrop" "new" 500u32 PreciseDecimal("120");"#,
+            &network,
+            BlobProvider::default(),
+            &schema_source,
+        );
+        assert!(result.is_ok());
+
+        let result = compile_with_schemas(
+            r#"CALL_FUNCTION Address("package_sim1p4r4955skdjq9swg8s5jguvcjvyj7tsxct87a9z6sw76cdfd2jg3zk") "Airdrop" "new" "500" PreciseDecimal("120");"#,
+            &network,
+            BlobProvider::default(),
+            &schema_source,
+        );
+        assert!(matches!(
+            result,
+            Err(CompileError::GeneratorError(
+                GeneratorError::ArgumentsDoNotMatchSchema { .. }
+            ))
+        ));
+    }
 }
@@ -61,6 +61,9 @@ pub enum InstructionIdent {
     DropNamedProofs,
     DropAllProofs,
     AllocateGlobalAddress,
+    AssertPackageCodeHash,
+    EnqueueDeferredManifest,
+    ExecuteSubintent,
 
     // ==============
     // Call direct vault method aliases
@@ -105,6 +108,7 @@ pub enum InstructionIdent {
     MintFungible,
     MintNonFungible,
     MintRuidNonFungible,
+    RecallFromVaults,
     CreateValidator,
 }
 
@@ -156,6 +160,9 @@ impl InstructionIdent {
             "DROP_NAMED_PROOFS" => InstructionIdent::DropNamedProofs,
             "DROP_ALL_PROOFS" => InstructionIdent::DropAllProofs,
             "ALLOCATE_GLOBAL_ADDRESS" => InstructionIdent::AllocateGlobalAddress,
+            "ASSERT_PACKAGE_CODE_HASH" => InstructionIdent::AssertPackageCodeHash,
+            "ENQUEUE_DEFERRED_MANIFEST" => InstructionIdent::EnqueueDeferredManifest,
+            "EXECUTE_SUBINTENT" => InstructionIdent::ExecuteSubintent,
 
             // ==============
             // Call direct vault method aliases
@@ -203,6 +210,7 @@ impl InstructionIdent {
             "MINT_FUNGIBLE" => InstructionIdent::MintFungible,
             "MINT_NON_FUNGIBLE" => InstructionIdent::MintNonFungible,
             "MINT_RUID_NON_FUNGIBLE" => InstructionIdent::MintRuidNonFungible,
+            "RECALL_FROM_VAULTS" => InstructionIdent::RecallFromVaults,
             "CLAIM_PACKAGE_ROYALTIES" => InstructionIdent::ClaimPackageRoyalties,
             "CREATE_VALIDATOR" => InstructionIdent::CreateValidator,
             _ => {
@@ -613,6 +621,21 @@ impl Parser {
                 address_reservation: self.parse_value()?,
                 named_address: self.parse_value()?,
             },
+            InstructionIdent::AssertPackageCodeHash => Instruction::AssertPackageCodeHash {
+                package_address: self.parse_value()?,
+                code_hash: self.parse_value()?,
+            },
+            InstructionIdent::EnqueueDeferredManifest => Instruction::EnqueueDeferredManifest {
+                instructions: self.parse_value()?,
+                execute_at_epoch: self.parse_value()?,
+                expiry_epoch: self.parse_value()?,
+                payment: self.parse_value()?,
+                canceller: self.parse_value()?,
+            },
+            InstructionIdent::ExecuteSubintent => Instruction::ExecuteSubintent {
+                subintent: self.parse_value()?,
+                inputs: self.parse_value()?,
+            },
 
             /* Call direct vault method aliases */
             InstructionIdent::RecallFromVault => Instruction::RecallFromVault {
@@ -724,6 +747,10 @@ impl Parser {
                 address: self.parse_value()?,
                 args: self.parse_values_till_semicolon()?,
             },
+            InstructionIdent::RecallFromVaults => Instruction::RecallFromVaults {
+                address: self.parse_value()?,
+                args: self.parse_values_till_semicolon()?,
+            },
             InstructionIdent::ClaimPackageRoyalties => Instruction::ClaimPackageRoyalties {
                 address: self.parse_value()?,
                 args: self.parse_values_till_semicolon()?,
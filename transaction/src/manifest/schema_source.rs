@@ -0,0 +1,132 @@
+use radix_engine_interface::blueprints::package::PackageDefinition;
+use radix_engine_interface::prelude::*;
+use radix_engine_interface::schema::TypeRef;
+
+/// The schema of the arguments of a blueprint function or method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputSchema {
+    pub schema: VersionedScryptoSchema,
+    pub type_id: LocalTypeId,
+}
+
+//========
+// Traits
+//========
+
+/// Provides the input schemas of blueprint functions and methods, so that the arguments of
+/// `CALL_FUNCTION` and `CALL_METHOD` instructions can be checked while compiling a manifest.
+pub trait IsSchemaSource {
+    fn function_input_schema(
+        &self,
+        package_address: &PackageAddress,
+        blueprint_name: &str,
+        function_name: &str,
+    ) -> Option<InputSchema>;
+
+    fn method_input_schema(
+        &self,
+        address: &GlobalAddress,
+        method_name: &str,
+    ) -> Option<InputSchema>;
+}
+
+//=========================
+// Default Schema Source
+//=========================
+
+/// A schema source built from the definitions of packages, along with the blueprints of the
+/// components the manifest is expected to call.
+#[derive(Default, Debug, Clone)]
+pub struct SchemaSource {
+    functions: IndexMap<(BlueprintId, String), InputSchema>,
+    methods: IndexMap<(BlueprintId, String), InputSchema>,
+    components: IndexMap<GlobalAddress, BlueprintId>,
+}
+
+impl SchemaSource {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Registers the functions and methods of every blueprint in the package.
+    ///
+    /// Functions and methods with a generic input aren't registered, so they are not checked.
+    pub fn add_package(&mut self, package_address: PackageAddress, definition: &PackageDefinition) {
+        for (blueprint_name, blueprint) in &definition.blueprints {
+            let blueprint_id = BlueprintId::new(&package_address, blueprint_name);
+            for (name, function) in &blueprint.schema.functions.functions {
+                let type_id = match function.input {
+                    TypeRef::Static(type_id) => type_id,
+                    TypeRef::Generic(_) => continue,
+                };
+                let input_schema = InputSchema {
+                    schema: blueprint.schema.schema.clone(),
+                    type_id,
+                };
+                let key = (blueprint_id.clone(), name.clone());
+                if function.receiver.is_some() {
+                    self.methods.insert(key, input_schema);
+                } else {
+                    self.functions.insert(key, input_schema);
+                }
+            }
+        }
+    }
+
+    /// Records which blueprint the component at the given address is an instance of.
+    pub fn add_component<A: Into<GlobalAddress>>(&mut self, address: A, blueprint_id: BlueprintId) {
+        self.components.insert(address.into(), blueprint_id);
+    }
+}
+
+impl IsSchemaSource for SchemaSource {
+    fn function_input_schema(
+        &self,
+        package_address: &PackageAddress,
+        blueprint_name: &str,
+        function_name: &str,
+    ) -> Option<InputSchema> {
+        let blueprint_id = BlueprintId::new(package_address, blueprint_name);
+        self.functions
+            .get(&(blueprint_id, function_name.to_string()))
+            .cloned()
+    }
+
+    fn method_input_schema(
+        &self,
+        address: &GlobalAddress,
+        method_name: &str,
+    ) -> Option<InputSchema> {
+        let blueprint_id = self.components.get(address)?;
+        self.methods
+            .get(&(blueprint_id.clone(), method_name.to_string()))
+            .cloned()
+    }
+}
+
+//======================
+// Empty Schema Source
+//======================
+
+/// A schema source which knows no schemas, so no arguments are checked.
+#[derive(Default, Debug, Clone)]
+pub struct EmptySchemaSource;
+
+impl IsSchemaSource for EmptySchemaSource {
+    fn function_input_schema(
+        &self,
+        _package_address: &PackageAddress,
+        _blueprint_name: &str,
+        _function_name: &str,
+    ) -> Option<InputSchema> {
+        None
+    }
+
+    fn method_input_schema(
+        &self,
+        _address: &GlobalAddress,
+        _method_name: &str,
+    ) -> Option<InputSchema> {
+        None
+    }
+}
@@ -3,7 +3,16 @@ use crate::internal_prelude::*;
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, Default)]
 pub struct AuthZoneParams {
     pub initial_proofs: BTreeSet<NonFungibleGlobalId>,
+    /// The signature proofs of the signers of the intent. Unlike the initial proofs, these don't
+    /// include the proofs assumed by a preview or given to system transactions.
+    pub signatories: BTreeSet<NonFungibleGlobalId>,
     pub virtual_resources: BTreeSet<ResourceAddress>,
+    /// Amounts of resources which are assumed to be present in the initial auth zone, for
+    /// `require(resource)` and `require_amount(..)` checks.
+    pub virtual_resource_amounts: BTreeMap<ResourceAddress, Decimal>,
+    /// The signature proofs of the signers of each subintent, in the order in which the
+    /// subintents are executed.
+    pub subintent_initial_proofs: Vec<BTreeSet<NonFungibleGlobalId>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -15,12 +24,20 @@ pub struct EpochRange {
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct ExecutionContext {
     pub intent_hash: TransactionIntentHash,
+    /// The hashes of the subintents executed by the transaction, which are tracked alongside
+    /// the intent hash
+    pub subintent_hashes: Vec<TransactionIntentHash>,
     pub epoch_range: Option<EpochRange>,
     pub pre_allocated_addresses: Vec<PreAllocatedAddress>,
     pub payload_size: usize,
     pub num_of_signature_validations: usize,
     pub auth_zone_params: AuthZoneParams,
     pub costing_parameters: TransactionCostingParameters,
+    /// If set, the resources left on the worktop at the end of the transaction are deposited
+    /// into this account instead of failing the transaction
+    pub worktop_leftovers_recipient: Option<ComponentAddress>,
+    /// The message attached to the transaction, for recording in the receipt
+    pub message: Option<TransactionMessageMetadata>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -98,6 +115,14 @@ impl<'a> Executable<'a> {
         for proof in &context.auth_zone_params.initial_proofs {
             references.insert(proof.resource_address().clone().into());
         }
+        for proof in context
+            .auth_zone_params
+            .subintent_initial_proofs
+            .iter()
+            .flatten()
+        {
+            references.insert(proof.resource_address().clone().into());
+        }
         for resource in &context.auth_zone_params.virtual_resources {
             references.insert(resource.clone().into());
         }
@@ -110,6 +135,9 @@ impl<'a> Executable<'a> {
                     .into(),
             );
         }
+        if let Some(recipient) = context.worktop_leftovers_recipient {
+            references.insert(recipient.into());
+        }
 
         Self {
             encoded_instructions,
@@ -123,6 +151,10 @@ impl<'a> Executable<'a> {
         &self.context.intent_hash
     }
 
+    pub fn subintent_hashes(&self) -> &[TransactionIntentHash] {
+        &self.context.subintent_hashes
+    }
+
     pub fn epoch_range(&self) -> Option<&EpochRange> {
         self.context.epoch_range.as_ref()
     }
@@ -140,6 +172,19 @@ impl<'a> Executable<'a> {
         self.context.epoch_range = None;
     }
 
+    pub fn set_worktop_leftovers_recipient(&mut self, recipient: ComponentAddress) {
+        self.references.insert(recipient.into());
+        self.context.worktop_leftovers_recipient = Some(recipient);
+    }
+
+    pub fn set_tip_percentage(&mut self, tip_percentage: u16) {
+        self.context.costing_parameters.tip_percentage = tip_percentage;
+    }
+
+    pub fn message(&self) -> Option<&TransactionMessageMetadata> {
+        self.context.message.as_ref()
+    }
+
     pub fn costing_parameters(&self) -> &TransactionCostingParameters {
         &self.context.costing_parameters
     }
@@ -164,6 +209,10 @@ impl<'a> Executable<'a> {
         &self.context.pre_allocated_addresses
     }
 
+    pub fn worktop_leftovers_recipient(&self) -> Option<&ComponentAddress> {
+        self.context.worktop_leftovers_recipient.as_ref()
+    }
+
     pub fn payload_size(&self) -> usize {
         self.context.payload_size
     }
@@ -208,11 +208,14 @@ Enum<3u8>(
                         ),
                         expiry_epoch: Epoch::of(66)
                     },
+                    subintent_hashes: vec![],
                     epoch_range: Some(EpochRange {
                         start_epoch_inclusive: Epoch::of(55),
                         end_epoch_exclusive: Epoch::of(66)
                     }),
                     pre_allocated_addresses: vec![],
+                    worktop_leftovers_recipient: None,
+                    message: None,
                     // Source of discrepancy:
                     // * Manifest SBOR payload prefix byte: not counted
                     // * Array header: should be 1 + 1 + len(LEB128(size)), instead of fixed 2
@@ -224,7 +227,13 @@ Enum<3u8>(
                             NonFungibleGlobalId::from_public_key(&sig_1_private_key.public_key()),
                             NonFungibleGlobalId::from_public_key(&sig_2_private_key.public_key())
                         ),
-                        virtual_resources: btreeset!()
+                        signatories: btreeset!(
+                            NonFungibleGlobalId::from_public_key(&sig_1_private_key.public_key()),
+                            NonFungibleGlobalId::from_public_key(&sig_2_private_key.public_key())
+                        ),
+                        virtual_resources: btreeset!(),
+                        virtual_resource_amounts: btreemap!(),
+                        subintent_initial_proofs: vec![]
                     },
                     costing_parameters: TransactionCostingParameters {
                         tip_percentage: 4,
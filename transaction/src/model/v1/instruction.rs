@@ -1,5 +1,7 @@
 use crate::prelude::*;
 use radix_engine_common::data::scrypto::model::*;
+use radix_engine_interface::blueprints::package::CodeHash;
+use radix_engine_interface::blueprints::resource::AccessRule;
 use radix_engine_interface::data::manifest::{model::*, ManifestValue};
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::types::*;
@@ -698,6 +700,37 @@ pub enum InstructionV1 {
         package_address: PackageAddress,
         blueprint_name: String,
     },
+
+    /// Asserts that the package contains the code with the given hash.
+    #[sbor(discriminator(INSTRUCTION_ASSERT_PACKAGE_CODE_HASH_DISCRIMINATOR))]
+    AssertPackageCodeHash {
+        package_address: PackageAddress,
+        code_hash: CodeHash,
+    },
+
+    /// Enqueues the instructions into the deferred execution queue, paid for by the bucket.
+    ///
+    /// Anyone can execute the instructions from `execute_at_epoch` until `expiry_epoch`, and
+    /// receives the payment for doing so. Until then, they can be cancelled and the payment
+    /// reclaimed by whoever satisfies the `canceller` rule.
+    #[sbor(discriminator(INSTRUCTION_ENQUEUE_DEFERRED_MANIFEST_DISCRIMINATOR))]
+    EnqueueDeferredManifest {
+        instructions: Vec<InstructionV1>,
+        execute_at_epoch: Epoch,
+        expiry_epoch: Epoch,
+        payment: ManifestBucket,
+        canceller: AccessRule,
+    },
+
+    /// Executes a signed subintent in its own frame, passing the buckets into its worktop.
+    ///
+    /// The buckets must cover the required inputs of the subintent, and everything left on its
+    /// worktop, which must cover its declared outputs, is put onto the worktop.
+    #[sbor(discriminator(INSTRUCTION_EXECUTE_SUBINTENT_DISCRIMINATOR))]
+    ExecuteSubintent {
+        subintent: SignedSubintentV1,
+        inputs: Vec<ManifestBucket>,
+    },
 }
 
 //===============================================================
@@ -766,3 +799,6 @@ pub const INSTRUCTION_CALL_DIRECT_VAULT_METHOD_DISCRIMINATOR: u8 = 0x45;
 pub const INSTRUCTION_DROP_NAMED_PROOFS_DISCRIMINATOR: u8 = 0x52;
 pub const INSTRUCTION_DROP_ALL_PROOFS_DISCRIMINATOR: u8 = 0x50;
 pub const INSTRUCTION_ALLOCATE_GLOBAL_ADDRESS_DISCRIMINATOR: u8 = 0x51;
+pub const INSTRUCTION_ASSERT_PACKAGE_CODE_HASH_DISCRIMINATOR: u8 = 0x53;
+pub const INSTRUCTION_ENQUEUE_DEFERRED_MANIFEST_DISCRIMINATOR: u8 = 0x54;
+pub const INSTRUCTION_EXECUTE_SUBINTENT_DISCRIMINATOR: u8 = 0x55;
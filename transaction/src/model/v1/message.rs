@@ -19,7 +19,7 @@ impl Default for MessageV1 {
 // PLAINTEXT MESSAGE
 //============================================================================
 
-#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor, ScryptoSbor)]
 pub struct PlaintextMessageV1 {
     pub mime_type: String,
     pub message: MessageContentsV1,
@@ -29,7 +29,7 @@ pub struct PlaintextMessageV1 {
 /// whether the message is intended to be displayable as text, or not.
 ///
 /// This data model ensures that messages intended to be displayable as text are valid unicode strings.
-#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor, ScryptoSbor)]
 pub enum MessageContentsV1 {
     String(String),
     Bytes(Vec<u8>),
@@ -76,7 +76,7 @@ pub struct EncryptedMessageV1 {
     pub decryptors_by_curve: IndexMap<CurveType, DecryptorsByCurve>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ManifestSbor)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ManifestSbor, ScryptoSbor)]
 pub enum CurveType {
     Ed25519,
     Secp256k1,
@@ -108,11 +108,18 @@ impl DecryptorsByCurve {
             DecryptorsByCurve::Secp256k1 { decryptors, .. } => decryptors.len(),
         }
     }
+
+    pub fn fingerprints(&self) -> Vec<PublicKeyFingerprint> {
+        match self {
+            DecryptorsByCurve::Ed25519 { decryptors, .. } => decryptors.keys().cloned().collect(),
+            DecryptorsByCurve::Secp256k1 { decryptors, .. } => decryptors.keys().cloned().collect(),
+        }
+    }
 }
 
 /// The last 8 bytes of the Blake2b-256 hash of the public key bytes,
 /// in their standard Radix byte-serialization.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, ManifestSbor)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, ManifestSbor, ScryptoSbor)]
 #[sbor(transparent)]
 pub struct PublicKeyFingerprint(pub [u8; Self::LENGTH]);
 
@@ -161,6 +168,45 @@ impl AesWrapped128BitKey {
     pub const LENGTH: usize = 24;
 }
 
+//============================================================================
+// METADATA
+//============================================================================
+
+/// The message of a transaction, as recorded in its receipt.
+///
+/// Plaintext messages are recorded in full. Encrypted messages are recorded by the length of
+/// their payload and the fingerprints of their decryptors, which lets a wallet tell whether it
+/// can decrypt a message without having to fetch the transaction.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum TransactionMessageMetadata {
+    Plaintext(PlaintextMessageV1),
+    Encrypted {
+        encrypted_length: usize,
+        decryptors_by_curve: IndexMap<CurveType, Vec<PublicKeyFingerprint>>,
+    },
+}
+
+impl MessageV1 {
+    pub fn metadata(&self) -> Option<TransactionMessageMetadata> {
+        match self {
+            MessageV1::None => None,
+            MessageV1::Plaintext(message) => {
+                Some(TransactionMessageMetadata::Plaintext(message.clone()))
+            }
+            MessageV1::Encrypted(EncryptedMessageV1 {
+                encrypted,
+                decryptors_by_curve,
+            }) => Some(TransactionMessageMetadata::Encrypted {
+                encrypted_length: encrypted.0.len(),
+                decryptors_by_curve: decryptors_by_curve
+                    .iter()
+                    .map(|(curve_type, decryptors)| (*curve_type, decryptors.fingerprints()))
+                    .collect(),
+            }),
+        }
+    }
+}
+
 //============================================================================
 // PREPARATION
 //============================================================================
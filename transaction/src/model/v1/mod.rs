@@ -12,6 +12,7 @@ mod notarized_transaction;
 mod notary_signature;
 mod preview_transaction;
 mod signed_intent;
+mod subintent;
 mod system_transaction;
 mod test_transaction;
 mod validated_notarized_transaction;
@@ -28,6 +29,7 @@ pub use notarized_transaction::*;
 pub use notary_signature::*;
 pub use preview_transaction::*;
 pub use signed_intent::*;
+pub use subintent::*;
 pub use system_transaction::*;
 pub use test_transaction::*;
 pub use validated_notarized_transaction::*;
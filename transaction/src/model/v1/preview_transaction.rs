@@ -8,6 +8,16 @@ pub struct PreviewFlags {
     pub skip_epoch_check: bool,
 }
 
+/// A proof which is placed into the initial auth zone of a preview, without the badge having
+/// to be owned by any of the signers.
+///
+/// Virtual proofs can only satisfy access rules; they can't be popped from the auth zone.
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub enum VirtualProof {
+    Amount(ResourceAddress, Decimal),
+    NonFungibles(ResourceAddress, BTreeSet<NonFungibleLocalId>),
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
 pub struct PreviewIntentV1 {
     pub intent: IntentV1,
@@ -15,11 +25,33 @@ pub struct PreviewIntentV1 {
     pub flags: PreviewFlags,
 }
 
+/// A [`PreviewIntentV1`] extended with the virtual proofs to place into the initial auth zone.
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct PreviewIntentV2 {
+    pub intent: IntentV1,
+    pub signer_public_keys: Vec<PublicKey>,
+    pub flags: PreviewFlags,
+    pub virtual_proofs: Vec<VirtualProof>,
+}
+
+impl From<PreviewIntentV1> for PreviewIntentV2 {
+    fn from(preview_intent: PreviewIntentV1) -> Self {
+        Self {
+            intent: preview_intent.intent,
+            signer_public_keys: preview_intent.signer_public_keys,
+            flags: preview_intent.flags,
+            virtual_proofs: vec![],
+        }
+    }
+}
+
 pub struct ValidatedPreviewIntent {
     pub intent: PreparedIntentV1,
     pub encoded_instructions: Vec<u8>,
+    pub subintents: Vec<ValidatedSubintentV1>,
     pub signer_public_keys: Vec<PublicKey>,
     pub flags: PreviewFlags,
+    pub virtual_proofs: Vec<VirtualProof>,
 }
 
 impl ValidatedPreviewIntent {
@@ -42,7 +74,24 @@ impl ValidatedPreviewIntent {
                 Decimal::ZERO
             },
         };
-        let initial_proofs = AuthAddresses::signer_set(&self.signer_public_keys);
+        let mut initial_proofs = AuthAddresses::signer_set(&self.signer_public_keys);
+        let mut virtual_resource_amounts = BTreeMap::<ResourceAddress, Decimal>::new();
+        for virtual_proof in &self.virtual_proofs {
+            let (resource_address, amount) = match virtual_proof {
+                VirtualProof::Amount(resource_address, amount) => (*resource_address, *amount),
+                VirtualProof::NonFungibles(resource_address, ids) => {
+                    initial_proofs.extend(
+                        ids.iter()
+                            .map(|id| NonFungibleGlobalId::new(*resource_address, id.clone())),
+                    );
+                    (*resource_address, Decimal::from(ids.len()))
+                }
+            };
+            let total = virtual_resource_amounts
+                .entry(resource_address)
+                .or_insert(Decimal::ZERO);
+            *total = total.checked_add(amount).unwrap_or(Decimal::MAX);
+        }
 
         let intent_hash = intent.intent_hash();
 
@@ -61,6 +110,22 @@ impl ValidatedPreviewIntent {
                         expiry_epoch: intent.header.inner.end_epoch_exclusive,
                     }
                 },
+                subintent_hashes: self
+                    .subintents
+                    .iter()
+                    .map(|subintent| {
+                        if flags.skip_epoch_check {
+                            TransactionIntentHash::NotToCheck {
+                                intent_hash: subintent.subintent_hash,
+                            }
+                        } else {
+                            TransactionIntentHash::ToCheck {
+                                intent_hash: subintent.subintent_hash,
+                                expiry_epoch: subintent.end_epoch_exclusive,
+                            }
+                        }
+                    })
+                    .collect(),
                 epoch_range: if flags.skip_epoch_check {
                     None
                 } else {
@@ -73,10 +138,19 @@ impl ValidatedPreviewIntent {
                 num_of_signature_validations: 0, // Accounted for by tests in `common_transformation_costs.rs`.
                 auth_zone_params: AuthZoneParams {
                     initial_proofs,
+                    signatories: AuthAddresses::signer_set(&self.signer_public_keys),
                     virtual_resources,
+                    virtual_resource_amounts,
+                    subintent_initial_proofs: self
+                        .subintents
+                        .iter()
+                        .map(|subintent| AuthAddresses::signer_set(&subintent.signer_keys))
+                        .collect(),
                 },
                 costing_parameters: fee_payment,
                 pre_allocated_addresses: vec![],
+                worktop_leftovers_recipient: None,
+                message: intent.message.inner.metadata(),
             },
         )
     }
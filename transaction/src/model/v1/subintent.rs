@@ -0,0 +1,64 @@
+use super::*;
+use crate::internal_prelude::*;
+use radix_engine_interface::blueprints::transaction_processor::SubintentConstraints;
+
+/// A partial manifest which its signers allow anyone to execute as part of their own transaction,
+/// provided the resources declared by its constraints are exchanged.
+///
+/// The instructions run in their own frame, with their own worktop and an auth zone holding only
+/// the signature proofs of the subintent signers.
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct SubintentV1 {
+    pub network_id: u8,
+    pub start_epoch_inclusive: Epoch,
+    pub end_epoch_exclusive: Epoch,
+    pub nonce: u32,
+    pub instructions: Vec<InstructionV1>,
+    pub constraints: SubintentConstraints,
+}
+
+impl SubintentV1 {
+    /// The hash signed by the subintent signers, which is also tracked to prevent the subintent
+    /// from being executed more than once.
+    pub fn subintent_hash(&self) -> Hash {
+        hash(manifest_encode(self).expect("Subintent can be encoded"))
+    }
+
+    pub fn sign<S: Signer>(self, signers: &[&S]) -> SignedSubintentV1 {
+        let subintent_hash = self.subintent_hash();
+        let signatures = signers
+            .iter()
+            .map(|signer| IntentSignatureV1(signer.sign_with_public_key(&subintent_hash)))
+            .collect();
+        SignedSubintentV1 {
+            subintent: self,
+            signatures,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct SignedSubintentV1 {
+    pub subintent: SubintentV1,
+    pub signatures: Vec<IntentSignatureV1>,
+}
+
+impl SignedSubintentV1 {
+    /// Returns the subintents executed by the given instructions, in execution order.
+    pub fn extract_all(instructions: &[InstructionV1]) -> Vec<&SignedSubintentV1> {
+        instructions
+            .iter()
+            .filter_map(|instruction| match instruction {
+                InstructionV1::ExecuteSubintent { subintent, .. } => Some(subintent),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ValidatedSubintentV1 {
+    pub subintent_hash: Hash,
+    pub end_epoch_exclusive: Epoch,
+    pub signer_keys: Vec<PublicKey>,
+}
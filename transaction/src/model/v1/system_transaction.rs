@@ -108,18 +108,24 @@ impl PreparedSystemTransactionV1 {
                 intent_hash: TransactionIntentHash::NotToCheck {
                     intent_hash: self.hash_for_execution.hash,
                 },
+                subintent_hashes: vec![],
                 epoch_range: None,
                 payload_size: 0,
                 num_of_signature_validations: 0,
                 auth_zone_params: AuthZoneParams {
                     initial_proofs,
+                    signatories: BTreeSet::new(),
                     virtual_resources: BTreeSet::new(),
+                    virtual_resource_amounts: BTreeMap::new(),
+                    subintent_initial_proofs: vec![],
                 },
                 costing_parameters: TransactionCostingParameters {
                     tip_percentage: 0,
                     free_credit_in_xrd: Decimal::ZERO,
                 },
                 pre_allocated_addresses: self.pre_allocated_addresses.inner.clone(),
+                worktop_leftovers_recipient: None,
+                message: None,
             },
         )
     }
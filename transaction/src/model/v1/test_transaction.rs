@@ -1,5 +1,6 @@
 use crate::internal_prelude::*;
 use crate::model::*;
+use radix_engine_interface::api::node_modules::auth::AuthAddresses;
 use radix_engine_interface::blueprints::resource::NonFungibleGlobalId;
 use radix_engine_interface::crypto::hash;
 use radix_engine_interface::data::manifest::*;
@@ -19,6 +20,9 @@ pub struct PreparedTestTransaction {
     pub references: IndexSet<Reference>,
     pub blobs: IndexMap<Hash, Vec<u8>>,
     pub hash: Hash,
+    pub subintent_hashes: Vec<Hash>,
+    /// The keys which signed each subintent, or none if its signatures are invalid
+    pub subintent_signer_keys: Vec<Vec<PublicKey>>,
 }
 
 impl TestTransaction {
@@ -37,12 +41,28 @@ impl TestTransaction {
     }
 
     pub fn prepare(self) -> Result<PreparedTestTransaction, PrepareError> {
+        let subintents = SignedSubintentV1::extract_all(&self.instructions.0);
+        let subintent_hashes = subintents
+            .iter()
+            .map(|subintent| subintent.subintent.subintent_hash())
+            .collect();
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        let subintent_signer_keys = subintents
+            .iter()
+            .map(|subintent| {
+                validator
+                    .validate_subintent_signatures_v1(subintent)
+                    .unwrap_or_default()
+            })
+            .collect();
         let prepared_instructions = self.instructions.prepare_partial()?;
         Ok(PreparedTestTransaction {
             encoded_instructions: manifest_encode(&prepared_instructions.inner.0)?,
             references: prepared_instructions.references,
             blobs: self.blobs.prepare_partial()?.blobs_by_hash,
             hash: self.hash,
+            subintent_hashes,
+            subintent_signer_keys,
         })
     }
 }
@@ -52,6 +72,18 @@ impl PreparedTestTransaction {
         &'a self,
         initial_proofs: BTreeSet<NonFungibleGlobalId>,
     ) -> Executable<'a> {
+        // Test transactions aren't signed, so the signature proofs given as initial proofs stand
+        // in for the proofs of their signers
+        let signatories = initial_proofs
+            .iter()
+            .filter(|proof| {
+                let resource_address = proof.resource_address();
+                resource_address == SECP256K1_SIGNATURE_VIRTUAL_BADGE
+                    || resource_address == ED25519_SIGNATURE_VIRTUAL_BADGE
+            })
+            .cloned()
+            .collect();
+
         Executable::new(
             &self.encoded_instructions,
             &self.references,
@@ -60,6 +92,13 @@ impl PreparedTestTransaction {
                 intent_hash: TransactionIntentHash::NotToCheck {
                     intent_hash: self.hash,
                 },
+                subintent_hashes: self
+                    .subintent_hashes
+                    .iter()
+                    .map(|subintent_hash| TransactionIntentHash::NotToCheck {
+                        intent_hash: *subintent_hash,
+                    })
+                    .collect(),
                 epoch_range: None,
                 payload_size: self.encoded_instructions.len()
                     + self.blobs.values().map(|x| x.len()).sum::<usize>(),
@@ -67,13 +106,22 @@ impl PreparedTestTransaction {
                 num_of_signature_validations: initial_proofs.len() + 1,
                 auth_zone_params: AuthZoneParams {
                     initial_proofs,
+                    signatories,
                     virtual_resources: BTreeSet::new(),
+                    virtual_resource_amounts: BTreeMap::new(),
+                    subintent_initial_proofs: self
+                        .subintent_signer_keys
+                        .iter()
+                        .map(|signer_keys| AuthAddresses::signer_set(signer_keys))
+                        .collect(),
                 },
                 costing_parameters: TransactionCostingParameters {
                     tip_percentage: DEFAULT_TIP_PERCENTAGE,
                     free_credit_in_xrd: Decimal::ZERO,
                 },
                 pre_allocated_addresses: vec![],
+                worktop_leftovers_recipient: None,
+                message: None,
             },
         )
     }
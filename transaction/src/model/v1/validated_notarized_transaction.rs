@@ -6,6 +6,7 @@ pub struct ValidatedNotarizedTransactionV1 {
     pub prepared: PreparedNotarizedTransactionV1,
     pub encoded_instructions: Vec<u8>,
     pub signer_keys: Vec<PublicKey>,
+    pub subintents: Vec<ValidatedSubintentV1>,
     pub num_of_signature_validations: usize,
 }
 
@@ -46,6 +47,14 @@ impl ValidatedNotarizedTransactionV1 {
                     intent_hash: intent_hash.into_hash(),
                     expiry_epoch: header.end_epoch_exclusive,
                 },
+                subintent_hashes: self
+                    .subintents
+                    .iter()
+                    .map(|subintent| TransactionIntentHash::ToCheck {
+                        intent_hash: subintent.subintent_hash,
+                        expiry_epoch: subintent.end_epoch_exclusive,
+                    })
+                    .collect(),
                 epoch_range: Some(EpochRange {
                     start_epoch_inclusive: header.start_epoch_inclusive,
                     end_epoch_exclusive: header.end_epoch_exclusive,
@@ -54,13 +63,22 @@ impl ValidatedNotarizedTransactionV1 {
                 num_of_signature_validations: self.num_of_signature_validations,
                 auth_zone_params: AuthZoneParams {
                     initial_proofs: AuthAddresses::signer_set(&self.signer_keys),
+                    signatories: AuthAddresses::signer_set(&self.signer_keys),
                     virtual_resources: BTreeSet::new(),
+                    virtual_resource_amounts: BTreeMap::new(),
+                    subintent_initial_proofs: self
+                        .subintents
+                        .iter()
+                        .map(|subintent| AuthAddresses::signer_set(&subintent.signer_keys))
+                        .collect(),
                 },
                 costing_parameters: TransactionCostingParameters {
                     tip_percentage: intent.header.inner.tip_percentage,
                     free_credit_in_xrd,
                 },
                 pre_allocated_addresses: vec![],
+                worktop_leftovers_recipient: None,
+                message: intent.message.inner.metadata(),
             },
         )
     }
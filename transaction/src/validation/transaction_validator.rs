@@ -1,5 +1,9 @@
 use crate::internal_prelude::*;
 
+/// Statically validates transaction payloads, without access to the ledger state.
+///
+/// This is the validation the node performs before a transaction is executed, so it can also be
+/// used to check a transaction before it is submitted.
 pub trait TransactionValidator<Prepared: TransactionPayloadPreparable> {
     type Validated;
 
@@ -44,13 +48,20 @@ pub trait TransactionValidator<Prepared: TransactionPayloadPreparable> {
     ) -> Result<Self::Validated, TransactionValidationError>;
 }
 
+/// The limits enforced by static validation.
+///
+/// [`ValidationConfig::default`] holds the limits enforced by the node, which individual fields
+/// can be overridden from.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ValidationConfig {
     pub network_id: u8,
     pub max_notarized_payload_size: usize,
     pub min_tip_percentage: u16,
     pub max_tip_percentage: u16,
+    /// The maximum number of epochs between the start and end epoch of an intent.
     pub max_epoch_range: u64,
+    /// The maximum number of signatures on an intent or subintent, excluding the notary's.
+    pub max_signatures_per_intent: usize,
     pub message_validation: MessageValidationConfig,
 }
 
@@ -62,6 +73,7 @@ impl ValidationConfig {
             min_tip_percentage: MIN_TIP_PERCENTAGE,
             max_tip_percentage: MAX_TIP_PERCENTAGE,
             max_epoch_range: MAX_EPOCH_RANGE,
+            max_signatures_per_intent: MAX_NUMBER_OF_INTENT_SIGNATURES,
             message_validation: MessageValidationConfig::default(),
         }
     }
@@ -115,18 +127,30 @@ impl TransactionValidator<PreparedNotarizedTransactionV1> for NotarizedTransacti
             .validate_signatures_v1(&transaction)
             .map_err(TransactionValidationError::SignatureValidationError)?;
 
+        let subintents = self
+            .validate_subintents_v1(
+                &transaction.signed_intent.intent.header.inner,
+                &transaction.signed_intent.intent.instructions.inner.0,
+            )
+            .map_err(TransactionValidationError::SubintentValidationError)?;
+
         let num_of_signature_validations = transaction
             .signed_intent
             .intent_signatures
             .inner
             .signatures
             .len()
+            + subintents
+                .iter()
+                .map(|subintent| subintent.signer_keys.len())
+                .sum::<usize>()
             + 1;
 
         Ok(ValidatedNotarizedTransactionV1 {
             prepared: transaction,
             encoded_instructions,
             signer_keys,
+            subintents,
             num_of_signature_validations,
         })
     }
@@ -140,6 +164,13 @@ impl NotarizedTransactionValidator {
     pub fn validate_preview_intent_v1(
         &self,
         preview_intent: PreviewIntentV1,
+    ) -> Result<ValidatedPreviewIntent, TransactionValidationError> {
+        self.validate_preview_intent_v2(preview_intent.into())
+    }
+
+    pub fn validate_preview_intent_v2(
+        &self,
+        preview_intent: PreviewIntentV2,
     ) -> Result<ValidatedPreviewIntent, TransactionValidationError> {
         let intent = preview_intent.intent.prepare()?;
 
@@ -147,11 +178,17 @@ impl NotarizedTransactionValidator {
 
         let encoded_instructions = manifest_encode(&intent.instructions.inner.0)?;
 
+        let subintents = self
+            .validate_subintents_v1(&intent.header.inner, &intent.instructions.inner.0)
+            .map_err(TransactionValidationError::SubintentValidationError)?;
+
         Ok(ValidatedPreviewIntent {
             intent,
             encoded_instructions,
+            subintents,
             signer_public_keys: preview_intent.signer_public_keys,
             flags: preview_intent.flags,
+            virtual_proofs: preview_intent.virtual_proofs,
         })
     }
 
@@ -274,12 +311,164 @@ impl NotarizedTransactionValidator {
                     let _ = id_validator.new_address_reservation();
                     id_validator.new_named_address();
                 }
+                InstructionV1::AssertPackageCodeHash { .. } => {}
+                InstructionV1::EnqueueDeferredManifest {
+                    instructions,
+                    payment,
+                    ..
+                } => {
+                    id_validator
+                        .drop_bucket(&payment)
+                        .map_err(TransactionValidationError::IdValidationError)?;
+                    Self::validate_deferred_instructions_v1(instructions)?;
+                }
+                InstructionV1::ExecuteSubintent { subintent, inputs } => {
+                    for input in inputs {
+                        id_validator
+                            .drop_bucket(&input)
+                            .map_err(TransactionValidationError::IdValidationError)?;
+                    }
+                    Self::validate_subintent_instructions_v1(&subintent.subintent.instructions)?;
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Validates the instructions of a subintent, which are run in their own processor, so
+    /// bucket and proof ids are validated separately from the executing manifest.
+    pub fn validate_subintent_instructions_v1(
+        instructions: &[InstructionV1],
+    ) -> Result<(), TransactionValidationError> {
+        if !SignedSubintentV1::extract_all(instructions).is_empty() {
+            return Err(TransactionValidationError::SubintentValidationError(
+                SubintentValidationError::NestedSubintent,
+            ));
+        }
+
+        Self::validate_instructions_v1(instructions)
+    }
+
+    /// Validates the header and signatures of the subintents executed by the instructions,
+    /// returning them in execution order.
+    ///
+    /// The epoch range of each subintent must contain that of the transaction, so that the
+    /// runtime epoch check of the transaction also covers the subintents.
+    pub fn validate_subintents_v1(
+        &self,
+        header: &TransactionHeaderV1,
+        instructions: &[InstructionV1],
+    ) -> Result<Vec<ValidatedSubintentV1>, SubintentValidationError> {
+        let mut subintent_hashes = index_set_new();
+        let mut validated_subintents = Vec::new();
+        for signed_subintent in SignedSubintentV1::extract_all(instructions) {
+            let subintent = &signed_subintent.subintent;
+            if subintent.network_id != self.config.network_id {
+                return Err(SubintentValidationError::InvalidNetwork);
+            }
+            let max_end_epoch = subintent
+                .start_epoch_inclusive
+                .after(self.config.max_epoch_range)
+                .ok_or(SubintentValidationError::InvalidEpochRange)?;
+            if subintent.end_epoch_exclusive > max_end_epoch
+                || header.start_epoch_inclusive < subintent.start_epoch_inclusive
+                || header.end_epoch_exclusive > subintent.end_epoch_exclusive
+            {
+                return Err(SubintentValidationError::InvalidEpochRange);
+            }
+
+            let subintent_hash = subintent.subintent_hash();
+            if !subintent_hashes.insert(subintent_hash) {
+                return Err(SubintentValidationError::DuplicateSubintent);
+            }
+
+            let signer_keys = self
+                .validate_subintent_signatures_v1(signed_subintent)
+                .map_err(SubintentValidationError::SignatureValidationError)?;
+
+            validated_subintents.push(ValidatedSubintentV1 {
+                subintent_hash,
+                end_epoch_exclusive: subintent.end_epoch_exclusive,
+                signer_keys,
+            });
+        }
+
+        Ok(validated_subintents)
+    }
+
+    pub fn validate_subintent_signatures_v1(
+        &self,
+        signed_subintent: &SignedSubintentV1,
+    ) -> Result<Vec<PublicKey>, SignatureValidationError> {
+        let subintent_hash = signed_subintent.subintent.subintent_hash();
+        let signers =
+            self.validate_intent_signatures_v1(&subintent_hash, &signed_subintent.signatures)?;
+
+        Ok(signers.into_iter().collect())
+    }
+
+    /// Recovers the signers of the signatures on an intent, which must be distinct and within
+    /// the configured limit.
+    fn validate_intent_signatures_v1(
+        &self,
+        signed_hash: &Hash,
+        signatures: &[IntentSignatureV1],
+    ) -> Result<IndexSet<PublicKey>, SignatureValidationError> {
+        if signatures.len() > self.config.max_signatures_per_intent {
+            return Err(SignatureValidationError::TooManySignatures {
+                actual: signatures.len(),
+                permitted: self.config.max_signatures_per_intent,
+            });
+        }
+
+        let mut signers = index_set_new();
+        for signature in signatures {
+            let public_key = recover(signed_hash, &signature.0)
+                .ok_or(SignatureValidationError::InvalidIntentSignature)?;
+
+            if !verify(signed_hash, &public_key, &signature.0.signature()) {
+                return Err(SignatureValidationError::InvalidIntentSignature);
+            }
+
+            if !signers.insert(public_key) {
+                return Err(SignatureValidationError::DuplicateSigner(public_key));
+            }
+        }
+
+        Ok(signers)
+    }
+
+    /// Validates the instructions of a deferred manifest, which are run in their own processor
+    /// when the manifest is executed, so bucket and proof ids are validated separately from the
+    /// enqueuing manifest.
+    pub fn validate_deferred_instructions_v1(
+        instructions: &[InstructionV1],
+    ) -> Result<(), TransactionValidationError> {
+        for inst in instructions {
+            match inst {
+                InstructionV1::EnqueueDeferredManifest { .. } => {
+                    return Err(TransactionValidationError::DeferredManifestValidationError(
+                        DeferredManifestValidationError::NestedDeferredManifest,
+                    ));
+                }
+                InstructionV1::CallDirectVaultMethod { .. } => {
+                    return Err(TransactionValidationError::DeferredManifestValidationError(
+                        DeferredManifestValidationError::DirectVaultMethodCall,
+                    ));
+                }
+                InstructionV1::ExecuteSubintent { .. } => {
+                    return Err(TransactionValidationError::DeferredManifestValidationError(
+                        DeferredManifestValidationError::SubintentExecution,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Self::validate_instructions_v1(instructions)
+    }
+
     pub fn validate_header_v1(
         &self,
         header: &TransactionHeaderV1,
@@ -316,32 +505,12 @@ impl NotarizedTransactionValidator {
         transaction: &PreparedNotarizedTransactionV1,
     ) -> Result<Vec<PublicKey>, SignatureValidationError> {
         // TODO: split into static validation part and runtime validation part to support more signatures
-        if transaction
-            .signed_intent
-            .intent_signatures
-            .inner
-            .signatures
-            .len()
-            > MAX_NUMBER_OF_INTENT_SIGNATURES
-        {
-            return Err(SignatureValidationError::TooManySignatures);
-        }
-
         // verify intent signature
-        let mut signers = index_set_new();
         let intent_hash = transaction.intent_hash().into_hash();
-        for intent_signature in &transaction.signed_intent.intent_signatures.inner.signatures {
-            let public_key = recover(&intent_hash, &intent_signature.0)
-                .ok_or(SignatureValidationError::InvalidIntentSignature)?;
-
-            if !verify(&intent_hash, &public_key, &intent_signature.0.signature()) {
-                return Err(SignatureValidationError::InvalidIntentSignature);
-            }
-
-            if !signers.insert(public_key) {
-                return Err(SignatureValidationError::DuplicateSigner);
-            }
-        }
+        let mut signers = self.validate_intent_signatures_v1(
+            &intent_hash,
+            &transaction.signed_intent.intent_signatures.inner.signatures,
+        )?;
 
         let header = &transaction.signed_intent.intent.header.inner;
 
@@ -436,6 +605,7 @@ impl NotarizedTransactionValidator {
 
 #[cfg(test)]
 mod tests {
+    use radix_engine_interface::blueprints::transaction_processor::SubintentConstraints;
     use radix_engine_interface::network::NetworkDefinition;
 
     use super::*;
@@ -488,18 +658,47 @@ mod tests {
     fn test_invalid_signatures() {
         assert_invalid_tx!(
             TransactionValidationError::SignatureValidationError(
-                SignatureValidationError::TooManySignatures
+                SignatureValidationError::TooManySignatures {
+                    actual: 19,
+                    permitted: MAX_NUMBER_OF_INTENT_SIGNATURES,
+                }
             ),
             (Epoch::zero(), Epoch::of(100), 5, (1..20).collect(), 2)
         );
         assert_invalid_tx!(
             TransactionValidationError::SignatureValidationError(
-                SignatureValidationError::DuplicateSigner
+                SignatureValidationError::DuplicateSigner(
+                    Secp256k1PrivateKey::from_u64(1)
+                        .unwrap()
+                        .public_key()
+                        .into()
+                )
             ),
             (Epoch::zero(), Epoch::of(100), 5, vec![1, 1], 2)
         );
     }
 
+    #[test]
+    fn test_configured_signature_limit() {
+        let validator = NotarizedTransactionValidator::new(ValidationConfig {
+            max_signatures_per_intent: 1,
+            ..ValidationConfig::simulator()
+        });
+        let transaction = create_transaction(Epoch::zero(), Epoch::of(100), 5, vec![1, 2], 3);
+
+        let result = validator.validate(transaction.prepare().unwrap());
+
+        assert_eq!(
+            result.expect_err("Should be an error"),
+            TransactionValidationError::SignatureValidationError(
+                SignatureValidationError::TooManySignatures {
+                    actual: 2,
+                    permitted: 1,
+                }
+            )
+        );
+    }
+
     #[test]
     fn test_valid_preview() {
         // Build the whole transaction but only really care about the intent
@@ -852,6 +1051,161 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_nested_deferred_manifest() {
+        let nested = ManifestBuilder::new()
+            .take_from_worktop(XRD, dec!(1), "payment")
+            .enqueue_deferred_manifest(
+                ManifestBuilder::new().build(),
+                Epoch::of(10),
+                Epoch::of(20),
+                "payment",
+                AccessRule::AllowAll,
+            )
+            .build();
+        let transaction = create_transaction_advanced(
+            Epoch::of(0),
+            Epoch::of(40),
+            123,
+            vec![55],
+            66,
+            ManifestBuilder::new()
+                .take_from_worktop(XRD, dec!(1), "payment")
+                .enqueue_deferred_manifest(
+                    nested,
+                    Epoch::of(10),
+                    Epoch::of(20),
+                    "payment",
+                    AccessRule::AllowAll,
+                )
+                .build(),
+        );
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        assert_eq!(
+            validator.validate_from_payload_bytes(&transaction.to_payload_bytes().unwrap()),
+            Err(TransactionValidationError::DeferredManifestValidationError(
+                DeferredManifestValidationError::NestedDeferredManifest
+            ))
+        );
+    }
+
+    fn create_subintent(
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        manifest: TransactionManifestV1,
+    ) -> SubintentV1 {
+        SubintentV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: start_epoch,
+            end_epoch_exclusive: end_epoch,
+            nonce: 5,
+            instructions: manifest.instructions,
+            constraints: SubintentConstraints::default(),
+        }
+    }
+
+    fn validate_with_subintent(
+        subintent: SignedSubintentV1,
+    ) -> Result<ValidatedNotarizedTransactionV1, TransactionValidationError> {
+        let transaction = create_transaction_advanced(
+            Epoch::of(10),
+            Epoch::of(20),
+            123,
+            vec![55],
+            66,
+            ManifestBuilder::new()
+                .execute_subintent(subintent, Vec::<ManifestBucket>::new())
+                .build(),
+        );
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        validator.validate_from_payload_bytes(&transaction.to_payload_bytes().unwrap())
+    }
+
+    #[test]
+    fn test_valid_subintent() {
+        let signer = Secp256k1PrivateKey::from_u64(77).unwrap();
+        let subintent = create_subintent(
+            Epoch::of(0),
+            Epoch::of(40),
+            ManifestBuilder::new().drop_auth_zone_proofs().build(),
+        )
+        .sign(&[&signer]);
+        let subintent_hash = subintent.subintent.subintent_hash();
+
+        let validated = validate_with_subintent(subintent).unwrap();
+
+        assert_eq!(
+            validated.subintents,
+            vec![ValidatedSubintentV1 {
+                subintent_hash,
+                end_epoch_exclusive: Epoch::of(40),
+                signer_keys: vec![signer.public_key().into()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_subintent_epoch_range_must_cover_transaction() {
+        let subintent = create_subintent(
+            Epoch::of(0),
+            Epoch::of(15),
+            ManifestBuilder::new().drop_auth_zone_proofs().build(),
+        )
+        .sign(&[&Secp256k1PrivateKey::from_u64(77).unwrap()]);
+
+        assert_eq!(
+            validate_with_subintent(subintent),
+            Err(TransactionValidationError::SubintentValidationError(
+                SubintentValidationError::InvalidEpochRange
+            ))
+        );
+    }
+
+    #[test]
+    fn test_subintent_duplicate_signer() {
+        let signer = Secp256k1PrivateKey::from_u64(77).unwrap();
+        let subintent = create_subintent(
+            Epoch::of(0),
+            Epoch::of(40),
+            ManifestBuilder::new().drop_auth_zone_proofs().build(),
+        )
+        .sign(&[&signer, &signer]);
+
+        assert_eq!(
+            validate_with_subintent(subintent),
+            Err(TransactionValidationError::SubintentValidationError(
+                SubintentValidationError::SignatureValidationError(
+                    SignatureValidationError::DuplicateSigner(signer.public_key().into())
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_nested_subintent() {
+        let nested = create_subintent(
+            Epoch::of(0),
+            Epoch::of(40),
+            ManifestBuilder::new().drop_auth_zone_proofs().build(),
+        )
+        .sign(&[&Secp256k1PrivateKey::from_u64(77).unwrap()]);
+        let subintent = create_subintent(
+            Epoch::of(0),
+            Epoch::of(40),
+            ManifestBuilder::new()
+                .execute_subintent(nested, Vec::<ManifestBucket>::new())
+                .build(),
+        )
+        .sign(&[&Secp256k1PrivateKey::from_u64(78).unwrap()]);
+
+        assert_eq!(
+            validate_with_subintent(subintent),
+            Err(TransactionValidationError::SubintentValidationError(
+                SubintentValidationError::NestedSubintent
+            ))
+        );
+    }
+
     #[test]
     fn verify_call_direct_method_args_are_processed() {
         let transaction = create_transaction_advanced(
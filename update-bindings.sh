@@ -28,6 +28,9 @@ list=(
     "package_sim1pkgxxxxxxxxxaccntxxxxxxxxxx000929625493xxxxxxxxxrn8jm6" # Account
     "package_sim1pkgxxxxxxxxxplxxxxxxxxxxxxx020379220524xxxxxxxxxl5e8k6" # Pools
     "package_sim1pkgxxxxxxxxxcntrlrxxxxxxxxx000648572295xxxxxxxxxxc5z0l" # Access Controller
+    "package_sim1pkgxxxxxxxxxprcfedxxxxxxxxx076707005457xxxxxxxxxlg8gf3" # Price Feed
+    "package_sim1pkgxxxxxxxxxpaymntxxxxxxxxx002478752670xxxxxxxxxlkm6rh" # Payment Scheduler
+    "package_sim1pkgxxxxxxxxxmtchngxxxxxxxxx000866890702xxxxxxxxx9q8kr5" # Order Book
 );
 for address in ${list[@]}; 
 do